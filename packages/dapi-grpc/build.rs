@@ -72,12 +72,15 @@ struct MappingConfig {
 fn configure_platform(mut platform: MappingConfig) -> MappingConfig {
     // Derive features for versioned messages
     //
-    // "GetConsensusParamsRequest" is excluded as this message does not support proofs
-    const VERSIONED_REQUESTS: [&str; 44] = [
+    // "GetConsensusParamsRequest", "SimulateStateTransitionRequest" and
+    // "GetBlockStateTransitionsRequest" are excluded as these messages do not support proofs
+    const VERSIONED_REQUESTS: [&str; 53] = [
         "GetDataContractHistoryRequest",
         "GetDataContractRequest",
         "GetDataContractsRequest",
         "GetDocumentsRequest",
+        "SubscribeDocumentsRequest",
+        "GetDocumentsAggregateRequest",
         "GetIdentitiesByPublicKeyHashesRequest",
         "GetIdentitiesRequest",
         "GetIdentitiesBalancesRequest",
@@ -89,6 +92,7 @@ fn configure_platform(mut platform: MappingConfig) -> MappingConfig {
         "GetIdentityByPublicKeyHashRequest",
         "GetIdentityKeysRequest",
         "GetIdentityRequest",
+        "GetIdentityStateTransitionsRequest",
         "WaitForStateTransitionResultRequest",
         "GetProtocolVersionUpgradeStateRequest",
         "GetProtocolVersionUpgradeVoteStatusRequest",
@@ -100,6 +104,7 @@ fn configure_platform(mut platform: MappingConfig) -> MappingConfig {
         "GetContestedResourceVotersForIdentityRequest",
         "GetContestedResourceIdentityVotesRequest",
         "GetVotePollsByEndDateRequest",
+        "GetYesNoAbstainVotePollStateRequest",
         "GetTotalCreditsInPlatformRequest",
         "GetEvonodesProposedEpochBlocksByIdsRequest",
         "GetEvonodesProposedEpochBlocksByRangeRequest",
@@ -113,6 +118,11 @@ fn configure_platform(mut platform: MappingConfig) -> MappingConfig {
         "GetTokenContractInfoRequest",
         "GetTokenStatusesRequest",
         "GetTokenTotalSupplyRequest",
+        "GetTokenHoldersRequest",
+        "GetTokenOrderBookRequest",
+        "GetTokenVestingsRequest",
+        "GetTokenAllowancesRequest",
+        "GetTokenEventsRequest",
         "GetGroupInfoRequest",
         "GetGroupInfosRequest",
         "GetGroupActionsRequest",
@@ -123,16 +133,20 @@ fn configure_platform(mut platform: MappingConfig) -> MappingConfig {
     // The following responses are excluded as they don't support proofs:
     // - "GetConsensusParamsResponse"
     // - "GetStatusResponse"
+    // - "SimulateStateTransitionResponse"
+    // - "GetBlockStateTransitionsResponse"
     //
     // The following responses are excluded as they need custom proof handling:
     // - "GetIdentityByNonUniquePublicKeyHashResponse"
+    // - "SubscribeDocumentsResponse", which carries a proof per matched state transition
     //
     //  "GetEvonodesProposedEpochBlocksResponse" is used for 2 Requests
-    const VERSIONED_RESPONSES: [&str; 42] = [
+    const VERSIONED_RESPONSES: [&str; 50] = [
         "GetDataContractHistoryResponse",
         "GetDataContractResponse",
         "GetDataContractsResponse",
         "GetDocumentsResponse",
+        "GetDocumentsAggregateResponse",
         "GetIdentitiesByPublicKeyHashesResponse",
         "GetIdentitiesResponse",
         "GetIdentitiesBalancesResponse",
@@ -143,6 +157,7 @@ fn configure_platform(mut platform: MappingConfig) -> MappingConfig {
         "GetIdentityByPublicKeyHashResponse",
         "GetIdentityKeysResponse",
         "GetIdentityResponse",
+        "GetIdentityStateTransitionsResponse",
         "WaitForStateTransitionResultResponse",
         "GetEpochsInfoResponse",
         "GetProtocolVersionUpgradeStateResponse",
@@ -155,6 +170,7 @@ fn configure_platform(mut platform: MappingConfig) -> MappingConfig {
         "GetContestedResourceVotersForIdentityResponse",
         "GetContestedResourceIdentityVotesResponse",
         "GetVotePollsByEndDateResponse",
+        "GetYesNoAbstainVotePollStateResponse",
         "GetTotalCreditsInPlatformResponse",
         "GetEvonodesProposedEpochBlocksResponse",
         "GetIdentityTokenBalancesResponse",
@@ -166,6 +182,11 @@ fn configure_platform(mut platform: MappingConfig) -> MappingConfig {
         "GetTokenContractInfoResponse",
         "GetTokenStatusesResponse",
         "GetTokenTotalSupplyResponse",
+        "GetTokenHoldersResponse",
+        "GetTokenOrderBookResponse",
+        "GetTokenVestingsResponse",
+        "GetTokenAllowancesResponse",
+        "GetTokenEventsResponse",
         "GetGroupInfoResponse",
         "GetGroupInfosResponse",
         "GetGroupActionsResponse",
@@ -232,6 +232,68 @@ public final class CoreGrpc {
     return getGetEstimatedTransactionFeeMethod;
   }
 
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest,
+      org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse> getGetBlockFiltersMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getBlockFilters",
+      requestType = org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest.class,
+      responseType = org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest,
+      org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse> getGetBlockFiltersMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest, org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse> getGetBlockFiltersMethod;
+    if ((getGetBlockFiltersMethod = CoreGrpc.getGetBlockFiltersMethod) == null) {
+      synchronized (CoreGrpc.class) {
+        if ((getGetBlockFiltersMethod = CoreGrpc.getGetBlockFiltersMethod) == null) {
+          CoreGrpc.getGetBlockFiltersMethod = getGetBlockFiltersMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest, org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getBlockFilters"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new CoreMethodDescriptorSupplier("getBlockFilters"))
+              .build();
+        }
+      }
+    }
+    return getGetBlockFiltersMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest,
+      org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse> getGetBlockFilterHeadersMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getBlockFilterHeaders",
+      requestType = org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest.class,
+      responseType = org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest,
+      org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse> getGetBlockFilterHeadersMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest, org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse> getGetBlockFilterHeadersMethod;
+    if ((getGetBlockFilterHeadersMethod = CoreGrpc.getGetBlockFilterHeadersMethod) == null) {
+      synchronized (CoreGrpc.class) {
+        if ((getGetBlockFilterHeadersMethod = CoreGrpc.getGetBlockFilterHeadersMethod) == null) {
+          CoreGrpc.getGetBlockFilterHeadersMethod = getGetBlockFilterHeadersMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest, org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getBlockFilterHeaders"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new CoreMethodDescriptorSupplier("getBlockFilterHeaders"))
+              .build();
+        }
+      }
+    }
+    return getGetBlockFilterHeadersMethod;
+  }
+
   private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.CoreOuterClass.BlockHeadersWithChainLocksRequest,
       org.dash.platform.dapi.v0.CoreOuterClass.BlockHeadersWithChainLocksResponse> getSubscribeToBlockHeadersWithChainLocksMethod;
 
@@ -422,6 +484,20 @@ public final class CoreGrpc {
       io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetEstimatedTransactionFeeMethod(), responseObserver);
     }
 
+    /**
+     */
+    public void getBlockFilters(org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetBlockFiltersMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void getBlockFilterHeaders(org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetBlockFilterHeadersMethod(), responseObserver);
+    }
+
     /**
      */
     public void subscribeToBlockHeadersWithChainLocks(org.dash.platform.dapi.v0.CoreOuterClass.BlockHeadersWithChainLocksRequest request,
@@ -494,6 +570,20 @@ public final class CoreGrpc {
                 org.dash.platform.dapi.v0.CoreOuterClass.GetEstimatedTransactionFeeRequest,
                 org.dash.platform.dapi.v0.CoreOuterClass.GetEstimatedTransactionFeeResponse>(
                   this, METHODID_GET_ESTIMATED_TRANSACTION_FEE)))
+          .addMethod(
+            getGetBlockFiltersMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest,
+                org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse>(
+                  this, METHODID_GET_BLOCK_FILTERS)))
+          .addMethod(
+            getGetBlockFilterHeadersMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest,
+                org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse>(
+                  this, METHODID_GET_BLOCK_FILTER_HEADERS)))
           .addMethod(
             getSubscribeToBlockHeadersWithChainLocksMethod(),
             io.grpc.stub.ServerCalls.asyncServerStreamingCall(
@@ -589,6 +679,22 @@ public final class CoreGrpc {
           getChannel().newCall(getGetEstimatedTransactionFeeMethod(), getCallOptions()), request, responseObserver);
     }
 
+    /**
+     */
+    public void getBlockFilters(org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetBlockFiltersMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void getBlockFilterHeaders(org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetBlockFilterHeadersMethod(), getCallOptions()), request, responseObserver);
+    }
+
     /**
      */
     public void subscribeToBlockHeadersWithChainLocks(org.dash.platform.dapi.v0.CoreOuterClass.BlockHeadersWithChainLocksRequest request,
@@ -677,6 +783,20 @@ public final class CoreGrpc {
           getChannel(), getGetEstimatedTransactionFeeMethod(), getCallOptions(), request);
     }
 
+    /**
+     */
+    public org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse getBlockFilters(org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetBlockFiltersMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse getBlockFilterHeaders(org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetBlockFilterHeadersMethod(), getCallOptions(), request);
+    }
+
     /**
      */
     public java.util.Iterator<org.dash.platform.dapi.v0.CoreOuterClass.BlockHeadersWithChainLocksResponse> subscribeToBlockHeadersWithChainLocks(
@@ -771,6 +891,22 @@ public final class CoreGrpc {
       return io.grpc.stub.ClientCalls.futureUnaryCall(
           getChannel().newCall(getGetEstimatedTransactionFeeMethod(), getCallOptions()), request);
     }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse> getBlockFilters(
+        org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetBlockFiltersMethod(), getCallOptions()), request);
+    }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse> getBlockFilterHeaders(
+        org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetBlockFilterHeadersMethod(), getCallOptions()), request);
+    }
   }
 
   private static final int METHODID_GET_BLOCKCHAIN_STATUS = 0;
@@ -780,9 +916,11 @@ public final class CoreGrpc {
   private static final int METHODID_BROADCAST_TRANSACTION = 4;
   private static final int METHODID_GET_TRANSACTION = 5;
   private static final int METHODID_GET_ESTIMATED_TRANSACTION_FEE = 6;
-  private static final int METHODID_SUBSCRIBE_TO_BLOCK_HEADERS_WITH_CHAIN_LOCKS = 7;
-  private static final int METHODID_SUBSCRIBE_TO_TRANSACTIONS_WITH_PROOFS = 8;
-  private static final int METHODID_SUBSCRIBE_TO_MASTERNODE_LIST = 9;
+  private static final int METHODID_GET_BLOCK_FILTERS = 7;
+  private static final int METHODID_GET_BLOCK_FILTER_HEADERS = 8;
+  private static final int METHODID_SUBSCRIBE_TO_BLOCK_HEADERS_WITH_CHAIN_LOCKS = 9;
+  private static final int METHODID_SUBSCRIBE_TO_TRANSACTIONS_WITH_PROOFS = 10;
+  private static final int METHODID_SUBSCRIBE_TO_MASTERNODE_LIST = 11;
 
   private static final class MethodHandlers<Req, Resp> implements
       io.grpc.stub.ServerCalls.UnaryMethod<Req, Resp>,
@@ -829,6 +967,14 @@ public final class CoreGrpc {
           serviceImpl.getEstimatedTransactionFee((org.dash.platform.dapi.v0.CoreOuterClass.GetEstimatedTransactionFeeRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.CoreOuterClass.GetEstimatedTransactionFeeResponse>) responseObserver);
           break;
+        case METHODID_GET_BLOCK_FILTERS:
+          serviceImpl.getBlockFilters((org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFiltersResponse>) responseObserver);
+          break;
+        case METHODID_GET_BLOCK_FILTER_HEADERS:
+          serviceImpl.getBlockFilterHeaders((org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.CoreOuterClass.GetBlockFilterHeadersResponse>) responseObserver);
+          break;
         case METHODID_SUBSCRIBE_TO_BLOCK_HEADERS_WITH_CHAIN_LOCKS:
           serviceImpl.subscribeToBlockHeadersWithChainLocks((org.dash.platform.dapi.v0.CoreOuterClass.BlockHeadersWithChainLocksRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.CoreOuterClass.BlockHeadersWithChainLocksResponse>) responseObserver);
@@ -909,6 +1055,8 @@ public final class CoreGrpc {
               .addMethod(getBroadcastTransactionMethod())
               .addMethod(getGetTransactionMethod())
               .addMethod(getGetEstimatedTransactionFeeMethod())
+              .addMethod(getGetBlockFiltersMethod())
+              .addMethod(getGetBlockFilterHeadersMethod())
               .addMethod(getSubscribeToBlockHeadersWithChainLocksMethod())
               .addMethod(getSubscribeToTransactionsWithProofsMethod())
               .addMethod(getSubscribeToMasternodeListMethod())
@@ -40,6 +40,10 @@ const {
             BlockHeadersWithChainLocksResponse: PBJSBlockHeadersWithChainLocksResponse,
             GetEstimatedTransactionFeeRequest: PBJSGetEstimatedTransactionFeeRequest,
             GetEstimatedTransactionFeeResponse: PBJSGetEstimatedTransactionFeeResponse,
+            GetBlockFiltersRequest: PBJSGetBlockFiltersRequest,
+            GetBlockFiltersResponse: PBJSGetBlockFiltersResponse,
+            GetBlockFilterHeadersRequest: PBJSGetBlockFilterHeadersRequest,
+            GetBlockFilterHeadersResponse: PBJSGetBlockFilterHeadersResponse,
             TransactionsWithProofsRequest: PBJSTransactionsWithProofsRequest,
             TransactionsWithProofsResponse: PBJSTransactionsWithProofsResponse,
             MasternodeListRequest: PBJSMasternodeListRequest,
@@ -60,6 +64,8 @@ const {
   GetTransactionResponse: ProtocGetTransactionResponse,
   BlockHeadersWithChainLocksResponse: ProtocBlockHeadersWithChainLocksResponse,
   GetEstimatedTransactionFeeResponse: ProtocGetEstimatedTransactionFeeResponse,
+  GetBlockFiltersResponse: ProtocGetBlockFiltersResponse,
+  GetBlockFilterHeadersResponse: ProtocGetBlockFilterHeadersResponse,
   TransactionsWithProofsResponse: ProtocTransactionsWithProofsResponse,
   MasternodeListResponse: ProtocMasternodeListResponse,
 } = require('./core_protoc');
@@ -115,6 +121,14 @@ class CorePromiseClient {
     this.client.getEstimatedTransactionFee = promisify(
       this.client.getEstimatedTransactionFee.bind(this.client),
     );
+
+    this.client.getBlockFilters = promisify(
+      this.client.getBlockFilters.bind(this.client),
+    );
+
+    this.client.getBlockFilterHeaders = promisify(
+      this.client.getBlockFilterHeaders.bind(this.client),
+    );
   }
 
   /**
@@ -334,6 +348,68 @@ class CorePromiseClient {
     );
   }
 
+  /**
+   * @param {!GetBlockFiltersRequest} getBlockFiltersRequest
+   * @param {?Object<string, string>} metadata
+   * @param {CallOptions} [options={}]
+   * @returns {Promise<!GetBlockFiltersResponse>}
+   */
+  getBlockFilters(getBlockFiltersRequest, metadata = {}, options = {}) {
+    if (!isObject(metadata)) {
+      throw new Error('metadata must be an object');
+    }
+
+    return this.client.getBlockFilters(
+      getBlockFiltersRequest,
+      convertObjectToMetadata(metadata),
+      {
+        interceptors: [
+          jsonToProtobufInterceptorFactory(
+            jsonToProtobufFactory(
+              ProtocGetBlockFiltersResponse,
+              PBJSGetBlockFiltersResponse,
+            ),
+            protobufToJsonFactory(
+              PBJSGetBlockFiltersRequest,
+            ),
+          ),
+        ],
+        ...options,
+      },
+    );
+  }
+
+  /**
+   * @param {!GetBlockFilterHeadersRequest} getBlockFilterHeadersRequest
+   * @param {?Object<string, string>} metadata
+   * @param {CallOptions} [options={}]
+   * @returns {Promise<!GetBlockFilterHeadersResponse>}
+   */
+  getBlockFilterHeaders(getBlockFilterHeadersRequest, metadata = {}, options = {}) {
+    if (!isObject(metadata)) {
+      throw new Error('metadata must be an object');
+    }
+
+    return this.client.getBlockFilterHeaders(
+      getBlockFilterHeadersRequest,
+      convertObjectToMetadata(metadata),
+      {
+        interceptors: [
+          jsonToProtobufInterceptorFactory(
+            jsonToProtobufFactory(
+              ProtocGetBlockFilterHeadersResponse,
+              PBJSGetBlockFilterHeadersResponse,
+            ),
+            protobufToJsonFactory(
+              PBJSGetBlockFilterHeadersRequest,
+            ),
+          ),
+        ],
+        ...options,
+      },
+    );
+  }
+
   /**
    * @param {!BlockHeadersWithChainLocksRequest} blockHeadersWithChainLocksRequest
    * @param {?Object<string, string>} metadata
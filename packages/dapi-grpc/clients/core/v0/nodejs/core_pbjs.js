@@ -317,6 +317,72 @@ $root.org = (function() {
                          * @variation 2
                          */
 
+                        /**
+                         * Callback as used by {@link org.dash.platform.dapi.v0.Core#getBlockFilters}.
+                         * @memberof org.dash.platform.dapi.v0.Core
+                         * @typedef getBlockFiltersCallback
+                         * @type {function}
+                         * @param {Error|null} error Error, if any
+                         * @param {org.dash.platform.dapi.v0.GetBlockFiltersResponse} [response] GetBlockFiltersResponse
+                         */
+
+                        /**
+                         * Calls getBlockFilters.
+                         * @function getBlockFilters
+                         * @memberof org.dash.platform.dapi.v0.Core
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFiltersRequest} request GetBlockFiltersRequest message or plain object
+                         * @param {org.dash.platform.dapi.v0.Core.getBlockFiltersCallback} callback Node-style callback called with the error, if any, and GetBlockFiltersResponse
+                         * @returns {undefined}
+                         * @variation 1
+                         */
+                        Object.defineProperty(Core.prototype.getBlockFilters = function getBlockFilters(request, callback) {
+                            return this.rpcCall(getBlockFilters, $root.org.dash.platform.dapi.v0.GetBlockFiltersRequest, $root.org.dash.platform.dapi.v0.GetBlockFiltersResponse, request, callback);
+                        }, "name", { value: "getBlockFilters" });
+
+                        /**
+                         * Calls getBlockFilters.
+                         * @function getBlockFilters
+                         * @memberof org.dash.platform.dapi.v0.Core
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFiltersRequest} request GetBlockFiltersRequest message or plain object
+                         * @returns {Promise<org.dash.platform.dapi.v0.GetBlockFiltersResponse>} Promise
+                         * @variation 2
+                         */
+
+                        /**
+                         * Callback as used by {@link org.dash.platform.dapi.v0.Core#getBlockFilterHeaders}.
+                         * @memberof org.dash.platform.dapi.v0.Core
+                         * @typedef getBlockFilterHeadersCallback
+                         * @type {function}
+                         * @param {Error|null} error Error, if any
+                         * @param {org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse} [response] GetBlockFilterHeadersResponse
+                         */
+
+                        /**
+                         * Calls getBlockFilterHeaders.
+                         * @function getBlockFilterHeaders
+                         * @memberof org.dash.platform.dapi.v0.Core
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFilterHeadersRequest} request GetBlockFilterHeadersRequest message or plain object
+                         * @param {org.dash.platform.dapi.v0.Core.getBlockFilterHeadersCallback} callback Node-style callback called with the error, if any, and GetBlockFilterHeadersResponse
+                         * @returns {undefined}
+                         * @variation 1
+                         */
+                        Object.defineProperty(Core.prototype.getBlockFilterHeaders = function getBlockFilterHeaders(request, callback) {
+                            return this.rpcCall(getBlockFilterHeaders, $root.org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest, $root.org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse, request, callback);
+                        }, "name", { value: "getBlockFilterHeaders" });
+
+                        /**
+                         * Calls getBlockFilterHeaders.
+                         * @function getBlockFilterHeaders
+                         * @memberof org.dash.platform.dapi.v0.Core
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFilterHeadersRequest} request GetBlockFilterHeadersRequest message or plain object
+                         * @returns {Promise<org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse>} Promise
+                         * @variation 2
+                         */
+
                         /**
                          * Callback as used by {@link org.dash.platform.dapi.v0.Core#subscribeToBlockHeadersWithChainLocks}.
                          * @memberof org.dash.platform.dapi.v0.Core
@@ -7105,6 +7171,1122 @@ $root.org = (function() {
                         return MasternodeListResponse;
                     })();
 
+                    v0.GetBlockFiltersRequest = (function() {
+
+                        /**
+                         * Properties of a GetBlockFiltersRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetBlockFiltersRequest
+                         * @property {number|null} [startHeight] GetBlockFiltersRequest startHeight
+                         * @property {number|null} [count] GetBlockFiltersRequest count
+                         */
+
+                        /**
+                         * Constructs a new GetBlockFiltersRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetBlockFiltersRequest.
+                         * @implements IGetBlockFiltersRequest
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFiltersRequest=} [properties] Properties to set
+                         */
+                        function GetBlockFiltersRequest(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetBlockFiltersRequest startHeight.
+                         * @member {number} startHeight
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersRequest
+                         * @instance
+                         */
+                        GetBlockFiltersRequest.prototype.startHeight = 0;
+
+                        /**
+                         * GetBlockFiltersRequest count.
+                         * @member {number} count
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersRequest
+                         * @instance
+                         */
+                        GetBlockFiltersRequest.prototype.count = 0;
+
+                        /**
+                         * Creates a new GetBlockFiltersRequest instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFiltersRequest=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFiltersRequest} GetBlockFiltersRequest instance
+                         */
+                        GetBlockFiltersRequest.create = function create(properties) {
+                            return new GetBlockFiltersRequest(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetBlockFiltersRequest message. Does not implicitly {@link org.dash.platform.dapi.v0.GetBlockFiltersRequest.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFiltersRequest} message GetBlockFiltersRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetBlockFiltersRequest.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.startHeight != null && Object.hasOwnProperty.call(message, "startHeight"))
+                                writer.uint32(/* id 1, wireType 0 =*/8).uint32(message.startHeight);
+                            if (message.count != null && Object.hasOwnProperty.call(message, "count"))
+                                writer.uint32(/* id 2, wireType 0 =*/16).uint32(message.count);
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetBlockFiltersRequest message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetBlockFiltersRequest.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFiltersRequest} message GetBlockFiltersRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetBlockFiltersRequest.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetBlockFiltersRequest message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFiltersRequest} GetBlockFiltersRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetBlockFiltersRequest.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetBlockFiltersRequest();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.startHeight = reader.uint32();
+                                    break;
+                                case 2:
+                                    message.count = reader.uint32();
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetBlockFiltersRequest message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFiltersRequest} GetBlockFiltersRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetBlockFiltersRequest.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetBlockFiltersRequest message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersRequest
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetBlockFiltersRequest.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            if (message.startHeight != null && message.hasOwnProperty("startHeight"))
+                                if (!$util.isInteger(message.startHeight))
+                                    return "startHeight: integer expected";
+                            if (message.count != null && message.hasOwnProperty("count"))
+                                if (!$util.isInteger(message.count))
+                                    return "count: integer expected";
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetBlockFiltersRequest message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersRequest
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFiltersRequest} GetBlockFiltersRequest
+                         */
+                        GetBlockFiltersRequest.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetBlockFiltersRequest)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetBlockFiltersRequest();
+                            if (object.startHeight != null)
+                                message.startHeight = object.startHeight >>> 0;
+                            if (object.count != null)
+                                message.count = object.count >>> 0;
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetBlockFiltersRequest message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetBlockFiltersRequest} message GetBlockFiltersRequest
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetBlockFiltersRequest.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults) {
+                                object.startHeight = 0;
+                                object.count = 0;
+                            }
+                            if (message.startHeight != null && message.hasOwnProperty("startHeight"))
+                                object.startHeight = message.startHeight;
+                            if (message.count != null && message.hasOwnProperty("count"))
+                                object.count = message.count;
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetBlockFiltersRequest to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersRequest
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetBlockFiltersRequest.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        return GetBlockFiltersRequest;
+                    })();
+
+                    v0.GetBlockFiltersResponse = (function() {
+
+                        /**
+                         * Properties of a GetBlockFiltersResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetBlockFiltersResponse
+                         * @property {Array.<org.dash.platform.dapi.v0.GetBlockFiltersResponse.IBlockFilter>|null} [filters] GetBlockFiltersResponse filters
+                         */
+
+                        /**
+                         * Constructs a new GetBlockFiltersResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetBlockFiltersResponse.
+                         * @implements IGetBlockFiltersResponse
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFiltersResponse=} [properties] Properties to set
+                         */
+                        function GetBlockFiltersResponse(properties) {
+                            this.filters = [];
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetBlockFiltersResponse filters.
+                         * @member {Array.<org.dash.platform.dapi.v0.GetBlockFiltersResponse.IBlockFilter>} filters
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                         * @instance
+                         */
+                        GetBlockFiltersResponse.prototype.filters = $util.emptyArray;
+
+                        /**
+                         * Creates a new GetBlockFiltersResponse instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFiltersResponse=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFiltersResponse} GetBlockFiltersResponse instance
+                         */
+                        GetBlockFiltersResponse.create = function create(properties) {
+                            return new GetBlockFiltersResponse(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetBlockFiltersResponse message. Does not implicitly {@link org.dash.platform.dapi.v0.GetBlockFiltersResponse.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFiltersResponse} message GetBlockFiltersResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetBlockFiltersResponse.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.filters != null && message.filters.length)
+                                for (var i = 0; i < message.filters.length; ++i)
+                                    $root.org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter.encode(message.filters[i], writer.uint32(/* id 1, wireType 2 =*/10).fork()).ldelim();
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetBlockFiltersResponse message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetBlockFiltersResponse.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFiltersResponse} message GetBlockFiltersResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetBlockFiltersResponse.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetBlockFiltersResponse message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFiltersResponse} GetBlockFiltersResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetBlockFiltersResponse.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetBlockFiltersResponse();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    if (!(message.filters && message.filters.length))
+                                        message.filters = [];
+                                    message.filters.push($root.org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter.decode(reader, reader.uint32()));
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetBlockFiltersResponse message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFiltersResponse} GetBlockFiltersResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetBlockFiltersResponse.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetBlockFiltersResponse message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetBlockFiltersResponse.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            if (message.filters != null && message.hasOwnProperty("filters")) {
+                                if (!Array.isArray(message.filters))
+                                    return "filters: array expected";
+                                for (var i = 0; i < message.filters.length; ++i) {
+                                    var error = $root.org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter.verify(message.filters[i]);
+                                    if (error)
+                                        return "filters." + error;
+                                }
+                            }
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetBlockFiltersResponse message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFiltersResponse} GetBlockFiltersResponse
+                         */
+                        GetBlockFiltersResponse.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetBlockFiltersResponse)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetBlockFiltersResponse();
+                            if (object.filters) {
+                                if (!Array.isArray(object.filters))
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetBlockFiltersResponse.filters: array expected");
+                                message.filters = [];
+                                for (var i = 0; i < object.filters.length; ++i) {
+                                    if (typeof object.filters[i] !== "object")
+                                        throw TypeError(".org.dash.platform.dapi.v0.GetBlockFiltersResponse.filters: object expected");
+                                    message.filters[i] = $root.org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter.fromObject(object.filters[i]);
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetBlockFiltersResponse message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetBlockFiltersResponse} message GetBlockFiltersResponse
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetBlockFiltersResponse.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.arrays || options.defaults)
+                                object.filters = [];
+                            if (message.filters && message.filters.length) {
+                                object.filters = [];
+                                for (var j = 0; j < message.filters.length; ++j)
+                                    object.filters[j] = $root.org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter.toObject(message.filters[j], options);
+                            }
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetBlockFiltersResponse to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetBlockFiltersResponse.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        GetBlockFiltersResponse.BlockFilter = (function() {
+
+                            /**
+                             * Properties of a BlockFilter.
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                             * @interface IBlockFilter
+                             * @property {number|null} [height] BlockFilter height
+                             * @property {Uint8Array|null} [blockHash] BlockFilter blockHash
+                             * @property {Uint8Array|null} [filter] BlockFilter filter
+                             */
+
+                            /**
+                             * Constructs a new BlockFilter.
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse
+                             * @classdesc Represents a BlockFilter.
+                             * @implements IBlockFilter
+                             * @constructor
+                             * @param {org.dash.platform.dapi.v0.GetBlockFiltersResponse.IBlockFilter=} [properties] Properties to set
+                             */
+                            function BlockFilter(properties) {
+                                if (properties)
+                                    for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                        if (properties[keys[i]] != null)
+                                            this[keys[i]] = properties[keys[i]];
+                            }
+
+                            /**
+                             * BlockFilter height.
+                             * @member {number} height
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @instance
+                             */
+                            BlockFilter.prototype.height = 0;
+
+                            /**
+                             * BlockFilter blockHash.
+                             * @member {Uint8Array} blockHash
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @instance
+                             */
+                            BlockFilter.prototype.blockHash = $util.newBuffer([]);
+
+                            /**
+                             * BlockFilter filter.
+                             * @member {Uint8Array} filter
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @instance
+                             */
+                            BlockFilter.prototype.filter = $util.newBuffer([]);
+
+                            /**
+                             * Creates a new BlockFilter instance using the specified properties.
+                             * @function create
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetBlockFiltersResponse.IBlockFilter=} [properties] Properties to set
+                             * @returns {org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter} BlockFilter instance
+                             */
+                            BlockFilter.create = function create(properties) {
+                                return new BlockFilter(properties);
+                            };
+
+                            /**
+                             * Encodes the specified BlockFilter message. Does not implicitly {@link org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter.verify|verify} messages.
+                             * @function encode
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetBlockFiltersResponse.IBlockFilter} message BlockFilter message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            BlockFilter.encode = function encode(message, writer) {
+                                if (!writer)
+                                    writer = $Writer.create();
+                                if (message.height != null && Object.hasOwnProperty.call(message, "height"))
+                                    writer.uint32(/* id 1, wireType 0 =*/8).uint32(message.height);
+                                if (message.blockHash != null && Object.hasOwnProperty.call(message, "blockHash"))
+                                    writer.uint32(/* id 2, wireType 2 =*/18).bytes(message.blockHash);
+                                if (message.filter != null && Object.hasOwnProperty.call(message, "filter"))
+                                    writer.uint32(/* id 3, wireType 2 =*/26).bytes(message.filter);
+                                return writer;
+                            };
+
+                            /**
+                             * Encodes the specified BlockFilter message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter.verify|verify} messages.
+                             * @function encodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetBlockFiltersResponse.IBlockFilter} message BlockFilter message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            BlockFilter.encodeDelimited = function encodeDelimited(message, writer) {
+                                return this.encode(message, writer).ldelim();
+                            };
+
+                            /**
+                             * Decodes a BlockFilter message from the specified reader or buffer.
+                             * @function decode
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @param {number} [length] Message length if known beforehand
+                             * @returns {org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter} BlockFilter
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            BlockFilter.decode = function decode(reader, length) {
+                                if (!(reader instanceof $Reader))
+                                    reader = $Reader.create(reader);
+                                var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter();
+                                while (reader.pos < end) {
+                                    var tag = reader.uint32();
+                                    switch (tag >>> 3) {
+                                    case 1:
+                                        message.height = reader.uint32();
+                                        break;
+                                    case 2:
+                                        message.blockHash = reader.bytes();
+                                        break;
+                                    case 3:
+                                        message.filter = reader.bytes();
+                                        break;
+                                    default:
+                                        reader.skipType(tag & 7);
+                                        break;
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Decodes a BlockFilter message from the specified reader or buffer, length delimited.
+                             * @function decodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @returns {org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter} BlockFilter
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            BlockFilter.decodeDelimited = function decodeDelimited(reader) {
+                                if (!(reader instanceof $Reader))
+                                    reader = new $Reader(reader);
+                                return this.decode(reader, reader.uint32());
+                            };
+
+                            /**
+                             * Verifies a BlockFilter message.
+                             * @function verify
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @static
+                             * @param {Object.<string,*>} message Plain object to verify
+                             * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                             */
+                            BlockFilter.verify = function verify(message) {
+                                if (typeof message !== "object" || message === null)
+                                    return "object expected";
+                                if (message.height != null && message.hasOwnProperty("height"))
+                                    if (!$util.isInteger(message.height))
+                                        return "height: integer expected";
+                                if (message.blockHash != null && message.hasOwnProperty("blockHash"))
+                                    if (!(message.blockHash && typeof message.blockHash.length === "number" || $util.isString(message.blockHash)))
+                                        return "blockHash: buffer expected";
+                                if (message.filter != null && message.hasOwnProperty("filter"))
+                                    if (!(message.filter && typeof message.filter.length === "number" || $util.isString(message.filter)))
+                                        return "filter: buffer expected";
+                                return null;
+                            };
+
+                            /**
+                             * Creates a BlockFilter message from a plain object. Also converts values to their respective internal types.
+                             * @function fromObject
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @static
+                             * @param {Object.<string,*>} object Plain object
+                             * @returns {org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter} BlockFilter
+                             */
+                            BlockFilter.fromObject = function fromObject(object) {
+                                if (object instanceof $root.org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter)
+                                    return object;
+                                var message = new $root.org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter();
+                                if (object.height != null)
+                                    message.height = object.height >>> 0;
+                                if (object.blockHash != null)
+                                    if (typeof object.blockHash === "string")
+                                        $util.base64.decode(object.blockHash, message.blockHash = $util.newBuffer($util.base64.length(object.blockHash)), 0);
+                                    else if (object.blockHash.length >= 0)
+                                        message.blockHash = object.blockHash;
+                                if (object.filter != null)
+                                    if (typeof object.filter === "string")
+                                        $util.base64.decode(object.filter, message.filter = $util.newBuffer($util.base64.length(object.filter)), 0);
+                                    else if (object.filter.length >= 0)
+                                        message.filter = object.filter;
+                                return message;
+                            };
+
+                            /**
+                             * Creates a plain object from a BlockFilter message. Also converts values to other types if specified.
+                             * @function toObject
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter} message BlockFilter
+                             * @param {$protobuf.IConversionOptions} [options] Conversion options
+                             * @returns {Object.<string,*>} Plain object
+                             */
+                            BlockFilter.toObject = function toObject(message, options) {
+                                if (!options)
+                                    options = {};
+                                var object = {};
+                                if (options.defaults) {
+                                    object.height = 0;
+                                    if (options.bytes === String)
+                                        object.blockHash = "";
+                                    else {
+                                        object.blockHash = [];
+                                        if (options.bytes !== Array)
+                                            object.blockHash = $util.newBuffer(object.blockHash);
+                                    }
+                                    if (options.bytes === String)
+                                        object.filter = "";
+                                    else {
+                                        object.filter = [];
+                                        if (options.bytes !== Array)
+                                            object.filter = $util.newBuffer(object.filter);
+                                    }
+                                }
+                                if (message.height != null && message.hasOwnProperty("height"))
+                                    object.height = message.height;
+                                if (message.blockHash != null && message.hasOwnProperty("blockHash"))
+                                    object.blockHash = options.bytes === String ? $util.base64.encode(message.blockHash, 0, message.blockHash.length) : options.bytes === Array ? Array.prototype.slice.call(message.blockHash) : message.blockHash;
+                                if (message.filter != null && message.hasOwnProperty("filter"))
+                                    object.filter = options.bytes === String ? $util.base64.encode(message.filter, 0, message.filter.length) : options.bytes === Array ? Array.prototype.slice.call(message.filter) : message.filter;
+                                return object;
+                            };
+
+                            /**
+                             * Converts this BlockFilter to JSON.
+                             * @function toJSON
+                             * @memberof org.dash.platform.dapi.v0.GetBlockFiltersResponse.BlockFilter
+                             * @instance
+                             * @returns {Object.<string,*>} JSON object
+                             */
+                            BlockFilter.prototype.toJSON = function toJSON() {
+                                return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                            };
+
+                            return BlockFilter;
+                        })();
+
+                        return GetBlockFiltersResponse;
+                    })();
+
+                    v0.GetBlockFilterHeadersRequest = (function() {
+
+                        /**
+                         * Properties of a GetBlockFilterHeadersRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetBlockFilterHeadersRequest
+                         * @property {number|null} [startHeight] GetBlockFilterHeadersRequest startHeight
+                         * @property {number|null} [count] GetBlockFilterHeadersRequest count
+                         */
+
+                        /**
+                         * Constructs a new GetBlockFilterHeadersRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetBlockFilterHeadersRequest.
+                         * @implements IGetBlockFilterHeadersRequest
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFilterHeadersRequest=} [properties] Properties to set
+                         */
+                        function GetBlockFilterHeadersRequest(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetBlockFilterHeadersRequest startHeight.
+                         * @member {number} startHeight
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest
+                         * @instance
+                         */
+                        GetBlockFilterHeadersRequest.prototype.startHeight = 0;
+
+                        /**
+                         * GetBlockFilterHeadersRequest count.
+                         * @member {number} count
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest
+                         * @instance
+                         */
+                        GetBlockFilterHeadersRequest.prototype.count = 0;
+
+                        /**
+                         * Creates a new GetBlockFilterHeadersRequest instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFilterHeadersRequest=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest} GetBlockFilterHeadersRequest instance
+                         */
+                        GetBlockFilterHeadersRequest.create = function create(properties) {
+                            return new GetBlockFilterHeadersRequest(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetBlockFilterHeadersRequest message. Does not implicitly {@link org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFilterHeadersRequest} message GetBlockFilterHeadersRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetBlockFilterHeadersRequest.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.startHeight != null && Object.hasOwnProperty.call(message, "startHeight"))
+                                writer.uint32(/* id 1, wireType 0 =*/8).uint32(message.startHeight);
+                            if (message.count != null && Object.hasOwnProperty.call(message, "count"))
+                                writer.uint32(/* id 2, wireType 0 =*/16).uint32(message.count);
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetBlockFilterHeadersRequest message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFilterHeadersRequest} message GetBlockFilterHeadersRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetBlockFilterHeadersRequest.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetBlockFilterHeadersRequest message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest} GetBlockFilterHeadersRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetBlockFilterHeadersRequest.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.startHeight = reader.uint32();
+                                    break;
+                                case 2:
+                                    message.count = reader.uint32();
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetBlockFilterHeadersRequest message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest} GetBlockFilterHeadersRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetBlockFilterHeadersRequest.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetBlockFilterHeadersRequest message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetBlockFilterHeadersRequest.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            if (message.startHeight != null && message.hasOwnProperty("startHeight"))
+                                if (!$util.isInteger(message.startHeight))
+                                    return "startHeight: integer expected";
+                            if (message.count != null && message.hasOwnProperty("count"))
+                                if (!$util.isInteger(message.count))
+                                    return "count: integer expected";
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetBlockFilterHeadersRequest message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest} GetBlockFilterHeadersRequest
+                         */
+                        GetBlockFilterHeadersRequest.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest();
+                            if (object.startHeight != null)
+                                message.startHeight = object.startHeight >>> 0;
+                            if (object.count != null)
+                                message.count = object.count >>> 0;
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetBlockFilterHeadersRequest message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest} message GetBlockFilterHeadersRequest
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetBlockFilterHeadersRequest.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults) {
+                                object.startHeight = 0;
+                                object.count = 0;
+                            }
+                            if (message.startHeight != null && message.hasOwnProperty("startHeight"))
+                                object.startHeight = message.startHeight;
+                            if (message.count != null && message.hasOwnProperty("count"))
+                                object.count = message.count;
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetBlockFilterHeadersRequest to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersRequest
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetBlockFilterHeadersRequest.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        return GetBlockFilterHeadersRequest;
+                    })();
+
+                    v0.GetBlockFilterHeadersResponse = (function() {
+
+                        /**
+                         * Properties of a GetBlockFilterHeadersResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetBlockFilterHeadersResponse
+                         * @property {Uint8Array|null} [previousFilterHeader] GetBlockFilterHeadersResponse previousFilterHeader
+                         * @property {Array.<Uint8Array>|null} [filterHashes] GetBlockFilterHeadersResponse filterHashes
+                         */
+
+                        /**
+                         * Constructs a new GetBlockFilterHeadersResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetBlockFilterHeadersResponse.
+                         * @implements IGetBlockFilterHeadersResponse
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFilterHeadersResponse=} [properties] Properties to set
+                         */
+                        function GetBlockFilterHeadersResponse(properties) {
+                            this.filterHashes = [];
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetBlockFilterHeadersResponse previousFilterHeader.
+                         * @member {Uint8Array} previousFilterHeader
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse
+                         * @instance
+                         */
+                        GetBlockFilterHeadersResponse.prototype.previousFilterHeader = $util.newBuffer([]);
+
+                        /**
+                         * GetBlockFilterHeadersResponse filterHashes.
+                         * @member {Array.<Uint8Array>} filterHashes
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse
+                         * @instance
+                         */
+                        GetBlockFilterHeadersResponse.prototype.filterHashes = $util.emptyArray;
+
+                        /**
+                         * Creates a new GetBlockFilterHeadersResponse instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFilterHeadersResponse=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse} GetBlockFilterHeadersResponse instance
+                         */
+                        GetBlockFilterHeadersResponse.create = function create(properties) {
+                            return new GetBlockFilterHeadersResponse(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetBlockFilterHeadersResponse message. Does not implicitly {@link org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFilterHeadersResponse} message GetBlockFilterHeadersResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetBlockFilterHeadersResponse.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.previousFilterHeader != null && Object.hasOwnProperty.call(message, "previousFilterHeader"))
+                                writer.uint32(/* id 1, wireType 2 =*/10).bytes(message.previousFilterHeader);
+                            if (message.filterHashes != null && message.filterHashes.length)
+                                for (var i = 0; i < message.filterHashes.length; ++i)
+                                    writer.uint32(/* id 2, wireType 2 =*/18).bytes(message.filterHashes[i]);
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetBlockFilterHeadersResponse message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetBlockFilterHeadersResponse} message GetBlockFilterHeadersResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetBlockFilterHeadersResponse.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetBlockFilterHeadersResponse message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse} GetBlockFilterHeadersResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetBlockFilterHeadersResponse.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.previousFilterHeader = reader.bytes();
+                                    break;
+                                case 2:
+                                    if (!(message.filterHashes && message.filterHashes.length))
+                                        message.filterHashes = [];
+                                    message.filterHashes.push(reader.bytes());
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetBlockFilterHeadersResponse message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse} GetBlockFilterHeadersResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetBlockFilterHeadersResponse.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetBlockFilterHeadersResponse message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetBlockFilterHeadersResponse.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            if (message.previousFilterHeader != null && message.hasOwnProperty("previousFilterHeader"))
+                                if (!(message.previousFilterHeader && typeof message.previousFilterHeader.length === "number" || $util.isString(message.previousFilterHeader)))
+                                    return "previousFilterHeader: buffer expected";
+                            if (message.filterHashes != null && message.hasOwnProperty("filterHashes")) {
+                                if (!Array.isArray(message.filterHashes))
+                                    return "filterHashes: array expected";
+                                for (var i = 0; i < message.filterHashes.length; ++i)
+                                    if (!(message.filterHashes[i] && typeof message.filterHashes[i].length === "number" || $util.isString(message.filterHashes[i])))
+                                        return "filterHashes: buffer[] expected";
+                            }
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetBlockFilterHeadersResponse message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse} GetBlockFilterHeadersResponse
+                         */
+                        GetBlockFilterHeadersResponse.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse();
+                            if (object.previousFilterHeader != null)
+                                if (typeof object.previousFilterHeader === "string")
+                                    $util.base64.decode(object.previousFilterHeader, message.previousFilterHeader = $util.newBuffer($util.base64.length(object.previousFilterHeader)), 0);
+                                else if (object.previousFilterHeader.length >= 0)
+                                    message.previousFilterHeader = object.previousFilterHeader;
+                            if (object.filterHashes) {
+                                if (!Array.isArray(object.filterHashes))
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse.filterHashes: array expected");
+                                message.filterHashes = [];
+                                for (var i = 0; i < object.filterHashes.length; ++i)
+                                    if (typeof object.filterHashes[i] === "string")
+                                        $util.base64.decode(object.filterHashes[i], message.filterHashes[i] = $util.newBuffer($util.base64.length(object.filterHashes[i])), 0);
+                                    else if (object.filterHashes[i].length >= 0)
+                                        message.filterHashes[i] = object.filterHashes[i];
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetBlockFilterHeadersResponse message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse} message GetBlockFilterHeadersResponse
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetBlockFilterHeadersResponse.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.arrays || options.defaults)
+                                object.filterHashes = [];
+                            if (options.defaults)
+                                if (options.bytes === String)
+                                    object.previousFilterHeader = "";
+                                else {
+                                    object.previousFilterHeader = [];
+                                    if (options.bytes !== Array)
+                                        object.previousFilterHeader = $util.newBuffer(object.previousFilterHeader);
+                                }
+                            if (message.previousFilterHeader != null && message.hasOwnProperty("previousFilterHeader"))
+                                object.previousFilterHeader = options.bytes === String ? $util.base64.encode(message.previousFilterHeader, 0, message.previousFilterHeader.length) : options.bytes === Array ? Array.prototype.slice.call(message.previousFilterHeader) : message.previousFilterHeader;
+                            if (message.filterHashes && message.filterHashes.length) {
+                                object.filterHashes = [];
+                                for (var j = 0; j < message.filterHashes.length; ++j)
+                                    object.filterHashes[j] = options.bytes === String ? $util.base64.encode(message.filterHashes[j], 0, message.filterHashes[j].length) : options.bytes === Array ? Array.prototype.slice.call(message.filterHashes[j]) : message.filterHashes[j];
+                            }
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetBlockFilterHeadersResponse to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetBlockFilterHeadersResponse
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetBlockFilterHeadersResponse.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        return GetBlockFilterHeadersResponse;
+                    })();
+
                     return v0;
                 })();
 
@@ -29,6 +29,7 @@ CF_EXTERN_C_BEGIN
 
 @class BlockHeaders;
 @class BloomFilter;
+@class GetBlockFiltersResponse_BlockFilter;
 @class GetBlockchainStatusResponse_Chain;
 @class GetBlockchainStatusResponse_Network;
 @class GetBlockchainStatusResponse_NetworkFee;
@@ -662,6 +663,97 @@ GPB_FINAL @interface MasternodeListResponse : GPBMessage
 
 @end
 
+#pragma mark - GetBlockFiltersRequest
+
+typedef GPB_ENUM(GetBlockFiltersRequest_FieldNumber) {
+  GetBlockFiltersRequest_FieldNumber_StartHeight = 1,
+  GetBlockFiltersRequest_FieldNumber_Count = 2,
+};
+
+/**
+ * BIP158 basic compact block filters for a range of blocks
+ **/
+GPB_FINAL @interface GetBlockFiltersRequest : GPBMessage
+
+@property(nonatomic, readwrite) uint32_t startHeight;
+
+@property(nonatomic, readwrite) uint32_t count;
+
+@end
+
+#pragma mark - GetBlockFiltersResponse
+
+typedef GPB_ENUM(GetBlockFiltersResponse_FieldNumber) {
+  GetBlockFiltersResponse_FieldNumber_FiltersArray = 1,
+};
+
+GPB_FINAL @interface GetBlockFiltersResponse : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetBlockFiltersResponse_BlockFilter*> *filtersArray;
+/** The number of items in @c filtersArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger filtersArray_Count;
+
+@end
+
+#pragma mark - GetBlockFiltersResponse_BlockFilter
+
+typedef GPB_ENUM(GetBlockFiltersResponse_BlockFilter_FieldNumber) {
+  GetBlockFiltersResponse_BlockFilter_FieldNumber_Height = 1,
+  GetBlockFiltersResponse_BlockFilter_FieldNumber_BlockHash = 2,
+  GetBlockFiltersResponse_BlockFilter_FieldNumber_Filter = 3,
+};
+
+GPB_FINAL @interface GetBlockFiltersResponse_BlockFilter : GPBMessage
+
+@property(nonatomic, readwrite) uint32_t height;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *blockHash;
+
+/** Serialized BIP158 basic filter */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *filter;
+
+@end
+
+#pragma mark - GetBlockFilterHeadersRequest
+
+typedef GPB_ENUM(GetBlockFilterHeadersRequest_FieldNumber) {
+  GetBlockFilterHeadersRequest_FieldNumber_StartHeight = 1,
+  GetBlockFilterHeadersRequest_FieldNumber_Count = 2,
+};
+
+/**
+ * BIP157 filter header chain for a range of blocks, as in `cfheaders`
+ **/
+GPB_FINAL @interface GetBlockFilterHeadersRequest : GPBMessage
+
+@property(nonatomic, readwrite) uint32_t startHeight;
+
+@property(nonatomic, readwrite) uint32_t count;
+
+@end
+
+#pragma mark - GetBlockFilterHeadersResponse
+
+typedef GPB_ENUM(GetBlockFilterHeadersResponse_FieldNumber) {
+  GetBlockFilterHeadersResponse_FieldNumber_PreviousFilterHeader = 1,
+  GetBlockFilterHeadersResponse_FieldNumber_FilterHashesArray = 2,
+};
+
+GPB_FINAL @interface GetBlockFilterHeadersResponse : GPBMessage
+
+/** Filter header of the block preceding `start_height`, zeros for the genesis block */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *previousFilterHeader;
+
+/**
+ * Double SHA256 of each filter, the headers follow as
+ * header(n) = dsha256(filter_hash(n) || header(n - 1))
+ **/
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *filterHashesArray;
+/** The number of items in @c filterHashesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger filterHashesArray_Count;
+
+@end
+
 NS_ASSUME_NONNULL_END
 
 CF_EXTERN_C_END
@@ -29,6 +29,8 @@
 // We don't use [Foo class] because it is not a static value.
 GPBObjCClassDeclaration(BlockHeaders);
 GPBObjCClassDeclaration(BloomFilter);
+GPBObjCClassDeclaration(GetBlockFiltersResponse);
+GPBObjCClassDeclaration(GetBlockFiltersResponse_BlockFilter);
 GPBObjCClassDeclaration(GetBlockchainStatusResponse);
 GPBObjCClassDeclaration(GetBlockchainStatusResponse_Chain);
 GPBObjCClassDeclaration(GetBlockchainStatusResponse_Network);
@@ -1946,6 +1948,287 @@ typedef struct MasternodeListResponse__storage_ {
 
 @end
 
+#pragma mark - GetBlockFiltersRequest
+
+@implementation GetBlockFiltersRequest
+
+@dynamic startHeight;
+@dynamic count;
+
+typedef struct GetBlockFiltersRequest__storage_ {
+  uint32_t _has_storage_[1];
+  uint32_t startHeight;
+  uint32_t count;
+} GetBlockFiltersRequest__storage_;
+
+// This method is threadsafe because it is initially called
+// in +initialize for each subclass.
++ (GPBDescriptor *)descriptor {
+  static GPBDescriptor *descriptor = nil;
+  if (!descriptor) {
+    static GPBMessageFieldDescription fields[] = {
+      {
+        .name = "startHeight",
+        .dataTypeSpecific.clazz = Nil,
+        .number = GetBlockFiltersRequest_FieldNumber_StartHeight,
+        .hasIndex = 0,
+        .offset = (uint32_t)offsetof(GetBlockFiltersRequest__storage_, startHeight),
+        .flags = (GPBFieldFlags)(GPBFieldOptional | GPBFieldClearHasIvarOnZero),
+        .dataType = GPBDataTypeUInt32,
+      },
+      {
+        .name = "count",
+        .dataTypeSpecific.clazz = Nil,
+        .number = GetBlockFiltersRequest_FieldNumber_Count,
+        .hasIndex = 1,
+        .offset = (uint32_t)offsetof(GetBlockFiltersRequest__storage_, count),
+        .flags = (GPBFieldFlags)(GPBFieldOptional | GPBFieldClearHasIvarOnZero),
+        .dataType = GPBDataTypeUInt32,
+      },
+    };
+    GPBDescriptor *localDescriptor =
+        [GPBDescriptor allocDescriptorForClass:[GetBlockFiltersRequest class]
+                                     rootClass:[CoreRoot class]
+                                          file:CoreRoot_FileDescriptor()
+                                        fields:fields
+                                    fieldCount:(uint32_t)(sizeof(fields) / sizeof(GPBMessageFieldDescription))
+                                   storageSize:sizeof(GetBlockFiltersRequest__storage_)
+                                         flags:(GPBDescriptorInitializationFlags)(GPBDescriptorInitializationFlag_UsesClassRefs | GPBDescriptorInitializationFlag_Proto3OptionalKnown)];
+    #if defined(DEBUG) && DEBUG
+      NSAssert(descriptor == nil, @"Startup recursed!");
+    #endif  // DEBUG
+    descriptor = localDescriptor;
+  }
+  return descriptor;
+}
+
+@end
+
+#pragma mark - GetBlockFiltersResponse
+
+@implementation GetBlockFiltersResponse
+
+@dynamic filtersArray, filtersArray_Count;
+
+typedef struct GetBlockFiltersResponse__storage_ {
+  uint32_t _has_storage_[1];
+  NSMutableArray *filtersArray;
+} GetBlockFiltersResponse__storage_;
+
+// This method is threadsafe because it is initially called
+// in +initialize for each subclass.
++ (GPBDescriptor *)descriptor {
+  static GPBDescriptor *descriptor = nil;
+  if (!descriptor) {
+    static GPBMessageFieldDescription fields[] = {
+      {
+        .name = "filtersArray",
+        .dataTypeSpecific.clazz = GPBObjCClass(GetBlockFiltersResponse_BlockFilter),
+        .number = GetBlockFiltersResponse_FieldNumber_FiltersArray,
+        .hasIndex = GPBNoHasBit,
+        .offset = (uint32_t)offsetof(GetBlockFiltersResponse__storage_, filtersArray),
+        .flags = GPBFieldRepeated,
+        .dataType = GPBDataTypeMessage,
+      },
+    };
+    GPBDescriptor *localDescriptor =
+        [GPBDescriptor allocDescriptorForClass:[GetBlockFiltersResponse class]
+                                     rootClass:[CoreRoot class]
+                                          file:CoreRoot_FileDescriptor()
+                                        fields:fields
+                                    fieldCount:(uint32_t)(sizeof(fields) / sizeof(GPBMessageFieldDescription))
+                                   storageSize:sizeof(GetBlockFiltersResponse__storage_)
+                                         flags:(GPBDescriptorInitializationFlags)(GPBDescriptorInitializationFlag_UsesClassRefs | GPBDescriptorInitializationFlag_Proto3OptionalKnown)];
+    #if defined(DEBUG) && DEBUG
+      NSAssert(descriptor == nil, @"Startup recursed!");
+    #endif  // DEBUG
+    descriptor = localDescriptor;
+  }
+  return descriptor;
+}
+
+@end
+
+#pragma mark - GetBlockFiltersResponse_BlockFilter
+
+@implementation GetBlockFiltersResponse_BlockFilter
+
+@dynamic height;
+@dynamic blockHash;
+@dynamic filter;
+
+typedef struct GetBlockFiltersResponse_BlockFilter__storage_ {
+  uint32_t _has_storage_[1];
+  uint32_t height;
+  NSData *blockHash;
+  NSData *filter;
+} GetBlockFiltersResponse_BlockFilter__storage_;
+
+// This method is threadsafe because it is initially called
+// in +initialize for each subclass.
++ (GPBDescriptor *)descriptor {
+  static GPBDescriptor *descriptor = nil;
+  if (!descriptor) {
+    static GPBMessageFieldDescription fields[] = {
+      {
+        .name = "height",
+        .dataTypeSpecific.clazz = Nil,
+        .number = GetBlockFiltersResponse_BlockFilter_FieldNumber_Height,
+        .hasIndex = 0,
+        .offset = (uint32_t)offsetof(GetBlockFiltersResponse_BlockFilter__storage_, height),
+        .flags = (GPBFieldFlags)(GPBFieldOptional | GPBFieldClearHasIvarOnZero),
+        .dataType = GPBDataTypeUInt32,
+      },
+      {
+        .name = "blockHash",
+        .dataTypeSpecific.clazz = Nil,
+        .number = GetBlockFiltersResponse_BlockFilter_FieldNumber_BlockHash,
+        .hasIndex = 1,
+        .offset = (uint32_t)offsetof(GetBlockFiltersResponse_BlockFilter__storage_, blockHash),
+        .flags = (GPBFieldFlags)(GPBFieldOptional | GPBFieldClearHasIvarOnZero),
+        .dataType = GPBDataTypeBytes,
+      },
+      {
+        .name = "filter",
+        .dataTypeSpecific.clazz = Nil,
+        .number = GetBlockFiltersResponse_BlockFilter_FieldNumber_Filter,
+        .hasIndex = 2,
+        .offset = (uint32_t)offsetof(GetBlockFiltersResponse_BlockFilter__storage_, filter),
+        .flags = (GPBFieldFlags)(GPBFieldOptional | GPBFieldClearHasIvarOnZero),
+        .dataType = GPBDataTypeBytes,
+      },
+    };
+    GPBDescriptor *localDescriptor =
+        [GPBDescriptor allocDescriptorForClass:[GetBlockFiltersResponse_BlockFilter class]
+                                     rootClass:[CoreRoot class]
+                                          file:CoreRoot_FileDescriptor()
+                                        fields:fields
+                                    fieldCount:(uint32_t)(sizeof(fields) / sizeof(GPBMessageFieldDescription))
+                                   storageSize:sizeof(GetBlockFiltersResponse_BlockFilter__storage_)
+                                         flags:(GPBDescriptorInitializationFlags)(GPBDescriptorInitializationFlag_UsesClassRefs | GPBDescriptorInitializationFlag_Proto3OptionalKnown)];
+    [localDescriptor setupContainingMessageClass:GPBObjCClass(GetBlockFiltersResponse)];
+    #if defined(DEBUG) && DEBUG
+      NSAssert(descriptor == nil, @"Startup recursed!");
+    #endif  // DEBUG
+    descriptor = localDescriptor;
+  }
+  return descriptor;
+}
+
+@end
+
+#pragma mark - GetBlockFilterHeadersRequest
+
+@implementation GetBlockFilterHeadersRequest
+
+@dynamic startHeight;
+@dynamic count;
+
+typedef struct GetBlockFilterHeadersRequest__storage_ {
+  uint32_t _has_storage_[1];
+  uint32_t startHeight;
+  uint32_t count;
+} GetBlockFilterHeadersRequest__storage_;
+
+// This method is threadsafe because it is initially called
+// in +initialize for each subclass.
++ (GPBDescriptor *)descriptor {
+  static GPBDescriptor *descriptor = nil;
+  if (!descriptor) {
+    static GPBMessageFieldDescription fields[] = {
+      {
+        .name = "startHeight",
+        .dataTypeSpecific.clazz = Nil,
+        .number = GetBlockFilterHeadersRequest_FieldNumber_StartHeight,
+        .hasIndex = 0,
+        .offset = (uint32_t)offsetof(GetBlockFilterHeadersRequest__storage_, startHeight),
+        .flags = (GPBFieldFlags)(GPBFieldOptional | GPBFieldClearHasIvarOnZero),
+        .dataType = GPBDataTypeUInt32,
+      },
+      {
+        .name = "count",
+        .dataTypeSpecific.clazz = Nil,
+        .number = GetBlockFilterHeadersRequest_FieldNumber_Count,
+        .hasIndex = 1,
+        .offset = (uint32_t)offsetof(GetBlockFilterHeadersRequest__storage_, count),
+        .flags = (GPBFieldFlags)(GPBFieldOptional | GPBFieldClearHasIvarOnZero),
+        .dataType = GPBDataTypeUInt32,
+      },
+    };
+    GPBDescriptor *localDescriptor =
+        [GPBDescriptor allocDescriptorForClass:[GetBlockFilterHeadersRequest class]
+                                     rootClass:[CoreRoot class]
+                                          file:CoreRoot_FileDescriptor()
+                                        fields:fields
+                                    fieldCount:(uint32_t)(sizeof(fields) / sizeof(GPBMessageFieldDescription))
+                                   storageSize:sizeof(GetBlockFilterHeadersRequest__storage_)
+                                         flags:(GPBDescriptorInitializationFlags)(GPBDescriptorInitializationFlag_UsesClassRefs | GPBDescriptorInitializationFlag_Proto3OptionalKnown)];
+    #if defined(DEBUG) && DEBUG
+      NSAssert(descriptor == nil, @"Startup recursed!");
+    #endif  // DEBUG
+    descriptor = localDescriptor;
+  }
+  return descriptor;
+}
+
+@end
+
+#pragma mark - GetBlockFilterHeadersResponse
+
+@implementation GetBlockFilterHeadersResponse
+
+@dynamic previousFilterHeader;
+@dynamic filterHashesArray, filterHashesArray_Count;
+
+typedef struct GetBlockFilterHeadersResponse__storage_ {
+  uint32_t _has_storage_[1];
+  NSData *previousFilterHeader;
+  NSMutableArray *filterHashesArray;
+} GetBlockFilterHeadersResponse__storage_;
+
+// This method is threadsafe because it is initially called
+// in +initialize for each subclass.
++ (GPBDescriptor *)descriptor {
+  static GPBDescriptor *descriptor = nil;
+  if (!descriptor) {
+    static GPBMessageFieldDescription fields[] = {
+      {
+        .name = "previousFilterHeader",
+        .dataTypeSpecific.clazz = Nil,
+        .number = GetBlockFilterHeadersResponse_FieldNumber_PreviousFilterHeader,
+        .hasIndex = 0,
+        .offset = (uint32_t)offsetof(GetBlockFilterHeadersResponse__storage_, previousFilterHeader),
+        .flags = (GPBFieldFlags)(GPBFieldOptional | GPBFieldClearHasIvarOnZero),
+        .dataType = GPBDataTypeBytes,
+      },
+      {
+        .name = "filterHashesArray",
+        .dataTypeSpecific.clazz = Nil,
+        .number = GetBlockFilterHeadersResponse_FieldNumber_FilterHashesArray,
+        .hasIndex = GPBNoHasBit,
+        .offset = (uint32_t)offsetof(GetBlockFilterHeadersResponse__storage_, filterHashesArray),
+        .flags = GPBFieldRepeated,
+        .dataType = GPBDataTypeBytes,
+      },
+    };
+    GPBDescriptor *localDescriptor =
+        [GPBDescriptor allocDescriptorForClass:[GetBlockFilterHeadersResponse class]
+                                     rootClass:[CoreRoot class]
+                                          file:CoreRoot_FileDescriptor()
+                                        fields:fields
+                                    fieldCount:(uint32_t)(sizeof(fields) / sizeof(GPBMessageFieldDescription))
+                                   storageSize:sizeof(GetBlockFilterHeadersResponse__storage_)
+                                         flags:(GPBDescriptorInitializationFlags)(GPBDescriptorInitializationFlag_UsesClassRefs | GPBDescriptorInitializationFlag_Proto3OptionalKnown)];
+    #if defined(DEBUG) && DEBUG
+      NSAssert(descriptor == nil, @"Startup recursed!");
+    #endif  // DEBUG
+    descriptor = localDescriptor;
+  }
+  return descriptor;
+}
+
+@end
+
 
 #pragma clang diagnostic pop
 
@@ -20,6 +20,10 @@
 @class BroadcastTransactionResponse;
 @class GetBestBlockHeightRequest;
 @class GetBestBlockHeightResponse;
+@class GetBlockFilterHeadersRequest;
+@class GetBlockFilterHeadersResponse;
+@class GetBlockFiltersRequest;
+@class GetBlockFiltersResponse;
 @class GetBlockRequest;
 @class GetBlockResponse;
 @class GetBlockchainStatusRequest;
@@ -77,6 +81,14 @@ NS_ASSUME_NONNULL_BEGIN
 
 - (GRPCUnaryProtoCall *)getEstimatedTransactionFeeWithMessage:(GetEstimatedTransactionFeeRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
 
+#pragma mark getBlockFilters(GetBlockFiltersRequest) returns (GetBlockFiltersResponse)
+
+- (GRPCUnaryProtoCall *)getBlockFiltersWithMessage:(GetBlockFiltersRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
+#pragma mark getBlockFilterHeaders(GetBlockFilterHeadersRequest) returns (GetBlockFilterHeadersResponse)
+
+- (GRPCUnaryProtoCall *)getBlockFilterHeadersWithMessage:(GetBlockFilterHeadersRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
 #pragma mark subscribeToBlockHeadersWithChainLocks(BlockHeadersWithChainLocksRequest) returns (stream BlockHeadersWithChainLocksResponse)
 
 - (GRPCUnaryProtoCall *)subscribeToBlockHeadersWithChainLocksWithMessage:(BlockHeadersWithChainLocksRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
@@ -146,6 +158,20 @@ NS_ASSUME_NONNULL_BEGIN
 - (GRPCProtoCall *)RPCTogetEstimatedTransactionFeeWithRequest:(GetEstimatedTransactionFeeRequest *)request handler:(void(^)(GetEstimatedTransactionFeeResponse *_Nullable response, NSError *_Nullable error))handler;
 
 
+#pragma mark getBlockFilters(GetBlockFiltersRequest) returns (GetBlockFiltersResponse)
+
+- (void)getBlockFiltersWithRequest:(GetBlockFiltersRequest *)request handler:(void(^)(GetBlockFiltersResponse *_Nullable response, NSError *_Nullable error))handler;
+
+- (GRPCProtoCall *)RPCTogetBlockFiltersWithRequest:(GetBlockFiltersRequest *)request handler:(void(^)(GetBlockFiltersResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
+#pragma mark getBlockFilterHeaders(GetBlockFilterHeadersRequest) returns (GetBlockFilterHeadersResponse)
+
+- (void)getBlockFilterHeadersWithRequest:(GetBlockFilterHeadersRequest *)request handler:(void(^)(GetBlockFilterHeadersResponse *_Nullable response, NSError *_Nullable error))handler;
+
+- (GRPCProtoCall *)RPCTogetBlockFilterHeadersWithRequest:(GetBlockFilterHeadersRequest *)request handler:(void(^)(GetBlockFilterHeadersResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
 #pragma mark subscribeToBlockHeadersWithChainLocks(BlockHeadersWithChainLocksRequest) returns (stream BlockHeadersWithChainLocksResponse)
 
 - (void)subscribeToBlockHeadersWithChainLocksWithRequest:(BlockHeadersWithChainLocksRequest *)request eventHandler:(void(^)(BOOL done, BlockHeadersWithChainLocksResponse *_Nullable response, NSError *_Nullable error))eventHandler;
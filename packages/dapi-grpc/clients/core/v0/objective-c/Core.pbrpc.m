@@ -195,6 +195,46 @@
              responseClass:[GetEstimatedTransactionFeeResponse class]];
 }
 
+#pragma mark getBlockFilters(GetBlockFiltersRequest) returns (GetBlockFiltersResponse)
+
+- (void)getBlockFiltersWithRequest:(GetBlockFiltersRequest *)request handler:(void(^)(GetBlockFiltersResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTogetBlockFiltersWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+- (GRPCProtoCall *)RPCTogetBlockFiltersWithRequest:(GetBlockFiltersRequest *)request handler:(void(^)(GetBlockFiltersResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"getBlockFilters"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[GetBlockFiltersResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+- (GRPCUnaryProtoCall *)getBlockFiltersWithMessage:(GetBlockFiltersRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"getBlockFilters"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[GetBlockFiltersResponse class]];
+}
+
+#pragma mark getBlockFilterHeaders(GetBlockFilterHeadersRequest) returns (GetBlockFilterHeadersResponse)
+
+- (void)getBlockFilterHeadersWithRequest:(GetBlockFilterHeadersRequest *)request handler:(void(^)(GetBlockFilterHeadersResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTogetBlockFilterHeadersWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+- (GRPCProtoCall *)RPCTogetBlockFilterHeadersWithRequest:(GetBlockFilterHeadersRequest *)request handler:(void(^)(GetBlockFilterHeadersResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"getBlockFilterHeaders"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[GetBlockFilterHeadersResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+- (GRPCUnaryProtoCall *)getBlockFilterHeadersWithMessage:(GetBlockFilterHeadersRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"getBlockFilterHeaders"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[GetBlockFilterHeadersResponse class]];
+}
+
 #pragma mark subscribeToBlockHeadersWithChainLocks(BlockHeadersWithChainLocksRequest) returns (stream BlockHeadersWithChainLocksResponse)
 
 - (void)subscribeToBlockHeadersWithChainLocksWithRequest:(BlockHeadersWithChainLocksRequest *)request eventHandler:(void(^)(BOOL done, BlockHeadersWithChainLocksResponse *_Nullable response, NSError *_Nullable error))eventHandler{
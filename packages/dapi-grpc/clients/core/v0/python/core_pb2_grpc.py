@@ -49,6 +49,16 @@ class CoreStub(object):
                 request_serializer=core__pb2.GetEstimatedTransactionFeeRequest.SerializeToString,
                 response_deserializer=core__pb2.GetEstimatedTransactionFeeResponse.FromString,
                 )
+        self.getBlockFilters = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Core/getBlockFilters',
+                request_serializer=core__pb2.GetBlockFiltersRequest.SerializeToString,
+                response_deserializer=core__pb2.GetBlockFiltersResponse.FromString,
+                )
+        self.getBlockFilterHeaders = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Core/getBlockFilterHeaders',
+                request_serializer=core__pb2.GetBlockFilterHeadersRequest.SerializeToString,
+                response_deserializer=core__pb2.GetBlockFilterHeadersResponse.FromString,
+                )
         self.subscribeToBlockHeadersWithChainLocks = channel.unary_stream(
                 '/org.dash.platform.dapi.v0.Core/subscribeToBlockHeadersWithChainLocks',
                 request_serializer=core__pb2.BlockHeadersWithChainLocksRequest.SerializeToString,
@@ -111,6 +121,18 @@ class CoreServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def getBlockFilters(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def getBlockFilterHeaders(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def subscribeToBlockHeadersWithChainLocks(self, request, context):
         """Missing associated documentation comment in .proto file."""
         context.set_code(grpc.StatusCode.UNIMPLEMENTED)
@@ -167,6 +189,16 @@ def add_CoreServicer_to_server(servicer, server):
                     request_deserializer=core__pb2.GetEstimatedTransactionFeeRequest.FromString,
                     response_serializer=core__pb2.GetEstimatedTransactionFeeResponse.SerializeToString,
             ),
+            'getBlockFilters': grpc.unary_unary_rpc_method_handler(
+                    servicer.getBlockFilters,
+                    request_deserializer=core__pb2.GetBlockFiltersRequest.FromString,
+                    response_serializer=core__pb2.GetBlockFiltersResponse.SerializeToString,
+            ),
+            'getBlockFilterHeaders': grpc.unary_unary_rpc_method_handler(
+                    servicer.getBlockFilterHeaders,
+                    request_deserializer=core__pb2.GetBlockFilterHeadersRequest.FromString,
+                    response_serializer=core__pb2.GetBlockFilterHeadersResponse.SerializeToString,
+            ),
             'subscribeToBlockHeadersWithChainLocks': grpc.unary_stream_rpc_method_handler(
                     servicer.subscribeToBlockHeadersWithChainLocks,
                     request_deserializer=core__pb2.BlockHeadersWithChainLocksRequest.FromString,
@@ -311,6 +343,40 @@ class Core(object):
             options, channel_credentials,
             insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
 
+    @staticmethod
+    def getBlockFilters(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Core/getBlockFilters',
+            core__pb2.GetBlockFiltersRequest.SerializeToString,
+            core__pb2.GetBlockFiltersResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def getBlockFilterHeaders(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Core/getBlockFilterHeaders',
+            core__pb2.GetBlockFilterHeadersRequest.SerializeToString,
+            core__pb2.GetBlockFilterHeadersResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
     @staticmethod
     def subscribeToBlockHeadersWithChainLocks(request,
             target,
@@ -149,6 +149,34 @@ class CorePromiseClient {
     );
   }
 
+  /**
+   * @param {!GetBlockFiltersRequest} getBlockFiltersRequest
+   * @param {?Object<string, string>} metadata
+   * @returns {Promise<!GetBlockFiltersResponse>}
+   */
+  getBlockFilters(getBlockFiltersRequest, metadata = {}) {
+    return promisify(
+      this.client.getBlockFilters.bind(this.client),
+    )(
+      getBlockFiltersRequest,
+      metadata,
+    );
+  }
+
+  /**
+   * @param {!GetBlockFilterHeadersRequest} getBlockFilterHeadersRequest
+   * @param {?Object<string, string>} metadata
+   * @returns {Promise<!GetBlockFilterHeadersResponse>}
+   */
+  getBlockFilterHeaders(getBlockFilterHeadersRequest, metadata = {}) {
+    return promisify(
+      this.client.getBlockFilterHeaders.bind(this.client),
+    )(
+      getBlockFilterHeadersRequest,
+      metadata,
+    );
+  }
+
   /**
    * @param {!BlockHeadersWithChainLocksRequest} blockHeadersWithChainLocksRequest
    * @param {?Object<string, string>} metadata
@@ -865,3 +865,135 @@ export namespace MasternodeListResponse {
   }
 }
 
+export class GetBlockFiltersRequest extends jspb.Message {
+  getStartHeight(): number;
+  setStartHeight(value: number): void;
+
+  getCount(): number;
+  setCount(value: number): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetBlockFiltersRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetBlockFiltersRequest): GetBlockFiltersRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetBlockFiltersRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetBlockFiltersRequest;
+  static deserializeBinaryFromReader(message: GetBlockFiltersRequest, reader: jspb.BinaryReader): GetBlockFiltersRequest;
+}
+
+export namespace GetBlockFiltersRequest {
+  export type AsObject = {
+    startHeight: number,
+    count: number,
+  }
+}
+
+export class GetBlockFiltersResponse extends jspb.Message {
+  clearFiltersList(): void;
+  getFiltersList(): Array<GetBlockFiltersResponse.BlockFilter>;
+  setFiltersList(value: Array<GetBlockFiltersResponse.BlockFilter>): void;
+  addFilters(value?: GetBlockFiltersResponse.BlockFilter, index?: number): GetBlockFiltersResponse.BlockFilter;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetBlockFiltersResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetBlockFiltersResponse): GetBlockFiltersResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetBlockFiltersResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetBlockFiltersResponse;
+  static deserializeBinaryFromReader(message: GetBlockFiltersResponse, reader: jspb.BinaryReader): GetBlockFiltersResponse;
+}
+
+export namespace GetBlockFiltersResponse {
+  export type AsObject = {
+    filtersList: Array<GetBlockFiltersResponse.BlockFilter.AsObject>,
+  }
+
+  export class BlockFilter extends jspb.Message {
+    getHeight(): number;
+    setHeight(value: number): void;
+
+    getBlockHash(): Uint8Array | string;
+    getBlockHash_asU8(): Uint8Array;
+    getBlockHash_asB64(): string;
+    setBlockHash(value: Uint8Array | string): void;
+
+    getFilter(): Uint8Array | string;
+    getFilter_asU8(): Uint8Array;
+    getFilter_asB64(): string;
+    setFilter(value: Uint8Array | string): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): BlockFilter.AsObject;
+    static toObject(includeInstance: boolean, msg: BlockFilter): BlockFilter.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: BlockFilter, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): BlockFilter;
+    static deserializeBinaryFromReader(message: BlockFilter, reader: jspb.BinaryReader): BlockFilter;
+  }
+
+  export namespace BlockFilter {
+    export type AsObject = {
+      height: number,
+      blockHash: Uint8Array | string,
+      filter: Uint8Array | string,
+    }
+  }
+}
+
+export class GetBlockFilterHeadersRequest extends jspb.Message {
+  getStartHeight(): number;
+  setStartHeight(value: number): void;
+
+  getCount(): number;
+  setCount(value: number): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetBlockFilterHeadersRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetBlockFilterHeadersRequest): GetBlockFilterHeadersRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetBlockFilterHeadersRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetBlockFilterHeadersRequest;
+  static deserializeBinaryFromReader(message: GetBlockFilterHeadersRequest, reader: jspb.BinaryReader): GetBlockFilterHeadersRequest;
+}
+
+export namespace GetBlockFilterHeadersRequest {
+  export type AsObject = {
+    startHeight: number,
+    count: number,
+  }
+}
+
+export class GetBlockFilterHeadersResponse extends jspb.Message {
+  getPreviousFilterHeader(): Uint8Array | string;
+  getPreviousFilterHeader_asU8(): Uint8Array;
+  getPreviousFilterHeader_asB64(): string;
+  setPreviousFilterHeader(value: Uint8Array | string): void;
+
+  clearFilterHashesList(): void;
+  getFilterHashesList(): Array<Uint8Array | string>;
+  getFilterHashesList_asU8(): Array<Uint8Array>;
+  getFilterHashesList_asB64(): Array<string>;
+  setFilterHashesList(value: Array<Uint8Array | string>): void;
+  addFilterHashes(value: Uint8Array | string, index?: number): Uint8Array | string;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetBlockFilterHeadersResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetBlockFilterHeadersResponse): GetBlockFilterHeadersResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetBlockFilterHeadersResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetBlockFilterHeadersResponse;
+  static deserializeBinaryFromReader(message: GetBlockFilterHeadersResponse, reader: jspb.BinaryReader): GetBlockFilterHeadersResponse;
+}
+
+export namespace GetBlockFilterHeadersResponse {
+  export type AsObject = {
+    previousFilterHeader: Uint8Array | string,
+    filterHashesList: Array<Uint8Array | string>,
+  }
+}
+
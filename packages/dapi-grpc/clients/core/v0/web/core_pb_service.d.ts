@@ -67,6 +67,24 @@ type CoregetEstimatedTransactionFee = {
   readonly responseType: typeof core_pb.GetEstimatedTransactionFeeResponse;
 };
 
+type CoregetBlockFilters = {
+  readonly methodName: string;
+  readonly service: typeof Core;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof core_pb.GetBlockFiltersRequest;
+  readonly responseType: typeof core_pb.GetBlockFiltersResponse;
+};
+
+type CoregetBlockFilterHeaders = {
+  readonly methodName: string;
+  readonly service: typeof Core;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof core_pb.GetBlockFilterHeadersRequest;
+  readonly responseType: typeof core_pb.GetBlockFilterHeadersResponse;
+};
+
 type CoresubscribeToBlockHeadersWithChainLocks = {
   readonly methodName: string;
   readonly service: typeof Core;
@@ -103,6 +121,8 @@ export class Core {
   static readonly broadcastTransaction: CorebroadcastTransaction;
   static readonly getTransaction: CoregetTransaction;
   static readonly getEstimatedTransactionFee: CoregetEstimatedTransactionFee;
+  static readonly getBlockFilters: CoregetBlockFilters;
+  static readonly getBlockFilterHeaders: CoregetBlockFilterHeaders;
   static readonly subscribeToBlockHeadersWithChainLocks: CoresubscribeToBlockHeadersWithChainLocks;
   static readonly subscribeToTransactionsWithProofs: CoresubscribeToTransactionsWithProofs;
   static readonly subscribeToMasternodeList: CoresubscribeToMasternodeList;
@@ -203,6 +223,24 @@ export class CoreClient {
     requestMessage: core_pb.GetEstimatedTransactionFeeRequest,
     callback: (error: ServiceError|null, responseMessage: core_pb.GetEstimatedTransactionFeeResponse|null) => void
   ): UnaryResponse;
+  getBlockFilters(
+    requestMessage: core_pb.GetBlockFiltersRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: core_pb.GetBlockFiltersResponse|null) => void
+  ): UnaryResponse;
+  getBlockFilters(
+    requestMessage: core_pb.GetBlockFiltersRequest,
+    callback: (error: ServiceError|null, responseMessage: core_pb.GetBlockFiltersResponse|null) => void
+  ): UnaryResponse;
+  getBlockFilterHeaders(
+    requestMessage: core_pb.GetBlockFilterHeadersRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: core_pb.GetBlockFilterHeadersResponse|null) => void
+  ): UnaryResponse;
+  getBlockFilterHeaders(
+    requestMessage: core_pb.GetBlockFilterHeadersRequest,
+    callback: (error: ServiceError|null, responseMessage: core_pb.GetBlockFilterHeadersResponse|null) => void
+  ): UnaryResponse;
   subscribeToBlockHeadersWithChainLocks(requestMessage: core_pb.BlockHeadersWithChainLocksRequest, metadata?: grpc.Metadata): ResponseStream<core_pb.BlockHeadersWithChainLocksResponse>;
   subscribeToTransactionsWithProofs(requestMessage: core_pb.TransactionsWithProofsRequest, metadata?: grpc.Metadata): ResponseStream<core_pb.TransactionsWithProofsResponse>;
   subscribeToMasternodeList(requestMessage: core_pb.MasternodeListRequest, metadata?: grpc.Metadata): ResponseStream<core_pb.MasternodeListResponse>;
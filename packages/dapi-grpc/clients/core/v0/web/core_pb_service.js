@@ -73,6 +73,24 @@ Core.getEstimatedTransactionFee = {
   responseType: core_pb.GetEstimatedTransactionFeeResponse
 };
 
+Core.getBlockFilters = {
+  methodName: "getBlockFilters",
+  service: Core,
+  requestStream: false,
+  responseStream: false,
+  requestType: core_pb.GetBlockFiltersRequest,
+  responseType: core_pb.GetBlockFiltersResponse
+};
+
+Core.getBlockFilterHeaders = {
+  methodName: "getBlockFilterHeaders",
+  service: Core,
+  requestStream: false,
+  responseStream: false,
+  requestType: core_pb.GetBlockFilterHeadersRequest,
+  responseType: core_pb.GetBlockFilterHeadersResponse
+};
+
 Core.subscribeToBlockHeadersWithChainLocks = {
   methodName: "subscribeToBlockHeadersWithChainLocks",
   service: Core,
@@ -324,6 +342,68 @@ CoreClient.prototype.getEstimatedTransactionFee = function getEstimatedTransacti
   };
 };
 
+CoreClient.prototype.getBlockFilters = function getBlockFilters(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Core.getBlockFilters, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
+CoreClient.prototype.getBlockFilterHeaders = function getBlockFilterHeaders(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Core.getBlockFilterHeaders, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
 CoreClient.prototype.subscribeToBlockHeadersWithChainLocks = function subscribeToBlockHeadersWithChainLocks(requestMessage, metadata) {
   var listeners = {
     data: [],
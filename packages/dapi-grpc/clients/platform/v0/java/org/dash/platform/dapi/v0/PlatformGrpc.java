@@ -480,6 +480,68 @@ public final class PlatformGrpc {
     return getGetDocumentsMethod;
   }
 
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse> getGetDocumentsAggregateMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getDocumentsAggregate",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse> getGetDocumentsAggregateMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse> getGetDocumentsAggregateMethod;
+    if ((getGetDocumentsAggregateMethod = PlatformGrpc.getGetDocumentsAggregateMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetDocumentsAggregateMethod = PlatformGrpc.getGetDocumentsAggregateMethod) == null) {
+          PlatformGrpc.getGetDocumentsAggregateMethod = getGetDocumentsAggregateMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getDocumentsAggregate"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getDocumentsAggregate"))
+              .build();
+        }
+      }
+    }
+    return getGetDocumentsAggregateMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsResponse> getSubscribeDocumentsMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "subscribeDocuments",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.SERVER_STREAMING)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsResponse> getSubscribeDocumentsMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsResponse> getSubscribeDocumentsMethod;
+    if ((getSubscribeDocumentsMethod = PlatformGrpc.getSubscribeDocumentsMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getSubscribeDocumentsMethod = PlatformGrpc.getSubscribeDocumentsMethod) == null) {
+          PlatformGrpc.getSubscribeDocumentsMethod = getSubscribeDocumentsMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.SERVER_STREAMING)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "subscribeDocuments"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("subscribeDocuments"))
+              .build();
+        }
+      }
+    }
+    return getSubscribeDocumentsMethod;
+  }
+
   private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByPublicKeyHashRequest,
       org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByPublicKeyHashResponse> getGetIdentityByPublicKeyHashMethod;
 
@@ -542,6 +604,37 @@ public final class PlatformGrpc {
     return getGetIdentityByNonUniquePublicKeyHashMethod;
   }
 
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse> getGetIdentityStateTransitionsMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getIdentityStateTransitions",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse> getGetIdentityStateTransitionsMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse> getGetIdentityStateTransitionsMethod;
+    if ((getGetIdentityStateTransitionsMethod = PlatformGrpc.getGetIdentityStateTransitionsMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetIdentityStateTransitionsMethod = PlatformGrpc.getGetIdentityStateTransitionsMethod) == null) {
+          PlatformGrpc.getGetIdentityStateTransitionsMethod = getGetIdentityStateTransitionsMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getIdentityStateTransitions"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getIdentityStateTransitions"))
+              .build();
+        }
+      }
+    }
+    return getGetIdentityStateTransitionsMethod;
+  }
+
   private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.WaitForStateTransitionResultRequest,
       org.dash.platform.dapi.v0.PlatformOuterClass.WaitForStateTransitionResultResponse> getWaitForStateTransitionResultMethod;
 
@@ -573,6 +666,37 @@ public final class PlatformGrpc {
     return getWaitForStateTransitionResultMethod;
   }
 
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse> getSimulateStateTransitionMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "simulateStateTransition",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse> getSimulateStateTransitionMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest, org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse> getSimulateStateTransitionMethod;
+    if ((getSimulateStateTransitionMethod = PlatformGrpc.getSimulateStateTransitionMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getSimulateStateTransitionMethod = PlatformGrpc.getSimulateStateTransitionMethod) == null) {
+          PlatformGrpc.getSimulateStateTransitionMethod = getSimulateStateTransitionMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest, org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "simulateStateTransition"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("simulateStateTransition"))
+              .build();
+        }
+      }
+    }
+    return getSimulateStateTransitionMethod;
+  }
+
   private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsRequest,
       org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsResponse> getGetConsensusParamsMethod;
 
@@ -728,6 +852,37 @@ public final class PlatformGrpc {
     return getGetFinalizedEpochInfosMethod;
   }
 
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse> getGetBlockStateTransitionsMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getBlockStateTransitions",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse> getGetBlockStateTransitionsMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse> getGetBlockStateTransitionsMethod;
+    if ((getGetBlockStateTransitionsMethod = PlatformGrpc.getGetBlockStateTransitionsMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetBlockStateTransitionsMethod = PlatformGrpc.getGetBlockStateTransitionsMethod) == null) {
+          PlatformGrpc.getGetBlockStateTransitionsMethod = getGetBlockStateTransitionsMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getBlockStateTransitions"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getBlockStateTransitions"))
+              .build();
+        }
+      }
+    }
+    return getGetBlockStateTransitionsMethod;
+  }
+
   private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourcesRequest,
       org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourcesResponse> getGetContestedResourcesMethod;
 
@@ -883,6 +1038,37 @@ public final class PlatformGrpc {
     return getGetVotePollsByEndDateMethod;
   }
 
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse> getGetYesNoAbstainVotePollStateMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getYesNoAbstainVotePollState",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse> getGetYesNoAbstainVotePollStateMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse> getGetYesNoAbstainVotePollStateMethod;
+    if ((getGetYesNoAbstainVotePollStateMethod = PlatformGrpc.getGetYesNoAbstainVotePollStateMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetYesNoAbstainVotePollStateMethod = PlatformGrpc.getGetYesNoAbstainVotePollStateMethod) == null) {
+          PlatformGrpc.getGetYesNoAbstainVotePollStateMethod = getGetYesNoAbstainVotePollStateMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getYesNoAbstainVotePollState"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getYesNoAbstainVotePollState"))
+              .build();
+        }
+      }
+    }
+    return getGetYesNoAbstainVotePollStateMethod;
+  }
+
   private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetPrefundedSpecializedBalanceRequest,
       org.dash.platform.dapi.v0.PlatformOuterClass.GetPrefundedSpecializedBalanceResponse> getGetPrefundedSpecializedBalanceMethod;
 
@@ -1348,6 +1534,161 @@ public final class PlatformGrpc {
     return getGetTokenTotalSupplyMethod;
   }
 
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse> getGetTokenHoldersMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getTokenHolders",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse> getGetTokenHoldersMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse> getGetTokenHoldersMethod;
+    if ((getGetTokenHoldersMethod = PlatformGrpc.getGetTokenHoldersMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetTokenHoldersMethod = PlatformGrpc.getGetTokenHoldersMethod) == null) {
+          PlatformGrpc.getGetTokenHoldersMethod = getGetTokenHoldersMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getTokenHolders"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getTokenHolders"))
+              .build();
+        }
+      }
+    }
+    return getGetTokenHoldersMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse> getGetTokenOrderBookMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getTokenOrderBook",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse> getGetTokenOrderBookMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse> getGetTokenOrderBookMethod;
+    if ((getGetTokenOrderBookMethod = PlatformGrpc.getGetTokenOrderBookMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetTokenOrderBookMethod = PlatformGrpc.getGetTokenOrderBookMethod) == null) {
+          PlatformGrpc.getGetTokenOrderBookMethod = getGetTokenOrderBookMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getTokenOrderBook"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getTokenOrderBook"))
+              .build();
+        }
+      }
+    }
+    return getGetTokenOrderBookMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse> getGetTokenVestingsMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getTokenVestings",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse> getGetTokenVestingsMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse> getGetTokenVestingsMethod;
+    if ((getGetTokenVestingsMethod = PlatformGrpc.getGetTokenVestingsMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetTokenVestingsMethod = PlatformGrpc.getGetTokenVestingsMethod) == null) {
+          PlatformGrpc.getGetTokenVestingsMethod = getGetTokenVestingsMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getTokenVestings"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getTokenVestings"))
+              .build();
+        }
+      }
+    }
+    return getGetTokenVestingsMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse> getGetTokenAllowancesMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getTokenAllowances",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse> getGetTokenAllowancesMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse> getGetTokenAllowancesMethod;
+    if ((getGetTokenAllowancesMethod = PlatformGrpc.getGetTokenAllowancesMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetTokenAllowancesMethod = PlatformGrpc.getGetTokenAllowancesMethod) == null) {
+          PlatformGrpc.getGetTokenAllowancesMethod = getGetTokenAllowancesMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getTokenAllowances"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getTokenAllowances"))
+              .build();
+        }
+      }
+    }
+    return getGetTokenAllowancesMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse> getGetTokenEventsMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getTokenEvents",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse> getGetTokenEventsMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse> getGetTokenEventsMethod;
+    if ((getGetTokenEventsMethod = PlatformGrpc.getGetTokenEventsMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetTokenEventsMethod = PlatformGrpc.getGetTokenEventsMethod) == null) {
+          PlatformGrpc.getGetTokenEventsMethod = getGetTokenEventsMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getTokenEvents"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getTokenEvents"))
+              .build();
+        }
+      }
+    }
+    return getGetTokenEventsMethod;
+  }
+
   private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetGroupInfoRequest,
       org.dash.platform.dapi.v0.PlatformOuterClass.GetGroupInfoResponse> getGetGroupInfoMethod;
 
@@ -1625,6 +1966,20 @@ public final class PlatformGrpc {
       io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetDocumentsMethod(), responseObserver);
     }
 
+    /**
+     */
+    public void getDocumentsAggregate(org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetDocumentsAggregateMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void subscribeDocuments(org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getSubscribeDocumentsMethod(), responseObserver);
+    }
+
     /**
      */
     public void getIdentityByPublicKeyHash(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByPublicKeyHashRequest request,
@@ -1639,6 +1994,13 @@ public final class PlatformGrpc {
       io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetIdentityByNonUniquePublicKeyHashMethod(), responseObserver);
     }
 
+    /**
+     */
+    public void getIdentityStateTransitions(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetIdentityStateTransitionsMethod(), responseObserver);
+    }
+
     /**
      */
     public void waitForStateTransitionResult(org.dash.platform.dapi.v0.PlatformOuterClass.WaitForStateTransitionResultRequest request,
@@ -1646,6 +2008,13 @@ public final class PlatformGrpc {
       io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getWaitForStateTransitionResultMethod(), responseObserver);
     }
 
+    /**
+     */
+    public void simulateStateTransition(org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getSimulateStateTransitionMethod(), responseObserver);
+    }
+
     /**
      */
     public void getConsensusParams(org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsRequest request,
@@ -1681,6 +2050,16 @@ public final class PlatformGrpc {
       io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetFinalizedEpochInfosMethod(), responseObserver);
     }
 
+    /**
+     * <pre>
+     * Which state transitions were executed in a recent block, with their results
+     * </pre>
+     */
+    public void getBlockStateTransitions(org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetBlockStateTransitionsMethod(), responseObserver);
+    }
+
     /**
      * <pre>
      * What votes are currently happening for a specific contested index
@@ -1731,6 +2110,16 @@ public final class PlatformGrpc {
       io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetVotePollsByEndDateMethod(), responseObserver);
     }
 
+    /**
+     * <pre>
+     * How did masternodes vote on a yes/no/abstain poll?
+     * </pre>
+     */
+    public void getYesNoAbstainVotePollState(org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetYesNoAbstainVotePollStateMethod(), responseObserver);
+    }
+
     /**
      */
     public void getPrefundedSpecializedBalance(org.dash.platform.dapi.v0.PlatformOuterClass.GetPrefundedSpecializedBalanceRequest request,
@@ -1836,6 +2225,41 @@ public final class PlatformGrpc {
       io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetTokenTotalSupplyMethod(), responseObserver);
     }
 
+    /**
+     */
+    public void getTokenHolders(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetTokenHoldersMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void getTokenOrderBook(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetTokenOrderBookMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void getTokenVestings(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetTokenVestingsMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void getTokenAllowances(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetTokenAllowancesMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void getTokenEvents(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetTokenEventsMethod(), responseObserver);
+    }
+
     /**
      */
     public void getGroupInfo(org.dash.platform.dapi.v0.PlatformOuterClass.GetGroupInfoRequest request,
@@ -1971,6 +2395,20 @@ public final class PlatformGrpc {
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsRequest,
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsResponse>(
                   this, METHODID_GET_DOCUMENTS)))
+          .addMethod(
+            getGetDocumentsAggregateMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse>(
+                  this, METHODID_GET_DOCUMENTS_AGGREGATE)))
+          .addMethod(
+            getSubscribeDocumentsMethod(),
+            io.grpc.stub.ServerCalls.asyncServerStreamingCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsResponse>(
+                  this, METHODID_SUBSCRIBE_DOCUMENTS)))
           .addMethod(
             getGetIdentityByPublicKeyHashMethod(),
             io.grpc.stub.ServerCalls.asyncUnaryCall(
@@ -1985,6 +2423,13 @@ public final class PlatformGrpc {
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByNonUniquePublicKeyHashRequest,
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByNonUniquePublicKeyHashResponse>(
                   this, METHODID_GET_IDENTITY_BY_NON_UNIQUE_PUBLIC_KEY_HASH)))
+          .addMethod(
+            getGetIdentityStateTransitionsMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse>(
+                  this, METHODID_GET_IDENTITY_STATE_TRANSITIONS)))
           .addMethod(
             getWaitForStateTransitionResultMethod(),
             io.grpc.stub.ServerCalls.asyncUnaryCall(
@@ -1992,6 +2437,13 @@ public final class PlatformGrpc {
                 org.dash.platform.dapi.v0.PlatformOuterClass.WaitForStateTransitionResultRequest,
                 org.dash.platform.dapi.v0.PlatformOuterClass.WaitForStateTransitionResultResponse>(
                   this, METHODID_WAIT_FOR_STATE_TRANSITION_RESULT)))
+          .addMethod(
+            getSimulateStateTransitionMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse>(
+                  this, METHODID_SIMULATE_STATE_TRANSITION)))
           .addMethod(
             getGetConsensusParamsMethod(),
             io.grpc.stub.ServerCalls.asyncUnaryCall(
@@ -2027,6 +2479,13 @@ public final class PlatformGrpc {
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetFinalizedEpochInfosRequest,
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetFinalizedEpochInfosResponse>(
                   this, METHODID_GET_FINALIZED_EPOCH_INFOS)))
+          .addMethod(
+            getGetBlockStateTransitionsMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse>(
+                  this, METHODID_GET_BLOCK_STATE_TRANSITIONS)))
           .addMethod(
             getGetContestedResourcesMethod(),
             io.grpc.stub.ServerCalls.asyncUnaryCall(
@@ -2062,6 +2521,13 @@ public final class PlatformGrpc {
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetVotePollsByEndDateRequest,
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetVotePollsByEndDateResponse>(
                   this, METHODID_GET_VOTE_POLLS_BY_END_DATE)))
+          .addMethod(
+            getGetYesNoAbstainVotePollStateMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse>(
+                  this, METHODID_GET_YES_NO_ABSTAIN_VOTE_POLL_STATE)))
           .addMethod(
             getGetPrefundedSpecializedBalanceMethod(),
             io.grpc.stub.ServerCalls.asyncUnaryCall(
@@ -2167,6 +2633,41 @@ public final class PlatformGrpc {
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenTotalSupplyRequest,
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenTotalSupplyResponse>(
                   this, METHODID_GET_TOKEN_TOTAL_SUPPLY)))
+          .addMethod(
+            getGetTokenHoldersMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse>(
+                  this, METHODID_GET_TOKEN_HOLDERS)))
+          .addMethod(
+            getGetTokenOrderBookMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse>(
+                  this, METHODID_GET_TOKEN_ORDER_BOOK)))
+          .addMethod(
+            getGetTokenVestingsMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse>(
+                  this, METHODID_GET_TOKEN_VESTINGS)))
+          .addMethod(
+            getGetTokenAllowancesMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse>(
+                  this, METHODID_GET_TOKEN_ALLOWANCES)))
+          .addMethod(
+            getGetTokenEventsMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse>(
+                  this, METHODID_GET_TOKEN_EVENTS)))
           .addMethod(
             getGetGroupInfoMethod(),
             io.grpc.stub.ServerCalls.asyncUnaryCall(
@@ -2333,6 +2834,22 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetDocumentsMethod(), getCallOptions()), request, responseObserver);
     }
 
+    /**
+     */
+    public void getDocumentsAggregate(org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetDocumentsAggregateMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void subscribeDocuments(org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncServerStreamingCall(
+          getChannel().newCall(getSubscribeDocumentsMethod(), getCallOptions()), request, responseObserver);
+    }
+
     /**
      */
     public void getIdentityByPublicKeyHash(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByPublicKeyHashRequest request,
@@ -2349,6 +2866,14 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetIdentityByNonUniquePublicKeyHashMethod(), getCallOptions()), request, responseObserver);
     }
 
+    /**
+     */
+    public void getIdentityStateTransitions(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetIdentityStateTransitionsMethod(), getCallOptions()), request, responseObserver);
+    }
+
     /**
      */
     public void waitForStateTransitionResult(org.dash.platform.dapi.v0.PlatformOuterClass.WaitForStateTransitionResultRequest request,
@@ -2357,6 +2882,14 @@ public final class PlatformGrpc {
           getChannel().newCall(getWaitForStateTransitionResultMethod(), getCallOptions()), request, responseObserver);
     }
 
+    /**
+     */
+    public void simulateStateTransition(org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getSimulateStateTransitionMethod(), getCallOptions()), request, responseObserver);
+    }
+
     /**
      */
     public void getConsensusParams(org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsRequest request,
@@ -2397,6 +2930,17 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetFinalizedEpochInfosMethod(), getCallOptions()), request, responseObserver);
     }
 
+    /**
+     * <pre>
+     * Which state transitions were executed in a recent block, with their results
+     * </pre>
+     */
+    public void getBlockStateTransitions(org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetBlockStateTransitionsMethod(), getCallOptions()), request, responseObserver);
+    }
+
     /**
      * <pre>
      * What votes are currently happening for a specific contested index
@@ -2452,6 +2996,17 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetVotePollsByEndDateMethod(), getCallOptions()), request, responseObserver);
     }
 
+    /**
+     * <pre>
+     * How did masternodes vote on a yes/no/abstain poll?
+     * </pre>
+     */
+    public void getYesNoAbstainVotePollState(org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetYesNoAbstainVotePollStateMethod(), getCallOptions()), request, responseObserver);
+    }
+
     /**
      */
     public void getPrefundedSpecializedBalance(org.dash.platform.dapi.v0.PlatformOuterClass.GetPrefundedSpecializedBalanceRequest request,
@@ -2572,6 +3127,46 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetTokenTotalSupplyMethod(), getCallOptions()), request, responseObserver);
     }
 
+    /**
+     */
+    public void getTokenHolders(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetTokenHoldersMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void getTokenOrderBook(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetTokenOrderBookMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void getTokenVestings(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetTokenVestingsMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void getTokenAllowances(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetTokenAllowancesMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void getTokenEvents(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetTokenEventsMethod(), getCallOptions()), request, responseObserver);
+    }
+
     /**
      */
     public void getGroupInfo(org.dash.platform.dapi.v0.PlatformOuterClass.GetGroupInfoRequest request,
@@ -2724,6 +3319,21 @@ public final class PlatformGrpc {
           getChannel(), getGetDocumentsMethod(), getCallOptions(), request);
     }
 
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse getDocumentsAggregate(org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetDocumentsAggregateMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public java.util.Iterator<org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsResponse> subscribeDocuments(
+        org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsRequest request) {
+      return io.grpc.stub.ClientCalls.blockingServerStreamingCall(
+          getChannel(), getSubscribeDocumentsMethod(), getCallOptions(), request);
+    }
+
     /**
      */
     public org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByPublicKeyHashResponse getIdentityByPublicKeyHash(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByPublicKeyHashRequest request) {
@@ -2738,6 +3348,13 @@ public final class PlatformGrpc {
           getChannel(), getGetIdentityByNonUniquePublicKeyHashMethod(), getCallOptions(), request);
     }
 
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse getIdentityStateTransitions(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetIdentityStateTransitionsMethod(), getCallOptions(), request);
+    }
+
     /**
      */
     public org.dash.platform.dapi.v0.PlatformOuterClass.WaitForStateTransitionResultResponse waitForStateTransitionResult(org.dash.platform.dapi.v0.PlatformOuterClass.WaitForStateTransitionResultRequest request) {
@@ -2745,6 +3362,13 @@ public final class PlatformGrpc {
           getChannel(), getWaitForStateTransitionResultMethod(), getCallOptions(), request);
     }
 
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse simulateStateTransition(org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getSimulateStateTransitionMethod(), getCallOptions(), request);
+    }
+
     /**
      */
     public org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsResponse getConsensusParams(org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsRequest request) {
@@ -2780,6 +3404,16 @@ public final class PlatformGrpc {
           getChannel(), getGetFinalizedEpochInfosMethod(), getCallOptions(), request);
     }
 
+    /**
+     * <pre>
+     * Which state transitions were executed in a recent block, with their results
+     * </pre>
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse getBlockStateTransitions(org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetBlockStateTransitionsMethod(), getCallOptions(), request);
+    }
+
     /**
      * <pre>
      * What votes are currently happening for a specific contested index
@@ -2830,6 +3464,16 @@ public final class PlatformGrpc {
           getChannel(), getGetVotePollsByEndDateMethod(), getCallOptions(), request);
     }
 
+    /**
+     * <pre>
+     * How did masternodes vote on a yes/no/abstain poll?
+     * </pre>
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse getYesNoAbstainVotePollState(org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetYesNoAbstainVotePollStateMethod(), getCallOptions(), request);
+    }
+
     /**
      */
     public org.dash.platform.dapi.v0.PlatformOuterClass.GetPrefundedSpecializedBalanceResponse getPrefundedSpecializedBalance(org.dash.platform.dapi.v0.PlatformOuterClass.GetPrefundedSpecializedBalanceRequest request) {
@@ -2935,6 +3579,41 @@ public final class PlatformGrpc {
           getChannel(), getGetTokenTotalSupplyMethod(), getCallOptions(), request);
     }
 
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse getTokenHolders(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetTokenHoldersMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse getTokenOrderBook(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetTokenOrderBookMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse getTokenVestings(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetTokenVestingsMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse getTokenAllowances(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetTokenAllowancesMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse getTokenEvents(org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetTokenEventsMethod(), getCallOptions(), request);
+    }
+
     /**
      */
     public org.dash.platform.dapi.v0.PlatformOuterClass.GetGroupInfoResponse getGroupInfo(org.dash.platform.dapi.v0.PlatformOuterClass.GetGroupInfoRequest request) {
@@ -3098,6 +3777,14 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetDocumentsMethod(), getCallOptions()), request);
     }
 
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse> getDocumentsAggregate(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetDocumentsAggregateMethod(), getCallOptions()), request);
+    }
+
     /**
      */
     public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByPublicKeyHashResponse> getIdentityByPublicKeyHash(
@@ -3114,6 +3801,14 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetIdentityByNonUniquePublicKeyHashMethod(), getCallOptions()), request);
     }
 
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse> getIdentityStateTransitions(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetIdentityStateTransitionsMethod(), getCallOptions()), request);
+    }
+
     /**
      */
     public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.WaitForStateTransitionResultResponse> waitForStateTransitionResult(
@@ -3122,6 +3817,14 @@ public final class PlatformGrpc {
           getChannel().newCall(getWaitForStateTransitionResultMethod(), getCallOptions()), request);
     }
 
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse> simulateStateTransition(
+        org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getSimulateStateTransitionMethod(), getCallOptions()), request);
+    }
+
     /**
      */
     public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsResponse> getConsensusParams(
@@ -3162,6 +3865,17 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetFinalizedEpochInfosMethod(), getCallOptions()), request);
     }
 
+    /**
+     * <pre>
+     * Which state transitions were executed in a recent block, with their results
+     * </pre>
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse> getBlockStateTransitions(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetBlockStateTransitionsMethod(), getCallOptions()), request);
+    }
+
     /**
      * <pre>
      * What votes are currently happening for a specific contested index
@@ -3217,6 +3931,17 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetVotePollsByEndDateMethod(), getCallOptions()), request);
     }
 
+    /**
+     * <pre>
+     * How did masternodes vote on a yes/no/abstain poll?
+     * </pre>
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse> getYesNoAbstainVotePollState(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetYesNoAbstainVotePollStateMethod(), getCallOptions()), request);
+    }
+
     /**
      */
     public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetPrefundedSpecializedBalanceResponse> getPrefundedSpecializedBalance(
@@ -3337,6 +4062,46 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetTokenTotalSupplyMethod(), getCallOptions()), request);
     }
 
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse> getTokenHolders(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetTokenHoldersMethod(), getCallOptions()), request);
+    }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse> getTokenOrderBook(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetTokenOrderBookMethod(), getCallOptions()), request);
+    }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse> getTokenVestings(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetTokenVestingsMethod(), getCallOptions()), request);
+    }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse> getTokenAllowances(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetTokenAllowancesMethod(), getCallOptions()), request);
+    }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse> getTokenEvents(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetTokenEventsMethod(), getCallOptions()), request);
+    }
+
     /**
      */
     public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetGroupInfoResponse> getGroupInfo(
@@ -3385,38 +4150,49 @@ public final class PlatformGrpc {
   private static final int METHODID_GET_DATA_CONTRACT_HISTORY = 12;
   private static final int METHODID_GET_DATA_CONTRACTS = 13;
   private static final int METHODID_GET_DOCUMENTS = 14;
-  private static final int METHODID_GET_IDENTITY_BY_PUBLIC_KEY_HASH = 15;
-  private static final int METHODID_GET_IDENTITY_BY_NON_UNIQUE_PUBLIC_KEY_HASH = 16;
-  private static final int METHODID_WAIT_FOR_STATE_TRANSITION_RESULT = 17;
-  private static final int METHODID_GET_CONSENSUS_PARAMS = 18;
-  private static final int METHODID_GET_PROTOCOL_VERSION_UPGRADE_STATE = 19;
-  private static final int METHODID_GET_PROTOCOL_VERSION_UPGRADE_VOTE_STATUS = 20;
-  private static final int METHODID_GET_EPOCHS_INFO = 21;
-  private static final int METHODID_GET_FINALIZED_EPOCH_INFOS = 22;
-  private static final int METHODID_GET_CONTESTED_RESOURCES = 23;
-  private static final int METHODID_GET_CONTESTED_RESOURCE_VOTE_STATE = 24;
-  private static final int METHODID_GET_CONTESTED_RESOURCE_VOTERS_FOR_IDENTITY = 25;
-  private static final int METHODID_GET_CONTESTED_RESOURCE_IDENTITY_VOTES = 26;
-  private static final int METHODID_GET_VOTE_POLLS_BY_END_DATE = 27;
-  private static final int METHODID_GET_PREFUNDED_SPECIALIZED_BALANCE = 28;
-  private static final int METHODID_GET_TOTAL_CREDITS_IN_PLATFORM = 29;
-  private static final int METHODID_GET_PATH_ELEMENTS = 30;
-  private static final int METHODID_GET_STATUS = 31;
-  private static final int METHODID_GET_CURRENT_QUORUMS_INFO = 32;
-  private static final int METHODID_GET_IDENTITY_TOKEN_BALANCES = 33;
-  private static final int METHODID_GET_IDENTITIES_TOKEN_BALANCES = 34;
-  private static final int METHODID_GET_IDENTITY_TOKEN_INFOS = 35;
-  private static final int METHODID_GET_IDENTITIES_TOKEN_INFOS = 36;
-  private static final int METHODID_GET_TOKEN_STATUSES = 37;
-  private static final int METHODID_GET_TOKEN_DIRECT_PURCHASE_PRICES = 38;
-  private static final int METHODID_GET_TOKEN_CONTRACT_INFO = 39;
-  private static final int METHODID_GET_TOKEN_PRE_PROGRAMMED_DISTRIBUTIONS = 40;
-  private static final int METHODID_GET_TOKEN_PERPETUAL_DISTRIBUTION_LAST_CLAIM = 41;
-  private static final int METHODID_GET_TOKEN_TOTAL_SUPPLY = 42;
-  private static final int METHODID_GET_GROUP_INFO = 43;
-  private static final int METHODID_GET_GROUP_INFOS = 44;
-  private static final int METHODID_GET_GROUP_ACTIONS = 45;
-  private static final int METHODID_GET_GROUP_ACTION_SIGNERS = 46;
+  private static final int METHODID_GET_DOCUMENTS_AGGREGATE = 15;
+  private static final int METHODID_SUBSCRIBE_DOCUMENTS = 16;
+  private static final int METHODID_GET_IDENTITY_BY_PUBLIC_KEY_HASH = 17;
+  private static final int METHODID_GET_IDENTITY_BY_NON_UNIQUE_PUBLIC_KEY_HASH = 18;
+  private static final int METHODID_GET_IDENTITY_STATE_TRANSITIONS = 19;
+  private static final int METHODID_WAIT_FOR_STATE_TRANSITION_RESULT = 20;
+  private static final int METHODID_SIMULATE_STATE_TRANSITION = 21;
+  private static final int METHODID_GET_CONSENSUS_PARAMS = 22;
+  private static final int METHODID_GET_PROTOCOL_VERSION_UPGRADE_STATE = 23;
+  private static final int METHODID_GET_PROTOCOL_VERSION_UPGRADE_VOTE_STATUS = 24;
+  private static final int METHODID_GET_EPOCHS_INFO = 25;
+  private static final int METHODID_GET_FINALIZED_EPOCH_INFOS = 26;
+  private static final int METHODID_GET_BLOCK_STATE_TRANSITIONS = 27;
+  private static final int METHODID_GET_CONTESTED_RESOURCES = 28;
+  private static final int METHODID_GET_CONTESTED_RESOURCE_VOTE_STATE = 29;
+  private static final int METHODID_GET_CONTESTED_RESOURCE_VOTERS_FOR_IDENTITY = 30;
+  private static final int METHODID_GET_CONTESTED_RESOURCE_IDENTITY_VOTES = 31;
+  private static final int METHODID_GET_VOTE_POLLS_BY_END_DATE = 32;
+  private static final int METHODID_GET_YES_NO_ABSTAIN_VOTE_POLL_STATE = 33;
+  private static final int METHODID_GET_PREFUNDED_SPECIALIZED_BALANCE = 34;
+  private static final int METHODID_GET_TOTAL_CREDITS_IN_PLATFORM = 35;
+  private static final int METHODID_GET_PATH_ELEMENTS = 36;
+  private static final int METHODID_GET_STATUS = 37;
+  private static final int METHODID_GET_CURRENT_QUORUMS_INFO = 38;
+  private static final int METHODID_GET_IDENTITY_TOKEN_BALANCES = 39;
+  private static final int METHODID_GET_IDENTITIES_TOKEN_BALANCES = 40;
+  private static final int METHODID_GET_IDENTITY_TOKEN_INFOS = 41;
+  private static final int METHODID_GET_IDENTITIES_TOKEN_INFOS = 42;
+  private static final int METHODID_GET_TOKEN_STATUSES = 43;
+  private static final int METHODID_GET_TOKEN_DIRECT_PURCHASE_PRICES = 44;
+  private static final int METHODID_GET_TOKEN_CONTRACT_INFO = 45;
+  private static final int METHODID_GET_TOKEN_PRE_PROGRAMMED_DISTRIBUTIONS = 46;
+  private static final int METHODID_GET_TOKEN_PERPETUAL_DISTRIBUTION_LAST_CLAIM = 47;
+  private static final int METHODID_GET_TOKEN_TOTAL_SUPPLY = 48;
+  private static final int METHODID_GET_TOKEN_HOLDERS = 49;
+  private static final int METHODID_GET_TOKEN_ORDER_BOOK = 50;
+  private static final int METHODID_GET_TOKEN_VESTINGS = 51;
+  private static final int METHODID_GET_TOKEN_ALLOWANCES = 52;
+  private static final int METHODID_GET_TOKEN_EVENTS = 53;
+  private static final int METHODID_GET_GROUP_INFO = 54;
+  private static final int METHODID_GET_GROUP_INFOS = 55;
+  private static final int METHODID_GET_GROUP_ACTIONS = 56;
+  private static final int METHODID_GET_GROUP_ACTION_SIGNERS = 57;
 
   private static final class MethodHandlers<Req, Resp> implements
       io.grpc.stub.ServerCalls.UnaryMethod<Req, Resp>,
@@ -3495,6 +4271,14 @@ public final class PlatformGrpc {
           serviceImpl.getDocuments((org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsResponse>) responseObserver);
           break;
+        case METHODID_GET_DOCUMENTS_AGGREGATE:
+          serviceImpl.getDocumentsAggregate((org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsAggregateResponse>) responseObserver);
+          break;
+        case METHODID_SUBSCRIBE_DOCUMENTS:
+          serviceImpl.subscribeDocuments((org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.SubscribeDocumentsResponse>) responseObserver);
+          break;
         case METHODID_GET_IDENTITY_BY_PUBLIC_KEY_HASH:
           serviceImpl.getIdentityByPublicKeyHash((org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByPublicKeyHashRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByPublicKeyHashResponse>) responseObserver);
@@ -3503,10 +4287,18 @@ public final class PlatformGrpc {
           serviceImpl.getIdentityByNonUniquePublicKeyHash((org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByNonUniquePublicKeyHashRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityByNonUniquePublicKeyHashResponse>) responseObserver);
           break;
+        case METHODID_GET_IDENTITY_STATE_TRANSITIONS:
+          serviceImpl.getIdentityStateTransitions((org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityStateTransitionsResponse>) responseObserver);
+          break;
         case METHODID_WAIT_FOR_STATE_TRANSITION_RESULT:
           serviceImpl.waitForStateTransitionResult((org.dash.platform.dapi.v0.PlatformOuterClass.WaitForStateTransitionResultRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.WaitForStateTransitionResultResponse>) responseObserver);
           break;
+        case METHODID_SIMULATE_STATE_TRANSITION:
+          serviceImpl.simulateStateTransition((org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.SimulateStateTransitionResponse>) responseObserver);
+          break;
         case METHODID_GET_CONSENSUS_PARAMS:
           serviceImpl.getConsensusParams((org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetConsensusParamsResponse>) responseObserver);
@@ -3527,6 +4319,10 @@ public final class PlatformGrpc {
           serviceImpl.getFinalizedEpochInfos((org.dash.platform.dapi.v0.PlatformOuterClass.GetFinalizedEpochInfosRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetFinalizedEpochInfosResponse>) responseObserver);
           break;
+        case METHODID_GET_BLOCK_STATE_TRANSITIONS:
+          serviceImpl.getBlockStateTransitions((org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetBlockStateTransitionsResponse>) responseObserver);
+          break;
         case METHODID_GET_CONTESTED_RESOURCES:
           serviceImpl.getContestedResources((org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourcesRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourcesResponse>) responseObserver);
@@ -3547,6 +4343,10 @@ public final class PlatformGrpc {
           serviceImpl.getVotePollsByEndDate((org.dash.platform.dapi.v0.PlatformOuterClass.GetVotePollsByEndDateRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetVotePollsByEndDateResponse>) responseObserver);
           break;
+        case METHODID_GET_YES_NO_ABSTAIN_VOTE_POLL_STATE:
+          serviceImpl.getYesNoAbstainVotePollState((org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetYesNoAbstainVotePollStateResponse>) responseObserver);
+          break;
         case METHODID_GET_PREFUNDED_SPECIALIZED_BALANCE:
           serviceImpl.getPrefundedSpecializedBalance((org.dash.platform.dapi.v0.PlatformOuterClass.GetPrefundedSpecializedBalanceRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetPrefundedSpecializedBalanceResponse>) responseObserver);
@@ -3607,6 +4407,26 @@ public final class PlatformGrpc {
           serviceImpl.getTokenTotalSupply((org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenTotalSupplyRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenTotalSupplyResponse>) responseObserver);
           break;
+        case METHODID_GET_TOKEN_HOLDERS:
+          serviceImpl.getTokenHolders((org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenHoldersResponse>) responseObserver);
+          break;
+        case METHODID_GET_TOKEN_ORDER_BOOK:
+          serviceImpl.getTokenOrderBook((org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenOrderBookResponse>) responseObserver);
+          break;
+        case METHODID_GET_TOKEN_VESTINGS:
+          serviceImpl.getTokenVestings((org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenVestingsResponse>) responseObserver);
+          break;
+        case METHODID_GET_TOKEN_ALLOWANCES:
+          serviceImpl.getTokenAllowances((org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenAllowancesResponse>) responseObserver);
+          break;
+        case METHODID_GET_TOKEN_EVENTS:
+          serviceImpl.getTokenEvents((org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetTokenEventsResponse>) responseObserver);
+          break;
         case METHODID_GET_GROUP_INFO:
           serviceImpl.getGroupInfo((org.dash.platform.dapi.v0.PlatformOuterClass.GetGroupInfoRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetGroupInfoResponse>) responseObserver);
@@ -3699,19 +4519,25 @@ public final class PlatformGrpc {
               .addMethod(getGetDataContractHistoryMethod())
               .addMethod(getGetDataContractsMethod())
               .addMethod(getGetDocumentsMethod())
+              .addMethod(getGetDocumentsAggregateMethod())
+              .addMethod(getSubscribeDocumentsMethod())
               .addMethod(getGetIdentityByPublicKeyHashMethod())
               .addMethod(getGetIdentityByNonUniquePublicKeyHashMethod())
+              .addMethod(getGetIdentityStateTransitionsMethod())
               .addMethod(getWaitForStateTransitionResultMethod())
+              .addMethod(getSimulateStateTransitionMethod())
               .addMethod(getGetConsensusParamsMethod())
               .addMethod(getGetProtocolVersionUpgradeStateMethod())
               .addMethod(getGetProtocolVersionUpgradeVoteStatusMethod())
               .addMethod(getGetEpochsInfoMethod())
               .addMethod(getGetFinalizedEpochInfosMethod())
+              .addMethod(getGetBlockStateTransitionsMethod())
               .addMethod(getGetContestedResourcesMethod())
               .addMethod(getGetContestedResourceVoteStateMethod())
               .addMethod(getGetContestedResourceVotersForIdentityMethod())
               .addMethod(getGetContestedResourceIdentityVotesMethod())
               .addMethod(getGetVotePollsByEndDateMethod())
+              .addMethod(getGetYesNoAbstainVotePollStateMethod())
               .addMethod(getGetPrefundedSpecializedBalanceMethod())
               .addMethod(getGetTotalCreditsInPlatformMethod())
               .addMethod(getGetPathElementsMethod())
@@ -3727,6 +4553,11 @@ public final class PlatformGrpc {
               .addMethod(getGetTokenPreProgrammedDistributionsMethod())
               .addMethod(getGetTokenPerpetualDistributionLastClaimMethod())
               .addMethod(getGetTokenTotalSupplyMethod())
+              .addMethod(getGetTokenHoldersMethod())
+              .addMethod(getGetTokenOrderBookMethod())
+              .addMethod(getGetTokenVestingsMethod())
+              .addMethod(getGetTokenAllowancesMethod())
+              .addMethod(getGetTokenEventsMethod())
               .addMethod(getGetGroupInfoMethod())
               .addMethod(getGetGroupInfosMethod())
               .addMethod(getGetGroupActionsMethod())
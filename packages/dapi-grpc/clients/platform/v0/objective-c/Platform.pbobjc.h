@@ -30,6 +30,11 @@ CF_EXTERN_C_BEGIN
 @class AllKeys;
 @class GPBBytesValue;
 @class GPBUInt32Value;
+@class GetBlockStateTransitionsRequest_GetBlockStateTransitionsRequestV0;
+@class GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0;
+@class GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition;
+@class GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeRefund;
+@class GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeResult;
 @class GetConsensusParamsRequest_GetConsensusParamsRequestV0;
 @class GetConsensusParamsResponse_ConsensusParamsBlock;
 @class GetConsensusParamsResponse_ConsensusParamsEvidence;
@@ -68,6 +73,9 @@ CF_EXTERN_C_BEGIN
 @class GetDataContractsResponse_DataContractEntry;
 @class GetDataContractsResponse_DataContracts;
 @class GetDataContractsResponse_GetDataContractsResponseV0;
+@class GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0;
+@class GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0;
+@class GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_DocumentsAggregate;
 @class GetDocumentsRequest_GetDocumentsRequestV0;
 @class GetDocumentsResponse_GetDocumentsResponseV0;
 @class GetDocumentsResponse_GetDocumentsResponseV0_Documents;
@@ -106,6 +114,7 @@ CF_EXTERN_C_BEGIN
 @class GetGroupActionsResponse_GetGroupActionsResponseV0_MintEvent;
 @class GetGroupActionsResponse_GetGroupActionsResponseV0_TokenConfigUpdateEvent;
 @class GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent;
+@class GetGroupActionsResponse_GetGroupActionsResponseV0_TransferFromEvent;
 @class GetGroupActionsResponse_GetGroupActionsResponseV0_UnfreezeEvent;
 @class GetGroupActionsResponse_GetGroupActionsResponseV0_UpdateDirectPurchasePriceEvent;
 @class GetGroupActionsResponse_GetGroupActionsResponseV0_UpdateDirectPurchasePriceEvent_PriceForQuantity;
@@ -159,6 +168,11 @@ CF_EXTERN_C_BEGIN
 @class GetIdentityNonceResponse_GetIdentityNonceResponseV0;
 @class GetIdentityRequest_GetIdentityRequestV0;
 @class GetIdentityResponse_GetIdentityResponseV0;
+@class GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0;
+@class GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_StartAfter;
+@class GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0;
+@class GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry;
+@class GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitions;
 @class GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0;
 @class GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0;
 @class GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry;
@@ -193,6 +207,10 @@ CF_EXTERN_C_BEGIN
 @class GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive;
 @class GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash;
 @class GetStatusResponse_GetStatusResponseV0_Version_Software;
+@class GetTokenAllowancesRequest_GetTokenAllowancesRequestV0;
+@class GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender;
+@class GetTokenAllowancesResponse_GetTokenAllowancesResponseV0;
+@class GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_TokenAllowances;
 @class GetTokenContractInfoRequest_GetTokenContractInfoRequestV0;
 @class GetTokenContractInfoResponse_GetTokenContractInfoResponseV0;
 @class GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData;
@@ -202,6 +220,22 @@ CF_EXTERN_C_BEGIN
 @class GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PricingSchedule;
 @class GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry;
 @class GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePrices;
+@class GetTokenEventsRequest_GetTokenEventsRequestV0;
+@class GetTokenEventsRequest_GetTokenEventsRequestV0_BlockRange;
+@class GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter;
+@class GetTokenEventsResponse_GetTokenEventsResponseV0;
+@class GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry;
+@class GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEvents;
+@class GetTokenHoldersRequest_GetTokenHoldersRequestV0;
+@class GetTokenHoldersRequest_StartAtIdentityId;
+@class GetTokenHoldersResponse_GetTokenHoldersResponseV0;
+@class GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolderEntry;
+@class GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolders;
+@class GetTokenOrderBookRequest_GetTokenOrderBookRequestV0;
+@class GetTokenOrderBookRequest_QuoteToken;
+@class GetTokenOrderBookResponse_GetTokenOrderBookResponseV0;
+@class GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrderEntry;
+@class GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrders;
 @class GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo;
 @class GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0;
 @class GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0;
@@ -219,6 +253,9 @@ CF_EXTERN_C_BEGIN
 @class GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0;
 @class GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0;
 @class GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry;
+@class GetTokenVestingsRequest_GetTokenVestingsRequestV0;
+@class GetTokenVestingsResponse_GetTokenVestingsResponseV0;
+@class GetTokenVestingsResponse_GetTokenVestingsResponseV0_TokenVestings;
 @class GetTotalCreditsInPlatformRequest_GetTotalCreditsInPlatformRequestV0;
 @class GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0;
 @class GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0;
@@ -227,13 +264,25 @@ CF_EXTERN_C_BEGIN
 @class GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0;
 @class GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp;
 @class GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps;
+@class GetYesNoAbstainVotePollStateRequest_GetYesNoAbstainVotePollStateRequestV0;
+@class GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0;
+@class GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FinalTally;
+@class GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote;
+@class GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVotePollState;
 @class KeyRequestType;
 @class Proof;
 @class ResponseMetadata;
 @class SearchKey;
 @class SecurityLevelMap;
+@class SimulateStateTransitionRequest_SimulateStateTransitionRequestV0;
+@class SimulateStateTransitionResponse_SimulateStateTransitionResponseV0;
+@class SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeRefund;
+@class SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeResult;
 @class SpecificKeys;
 @class StateTransitionBroadcastError;
+@class SubscribeDocumentsRequest_SubscribeDocumentsRequestV0;
+@class SubscribeDocumentsResponse_SubscribeDocumentsResponseV0;
+@class SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_MatchedStateTransition;
 @class WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0;
 @class WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0;
 
@@ -287,6 +336,110 @@ GPBEnumDescriptor *SecurityLevelMap_KeyKindRequestType_EnumDescriptor(void);
  **/
 BOOL SecurityLevelMap_KeyKindRequestType_IsValidValue(int32_t value);
 
+#pragma mark - Enum GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_AggregateFunction
+
+typedef GPB_ENUM(GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_AggregateFunction) {
+  /**
+   * Value used if any message's field encounters a value that is not defined
+   * by this enum. The message will also have C functions to get/set the rawValue
+   * of the field.
+   **/
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_AggregateFunction_GPBUnrecognizedEnumeratorValue = kGPBUnrecognizedEnumeratorValue,
+  /** Count documents matching the query */
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_AggregateFunction_Count = 0,
+
+  /** Sum an integer property over documents matching the query */
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_AggregateFunction_Sum = 1,
+};
+
+GPBEnumDescriptor *GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_AggregateFunction_EnumDescriptor(void);
+
+/**
+ * Checks to see if the given value is defined by the enum or was not known at
+ * the time this source was generated.
+ **/
+BOOL GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_AggregateFunction_IsValidValue(int32_t value);
+
+#pragma mark - Enum SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction
+
+typedef GPB_ENUM(SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction) {
+  /**
+   * Value used if any message's field encounters a value that is not defined
+   * by this enum. The message will also have C functions to get/set the rawValue
+   * of the field.
+   **/
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction_GPBUnrecognizedEnumeratorValue = kGPBUnrecognizedEnumeratorValue,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction_Create = 0,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction_Replace = 1,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction_Delete = 2,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction_Transfer = 3,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction_UpdatePrice = 4,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction_Purchase = 5,
+};
+
+GPBEnumDescriptor *SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction_EnumDescriptor(void);
+
+/**
+ * Checks to see if the given value is defined by the enum or was not known at
+ * the time this source was generated.
+ **/
+BOOL SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction_IsValidValue(int32_t value);
+
+#pragma mark - Enum GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutionStatus
+
+typedef GPB_ENUM(GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutionStatus) {
+  /**
+   * Value used if any message's field encounters a value that is not defined
+   * by this enum. The message will also have C functions to get/set the rawValue
+   * of the field.
+   **/
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutionStatus_GPBUnrecognizedEnumeratorValue = kGPBUnrecognizedEnumeratorValue,
+  /** Executed and applied to the state */
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutionStatus_Success = 0,
+
+  /** Failed validation but was charged fees */
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutionStatus_PaidConsensusError = 1,
+
+  /** Failed validation without paying fees */
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutionStatus_UnpaidConsensusError = 2,
+
+  /** Failed because of an internal error */
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutionStatus_InternalError = 3,
+
+  /** Was not executed */
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutionStatus_NotExecuted = 4,
+};
+
+GPBEnumDescriptor *GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutionStatus_EnumDescriptor(void);
+
+/**
+ * Checks to see if the given value is defined by the enum or was not known at
+ * the time this source was generated.
+ **/
+BOOL GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutionStatus_IsValidValue(int32_t value);
+
+#pragma mark - Enum GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_VoteChoiceType
+
+typedef GPB_ENUM(GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_VoteChoiceType) {
+  /**
+   * Value used if any message's field encounters a value that is not defined
+   * by this enum. The message will also have C functions to get/set the rawValue
+   * of the field.
+   **/
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_VoteChoiceType_GPBUnrecognizedEnumeratorValue = kGPBUnrecognizedEnumeratorValue,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_VoteChoiceType_Yes = 0,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_VoteChoiceType_No = 1,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_VoteChoiceType_Abstain = 2,
+};
+
+GPBEnumDescriptor *GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_VoteChoiceType_EnumDescriptor(void);
+
+/**
+ * Checks to see if the given value is defined by the enum or was not known at
+ * the time this source was generated.
+ **/
+BOOL GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_VoteChoiceType_IsValidValue(int32_t value);
+
 #pragma mark - Enum GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_ResultType
 
 typedef GPB_ENUM(GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_ResultType) {
@@ -353,6 +506,57 @@ GPBEnumDescriptor *GetContestedResourceIdentityVotesResponse_GetContestedResourc
  **/
 BOOL GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_VoteChoiceType_IsValidValue(int32_t value);
 
+#pragma mark - Enum GetTokenOrderBookRequest_OrderSide
+
+typedef GPB_ENUM(GetTokenOrderBookRequest_OrderSide) {
+  /**
+   * Value used if any message's field encounters a value that is not defined
+   * by this enum. The message will also have C functions to get/set the rawValue
+   * of the field.
+   **/
+  GetTokenOrderBookRequest_OrderSide_GPBUnrecognizedEnumeratorValue = kGPBUnrecognizedEnumeratorValue,
+  GetTokenOrderBookRequest_OrderSide_Buy = 0,
+  GetTokenOrderBookRequest_OrderSide_Sell = 1,
+};
+
+GPBEnumDescriptor *GetTokenOrderBookRequest_OrderSide_EnumDescriptor(void);
+
+/**
+ * Checks to see if the given value is defined by the enum or was not known at
+ * the time this source was generated.
+ **/
+BOOL GetTokenOrderBookRequest_OrderSide_IsValidValue(int32_t value);
+
+#pragma mark - Enum GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType
+
+typedef GPB_ENUM(GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType) {
+  /**
+   * Value used if any message's field encounters a value that is not defined
+   * by this enum. The message will also have C functions to get/set the rawValue
+   * of the field.
+   **/
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_GPBUnrecognizedEnumeratorValue = kGPBUnrecognizedEnumeratorValue,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_Mint = 0,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_Burn = 1,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_Freeze = 2,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_Unfreeze = 3,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_DestroyFrozenFunds = 4,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_Transfer = 5,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_Claim = 6,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_EmergencyAction = 7,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_ConfigUpdate = 8,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_DirectPricing = 9,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_DirectPurchase = 10,
+};
+
+GPBEnumDescriptor *GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_EnumDescriptor(void);
+
+/**
+ * Checks to see if the given value is defined by the enum or was not known at
+ * the time this source was generated.
+ **/
+BOOL GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType_IsValidValue(int32_t value);
+
 #pragma mark - Enum GetGroupActionsRequest_ActionStatus
 
 typedef GPB_ENUM(GetGroupActionsRequest_ActionStatus) {
@@ -2355,93 +2559,139 @@ GPB_FINAL @interface GetDocumentsResponse_GetDocumentsResponseV0_Documents : GPB
 
 @end
 
-#pragma mark - GetIdentityByPublicKeyHashRequest
+#pragma mark - GetDocumentsAggregateRequest
 
-typedef GPB_ENUM(GetIdentityByPublicKeyHashRequest_FieldNumber) {
-  GetIdentityByPublicKeyHashRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetDocumentsAggregateRequest_FieldNumber) {
+  GetDocumentsAggregateRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetIdentityByPublicKeyHashRequest_Version_OneOfCase) {
-  GetIdentityByPublicKeyHashRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityByPublicKeyHashRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetDocumentsAggregateRequest_Version_OneOfCase) {
+  GetDocumentsAggregateRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetDocumentsAggregateRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetIdentityByPublicKeyHashRequest : GPBMessage
+GPB_FINAL @interface GetDocumentsAggregateRequest : GPBMessage
 
-@property(nonatomic, readonly) GetIdentityByPublicKeyHashRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetDocumentsAggregateRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetIdentityByPublicKeyHashRequest_ClearVersionOneOfCase(GetIdentityByPublicKeyHashRequest *message);
+void GetDocumentsAggregateRequest_ClearVersionOneOfCase(GetDocumentsAggregateRequest *message);
 
-#pragma mark - GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0
+#pragma mark - GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0
 
-typedef GPB_ENUM(GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0_FieldNumber) {
-  GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0_FieldNumber_PublicKeyHash = 1,
-  GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0_FieldNumber_Prove = 2,
+typedef GPB_ENUM(GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_FieldNumber) {
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_FieldNumber_DataContractId = 1,
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_FieldNumber_DocumentType = 2,
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_FieldNumber_Where = 3,
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_FieldNumber_OrderBy = 4,
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_FieldNumber_Function = 5,
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_FieldNumber_SumProperty = 6,
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_FieldNumber_Limit = 7,
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_FieldNumber_StartAfter = 8,
+  GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_FieldNumber_Prove = 9,
 };
 
-GPB_FINAL @interface GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0 : GPBMessage
+GPB_FINAL @interface GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0 : GPBMessage
 
-/** The public key hash of the identity being requested */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *publicKeyHash;
+/** The ID of the data contract containing the documents */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *dataContractId;
+
+/** The type of documents being aggregated */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *documentType;
+
+/** Conditions to be met by aggregated documents */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *where;
+
+/** Ordering criteria, selects the index to use */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *orderBy;
+
+/** Aggregate function to apply */
+@property(nonatomic, readwrite) GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_AggregateFunction function;
+
+/** Integer property to sum, required for SUM */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *sumProperty;
+
+/**
+ * Maximum number of documents covered by a proof, at most the maximum
+ * query limit of the node; not used without proofs
+ **/
+@property(nonatomic, readwrite) uint32_t limit;
+
+/**
+ * Continue aggregation after this document, as returned in a previous
+ * response
+ **/
+@property(nonatomic, readwrite, copy, null_resettable) NSData *startAfter;
 
 /** Flag to request a proof as the response */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetIdentityByPublicKeyHashResponse
+/**
+ * Fetches the raw value of a @c GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0's @c function property, even
+ * if the value was not defined by the enum at the time the code was generated.
+ **/
+int32_t GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_Function_RawValue(GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0 *message);
+/**
+ * Sets the raw value of an @c GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0's @c function property, allowing
+ * it to be set to a value that was not defined by the enum at the time the code
+ * was generated.
+ **/
+void SetGetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0_Function_RawValue(GetDocumentsAggregateRequest_GetDocumentsAggregateRequestV0 *message, int32_t value);
 
-typedef GPB_ENUM(GetIdentityByPublicKeyHashResponse_FieldNumber) {
-  GetIdentityByPublicKeyHashResponse_FieldNumber_V0 = 1,
+#pragma mark - GetDocumentsAggregateResponse
+
+typedef GPB_ENUM(GetDocumentsAggregateResponse_FieldNumber) {
+  GetDocumentsAggregateResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetIdentityByPublicKeyHashResponse_Version_OneOfCase) {
-  GetIdentityByPublicKeyHashResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityByPublicKeyHashResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetDocumentsAggregateResponse_Version_OneOfCase) {
+  GetDocumentsAggregateResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetDocumentsAggregateResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetIdentityByPublicKeyHashResponse : GPBMessage
+GPB_FINAL @interface GetDocumentsAggregateResponse : GPBMessage
 
-@property(nonatomic, readonly) GetIdentityByPublicKeyHashResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetDocumentsAggregateResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetIdentityByPublicKeyHashResponse_ClearVersionOneOfCase(GetIdentityByPublicKeyHashResponse *message);
+void GetDocumentsAggregateResponse_ClearVersionOneOfCase(GetDocumentsAggregateResponse *message);
 
-#pragma mark - GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0
+#pragma mark - GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0
 
-typedef GPB_ENUM(GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_FieldNumber) {
-  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_FieldNumber_Identity = 1,
-  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_FieldNumber_Proof = 2,
-  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_FieldNumber) {
+  GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_FieldNumber_Aggregate = 1,
+  GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_FieldNumber_Proof = 2,
+  GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_Result_OneOfCase) {
-  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_Result_OneOfCase_Identity = 1,
-  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_Result_OneOfCase) {
+  GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_Result_OneOfCase_Aggregate = 1,
+  GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0 : GPBMessage
+GPB_FINAL @interface GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** The actual identity data corresponding to the */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *identity;
+/** The computed aggregate */
+@property(nonatomic, readwrite, strong, null_resettable) GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_DocumentsAggregate *aggregate;
 
-/** requested public key hash */
+/** Cryptographic proof of the aggregated documents, if requested */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
 /** Metadata about the blockchain state */
@@ -2454,232 +2704,301 @@ GPB_FINAL @interface GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHa
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_ClearResultOneOfCase(GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0 *message);
+void GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_ClearResultOneOfCase(GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0 *message);
 
-#pragma mark - GetIdentityByNonUniquePublicKeyHashRequest
+#pragma mark - GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_DocumentsAggregate
 
-typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashRequest_FieldNumber) {
-  GetIdentityByNonUniquePublicKeyHashRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_DocumentsAggregate_FieldNumber) {
+  GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_DocumentsAggregate_FieldNumber_Count = 1,
+  GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_DocumentsAggregate_FieldNumber_Sum = 2,
+  GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_DocumentsAggregate_FieldNumber_NextStartAfter = 3,
 };
 
-typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashRequest_Version_OneOfCase) {
-  GetIdentityByNonUniquePublicKeyHashRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityByNonUniquePublicKeyHashRequest_Version_OneOfCase_V0 = 1,
+GPB_FINAL @interface GetDocumentsAggregateResponse_GetDocumentsAggregateResponseV0_DocumentsAggregate : GPBMessage
+
+/** Number of documents covered by this response */
+@property(nonatomic, readwrite) uint64_t count;
+
+/** Sum of the requested property, 0 for COUNT */
+@property(nonatomic, readwrite) int64_t sum;
+
+/** Set when more documents match; pass as `start_after` to continue */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *nextStartAfter;
+
+@end
+
+#pragma mark - SubscribeDocumentsRequest
+
+typedef GPB_ENUM(SubscribeDocumentsRequest_FieldNumber) {
+  SubscribeDocumentsRequest_FieldNumber_V0 = 1,
 };
 
-GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashRequest : GPBMessage
+typedef GPB_ENUM(SubscribeDocumentsRequest_Version_OneOfCase) {
+  SubscribeDocumentsRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  SubscribeDocumentsRequest_Version_OneOfCase_V0 = 1,
+};
 
-@property(nonatomic, readonly) GetIdentityByNonUniquePublicKeyHashRequest_Version_OneOfCase versionOneOfCase;
+GPB_FINAL @interface SubscribeDocumentsRequest : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0 *v0;
+@property(nonatomic, readonly) SubscribeDocumentsRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) SubscribeDocumentsRequest_SubscribeDocumentsRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetIdentityByNonUniquePublicKeyHashRequest_ClearVersionOneOfCase(GetIdentityByNonUniquePublicKeyHashRequest *message);
+void SubscribeDocumentsRequest_ClearVersionOneOfCase(SubscribeDocumentsRequest *message);
 
-#pragma mark - GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0
+#pragma mark - SubscribeDocumentsRequest_SubscribeDocumentsRequestV0
 
-typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0_FieldNumber) {
-  GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0_FieldNumber_PublicKeyHash = 1,
-  GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0_FieldNumber_StartAfter = 2,
-  GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0_FieldNumber_Prove = 3,
+typedef GPB_ENUM(SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_FieldNumber) {
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_FieldNumber_DataContractId = 1,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_FieldNumber_DocumentType = 2,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_FieldNumber_Action = 3,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_FieldNumber_NewDocumentWhere = 4,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_FieldNumber_OriginalDocumentWhere = 5,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_FieldNumber_ValueClause = 6,
+  SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_FieldNumber_Prove = 7,
 };
 
-GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0 : GPBMessage
+GPB_FINAL @interface SubscribeDocumentsRequest_SubscribeDocumentsRequestV0 : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *publicKeyHash;
+/** The ID of the data contract of the documents */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *dataContractId;
 
-/** Give one result after a previous result */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *startAfter;
-/** Test to see if @c startAfter has been set. */
-@property(nonatomic, readwrite) BOOL hasStartAfter;
+/** The document type to watch */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *documentType;
+
+/** The kind of document transitions to watch */
+@property(nonatomic, readwrite) SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_DocumentAction action;
 
+/**
+ * CBOR-encoded where clauses on the new document data, only used by
+ * CREATE and REPLACE
+ **/
+@property(nonatomic, readwrite, copy, null_resettable) NSData *newDocumentWhere NS_RETURNS_NOT_RETAINED;
+
+/**
+ * CBOR-encoded where clauses on the document before the transition, not
+ * used by CREATE
+ **/
+@property(nonatomic, readwrite, copy, null_resettable) NSData *originalDocumentWhere;
+
+/**
+ * CBOR-encoded [operator, value] clause on the recipient (TRANSFER), the
+ * purchaser (PURCHASE) or the new price (UPDATE_PRICE)
+ **/
+@property(nonatomic, readwrite, copy, null_resettable) NSData *valueClause;
+
+/** Flag to request a proof of every matched state transition */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetIdentityByNonUniquePublicKeyHashResponse
+/**
+ * Fetches the raw value of a @c SubscribeDocumentsRequest_SubscribeDocumentsRequestV0's @c action property, even
+ * if the value was not defined by the enum at the time the code was generated.
+ **/
+int32_t SubscribeDocumentsRequest_SubscribeDocumentsRequestV0_Action_RawValue(SubscribeDocumentsRequest_SubscribeDocumentsRequestV0 *message);
+/**
+ * Sets the raw value of an @c SubscribeDocumentsRequest_SubscribeDocumentsRequestV0's @c action property, allowing
+ * it to be set to a value that was not defined by the enum at the time the code
+ * was generated.
+ **/
+void SetSubscribeDocumentsRequest_SubscribeDocumentsRequestV0_Action_RawValue(SubscribeDocumentsRequest_SubscribeDocumentsRequestV0 *message, int32_t value);
 
-typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_FieldNumber) {
-  GetIdentityByNonUniquePublicKeyHashResponse_FieldNumber_V0 = 1,
+#pragma mark - SubscribeDocumentsResponse
+
+typedef GPB_ENUM(SubscribeDocumentsResponse_FieldNumber) {
+  SubscribeDocumentsResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_Version_OneOfCase) {
-  GetIdentityByNonUniquePublicKeyHashResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityByNonUniquePublicKeyHashResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(SubscribeDocumentsResponse_Version_OneOfCase) {
+  SubscribeDocumentsResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  SubscribeDocumentsResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashResponse : GPBMessage
+GPB_FINAL @interface SubscribeDocumentsResponse : GPBMessage
 
-@property(nonatomic, readonly) GetIdentityByNonUniquePublicKeyHashResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) SubscribeDocumentsResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) SubscribeDocumentsResponse_SubscribeDocumentsResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetIdentityByNonUniquePublicKeyHashResponse_ClearVersionOneOfCase(GetIdentityByNonUniquePublicKeyHashResponse *message);
-
-#pragma mark - GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0
+void SubscribeDocumentsResponse_ClearVersionOneOfCase(SubscribeDocumentsResponse *message);
 
-typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_FieldNumber) {
-  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_FieldNumber_Identity = 1,
-  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_FieldNumber_Proof = 2,
-  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_FieldNumber_Metadata = 3,
-};
+#pragma mark - SubscribeDocumentsResponse_SubscribeDocumentsResponseV0
 
-typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_Result_OneOfCase) {
-  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_Result_OneOfCase_Identity = 1,
-  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_FieldNumber) {
+  SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_FieldNumber_BlockHeight = 1,
+  SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_FieldNumber_StateTransitionsArray = 2,
+  SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_FieldNumber_Metadata = 3,
+  SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_FieldNumber_ProofHeight = 4,
+  SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_FieldNumber_Error = 5,
 };
 
-GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0 : GPBMessage
-
-@property(nonatomic, readonly) GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_Result_OneOfCase resultOneOfCase;
+GPB_FINAL @interface SubscribeDocumentsResponse_SubscribeDocumentsResponseV0 : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityResponse *identity;
+/** The height of the block the transitions were */
+@property(nonatomic, readwrite) uint64_t blockHeight;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse *proof;
+/**
+ * executed in
+ * Batch state transitions of the block with matching document transitions
+ **/
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_MatchedStateTransition*> *stateTransitionsArray;
+/** The number of items in @c stateTransitionsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger stateTransitionsArray_Count;
 
 /** Metadata about the blockchain state */
 @property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
 /** Test to see if @c metadata has been set. */
 @property(nonatomic, readwrite) BOOL hasMetadata;
 
-@end
+/**
+ * Height of the committed state the proofs were made against. Proofs are
+ * made when the block is sent, so this is block_height or, when the
+ * subscriber is behind, a later height. A proof made at a later height
+ * fails to verify if a following block changed the proven documents.
+ **/
+@property(nonatomic, readwrite) uint64_t proofHeight;
 
 /**
- * Clears whatever value was set for the oneof 'result'.
+ * Why the block could not be matched against the subscription, no state
+ * transitions are sent for it and the stream stays open
  **/
-void GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_ClearResultOneOfCase(GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0 *message);
+@property(nonatomic, readwrite, copy, null_resettable) NSString *error;
 
-#pragma mark - GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityResponse
+@end
 
-typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityResponse_FieldNumber) {
-  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityResponse_FieldNumber_Identity = 1,
-};
+#pragma mark - SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_MatchedStateTransition
 
-GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityResponse : GPBMessage
+typedef GPB_ENUM(SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_MatchedStateTransition_FieldNumber) {
+  SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_MatchedStateTransition_FieldNumber_StateTransition = 1,
+  SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_MatchedStateTransition_FieldNumber_TransitionIndicesArray = 2,
+  SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_MatchedStateTransition_FieldNumber_Proof = 3,
+  SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_MatchedStateTransition_FieldNumber_ProofError = 4,
+};
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *identity;
-/** Test to see if @c identity has been set. */
-@property(nonatomic, readwrite) BOOL hasIdentity;
+GPB_FINAL @interface SubscribeDocumentsResponse_SubscribeDocumentsResponseV0_MatchedStateTransition : GPBMessage
 
-@end
+/** The serialized batch state transition */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *stateTransition;
 
-#pragma mark - GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse
+/** Indices of the matching document transitions within the batch */
+@property(nonatomic, readwrite, strong, null_resettable) GPBUInt32Array *transitionIndicesArray;
+/** The number of items in @c transitionIndicesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger transitionIndicesArray_Count;
 
-typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse_FieldNumber) {
-  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse_FieldNumber_GrovedbIdentityPublicKeyHashProof = 1,
-  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse_FieldNumber_IdentityProofBytes = 2,
-};
-
-GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse : GPBMessage
-
-@property(nonatomic, readwrite, strong, null_resettable) Proof *grovedbIdentityPublicKeyHashProof;
-/** Test to see if @c grovedbIdentityPublicKeyHashProof has been set. */
-@property(nonatomic, readwrite) BOOL hasGrovedbIdentityPublicKeyHashProof;
+/**
+ * Proof of the state transition execution result, if requested, made
+ * against the committed state at proof_height
+ **/
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+/** Test to see if @c proof has been set. */
+@property(nonatomic, readwrite) BOOL hasProof;
 
-/** A hack, we return 2 proofs */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *identityProofBytes;
-/** Test to see if @c identityProofBytes has been set. */
-@property(nonatomic, readwrite) BOOL hasIdentityProofBytes;
+/**
+ * Why the requested proof could not be made, the state transition is
+ * still sent
+ **/
+@property(nonatomic, readwrite, copy, null_resettable) NSString *proofError;
 
 @end
 
-#pragma mark - WaitForStateTransitionResultRequest
+#pragma mark - GetIdentityByPublicKeyHashRequest
 
-typedef GPB_ENUM(WaitForStateTransitionResultRequest_FieldNumber) {
-  WaitForStateTransitionResultRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetIdentityByPublicKeyHashRequest_FieldNumber) {
+  GetIdentityByPublicKeyHashRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(WaitForStateTransitionResultRequest_Version_OneOfCase) {
-  WaitForStateTransitionResultRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  WaitForStateTransitionResultRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetIdentityByPublicKeyHashRequest_Version_OneOfCase) {
+  GetIdentityByPublicKeyHashRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityByPublicKeyHashRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface WaitForStateTransitionResultRequest : GPBMessage
+GPB_FINAL @interface GetIdentityByPublicKeyHashRequest : GPBMessage
 
-@property(nonatomic, readonly) WaitForStateTransitionResultRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetIdentityByPublicKeyHashRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void WaitForStateTransitionResultRequest_ClearVersionOneOfCase(WaitForStateTransitionResultRequest *message);
+void GetIdentityByPublicKeyHashRequest_ClearVersionOneOfCase(GetIdentityByPublicKeyHashRequest *message);
 
-#pragma mark - WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0
+#pragma mark - GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0
 
-typedef GPB_ENUM(WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0_FieldNumber) {
-  WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0_FieldNumber_StateTransitionHash = 1,
-  WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0_FieldNumber_Prove = 2,
+typedef GPB_ENUM(GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0_FieldNumber) {
+  GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0_FieldNumber_PublicKeyHash = 1,
+  GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0_FieldNumber_Prove = 2,
 };
 
-GPB_FINAL @interface WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0 : GPBMessage
+GPB_FINAL @interface GetIdentityByPublicKeyHashRequest_GetIdentityByPublicKeyHashRequestV0 : GPBMessage
 
-/** The hash of the state transition to wait for */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *stateTransitionHash;
+/** The public key hash of the identity being requested */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *publicKeyHash;
 
 /** Flag to request a proof as the response */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - WaitForStateTransitionResultResponse
+#pragma mark - GetIdentityByPublicKeyHashResponse
 
-typedef GPB_ENUM(WaitForStateTransitionResultResponse_FieldNumber) {
-  WaitForStateTransitionResultResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetIdentityByPublicKeyHashResponse_FieldNumber) {
+  GetIdentityByPublicKeyHashResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(WaitForStateTransitionResultResponse_Version_OneOfCase) {
-  WaitForStateTransitionResultResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  WaitForStateTransitionResultResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetIdentityByPublicKeyHashResponse_Version_OneOfCase) {
+  GetIdentityByPublicKeyHashResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityByPublicKeyHashResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface WaitForStateTransitionResultResponse : GPBMessage
+GPB_FINAL @interface GetIdentityByPublicKeyHashResponse : GPBMessage
 
-@property(nonatomic, readonly) WaitForStateTransitionResultResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetIdentityByPublicKeyHashResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void WaitForStateTransitionResultResponse_ClearVersionOneOfCase(WaitForStateTransitionResultResponse *message);
+void GetIdentityByPublicKeyHashResponse_ClearVersionOneOfCase(GetIdentityByPublicKeyHashResponse *message);
 
-#pragma mark - WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0
+#pragma mark - GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0
 
-typedef GPB_ENUM(WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_FieldNumber) {
-  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_FieldNumber_Error = 1,
-  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_FieldNumber_Proof = 2,
-  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_FieldNumber) {
+  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_FieldNumber_Identity = 1,
+  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_FieldNumber_Proof = 2,
+  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_Result_OneOfCase) {
-  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_Result_OneOfCase_Error = 1,
-  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_Result_OneOfCase) {
+  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_Result_OneOfCase_Identity = 1,
+  GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0 : GPBMessage
+GPB_FINAL @interface GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** Any error that occurred during the state transition broadcast */
-@property(nonatomic, readwrite, strong, null_resettable) StateTransitionBroadcastError *error;
+/** The actual identity data corresponding to the */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identity;
 
-/** Cryptographic proof for the state transition, if requested */
+/** requested public key hash */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
 /** Metadata about the blockchain state */
@@ -2692,219 +3011,267 @@ GPB_FINAL @interface WaitForStateTransitionResultResponse_WaitForStateTransition
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_ClearResultOneOfCase(WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0 *message);
+void GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0_ClearResultOneOfCase(GetIdentityByPublicKeyHashResponse_GetIdentityByPublicKeyHashResponseV0 *message);
 
-#pragma mark - GetConsensusParamsRequest
+#pragma mark - GetIdentityByNonUniquePublicKeyHashRequest
 
-typedef GPB_ENUM(GetConsensusParamsRequest_FieldNumber) {
-  GetConsensusParamsRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashRequest_FieldNumber) {
+  GetIdentityByNonUniquePublicKeyHashRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetConsensusParamsRequest_Version_OneOfCase) {
-  GetConsensusParamsRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetConsensusParamsRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashRequest_Version_OneOfCase) {
+  GetIdentityByNonUniquePublicKeyHashRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityByNonUniquePublicKeyHashRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetConsensusParamsRequest : GPBMessage
+GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashRequest : GPBMessage
 
-@property(nonatomic, readonly) GetConsensusParamsRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetIdentityByNonUniquePublicKeyHashRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetConsensusParamsRequest_GetConsensusParamsRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetConsensusParamsRequest_ClearVersionOneOfCase(GetConsensusParamsRequest *message);
+void GetIdentityByNonUniquePublicKeyHashRequest_ClearVersionOneOfCase(GetIdentityByNonUniquePublicKeyHashRequest *message);
 
-#pragma mark - GetConsensusParamsRequest_GetConsensusParamsRequestV0
+#pragma mark - GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0
 
-typedef GPB_ENUM(GetConsensusParamsRequest_GetConsensusParamsRequestV0_FieldNumber) {
-  GetConsensusParamsRequest_GetConsensusParamsRequestV0_FieldNumber_Height = 1,
-  GetConsensusParamsRequest_GetConsensusParamsRequestV0_FieldNumber_Prove = 2,
+typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0_FieldNumber) {
+  GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0_FieldNumber_PublicKeyHash = 1,
+  GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0_FieldNumber_StartAfter = 2,
+  GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0_FieldNumber_Prove = 3,
 };
 
-GPB_FINAL @interface GetConsensusParamsRequest_GetConsensusParamsRequestV0 : GPBMessage
+GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashRequest_GetIdentityByNonUniquePublicKeyHashRequestV0 : GPBMessage
 
-/** The blockchain height at which to get the consensus parameters */
-@property(nonatomic, readwrite) int32_t height;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *publicKeyHash;
+
+/** Give one result after a previous result */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *startAfter;
+/** Test to see if @c startAfter has been set. */
+@property(nonatomic, readwrite) BOOL hasStartAfter;
 
-/** Flag to request a proof as the response */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetConsensusParamsResponse
+#pragma mark - GetIdentityByNonUniquePublicKeyHashResponse
 
-typedef GPB_ENUM(GetConsensusParamsResponse_FieldNumber) {
-  GetConsensusParamsResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_FieldNumber) {
+  GetIdentityByNonUniquePublicKeyHashResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetConsensusParamsResponse_Version_OneOfCase) {
-  GetConsensusParamsResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetConsensusParamsResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_Version_OneOfCase) {
+  GetIdentityByNonUniquePublicKeyHashResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityByNonUniquePublicKeyHashResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetConsensusParamsResponse : GPBMessage
+GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashResponse : GPBMessage
 
-@property(nonatomic, readonly) GetConsensusParamsResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetIdentityByNonUniquePublicKeyHashResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetConsensusParamsResponse_GetConsensusParamsResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetConsensusParamsResponse_ClearVersionOneOfCase(GetConsensusParamsResponse *message);
+void GetIdentityByNonUniquePublicKeyHashResponse_ClearVersionOneOfCase(GetIdentityByNonUniquePublicKeyHashResponse *message);
 
-#pragma mark - GetConsensusParamsResponse_ConsensusParamsBlock
+#pragma mark - GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0
 
-typedef GPB_ENUM(GetConsensusParamsResponse_ConsensusParamsBlock_FieldNumber) {
-  GetConsensusParamsResponse_ConsensusParamsBlock_FieldNumber_MaxBytes = 1,
-  GetConsensusParamsResponse_ConsensusParamsBlock_FieldNumber_MaxGas = 2,
-  GetConsensusParamsResponse_ConsensusParamsBlock_FieldNumber_TimeIotaMs = 3,
+typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_FieldNumber) {
+  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_FieldNumber_Identity = 1,
+  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_FieldNumber_Proof = 2,
+  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_FieldNumber_Metadata = 3,
 };
 
-GPB_FINAL @interface GetConsensusParamsResponse_ConsensusParamsBlock : GPBMessage
+typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_Result_OneOfCase) {
+  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_Result_OneOfCase_Identity = 1,
+  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_Result_OneOfCase_Proof = 2,
+};
 
-/** The maximum size of a block in bytes */
-@property(nonatomic, readwrite, copy, null_resettable) NSString *maxBytes;
+GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0 : GPBMessage
 
-/** The maximum gas allowed in a block */
-@property(nonatomic, readwrite, copy, null_resettable) NSString *maxGas;
+@property(nonatomic, readonly) GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** The minimum time increment between consecutive */
-@property(nonatomic, readwrite, copy, null_resettable) NSString *timeIotaMs;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityResponse *identity;
 
-@end
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse *proof;
 
-#pragma mark - GetConsensusParamsResponse_ConsensusParamsEvidence
+/** Metadata about the blockchain state */
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
 
-typedef GPB_ENUM(GetConsensusParamsResponse_ConsensusParamsEvidence_FieldNumber) {
-  GetConsensusParamsResponse_ConsensusParamsEvidence_FieldNumber_MaxAgeNumBlocks = 1,
-  GetConsensusParamsResponse_ConsensusParamsEvidence_FieldNumber_MaxAgeDuration = 2,
-  GetConsensusParamsResponse_ConsensusParamsEvidence_FieldNumber_MaxBytes = 3,
-};
+@end
 
-GPB_FINAL @interface GetConsensusParamsResponse_ConsensusParamsEvidence : GPBMessage
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_ClearResultOneOfCase(GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0 *message);
 
-/** The maximum age of evidence, in number of blocks */
-@property(nonatomic, readwrite, copy, null_resettable) NSString *maxAgeNumBlocks;
+#pragma mark - GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityResponse
 
-/** The maximum age of evidence, as a duration */
-@property(nonatomic, readwrite, copy, null_resettable) NSString *maxAgeDuration;
+typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityResponse_FieldNumber) {
+  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityResponse_FieldNumber_Identity = 1,
+};
 
-/** The maximum size of evidence in bytes */
-@property(nonatomic, readwrite, copy, null_resettable) NSString *maxBytes;
+GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityResponse : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identity;
+/** Test to see if @c identity has been set. */
+@property(nonatomic, readwrite) BOOL hasIdentity;
 
 @end
 
-#pragma mark - GetConsensusParamsResponse_GetConsensusParamsResponseV0
+#pragma mark - GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse
 
-typedef GPB_ENUM(GetConsensusParamsResponse_GetConsensusParamsResponseV0_FieldNumber) {
-  GetConsensusParamsResponse_GetConsensusParamsResponseV0_FieldNumber_Block = 1,
-  GetConsensusParamsResponse_GetConsensusParamsResponseV0_FieldNumber_Evidence = 2,
+typedef GPB_ENUM(GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse_FieldNumber) {
+  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse_FieldNumber_GrovedbIdentityPublicKeyHashProof = 1,
+  GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse_FieldNumber_IdentityProofBytes = 2,
 };
 
-GPB_FINAL @interface GetConsensusParamsResponse_GetConsensusParamsResponseV0 : GPBMessage
+GPB_FINAL @interface GetIdentityByNonUniquePublicKeyHashResponse_GetIdentityByNonUniquePublicKeyHashResponseV0_IdentityProvedResponse : GPBMessage
 
-/** Consensus parameters related to block creation and validation */
-@property(nonatomic, readwrite, strong, null_resettable) GetConsensusParamsResponse_ConsensusParamsBlock *block;
-/** Test to see if @c block has been set. */
-@property(nonatomic, readwrite) BOOL hasBlock;
+@property(nonatomic, readwrite, strong, null_resettable) Proof *grovedbIdentityPublicKeyHashProof;
+/** Test to see if @c grovedbIdentityPublicKeyHashProof has been set. */
+@property(nonatomic, readwrite) BOOL hasGrovedbIdentityPublicKeyHashProof;
 
-/** Consensus parameters related to evidence */
-@property(nonatomic, readwrite, strong, null_resettable) GetConsensusParamsResponse_ConsensusParamsEvidence *evidence;
-/** Test to see if @c evidence has been set. */
-@property(nonatomic, readwrite) BOOL hasEvidence;
+/** A hack, we return 2 proofs */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityProofBytes;
+/** Test to see if @c identityProofBytes has been set. */
+@property(nonatomic, readwrite) BOOL hasIdentityProofBytes;
 
 @end
 
-#pragma mark - GetProtocolVersionUpgradeStateRequest
+#pragma mark - GetIdentityStateTransitionsRequest
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeStateRequest_FieldNumber) {
-  GetProtocolVersionUpgradeStateRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetIdentityStateTransitionsRequest_FieldNumber) {
+  GetIdentityStateTransitionsRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeStateRequest_Version_OneOfCase) {
-  GetProtocolVersionUpgradeStateRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetProtocolVersionUpgradeStateRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetIdentityStateTransitionsRequest_Version_OneOfCase) {
+  GetIdentityStateTransitionsRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityStateTransitionsRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetProtocolVersionUpgradeStateRequest : GPBMessage
+/**
+ * State transitions owned by an identity that were executed in a block, kept
+ * from protocol version 11
+ **/
+GPB_FINAL @interface GetIdentityStateTransitionsRequest : GPBMessage
 
-@property(nonatomic, readonly) GetProtocolVersionUpgradeStateRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetIdentityStateTransitionsRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeStateRequest_GetProtocolVersionUpgradeStateRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetProtocolVersionUpgradeStateRequest_ClearVersionOneOfCase(GetProtocolVersionUpgradeStateRequest *message);
+void GetIdentityStateTransitionsRequest_ClearVersionOneOfCase(GetIdentityStateTransitionsRequest *message);
 
-#pragma mark - GetProtocolVersionUpgradeStateRequest_GetProtocolVersionUpgradeStateRequestV0
+#pragma mark - GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeStateRequest_GetProtocolVersionUpgradeStateRequestV0_FieldNumber) {
-  GetProtocolVersionUpgradeStateRequest_GetProtocolVersionUpgradeStateRequestV0_FieldNumber_Prove = 1,
+typedef GPB_ENUM(GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_FieldNumber) {
+  GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_FieldNumber_IdentityId = 1,
+  GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_FieldNumber_StartAfter = 2,
+  GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_FieldNumber_Count = 3,
+  GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_FieldNumber_OrderAscending = 4,
+  GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_FieldNumber_Prove = 5,
 };
 
-GPB_FINAL @interface GetProtocolVersionUpgradeStateRequest_GetProtocolVersionUpgradeStateRequestV0 : GPBMessage
+GPB_FINAL @interface GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0 : GPBMessage
+
+/** The identity owning the state transitions */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
+
+/** Only return state transitions after this one, in the requested order */
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_StartAfter *startAfter;
+/** Test to see if @c startAfter has been set. */
+@property(nonatomic, readwrite) BOOL hasStartAfter;
+
+/** The maximum number of state transitions to return */
+@property(nonatomic, readwrite) uint32_t count;
+
+@property(nonatomic, readwrite) BOOL hasCount;
+/** Whether the oldest state transitions come first */
+@property(nonatomic, readwrite) BOOL orderAscending;
 
 /** Flag to request a proof as the response */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetProtocolVersionUpgradeStateResponse
+#pragma mark - GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_StartAfter
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_FieldNumber) {
-  GetProtocolVersionUpgradeStateResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_StartAfter_FieldNumber) {
+  GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_StartAfter_FieldNumber_BlockHeight = 1,
+  GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_StartAfter_FieldNumber_IndexInBlock = 2,
 };
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_Version_OneOfCase) {
-  GetProtocolVersionUpgradeStateResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetProtocolVersionUpgradeStateResponse_Version_OneOfCase_V0 = 1,
+/**
+ * Position of a state transition within the history of an identity
+ **/
+GPB_FINAL @interface GetIdentityStateTransitionsRequest_GetIdentityStateTransitionsRequestV0_StartAfter : GPBMessage
+
+@property(nonatomic, readwrite) uint64_t blockHeight;
+
+@property(nonatomic, readwrite) uint32_t indexInBlock;
+
+@end
+
+#pragma mark - GetIdentityStateTransitionsResponse
+
+typedef GPB_ENUM(GetIdentityStateTransitionsResponse_FieldNumber) {
+  GetIdentityStateTransitionsResponse_FieldNumber_V0 = 1,
 };
 
-GPB_FINAL @interface GetProtocolVersionUpgradeStateResponse : GPBMessage
+typedef GPB_ENUM(GetIdentityStateTransitionsResponse_Version_OneOfCase) {
+  GetIdentityStateTransitionsResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityStateTransitionsResponse_Version_OneOfCase_V0 = 1,
+};
 
-@property(nonatomic, readonly) GetProtocolVersionUpgradeStateResponse_Version_OneOfCase versionOneOfCase;
+GPB_FINAL @interface GetIdentityStateTransitionsResponse : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0 *v0;
+@property(nonatomic, readonly) GetIdentityStateTransitionsResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetProtocolVersionUpgradeStateResponse_ClearVersionOneOfCase(GetProtocolVersionUpgradeStateResponse *message);
+void GetIdentityStateTransitionsResponse_ClearVersionOneOfCase(GetIdentityStateTransitionsResponse *message);
 
-#pragma mark - GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0
+#pragma mark - GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_FieldNumber) {
-  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_FieldNumber_Versions = 1,
-  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_FieldNumber_Proof = 2,
-  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_FieldNumber) {
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_FieldNumber_StateTransitions = 1,
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_FieldNumber_Proof = 2,
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Result_OneOfCase) {
-  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Result_OneOfCase_Versions = 1,
-  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_Result_OneOfCase) {
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_Result_OneOfCase_StateTransitions = 1,
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0 : GPBMessage
+GPB_FINAL @interface GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** The actual protocol version information */
-@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Versions *versions;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitions *stateTransitions;
 
-/** Cryptographic proof of the protocol version */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
 /** Metadata about the blockchain state */
@@ -2917,137 +3284,142 @@ GPB_FINAL @interface GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUp
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_ClearResultOneOfCase(GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0 *message);
+void GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_ClearResultOneOfCase(GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0 *message);
 
-#pragma mark - GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Versions
+#pragma mark - GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Versions_FieldNumber) {
-  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Versions_FieldNumber_VersionsArray = 1,
+typedef GPB_ENUM(GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry_FieldNumber) {
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry_FieldNumber_StateTransitionHash = 1,
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry_FieldNumber_StateTransitionType = 2,
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry_FieldNumber_BlockHeight = 3,
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry_FieldNumber_BlockTimeMs = 4,
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry_FieldNumber_IndexInBlock = 5,
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry_FieldNumber_FeePaid = 6,
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry_FieldNumber_ErrorCode = 7,
 };
 
-/**
- * Versions holds a collection of version entries
- **/
-GPB_FINAL @interface GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Versions : GPBMessage
+GPB_FINAL @interface GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry : GPBMessage
 
-/** List of protocol version entries */
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry*> *versionsArray;
-/** The number of items in @c versionsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger versionsArray_Count;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *stateTransitionHash;
 
+@property(nonatomic, readwrite) uint32_t stateTransitionType;
+
+@property(nonatomic, readwrite) uint64_t blockHeight;
+
+@property(nonatomic, readwrite) uint64_t blockTimeMs;
+
+/** Position of the state transition in the block */
+@property(nonatomic, readwrite) uint32_t indexInBlock;
+
+@property(nonatomic, readwrite) uint64_t feePaid;
+
+/** Code of the consensus error, unset if the execution succeeded */
+@property(nonatomic, readwrite) uint32_t errorCode;
+
+@property(nonatomic, readwrite) BOOL hasErrorCode;
 @end
 
-#pragma mark - GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry
+#pragma mark - GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitions
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry_FieldNumber) {
-  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry_FieldNumber_VersionNumber = 1,
-  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry_FieldNumber_VoteCount = 2,
+typedef GPB_ENUM(GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitions_FieldNumber) {
+  GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitions_FieldNumber_StateTransitionsArray = 1,
 };
 
-/**
- * VersionEntry represents a single entry of a protocol version
- **/
-GPB_FINAL @interface GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry : GPBMessage
-
-/** The protocol version number */
-@property(nonatomic, readwrite) uint32_t versionNumber;
+GPB_FINAL @interface GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitions : GPBMessage
 
-/** The vote count for this protocol version */
-@property(nonatomic, readwrite) uint32_t voteCount;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetIdentityStateTransitionsResponse_GetIdentityStateTransitionsResponseV0_IdentityStateTransitionEntry*> *stateTransitionsArray;
+/** The number of items in @c stateTransitionsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger stateTransitionsArray_Count;
 
 @end
 
-#pragma mark - GetProtocolVersionUpgradeVoteStatusRequest
+#pragma mark - WaitForStateTransitionResultRequest
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusRequest_FieldNumber) {
-  GetProtocolVersionUpgradeVoteStatusRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(WaitForStateTransitionResultRequest_FieldNumber) {
+  WaitForStateTransitionResultRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusRequest_Version_OneOfCase) {
-  GetProtocolVersionUpgradeVoteStatusRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetProtocolVersionUpgradeVoteStatusRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(WaitForStateTransitionResultRequest_Version_OneOfCase) {
+  WaitForStateTransitionResultRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  WaitForStateTransitionResultRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusRequest : GPBMessage
+GPB_FINAL @interface WaitForStateTransitionResultRequest : GPBMessage
 
-@property(nonatomic, readonly) GetProtocolVersionUpgradeVoteStatusRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) WaitForStateTransitionResultRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetProtocolVersionUpgradeVoteStatusRequest_ClearVersionOneOfCase(GetProtocolVersionUpgradeVoteStatusRequest *message);
+void WaitForStateTransitionResultRequest_ClearVersionOneOfCase(WaitForStateTransitionResultRequest *message);
 
-#pragma mark - GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0
+#pragma mark - WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0_FieldNumber) {
-  GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0_FieldNumber_StartProTxHash = 1,
-  GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0_FieldNumber_Count = 2,
-  GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0_FieldNumber_Prove = 3,
+typedef GPB_ENUM(WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0_FieldNumber) {
+  WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0_FieldNumber_StateTransitionHash = 1,
+  WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0_FieldNumber_Prove = 2,
 };
 
-GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0 : GPBMessage
-
-/** The starting masternode provider transaction */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *startProTxHash;
+GPB_FINAL @interface WaitForStateTransitionResultRequest_WaitForStateTransitionResultRequestV0 : GPBMessage
 
-/** hash to filter the votes by */
-@property(nonatomic, readwrite) uint32_t count;
+/** The hash of the state transition to wait for */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *stateTransitionHash;
 
 /** Flag to request a proof as the response */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetProtocolVersionUpgradeVoteStatusResponse
+#pragma mark - WaitForStateTransitionResultResponse
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_FieldNumber) {
-  GetProtocolVersionUpgradeVoteStatusResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(WaitForStateTransitionResultResponse_FieldNumber) {
+  WaitForStateTransitionResultResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_Version_OneOfCase) {
-  GetProtocolVersionUpgradeVoteStatusResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetProtocolVersionUpgradeVoteStatusResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(WaitForStateTransitionResultResponse_Version_OneOfCase) {
+  WaitForStateTransitionResultResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  WaitForStateTransitionResultResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusResponse : GPBMessage
+GPB_FINAL @interface WaitForStateTransitionResultResponse : GPBMessage
 
-@property(nonatomic, readonly) GetProtocolVersionUpgradeVoteStatusResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) WaitForStateTransitionResultResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetProtocolVersionUpgradeVoteStatusResponse_ClearVersionOneOfCase(GetProtocolVersionUpgradeVoteStatusResponse *message);
+void WaitForStateTransitionResultResponse_ClearVersionOneOfCase(WaitForStateTransitionResultResponse *message);
 
-#pragma mark - GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0
+#pragma mark - WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_FieldNumber) {
-  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_FieldNumber_Versions = 1,
-  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_FieldNumber_Proof = 2,
-  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_FieldNumber) {
+  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_FieldNumber_Error = 1,
+  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_FieldNumber_Proof = 2,
+  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_Result_OneOfCase) {
-  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_Result_OneOfCase_Versions = 1,
-  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_Result_OneOfCase) {
+  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_Result_OneOfCase_Error = 1,
+  WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0 : GPBMessage
+GPB_FINAL @interface WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** The actual version signal information */
-@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignals *versions;
+/** Any error that occurred during the state transition broadcast */
+@property(nonatomic, readwrite, strong, null_resettable) StateTransitionBroadcastError *error;
 
-/** Cryptographic proof of the version signal information, */
+/** Cryptographic proof for the state transition, if requested */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
 /** Metadata about the blockchain state */
@@ -3060,309 +3432,347 @@ GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVers
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_ClearResultOneOfCase(GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0 *message);
+void WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0_ClearResultOneOfCase(WaitForStateTransitionResultResponse_WaitForStateTransitionResultResponseV0 *message);
 
-#pragma mark - GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignals
+#pragma mark - SimulateStateTransitionRequest
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignals_FieldNumber) {
-  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignals_FieldNumber_VersionSignalsArray = 1,
+typedef GPB_ENUM(SimulateStateTransitionRequest_FieldNumber) {
+  SimulateStateTransitionRequest_FieldNumber_V0 = 1,
 };
 
-/**
- * VersionSignals holds a collection of version signal entries
- **/
-GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignals : GPBMessage
+typedef GPB_ENUM(SimulateStateTransitionRequest_Version_OneOfCase) {
+  SimulateStateTransitionRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  SimulateStateTransitionRequest_Version_OneOfCase_V0 = 1,
+};
 
-/** List of version signal entries */
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal*> *versionSignalsArray;
-/** The number of items in @c versionSignalsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger versionSignalsArray_Count;
+GPB_FINAL @interface SimulateStateTransitionRequest : GPBMessage
 
-@end
+@property(nonatomic, readonly) SimulateStateTransitionRequest_Version_OneOfCase versionOneOfCase;
 
-#pragma mark - GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal
+@property(nonatomic, readwrite, strong, null_resettable) SimulateStateTransitionRequest_SimulateStateTransitionRequestV0 *v0;
 
-typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal_FieldNumber) {
-  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal_FieldNumber_ProTxHash = 1,
-  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal_FieldNumber_Version = 2,
-};
+@end
 
 /**
- * VersionSignal represents a single voting signal for a protocol version
+ * Clears whatever value was set for the oneof 'version'.
  **/
-GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal : GPBMessage
+void SimulateStateTransitionRequest_ClearVersionOneOfCase(SimulateStateTransitionRequest *message);
 
-/** The masternode provider transaction hash */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *proTxHash;
+#pragma mark - SimulateStateTransitionRequest_SimulateStateTransitionRequestV0
 
-/** associated with the vote */
-@property(nonatomic, readwrite) uint32_t version;
+typedef GPB_ENUM(SimulateStateTransitionRequest_SimulateStateTransitionRequestV0_FieldNumber) {
+  SimulateStateTransitionRequest_SimulateStateTransitionRequestV0_FieldNumber_StateTransition = 1,
+};
+
+GPB_FINAL @interface SimulateStateTransitionRequest_SimulateStateTransitionRequestV0 : GPBMessage
+
+/** The serialized state transition to simulate */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *stateTransition;
 
 @end
 
-#pragma mark - GetEpochsInfoRequest
+#pragma mark - SimulateStateTransitionResponse
 
-typedef GPB_ENUM(GetEpochsInfoRequest_FieldNumber) {
-  GetEpochsInfoRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(SimulateStateTransitionResponse_FieldNumber) {
+  SimulateStateTransitionResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetEpochsInfoRequest_Version_OneOfCase) {
-  GetEpochsInfoRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetEpochsInfoRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(SimulateStateTransitionResponse_Version_OneOfCase) {
+  SimulateStateTransitionResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  SimulateStateTransitionResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetEpochsInfoRequest : GPBMessage
+GPB_FINAL @interface SimulateStateTransitionResponse : GPBMessage
 
-@property(nonatomic, readonly) GetEpochsInfoRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) SimulateStateTransitionResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetEpochsInfoRequest_GetEpochsInfoRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) SimulateStateTransitionResponse_SimulateStateTransitionResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetEpochsInfoRequest_ClearVersionOneOfCase(GetEpochsInfoRequest *message);
+void SimulateStateTransitionResponse_ClearVersionOneOfCase(SimulateStateTransitionResponse *message);
 
-#pragma mark - GetEpochsInfoRequest_GetEpochsInfoRequestV0
+#pragma mark - SimulateStateTransitionResponse_SimulateStateTransitionResponseV0
 
-typedef GPB_ENUM(GetEpochsInfoRequest_GetEpochsInfoRequestV0_FieldNumber) {
-  GetEpochsInfoRequest_GetEpochsInfoRequestV0_FieldNumber_StartEpoch = 1,
-  GetEpochsInfoRequest_GetEpochsInfoRequestV0_FieldNumber_Count = 2,
-  GetEpochsInfoRequest_GetEpochsInfoRequestV0_FieldNumber_Ascending = 3,
-  GetEpochsInfoRequest_GetEpochsInfoRequestV0_FieldNumber_Prove = 4,
+typedef GPB_ENUM(SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FieldNumber) {
+  SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FieldNumber_FeeResult = 1,
+  SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FieldNumber_Error = 2,
+  SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FieldNumber_Metadata = 3,
 };
 
-GPB_FINAL @interface GetEpochsInfoRequest_GetEpochsInfoRequestV0 : GPBMessage
-
-/** The starting epoch for the request */
-@property(nonatomic, readwrite, strong, null_resettable) GPBUInt32Value *startEpoch;
-/** Test to see if @c startEpoch has been set. */
-@property(nonatomic, readwrite) BOOL hasStartEpoch;
+GPB_FINAL @interface SimulateStateTransitionResponse_SimulateStateTransitionResponseV0 : GPBMessage
 
-/** The number of epochs to retrieve information for */
-@property(nonatomic, readwrite) uint32_t count;
+/** The estimated fees, unset if the state transition would not be paid for */
+@property(nonatomic, readwrite, strong, null_resettable) SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeResult *feeResult;
+/** Test to see if @c feeResult has been set. */
+@property(nonatomic, readwrite) BOOL hasFeeResult;
 
-/** Flag indicating if the epochs should be listed in ascending order */
-@property(nonatomic, readwrite) BOOL ascending;
+/** The consensus error the state transition would fail with */
+@property(nonatomic, readwrite, strong, null_resettable) StateTransitionBroadcastError *error;
+/** Test to see if @c error has been set. */
+@property(nonatomic, readwrite) BOOL hasError;
 
-/** Flag to request a proof as the response */
-@property(nonatomic, readwrite) BOOL prove;
+/** Metadata about the blockchain state */
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
 
 @end
 
-#pragma mark - GetEpochsInfoResponse
-
-typedef GPB_ENUM(GetEpochsInfoResponse_FieldNumber) {
-  GetEpochsInfoResponse_FieldNumber_V0 = 1,
-};
+#pragma mark - SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeRefund
 
-typedef GPB_ENUM(GetEpochsInfoResponse_Version_OneOfCase) {
-  GetEpochsInfoResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetEpochsInfoResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeRefund_FieldNumber) {
+  SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeRefund_FieldNumber_IdentityId = 1,
+  SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeRefund_FieldNumber_Credits = 2,
 };
 
-GPB_FINAL @interface GetEpochsInfoResponse : GPBMessage
+GPB_FINAL @interface SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeRefund : GPBMessage
 
-@property(nonatomic, readonly) GetEpochsInfoResponse_Version_OneOfCase versionOneOfCase;
+/** The identity receiving the refund */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetEpochsInfoResponse_GetEpochsInfoResponseV0 *v0;
+/** The refund summed over all epochs */
+@property(nonatomic, readwrite) uint64_t credits;
 
 @end
 
-/**
- * Clears whatever value was set for the oneof 'version'.
- **/
-void GetEpochsInfoResponse_ClearVersionOneOfCase(GetEpochsInfoResponse *message);
+#pragma mark - SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeResult
 
-#pragma mark - GetEpochsInfoResponse_GetEpochsInfoResponseV0
+typedef GPB_ENUM(SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeResult_FieldNumber) {
+  SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeResult_FieldNumber_StorageFee = 1,
+  SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeResult_FieldNumber_ProcessingFee = 2,
+  SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeResult_FieldNumber_FeeRefundsArray = 3,
+  SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeResult_FieldNumber_RemovedBytesFromSystem = 4,
+};
 
-typedef GPB_ENUM(GetEpochsInfoResponse_GetEpochsInfoResponseV0_FieldNumber) {
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_FieldNumber_Epochs = 1,
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_FieldNumber_Proof = 2,
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_FieldNumber_Metadata = 3,
-};
+GPB_FINAL @interface SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeResult : GPBMessage
 
-typedef GPB_ENUM(GetEpochsInfoResponse_GetEpochsInfoResponseV0_Result_OneOfCase) {
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_Result_OneOfCase_Epochs = 1,
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_Result_OneOfCase_Proof = 2,
-};
+@property(nonatomic, readwrite) uint64_t storageFee;
 
-GPB_FINAL @interface GetEpochsInfoResponse_GetEpochsInfoResponseV0 : GPBMessage
+@property(nonatomic, readwrite) uint64_t processingFee;
 
-@property(nonatomic, readonly) GetEpochsInfoResponse_GetEpochsInfoResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<SimulateStateTransitionResponse_SimulateStateTransitionResponseV0_FeeRefund*> *feeRefundsArray;
+/** The number of items in @c feeRefundsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger feeRefundsArray_Count;
 
-/** The actual information about the requested epochs */
-@property(nonatomic, readwrite, strong, null_resettable) GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfos *epochs;
+@property(nonatomic, readwrite) uint32_t removedBytesFromSystem;
 
-/** Cryptographic proof of the epoch information, if requested */
-@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+@end
 
-/** Metadata about the blockchain state */
-@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
-/** Test to see if @c metadata has been set. */
-@property(nonatomic, readwrite) BOOL hasMetadata;
+#pragma mark - GetConsensusParamsRequest
+
+typedef GPB_ENUM(GetConsensusParamsRequest_FieldNumber) {
+  GetConsensusParamsRequest_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetConsensusParamsRequest_Version_OneOfCase) {
+  GetConsensusParamsRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetConsensusParamsRequest_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetConsensusParamsRequest : GPBMessage
+
+@property(nonatomic, readonly) GetConsensusParamsRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetConsensusParamsRequest_GetConsensusParamsRequestV0 *v0;
 
 @end
 
 /**
- * Clears whatever value was set for the oneof 'result'.
+ * Clears whatever value was set for the oneof 'version'.
  **/
-void GetEpochsInfoResponse_GetEpochsInfoResponseV0_ClearResultOneOfCase(GetEpochsInfoResponse_GetEpochsInfoResponseV0 *message);
+void GetConsensusParamsRequest_ClearVersionOneOfCase(GetConsensusParamsRequest *message);
 
-#pragma mark - GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfos
+#pragma mark - GetConsensusParamsRequest_GetConsensusParamsRequestV0
 
-typedef GPB_ENUM(GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfos_FieldNumber) {
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfos_FieldNumber_EpochInfosArray = 1,
+typedef GPB_ENUM(GetConsensusParamsRequest_GetConsensusParamsRequestV0_FieldNumber) {
+  GetConsensusParamsRequest_GetConsensusParamsRequestV0_FieldNumber_Height = 1,
+  GetConsensusParamsRequest_GetConsensusParamsRequestV0_FieldNumber_Prove = 2,
 };
 
-/**
- * EpochInfos holds a collection of epoch information entries
- **/
-GPB_FINAL @interface GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfos : GPBMessage
+GPB_FINAL @interface GetConsensusParamsRequest_GetConsensusParamsRequestV0 : GPBMessage
 
-/** List of information for each requested epoch */
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo*> *epochInfosArray;
-/** The number of items in @c epochInfosArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger epochInfosArray_Count;
+/** The blockchain height at which to get the consensus parameters */
+@property(nonatomic, readwrite) int32_t height;
+
+/** Flag to request a proof as the response */
+@property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo
+#pragma mark - GetConsensusParamsResponse
 
-typedef GPB_ENUM(GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber) {
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_Number = 1,
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_FirstBlockHeight = 2,
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_FirstCoreBlockHeight = 3,
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_StartTime = 4,
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_FeeMultiplier = 5,
-  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_ProtocolVersion = 6,
+typedef GPB_ENUM(GetConsensusParamsResponse_FieldNumber) {
+  GetConsensusParamsResponse_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetConsensusParamsResponse_Version_OneOfCase) {
+  GetConsensusParamsResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetConsensusParamsResponse_Version_OneOfCase_V0 = 1,
 };
 
+GPB_FINAL @interface GetConsensusParamsResponse : GPBMessage
+
+@property(nonatomic, readonly) GetConsensusParamsResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetConsensusParamsResponse_GetConsensusParamsResponseV0 *v0;
+
+@end
+
 /**
- * EpochInfo represents information about a single epoch
+ * Clears whatever value was set for the oneof 'version'.
  **/
-GPB_FINAL @interface GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo : GPBMessage
+void GetConsensusParamsResponse_ClearVersionOneOfCase(GetConsensusParamsResponse *message);
 
-/** The number of the epoch */
-@property(nonatomic, readwrite) uint32_t number;
+#pragma mark - GetConsensusParamsResponse_ConsensusParamsBlock
 
-/** The height of the first block in this epoch */
-@property(nonatomic, readwrite) uint64_t firstBlockHeight;
+typedef GPB_ENUM(GetConsensusParamsResponse_ConsensusParamsBlock_FieldNumber) {
+  GetConsensusParamsResponse_ConsensusParamsBlock_FieldNumber_MaxBytes = 1,
+  GetConsensusParamsResponse_ConsensusParamsBlock_FieldNumber_MaxGas = 2,
+  GetConsensusParamsResponse_ConsensusParamsBlock_FieldNumber_TimeIotaMs = 3,
+};
 
-/** The height of the first Core block in this epoch */
-@property(nonatomic, readwrite) uint32_t firstCoreBlockHeight;
+GPB_FINAL @interface GetConsensusParamsResponse_ConsensusParamsBlock : GPBMessage
 
-/** The start time of the epoch */
-@property(nonatomic, readwrite) uint64_t startTime;
+/** The maximum size of a block in bytes */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *maxBytes;
 
-/** The fee multiplier applicable in this epoch */
-@property(nonatomic, readwrite) double feeMultiplier;
+/** The maximum gas allowed in a block */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *maxGas;
 
-@property(nonatomic, readwrite) uint32_t protocolVersion;
+/** The minimum time increment between consecutive */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *timeIotaMs;
 
 @end
 
-#pragma mark - GetFinalizedEpochInfosRequest
+#pragma mark - GetConsensusParamsResponse_ConsensusParamsEvidence
 
-typedef GPB_ENUM(GetFinalizedEpochInfosRequest_FieldNumber) {
-  GetFinalizedEpochInfosRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetConsensusParamsResponse_ConsensusParamsEvidence_FieldNumber) {
+  GetConsensusParamsResponse_ConsensusParamsEvidence_FieldNumber_MaxAgeNumBlocks = 1,
+  GetConsensusParamsResponse_ConsensusParamsEvidence_FieldNumber_MaxAgeDuration = 2,
+  GetConsensusParamsResponse_ConsensusParamsEvidence_FieldNumber_MaxBytes = 3,
 };
 
-typedef GPB_ENUM(GetFinalizedEpochInfosRequest_Version_OneOfCase) {
-  GetFinalizedEpochInfosRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetFinalizedEpochInfosRequest_Version_OneOfCase_V0 = 1,
+GPB_FINAL @interface GetConsensusParamsResponse_ConsensusParamsEvidence : GPBMessage
+
+/** The maximum age of evidence, in number of blocks */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *maxAgeNumBlocks;
+
+/** The maximum age of evidence, as a duration */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *maxAgeDuration;
+
+/** The maximum size of evidence in bytes */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *maxBytes;
+
+@end
+
+#pragma mark - GetConsensusParamsResponse_GetConsensusParamsResponseV0
+
+typedef GPB_ENUM(GetConsensusParamsResponse_GetConsensusParamsResponseV0_FieldNumber) {
+  GetConsensusParamsResponse_GetConsensusParamsResponseV0_FieldNumber_Block = 1,
+  GetConsensusParamsResponse_GetConsensusParamsResponseV0_FieldNumber_Evidence = 2,
 };
 
-GPB_FINAL @interface GetFinalizedEpochInfosRequest : GPBMessage
+GPB_FINAL @interface GetConsensusParamsResponse_GetConsensusParamsResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetFinalizedEpochInfosRequest_Version_OneOfCase versionOneOfCase;
+/** Consensus parameters related to block creation and validation */
+@property(nonatomic, readwrite, strong, null_resettable) GetConsensusParamsResponse_ConsensusParamsBlock *block;
+/** Test to see if @c block has been set. */
+@property(nonatomic, readwrite) BOOL hasBlock;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0 *v0;
+/** Consensus parameters related to evidence */
+@property(nonatomic, readwrite, strong, null_resettable) GetConsensusParamsResponse_ConsensusParamsEvidence *evidence;
+/** Test to see if @c evidence has been set. */
+@property(nonatomic, readwrite) BOOL hasEvidence;
 
 @end
 
-/**
- * Clears whatever value was set for the oneof 'version'.
- **/
-void GetFinalizedEpochInfosRequest_ClearVersionOneOfCase(GetFinalizedEpochInfosRequest *message);
+#pragma mark - GetProtocolVersionUpgradeStateRequest
 
-#pragma mark - GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0
+typedef GPB_ENUM(GetProtocolVersionUpgradeStateRequest_FieldNumber) {
+  GetProtocolVersionUpgradeStateRequest_FieldNumber_V0 = 1,
+};
 
-typedef GPB_ENUM(GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber) {
-  GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber_StartEpochIndex = 1,
-  GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber_StartEpochIndexIncluded = 2,
-  GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber_EndEpochIndex = 3,
-  GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber_EndEpochIndexIncluded = 4,
-  GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber_Prove = 5,
+typedef GPB_ENUM(GetProtocolVersionUpgradeStateRequest_Version_OneOfCase) {
+  GetProtocolVersionUpgradeStateRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetProtocolVersionUpgradeStateRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0 : GPBMessage
+GPB_FINAL @interface GetProtocolVersionUpgradeStateRequest : GPBMessage
 
-/** The starting epoch index */
-@property(nonatomic, readwrite) uint32_t startEpochIndex;
+@property(nonatomic, readonly) GetProtocolVersionUpgradeStateRequest_Version_OneOfCase versionOneOfCase;
 
-/** Whether to include the start epoch */
-@property(nonatomic, readwrite) BOOL startEpochIndexIncluded;
+@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeStateRequest_GetProtocolVersionUpgradeStateRequestV0 *v0;
 
-/** The ending epoch index */
-@property(nonatomic, readwrite) uint32_t endEpochIndex;
+@end
 
-/** Whether to include the end epoch */
-@property(nonatomic, readwrite) BOOL endEpochIndexIncluded;
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetProtocolVersionUpgradeStateRequest_ClearVersionOneOfCase(GetProtocolVersionUpgradeStateRequest *message);
+
+#pragma mark - GetProtocolVersionUpgradeStateRequest_GetProtocolVersionUpgradeStateRequestV0
+
+typedef GPB_ENUM(GetProtocolVersionUpgradeStateRequest_GetProtocolVersionUpgradeStateRequestV0_FieldNumber) {
+  GetProtocolVersionUpgradeStateRequest_GetProtocolVersionUpgradeStateRequestV0_FieldNumber_Prove = 1,
+};
+
+GPB_FINAL @interface GetProtocolVersionUpgradeStateRequest_GetProtocolVersionUpgradeStateRequestV0 : GPBMessage
 
 /** Flag to request a proof as the response */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetFinalizedEpochInfosResponse
+#pragma mark - GetProtocolVersionUpgradeStateResponse
 
-typedef GPB_ENUM(GetFinalizedEpochInfosResponse_FieldNumber) {
-  GetFinalizedEpochInfosResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_FieldNumber) {
+  GetProtocolVersionUpgradeStateResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetFinalizedEpochInfosResponse_Version_OneOfCase) {
-  GetFinalizedEpochInfosResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetFinalizedEpochInfosResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_Version_OneOfCase) {
+  GetProtocolVersionUpgradeStateResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetProtocolVersionUpgradeStateResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetFinalizedEpochInfosResponse : GPBMessage
+GPB_FINAL @interface GetProtocolVersionUpgradeStateResponse : GPBMessage
 
-@property(nonatomic, readonly) GetFinalizedEpochInfosResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetProtocolVersionUpgradeStateResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetFinalizedEpochInfosResponse_ClearVersionOneOfCase(GetFinalizedEpochInfosResponse *message);
+void GetProtocolVersionUpgradeStateResponse_ClearVersionOneOfCase(GetProtocolVersionUpgradeStateResponse *message);
 
-#pragma mark - GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0
+#pragma mark - GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0
 
-typedef GPB_ENUM(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FieldNumber) {
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FieldNumber_Epochs = 1,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FieldNumber_Proof = 2,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_FieldNumber) {
+  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_FieldNumber_Versions = 1,
+  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_FieldNumber_Proof = 2,
+  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_Result_OneOfCase) {
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_Result_OneOfCase_Epochs = 1,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Result_OneOfCase) {
+  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Result_OneOfCase_Versions = 1,
+  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0 : GPBMessage
+GPB_FINAL @interface GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** The actual finalized information about the requested epochs */
-@property(nonatomic, readwrite, strong, null_resettable) GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfos *epochs;
+/** The actual protocol version information */
+@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Versions *versions;
 
-/** Cryptographic proof of the finalized epoch */
+/** Cryptographic proof of the protocol version */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
 /** Metadata about the blockchain state */
@@ -3375,241 +3785,140 @@ GPB_FINAL @interface GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosRespon
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_ClearResultOneOfCase(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0 *message);
+void GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_ClearResultOneOfCase(GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0 *message);
 
-#pragma mark - GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfos
+#pragma mark - GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Versions
 
-typedef GPB_ENUM(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfos_FieldNumber) {
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfos_FieldNumber_FinalizedEpochInfosArray = 1,
+typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Versions_FieldNumber) {
+  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Versions_FieldNumber_VersionsArray = 1,
 };
 
 /**
- * FinalizedEpochInfos holds a collection of finalized epoch information
- * entries
+ * Versions holds a collection of version entries
  **/
-GPB_FINAL @interface GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfos : GPBMessage
-
-/** List of finalized information for each requested epoch */
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo*> *finalizedEpochInfosArray;
-/** The number of items in @c finalizedEpochInfosArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger finalizedEpochInfosArray_Count;
-
-@end
-
-#pragma mark - GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo
-
-typedef GPB_ENUM(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber) {
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_Number = 1,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_FirstBlockHeight = 2,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_FirstCoreBlockHeight = 3,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_FirstBlockTime = 4,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_FeeMultiplier = 5,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_ProtocolVersion = 6,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_TotalBlocksInEpoch = 7,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_NextEpochStartCoreBlockHeight = 8,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_TotalProcessingFees = 9,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_TotalDistributedStorageFees = 10,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_TotalCreatedStorageFees = 11,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_CoreBlockRewards = 12,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_BlockProposersArray = 13,
-};
-
-/**
- * FinalizedEpochInfo represents finalized information about a single epoch
- **/
-GPB_FINAL @interface GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo : GPBMessage
-
-/** The number of the epoch */
-@property(nonatomic, readwrite) uint32_t number;
-
-/** The height of the first block in this epoch */
-@property(nonatomic, readwrite) uint64_t firstBlockHeight;
-
-/** The height of the first Core block in this epoch */
-@property(nonatomic, readwrite) uint32_t firstCoreBlockHeight;
-
-/** The timestamp of the first block (milliseconds) */
-@property(nonatomic, readwrite) uint64_t firstBlockTime;
-
-/** The fee multiplier (converted from permille) */
-@property(nonatomic, readwrite) double feeMultiplier;
-
-/** The protocol version for this epoch */
-@property(nonatomic, readwrite) uint32_t protocolVersion;
-
-/** Total number of blocks in the epoch */
-@property(nonatomic, readwrite) uint64_t totalBlocksInEpoch;
-
-/** Core block height where next epoch starts */
-@property(nonatomic, readwrite) uint32_t nextEpochStartCoreBlockHeight;
-
-/** Total processing fees collected */
-@property(nonatomic, readwrite) uint64_t totalProcessingFees;
-
-/** Total storage fees distributed */
-@property(nonatomic, readwrite) uint64_t totalDistributedStorageFees;
-
-/** Total storage fees created */
-@property(nonatomic, readwrite) uint64_t totalCreatedStorageFees;
-
-/** Rewards from core blocks */
-@property(nonatomic, readwrite) uint64_t coreBlockRewards;
+GPB_FINAL @interface GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_Versions : GPBMessage
 
-/** List of block proposers and their counts */
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer*> *blockProposersArray;
-/** The number of items in @c blockProposersArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger blockProposersArray_Count;
+/** List of protocol version entries */
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry*> *versionsArray;
+/** The number of items in @c versionsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger versionsArray_Count;
 
 @end
 
-#pragma mark - GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer
+#pragma mark - GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry
 
-typedef GPB_ENUM(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer_FieldNumber) {
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer_FieldNumber_ProposerId = 1,
-  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer_FieldNumber_BlockCount = 2,
+typedef GPB_ENUM(GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry_FieldNumber) {
+  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry_FieldNumber_VersionNumber = 1,
+  GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry_FieldNumber_VoteCount = 2,
 };
 
 /**
- * BlockProposer represents a block proposer and their block count
+ * VersionEntry represents a single entry of a protocol version
  **/
-GPB_FINAL @interface GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer : GPBMessage
+GPB_FINAL @interface GetProtocolVersionUpgradeStateResponse_GetProtocolVersionUpgradeStateResponseV0_VersionEntry : GPBMessage
 
-/** The proposer's identifier */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *proposerId;
+/** The protocol version number */
+@property(nonatomic, readwrite) uint32_t versionNumber;
 
-/** Number of blocks proposed */
-@property(nonatomic, readwrite) uint32_t blockCount;
+/** The vote count for this protocol version */
+@property(nonatomic, readwrite) uint32_t voteCount;
 
 @end
 
-#pragma mark - GetContestedResourcesRequest
+#pragma mark - GetProtocolVersionUpgradeVoteStatusRequest
 
-typedef GPB_ENUM(GetContestedResourcesRequest_FieldNumber) {
-  GetContestedResourcesRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusRequest_FieldNumber) {
+  GetProtocolVersionUpgradeVoteStatusRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetContestedResourcesRequest_Version_OneOfCase) {
-  GetContestedResourcesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourcesRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusRequest_Version_OneOfCase) {
+  GetProtocolVersionUpgradeVoteStatusRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetProtocolVersionUpgradeVoteStatusRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetContestedResourcesRequest : GPBMessage
+GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusRequest : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourcesRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetProtocolVersionUpgradeVoteStatusRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourcesRequest_GetContestedResourcesRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetContestedResourcesRequest_ClearVersionOneOfCase(GetContestedResourcesRequest *message);
+void GetProtocolVersionUpgradeVoteStatusRequest_ClearVersionOneOfCase(GetProtocolVersionUpgradeVoteStatusRequest *message);
 
-#pragma mark - GetContestedResourcesRequest_GetContestedResourcesRequestV0
+#pragma mark - GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0
 
-typedef GPB_ENUM(GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber) {
-  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_ContractId = 1,
-  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_DocumentTypeName = 2,
-  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_IndexName = 3,
-  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_StartIndexValuesArray = 4,
-  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_EndIndexValuesArray = 5,
-  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_StartAtValueInfo = 6,
-  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_Count = 7,
-  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_OrderAscending = 8,
-  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_Prove = 9,
+typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0_FieldNumber) {
+  GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0_FieldNumber_StartProTxHash = 1,
+  GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0_FieldNumber_Count = 2,
+  GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0_FieldNumber_Prove = 3,
 };
 
-GPB_FINAL @interface GetContestedResourcesRequest_GetContestedResourcesRequestV0 : GPBMessage
-
-@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
-
-@property(nonatomic, readwrite, copy, null_resettable) NSString *documentTypeName;
-
-@property(nonatomic, readwrite, copy, null_resettable) NSString *indexName;
-
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *startIndexValuesArray;
-/** The number of items in @c startIndexValuesArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger startIndexValuesArray_Count;
-
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *endIndexValuesArray;
-/** The number of items in @c endIndexValuesArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger endIndexValuesArray_Count;
+GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusRequest_GetProtocolVersionUpgradeVoteStatusRequestV0 : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo *startAtValueInfo;
-/** Test to see if @c startAtValueInfo has been set. */
-@property(nonatomic, readwrite) BOOL hasStartAtValueInfo;
+/** The starting masternode provider transaction */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *startProTxHash;
 
+/** hash to filter the votes by */
 @property(nonatomic, readwrite) uint32_t count;
 
-@property(nonatomic, readwrite) BOOL hasCount;
-@property(nonatomic, readwrite) BOOL orderAscending;
-
+/** Flag to request a proof as the response */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo
-
-typedef GPB_ENUM(GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo_FieldNumber) {
-  GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo_FieldNumber_StartValue = 1,
-  GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo_FieldNumber_StartValueIncluded = 2,
-};
-
-GPB_FINAL @interface GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo : GPBMessage
-
-@property(nonatomic, readwrite, copy, null_resettable) NSData *startValue;
-
-@property(nonatomic, readwrite) BOOL startValueIncluded;
-
-@end
-
-#pragma mark - GetContestedResourcesResponse
+#pragma mark - GetProtocolVersionUpgradeVoteStatusResponse
 
-typedef GPB_ENUM(GetContestedResourcesResponse_FieldNumber) {
-  GetContestedResourcesResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_FieldNumber) {
+  GetProtocolVersionUpgradeVoteStatusResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetContestedResourcesResponse_Version_OneOfCase) {
-  GetContestedResourcesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourcesResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_Version_OneOfCase) {
+  GetProtocolVersionUpgradeVoteStatusResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetProtocolVersionUpgradeVoteStatusResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetContestedResourcesResponse : GPBMessage
+GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusResponse : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourcesResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetProtocolVersionUpgradeVoteStatusResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourcesResponse_GetContestedResourcesResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetContestedResourcesResponse_ClearVersionOneOfCase(GetContestedResourcesResponse *message);
+void GetProtocolVersionUpgradeVoteStatusResponse_ClearVersionOneOfCase(GetProtocolVersionUpgradeVoteStatusResponse *message);
 
-#pragma mark - GetContestedResourcesResponse_GetContestedResourcesResponseV0
+#pragma mark - GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0
 
-typedef GPB_ENUM(GetContestedResourcesResponse_GetContestedResourcesResponseV0_FieldNumber) {
-  GetContestedResourcesResponse_GetContestedResourcesResponseV0_FieldNumber_ContestedResourceValues = 1,
-  GetContestedResourcesResponse_GetContestedResourcesResponseV0_FieldNumber_Proof = 2,
-  GetContestedResourcesResponse_GetContestedResourcesResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_FieldNumber) {
+  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_FieldNumber_Versions = 1,
+  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_FieldNumber_Proof = 2,
+  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetContestedResourcesResponse_GetContestedResourcesResponseV0_Result_OneOfCase) {
-  GetContestedResourcesResponse_GetContestedResourcesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourcesResponse_GetContestedResourcesResponseV0_Result_OneOfCase_ContestedResourceValues = 1,
-  GetContestedResourcesResponse_GetContestedResourcesResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_Result_OneOfCase) {
+  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_Result_OneOfCase_Versions = 1,
+  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetContestedResourcesResponse_GetContestedResourcesResponseV0 : GPBMessage
+GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourcesResponse_GetContestedResourcesResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_Result_OneOfCase resultOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourcesResponse_GetContestedResourcesResponseV0_ContestedResourceValues *contestedResourceValues;
+/** The actual version signal information */
+@property(nonatomic, readwrite, strong, null_resettable) GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignals *versions;
 
+/** Cryptographic proof of the version signal information, */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
+/** Metadata about the blockchain state */
 @property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
 /** Test to see if @c metadata has been set. */
 @property(nonatomic, readwrite) BOOL hasMetadata;
@@ -3619,155 +3928,146 @@ GPB_FINAL @interface GetContestedResourcesResponse_GetContestedResourcesResponse
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetContestedResourcesResponse_GetContestedResourcesResponseV0_ClearResultOneOfCase(GetContestedResourcesResponse_GetContestedResourcesResponseV0 *message);
+void GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_ClearResultOneOfCase(GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0 *message);
 
-#pragma mark - GetContestedResourcesResponse_GetContestedResourcesResponseV0_ContestedResourceValues
+#pragma mark - GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignals
 
-typedef GPB_ENUM(GetContestedResourcesResponse_GetContestedResourcesResponseV0_ContestedResourceValues_FieldNumber) {
-  GetContestedResourcesResponse_GetContestedResourcesResponseV0_ContestedResourceValues_FieldNumber_ContestedResourceValuesArray = 1,
+typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignals_FieldNumber) {
+  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignals_FieldNumber_VersionSignalsArray = 1,
 };
 
-GPB_FINAL @interface GetContestedResourcesResponse_GetContestedResourcesResponseV0_ContestedResourceValues : GPBMessage
+/**
+ * VersionSignals holds a collection of version signal entries
+ **/
+GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignals : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *contestedResourceValuesArray;
-/** The number of items in @c contestedResourceValuesArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger contestedResourceValuesArray_Count;
+/** List of version signal entries */
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal*> *versionSignalsArray;
+/** The number of items in @c versionSignalsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger versionSignalsArray_Count;
 
 @end
 
-#pragma mark - GetVotePollsByEndDateRequest
-
-typedef GPB_ENUM(GetVotePollsByEndDateRequest_FieldNumber) {
-  GetVotePollsByEndDateRequest_FieldNumber_V0 = 1,
-};
+#pragma mark - GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal
 
-typedef GPB_ENUM(GetVotePollsByEndDateRequest_Version_OneOfCase) {
-  GetVotePollsByEndDateRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetVotePollsByEndDateRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal_FieldNumber) {
+  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal_FieldNumber_ProTxHash = 1,
+  GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal_FieldNumber_Version = 2,
 };
 
-GPB_FINAL @interface GetVotePollsByEndDateRequest : GPBMessage
+/**
+ * VersionSignal represents a single voting signal for a protocol version
+ **/
+GPB_FINAL @interface GetProtocolVersionUpgradeVoteStatusResponse_GetProtocolVersionUpgradeVoteStatusResponseV0_VersionSignal : GPBMessage
 
-@property(nonatomic, readonly) GetVotePollsByEndDateRequest_Version_OneOfCase versionOneOfCase;
+/** The masternode provider transaction hash */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *proTxHash;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0 *v0;
+/** associated with the vote */
+@property(nonatomic, readwrite) uint32_t version;
 
 @end
 
-/**
- * Clears whatever value was set for the oneof 'version'.
- **/
-void GetVotePollsByEndDateRequest_ClearVersionOneOfCase(GetVotePollsByEndDateRequest *message);
-
-#pragma mark - GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0
+#pragma mark - GetEpochsInfoRequest
 
-typedef GPB_ENUM(GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber) {
-  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_StartTimeInfo = 1,
-  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_EndTimeInfo = 2,
-  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_Limit = 3,
-  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_Offset = 4,
-  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_Ascending = 5,
-  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_Prove = 6,
+typedef GPB_ENUM(GetEpochsInfoRequest_FieldNumber) {
+  GetEpochsInfoRequest_FieldNumber_V0 = 1,
 };
 
-GPB_FINAL @interface GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0 : GPBMessage
-
-@property(nonatomic, readwrite, strong, null_resettable) GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo *startTimeInfo;
-/** Test to see if @c startTimeInfo has been set. */
-@property(nonatomic, readwrite) BOOL hasStartTimeInfo;
-
-@property(nonatomic, readwrite, strong, null_resettable) GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo *endTimeInfo;
-/** Test to see if @c endTimeInfo has been set. */
-@property(nonatomic, readwrite) BOOL hasEndTimeInfo;
-
-@property(nonatomic, readwrite) uint32_t limit;
+typedef GPB_ENUM(GetEpochsInfoRequest_Version_OneOfCase) {
+  GetEpochsInfoRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetEpochsInfoRequest_Version_OneOfCase_V0 = 1,
+};
 
-@property(nonatomic, readwrite) BOOL hasLimit;
-@property(nonatomic, readwrite) uint32_t offset;
+GPB_FINAL @interface GetEpochsInfoRequest : GPBMessage
 
-@property(nonatomic, readwrite) BOOL hasOffset;
-@property(nonatomic, readwrite) BOOL ascending;
+@property(nonatomic, readonly) GetEpochsInfoRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite) BOOL prove;
+@property(nonatomic, readwrite, strong, null_resettable) GetEpochsInfoRequest_GetEpochsInfoRequestV0 *v0;
 
 @end
 
-#pragma mark - GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo
-
-typedef GPB_ENUM(GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo_FieldNumber) {
-  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo_FieldNumber_StartTimeMs = 1,
-  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo_FieldNumber_StartTimeIncluded = 2,
-};
-
-GPB_FINAL @interface GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo : GPBMessage
-
-@property(nonatomic, readwrite) uint64_t startTimeMs;
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetEpochsInfoRequest_ClearVersionOneOfCase(GetEpochsInfoRequest *message);
 
-@property(nonatomic, readwrite) BOOL startTimeIncluded;
+#pragma mark - GetEpochsInfoRequest_GetEpochsInfoRequestV0
 
-@end
+typedef GPB_ENUM(GetEpochsInfoRequest_GetEpochsInfoRequestV0_FieldNumber) {
+  GetEpochsInfoRequest_GetEpochsInfoRequestV0_FieldNumber_StartEpoch = 1,
+  GetEpochsInfoRequest_GetEpochsInfoRequestV0_FieldNumber_Count = 2,
+  GetEpochsInfoRequest_GetEpochsInfoRequestV0_FieldNumber_Ascending = 3,
+  GetEpochsInfoRequest_GetEpochsInfoRequestV0_FieldNumber_Prove = 4,
+};
 
-#pragma mark - GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo
+GPB_FINAL @interface GetEpochsInfoRequest_GetEpochsInfoRequestV0 : GPBMessage
 
-typedef GPB_ENUM(GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo_FieldNumber) {
-  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo_FieldNumber_EndTimeMs = 1,
-  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo_FieldNumber_EndTimeIncluded = 2,
-};
+/** The starting epoch for the request */
+@property(nonatomic, readwrite, strong, null_resettable) GPBUInt32Value *startEpoch;
+/** Test to see if @c startEpoch has been set. */
+@property(nonatomic, readwrite) BOOL hasStartEpoch;
 
-GPB_FINAL @interface GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo : GPBMessage
+/** The number of epochs to retrieve information for */
+@property(nonatomic, readwrite) uint32_t count;
 
-@property(nonatomic, readwrite) uint64_t endTimeMs;
+/** Flag indicating if the epochs should be listed in ascending order */
+@property(nonatomic, readwrite) BOOL ascending;
 
-@property(nonatomic, readwrite) BOOL endTimeIncluded;
+/** Flag to request a proof as the response */
+@property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetVotePollsByEndDateResponse
+#pragma mark - GetEpochsInfoResponse
 
-typedef GPB_ENUM(GetVotePollsByEndDateResponse_FieldNumber) {
-  GetVotePollsByEndDateResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetEpochsInfoResponse_FieldNumber) {
+  GetEpochsInfoResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetVotePollsByEndDateResponse_Version_OneOfCase) {
-  GetVotePollsByEndDateResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetVotePollsByEndDateResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetEpochsInfoResponse_Version_OneOfCase) {
+  GetEpochsInfoResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetEpochsInfoResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetVotePollsByEndDateResponse : GPBMessage
+GPB_FINAL @interface GetEpochsInfoResponse : GPBMessage
 
-@property(nonatomic, readonly) GetVotePollsByEndDateResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetEpochsInfoResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetEpochsInfoResponse_GetEpochsInfoResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetVotePollsByEndDateResponse_ClearVersionOneOfCase(GetVotePollsByEndDateResponse *message);
+void GetEpochsInfoResponse_ClearVersionOneOfCase(GetEpochsInfoResponse *message);
 
-#pragma mark - GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0
+#pragma mark - GetEpochsInfoResponse_GetEpochsInfoResponseV0
 
-typedef GPB_ENUM(GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_FieldNumber) {
-  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_FieldNumber_VotePollsByTimestamps = 1,
-  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_FieldNumber_Proof = 2,
-  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetEpochsInfoResponse_GetEpochsInfoResponseV0_FieldNumber) {
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_FieldNumber_Epochs = 1,
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_FieldNumber_Proof = 2,
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_Result_OneOfCase) {
-  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_Result_OneOfCase_VotePollsByTimestamps = 1,
-  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetEpochsInfoResponse_GetEpochsInfoResponseV0_Result_OneOfCase) {
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_Result_OneOfCase_Epochs = 1,
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0 : GPBMessage
+GPB_FINAL @interface GetEpochsInfoResponse_GetEpochsInfoResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetEpochsInfoResponse_GetEpochsInfoResponseV0_Result_OneOfCase resultOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps *votePollsByTimestamps;
+/** The actual information about the requested epochs */
+@property(nonatomic, readwrite, strong, null_resettable) GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfos *epochs;
 
+/** Cryptographic proof of the epoch information, if requested */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
+/** Metadata about the blockchain state */
 @property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
 /** Test to see if @c metadata has been set. */
 @property(nonatomic, readwrite) BOOL hasMetadata;
@@ -3777,183 +4077,163 @@ GPB_FINAL @interface GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponse
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_ClearResultOneOfCase(GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0 *message);
+void GetEpochsInfoResponse_GetEpochsInfoResponseV0_ClearResultOneOfCase(GetEpochsInfoResponse_GetEpochsInfoResponseV0 *message);
 
-#pragma mark - GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp
+#pragma mark - GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfos
 
-typedef GPB_ENUM(GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp_FieldNumber) {
-  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp_FieldNumber_Timestamp = 1,
-  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp_FieldNumber_SerializedVotePollsArray = 2,
+typedef GPB_ENUM(GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfos_FieldNumber) {
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfos_FieldNumber_EpochInfosArray = 1,
 };
 
-GPB_FINAL @interface GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp : GPBMessage
-
-@property(nonatomic, readwrite) uint64_t timestamp;
+/**
+ * EpochInfos holds a collection of epoch information entries
+ **/
+GPB_FINAL @interface GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfos : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *serializedVotePollsArray;
-/** The number of items in @c serializedVotePollsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger serializedVotePollsArray_Count;
+/** List of information for each requested epoch */
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo*> *epochInfosArray;
+/** The number of items in @c epochInfosArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger epochInfosArray_Count;
 
 @end
 
-#pragma mark - GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps
+#pragma mark - GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo
 
-typedef GPB_ENUM(GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps_FieldNumber) {
-  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps_FieldNumber_VotePollsByTimestampsArray = 1,
-  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps_FieldNumber_FinishedResults = 2,
+typedef GPB_ENUM(GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber) {
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_Number = 1,
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_FirstBlockHeight = 2,
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_FirstCoreBlockHeight = 3,
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_StartTime = 4,
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_FeeMultiplier = 5,
+  GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo_FieldNumber_ProtocolVersion = 6,
 };
 
-GPB_FINAL @interface GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps : GPBMessage
+/**
+ * EpochInfo represents information about a single epoch
+ **/
+GPB_FINAL @interface GetEpochsInfoResponse_GetEpochsInfoResponseV0_EpochInfo : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp*> *votePollsByTimestampsArray;
-/** The number of items in @c votePollsByTimestampsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger votePollsByTimestampsArray_Count;
+/** The number of the epoch */
+@property(nonatomic, readwrite) uint32_t number;
 
-@property(nonatomic, readwrite) BOOL finishedResults;
+/** The height of the first block in this epoch */
+@property(nonatomic, readwrite) uint64_t firstBlockHeight;
+
+/** The height of the first Core block in this epoch */
+@property(nonatomic, readwrite) uint32_t firstCoreBlockHeight;
+
+/** The start time of the epoch */
+@property(nonatomic, readwrite) uint64_t startTime;
+
+/** The fee multiplier applicable in this epoch */
+@property(nonatomic, readwrite) double feeMultiplier;
+
+@property(nonatomic, readwrite) uint32_t protocolVersion;
 
 @end
 
-#pragma mark - GetContestedResourceVoteStateRequest
+#pragma mark - GetFinalizedEpochInfosRequest
 
-typedef GPB_ENUM(GetContestedResourceVoteStateRequest_FieldNumber) {
-  GetContestedResourceVoteStateRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetFinalizedEpochInfosRequest_FieldNumber) {
+  GetFinalizedEpochInfosRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetContestedResourceVoteStateRequest_Version_OneOfCase) {
-  GetContestedResourceVoteStateRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourceVoteStateRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetFinalizedEpochInfosRequest_Version_OneOfCase) {
+  GetFinalizedEpochInfosRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetFinalizedEpochInfosRequest_Version_OneOfCase_V0 = 1,
 };
 
-/**
- * What's the state of a contested resource vote? (ie who is winning?)
- **/
-GPB_FINAL @interface GetContestedResourceVoteStateRequest : GPBMessage
+GPB_FINAL @interface GetFinalizedEpochInfosRequest : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourceVoteStateRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetFinalizedEpochInfosRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetContestedResourceVoteStateRequest_ClearVersionOneOfCase(GetContestedResourceVoteStateRequest *message);
+void GetFinalizedEpochInfosRequest_ClearVersionOneOfCase(GetFinalizedEpochInfosRequest *message);
 
-#pragma mark - GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0
+#pragma mark - GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0
 
-typedef GPB_ENUM(GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber) {
-  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_ContractId = 1,
-  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_DocumentTypeName = 2,
-  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_IndexName = 3,
-  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_IndexValuesArray = 4,
-  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_ResultType = 5,
-  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_AllowIncludeLockedAndAbstainingVoteTally = 6,
-  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_StartAtIdentifierInfo = 7,
-  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_Count = 8,
-  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_Prove = 9,
+typedef GPB_ENUM(GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber) {
+  GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber_StartEpochIndex = 1,
+  GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber_StartEpochIndexIncluded = 2,
+  GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber_EndEpochIndex = 3,
+  GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber_EndEpochIndexIncluded = 4,
+  GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0_FieldNumber_Prove = 5,
 };
 
-GPB_FINAL @interface GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0 : GPBMessage
+GPB_FINAL @interface GetFinalizedEpochInfosRequest_GetFinalizedEpochInfosRequestV0 : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
+/** The starting epoch index */
+@property(nonatomic, readwrite) uint32_t startEpochIndex;
 
-@property(nonatomic, readwrite, copy, null_resettable) NSString *documentTypeName;
+/** Whether to include the start epoch */
+@property(nonatomic, readwrite) BOOL startEpochIndexIncluded;
 
-@property(nonatomic, readwrite, copy, null_resettable) NSString *indexName;
+/** The ending epoch index */
+@property(nonatomic, readwrite) uint32_t endEpochIndex;
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *indexValuesArray;
-/** The number of items in @c indexValuesArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger indexValuesArray_Count;
+/** Whether to include the end epoch */
+@property(nonatomic, readwrite) BOOL endEpochIndexIncluded;
 
-@property(nonatomic, readwrite) GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_ResultType resultType;
+/** Flag to request a proof as the response */
+@property(nonatomic, readwrite) BOOL prove;
 
-@property(nonatomic, readwrite) BOOL allowIncludeLockedAndAbstainingVoteTally;
+@end
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo *startAtIdentifierInfo;
-/** Test to see if @c startAtIdentifierInfo has been set. */
-@property(nonatomic, readwrite) BOOL hasStartAtIdentifierInfo;
-
-@property(nonatomic, readwrite) uint32_t count;
-
-@property(nonatomic, readwrite) BOOL hasCount;
-@property(nonatomic, readwrite) BOOL prove;
-
-@end
-
-/**
- * Fetches the raw value of a @c GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0's @c resultType property, even
- * if the value was not defined by the enum at the time the code was generated.
- **/
-int32_t GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_ResultType_RawValue(GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0 *message);
-/**
- * Sets the raw value of an @c GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0's @c resultType property, allowing
- * it to be set to a value that was not defined by the enum at the time the code
- * was generated.
- **/
-void SetGetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_ResultType_RawValue(GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0 *message, int32_t value);
-
-#pragma mark - GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo
-
-typedef GPB_ENUM(GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo_FieldNumber) {
-  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo_FieldNumber_StartIdentifier = 1,
-  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo_FieldNumber_StartIdentifierIncluded = 2,
-};
-
-GPB_FINAL @interface GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo : GPBMessage
-
-@property(nonatomic, readwrite, copy, null_resettable) NSData *startIdentifier;
-
-@property(nonatomic, readwrite) BOOL startIdentifierIncluded;
-
-@end
-
-#pragma mark - GetContestedResourceVoteStateResponse
+#pragma mark - GetFinalizedEpochInfosResponse
 
-typedef GPB_ENUM(GetContestedResourceVoteStateResponse_FieldNumber) {
-  GetContestedResourceVoteStateResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetFinalizedEpochInfosResponse_FieldNumber) {
+  GetFinalizedEpochInfosResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetContestedResourceVoteStateResponse_Version_OneOfCase) {
-  GetContestedResourceVoteStateResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourceVoteStateResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetFinalizedEpochInfosResponse_Version_OneOfCase) {
+  GetFinalizedEpochInfosResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetFinalizedEpochInfosResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetContestedResourceVoteStateResponse : GPBMessage
+GPB_FINAL @interface GetFinalizedEpochInfosResponse : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourceVoteStateResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetFinalizedEpochInfosResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetContestedResourceVoteStateResponse_ClearVersionOneOfCase(GetContestedResourceVoteStateResponse *message);
+void GetFinalizedEpochInfosResponse_ClearVersionOneOfCase(GetFinalizedEpochInfosResponse *message);
 
-#pragma mark - GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0
+#pragma mark - GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0
 
-typedef GPB_ENUM(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FieldNumber) {
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FieldNumber_ContestedResourceContenders = 1,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FieldNumber_Proof = 2,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FieldNumber) {
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FieldNumber_Epochs = 1,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FieldNumber_Proof = 2,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Result_OneOfCase) {
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Result_OneOfCase_ContestedResourceContenders = 1,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_Result_OneOfCase) {
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_Result_OneOfCase_Epochs = 1,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0 : GPBMessage
+GPB_FINAL @interface GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_Result_OneOfCase resultOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders *contestedResourceContenders;
+/** The actual finalized information about the requested epochs */
+@property(nonatomic, readwrite, strong, null_resettable) GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfos *epochs;
 
+/** Cryptographic proof of the finalized epoch */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
+/** Metadata about the blockchain state */
 @property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
 /** Test to see if @c metadata has been set. */
 @property(nonatomic, readwrite) BOOL hasMetadata;
@@ -3963,373 +4243,407 @@ GPB_FINAL @interface GetContestedResourceVoteStateResponse_GetContestedResourceV
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ClearResultOneOfCase(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0 *message);
+void GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_ClearResultOneOfCase(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0 *message);
 
-#pragma mark - GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo
+#pragma mark - GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfos
 
-typedef GPB_ENUM(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber) {
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_FinishedVoteOutcome = 1,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_WonByIdentityId = 2,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_FinishedAtBlockHeight = 3,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_FinishedAtCoreBlockHeight = 4,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_FinishedAtBlockTimeMs = 5,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_FinishedAtEpoch = 6,
+typedef GPB_ENUM(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfos_FieldNumber) {
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfos_FieldNumber_FinalizedEpochInfosArray = 1,
 };
 
-GPB_FINAL @interface GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo : GPBMessage
+/**
+ * FinalizedEpochInfos holds a collection of finalized epoch information
+ * entries
+ **/
+GPB_FINAL @interface GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfos : GPBMessage
 
-@property(nonatomic, readwrite) GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FinishedVoteOutcome finishedVoteOutcome;
+/** List of finalized information for each requested epoch */
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo*> *finalizedEpochInfosArray;
+/** The number of items in @c finalizedEpochInfosArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger finalizedEpochInfosArray_Count;
 
-/** Only used when vote_choice_type is TOWARDS_IDENTITY */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *wonByIdentityId;
-/** Test to see if @c wonByIdentityId has been set. */
-@property(nonatomic, readwrite) BOOL hasWonByIdentityId;
+@end
 
-@property(nonatomic, readwrite) uint64_t finishedAtBlockHeight;
+#pragma mark - GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo
 
-@property(nonatomic, readwrite) uint32_t finishedAtCoreBlockHeight;
+typedef GPB_ENUM(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber) {
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_Number = 1,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_FirstBlockHeight = 2,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_FirstCoreBlockHeight = 3,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_FirstBlockTime = 4,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_FeeMultiplier = 5,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_ProtocolVersion = 6,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_TotalBlocksInEpoch = 7,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_NextEpochStartCoreBlockHeight = 8,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_TotalProcessingFees = 9,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_TotalDistributedStorageFees = 10,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_TotalCreatedStorageFees = 11,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_CoreBlockRewards = 12,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo_FieldNumber_BlockProposersArray = 13,
+};
 
-@property(nonatomic, readwrite) uint64_t finishedAtBlockTimeMs;
+/**
+ * FinalizedEpochInfo represents finalized information about a single epoch
+ **/
+GPB_FINAL @interface GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_FinalizedEpochInfo : GPBMessage
 
-@property(nonatomic, readwrite) uint32_t finishedAtEpoch;
+/** The number of the epoch */
+@property(nonatomic, readwrite) uint32_t number;
 
-@end
+/** The height of the first block in this epoch */
+@property(nonatomic, readwrite) uint64_t firstBlockHeight;
 
-/**
- * Fetches the raw value of a @c GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo's @c finishedVoteOutcome property, even
- * if the value was not defined by the enum at the time the code was generated.
- **/
-int32_t GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FinishedVoteOutcome_RawValue(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo *message);
-/**
- * Sets the raw value of an @c GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo's @c finishedVoteOutcome property, allowing
- * it to be set to a value that was not defined by the enum at the time the code
- * was generated.
- **/
-void SetGetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FinishedVoteOutcome_RawValue(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo *message, int32_t value);
+/** The height of the first Core block in this epoch */
+@property(nonatomic, readwrite) uint32_t firstCoreBlockHeight;
 
-#pragma mark - GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders
+/** The timestamp of the first block (milliseconds) */
+@property(nonatomic, readwrite) uint64_t firstBlockTime;
 
-typedef GPB_ENUM(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders_FieldNumber) {
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders_FieldNumber_ContendersArray = 1,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders_FieldNumber_AbstainVoteTally = 2,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders_FieldNumber_LockVoteTally = 3,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders_FieldNumber_FinishedVoteInfo = 4,
-};
+/** The fee multiplier (converted from permille) */
+@property(nonatomic, readwrite) double feeMultiplier;
 
-GPB_FINAL @interface GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders : GPBMessage
+/** The protocol version for this epoch */
+@property(nonatomic, readwrite) uint32_t protocolVersion;
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender*> *contendersArray;
-/** The number of items in @c contendersArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger contendersArray_Count;
+/** Total number of blocks in the epoch */
+@property(nonatomic, readwrite) uint64_t totalBlocksInEpoch;
 
-@property(nonatomic, readwrite) uint32_t abstainVoteTally;
+/** Core block height where next epoch starts */
+@property(nonatomic, readwrite) uint32_t nextEpochStartCoreBlockHeight;
 
-@property(nonatomic, readwrite) BOOL hasAbstainVoteTally;
-@property(nonatomic, readwrite) uint32_t lockVoteTally;
+/** Total processing fees collected */
+@property(nonatomic, readwrite) uint64_t totalProcessingFees;
 
-@property(nonatomic, readwrite) BOOL hasLockVoteTally;
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo *finishedVoteInfo;
-/** Test to see if @c finishedVoteInfo has been set. */
-@property(nonatomic, readwrite) BOOL hasFinishedVoteInfo;
+/** Total storage fees distributed */
+@property(nonatomic, readwrite) uint64_t totalDistributedStorageFees;
+
+/** Total storage fees created */
+@property(nonatomic, readwrite) uint64_t totalCreatedStorageFees;
+
+/** Rewards from core blocks */
+@property(nonatomic, readwrite) uint64_t coreBlockRewards;
+
+/** List of block proposers and their counts */
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer*> *blockProposersArray;
+/** The number of items in @c blockProposersArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger blockProposersArray_Count;
 
 @end
 
-#pragma mark - GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender
+#pragma mark - GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer
 
-typedef GPB_ENUM(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender_FieldNumber) {
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender_FieldNumber_Identifier = 1,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender_FieldNumber_VoteCount = 2,
-  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender_FieldNumber_Document = 3,
+typedef GPB_ENUM(GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer_FieldNumber) {
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer_FieldNumber_ProposerId = 1,
+  GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer_FieldNumber_BlockCount = 2,
 };
 
-GPB_FINAL @interface GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender : GPBMessage
-
-@property(nonatomic, readwrite, copy, null_resettable) NSData *identifier;
+/**
+ * BlockProposer represents a block proposer and their block count
+ **/
+GPB_FINAL @interface GetFinalizedEpochInfosResponse_GetFinalizedEpochInfosResponseV0_BlockProposer : GPBMessage
 
-@property(nonatomic, readwrite) uint32_t voteCount;
+/** The proposer's identifier */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *proposerId;
 
-@property(nonatomic, readwrite) BOOL hasVoteCount;
-@property(nonatomic, readwrite, copy, null_resettable) NSData *document;
-/** Test to see if @c document has been set. */
-@property(nonatomic, readwrite) BOOL hasDocument;
+/** Number of blocks proposed */
+@property(nonatomic, readwrite) uint32_t blockCount;
 
 @end
 
-#pragma mark - GetContestedResourceVotersForIdentityRequest
+#pragma mark - GetBlockStateTransitionsRequest
 
-typedef GPB_ENUM(GetContestedResourceVotersForIdentityRequest_FieldNumber) {
-  GetContestedResourceVotersForIdentityRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetBlockStateTransitionsRequest_FieldNumber) {
+  GetBlockStateTransitionsRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetContestedResourceVotersForIdentityRequest_Version_OneOfCase) {
-  GetContestedResourceVotersForIdentityRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourceVotersForIdentityRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetBlockStateTransitionsRequest_Version_OneOfCase) {
+  GetBlockStateTransitionsRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetBlockStateTransitionsRequest_Version_OneOfCase_V0 = 1,
 };
 
-/**
- * Who voted for a contested resource to go to a specific identity?
- **/
-GPB_FINAL @interface GetContestedResourceVotersForIdentityRequest : GPBMessage
+GPB_FINAL @interface GetBlockStateTransitionsRequest : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourceVotersForIdentityRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetBlockStateTransitionsRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetBlockStateTransitionsRequest_GetBlockStateTransitionsRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetContestedResourceVotersForIdentityRequest_ClearVersionOneOfCase(GetContestedResourceVotersForIdentityRequest *message);
+void GetBlockStateTransitionsRequest_ClearVersionOneOfCase(GetBlockStateTransitionsRequest *message);
 
-#pragma mark - GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0
+#pragma mark - GetBlockStateTransitionsRequest_GetBlockStateTransitionsRequestV0
 
-typedef GPB_ENUM(GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber) {
-  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_ContractId = 1,
-  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_DocumentTypeName = 2,
-  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_IndexName = 3,
-  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_IndexValuesArray = 4,
-  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_ContestantId = 5,
-  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_StartAtIdentifierInfo = 6,
-  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_Count = 7,
-  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_OrderAscending = 8,
-  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_Prove = 9,
+typedef GPB_ENUM(GetBlockStateTransitionsRequest_GetBlockStateTransitionsRequestV0_FieldNumber) {
+  GetBlockStateTransitionsRequest_GetBlockStateTransitionsRequestV0_FieldNumber_Height = 1,
 };
 
-GPB_FINAL @interface GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0 : GPBMessage
+GPB_FINAL @interface GetBlockStateTransitionsRequest_GetBlockStateTransitionsRequestV0 : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
+/** The height of the block to get the executed */
+@property(nonatomic, readwrite) uint64_t height;
 
-@property(nonatomic, readwrite, copy, null_resettable) NSString *documentTypeName;
+@end
 
-@property(nonatomic, readwrite, copy, null_resettable) NSString *indexName;
+#pragma mark - GetBlockStateTransitionsResponse
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *indexValuesArray;
-/** The number of items in @c indexValuesArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger indexValuesArray_Count;
-
-@property(nonatomic, readwrite, copy, null_resettable) NSData *contestantId;
+typedef GPB_ENUM(GetBlockStateTransitionsResponse_FieldNumber) {
+  GetBlockStateTransitionsResponse_FieldNumber_V0 = 1,
+};
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo *startAtIdentifierInfo;
-/** Test to see if @c startAtIdentifierInfo has been set. */
-@property(nonatomic, readwrite) BOOL hasStartAtIdentifierInfo;
+typedef GPB_ENUM(GetBlockStateTransitionsResponse_Version_OneOfCase) {
+  GetBlockStateTransitionsResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetBlockStateTransitionsResponse_Version_OneOfCase_V0 = 1,
+};
 
-@property(nonatomic, readwrite) uint32_t count;
+GPB_FINAL @interface GetBlockStateTransitionsResponse : GPBMessage
 
-@property(nonatomic, readwrite) BOOL hasCount;
-@property(nonatomic, readwrite) BOOL orderAscending;
+@property(nonatomic, readonly) GetBlockStateTransitionsResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite) BOOL prove;
+@property(nonatomic, readwrite, strong, null_resettable) GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0 *v0;
 
 @end
 
-#pragma mark - GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetBlockStateTransitionsResponse_ClearVersionOneOfCase(GetBlockStateTransitionsResponse *message);
 
-typedef GPB_ENUM(GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo_FieldNumber) {
-  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo_FieldNumber_StartIdentifier = 1,
-  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo_FieldNumber_StartIdentifierIncluded = 2,
+#pragma mark - GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0
+
+typedef GPB_ENUM(GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FieldNumber) {
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FieldNumber_BlockHeight = 1,
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FieldNumber_BlockTimeMs = 2,
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FieldNumber_StateTransitionsArray = 3,
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FieldNumber_Metadata = 4,
 };
 
-GPB_FINAL @interface GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo : GPBMessage
+GPB_FINAL @interface GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0 : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *startIdentifier;
+@property(nonatomic, readwrite) uint64_t blockHeight;
 
-@property(nonatomic, readwrite) BOOL startIdentifierIncluded;
+@property(nonatomic, readwrite) uint64_t blockTimeMs;
 
-@end
+/** The executed state transitions in block order */
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition*> *stateTransitionsArray;
+/** The number of items in @c stateTransitionsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger stateTransitionsArray_Count;
 
-#pragma mark - GetContestedResourceVotersForIdentityResponse
+/** Metadata about the blockchain state */
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
 
-typedef GPB_ENUM(GetContestedResourceVotersForIdentityResponse_FieldNumber) {
-  GetContestedResourceVotersForIdentityResponse_FieldNumber_V0 = 1,
-};
+@end
 
-typedef GPB_ENUM(GetContestedResourceVotersForIdentityResponse_Version_OneOfCase) {
-  GetContestedResourceVotersForIdentityResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourceVotersForIdentityResponse_Version_OneOfCase_V0 = 1,
+#pragma mark - GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeRefund
+
+typedef GPB_ENUM(GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeRefund_FieldNumber) {
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeRefund_FieldNumber_IdentityId = 1,
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeRefund_FieldNumber_Credits = 2,
 };
 
-GPB_FINAL @interface GetContestedResourceVotersForIdentityResponse : GPBMessage
+GPB_FINAL @interface GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeRefund : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourceVotersForIdentityResponse_Version_OneOfCase versionOneOfCase;
+/** The identity receiving the refund */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0 *v0;
+/** The refund summed over all epochs */
+@property(nonatomic, readwrite) uint64_t credits;
 
 @end
 
-/**
- * Clears whatever value was set for the oneof 'version'.
- **/
-void GetContestedResourceVotersForIdentityResponse_ClearVersionOneOfCase(GetContestedResourceVotersForIdentityResponse *message);
-
-#pragma mark - GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0
-
-typedef GPB_ENUM(GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_FieldNumber) {
-  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_FieldNumber_ContestedResourceVoters = 1,
-  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_FieldNumber_Proof = 2,
-  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_FieldNumber_Metadata = 3,
-};
+#pragma mark - GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeResult
 
-typedef GPB_ENUM(GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_Result_OneOfCase) {
-  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_Result_OneOfCase_ContestedResourceVoters = 1,
-  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeResult_FieldNumber) {
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeResult_FieldNumber_StorageFee = 1,
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeResult_FieldNumber_ProcessingFee = 2,
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeResult_FieldNumber_FeeRefundsArray = 3,
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeResult_FieldNumber_RemovedBytesFromSystem = 4,
 };
 
-GPB_FINAL @interface GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0 : GPBMessage
+GPB_FINAL @interface GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeResult : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readwrite) uint64_t storageFee;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters *contestedResourceVoters;
+@property(nonatomic, readwrite) uint64_t processingFee;
 
-@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeRefund*> *feeRefundsArray;
+/** The number of items in @c feeRefundsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger feeRefundsArray_Count;
 
-@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
-/** Test to see if @c metadata has been set. */
-@property(nonatomic, readwrite) BOOL hasMetadata;
+@property(nonatomic, readwrite) uint32_t removedBytesFromSystem;
 
 @end
 
-/**
- * Clears whatever value was set for the oneof 'result'.
- **/
-void GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ClearResultOneOfCase(GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0 *message);
-
-#pragma mark - GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters
+#pragma mark - GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition
 
-typedef GPB_ENUM(GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters_FieldNumber) {
-  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters_FieldNumber_VotersArray = 1,
-  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters_FieldNumber_FinishedResults = 2,
+typedef GPB_ENUM(GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition_FieldNumber) {
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition_FieldNumber_StateTransition = 1,
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition_FieldNumber_Status = 2,
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition_FieldNumber_FeeResult = 3,
+  GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition_FieldNumber_Error = 4,
 };
 
-GPB_FINAL @interface GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters : GPBMessage
+GPB_FINAL @interface GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *votersArray;
-/** The number of items in @c votersArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger votersArray_Count;
+/** The serialized state transition */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *stateTransition;
 
-@property(nonatomic, readwrite) BOOL finishedResults;
+/** The result of the execution */
+@property(nonatomic, readwrite) GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutionStatus status;
+
+/** The fees paid, unset if the state transition was not paid for */
+@property(nonatomic, readwrite, strong, null_resettable) GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_FeeResult *feeResult;
+/** Test to see if @c feeResult has been set. */
+@property(nonatomic, readwrite) BOOL hasFeeResult;
+
+/** The consensus error the state transition failed with, if any */
+@property(nonatomic, readwrite, strong, null_resettable) StateTransitionBroadcastError *error;
+/** Test to see if @c error has been set. */
+@property(nonatomic, readwrite) BOOL hasError;
 
 @end
 
-#pragma mark - GetContestedResourceIdentityVotesRequest
+/**
+ * Fetches the raw value of a @c GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition's @c status property, even
+ * if the value was not defined by the enum at the time the code was generated.
+ **/
+int32_t GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition_Status_RawValue(GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition *message);
+/**
+ * Sets the raw value of an @c GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition's @c status property, allowing
+ * it to be set to a value that was not defined by the enum at the time the code
+ * was generated.
+ **/
+void SetGetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition_Status_RawValue(GetBlockStateTransitionsResponse_GetBlockStateTransitionsResponseV0_ExecutedStateTransition *message, int32_t value);
 
-typedef GPB_ENUM(GetContestedResourceIdentityVotesRequest_FieldNumber) {
-  GetContestedResourceIdentityVotesRequest_FieldNumber_V0 = 1,
+#pragma mark - GetContestedResourcesRequest
+
+typedef GPB_ENUM(GetContestedResourcesRequest_FieldNumber) {
+  GetContestedResourcesRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetContestedResourceIdentityVotesRequest_Version_OneOfCase) {
-  GetContestedResourceIdentityVotesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourceIdentityVotesRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetContestedResourcesRequest_Version_OneOfCase) {
+  GetContestedResourcesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourcesRequest_Version_OneOfCase_V0 = 1,
 };
 
-/**
- * How did an identity vote?
- **/
-GPB_FINAL @interface GetContestedResourceIdentityVotesRequest : GPBMessage
+GPB_FINAL @interface GetContestedResourcesRequest : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourceIdentityVotesRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetContestedResourcesRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourcesRequest_GetContestedResourcesRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetContestedResourceIdentityVotesRequest_ClearVersionOneOfCase(GetContestedResourceIdentityVotesRequest *message);
+void GetContestedResourcesRequest_ClearVersionOneOfCase(GetContestedResourcesRequest *message);
 
-#pragma mark - GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0
+#pragma mark - GetContestedResourcesRequest_GetContestedResourcesRequestV0
 
-typedef GPB_ENUM(GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber) {
-  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_IdentityId = 1,
-  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_Limit = 2,
-  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_Offset = 3,
-  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_OrderAscending = 4,
-  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_StartAtVotePollIdInfo = 5,
-  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_Prove = 6,
+typedef GPB_ENUM(GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber) {
+  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_ContractId = 1,
+  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_DocumentTypeName = 2,
+  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_IndexName = 3,
+  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_StartIndexValuesArray = 4,
+  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_EndIndexValuesArray = 5,
+  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_StartAtValueInfo = 6,
+  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_Count = 7,
+  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_OrderAscending = 8,
+  GetContestedResourcesRequest_GetContestedResourcesRequestV0_FieldNumber_Prove = 9,
 };
 
-GPB_FINAL @interface GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0 : GPBMessage
+GPB_FINAL @interface GetContestedResourcesRequest_GetContestedResourcesRequestV0 : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
 
-@property(nonatomic, readwrite, strong, null_resettable) GPBUInt32Value *limit;
-/** Test to see if @c limit has been set. */
-@property(nonatomic, readwrite) BOOL hasLimit;
+@property(nonatomic, readwrite, copy, null_resettable) NSString *documentTypeName;
 
-@property(nonatomic, readwrite, strong, null_resettable) GPBUInt32Value *offset;
-/** Test to see if @c offset has been set. */
-@property(nonatomic, readwrite) BOOL hasOffset;
+@property(nonatomic, readwrite, copy, null_resettable) NSString *indexName;
 
-@property(nonatomic, readwrite) BOOL orderAscending;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *startIndexValuesArray;
+/** The number of items in @c startIndexValuesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger startIndexValuesArray_Count;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo *startAtVotePollIdInfo;
-/** Test to see if @c startAtVotePollIdInfo has been set. */
-@property(nonatomic, readwrite) BOOL hasStartAtVotePollIdInfo;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *endIndexValuesArray;
+/** The number of items in @c endIndexValuesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger endIndexValuesArray_Count;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo *startAtValueInfo;
+/** Test to see if @c startAtValueInfo has been set. */
+@property(nonatomic, readwrite) BOOL hasStartAtValueInfo;
+
+@property(nonatomic, readwrite) uint32_t count;
+
+@property(nonatomic, readwrite) BOOL hasCount;
+@property(nonatomic, readwrite) BOOL orderAscending;
 
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo
+#pragma mark - GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo
 
-typedef GPB_ENUM(GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo_FieldNumber) {
-  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo_FieldNumber_StartAtPollIdentifier = 1,
-  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo_FieldNumber_StartPollIdentifierIncluded = 2,
+typedef GPB_ENUM(GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo_FieldNumber) {
+  GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo_FieldNumber_StartValue = 1,
+  GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo_FieldNumber_StartValueIncluded = 2,
 };
 
-GPB_FINAL @interface GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo : GPBMessage
+GPB_FINAL @interface GetContestedResourcesRequest_GetContestedResourcesRequestV0_StartAtValueInfo : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *startAtPollIdentifier;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *startValue;
 
-@property(nonatomic, readwrite) BOOL startPollIdentifierIncluded;
+@property(nonatomic, readwrite) BOOL startValueIncluded;
 
 @end
 
-#pragma mark - GetContestedResourceIdentityVotesResponse
+#pragma mark - GetContestedResourcesResponse
 
-typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_FieldNumber) {
-  GetContestedResourceIdentityVotesResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetContestedResourcesResponse_FieldNumber) {
+  GetContestedResourcesResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_Version_OneOfCase) {
-  GetContestedResourceIdentityVotesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourceIdentityVotesResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetContestedResourcesResponse_Version_OneOfCase) {
+  GetContestedResourcesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourcesResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetContestedResourceIdentityVotesResponse : GPBMessage
+GPB_FINAL @interface GetContestedResourcesResponse : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourceIdentityVotesResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetContestedResourcesResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourcesResponse_GetContestedResourcesResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetContestedResourceIdentityVotesResponse_ClearVersionOneOfCase(GetContestedResourceIdentityVotesResponse *message);
+void GetContestedResourcesResponse_ClearVersionOneOfCase(GetContestedResourcesResponse *message);
 
-#pragma mark - GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0
+#pragma mark - GetContestedResourcesResponse_GetContestedResourcesResponseV0
 
-typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_FieldNumber) {
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_FieldNumber_Votes = 1,
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_FieldNumber_Proof = 2,
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetContestedResourcesResponse_GetContestedResourcesResponseV0_FieldNumber) {
+  GetContestedResourcesResponse_GetContestedResourcesResponseV0_FieldNumber_ContestedResourceValues = 1,
+  GetContestedResourcesResponse_GetContestedResourcesResponseV0_FieldNumber_Proof = 2,
+  GetContestedResourcesResponse_GetContestedResourcesResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_Result_OneOfCase) {
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_Result_OneOfCase_Votes = 1,
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetContestedResourcesResponse_GetContestedResourcesResponseV0_Result_OneOfCase) {
+  GetContestedResourcesResponse_GetContestedResourcesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourcesResponse_GetContestedResourcesResponseV0_Result_OneOfCase_ContestedResourceValues = 1,
+  GetContestedResourcesResponse_GetContestedResourcesResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0 : GPBMessage
+GPB_FINAL @interface GetContestedResourcesResponse_GetContestedResourcesResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetContestedResourcesResponse_GetContestedResourcesResponseV0_Result_OneOfCase resultOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes *votes;
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourcesResponse_GetContestedResourcesResponseV0_ContestedResourceValues *contestedResourceValues;
 
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
@@ -4342,257 +4656,1736 @@ GPB_FINAL @interface GetContestedResourceIdentityVotesResponse_GetContestedResou
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ClearResultOneOfCase(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0 *message);
+void GetContestedResourcesResponse_GetContestedResourcesResponseV0_ClearResultOneOfCase(GetContestedResourcesResponse_GetContestedResourcesResponseV0 *message);
 
-#pragma mark - GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes
+#pragma mark - GetContestedResourcesResponse_GetContestedResourcesResponseV0_ContestedResourceValues
 
-typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes_FieldNumber) {
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes_FieldNumber_ContestedResourceIdentityVotesArray = 1,
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes_FieldNumber_FinishedResults = 2,
+typedef GPB_ENUM(GetContestedResourcesResponse_GetContestedResourcesResponseV0_ContestedResourceValues_FieldNumber) {
+  GetContestedResourcesResponse_GetContestedResourcesResponseV0_ContestedResourceValues_FieldNumber_ContestedResourceValuesArray = 1,
 };
 
-GPB_FINAL @interface GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes : GPBMessage
-
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote*> *contestedResourceIdentityVotesArray;
-/** The number of items in @c contestedResourceIdentityVotesArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger contestedResourceIdentityVotesArray_Count;
+GPB_FINAL @interface GetContestedResourcesResponse_GetContestedResourcesResponseV0_ContestedResourceValues : GPBMessage
 
-@property(nonatomic, readwrite) BOOL finishedResults;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *contestedResourceValuesArray;
+/** The number of items in @c contestedResourceValuesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger contestedResourceValuesArray_Count;
 
 @end
 
-#pragma mark - GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice
+#pragma mark - GetVotePollsByEndDateRequest
 
-typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_FieldNumber) {
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_FieldNumber_VoteChoiceType = 1,
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_FieldNumber_IdentityId = 2,
+typedef GPB_ENUM(GetVotePollsByEndDateRequest_FieldNumber) {
+  GetVotePollsByEndDateRequest_FieldNumber_V0 = 1,
 };
 
-GPB_FINAL @interface GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice : GPBMessage
+typedef GPB_ENUM(GetVotePollsByEndDateRequest_Version_OneOfCase) {
+  GetVotePollsByEndDateRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetVotePollsByEndDateRequest_Version_OneOfCase_V0 = 1,
+};
 
-@property(nonatomic, readwrite) GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_VoteChoiceType voteChoiceType;
+GPB_FINAL @interface GetVotePollsByEndDateRequest : GPBMessage
 
-/** Only used when vote_choice_type is TOWARDS_IDENTITY */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
-/** Test to see if @c identityId has been set. */
-@property(nonatomic, readwrite) BOOL hasIdentityId;
+@property(nonatomic, readonly) GetVotePollsByEndDateRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0 *v0;
 
 @end
 
 /**
- * Fetches the raw value of a @c GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice's @c voteChoiceType property, even
- * if the value was not defined by the enum at the time the code was generated.
+ * Clears whatever value was set for the oneof 'version'.
  **/
-int32_t GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_VoteChoiceType_RawValue(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice *message);
+void GetVotePollsByEndDateRequest_ClearVersionOneOfCase(GetVotePollsByEndDateRequest *message);
+
+#pragma mark - GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0
+
+typedef GPB_ENUM(GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber) {
+  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_StartTimeInfo = 1,
+  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_EndTimeInfo = 2,
+  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_Limit = 3,
+  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_Offset = 4,
+  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_Ascending = 5,
+  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_FieldNumber_Prove = 6,
+};
+
+GPB_FINAL @interface GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0 : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo *startTimeInfo;
+/** Test to see if @c startTimeInfo has been set. */
+@property(nonatomic, readwrite) BOOL hasStartTimeInfo;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo *endTimeInfo;
+/** Test to see if @c endTimeInfo has been set. */
+@property(nonatomic, readwrite) BOOL hasEndTimeInfo;
+
+@property(nonatomic, readwrite) uint32_t limit;
+
+@property(nonatomic, readwrite) BOOL hasLimit;
+@property(nonatomic, readwrite) uint32_t offset;
+
+@property(nonatomic, readwrite) BOOL hasOffset;
+@property(nonatomic, readwrite) BOOL ascending;
+
+@property(nonatomic, readwrite) BOOL prove;
+
+@end
+
+#pragma mark - GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo
+
+typedef GPB_ENUM(GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo_FieldNumber) {
+  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo_FieldNumber_StartTimeMs = 1,
+  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo_FieldNumber_StartTimeIncluded = 2,
+};
+
+GPB_FINAL @interface GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_StartAtTimeInfo : GPBMessage
+
+@property(nonatomic, readwrite) uint64_t startTimeMs;
+
+@property(nonatomic, readwrite) BOOL startTimeIncluded;
+
+@end
+
+#pragma mark - GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo
+
+typedef GPB_ENUM(GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo_FieldNumber) {
+  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo_FieldNumber_EndTimeMs = 1,
+  GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo_FieldNumber_EndTimeIncluded = 2,
+};
+
+GPB_FINAL @interface GetVotePollsByEndDateRequest_GetVotePollsByEndDateRequestV0_EndAtTimeInfo : GPBMessage
+
+@property(nonatomic, readwrite) uint64_t endTimeMs;
+
+@property(nonatomic, readwrite) BOOL endTimeIncluded;
+
+@end
+
+#pragma mark - GetVotePollsByEndDateResponse
+
+typedef GPB_ENUM(GetVotePollsByEndDateResponse_FieldNumber) {
+  GetVotePollsByEndDateResponse_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetVotePollsByEndDateResponse_Version_OneOfCase) {
+  GetVotePollsByEndDateResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetVotePollsByEndDateResponse_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetVotePollsByEndDateResponse : GPBMessage
+
+@property(nonatomic, readonly) GetVotePollsByEndDateResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0 *v0;
+
+@end
+
 /**
- * Sets the raw value of an @c GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice's @c voteChoiceType property, allowing
- * it to be set to a value that was not defined by the enum at the time the code
- * was generated.
+ * Clears whatever value was set for the oneof 'version'.
  **/
-void SetGetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_VoteChoiceType_RawValue(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice *message, int32_t value);
+void GetVotePollsByEndDateResponse_ClearVersionOneOfCase(GetVotePollsByEndDateResponse *message);
 
-#pragma mark - GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote
+#pragma mark - GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0
 
-typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote_FieldNumber) {
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote_FieldNumber_ContractId = 1,
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote_FieldNumber_DocumentTypeName = 2,
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote_FieldNumber_SerializedIndexStorageValuesArray = 3,
-  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote_FieldNumber_VoteChoice = 4,
+typedef GPB_ENUM(GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_FieldNumber) {
+  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_FieldNumber_VotePollsByTimestamps = 1,
+  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_FieldNumber_Proof = 2,
+  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_FieldNumber_Metadata = 3,
 };
 
-GPB_FINAL @interface GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote : GPBMessage
+typedef GPB_ENUM(GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_Result_OneOfCase) {
+  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_Result_OneOfCase_VotePollsByTimestamps = 1,
+  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_Result_OneOfCase_Proof = 2,
+};
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
+GPB_FINAL @interface GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0 : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSString *documentTypeName;
+@property(nonatomic, readonly) GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_Result_OneOfCase resultOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *serializedIndexStorageValuesArray;
-/** The number of items in @c serializedIndexStorageValuesArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger serializedIndexStorageValuesArray_Count;
+@property(nonatomic, readwrite, strong, null_resettable) GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps *votePollsByTimestamps;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice *voteChoice;
-/** Test to see if @c voteChoice has been set. */
-@property(nonatomic, readwrite) BOOL hasVoteChoice;
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
 
 @end
 
-#pragma mark - GetPrefundedSpecializedBalanceRequest
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_ClearResultOneOfCase(GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0 *message);
 
-typedef GPB_ENUM(GetPrefundedSpecializedBalanceRequest_FieldNumber) {
-  GetPrefundedSpecializedBalanceRequest_FieldNumber_V0 = 1,
+#pragma mark - GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp
+
+typedef GPB_ENUM(GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp_FieldNumber) {
+  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp_FieldNumber_Timestamp = 1,
+  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp_FieldNumber_SerializedVotePollsArray = 2,
 };
 
-typedef GPB_ENUM(GetPrefundedSpecializedBalanceRequest_Version_OneOfCase) {
-  GetPrefundedSpecializedBalanceRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetPrefundedSpecializedBalanceRequest_Version_OneOfCase_V0 = 1,
+GPB_FINAL @interface GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp : GPBMessage
+
+@property(nonatomic, readwrite) uint64_t timestamp;
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *serializedVotePollsArray;
+/** The number of items in @c serializedVotePollsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger serializedVotePollsArray_Count;
+
+@end
+
+#pragma mark - GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps
+
+typedef GPB_ENUM(GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps_FieldNumber) {
+  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps_FieldNumber_VotePollsByTimestampsArray = 1,
+  GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps_FieldNumber_FinishedResults = 2,
 };
 
-GPB_FINAL @interface GetPrefundedSpecializedBalanceRequest : GPBMessage
+GPB_FINAL @interface GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamps : GPBMessage
 
-@property(nonatomic, readonly) GetPrefundedSpecializedBalanceRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetVotePollsByEndDateResponse_GetVotePollsByEndDateResponseV0_SerializedVotePollsByTimestamp*> *votePollsByTimestampsArray;
+/** The number of items in @c votePollsByTimestampsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger votePollsByTimestampsArray_Count;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0 *v0;
+@property(nonatomic, readwrite) BOOL finishedResults;
+
+@end
+
+#pragma mark - GetYesNoAbstainVotePollStateRequest
+
+typedef GPB_ENUM(GetYesNoAbstainVotePollStateRequest_FieldNumber) {
+  GetYesNoAbstainVotePollStateRequest_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetYesNoAbstainVotePollStateRequest_Version_OneOfCase) {
+  GetYesNoAbstainVotePollStateRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetYesNoAbstainVotePollStateRequest_Version_OneOfCase_V0 = 1,
+};
+
+/**
+ * The votes and, once the poll ended, the final tally of a yes/no/abstain vote
+ * poll
+ **/
+GPB_FINAL @interface GetYesNoAbstainVotePollStateRequest : GPBMessage
+
+@property(nonatomic, readonly) GetYesNoAbstainVotePollStateRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetYesNoAbstainVotePollStateRequest_GetYesNoAbstainVotePollStateRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetPrefundedSpecializedBalanceRequest_ClearVersionOneOfCase(GetPrefundedSpecializedBalanceRequest *message);
+void GetYesNoAbstainVotePollStateRequest_ClearVersionOneOfCase(GetYesNoAbstainVotePollStateRequest *message);
 
-#pragma mark - GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0
+#pragma mark - GetYesNoAbstainVotePollStateRequest_GetYesNoAbstainVotePollStateRequestV0
 
-typedef GPB_ENUM(GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0_FieldNumber) {
-  GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0_FieldNumber_Id_p = 1,
-  GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0_FieldNumber_Prove = 2,
+typedef GPB_ENUM(GetYesNoAbstainVotePollStateRequest_GetYesNoAbstainVotePollStateRequestV0_FieldNumber) {
+  GetYesNoAbstainVotePollStateRequest_GetYesNoAbstainVotePollStateRequestV0_FieldNumber_VotePollId = 1,
+  GetYesNoAbstainVotePollStateRequest_GetYesNoAbstainVotePollStateRequestV0_FieldNumber_Prove = 2,
 };
 
-GPB_FINAL @interface GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0 : GPBMessage
+GPB_FINAL @interface GetYesNoAbstainVotePollStateRequest_GetYesNoAbstainVotePollStateRequestV0 : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *id_p;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *votePollId;
 
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetPrefundedSpecializedBalanceResponse
+#pragma mark - GetYesNoAbstainVotePollStateResponse
 
-typedef GPB_ENUM(GetPrefundedSpecializedBalanceResponse_FieldNumber) {
-  GetPrefundedSpecializedBalanceResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetYesNoAbstainVotePollStateResponse_FieldNumber) {
+  GetYesNoAbstainVotePollStateResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetPrefundedSpecializedBalanceResponse_Version_OneOfCase) {
-  GetPrefundedSpecializedBalanceResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetPrefundedSpecializedBalanceResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetYesNoAbstainVotePollStateResponse_Version_OneOfCase) {
+  GetYesNoAbstainVotePollStateResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetYesNoAbstainVotePollStateResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetPrefundedSpecializedBalanceResponse : GPBMessage
+GPB_FINAL @interface GetYesNoAbstainVotePollStateResponse : GPBMessage
+
+@property(nonatomic, readonly) GetYesNoAbstainVotePollStateResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetYesNoAbstainVotePollStateResponse_ClearVersionOneOfCase(GetYesNoAbstainVotePollStateResponse *message);
+
+#pragma mark - GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0
+
+typedef GPB_ENUM(GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FieldNumber) {
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FieldNumber_VotePollState = 1,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FieldNumber_Proof = 2,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FieldNumber_Metadata = 3,
+};
+
+typedef GPB_ENUM(GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_Result_OneOfCase) {
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_Result_OneOfCase_VotePollState = 1,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_Result_OneOfCase_Proof = 2,
+};
+
+GPB_FINAL @interface GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0 : GPBMessage
+
+@property(nonatomic, readonly) GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_Result_OneOfCase resultOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVotePollState *votePollState;
+
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_ClearResultOneOfCase(GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0 *message);
+
+#pragma mark - GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote
+
+typedef GPB_ENUM(GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_FieldNumber) {
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_FieldNumber_ProTxHash = 1,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_FieldNumber_VoteChoice = 2,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_FieldNumber_Strength = 3,
+};
+
+GPB_FINAL @interface GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *proTxHash;
+
+@property(nonatomic, readwrite) GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_VoteChoiceType voteChoice;
+
+@property(nonatomic, readwrite) uint32_t strength;
+
+@end
+
+/**
+ * Fetches the raw value of a @c GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote's @c voteChoice property, even
+ * if the value was not defined by the enum at the time the code was generated.
+ **/
+int32_t GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_VoteChoice_RawValue(GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote *message);
+/**
+ * Sets the raw value of an @c GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote's @c voteChoice property, allowing
+ * it to be set to a value that was not defined by the enum at the time the code
+ * was generated.
+ **/
+void SetGetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote_VoteChoice_RawValue(GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote *message, int32_t value);
+
+#pragma mark - GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FinalTally
+
+typedef GPB_ENUM(GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FinalTally_FieldNumber) {
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FinalTally_FieldNumber_Yes = 1,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FinalTally_FieldNumber_No = 2,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FinalTally_FieldNumber_Abstain = 3,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FinalTally_FieldNumber_FinishedAtBlockHeight = 4,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FinalTally_FieldNumber_FinishedAtTimeMs = 5,
+};
+
+GPB_FINAL @interface GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FinalTally : GPBMessage
+
+@property(nonatomic, readwrite) uint32_t yes;
+
+@property(nonatomic, readwrite) uint32_t no;
+
+@property(nonatomic, readwrite) uint32_t abstain;
+
+@property(nonatomic, readwrite) uint64_t finishedAtBlockHeight;
+
+@property(nonatomic, readwrite) uint64_t finishedAtTimeMs;
+
+@end
+
+#pragma mark - GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVotePollState
+
+typedef GPB_ENUM(GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVotePollState_FieldNumber) {
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVotePollState_FieldNumber_SerializedVotePoll = 1,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVotePollState_FieldNumber_VotesArray = 2,
+  GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVotePollState_FieldNumber_FinalTally = 3,
+};
+
+GPB_FINAL @interface GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVotePollState : GPBMessage
+
+/** The serialized vote poll, not set if no masternode voted on it yet */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *serializedVotePoll;
+/** Test to see if @c serializedVotePoll has been set. */
+@property(nonatomic, readwrite) BOOL hasSerializedVotePoll;
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_YesNoAbstainVote*> *votesArray;
+/** The number of items in @c votesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger votesArray_Count;
+
+/** Only set once the poll ended */
+@property(nonatomic, readwrite, strong, null_resettable) GetYesNoAbstainVotePollStateResponse_GetYesNoAbstainVotePollStateResponseV0_FinalTally *finalTally;
+/** Test to see if @c finalTally has been set. */
+@property(nonatomic, readwrite) BOOL hasFinalTally;
+
+@end
+
+#pragma mark - GetContestedResourceVoteStateRequest
+
+typedef GPB_ENUM(GetContestedResourceVoteStateRequest_FieldNumber) {
+  GetContestedResourceVoteStateRequest_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetContestedResourceVoteStateRequest_Version_OneOfCase) {
+  GetContestedResourceVoteStateRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourceVoteStateRequest_Version_OneOfCase_V0 = 1,
+};
+
+/**
+ * What's the state of a contested resource vote? (ie who is winning?)
+ **/
+GPB_FINAL @interface GetContestedResourceVoteStateRequest : GPBMessage
+
+@property(nonatomic, readonly) GetContestedResourceVoteStateRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetContestedResourceVoteStateRequest_ClearVersionOneOfCase(GetContestedResourceVoteStateRequest *message);
+
+#pragma mark - GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0
+
+typedef GPB_ENUM(GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber) {
+  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_ContractId = 1,
+  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_DocumentTypeName = 2,
+  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_IndexName = 3,
+  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_IndexValuesArray = 4,
+  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_ResultType = 5,
+  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_AllowIncludeLockedAndAbstainingVoteTally = 6,
+  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_StartAtIdentifierInfo = 7,
+  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_Count = 8,
+  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_FieldNumber_Prove = 9,
+};
+
+GPB_FINAL @interface GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0 : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSString *documentTypeName;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSString *indexName;
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *indexValuesArray;
+/** The number of items in @c indexValuesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger indexValuesArray_Count;
+
+@property(nonatomic, readwrite) GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_ResultType resultType;
+
+@property(nonatomic, readwrite) BOOL allowIncludeLockedAndAbstainingVoteTally;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo *startAtIdentifierInfo;
+/** Test to see if @c startAtIdentifierInfo has been set. */
+@property(nonatomic, readwrite) BOOL hasStartAtIdentifierInfo;
+
+@property(nonatomic, readwrite) uint32_t count;
+
+@property(nonatomic, readwrite) BOOL hasCount;
+@property(nonatomic, readwrite) BOOL prove;
+
+@end
+
+/**
+ * Fetches the raw value of a @c GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0's @c resultType property, even
+ * if the value was not defined by the enum at the time the code was generated.
+ **/
+int32_t GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_ResultType_RawValue(GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0 *message);
+/**
+ * Sets the raw value of an @c GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0's @c resultType property, allowing
+ * it to be set to a value that was not defined by the enum at the time the code
+ * was generated.
+ **/
+void SetGetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_ResultType_RawValue(GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0 *message, int32_t value);
+
+#pragma mark - GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo
+
+typedef GPB_ENUM(GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo_FieldNumber) {
+  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo_FieldNumber_StartIdentifier = 1,
+  GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo_FieldNumber_StartIdentifierIncluded = 2,
+};
+
+GPB_FINAL @interface GetContestedResourceVoteStateRequest_GetContestedResourceVoteStateRequestV0_StartAtIdentifierInfo : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *startIdentifier;
+
+@property(nonatomic, readwrite) BOOL startIdentifierIncluded;
+
+@end
+
+#pragma mark - GetContestedResourceVoteStateResponse
+
+typedef GPB_ENUM(GetContestedResourceVoteStateResponse_FieldNumber) {
+  GetContestedResourceVoteStateResponse_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetContestedResourceVoteStateResponse_Version_OneOfCase) {
+  GetContestedResourceVoteStateResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourceVoteStateResponse_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetContestedResourceVoteStateResponse : GPBMessage
+
+@property(nonatomic, readonly) GetContestedResourceVoteStateResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetContestedResourceVoteStateResponse_ClearVersionOneOfCase(GetContestedResourceVoteStateResponse *message);
+
+#pragma mark - GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0
+
+typedef GPB_ENUM(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FieldNumber) {
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FieldNumber_ContestedResourceContenders = 1,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FieldNumber_Proof = 2,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FieldNumber_Metadata = 3,
+};
+
+typedef GPB_ENUM(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Result_OneOfCase) {
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Result_OneOfCase_ContestedResourceContenders = 1,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Result_OneOfCase_Proof = 2,
+};
+
+GPB_FINAL @interface GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0 : GPBMessage
+
+@property(nonatomic, readonly) GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Result_OneOfCase resultOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders *contestedResourceContenders;
+
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ClearResultOneOfCase(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0 *message);
+
+#pragma mark - GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo
+
+typedef GPB_ENUM(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber) {
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_FinishedVoteOutcome = 1,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_WonByIdentityId = 2,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_FinishedAtBlockHeight = 3,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_FinishedAtCoreBlockHeight = 4,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_FinishedAtBlockTimeMs = 5,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FieldNumber_FinishedAtEpoch = 6,
+};
+
+GPB_FINAL @interface GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo : GPBMessage
+
+@property(nonatomic, readwrite) GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FinishedVoteOutcome finishedVoteOutcome;
+
+/** Only used when vote_choice_type is TOWARDS_IDENTITY */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *wonByIdentityId;
+/** Test to see if @c wonByIdentityId has been set. */
+@property(nonatomic, readwrite) BOOL hasWonByIdentityId;
+
+@property(nonatomic, readwrite) uint64_t finishedAtBlockHeight;
+
+@property(nonatomic, readwrite) uint32_t finishedAtCoreBlockHeight;
+
+@property(nonatomic, readwrite) uint64_t finishedAtBlockTimeMs;
+
+@property(nonatomic, readwrite) uint32_t finishedAtEpoch;
+
+@end
+
+/**
+ * Fetches the raw value of a @c GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo's @c finishedVoteOutcome property, even
+ * if the value was not defined by the enum at the time the code was generated.
+ **/
+int32_t GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FinishedVoteOutcome_RawValue(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo *message);
+/**
+ * Sets the raw value of an @c GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo's @c finishedVoteOutcome property, allowing
+ * it to be set to a value that was not defined by the enum at the time the code
+ * was generated.
+ **/
+void SetGetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo_FinishedVoteOutcome_RawValue(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo *message, int32_t value);
+
+#pragma mark - GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders
+
+typedef GPB_ENUM(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders_FieldNumber) {
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders_FieldNumber_ContendersArray = 1,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders_FieldNumber_AbstainVoteTally = 2,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders_FieldNumber_LockVoteTally = 3,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders_FieldNumber_FinishedVoteInfo = 4,
+};
+
+GPB_FINAL @interface GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_ContestedResourceContenders : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender*> *contendersArray;
+/** The number of items in @c contendersArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger contendersArray_Count;
+
+@property(nonatomic, readwrite) uint32_t abstainVoteTally;
+
+@property(nonatomic, readwrite) BOOL hasAbstainVoteTally;
+@property(nonatomic, readwrite) uint32_t lockVoteTally;
+
+@property(nonatomic, readwrite) BOOL hasLockVoteTally;
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_FinishedVoteInfo *finishedVoteInfo;
+/** Test to see if @c finishedVoteInfo has been set. */
+@property(nonatomic, readwrite) BOOL hasFinishedVoteInfo;
+
+@end
+
+#pragma mark - GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender
+
+typedef GPB_ENUM(GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender_FieldNumber) {
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender_FieldNumber_Identifier = 1,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender_FieldNumber_VoteCount = 2,
+  GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender_FieldNumber_Document = 3,
+};
+
+GPB_FINAL @interface GetContestedResourceVoteStateResponse_GetContestedResourceVoteStateResponseV0_Contender : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identifier;
+
+@property(nonatomic, readwrite) uint32_t voteCount;
+
+@property(nonatomic, readwrite) BOOL hasVoteCount;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *document;
+/** Test to see if @c document has been set. */
+@property(nonatomic, readwrite) BOOL hasDocument;
+
+@end
+
+#pragma mark - GetContestedResourceVotersForIdentityRequest
+
+typedef GPB_ENUM(GetContestedResourceVotersForIdentityRequest_FieldNumber) {
+  GetContestedResourceVotersForIdentityRequest_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetContestedResourceVotersForIdentityRequest_Version_OneOfCase) {
+  GetContestedResourceVotersForIdentityRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourceVotersForIdentityRequest_Version_OneOfCase_V0 = 1,
+};
+
+/**
+ * Who voted for a contested resource to go to a specific identity?
+ **/
+GPB_FINAL @interface GetContestedResourceVotersForIdentityRequest : GPBMessage
+
+@property(nonatomic, readonly) GetContestedResourceVotersForIdentityRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetContestedResourceVotersForIdentityRequest_ClearVersionOneOfCase(GetContestedResourceVotersForIdentityRequest *message);
+
+#pragma mark - GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0
+
+typedef GPB_ENUM(GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber) {
+  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_ContractId = 1,
+  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_DocumentTypeName = 2,
+  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_IndexName = 3,
+  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_IndexValuesArray = 4,
+  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_ContestantId = 5,
+  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_StartAtIdentifierInfo = 6,
+  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_Count = 7,
+  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_OrderAscending = 8,
+  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_FieldNumber_Prove = 9,
+};
+
+GPB_FINAL @interface GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0 : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSString *documentTypeName;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSString *indexName;
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *indexValuesArray;
+/** The number of items in @c indexValuesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger indexValuesArray_Count;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *contestantId;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo *startAtIdentifierInfo;
+/** Test to see if @c startAtIdentifierInfo has been set. */
+@property(nonatomic, readwrite) BOOL hasStartAtIdentifierInfo;
+
+@property(nonatomic, readwrite) uint32_t count;
+
+@property(nonatomic, readwrite) BOOL hasCount;
+@property(nonatomic, readwrite) BOOL orderAscending;
+
+@property(nonatomic, readwrite) BOOL prove;
+
+@end
+
+#pragma mark - GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo
+
+typedef GPB_ENUM(GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo_FieldNumber) {
+  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo_FieldNumber_StartIdentifier = 1,
+  GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo_FieldNumber_StartIdentifierIncluded = 2,
+};
+
+GPB_FINAL @interface GetContestedResourceVotersForIdentityRequest_GetContestedResourceVotersForIdentityRequestV0_StartAtIdentifierInfo : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *startIdentifier;
+
+@property(nonatomic, readwrite) BOOL startIdentifierIncluded;
+
+@end
+
+#pragma mark - GetContestedResourceVotersForIdentityResponse
+
+typedef GPB_ENUM(GetContestedResourceVotersForIdentityResponse_FieldNumber) {
+  GetContestedResourceVotersForIdentityResponse_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetContestedResourceVotersForIdentityResponse_Version_OneOfCase) {
+  GetContestedResourceVotersForIdentityResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourceVotersForIdentityResponse_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetContestedResourceVotersForIdentityResponse : GPBMessage
+
+@property(nonatomic, readonly) GetContestedResourceVotersForIdentityResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetContestedResourceVotersForIdentityResponse_ClearVersionOneOfCase(GetContestedResourceVotersForIdentityResponse *message);
+
+#pragma mark - GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0
+
+typedef GPB_ENUM(GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_FieldNumber) {
+  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_FieldNumber_ContestedResourceVoters = 1,
+  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_FieldNumber_Proof = 2,
+  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_FieldNumber_Metadata = 3,
+};
+
+typedef GPB_ENUM(GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_Result_OneOfCase) {
+  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_Result_OneOfCase_ContestedResourceVoters = 1,
+  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_Result_OneOfCase_Proof = 2,
+};
+
+GPB_FINAL @interface GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0 : GPBMessage
+
+@property(nonatomic, readonly) GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_Result_OneOfCase resultOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters *contestedResourceVoters;
+
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ClearResultOneOfCase(GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0 *message);
+
+#pragma mark - GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters
+
+typedef GPB_ENUM(GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters_FieldNumber) {
+  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters_FieldNumber_VotersArray = 1,
+  GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters_FieldNumber_FinishedResults = 2,
+};
+
+GPB_FINAL @interface GetContestedResourceVotersForIdentityResponse_GetContestedResourceVotersForIdentityResponseV0_ContestedResourceVoters : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *votersArray;
+/** The number of items in @c votersArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger votersArray_Count;
+
+@property(nonatomic, readwrite) BOOL finishedResults;
+
+@end
+
+#pragma mark - GetContestedResourceIdentityVotesRequest
+
+typedef GPB_ENUM(GetContestedResourceIdentityVotesRequest_FieldNumber) {
+  GetContestedResourceIdentityVotesRequest_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetContestedResourceIdentityVotesRequest_Version_OneOfCase) {
+  GetContestedResourceIdentityVotesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourceIdentityVotesRequest_Version_OneOfCase_V0 = 1,
+};
+
+/**
+ * How did an identity vote?
+ **/
+GPB_FINAL @interface GetContestedResourceIdentityVotesRequest : GPBMessage
+
+@property(nonatomic, readonly) GetContestedResourceIdentityVotesRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetContestedResourceIdentityVotesRequest_ClearVersionOneOfCase(GetContestedResourceIdentityVotesRequest *message);
+
+#pragma mark - GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0
+
+typedef GPB_ENUM(GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber) {
+  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_IdentityId = 1,
+  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_Limit = 2,
+  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_Offset = 3,
+  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_OrderAscending = 4,
+  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_StartAtVotePollIdInfo = 5,
+  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_FieldNumber_Prove = 6,
+};
+
+GPB_FINAL @interface GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0 : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
+
+@property(nonatomic, readwrite, strong, null_resettable) GPBUInt32Value *limit;
+/** Test to see if @c limit has been set. */
+@property(nonatomic, readwrite) BOOL hasLimit;
+
+@property(nonatomic, readwrite, strong, null_resettable) GPBUInt32Value *offset;
+/** Test to see if @c offset has been set. */
+@property(nonatomic, readwrite) BOOL hasOffset;
+
+@property(nonatomic, readwrite) BOOL orderAscending;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo *startAtVotePollIdInfo;
+/** Test to see if @c startAtVotePollIdInfo has been set. */
+@property(nonatomic, readwrite) BOOL hasStartAtVotePollIdInfo;
+
+@property(nonatomic, readwrite) BOOL prove;
+
+@end
+
+#pragma mark - GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo
+
+typedef GPB_ENUM(GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo_FieldNumber) {
+  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo_FieldNumber_StartAtPollIdentifier = 1,
+  GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo_FieldNumber_StartPollIdentifierIncluded = 2,
+};
+
+GPB_FINAL @interface GetContestedResourceIdentityVotesRequest_GetContestedResourceIdentityVotesRequestV0_StartAtVotePollIdInfo : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *startAtPollIdentifier;
+
+@property(nonatomic, readwrite) BOOL startPollIdentifierIncluded;
+
+@end
+
+#pragma mark - GetContestedResourceIdentityVotesResponse
+
+typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_FieldNumber) {
+  GetContestedResourceIdentityVotesResponse_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_Version_OneOfCase) {
+  GetContestedResourceIdentityVotesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourceIdentityVotesResponse_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetContestedResourceIdentityVotesResponse : GPBMessage
+
+@property(nonatomic, readonly) GetContestedResourceIdentityVotesResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetContestedResourceIdentityVotesResponse_ClearVersionOneOfCase(GetContestedResourceIdentityVotesResponse *message);
+
+#pragma mark - GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0
+
+typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_FieldNumber) {
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_FieldNumber_Votes = 1,
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_FieldNumber_Proof = 2,
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_FieldNumber_Metadata = 3,
+};
+
+typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_Result_OneOfCase) {
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_Result_OneOfCase_Votes = 1,
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_Result_OneOfCase_Proof = 2,
+};
+
+GPB_FINAL @interface GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0 : GPBMessage
+
+@property(nonatomic, readonly) GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_Result_OneOfCase resultOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes *votes;
+
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ClearResultOneOfCase(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0 *message);
+
+#pragma mark - GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes
+
+typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes_FieldNumber) {
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes_FieldNumber_ContestedResourceIdentityVotesArray = 1,
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes_FieldNumber_FinishedResults = 2,
+};
+
+GPB_FINAL @interface GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVotes : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote*> *contestedResourceIdentityVotesArray;
+/** The number of items in @c contestedResourceIdentityVotesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger contestedResourceIdentityVotesArray_Count;
+
+@property(nonatomic, readwrite) BOOL finishedResults;
+
+@end
+
+#pragma mark - GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice
+
+typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_FieldNumber) {
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_FieldNumber_VoteChoiceType = 1,
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_FieldNumber_IdentityId = 2,
+};
+
+GPB_FINAL @interface GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice : GPBMessage
+
+@property(nonatomic, readwrite) GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_VoteChoiceType voteChoiceType;
+
+/** Only used when vote_choice_type is TOWARDS_IDENTITY */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
+/** Test to see if @c identityId has been set. */
+@property(nonatomic, readwrite) BOOL hasIdentityId;
+
+@end
+
+/**
+ * Fetches the raw value of a @c GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice's @c voteChoiceType property, even
+ * if the value was not defined by the enum at the time the code was generated.
+ **/
+int32_t GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_VoteChoiceType_RawValue(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice *message);
+/**
+ * Sets the raw value of an @c GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice's @c voteChoiceType property, allowing
+ * it to be set to a value that was not defined by the enum at the time the code
+ * was generated.
+ **/
+void SetGetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice_VoteChoiceType_RawValue(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice *message, int32_t value);
+
+#pragma mark - GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote
+
+typedef GPB_ENUM(GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote_FieldNumber) {
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote_FieldNumber_ContractId = 1,
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote_FieldNumber_DocumentTypeName = 2,
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote_FieldNumber_SerializedIndexStorageValuesArray = 3,
+  GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote_FieldNumber_VoteChoice = 4,
+};
+
+GPB_FINAL @interface GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ContestedResourceIdentityVote : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSString *documentTypeName;
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *serializedIndexStorageValuesArray;
+/** The number of items in @c serializedIndexStorageValuesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger serializedIndexStorageValuesArray_Count;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetContestedResourceIdentityVotesResponse_GetContestedResourceIdentityVotesResponseV0_ResourceVoteChoice *voteChoice;
+/** Test to see if @c voteChoice has been set. */
+@property(nonatomic, readwrite) BOOL hasVoteChoice;
+
+@end
+
+#pragma mark - GetPrefundedSpecializedBalanceRequest
+
+typedef GPB_ENUM(GetPrefundedSpecializedBalanceRequest_FieldNumber) {
+  GetPrefundedSpecializedBalanceRequest_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetPrefundedSpecializedBalanceRequest_Version_OneOfCase) {
+  GetPrefundedSpecializedBalanceRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetPrefundedSpecializedBalanceRequest_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetPrefundedSpecializedBalanceRequest : GPBMessage
+
+@property(nonatomic, readonly) GetPrefundedSpecializedBalanceRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetPrefundedSpecializedBalanceRequest_ClearVersionOneOfCase(GetPrefundedSpecializedBalanceRequest *message);
+
+#pragma mark - GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0
+
+typedef GPB_ENUM(GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0_FieldNumber) {
+  GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0_FieldNumber_Id_p = 1,
+  GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0_FieldNumber_Prove = 2,
+};
+
+GPB_FINAL @interface GetPrefundedSpecializedBalanceRequest_GetPrefundedSpecializedBalanceRequestV0 : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *id_p;
+
+@property(nonatomic, readwrite) BOOL prove;
+
+@end
+
+#pragma mark - GetPrefundedSpecializedBalanceResponse
+
+typedef GPB_ENUM(GetPrefundedSpecializedBalanceResponse_FieldNumber) {
+  GetPrefundedSpecializedBalanceResponse_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetPrefundedSpecializedBalanceResponse_Version_OneOfCase) {
+  GetPrefundedSpecializedBalanceResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetPrefundedSpecializedBalanceResponse_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetPrefundedSpecializedBalanceResponse : GPBMessage
+
+@property(nonatomic, readonly) GetPrefundedSpecializedBalanceResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetPrefundedSpecializedBalanceResponse_ClearVersionOneOfCase(GetPrefundedSpecializedBalanceResponse *message);
+
+#pragma mark - GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0
+
+typedef GPB_ENUM(GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_FieldNumber) {
+  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_FieldNumber_Balance = 1,
+  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_FieldNumber_Proof = 2,
+  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_FieldNumber_Metadata = 3,
+};
+
+typedef GPB_ENUM(GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_Result_OneOfCase) {
+  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_Result_OneOfCase_Balance = 1,
+  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_Result_OneOfCase_Proof = 2,
+};
+
+GPB_FINAL @interface GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0 : GPBMessage
+
+@property(nonatomic, readonly) GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_Result_OneOfCase resultOneOfCase;
+
+@property(nonatomic, readwrite) uint64_t balance;
+
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_ClearResultOneOfCase(GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0 *message);
+
+#pragma mark - GetTotalCreditsInPlatformRequest
+
+typedef GPB_ENUM(GetTotalCreditsInPlatformRequest_FieldNumber) {
+  GetTotalCreditsInPlatformRequest_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetTotalCreditsInPlatformRequest_Version_OneOfCase) {
+  GetTotalCreditsInPlatformRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTotalCreditsInPlatformRequest_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetTotalCreditsInPlatformRequest : GPBMessage
+
+@property(nonatomic, readonly) GetTotalCreditsInPlatformRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetTotalCreditsInPlatformRequest_GetTotalCreditsInPlatformRequestV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetTotalCreditsInPlatformRequest_ClearVersionOneOfCase(GetTotalCreditsInPlatformRequest *message);
+
+#pragma mark - GetTotalCreditsInPlatformRequest_GetTotalCreditsInPlatformRequestV0
+
+typedef GPB_ENUM(GetTotalCreditsInPlatformRequest_GetTotalCreditsInPlatformRequestV0_FieldNumber) {
+  GetTotalCreditsInPlatformRequest_GetTotalCreditsInPlatformRequestV0_FieldNumber_Prove = 1,
+};
+
+GPB_FINAL @interface GetTotalCreditsInPlatformRequest_GetTotalCreditsInPlatformRequestV0 : GPBMessage
+
+@property(nonatomic, readwrite) BOOL prove;
+
+@end
+
+#pragma mark - GetTotalCreditsInPlatformResponse
+
+typedef GPB_ENUM(GetTotalCreditsInPlatformResponse_FieldNumber) {
+  GetTotalCreditsInPlatformResponse_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetTotalCreditsInPlatformResponse_Version_OneOfCase) {
+  GetTotalCreditsInPlatformResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTotalCreditsInPlatformResponse_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetTotalCreditsInPlatformResponse : GPBMessage
+
+@property(nonatomic, readonly) GetTotalCreditsInPlatformResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetTotalCreditsInPlatformResponse_ClearVersionOneOfCase(GetTotalCreditsInPlatformResponse *message);
+
+#pragma mark - GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0
+
+typedef GPB_ENUM(GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_FieldNumber) {
+  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_FieldNumber_Credits = 1,
+  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_FieldNumber_Proof = 2,
+  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_FieldNumber_Metadata = 3,
+};
+
+typedef GPB_ENUM(GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_Result_OneOfCase) {
+  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_Result_OneOfCase_Credits = 1,
+  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_Result_OneOfCase_Proof = 2,
+};
+
+GPB_FINAL @interface GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0 : GPBMessage
+
+@property(nonatomic, readonly) GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_Result_OneOfCase resultOneOfCase;
+
+@property(nonatomic, readwrite) uint64_t credits;
+
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_ClearResultOneOfCase(GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0 *message);
+
+#pragma mark - GetPathElementsRequest
+
+typedef GPB_ENUM(GetPathElementsRequest_FieldNumber) {
+  GetPathElementsRequest_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetPathElementsRequest_Version_OneOfCase) {
+  GetPathElementsRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetPathElementsRequest_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetPathElementsRequest : GPBMessage
+
+@property(nonatomic, readonly) GetPathElementsRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetPathElementsRequest_GetPathElementsRequestV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetPathElementsRequest_ClearVersionOneOfCase(GetPathElementsRequest *message);
+
+#pragma mark - GetPathElementsRequest_GetPathElementsRequestV0
+
+typedef GPB_ENUM(GetPathElementsRequest_GetPathElementsRequestV0_FieldNumber) {
+  GetPathElementsRequest_GetPathElementsRequestV0_FieldNumber_PathArray = 1,
+  GetPathElementsRequest_GetPathElementsRequestV0_FieldNumber_KeysArray = 2,
+  GetPathElementsRequest_GetPathElementsRequestV0_FieldNumber_Prove = 3,
+};
+
+GPB_FINAL @interface GetPathElementsRequest_GetPathElementsRequestV0 : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *pathArray;
+/** The number of items in @c pathArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger pathArray_Count;
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *keysArray;
+/** The number of items in @c keysArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger keysArray_Count;
+
+@property(nonatomic, readwrite) BOOL prove;
+
+@end
+
+#pragma mark - GetPathElementsResponse
+
+typedef GPB_ENUM(GetPathElementsResponse_FieldNumber) {
+  GetPathElementsResponse_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetPathElementsResponse_Version_OneOfCase) {
+  GetPathElementsResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetPathElementsResponse_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetPathElementsResponse : GPBMessage
+
+@property(nonatomic, readonly) GetPathElementsResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetPathElementsResponse_GetPathElementsResponseV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetPathElementsResponse_ClearVersionOneOfCase(GetPathElementsResponse *message);
+
+#pragma mark - GetPathElementsResponse_GetPathElementsResponseV0
+
+typedef GPB_ENUM(GetPathElementsResponse_GetPathElementsResponseV0_FieldNumber) {
+  GetPathElementsResponse_GetPathElementsResponseV0_FieldNumber_Elements = 1,
+  GetPathElementsResponse_GetPathElementsResponseV0_FieldNumber_Proof = 2,
+  GetPathElementsResponse_GetPathElementsResponseV0_FieldNumber_Metadata = 3,
+};
+
+typedef GPB_ENUM(GetPathElementsResponse_GetPathElementsResponseV0_Result_OneOfCase) {
+  GetPathElementsResponse_GetPathElementsResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetPathElementsResponse_GetPathElementsResponseV0_Result_OneOfCase_Elements = 1,
+  GetPathElementsResponse_GetPathElementsResponseV0_Result_OneOfCase_Proof = 2,
+};
+
+GPB_FINAL @interface GetPathElementsResponse_GetPathElementsResponseV0 : GPBMessage
+
+@property(nonatomic, readonly) GetPathElementsResponse_GetPathElementsResponseV0_Result_OneOfCase resultOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetPathElementsResponse_GetPathElementsResponseV0_Elements *elements;
+
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetPathElementsResponse_GetPathElementsResponseV0_ClearResultOneOfCase(GetPathElementsResponse_GetPathElementsResponseV0 *message);
+
+#pragma mark - GetPathElementsResponse_GetPathElementsResponseV0_Elements
+
+typedef GPB_ENUM(GetPathElementsResponse_GetPathElementsResponseV0_Elements_FieldNumber) {
+  GetPathElementsResponse_GetPathElementsResponseV0_Elements_FieldNumber_ElementsArray = 1,
+};
+
+GPB_FINAL @interface GetPathElementsResponse_GetPathElementsResponseV0_Elements : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *elementsArray;
+/** The number of items in @c elementsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger elementsArray_Count;
+
+@end
+
+#pragma mark - GetStatusRequest
+
+typedef GPB_ENUM(GetStatusRequest_FieldNumber) {
+  GetStatusRequest_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetStatusRequest_Version_OneOfCase) {
+  GetStatusRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetStatusRequest_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetStatusRequest : GPBMessage
+
+@property(nonatomic, readonly) GetStatusRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusRequest_GetStatusRequestV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetStatusRequest_ClearVersionOneOfCase(GetStatusRequest *message);
+
+#pragma mark - GetStatusRequest_GetStatusRequestV0
+
+GPB_FINAL @interface GetStatusRequest_GetStatusRequestV0 : GPBMessage
+
+@end
+
+#pragma mark - GetStatusResponse
+
+typedef GPB_ENUM(GetStatusResponse_FieldNumber) {
+  GetStatusResponse_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetStatusResponse_Version_OneOfCase) {
+  GetStatusResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetStatusResponse_Version_OneOfCase_V0 = 1,
+};
+
+GPB_FINAL @interface GetStatusResponse : GPBMessage
+
+@property(nonatomic, readonly) GetStatusResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetStatusResponse_ClearVersionOneOfCase(GetStatusResponse *message);
+
+#pragma mark - GetStatusResponse_GetStatusResponseV0
+
+typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_FieldNumber) {
+  GetStatusResponse_GetStatusResponseV0_FieldNumber_Version = 1,
+  GetStatusResponse_GetStatusResponseV0_FieldNumber_Node = 2,
+  GetStatusResponse_GetStatusResponseV0_FieldNumber_Chain = 3,
+  GetStatusResponse_GetStatusResponseV0_FieldNumber_Network = 4,
+  GetStatusResponse_GetStatusResponseV0_FieldNumber_StateSync = 5,
+  GetStatusResponse_GetStatusResponseV0_FieldNumber_Time = 6,
+};
+
+GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0 : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Version *version;
+/** Test to see if @c version has been set. */
+@property(nonatomic, readwrite) BOOL hasVersion;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Node *node;
+/** Test to see if @c node has been set. */
+@property(nonatomic, readwrite) BOOL hasNode;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Chain *chain;
+/** Test to see if @c chain has been set. */
+@property(nonatomic, readwrite) BOOL hasChain;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Network *network;
+/** Test to see if @c network has been set. */
+@property(nonatomic, readwrite) BOOL hasNetwork;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_StateSync *stateSync;
+/** Test to see if @c stateSync has been set. */
+@property(nonatomic, readwrite) BOOL hasStateSync;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Time *time;
+/** Test to see if @c time has been set. */
+@property(nonatomic, readwrite) BOOL hasTime;
+
+@end
+
+#pragma mark - GetStatusResponse_GetStatusResponseV0_Version
+
+typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Version_FieldNumber) {
+  GetStatusResponse_GetStatusResponseV0_Version_FieldNumber_Software = 1,
+  GetStatusResponse_GetStatusResponseV0_Version_FieldNumber_Protocol = 2,
+};
+
+GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Version : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Version_Software *software;
+/** Test to see if @c software has been set. */
+@property(nonatomic, readwrite) BOOL hasSoftware;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Version_Protocol *protocol;
+/** Test to see if @c protocol has been set. */
+@property(nonatomic, readwrite) BOOL hasProtocol;
+
+@end
+
+#pragma mark - GetStatusResponse_GetStatusResponseV0_Version_Software
+
+typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Version_Software_FieldNumber) {
+  GetStatusResponse_GetStatusResponseV0_Version_Software_FieldNumber_Dapi = 1,
+  GetStatusResponse_GetStatusResponseV0_Version_Software_FieldNumber_Drive = 2,
+  GetStatusResponse_GetStatusResponseV0_Version_Software_FieldNumber_Tenderdash = 3,
+};
+
+GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Version_Software : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSString *dapi;
+
+/** It will be missing if Drive is not responding */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *drive;
+/** Test to see if @c drive has been set. */
+@property(nonatomic, readwrite) BOOL hasDrive;
+
+/** It will be missing if Tenderdash is not responding */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *tenderdash;
+/** Test to see if @c tenderdash has been set. */
+@property(nonatomic, readwrite) BOOL hasTenderdash;
+
+@end
+
+#pragma mark - GetStatusResponse_GetStatusResponseV0_Version_Protocol
+
+typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Version_Protocol_FieldNumber) {
+  GetStatusResponse_GetStatusResponseV0_Version_Protocol_FieldNumber_Tenderdash = 1,
+  GetStatusResponse_GetStatusResponseV0_Version_Protocol_FieldNumber_Drive = 2,
+};
+
+GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Version_Protocol : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash *tenderdash;
+/** Test to see if @c tenderdash has been set. */
+@property(nonatomic, readwrite) BOOL hasTenderdash;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive *drive;
+/** Test to see if @c drive has been set. */
+@property(nonatomic, readwrite) BOOL hasDrive;
+
+@end
+
+#pragma mark - GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash
+
+typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash_FieldNumber) {
+  GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash_FieldNumber_P2P = 1,
+  GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash_FieldNumber_Block = 2,
+};
+
+GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash : GPBMessage
+
+@property(nonatomic, readwrite) uint32_t p2P;
+
+@property(nonatomic, readwrite) uint32_t block;
+
+@end
+
+#pragma mark - GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive
+
+typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive_FieldNumber) {
+  GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive_FieldNumber_Latest = 3,
+  GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive_FieldNumber_Current = 4,
+  GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive_FieldNumber_NextEpoch = 5,
+};
+
+GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive : GPBMessage
+
+/** Highest protocol version supported by this node */
+@property(nonatomic, readwrite) uint32_t latest;
+
+/** Protocol version used in current epoch */
+@property(nonatomic, readwrite) uint32_t current;
+
+/** Protocol version that will be used in the next epoch */
+@property(nonatomic, readwrite) uint32_t nextEpoch;
+
+@end
+
+#pragma mark - GetStatusResponse_GetStatusResponseV0_Time
+
+typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Time_FieldNumber) {
+  GetStatusResponse_GetStatusResponseV0_Time_FieldNumber_Local = 1,
+  GetStatusResponse_GetStatusResponseV0_Time_FieldNumber_Block = 2,
+  GetStatusResponse_GetStatusResponseV0_Time_FieldNumber_Genesis = 3,
+  GetStatusResponse_GetStatusResponseV0_Time_FieldNumber_Epoch = 4,
+};
+
+GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Time : GPBMessage
+
+@property(nonatomic, readwrite) uint64_t local;
+
+/** It will be missing if Drive is not responding */
+@property(nonatomic, readwrite) uint64_t block;
+
+@property(nonatomic, readwrite) BOOL hasBlock;
+/** It will be missing if Drive is not responding */
+@property(nonatomic, readwrite) uint64_t genesis;
+
+@property(nonatomic, readwrite) BOOL hasGenesis;
+/** It will be missing if Drive is not responding */
+@property(nonatomic, readwrite) uint32_t epoch;
+
+@property(nonatomic, readwrite) BOOL hasEpoch;
+@end
+
+#pragma mark - GetStatusResponse_GetStatusResponseV0_Node
+
+typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Node_FieldNumber) {
+  GetStatusResponse_GetStatusResponseV0_Node_FieldNumber_Id_p = 1,
+  GetStatusResponse_GetStatusResponseV0_Node_FieldNumber_ProTxHash = 2,
+};
+
+GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Node : GPBMessage
+
+/** Platform node ID */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *id_p;
+
+/** Evo masternode pro tx hash. It will be absent if the node is a fullnode */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *proTxHash;
+/** Test to see if @c proTxHash has been set. */
+@property(nonatomic, readwrite) BOOL hasProTxHash;
+
+@end
+
+#pragma mark - GetStatusResponse_GetStatusResponseV0_Chain
+
+typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber) {
+  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_CatchingUp = 1,
+  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_LatestBlockHash = 2,
+  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_LatestAppHash = 3,
+  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_LatestBlockHeight = 4,
+  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_EarliestBlockHash = 5,
+  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_EarliestAppHash = 6,
+  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_EarliestBlockHeight = 7,
+  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_MaxPeerBlockHeight = 9,
+  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_CoreChainLockedHeight = 10,
+};
+
+GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Chain : GPBMessage
+
+@property(nonatomic, readwrite) BOOL catchingUp;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *latestBlockHash;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *latestAppHash;
+
+@property(nonatomic, readwrite) uint64_t latestBlockHeight;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *earliestBlockHash;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *earliestAppHash;
+
+@property(nonatomic, readwrite) uint64_t earliestBlockHeight;
+
+@property(nonatomic, readwrite) uint64_t maxPeerBlockHeight;
+
+/**
+ * Latest known core height in consensus.
+ * It will be missing if Drive is not responding
+ **/
+@property(nonatomic, readwrite) uint32_t coreChainLockedHeight;
+
+@property(nonatomic, readwrite) BOOL hasCoreChainLockedHeight;
+@end
+
+#pragma mark - GetStatusResponse_GetStatusResponseV0_Network
+
+typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Network_FieldNumber) {
+  GetStatusResponse_GetStatusResponseV0_Network_FieldNumber_ChainId = 1,
+  GetStatusResponse_GetStatusResponseV0_Network_FieldNumber_PeersCount = 2,
+  GetStatusResponse_GetStatusResponseV0_Network_FieldNumber_Listening = 3,
+};
+
+GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Network : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSString *chainId;
+
+@property(nonatomic, readwrite) uint32_t peersCount;
+
+@property(nonatomic, readwrite) BOOL listening;
+
+@end
+
+#pragma mark - GetStatusResponse_GetStatusResponseV0_StateSync
+
+typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber) {
+  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_TotalSyncedTime = 1,
+  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_RemainingTime = 2,
+  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_TotalSnapshots = 3,
+  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_ChunkProcessAvgTime = 4,
+  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_SnapshotHeight = 5,
+  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_SnapshotChunksCount = 6,
+  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_BackfilledBlocks = 7,
+  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_BackfillBlocksTotal = 8,
+};
+
+GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_StateSync : GPBMessage
+
+@property(nonatomic, readwrite) uint64_t totalSyncedTime;
+
+@property(nonatomic, readwrite) uint64_t remainingTime;
+
+@property(nonatomic, readwrite) uint32_t totalSnapshots;
+
+@property(nonatomic, readwrite) uint64_t chunkProcessAvgTime;
+
+@property(nonatomic, readwrite) uint64_t snapshotHeight;
+
+@property(nonatomic, readwrite) uint64_t snapshotChunksCount;
 
-@property(nonatomic, readonly) GetPrefundedSpecializedBalanceResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readwrite) uint64_t backfilledBlocks;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0 *v0;
+@property(nonatomic, readwrite) uint64_t backfillBlocksTotal;
 
 @end
 
-/**
- * Clears whatever value was set for the oneof 'version'.
- **/
-void GetPrefundedSpecializedBalanceResponse_ClearVersionOneOfCase(GetPrefundedSpecializedBalanceResponse *message);
-
-#pragma mark - GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0
+#pragma mark - GetCurrentQuorumsInfoRequest
 
-typedef GPB_ENUM(GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_FieldNumber) {
-  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_FieldNumber_Balance = 1,
-  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_FieldNumber_Proof = 2,
-  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetCurrentQuorumsInfoRequest_FieldNumber) {
+  GetCurrentQuorumsInfoRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_Result_OneOfCase) {
-  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_Result_OneOfCase_Balance = 1,
-  GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetCurrentQuorumsInfoRequest_Version_OneOfCase) {
+  GetCurrentQuorumsInfoRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetCurrentQuorumsInfoRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0 : GPBMessage
-
-@property(nonatomic, readonly) GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_Result_OneOfCase resultOneOfCase;
-
-@property(nonatomic, readwrite) uint64_t balance;
+GPB_FINAL @interface GetCurrentQuorumsInfoRequest : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+@property(nonatomic, readonly) GetCurrentQuorumsInfoRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
-/** Test to see if @c metadata has been set. */
-@property(nonatomic, readwrite) BOOL hasMetadata;
+@property(nonatomic, readwrite, strong, null_resettable) GetCurrentQuorumsInfoRequest_GetCurrentQuorumsInfoRequestV0 *v0;
 
 @end
 
 /**
- * Clears whatever value was set for the oneof 'result'.
+ * Clears whatever value was set for the oneof 'version'.
  **/
-void GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0_ClearResultOneOfCase(GetPrefundedSpecializedBalanceResponse_GetPrefundedSpecializedBalanceResponseV0 *message);
+void GetCurrentQuorumsInfoRequest_ClearVersionOneOfCase(GetCurrentQuorumsInfoRequest *message);
 
-#pragma mark - GetTotalCreditsInPlatformRequest
+#pragma mark - GetCurrentQuorumsInfoRequest_GetCurrentQuorumsInfoRequestV0
 
-typedef GPB_ENUM(GetTotalCreditsInPlatformRequest_FieldNumber) {
-  GetTotalCreditsInPlatformRequest_FieldNumber_V0 = 1,
+GPB_FINAL @interface GetCurrentQuorumsInfoRequest_GetCurrentQuorumsInfoRequestV0 : GPBMessage
+
+@end
+
+#pragma mark - GetCurrentQuorumsInfoResponse
+
+typedef GPB_ENUM(GetCurrentQuorumsInfoResponse_FieldNumber) {
+  GetCurrentQuorumsInfoResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetTotalCreditsInPlatformRequest_Version_OneOfCase) {
-  GetTotalCreditsInPlatformRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTotalCreditsInPlatformRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetCurrentQuorumsInfoResponse_Version_OneOfCase) {
+  GetCurrentQuorumsInfoResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetCurrentQuorumsInfoResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetTotalCreditsInPlatformRequest : GPBMessage
+GPB_FINAL @interface GetCurrentQuorumsInfoResponse : GPBMessage
 
-@property(nonatomic, readonly) GetTotalCreditsInPlatformRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetCurrentQuorumsInfoResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTotalCreditsInPlatformRequest_GetTotalCreditsInPlatformRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTotalCreditsInPlatformRequest_ClearVersionOneOfCase(GetTotalCreditsInPlatformRequest *message);
+void GetCurrentQuorumsInfoResponse_ClearVersionOneOfCase(GetCurrentQuorumsInfoResponse *message);
 
-#pragma mark - GetTotalCreditsInPlatformRequest_GetTotalCreditsInPlatformRequestV0
+#pragma mark - GetCurrentQuorumsInfoResponse_ValidatorV0
 
-typedef GPB_ENUM(GetTotalCreditsInPlatformRequest_GetTotalCreditsInPlatformRequestV0_FieldNumber) {
-  GetTotalCreditsInPlatformRequest_GetTotalCreditsInPlatformRequestV0_FieldNumber_Prove = 1,
+typedef GPB_ENUM(GetCurrentQuorumsInfoResponse_ValidatorV0_FieldNumber) {
+  GetCurrentQuorumsInfoResponse_ValidatorV0_FieldNumber_ProTxHash = 1,
+  GetCurrentQuorumsInfoResponse_ValidatorV0_FieldNumber_NodeIp = 2,
+  GetCurrentQuorumsInfoResponse_ValidatorV0_FieldNumber_IsBanned = 3,
 };
 
-GPB_FINAL @interface GetTotalCreditsInPlatformRequest_GetTotalCreditsInPlatformRequestV0 : GPBMessage
+GPB_FINAL @interface GetCurrentQuorumsInfoResponse_ValidatorV0 : GPBMessage
 
-@property(nonatomic, readwrite) BOOL prove;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *proTxHash;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSString *nodeIp;
+
+@property(nonatomic, readwrite) BOOL isBanned;
 
 @end
 
-#pragma mark - GetTotalCreditsInPlatformResponse
+#pragma mark - GetCurrentQuorumsInfoResponse_ValidatorSetV0
 
-typedef GPB_ENUM(GetTotalCreditsInPlatformResponse_FieldNumber) {
-  GetTotalCreditsInPlatformResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetCurrentQuorumsInfoResponse_ValidatorSetV0_FieldNumber) {
+  GetCurrentQuorumsInfoResponse_ValidatorSetV0_FieldNumber_QuorumHash = 1,
+  GetCurrentQuorumsInfoResponse_ValidatorSetV0_FieldNumber_CoreHeight = 2,
+  GetCurrentQuorumsInfoResponse_ValidatorSetV0_FieldNumber_MembersArray = 3,
+  GetCurrentQuorumsInfoResponse_ValidatorSetV0_FieldNumber_ThresholdPublicKey = 4,
 };
 
-typedef GPB_ENUM(GetTotalCreditsInPlatformResponse_Version_OneOfCase) {
-  GetTotalCreditsInPlatformResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTotalCreditsInPlatformResponse_Version_OneOfCase_V0 = 1,
-};
+GPB_FINAL @interface GetCurrentQuorumsInfoResponse_ValidatorSetV0 : GPBMessage
 
-GPB_FINAL @interface GetTotalCreditsInPlatformResponse : GPBMessage
+@property(nonatomic, readwrite, copy, null_resettable) NSData *quorumHash;
 
-@property(nonatomic, readonly) GetTotalCreditsInPlatformResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readwrite) uint32_t coreHeight;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetCurrentQuorumsInfoResponse_ValidatorV0*> *membersArray;
+/** The number of items in @c membersArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger membersArray_Count;
 
-@end
+@property(nonatomic, readwrite, copy, null_resettable) NSData *thresholdPublicKey;
 
-/**
- * Clears whatever value was set for the oneof 'version'.
- **/
-void GetTotalCreditsInPlatformResponse_ClearVersionOneOfCase(GetTotalCreditsInPlatformResponse *message);
+@end
 
-#pragma mark - GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0
+#pragma mark - GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0
 
-typedef GPB_ENUM(GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_FieldNumber) {
-  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_FieldNumber_Credits = 1,
-  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_FieldNumber_Proof = 2,
-  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber) {
+  GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber_QuorumHashesArray = 1,
+  GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber_CurrentQuorumHash = 2,
+  GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber_ValidatorSetsArray = 3,
+  GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber_LastBlockProposer = 4,
+  GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber_Metadata = 5,
 };
 
-typedef GPB_ENUM(GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_Result_OneOfCase) {
-  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_Result_OneOfCase_Credits = 1,
-  GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_Result_OneOfCase_Proof = 2,
-};
+GPB_FINAL @interface GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0 : GPBMessage
 
-GPB_FINAL @interface GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0 : GPBMessage
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *quorumHashesArray;
+/** The number of items in @c quorumHashesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger quorumHashesArray_Count;
 
-@property(nonatomic, readonly) GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *currentQuorumHash;
 
-@property(nonatomic, readwrite) uint64_t credits;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetCurrentQuorumsInfoResponse_ValidatorSetV0*> *validatorSetsArray;
+/** The number of items in @c validatorSetsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger validatorSetsArray_Count;
 
-@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *lastBlockProposer;
 
 @property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
 /** Test to see if @c metadata has been set. */
@@ -4600,678 +6393,661 @@ GPB_FINAL @interface GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatform
 
 @end
 
-/**
- * Clears whatever value was set for the oneof 'result'.
- **/
-void GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0_ClearResultOneOfCase(GetTotalCreditsInPlatformResponse_GetTotalCreditsInPlatformResponseV0 *message);
-
-#pragma mark - GetPathElementsRequest
+#pragma mark - GetIdentityTokenBalancesRequest
 
-typedef GPB_ENUM(GetPathElementsRequest_FieldNumber) {
-  GetPathElementsRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetIdentityTokenBalancesRequest_FieldNumber) {
+  GetIdentityTokenBalancesRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetPathElementsRequest_Version_OneOfCase) {
-  GetPathElementsRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetPathElementsRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetIdentityTokenBalancesRequest_Version_OneOfCase) {
+  GetIdentityTokenBalancesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityTokenBalancesRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetPathElementsRequest : GPBMessage
+GPB_FINAL @interface GetIdentityTokenBalancesRequest : GPBMessage
 
-@property(nonatomic, readonly) GetPathElementsRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetIdentityTokenBalancesRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetPathElementsRequest_GetPathElementsRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetPathElementsRequest_ClearVersionOneOfCase(GetPathElementsRequest *message);
+void GetIdentityTokenBalancesRequest_ClearVersionOneOfCase(GetIdentityTokenBalancesRequest *message);
 
-#pragma mark - GetPathElementsRequest_GetPathElementsRequestV0
+#pragma mark - GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0
 
-typedef GPB_ENUM(GetPathElementsRequest_GetPathElementsRequestV0_FieldNumber) {
-  GetPathElementsRequest_GetPathElementsRequestV0_FieldNumber_PathArray = 1,
-  GetPathElementsRequest_GetPathElementsRequestV0_FieldNumber_KeysArray = 2,
-  GetPathElementsRequest_GetPathElementsRequestV0_FieldNumber_Prove = 3,
+typedef GPB_ENUM(GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0_FieldNumber) {
+  GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0_FieldNumber_IdentityId = 1,
+  GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0_FieldNumber_TokenIdsArray = 2,
+  GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0_FieldNumber_Prove = 3,
 };
 
-GPB_FINAL @interface GetPathElementsRequest_GetPathElementsRequestV0 : GPBMessage
+GPB_FINAL @interface GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0 : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *pathArray;
-/** The number of items in @c pathArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger pathArray_Count;
+/** ID of the identity */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *keysArray;
-/** The number of items in @c keysArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger keysArray_Count;
+/** List of token IDs */
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *tokenIdsArray;
+/** The number of items in @c tokenIdsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger tokenIdsArray_Count;
 
+/** Flag to request a proof as the response */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetPathElementsResponse
+#pragma mark - GetIdentityTokenBalancesResponse
 
-typedef GPB_ENUM(GetPathElementsResponse_FieldNumber) {
-  GetPathElementsResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetIdentityTokenBalancesResponse_FieldNumber) {
+  GetIdentityTokenBalancesResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetPathElementsResponse_Version_OneOfCase) {
-  GetPathElementsResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetPathElementsResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetIdentityTokenBalancesResponse_Version_OneOfCase) {
+  GetIdentityTokenBalancesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityTokenBalancesResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetPathElementsResponse : GPBMessage
+GPB_FINAL @interface GetIdentityTokenBalancesResponse : GPBMessage
 
-@property(nonatomic, readonly) GetPathElementsResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetIdentityTokenBalancesResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetPathElementsResponse_GetPathElementsResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetPathElementsResponse_ClearVersionOneOfCase(GetPathElementsResponse *message);
+void GetIdentityTokenBalancesResponse_ClearVersionOneOfCase(GetIdentityTokenBalancesResponse *message);
 
-#pragma mark - GetPathElementsResponse_GetPathElementsResponseV0
+#pragma mark - GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0
 
-typedef GPB_ENUM(GetPathElementsResponse_GetPathElementsResponseV0_FieldNumber) {
-  GetPathElementsResponse_GetPathElementsResponseV0_FieldNumber_Elements = 1,
-  GetPathElementsResponse_GetPathElementsResponseV0_FieldNumber_Proof = 2,
-  GetPathElementsResponse_GetPathElementsResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_FieldNumber) {
+  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_FieldNumber_TokenBalances = 1,
+  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_FieldNumber_Proof = 2,
+  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetPathElementsResponse_GetPathElementsResponseV0_Result_OneOfCase) {
-  GetPathElementsResponse_GetPathElementsResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetPathElementsResponse_GetPathElementsResponseV0_Result_OneOfCase_Elements = 1,
-  GetPathElementsResponse_GetPathElementsResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_Result_OneOfCase) {
+  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_Result_OneOfCase_TokenBalances = 1,
+  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetPathElementsResponse_GetPathElementsResponseV0 : GPBMessage
-
-@property(nonatomic, readonly) GetPathElementsResponse_GetPathElementsResponseV0_Result_OneOfCase resultOneOfCase;
-
-@property(nonatomic, readwrite, strong, null_resettable) GetPathElementsResponse_GetPathElementsResponseV0_Elements *elements;
-
-@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
-
-@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
-/** Test to see if @c metadata has been set. */
-@property(nonatomic, readwrite) BOOL hasMetadata;
-
-@end
-
-/**
- * Clears whatever value was set for the oneof 'result'.
- **/
-void GetPathElementsResponse_GetPathElementsResponseV0_ClearResultOneOfCase(GetPathElementsResponse_GetPathElementsResponseV0 *message);
+GPB_FINAL @interface GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0 : GPBMessage
 
-#pragma mark - GetPathElementsResponse_GetPathElementsResponseV0_Elements
+@property(nonatomic, readonly) GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_Result_OneOfCase resultOneOfCase;
 
-typedef GPB_ENUM(GetPathElementsResponse_GetPathElementsResponseV0_Elements_FieldNumber) {
-  GetPathElementsResponse_GetPathElementsResponseV0_Elements_FieldNumber_ElementsArray = 1,
-};
+/** Actual token balances */
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalances *tokenBalances;
 
-GPB_FINAL @interface GetPathElementsResponse_GetPathElementsResponseV0_Elements : GPBMessage
+/** Proof of the token balances, if requested */
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *elementsArray;
-/** The number of items in @c elementsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger elementsArray_Count;
+/** Metadata about the blockchain state */
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
 
 @end
 
-#pragma mark - GetStatusRequest
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_ClearResultOneOfCase(GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0 *message);
 
-typedef GPB_ENUM(GetStatusRequest_FieldNumber) {
-  GetStatusRequest_FieldNumber_V0 = 1,
-};
+#pragma mark - GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry
 
-typedef GPB_ENUM(GetStatusRequest_Version_OneOfCase) {
-  GetStatusRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetStatusRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry_FieldNumber) {
+  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry_FieldNumber_TokenId = 1,
+  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry_FieldNumber_Balance = 2,
 };
 
-GPB_FINAL @interface GetStatusRequest : GPBMessage
+GPB_FINAL @interface GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry : GPBMessage
 
-@property(nonatomic, readonly) GetStatusRequest_Version_OneOfCase versionOneOfCase;
+/** Token ID */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusRequest_GetStatusRequestV0 *v0;
+/** Token balance for the contract */
+@property(nonatomic, readwrite) uint64_t balance;
 
+@property(nonatomic, readwrite) BOOL hasBalance;
 @end
 
-/**
- * Clears whatever value was set for the oneof 'version'.
- **/
-void GetStatusRequest_ClearVersionOneOfCase(GetStatusRequest *message);
+#pragma mark - GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalances
 
-#pragma mark - GetStatusRequest_GetStatusRequestV0
+typedef GPB_ENUM(GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalances_FieldNumber) {
+  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalances_FieldNumber_TokenBalancesArray = 1,
+};
 
-GPB_FINAL @interface GetStatusRequest_GetStatusRequestV0 : GPBMessage
+GPB_FINAL @interface GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalances : GPBMessage
+
+/** List of token balances */
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry*> *tokenBalancesArray;
+/** The number of items in @c tokenBalancesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger tokenBalancesArray_Count;
 
 @end
 
-#pragma mark - GetStatusResponse
+#pragma mark - GetIdentitiesTokenBalancesRequest
 
-typedef GPB_ENUM(GetStatusResponse_FieldNumber) {
-  GetStatusResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetIdentitiesTokenBalancesRequest_FieldNumber) {
+  GetIdentitiesTokenBalancesRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetStatusResponse_Version_OneOfCase) {
-  GetStatusResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetStatusResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetIdentitiesTokenBalancesRequest_Version_OneOfCase) {
+  GetIdentitiesTokenBalancesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentitiesTokenBalancesRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetStatusResponse : GPBMessage
+GPB_FINAL @interface GetIdentitiesTokenBalancesRequest : GPBMessage
 
-@property(nonatomic, readonly) GetStatusResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetIdentitiesTokenBalancesRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetStatusResponse_ClearVersionOneOfCase(GetStatusResponse *message);
+void GetIdentitiesTokenBalancesRequest_ClearVersionOneOfCase(GetIdentitiesTokenBalancesRequest *message);
 
-#pragma mark - GetStatusResponse_GetStatusResponseV0
+#pragma mark - GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0
 
-typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_FieldNumber) {
-  GetStatusResponse_GetStatusResponseV0_FieldNumber_Version = 1,
-  GetStatusResponse_GetStatusResponseV0_FieldNumber_Node = 2,
-  GetStatusResponse_GetStatusResponseV0_FieldNumber_Chain = 3,
-  GetStatusResponse_GetStatusResponseV0_FieldNumber_Network = 4,
-  GetStatusResponse_GetStatusResponseV0_FieldNumber_StateSync = 5,
-  GetStatusResponse_GetStatusResponseV0_FieldNumber_Time = 6,
+typedef GPB_ENUM(GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0_FieldNumber) {
+  GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0_FieldNumber_TokenId = 1,
+  GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0_FieldNumber_IdentityIdsArray = 2,
+  GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0_FieldNumber_Prove = 3,
 };
 
-GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0 : GPBMessage
-
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Version *version;
-/** Test to see if @c version has been set. */
-@property(nonatomic, readwrite) BOOL hasVersion;
-
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Node *node;
-/** Test to see if @c node has been set. */
-@property(nonatomic, readwrite) BOOL hasNode;
-
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Chain *chain;
-/** Test to see if @c chain has been set. */
-@property(nonatomic, readwrite) BOOL hasChain;
+GPB_FINAL @interface GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0 : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Network *network;
-/** Test to see if @c network has been set. */
-@property(nonatomic, readwrite) BOOL hasNetwork;
+/** Token ID */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_StateSync *stateSync;
-/** Test to see if @c stateSync has been set. */
-@property(nonatomic, readwrite) BOOL hasStateSync;
+/** List of identity IDs */
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *identityIdsArray;
+/** The number of items in @c identityIdsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger identityIdsArray_Count;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Time *time;
-/** Test to see if @c time has been set. */
-@property(nonatomic, readwrite) BOOL hasTime;
+/** Flag to request a proof as the response */
+@property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetStatusResponse_GetStatusResponseV0_Version
+#pragma mark - GetIdentitiesTokenBalancesResponse
 
-typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Version_FieldNumber) {
-  GetStatusResponse_GetStatusResponseV0_Version_FieldNumber_Software = 1,
-  GetStatusResponse_GetStatusResponseV0_Version_FieldNumber_Protocol = 2,
+typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_FieldNumber) {
+  GetIdentitiesTokenBalancesResponse_FieldNumber_V0 = 1,
 };
 
-GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Version : GPBMessage
+typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_Version_OneOfCase) {
+  GetIdentitiesTokenBalancesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentitiesTokenBalancesResponse_Version_OneOfCase_V0 = 1,
+};
 
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Version_Software *software;
-/** Test to see if @c software has been set. */
-@property(nonatomic, readwrite) BOOL hasSoftware;
+GPB_FINAL @interface GetIdentitiesTokenBalancesResponse : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Version_Protocol *protocol;
-/** Test to see if @c protocol has been set. */
-@property(nonatomic, readwrite) BOOL hasProtocol;
+@property(nonatomic, readonly) GetIdentitiesTokenBalancesResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0 *v0;
 
 @end
 
-#pragma mark - GetStatusResponse_GetStatusResponseV0_Version_Software
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetIdentitiesTokenBalancesResponse_ClearVersionOneOfCase(GetIdentitiesTokenBalancesResponse *message);
 
-typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Version_Software_FieldNumber) {
-  GetStatusResponse_GetStatusResponseV0_Version_Software_FieldNumber_Dapi = 1,
-  GetStatusResponse_GetStatusResponseV0_Version_Software_FieldNumber_Drive = 2,
-  GetStatusResponse_GetStatusResponseV0_Version_Software_FieldNumber_Tenderdash = 3,
+#pragma mark - GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0
+
+typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_FieldNumber) {
+  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_FieldNumber_IdentityTokenBalances = 1,
+  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_FieldNumber_Proof = 2,
+  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_FieldNumber_Metadata = 3,
 };
 
-GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Version_Software : GPBMessage
+typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_Result_OneOfCase) {
+  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_Result_OneOfCase_IdentityTokenBalances = 1,
+  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_Result_OneOfCase_Proof = 2,
+};
 
-@property(nonatomic, readwrite, copy, null_resettable) NSString *dapi;
+GPB_FINAL @interface GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0 : GPBMessage
 
-/** It will be missing if Drive is not responding */
-@property(nonatomic, readwrite, copy, null_resettable) NSString *drive;
-/** Test to see if @c drive has been set. */
-@property(nonatomic, readwrite) BOOL hasDrive;
+@property(nonatomic, readonly) GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** It will be missing if Tenderdash is not responding */
-@property(nonatomic, readwrite, copy, null_resettable) NSString *tenderdash;
-/** Test to see if @c tenderdash has been set. */
-@property(nonatomic, readwrite) BOOL hasTenderdash;
+/** Actual identity token balances */
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalances *identityTokenBalances;
+
+/** Proof of the balances, if requested */
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+
+/** Metadata about the blockchain state */
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
 
 @end
 
-#pragma mark - GetStatusResponse_GetStatusResponseV0_Version_Protocol
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_ClearResultOneOfCase(GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0 *message);
 
-typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Version_Protocol_FieldNumber) {
-  GetStatusResponse_GetStatusResponseV0_Version_Protocol_FieldNumber_Tenderdash = 1,
-  GetStatusResponse_GetStatusResponseV0_Version_Protocol_FieldNumber_Drive = 2,
+#pragma mark - GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry
+
+typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry_FieldNumber) {
+  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry_FieldNumber_IdentityId = 1,
+  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry_FieldNumber_Balance = 2,
 };
 
-GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Version_Protocol : GPBMessage
+GPB_FINAL @interface GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash *tenderdash;
-/** Test to see if @c tenderdash has been set. */
-@property(nonatomic, readwrite) BOOL hasTenderdash;
+/** Identity ID */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive *drive;
-/** Test to see if @c drive has been set. */
-@property(nonatomic, readwrite) BOOL hasDrive;
+/** Token balance for the identity */
+@property(nonatomic, readwrite) uint64_t balance;
 
+@property(nonatomic, readwrite) BOOL hasBalance;
 @end
 
-#pragma mark - GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash
+#pragma mark - GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalances
 
-typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash_FieldNumber) {
-  GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash_FieldNumber_P2P = 1,
-  GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash_FieldNumber_Block = 2,
+typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalances_FieldNumber) {
+  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalances_FieldNumber_IdentityTokenBalancesArray = 1,
 };
 
-GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Version_Protocol_Tenderdash : GPBMessage
-
-@property(nonatomic, readwrite) uint32_t p2P;
+GPB_FINAL @interface GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalances : GPBMessage
 
-@property(nonatomic, readwrite) uint32_t block;
+/** List of identity token balances */
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry*> *identityTokenBalancesArray;
+/** The number of items in @c identityTokenBalancesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger identityTokenBalancesArray_Count;
 
 @end
 
-#pragma mark - GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive
+#pragma mark - GetIdentityTokenInfosRequest
 
-typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive_FieldNumber) {
-  GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive_FieldNumber_Latest = 3,
-  GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive_FieldNumber_Current = 4,
-  GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive_FieldNumber_NextEpoch = 5,
+typedef GPB_ENUM(GetIdentityTokenInfosRequest_FieldNumber) {
+  GetIdentityTokenInfosRequest_FieldNumber_V0 = 1,
 };
 
-GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Version_Protocol_Drive : GPBMessage
+typedef GPB_ENUM(GetIdentityTokenInfosRequest_Version_OneOfCase) {
+  GetIdentityTokenInfosRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityTokenInfosRequest_Version_OneOfCase_V0 = 1,
+};
 
-/** Highest protocol version supported by this node */
-@property(nonatomic, readwrite) uint32_t latest;
+GPB_FINAL @interface GetIdentityTokenInfosRequest : GPBMessage
 
-/** Protocol version used in current epoch */
-@property(nonatomic, readwrite) uint32_t current;
+@property(nonatomic, readonly) GetIdentityTokenInfosRequest_Version_OneOfCase versionOneOfCase;
 
-/** Protocol version that will be used in the next epoch */
-@property(nonatomic, readwrite) uint32_t nextEpoch;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0 *v0;
 
 @end
 
-#pragma mark - GetStatusResponse_GetStatusResponseV0_Time
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetIdentityTokenInfosRequest_ClearVersionOneOfCase(GetIdentityTokenInfosRequest *message);
 
-typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Time_FieldNumber) {
-  GetStatusResponse_GetStatusResponseV0_Time_FieldNumber_Local = 1,
-  GetStatusResponse_GetStatusResponseV0_Time_FieldNumber_Block = 2,
-  GetStatusResponse_GetStatusResponseV0_Time_FieldNumber_Genesis = 3,
-  GetStatusResponse_GetStatusResponseV0_Time_FieldNumber_Epoch = 4,
+#pragma mark - GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0
+
+typedef GPB_ENUM(GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0_FieldNumber) {
+  GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0_FieldNumber_IdentityId = 1,
+  GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0_FieldNumber_TokenIdsArray = 2,
+  GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0_FieldNumber_Prove = 3,
 };
 
-GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Time : GPBMessage
-
-@property(nonatomic, readwrite) uint64_t local;
+GPB_FINAL @interface GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0 : GPBMessage
 
-/** It will be missing if Drive is not responding */
-@property(nonatomic, readwrite) uint64_t block;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
 
-@property(nonatomic, readwrite) BOOL hasBlock;
-/** It will be missing if Drive is not responding */
-@property(nonatomic, readwrite) uint64_t genesis;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *tokenIdsArray;
+/** The number of items in @c tokenIdsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger tokenIdsArray_Count;
 
-@property(nonatomic, readwrite) BOOL hasGenesis;
-/** It will be missing if Drive is not responding */
-@property(nonatomic, readwrite) uint32_t epoch;
+@property(nonatomic, readwrite) BOOL prove;
 
-@property(nonatomic, readwrite) BOOL hasEpoch;
 @end
 
-#pragma mark - GetStatusResponse_GetStatusResponseV0_Node
+#pragma mark - GetIdentityTokenInfosResponse
 
-typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Node_FieldNumber) {
-  GetStatusResponse_GetStatusResponseV0_Node_FieldNumber_Id_p = 1,
-  GetStatusResponse_GetStatusResponseV0_Node_FieldNumber_ProTxHash = 2,
+typedef GPB_ENUM(GetIdentityTokenInfosResponse_FieldNumber) {
+  GetIdentityTokenInfosResponse_FieldNumber_V0 = 1,
 };
 
-GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Node : GPBMessage
+typedef GPB_ENUM(GetIdentityTokenInfosResponse_Version_OneOfCase) {
+  GetIdentityTokenInfosResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityTokenInfosResponse_Version_OneOfCase_V0 = 1,
+};
 
-/** Platform node ID */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *id_p;
+GPB_FINAL @interface GetIdentityTokenInfosResponse : GPBMessage
 
-/** Evo masternode pro tx hash. It will be absent if the node is a fullnode */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *proTxHash;
-/** Test to see if @c proTxHash has been set. */
-@property(nonatomic, readwrite) BOOL hasProTxHash;
+@property(nonatomic, readonly) GetIdentityTokenInfosResponse_Version_OneOfCase versionOneOfCase;
 
-@end
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0 *v0;
 
-#pragma mark - GetStatusResponse_GetStatusResponseV0_Chain
+@end
 
-typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber) {
-  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_CatchingUp = 1,
-  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_LatestBlockHash = 2,
-  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_LatestAppHash = 3,
-  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_LatestBlockHeight = 4,
-  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_EarliestBlockHash = 5,
-  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_EarliestAppHash = 6,
-  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_EarliestBlockHeight = 7,
-  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_MaxPeerBlockHeight = 9,
-  GetStatusResponse_GetStatusResponseV0_Chain_FieldNumber_CoreChainLockedHeight = 10,
-};
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetIdentityTokenInfosResponse_ClearVersionOneOfCase(GetIdentityTokenInfosResponse *message);
 
-GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Chain : GPBMessage
+#pragma mark - GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0
 
-@property(nonatomic, readwrite) BOOL catchingUp;
+typedef GPB_ENUM(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_FieldNumber) {
+  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_FieldNumber_TokenInfos = 1,
+  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_FieldNumber_Proof = 2,
+  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_FieldNumber_Metadata = 3,
+};
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *latestBlockHash;
+typedef GPB_ENUM(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_Result_OneOfCase) {
+  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_Result_OneOfCase_TokenInfos = 1,
+  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_Result_OneOfCase_Proof = 2,
+};
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *latestAppHash;
+GPB_FINAL @interface GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0 : GPBMessage
 
-@property(nonatomic, readwrite) uint64_t latestBlockHeight;
+@property(nonatomic, readonly) GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_Result_OneOfCase resultOneOfCase;
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *earliestBlockHash;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfos *tokenInfos;
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *earliestAppHash;
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
-@property(nonatomic, readwrite) uint64_t earliestBlockHeight;
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
 
-@property(nonatomic, readwrite) uint64_t maxPeerBlockHeight;
+@end
 
 /**
- * Latest known core height in consensus.
- * It will be missing if Drive is not responding
+ * Clears whatever value was set for the oneof 'result'.
  **/
-@property(nonatomic, readwrite) uint32_t coreChainLockedHeight;
-
-@property(nonatomic, readwrite) BOOL hasCoreChainLockedHeight;
-@end
+void GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_ClearResultOneOfCase(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0 *message);
 
-#pragma mark - GetStatusResponse_GetStatusResponseV0_Network
+#pragma mark - GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenIdentityInfoEntry
 
-typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_Network_FieldNumber) {
-  GetStatusResponse_GetStatusResponseV0_Network_FieldNumber_ChainId = 1,
-  GetStatusResponse_GetStatusResponseV0_Network_FieldNumber_PeersCount = 2,
-  GetStatusResponse_GetStatusResponseV0_Network_FieldNumber_Listening = 3,
+typedef GPB_ENUM(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenIdentityInfoEntry_FieldNumber) {
+  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenIdentityInfoEntry_FieldNumber_Frozen = 1,
 };
 
-GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_Network : GPBMessage
-
-@property(nonatomic, readwrite, copy, null_resettable) NSString *chainId;
-
-@property(nonatomic, readwrite) uint32_t peersCount;
+GPB_FINAL @interface GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenIdentityInfoEntry : GPBMessage
 
-@property(nonatomic, readwrite) BOOL listening;
+@property(nonatomic, readwrite) BOOL frozen;
 
 @end
 
-#pragma mark - GetStatusResponse_GetStatusResponseV0_StateSync
+#pragma mark - GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry
 
-typedef GPB_ENUM(GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber) {
-  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_TotalSyncedTime = 1,
-  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_RemainingTime = 2,
-  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_TotalSnapshots = 3,
-  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_ChunkProcessAvgTime = 4,
-  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_SnapshotHeight = 5,
-  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_SnapshotChunksCount = 6,
-  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_BackfilledBlocks = 7,
-  GetStatusResponse_GetStatusResponseV0_StateSync_FieldNumber_BackfillBlocksTotal = 8,
+typedef GPB_ENUM(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry_FieldNumber) {
+  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry_FieldNumber_TokenId = 1,
+  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry_FieldNumber_Info = 2,
 };
 
-GPB_FINAL @interface GetStatusResponse_GetStatusResponseV0_StateSync : GPBMessage
-
-@property(nonatomic, readwrite) uint64_t totalSyncedTime;
+GPB_FINAL @interface GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry : GPBMessage
 
-@property(nonatomic, readwrite) uint64_t remainingTime;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-@property(nonatomic, readwrite) uint32_t totalSnapshots;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenIdentityInfoEntry *info;
+/** Test to see if @c info has been set. */
+@property(nonatomic, readwrite) BOOL hasInfo;
 
-@property(nonatomic, readwrite) uint64_t chunkProcessAvgTime;
+@end
 
-@property(nonatomic, readwrite) uint64_t snapshotHeight;
+#pragma mark - GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfos
 
-@property(nonatomic, readwrite) uint64_t snapshotChunksCount;
+typedef GPB_ENUM(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfos_FieldNumber) {
+  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfos_FieldNumber_TokenInfosArray = 1,
+};
 
-@property(nonatomic, readwrite) uint64_t backfilledBlocks;
+GPB_FINAL @interface GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfos : GPBMessage
 
-@property(nonatomic, readwrite) uint64_t backfillBlocksTotal;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry*> *tokenInfosArray;
+/** The number of items in @c tokenInfosArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger tokenInfosArray_Count;
 
 @end
 
-#pragma mark - GetCurrentQuorumsInfoRequest
+#pragma mark - GetIdentitiesTokenInfosRequest
 
-typedef GPB_ENUM(GetCurrentQuorumsInfoRequest_FieldNumber) {
-  GetCurrentQuorumsInfoRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetIdentitiesTokenInfosRequest_FieldNumber) {
+  GetIdentitiesTokenInfosRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetCurrentQuorumsInfoRequest_Version_OneOfCase) {
-  GetCurrentQuorumsInfoRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetCurrentQuorumsInfoRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetIdentitiesTokenInfosRequest_Version_OneOfCase) {
+  GetIdentitiesTokenInfosRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentitiesTokenInfosRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetCurrentQuorumsInfoRequest : GPBMessage
+GPB_FINAL @interface GetIdentitiesTokenInfosRequest : GPBMessage
 
-@property(nonatomic, readonly) GetCurrentQuorumsInfoRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetIdentitiesTokenInfosRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetCurrentQuorumsInfoRequest_GetCurrentQuorumsInfoRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetCurrentQuorumsInfoRequest_ClearVersionOneOfCase(GetCurrentQuorumsInfoRequest *message);
+void GetIdentitiesTokenInfosRequest_ClearVersionOneOfCase(GetIdentitiesTokenInfosRequest *message);
 
-#pragma mark - GetCurrentQuorumsInfoRequest_GetCurrentQuorumsInfoRequestV0
+#pragma mark - GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0
 
-GPB_FINAL @interface GetCurrentQuorumsInfoRequest_GetCurrentQuorumsInfoRequestV0 : GPBMessage
+typedef GPB_ENUM(GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0_FieldNumber) {
+  GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0_FieldNumber_TokenId = 1,
+  GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0_FieldNumber_IdentityIdsArray = 2,
+  GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0_FieldNumber_Prove = 3,
+};
+
+GPB_FINAL @interface GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0 : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *identityIdsArray;
+/** The number of items in @c identityIdsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger identityIdsArray_Count;
+
+@property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetCurrentQuorumsInfoResponse
+#pragma mark - GetIdentitiesTokenInfosResponse
 
-typedef GPB_ENUM(GetCurrentQuorumsInfoResponse_FieldNumber) {
-  GetCurrentQuorumsInfoResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_FieldNumber) {
+  GetIdentitiesTokenInfosResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetCurrentQuorumsInfoResponse_Version_OneOfCase) {
-  GetCurrentQuorumsInfoResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetCurrentQuorumsInfoResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_Version_OneOfCase) {
+  GetIdentitiesTokenInfosResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentitiesTokenInfosResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetCurrentQuorumsInfoResponse : GPBMessage
+GPB_FINAL @interface GetIdentitiesTokenInfosResponse : GPBMessage
 
-@property(nonatomic, readonly) GetCurrentQuorumsInfoResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetIdentitiesTokenInfosResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetCurrentQuorumsInfoResponse_ClearVersionOneOfCase(GetCurrentQuorumsInfoResponse *message);
+void GetIdentitiesTokenInfosResponse_ClearVersionOneOfCase(GetIdentitiesTokenInfosResponse *message);
 
-#pragma mark - GetCurrentQuorumsInfoResponse_ValidatorV0
+#pragma mark - GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0
 
-typedef GPB_ENUM(GetCurrentQuorumsInfoResponse_ValidatorV0_FieldNumber) {
-  GetCurrentQuorumsInfoResponse_ValidatorV0_FieldNumber_ProTxHash = 1,
-  GetCurrentQuorumsInfoResponse_ValidatorV0_FieldNumber_NodeIp = 2,
-  GetCurrentQuorumsInfoResponse_ValidatorV0_FieldNumber_IsBanned = 3,
+typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_FieldNumber) {
+  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_FieldNumber_IdentityTokenInfos = 1,
+  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_FieldNumber_Proof = 2,
+  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_FieldNumber_Metadata = 3,
 };
 
-GPB_FINAL @interface GetCurrentQuorumsInfoResponse_ValidatorV0 : GPBMessage
-
-@property(nonatomic, readwrite, copy, null_resettable) NSData *proTxHash;
+typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_Result_OneOfCase) {
+  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_Result_OneOfCase_IdentityTokenInfos = 1,
+  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_Result_OneOfCase_Proof = 2,
+};
 
-@property(nonatomic, readwrite, copy, null_resettable) NSString *nodeIp;
+GPB_FINAL @interface GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0 : GPBMessage
 
-@property(nonatomic, readwrite) BOOL isBanned;
+@property(nonatomic, readonly) GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_Result_OneOfCase resultOneOfCase;
 
-@end
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_IdentityTokenInfos *identityTokenInfos;
 
-#pragma mark - GetCurrentQuorumsInfoResponse_ValidatorSetV0
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
-typedef GPB_ENUM(GetCurrentQuorumsInfoResponse_ValidatorSetV0_FieldNumber) {
-  GetCurrentQuorumsInfoResponse_ValidatorSetV0_FieldNumber_QuorumHash = 1,
-  GetCurrentQuorumsInfoResponse_ValidatorSetV0_FieldNumber_CoreHeight = 2,
-  GetCurrentQuorumsInfoResponse_ValidatorSetV0_FieldNumber_MembersArray = 3,
-  GetCurrentQuorumsInfoResponse_ValidatorSetV0_FieldNumber_ThresholdPublicKey = 4,
-};
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
 
-GPB_FINAL @interface GetCurrentQuorumsInfoResponse_ValidatorSetV0 : GPBMessage
+@end
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *quorumHash;
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_ClearResultOneOfCase(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0 *message);
 
-@property(nonatomic, readwrite) uint32_t coreHeight;
+#pragma mark - GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenIdentityInfoEntry
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetCurrentQuorumsInfoResponse_ValidatorV0*> *membersArray;
-/** The number of items in @c membersArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger membersArray_Count;
+typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenIdentityInfoEntry_FieldNumber) {
+  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenIdentityInfoEntry_FieldNumber_Frozen = 1,
+};
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *thresholdPublicKey;
+GPB_FINAL @interface GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenIdentityInfoEntry : GPBMessage
+
+@property(nonatomic, readwrite) BOOL frozen;
 
 @end
 
-#pragma mark - GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0
+#pragma mark - GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry
 
-typedef GPB_ENUM(GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber) {
-  GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber_QuorumHashesArray = 1,
-  GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber_CurrentQuorumHash = 2,
-  GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber_ValidatorSetsArray = 3,
-  GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber_LastBlockProposer = 4,
-  GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0_FieldNumber_Metadata = 5,
+typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry_FieldNumber) {
+  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry_FieldNumber_IdentityId = 1,
+  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry_FieldNumber_Info = 2,
 };
 
-GPB_FINAL @interface GetCurrentQuorumsInfoResponse_GetCurrentQuorumsInfoResponseV0 : GPBMessage
+GPB_FINAL @interface GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *quorumHashesArray;
-/** The number of items in @c quorumHashesArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger quorumHashesArray_Count;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *currentQuorumHash;
+@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenIdentityInfoEntry *info;
+/** Test to see if @c info has been set. */
+@property(nonatomic, readwrite) BOOL hasInfo;
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetCurrentQuorumsInfoResponse_ValidatorSetV0*> *validatorSetsArray;
-/** The number of items in @c validatorSetsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger validatorSetsArray_Count;
+@end
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *lastBlockProposer;
+#pragma mark - GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_IdentityTokenInfos
 
-@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
-/** Test to see if @c metadata has been set. */
-@property(nonatomic, readwrite) BOOL hasMetadata;
+typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_IdentityTokenInfos_FieldNumber) {
+  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_IdentityTokenInfos_FieldNumber_TokenInfosArray = 1,
+};
+
+GPB_FINAL @interface GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_IdentityTokenInfos : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry*> *tokenInfosArray;
+/** The number of items in @c tokenInfosArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger tokenInfosArray_Count;
 
 @end
 
-#pragma mark - GetIdentityTokenBalancesRequest
+#pragma mark - GetTokenStatusesRequest
 
-typedef GPB_ENUM(GetIdentityTokenBalancesRequest_FieldNumber) {
-  GetIdentityTokenBalancesRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenStatusesRequest_FieldNumber) {
+  GetTokenStatusesRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetIdentityTokenBalancesRequest_Version_OneOfCase) {
-  GetIdentityTokenBalancesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityTokenBalancesRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenStatusesRequest_Version_OneOfCase) {
+  GetTokenStatusesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenStatusesRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetIdentityTokenBalancesRequest : GPBMessage
+GPB_FINAL @interface GetTokenStatusesRequest : GPBMessage
 
-@property(nonatomic, readonly) GetIdentityTokenBalancesRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenStatusesRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenStatusesRequest_GetTokenStatusesRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetIdentityTokenBalancesRequest_ClearVersionOneOfCase(GetIdentityTokenBalancesRequest *message);
+void GetTokenStatusesRequest_ClearVersionOneOfCase(GetTokenStatusesRequest *message);
 
-#pragma mark - GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0
+#pragma mark - GetTokenStatusesRequest_GetTokenStatusesRequestV0
 
-typedef GPB_ENUM(GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0_FieldNumber) {
-  GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0_FieldNumber_IdentityId = 1,
-  GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0_FieldNumber_TokenIdsArray = 2,
-  GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0_FieldNumber_Prove = 3,
+typedef GPB_ENUM(GetTokenStatusesRequest_GetTokenStatusesRequestV0_FieldNumber) {
+  GetTokenStatusesRequest_GetTokenStatusesRequestV0_FieldNumber_TokenIdsArray = 1,
+  GetTokenStatusesRequest_GetTokenStatusesRequestV0_FieldNumber_Prove = 2,
 };
 
-GPB_FINAL @interface GetIdentityTokenBalancesRequest_GetIdentityTokenBalancesRequestV0 : GPBMessage
-
-/** ID of the identity */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
+GPB_FINAL @interface GetTokenStatusesRequest_GetTokenStatusesRequestV0 : GPBMessage
 
-/** List of token IDs */
 @property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *tokenIdsArray;
 /** The number of items in @c tokenIdsArray without causing the array to be created. */
 @property(nonatomic, readonly) NSUInteger tokenIdsArray_Count;
 
-/** Flag to request a proof as the response */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetIdentityTokenBalancesResponse
+#pragma mark - GetTokenStatusesResponse
 
-typedef GPB_ENUM(GetIdentityTokenBalancesResponse_FieldNumber) {
-  GetIdentityTokenBalancesResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenStatusesResponse_FieldNumber) {
+  GetTokenStatusesResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetIdentityTokenBalancesResponse_Version_OneOfCase) {
-  GetIdentityTokenBalancesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityTokenBalancesResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenStatusesResponse_Version_OneOfCase) {
+  GetTokenStatusesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenStatusesResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetIdentityTokenBalancesResponse : GPBMessage
+GPB_FINAL @interface GetTokenStatusesResponse : GPBMessage
 
-@property(nonatomic, readonly) GetIdentityTokenBalancesResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenStatusesResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenStatusesResponse_GetTokenStatusesResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetIdentityTokenBalancesResponse_ClearVersionOneOfCase(GetIdentityTokenBalancesResponse *message);
+void GetTokenStatusesResponse_ClearVersionOneOfCase(GetTokenStatusesResponse *message);
 
-#pragma mark - GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0
+#pragma mark - GetTokenStatusesResponse_GetTokenStatusesResponseV0
 
-typedef GPB_ENUM(GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_FieldNumber) {
-  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_FieldNumber_TokenBalances = 1,
-  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_FieldNumber_Proof = 2,
-  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetTokenStatusesResponse_GetTokenStatusesResponseV0_FieldNumber) {
+  GetTokenStatusesResponse_GetTokenStatusesResponseV0_FieldNumber_TokenStatuses = 1,
+  GetTokenStatusesResponse_GetTokenStatusesResponseV0_FieldNumber_Proof = 2,
+  GetTokenStatusesResponse_GetTokenStatusesResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_Result_OneOfCase) {
-  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_Result_OneOfCase_TokenBalances = 1,
-  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetTokenStatusesResponse_GetTokenStatusesResponseV0_Result_OneOfCase) {
+  GetTokenStatusesResponse_GetTokenStatusesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenStatusesResponse_GetTokenStatusesResponseV0_Result_OneOfCase_TokenStatuses = 1,
+  GetTokenStatusesResponse_GetTokenStatusesResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0 : GPBMessage
+GPB_FINAL @interface GetTokenStatusesResponse_GetTokenStatusesResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetTokenStatusesResponse_GetTokenStatusesResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** Actual token balances */
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalances *tokenBalances;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatuses *tokenStatuses;
 
-/** Proof of the token balances, if requested */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
-/** Metadata about the blockchain state */
 @property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
 /** Test to see if @c metadata has been set. */
 @property(nonatomic, readwrite) BOOL hasMetadata;
@@ -5281,137 +7057,151 @@ GPB_FINAL @interface GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesRe
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_ClearResultOneOfCase(GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0 *message);
+void GetTokenStatusesResponse_GetTokenStatusesResponseV0_ClearResultOneOfCase(GetTokenStatusesResponse_GetTokenStatusesResponseV0 *message);
 
-#pragma mark - GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry
+#pragma mark - GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry
 
-typedef GPB_ENUM(GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry_FieldNumber) {
-  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry_FieldNumber_TokenId = 1,
-  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry_FieldNumber_Balance = 2,
+typedef GPB_ENUM(GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry_FieldNumber) {
+  GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry_FieldNumber_TokenId = 1,
+  GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry_FieldNumber_Paused = 2,
 };
 
-GPB_FINAL @interface GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry : GPBMessage
+GPB_FINAL @interface GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry : GPBMessage
 
-/** Token ID */
 @property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-/** Token balance for the contract */
-@property(nonatomic, readwrite) uint64_t balance;
+@property(nonatomic, readwrite) BOOL paused;
 
-@property(nonatomic, readwrite) BOOL hasBalance;
+@property(nonatomic, readwrite) BOOL hasPaused;
 @end
 
-#pragma mark - GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalances
+#pragma mark - GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatuses
 
-typedef GPB_ENUM(GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalances_FieldNumber) {
-  GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalances_FieldNumber_TokenBalancesArray = 1,
+typedef GPB_ENUM(GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatuses_FieldNumber) {
+  GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatuses_FieldNumber_TokenStatusesArray = 1,
 };
 
-GPB_FINAL @interface GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalances : GPBMessage
+GPB_FINAL @interface GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatuses : GPBMessage
 
-/** List of token balances */
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetIdentityTokenBalancesResponse_GetIdentityTokenBalancesResponseV0_TokenBalanceEntry*> *tokenBalancesArray;
-/** The number of items in @c tokenBalancesArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger tokenBalancesArray_Count;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry*> *tokenStatusesArray;
+/** The number of items in @c tokenStatusesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger tokenStatusesArray_Count;
 
 @end
 
-#pragma mark - GetIdentitiesTokenBalancesRequest
+#pragma mark - GetTokenDirectPurchasePricesRequest
 
-typedef GPB_ENUM(GetIdentitiesTokenBalancesRequest_FieldNumber) {
-  GetIdentitiesTokenBalancesRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenDirectPurchasePricesRequest_FieldNumber) {
+  GetTokenDirectPurchasePricesRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetIdentitiesTokenBalancesRequest_Version_OneOfCase) {
-  GetIdentitiesTokenBalancesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentitiesTokenBalancesRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenDirectPurchasePricesRequest_Version_OneOfCase) {
+  GetTokenDirectPurchasePricesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenDirectPurchasePricesRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenBalancesRequest : GPBMessage
+/**
+ * Retrieve direct purchase prices defined for one or more tokens.
+ *
+ * Some tokens can have a direct purchase price defined using
+ * `TokenSetPriceForDirectPurchaseTransition` (see `dpp` crate for details).
+ * This request retrieves the direct purchase prices for those tokens and
+ * returns [GetTokenDirectPurchasePricesResponse].
+ **/
+GPB_FINAL @interface GetTokenDirectPurchasePricesRequest : GPBMessage
 
-@property(nonatomic, readonly) GetIdentitiesTokenBalancesRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenDirectPurchasePricesRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetIdentitiesTokenBalancesRequest_ClearVersionOneOfCase(GetIdentitiesTokenBalancesRequest *message);
+void GetTokenDirectPurchasePricesRequest_ClearVersionOneOfCase(GetTokenDirectPurchasePricesRequest *message);
 
-#pragma mark - GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0
+#pragma mark - GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0
 
-typedef GPB_ENUM(GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0_FieldNumber) {
-  GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0_FieldNumber_TokenId = 1,
-  GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0_FieldNumber_IdentityIdsArray = 2,
-  GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0_FieldNumber_Prove = 3,
+typedef GPB_ENUM(GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0_FieldNumber) {
+  GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0_FieldNumber_TokenIdsArray = 1,
+  GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0_FieldNumber_Prove = 2,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenBalancesRequest_GetIdentitiesTokenBalancesRequestV0 : GPBMessage
-
-/** Token ID */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
+GPB_FINAL @interface GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0 : GPBMessage
 
-/** List of identity IDs */
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *identityIdsArray;
-/** The number of items in @c identityIdsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger identityIdsArray_Count;
+/**
+ * List of token IDs to get prices for.
+ *
+ * The list must not be empty.
+ * Token IDs must have 32 bytes and be unique.
+ * Results for non-unique token IDs are undefined.
+ **/
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *tokenIdsArray;
+/** The number of items in @c tokenIdsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger tokenIdsArray_Count;
 
-/** Flag to request a proof as the response */
+/** Whether to return proofs for the response, or just direct response. */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetIdentitiesTokenBalancesResponse
+#pragma mark - GetTokenDirectPurchasePricesResponse
 
-typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_FieldNumber) {
-  GetIdentitiesTokenBalancesResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_FieldNumber) {
+  GetTokenDirectPurchasePricesResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_Version_OneOfCase) {
-  GetIdentitiesTokenBalancesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentitiesTokenBalancesResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_Version_OneOfCase) {
+  GetTokenDirectPurchasePricesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenDirectPurchasePricesResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenBalancesResponse : GPBMessage
+/**
+ * Response to GetTokenDirectPurchasePricesRequest, containing information about
+ * direct purchase prices defined for requested token IDs.
+ **/
+GPB_FINAL @interface GetTokenDirectPurchasePricesResponse : GPBMessage
 
-@property(nonatomic, readonly) GetIdentitiesTokenBalancesResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenDirectPurchasePricesResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetIdentitiesTokenBalancesResponse_ClearVersionOneOfCase(GetIdentitiesTokenBalancesResponse *message);
+void GetTokenDirectPurchasePricesResponse_ClearVersionOneOfCase(GetTokenDirectPurchasePricesResponse *message);
 
-#pragma mark - GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0
+#pragma mark - GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0
 
-typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_FieldNumber) {
-  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_FieldNumber_IdentityTokenBalances = 1,
-  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_FieldNumber_Proof = 2,
-  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_FieldNumber) {
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_FieldNumber_TokenDirectPurchasePrices = 1,
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_FieldNumber_Proof = 2,
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_Result_OneOfCase) {
-  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_Result_OneOfCase_IdentityTokenBalances = 1,
-  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_Result_OneOfCase) {
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_Result_OneOfCase_TokenDirectPurchasePrices = 1,
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0 : GPBMessage
+GPB_FINAL @interface GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** Actual identity token balances */
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalances *identityTokenBalances;
+/** Contains the list of token IDs and their corresponding direct */
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePrices *tokenDirectPurchasePrices;
 
-/** Proof of the balances, if requested */
+/**
+ * Requested information in a form of cryptographic proof.
+ * In Rust, use `FromProof` trait to convert it to the actual data.
+ **/
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
-/** Metadata about the blockchain state */
+/** Metadata about the blockchain state. */
 @property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
 /** Test to see if @c metadata has been set. */
 @property(nonatomic, readwrite) BOOL hasMetadata;
@@ -5421,272 +7211,343 @@ GPB_FINAL @interface GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalanc
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_ClearResultOneOfCase(GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0 *message);
+void GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_ClearResultOneOfCase(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0 *message);
 
-#pragma mark - GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry
+#pragma mark - GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity
 
-typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry_FieldNumber) {
-  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry_FieldNumber_IdentityId = 1,
-  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry_FieldNumber_Balance = 2,
+typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity_FieldNumber) {
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity_FieldNumber_Quantity = 1,
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity_FieldNumber_Price = 2,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry : GPBMessage
+/**
+ * Contains the individual price tier for a specific quantity of tokens.
+ **/
+GPB_FINAL @interface GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity : GPBMessage
 
-/** Identity ID */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
+/** Minimum quantity of tokens to purchase to get this price. */
+@property(nonatomic, readwrite) uint64_t quantity;
 
-/** Token balance for the identity */
-@property(nonatomic, readwrite) uint64_t balance;
+/** Price for the specified quantity of tokens. */
+@property(nonatomic, readwrite) uint64_t price;
 
-@property(nonatomic, readwrite) BOOL hasBalance;
 @end
 
-#pragma mark - GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalances
+#pragma mark - GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PricingSchedule
 
-typedef GPB_ENUM(GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalances_FieldNumber) {
-  GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalances_FieldNumber_IdentityTokenBalancesArray = 1,
+typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PricingSchedule_FieldNumber) {
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PricingSchedule_FieldNumber_PriceForQuantityArray = 1,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalances : GPBMessage
+/**
+ * Contains list of price tiers for a specific token.
+ **/
+GPB_FINAL @interface GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PricingSchedule : GPBMessage
 
-/** List of identity token balances */
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetIdentitiesTokenBalancesResponse_GetIdentitiesTokenBalancesResponseV0_IdentityTokenBalanceEntry*> *identityTokenBalancesArray;
-/** The number of items in @c identityTokenBalancesArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger identityTokenBalancesArray_Count;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity*> *priceForQuantityArray;
+/** The number of items in @c priceForQuantityArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger priceForQuantityArray_Count;
 
 @end
 
-#pragma mark - GetIdentityTokenInfosRequest
+#pragma mark - GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry
 
-typedef GPB_ENUM(GetIdentityTokenInfosRequest_FieldNumber) {
-  GetIdentityTokenInfosRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_FieldNumber) {
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_FieldNumber_TokenId = 1,
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_FieldNumber_FixedPrice = 2,
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_FieldNumber_VariablePrice = 3,
 };
 
-typedef GPB_ENUM(GetIdentityTokenInfosRequest_Version_OneOfCase) {
-  GetIdentityTokenInfosRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityTokenInfosRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_Price_OneOfCase) {
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_Price_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_Price_OneOfCase_FixedPrice = 2,
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_Price_OneOfCase_VariablePrice = 3,
 };
 
-GPB_FINAL @interface GetIdentityTokenInfosRequest : GPBMessage
+GPB_FINAL @interface GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry : GPBMessage
 
-@property(nonatomic, readonly) GetIdentityTokenInfosRequest_Version_OneOfCase versionOneOfCase;
+/** 32-byte token identifier */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0 *v0;
+/** Price of the token; optional */
+@property(nonatomic, readonly) GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_Price_OneOfCase priceOneOfCase;
 
-@end
+/** Fixed price for the token. */
+@property(nonatomic, readwrite) uint64_t fixedPrice;
 
 /**
- * Clears whatever value was set for the oneof 'version'.
+ * Tiered pricing for the token, where the price varies based on the
+ * quantity purchased.
  **/
-void GetIdentityTokenInfosRequest_ClearVersionOneOfCase(GetIdentityTokenInfosRequest *message);
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PricingSchedule *variablePrice;
 
-#pragma mark - GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0
+@end
 
-typedef GPB_ENUM(GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0_FieldNumber) {
-  GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0_FieldNumber_IdentityId = 1,
-  GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0_FieldNumber_TokenIdsArray = 2,
-  GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0_FieldNumber_Prove = 3,
-};
+/**
+ * Clears whatever value was set for the oneof 'price'.
+ **/
+void GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_ClearPriceOneOfCase(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry *message);
 
-GPB_FINAL @interface GetIdentityTokenInfosRequest_GetIdentityTokenInfosRequestV0 : GPBMessage
+#pragma mark - GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePrices
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
+typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePrices_FieldNumber) {
+  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePrices_FieldNumber_TokenDirectPurchasePriceArray = 1,
+};
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *tokenIdsArray;
-/** The number of items in @c tokenIdsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger tokenIdsArray_Count;
+/**
+ * For each requested token, contains list of token IDs and their
+ * corresponding direct purchase prices.
+ **/
+GPB_FINAL @interface GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePrices : GPBMessage
 
-@property(nonatomic, readwrite) BOOL prove;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry*> *tokenDirectPurchasePriceArray;
+/** The number of items in @c tokenDirectPurchasePriceArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger tokenDirectPurchasePriceArray_Count;
 
 @end
 
-#pragma mark - GetIdentityTokenInfosResponse
+#pragma mark - GetTokenContractInfoRequest
 
-typedef GPB_ENUM(GetIdentityTokenInfosResponse_FieldNumber) {
-  GetIdentityTokenInfosResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenContractInfoRequest_FieldNumber) {
+  GetTokenContractInfoRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetIdentityTokenInfosResponse_Version_OneOfCase) {
-  GetIdentityTokenInfosResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityTokenInfosResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenContractInfoRequest_Version_OneOfCase) {
+  GetTokenContractInfoRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenContractInfoRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetIdentityTokenInfosResponse : GPBMessage
+/**
+ * Request to retrieve token contract info for a specific token ID.
+ **/
+GPB_FINAL @interface GetTokenContractInfoRequest : GPBMessage
 
-@property(nonatomic, readonly) GetIdentityTokenInfosResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenContractInfoRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenContractInfoRequest_GetTokenContractInfoRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetIdentityTokenInfosResponse_ClearVersionOneOfCase(GetIdentityTokenInfosResponse *message);
+void GetTokenContractInfoRequest_ClearVersionOneOfCase(GetTokenContractInfoRequest *message);
 
-#pragma mark - GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0
+#pragma mark - GetTokenContractInfoRequest_GetTokenContractInfoRequestV0
 
-typedef GPB_ENUM(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_FieldNumber) {
-  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_FieldNumber_TokenInfos = 1,
-  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_FieldNumber_Proof = 2,
-  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetTokenContractInfoRequest_GetTokenContractInfoRequestV0_FieldNumber) {
+  GetTokenContractInfoRequest_GetTokenContractInfoRequestV0_FieldNumber_TokenId = 1,
+  GetTokenContractInfoRequest_GetTokenContractInfoRequestV0_FieldNumber_Prove = 2,
 };
 
-typedef GPB_ENUM(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_Result_OneOfCase) {
-  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_Result_OneOfCase_TokenInfos = 1,
-  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_Result_OneOfCase_Proof = 2,
-};
+GPB_FINAL @interface GetTokenContractInfoRequest_GetTokenContractInfoRequestV0 : GPBMessage
 
-GPB_FINAL @interface GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0 : GPBMessage
+/**
+ * The token ID to retrieve contract info for.
+ * Must be exactly 32 bytes.
+ **/
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-@property(nonatomic, readonly) GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_Result_OneOfCase resultOneOfCase;
+/** Whether to return a cryptographic proof. */
+@property(nonatomic, readwrite) BOOL prove;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfos *tokenInfos;
+@end
 
-@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+#pragma mark - GetTokenContractInfoResponse
 
-@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
-/** Test to see if @c metadata has been set. */
-@property(nonatomic, readwrite) BOOL hasMetadata;
+typedef GPB_ENUM(GetTokenContractInfoResponse_FieldNumber) {
+  GetTokenContractInfoResponse_FieldNumber_V0 = 1,
+};
 
-@end
+typedef GPB_ENUM(GetTokenContractInfoResponse_Version_OneOfCase) {
+  GetTokenContractInfoResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenContractInfoResponse_Version_OneOfCase_V0 = 1,
+};
 
 /**
- * Clears whatever value was set for the oneof 'result'.
+ * Response to GetTokenContractInfoRequest.
  **/
-void GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_ClearResultOneOfCase(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0 *message);
+GPB_FINAL @interface GetTokenContractInfoResponse : GPBMessage
 
-#pragma mark - GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenIdentityInfoEntry
+@property(nonatomic, readonly) GetTokenContractInfoResponse_Version_OneOfCase versionOneOfCase;
 
-typedef GPB_ENUM(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenIdentityInfoEntry_FieldNumber) {
-  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenIdentityInfoEntry_FieldNumber_Frozen = 1,
-};
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenContractInfoResponse_GetTokenContractInfoResponseV0 *v0;
 
-GPB_FINAL @interface GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenIdentityInfoEntry : GPBMessage
+@end
 
-@property(nonatomic, readwrite) BOOL frozen;
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetTokenContractInfoResponse_ClearVersionOneOfCase(GetTokenContractInfoResponse *message);
 
-@end
+#pragma mark - GetTokenContractInfoResponse_GetTokenContractInfoResponseV0
 
-#pragma mark - GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry
+typedef GPB_ENUM(GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_FieldNumber) {
+  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_FieldNumber_Data_p = 1,
+  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_FieldNumber_Proof = 2,
+  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_FieldNumber_Metadata = 3,
+};
 
-typedef GPB_ENUM(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry_FieldNumber) {
-  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry_FieldNumber_TokenId = 1,
-  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry_FieldNumber_Info = 2,
+typedef GPB_ENUM(GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_Result_OneOfCase) {
+  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_Result_OneOfCase_Data_p = 1,
+  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry : GPBMessage
+GPB_FINAL @interface GetTokenContractInfoResponse_GetTokenContractInfoResponseV0 : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
+@property(nonatomic, readonly) GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_Result_OneOfCase resultOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenIdentityInfoEntry *info;
-/** Test to see if @c info has been set. */
-@property(nonatomic, readwrite) BOOL hasInfo;
+/** Direct token contract data */
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData *data_p;
+
+/** Cryptographic proof of token contract info */
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+
+/** Metadata about the blockchain state at the time of the query */
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
 
 @end
 
-#pragma mark - GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfos
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_ClearResultOneOfCase(GetTokenContractInfoResponse_GetTokenContractInfoResponseV0 *message);
 
-typedef GPB_ENUM(GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfos_FieldNumber) {
-  GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfos_FieldNumber_TokenInfosArray = 1,
+#pragma mark - GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData
+
+typedef GPB_ENUM(GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData_FieldNumber) {
+  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData_FieldNumber_ContractId = 1,
+  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData_FieldNumber_TokenContractPosition = 2,
 };
 
-GPB_FINAL @interface GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfos : GPBMessage
+/**
+ * Direct token contract info.
+ **/
+GPB_FINAL @interface GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetIdentityTokenInfosResponse_GetIdentityTokenInfosResponseV0_TokenInfoEntry*> *tokenInfosArray;
-/** The number of items in @c tokenInfosArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger tokenInfosArray_Count;
+/** The ID of the contract associated with the token. */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
+
+/** The position of the token within the contract. */
+@property(nonatomic, readwrite) uint32_t tokenContractPosition;
 
 @end
 
-#pragma mark - GetIdentitiesTokenInfosRequest
+#pragma mark - GetTokenPreProgrammedDistributionsRequest
 
-typedef GPB_ENUM(GetIdentitiesTokenInfosRequest_FieldNumber) {
-  GetIdentitiesTokenInfosRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenPreProgrammedDistributionsRequest_FieldNumber) {
+  GetTokenPreProgrammedDistributionsRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetIdentitiesTokenInfosRequest_Version_OneOfCase) {
-  GetIdentitiesTokenInfosRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentitiesTokenInfosRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenPreProgrammedDistributionsRequest_Version_OneOfCase) {
+  GetTokenPreProgrammedDistributionsRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenPreProgrammedDistributionsRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenInfosRequest : GPBMessage
+GPB_FINAL @interface GetTokenPreProgrammedDistributionsRequest : GPBMessage
 
-@property(nonatomic, readonly) GetIdentitiesTokenInfosRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenPreProgrammedDistributionsRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetIdentitiesTokenInfosRequest_ClearVersionOneOfCase(GetIdentitiesTokenInfosRequest *message);
+void GetTokenPreProgrammedDistributionsRequest_ClearVersionOneOfCase(GetTokenPreProgrammedDistributionsRequest *message);
 
-#pragma mark - GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0
+#pragma mark - GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0
 
-typedef GPB_ENUM(GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0_FieldNumber) {
-  GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0_FieldNumber_TokenId = 1,
-  GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0_FieldNumber_IdentityIdsArray = 2,
-  GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0_FieldNumber_Prove = 3,
+typedef GPB_ENUM(GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_FieldNumber) {
+  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_FieldNumber_TokenId = 1,
+  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_FieldNumber_StartAtInfo = 2,
+  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_FieldNumber_Limit = 3,
+  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_FieldNumber_Prove = 4,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenInfosRequest_GetIdentitiesTokenInfosRequestV0 : GPBMessage
+GPB_FINAL @interface GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0 : GPBMessage
 
 @property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *identityIdsArray;
-/** The number of items in @c identityIdsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger identityIdsArray_Count;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo *startAtInfo;
+/** Test to see if @c startAtInfo has been set. */
+@property(nonatomic, readwrite) BOOL hasStartAtInfo;
 
+@property(nonatomic, readwrite) uint32_t limit;
+
+@property(nonatomic, readwrite) BOOL hasLimit;
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetIdentitiesTokenInfosResponse
+#pragma mark - GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo
 
-typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_FieldNumber) {
-  GetIdentitiesTokenInfosResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo_FieldNumber) {
+  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo_FieldNumber_StartTimeMs = 1,
+  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo_FieldNumber_StartRecipient = 2,
+  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo_FieldNumber_StartRecipientIncluded = 3,
 };
 
-typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_Version_OneOfCase) {
-  GetIdentitiesTokenInfosResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentitiesTokenInfosResponse_Version_OneOfCase_V0 = 1,
+GPB_FINAL @interface GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo : GPBMessage
+
+@property(nonatomic, readwrite) uint64_t startTimeMs;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *startRecipient;
+/** Test to see if @c startRecipient has been set. */
+@property(nonatomic, readwrite) BOOL hasStartRecipient;
+
+@property(nonatomic, readwrite) BOOL startRecipientIncluded;
+
+@property(nonatomic, readwrite) BOOL hasStartRecipientIncluded;
+@end
+
+#pragma mark - GetTokenPreProgrammedDistributionsResponse
+
+typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_FieldNumber) {
+  GetTokenPreProgrammedDistributionsResponse_FieldNumber_V0 = 1,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenInfosResponse : GPBMessage
+typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_Version_OneOfCase) {
+  GetTokenPreProgrammedDistributionsResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenPreProgrammedDistributionsResponse_Version_OneOfCase_V0 = 1,
+};
 
-@property(nonatomic, readonly) GetIdentitiesTokenInfosResponse_Version_OneOfCase versionOneOfCase;
+GPB_FINAL @interface GetTokenPreProgrammedDistributionsResponse : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0 *v0;
+@property(nonatomic, readonly) GetTokenPreProgrammedDistributionsResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetIdentitiesTokenInfosResponse_ClearVersionOneOfCase(GetIdentitiesTokenInfosResponse *message);
+void GetTokenPreProgrammedDistributionsResponse_ClearVersionOneOfCase(GetTokenPreProgrammedDistributionsResponse *message);
 
-#pragma mark - GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0
+#pragma mark - GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0
 
-typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_FieldNumber) {
-  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_FieldNumber_IdentityTokenInfos = 1,
-  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_FieldNumber_Proof = 2,
-  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_FieldNumber) {
+  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_FieldNumber_TokenDistributions = 1,
+  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_FieldNumber_Proof = 2,
+  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_Result_OneOfCase) {
-  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_Result_OneOfCase_IdentityTokenInfos = 1,
-  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_Result_OneOfCase) {
+  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_Result_OneOfCase_TokenDistributions = 1,
+  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0 : GPBMessage
+GPB_FINAL @interface GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_Result_OneOfCase resultOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_IdentityTokenInfos *identityTokenInfos;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributions *tokenDistributions;
 
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
@@ -5699,138 +7560,173 @@ GPB_FINAL @interface GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResp
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_ClearResultOneOfCase(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0 *message);
+void GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_ClearResultOneOfCase(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0 *message);
 
-#pragma mark - GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenIdentityInfoEntry
+#pragma mark - GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry
 
-typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenIdentityInfoEntry_FieldNumber) {
-  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenIdentityInfoEntry_FieldNumber_Frozen = 1,
+typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry_FieldNumber) {
+  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry_FieldNumber_RecipientId = 1,
+  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry_FieldNumber_Amount = 2,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenIdentityInfoEntry : GPBMessage
+GPB_FINAL @interface GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry : GPBMessage
 
-@property(nonatomic, readwrite) BOOL frozen;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *recipientId;
+
+@property(nonatomic, readwrite) uint64_t amount;
 
 @end
 
-#pragma mark - GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry
+#pragma mark - GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry
 
-typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry_FieldNumber) {
-  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry_FieldNumber_IdentityId = 1,
-  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry_FieldNumber_Info = 2,
+typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry_FieldNumber) {
+  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry_FieldNumber_Timestamp = 1,
+  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry_FieldNumber_DistributionsArray = 2,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry : GPBMessage
+GPB_FINAL @interface GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
+@property(nonatomic, readwrite) uint64_t timestamp;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenIdentityInfoEntry *info;
-/** Test to see if @c info has been set. */
-@property(nonatomic, readwrite) BOOL hasInfo;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry*> *distributionsArray;
+/** The number of items in @c distributionsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger distributionsArray_Count;
 
 @end
 
-#pragma mark - GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_IdentityTokenInfos
+#pragma mark - GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributions
 
-typedef GPB_ENUM(GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_IdentityTokenInfos_FieldNumber) {
-  GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_IdentityTokenInfos_FieldNumber_TokenInfosArray = 1,
+typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributions_FieldNumber) {
+  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributions_FieldNumber_TokenDistributionsArray = 1,
 };
 
-GPB_FINAL @interface GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_IdentityTokenInfos : GPBMessage
+GPB_FINAL @interface GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributions : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetIdentitiesTokenInfosResponse_GetIdentitiesTokenInfosResponseV0_TokenInfoEntry*> *tokenInfosArray;
-/** The number of items in @c tokenInfosArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger tokenInfosArray_Count;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry*> *tokenDistributionsArray;
+/** The number of items in @c tokenDistributionsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger tokenDistributionsArray_Count;
 
 @end
 
-#pragma mark - GetTokenStatusesRequest
+#pragma mark - GetTokenPerpetualDistributionLastClaimRequest
 
-typedef GPB_ENUM(GetTokenStatusesRequest_FieldNumber) {
-  GetTokenStatusesRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimRequest_FieldNumber) {
+  GetTokenPerpetualDistributionLastClaimRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetTokenStatusesRequest_Version_OneOfCase) {
-  GetTokenStatusesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenStatusesRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimRequest_Version_OneOfCase) {
+  GetTokenPerpetualDistributionLastClaimRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenPerpetualDistributionLastClaimRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetTokenStatusesRequest : GPBMessage
+GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimRequest : GPBMessage
 
-@property(nonatomic, readonly) GetTokenStatusesRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenPerpetualDistributionLastClaimRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenStatusesRequest_GetTokenStatusesRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenStatusesRequest_ClearVersionOneOfCase(GetTokenStatusesRequest *message);
+void GetTokenPerpetualDistributionLastClaimRequest_ClearVersionOneOfCase(GetTokenPerpetualDistributionLastClaimRequest *message);
 
-#pragma mark - GetTokenStatusesRequest_GetTokenStatusesRequestV0
+#pragma mark - GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo
 
-typedef GPB_ENUM(GetTokenStatusesRequest_GetTokenStatusesRequestV0_FieldNumber) {
-  GetTokenStatusesRequest_GetTokenStatusesRequestV0_FieldNumber_TokenIdsArray = 1,
-  GetTokenStatusesRequest_GetTokenStatusesRequestV0_FieldNumber_Prove = 2,
+typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo_FieldNumber) {
+  GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo_FieldNumber_ContractId = 1,
+  GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo_FieldNumber_TokenContractPosition = 2,
 };
 
-GPB_FINAL @interface GetTokenStatusesRequest_GetTokenStatusesRequestV0 : GPBMessage
+GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *tokenIdsArray;
-/** The number of items in @c tokenIdsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger tokenIdsArray_Count;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
+
+/** the token contract position */
+@property(nonatomic, readwrite) uint32_t tokenContractPosition;
 
+@end
+
+#pragma mark - GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0
+
+typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0_FieldNumber) {
+  GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0_FieldNumber_TokenId = 1,
+  GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0_FieldNumber_ContractInfo = 2,
+  GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0_FieldNumber_IdentityId = 4,
+  GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0_FieldNumber_Prove = 5,
+};
+
+GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0 : GPBMessage
+
+/** 32‑byte token identifier */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
+
+/**
+ * This should be set if you wish to get back the last claim info as a
+ * specific type
+ **/
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo *contractInfo;
+/** Test to see if @c contractInfo has been set. */
+@property(nonatomic, readwrite) BOOL hasContractInfo;
+
+/** Identity whose last‑claim timestamp is requested */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
+
+/** Return GroveDB / signature proof instead of raw value */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetTokenStatusesResponse
+#pragma mark - GetTokenPerpetualDistributionLastClaimResponse
 
-typedef GPB_ENUM(GetTokenStatusesResponse_FieldNumber) {
-  GetTokenStatusesResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_FieldNumber) {
+  GetTokenPerpetualDistributionLastClaimResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetTokenStatusesResponse_Version_OneOfCase) {
-  GetTokenStatusesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenStatusesResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_Version_OneOfCase) {
+  GetTokenPerpetualDistributionLastClaimResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenPerpetualDistributionLastClaimResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetTokenStatusesResponse : GPBMessage
+GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimResponse : GPBMessage
 
-@property(nonatomic, readonly) GetTokenStatusesResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenPerpetualDistributionLastClaimResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenStatusesResponse_GetTokenStatusesResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenStatusesResponse_ClearVersionOneOfCase(GetTokenStatusesResponse *message);
-
-#pragma mark - GetTokenStatusesResponse_GetTokenStatusesResponseV0
+void GetTokenPerpetualDistributionLastClaimResponse_ClearVersionOneOfCase(GetTokenPerpetualDistributionLastClaimResponse *message);
 
-typedef GPB_ENUM(GetTokenStatusesResponse_GetTokenStatusesResponseV0_FieldNumber) {
-  GetTokenStatusesResponse_GetTokenStatusesResponseV0_FieldNumber_TokenStatuses = 1,
-  GetTokenStatusesResponse_GetTokenStatusesResponseV0_FieldNumber_Proof = 2,
-  GetTokenStatusesResponse_GetTokenStatusesResponseV0_FieldNumber_Metadata = 3,
-};
+#pragma mark - GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0
 
-typedef GPB_ENUM(GetTokenStatusesResponse_GetTokenStatusesResponseV0_Result_OneOfCase) {
-  GetTokenStatusesResponse_GetTokenStatusesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenStatusesResponse_GetTokenStatusesResponseV0_Result_OneOfCase_TokenStatuses = 1,
-  GetTokenStatusesResponse_GetTokenStatusesResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_FieldNumber) {
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_FieldNumber_LastClaim = 1,
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_FieldNumber_Proof = 2,
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_FieldNumber_Metadata = 3,
 };
 
-GPB_FINAL @interface GetTokenStatusesResponse_GetTokenStatusesResponseV0 : GPBMessage
+typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_Result_OneOfCase) {
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_Result_OneOfCase_LastClaim = 1,
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_Result_OneOfCase_Proof = 2,
+};
 
-@property(nonatomic, readonly) GetTokenStatusesResponse_GetTokenStatusesResponseV0_Result_OneOfCase resultOneOfCase;
+GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0 : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatuses *tokenStatuses;
+@property(nonatomic, readonly) GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_Result_OneOfCase resultOneOfCase;
+
+/** Direct answer */
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo *lastClaim;
 
+/** GroveDB / quorum proof */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
+/** Chain context */
 @property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
 /** Test to see if @c metadata has been set. */
 @property(nonatomic, readwrite) BOOL hasMetadata;
@@ -5840,151 +7736,133 @@ GPB_FINAL @interface GetTokenStatusesResponse_GetTokenStatusesResponseV0 : GPBMe
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetTokenStatusesResponse_GetTokenStatusesResponseV0_ClearResultOneOfCase(GetTokenStatusesResponse_GetTokenStatusesResponseV0 *message);
+void GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_ClearResultOneOfCase(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0 *message);
 
-#pragma mark - GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry
+#pragma mark - GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo
 
-typedef GPB_ENUM(GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry_FieldNumber) {
-  GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry_FieldNumber_TokenId = 1,
-  GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry_FieldNumber_Paused = 2,
+typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_FieldNumber) {
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_FieldNumber_TimestampMs = 1,
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_FieldNumber_BlockHeight = 2,
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_FieldNumber_Epoch = 3,
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_FieldNumber_RawBytes = 4,
 };
 
-GPB_FINAL @interface GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry : GPBMessage
-
-@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
+typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase) {
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase_TimestampMs = 1,
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase_BlockHeight = 2,
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase_Epoch = 3,
+  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase_RawBytes = 4,
+};
 
-@property(nonatomic, readwrite) BOOL paused;
+GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo : GPBMessage
 
-@property(nonatomic, readwrite) BOOL hasPaused;
-@end
+@property(nonatomic, readonly) GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase paidAtOneOfCase;
 
-#pragma mark - GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatuses
+/** Unix epoch, ms */
+@property(nonatomic, readwrite) uint64_t timestampMs;
 
-typedef GPB_ENUM(GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatuses_FieldNumber) {
-  GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatuses_FieldNumber_TokenStatusesArray = 1,
-};
+/** Core‑block height */
+@property(nonatomic, readwrite) uint64_t blockHeight;
 
-GPB_FINAL @interface GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatuses : GPBMessage
+/** Epoch index */
+@property(nonatomic, readwrite) uint32_t epoch;
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenStatusesResponse_GetTokenStatusesResponseV0_TokenStatusEntry*> *tokenStatusesArray;
-/** The number of items in @c tokenStatusesArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger tokenStatusesArray_Count;
+/** Arbitrary encoding */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *rawBytes;
 
 @end
 
-#pragma mark - GetTokenDirectPurchasePricesRequest
+/**
+ * Clears whatever value was set for the oneof 'paidAt'.
+ **/
+void GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_ClearPaidAtOneOfCase(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo *message);
 
-typedef GPB_ENUM(GetTokenDirectPurchasePricesRequest_FieldNumber) {
-  GetTokenDirectPurchasePricesRequest_FieldNumber_V0 = 1,
+#pragma mark - GetTokenTotalSupplyRequest
+
+typedef GPB_ENUM(GetTokenTotalSupplyRequest_FieldNumber) {
+  GetTokenTotalSupplyRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetTokenDirectPurchasePricesRequest_Version_OneOfCase) {
-  GetTokenDirectPurchasePricesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenDirectPurchasePricesRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenTotalSupplyRequest_Version_OneOfCase) {
+  GetTokenTotalSupplyRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenTotalSupplyRequest_Version_OneOfCase_V0 = 1,
 };
 
-/**
- * Retrieve direct purchase prices defined for one or more tokens.
- *
- * Some tokens can have a direct purchase price defined using
- * `TokenSetPriceForDirectPurchaseTransition` (see `dpp` crate for details).
- * This request retrieves the direct purchase prices for those tokens and
- * returns [GetTokenDirectPurchasePricesResponse].
- **/
-GPB_FINAL @interface GetTokenDirectPurchasePricesRequest : GPBMessage
+GPB_FINAL @interface GetTokenTotalSupplyRequest : GPBMessage
 
-@property(nonatomic, readonly) GetTokenDirectPurchasePricesRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenTotalSupplyRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenDirectPurchasePricesRequest_ClearVersionOneOfCase(GetTokenDirectPurchasePricesRequest *message);
+void GetTokenTotalSupplyRequest_ClearVersionOneOfCase(GetTokenTotalSupplyRequest *message);
 
-#pragma mark - GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0
+#pragma mark - GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0
 
-typedef GPB_ENUM(GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0_FieldNumber) {
-  GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0_FieldNumber_TokenIdsArray = 1,
-  GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0_FieldNumber_Prove = 2,
+typedef GPB_ENUM(GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0_FieldNumber) {
+  GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0_FieldNumber_TokenId = 1,
+  GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0_FieldNumber_Prove = 2,
 };
 
-GPB_FINAL @interface GetTokenDirectPurchasePricesRequest_GetTokenDirectPurchasePricesRequestV0 : GPBMessage
+GPB_FINAL @interface GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0 : GPBMessage
 
-/**
- * List of token IDs to get prices for.
- *
- * The list must not be empty.
- * Token IDs must have 32 bytes and be unique.
- * Results for non-unique token IDs are undefined.
- **/
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *tokenIdsArray;
-/** The number of items in @c tokenIdsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger tokenIdsArray_Count;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-/** Whether to return proofs for the response, or just direct response. */
 @property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetTokenDirectPurchasePricesResponse
+#pragma mark - GetTokenTotalSupplyResponse
 
-typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_FieldNumber) {
-  GetTokenDirectPurchasePricesResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenTotalSupplyResponse_FieldNumber) {
+  GetTokenTotalSupplyResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_Version_OneOfCase) {
-  GetTokenDirectPurchasePricesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenDirectPurchasePricesResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenTotalSupplyResponse_Version_OneOfCase) {
+  GetTokenTotalSupplyResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenTotalSupplyResponse_Version_OneOfCase_V0 = 1,
 };
 
-/**
- * Response to GetTokenDirectPurchasePricesRequest, containing information about
- * direct purchase prices defined for requested token IDs.
- **/
-GPB_FINAL @interface GetTokenDirectPurchasePricesResponse : GPBMessage
+GPB_FINAL @interface GetTokenTotalSupplyResponse : GPBMessage
 
-@property(nonatomic, readonly) GetTokenDirectPurchasePricesResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenTotalSupplyResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenDirectPurchasePricesResponse_ClearVersionOneOfCase(GetTokenDirectPurchasePricesResponse *message);
+void GetTokenTotalSupplyResponse_ClearVersionOneOfCase(GetTokenTotalSupplyResponse *message);
 
-#pragma mark - GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0
+#pragma mark - GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0
 
-typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_FieldNumber) {
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_FieldNumber_TokenDirectPurchasePrices = 1,
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_FieldNumber_Proof = 2,
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_FieldNumber) {
+  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_FieldNumber_TokenTotalSupply = 1,
+  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_FieldNumber_Proof = 2,
+  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_Result_OneOfCase) {
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_Result_OneOfCase_TokenDirectPurchasePrices = 1,
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_Result_OneOfCase) {
+  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_Result_OneOfCase_TokenTotalSupply = 1,
+  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0 : GPBMessage
+GPB_FINAL @interface GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** Contains the list of token IDs and their corresponding direct */
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePrices *tokenDirectPurchasePrices;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry *tokenTotalSupply;
 
-/**
- * Requested information in a form of cryptographic proof.
- * In Rust, use `FromProof` trait to convert it to the actual data.
- **/
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
-/** Metadata about the blockchain state. */
 @property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
 /** Test to see if @c metadata has been set. */
 @property(nonatomic, readwrite) BOOL hasMetadata;
@@ -5994,200 +7872,142 @@ GPB_FINAL @interface GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchase
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_ClearResultOneOfCase(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0 *message);
-
-#pragma mark - GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity
-
-typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity_FieldNumber) {
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity_FieldNumber_Quantity = 1,
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity_FieldNumber_Price = 2,
-};
-
-/**
- * Contains the individual price tier for a specific quantity of tokens.
- **/
-GPB_FINAL @interface GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity : GPBMessage
-
-/** Minimum quantity of tokens to purchase to get this price. */
-@property(nonatomic, readwrite) uint64_t quantity;
-
-/** Price for the specified quantity of tokens. */
-@property(nonatomic, readwrite) uint64_t price;
-
-@end
-
-#pragma mark - GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PricingSchedule
-
-typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PricingSchedule_FieldNumber) {
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PricingSchedule_FieldNumber_PriceForQuantityArray = 1,
-};
-
-/**
- * Contains list of price tiers for a specific token.
- **/
-GPB_FINAL @interface GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PricingSchedule : GPBMessage
-
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PriceForQuantity*> *priceForQuantityArray;
-/** The number of items in @c priceForQuantityArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger priceForQuantityArray_Count;
-
-@end
-
-#pragma mark - GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry
+void GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_ClearResultOneOfCase(GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0 *message);
 
-typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_FieldNumber) {
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_FieldNumber_TokenId = 1,
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_FieldNumber_FixedPrice = 2,
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_FieldNumber_VariablePrice = 3,
-};
+#pragma mark - GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry
 
-typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_Price_OneOfCase) {
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_Price_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_Price_OneOfCase_FixedPrice = 2,
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_Price_OneOfCase_VariablePrice = 3,
+typedef GPB_ENUM(GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry_FieldNumber) {
+  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry_FieldNumber_TokenId = 1,
+  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry_FieldNumber_TotalAggregatedAmountInUserAccounts = 2,
+  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry_FieldNumber_TotalSystemAmount = 3,
 };
 
-GPB_FINAL @interface GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry : GPBMessage
+GPB_FINAL @interface GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry : GPBMessage
 
-/** 32-byte token identifier */
 @property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-/** Price of the token; optional */
-@property(nonatomic, readonly) GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_Price_OneOfCase priceOneOfCase;
-
-/** Fixed price for the token. */
-@property(nonatomic, readwrite) uint64_t fixedPrice;
-
-/**
- * Tiered pricing for the token, where the price varies based on the
- * quantity purchased.
- **/
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_PricingSchedule *variablePrice;
-
-@end
-
-/**
- * Clears whatever value was set for the oneof 'price'.
- **/
-void GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry_ClearPriceOneOfCase(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry *message);
-
-#pragma mark - GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePrices
-
-typedef GPB_ENUM(GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePrices_FieldNumber) {
-  GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePrices_FieldNumber_TokenDirectPurchasePriceArray = 1,
-};
-
-/**
- * For each requested token, contains list of token IDs and their
- * corresponding direct purchase prices.
- **/
-GPB_FINAL @interface GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePrices : GPBMessage
+@property(nonatomic, readwrite) uint64_t totalAggregatedAmountInUserAccounts;
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenDirectPurchasePricesResponse_GetTokenDirectPurchasePricesResponseV0_TokenDirectPurchasePriceEntry*> *tokenDirectPurchasePriceArray;
-/** The number of items in @c tokenDirectPurchasePriceArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger tokenDirectPurchasePriceArray_Count;
+@property(nonatomic, readwrite) uint64_t totalSystemAmount;
 
 @end
 
-#pragma mark - GetTokenContractInfoRequest
+#pragma mark - GetTokenHoldersRequest
 
-typedef GPB_ENUM(GetTokenContractInfoRequest_FieldNumber) {
-  GetTokenContractInfoRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenHoldersRequest_FieldNumber) {
+  GetTokenHoldersRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetTokenContractInfoRequest_Version_OneOfCase) {
-  GetTokenContractInfoRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenContractInfoRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenHoldersRequest_Version_OneOfCase) {
+  GetTokenHoldersRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenHoldersRequest_Version_OneOfCase_V0 = 1,
 };
 
 /**
- * Request to retrieve token contract info for a specific token ID.
+ * Balances of all holders of a token, paginated by identity id
  **/
-GPB_FINAL @interface GetTokenContractInfoRequest : GPBMessage
+GPB_FINAL @interface GetTokenHoldersRequest : GPBMessage
 
-@property(nonatomic, readonly) GetTokenContractInfoRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenHoldersRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenContractInfoRequest_GetTokenContractInfoRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenHoldersRequest_GetTokenHoldersRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenContractInfoRequest_ClearVersionOneOfCase(GetTokenContractInfoRequest *message);
+void GetTokenHoldersRequest_ClearVersionOneOfCase(GetTokenHoldersRequest *message);
 
-#pragma mark - GetTokenContractInfoRequest_GetTokenContractInfoRequestV0
+#pragma mark - GetTokenHoldersRequest_StartAtIdentityId
 
-typedef GPB_ENUM(GetTokenContractInfoRequest_GetTokenContractInfoRequestV0_FieldNumber) {
-  GetTokenContractInfoRequest_GetTokenContractInfoRequestV0_FieldNumber_TokenId = 1,
-  GetTokenContractInfoRequest_GetTokenContractInfoRequestV0_FieldNumber_Prove = 2,
+typedef GPB_ENUM(GetTokenHoldersRequest_StartAtIdentityId_FieldNumber) {
+  GetTokenHoldersRequest_StartAtIdentityId_FieldNumber_StartIdentityId = 1,
+  GetTokenHoldersRequest_StartAtIdentityId_FieldNumber_StartIdentityIdIncluded = 2,
 };
 
-GPB_FINAL @interface GetTokenContractInfoRequest_GetTokenContractInfoRequestV0 : GPBMessage
+GPB_FINAL @interface GetTokenHoldersRequest_StartAtIdentityId : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *startIdentityId;
+
+@property(nonatomic, readwrite) BOOL startIdentityIdIncluded;
+
+@end
+
+#pragma mark - GetTokenHoldersRequest_GetTokenHoldersRequestV0
+
+typedef GPB_ENUM(GetTokenHoldersRequest_GetTokenHoldersRequestV0_FieldNumber) {
+  GetTokenHoldersRequest_GetTokenHoldersRequestV0_FieldNumber_TokenId = 1,
+  GetTokenHoldersRequest_GetTokenHoldersRequestV0_FieldNumber_StartAtIdentityId = 2,
+  GetTokenHoldersRequest_GetTokenHoldersRequestV0_FieldNumber_Count = 3,
+  GetTokenHoldersRequest_GetTokenHoldersRequestV0_FieldNumber_Prove = 4,
+  GetTokenHoldersRequest_GetTokenHoldersRequestV0_FieldNumber_OrderByBalance = 5,
+};
+
+GPB_FINAL @interface GetTokenHoldersRequest_GetTokenHoldersRequestV0 : GPBMessage
 
-/**
- * The token ID to retrieve contract info for.
- * Must be exactly 32 bytes.
- **/
 @property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-/** Whether to return a cryptographic proof. */
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenHoldersRequest_StartAtIdentityId *startAtIdentityId;
+/** Test to see if @c startAtIdentityId has been set. */
+@property(nonatomic, readwrite) BOOL hasStartAtIdentityId;
+
+@property(nonatomic, readwrite) uint32_t count;
+
+@property(nonatomic, readwrite) BOOL hasCount;
 @property(nonatomic, readwrite) BOOL prove;
 
+/** Sort the page by balance, highest first */
+@property(nonatomic, readwrite) BOOL orderByBalance;
+
 @end
 
-#pragma mark - GetTokenContractInfoResponse
+#pragma mark - GetTokenHoldersResponse
 
-typedef GPB_ENUM(GetTokenContractInfoResponse_FieldNumber) {
-  GetTokenContractInfoResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenHoldersResponse_FieldNumber) {
+  GetTokenHoldersResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetTokenContractInfoResponse_Version_OneOfCase) {
-  GetTokenContractInfoResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenContractInfoResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenHoldersResponse_Version_OneOfCase) {
+  GetTokenHoldersResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenHoldersResponse_Version_OneOfCase_V0 = 1,
 };
 
-/**
- * Response to GetTokenContractInfoRequest.
- **/
-GPB_FINAL @interface GetTokenContractInfoResponse : GPBMessage
+GPB_FINAL @interface GetTokenHoldersResponse : GPBMessage
 
-@property(nonatomic, readonly) GetTokenContractInfoResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenHoldersResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenContractInfoResponse_GetTokenContractInfoResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenHoldersResponse_GetTokenHoldersResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenContractInfoResponse_ClearVersionOneOfCase(GetTokenContractInfoResponse *message);
+void GetTokenHoldersResponse_ClearVersionOneOfCase(GetTokenHoldersResponse *message);
 
-#pragma mark - GetTokenContractInfoResponse_GetTokenContractInfoResponseV0
+#pragma mark - GetTokenHoldersResponse_GetTokenHoldersResponseV0
 
-typedef GPB_ENUM(GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_FieldNumber) {
-  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_FieldNumber_Data_p = 1,
-  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_FieldNumber_Proof = 2,
-  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetTokenHoldersResponse_GetTokenHoldersResponseV0_FieldNumber) {
+  GetTokenHoldersResponse_GetTokenHoldersResponseV0_FieldNumber_TokenHolders = 1,
+  GetTokenHoldersResponse_GetTokenHoldersResponseV0_FieldNumber_Proof = 2,
+  GetTokenHoldersResponse_GetTokenHoldersResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_Result_OneOfCase) {
-  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_Result_OneOfCase_Data_p = 1,
-  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetTokenHoldersResponse_GetTokenHoldersResponseV0_Result_OneOfCase) {
+  GetTokenHoldersResponse_GetTokenHoldersResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenHoldersResponse_GetTokenHoldersResponseV0_Result_OneOfCase_TokenHolders = 1,
+  GetTokenHoldersResponse_GetTokenHoldersResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetTokenContractInfoResponse_GetTokenContractInfoResponseV0 : GPBMessage
+GPB_FINAL @interface GetTokenHoldersResponse_GetTokenHoldersResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetTokenHoldersResponse_GetTokenHoldersResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** Direct token contract data */
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData *data_p;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolders *tokenHolders;
 
-/** Cryptographic proof of token contract info */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
-/** Metadata about the blockchain state at the time of the query */
 @property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
 /** Test to see if @c metadata has been set. */
 @property(nonatomic, readwrite) BOOL hasMetadata;
@@ -6197,140 +8017,166 @@ GPB_FINAL @interface GetTokenContractInfoResponse_GetTokenContractInfoResponseV0
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_ClearResultOneOfCase(GetTokenContractInfoResponse_GetTokenContractInfoResponseV0 *message);
+void GetTokenHoldersResponse_GetTokenHoldersResponseV0_ClearResultOneOfCase(GetTokenHoldersResponse_GetTokenHoldersResponseV0 *message);
 
-#pragma mark - GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData
+#pragma mark - GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolderEntry
 
-typedef GPB_ENUM(GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData_FieldNumber) {
-  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData_FieldNumber_ContractId = 1,
-  GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData_FieldNumber_TokenContractPosition = 2,
+typedef GPB_ENUM(GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolderEntry_FieldNumber) {
+  GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolderEntry_FieldNumber_IdentityId = 1,
+  GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolderEntry_FieldNumber_Balance = 2,
 };
 
-/**
- * Direct token contract info.
- **/
-GPB_FINAL @interface GetTokenContractInfoResponse_GetTokenContractInfoResponseV0_TokenContractInfoData : GPBMessage
+GPB_FINAL @interface GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolderEntry : GPBMessage
 
-/** The ID of the contract associated with the token. */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
 
-/** The position of the token within the contract. */
-@property(nonatomic, readwrite) uint32_t tokenContractPosition;
+@property(nonatomic, readwrite) uint64_t balance;
 
 @end
 
-#pragma mark - GetTokenPreProgrammedDistributionsRequest
+#pragma mark - GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolders
 
-typedef GPB_ENUM(GetTokenPreProgrammedDistributionsRequest_FieldNumber) {
-  GetTokenPreProgrammedDistributionsRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolders_FieldNumber) {
+  GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolders_FieldNumber_TokenHoldersArray = 1,
 };
 
-typedef GPB_ENUM(GetTokenPreProgrammedDistributionsRequest_Version_OneOfCase) {
-  GetTokenPreProgrammedDistributionsRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenPreProgrammedDistributionsRequest_Version_OneOfCase_V0 = 1,
+GPB_FINAL @interface GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolders : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenHoldersResponse_GetTokenHoldersResponseV0_TokenHolderEntry*> *tokenHoldersArray;
+/** The number of items in @c tokenHoldersArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger tokenHoldersArray_Count;
+
+@end
+
+#pragma mark - GetTokenOrderBookRequest
+
+typedef GPB_ENUM(GetTokenOrderBookRequest_FieldNumber) {
+  GetTokenOrderBookRequest_FieldNumber_V0 = 1,
 };
 
-GPB_FINAL @interface GetTokenPreProgrammedDistributionsRequest : GPBMessage
+typedef GPB_ENUM(GetTokenOrderBookRequest_Version_OneOfCase) {
+  GetTokenOrderBookRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenOrderBookRequest_Version_OneOfCase_V0 = 1,
+};
 
-@property(nonatomic, readonly) GetTokenPreProgrammedDistributionsRequest_Version_OneOfCase versionOneOfCase;
+/**
+ * Resting limit orders of one side of a token order book, in price-time
+ * priority
+ **/
+GPB_FINAL @interface GetTokenOrderBookRequest : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0 *v0;
+@property(nonatomic, readonly) GetTokenOrderBookRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenOrderBookRequest_GetTokenOrderBookRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenPreProgrammedDistributionsRequest_ClearVersionOneOfCase(GetTokenPreProgrammedDistributionsRequest *message);
+void GetTokenOrderBookRequest_ClearVersionOneOfCase(GetTokenOrderBookRequest *message);
 
-#pragma mark - GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0
+#pragma mark - GetTokenOrderBookRequest_QuoteToken
 
-typedef GPB_ENUM(GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_FieldNumber) {
-  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_FieldNumber_TokenId = 1,
-  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_FieldNumber_StartAtInfo = 2,
-  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_FieldNumber_Limit = 3,
-  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_FieldNumber_Prove = 4,
+typedef GPB_ENUM(GetTokenOrderBookRequest_QuoteToken_FieldNumber) {
+  GetTokenOrderBookRequest_QuoteToken_FieldNumber_ContractId = 1,
+  GetTokenOrderBookRequest_QuoteToken_FieldNumber_TokenPosition = 2,
 };
 
-GPB_FINAL @interface GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0 : GPBMessage
-
-@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
-
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo *startAtInfo;
-/** Test to see if @c startAtInfo has been set. */
-@property(nonatomic, readwrite) BOOL hasStartAtInfo;
+GPB_FINAL @interface GetTokenOrderBookRequest_QuoteToken : GPBMessage
 
-@property(nonatomic, readwrite) uint32_t limit;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
 
-@property(nonatomic, readwrite) BOOL hasLimit;
-@property(nonatomic, readwrite) BOOL prove;
+@property(nonatomic, readwrite) uint32_t tokenPosition;
 
 @end
 
-#pragma mark - GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo
+#pragma mark - GetTokenOrderBookRequest_GetTokenOrderBookRequestV0
 
-typedef GPB_ENUM(GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo_FieldNumber) {
-  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo_FieldNumber_StartTimeMs = 1,
-  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo_FieldNumber_StartRecipient = 2,
-  GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo_FieldNumber_StartRecipientIncluded = 3,
+typedef GPB_ENUM(GetTokenOrderBookRequest_GetTokenOrderBookRequestV0_FieldNumber) {
+  GetTokenOrderBookRequest_GetTokenOrderBookRequestV0_FieldNumber_TokenId = 1,
+  GetTokenOrderBookRequest_GetTokenOrderBookRequestV0_FieldNumber_Side = 2,
+  GetTokenOrderBookRequest_GetTokenOrderBookRequestV0_FieldNumber_QuoteToken = 3,
+  GetTokenOrderBookRequest_GetTokenOrderBookRequestV0_FieldNumber_Count = 4,
+  GetTokenOrderBookRequest_GetTokenOrderBookRequestV0_FieldNumber_Prove = 5,
 };
 
-GPB_FINAL @interface GetTokenPreProgrammedDistributionsRequest_GetTokenPreProgrammedDistributionsRequestV0_StartAtInfo : GPBMessage
+GPB_FINAL @interface GetTokenOrderBookRequest_GetTokenOrderBookRequestV0 : GPBMessage
 
-@property(nonatomic, readwrite) uint64_t startTimeMs;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *startRecipient;
-/** Test to see if @c startRecipient has been set. */
-@property(nonatomic, readwrite) BOOL hasStartRecipient;
+@property(nonatomic, readwrite) GetTokenOrderBookRequest_OrderSide side;
 
-@property(nonatomic, readwrite) BOOL startRecipientIncluded;
+/**
+ * The token the orders are quoted in, orders quoted in Dash credits if not
+ * set
+ **/
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenOrderBookRequest_QuoteToken *quoteToken;
+/** Test to see if @c quoteToken has been set. */
+@property(nonatomic, readwrite) BOOL hasQuoteToken;
+
+@property(nonatomic, readwrite) uint32_t count;
+
+@property(nonatomic, readwrite) BOOL hasCount;
+@property(nonatomic, readwrite) BOOL prove;
 
-@property(nonatomic, readwrite) BOOL hasStartRecipientIncluded;
 @end
 
-#pragma mark - GetTokenPreProgrammedDistributionsResponse
+/**
+ * Fetches the raw value of a @c GetTokenOrderBookRequest_GetTokenOrderBookRequestV0's @c side property, even
+ * if the value was not defined by the enum at the time the code was generated.
+ **/
+int32_t GetTokenOrderBookRequest_GetTokenOrderBookRequestV0_Side_RawValue(GetTokenOrderBookRequest_GetTokenOrderBookRequestV0 *message);
+/**
+ * Sets the raw value of an @c GetTokenOrderBookRequest_GetTokenOrderBookRequestV0's @c side property, allowing
+ * it to be set to a value that was not defined by the enum at the time the code
+ * was generated.
+ **/
+void SetGetTokenOrderBookRequest_GetTokenOrderBookRequestV0_Side_RawValue(GetTokenOrderBookRequest_GetTokenOrderBookRequestV0 *message, int32_t value);
 
-typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_FieldNumber) {
-  GetTokenPreProgrammedDistributionsResponse_FieldNumber_V0 = 1,
+#pragma mark - GetTokenOrderBookResponse
+
+typedef GPB_ENUM(GetTokenOrderBookResponse_FieldNumber) {
+  GetTokenOrderBookResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_Version_OneOfCase) {
-  GetTokenPreProgrammedDistributionsResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenPreProgrammedDistributionsResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenOrderBookResponse_Version_OneOfCase) {
+  GetTokenOrderBookResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenOrderBookResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetTokenPreProgrammedDistributionsResponse : GPBMessage
+GPB_FINAL @interface GetTokenOrderBookResponse : GPBMessage
 
-@property(nonatomic, readonly) GetTokenPreProgrammedDistributionsResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenOrderBookResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenOrderBookResponse_GetTokenOrderBookResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenPreProgrammedDistributionsResponse_ClearVersionOneOfCase(GetTokenPreProgrammedDistributionsResponse *message);
+void GetTokenOrderBookResponse_ClearVersionOneOfCase(GetTokenOrderBookResponse *message);
 
-#pragma mark - GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0
+#pragma mark - GetTokenOrderBookResponse_GetTokenOrderBookResponseV0
 
-typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_FieldNumber) {
-  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_FieldNumber_TokenDistributions = 1,
-  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_FieldNumber_Proof = 2,
-  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_FieldNumber) {
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_FieldNumber_Orders = 1,
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_FieldNumber_Proof = 2,
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_Result_OneOfCase) {
-  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_Result_OneOfCase_TokenDistributions = 1,
-  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_Result_OneOfCase) {
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_Result_OneOfCase_Orders = 1,
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0 : GPBMessage
+GPB_FINAL @interface GetTokenOrderBookResponse_GetTokenOrderBookResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_Result_OneOfCase resultOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributions *tokenDistributions;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrders *orders;
 
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
@@ -6343,173 +8189,313 @@ GPB_FINAL @interface GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgr
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_ClearResultOneOfCase(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0 *message);
+void GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_ClearResultOneOfCase(GetTokenOrderBookResponse_GetTokenOrderBookResponseV0 *message);
 
-#pragma mark - GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry
+#pragma mark - GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrderEntry
 
-typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry_FieldNumber) {
-  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry_FieldNumber_RecipientId = 1,
-  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry_FieldNumber_Amount = 2,
+typedef GPB_ENUM(GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrderEntry_FieldNumber) {
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrderEntry_FieldNumber_OrderId = 1,
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrderEntry_FieldNumber_OwnerId = 2,
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrderEntry_FieldNumber_PricePerToken = 3,
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrderEntry_FieldNumber_OriginalAmount = 4,
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrderEntry_FieldNumber_RemainingAmount = 5,
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrderEntry_FieldNumber_CreatedAt = 6,
 };
 
-GPB_FINAL @interface GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry : GPBMessage
+GPB_FINAL @interface GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrderEntry : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *orderId;
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *ownerId;
+
+@property(nonatomic, readwrite) uint64_t pricePerToken;
+
+@property(nonatomic, readwrite) uint64_t originalAmount;
+
+@property(nonatomic, readwrite) uint64_t remainingAmount;
+
+@property(nonatomic, readwrite) uint64_t createdAt;
+
+@end
+
+#pragma mark - GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrders
+
+typedef GPB_ENUM(GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrders_FieldNumber) {
+  GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrders_FieldNumber_OrdersArray = 1,
+};
+
+GPB_FINAL @interface GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrders : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenOrderBookResponse_GetTokenOrderBookResponseV0_TokenOrderEntry*> *ordersArray;
+/** The number of items in @c ordersArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger ordersArray_Count;
+
+@end
+
+#pragma mark - GetTokenVestingsRequest
+
+typedef GPB_ENUM(GetTokenVestingsRequest_FieldNumber) {
+  GetTokenVestingsRequest_FieldNumber_V0 = 1,
+};
+
+typedef GPB_ENUM(GetTokenVestingsRequest_Version_OneOfCase) {
+  GetTokenVestingsRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenVestingsRequest_Version_OneOfCase_V0 = 1,
+};
+
+/**
+ * Vestings of a token locked for a recipient, ordered by vesting id
+ **/
+GPB_FINAL @interface GetTokenVestingsRequest : GPBMessage
+
+@property(nonatomic, readonly) GetTokenVestingsRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenVestingsRequest_GetTokenVestingsRequestV0 *v0;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetTokenVestingsRequest_ClearVersionOneOfCase(GetTokenVestingsRequest *message);
+
+#pragma mark - GetTokenVestingsRequest_GetTokenVestingsRequestV0
+
+typedef GPB_ENUM(GetTokenVestingsRequest_GetTokenVestingsRequestV0_FieldNumber) {
+  GetTokenVestingsRequest_GetTokenVestingsRequestV0_FieldNumber_TokenId = 1,
+  GetTokenVestingsRequest_GetTokenVestingsRequestV0_FieldNumber_RecipientId = 2,
+  GetTokenVestingsRequest_GetTokenVestingsRequestV0_FieldNumber_StartAfter = 3,
+  GetTokenVestingsRequest_GetTokenVestingsRequestV0_FieldNumber_Count = 4,
+  GetTokenVestingsRequest_GetTokenVestingsRequestV0_FieldNumber_Prove = 5,
+};
+
+GPB_FINAL @interface GetTokenVestingsRequest_GetTokenVestingsRequestV0 : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
 @property(nonatomic, readwrite, copy, null_resettable) NSData *recipientId;
 
-@property(nonatomic, readwrite) uint64_t amount;
+/** Only return vestings with an id greater than this one */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *startAfter;
+/** Test to see if @c startAfter has been set. */
+@property(nonatomic, readwrite) BOOL hasStartAfter;
+
+@property(nonatomic, readwrite) uint32_t count;
+
+@property(nonatomic, readwrite) BOOL hasCount;
+@property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry
+#pragma mark - GetTokenVestingsResponse
 
-typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry_FieldNumber) {
-  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry_FieldNumber_Timestamp = 1,
-  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry_FieldNumber_DistributionsArray = 2,
+typedef GPB_ENUM(GetTokenVestingsResponse_FieldNumber) {
+  GetTokenVestingsResponse_FieldNumber_V0 = 1,
 };
 
-GPB_FINAL @interface GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry : GPBMessage
+typedef GPB_ENUM(GetTokenVestingsResponse_Version_OneOfCase) {
+  GetTokenVestingsResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenVestingsResponse_Version_OneOfCase_V0 = 1,
+};
 
-@property(nonatomic, readwrite) uint64_t timestamp;
+GPB_FINAL @interface GetTokenVestingsResponse : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributionEntry*> *distributionsArray;
-/** The number of items in @c distributionsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger distributionsArray_Count;
+@property(nonatomic, readonly) GetTokenVestingsResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenVestingsResponse_GetTokenVestingsResponseV0 *v0;
 
 @end
 
-#pragma mark - GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributions
+/**
+ * Clears whatever value was set for the oneof 'version'.
+ **/
+void GetTokenVestingsResponse_ClearVersionOneOfCase(GetTokenVestingsResponse *message);
 
-typedef GPB_ENUM(GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributions_FieldNumber) {
-  GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributions_FieldNumber_TokenDistributionsArray = 1,
+#pragma mark - GetTokenVestingsResponse_GetTokenVestingsResponseV0
+
+typedef GPB_ENUM(GetTokenVestingsResponse_GetTokenVestingsResponseV0_FieldNumber) {
+  GetTokenVestingsResponse_GetTokenVestingsResponseV0_FieldNumber_Vestings = 1,
+  GetTokenVestingsResponse_GetTokenVestingsResponseV0_FieldNumber_Proof = 2,
+  GetTokenVestingsResponse_GetTokenVestingsResponseV0_FieldNumber_Metadata = 3,
+};
+
+typedef GPB_ENUM(GetTokenVestingsResponse_GetTokenVestingsResponseV0_Result_OneOfCase) {
+  GetTokenVestingsResponse_GetTokenVestingsResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenVestingsResponse_GetTokenVestingsResponseV0_Result_OneOfCase_Vestings = 1,
+  GetTokenVestingsResponse_GetTokenVestingsResponseV0_Result_OneOfCase_Proof = 2,
+};
+
+GPB_FINAL @interface GetTokenVestingsResponse_GetTokenVestingsResponseV0 : GPBMessage
+
+@property(nonatomic, readonly) GetTokenVestingsResponse_GetTokenVestingsResponseV0_Result_OneOfCase resultOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenVestingsResponse_GetTokenVestingsResponseV0_TokenVestings *vestings;
+
+@property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
+
+@property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
+/** Test to see if @c metadata has been set. */
+@property(nonatomic, readwrite) BOOL hasMetadata;
+
+@end
+
+/**
+ * Clears whatever value was set for the oneof 'result'.
+ **/
+void GetTokenVestingsResponse_GetTokenVestingsResponseV0_ClearResultOneOfCase(GetTokenVestingsResponse_GetTokenVestingsResponseV0 *message);
+
+#pragma mark - GetTokenVestingsResponse_GetTokenVestingsResponseV0_TokenVestings
+
+typedef GPB_ENUM(GetTokenVestingsResponse_GetTokenVestingsResponseV0_TokenVestings_FieldNumber) {
+  GetTokenVestingsResponse_GetTokenVestingsResponseV0_TokenVestings_FieldNumber_VestingsArray = 1,
 };
 
-GPB_FINAL @interface GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenDistributions : GPBMessage
+/**
+ * Serialized token vestings
+ **/
+GPB_FINAL @interface GetTokenVestingsResponse_GetTokenVestingsResponseV0_TokenVestings : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenPreProgrammedDistributionsResponse_GetTokenPreProgrammedDistributionsResponseV0_TokenTimedDistributionEntry*> *tokenDistributionsArray;
-/** The number of items in @c tokenDistributionsArray without causing the array to be created. */
-@property(nonatomic, readonly) NSUInteger tokenDistributionsArray_Count;
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *vestingsArray;
+/** The number of items in @c vestingsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger vestingsArray_Count;
 
 @end
 
-#pragma mark - GetTokenPerpetualDistributionLastClaimRequest
+#pragma mark - GetTokenAllowancesRequest
 
-typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimRequest_FieldNumber) {
-  GetTokenPerpetualDistributionLastClaimRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenAllowancesRequest_FieldNumber) {
+  GetTokenAllowancesRequest_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimRequest_Version_OneOfCase) {
-  GetTokenPerpetualDistributionLastClaimRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenPerpetualDistributionLastClaimRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenAllowancesRequest_Version_OneOfCase) {
+  GetTokenAllowancesRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenAllowancesRequest_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimRequest : GPBMessage
+/**
+ * Allowances an owner granted for a token, ordered by spender
+ **/
+GPB_FINAL @interface GetTokenAllowancesRequest : GPBMessage
 
-@property(nonatomic, readonly) GetTokenPerpetualDistributionLastClaimRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenAllowancesRequest_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenAllowancesRequest_GetTokenAllowancesRequestV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenPerpetualDistributionLastClaimRequest_ClearVersionOneOfCase(GetTokenPerpetualDistributionLastClaimRequest *message);
+void GetTokenAllowancesRequest_ClearVersionOneOfCase(GetTokenAllowancesRequest *message);
 
-#pragma mark - GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo
+#pragma mark - GetTokenAllowancesRequest_GetTokenAllowancesRequestV0
 
-typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo_FieldNumber) {
-  GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo_FieldNumber_ContractId = 1,
-  GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo_FieldNumber_TokenContractPosition = 2,
+typedef GPB_ENUM(GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_FieldNumber) {
+  GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_FieldNumber_TokenId = 1,
+  GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_FieldNumber_OwnerId = 2,
+  GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_FieldNumber_StartAfter = 3,
+  GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_FieldNumber_Count = 4,
+  GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_FieldNumber_Prove = 5,
 };
 
-GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo : GPBMessage
+GPB_FINAL @interface GetTokenAllowancesRequest_GetTokenAllowancesRequestV0 : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *contractId;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-/** the token contract position */
-@property(nonatomic, readwrite) uint32_t tokenContractPosition;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *ownerId;
+
+/**
+ * Only return allowances of spenders after this one, identities come
+ * before groups
+ **/
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender *startAfter;
+/** Test to see if @c startAfter has been set. */
+@property(nonatomic, readwrite) BOOL hasStartAfter;
+
+@property(nonatomic, readwrite) uint32_t count;
+
+@property(nonatomic, readwrite) BOOL hasCount;
+@property(nonatomic, readwrite) BOOL prove;
 
 @end
 
-#pragma mark - GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0
+#pragma mark - GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender
 
-typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0_FieldNumber) {
-  GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0_FieldNumber_TokenId = 1,
-  GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0_FieldNumber_ContractInfo = 2,
-  GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0_FieldNumber_IdentityId = 4,
-  GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0_FieldNumber_Prove = 5,
+typedef GPB_ENUM(GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender_FieldNumber) {
+  GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender_FieldNumber_IdentityId = 1,
+  GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender_FieldNumber_GroupContractPosition = 2,
 };
 
-GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimRequest_GetTokenPerpetualDistributionLastClaimRequestV0 : GPBMessage
-
-/** 32‑byte token identifier */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
+typedef GPB_ENUM(GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender_Spender_OneOfCase) {
+  GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender_Spender_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender_Spender_OneOfCase_IdentityId = 1,
+  GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender_Spender_OneOfCase_GroupContractPosition = 2,
+};
 
 /**
- * This should be set if you wish to get back the last claim info as a
- * specific type
+ * The identity or group allowed to transfer tokens of the owner
  **/
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenPerpetualDistributionLastClaimRequest_ContractTokenInfo *contractInfo;
-/** Test to see if @c contractInfo has been set. */
-@property(nonatomic, readwrite) BOOL hasContractInfo;
+GPB_FINAL @interface GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender : GPBMessage
+
+@property(nonatomic, readonly) GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender_Spender_OneOfCase spenderOneOfCase;
 
-/** Identity whose last‑claim timestamp is requested */
 @property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
 
-/** Return GroveDB / signature proof instead of raw value */
-@property(nonatomic, readwrite) BOOL prove;
+@property(nonatomic, readwrite) uint32_t groupContractPosition;
 
 @end
 
-#pragma mark - GetTokenPerpetualDistributionLastClaimResponse
+/**
+ * Clears whatever value was set for the oneof 'spender'.
+ **/
+void GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender_ClearSpenderOneOfCase(GetTokenAllowancesRequest_GetTokenAllowancesRequestV0_AllowanceSpender *message);
 
-typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_FieldNumber) {
-  GetTokenPerpetualDistributionLastClaimResponse_FieldNumber_V0 = 1,
+#pragma mark - GetTokenAllowancesResponse
+
+typedef GPB_ENUM(GetTokenAllowancesResponse_FieldNumber) {
+  GetTokenAllowancesResponse_FieldNumber_V0 = 1,
 };
 
-typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_Version_OneOfCase) {
-  GetTokenPerpetualDistributionLastClaimResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenPerpetualDistributionLastClaimResponse_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenAllowancesResponse_Version_OneOfCase) {
+  GetTokenAllowancesResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenAllowancesResponse_Version_OneOfCase_V0 = 1,
 };
 
-GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimResponse : GPBMessage
+GPB_FINAL @interface GetTokenAllowancesResponse : GPBMessage
 
-@property(nonatomic, readonly) GetTokenPerpetualDistributionLastClaimResponse_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readonly) GetTokenAllowancesResponse_Version_OneOfCase versionOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0 *v0;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenAllowancesResponse_GetTokenAllowancesResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenPerpetualDistributionLastClaimResponse_ClearVersionOneOfCase(GetTokenPerpetualDistributionLastClaimResponse *message);
+void GetTokenAllowancesResponse_ClearVersionOneOfCase(GetTokenAllowancesResponse *message);
 
-#pragma mark - GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0
+#pragma mark - GetTokenAllowancesResponse_GetTokenAllowancesResponseV0
 
-typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_FieldNumber) {
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_FieldNumber_LastClaim = 1,
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_FieldNumber_Proof = 2,
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_FieldNumber) {
+  GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_FieldNumber_Allowances = 1,
+  GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_FieldNumber_Proof = 2,
+  GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_Result_OneOfCase) {
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_Result_OneOfCase_LastClaim = 1,
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_Result_OneOfCase) {
+  GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_Result_OneOfCase_Allowances = 1,
+  GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0 : GPBMessage
+GPB_FINAL @interface GetTokenAllowancesResponse_GetTokenAllowancesResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_Result_OneOfCase resultOneOfCase;
 
-/** Direct answer */
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo *lastClaim;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_TokenAllowances *allowances;
 
-/** GroveDB / quorum proof */
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
-/** Chain context */
 @property(nonatomic, readwrite, strong, null_resettable) ResponseMetadata *metadata;
 /** Test to see if @c metadata has been set. */
 @property(nonatomic, readwrite) BOOL hasMetadata;
@@ -6519,130 +8505,218 @@ GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerp
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_ClearResultOneOfCase(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0 *message);
+void GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_ClearResultOneOfCase(GetTokenAllowancesResponse_GetTokenAllowancesResponseV0 *message);
 
-#pragma mark - GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo
+#pragma mark - GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_TokenAllowances
 
-typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_FieldNumber) {
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_FieldNumber_TimestampMs = 1,
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_FieldNumber_BlockHeight = 2,
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_FieldNumber_Epoch = 3,
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_FieldNumber_RawBytes = 4,
+typedef GPB_ENUM(GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_TokenAllowances_FieldNumber) {
+  GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_TokenAllowances_FieldNumber_AllowancesArray = 1,
 };
 
-typedef GPB_ENUM(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase) {
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase_TimestampMs = 1,
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase_BlockHeight = 2,
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase_Epoch = 3,
-  GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase_RawBytes = 4,
-};
+/**
+ * Serialized token allowances
+ **/
+GPB_FINAL @interface GetTokenAllowancesResponse_GetTokenAllowancesResponseV0_TokenAllowances : GPBMessage
 
-GPB_FINAL @interface GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo : GPBMessage
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<NSData*> *allowancesArray;
+/** The number of items in @c allowancesArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger allowancesArray_Count;
 
-@property(nonatomic, readonly) GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_PaidAt_OneOfCase paidAtOneOfCase;
+@end
 
-/** Unix epoch, ms */
-@property(nonatomic, readwrite) uint64_t timestampMs;
+#pragma mark - GetTokenEventsRequest
 
-/** Core‑block height */
-@property(nonatomic, readwrite) uint64_t blockHeight;
+typedef GPB_ENUM(GetTokenEventsRequest_FieldNumber) {
+  GetTokenEventsRequest_FieldNumber_V0 = 1,
+};
 
-/** Epoch index */
-@property(nonatomic, readwrite) uint32_t epoch;
+typedef GPB_ENUM(GetTokenEventsRequest_Version_OneOfCase) {
+  GetTokenEventsRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenEventsRequest_Version_OneOfCase_V0 = 1,
+};
 
-/** Arbitrary encoding */
-@property(nonatomic, readwrite, copy, null_resettable) NSData *rawBytes;
+/**
+ * Historical events of a token kept in the token history contract, ordered by
+ * the height of the block they were recorded in when a block range is given
+ * and by the block time otherwise
+ **/
+GPB_FINAL @interface GetTokenEventsRequest : GPBMessage
+
+@property(nonatomic, readonly) GetTokenEventsRequest_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenEventsRequest_GetTokenEventsRequestV0 *v0;
 
 @end
 
 /**
- * Clears whatever value was set for the oneof 'paidAt'.
+ * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo_ClearPaidAtOneOfCase(GetTokenPerpetualDistributionLastClaimResponse_GetTokenPerpetualDistributionLastClaimResponseV0_LastClaimInfo *message);
+void GetTokenEventsRequest_ClearVersionOneOfCase(GetTokenEventsRequest *message);
 
-#pragma mark - GetTokenTotalSupplyRequest
+#pragma mark - GetTokenEventsRequest_GetTokenEventsRequestV0
 
-typedef GPB_ENUM(GetTokenTotalSupplyRequest_FieldNumber) {
-  GetTokenTotalSupplyRequest_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenEventsRequest_GetTokenEventsRequestV0_FieldNumber) {
+  GetTokenEventsRequest_GetTokenEventsRequestV0_FieldNumber_TokenId = 1,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_FieldNumber_EventType = 2,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_FieldNumber_IdentityId = 3,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_FieldNumber_BlockRange = 4,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_FieldNumber_StartAfter = 5,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_FieldNumber_Count = 6,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_FieldNumber_OrderAscending = 7,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_FieldNumber_Prove = 8,
 };
 
-typedef GPB_ENUM(GetTokenTotalSupplyRequest_Version_OneOfCase) {
-  GetTokenTotalSupplyRequest_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenTotalSupplyRequest_Version_OneOfCase_V0 = 1,
+typedef GPB_ENUM(GetTokenEventsRequest_GetTokenEventsRequestV0_Filter_OneOfCase) {
+  GetTokenEventsRequest_GetTokenEventsRequestV0_Filter_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_Filter_OneOfCase_IdentityId = 3,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_Filter_OneOfCase_BlockRange = 4,
 };
 
-GPB_FINAL @interface GetTokenTotalSupplyRequest : GPBMessage
+GPB_FINAL @interface GetTokenEventsRequest_GetTokenEventsRequestV0 : GPBMessage
 
-@property(nonatomic, readonly) GetTokenTotalSupplyRequest_Version_OneOfCase versionOneOfCase;
+@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0 *v0;
+/** Events of every type are returned when not set */
+@property(nonatomic, readwrite) GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType eventType;
+
+@property(nonatomic, readwrite) BOOL hasEventType;
+@property(nonatomic, readonly) GetTokenEventsRequest_GetTokenEventsRequestV0_Filter_OneOfCase filterOneOfCase;
+
+/**
+ * Only return events performed by or involving this identity, such as
+ * transfers it received
+ **/
+@property(nonatomic, readwrite, copy, null_resettable) NSData *identityId;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenEventsRequest_GetTokenEventsRequestV0_BlockRange *blockRange;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter *startAfter;
+/** Test to see if @c startAfter has been set. */
+@property(nonatomic, readwrite) BOOL hasStartAfter;
+
+@property(nonatomic, readwrite) uint32_t count;
+
+@property(nonatomic, readwrite) BOOL hasCount;
+@property(nonatomic, readwrite) BOOL orderAscending;
+
+@property(nonatomic, readwrite) BOOL prove;
 
 @end
 
 /**
- * Clears whatever value was set for the oneof 'version'.
+ * Fetches the raw value of a @c GetTokenEventsRequest_GetTokenEventsRequestV0's @c eventType property, even
+ * if the value was not defined by the enum at the time the code was generated.
  **/
-void GetTokenTotalSupplyRequest_ClearVersionOneOfCase(GetTokenTotalSupplyRequest *message);
+int32_t GetTokenEventsRequest_GetTokenEventsRequestV0_EventType_RawValue(GetTokenEventsRequest_GetTokenEventsRequestV0 *message);
+/**
+ * Sets the raw value of an @c GetTokenEventsRequest_GetTokenEventsRequestV0's @c eventType property, allowing
+ * it to be set to a value that was not defined by the enum at the time the code
+ * was generated.
+ **/
+void SetGetTokenEventsRequest_GetTokenEventsRequestV0_EventType_RawValue(GetTokenEventsRequest_GetTokenEventsRequestV0 *message, int32_t value);
 
-#pragma mark - GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0
+/**
+ * Clears whatever value was set for the oneof 'filter'.
+ **/
+void GetTokenEventsRequest_GetTokenEventsRequestV0_ClearFilterOneOfCase(GetTokenEventsRequest_GetTokenEventsRequestV0 *message);
 
-typedef GPB_ENUM(GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0_FieldNumber) {
-  GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0_FieldNumber_TokenId = 1,
-  GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0_FieldNumber_Prove = 2,
+#pragma mark - GetTokenEventsRequest_GetTokenEventsRequestV0_BlockRange
+
+typedef GPB_ENUM(GetTokenEventsRequest_GetTokenEventsRequestV0_BlockRange_FieldNumber) {
+  GetTokenEventsRequest_GetTokenEventsRequestV0_BlockRange_FieldNumber_StartHeight = 1,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_BlockRange_FieldNumber_EndHeight = 2,
 };
 
-GPB_FINAL @interface GetTokenTotalSupplyRequest_GetTokenTotalSupplyRequestV0 : GPBMessage
+GPB_FINAL @interface GetTokenEventsRequest_GetTokenEventsRequestV0_BlockRange : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
+/** Inclusive */
+@property(nonatomic, readwrite) uint64_t startHeight;
 
-@property(nonatomic, readwrite) BOOL prove;
+/** Exclusive */
+@property(nonatomic, readwrite) uint64_t endHeight;
 
 @end
 
-#pragma mark - GetTokenTotalSupplyResponse
+#pragma mark - GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter
 
-typedef GPB_ENUM(GetTokenTotalSupplyResponse_FieldNumber) {
-  GetTokenTotalSupplyResponse_FieldNumber_V0 = 1,
+typedef GPB_ENUM(GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter_FieldNumber) {
+  GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter_FieldNumber_DocumentId = 1,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter_FieldNumber_EventType = 2,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter_FieldNumber_CreatedAt = 3,
+  GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter_FieldNumber_CreatedAtBlockHeight = 4,
 };
 
-typedef GPB_ENUM(GetTokenTotalSupplyResponse_Version_OneOfCase) {
-  GetTokenTotalSupplyResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenTotalSupplyResponse_Version_OneOfCase_V0 = 1,
+/**
+ * The last event of the previous page, events of different types recorded
+ * at the same position are ordered by their type and then by document id
+ **/
+GPB_FINAL @interface GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter : GPBMessage
+
+@property(nonatomic, readwrite, copy, null_resettable) NSData *documentId;
+
+@property(nonatomic, readwrite) GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType eventType;
+
+@property(nonatomic, readwrite) uint64_t createdAt;
+
+@property(nonatomic, readwrite) uint64_t createdAtBlockHeight;
+
+@end
+
+/**
+ * Fetches the raw value of a @c GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter's @c eventType property, even
+ * if the value was not defined by the enum at the time the code was generated.
+ **/
+int32_t GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter_EventType_RawValue(GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter *message);
+/**
+ * Sets the raw value of an @c GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter's @c eventType property, allowing
+ * it to be set to a value that was not defined by the enum at the time the code
+ * was generated.
+ **/
+void SetGetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter_EventType_RawValue(GetTokenEventsRequest_GetTokenEventsRequestV0_StartAfter *message, int32_t value);
+
+#pragma mark - GetTokenEventsResponse
+
+typedef GPB_ENUM(GetTokenEventsResponse_FieldNumber) {
+  GetTokenEventsResponse_FieldNumber_V0 = 1,
 };
 
-GPB_FINAL @interface GetTokenTotalSupplyResponse : GPBMessage
+typedef GPB_ENUM(GetTokenEventsResponse_Version_OneOfCase) {
+  GetTokenEventsResponse_Version_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenEventsResponse_Version_OneOfCase_V0 = 1,
+};
 
-@property(nonatomic, readonly) GetTokenTotalSupplyResponse_Version_OneOfCase versionOneOfCase;
+GPB_FINAL @interface GetTokenEventsResponse : GPBMessage
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0 *v0;
+@property(nonatomic, readonly) GetTokenEventsResponse_Version_OneOfCase versionOneOfCase;
+
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenEventsResponse_GetTokenEventsResponseV0 *v0;
 
 @end
 
 /**
  * Clears whatever value was set for the oneof 'version'.
  **/
-void GetTokenTotalSupplyResponse_ClearVersionOneOfCase(GetTokenTotalSupplyResponse *message);
+void GetTokenEventsResponse_ClearVersionOneOfCase(GetTokenEventsResponse *message);
 
-#pragma mark - GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0
+#pragma mark - GetTokenEventsResponse_GetTokenEventsResponseV0
 
-typedef GPB_ENUM(GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_FieldNumber) {
-  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_FieldNumber_TokenTotalSupply = 1,
-  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_FieldNumber_Proof = 2,
-  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_FieldNumber_Metadata = 3,
+typedef GPB_ENUM(GetTokenEventsResponse_GetTokenEventsResponseV0_FieldNumber) {
+  GetTokenEventsResponse_GetTokenEventsResponseV0_FieldNumber_Events = 1,
+  GetTokenEventsResponse_GetTokenEventsResponseV0_FieldNumber_Proof = 2,
+  GetTokenEventsResponse_GetTokenEventsResponseV0_FieldNumber_Metadata = 3,
 };
 
-typedef GPB_ENUM(GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_Result_OneOfCase) {
-  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
-  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_Result_OneOfCase_TokenTotalSupply = 1,
-  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_Result_OneOfCase_Proof = 2,
+typedef GPB_ENUM(GetTokenEventsResponse_GetTokenEventsResponseV0_Result_OneOfCase) {
+  GetTokenEventsResponse_GetTokenEventsResponseV0_Result_OneOfCase_GPBUnsetOneOfCase = 0,
+  GetTokenEventsResponse_GetTokenEventsResponseV0_Result_OneOfCase_Events = 1,
+  GetTokenEventsResponse_GetTokenEventsResponseV0_Result_OneOfCase_Proof = 2,
 };
 
-GPB_FINAL @interface GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0 : GPBMessage
+GPB_FINAL @interface GetTokenEventsResponse_GetTokenEventsResponseV0 : GPBMessage
 
-@property(nonatomic, readonly) GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_Result_OneOfCase resultOneOfCase;
+@property(nonatomic, readonly) GetTokenEventsResponse_GetTokenEventsResponseV0_Result_OneOfCase resultOneOfCase;
 
-@property(nonatomic, readwrite, strong, null_resettable) GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry *tokenTotalSupply;
+@property(nonatomic, readwrite, strong, null_resettable) GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEvents *events;
 
 @property(nonatomic, readwrite, strong, null_resettable) Proof *proof;
 
@@ -6655,23 +8729,61 @@ GPB_FINAL @interface GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0 :
 /**
  * Clears whatever value was set for the oneof 'result'.
  **/
-void GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_ClearResultOneOfCase(GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0 *message);
+void GetTokenEventsResponse_GetTokenEventsResponseV0_ClearResultOneOfCase(GetTokenEventsResponse_GetTokenEventsResponseV0 *message);
 
-#pragma mark - GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry
+#pragma mark - GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry
 
-typedef GPB_ENUM(GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry_FieldNumber) {
-  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry_FieldNumber_TokenId = 1,
-  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry_FieldNumber_TotalAggregatedAmountInUserAccounts = 2,
-  GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry_FieldNumber_TotalSystemAmount = 3,
+typedef GPB_ENUM(GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry_FieldNumber) {
+  GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry_FieldNumber_DocumentId = 1,
+  GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry_FieldNumber_OwnerId = 2,
+  GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry_FieldNumber_CreatedAt = 3,
+  GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry_FieldNumber_CreatedAtBlockHeight = 4,
+  GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry_FieldNumber_Event = 5,
+  GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry_FieldNumber_EventType = 6,
 };
 
-GPB_FINAL @interface GetTokenTotalSupplyResponse_GetTokenTotalSupplyResponseV0_TokenTotalSupplyEntry : GPBMessage
+GPB_FINAL @interface GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry : GPBMessage
 
-@property(nonatomic, readwrite, copy, null_resettable) NSData *tokenId;
+/** Id of the history document holding the event */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *documentId;
 
-@property(nonatomic, readwrite) uint64_t totalAggregatedAmountInUserAccounts;
+/** Identity that performed the action */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *ownerId;
 
-@property(nonatomic, readwrite) uint64_t totalSystemAmount;
+@property(nonatomic, readwrite) uint64_t createdAt;
+
+@property(nonatomic, readwrite) uint64_t createdAtBlockHeight;
+
+/** Serialized token event */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *event;
+
+@property(nonatomic, readwrite) GetTokenEventsRequest_GetTokenEventsRequestV0_TokenEventType eventType;
+
+@end
+
+/**
+ * Fetches the raw value of a @c GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry's @c eventType property, even
+ * if the value was not defined by the enum at the time the code was generated.
+ **/
+int32_t GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry_EventType_RawValue(GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry *message);
+/**
+ * Sets the raw value of an @c GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry's @c eventType property, allowing
+ * it to be set to a value that was not defined by the enum at the time the code
+ * was generated.
+ **/
+void SetGetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry_EventType_RawValue(GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry *message, int32_t value);
+
+#pragma mark - GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEvents
+
+typedef GPB_ENUM(GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEvents_FieldNumber) {
+  GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEvents_FieldNumber_EventsArray = 1,
+};
+
+GPB_FINAL @interface GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEvents : GPBMessage
+
+@property(nonatomic, readwrite, strong, null_resettable) NSMutableArray<GetTokenEventsResponse_GetTokenEventsResponseV0_TokenEventEntry*> *eventsArray;
+/** The number of items in @c eventsArray without causing the array to be created. */
+@property(nonatomic, readonly) NSUInteger eventsArray_Count;
 
 @end
 
@@ -7153,6 +9265,36 @@ GPB_FINAL @interface GetGroupActionsResponse_GetGroupActionsResponseV0_MintEvent
 
 @end
 
+#pragma mark - GetGroupActionsResponse_GetGroupActionsResponseV0_TransferFromEvent
+
+typedef GPB_ENUM(GetGroupActionsResponse_GetGroupActionsResponseV0_TransferFromEvent_FieldNumber) {
+  GetGroupActionsResponse_GetGroupActionsResponseV0_TransferFromEvent_FieldNumber_FromId = 1,
+  GetGroupActionsResponse_GetGroupActionsResponseV0_TransferFromEvent_FieldNumber_RecipientId = 2,
+  GetGroupActionsResponse_GetGroupActionsResponseV0_TransferFromEvent_FieldNumber_Amount = 3,
+  GetGroupActionsResponse_GetGroupActionsResponseV0_TransferFromEvent_FieldNumber_PublicNote = 4,
+};
+
+/**
+ * Transfer from event
+ **/
+GPB_FINAL @interface GetGroupActionsResponse_GetGroupActionsResponseV0_TransferFromEvent : GPBMessage
+
+/** Identity the tokens are taken from */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *fromId;
+
+/** Recipient identifier */
+@property(nonatomic, readwrite, copy, null_resettable) NSData *recipientId;
+
+/** Amount to transfer */
+@property(nonatomic, readwrite) uint64_t amount;
+
+/** Public note */
+@property(nonatomic, readwrite, copy, null_resettable) NSString *publicNote;
+/** Test to see if @c publicNote has been set. */
+@property(nonatomic, readwrite) BOOL hasPublicNote;
+
+@end
+
 #pragma mark - GetGroupActionsResponse_GetGroupActionsResponseV0_BurnEvent
 
 typedef GPB_ENUM(GetGroupActionsResponse_GetGroupActionsResponseV0_BurnEvent_FieldNumber) {
@@ -7540,6 +9682,7 @@ typedef GPB_ENUM(GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent_Fi
   GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent_FieldNumber_EmergencyAction = 6,
   GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent_FieldNumber_TokenConfigUpdate = 7,
   GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent_FieldNumber_UpdatePrice = 8,
+  GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent_FieldNumber_TransferFrom = 9,
 };
 
 typedef GPB_ENUM(GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent_Type_OneOfCase) {
@@ -7552,6 +9695,7 @@ typedef GPB_ENUM(GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent_Ty
   GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent_Type_OneOfCase_EmergencyAction = 6,
   GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent_Type_OneOfCase_TokenConfigUpdate = 7,
   GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent_Type_OneOfCase_UpdatePrice = 8,
+  GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEvent_Type_OneOfCase_TransferFrom = 9,
 };
 
 /**
@@ -7585,6 +9729,9 @@ GPB_FINAL @interface GetGroupActionsResponse_GetGroupActionsResponseV0_TokenEven
 /** Updating the token direct selling price */
 @property(nonatomic, readwrite, strong, null_resettable) GetGroupActionsResponse_GetGroupActionsResponseV0_UpdateDirectPurchasePriceEvent *updatePrice;
 
+/** Transfer on behalf of an owner within an allowance */
+@property(nonatomic, readwrite, strong, null_resettable) GetGroupActionsResponse_GetGroupActionsResponseV0_TransferFromEvent *transferFrom;
+
 @end
 
 /**
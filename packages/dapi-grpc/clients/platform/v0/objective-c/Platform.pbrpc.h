@@ -16,6 +16,8 @@
 
 @class BroadcastStateTransitionRequest;
 @class BroadcastStateTransitionResponse;
+@class GetBlockStateTransitionsRequest;
+@class GetBlockStateTransitionsResponse;
 @class GetConsensusParamsRequest;
 @class GetConsensusParamsResponse;
 @class GetContestedResourceIdentityVotesRequest;
@@ -34,6 +36,8 @@
 @class GetDataContractResponse;
 @class GetDataContractsRequest;
 @class GetDataContractsResponse;
+@class GetDocumentsAggregateRequest;
+@class GetDocumentsAggregateResponse;
 @class GetDocumentsRequest;
 @class GetDocumentsResponse;
 @class GetEpochsInfoRequest;
@@ -75,6 +79,8 @@
 @class GetIdentityNonceResponse;
 @class GetIdentityRequest;
 @class GetIdentityResponse;
+@class GetIdentityStateTransitionsRequest;
+@class GetIdentityStateTransitionsResponse;
 @class GetIdentityTokenBalancesRequest;
 @class GetIdentityTokenBalancesResponse;
 @class GetIdentityTokenInfosRequest;
@@ -89,10 +95,18 @@
 @class GetProtocolVersionUpgradeVoteStatusResponse;
 @class GetStatusRequest;
 @class GetStatusResponse;
+@class GetTokenAllowancesRequest;
+@class GetTokenAllowancesResponse;
 @class GetTokenContractInfoRequest;
 @class GetTokenContractInfoResponse;
 @class GetTokenDirectPurchasePricesRequest;
 @class GetTokenDirectPurchasePricesResponse;
+@class GetTokenEventsRequest;
+@class GetTokenEventsResponse;
+@class GetTokenHoldersRequest;
+@class GetTokenHoldersResponse;
+@class GetTokenOrderBookRequest;
+@class GetTokenOrderBookResponse;
 @class GetTokenPerpetualDistributionLastClaimRequest;
 @class GetTokenPerpetualDistributionLastClaimResponse;
 @class GetTokenPreProgrammedDistributionsRequest;
@@ -101,10 +115,18 @@
 @class GetTokenStatusesResponse;
 @class GetTokenTotalSupplyRequest;
 @class GetTokenTotalSupplyResponse;
+@class GetTokenVestingsRequest;
+@class GetTokenVestingsResponse;
 @class GetTotalCreditsInPlatformRequest;
 @class GetTotalCreditsInPlatformResponse;
 @class GetVotePollsByEndDateRequest;
 @class GetVotePollsByEndDateResponse;
+@class GetYesNoAbstainVotePollStateRequest;
+@class GetYesNoAbstainVotePollStateResponse;
+@class SimulateStateTransitionRequest;
+@class SimulateStateTransitionResponse;
+@class SubscribeDocumentsRequest;
+@class SubscribeDocumentsResponse;
 @class WaitForStateTransitionResultRequest;
 @class WaitForStateTransitionResultResponse;
 
@@ -197,6 +219,14 @@ NS_ASSUME_NONNULL_BEGIN
 
 - (GRPCUnaryProtoCall *)getDocumentsWithMessage:(GetDocumentsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
 
+#pragma mark getDocumentsAggregate(GetDocumentsAggregateRequest) returns (GetDocumentsAggregateResponse)
+
+- (GRPCUnaryProtoCall *)getDocumentsAggregateWithMessage:(GetDocumentsAggregateRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
+#pragma mark subscribeDocuments(SubscribeDocumentsRequest) returns (stream SubscribeDocumentsResponse)
+
+- (GRPCUnaryProtoCall *)subscribeDocumentsWithMessage:(SubscribeDocumentsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
 #pragma mark getIdentityByPublicKeyHash(GetIdentityByPublicKeyHashRequest) returns (GetIdentityByPublicKeyHashResponse)
 
 - (GRPCUnaryProtoCall *)getIdentityByPublicKeyHashWithMessage:(GetIdentityByPublicKeyHashRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
@@ -205,10 +235,18 @@ NS_ASSUME_NONNULL_BEGIN
 
 - (GRPCUnaryProtoCall *)getIdentityByNonUniquePublicKeyHashWithMessage:(GetIdentityByNonUniquePublicKeyHashRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
 
+#pragma mark getIdentityStateTransitions(GetIdentityStateTransitionsRequest) returns (GetIdentityStateTransitionsResponse)
+
+- (GRPCUnaryProtoCall *)getIdentityStateTransitionsWithMessage:(GetIdentityStateTransitionsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
 #pragma mark waitForStateTransitionResult(WaitForStateTransitionResultRequest) returns (WaitForStateTransitionResultResponse)
 
 - (GRPCUnaryProtoCall *)waitForStateTransitionResultWithMessage:(WaitForStateTransitionResultRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
 
+#pragma mark simulateStateTransition(SimulateStateTransitionRequest) returns (SimulateStateTransitionResponse)
+
+- (GRPCUnaryProtoCall *)simulateStateTransitionWithMessage:(SimulateStateTransitionRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
 #pragma mark getConsensusParams(GetConsensusParamsRequest) returns (GetConsensusParamsResponse)
 
 - (GRPCUnaryProtoCall *)getConsensusParamsWithMessage:(GetConsensusParamsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
@@ -229,6 +267,13 @@ NS_ASSUME_NONNULL_BEGIN
 
 - (GRPCUnaryProtoCall *)getFinalizedEpochInfosWithMessage:(GetFinalizedEpochInfosRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
 
+#pragma mark getBlockStateTransitions(GetBlockStateTransitionsRequest) returns (GetBlockStateTransitionsResponse)
+
+/**
+ * Which state transitions were executed in a recent block, with their results
+ */
+- (GRPCUnaryProtoCall *)getBlockStateTransitionsWithMessage:(GetBlockStateTransitionsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
 #pragma mark getContestedResources(GetContestedResourcesRequest) returns (GetContestedResourcesResponse)
 
 /**
@@ -264,6 +309,13 @@ NS_ASSUME_NONNULL_BEGIN
  */
 - (GRPCUnaryProtoCall *)getVotePollsByEndDateWithMessage:(GetVotePollsByEndDateRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
 
+#pragma mark getYesNoAbstainVotePollState(GetYesNoAbstainVotePollStateRequest) returns (GetYesNoAbstainVotePollStateResponse)
+
+/**
+ * How did masternodes vote on a yes/no/abstain poll?
+ */
+- (GRPCUnaryProtoCall *)getYesNoAbstainVotePollStateWithMessage:(GetYesNoAbstainVotePollStateRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
 #pragma mark getPrefundedSpecializedBalance(GetPrefundedSpecializedBalanceRequest) returns (GetPrefundedSpecializedBalanceResponse)
 
 - (GRPCUnaryProtoCall *)getPrefundedSpecializedBalanceWithMessage:(GetPrefundedSpecializedBalanceRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
@@ -324,6 +376,26 @@ NS_ASSUME_NONNULL_BEGIN
 
 - (GRPCUnaryProtoCall *)getTokenTotalSupplyWithMessage:(GetTokenTotalSupplyRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
 
+#pragma mark getTokenHolders(GetTokenHoldersRequest) returns (GetTokenHoldersResponse)
+
+- (GRPCUnaryProtoCall *)getTokenHoldersWithMessage:(GetTokenHoldersRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
+#pragma mark getTokenOrderBook(GetTokenOrderBookRequest) returns (GetTokenOrderBookResponse)
+
+- (GRPCUnaryProtoCall *)getTokenOrderBookWithMessage:(GetTokenOrderBookRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
+#pragma mark getTokenVestings(GetTokenVestingsRequest) returns (GetTokenVestingsResponse)
+
+- (GRPCUnaryProtoCall *)getTokenVestingsWithMessage:(GetTokenVestingsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
+#pragma mark getTokenAllowances(GetTokenAllowancesRequest) returns (GetTokenAllowancesResponse)
+
+- (GRPCUnaryProtoCall *)getTokenAllowancesWithMessage:(GetTokenAllowancesRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
+#pragma mark getTokenEvents(GetTokenEventsRequest) returns (GetTokenEventsResponse)
+
+- (GRPCUnaryProtoCall *)getTokenEventsWithMessage:(GetTokenEventsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
+
 #pragma mark getGroupInfo(GetGroupInfoRequest) returns (GetGroupInfoResponse)
 
 - (GRPCUnaryProtoCall *)getGroupInfoWithMessage:(GetGroupInfoRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions;
@@ -453,6 +525,20 @@ NS_ASSUME_NONNULL_BEGIN
 - (GRPCProtoCall *)RPCTogetDocumentsWithRequest:(GetDocumentsRequest *)request handler:(void(^)(GetDocumentsResponse *_Nullable response, NSError *_Nullable error))handler;
 
 
+#pragma mark getDocumentsAggregate(GetDocumentsAggregateRequest) returns (GetDocumentsAggregateResponse)
+
+- (void)getDocumentsAggregateWithRequest:(GetDocumentsAggregateRequest *)request handler:(void(^)(GetDocumentsAggregateResponse *_Nullable response, NSError *_Nullable error))handler;
+
+- (GRPCProtoCall *)RPCTogetDocumentsAggregateWithRequest:(GetDocumentsAggregateRequest *)request handler:(void(^)(GetDocumentsAggregateResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
+#pragma mark subscribeDocuments(SubscribeDocumentsRequest) returns (stream SubscribeDocumentsResponse)
+
+- (void)subscribeDocumentsWithRequest:(SubscribeDocumentsRequest *)request eventHandler:(void(^)(BOOL done, SubscribeDocumentsResponse *_Nullable response, NSError *_Nullable error))eventHandler;
+
+- (GRPCProtoCall *)RPCTosubscribeDocumentsWithRequest:(SubscribeDocumentsRequest *)request eventHandler:(void(^)(BOOL done, SubscribeDocumentsResponse *_Nullable response, NSError *_Nullable error))eventHandler;
+
+
 #pragma mark getIdentityByPublicKeyHash(GetIdentityByPublicKeyHashRequest) returns (GetIdentityByPublicKeyHashResponse)
 
 - (void)getIdentityByPublicKeyHashWithRequest:(GetIdentityByPublicKeyHashRequest *)request handler:(void(^)(GetIdentityByPublicKeyHashResponse *_Nullable response, NSError *_Nullable error))handler;
@@ -467,6 +553,13 @@ NS_ASSUME_NONNULL_BEGIN
 - (GRPCProtoCall *)RPCTogetIdentityByNonUniquePublicKeyHashWithRequest:(GetIdentityByNonUniquePublicKeyHashRequest *)request handler:(void(^)(GetIdentityByNonUniquePublicKeyHashResponse *_Nullable response, NSError *_Nullable error))handler;
 
 
+#pragma mark getIdentityStateTransitions(GetIdentityStateTransitionsRequest) returns (GetIdentityStateTransitionsResponse)
+
+- (void)getIdentityStateTransitionsWithRequest:(GetIdentityStateTransitionsRequest *)request handler:(void(^)(GetIdentityStateTransitionsResponse *_Nullable response, NSError *_Nullable error))handler;
+
+- (GRPCProtoCall *)RPCTogetIdentityStateTransitionsWithRequest:(GetIdentityStateTransitionsRequest *)request handler:(void(^)(GetIdentityStateTransitionsResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
 #pragma mark waitForStateTransitionResult(WaitForStateTransitionResultRequest) returns (WaitForStateTransitionResultResponse)
 
 - (void)waitForStateTransitionResultWithRequest:(WaitForStateTransitionResultRequest *)request handler:(void(^)(WaitForStateTransitionResultResponse *_Nullable response, NSError *_Nullable error))handler;
@@ -474,6 +567,13 @@ NS_ASSUME_NONNULL_BEGIN
 - (GRPCProtoCall *)RPCTowaitForStateTransitionResultWithRequest:(WaitForStateTransitionResultRequest *)request handler:(void(^)(WaitForStateTransitionResultResponse *_Nullable response, NSError *_Nullable error))handler;
 
 
+#pragma mark simulateStateTransition(SimulateStateTransitionRequest) returns (SimulateStateTransitionResponse)
+
+- (void)simulateStateTransitionWithRequest:(SimulateStateTransitionRequest *)request handler:(void(^)(SimulateStateTransitionResponse *_Nullable response, NSError *_Nullable error))handler;
+
+- (GRPCProtoCall *)RPCTosimulateStateTransitionWithRequest:(SimulateStateTransitionRequest *)request handler:(void(^)(SimulateStateTransitionResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
 #pragma mark getConsensusParams(GetConsensusParamsRequest) returns (GetConsensusParamsResponse)
 
 - (void)getConsensusParamsWithRequest:(GetConsensusParamsRequest *)request handler:(void(^)(GetConsensusParamsResponse *_Nullable response, NSError *_Nullable error))handler;
@@ -509,6 +609,23 @@ NS_ASSUME_NONNULL_BEGIN
 - (GRPCProtoCall *)RPCTogetFinalizedEpochInfosWithRequest:(GetFinalizedEpochInfosRequest *)request handler:(void(^)(GetFinalizedEpochInfosResponse *_Nullable response, NSError *_Nullable error))handler;
 
 
+#pragma mark getBlockStateTransitions(GetBlockStateTransitionsRequest) returns (GetBlockStateTransitionsResponse)
+
+/**
+ * Which state transitions were executed in a recent block, with their results
+ *
+ * This method belongs to a set of APIs that have been deprecated. Using the v2 API is recommended.
+ */
+- (void)getBlockStateTransitionsWithRequest:(GetBlockStateTransitionsRequest *)request handler:(void(^)(GetBlockStateTransitionsResponse *_Nullable response, NSError *_Nullable error))handler;
+
+/**
+ * Which state transitions were executed in a recent block, with their results
+ *
+ * This method belongs to a set of APIs that have been deprecated. Using the v2 API is recommended.
+ */
+- (GRPCProtoCall *)RPCTogetBlockStateTransitionsWithRequest:(GetBlockStateTransitionsRequest *)request handler:(void(^)(GetBlockStateTransitionsResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
 #pragma mark getContestedResources(GetContestedResourcesRequest) returns (GetContestedResourcesResponse)
 
 /**
@@ -594,6 +711,23 @@ NS_ASSUME_NONNULL_BEGIN
 - (GRPCProtoCall *)RPCTogetVotePollsByEndDateWithRequest:(GetVotePollsByEndDateRequest *)request handler:(void(^)(GetVotePollsByEndDateResponse *_Nullable response, NSError *_Nullable error))handler;
 
 
+#pragma mark getYesNoAbstainVotePollState(GetYesNoAbstainVotePollStateRequest) returns (GetYesNoAbstainVotePollStateResponse)
+
+/**
+ * How did masternodes vote on a yes/no/abstain poll?
+ *
+ * This method belongs to a set of APIs that have been deprecated. Using the v2 API is recommended.
+ */
+- (void)getYesNoAbstainVotePollStateWithRequest:(GetYesNoAbstainVotePollStateRequest *)request handler:(void(^)(GetYesNoAbstainVotePollStateResponse *_Nullable response, NSError *_Nullable error))handler;
+
+/**
+ * How did masternodes vote on a yes/no/abstain poll?
+ *
+ * This method belongs to a set of APIs that have been deprecated. Using the v2 API is recommended.
+ */
+- (GRPCProtoCall *)RPCTogetYesNoAbstainVotePollStateWithRequest:(GetYesNoAbstainVotePollStateRequest *)request handler:(void(^)(GetYesNoAbstainVotePollStateResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
 #pragma mark getPrefundedSpecializedBalance(GetPrefundedSpecializedBalanceRequest) returns (GetPrefundedSpecializedBalanceResponse)
 
 - (void)getPrefundedSpecializedBalanceWithRequest:(GetPrefundedSpecializedBalanceRequest *)request handler:(void(^)(GetPrefundedSpecializedBalanceResponse *_Nullable response, NSError *_Nullable error))handler;
@@ -699,6 +833,41 @@ NS_ASSUME_NONNULL_BEGIN
 - (GRPCProtoCall *)RPCTogetTokenTotalSupplyWithRequest:(GetTokenTotalSupplyRequest *)request handler:(void(^)(GetTokenTotalSupplyResponse *_Nullable response, NSError *_Nullable error))handler;
 
 
+#pragma mark getTokenHolders(GetTokenHoldersRequest) returns (GetTokenHoldersResponse)
+
+- (void)getTokenHoldersWithRequest:(GetTokenHoldersRequest *)request handler:(void(^)(GetTokenHoldersResponse *_Nullable response, NSError *_Nullable error))handler;
+
+- (GRPCProtoCall *)RPCTogetTokenHoldersWithRequest:(GetTokenHoldersRequest *)request handler:(void(^)(GetTokenHoldersResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
+#pragma mark getTokenOrderBook(GetTokenOrderBookRequest) returns (GetTokenOrderBookResponse)
+
+- (void)getTokenOrderBookWithRequest:(GetTokenOrderBookRequest *)request handler:(void(^)(GetTokenOrderBookResponse *_Nullable response, NSError *_Nullable error))handler;
+
+- (GRPCProtoCall *)RPCTogetTokenOrderBookWithRequest:(GetTokenOrderBookRequest *)request handler:(void(^)(GetTokenOrderBookResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
+#pragma mark getTokenVestings(GetTokenVestingsRequest) returns (GetTokenVestingsResponse)
+
+- (void)getTokenVestingsWithRequest:(GetTokenVestingsRequest *)request handler:(void(^)(GetTokenVestingsResponse *_Nullable response, NSError *_Nullable error))handler;
+
+- (GRPCProtoCall *)RPCTogetTokenVestingsWithRequest:(GetTokenVestingsRequest *)request handler:(void(^)(GetTokenVestingsResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
+#pragma mark getTokenAllowances(GetTokenAllowancesRequest) returns (GetTokenAllowancesResponse)
+
+- (void)getTokenAllowancesWithRequest:(GetTokenAllowancesRequest *)request handler:(void(^)(GetTokenAllowancesResponse *_Nullable response, NSError *_Nullable error))handler;
+
+- (GRPCProtoCall *)RPCTogetTokenAllowancesWithRequest:(GetTokenAllowancesRequest *)request handler:(void(^)(GetTokenAllowancesResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
+#pragma mark getTokenEvents(GetTokenEventsRequest) returns (GetTokenEventsResponse)
+
+- (void)getTokenEventsWithRequest:(GetTokenEventsRequest *)request handler:(void(^)(GetTokenEventsResponse *_Nullable response, NSError *_Nullable error))handler;
+
+- (GRPCProtoCall *)RPCTogetTokenEventsWithRequest:(GetTokenEventsRequest *)request handler:(void(^)(GetTokenEventsResponse *_Nullable response, NSError *_Nullable error))handler;
+
+
 #pragma mark getGroupInfo(GetGroupInfoRequest) returns (GetGroupInfoResponse)
 
 - (void)getGroupInfoWithRequest:(GetGroupInfoRequest *)request handler:(void(^)(GetGroupInfoResponse *_Nullable response, NSError *_Nullable error))handler;
@@ -370,6 +370,46 @@
              responseClass:[GetDocumentsResponse class]];
 }
 
+#pragma mark getDocumentsAggregate(GetDocumentsAggregateRequest) returns (GetDocumentsAggregateResponse)
+
+- (void)getDocumentsAggregateWithRequest:(GetDocumentsAggregateRequest *)request handler:(void(^)(GetDocumentsAggregateResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTogetDocumentsAggregateWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+- (GRPCProtoCall *)RPCTogetDocumentsAggregateWithRequest:(GetDocumentsAggregateRequest *)request handler:(void(^)(GetDocumentsAggregateResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"getDocumentsAggregate"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[GetDocumentsAggregateResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+- (GRPCUnaryProtoCall *)getDocumentsAggregateWithMessage:(GetDocumentsAggregateRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"getDocumentsAggregate"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[GetDocumentsAggregateResponse class]];
+}
+
+#pragma mark subscribeDocuments(SubscribeDocumentsRequest) returns (stream SubscribeDocumentsResponse)
+
+- (void)subscribeDocumentsWithRequest:(SubscribeDocumentsRequest *)request eventHandler:(void(^)(BOOL done, SubscribeDocumentsResponse *_Nullable response, NSError *_Nullable error))eventHandler{
+  [[self RPCTosubscribeDocumentsWithRequest:request eventHandler:eventHandler] start];
+}
+// Returns a not-yet-started RPC object.
+- (GRPCProtoCall *)RPCTosubscribeDocumentsWithRequest:(SubscribeDocumentsRequest *)request eventHandler:(void(^)(BOOL done, SubscribeDocumentsResponse *_Nullable response, NSError *_Nullable error))eventHandler{
+  return [self RPCToMethod:@"subscribeDocuments"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[SubscribeDocumentsResponse class]
+        responsesWriteable:[GRXWriteable writeableWithEventHandler:eventHandler]];
+}
+- (GRPCUnaryProtoCall *)subscribeDocumentsWithMessage:(SubscribeDocumentsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"subscribeDocuments"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[SubscribeDocumentsResponse class]];
+}
+
 #pragma mark getIdentityByPublicKeyHash(GetIdentityByPublicKeyHashRequest) returns (GetIdentityByPublicKeyHashResponse)
 
 - (void)getIdentityByPublicKeyHashWithRequest:(GetIdentityByPublicKeyHashRequest *)request handler:(void(^)(GetIdentityByPublicKeyHashResponse *_Nullable response, NSError *_Nullable error))handler{
@@ -410,6 +450,26 @@
              responseClass:[GetIdentityByNonUniquePublicKeyHashResponse class]];
 }
 
+#pragma mark getIdentityStateTransitions(GetIdentityStateTransitionsRequest) returns (GetIdentityStateTransitionsResponse)
+
+- (void)getIdentityStateTransitionsWithRequest:(GetIdentityStateTransitionsRequest *)request handler:(void(^)(GetIdentityStateTransitionsResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTogetIdentityStateTransitionsWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+- (GRPCProtoCall *)RPCTogetIdentityStateTransitionsWithRequest:(GetIdentityStateTransitionsRequest *)request handler:(void(^)(GetIdentityStateTransitionsResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"getIdentityStateTransitions"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[GetIdentityStateTransitionsResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+- (GRPCUnaryProtoCall *)getIdentityStateTransitionsWithMessage:(GetIdentityStateTransitionsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"getIdentityStateTransitions"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[GetIdentityStateTransitionsResponse class]];
+}
+
 #pragma mark waitForStateTransitionResult(WaitForStateTransitionResultRequest) returns (WaitForStateTransitionResultResponse)
 
 - (void)waitForStateTransitionResultWithRequest:(WaitForStateTransitionResultRequest *)request handler:(void(^)(WaitForStateTransitionResultResponse *_Nullable response, NSError *_Nullable error))handler{
@@ -430,6 +490,26 @@
              responseClass:[WaitForStateTransitionResultResponse class]];
 }
 
+#pragma mark simulateStateTransition(SimulateStateTransitionRequest) returns (SimulateStateTransitionResponse)
+
+- (void)simulateStateTransitionWithRequest:(SimulateStateTransitionRequest *)request handler:(void(^)(SimulateStateTransitionResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTosimulateStateTransitionWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+- (GRPCProtoCall *)RPCTosimulateStateTransitionWithRequest:(SimulateStateTransitionRequest *)request handler:(void(^)(SimulateStateTransitionResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"simulateStateTransition"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[SimulateStateTransitionResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+- (GRPCUnaryProtoCall *)simulateStateTransitionWithMessage:(SimulateStateTransitionRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"simulateStateTransition"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[SimulateStateTransitionResponse class]];
+}
+
 #pragma mark getConsensusParams(GetConsensusParamsRequest) returns (GetConsensusParamsResponse)
 
 - (void)getConsensusParamsWithRequest:(GetConsensusParamsRequest *)request handler:(void(^)(GetConsensusParamsResponse *_Nullable response, NSError *_Nullable error))handler{
@@ -530,6 +610,39 @@
              responseClass:[GetFinalizedEpochInfosResponse class]];
 }
 
+#pragma mark getBlockStateTransitions(GetBlockStateTransitionsRequest) returns (GetBlockStateTransitionsResponse)
+
+/**
+ * Which state transitions were executed in a recent block, with their results
+ *
+ * This method belongs to a set of APIs that have been deprecated. Using the v2 API is recommended.
+ */
+- (void)getBlockStateTransitionsWithRequest:(GetBlockStateTransitionsRequest *)request handler:(void(^)(GetBlockStateTransitionsResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTogetBlockStateTransitionsWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+/**
+ * Which state transitions were executed in a recent block, with their results
+ *
+ * This method belongs to a set of APIs that have been deprecated. Using the v2 API is recommended.
+ */
+- (GRPCProtoCall *)RPCTogetBlockStateTransitionsWithRequest:(GetBlockStateTransitionsRequest *)request handler:(void(^)(GetBlockStateTransitionsResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"getBlockStateTransitions"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[GetBlockStateTransitionsResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+/**
+ * Which state transitions were executed in a recent block, with their results
+ */
+- (GRPCUnaryProtoCall *)getBlockStateTransitionsWithMessage:(GetBlockStateTransitionsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"getBlockStateTransitions"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[GetBlockStateTransitionsResponse class]];
+}
+
 #pragma mark getContestedResources(GetContestedResourcesRequest) returns (GetContestedResourcesResponse)
 
 /**
@@ -695,6 +808,39 @@
              responseClass:[GetVotePollsByEndDateResponse class]];
 }
 
+#pragma mark getYesNoAbstainVotePollState(GetYesNoAbstainVotePollStateRequest) returns (GetYesNoAbstainVotePollStateResponse)
+
+/**
+ * How did masternodes vote on a yes/no/abstain poll?
+ *
+ * This method belongs to a set of APIs that have been deprecated. Using the v2 API is recommended.
+ */
+- (void)getYesNoAbstainVotePollStateWithRequest:(GetYesNoAbstainVotePollStateRequest *)request handler:(void(^)(GetYesNoAbstainVotePollStateResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTogetYesNoAbstainVotePollStateWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+/**
+ * How did masternodes vote on a yes/no/abstain poll?
+ *
+ * This method belongs to a set of APIs that have been deprecated. Using the v2 API is recommended.
+ */
+- (GRPCProtoCall *)RPCTogetYesNoAbstainVotePollStateWithRequest:(GetYesNoAbstainVotePollStateRequest *)request handler:(void(^)(GetYesNoAbstainVotePollStateResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"getYesNoAbstainVotePollState"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[GetYesNoAbstainVotePollStateResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+/**
+ * How did masternodes vote on a yes/no/abstain poll?
+ */
+- (GRPCUnaryProtoCall *)getYesNoAbstainVotePollStateWithMessage:(GetYesNoAbstainVotePollStateRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"getYesNoAbstainVotePollState"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[GetYesNoAbstainVotePollStateResponse class]];
+}
+
 #pragma mark getPrefundedSpecializedBalance(GetPrefundedSpecializedBalanceRequest) returns (GetPrefundedSpecializedBalanceResponse)
 
 - (void)getPrefundedSpecializedBalanceWithRequest:(GetPrefundedSpecializedBalanceRequest *)request handler:(void(^)(GetPrefundedSpecializedBalanceResponse *_Nullable response, NSError *_Nullable error))handler{
@@ -995,6 +1141,106 @@
              responseClass:[GetTokenTotalSupplyResponse class]];
 }
 
+#pragma mark getTokenHolders(GetTokenHoldersRequest) returns (GetTokenHoldersResponse)
+
+- (void)getTokenHoldersWithRequest:(GetTokenHoldersRequest *)request handler:(void(^)(GetTokenHoldersResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTogetTokenHoldersWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+- (GRPCProtoCall *)RPCTogetTokenHoldersWithRequest:(GetTokenHoldersRequest *)request handler:(void(^)(GetTokenHoldersResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"getTokenHolders"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[GetTokenHoldersResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+- (GRPCUnaryProtoCall *)getTokenHoldersWithMessage:(GetTokenHoldersRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"getTokenHolders"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[GetTokenHoldersResponse class]];
+}
+
+#pragma mark getTokenOrderBook(GetTokenOrderBookRequest) returns (GetTokenOrderBookResponse)
+
+- (void)getTokenOrderBookWithRequest:(GetTokenOrderBookRequest *)request handler:(void(^)(GetTokenOrderBookResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTogetTokenOrderBookWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+- (GRPCProtoCall *)RPCTogetTokenOrderBookWithRequest:(GetTokenOrderBookRequest *)request handler:(void(^)(GetTokenOrderBookResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"getTokenOrderBook"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[GetTokenOrderBookResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+- (GRPCUnaryProtoCall *)getTokenOrderBookWithMessage:(GetTokenOrderBookRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"getTokenOrderBook"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[GetTokenOrderBookResponse class]];
+}
+
+#pragma mark getTokenVestings(GetTokenVestingsRequest) returns (GetTokenVestingsResponse)
+
+- (void)getTokenVestingsWithRequest:(GetTokenVestingsRequest *)request handler:(void(^)(GetTokenVestingsResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTogetTokenVestingsWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+- (GRPCProtoCall *)RPCTogetTokenVestingsWithRequest:(GetTokenVestingsRequest *)request handler:(void(^)(GetTokenVestingsResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"getTokenVestings"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[GetTokenVestingsResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+- (GRPCUnaryProtoCall *)getTokenVestingsWithMessage:(GetTokenVestingsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"getTokenVestings"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[GetTokenVestingsResponse class]];
+}
+
+#pragma mark getTokenAllowances(GetTokenAllowancesRequest) returns (GetTokenAllowancesResponse)
+
+- (void)getTokenAllowancesWithRequest:(GetTokenAllowancesRequest *)request handler:(void(^)(GetTokenAllowancesResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTogetTokenAllowancesWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+- (GRPCProtoCall *)RPCTogetTokenAllowancesWithRequest:(GetTokenAllowancesRequest *)request handler:(void(^)(GetTokenAllowancesResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"getTokenAllowances"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[GetTokenAllowancesResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+- (GRPCUnaryProtoCall *)getTokenAllowancesWithMessage:(GetTokenAllowancesRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"getTokenAllowances"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[GetTokenAllowancesResponse class]];
+}
+
+#pragma mark getTokenEvents(GetTokenEventsRequest) returns (GetTokenEventsResponse)
+
+- (void)getTokenEventsWithRequest:(GetTokenEventsRequest *)request handler:(void(^)(GetTokenEventsResponse *_Nullable response, NSError *_Nullable error))handler{
+  [[self RPCTogetTokenEventsWithRequest:request handler:handler] start];
+}
+// Returns a not-yet-started RPC object.
+- (GRPCProtoCall *)RPCTogetTokenEventsWithRequest:(GetTokenEventsRequest *)request handler:(void(^)(GetTokenEventsResponse *_Nullable response, NSError *_Nullable error))handler{
+  return [self RPCToMethod:@"getTokenEvents"
+            requestsWriter:[GRXWriter writerWithValue:request]
+             responseClass:[GetTokenEventsResponse class]
+        responsesWriteable:[GRXWriteable writeableWithSingleHandler:handler]];
+}
+- (GRPCUnaryProtoCall *)getTokenEventsWithMessage:(GetTokenEventsRequest *)message responseHandler:(id<GRPCProtoResponseHandler>)handler callOptions:(GRPCCallOptions *_Nullable)callOptions {
+  return [self RPCToMethod:@"getTokenEvents"
+                   message:message
+           responseHandler:handler
+               callOptions:callOptions
+             responseClass:[GetTokenEventsResponse class]];
+}
+
 #pragma mark getGroupInfo(GetGroupInfoRequest) returns (GetGroupInfoResponse)
 
 - (void)getGroupInfoWithRequest:(GetGroupInfoRequest *)request handler:(void(^)(GetGroupInfoResponse *_Nullable response, NSError *_Nullable error))handler{
@@ -89,6 +89,16 @@ class PlatformStub(object):
                 request_serializer=platform__pb2.GetDocumentsRequest.SerializeToString,
                 response_deserializer=platform__pb2.GetDocumentsResponse.FromString,
                 )
+        self.getDocumentsAggregate = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getDocumentsAggregate',
+                request_serializer=platform__pb2.GetDocumentsAggregateRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetDocumentsAggregateResponse.FromString,
+                )
+        self.subscribeDocuments = channel.unary_stream(
+                '/org.dash.platform.dapi.v0.Platform/subscribeDocuments',
+                request_serializer=platform__pb2.SubscribeDocumentsRequest.SerializeToString,
+                response_deserializer=platform__pb2.SubscribeDocumentsResponse.FromString,
+                )
         self.getIdentityByPublicKeyHash = channel.unary_unary(
                 '/org.dash.platform.dapi.v0.Platform/getIdentityByPublicKeyHash',
                 request_serializer=platform__pb2.GetIdentityByPublicKeyHashRequest.SerializeToString,
@@ -99,11 +109,21 @@ class PlatformStub(object):
                 request_serializer=platform__pb2.GetIdentityByNonUniquePublicKeyHashRequest.SerializeToString,
                 response_deserializer=platform__pb2.GetIdentityByNonUniquePublicKeyHashResponse.FromString,
                 )
+        self.getIdentityStateTransitions = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getIdentityStateTransitions',
+                request_serializer=platform__pb2.GetIdentityStateTransitionsRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetIdentityStateTransitionsResponse.FromString,
+                )
         self.waitForStateTransitionResult = channel.unary_unary(
                 '/org.dash.platform.dapi.v0.Platform/waitForStateTransitionResult',
                 request_serializer=platform__pb2.WaitForStateTransitionResultRequest.SerializeToString,
                 response_deserializer=platform__pb2.WaitForStateTransitionResultResponse.FromString,
                 )
+        self.simulateStateTransition = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/simulateStateTransition',
+                request_serializer=platform__pb2.SimulateStateTransitionRequest.SerializeToString,
+                response_deserializer=platform__pb2.SimulateStateTransitionResponse.FromString,
+                )
         self.getConsensusParams = channel.unary_unary(
                 '/org.dash.platform.dapi.v0.Platform/getConsensusParams',
                 request_serializer=platform__pb2.GetConsensusParamsRequest.SerializeToString,
@@ -129,6 +149,11 @@ class PlatformStub(object):
                 request_serializer=platform__pb2.GetFinalizedEpochInfosRequest.SerializeToString,
                 response_deserializer=platform__pb2.GetFinalizedEpochInfosResponse.FromString,
                 )
+        self.getBlockStateTransitions = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getBlockStateTransitions',
+                request_serializer=platform__pb2.GetBlockStateTransitionsRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetBlockStateTransitionsResponse.FromString,
+                )
         self.getContestedResources = channel.unary_unary(
                 '/org.dash.platform.dapi.v0.Platform/getContestedResources',
                 request_serializer=platform__pb2.GetContestedResourcesRequest.SerializeToString,
@@ -154,6 +179,11 @@ class PlatformStub(object):
                 request_serializer=platform__pb2.GetVotePollsByEndDateRequest.SerializeToString,
                 response_deserializer=platform__pb2.GetVotePollsByEndDateResponse.FromString,
                 )
+        self.getYesNoAbstainVotePollState = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getYesNoAbstainVotePollState',
+                request_serializer=platform__pb2.GetYesNoAbstainVotePollStateRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetYesNoAbstainVotePollStateResponse.FromString,
+                )
         self.getPrefundedSpecializedBalance = channel.unary_unary(
                 '/org.dash.platform.dapi.v0.Platform/getPrefundedSpecializedBalance',
                 request_serializer=platform__pb2.GetPrefundedSpecializedBalanceRequest.SerializeToString,
@@ -229,6 +259,31 @@ class PlatformStub(object):
                 request_serializer=platform__pb2.GetTokenTotalSupplyRequest.SerializeToString,
                 response_deserializer=platform__pb2.GetTokenTotalSupplyResponse.FromString,
                 )
+        self.getTokenHolders = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getTokenHolders',
+                request_serializer=platform__pb2.GetTokenHoldersRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetTokenHoldersResponse.FromString,
+                )
+        self.getTokenOrderBook = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getTokenOrderBook',
+                request_serializer=platform__pb2.GetTokenOrderBookRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetTokenOrderBookResponse.FromString,
+                )
+        self.getTokenVestings = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getTokenVestings',
+                request_serializer=platform__pb2.GetTokenVestingsRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetTokenVestingsResponse.FromString,
+                )
+        self.getTokenAllowances = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getTokenAllowances',
+                request_serializer=platform__pb2.GetTokenAllowancesRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetTokenAllowancesResponse.FromString,
+                )
+        self.getTokenEvents = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getTokenEvents',
+                request_serializer=platform__pb2.GetTokenEventsRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetTokenEventsResponse.FromString,
+                )
         self.getGroupInfo = channel.unary_unary(
                 '/org.dash.platform.dapi.v0.Platform/getGroupInfo',
                 request_serializer=platform__pb2.GetGroupInfoRequest.SerializeToString,
@@ -344,6 +399,18 @@ class PlatformServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def getDocumentsAggregate(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def subscribeDocuments(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def getIdentityByPublicKeyHash(self, request, context):
         """Missing associated documentation comment in .proto file."""
         context.set_code(grpc.StatusCode.UNIMPLEMENTED)
@@ -356,12 +423,24 @@ class PlatformServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def getIdentityStateTransitions(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def waitForStateTransitionResult(self, request, context):
         """Missing associated documentation comment in .proto file."""
         context.set_code(grpc.StatusCode.UNIMPLEMENTED)
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def simulateStateTransition(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def getConsensusParams(self, request, context):
         """Missing associated documentation comment in .proto file."""
         context.set_code(grpc.StatusCode.UNIMPLEMENTED)
@@ -392,6 +471,13 @@ class PlatformServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def getBlockStateTransitions(self, request, context):
+        """Which state transitions were executed in a recent block, with their results
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def getContestedResources(self, request, context):
         """What votes are currently happening for a specific contested index
         """
@@ -427,6 +513,13 @@ class PlatformServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def getYesNoAbstainVotePollState(self, request, context):
+        """How did masternodes vote on a yes/no/abstain poll?
+        """
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def getPrefundedSpecializedBalance(self, request, context):
         """Missing associated documentation comment in .proto file."""
         context.set_code(grpc.StatusCode.UNIMPLEMENTED)
@@ -517,6 +610,36 @@ class PlatformServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def getTokenHolders(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def getTokenOrderBook(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def getTokenVestings(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def getTokenAllowances(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def getTokenEvents(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def getGroupInfo(self, request, context):
         """Missing associated documentation comment in .proto file."""
         context.set_code(grpc.StatusCode.UNIMPLEMENTED)
@@ -619,6 +742,16 @@ def add_PlatformServicer_to_server(servicer, server):
                     request_deserializer=platform__pb2.GetDocumentsRequest.FromString,
                     response_serializer=platform__pb2.GetDocumentsResponse.SerializeToString,
             ),
+            'getDocumentsAggregate': grpc.unary_unary_rpc_method_handler(
+                    servicer.getDocumentsAggregate,
+                    request_deserializer=platform__pb2.GetDocumentsAggregateRequest.FromString,
+                    response_serializer=platform__pb2.GetDocumentsAggregateResponse.SerializeToString,
+            ),
+            'subscribeDocuments': grpc.unary_stream_rpc_method_handler(
+                    servicer.subscribeDocuments,
+                    request_deserializer=platform__pb2.SubscribeDocumentsRequest.FromString,
+                    response_serializer=platform__pb2.SubscribeDocumentsResponse.SerializeToString,
+            ),
             'getIdentityByPublicKeyHash': grpc.unary_unary_rpc_method_handler(
                     servicer.getIdentityByPublicKeyHash,
                     request_deserializer=platform__pb2.GetIdentityByPublicKeyHashRequest.FromString,
@@ -629,11 +762,21 @@ def add_PlatformServicer_to_server(servicer, server):
                     request_deserializer=platform__pb2.GetIdentityByNonUniquePublicKeyHashRequest.FromString,
                     response_serializer=platform__pb2.GetIdentityByNonUniquePublicKeyHashResponse.SerializeToString,
             ),
+            'getIdentityStateTransitions': grpc.unary_unary_rpc_method_handler(
+                    servicer.getIdentityStateTransitions,
+                    request_deserializer=platform__pb2.GetIdentityStateTransitionsRequest.FromString,
+                    response_serializer=platform__pb2.GetIdentityStateTransitionsResponse.SerializeToString,
+            ),
             'waitForStateTransitionResult': grpc.unary_unary_rpc_method_handler(
                     servicer.waitForStateTransitionResult,
                     request_deserializer=platform__pb2.WaitForStateTransitionResultRequest.FromString,
                     response_serializer=platform__pb2.WaitForStateTransitionResultResponse.SerializeToString,
             ),
+            'simulateStateTransition': grpc.unary_unary_rpc_method_handler(
+                    servicer.simulateStateTransition,
+                    request_deserializer=platform__pb2.SimulateStateTransitionRequest.FromString,
+                    response_serializer=platform__pb2.SimulateStateTransitionResponse.SerializeToString,
+            ),
             'getConsensusParams': grpc.unary_unary_rpc_method_handler(
                     servicer.getConsensusParams,
                     request_deserializer=platform__pb2.GetConsensusParamsRequest.FromString,
@@ -659,6 +802,11 @@ def add_PlatformServicer_to_server(servicer, server):
                     request_deserializer=platform__pb2.GetFinalizedEpochInfosRequest.FromString,
                     response_serializer=platform__pb2.GetFinalizedEpochInfosResponse.SerializeToString,
             ),
+            'getBlockStateTransitions': grpc.unary_unary_rpc_method_handler(
+                    servicer.getBlockStateTransitions,
+                    request_deserializer=platform__pb2.GetBlockStateTransitionsRequest.FromString,
+                    response_serializer=platform__pb2.GetBlockStateTransitionsResponse.SerializeToString,
+            ),
             'getContestedResources': grpc.unary_unary_rpc_method_handler(
                     servicer.getContestedResources,
                     request_deserializer=platform__pb2.GetContestedResourcesRequest.FromString,
@@ -684,6 +832,11 @@ def add_PlatformServicer_to_server(servicer, server):
                     request_deserializer=platform__pb2.GetVotePollsByEndDateRequest.FromString,
                     response_serializer=platform__pb2.GetVotePollsByEndDateResponse.SerializeToString,
             ),
+            'getYesNoAbstainVotePollState': grpc.unary_unary_rpc_method_handler(
+                    servicer.getYesNoAbstainVotePollState,
+                    request_deserializer=platform__pb2.GetYesNoAbstainVotePollStateRequest.FromString,
+                    response_serializer=platform__pb2.GetYesNoAbstainVotePollStateResponse.SerializeToString,
+            ),
             'getPrefundedSpecializedBalance': grpc.unary_unary_rpc_method_handler(
                     servicer.getPrefundedSpecializedBalance,
                     request_deserializer=platform__pb2.GetPrefundedSpecializedBalanceRequest.FromString,
@@ -759,6 +912,31 @@ def add_PlatformServicer_to_server(servicer, server):
                     request_deserializer=platform__pb2.GetTokenTotalSupplyRequest.FromString,
                     response_serializer=platform__pb2.GetTokenTotalSupplyResponse.SerializeToString,
             ),
+            'getTokenHolders': grpc.unary_unary_rpc_method_handler(
+                    servicer.getTokenHolders,
+                    request_deserializer=platform__pb2.GetTokenHoldersRequest.FromString,
+                    response_serializer=platform__pb2.GetTokenHoldersResponse.SerializeToString,
+            ),
+            'getTokenOrderBook': grpc.unary_unary_rpc_method_handler(
+                    servicer.getTokenOrderBook,
+                    request_deserializer=platform__pb2.GetTokenOrderBookRequest.FromString,
+                    response_serializer=platform__pb2.GetTokenOrderBookResponse.SerializeToString,
+            ),
+            'getTokenVestings': grpc.unary_unary_rpc_method_handler(
+                    servicer.getTokenVestings,
+                    request_deserializer=platform__pb2.GetTokenVestingsRequest.FromString,
+                    response_serializer=platform__pb2.GetTokenVestingsResponse.SerializeToString,
+            ),
+            'getTokenAllowances': grpc.unary_unary_rpc_method_handler(
+                    servicer.getTokenAllowances,
+                    request_deserializer=platform__pb2.GetTokenAllowancesRequest.FromString,
+                    response_serializer=platform__pb2.GetTokenAllowancesResponse.SerializeToString,
+            ),
+            'getTokenEvents': grpc.unary_unary_rpc_method_handler(
+                    servicer.getTokenEvents,
+                    request_deserializer=platform__pb2.GetTokenEventsRequest.FromString,
+                    response_serializer=platform__pb2.GetTokenEventsResponse.SerializeToString,
+            ),
             'getGroupInfo': grpc.unary_unary_rpc_method_handler(
                     servicer.getGroupInfo,
                     request_deserializer=platform__pb2.GetGroupInfoRequest.FromString,
@@ -1044,6 +1222,40 @@ class Platform(object):
             options, channel_credentials,
             insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
 
+    @staticmethod
+    def getDocumentsAggregate(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getDocumentsAggregate',
+            platform__pb2.GetDocumentsAggregateRequest.SerializeToString,
+            platform__pb2.GetDocumentsAggregateResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def subscribeDocuments(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_stream(request, target, '/org.dash.platform.dapi.v0.Platform/subscribeDocuments',
+            platform__pb2.SubscribeDocumentsRequest.SerializeToString,
+            platform__pb2.SubscribeDocumentsResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
     @staticmethod
     def getIdentityByPublicKeyHash(request,
             target,
@@ -1078,6 +1290,23 @@ class Platform(object):
             options, channel_credentials,
             insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
 
+    @staticmethod
+    def getIdentityStateTransitions(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getIdentityStateTransitions',
+            platform__pb2.GetIdentityStateTransitionsRequest.SerializeToString,
+            platform__pb2.GetIdentityStateTransitionsResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
     @staticmethod
     def waitForStateTransitionResult(request,
             target,
@@ -1095,6 +1324,23 @@ class Platform(object):
             options, channel_credentials,
             insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
 
+    @staticmethod
+    def simulateStateTransition(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/simulateStateTransition',
+            platform__pb2.SimulateStateTransitionRequest.SerializeToString,
+            platform__pb2.SimulateStateTransitionResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
     @staticmethod
     def getConsensusParams(request,
             target,
@@ -1180,6 +1426,23 @@ class Platform(object):
             options, channel_credentials,
             insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
 
+    @staticmethod
+    def getBlockStateTransitions(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getBlockStateTransitions',
+            platform__pb2.GetBlockStateTransitionsRequest.SerializeToString,
+            platform__pb2.GetBlockStateTransitionsResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
     @staticmethod
     def getContestedResources(request,
             target,
@@ -1265,6 +1528,23 @@ class Platform(object):
             options, channel_credentials,
             insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
 
+    @staticmethod
+    def getYesNoAbstainVotePollState(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getYesNoAbstainVotePollState',
+            platform__pb2.GetYesNoAbstainVotePollStateRequest.SerializeToString,
+            platform__pb2.GetYesNoAbstainVotePollStateResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
     @staticmethod
     def getPrefundedSpecializedBalance(request,
             target,
@@ -1520,6 +1800,91 @@ class Platform(object):
             options, channel_credentials,
             insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
 
+    @staticmethod
+    def getTokenHolders(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getTokenHolders',
+            platform__pb2.GetTokenHoldersRequest.SerializeToString,
+            platform__pb2.GetTokenHoldersResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def getTokenOrderBook(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getTokenOrderBook',
+            platform__pb2.GetTokenOrderBookRequest.SerializeToString,
+            platform__pb2.GetTokenOrderBookResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def getTokenVestings(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getTokenVestings',
+            platform__pb2.GetTokenVestingsRequest.SerializeToString,
+            platform__pb2.GetTokenVestingsResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def getTokenAllowances(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getTokenAllowances',
+            platform__pb2.GetTokenAllowancesRequest.SerializeToString,
+            platform__pb2.GetTokenAllowancesResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def getTokenEvents(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getTokenEvents',
+            platform__pb2.GetTokenEventsRequest.SerializeToString,
+            platform__pb2.GetTokenEventsResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
     @staticmethod
     def getGroupInfo(request,
             target,
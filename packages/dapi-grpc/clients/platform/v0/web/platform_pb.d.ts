@@ -2434,6 +2434,397 @@ export namespace GetDocumentsResponse {
   }
 }
 
+export class GetDocumentsAggregateRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetDocumentsAggregateRequest.GetDocumentsAggregateRequestV0 | undefined;
+  setV0(value?: GetDocumentsAggregateRequest.GetDocumentsAggregateRequestV0): void;
+
+  getVersionCase(): GetDocumentsAggregateRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetDocumentsAggregateRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetDocumentsAggregateRequest): GetDocumentsAggregateRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetDocumentsAggregateRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetDocumentsAggregateRequest;
+  static deserializeBinaryFromReader(message: GetDocumentsAggregateRequest, reader: jspb.BinaryReader): GetDocumentsAggregateRequest;
+}
+
+export namespace GetDocumentsAggregateRequest {
+  export type AsObject = {
+    v0?: GetDocumentsAggregateRequest.GetDocumentsAggregateRequestV0.AsObject,
+  }
+
+  export class GetDocumentsAggregateRequestV0 extends jspb.Message {
+    getDataContractId(): Uint8Array | string;
+    getDataContractId_asU8(): Uint8Array;
+    getDataContractId_asB64(): string;
+    setDataContractId(value: Uint8Array | string): void;
+
+    getDocumentType(): string;
+    setDocumentType(value: string): void;
+
+    getWhere(): Uint8Array | string;
+    getWhere_asU8(): Uint8Array;
+    getWhere_asB64(): string;
+    setWhere(value: Uint8Array | string): void;
+
+    getOrderBy(): Uint8Array | string;
+    getOrderBy_asU8(): Uint8Array;
+    getOrderBy_asB64(): string;
+    setOrderBy(value: Uint8Array | string): void;
+
+    getFunction(): GetDocumentsAggregateRequest.GetDocumentsAggregateRequestV0.AggregateFunctionMap[keyof GetDocumentsAggregateRequest.GetDocumentsAggregateRequestV0.AggregateFunctionMap];
+    setFunction(value: GetDocumentsAggregateRequest.GetDocumentsAggregateRequestV0.AggregateFunctionMap[keyof GetDocumentsAggregateRequest.GetDocumentsAggregateRequestV0.AggregateFunctionMap]): void;
+
+    getSumProperty(): string;
+    setSumProperty(value: string): void;
+
+    getLimit(): number;
+    setLimit(value: number): void;
+
+    getStartAfter(): Uint8Array | string;
+    getStartAfter_asU8(): Uint8Array;
+    getStartAfter_asB64(): string;
+    setStartAfter(value: Uint8Array | string): void;
+
+    getProve(): boolean;
+    setProve(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetDocumentsAggregateRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetDocumentsAggregateRequestV0): GetDocumentsAggregateRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetDocumentsAggregateRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetDocumentsAggregateRequestV0;
+    static deserializeBinaryFromReader(message: GetDocumentsAggregateRequestV0, reader: jspb.BinaryReader): GetDocumentsAggregateRequestV0;
+  }
+
+  export namespace GetDocumentsAggregateRequestV0 {
+    export type AsObject = {
+      dataContractId: Uint8Array | string,
+      documentType: string,
+      where: Uint8Array | string,
+      orderBy: Uint8Array | string,
+      function: GetDocumentsAggregateRequest.GetDocumentsAggregateRequestV0.AggregateFunctionMap[keyof GetDocumentsAggregateRequest.GetDocumentsAggregateRequestV0.AggregateFunctionMap],
+      sumProperty: string,
+      limit: number,
+      startAfter: Uint8Array | string,
+      prove: boolean,
+    }
+
+    export interface AggregateFunctionMap {
+      COUNT: 0;
+      SUM: 1;
+    }
+
+    export const AggregateFunction: AggregateFunctionMap;
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetDocumentsAggregateResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetDocumentsAggregateResponse.GetDocumentsAggregateResponseV0 | undefined;
+  setV0(value?: GetDocumentsAggregateResponse.GetDocumentsAggregateResponseV0): void;
+
+  getVersionCase(): GetDocumentsAggregateResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetDocumentsAggregateResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetDocumentsAggregateResponse): GetDocumentsAggregateResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetDocumentsAggregateResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetDocumentsAggregateResponse;
+  static deserializeBinaryFromReader(message: GetDocumentsAggregateResponse, reader: jspb.BinaryReader): GetDocumentsAggregateResponse;
+}
+
+export namespace GetDocumentsAggregateResponse {
+  export type AsObject = {
+    v0?: GetDocumentsAggregateResponse.GetDocumentsAggregateResponseV0.AsObject,
+  }
+
+  export class GetDocumentsAggregateResponseV0 extends jspb.Message {
+    hasAggregate(): boolean;
+    clearAggregate(): void;
+    getAggregate(): GetDocumentsAggregateResponse.GetDocumentsAggregateResponseV0.DocumentsAggregate | undefined;
+    setAggregate(value?: GetDocumentsAggregateResponse.GetDocumentsAggregateResponseV0.DocumentsAggregate): void;
+
+    hasProof(): boolean;
+    clearProof(): void;
+    getProof(): Proof | undefined;
+    setProof(value?: Proof): void;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    getResultCase(): GetDocumentsAggregateResponseV0.ResultCase;
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetDocumentsAggregateResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetDocumentsAggregateResponseV0): GetDocumentsAggregateResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetDocumentsAggregateResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetDocumentsAggregateResponseV0;
+    static deserializeBinaryFromReader(message: GetDocumentsAggregateResponseV0, reader: jspb.BinaryReader): GetDocumentsAggregateResponseV0;
+  }
+
+  export namespace GetDocumentsAggregateResponseV0 {
+    export type AsObject = {
+      aggregate?: GetDocumentsAggregateResponse.GetDocumentsAggregateResponseV0.DocumentsAggregate.AsObject,
+      proof?: Proof.AsObject,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export class DocumentsAggregate extends jspb.Message {
+      getCount(): number;
+      setCount(value: number): void;
+
+      getSum(): number;
+      setSum(value: number): void;
+
+      getNextStartAfter(): Uint8Array | string;
+      getNextStartAfter_asU8(): Uint8Array;
+      getNextStartAfter_asB64(): string;
+      setNextStartAfter(value: Uint8Array | string): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): DocumentsAggregate.AsObject;
+      static toObject(includeInstance: boolean, msg: DocumentsAggregate): DocumentsAggregate.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: DocumentsAggregate, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): DocumentsAggregate;
+      static deserializeBinaryFromReader(message: DocumentsAggregate, reader: jspb.BinaryReader): DocumentsAggregate;
+    }
+
+    export namespace DocumentsAggregate {
+      export type AsObject = {
+        count: number,
+        sum: number,
+        nextStartAfter: Uint8Array | string,
+      }
+    }
+
+    export enum ResultCase {
+      RESULT_NOT_SET = 0,
+      AGGREGATE = 1,
+      PROOF = 2,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class SubscribeDocumentsRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): SubscribeDocumentsRequest.SubscribeDocumentsRequestV0 | undefined;
+  setV0(value?: SubscribeDocumentsRequest.SubscribeDocumentsRequestV0): void;
+
+  getVersionCase(): SubscribeDocumentsRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): SubscribeDocumentsRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: SubscribeDocumentsRequest): SubscribeDocumentsRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: SubscribeDocumentsRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): SubscribeDocumentsRequest;
+  static deserializeBinaryFromReader(message: SubscribeDocumentsRequest, reader: jspb.BinaryReader): SubscribeDocumentsRequest;
+}
+
+export namespace SubscribeDocumentsRequest {
+  export type AsObject = {
+    v0?: SubscribeDocumentsRequest.SubscribeDocumentsRequestV0.AsObject,
+  }
+
+  export class SubscribeDocumentsRequestV0 extends jspb.Message {
+    getDataContractId(): Uint8Array | string;
+    getDataContractId_asU8(): Uint8Array;
+    getDataContractId_asB64(): string;
+    setDataContractId(value: Uint8Array | string): void;
+
+    getDocumentType(): string;
+    setDocumentType(value: string): void;
+
+    getAction(): SubscribeDocumentsRequest.SubscribeDocumentsRequestV0.DocumentActionMap[keyof SubscribeDocumentsRequest.SubscribeDocumentsRequestV0.DocumentActionMap];
+    setAction(value: SubscribeDocumentsRequest.SubscribeDocumentsRequestV0.DocumentActionMap[keyof SubscribeDocumentsRequest.SubscribeDocumentsRequestV0.DocumentActionMap]): void;
+
+    getNewDocumentWhere(): Uint8Array | string;
+    getNewDocumentWhere_asU8(): Uint8Array;
+    getNewDocumentWhere_asB64(): string;
+    setNewDocumentWhere(value: Uint8Array | string): void;
+
+    getOriginalDocumentWhere(): Uint8Array | string;
+    getOriginalDocumentWhere_asU8(): Uint8Array;
+    getOriginalDocumentWhere_asB64(): string;
+    setOriginalDocumentWhere(value: Uint8Array | string): void;
+
+    getValueClause(): Uint8Array | string;
+    getValueClause_asU8(): Uint8Array;
+    getValueClause_asB64(): string;
+    setValueClause(value: Uint8Array | string): void;
+
+    getProve(): boolean;
+    setProve(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): SubscribeDocumentsRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: SubscribeDocumentsRequestV0): SubscribeDocumentsRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: SubscribeDocumentsRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): SubscribeDocumentsRequestV0;
+    static deserializeBinaryFromReader(message: SubscribeDocumentsRequestV0, reader: jspb.BinaryReader): SubscribeDocumentsRequestV0;
+  }
+
+  export namespace SubscribeDocumentsRequestV0 {
+    export type AsObject = {
+      dataContractId: Uint8Array | string,
+      documentType: string,
+      action: SubscribeDocumentsRequest.SubscribeDocumentsRequestV0.DocumentActionMap[keyof SubscribeDocumentsRequest.SubscribeDocumentsRequestV0.DocumentActionMap],
+      newDocumentWhere: Uint8Array | string,
+      originalDocumentWhere: Uint8Array | string,
+      valueClause: Uint8Array | string,
+      prove: boolean,
+    }
+
+    export interface DocumentActionMap {
+      CREATE: 0;
+      REPLACE: 1;
+      DELETE: 2;
+      TRANSFER: 3;
+      UPDATE_PRICE: 4;
+      PURCHASE: 5;
+    }
+
+    export const DocumentAction: DocumentActionMap;
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class SubscribeDocumentsResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): SubscribeDocumentsResponse.SubscribeDocumentsResponseV0 | undefined;
+  setV0(value?: SubscribeDocumentsResponse.SubscribeDocumentsResponseV0): void;
+
+  getVersionCase(): SubscribeDocumentsResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): SubscribeDocumentsResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: SubscribeDocumentsResponse): SubscribeDocumentsResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: SubscribeDocumentsResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): SubscribeDocumentsResponse;
+  static deserializeBinaryFromReader(message: SubscribeDocumentsResponse, reader: jspb.BinaryReader): SubscribeDocumentsResponse;
+}
+
+export namespace SubscribeDocumentsResponse {
+  export type AsObject = {
+    v0?: SubscribeDocumentsResponse.SubscribeDocumentsResponseV0.AsObject,
+  }
+
+  export class SubscribeDocumentsResponseV0 extends jspb.Message {
+    getBlockHeight(): number;
+    setBlockHeight(value: number): void;
+
+    clearStateTransitionsList(): void;
+    getStateTransitionsList(): Array<SubscribeDocumentsResponse.SubscribeDocumentsResponseV0.MatchedStateTransition>;
+    setStateTransitionsList(value: Array<SubscribeDocumentsResponse.SubscribeDocumentsResponseV0.MatchedStateTransition>): void;
+    addStateTransitions(value?: SubscribeDocumentsResponse.SubscribeDocumentsResponseV0.MatchedStateTransition, index?: number): SubscribeDocumentsResponse.SubscribeDocumentsResponseV0.MatchedStateTransition;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    getProofHeight(): number;
+    setProofHeight(value: number): void;
+
+    getError(): string;
+    setError(value: string): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): SubscribeDocumentsResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: SubscribeDocumentsResponseV0): SubscribeDocumentsResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: SubscribeDocumentsResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): SubscribeDocumentsResponseV0;
+    static deserializeBinaryFromReader(message: SubscribeDocumentsResponseV0, reader: jspb.BinaryReader): SubscribeDocumentsResponseV0;
+  }
+
+  export namespace SubscribeDocumentsResponseV0 {
+    export type AsObject = {
+      blockHeight: number,
+      stateTransitionsList: Array<SubscribeDocumentsResponse.SubscribeDocumentsResponseV0.MatchedStateTransition.AsObject>,
+      metadata?: ResponseMetadata.AsObject,
+      proofHeight: number,
+      error: string,
+    }
+
+    export class MatchedStateTransition extends jspb.Message {
+      getStateTransition(): Uint8Array | string;
+      getStateTransition_asU8(): Uint8Array;
+      getStateTransition_asB64(): string;
+      setStateTransition(value: Uint8Array | string): void;
+
+      clearTransitionIndicesList(): void;
+      getTransitionIndicesList(): Array<number>;
+      setTransitionIndicesList(value: Array<number>): void;
+      addTransitionIndices(value: number, index?: number): number;
+
+      hasProof(): boolean;
+      clearProof(): void;
+      getProof(): Proof | undefined;
+      setProof(value?: Proof): void;
+
+      getProofError(): string;
+      setProofError(value: string): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): MatchedStateTransition.AsObject;
+      static toObject(includeInstance: boolean, msg: MatchedStateTransition): MatchedStateTransition.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: MatchedStateTransition, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): MatchedStateTransition;
+      static deserializeBinaryFromReader(message: MatchedStateTransition, reader: jspb.BinaryReader): MatchedStateTransition;
+    }
+
+    export namespace MatchedStateTransition {
+      export type AsObject = {
+        stateTransition: Uint8Array | string,
+        transitionIndicesList: Array<number>,
+        proof?: Proof.AsObject,
+        proofError: string,
+      }
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
 export class GetIdentityByPublicKeyHashRequest extends jspb.Message {
   hasV0(): boolean;
   clearV0(): void;
@@ -2744,48 +3135,281 @@ export namespace GetIdentityByNonUniquePublicKeyHashResponse {
   }
 }
 
-export class WaitForStateTransitionResultRequest extends jspb.Message {
+export class GetIdentityStateTransitionsRequest extends jspb.Message {
   hasV0(): boolean;
   clearV0(): void;
-  getV0(): WaitForStateTransitionResultRequest.WaitForStateTransitionResultRequestV0 | undefined;
-  setV0(value?: WaitForStateTransitionResultRequest.WaitForStateTransitionResultRequestV0): void;
+  getV0(): GetIdentityStateTransitionsRequest.GetIdentityStateTransitionsRequestV0 | undefined;
+  setV0(value?: GetIdentityStateTransitionsRequest.GetIdentityStateTransitionsRequestV0): void;
 
-  getVersionCase(): WaitForStateTransitionResultRequest.VersionCase;
+  getVersionCase(): GetIdentityStateTransitionsRequest.VersionCase;
   serializeBinary(): Uint8Array;
-  toObject(includeInstance?: boolean): WaitForStateTransitionResultRequest.AsObject;
-  static toObject(includeInstance: boolean, msg: WaitForStateTransitionResultRequest): WaitForStateTransitionResultRequest.AsObject;
+  toObject(includeInstance?: boolean): GetIdentityStateTransitionsRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetIdentityStateTransitionsRequest): GetIdentityStateTransitionsRequest.AsObject;
   static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
   static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-  static serializeBinaryToWriter(message: WaitForStateTransitionResultRequest, writer: jspb.BinaryWriter): void;
-  static deserializeBinary(bytes: Uint8Array): WaitForStateTransitionResultRequest;
-  static deserializeBinaryFromReader(message: WaitForStateTransitionResultRequest, reader: jspb.BinaryReader): WaitForStateTransitionResultRequest;
+  static serializeBinaryToWriter(message: GetIdentityStateTransitionsRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetIdentityStateTransitionsRequest;
+  static deserializeBinaryFromReader(message: GetIdentityStateTransitionsRequest, reader: jspb.BinaryReader): GetIdentityStateTransitionsRequest;
 }
 
-export namespace WaitForStateTransitionResultRequest {
+export namespace GetIdentityStateTransitionsRequest {
   export type AsObject = {
-    v0?: WaitForStateTransitionResultRequest.WaitForStateTransitionResultRequestV0.AsObject,
+    v0?: GetIdentityStateTransitionsRequest.GetIdentityStateTransitionsRequestV0.AsObject,
   }
 
-  export class WaitForStateTransitionResultRequestV0 extends jspb.Message {
-    getStateTransitionHash(): Uint8Array | string;
-    getStateTransitionHash_asU8(): Uint8Array;
-    getStateTransitionHash_asB64(): string;
-    setStateTransitionHash(value: Uint8Array | string): void;
+  export class GetIdentityStateTransitionsRequestV0 extends jspb.Message {
+    getIdentityId(): Uint8Array | string;
+    getIdentityId_asU8(): Uint8Array;
+    getIdentityId_asB64(): string;
+    setIdentityId(value: Uint8Array | string): void;
+
+    hasStartAfter(): boolean;
+    clearStartAfter(): void;
+    getStartAfter(): GetIdentityStateTransitionsRequest.GetIdentityStateTransitionsRequestV0.StartAfter | undefined;
+    setStartAfter(value?: GetIdentityStateTransitionsRequest.GetIdentityStateTransitionsRequestV0.StartAfter): void;
+
+    hasCount(): boolean;
+    clearCount(): void;
+    getCount(): number;
+    setCount(value: number): void;
+
+    getOrderAscending(): boolean;
+    setOrderAscending(value: boolean): void;
 
     getProve(): boolean;
     setProve(value: boolean): void;
 
     serializeBinary(): Uint8Array;
-    toObject(includeInstance?: boolean): WaitForStateTransitionResultRequestV0.AsObject;
-    static toObject(includeInstance: boolean, msg: WaitForStateTransitionResultRequestV0): WaitForStateTransitionResultRequestV0.AsObject;
+    toObject(includeInstance?: boolean): GetIdentityStateTransitionsRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetIdentityStateTransitionsRequestV0): GetIdentityStateTransitionsRequestV0.AsObject;
     static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
     static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-    static serializeBinaryToWriter(message: WaitForStateTransitionResultRequestV0, writer: jspb.BinaryWriter): void;
-    static deserializeBinary(bytes: Uint8Array): WaitForStateTransitionResultRequestV0;
-    static deserializeBinaryFromReader(message: WaitForStateTransitionResultRequestV0, reader: jspb.BinaryReader): WaitForStateTransitionResultRequestV0;
+    static serializeBinaryToWriter(message: GetIdentityStateTransitionsRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetIdentityStateTransitionsRequestV0;
+    static deserializeBinaryFromReader(message: GetIdentityStateTransitionsRequestV0, reader: jspb.BinaryReader): GetIdentityStateTransitionsRequestV0;
   }
 
-  export namespace WaitForStateTransitionResultRequestV0 {
+  export namespace GetIdentityStateTransitionsRequestV0 {
+    export type AsObject = {
+      identityId: Uint8Array | string,
+      startAfter?: GetIdentityStateTransitionsRequest.GetIdentityStateTransitionsRequestV0.StartAfter.AsObject,
+      count: number,
+      orderAscending: boolean,
+      prove: boolean,
+    }
+
+    export class StartAfter extends jspb.Message {
+      getBlockHeight(): string;
+      setBlockHeight(value: string): void;
+
+      getIndexInBlock(): number;
+      setIndexInBlock(value: number): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): StartAfter.AsObject;
+      static toObject(includeInstance: boolean, msg: StartAfter): StartAfter.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: StartAfter, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): StartAfter;
+      static deserializeBinaryFromReader(message: StartAfter, reader: jspb.BinaryReader): StartAfter;
+    }
+
+    export namespace StartAfter {
+      export type AsObject = {
+        blockHeight: string,
+        indexInBlock: number,
+      }
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetIdentityStateTransitionsResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetIdentityStateTransitionsResponse.GetIdentityStateTransitionsResponseV0 | undefined;
+  setV0(value?: GetIdentityStateTransitionsResponse.GetIdentityStateTransitionsResponseV0): void;
+
+  getVersionCase(): GetIdentityStateTransitionsResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetIdentityStateTransitionsResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetIdentityStateTransitionsResponse): GetIdentityStateTransitionsResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetIdentityStateTransitionsResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetIdentityStateTransitionsResponse;
+  static deserializeBinaryFromReader(message: GetIdentityStateTransitionsResponse, reader: jspb.BinaryReader): GetIdentityStateTransitionsResponse;
+}
+
+export namespace GetIdentityStateTransitionsResponse {
+  export type AsObject = {
+    v0?: GetIdentityStateTransitionsResponse.GetIdentityStateTransitionsResponseV0.AsObject,
+  }
+
+  export class GetIdentityStateTransitionsResponseV0 extends jspb.Message {
+    hasStateTransitions(): boolean;
+    clearStateTransitions(): void;
+    getStateTransitions(): GetIdentityStateTransitionsResponse.GetIdentityStateTransitionsResponseV0.IdentityStateTransitions | undefined;
+    setStateTransitions(value?: GetIdentityStateTransitionsResponse.GetIdentityStateTransitionsResponseV0.IdentityStateTransitions): void;
+
+    hasProof(): boolean;
+    clearProof(): void;
+    getProof(): Proof | undefined;
+    setProof(value?: Proof): void;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    getResultCase(): GetIdentityStateTransitionsResponseV0.ResultCase;
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetIdentityStateTransitionsResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetIdentityStateTransitionsResponseV0): GetIdentityStateTransitionsResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetIdentityStateTransitionsResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetIdentityStateTransitionsResponseV0;
+    static deserializeBinaryFromReader(message: GetIdentityStateTransitionsResponseV0, reader: jspb.BinaryReader): GetIdentityStateTransitionsResponseV0;
+  }
+
+  export namespace GetIdentityStateTransitionsResponseV0 {
+    export type AsObject = {
+      stateTransitions?: GetIdentityStateTransitionsResponse.GetIdentityStateTransitionsResponseV0.IdentityStateTransitions.AsObject,
+      proof?: Proof.AsObject,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export class IdentityStateTransitionEntry extends jspb.Message {
+      getStateTransitionHash(): Uint8Array | string;
+      getStateTransitionHash_asU8(): Uint8Array;
+      getStateTransitionHash_asB64(): string;
+      setStateTransitionHash(value: Uint8Array | string): void;
+
+      getStateTransitionType(): number;
+      setStateTransitionType(value: number): void;
+
+      getBlockHeight(): string;
+      setBlockHeight(value: string): void;
+
+      getBlockTimeMs(): string;
+      setBlockTimeMs(value: string): void;
+
+      getIndexInBlock(): number;
+      setIndexInBlock(value: number): void;
+
+      getFeePaid(): string;
+      setFeePaid(value: string): void;
+
+      hasErrorCode(): boolean;
+      clearErrorCode(): void;
+      getErrorCode(): number;
+      setErrorCode(value: number): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): IdentityStateTransitionEntry.AsObject;
+      static toObject(includeInstance: boolean, msg: IdentityStateTransitionEntry): IdentityStateTransitionEntry.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: IdentityStateTransitionEntry, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): IdentityStateTransitionEntry;
+      static deserializeBinaryFromReader(message: IdentityStateTransitionEntry, reader: jspb.BinaryReader): IdentityStateTransitionEntry;
+    }
+
+    export namespace IdentityStateTransitionEntry {
+      export type AsObject = {
+        stateTransitionHash: Uint8Array | string,
+        stateTransitionType: number,
+        blockHeight: string,
+        blockTimeMs: string,
+        indexInBlock: number,
+        feePaid: string,
+        errorCode: number,
+      }
+    }
+
+    export class IdentityStateTransitions extends jspb.Message {
+      clearStateTransitionsList(): void;
+      getStateTransitionsList(): Array<GetIdentityStateTransitionsResponse.GetIdentityStateTransitionsResponseV0.IdentityStateTransitionEntry>;
+      setStateTransitionsList(value: Array<GetIdentityStateTransitionsResponse.GetIdentityStateTransitionsResponseV0.IdentityStateTransitionEntry>): void;
+      addStateTransitions(value?: GetIdentityStateTransitionsResponse.GetIdentityStateTransitionsResponseV0.IdentityStateTransitionEntry, index?: number): GetIdentityStateTransitionsResponse.GetIdentityStateTransitionsResponseV0.IdentityStateTransitionEntry;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): IdentityStateTransitions.AsObject;
+      static toObject(includeInstance: boolean, msg: IdentityStateTransitions): IdentityStateTransitions.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: IdentityStateTransitions, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): IdentityStateTransitions;
+      static deserializeBinaryFromReader(message: IdentityStateTransitions, reader: jspb.BinaryReader): IdentityStateTransitions;
+    }
+
+    export namespace IdentityStateTransitions {
+      export type AsObject = {
+        stateTransitionsList: Array<GetIdentityStateTransitionsResponse.GetIdentityStateTransitionsResponseV0.IdentityStateTransitionEntry.AsObject>,
+      }
+    }
+
+    export enum ResultCase {
+      RESULT_NOT_SET = 0,
+      STATE_TRANSITIONS = 1,
+      PROOF = 2,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class WaitForStateTransitionResultRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): WaitForStateTransitionResultRequest.WaitForStateTransitionResultRequestV0 | undefined;
+  setV0(value?: WaitForStateTransitionResultRequest.WaitForStateTransitionResultRequestV0): void;
+
+  getVersionCase(): WaitForStateTransitionResultRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): WaitForStateTransitionResultRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: WaitForStateTransitionResultRequest): WaitForStateTransitionResultRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: WaitForStateTransitionResultRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): WaitForStateTransitionResultRequest;
+  static deserializeBinaryFromReader(message: WaitForStateTransitionResultRequest, reader: jspb.BinaryReader): WaitForStateTransitionResultRequest;
+}
+
+export namespace WaitForStateTransitionResultRequest {
+  export type AsObject = {
+    v0?: WaitForStateTransitionResultRequest.WaitForStateTransitionResultRequestV0.AsObject,
+  }
+
+  export class WaitForStateTransitionResultRequestV0 extends jspb.Message {
+    getStateTransitionHash(): Uint8Array | string;
+    getStateTransitionHash_asU8(): Uint8Array;
+    getStateTransitionHash_asB64(): string;
+    setStateTransitionHash(value: Uint8Array | string): void;
+
+    getProve(): boolean;
+    setProve(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): WaitForStateTransitionResultRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: WaitForStateTransitionResultRequestV0): WaitForStateTransitionResultRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: WaitForStateTransitionResultRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): WaitForStateTransitionResultRequestV0;
+    static deserializeBinaryFromReader(message: WaitForStateTransitionResultRequestV0, reader: jspb.BinaryReader): WaitForStateTransitionResultRequestV0;
+  }
+
+  export namespace WaitForStateTransitionResultRequestV0 {
     export type AsObject = {
       stateTransitionHash: Uint8Array | string,
       prove: boolean,
@@ -2867,6 +3491,178 @@ export namespace WaitForStateTransitionResultResponse {
   }
 }
 
+export class SimulateStateTransitionRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): SimulateStateTransitionRequest.SimulateStateTransitionRequestV0 | undefined;
+  setV0(value?: SimulateStateTransitionRequest.SimulateStateTransitionRequestV0): void;
+
+  getVersionCase(): SimulateStateTransitionRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): SimulateStateTransitionRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: SimulateStateTransitionRequest): SimulateStateTransitionRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: SimulateStateTransitionRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): SimulateStateTransitionRequest;
+  static deserializeBinaryFromReader(message: SimulateStateTransitionRequest, reader: jspb.BinaryReader): SimulateStateTransitionRequest;
+}
+
+export namespace SimulateStateTransitionRequest {
+  export type AsObject = {
+    v0?: SimulateStateTransitionRequest.SimulateStateTransitionRequestV0.AsObject,
+  }
+
+  export class SimulateStateTransitionRequestV0 extends jspb.Message {
+    getStateTransition(): Uint8Array | string;
+    getStateTransition_asU8(): Uint8Array;
+    getStateTransition_asB64(): string;
+    setStateTransition(value: Uint8Array | string): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): SimulateStateTransitionRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: SimulateStateTransitionRequestV0): SimulateStateTransitionRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: SimulateStateTransitionRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): SimulateStateTransitionRequestV0;
+    static deserializeBinaryFromReader(message: SimulateStateTransitionRequestV0, reader: jspb.BinaryReader): SimulateStateTransitionRequestV0;
+  }
+
+  export namespace SimulateStateTransitionRequestV0 {
+    export type AsObject = {
+      stateTransition: Uint8Array | string,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class SimulateStateTransitionResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): SimulateStateTransitionResponse.SimulateStateTransitionResponseV0 | undefined;
+  setV0(value?: SimulateStateTransitionResponse.SimulateStateTransitionResponseV0): void;
+
+  getVersionCase(): SimulateStateTransitionResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): SimulateStateTransitionResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: SimulateStateTransitionResponse): SimulateStateTransitionResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: SimulateStateTransitionResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): SimulateStateTransitionResponse;
+  static deserializeBinaryFromReader(message: SimulateStateTransitionResponse, reader: jspb.BinaryReader): SimulateStateTransitionResponse;
+}
+
+export namespace SimulateStateTransitionResponse {
+  export type AsObject = {
+    v0?: SimulateStateTransitionResponse.SimulateStateTransitionResponseV0.AsObject,
+  }
+
+  export class SimulateStateTransitionResponseV0 extends jspb.Message {
+    hasFeeResult(): boolean;
+    clearFeeResult(): void;
+    getFeeResult(): SimulateStateTransitionResponse.SimulateStateTransitionResponseV0.FeeResult | undefined;
+    setFeeResult(value?: SimulateStateTransitionResponse.SimulateStateTransitionResponseV0.FeeResult): void;
+
+    hasError(): boolean;
+    clearError(): void;
+    getError(): StateTransitionBroadcastError | undefined;
+    setError(value?: StateTransitionBroadcastError): void;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): SimulateStateTransitionResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: SimulateStateTransitionResponseV0): SimulateStateTransitionResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: SimulateStateTransitionResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): SimulateStateTransitionResponseV0;
+    static deserializeBinaryFromReader(message: SimulateStateTransitionResponseV0, reader: jspb.BinaryReader): SimulateStateTransitionResponseV0;
+  }
+
+  export namespace SimulateStateTransitionResponseV0 {
+    export type AsObject = {
+      feeResult?: SimulateStateTransitionResponse.SimulateStateTransitionResponseV0.FeeResult.AsObject,
+      error?: StateTransitionBroadcastError.AsObject,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export class FeeRefund extends jspb.Message {
+      getIdentityId(): Uint8Array | string;
+      getIdentityId_asU8(): Uint8Array;
+      getIdentityId_asB64(): string;
+      setIdentityId(value: Uint8Array | string): void;
+
+      getCredits(): string;
+      setCredits(value: string): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): FeeRefund.AsObject;
+      static toObject(includeInstance: boolean, msg: FeeRefund): FeeRefund.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: FeeRefund, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): FeeRefund;
+      static deserializeBinaryFromReader(message: FeeRefund, reader: jspb.BinaryReader): FeeRefund;
+    }
+
+    export namespace FeeRefund {
+      export type AsObject = {
+        identityId: Uint8Array | string,
+        credits: string,
+      }
+    }
+
+    export class FeeResult extends jspb.Message {
+      getStorageFee(): string;
+      setStorageFee(value: string): void;
+
+      getProcessingFee(): string;
+      setProcessingFee(value: string): void;
+
+      clearFeeRefundsList(): void;
+      getFeeRefundsList(): Array<SimulateStateTransitionResponse.SimulateStateTransitionResponseV0.FeeRefund>;
+      setFeeRefundsList(value: Array<SimulateStateTransitionResponse.SimulateStateTransitionResponseV0.FeeRefund>): void;
+      addFeeRefunds(value?: SimulateStateTransitionResponse.SimulateStateTransitionResponseV0.FeeRefund, index?: number): SimulateStateTransitionResponse.SimulateStateTransitionResponseV0.FeeRefund;
+
+      getRemovedBytesFromSystem(): number;
+      setRemovedBytesFromSystem(value: number): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): FeeResult.AsObject;
+      static toObject(includeInstance: boolean, msg: FeeResult): FeeResult.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: FeeResult, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): FeeResult;
+      static deserializeBinaryFromReader(message: FeeResult, reader: jspb.BinaryReader): FeeResult;
+    }
+
+    export namespace FeeResult {
+      export type AsObject = {
+        storageFee: string,
+        processingFee: string,
+        feeRefundsList: Array<SimulateStateTransitionResponse.SimulateStateTransitionResponseV0.FeeRefund.AsObject>,
+        removedBytesFromSystem: number,
+      }
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
 export class GetConsensusParamsRequest extends jspb.Message {
   hasV0(): boolean;
   clearV0(): void;
@@ -3813,36 +4609,256 @@ export namespace GetFinalizedEpochInfosResponse {
   }
 }
 
-export class GetContestedResourcesRequest extends jspb.Message {
+export class GetBlockStateTransitionsRequest extends jspb.Message {
   hasV0(): boolean;
   clearV0(): void;
-  getV0(): GetContestedResourcesRequest.GetContestedResourcesRequestV0 | undefined;
-  setV0(value?: GetContestedResourcesRequest.GetContestedResourcesRequestV0): void;
+  getV0(): GetBlockStateTransitionsRequest.GetBlockStateTransitionsRequestV0 | undefined;
+  setV0(value?: GetBlockStateTransitionsRequest.GetBlockStateTransitionsRequestV0): void;
 
-  getVersionCase(): GetContestedResourcesRequest.VersionCase;
+  getVersionCase(): GetBlockStateTransitionsRequest.VersionCase;
   serializeBinary(): Uint8Array;
-  toObject(includeInstance?: boolean): GetContestedResourcesRequest.AsObject;
-  static toObject(includeInstance: boolean, msg: GetContestedResourcesRequest): GetContestedResourcesRequest.AsObject;
+  toObject(includeInstance?: boolean): GetBlockStateTransitionsRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetBlockStateTransitionsRequest): GetBlockStateTransitionsRequest.AsObject;
   static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
   static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-  static serializeBinaryToWriter(message: GetContestedResourcesRequest, writer: jspb.BinaryWriter): void;
-  static deserializeBinary(bytes: Uint8Array): GetContestedResourcesRequest;
-  static deserializeBinaryFromReader(message: GetContestedResourcesRequest, reader: jspb.BinaryReader): GetContestedResourcesRequest;
+  static serializeBinaryToWriter(message: GetBlockStateTransitionsRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetBlockStateTransitionsRequest;
+  static deserializeBinaryFromReader(message: GetBlockStateTransitionsRequest, reader: jspb.BinaryReader): GetBlockStateTransitionsRequest;
 }
 
-export namespace GetContestedResourcesRequest {
+export namespace GetBlockStateTransitionsRequest {
   export type AsObject = {
-    v0?: GetContestedResourcesRequest.GetContestedResourcesRequestV0.AsObject,
+    v0?: GetBlockStateTransitionsRequest.GetBlockStateTransitionsRequestV0.AsObject,
   }
 
-  export class GetContestedResourcesRequestV0 extends jspb.Message {
-    getContractId(): Uint8Array | string;
-    getContractId_asU8(): Uint8Array;
-    getContractId_asB64(): string;
-    setContractId(value: Uint8Array | string): void;
+  export class GetBlockStateTransitionsRequestV0 extends jspb.Message {
+    getHeight(): string;
+    setHeight(value: string): void;
 
-    getDocumentTypeName(): string;
-    setDocumentTypeName(value: string): void;
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetBlockStateTransitionsRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetBlockStateTransitionsRequestV0): GetBlockStateTransitionsRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetBlockStateTransitionsRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetBlockStateTransitionsRequestV0;
+    static deserializeBinaryFromReader(message: GetBlockStateTransitionsRequestV0, reader: jspb.BinaryReader): GetBlockStateTransitionsRequestV0;
+  }
+
+  export namespace GetBlockStateTransitionsRequestV0 {
+    export type AsObject = {
+      height: string,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetBlockStateTransitionsResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0 | undefined;
+  setV0(value?: GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0): void;
+
+  getVersionCase(): GetBlockStateTransitionsResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetBlockStateTransitionsResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetBlockStateTransitionsResponse): GetBlockStateTransitionsResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetBlockStateTransitionsResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetBlockStateTransitionsResponse;
+  static deserializeBinaryFromReader(message: GetBlockStateTransitionsResponse, reader: jspb.BinaryReader): GetBlockStateTransitionsResponse;
+}
+
+export namespace GetBlockStateTransitionsResponse {
+  export type AsObject = {
+    v0?: GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.AsObject,
+  }
+
+  export class GetBlockStateTransitionsResponseV0 extends jspb.Message {
+    getBlockHeight(): string;
+    setBlockHeight(value: string): void;
+
+    getBlockTimeMs(): string;
+    setBlockTimeMs(value: string): void;
+
+    clearStateTransitionsList(): void;
+    getStateTransitionsList(): Array<GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.ExecutedStateTransition>;
+    setStateTransitionsList(value: Array<GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.ExecutedStateTransition>): void;
+    addStateTransitions(value?: GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.ExecutedStateTransition, index?: number): GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.ExecutedStateTransition;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetBlockStateTransitionsResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetBlockStateTransitionsResponseV0): GetBlockStateTransitionsResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetBlockStateTransitionsResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetBlockStateTransitionsResponseV0;
+    static deserializeBinaryFromReader(message: GetBlockStateTransitionsResponseV0, reader: jspb.BinaryReader): GetBlockStateTransitionsResponseV0;
+  }
+
+  export namespace GetBlockStateTransitionsResponseV0 {
+    export type AsObject = {
+      blockHeight: string,
+      blockTimeMs: string,
+      stateTransitionsList: Array<GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.ExecutedStateTransition.AsObject>,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export class FeeRefund extends jspb.Message {
+      getIdentityId(): Uint8Array | string;
+      getIdentityId_asU8(): Uint8Array;
+      getIdentityId_asB64(): string;
+      setIdentityId(value: Uint8Array | string): void;
+
+      getCredits(): string;
+      setCredits(value: string): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): FeeRefund.AsObject;
+      static toObject(includeInstance: boolean, msg: FeeRefund): FeeRefund.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: FeeRefund, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): FeeRefund;
+      static deserializeBinaryFromReader(message: FeeRefund, reader: jspb.BinaryReader): FeeRefund;
+    }
+
+    export namespace FeeRefund {
+      export type AsObject = {
+        identityId: Uint8Array | string,
+        credits: string,
+      }
+    }
+
+    export class FeeResult extends jspb.Message {
+      getStorageFee(): string;
+      setStorageFee(value: string): void;
+
+      getProcessingFee(): string;
+      setProcessingFee(value: string): void;
+
+      clearFeeRefundsList(): void;
+      getFeeRefundsList(): Array<GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.FeeRefund>;
+      setFeeRefundsList(value: Array<GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.FeeRefund>): void;
+      addFeeRefunds(value?: GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.FeeRefund, index?: number): GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.FeeRefund;
+
+      getRemovedBytesFromSystem(): number;
+      setRemovedBytesFromSystem(value: number): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): FeeResult.AsObject;
+      static toObject(includeInstance: boolean, msg: FeeResult): FeeResult.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: FeeResult, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): FeeResult;
+      static deserializeBinaryFromReader(message: FeeResult, reader: jspb.BinaryReader): FeeResult;
+    }
+
+    export namespace FeeResult {
+      export type AsObject = {
+        storageFee: string,
+        processingFee: string,
+        feeRefundsList: Array<GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.FeeRefund.AsObject>,
+        removedBytesFromSystem: number,
+      }
+    }
+
+    export class ExecutedStateTransition extends jspb.Message {
+      getStateTransition(): Uint8Array | string;
+      getStateTransition_asU8(): Uint8Array;
+      getStateTransition_asB64(): string;
+      setStateTransition(value: Uint8Array | string): void;
+
+      getStatus(): GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.ExecutionStatusMap[keyof GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.ExecutionStatusMap];
+      setStatus(value: GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.ExecutionStatusMap[keyof GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.ExecutionStatusMap]): void;
+
+      hasFeeResult(): boolean;
+      clearFeeResult(): void;
+      getFeeResult(): GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.FeeResult | undefined;
+      setFeeResult(value?: GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.FeeResult): void;
+
+      hasError(): boolean;
+      clearError(): void;
+      getError(): StateTransitionBroadcastError | undefined;
+      setError(value?: StateTransitionBroadcastError): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): ExecutedStateTransition.AsObject;
+      static toObject(includeInstance: boolean, msg: ExecutedStateTransition): ExecutedStateTransition.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: ExecutedStateTransition, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): ExecutedStateTransition;
+      static deserializeBinaryFromReader(message: ExecutedStateTransition, reader: jspb.BinaryReader): ExecutedStateTransition;
+    }
+
+    export namespace ExecutedStateTransition {
+      export type AsObject = {
+        stateTransition: Uint8Array | string,
+        status: GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.ExecutionStatusMap[keyof GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.ExecutionStatusMap],
+        feeResult?: GetBlockStateTransitionsResponse.GetBlockStateTransitionsResponseV0.FeeResult.AsObject,
+        error?: StateTransitionBroadcastError.AsObject,
+      }
+    }
+
+    export interface ExecutionStatusMap {
+      SUCCESS: 0;
+      PAID_CONSENSUS_ERROR: 1;
+      UNPAID_CONSENSUS_ERROR: 2;
+      INTERNAL_ERROR: 3;
+      NOT_EXECUTED: 4;
+    }
+
+    export const ExecutionStatus: ExecutionStatusMap;
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetContestedResourcesRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetContestedResourcesRequest.GetContestedResourcesRequestV0 | undefined;
+  setV0(value?: GetContestedResourcesRequest.GetContestedResourcesRequestV0): void;
+
+  getVersionCase(): GetContestedResourcesRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetContestedResourcesRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetContestedResourcesRequest): GetContestedResourcesRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetContestedResourcesRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetContestedResourcesRequest;
+  static deserializeBinaryFromReader(message: GetContestedResourcesRequest, reader: jspb.BinaryReader): GetContestedResourcesRequest;
+}
+
+export namespace GetContestedResourcesRequest {
+  export type AsObject = {
+    v0?: GetContestedResourcesRequest.GetContestedResourcesRequestV0.AsObject,
+  }
+
+  export class GetContestedResourcesRequestV0 extends jspb.Message {
+    getContractId(): Uint8Array | string;
+    getContractId_asU8(): Uint8Array;
+    getContractId_asB64(): string;
+    setContractId(value: Uint8Array | string): void;
+
+    getDocumentTypeName(): string;
+    setDocumentTypeName(value: string): void;
 
     getIndexName(): string;
     setIndexName(value: string): void;
@@ -4273,6 +5289,239 @@ export namespace GetVotePollsByEndDateResponse {
   }
 }
 
+export class GetYesNoAbstainVotePollStateRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetYesNoAbstainVotePollStateRequest.GetYesNoAbstainVotePollStateRequestV0 | undefined;
+  setV0(value?: GetYesNoAbstainVotePollStateRequest.GetYesNoAbstainVotePollStateRequestV0): void;
+
+  getVersionCase(): GetYesNoAbstainVotePollStateRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetYesNoAbstainVotePollStateRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetYesNoAbstainVotePollStateRequest): GetYesNoAbstainVotePollStateRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetYesNoAbstainVotePollStateRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetYesNoAbstainVotePollStateRequest;
+  static deserializeBinaryFromReader(message: GetYesNoAbstainVotePollStateRequest, reader: jspb.BinaryReader): GetYesNoAbstainVotePollStateRequest;
+}
+
+export namespace GetYesNoAbstainVotePollStateRequest {
+  export type AsObject = {
+    v0?: GetYesNoAbstainVotePollStateRequest.GetYesNoAbstainVotePollStateRequestV0.AsObject,
+  }
+
+  export class GetYesNoAbstainVotePollStateRequestV0 extends jspb.Message {
+    getVotePollId(): Uint8Array | string;
+    getVotePollId_asU8(): Uint8Array;
+    getVotePollId_asB64(): string;
+    setVotePollId(value: Uint8Array | string): void;
+
+    getProve(): boolean;
+    setProve(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetYesNoAbstainVotePollStateRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetYesNoAbstainVotePollStateRequestV0): GetYesNoAbstainVotePollStateRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetYesNoAbstainVotePollStateRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetYesNoAbstainVotePollStateRequestV0;
+    static deserializeBinaryFromReader(message: GetYesNoAbstainVotePollStateRequestV0, reader: jspb.BinaryReader): GetYesNoAbstainVotePollStateRequestV0;
+  }
+
+  export namespace GetYesNoAbstainVotePollStateRequestV0 {
+    export type AsObject = {
+      votePollId: Uint8Array | string,
+      prove: boolean,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetYesNoAbstainVotePollStateResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0 | undefined;
+  setV0(value?: GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0): void;
+
+  getVersionCase(): GetYesNoAbstainVotePollStateResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetYesNoAbstainVotePollStateResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetYesNoAbstainVotePollStateResponse): GetYesNoAbstainVotePollStateResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetYesNoAbstainVotePollStateResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetYesNoAbstainVotePollStateResponse;
+  static deserializeBinaryFromReader(message: GetYesNoAbstainVotePollStateResponse, reader: jspb.BinaryReader): GetYesNoAbstainVotePollStateResponse;
+}
+
+export namespace GetYesNoAbstainVotePollStateResponse {
+  export type AsObject = {
+    v0?: GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.AsObject,
+  }
+
+  export class GetYesNoAbstainVotePollStateResponseV0 extends jspb.Message {
+    hasVotePollState(): boolean;
+    clearVotePollState(): void;
+    getVotePollState(): GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVotePollState | undefined;
+    setVotePollState(value?: GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVotePollState): void;
+
+    hasProof(): boolean;
+    clearProof(): void;
+    getProof(): Proof | undefined;
+    setProof(value?: Proof): void;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    getResultCase(): GetYesNoAbstainVotePollStateResponseV0.ResultCase;
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetYesNoAbstainVotePollStateResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetYesNoAbstainVotePollStateResponseV0): GetYesNoAbstainVotePollStateResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetYesNoAbstainVotePollStateResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetYesNoAbstainVotePollStateResponseV0;
+    static deserializeBinaryFromReader(message: GetYesNoAbstainVotePollStateResponseV0, reader: jspb.BinaryReader): GetYesNoAbstainVotePollStateResponseV0;
+  }
+
+  export namespace GetYesNoAbstainVotePollStateResponseV0 {
+    export type AsObject = {
+      votePollState?: GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVotePollState.AsObject,
+      proof?: Proof.AsObject,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export class YesNoAbstainVote extends jspb.Message {
+      getProTxHash(): Uint8Array | string;
+      getProTxHash_asU8(): Uint8Array;
+      getProTxHash_asB64(): string;
+      setProTxHash(value: Uint8Array | string): void;
+
+      getVoteChoice(): GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVote.VoteChoiceTypeMap[keyof GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVote.VoteChoiceTypeMap];
+      setVoteChoice(value: GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVote.VoteChoiceTypeMap[keyof GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVote.VoteChoiceTypeMap]): void;
+
+      getStrength(): number;
+      setStrength(value: number): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): YesNoAbstainVote.AsObject;
+      static toObject(includeInstance: boolean, msg: YesNoAbstainVote): YesNoAbstainVote.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: YesNoAbstainVote, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): YesNoAbstainVote;
+      static deserializeBinaryFromReader(message: YesNoAbstainVote, reader: jspb.BinaryReader): YesNoAbstainVote;
+    }
+
+    export namespace YesNoAbstainVote {
+      export type AsObject = {
+        proTxHash: Uint8Array | string,
+        voteChoice: GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVote.VoteChoiceTypeMap[keyof GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVote.VoteChoiceTypeMap],
+        strength: number,
+      }
+
+      export interface VoteChoiceTypeMap {
+        YES: 0;
+        NO: 1;
+        ABSTAIN: 2;
+      }
+
+      export const VoteChoiceType: VoteChoiceTypeMap;
+    }
+
+    export class FinalTally extends jspb.Message {
+      getYes(): number;
+      setYes(value: number): void;
+
+      getNo(): number;
+      setNo(value: number): void;
+
+      getAbstain(): number;
+      setAbstain(value: number): void;
+
+      getFinishedAtBlockHeight(): string;
+      setFinishedAtBlockHeight(value: string): void;
+
+      getFinishedAtTimeMs(): string;
+      setFinishedAtTimeMs(value: string): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): FinalTally.AsObject;
+      static toObject(includeInstance: boolean, msg: FinalTally): FinalTally.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: FinalTally, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): FinalTally;
+      static deserializeBinaryFromReader(message: FinalTally, reader: jspb.BinaryReader): FinalTally;
+    }
+
+    export namespace FinalTally {
+      export type AsObject = {
+        yes: number,
+        no: number,
+        abstain: number,
+        finishedAtBlockHeight: string,
+        finishedAtTimeMs: string,
+      }
+    }
+
+    export class YesNoAbstainVotePollState extends jspb.Message {
+      hasSerializedVotePoll(): boolean;
+      clearSerializedVotePoll(): void;
+      getSerializedVotePoll(): Uint8Array | string;
+      getSerializedVotePoll_asU8(): Uint8Array;
+      getSerializedVotePoll_asB64(): string;
+      setSerializedVotePoll(value: Uint8Array | string): void;
+
+      clearVotesList(): void;
+      getVotesList(): Array<GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVote>;
+      setVotesList(value: Array<GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVote>): void;
+      addVotes(value?: GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVote, index?: number): GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVote;
+
+      hasFinalTally(): boolean;
+      clearFinalTally(): void;
+      getFinalTally(): GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.FinalTally | undefined;
+      setFinalTally(value?: GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.FinalTally): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): YesNoAbstainVotePollState.AsObject;
+      static toObject(includeInstance: boolean, msg: YesNoAbstainVotePollState): YesNoAbstainVotePollState.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: YesNoAbstainVotePollState, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): YesNoAbstainVotePollState;
+      static deserializeBinaryFromReader(message: YesNoAbstainVotePollState, reader: jspb.BinaryReader): YesNoAbstainVotePollState;
+    }
+
+    export namespace YesNoAbstainVotePollState {
+      export type AsObject = {
+        serializedVotePoll: Uint8Array | string,
+        votesList: Array<GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.YesNoAbstainVote.AsObject>,
+        finalTally?: GetYesNoAbstainVotePollStateResponse.GetYesNoAbstainVotePollStateResponseV0.FinalTally.AsObject,
+      }
+    }
+
+    export enum ResultCase {
+      RESULT_NOT_SET = 0,
+      VOTE_POLL_STATE = 1,
+      PROOF = 2,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
 export class GetContestedResourceVoteStateRequest extends jspb.Message {
   hasV0(): boolean;
   clearV0(): void;
@@ -7770,22 +9019,1022 @@ export namespace GetTokenPerpetualDistributionLastClaimRequest {
     setProve(value: boolean): void;
 
     serializeBinary(): Uint8Array;
-    toObject(includeInstance?: boolean): GetTokenPerpetualDistributionLastClaimRequestV0.AsObject;
-    static toObject(includeInstance: boolean, msg: GetTokenPerpetualDistributionLastClaimRequestV0): GetTokenPerpetualDistributionLastClaimRequestV0.AsObject;
+    toObject(includeInstance?: boolean): GetTokenPerpetualDistributionLastClaimRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenPerpetualDistributionLastClaimRequestV0): GetTokenPerpetualDistributionLastClaimRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetTokenPerpetualDistributionLastClaimRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenPerpetualDistributionLastClaimRequestV0;
+    static deserializeBinaryFromReader(message: GetTokenPerpetualDistributionLastClaimRequestV0, reader: jspb.BinaryReader): GetTokenPerpetualDistributionLastClaimRequestV0;
+  }
+
+  export namespace GetTokenPerpetualDistributionLastClaimRequestV0 {
+    export type AsObject = {
+      tokenId: Uint8Array | string,
+      contractInfo?: GetTokenPerpetualDistributionLastClaimRequest.ContractTokenInfo.AsObject,
+      identityId: Uint8Array | string,
+      prove: boolean,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetTokenPerpetualDistributionLastClaimResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0 | undefined;
+  setV0(value?: GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0): void;
+
+  getVersionCase(): GetTokenPerpetualDistributionLastClaimResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetTokenPerpetualDistributionLastClaimResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenPerpetualDistributionLastClaimResponse): GetTokenPerpetualDistributionLastClaimResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetTokenPerpetualDistributionLastClaimResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenPerpetualDistributionLastClaimResponse;
+  static deserializeBinaryFromReader(message: GetTokenPerpetualDistributionLastClaimResponse, reader: jspb.BinaryReader): GetTokenPerpetualDistributionLastClaimResponse;
+}
+
+export namespace GetTokenPerpetualDistributionLastClaimResponse {
+  export type AsObject = {
+    v0?: GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0.AsObject,
+  }
+
+  export class GetTokenPerpetualDistributionLastClaimResponseV0 extends jspb.Message {
+    hasLastClaim(): boolean;
+    clearLastClaim(): void;
+    getLastClaim(): GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0.LastClaimInfo | undefined;
+    setLastClaim(value?: GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0.LastClaimInfo): void;
+
+    hasProof(): boolean;
+    clearProof(): void;
+    getProof(): Proof | undefined;
+    setProof(value?: Proof): void;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    getResultCase(): GetTokenPerpetualDistributionLastClaimResponseV0.ResultCase;
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetTokenPerpetualDistributionLastClaimResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenPerpetualDistributionLastClaimResponseV0): GetTokenPerpetualDistributionLastClaimResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetTokenPerpetualDistributionLastClaimResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenPerpetualDistributionLastClaimResponseV0;
+    static deserializeBinaryFromReader(message: GetTokenPerpetualDistributionLastClaimResponseV0, reader: jspb.BinaryReader): GetTokenPerpetualDistributionLastClaimResponseV0;
+  }
+
+  export namespace GetTokenPerpetualDistributionLastClaimResponseV0 {
+    export type AsObject = {
+      lastClaim?: GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0.LastClaimInfo.AsObject,
+      proof?: Proof.AsObject,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export class LastClaimInfo extends jspb.Message {
+      hasTimestampMs(): boolean;
+      clearTimestampMs(): void;
+      getTimestampMs(): string;
+      setTimestampMs(value: string): void;
+
+      hasBlockHeight(): boolean;
+      clearBlockHeight(): void;
+      getBlockHeight(): string;
+      setBlockHeight(value: string): void;
+
+      hasEpoch(): boolean;
+      clearEpoch(): void;
+      getEpoch(): number;
+      setEpoch(value: number): void;
+
+      hasRawBytes(): boolean;
+      clearRawBytes(): void;
+      getRawBytes(): Uint8Array | string;
+      getRawBytes_asU8(): Uint8Array;
+      getRawBytes_asB64(): string;
+      setRawBytes(value: Uint8Array | string): void;
+
+      getPaidAtCase(): LastClaimInfo.PaidAtCase;
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): LastClaimInfo.AsObject;
+      static toObject(includeInstance: boolean, msg: LastClaimInfo): LastClaimInfo.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: LastClaimInfo, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): LastClaimInfo;
+      static deserializeBinaryFromReader(message: LastClaimInfo, reader: jspb.BinaryReader): LastClaimInfo;
+    }
+
+    export namespace LastClaimInfo {
+      export type AsObject = {
+        timestampMs: string,
+        blockHeight: string,
+        epoch: number,
+        rawBytes: Uint8Array | string,
+      }
+
+      export enum PaidAtCase {
+        PAID_AT_NOT_SET = 0,
+        TIMESTAMP_MS = 1,
+        BLOCK_HEIGHT = 2,
+        EPOCH = 3,
+        RAW_BYTES = 4,
+      }
+    }
+
+    export enum ResultCase {
+      RESULT_NOT_SET = 0,
+      LAST_CLAIM = 1,
+      PROOF = 2,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetTokenTotalSupplyRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetTokenTotalSupplyRequest.GetTokenTotalSupplyRequestV0 | undefined;
+  setV0(value?: GetTokenTotalSupplyRequest.GetTokenTotalSupplyRequestV0): void;
+
+  getVersionCase(): GetTokenTotalSupplyRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetTokenTotalSupplyRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenTotalSupplyRequest): GetTokenTotalSupplyRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetTokenTotalSupplyRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenTotalSupplyRequest;
+  static deserializeBinaryFromReader(message: GetTokenTotalSupplyRequest, reader: jspb.BinaryReader): GetTokenTotalSupplyRequest;
+}
+
+export namespace GetTokenTotalSupplyRequest {
+  export type AsObject = {
+    v0?: GetTokenTotalSupplyRequest.GetTokenTotalSupplyRequestV0.AsObject,
+  }
+
+  export class GetTokenTotalSupplyRequestV0 extends jspb.Message {
+    getTokenId(): Uint8Array | string;
+    getTokenId_asU8(): Uint8Array;
+    getTokenId_asB64(): string;
+    setTokenId(value: Uint8Array | string): void;
+
+    getProve(): boolean;
+    setProve(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetTokenTotalSupplyRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenTotalSupplyRequestV0): GetTokenTotalSupplyRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetTokenTotalSupplyRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenTotalSupplyRequestV0;
+    static deserializeBinaryFromReader(message: GetTokenTotalSupplyRequestV0, reader: jspb.BinaryReader): GetTokenTotalSupplyRequestV0;
+  }
+
+  export namespace GetTokenTotalSupplyRequestV0 {
+    export type AsObject = {
+      tokenId: Uint8Array | string,
+      prove: boolean,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetTokenTotalSupplyResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0 | undefined;
+  setV0(value?: GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0): void;
+
+  getVersionCase(): GetTokenTotalSupplyResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetTokenTotalSupplyResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenTotalSupplyResponse): GetTokenTotalSupplyResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetTokenTotalSupplyResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenTotalSupplyResponse;
+  static deserializeBinaryFromReader(message: GetTokenTotalSupplyResponse, reader: jspb.BinaryReader): GetTokenTotalSupplyResponse;
+}
+
+export namespace GetTokenTotalSupplyResponse {
+  export type AsObject = {
+    v0?: GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0.AsObject,
+  }
+
+  export class GetTokenTotalSupplyResponseV0 extends jspb.Message {
+    hasTokenTotalSupply(): boolean;
+    clearTokenTotalSupply(): void;
+    getTokenTotalSupply(): GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0.TokenTotalSupplyEntry | undefined;
+    setTokenTotalSupply(value?: GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0.TokenTotalSupplyEntry): void;
+
+    hasProof(): boolean;
+    clearProof(): void;
+    getProof(): Proof | undefined;
+    setProof(value?: Proof): void;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    getResultCase(): GetTokenTotalSupplyResponseV0.ResultCase;
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetTokenTotalSupplyResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenTotalSupplyResponseV0): GetTokenTotalSupplyResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetTokenTotalSupplyResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenTotalSupplyResponseV0;
+    static deserializeBinaryFromReader(message: GetTokenTotalSupplyResponseV0, reader: jspb.BinaryReader): GetTokenTotalSupplyResponseV0;
+  }
+
+  export namespace GetTokenTotalSupplyResponseV0 {
+    export type AsObject = {
+      tokenTotalSupply?: GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0.TokenTotalSupplyEntry.AsObject,
+      proof?: Proof.AsObject,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export class TokenTotalSupplyEntry extends jspb.Message {
+      getTokenId(): Uint8Array | string;
+      getTokenId_asU8(): Uint8Array;
+      getTokenId_asB64(): string;
+      setTokenId(value: Uint8Array | string): void;
+
+      getTotalAggregatedAmountInUserAccounts(): number;
+      setTotalAggregatedAmountInUserAccounts(value: number): void;
+
+      getTotalSystemAmount(): number;
+      setTotalSystemAmount(value: number): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): TokenTotalSupplyEntry.AsObject;
+      static toObject(includeInstance: boolean, msg: TokenTotalSupplyEntry): TokenTotalSupplyEntry.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: TokenTotalSupplyEntry, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): TokenTotalSupplyEntry;
+      static deserializeBinaryFromReader(message: TokenTotalSupplyEntry, reader: jspb.BinaryReader): TokenTotalSupplyEntry;
+    }
+
+    export namespace TokenTotalSupplyEntry {
+      export type AsObject = {
+        tokenId: Uint8Array | string,
+        totalAggregatedAmountInUserAccounts: number,
+        totalSystemAmount: number,
+      }
+    }
+
+    export enum ResultCase {
+      RESULT_NOT_SET = 0,
+      TOKEN_TOTAL_SUPPLY = 1,
+      PROOF = 2,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetTokenHoldersRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetTokenHoldersRequest.GetTokenHoldersRequestV0 | undefined;
+  setV0(value?: GetTokenHoldersRequest.GetTokenHoldersRequestV0): void;
+
+  getVersionCase(): GetTokenHoldersRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetTokenHoldersRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenHoldersRequest): GetTokenHoldersRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetTokenHoldersRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenHoldersRequest;
+  static deserializeBinaryFromReader(message: GetTokenHoldersRequest, reader: jspb.BinaryReader): GetTokenHoldersRequest;
+}
+
+export namespace GetTokenHoldersRequest {
+  export type AsObject = {
+    v0?: GetTokenHoldersRequest.GetTokenHoldersRequestV0.AsObject,
+  }
+
+  export class StartAtIdentityId extends jspb.Message {
+    getStartIdentityId(): Uint8Array | string;
+    getStartIdentityId_asU8(): Uint8Array;
+    getStartIdentityId_asB64(): string;
+    setStartIdentityId(value: Uint8Array | string): void;
+
+    getStartIdentityIdIncluded(): boolean;
+    setStartIdentityIdIncluded(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): StartAtIdentityId.AsObject;
+    static toObject(includeInstance: boolean, msg: StartAtIdentityId): StartAtIdentityId.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: StartAtIdentityId, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): StartAtIdentityId;
+    static deserializeBinaryFromReader(message: StartAtIdentityId, reader: jspb.BinaryReader): StartAtIdentityId;
+  }
+
+  export namespace StartAtIdentityId {
+    export type AsObject = {
+      startIdentityId: Uint8Array | string,
+      startIdentityIdIncluded: boolean,
+    }
+  }
+
+  export class GetTokenHoldersRequestV0 extends jspb.Message {
+    getTokenId(): Uint8Array | string;
+    getTokenId_asU8(): Uint8Array;
+    getTokenId_asB64(): string;
+    setTokenId(value: Uint8Array | string): void;
+
+    hasStartAtIdentityId(): boolean;
+    clearStartAtIdentityId(): void;
+    getStartAtIdentityId(): GetTokenHoldersRequest.StartAtIdentityId | undefined;
+    setStartAtIdentityId(value?: GetTokenHoldersRequest.StartAtIdentityId): void;
+
+    hasCount(): boolean;
+    clearCount(): void;
+    getCount(): number;
+    setCount(value: number): void;
+
+    getProve(): boolean;
+    setProve(value: boolean): void;
+
+    getOrderByBalance(): boolean;
+    setOrderByBalance(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetTokenHoldersRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenHoldersRequestV0): GetTokenHoldersRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetTokenHoldersRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenHoldersRequestV0;
+    static deserializeBinaryFromReader(message: GetTokenHoldersRequestV0, reader: jspb.BinaryReader): GetTokenHoldersRequestV0;
+  }
+
+  export namespace GetTokenHoldersRequestV0 {
+    export type AsObject = {
+      tokenId: Uint8Array | string,
+      startAtIdentityId?: GetTokenHoldersRequest.StartAtIdentityId.AsObject,
+      count: number,
+      prove: boolean,
+      orderByBalance: boolean,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetTokenHoldersResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetTokenHoldersResponse.GetTokenHoldersResponseV0 | undefined;
+  setV0(value?: GetTokenHoldersResponse.GetTokenHoldersResponseV0): void;
+
+  getVersionCase(): GetTokenHoldersResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetTokenHoldersResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenHoldersResponse): GetTokenHoldersResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetTokenHoldersResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenHoldersResponse;
+  static deserializeBinaryFromReader(message: GetTokenHoldersResponse, reader: jspb.BinaryReader): GetTokenHoldersResponse;
+}
+
+export namespace GetTokenHoldersResponse {
+  export type AsObject = {
+    v0?: GetTokenHoldersResponse.GetTokenHoldersResponseV0.AsObject,
+  }
+
+  export class GetTokenHoldersResponseV0 extends jspb.Message {
+    hasTokenHolders(): boolean;
+    clearTokenHolders(): void;
+    getTokenHolders(): GetTokenHoldersResponse.GetTokenHoldersResponseV0.TokenHolders | undefined;
+    setTokenHolders(value?: GetTokenHoldersResponse.GetTokenHoldersResponseV0.TokenHolders): void;
+
+    hasProof(): boolean;
+    clearProof(): void;
+    getProof(): Proof | undefined;
+    setProof(value?: Proof): void;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    getResultCase(): GetTokenHoldersResponseV0.ResultCase;
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetTokenHoldersResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenHoldersResponseV0): GetTokenHoldersResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetTokenHoldersResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenHoldersResponseV0;
+    static deserializeBinaryFromReader(message: GetTokenHoldersResponseV0, reader: jspb.BinaryReader): GetTokenHoldersResponseV0;
+  }
+
+  export namespace GetTokenHoldersResponseV0 {
+    export type AsObject = {
+      tokenHolders?: GetTokenHoldersResponse.GetTokenHoldersResponseV0.TokenHolders.AsObject,
+      proof?: Proof.AsObject,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export class TokenHolderEntry extends jspb.Message {
+      getIdentityId(): Uint8Array | string;
+      getIdentityId_asU8(): Uint8Array;
+      getIdentityId_asB64(): string;
+      setIdentityId(value: Uint8Array | string): void;
+
+      getBalance(): number;
+      setBalance(value: number): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): TokenHolderEntry.AsObject;
+      static toObject(includeInstance: boolean, msg: TokenHolderEntry): TokenHolderEntry.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: TokenHolderEntry, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): TokenHolderEntry;
+      static deserializeBinaryFromReader(message: TokenHolderEntry, reader: jspb.BinaryReader): TokenHolderEntry;
+    }
+
+    export namespace TokenHolderEntry {
+      export type AsObject = {
+        identityId: Uint8Array | string,
+        balance: number,
+      }
+    }
+
+    export class TokenHolders extends jspb.Message {
+      clearTokenHoldersList(): void;
+      getTokenHoldersList(): Array<GetTokenHoldersResponse.GetTokenHoldersResponseV0.TokenHolderEntry>;
+      setTokenHoldersList(value: Array<GetTokenHoldersResponse.GetTokenHoldersResponseV0.TokenHolderEntry>): void;
+      addTokenHolders(value?: GetTokenHoldersResponse.GetTokenHoldersResponseV0.TokenHolderEntry, index?: number): GetTokenHoldersResponse.GetTokenHoldersResponseV0.TokenHolderEntry;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): TokenHolders.AsObject;
+      static toObject(includeInstance: boolean, msg: TokenHolders): TokenHolders.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: TokenHolders, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): TokenHolders;
+      static deserializeBinaryFromReader(message: TokenHolders, reader: jspb.BinaryReader): TokenHolders;
+    }
+
+    export namespace TokenHolders {
+      export type AsObject = {
+        tokenHoldersList: Array<GetTokenHoldersResponse.GetTokenHoldersResponseV0.TokenHolderEntry.AsObject>,
+      }
+    }
+
+    export enum ResultCase {
+      RESULT_NOT_SET = 0,
+      TOKEN_HOLDERS = 1,
+      PROOF = 2,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetTokenOrderBookRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetTokenOrderBookRequest.GetTokenOrderBookRequestV0 | undefined;
+  setV0(value?: GetTokenOrderBookRequest.GetTokenOrderBookRequestV0): void;
+
+  getVersionCase(): GetTokenOrderBookRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetTokenOrderBookRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenOrderBookRequest): GetTokenOrderBookRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetTokenOrderBookRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenOrderBookRequest;
+  static deserializeBinaryFromReader(message: GetTokenOrderBookRequest, reader: jspb.BinaryReader): GetTokenOrderBookRequest;
+}
+
+export namespace GetTokenOrderBookRequest {
+  export type AsObject = {
+    v0?: GetTokenOrderBookRequest.GetTokenOrderBookRequestV0.AsObject,
+  }
+
+  export class QuoteToken extends jspb.Message {
+    getContractId(): Uint8Array | string;
+    getContractId_asU8(): Uint8Array;
+    getContractId_asB64(): string;
+    setContractId(value: Uint8Array | string): void;
+
+    getTokenPosition(): number;
+    setTokenPosition(value: number): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): QuoteToken.AsObject;
+    static toObject(includeInstance: boolean, msg: QuoteToken): QuoteToken.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: QuoteToken, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): QuoteToken;
+    static deserializeBinaryFromReader(message: QuoteToken, reader: jspb.BinaryReader): QuoteToken;
+  }
+
+  export namespace QuoteToken {
+    export type AsObject = {
+      contractId: Uint8Array | string,
+      tokenPosition: number,
+    }
+  }
+
+  export class GetTokenOrderBookRequestV0 extends jspb.Message {
+    getTokenId(): Uint8Array | string;
+    getTokenId_asU8(): Uint8Array;
+    getTokenId_asB64(): string;
+    setTokenId(value: Uint8Array | string): void;
+
+    getSide(): GetTokenOrderBookRequest.OrderSideMap[keyof GetTokenOrderBookRequest.OrderSideMap];
+    setSide(value: GetTokenOrderBookRequest.OrderSideMap[keyof GetTokenOrderBookRequest.OrderSideMap]): void;
+
+    hasQuoteToken(): boolean;
+    clearQuoteToken(): void;
+    getQuoteToken(): GetTokenOrderBookRequest.QuoteToken | undefined;
+    setQuoteToken(value?: GetTokenOrderBookRequest.QuoteToken): void;
+
+    hasCount(): boolean;
+    clearCount(): void;
+    getCount(): number;
+    setCount(value: number): void;
+
+    getProve(): boolean;
+    setProve(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetTokenOrderBookRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenOrderBookRequestV0): GetTokenOrderBookRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetTokenOrderBookRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenOrderBookRequestV0;
+    static deserializeBinaryFromReader(message: GetTokenOrderBookRequestV0, reader: jspb.BinaryReader): GetTokenOrderBookRequestV0;
+  }
+
+  export namespace GetTokenOrderBookRequestV0 {
+    export type AsObject = {
+      tokenId: Uint8Array | string,
+      side: GetTokenOrderBookRequest.OrderSideMap[keyof GetTokenOrderBookRequest.OrderSideMap],
+      quoteToken?: GetTokenOrderBookRequest.QuoteToken.AsObject,
+      count: number,
+      prove: boolean,
+    }
+  }
+
+  export interface OrderSideMap {
+    BUY: 0;
+    SELL: 1;
+  }
+
+  export const OrderSide: OrderSideMap;
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetTokenOrderBookResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetTokenOrderBookResponse.GetTokenOrderBookResponseV0 | undefined;
+  setV0(value?: GetTokenOrderBookResponse.GetTokenOrderBookResponseV0): void;
+
+  getVersionCase(): GetTokenOrderBookResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetTokenOrderBookResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenOrderBookResponse): GetTokenOrderBookResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetTokenOrderBookResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenOrderBookResponse;
+  static deserializeBinaryFromReader(message: GetTokenOrderBookResponse, reader: jspb.BinaryReader): GetTokenOrderBookResponse;
+}
+
+export namespace GetTokenOrderBookResponse {
+  export type AsObject = {
+    v0?: GetTokenOrderBookResponse.GetTokenOrderBookResponseV0.AsObject,
+  }
+
+  export class GetTokenOrderBookResponseV0 extends jspb.Message {
+    hasOrders(): boolean;
+    clearOrders(): void;
+    getOrders(): GetTokenOrderBookResponse.GetTokenOrderBookResponseV0.TokenOrders | undefined;
+    setOrders(value?: GetTokenOrderBookResponse.GetTokenOrderBookResponseV0.TokenOrders): void;
+
+    hasProof(): boolean;
+    clearProof(): void;
+    getProof(): Proof | undefined;
+    setProof(value?: Proof): void;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    getResultCase(): GetTokenOrderBookResponseV0.ResultCase;
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetTokenOrderBookResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenOrderBookResponseV0): GetTokenOrderBookResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetTokenOrderBookResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenOrderBookResponseV0;
+    static deserializeBinaryFromReader(message: GetTokenOrderBookResponseV0, reader: jspb.BinaryReader): GetTokenOrderBookResponseV0;
+  }
+
+  export namespace GetTokenOrderBookResponseV0 {
+    export type AsObject = {
+      orders?: GetTokenOrderBookResponse.GetTokenOrderBookResponseV0.TokenOrders.AsObject,
+      proof?: Proof.AsObject,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export class TokenOrderEntry extends jspb.Message {
+      getOrderId(): Uint8Array | string;
+      getOrderId_asU8(): Uint8Array;
+      getOrderId_asB64(): string;
+      setOrderId(value: Uint8Array | string): void;
+
+      getOwnerId(): Uint8Array | string;
+      getOwnerId_asU8(): Uint8Array;
+      getOwnerId_asB64(): string;
+      setOwnerId(value: Uint8Array | string): void;
+
+      getPricePerToken(): string;
+      setPricePerToken(value: string): void;
+
+      getOriginalAmount(): string;
+      setOriginalAmount(value: string): void;
+
+      getRemainingAmount(): string;
+      setRemainingAmount(value: string): void;
+
+      getCreatedAt(): string;
+      setCreatedAt(value: string): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): TokenOrderEntry.AsObject;
+      static toObject(includeInstance: boolean, msg: TokenOrderEntry): TokenOrderEntry.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: TokenOrderEntry, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): TokenOrderEntry;
+      static deserializeBinaryFromReader(message: TokenOrderEntry, reader: jspb.BinaryReader): TokenOrderEntry;
+    }
+
+    export namespace TokenOrderEntry {
+      export type AsObject = {
+        orderId: Uint8Array | string,
+        ownerId: Uint8Array | string,
+        pricePerToken: string,
+        originalAmount: string,
+        remainingAmount: string,
+        createdAt: string,
+      }
+    }
+
+    export class TokenOrders extends jspb.Message {
+      clearOrdersList(): void;
+      getOrdersList(): Array<GetTokenOrderBookResponse.GetTokenOrderBookResponseV0.TokenOrderEntry>;
+      setOrdersList(value: Array<GetTokenOrderBookResponse.GetTokenOrderBookResponseV0.TokenOrderEntry>): void;
+      addOrders(value?: GetTokenOrderBookResponse.GetTokenOrderBookResponseV0.TokenOrderEntry, index?: number): GetTokenOrderBookResponse.GetTokenOrderBookResponseV0.TokenOrderEntry;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): TokenOrders.AsObject;
+      static toObject(includeInstance: boolean, msg: TokenOrders): TokenOrders.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: TokenOrders, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): TokenOrders;
+      static deserializeBinaryFromReader(message: TokenOrders, reader: jspb.BinaryReader): TokenOrders;
+    }
+
+    export namespace TokenOrders {
+      export type AsObject = {
+        ordersList: Array<GetTokenOrderBookResponse.GetTokenOrderBookResponseV0.TokenOrderEntry.AsObject>,
+      }
+    }
+
+    export enum ResultCase {
+      RESULT_NOT_SET = 0,
+      ORDERS = 1,
+      PROOF = 2,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetTokenVestingsRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetTokenVestingsRequest.GetTokenVestingsRequestV0 | undefined;
+  setV0(value?: GetTokenVestingsRequest.GetTokenVestingsRequestV0): void;
+
+  getVersionCase(): GetTokenVestingsRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetTokenVestingsRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenVestingsRequest): GetTokenVestingsRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetTokenVestingsRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenVestingsRequest;
+  static deserializeBinaryFromReader(message: GetTokenVestingsRequest, reader: jspb.BinaryReader): GetTokenVestingsRequest;
+}
+
+export namespace GetTokenVestingsRequest {
+  export type AsObject = {
+    v0?: GetTokenVestingsRequest.GetTokenVestingsRequestV0.AsObject,
+  }
+
+  export class GetTokenVestingsRequestV0 extends jspb.Message {
+    getTokenId(): Uint8Array | string;
+    getTokenId_asU8(): Uint8Array;
+    getTokenId_asB64(): string;
+    setTokenId(value: Uint8Array | string): void;
+
+    getRecipientId(): Uint8Array | string;
+    getRecipientId_asU8(): Uint8Array;
+    getRecipientId_asB64(): string;
+    setRecipientId(value: Uint8Array | string): void;
+
+    hasStartAfter(): boolean;
+    clearStartAfter(): void;
+    getStartAfter(): Uint8Array | string;
+    getStartAfter_asU8(): Uint8Array;
+    getStartAfter_asB64(): string;
+    setStartAfter(value: Uint8Array | string): void;
+
+    hasCount(): boolean;
+    clearCount(): void;
+    getCount(): number;
+    setCount(value: number): void;
+
+    getProve(): boolean;
+    setProve(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetTokenVestingsRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenVestingsRequestV0): GetTokenVestingsRequestV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetTokenVestingsRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenVestingsRequestV0;
+    static deserializeBinaryFromReader(message: GetTokenVestingsRequestV0, reader: jspb.BinaryReader): GetTokenVestingsRequestV0;
+  }
+
+  export namespace GetTokenVestingsRequestV0 {
+    export type AsObject = {
+      tokenId: Uint8Array | string,
+      recipientId: Uint8Array | string,
+      startAfter: Uint8Array | string,
+      count: number,
+      prove: boolean,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetTokenVestingsResponse extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetTokenVestingsResponse.GetTokenVestingsResponseV0 | undefined;
+  setV0(value?: GetTokenVestingsResponse.GetTokenVestingsResponseV0): void;
+
+  getVersionCase(): GetTokenVestingsResponse.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetTokenVestingsResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenVestingsResponse): GetTokenVestingsResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetTokenVestingsResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenVestingsResponse;
+  static deserializeBinaryFromReader(message: GetTokenVestingsResponse, reader: jspb.BinaryReader): GetTokenVestingsResponse;
+}
+
+export namespace GetTokenVestingsResponse {
+  export type AsObject = {
+    v0?: GetTokenVestingsResponse.GetTokenVestingsResponseV0.AsObject,
+  }
+
+  export class GetTokenVestingsResponseV0 extends jspb.Message {
+    hasVestings(): boolean;
+    clearVestings(): void;
+    getVestings(): GetTokenVestingsResponse.GetTokenVestingsResponseV0.TokenVestings | undefined;
+    setVestings(value?: GetTokenVestingsResponse.GetTokenVestingsResponseV0.TokenVestings): void;
+
+    hasProof(): boolean;
+    clearProof(): void;
+    getProof(): Proof | undefined;
+    setProof(value?: Proof): void;
+
+    hasMetadata(): boolean;
+    clearMetadata(): void;
+    getMetadata(): ResponseMetadata | undefined;
+    setMetadata(value?: ResponseMetadata): void;
+
+    getResultCase(): GetTokenVestingsResponseV0.ResultCase;
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetTokenVestingsResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenVestingsResponseV0): GetTokenVestingsResponseV0.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: GetTokenVestingsResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenVestingsResponseV0;
+    static deserializeBinaryFromReader(message: GetTokenVestingsResponseV0, reader: jspb.BinaryReader): GetTokenVestingsResponseV0;
+  }
+
+  export namespace GetTokenVestingsResponseV0 {
+    export type AsObject = {
+      vestings?: GetTokenVestingsResponse.GetTokenVestingsResponseV0.TokenVestings.AsObject,
+      proof?: Proof.AsObject,
+      metadata?: ResponseMetadata.AsObject,
+    }
+
+    export class TokenVestings extends jspb.Message {
+      clearVestingsList(): void;
+      getVestingsList(): Array<Uint8Array | string>;
+      getVestingsList_asU8(): Array<Uint8Array>;
+      getVestingsList_asB64(): Array<string>;
+      setVestingsList(value: Array<Uint8Array | string>): void;
+      addVestings(value: Uint8Array | string, index?: number): Uint8Array | string;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): TokenVestings.AsObject;
+      static toObject(includeInstance: boolean, msg: TokenVestings): TokenVestings.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: TokenVestings, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): TokenVestings;
+      static deserializeBinaryFromReader(message: TokenVestings, reader: jspb.BinaryReader): TokenVestings;
+    }
+
+    export namespace TokenVestings {
+      export type AsObject = {
+        vestingsList: Array<Uint8Array | string>,
+      }
+    }
+
+    export enum ResultCase {
+      RESULT_NOT_SET = 0,
+      VESTINGS = 1,
+      PROOF = 2,
+    }
+  }
+
+  export enum VersionCase {
+    VERSION_NOT_SET = 0,
+    V0 = 1,
+  }
+}
+
+export class GetTokenAllowancesRequest extends jspb.Message {
+  hasV0(): boolean;
+  clearV0(): void;
+  getV0(): GetTokenAllowancesRequest.GetTokenAllowancesRequestV0 | undefined;
+  setV0(value?: GetTokenAllowancesRequest.GetTokenAllowancesRequestV0): void;
+
+  getVersionCase(): GetTokenAllowancesRequest.VersionCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetTokenAllowancesRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenAllowancesRequest): GetTokenAllowancesRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetTokenAllowancesRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenAllowancesRequest;
+  static deserializeBinaryFromReader(message: GetTokenAllowancesRequest, reader: jspb.BinaryReader): GetTokenAllowancesRequest;
+}
+
+export namespace GetTokenAllowancesRequest {
+  export type AsObject = {
+    v0?: GetTokenAllowancesRequest.GetTokenAllowancesRequestV0.AsObject,
+  }
+
+  export class GetTokenAllowancesRequestV0 extends jspb.Message {
+    getTokenId(): Uint8Array | string;
+    getTokenId_asU8(): Uint8Array;
+    getTokenId_asB64(): string;
+    setTokenId(value: Uint8Array | string): void;
+
+    getOwnerId(): Uint8Array | string;
+    getOwnerId_asU8(): Uint8Array;
+    getOwnerId_asB64(): string;
+    setOwnerId(value: Uint8Array | string): void;
+
+    hasStartAfter(): boolean;
+    clearStartAfter(): void;
+    getStartAfter(): GetTokenAllowancesRequest.GetTokenAllowancesRequestV0.AllowanceSpender | undefined;
+    setStartAfter(value?: GetTokenAllowancesRequest.GetTokenAllowancesRequestV0.AllowanceSpender): void;
+
+    hasCount(): boolean;
+    clearCount(): void;
+    getCount(): number;
+    setCount(value: number): void;
+
+    getProve(): boolean;
+    setProve(value: boolean): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): GetTokenAllowancesRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenAllowancesRequestV0): GetTokenAllowancesRequestV0.AsObject;
     static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
     static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-    static serializeBinaryToWriter(message: GetTokenPerpetualDistributionLastClaimRequestV0, writer: jspb.BinaryWriter): void;
-    static deserializeBinary(bytes: Uint8Array): GetTokenPerpetualDistributionLastClaimRequestV0;
-    static deserializeBinaryFromReader(message: GetTokenPerpetualDistributionLastClaimRequestV0, reader: jspb.BinaryReader): GetTokenPerpetualDistributionLastClaimRequestV0;
+    static serializeBinaryToWriter(message: GetTokenAllowancesRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenAllowancesRequestV0;
+    static deserializeBinaryFromReader(message: GetTokenAllowancesRequestV0, reader: jspb.BinaryReader): GetTokenAllowancesRequestV0;
   }
 
-  export namespace GetTokenPerpetualDistributionLastClaimRequestV0 {
+  export namespace GetTokenAllowancesRequestV0 {
     export type AsObject = {
       tokenId: Uint8Array | string,
-      contractInfo?: GetTokenPerpetualDistributionLastClaimRequest.ContractTokenInfo.AsObject,
-      identityId: Uint8Array | string,
+      ownerId: Uint8Array | string,
+      startAfter?: GetTokenAllowancesRequest.GetTokenAllowancesRequestV0.AllowanceSpender.AsObject,
+      count: number,
       prove: boolean,
     }
+
+    export class AllowanceSpender extends jspb.Message {
+      hasIdentityId(): boolean;
+      clearIdentityId(): void;
+      getIdentityId(): Uint8Array | string;
+      getIdentityId_asU8(): Uint8Array;
+      getIdentityId_asB64(): string;
+      setIdentityId(value: Uint8Array | string): void;
+
+      hasGroupContractPosition(): boolean;
+      clearGroupContractPosition(): void;
+      getGroupContractPosition(): number;
+      setGroupContractPosition(value: number): void;
+
+      getSpenderCase(): AllowanceSpender.SpenderCase;
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): AllowanceSpender.AsObject;
+      static toObject(includeInstance: boolean, msg: AllowanceSpender): AllowanceSpender.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: AllowanceSpender, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): AllowanceSpender;
+      static deserializeBinaryFromReader(message: AllowanceSpender, reader: jspb.BinaryReader): AllowanceSpender;
+    }
+
+    export namespace AllowanceSpender {
+      export type AsObject = {
+        identityId: Uint8Array | string,
+        groupContractPosition: number,
+      }
+
+      export enum SpenderCase {
+        SPENDER_NOT_SET = 0,
+        IDENTITY_ID = 1,
+        GROUP_CONTRACT_POSITION = 2,
+      }
+    }
   }
 
   export enum VersionCase {
@@ -7794,33 +10043,33 @@ export namespace GetTokenPerpetualDistributionLastClaimRequest {
   }
 }
 
-export class GetTokenPerpetualDistributionLastClaimResponse extends jspb.Message {
+export class GetTokenAllowancesResponse extends jspb.Message {
   hasV0(): boolean;
   clearV0(): void;
-  getV0(): GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0 | undefined;
-  setV0(value?: GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0): void;
+  getV0(): GetTokenAllowancesResponse.GetTokenAllowancesResponseV0 | undefined;
+  setV0(value?: GetTokenAllowancesResponse.GetTokenAllowancesResponseV0): void;
 
-  getVersionCase(): GetTokenPerpetualDistributionLastClaimResponse.VersionCase;
+  getVersionCase(): GetTokenAllowancesResponse.VersionCase;
   serializeBinary(): Uint8Array;
-  toObject(includeInstance?: boolean): GetTokenPerpetualDistributionLastClaimResponse.AsObject;
-  static toObject(includeInstance: boolean, msg: GetTokenPerpetualDistributionLastClaimResponse): GetTokenPerpetualDistributionLastClaimResponse.AsObject;
+  toObject(includeInstance?: boolean): GetTokenAllowancesResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenAllowancesResponse): GetTokenAllowancesResponse.AsObject;
   static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
   static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-  static serializeBinaryToWriter(message: GetTokenPerpetualDistributionLastClaimResponse, writer: jspb.BinaryWriter): void;
-  static deserializeBinary(bytes: Uint8Array): GetTokenPerpetualDistributionLastClaimResponse;
-  static deserializeBinaryFromReader(message: GetTokenPerpetualDistributionLastClaimResponse, reader: jspb.BinaryReader): GetTokenPerpetualDistributionLastClaimResponse;
+  static serializeBinaryToWriter(message: GetTokenAllowancesResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenAllowancesResponse;
+  static deserializeBinaryFromReader(message: GetTokenAllowancesResponse, reader: jspb.BinaryReader): GetTokenAllowancesResponse;
 }
 
-export namespace GetTokenPerpetualDistributionLastClaimResponse {
+export namespace GetTokenAllowancesResponse {
   export type AsObject = {
-    v0?: GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0.AsObject,
+    v0?: GetTokenAllowancesResponse.GetTokenAllowancesResponseV0.AsObject,
   }
 
-  export class GetTokenPerpetualDistributionLastClaimResponseV0 extends jspb.Message {
-    hasLastClaim(): boolean;
-    clearLastClaim(): void;
-    getLastClaim(): GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0.LastClaimInfo | undefined;
-    setLastClaim(value?: GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0.LastClaimInfo): void;
+  export class GetTokenAllowancesResponseV0 extends jspb.Message {
+    hasAllowances(): boolean;
+    clearAllowances(): void;
+    getAllowances(): GetTokenAllowancesResponse.GetTokenAllowancesResponseV0.TokenAllowances | undefined;
+    setAllowances(value?: GetTokenAllowancesResponse.GetTokenAllowancesResponseV0.TokenAllowances): void;
 
     hasProof(): boolean;
     clearProof(): void;
@@ -7832,78 +10081,51 @@ export namespace GetTokenPerpetualDistributionLastClaimResponse {
     getMetadata(): ResponseMetadata | undefined;
     setMetadata(value?: ResponseMetadata): void;
 
-    getResultCase(): GetTokenPerpetualDistributionLastClaimResponseV0.ResultCase;
+    getResultCase(): GetTokenAllowancesResponseV0.ResultCase;
     serializeBinary(): Uint8Array;
-    toObject(includeInstance?: boolean): GetTokenPerpetualDistributionLastClaimResponseV0.AsObject;
-    static toObject(includeInstance: boolean, msg: GetTokenPerpetualDistributionLastClaimResponseV0): GetTokenPerpetualDistributionLastClaimResponseV0.AsObject;
+    toObject(includeInstance?: boolean): GetTokenAllowancesResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenAllowancesResponseV0): GetTokenAllowancesResponseV0.AsObject;
     static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
     static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-    static serializeBinaryToWriter(message: GetTokenPerpetualDistributionLastClaimResponseV0, writer: jspb.BinaryWriter): void;
-    static deserializeBinary(bytes: Uint8Array): GetTokenPerpetualDistributionLastClaimResponseV0;
-    static deserializeBinaryFromReader(message: GetTokenPerpetualDistributionLastClaimResponseV0, reader: jspb.BinaryReader): GetTokenPerpetualDistributionLastClaimResponseV0;
+    static serializeBinaryToWriter(message: GetTokenAllowancesResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenAllowancesResponseV0;
+    static deserializeBinaryFromReader(message: GetTokenAllowancesResponseV0, reader: jspb.BinaryReader): GetTokenAllowancesResponseV0;
   }
 
-  export namespace GetTokenPerpetualDistributionLastClaimResponseV0 {
+  export namespace GetTokenAllowancesResponseV0 {
     export type AsObject = {
-      lastClaim?: GetTokenPerpetualDistributionLastClaimResponse.GetTokenPerpetualDistributionLastClaimResponseV0.LastClaimInfo.AsObject,
+      allowances?: GetTokenAllowancesResponse.GetTokenAllowancesResponseV0.TokenAllowances.AsObject,
       proof?: Proof.AsObject,
       metadata?: ResponseMetadata.AsObject,
     }
 
-    export class LastClaimInfo extends jspb.Message {
-      hasTimestampMs(): boolean;
-      clearTimestampMs(): void;
-      getTimestampMs(): string;
-      setTimestampMs(value: string): void;
-
-      hasBlockHeight(): boolean;
-      clearBlockHeight(): void;
-      getBlockHeight(): string;
-      setBlockHeight(value: string): void;
-
-      hasEpoch(): boolean;
-      clearEpoch(): void;
-      getEpoch(): number;
-      setEpoch(value: number): void;
-
-      hasRawBytes(): boolean;
-      clearRawBytes(): void;
-      getRawBytes(): Uint8Array | string;
-      getRawBytes_asU8(): Uint8Array;
-      getRawBytes_asB64(): string;
-      setRawBytes(value: Uint8Array | string): void;
+    export class TokenAllowances extends jspb.Message {
+      clearAllowancesList(): void;
+      getAllowancesList(): Array<Uint8Array | string>;
+      getAllowancesList_asU8(): Array<Uint8Array>;
+      getAllowancesList_asB64(): Array<string>;
+      setAllowancesList(value: Array<Uint8Array | string>): void;
+      addAllowances(value: Uint8Array | string, index?: number): Uint8Array | string;
 
-      getPaidAtCase(): LastClaimInfo.PaidAtCase;
       serializeBinary(): Uint8Array;
-      toObject(includeInstance?: boolean): LastClaimInfo.AsObject;
-      static toObject(includeInstance: boolean, msg: LastClaimInfo): LastClaimInfo.AsObject;
+      toObject(includeInstance?: boolean): TokenAllowances.AsObject;
+      static toObject(includeInstance: boolean, msg: TokenAllowances): TokenAllowances.AsObject;
       static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
       static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-      static serializeBinaryToWriter(message: LastClaimInfo, writer: jspb.BinaryWriter): void;
-      static deserializeBinary(bytes: Uint8Array): LastClaimInfo;
-      static deserializeBinaryFromReader(message: LastClaimInfo, reader: jspb.BinaryReader): LastClaimInfo;
+      static serializeBinaryToWriter(message: TokenAllowances, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): TokenAllowances;
+      static deserializeBinaryFromReader(message: TokenAllowances, reader: jspb.BinaryReader): TokenAllowances;
     }
 
-    export namespace LastClaimInfo {
+    export namespace TokenAllowances {
       export type AsObject = {
-        timestampMs: string,
-        blockHeight: string,
-        epoch: number,
-        rawBytes: Uint8Array | string,
-      }
-
-      export enum PaidAtCase {
-        PAID_AT_NOT_SET = 0,
-        TIMESTAMP_MS = 1,
-        BLOCK_HEIGHT = 2,
-        EPOCH = 3,
-        RAW_BYTES = 4,
+        allowancesList: Array<Uint8Array | string>,
       }
     }
 
     export enum ResultCase {
       RESULT_NOT_SET = 0,
-      LAST_CLAIM = 1,
+      ALLOWANCES = 1,
       PROOF = 2,
     }
   }
@@ -7914,52 +10136,169 @@ export namespace GetTokenPerpetualDistributionLastClaimResponse {
   }
 }
 
-export class GetTokenTotalSupplyRequest extends jspb.Message {
+export class GetTokenEventsRequest extends jspb.Message {
   hasV0(): boolean;
   clearV0(): void;
-  getV0(): GetTokenTotalSupplyRequest.GetTokenTotalSupplyRequestV0 | undefined;
-  setV0(value?: GetTokenTotalSupplyRequest.GetTokenTotalSupplyRequestV0): void;
+  getV0(): GetTokenEventsRequest.GetTokenEventsRequestV0 | undefined;
+  setV0(value?: GetTokenEventsRequest.GetTokenEventsRequestV0): void;
 
-  getVersionCase(): GetTokenTotalSupplyRequest.VersionCase;
+  getVersionCase(): GetTokenEventsRequest.VersionCase;
   serializeBinary(): Uint8Array;
-  toObject(includeInstance?: boolean): GetTokenTotalSupplyRequest.AsObject;
-  static toObject(includeInstance: boolean, msg: GetTokenTotalSupplyRequest): GetTokenTotalSupplyRequest.AsObject;
+  toObject(includeInstance?: boolean): GetTokenEventsRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenEventsRequest): GetTokenEventsRequest.AsObject;
   static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
   static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-  static serializeBinaryToWriter(message: GetTokenTotalSupplyRequest, writer: jspb.BinaryWriter): void;
-  static deserializeBinary(bytes: Uint8Array): GetTokenTotalSupplyRequest;
-  static deserializeBinaryFromReader(message: GetTokenTotalSupplyRequest, reader: jspb.BinaryReader): GetTokenTotalSupplyRequest;
+  static serializeBinaryToWriter(message: GetTokenEventsRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenEventsRequest;
+  static deserializeBinaryFromReader(message: GetTokenEventsRequest, reader: jspb.BinaryReader): GetTokenEventsRequest;
 }
 
-export namespace GetTokenTotalSupplyRequest {
+export namespace GetTokenEventsRequest {
   export type AsObject = {
-    v0?: GetTokenTotalSupplyRequest.GetTokenTotalSupplyRequestV0.AsObject,
+    v0?: GetTokenEventsRequest.GetTokenEventsRequestV0.AsObject,
   }
 
-  export class GetTokenTotalSupplyRequestV0 extends jspb.Message {
+  export class GetTokenEventsRequestV0 extends jspb.Message {
     getTokenId(): Uint8Array | string;
     getTokenId_asU8(): Uint8Array;
     getTokenId_asB64(): string;
     setTokenId(value: Uint8Array | string): void;
 
+    hasEventType(): boolean;
+    clearEventType(): void;
+    getEventType(): GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap[keyof GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap];
+    setEventType(value: GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap[keyof GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap]): void;
+
+    hasIdentityId(): boolean;
+    clearIdentityId(): void;
+    getIdentityId(): Uint8Array | string;
+    getIdentityId_asU8(): Uint8Array;
+    getIdentityId_asB64(): string;
+    setIdentityId(value: Uint8Array | string): void;
+
+    hasBlockRange(): boolean;
+    clearBlockRange(): void;
+    getBlockRange(): GetTokenEventsRequest.GetTokenEventsRequestV0.BlockRange | undefined;
+    setBlockRange(value?: GetTokenEventsRequest.GetTokenEventsRequestV0.BlockRange): void;
+
+    hasStartAfter(): boolean;
+    clearStartAfter(): void;
+    getStartAfter(): GetTokenEventsRequest.GetTokenEventsRequestV0.StartAfter | undefined;
+    setStartAfter(value?: GetTokenEventsRequest.GetTokenEventsRequestV0.StartAfter): void;
+
+    hasCount(): boolean;
+    clearCount(): void;
+    getCount(): number;
+    setCount(value: number): void;
+
+    getOrderAscending(): boolean;
+    setOrderAscending(value: boolean): void;
+
     getProve(): boolean;
     setProve(value: boolean): void;
 
+    getFilterCase(): GetTokenEventsRequestV0.FilterCase;
     serializeBinary(): Uint8Array;
-    toObject(includeInstance?: boolean): GetTokenTotalSupplyRequestV0.AsObject;
-    static toObject(includeInstance: boolean, msg: GetTokenTotalSupplyRequestV0): GetTokenTotalSupplyRequestV0.AsObject;
+    toObject(includeInstance?: boolean): GetTokenEventsRequestV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenEventsRequestV0): GetTokenEventsRequestV0.AsObject;
     static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
     static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-    static serializeBinaryToWriter(message: GetTokenTotalSupplyRequestV0, writer: jspb.BinaryWriter): void;
-    static deserializeBinary(bytes: Uint8Array): GetTokenTotalSupplyRequestV0;
-    static deserializeBinaryFromReader(message: GetTokenTotalSupplyRequestV0, reader: jspb.BinaryReader): GetTokenTotalSupplyRequestV0;
+    static serializeBinaryToWriter(message: GetTokenEventsRequestV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenEventsRequestV0;
+    static deserializeBinaryFromReader(message: GetTokenEventsRequestV0, reader: jspb.BinaryReader): GetTokenEventsRequestV0;
   }
 
-  export namespace GetTokenTotalSupplyRequestV0 {
+  export namespace GetTokenEventsRequestV0 {
     export type AsObject = {
       tokenId: Uint8Array | string,
+      eventType: GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap[keyof GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap],
+      identityId: Uint8Array | string,
+      blockRange?: GetTokenEventsRequest.GetTokenEventsRequestV0.BlockRange.AsObject,
+      startAfter?: GetTokenEventsRequest.GetTokenEventsRequestV0.StartAfter.AsObject,
+      count: number,
+      orderAscending: boolean,
       prove: boolean,
     }
+
+    export class BlockRange extends jspb.Message {
+      getStartHeight(): number;
+      setStartHeight(value: number): void;
+
+      getEndHeight(): number;
+      setEndHeight(value: number): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): BlockRange.AsObject;
+      static toObject(includeInstance: boolean, msg: BlockRange): BlockRange.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: BlockRange, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): BlockRange;
+      static deserializeBinaryFromReader(message: BlockRange, reader: jspb.BinaryReader): BlockRange;
+    }
+
+    export namespace BlockRange {
+      export type AsObject = {
+        startHeight: number,
+        endHeight: number,
+      }
+    }
+
+    export class StartAfter extends jspb.Message {
+      getDocumentId(): Uint8Array | string;
+      getDocumentId_asU8(): Uint8Array;
+      getDocumentId_asB64(): string;
+      setDocumentId(value: Uint8Array | string): void;
+
+      getEventType(): GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap[keyof GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap];
+      setEventType(value: GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap[keyof GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap]): void;
+
+      getCreatedAt(): number;
+      setCreatedAt(value: number): void;
+
+      getCreatedAtBlockHeight(): number;
+      setCreatedAtBlockHeight(value: number): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): StartAfter.AsObject;
+      static toObject(includeInstance: boolean, msg: StartAfter): StartAfter.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: StartAfter, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): StartAfter;
+      static deserializeBinaryFromReader(message: StartAfter, reader: jspb.BinaryReader): StartAfter;
+    }
+
+    export namespace StartAfter {
+      export type AsObject = {
+        documentId: Uint8Array | string,
+        eventType: GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap[keyof GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap],
+        createdAt: number,
+        createdAtBlockHeight: number,
+      }
+    }
+
+    export interface TokenEventTypeMap {
+      MINT: 0;
+      BURN: 1;
+      FREEZE: 2;
+      UNFREEZE: 3;
+      DESTROY_FROZEN_FUNDS: 4;
+      TRANSFER: 5;
+      CLAIM: 6;
+      EMERGENCY_ACTION: 7;
+      CONFIG_UPDATE: 8;
+      DIRECT_PRICING: 9;
+      DIRECT_PURCHASE: 10;
+    }
+
+    export const TokenEventType: TokenEventTypeMap;
+
+    export enum FilterCase {
+      FILTER_NOT_SET = 0,
+      IDENTITY_ID = 3,
+      BLOCK_RANGE = 4,
+    }
   }
 
   export enum VersionCase {
@@ -7968,33 +10307,33 @@ export namespace GetTokenTotalSupplyRequest {
   }
 }
 
-export class GetTokenTotalSupplyResponse extends jspb.Message {
+export class GetTokenEventsResponse extends jspb.Message {
   hasV0(): boolean;
   clearV0(): void;
-  getV0(): GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0 | undefined;
-  setV0(value?: GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0): void;
+  getV0(): GetTokenEventsResponse.GetTokenEventsResponseV0 | undefined;
+  setV0(value?: GetTokenEventsResponse.GetTokenEventsResponseV0): void;
 
-  getVersionCase(): GetTokenTotalSupplyResponse.VersionCase;
+  getVersionCase(): GetTokenEventsResponse.VersionCase;
   serializeBinary(): Uint8Array;
-  toObject(includeInstance?: boolean): GetTokenTotalSupplyResponse.AsObject;
-  static toObject(includeInstance: boolean, msg: GetTokenTotalSupplyResponse): GetTokenTotalSupplyResponse.AsObject;
+  toObject(includeInstance?: boolean): GetTokenEventsResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetTokenEventsResponse): GetTokenEventsResponse.AsObject;
   static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
   static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-  static serializeBinaryToWriter(message: GetTokenTotalSupplyResponse, writer: jspb.BinaryWriter): void;
-  static deserializeBinary(bytes: Uint8Array): GetTokenTotalSupplyResponse;
-  static deserializeBinaryFromReader(message: GetTokenTotalSupplyResponse, reader: jspb.BinaryReader): GetTokenTotalSupplyResponse;
+  static serializeBinaryToWriter(message: GetTokenEventsResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetTokenEventsResponse;
+  static deserializeBinaryFromReader(message: GetTokenEventsResponse, reader: jspb.BinaryReader): GetTokenEventsResponse;
 }
 
-export namespace GetTokenTotalSupplyResponse {
+export namespace GetTokenEventsResponse {
   export type AsObject = {
-    v0?: GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0.AsObject,
+    v0?: GetTokenEventsResponse.GetTokenEventsResponseV0.AsObject,
   }
 
-  export class GetTokenTotalSupplyResponseV0 extends jspb.Message {
-    hasTokenTotalSupply(): boolean;
-    clearTokenTotalSupply(): void;
-    getTokenTotalSupply(): GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0.TokenTotalSupplyEntry | undefined;
-    setTokenTotalSupply(value?: GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0.TokenTotalSupplyEntry): void;
+  export class GetTokenEventsResponseV0 extends jspb.Message {
+    hasEvents(): boolean;
+    clearEvents(): void;
+    getEvents(): GetTokenEventsResponse.GetTokenEventsResponseV0.TokenEvents | undefined;
+    setEvents(value?: GetTokenEventsResponse.GetTokenEventsResponseV0.TokenEvents): void;
 
     hasProof(): boolean;
     clearProof(): void;
@@ -8006,57 +10345,95 @@ export namespace GetTokenTotalSupplyResponse {
     getMetadata(): ResponseMetadata | undefined;
     setMetadata(value?: ResponseMetadata): void;
 
-    getResultCase(): GetTokenTotalSupplyResponseV0.ResultCase;
+    getResultCase(): GetTokenEventsResponseV0.ResultCase;
     serializeBinary(): Uint8Array;
-    toObject(includeInstance?: boolean): GetTokenTotalSupplyResponseV0.AsObject;
-    static toObject(includeInstance: boolean, msg: GetTokenTotalSupplyResponseV0): GetTokenTotalSupplyResponseV0.AsObject;
+    toObject(includeInstance?: boolean): GetTokenEventsResponseV0.AsObject;
+    static toObject(includeInstance: boolean, msg: GetTokenEventsResponseV0): GetTokenEventsResponseV0.AsObject;
     static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
     static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-    static serializeBinaryToWriter(message: GetTokenTotalSupplyResponseV0, writer: jspb.BinaryWriter): void;
-    static deserializeBinary(bytes: Uint8Array): GetTokenTotalSupplyResponseV0;
-    static deserializeBinaryFromReader(message: GetTokenTotalSupplyResponseV0, reader: jspb.BinaryReader): GetTokenTotalSupplyResponseV0;
+    static serializeBinaryToWriter(message: GetTokenEventsResponseV0, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): GetTokenEventsResponseV0;
+    static deserializeBinaryFromReader(message: GetTokenEventsResponseV0, reader: jspb.BinaryReader): GetTokenEventsResponseV0;
   }
 
-  export namespace GetTokenTotalSupplyResponseV0 {
+  export namespace GetTokenEventsResponseV0 {
     export type AsObject = {
-      tokenTotalSupply?: GetTokenTotalSupplyResponse.GetTokenTotalSupplyResponseV0.TokenTotalSupplyEntry.AsObject,
+      events?: GetTokenEventsResponse.GetTokenEventsResponseV0.TokenEvents.AsObject,
       proof?: Proof.AsObject,
       metadata?: ResponseMetadata.AsObject,
     }
 
-    export class TokenTotalSupplyEntry extends jspb.Message {
-      getTokenId(): Uint8Array | string;
-      getTokenId_asU8(): Uint8Array;
-      getTokenId_asB64(): string;
-      setTokenId(value: Uint8Array | string): void;
+    export class TokenEventEntry extends jspb.Message {
+      getDocumentId(): Uint8Array | string;
+      getDocumentId_asU8(): Uint8Array;
+      getDocumentId_asB64(): string;
+      setDocumentId(value: Uint8Array | string): void;
 
-      getTotalAggregatedAmountInUserAccounts(): number;
-      setTotalAggregatedAmountInUserAccounts(value: number): void;
+      getOwnerId(): Uint8Array | string;
+      getOwnerId_asU8(): Uint8Array;
+      getOwnerId_asB64(): string;
+      setOwnerId(value: Uint8Array | string): void;
 
-      getTotalSystemAmount(): number;
-      setTotalSystemAmount(value: number): void;
+      getCreatedAt(): number;
+      setCreatedAt(value: number): void;
+
+      getCreatedAtBlockHeight(): number;
+      setCreatedAtBlockHeight(value: number): void;
+
+      getEvent(): Uint8Array | string;
+      getEvent_asU8(): Uint8Array;
+      getEvent_asB64(): string;
+      setEvent(value: Uint8Array | string): void;
+
+      getEventType(): GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap[keyof GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap];
+      setEventType(value: GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap[keyof GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap]): void;
 
       serializeBinary(): Uint8Array;
-      toObject(includeInstance?: boolean): TokenTotalSupplyEntry.AsObject;
-      static toObject(includeInstance: boolean, msg: TokenTotalSupplyEntry): TokenTotalSupplyEntry.AsObject;
+      toObject(includeInstance?: boolean): TokenEventEntry.AsObject;
+      static toObject(includeInstance: boolean, msg: TokenEventEntry): TokenEventEntry.AsObject;
       static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
       static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
-      static serializeBinaryToWriter(message: TokenTotalSupplyEntry, writer: jspb.BinaryWriter): void;
-      static deserializeBinary(bytes: Uint8Array): TokenTotalSupplyEntry;
-      static deserializeBinaryFromReader(message: TokenTotalSupplyEntry, reader: jspb.BinaryReader): TokenTotalSupplyEntry;
+      static serializeBinaryToWriter(message: TokenEventEntry, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): TokenEventEntry;
+      static deserializeBinaryFromReader(message: TokenEventEntry, reader: jspb.BinaryReader): TokenEventEntry;
     }
 
-    export namespace TokenTotalSupplyEntry {
+    export namespace TokenEventEntry {
       export type AsObject = {
-        tokenId: Uint8Array | string,
-        totalAggregatedAmountInUserAccounts: number,
-        totalSystemAmount: number,
+        documentId: Uint8Array | string,
+        ownerId: Uint8Array | string,
+        createdAt: number,
+        createdAtBlockHeight: number,
+        event: Uint8Array | string,
+        eventType: GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap[keyof GetTokenEventsRequest.GetTokenEventsRequestV0.TokenEventTypeMap],
+      }
+    }
+
+    export class TokenEvents extends jspb.Message {
+      clearEventsList(): void;
+      getEventsList(): Array<GetTokenEventsResponse.GetTokenEventsResponseV0.TokenEventEntry>;
+      setEventsList(value: Array<GetTokenEventsResponse.GetTokenEventsResponseV0.TokenEventEntry>): void;
+      addEvents(value?: GetTokenEventsResponse.GetTokenEventsResponseV0.TokenEventEntry, index?: number): GetTokenEventsResponse.GetTokenEventsResponseV0.TokenEventEntry;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): TokenEvents.AsObject;
+      static toObject(includeInstance: boolean, msg: TokenEvents): TokenEvents.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: TokenEvents, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): TokenEvents;
+      static deserializeBinaryFromReader(message: TokenEvents, reader: jspb.BinaryReader): TokenEvents;
+    }
+
+    export namespace TokenEvents {
+      export type AsObject = {
+        eventsList: Array<GetTokenEventsResponse.GetTokenEventsResponseV0.TokenEventEntry.AsObject>,
       }
     }
 
     export enum ResultCase {
       RESULT_NOT_SET = 0,
-      TOKEN_TOTAL_SUPPLY = 1,
+      EVENTS = 1,
       PROOF = 2,
     }
   }
@@ -8700,6 +11077,44 @@ export namespace GetGroupActionsResponse {
       }
     }
 
+    export class TransferFromEvent extends jspb.Message {
+      getFromId(): Uint8Array | string;
+      getFromId_asU8(): Uint8Array;
+      getFromId_asB64(): string;
+      setFromId(value: Uint8Array | string): void;
+
+      getRecipientId(): Uint8Array | string;
+      getRecipientId_asU8(): Uint8Array;
+      getRecipientId_asB64(): string;
+      setRecipientId(value: Uint8Array | string): void;
+
+      getAmount(): number;
+      setAmount(value: number): void;
+
+      hasPublicNote(): boolean;
+      clearPublicNote(): void;
+      getPublicNote(): string;
+      setPublicNote(value: string): void;
+
+      serializeBinary(): Uint8Array;
+      toObject(includeInstance?: boolean): TransferFromEvent.AsObject;
+      static toObject(includeInstance: boolean, msg: TransferFromEvent): TransferFromEvent.AsObject;
+      static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+      static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+      static serializeBinaryToWriter(message: TransferFromEvent, writer: jspb.BinaryWriter): void;
+      static deserializeBinary(bytes: Uint8Array): TransferFromEvent;
+      static deserializeBinaryFromReader(message: TransferFromEvent, reader: jspb.BinaryReader): TransferFromEvent;
+    }
+
+    export namespace TransferFromEvent {
+      export type AsObject = {
+        fromId: Uint8Array | string,
+        recipientId: Uint8Array | string,
+        amount: number,
+        publicNote: string,
+      }
+    }
+
     export class BurnEvent extends jspb.Message {
       getAmount(): number;
       setAmount(value: number): void;
@@ -9211,6 +11626,11 @@ export namespace GetGroupActionsResponse {
       getUpdatePrice(): GetGroupActionsResponse.GetGroupActionsResponseV0.UpdateDirectPurchasePriceEvent | undefined;
       setUpdatePrice(value?: GetGroupActionsResponse.GetGroupActionsResponseV0.UpdateDirectPurchasePriceEvent): void;
 
+      hasTransferFrom(): boolean;
+      clearTransferFrom(): void;
+      getTransferFrom(): GetGroupActionsResponse.GetGroupActionsResponseV0.TransferFromEvent | undefined;
+      setTransferFrom(value?: GetGroupActionsResponse.GetGroupActionsResponseV0.TransferFromEvent): void;
+
       getTypeCase(): TokenEvent.TypeCase;
       serializeBinary(): Uint8Array;
       toObject(includeInstance?: boolean): TokenEvent.AsObject;
@@ -9232,6 +11652,7 @@ export namespace GetGroupActionsResponse {
         emergencyAction?: GetGroupActionsResponse.GetGroupActionsResponseV0.EmergencyActionEvent.AsObject,
         tokenConfigUpdate?: GetGroupActionsResponse.GetGroupActionsResponseV0.TokenConfigUpdateEvent.AsObject,
         updatePrice?: GetGroupActionsResponse.GetGroupActionsResponseV0.UpdateDirectPurchasePriceEvent.AsObject,
+        transferFrom?: GetGroupActionsResponse.GetGroupActionsResponseV0.TransferFromEvent.AsObject,
       }
 
       export enum TypeCase {
@@ -9244,6 +11665,7 @@ export namespace GetGroupActionsResponse {
         EMERGENCY_ACTION = 6,
         TOKEN_CONFIG_UPDATE = 7,
         UPDATE_PRICE = 8,
+        TRANSFER_FROM = 9,
       }
     }
 
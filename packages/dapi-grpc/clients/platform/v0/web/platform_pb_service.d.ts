@@ -139,6 +139,24 @@ type PlatformgetDocuments = {
   readonly responseType: typeof platform_pb.GetDocumentsResponse;
 };
 
+type PlatformgetDocumentsAggregate = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetDocumentsAggregateRequest;
+  readonly responseType: typeof platform_pb.GetDocumentsAggregateResponse;
+};
+
+type PlatformsubscribeDocuments = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: true;
+  readonly requestType: typeof platform_pb.SubscribeDocumentsRequest;
+  readonly responseType: typeof platform_pb.SubscribeDocumentsResponse;
+};
+
 type PlatformgetIdentityByPublicKeyHash = {
   readonly methodName: string;
   readonly service: typeof Platform;
@@ -157,6 +175,15 @@ type PlatformgetIdentityByNonUniquePublicKeyHash = {
   readonly responseType: typeof platform_pb.GetIdentityByNonUniquePublicKeyHashResponse;
 };
 
+type PlatformgetIdentityStateTransitions = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetIdentityStateTransitionsRequest;
+  readonly responseType: typeof platform_pb.GetIdentityStateTransitionsResponse;
+};
+
 type PlatformwaitForStateTransitionResult = {
   readonly methodName: string;
   readonly service: typeof Platform;
@@ -166,6 +193,15 @@ type PlatformwaitForStateTransitionResult = {
   readonly responseType: typeof platform_pb.WaitForStateTransitionResultResponse;
 };
 
+type PlatformsimulateStateTransition = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.SimulateStateTransitionRequest;
+  readonly responseType: typeof platform_pb.SimulateStateTransitionResponse;
+};
+
 type PlatformgetConsensusParams = {
   readonly methodName: string;
   readonly service: typeof Platform;
@@ -211,6 +247,15 @@ type PlatformgetFinalizedEpochInfos = {
   readonly responseType: typeof platform_pb.GetFinalizedEpochInfosResponse;
 };
 
+type PlatformgetBlockStateTransitions = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetBlockStateTransitionsRequest;
+  readonly responseType: typeof platform_pb.GetBlockStateTransitionsResponse;
+};
+
 type PlatformgetContestedResources = {
   readonly methodName: string;
   readonly service: typeof Platform;
@@ -256,6 +301,15 @@ type PlatformgetVotePollsByEndDate = {
   readonly responseType: typeof platform_pb.GetVotePollsByEndDateResponse;
 };
 
+type PlatformgetYesNoAbstainVotePollState = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetYesNoAbstainVotePollStateRequest;
+  readonly responseType: typeof platform_pb.GetYesNoAbstainVotePollStateResponse;
+};
+
 type PlatformgetPrefundedSpecializedBalance = {
   readonly methodName: string;
   readonly service: typeof Platform;
@@ -391,6 +445,51 @@ type PlatformgetTokenTotalSupply = {
   readonly responseType: typeof platform_pb.GetTokenTotalSupplyResponse;
 };
 
+type PlatformgetTokenHolders = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetTokenHoldersRequest;
+  readonly responseType: typeof platform_pb.GetTokenHoldersResponse;
+};
+
+type PlatformgetTokenOrderBook = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetTokenOrderBookRequest;
+  readonly responseType: typeof platform_pb.GetTokenOrderBookResponse;
+};
+
+type PlatformgetTokenVestings = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetTokenVestingsRequest;
+  readonly responseType: typeof platform_pb.GetTokenVestingsResponse;
+};
+
+type PlatformgetTokenAllowances = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetTokenAllowancesRequest;
+  readonly responseType: typeof platform_pb.GetTokenAllowancesResponse;
+};
+
+type PlatformgetTokenEvents = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetTokenEventsRequest;
+  readonly responseType: typeof platform_pb.GetTokenEventsResponse;
+};
+
 type PlatformgetGroupInfo = {
   readonly methodName: string;
   readonly service: typeof Platform;
@@ -444,19 +543,25 @@ export class Platform {
   static readonly getDataContractHistory: PlatformgetDataContractHistory;
   static readonly getDataContracts: PlatformgetDataContracts;
   static readonly getDocuments: PlatformgetDocuments;
+  static readonly getDocumentsAggregate: PlatformgetDocumentsAggregate;
+  static readonly subscribeDocuments: PlatformsubscribeDocuments;
   static readonly getIdentityByPublicKeyHash: PlatformgetIdentityByPublicKeyHash;
   static readonly getIdentityByNonUniquePublicKeyHash: PlatformgetIdentityByNonUniquePublicKeyHash;
+  static readonly getIdentityStateTransitions: PlatformgetIdentityStateTransitions;
   static readonly waitForStateTransitionResult: PlatformwaitForStateTransitionResult;
+  static readonly simulateStateTransition: PlatformsimulateStateTransition;
   static readonly getConsensusParams: PlatformgetConsensusParams;
   static readonly getProtocolVersionUpgradeState: PlatformgetProtocolVersionUpgradeState;
   static readonly getProtocolVersionUpgradeVoteStatus: PlatformgetProtocolVersionUpgradeVoteStatus;
   static readonly getEpochsInfo: PlatformgetEpochsInfo;
   static readonly getFinalizedEpochInfos: PlatformgetFinalizedEpochInfos;
+  static readonly getBlockStateTransitions: PlatformgetBlockStateTransitions;
   static readonly getContestedResources: PlatformgetContestedResources;
   static readonly getContestedResourceVoteState: PlatformgetContestedResourceVoteState;
   static readonly getContestedResourceVotersForIdentity: PlatformgetContestedResourceVotersForIdentity;
   static readonly getContestedResourceIdentityVotes: PlatformgetContestedResourceIdentityVotes;
   static readonly getVotePollsByEndDate: PlatformgetVotePollsByEndDate;
+  static readonly getYesNoAbstainVotePollState: PlatformgetYesNoAbstainVotePollState;
   static readonly getPrefundedSpecializedBalance: PlatformgetPrefundedSpecializedBalance;
   static readonly getTotalCreditsInPlatform: PlatformgetTotalCreditsInPlatform;
   static readonly getPathElements: PlatformgetPathElements;
@@ -472,6 +577,11 @@ export class Platform {
   static readonly getTokenPreProgrammedDistributions: PlatformgetTokenPreProgrammedDistributions;
   static readonly getTokenPerpetualDistributionLastClaim: PlatformgetTokenPerpetualDistributionLastClaim;
   static readonly getTokenTotalSupply: PlatformgetTokenTotalSupply;
+  static readonly getTokenHolders: PlatformgetTokenHolders;
+  static readonly getTokenOrderBook: PlatformgetTokenOrderBook;
+  static readonly getTokenVestings: PlatformgetTokenVestings;
+  static readonly getTokenAllowances: PlatformgetTokenAllowances;
+  static readonly getTokenEvents: PlatformgetTokenEvents;
   static readonly getGroupInfo: PlatformgetGroupInfo;
   static readonly getGroupInfos: PlatformgetGroupInfos;
   static readonly getGroupActions: PlatformgetGroupActions;
@@ -645,6 +755,16 @@ export class PlatformClient {
     requestMessage: platform_pb.GetDocumentsRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.GetDocumentsResponse|null) => void
   ): UnaryResponse;
+  getDocumentsAggregate(
+    requestMessage: platform_pb.GetDocumentsAggregateRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetDocumentsAggregateResponse|null) => void
+  ): UnaryResponse;
+  getDocumentsAggregate(
+    requestMessage: platform_pb.GetDocumentsAggregateRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetDocumentsAggregateResponse|null) => void
+  ): UnaryResponse;
+  subscribeDocuments(requestMessage: platform_pb.SubscribeDocumentsRequest, metadata?: grpc.Metadata): ResponseStream<platform_pb.SubscribeDocumentsResponse>;
   getIdentityByPublicKeyHash(
     requestMessage: platform_pb.GetIdentityByPublicKeyHashRequest,
     metadata: grpc.Metadata,
@@ -663,6 +783,15 @@ export class PlatformClient {
     requestMessage: platform_pb.GetIdentityByNonUniquePublicKeyHashRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityByNonUniquePublicKeyHashResponse|null) => void
   ): UnaryResponse;
+  getIdentityStateTransitions(
+    requestMessage: platform_pb.GetIdentityStateTransitionsRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityStateTransitionsResponse|null) => void
+  ): UnaryResponse;
+  getIdentityStateTransitions(
+    requestMessage: platform_pb.GetIdentityStateTransitionsRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityStateTransitionsResponse|null) => void
+  ): UnaryResponse;
   waitForStateTransitionResult(
     requestMessage: platform_pb.WaitForStateTransitionResultRequest,
     metadata: grpc.Metadata,
@@ -672,6 +801,15 @@ export class PlatformClient {
     requestMessage: platform_pb.WaitForStateTransitionResultRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.WaitForStateTransitionResultResponse|null) => void
   ): UnaryResponse;
+  simulateStateTransition(
+    requestMessage: platform_pb.SimulateStateTransitionRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.SimulateStateTransitionResponse|null) => void
+  ): UnaryResponse;
+  simulateStateTransition(
+    requestMessage: platform_pb.SimulateStateTransitionRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.SimulateStateTransitionResponse|null) => void
+  ): UnaryResponse;
   getConsensusParams(
     requestMessage: platform_pb.GetConsensusParamsRequest,
     metadata: grpc.Metadata,
@@ -717,6 +855,15 @@ export class PlatformClient {
     requestMessage: platform_pb.GetFinalizedEpochInfosRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.GetFinalizedEpochInfosResponse|null) => void
   ): UnaryResponse;
+  getBlockStateTransitions(
+    requestMessage: platform_pb.GetBlockStateTransitionsRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetBlockStateTransitionsResponse|null) => void
+  ): UnaryResponse;
+  getBlockStateTransitions(
+    requestMessage: platform_pb.GetBlockStateTransitionsRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetBlockStateTransitionsResponse|null) => void
+  ): UnaryResponse;
   getContestedResources(
     requestMessage: platform_pb.GetContestedResourcesRequest,
     metadata: grpc.Metadata,
@@ -762,6 +909,15 @@ export class PlatformClient {
     requestMessage: platform_pb.GetVotePollsByEndDateRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.GetVotePollsByEndDateResponse|null) => void
   ): UnaryResponse;
+  getYesNoAbstainVotePollState(
+    requestMessage: platform_pb.GetYesNoAbstainVotePollStateRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetYesNoAbstainVotePollStateResponse|null) => void
+  ): UnaryResponse;
+  getYesNoAbstainVotePollState(
+    requestMessage: platform_pb.GetYesNoAbstainVotePollStateRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetYesNoAbstainVotePollStateResponse|null) => void
+  ): UnaryResponse;
   getPrefundedSpecializedBalance(
     requestMessage: platform_pb.GetPrefundedSpecializedBalanceRequest,
     metadata: grpc.Metadata,
@@ -897,6 +1053,51 @@ export class PlatformClient {
     requestMessage: platform_pb.GetTokenTotalSupplyRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.GetTokenTotalSupplyResponse|null) => void
   ): UnaryResponse;
+  getTokenHolders(
+    requestMessage: platform_pb.GetTokenHoldersRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetTokenHoldersResponse|null) => void
+  ): UnaryResponse;
+  getTokenHolders(
+    requestMessage: platform_pb.GetTokenHoldersRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetTokenHoldersResponse|null) => void
+  ): UnaryResponse;
+  getTokenOrderBook(
+    requestMessage: platform_pb.GetTokenOrderBookRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetTokenOrderBookResponse|null) => void
+  ): UnaryResponse;
+  getTokenOrderBook(
+    requestMessage: platform_pb.GetTokenOrderBookRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetTokenOrderBookResponse|null) => void
+  ): UnaryResponse;
+  getTokenVestings(
+    requestMessage: platform_pb.GetTokenVestingsRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetTokenVestingsResponse|null) => void
+  ): UnaryResponse;
+  getTokenVestings(
+    requestMessage: platform_pb.GetTokenVestingsRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetTokenVestingsResponse|null) => void
+  ): UnaryResponse;
+  getTokenAllowances(
+    requestMessage: platform_pb.GetTokenAllowancesRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetTokenAllowancesResponse|null) => void
+  ): UnaryResponse;
+  getTokenAllowances(
+    requestMessage: platform_pb.GetTokenAllowancesRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetTokenAllowancesResponse|null) => void
+  ): UnaryResponse;
+  getTokenEvents(
+    requestMessage: platform_pb.GetTokenEventsRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetTokenEventsResponse|null) => void
+  ): UnaryResponse;
+  getTokenEvents(
+    requestMessage: platform_pb.GetTokenEventsRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetTokenEventsResponse|null) => void
+  ): UnaryResponse;
   getGroupInfo(
     requestMessage: platform_pb.GetGroupInfoRequest,
     metadata: grpc.Metadata,
@@ -145,6 +145,24 @@ Platform.getDocuments = {
   responseType: platform_pb.GetDocumentsResponse
 };
 
+Platform.getDocumentsAggregate = {
+  methodName: "getDocumentsAggregate",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetDocumentsAggregateRequest,
+  responseType: platform_pb.GetDocumentsAggregateResponse
+};
+
+Platform.subscribeDocuments = {
+  methodName: "subscribeDocuments",
+  service: Platform,
+  requestStream: false,
+  responseStream: true,
+  requestType: platform_pb.SubscribeDocumentsRequest,
+  responseType: platform_pb.SubscribeDocumentsResponse
+};
+
 Platform.getIdentityByPublicKeyHash = {
   methodName: "getIdentityByPublicKeyHash",
   service: Platform,
@@ -163,6 +181,15 @@ Platform.getIdentityByNonUniquePublicKeyHash = {
   responseType: platform_pb.GetIdentityByNonUniquePublicKeyHashResponse
 };
 
+Platform.getIdentityStateTransitions = {
+  methodName: "getIdentityStateTransitions",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetIdentityStateTransitionsRequest,
+  responseType: platform_pb.GetIdentityStateTransitionsResponse
+};
+
 Platform.waitForStateTransitionResult = {
   methodName: "waitForStateTransitionResult",
   service: Platform,
@@ -172,6 +199,15 @@ Platform.waitForStateTransitionResult = {
   responseType: platform_pb.WaitForStateTransitionResultResponse
 };
 
+Platform.simulateStateTransition = {
+  methodName: "simulateStateTransition",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.SimulateStateTransitionRequest,
+  responseType: platform_pb.SimulateStateTransitionResponse
+};
+
 Platform.getConsensusParams = {
   methodName: "getConsensusParams",
   service: Platform,
@@ -217,6 +253,15 @@ Platform.getFinalizedEpochInfos = {
   responseType: platform_pb.GetFinalizedEpochInfosResponse
 };
 
+Platform.getBlockStateTransitions = {
+  methodName: "getBlockStateTransitions",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetBlockStateTransitionsRequest,
+  responseType: platform_pb.GetBlockStateTransitionsResponse
+};
+
 Platform.getContestedResources = {
   methodName: "getContestedResources",
   service: Platform,
@@ -262,6 +307,15 @@ Platform.getVotePollsByEndDate = {
   responseType: platform_pb.GetVotePollsByEndDateResponse
 };
 
+Platform.getYesNoAbstainVotePollState = {
+  methodName: "getYesNoAbstainVotePollState",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetYesNoAbstainVotePollStateRequest,
+  responseType: platform_pb.GetYesNoAbstainVotePollStateResponse
+};
+
 Platform.getPrefundedSpecializedBalance = {
   methodName: "getPrefundedSpecializedBalance",
   service: Platform,
@@ -397,6 +451,51 @@ Platform.getTokenTotalSupply = {
   responseType: platform_pb.GetTokenTotalSupplyResponse
 };
 
+Platform.getTokenHolders = {
+  methodName: "getTokenHolders",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetTokenHoldersRequest,
+  responseType: platform_pb.GetTokenHoldersResponse
+};
+
+Platform.getTokenOrderBook = {
+  methodName: "getTokenOrderBook",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetTokenOrderBookRequest,
+  responseType: platform_pb.GetTokenOrderBookResponse
+};
+
+Platform.getTokenVestings = {
+  methodName: "getTokenVestings",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetTokenVestingsRequest,
+  responseType: platform_pb.GetTokenVestingsResponse
+};
+
+Platform.getTokenAllowances = {
+  methodName: "getTokenAllowances",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetTokenAllowancesRequest,
+  responseType: platform_pb.GetTokenAllowancesResponse
+};
+
+Platform.getTokenEvents = {
+  methodName: "getTokenEvents",
+  service: Platform,
+  requestStream: false,
+  responseStream: false,
+  requestType: platform_pb.GetTokenEventsRequest,
+  responseType: platform_pb.GetTokenEventsResponse
+};
+
 Platform.getGroupInfo = {
   methodName: "getGroupInfo",
   service: Platform,
@@ -905,6 +1004,76 @@ PlatformClient.prototype.getDocuments = function getDocuments(requestMessage, me
   };
 };
 
+PlatformClient.prototype.getDocumentsAggregate = function getDocumentsAggregate(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getDocumentsAggregate, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
+PlatformClient.prototype.subscribeDocuments = function subscribeDocuments(requestMessage, metadata) {
+  var listeners = {
+    data: [],
+    end: [],
+    status: []
+  };
+  var client = grpc.invoke(Platform.subscribeDocuments, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onMessage: function (responseMessage) {
+      listeners.data.forEach(function (handler) {
+        handler(responseMessage);
+      });
+    },
+    onEnd: function (status, statusMessage, trailers) {
+      listeners.status.forEach(function (handler) {
+        handler({ code: status, details: statusMessage, metadata: trailers });
+      });
+      listeners.end.forEach(function (handler) {
+        handler({ code: status, details: statusMessage, metadata: trailers });
+      });
+      listeners = null;
+    }
+  });
+  return {
+    on: function (type, handler) {
+      listeners[type].push(handler);
+      return this;
+    },
+    cancel: function () {
+      listeners = null;
+      client.close();
+    }
+  };
+};
+
 PlatformClient.prototype.getIdentityByPublicKeyHash = function getIdentityByPublicKeyHash(requestMessage, metadata, callback) {
   if (arguments.length === 2) {
     callback = arguments[1];
@@ -967,6 +1136,37 @@ PlatformClient.prototype.getIdentityByNonUniquePublicKeyHash = function getIdent
   };
 };
 
+PlatformClient.prototype.getIdentityStateTransitions = function getIdentityStateTransitions(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getIdentityStateTransitions, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
 PlatformClient.prototype.waitForStateTransitionResult = function waitForStateTransitionResult(requestMessage, metadata, callback) {
   if (arguments.length === 2) {
     callback = arguments[1];
@@ -998,6 +1198,37 @@ PlatformClient.prototype.waitForStateTransitionResult = function waitForStateTra
   };
 };
 
+PlatformClient.prototype.simulateStateTransition = function simulateStateTransition(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.simulateStateTransition, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
 PlatformClient.prototype.getConsensusParams = function getConsensusParams(requestMessage, metadata, callback) {
   if (arguments.length === 2) {
     callback = arguments[1];
@@ -1153,6 +1384,37 @@ PlatformClient.prototype.getFinalizedEpochInfos = function getFinalizedEpochInfo
   };
 };
 
+PlatformClient.prototype.getBlockStateTransitions = function getBlockStateTransitions(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getBlockStateTransitions, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
 PlatformClient.prototype.getContestedResources = function getContestedResources(requestMessage, metadata, callback) {
   if (arguments.length === 2) {
     callback = arguments[1];
@@ -1308,6 +1570,37 @@ PlatformClient.prototype.getVotePollsByEndDate = function getVotePollsByEndDate(
   };
 };
 
+PlatformClient.prototype.getYesNoAbstainVotePollState = function getYesNoAbstainVotePollState(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getYesNoAbstainVotePollState, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
 PlatformClient.prototype.getPrefundedSpecializedBalance = function getPrefundedSpecializedBalance(requestMessage, metadata, callback) {
   if (arguments.length === 2) {
     callback = arguments[1];
@@ -1773,6 +2066,161 @@ PlatformClient.prototype.getTokenTotalSupply = function getTokenTotalSupply(requ
   };
 };
 
+PlatformClient.prototype.getTokenHolders = function getTokenHolders(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getTokenHolders, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
+PlatformClient.prototype.getTokenOrderBook = function getTokenOrderBook(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getTokenOrderBook, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
+PlatformClient.prototype.getTokenVestings = function getTokenVestings(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getTokenVestings, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
+PlatformClient.prototype.getTokenAllowances = function getTokenAllowances(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getTokenAllowances, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
+PlatformClient.prototype.getTokenEvents = function getTokenEvents(requestMessage, metadata, callback) {
+  if (arguments.length === 2) {
+    callback = arguments[1];
+  }
+  var client = grpc.unary(Platform.getTokenEvents, {
+    request: requestMessage,
+    host: this.serviceHost,
+    metadata: metadata,
+    transport: this.options.transport,
+    debug: this.options.debug,
+    onEnd: function (response) {
+      if (callback) {
+        if (response.status !== grpc.Code.OK) {
+          var err = new Error(response.statusMessage);
+          err.code = response.status;
+          err.metadata = response.trailers;
+          callback(err, null);
+        } else {
+          callback(null, response.message);
+        }
+      }
+    }
+  });
+  return {
+    cancel: function () {
+      callback = null;
+      client.close();
+    }
+  };
+};
+
 PlatformClient.prototype.getGroupInfo = function getGroupInfo(requestMessage, metadata, callback) {
   if (arguments.length === 2) {
     callback = arguments[1];
@@ -2,7 +2,7 @@
 
 use crate::Uri;
 use chrono::Utc;
-use rand::{rngs::SmallRng, seq::IteratorRandom, SeedableRng};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
@@ -13,6 +13,22 @@ use std::time::Duration;
 
 const DEFAULT_BASE_BAN_PERIOD: Duration = Duration::from_secs(60);
 
+/// Latency assumed for addresses without any recorded requests.
+///
+/// It's optimistic, so new addresses get a fair chance to be selected and scored.
+const DEFAULT_LATENCY: Duration = Duration::from_millis(250);
+/// Lowest latency used for weighting, so a single very fast response doesn't starve other addresses.
+const MIN_LATENCY: Duration = Duration::from_millis(1);
+/// Smoothing factor of the latency moving average.
+const LATENCY_EWMA_ALPHA: f64 = 0.3;
+/// Smoothing factor of the error rate moving average.
+const ERROR_RATE_EWMA_ALPHA: f64 = 0.2;
+/// How much the error rate reduces selection weight of an address.
+///
+/// An address failing every request is selected as often as a healthy address with
+/// `1 + ERROR_RATE_PENALTY` times higher latency.
+const ERROR_RATE_PENALTY: f64 = 10.0;
+
 /// DAPI address.
 #[derive(Debug, Clone, Eq)]
 #[cfg_attr(feature = "mocks", derive(serde::Serialize, serde::Deserialize))]
@@ -68,11 +84,31 @@ impl Address {
 }
 
 /// Address status
-/// Contains information about the number of bans and the time until the next ban is lifted.
+/// Contains information about the number of bans, the time until the next ban is lifted,
+/// and the address health used for weighted selection.
 #[derive(Debug, Default, Clone)]
 pub struct AddressStatus {
     ban_count: usize,
     banned_until: Option<chrono::DateTime<Utc>>,
+    /// Exponentially weighted moving average of response latency
+    latency: Option<Duration>,
+    /// Exponentially weighted moving average of failed requests, between 0 and 1
+    error_rate: f64,
+    /// Number of recorded requests
+    requests: u64,
+}
+
+/// Health score of an [Address].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AddressScore {
+    /// Moving average of response latency, if any request was recorded.
+    pub latency: Option<Duration>,
+    /// Moving average of failed requests, between 0 (healthy) and 1 (always failing).
+    pub error_rate: f64,
+    /// Number of recorded requests.
+    pub requests: u64,
+    /// Relative weight used to select the address; higher is better.
+    pub weight: f64,
 }
 
 impl AddressStatus {
@@ -95,6 +131,43 @@ impl AddressStatus {
         self.ban_count = 0;
         self.banned_until = None;
     }
+
+    /// Record a request the node responded to.
+    pub fn record_success(&mut self, latency: Duration) {
+        self.latency = Some(match self.latency {
+            Some(average) => Duration::from_secs_f64(
+                LATENCY_EWMA_ALPHA * latency.as_secs_f64()
+                    + (1.0 - LATENCY_EWMA_ALPHA) * average.as_secs_f64(),
+            ),
+            None => latency,
+        });
+        self.error_rate *= 1.0 - ERROR_RATE_EWMA_ALPHA;
+        self.requests += 1;
+    }
+
+    /// Record a request the node failed to respond to.
+    pub fn record_failure(&mut self) {
+        self.error_rate = ERROR_RATE_EWMA_ALPHA + (1.0 - ERROR_RATE_EWMA_ALPHA) * self.error_rate;
+        self.requests += 1;
+    }
+
+    /// Health score of the [Address].
+    pub fn score(&self) -> AddressScore {
+        let latency = self.latency.unwrap_or(DEFAULT_LATENCY).max(MIN_LATENCY);
+
+        AddressScore {
+            latency: self.latency,
+            error_rate: self.error_rate,
+            requests: self.requests,
+            weight: 1.0 / (latency.as_secs_f64() * (1.0 + ERROR_RATE_PENALTY * self.error_rate)),
+        }
+    }
+
+    fn is_live(&self, now: chrono::DateTime<Utc>) -> bool {
+        self.banned_until
+            .map(|banned_until| banned_until < now)
+            .unwrap_or(true)
+    }
 }
 
 /// [AddressList] errors
@@ -169,6 +242,52 @@ impl AddressList {
         true
     }
 
+    /// Record a request the address responded to, updating its score.
+    /// Returns false if the address is not in the list.
+    pub fn record_success(&self, address: &Address, latency: Duration) -> bool {
+        let mut guard = self.addresses.write().unwrap();
+
+        let Some(status) = guard.get_mut(address) else {
+            return false;
+        };
+
+        status.record_success(latency);
+
+        true
+    }
+
+    /// Record a request the address failed to respond to, updating its score.
+    /// Returns false if the address is not in the list.
+    pub fn record_failure(&self, address: &Address) -> bool {
+        let mut guard = self.addresses.write().unwrap();
+
+        let Some(status) = guard.get_mut(address) else {
+            return false;
+        };
+
+        status.record_failure();
+
+        true
+    }
+
+    /// Get health score of the address.
+    /// Returns [None] if the address is not in the list.
+    pub fn score(&self, address: &Address) -> Option<AddressScore> {
+        let guard = self.addresses.read().unwrap();
+
+        guard.get(address).map(|status| status.score())
+    }
+
+    /// Get health scores of all addresses, both banned and not banned.
+    pub fn scores(&self) -> Vec<(Address, AddressScore)> {
+        let guard = self.addresses.read().unwrap();
+
+        guard
+            .iter()
+            .map(|(address, status)| (address.clone(), status.score()))
+            .collect()
+    }
+
     /// Check if the address is banned.
     pub fn is_banned(&self, address: &Address) -> bool {
         let guard = self.addresses.read().unwrap();
@@ -211,23 +330,56 @@ impl AddressList {
     }
 
     /// Randomly select a not banned address.
-    pub fn get_live_address(&self) -> Option<Address> {
+    ///
+    /// Addresses are weighted by their [AddressScore], so fast and healthy nodes are preferred.
+    /// The `excluded` address, like a node that just failed, is only selected if no other
+    /// address is live.
+    pub fn get_live_address(&self, excluded: Option<&Address>) -> Option<Address> {
+        let Some(excluded) = excluded else {
+            return self.get_live_addresses(1).pop();
+        };
+
+        self.get_live_addresses(2)
+            .into_iter()
+            .min_by_key(|address| address == excluded)
+    }
+
+    /// Randomly select up to `count` distinct not banned addresses.
+    ///
+    /// Addresses are weighted by their [AddressScore], so fast and healthy nodes are preferred.
+    pub fn get_live_addresses(&self, count: usize) -> Vec<Address> {
         let guard = self.addresses.read().unwrap();
 
         let mut rng = SmallRng::from_entropy();
 
         let now = chrono::Utc::now();
 
-        guard
+        let mut candidates: Vec<(&Address, f64)> = guard
             .iter()
-            .filter(|(_, status)| {
-                status
-                    .banned_until
-                    .map(|banned_until| banned_until < now)
-                    .unwrap_or(true)
-            })
-            .choose(&mut rng)
-            .map(|(addr, _)| addr.clone())
+            .filter(|(_, status)| status.is_live(now))
+            .map(|(address, status)| (address, status.score().weight))
+            .collect();
+
+        let mut selected = Vec::with_capacity(count.min(candidates.len()));
+
+        while selected.len() < count && !candidates.is_empty() {
+            let total_weight: f64 = candidates.iter().map(|(_, weight)| weight).sum();
+
+            let mut point = rng.gen_range(0.0..total_weight);
+            let index = candidates
+                .iter()
+                .position(|(_, weight)| {
+                    point -= weight;
+                    point < 0.0
+                })
+                // Floating point rounding can leave a tiny remainder
+                .unwrap_or(candidates.len() - 1);
+
+            let (address, _) = candidates.swap_remove(index);
+            selected.push(address.clone());
+        }
+
+        selected
     }
 
     /// Get number of all addresses, both banned and not banned.
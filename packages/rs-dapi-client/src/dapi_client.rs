@@ -1,13 +1,15 @@
 //! [DapiClient] definition.
 
-use backon::{ConstantBuilder, Retryable};
+use backon::{Retryable, Sleeper};
 use dapi_grpc::mock::Mockable;
 use dapi_grpc::tonic::async_trait;
 #[cfg(not(target_arch = "wasm32"))]
 use dapi_grpc::tonic::transport::Certificate;
+use futures::future::{select, Either};
+use futures::FutureExt;
 use std::fmt::{Debug, Display};
 use std::sync::atomic::AtomicUsize;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tracing::Instrument;
 
@@ -17,8 +19,8 @@ use crate::request_settings::AppliedRequestSettings;
 use crate::transport::{self, TransportError};
 use crate::{
     transport::{TransportClient, TransportRequest},
-    AddressList, CanRetry, DapiRequestExecutor, ExecutionError, ExecutionResponse, ExecutionResult,
-    RequestSettings,
+    Address, AddressList, CanRetry, DapiRequestExecutor, ErrorClass, ExecutionError,
+    ExecutionResponse, ExecutionResult, RequestSettings, RetryBackoff,
 };
 
 /// General DAPI request error type.
@@ -55,6 +57,13 @@ impl CanRetry for DapiClientError {
             Mock(_) => false,
         }
     }
+
+    fn error_class(&self) -> ErrorClass {
+        match self {
+            DapiClientError::Transport(transport_error) => transport_error.error_class(),
+            _ => ErrorClass::Permanent,
+        }
+    }
 }
 
 /// Serialization of [DapiClientError].
@@ -83,6 +92,9 @@ pub struct DapiClient {
     pub ca_certificate: Option<Certificate>,
     #[cfg(feature = "dump")]
     pub(crate) dump_dir: Option<std::path::PathBuf>,
+    /// Sequence number of the next dumped request, set when recording a session.
+    #[cfg(feature = "dump")]
+    pub(crate) dump_sequence: Option<Arc<std::sync::atomic::AtomicU64>>,
 }
 
 impl DapiClient {
@@ -97,6 +109,8 @@ impl DapiClient {
             pool: ConnectionPool::new(address_count),
             #[cfg(feature = "dump")]
             dump_dir: None,
+            #[cfg(feature = "dump")]
+            dump_sequence: None,
             #[cfg(not(target_arch = "wasm32"))]
             ca_certificate: None,
         }
@@ -121,6 +135,100 @@ impl DapiClient {
     pub fn address_list(&self) -> &AddressList {
         &self.address_list
     }
+
+    /// Execute the request on the address and record the outcome in the address score.
+    async fn execute_on_address<R>(
+        &self,
+        request: R,
+        address: &Address,
+        settings: &AppliedRequestSettings,
+    ) -> Result<R::Response, DapiClientError>
+    where
+        R: TransportRequest,
+    {
+        let mut transport_client =
+            R::Client::with_uri_and_settings(address.uri().clone(), settings, &self.pool)
+                .map_err(DapiClientError::Transport)?;
+
+        let started_at = chrono::Utc::now();
+
+        let result = request
+            .execute_transport(&mut transport_client, settings)
+            .await
+            .map_err(DapiClientError::Transport);
+
+        let latency = (chrono::Utc::now() - started_at)
+            .to_std()
+            .unwrap_or_default();
+
+        // Errors that can't be retried are valid responses from the node
+        match &result {
+            Err(error) if error.can_retry() => self.address_list.record_failure(address),
+            _ => self.address_list.record_success(address, latency),
+        };
+
+        result
+    }
+
+    /// Execute the request on the address, and if it doesn't respond within `hedge_delay`,
+    /// on another live address too.
+    ///
+    /// Returns the first successful response, together with the address that sent it.
+    async fn execute_hedged<R>(
+        &self,
+        request: R,
+        address: Address,
+        hedge_delay: Duration,
+        settings: &AppliedRequestSettings,
+    ) -> (Address, Result<R::Response, DapiClientError>)
+    where
+        R: TransportRequest,
+    {
+        let primary = self
+            .execute_on_address(request.clone(), &address, settings)
+            .boxed();
+        let delay = transport::BackonSleeper::default().sleep(hedge_delay);
+
+        let primary = match select(primary, Box::pin(delay)).await {
+            Either::Left((result, _)) => return (address, result),
+            Either::Right((_, primary)) => primary,
+        };
+
+        let Some(hedge_address) = self
+            .address_list
+            .get_live_addresses(2)
+            .into_iter()
+            .find(|hedge_address| hedge_address != &address)
+        else {
+            return (address, primary.await);
+        };
+
+        tracing::trace!(
+            %address,
+            %hedge_address,
+            "no response from {address} after {} secs, hedging {} request",
+            hedge_delay.as_secs_f32(),
+            request.method_name(),
+        );
+
+        let hedge = self
+            .execute_on_address(request, &hedge_address, settings)
+            .boxed();
+
+        // Use whichever response comes first, unless it's an error the other node may not return
+        match select(primary, hedge).await {
+            Either::Left((Err(error), hedge)) if error.can_retry() => {
+                tracing::debug!(?error, %address, "hedged request failed: {error}");
+                (hedge_address, hedge.await)
+            }
+            Either::Left((result, _)) => (address, result),
+            Either::Right((Err(error), primary)) if error.can_retry() => {
+                tracing::debug!(?error, address = %hedge_address, "hedged request failed: {error}");
+                (address, primary.await)
+            }
+            Either::Right((result, _)) => (hedge_address, result),
+        }
+    }
 }
 
 /// Ban address in case of retryable error or unban it
@@ -209,19 +317,26 @@ impl DapiRequestExecutor for DapiClient {
         let applied_settings = applied_settings.with_ca_certificate(self.ca_certificate.clone());
 
         // Setup retry policy:
-        let retry_settings = ConstantBuilder::default()
-            .with_max_times(applied_settings.retries)
-            .with_delay(Duration::from_millis(10));
+        let retry_backoff =
+            RetryBackoff::new(applied_settings.retry_policy, applied_settings.retries);
+        let retry_decision = retry_backoff.clone();
 
         // Save dump dir for later use, as self is moved into routine
         #[cfg(feature = "dump")]
         let dump_dir = self.dump_dir.clone();
         #[cfg(feature = "dump")]
+        let dump_sequence = self.dump_sequence.clone();
+        #[cfg(feature = "dump")]
         let dump_request = request.clone();
 
         let retries_counter_arc = Arc::new(AtomicUsize::new(0));
         let retries_counter_arc_ref = &retries_counter_arc;
 
+        // Address of the last failed attempt, skipped when the failure was node specific
+        let failed_address: Mutex<Option<Address>> = Mutex::new(None);
+        let failed_address_ref = &failed_address;
+        let retry_decision_ref = &retry_decision;
+
         // We need reference so that the closure is FnMut
         let applied_settings_ref = &applied_settings;
 
@@ -230,10 +345,19 @@ impl DapiRequestExecutor for DapiClient {
         let routine = move || {
             let retries_counter = Arc::clone(retries_counter_arc_ref);
 
+            let excluded_address = if retry_decision_ref.switches_node() {
+                failed_address_ref
+                    .lock()
+                    .expect("failed address lock poisoned")
+                    .clone()
+            } else {
+                None
+            };
+
             // Try to get an address to initialize transport on:
             let address_result = self
                 .address_list
-                .get_live_address()
+                .get_live_address(excluded_address.as_ref())
                 .ok_or(DapiClientError::NoAvailableAddresses);
 
             let _span = tracing::trace_span!(
@@ -265,23 +389,59 @@ impl DapiRequestExecutor for DapiClient {
                     address: None,
                 })?;
 
-                let pool = self.pool.clone();
-
-                let mut transport_client = R::Client::with_uri_and_settings(
-                    address.uri().clone(),
-                    applied_settings_ref,
-                    &pool,
-                )
-                .map_err(|error| ExecutionError {
-                    inner: DapiClientError::Transport(error),
-                    retries: retries_counter.load(std::sync::atomic::Ordering::Relaxed),
-                    address: Some(address.clone()),
-                })?;
-
-                let result = transport_request
-                    .execute_transport(&mut transport_client, applied_settings_ref)
-                    .await
-                    .map_err(DapiClientError::Transport);
+                let attempt = async {
+                    match applied_settings_ref.hedge_delay {
+                        Some(hedge_delay) if R::READ_ONLY => {
+                            self.execute_hedged(
+                                transport_request,
+                                address.clone(),
+                                hedge_delay,
+                                applied_settings_ref,
+                            )
+                            .await
+                        }
+                        _ => {
+                            let result = self
+                                .execute_on_address(
+                                    transport_request,
+                                    &address,
+                                    applied_settings_ref,
+                                )
+                                .await;
+
+                            (address.clone(), result)
+                        }
+                    }
+                };
+
+                // Don't let an attempt that hangs run past the deadline of the retry policy
+                let (address, result) = match retry_decision_ref.remaining() {
+                    Some(remaining) => {
+                        let timeout = transport::BackonSleeper::default().sleep(remaining);
+
+                        match select(Box::pin(attempt), Box::pin(timeout)).await {
+                            Either::Left((outcome, _)) => outcome,
+                            Either::Right(_) => {
+                                tracing::debug!(
+                                    %address,
+                                    "request deadline exceeded, cancelling {} request",
+                                    response_name
+                                );
+                                self.address_list.record_failure(&address);
+
+                                (
+                                    address.clone(),
+                                    Err(DapiClientError::Transport(TransportError::Grpc(
+                                        dapi_grpc::tonic::Status::deadline_exceeded(
+                                            "request deadline exceeded",
+                                        ),
+                                    ))),
+                                )
+                            }
+                        }
+                    }
+                    None => attempt.await,
+                };
 
                 let retries = retries_counter.load(std::sync::atomic::Ordering::Relaxed);
 
@@ -311,6 +471,12 @@ impl DapiRequestExecutor for DapiClient {
                     applied_settings_ref,
                 );
 
+                if let Err(error) = &execution_result {
+                    *failed_address_ref
+                        .lock()
+                        .expect("failed address lock poisoned") = error.address.clone();
+                }
+
                 execution_result
             }
         };
@@ -323,7 +489,7 @@ impl DapiRequestExecutor for DapiClient {
             ExecutionResponse<<R as TransportRequest>::Response>,
             ExecutionError<DapiClientError>,
         > = routine
-            .retry(retry_settings)
+            .retry(retry_backoff)
             .sleep(sleeper)
             .notify(|error, duration| {
                 let retries_counter = Arc::clone(&retries_counter_arc);
@@ -335,7 +501,7 @@ impl DapiRequestExecutor for DapiClient {
                     duration.as_secs_f32()
                 );
             })
-            .when(|e| e.can_retry())
+            .when(|e| retry_decision.should_retry(e))
             .instrument(tracing::info_span!("request routine"))
             .await;
 
@@ -347,7 +513,7 @@ impl DapiRequestExecutor for DapiClient {
 
         // Dump request and response to disk if dump_dir is set:
         #[cfg(feature = "dump")]
-        Self::dump_request_response(&dump_request, &result, dump_dir);
+        Self::dump_request_response(&dump_request, &result, dump_dir, dump_sequence);
 
         result
     }
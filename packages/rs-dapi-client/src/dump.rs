@@ -7,7 +7,14 @@ use crate::{
     transport::TransportRequest,
     DapiClient,
 };
-use std::{any::type_name, path::PathBuf};
+use std::{
+    any::type_name,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
 
 /// Data format of dumps created with [DapiClient::dump_dir].
 #[derive(Clone)]
@@ -129,6 +136,17 @@ impl<T: TransportRequest> DumpData<T> {
         Ok(file)
     }
 
+    /// Generate unique filename for this dump, recorded as part of a session.
+    ///
+    /// Same as [DumpData::filename()], with `sequence` number of the request in the session appended,
+    /// so that responses to repeated requests don't overwrite each other.
+    pub fn session_filename(&self, sequence: u64) -> Result<String, std::io::Error> {
+        let filename = self.filename()?;
+        let basename = filename.trim_end_matches(".json");
+
+        Ok(format!("{}_{:08}.json", basename, sequence))
+    }
+
     /// Load dump data from file.
     pub fn load<P: AsRef<std::path::Path>>(file: P) -> Result<Self, std::io::Error>
     where
@@ -165,6 +183,22 @@ impl DapiClient {
     /// Prefix of dump files.
     pub const DUMP_FILE_PREFIX: &'static str = "msg";
 
+    /// Parse sequence number from a filename generated by [DumpData::session_filename()].
+    ///
+    /// Returns `None` if the file was not recorded as part of a session.
+    pub fn dump_session_sequence(filename: &str) -> Option<u64> {
+        let basename = filename.strip_suffix(".json")?;
+        let mut parts = basename.split('_');
+
+        // prefix, request type, key, sequence
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(_), Some(_), Some(_), Some(sequence)) if parts.next().is_none() => {
+                sequence.parse().ok()
+            }
+            _ => None,
+        }
+    }
+
     /// Define directory where dumps of all traffic will be saved.
     ///
     /// Each request and response pair will be saved to a JSON file in `dump_dir`.
@@ -181,6 +215,18 @@ impl DapiClient {
         self
     }
 
+    /// Record all traffic of the session to `dump_dir`.
+    ///
+    /// Works like [DapiClient::dump_dir()], but keeps every response, even if the same request is sent
+    /// multiple times. Dump file name is generated by [DumpData::session_filename()], so that
+    /// [MockDapiClient](crate::mock::MockDapiClient) can serve the responses back in the same order.
+    pub fn record_session(mut self, dump_dir: PathBuf) -> Self {
+        self.dump_dir = Some(dump_dir);
+        self.dump_sequence = Some(Arc::new(AtomicU64::new(0)));
+
+        self
+    }
+
     /// Save dump of request and response to disk.
     ///
     /// Any errors are logged on `warn` level and ignored.
@@ -188,6 +234,7 @@ impl DapiClient {
         request: &R,
         response: &MockResult<R>,
         dump_dir: Option<PathBuf>,
+        dump_sequence: Option<Arc<AtomicU64>>,
     ) where
         <R as TransportRequest>::Response: Mockable,
     {
@@ -199,7 +246,11 @@ impl DapiClient {
         let data = DumpData::new(request, response);
 
         // Construct file name
-        let filename = match data.filename() {
+        let filename = match dump_sequence {
+            Some(sequence) => data.session_filename(sequence.fetch_add(1, Ordering::SeqCst)),
+            None => data.filename(),
+        };
+        let filename = match filename {
             Ok(f) => f,
             Err(e) => return tracing::warn!("unable to create dump file name: {}", e),
         };
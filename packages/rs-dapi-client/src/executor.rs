@@ -1,5 +1,5 @@
 use crate::transport::TransportRequest;
-use crate::{Address, CanRetry, DapiClientError, RequestSettings};
+use crate::{Address, CanRetry, DapiClientError, ErrorClass, RequestSettings};
 use dapi_grpc::mock::Mockable;
 use dapi_grpc::tonic::async_trait;
 use std::fmt::Debug;
@@ -79,6 +79,10 @@ impl<E: CanRetry> CanRetry for ExecutionError<E> {
     fn can_retry(&self) -> bool {
         self.inner.can_retry()
     }
+
+    fn error_class(&self) -> ErrorClass {
+        self.inner.error_class()
+    }
 }
 
 /// Request execution response.
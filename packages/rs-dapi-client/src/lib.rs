@@ -11,11 +11,13 @@ mod executor;
 #[cfg(feature = "mocks")]
 pub mod mock;
 mod request_settings;
+mod retry_policy;
 pub mod transport;
 
 pub use address_list::Address;
 pub use address_list::AddressList;
 pub use address_list::AddressListError;
+pub use address_list::AddressScore;
 pub use address_list::AddressStatus;
 pub use connection_pool::ConnectionPool;
 pub use dapi_client::{update_address_ban_status, DapiClient, DapiClientError};
@@ -31,6 +33,7 @@ pub use http::Uri;
 #[cfg(all(feature = "mocks", not(target_arch = "wasm32")))]
 pub use http_serde::http::Uri;
 pub use request_settings::RequestSettings;
+pub use retry_policy::{ErrorClass, RetryBackoff, RetryDecision, RetryPolicy};
 
 /// A DAPI request could be executed with an initialized [DapiClient].
 ///
@@ -85,6 +88,18 @@ pub trait CanRetry {
     /// Returns true if the operation can be retried safely.
     fn can_retry(&self) -> bool;
 
+    /// Classify the failure for [RetryPolicy].
+    ///
+    /// Defaults to [ErrorClass::NodeFailure] for errors that can be retried,
+    /// and [ErrorClass::Permanent] otherwise.
+    fn error_class(&self) -> ErrorClass {
+        if self.can_retry() {
+            ErrorClass::NodeFailure
+        } else {
+            ErrorClass::Permanent
+        }
+    }
+
     /// Get boolean flag that indicates if the error is retryable.
     ///
     /// Depreacted in favor of [CanRetry::can_retry].
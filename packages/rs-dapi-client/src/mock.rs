@@ -23,6 +23,7 @@ use std::{
     any::type_name,
     collections::HashMap,
     fmt::{Debug, Display},
+    sync::atomic::{AtomicUsize, Ordering},
 };
 
 /// Mock DAPI client.
@@ -61,11 +62,35 @@ impl MockDapiClient {
         Ok(self)
     }
 
+    /// Add next expected result for a request.
+    ///
+    /// Results of the same request are returned in the order they were added;
+    /// the last one is returned for all subsequent requests.
+    pub fn expect_next<R>(&mut self, request: &R, result: &MockResult<R>) -> &mut Self
+    where
+        R: TransportRequest + Mockable,
+        R::Response: Mockable,
+    {
+        let key = self.expectations.add_next(request, result);
+
+        tracing::trace!(
+            %key,
+            request_type = std::any::type_name::<R>(),
+            response_type = std::any::type_name::<R::Response>(),
+            "mock added next expectation"
+        );
+
+        self
+    }
+
     /// Load expectation from file.
     ///
     /// The file must contain JSON structure.
     /// See [DumpData](crate::DumpData) and [DapiClient::dump_dir()](crate::DapiClient::dump_dir()) more for details.
     ///
+    /// Files recorded with [DapiClient::record_session()](crate::DapiClient::record_session()) are added
+    /// with [MockDapiClient::expect_next()], so they must be loaded in the order of their sequence numbers.
+    ///
     /// # Panics
     ///
     /// Panics if the file can't be read or the data can't be parsed.
@@ -78,7 +103,12 @@ impl MockDapiClient {
         T: TransportRequest + Mockable,
         T::Response: Mockable,
     {
-        use crate::DumpData;
+        use crate::{DapiClient, DumpData};
+
+        let session_sequence = file
+            .as_ref()
+            .file_name()
+            .and_then(|name| DapiClient::dump_session_sequence(&name.to_string_lossy()));
 
         let buf = std::fs::read(file)?;
         let data = DumpData::<T>::mock_deserialize(&buf).ok_or({
@@ -86,6 +116,11 @@ impl MockDapiClient {
         })?;
 
         let (request, response) = data.deserialize();
+        if session_sequence.is_some() {
+            self.expect_next(&request, &response);
+            return Ok((request, response));
+        }
+
         self.expect(&request, &response).map_err(|e| {
             std::io::Error::new(
                 std::io::ErrorKind::InvalidInput,
@@ -212,10 +247,29 @@ impl ExpectedResult {
     }
 }
 
+#[derive(Default, Debug)]
+/// Sequence of results expected for a single request
+struct ExpectedResults {
+    results: Vec<ExpectedResult>,
+    /// Index of the next result to return
+    next: AtomicUsize,
+}
+
+impl ExpectedResults {
+    fn next(&self) -> &ExpectedResult {
+        let index = self.next.fetch_add(1, Ordering::SeqCst);
+
+        self.results
+            .get(index)
+            .or_else(|| self.results.last())
+            .expect("expected results are never empty")
+    }
+}
+
 #[derive(Default, Debug)]
 /// Requests expected by a mock and their responses.
 struct Expectations {
-    expectations: HashMap<Key, ExpectedResult>,
+    expectations: HashMap<Key, ExpectedResults>,
 }
 
 impl Expectations {
@@ -238,18 +292,41 @@ impl Expectations {
             )));
         }
 
-        self.expectations.insert(key.clone(), value);
+        self.expectations.insert(
+            key.clone(),
+            ExpectedResults {
+                results: vec![value],
+                next: AtomicUsize::new(0),
+            },
+        );
 
         Ok(key)
     }
 
+    /// Add next expected response for a request, after all responses already expected.
+    pub fn add_next<I: Mockable + Debug, O: Mockable>(&mut self, request: &I, result: &O) -> Key {
+        let key = Key::new(request);
+        let value = ExpectedResult::serialize(result);
+
+        self.expectations
+            .entry(key.clone())
+            .or_default()
+            .results
+            .push(value);
+
+        key
+    }
+
     /// Get the response for a given request.
     ///
     /// Returns `None` if the request has not been expected.
     pub fn get<I: Mockable, O: Mockable>(&self, request: &I) -> (Key, Option<O>) {
         let key = Key::new(request);
 
-        let response = self.expectations.get(&key).and_then(|v| v.deserialize());
+        let response = self
+            .expectations
+            .get(&key)
+            .and_then(|v| v.next().deserialize());
 
         (key, response)
     }
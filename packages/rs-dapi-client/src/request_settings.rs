@@ -1,5 +1,6 @@
 //! DAPI client request settings processing.
 
+use crate::RetryPolicy;
 #[cfg(not(target_arch = "wasm32"))]
 use dapi_grpc::tonic::transport::Certificate;
 use std::time::Duration;
@@ -31,6 +32,15 @@ pub struct RequestSettings {
     pub retries: Option<usize>,
     /// Ban DAPI address if node not responded or responded with error.
     pub ban_failed_address: Option<bool>,
+    /// Send the request to another node if the first one has not responded within this time,
+    /// and use the response that arrives first.
+    ///
+    /// Applies only to read-only requests. Disabled if not set.
+    pub hedge_delay: Option<Duration>,
+    /// Policy deciding if and when failed requests are retried.
+    ///
+    /// Defaults to [RetryPolicy::exponential].
+    pub retry_policy: Option<RetryPolicy>,
 }
 
 impl RequestSettings {
@@ -42,6 +52,8 @@ impl RequestSettings {
             timeout: None,
             retries: None,
             ban_failed_address: None,
+            hedge_delay: None,
+            retry_policy: None,
         }
     }
 
@@ -54,6 +66,8 @@ impl RequestSettings {
             timeout: rhs.timeout.or(self.timeout),
             retries: rhs.retries.or(self.retries),
             ban_failed_address: rhs.ban_failed_address.or(self.ban_failed_address),
+            hedge_delay: rhs.hedge_delay.or(self.hedge_delay),
+            retry_policy: rhs.retry_policy.or(self.retry_policy),
         }
     }
 
@@ -66,6 +80,8 @@ impl RequestSettings {
             ban_failed_address: self
                 .ban_failed_address
                 .unwrap_or(DEFAULT_BAN_FAILED_ADDRESS),
+            hedge_delay: self.hedge_delay,
+            retry_policy: self.retry_policy.unwrap_or(RetryPolicy::exponential()),
             #[cfg(not(target_arch = "wasm32"))]
             ca_certificate: None,
         }
@@ -83,6 +99,10 @@ pub struct AppliedRequestSettings {
     pub retries: usize,
     /// Ban DAPI address if node not responded or responded with error.
     pub ban_failed_address: bool,
+    /// Send read-only requests to another node if the first one has not responded within this time.
+    pub hedge_delay: Option<Duration>,
+    /// Policy deciding if and when failed requests are retried.
+    pub retry_policy: RetryPolicy,
     /// Certificate Authority certificate to use for verifying the server's certificate.
     #[cfg(not(target_arch = "wasm32"))]
     pub ca_certificate: Option<Certificate>,
@@ -0,0 +1,235 @@
+//! Retry policies applied when DAPI requests fail.
+
+use crate::CanRetry;
+use chrono::Utc;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Class of a request failure, used by [RetryPolicy] to decide what to do next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    /// Node is unavailable or overloaded, like gRPC `Unavailable` or `ResourceExhausted`.
+    Unavailable,
+    /// Request timed out or was cancelled.
+    Timeout,
+    /// Node failed to process the request, like gRPC `Internal` or `Unknown`.
+    NodeFailure,
+    /// Node responded with metadata older than accepted.
+    StaleNode,
+    /// Request is invalid or was rejected by consensus rules; retrying won't change the outcome.
+    Permanent,
+}
+
+/// What to do after a failed request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RetryDecision {
+    /// Return the error.
+    Fail,
+    /// Retry on another node after the backoff delay.
+    Retry,
+    /// Retry on another node immediately, as the failure is specific to the node.
+    SwitchNode,
+}
+
+/// Policy deciding if and when a failed request is retried.
+///
+/// Number of retries is limited by [RequestSettings::retries](crate::RequestSettings::retries).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+    /// Maximum delay between retries.
+    pub max_backoff: Duration,
+    /// Each next delay is this many times longer than the previous one.
+    pub backoff_multiplier: u32,
+    /// Randomize delays between half and full computed delay,
+    /// so clients failing at the same time don't retry at the same time.
+    pub jitter: bool,
+    /// Total time budget for all attempts; no retry is started if it would exceed the deadline,
+    /// and an attempt still running at the deadline fails with a timeout.
+    pub deadline: Option<Duration>,
+    /// Decision for [ErrorClass::Unavailable] errors.
+    pub on_unavailable: RetryDecision,
+    /// Decision for [ErrorClass::Timeout] errors.
+    pub on_timeout: RetryDecision,
+    /// Decision for [ErrorClass::NodeFailure] errors.
+    pub on_node_failure: RetryDecision,
+    /// Decision for [ErrorClass::StaleNode] errors.
+    pub on_stale_node: RetryDecision,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::exponential()
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff with jitter, starting at 100 ms and capped at 5 s.
+    ///
+    /// Stale nodes are switched immediately, other retryable failures are retried after the backoff.
+    pub const fn exponential() -> Self {
+        RetryPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            backoff_multiplier: 2,
+            jitter: true,
+            deadline: None,
+            on_unavailable: RetryDecision::Retry,
+            on_timeout: RetryDecision::Retry,
+            on_node_failure: RetryDecision::Retry,
+            on_stale_node: RetryDecision::SwitchNode,
+        }
+    }
+
+    /// Retry every retryable failure on another node after a short constant delay.
+    pub const fn immediate() -> Self {
+        RetryPolicy {
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(10),
+            backoff_multiplier: 1,
+            jitter: false,
+            deadline: None,
+            on_unavailable: RetryDecision::Retry,
+            on_timeout: RetryDecision::Retry,
+            on_node_failure: RetryDecision::Retry,
+            on_stale_node: RetryDecision::Retry,
+        }
+    }
+
+    /// Set total time budget for all attempts.
+    pub const fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Decide what to do after a failure of the given class.
+    ///
+    /// [ErrorClass::Permanent] failures are never retried.
+    pub fn decide(&self, class: ErrorClass) -> RetryDecision {
+        match class {
+            ErrorClass::Unavailable => self.on_unavailable,
+            ErrorClass::Timeout => self.on_timeout,
+            ErrorClass::NodeFailure => self.on_node_failure,
+            ErrorClass::StaleNode => self.on_stale_node,
+            ErrorClass::Permanent => RetryDecision::Fail,
+        }
+    }
+
+    /// Delay before the retry number `attempt`, starting from 0, without jitter.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let multiplier = self.backoff_multiplier.saturating_pow(attempt);
+
+        self.initial_backoff
+            .saturating_mul(multiplier)
+            .min(self.max_backoff)
+    }
+}
+
+/// Backoff schedule of a single request execution.
+///
+/// It's an iterator of delays between retries, as expected by [::backon].
+/// Every failure must be passed to [RetryBackoff::should_retry] before the next delay is taken,
+/// so the delay accounts for the failure class. Clones share the same schedule.
+#[derive(Debug, Clone)]
+pub struct RetryBackoff {
+    state: Arc<Mutex<RetryBackoffState>>,
+}
+
+#[derive(Debug)]
+struct RetryBackoffState {
+    policy: RetryPolicy,
+    retries_left: usize,
+    attempt: u32,
+    started_at: chrono::DateTime<Utc>,
+    last_decision: RetryDecision,
+    rng: SmallRng,
+}
+
+impl RetryBackoff {
+    /// Start a new backoff schedule allowing up to `max_retries` retries.
+    pub fn new(policy: RetryPolicy, max_retries: usize) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(RetryBackoffState {
+                policy,
+                retries_left: max_retries,
+                attempt: 0,
+                started_at: Utc::now(),
+                last_decision: RetryDecision::Retry,
+                rng: SmallRng::from_entropy(),
+            })),
+        }
+    }
+
+    /// Classify the failure and decide if the request should be retried.
+    pub fn should_retry<E: CanRetry>(&self, error: &E) -> bool {
+        let mut state = self.state.lock().expect("retry backoff lock poisoned");
+
+        let decision = state.policy.decide(error.error_class());
+        state.last_decision = decision;
+
+        decision != RetryDecision::Fail
+    }
+
+    /// Whether the last failure was classified as specific to the node that returned it.
+    pub fn switches_node(&self) -> bool {
+        let state = self.state.lock().expect("retry backoff lock poisoned");
+
+        state.last_decision == RetryDecision::SwitchNode
+    }
+
+    /// Time left until the deadline of the policy, if it has one.
+    pub fn remaining(&self) -> Option<Duration> {
+        let state = self.state.lock().expect("retry backoff lock poisoned");
+
+        let deadline = state.policy.deadline?;
+        let elapsed = (Utc::now() - state.started_at).to_std().unwrap_or_default();
+
+        Some(deadline.saturating_sub(elapsed))
+    }
+}
+
+impl Iterator for RetryBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut state = self.state.lock().expect("retry backoff lock poisoned");
+
+        if state.retries_left == 0 {
+            return None;
+        }
+
+        let delay = match state.last_decision {
+            RetryDecision::Fail => return None,
+            RetryDecision::SwitchNode => Duration::ZERO,
+            RetryDecision::Retry => {
+                let delay = state.policy.backoff(state.attempt);
+                if state.policy.jitter {
+                    let factor = state.rng.gen_range(0.5..=1.0);
+                    delay.mul_f64(factor)
+                } else {
+                    delay
+                }
+            }
+        };
+
+        if let Some(deadline) = state.policy.deadline {
+            let elapsed = (Utc::now() - state.started_at).to_std().unwrap_or_default();
+
+            if elapsed + delay >= deadline {
+                tracing::debug!(
+                    ?elapsed,
+                    ?deadline,
+                    "request deadline would be exceeded, not retrying"
+                );
+                return None;
+            }
+        }
+
+        state.retries_left -= 1;
+        state.attempt += 1;
+
+        Some(delay)
+    }
+}
@@ -8,7 +8,7 @@ pub(crate) mod wasm_channel;
 
 use crate::connection_pool::ConnectionPool;
 pub use crate::request_settings::AppliedRequestSettings;
-use crate::{CanRetry, RequestSettings, Uri};
+use crate::{CanRetry, ErrorClass, RequestSettings, Uri};
 use dapi_grpc::mock::Mockable;
 pub use futures::future::BoxFuture;
 use std::any;
@@ -35,6 +35,11 @@ pub trait TransportRequest: Clone + Send + Sync + Debug + Mockable {
     /// Settings that will override [DapiClient](crate::DapiClient)'s ones each time the request is executed.
     const SETTINGS_OVERRIDES: RequestSettings;
 
+    /// Whether the request doesn't change any state, so it's safe to send it to more than one node.
+    ///
+    /// Only read-only requests are hedged, see [RequestSettings::hedge_delay].
+    const READ_ONLY: bool = false;
+
     /// gRPC request name
     fn request_name(&self) -> &'static str {
         any::type_name::<Self>()
@@ -75,6 +80,12 @@ impl CanRetry for TransportError {
             TransportError::Grpc(status) => status.can_retry(),
         }
     }
+
+    fn error_class(&self) -> ErrorClass {
+        match self {
+            TransportError::Grpc(status) => status.error_class(),
+        }
+    }
 }
 
 /// Serialization of [TransportError].
@@ -6,7 +6,7 @@ use super::create_channel;
 use super::{CanRetry, TransportClient, TransportError, TransportRequest};
 use super::{CoreGrpcClient, PlatformGrpcClient};
 use crate::connection_pool::{ConnectionPool, PoolPrefix};
-use crate::{request_settings::AppliedRequestSettings, RequestSettings, Uri};
+use crate::{request_settings::AppliedRequestSettings, ErrorClass, RequestSettings, Uri};
 use dapi_grpc::core::v0::{self as core_proto};
 use dapi_grpc::platform::v0::{self as platform_proto};
 use dapi_grpc::tonic::{IntoRequest, Streaming};
@@ -104,6 +104,17 @@ impl CanRetry for dapi_grpc::tonic::Status {
                 | Unavailable
         )
     }
+
+    fn error_class(&self) -> ErrorClass {
+        use dapi_grpc::tonic::Code::*;
+
+        match self.code() {
+            Unavailable | ResourceExhausted => ErrorClass::Unavailable,
+            DeadlineExceeded | Cancelled => ErrorClass::Timeout,
+            Ok | DataLoss | Unknown | Aborted | Internal => ErrorClass::NodeFailure,
+            _ => ErrorClass::Permanent,
+        }
+    }
 }
 
 /// Macro to implement the `TransportRequest` trait for a given request type, response type, client type, and settings.
@@ -118,6 +129,10 @@ impl CanRetry for dapi_grpc::tonic::Status {
 ///   the [`DapiRequestExecutor::execute`](crate::DapiRequestExecutor::execute) method.
 /// - `$($method:tt)+`: The method of `$client` to be called to execute the request.
 ///
+/// Requests are not read-only by default, so they are never sent to more than one node. Queries
+/// that don't change any state can be prefixed with `read_only: true` to make them eligible for
+/// hedging.
+///
 /// # Example
 ///
 /// ```compile_fail
@@ -140,6 +155,9 @@ impl CanRetry for dapi_grpc::tonic::Status {
 /// - Implement the `execute_transport` function to execute the transport request using the provided client and settings.
 macro_rules! impl_transport_request_grpc {
     ($request:ty, $response:ty, $client:ty, $settings:expr, $($method:tt)+) => {
+        impl_transport_request_grpc!(read_only: false, $request, $response, $client, $settings, $($method)+);
+    };
+    (read_only: $read_only:literal, $request:ty, $response:ty, $client:ty, $settings:expr, $($method:tt)+) => {
         impl TransportRequest for $request {
             type Client = $client;
 
@@ -147,6 +165,8 @@ macro_rules! impl_transport_request_grpc {
 
             const SETTINGS_OVERRIDES: RequestSettings = $settings;
 
+            const READ_ONLY: bool = $read_only;
+
             fn method_name(&self) -> &'static str {
                 stringify!($($method)+)
             }
@@ -178,6 +198,7 @@ macro_rules! impl_transport_request_grpc {
 const STREAMING_TIMEOUT: Duration = Duration::from_secs(5 * 60);
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentityRequest,
     platform_proto::GetIdentityResponse,
     PlatformGrpcClient,
@@ -186,6 +207,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetDocumentsRequest,
     platform_proto::GetDocumentsResponse,
     PlatformGrpcClient,
@@ -194,6 +216,27 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
+    platform_proto::GetDocumentsAggregateRequest,
+    platform_proto::GetDocumentsAggregateResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_documents_aggregate
+);
+
+impl_transport_request_grpc!(
+    platform_proto::SubscribeDocumentsRequest,
+    Streaming<platform_proto::SubscribeDocumentsResponse>,
+    PlatformGrpcClient,
+    RequestSettings {
+        timeout: Some(STREAMING_TIMEOUT),
+        ..RequestSettings::default()
+    },
+    subscribe_documents
+);
+
+impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetDataContractRequest,
     platform_proto::GetDataContractResponse,
     PlatformGrpcClient,
@@ -202,6 +245,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetConsensusParamsRequest,
     platform_proto::GetConsensusParamsResponse,
     PlatformGrpcClient,
@@ -210,6 +254,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetDataContractHistoryRequest,
     platform_proto::GetDataContractHistoryResponse,
     PlatformGrpcClient,
@@ -234,11 +279,23 @@ impl_transport_request_grpc!(
         retries: Some(0),
         ban_failed_address: None,
         connect_timeout: None,
+        hedge_delay: None,
+        retry_policy: None,
     },
     wait_for_state_transition_result
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
+    platform_proto::SimulateStateTransitionRequest,
+    platform_proto::SimulateStateTransitionResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    simulate_state_transition
+);
+
+impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentityByPublicKeyHashRequest,
     platform_proto::GetIdentityByPublicKeyHashResponse,
     PlatformGrpcClient,
@@ -247,6 +304,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentityBalanceRequest,
     platform_proto::GetIdentityBalanceResponse,
     PlatformGrpcClient,
@@ -255,6 +313,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentitiesBalancesRequest,
     platform_proto::GetIdentitiesBalancesResponse,
     PlatformGrpcClient,
@@ -263,6 +322,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentityNonceRequest,
     platform_proto::GetIdentityNonceResponse,
     PlatformGrpcClient,
@@ -271,6 +331,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentityContractNonceRequest,
     platform_proto::GetIdentityContractNonceResponse,
     PlatformGrpcClient,
@@ -279,6 +340,16 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
+    platform_proto::GetIdentityStateTransitionsRequest,
+    platform_proto::GetIdentityStateTransitionsResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_identity_state_transitions
+);
+
+impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentityBalanceAndRevisionRequest,
     platform_proto::GetIdentityBalanceAndRevisionResponse,
     PlatformGrpcClient,
@@ -287,6 +358,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentitiesContractKeysRequest,
     platform_proto::GetIdentitiesContractKeysResponse,
     PlatformGrpcClient,
@@ -295,6 +367,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentityKeysRequest,
     platform_proto::GetIdentityKeysResponse,
     PlatformGrpcClient,
@@ -303,6 +376,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetEpochsInfoRequest,
     platform_proto::GetEpochsInfoResponse,
     PlatformGrpcClient,
@@ -311,6 +385,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetFinalizedEpochInfosRequest,
     platform_proto::GetFinalizedEpochInfosResponse,
     PlatformGrpcClient,
@@ -319,6 +394,16 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
+    platform_proto::GetBlockStateTransitionsRequest,
+    platform_proto::GetBlockStateTransitionsResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_block_state_transitions
+);
+
+impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetProtocolVersionUpgradeStateRequest,
     platform_proto::GetProtocolVersionUpgradeStateResponse,
     PlatformGrpcClient,
@@ -327,6 +412,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetProtocolVersionUpgradeVoteStatusRequest,
     platform_proto::GetProtocolVersionUpgradeVoteStatusResponse,
     PlatformGrpcClient,
@@ -335,6 +421,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetDataContractsRequest,
     platform_proto::GetDataContractsResponse,
     PlatformGrpcClient,
@@ -344,6 +431,7 @@ impl_transport_request_grpc!(
 
 // rpc getContestedResources(GetContestedResourcesRequest) returns (GetContestedResourcesResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetContestedResourcesRequest,
     platform_proto::GetContestedResourcesResponse,
     PlatformGrpcClient,
@@ -353,6 +441,7 @@ impl_transport_request_grpc!(
 
 //  rpc getContestedResourceVoteState(GetContestedResourceVoteStateRequest) returns (GetContestedResourceVoteStateResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetContestedResourceVoteStateRequest,
     platform_proto::GetContestedResourceVoteStateResponse,
     PlatformGrpcClient,
@@ -362,6 +451,7 @@ impl_transport_request_grpc!(
 
 // rpc getContestedResourceVotersForIdentity(GetContestedResourceVotersForIdentityRequest) returns (GetContestedResourceVotersForIdentityResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetContestedResourceVotersForIdentityRequest,
     platform_proto::GetContestedResourceVotersForIdentityResponse,
     PlatformGrpcClient,
@@ -370,6 +460,7 @@ impl_transport_request_grpc!(
 );
 // rpc getContestedResourceIdentityVoteStatus(GetContestedResourceIdentityVoteStatusRequest) returns (GetContestedResourceIdentityVoteStatusResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetContestedResourceIdentityVotesRequest,
     platform_proto::GetContestedResourceIdentityVotesResponse,
     PlatformGrpcClient,
@@ -378,6 +469,7 @@ impl_transport_request_grpc!(
 );
 // rpc GetVotePollsByEndDateRequest(GetVotePollsByEndDateRequest) returns (GetVotePollsByEndDateResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetVotePollsByEndDateRequest,
     platform_proto::GetVotePollsByEndDateResponse,
     PlatformGrpcClient,
@@ -385,8 +477,19 @@ impl_transport_request_grpc!(
     get_vote_polls_by_end_date
 );
 
+// rpc getYesNoAbstainVotePollState(GetYesNoAbstainVotePollStateRequest) returns (GetYesNoAbstainVotePollStateResponse);
+impl_transport_request_grpc!(
+    read_only: true,
+    platform_proto::GetYesNoAbstainVotePollStateRequest,
+    platform_proto::GetYesNoAbstainVotePollStateResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_yes_no_abstain_vote_poll_state
+);
+
 // rpc GetEvonodesProposedEpochBlocksByIdsRequest(GetEvonodesProposedEpochBlocksByIdsRequest) returns (GetEvonodesProposedEpochBlocksResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetEvonodesProposedEpochBlocksByIdsRequest,
     platform_proto::GetEvonodesProposedEpochBlocksResponse,
     PlatformGrpcClient,
@@ -396,6 +499,7 @@ impl_transport_request_grpc!(
 
 // rpc GetEvonodesProposedEpochBlocksByRangeRequest(GetEvonodesProposedEpochBlocksByRangeRequest) returns (GetEvonodesProposedEpochBlocksResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetEvonodesProposedEpochBlocksByRangeRequest,
     platform_proto::GetEvonodesProposedEpochBlocksResponse,
     PlatformGrpcClient,
@@ -405,6 +509,7 @@ impl_transport_request_grpc!(
 
 // rpc getPrefundedSpecializedBalance(GetPrefundedSpecializedBalanceRequest) returns (GetPrefundedSpecializedBalanceResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetPrefundedSpecializedBalanceRequest,
     platform_proto::GetPrefundedSpecializedBalanceResponse,
     PlatformGrpcClient,
@@ -414,6 +519,7 @@ impl_transport_request_grpc!(
 
 // rpc getPathElements(GetPathElementsRequest) returns (GetPathElementsResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetPathElementsRequest,
     platform_proto::GetPathElementsResponse,
     PlatformGrpcClient,
@@ -423,6 +529,7 @@ impl_transport_request_grpc!(
 
 // rpc getTotalCreditsInPlatform(GetTotalCreditsInPlatformRequest) returns (GetTotalCreditsInPlatformResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetTotalCreditsInPlatformRequest,
     platform_proto::GetTotalCreditsInPlatformResponse,
     PlatformGrpcClient,
@@ -432,6 +539,7 @@ impl_transport_request_grpc!(
 
 // rpc getCurrentQuorumsInfo(GetCurrentQuorumsInfoRequest) returns (GetCurrentQuorumsInfoResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetCurrentQuorumsInfoRequest,
     platform_proto::GetCurrentQuorumsInfoResponse,
     PlatformGrpcClient,
@@ -442,6 +550,7 @@ impl_transport_request_grpc!(
 // Link to each core gRPC request what client and method to use:
 
 impl_transport_request_grpc!(
+    read_only: true,
     core_proto::GetTransactionRequest,
     core_proto::GetTransactionResponse,
     CoreGrpcClient,
@@ -450,6 +559,7 @@ impl_transport_request_grpc!(
 );
 
 impl_transport_request_grpc!(
+    read_only: true,
     core_proto::GetBlockchainStatusRequest,
     core_proto::GetBlockchainStatusResponse,
     CoreGrpcClient,
@@ -457,6 +567,24 @@ impl_transport_request_grpc!(
     get_blockchain_status
 );
 
+impl_transport_request_grpc!(
+    read_only: true,
+    core_proto::GetBlockFiltersRequest,
+    core_proto::GetBlockFiltersResponse,
+    CoreGrpcClient,
+    RequestSettings::default(),
+    get_block_filters
+);
+
+impl_transport_request_grpc!(
+    read_only: true,
+    core_proto::GetBlockFilterHeadersRequest,
+    core_proto::GetBlockFilterHeadersResponse,
+    CoreGrpcClient,
+    RequestSettings::default(),
+    get_block_filter_headers
+);
+
 impl_transport_request_grpc!(
     core_proto::BroadcastTransactionRequest,
     core_proto::BroadcastTransactionResponse,
@@ -474,6 +602,8 @@ impl_transport_request_grpc!(
         ban_failed_address: None,
         connect_timeout: None,
         retries: None,
+        hedge_delay: None,
+        retry_policy: None,
     },
     subscribe_to_transactions_with_proofs
 );
@@ -491,6 +621,7 @@ impl_transport_request_grpc!(
 
 // rpc getStatus(GetStatusRequest) returns (GetStatusResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetStatusRequest,
     platform_proto::GetStatusResponse,
     PlatformGrpcClient,
@@ -500,6 +631,7 @@ impl_transport_request_grpc!(
 
 //   rpc getIdentityByNonUniquePublicKeyHash(GetIdentityByNonUniquePublicKeyHashRequest) returns (GetIdentityByNonUniquePublicKeyHashResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentityByNonUniquePublicKeyHashRequest,
     platform_proto::GetIdentityByNonUniquePublicKeyHashResponse,
     PlatformGrpcClient,
@@ -509,6 +641,7 @@ impl_transport_request_grpc!(
 
 // rpc getIdentityTokenBalances(GetIdentityTokenBalancesRequest) returns (GetIdentityTokenBalancesResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentityTokenBalancesRequest,
     platform_proto::GetIdentityTokenBalancesResponse,
     PlatformGrpcClient,
@@ -518,6 +651,7 @@ impl_transport_request_grpc!(
 
 // rpc getIdentitiesTokenBalances(GetIdentitiesTokenBalancesRequest) returns (GetIdentitiesTokenBalancesResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentitiesTokenBalancesRequest,
     platform_proto::GetIdentitiesTokenBalancesResponse,
     PlatformGrpcClient,
@@ -527,6 +661,7 @@ impl_transport_request_grpc!(
 
 // rpc getIdentityTokenInfos(GetIdentityTokenInfosRequest) returns (GetIdentityTokenInfosResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentityTokenInfosRequest,
     platform_proto::GetIdentityTokenInfosResponse,
     PlatformGrpcClient,
@@ -536,6 +671,7 @@ impl_transport_request_grpc!(
 
 // rpc getIdentitiesTokenInfos(GetIdentitiesTokenInfosRequest) returns (GetIdentitiesTokenInfosResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetIdentitiesTokenInfosRequest,
     platform_proto::GetIdentitiesTokenInfosResponse,
     PlatformGrpcClient,
@@ -545,6 +681,7 @@ impl_transport_request_grpc!(
 
 // rpc getTokenStatuses(GetTokenStatusesRequest) returns (GetTokenStatusesResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetTokenStatusesRequest,
     platform_proto::GetTokenStatusesResponse,
     PlatformGrpcClient,
@@ -554,6 +691,7 @@ impl_transport_request_grpc!(
 
 // rpc getTokenTotalSupply(GetTokenTotalSupplyRequest) returns (GetTokenTotalSupplyResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetTokenTotalSupplyRequest,
     platform_proto::GetTokenTotalSupplyResponse,
     PlatformGrpcClient,
@@ -561,8 +699,59 @@ impl_transport_request_grpc!(
     get_token_total_supply
 );
 
+// rpc getTokenHolders(GetTokenHoldersRequest) returns (GetTokenHoldersResponse);
+impl_transport_request_grpc!(
+    read_only: true,
+    platform_proto::GetTokenHoldersRequest,
+    platform_proto::GetTokenHoldersResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_token_holders
+);
+
+// rpc getTokenOrderBook(GetTokenOrderBookRequest) returns (GetTokenOrderBookResponse);
+impl_transport_request_grpc!(
+    read_only: true,
+    platform_proto::GetTokenOrderBookRequest,
+    platform_proto::GetTokenOrderBookResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_token_order_book
+);
+
+// rpc getTokenVestings(GetTokenVestingsRequest) returns (GetTokenVestingsResponse);
+impl_transport_request_grpc!(
+    read_only: true,
+    platform_proto::GetTokenVestingsRequest,
+    platform_proto::GetTokenVestingsResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_token_vestings
+);
+
+// rpc getTokenAllowances(GetTokenAllowancesRequest) returns (GetTokenAllowancesResponse);
+impl_transport_request_grpc!(
+    read_only: true,
+    platform_proto::GetTokenAllowancesRequest,
+    platform_proto::GetTokenAllowancesResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_token_allowances
+);
+
+// rpc getTokenEvents(GetTokenEventsRequest) returns (GetTokenEventsResponse);
+impl_transport_request_grpc!(
+    read_only: true,
+    platform_proto::GetTokenEventsRequest,
+    platform_proto::GetTokenEventsResponse,
+    PlatformGrpcClient,
+    RequestSettings::default(),
+    get_token_events
+);
+
 // rpc getGroupInfo(GetGroupInfoRequest) returns (GetGroupInfoResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetGroupInfoRequest,
     platform_proto::GetGroupInfoResponse,
     PlatformGrpcClient,
@@ -572,6 +761,7 @@ impl_transport_request_grpc!(
 
 // rpc getGroupInfos(GetGroupInfosRequest) returns (GetGroupInfosResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetGroupInfosRequest,
     platform_proto::GetGroupInfosResponse,
     PlatformGrpcClient,
@@ -581,6 +771,7 @@ impl_transport_request_grpc!(
 
 // rpc getGroupActions(GetGroupActionsRequest) returns (GetGroupActionsResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetGroupActionsRequest,
     platform_proto::GetGroupActionsResponse,
     PlatformGrpcClient,
@@ -590,6 +781,7 @@ impl_transport_request_grpc!(
 
 // rpc getGroupActionSigners(GetGroupActionSignersRequest) returns (GetGroupActionSignersResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetGroupActionSignersRequest,
     platform_proto::GetGroupActionSignersResponse,
     PlatformGrpcClient,
@@ -599,6 +791,7 @@ impl_transport_request_grpc!(
 
 // rpc getTokenDirectPurchasePrices(GetTokenDirectPurchasePricesRequest) returns (GetTokenDirectPurchasePricesResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetTokenDirectPurchasePricesRequest,
     platform_proto::GetTokenDirectPurchasePricesResponse,
     PlatformGrpcClient,
@@ -608,6 +801,7 @@ impl_transport_request_grpc!(
 
 // rpc getTokenContractInfo(GetTokenContractInfoRequest) returns (GetTokenContractInfoResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetTokenContractInfoRequest,
     platform_proto::GetTokenContractInfoResponse,
     PlatformGrpcClient,
@@ -617,6 +811,7 @@ impl_transport_request_grpc!(
 
 // rpc getTokenPerpetualDistributionLastClaimRequest(GetTokenPerpetualDistributionLastClaimRequest) returns (GetTokenPerpetualDistributionLastClaimResponse);
 impl_transport_request_grpc!(
+    read_only: true,
     platform_proto::GetTokenPerpetualDistributionLastClaimRequest,
     platform_proto::GetTokenPerpetualDistributionLastClaimResponse,
     PlatformGrpcClient,
@@ -0,0 +1,90 @@
+use rs_dapi_client::{Address, AddressList};
+use std::str::FromStr;
+use std::time::Duration;
+
+fn address(port: u16) -> Address {
+    Address::from_str(&format!("http://127.0.0.1:{port}")).expect("valid address")
+}
+
+#[test]
+fn test_address_score_tracks_latency_and_errors() {
+    let fast = address(1);
+    let slow = address(2);
+    let list = AddressList::from_iter([fast.clone(), slow.clone()]);
+
+    let initial = list.score(&fast).expect("address is in the list");
+    assert_eq!(initial.requests, 0);
+    assert_eq!(initial.latency, None);
+
+    for _ in 0..5 {
+        assert!(list.record_success(&fast, Duration::from_millis(20)));
+        assert!(list.record_success(&slow, Duration::from_millis(800)));
+    }
+
+    let fast_score = list.score(&fast).expect("address is in the list");
+    let slow_score = list.score(&slow).expect("address is in the list");
+
+    assert_eq!(fast_score.requests, 5);
+    assert_eq!(fast_score.latency, Some(Duration::from_millis(20)));
+    assert!(fast_score.weight > slow_score.weight);
+
+    assert!(list.record_failure(&fast));
+
+    let failed_score = list.score(&fast).expect("address is in the list");
+    assert!(failed_score.error_rate > 0.0);
+    assert!(failed_score.weight < fast_score.weight);
+
+    assert!(!list.record_success(&address(3), Duration::from_millis(1)));
+    assert_eq!(list.scores().len(), 2);
+}
+
+#[test]
+fn test_get_live_addresses_prefers_healthy_addresses() {
+    let healthy = address(1);
+    let failing = address(2);
+    let list = AddressList::from_iter([healthy.clone(), failing.clone()]);
+
+    for _ in 0..20 {
+        list.record_success(&healthy, Duration::from_millis(10));
+        list.record_failure(&failing);
+    }
+
+    let healthy_picks = (0..1000)
+        .filter(|_| list.get_live_address(None).as_ref() == Some(&healthy))
+        .count();
+
+    assert!(
+        healthy_picks > 900,
+        "healthy address picked {healthy_picks} times"
+    );
+}
+
+#[test]
+fn test_get_live_addresses_returns_distinct_live_addresses() {
+    let banned = address(1);
+    let list = AddressList::from_iter([banned.clone(), address(2), address(3)]);
+
+    list.ban(&banned);
+
+    let addresses = list.get_live_addresses(5);
+
+    assert_eq!(addresses.len(), 2);
+    assert!(!addresses.contains(&banned));
+    assert_ne!(addresses[0], addresses[1]);
+}
+
+#[test]
+fn test_get_live_address_skips_excluded_address() {
+    let failed = address(1);
+    let other = address(2);
+    let list = AddressList::from_iter([failed.clone(), other.clone()]);
+
+    for _ in 0..100 {
+        assert_eq!(list.get_live_address(Some(&failed)), Some(other.clone()));
+    }
+
+    // The excluded address is still used when no other address is live
+    list.ban(&other);
+
+    assert_eq!(list.get_live_address(Some(&failed)), Some(failed));
+}
@@ -43,3 +43,72 @@ async fn test_mock_get_identity_dapi_client() {
     assert_eq!(result, execution_response);
     assert_eq!(result2, execution_response);
 }
+
+#[tokio::test]
+#[cfg(feature = "mocks")]
+async fn test_mock_returns_next_expectations_in_order() {
+    let mut dapi = MockDapiClient::new();
+
+    let request = GetIdentityRequest::default();
+    let responses: Vec<_> = [1, 2]
+        .into_iter()
+        .map(|round| ExecutionResponse {
+            inner: GetIdentityResponse {
+                version: Some(dapi_grpc::platform::v0::get_identity_response::Version::V0(dapi_grpc::platform::v0::get_identity_response::GetIdentityResponseV0 {
+                    result: Some(
+                        dapi_grpc::platform::v0::get_identity_response::get_identity_response_v0::Result::Proof(Proof {
+                            round,
+                            ..Default::default()
+                        }),
+                    ),
+                    metadata: Default::default(),
+                }))
+            },
+            retries: 0,
+            address: "http://127.0.0.1:9000"
+                .parse()
+                .expect("failed to parse address"),
+        })
+        .collect();
+
+    for response in &responses {
+        dapi.expect_next(&request, &Ok(response.clone()));
+    }
+
+    let settings = RequestSettings::default();
+
+    let first = dapi.execute(request.clone(), settings).await.unwrap();
+    let second = dapi.execute(request.clone(), settings).await.unwrap();
+    // Last response is repeated once all responses were returned
+    let third = dapi.execute(request, settings).await.unwrap();
+
+    assert_eq!(first, responses[0]);
+    assert_eq!(second, responses[1]);
+    assert_eq!(third, responses[1]);
+}
+
+#[test]
+#[cfg(feature = "dump")]
+fn test_dump_session_sequence() {
+    use rs_dapi_client::{DapiClient, DumpData};
+
+    let data = DumpData::new(
+        &GetIdentityRequest::default(),
+        &Ok(ExecutionResponse {
+            inner: GetIdentityResponse::default(),
+            retries: 0,
+            address: "http://127.0.0.1:9000"
+                .parse()
+                .expect("failed to parse address"),
+        }),
+    );
+
+    let filename = data.filename().expect("dump file name");
+    let session_filename = data.session_filename(42).expect("session dump file name");
+
+    assert_eq!(DapiClient::dump_session_sequence(&filename), None);
+    assert_eq!(
+        DapiClient::dump_session_sequence(&session_filename),
+        Some(42)
+    );
+}
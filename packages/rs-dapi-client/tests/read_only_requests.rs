@@ -0,0 +1,21 @@
+use dapi_grpc::core::v0::{BroadcastTransactionRequest, GetTransactionRequest};
+use dapi_grpc::platform::v0::{
+    BroadcastStateTransitionRequest, GetIdentityRequest, GetTokenHoldersRequest,
+    SubscribeDocumentsRequest, WaitForStateTransitionResultRequest,
+};
+use rs_dapi_client::transport::TransportRequest;
+
+#[test]
+fn queries_are_read_only() {
+    assert!(GetIdentityRequest::READ_ONLY);
+    assert!(GetTokenHoldersRequest::READ_ONLY);
+    assert!(GetTransactionRequest::READ_ONLY);
+}
+
+#[test]
+fn state_changing_and_streaming_requests_are_not_read_only() {
+    assert!(!BroadcastStateTransitionRequest::READ_ONLY);
+    assert!(!WaitForStateTransitionResultRequest::READ_ONLY);
+    assert!(!SubscribeDocumentsRequest::READ_ONLY);
+    assert!(!BroadcastTransactionRequest::READ_ONLY);
+}
@@ -0,0 +1,137 @@
+use rs_dapi_client::{CanRetry, ErrorClass, RetryBackoff, RetryDecision, RetryPolicy};
+use std::time::Duration;
+
+#[derive(Debug)]
+struct TestError(ErrorClass);
+
+impl CanRetry for TestError {
+    fn can_retry(&self) -> bool {
+        self.0 != ErrorClass::Permanent
+    }
+
+    fn error_class(&self) -> ErrorClass {
+        self.0
+    }
+}
+
+fn policy() -> RetryPolicy {
+    RetryPolicy {
+        jitter: false,
+        ..RetryPolicy::exponential()
+    }
+}
+
+#[test]
+fn test_exponential_backoff_is_capped() {
+    let policy = RetryPolicy {
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_millis(500),
+        ..policy()
+    };
+
+    let mut backoff = RetryBackoff::new(policy, 5);
+
+    let delays: Vec<_> = (0..6)
+        .map_while(|_| {
+            assert!(backoff.should_retry(&TestError(ErrorClass::Unavailable)));
+            backoff.next()
+        })
+        .collect();
+
+    assert_eq!(
+        delays,
+        vec![
+            Duration::from_millis(100),
+            Duration::from_millis(200),
+            Duration::from_millis(400),
+            Duration::from_millis(500),
+            Duration::from_millis(500),
+        ]
+    );
+}
+
+#[test]
+fn test_jitter_keeps_delay_within_bounds() {
+    let mut backoff = RetryBackoff::new(RetryPolicy::exponential(), 100);
+
+    for attempt in 0..100 {
+        assert!(backoff.should_retry(&TestError(ErrorClass::Timeout)));
+        let delay = backoff.next().expect("retries left");
+        let max = RetryPolicy::exponential().backoff(attempt);
+
+        assert!(delay <= max && delay >= max / 2, "{delay:?} not in range");
+    }
+}
+
+#[test]
+fn test_permanent_errors_are_never_retried() {
+    let backoff = RetryBackoff::new(policy(), 5);
+
+    assert!(!backoff.should_retry(&TestError(ErrorClass::Permanent)));
+    assert_eq!(policy().decide(ErrorClass::Permanent), RetryDecision::Fail);
+}
+
+#[test]
+fn test_stale_node_is_switched_without_delay() {
+    let mut backoff = RetryBackoff::new(policy(), 5);
+
+    assert!(backoff.should_retry(&TestError(ErrorClass::StaleNode)));
+    assert_eq!(backoff.next(), Some(Duration::ZERO));
+
+    // Backoff continues from the number of retries made so far
+    assert!(backoff.should_retry(&TestError(ErrorClass::Unavailable)));
+    assert_eq!(backoff.next(), Some(Duration::from_millis(200)));
+}
+
+#[test]
+fn test_per_class_decisions_are_configurable() {
+    let policy = RetryPolicy {
+        on_timeout: RetryDecision::Fail,
+        ..policy()
+    };
+
+    let backoff = RetryBackoff::new(policy, 5);
+
+    assert!(!backoff.should_retry(&TestError(ErrorClass::Timeout)));
+    assert!(backoff.should_retry(&TestError(ErrorClass::NodeFailure)));
+}
+
+#[test]
+fn test_deadline_stops_retries() {
+    let policy = policy().with_deadline(Duration::from_millis(250));
+
+    let mut backoff = RetryBackoff::new(policy, 10);
+
+    assert!(backoff.should_retry(&TestError(ErrorClass::Unavailable)));
+    assert_eq!(backoff.next(), Some(Duration::from_millis(100)));
+
+    assert!(backoff.should_retry(&TestError(ErrorClass::Unavailable)));
+    assert_eq!(backoff.next(), Some(Duration::from_millis(200)));
+
+    // Next delay of 400 ms alone exceeds the deadline
+    assert!(backoff.should_retry(&TestError(ErrorClass::Unavailable)));
+    assert_eq!(backoff.next(), None);
+}
+
+#[test]
+fn test_remaining_time_is_bounded_by_deadline() {
+    let backoff = RetryBackoff::new(policy(), 10);
+    assert_eq!(backoff.remaining(), None);
+
+    let deadline = Duration::from_secs(10);
+    let backoff = RetryBackoff::new(policy().with_deadline(deadline), 10);
+
+    let remaining = backoff.remaining().expect("deadline is set");
+    assert!(remaining <= deadline && remaining > Duration::from_secs(9));
+}
+
+#[test]
+fn test_only_node_specific_failures_switch_node() {
+    let backoff = RetryBackoff::new(policy(), 5);
+
+    assert!(backoff.should_retry(&TestError(ErrorClass::StaleNode)));
+    assert!(backoff.switches_node());
+
+    assert!(backoff.should_retry(&TestError(ErrorClass::Unavailable)));
+    assert!(!backoff.switches_node());
+}
@@ -386,6 +386,47 @@ impl CoreClient {
         Ok(diff)
     }
 
+    /// Fetch the BIP158 basic filter of a block and its BIP157 filter header via `getblockfilter`.
+    /// Requires Dash Core to run with `-blockfilterindex`.
+    /// Uses caching, the filter of a block never changes.
+    pub async fn get_block_filter(
+        &self,
+        block_hash: dashcore_rpc::dashcore::BlockHash,
+    ) -> DAPIResult<(Vec<u8>, dashcore::hash_types::FilterHeader)> {
+        use dashcore_rpc::dashcore::hashes::Hash;
+        trace!("Core RPC: getblockfilter");
+
+        let key = make_cache_key("get_block_filter", &block_hash);
+
+        let this = self.clone();
+        let (filter, header): (Vec<u8>, [u8; 32]) = self
+            .cache
+            .get_or_try_insert::<_, _, _, DapiError>(key, move || {
+                let this = this.clone();
+                let block_hex = block_hash.to_string();
+                async move {
+                    let result: serde_json::Value = this
+                        .guarded_blocking_call(move |client| {
+                            let params = [
+                                serde_json::Value::String(block_hex),
+                                serde_json::Value::String("basic".to_string()),
+                            ];
+                            client.call("getblockfilter", &params)
+                        })
+                        .await??;
+
+                    let (filter, header) = parse_block_filter(&result)?;
+                    Ok((filter, header.to_byte_array()))
+                }
+            })
+            .await?;
+
+        Ok((
+            filter,
+            dashcore::hash_types::FilterHeader::from_byte_array(header),
+        ))
+    }
+
     /// Fetch general blockchain state information from Dash Core.
     pub async fn get_blockchain_info(
         &self,
@@ -455,6 +496,29 @@ impl CoreClient {
     }
 }
 
+/// Decode the filter and the filter header of a `getblockfilter` response.
+pub(crate) fn parse_block_filter(
+    result: &serde_json::Value,
+) -> DAPIResult<(Vec<u8>, dashcore::hash_types::FilterHeader)> {
+    let filter_hex = result
+        .get("filter")
+        .and_then(|filter| filter.as_str())
+        .ok_or_else(|| DapiError::invalid_data("getblockfilter response has no filter"))?;
+    let header_hex = result
+        .get("header")
+        .and_then(|header| header.as_str())
+        .ok_or_else(|| DapiError::invalid_data("getblockfilter response has no header"))?;
+
+    let filter = hex::decode(filter_hex)
+        .map_err(|e| DapiError::invalid_data(format!("invalid block filter: {}", e)))?;
+    // Core returns the header in the reversed byte order of hashes, which `FromStr` expects
+    let header = header_hex
+        .parse()
+        .map_err(|e| DapiError::invalid_data(format!("invalid filter header: {}", e)))?;
+
+    Ok((filter, header))
+}
+
 #[derive(Debug)]
 struct CoreRpcAccessGuard {
     semaphore: Arc<Semaphore>,
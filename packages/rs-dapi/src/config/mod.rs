@@ -96,6 +96,18 @@ pub struct DriveConfig {
     /// URI for connecting to the Drive service
     #[serde(rename = "dapi_drive_uri")]
     pub uri: String,
+    /// Maximum number of upstream workers serving document subscriptions from Drive
+    #[serde(
+        rename = "dapi_drive_subscription_max_upstreams",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub subscription_max_upstreams: usize,
+    /// Number of events queued per document subscriber before it is disconnected
+    #[serde(
+        rename = "dapi_drive_subscription_subscriber_capacity",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub subscription_subscriber_capacity: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -149,6 +161,8 @@ impl Default for DriveConfig {
     fn default() -> Self {
         Self {
             uri: "http://127.0.0.1:6000".to_string(),
+            subscription_max_upstreams: 2,
+            subscription_subscriber_capacity: 256,
         }
     }
 }
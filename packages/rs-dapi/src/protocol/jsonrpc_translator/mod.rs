@@ -1,5 +1,7 @@
+mod base64_bytes;
 mod error;
 mod params;
+mod platform;
 mod types;
 
 use dapi_grpc::core::v0::BroadcastTransactionRequest;
@@ -8,6 +10,7 @@ use serde_json::Value;
 
 use crate::error::{DapiError, DapiResult};
 
+pub use platform::PlatformCall;
 pub use types::{JsonRpcError, JsonRpcRequest, JsonRpcResponse};
 
 #[derive(Debug, Default, Clone)]
@@ -20,6 +23,7 @@ pub enum JsonRpcCall {
     CoreGetBestBlockHash,
     CoreGetBlockHash { height: u32 },
     CoreBroadcastTransaction(BroadcastTransactionRequest),
+    Platform(PlatformCall),
 }
 
 impl JsonRpcTranslator {
@@ -54,7 +58,12 @@ impl JsonRpcTranslator {
                 };
                 Ok(JsonRpcCall::CoreBroadcastTransaction(req))
             }
-            _ => Err(DapiError::MethodNotFound("Method not found".to_string())),
+            method => match PlatformCall::parse(method, json_rpc.params) {
+                Some(call) => call
+                    .map(JsonRpcCall::Platform)
+                    .map_err(DapiError::InvalidArgument),
+                None => Err(DapiError::MethodNotFound("Method not found".to_string())),
+            },
         }
     }
 
@@ -66,8 +75,7 @@ impl JsonRpcTranslator {
         response: GetStatusResponse,
         id: Option<Value>,
     ) -> DapiResult<JsonRpcResponse> {
-        let result = serde_json::to_value(&response)
-            .map_err(|e| DapiError::Internal(format!("Failed to serialize response: {}", e)))?;
+        let result = platform::response_to_json(&response)?;
         Ok(JsonRpcResponse::ok(result, id))
     }
 
@@ -251,6 +259,99 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn translate_platform_query_request() {
+        let t = JsonRpcTranslator::new();
+        let req = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "getIdentity".to_string(),
+            params: Some(json!({"version": {"v0": {"id": vec![1u8; 32], "prove": true}}})),
+            id: Some(json!(8)),
+        };
+        let call = t.translate_request(req).await.expect("translate ok");
+        match call {
+            JsonRpcCall::Platform(PlatformCall::GetIdentity(r)) => {
+                let Some(dapi_grpc::platform::v0::get_identity_request::Version::V0(v0)) =
+                    r.version
+                else {
+                    panic!("expected v0 request");
+                };
+                assert_eq!(v0.id, vec![1; 32]);
+                assert!(v0.prove);
+            }
+            _ => panic!("expected Platform GetIdentity"),
+        }
+    }
+
+    #[tokio::test]
+    async fn translate_platform_query_invalid_params_errors() {
+        let t = JsonRpcTranslator::new();
+        let req = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "getTokenStatuses".to_string(),
+            params: Some(json!({"version": {"v0": {"token_ids": "nope"}}})),
+            id: Some(json!(9)),
+        };
+        let err = t.translate_request(req).await.unwrap_err();
+        match err {
+            DapiError::InvalidArgument(msg) => assert!(msg.contains("invalid params")),
+            _ => panic!("expected InvalidArgument"),
+        }
+    }
+
+    #[tokio::test]
+    async fn translate_unknown_method_errors() {
+        let t = JsonRpcTranslator::new();
+        let req = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "getNothing".to_string(),
+            params: None,
+            id: Some(json!(10)),
+        };
+        let err = t.translate_request(req).await.unwrap_err();
+        assert!(matches!(err, DapiError::MethodNotFound(_)));
+    }
+
+    #[test]
+    fn platform_response_proof_is_base64() {
+        use dapi_grpc::platform::v0::get_identity_response::{
+            GetIdentityResponseV0, Version, get_identity_response_v0,
+        };
+        use dapi_grpc::platform::v0::{GetIdentityResponse, Proof};
+
+        let response = GetIdentityResponse {
+            version: Some(Version::V0(GetIdentityResponseV0 {
+                result: Some(get_identity_response_v0::Result::Proof(Proof {
+                    grovedb_proof: vec![0xff, 0x00],
+                    quorum_hash: vec![1, 2, 3],
+                    ..Default::default()
+                })),
+                metadata: None,
+            })),
+        };
+        let value = super::platform::response_to_json(&response).expect("serialize ok");
+        let proof = &value["version"]["v0"]["result"]["proof"];
+        assert_eq!(proof["grovedb_proof"], json!("/wA="));
+        assert_eq!(proof["quorum_hash"], json!("AQID"));
+    }
+
+    #[test]
+    fn platform_call_methods_match_names() {
+        let call = PlatformCall::parse(
+            "getTokenEvents",
+            Some(json!({"version": {"v0": {
+                "token_id": vec![0u8; 32],
+                "event_type": 0,
+                "order_ascending": false,
+                "prove": false,
+            }}})),
+        )
+        .expect("platform method")
+        .expect("valid params");
+        assert_eq!(call.method(), "getTokenEvents");
+        assert!(PlatformCall::parse("getBestBlockHash", None).is_none());
+    }
+
     #[test]
     fn parse_send_raw_tx_params_variants() {
         use super::params::parse_send_raw_tx_params;
@@ -1,5 +1,8 @@
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 
+use super::base64_bytes;
+
 fn parse_bool_flag(value: Option<&Value>, name: &str) -> Result<bool, String> {
     match value {
         Some(Value::Bool(b)) => Ok(*b),
@@ -71,3 +74,15 @@ pub fn parse_send_raw_tx_params(params: Option<Value>) -> Result<(Vec<u8>, bool,
         _ => Err("params must be an array or hex string".to_string()),
     }
 }
+
+/// Deserialize JSON-RPC params into a gRPC request message.
+/// Params must be an object holding the message fields, byte fields are given as base64 strings
+/// or arrays of numbers.
+pub fn parse_message_params<T: DeserializeOwned>(params: Option<Value>) -> Result<T, String> {
+    let map = match params {
+        Some(Value::Object(map)) => map,
+        _ => return Err("params must be object".to_string()),
+    };
+
+    base64_bytes::from_value(Value::Object(map)).map_err(|e| format!("invalid params: {e}"))
+}
@@ -0,0 +1,129 @@
+use dapi_grpc::platform::v0 as platform_proto;
+use dapi_grpc::platform::v0::platform_server::Platform;
+use serde_json::Value;
+
+use crate::error::{DapiError, DapiResult};
+use crate::metrics::MethodLabel;
+
+use super::base64_bytes;
+use super::params::parse_message_params;
+
+/// Generate [`PlatformCall`] from `"jsonRpcMethod" => Variant(Request) => grpc_method` entries.
+///
+/// Every unary Platform gRPC method is exposed under its proto name, taking the request message as
+/// params and returning the response message as result.
+macro_rules! platform_calls {
+    ($($json_method:literal => $variant:ident($request:ty) => $grpc_method:ident,)*) => {
+        /// Platform gRPC calls reachable through JSON-RPC, named after their proto methods
+        #[derive(Debug)]
+        pub enum PlatformCall {
+            $($variant($request),)*
+        }
+
+        impl PlatformCall {
+            /// Parse the params of a JSON-RPC Platform method into its gRPC request.
+            /// Returns `None` when the method is not a Platform method.
+            pub fn parse(method: &str, params: Option<Value>) -> Option<Result<Self, String>> {
+                match method {
+                    $($json_method => Some(parse_message_params(params).map(Self::$variant)),)*
+                    _ => None,
+                }
+            }
+
+            /// Name of the JSON-RPC method this call was parsed from.
+            pub fn method(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(_) => $json_method,)*
+                }
+            }
+
+            /// Execute the call against the Platform service and serialize the response to JSON.
+            /// Returns the metrics label of the request along with the result.
+            pub async fn execute<P: Platform>(
+                self,
+                platform: &P,
+            ) -> (MethodLabel, DapiResult<Value>) {
+                match self {
+                    $(Self::$variant(request) => {
+                        let method_label = crate::metrics::method_label(&request);
+                        let mut tonic_request = dapi_grpc::tonic::Request::new(request);
+                        crate::metrics::attach_method_label(
+                            tonic_request.extensions_mut(),
+                            method_label.clone(),
+                        );
+                        let result = match platform.$grpc_method(tonic_request).await {
+                            Ok(response) => response_to_json(&response.into_inner()),
+                            Err(status) => Err(status.into()),
+                        };
+                        (method_label, result)
+                    })*
+                }
+            }
+        }
+    };
+}
+
+platform_calls! {
+    "broadcastStateTransition" => BroadcastStateTransition(platform_proto::BroadcastStateTransitionRequest) => broadcast_state_transition,
+    "waitForStateTransitionResult" => WaitForStateTransitionResult(platform_proto::WaitForStateTransitionResultRequest) => wait_for_state_transition_result,
+    "simulateStateTransition" => SimulateStateTransition(platform_proto::SimulateStateTransitionRequest) => simulate_state_transition,
+    "getIdentity" => GetIdentity(platform_proto::GetIdentityRequest) => get_identity,
+    "getIdentityKeys" => GetIdentityKeys(platform_proto::GetIdentityKeysRequest) => get_identity_keys,
+    "getIdentitiesContractKeys" => GetIdentitiesContractKeys(platform_proto::GetIdentitiesContractKeysRequest) => get_identities_contract_keys,
+    "getIdentityNonce" => GetIdentityNonce(platform_proto::GetIdentityNonceRequest) => get_identity_nonce,
+    "getIdentityContractNonce" => GetIdentityContractNonce(platform_proto::GetIdentityContractNonceRequest) => get_identity_contract_nonce,
+    "getIdentityStateTransitions" => GetIdentityStateTransitions(platform_proto::GetIdentityStateTransitionsRequest) => get_identity_state_transitions,
+    "getIdentityBalance" => GetIdentityBalance(platform_proto::GetIdentityBalanceRequest) => get_identity_balance,
+    "getIdentitiesBalances" => GetIdentitiesBalances(platform_proto::GetIdentitiesBalancesRequest) => get_identities_balances,
+    "getIdentityBalanceAndRevision" => GetIdentityBalanceAndRevision(platform_proto::GetIdentityBalanceAndRevisionRequest) => get_identity_balance_and_revision,
+    "getIdentityByPublicKeyHash" => GetIdentityByPublicKeyHash(platform_proto::GetIdentityByPublicKeyHashRequest) => get_identity_by_public_key_hash,
+    "getIdentityByNonUniquePublicKeyHash" => GetIdentityByNonUniquePublicKeyHash(platform_proto::GetIdentityByNonUniquePublicKeyHashRequest) => get_identity_by_non_unique_public_key_hash,
+    "getEvonodesProposedEpochBlocksByIds" => GetEvonodesProposedEpochBlocksByIds(platform_proto::GetEvonodesProposedEpochBlocksByIdsRequest) => get_evonodes_proposed_epoch_blocks_by_ids,
+    "getEvonodesProposedEpochBlocksByRange" => GetEvonodesProposedEpochBlocksByRange(platform_proto::GetEvonodesProposedEpochBlocksByRangeRequest) => get_evonodes_proposed_epoch_blocks_by_range,
+    "getDataContract" => GetDataContract(platform_proto::GetDataContractRequest) => get_data_contract,
+    "getDataContractHistory" => GetDataContractHistory(platform_proto::GetDataContractHistoryRequest) => get_data_contract_history,
+    "getDataContracts" => GetDataContracts(platform_proto::GetDataContractsRequest) => get_data_contracts,
+    "getDocuments" => GetDocuments(platform_proto::GetDocumentsRequest) => get_documents,
+    "getDocumentsAggregate" => GetDocumentsAggregate(platform_proto::GetDocumentsAggregateRequest) => get_documents_aggregate,
+    "getConsensusParams" => GetConsensusParams(platform_proto::GetConsensusParamsRequest) => get_consensus_params,
+    "getProtocolVersionUpgradeState" => GetProtocolVersionUpgradeState(platform_proto::GetProtocolVersionUpgradeStateRequest) => get_protocol_version_upgrade_state,
+    "getProtocolVersionUpgradeVoteStatus" => GetProtocolVersionUpgradeVoteStatus(platform_proto::GetProtocolVersionUpgradeVoteStatusRequest) => get_protocol_version_upgrade_vote_status,
+    "getEpochsInfo" => GetEpochsInfo(platform_proto::GetEpochsInfoRequest) => get_epochs_info,
+    "getFinalizedEpochInfos" => GetFinalizedEpochInfos(platform_proto::GetFinalizedEpochInfosRequest) => get_finalized_epoch_infos,
+    "getBlockStateTransitions" => GetBlockStateTransitions(platform_proto::GetBlockStateTransitionsRequest) => get_block_state_transitions,
+    "getPathElements" => GetPathElements(platform_proto::GetPathElementsRequest) => get_path_elements,
+    "getTotalCreditsInPlatform" => GetTotalCreditsInPlatform(platform_proto::GetTotalCreditsInPlatformRequest) => get_total_credits_in_platform,
+    "getCurrentQuorumsInfo" => GetCurrentQuorumsInfo(platform_proto::GetCurrentQuorumsInfoRequest) => get_current_quorums_info,
+    "getContestedResources" => GetContestedResources(platform_proto::GetContestedResourcesRequest) => get_contested_resources,
+    "getPrefundedSpecializedBalance" => GetPrefundedSpecializedBalance(platform_proto::GetPrefundedSpecializedBalanceRequest) => get_prefunded_specialized_balance,
+    "getContestedResourceVoteState" => GetContestedResourceVoteState(platform_proto::GetContestedResourceVoteStateRequest) => get_contested_resource_vote_state,
+    "getContestedResourceVotersForIdentity" => GetContestedResourceVotersForIdentity(platform_proto::GetContestedResourceVotersForIdentityRequest) => get_contested_resource_voters_for_identity,
+    "getContestedResourceIdentityVotes" => GetContestedResourceIdentityVotes(platform_proto::GetContestedResourceIdentityVotesRequest) => get_contested_resource_identity_votes,
+    "getVotePollsByEndDate" => GetVotePollsByEndDate(platform_proto::GetVotePollsByEndDateRequest) => get_vote_polls_by_end_date,
+    "getYesNoAbstainVotePollState" => GetYesNoAbstainVotePollState(platform_proto::GetYesNoAbstainVotePollStateRequest) => get_yes_no_abstain_vote_poll_state,
+    "getIdentityTokenBalances" => GetIdentityTokenBalances(platform_proto::GetIdentityTokenBalancesRequest) => get_identity_token_balances,
+    "getIdentitiesTokenBalances" => GetIdentitiesTokenBalances(platform_proto::GetIdentitiesTokenBalancesRequest) => get_identities_token_balances,
+    "getIdentityTokenInfos" => GetIdentityTokenInfos(platform_proto::GetIdentityTokenInfosRequest) => get_identity_token_infos,
+    "getIdentitiesTokenInfos" => GetIdentitiesTokenInfos(platform_proto::GetIdentitiesTokenInfosRequest) => get_identities_token_infos,
+    "getTokenStatuses" => GetTokenStatuses(platform_proto::GetTokenStatusesRequest) => get_token_statuses,
+    "getTokenDirectPurchasePrices" => GetTokenDirectPurchasePrices(platform_proto::GetTokenDirectPurchasePricesRequest) => get_token_direct_purchase_prices,
+    "getTokenContractInfo" => GetTokenContractInfo(platform_proto::GetTokenContractInfoRequest) => get_token_contract_info,
+    "getTokenPreProgrammedDistributions" => GetTokenPreProgrammedDistributions(platform_proto::GetTokenPreProgrammedDistributionsRequest) => get_token_pre_programmed_distributions,
+    "getTokenPerpetualDistributionLastClaim" => GetTokenPerpetualDistributionLastClaim(platform_proto::GetTokenPerpetualDistributionLastClaimRequest) => get_token_perpetual_distribution_last_claim,
+    "getTokenTotalSupply" => GetTokenTotalSupply(platform_proto::GetTokenTotalSupplyRequest) => get_token_total_supply,
+    "getTokenHolders" => GetTokenHolders(platform_proto::GetTokenHoldersRequest) => get_token_holders,
+    "getTokenOrderBook" => GetTokenOrderBook(platform_proto::GetTokenOrderBookRequest) => get_token_order_book,
+    "getTokenVestings" => GetTokenVestings(platform_proto::GetTokenVestingsRequest) => get_token_vestings,
+    "getTokenAllowances" => GetTokenAllowances(platform_proto::GetTokenAllowancesRequest) => get_token_allowances,
+    "getTokenEvents" => GetTokenEvents(platform_proto::GetTokenEventsRequest) => get_token_events,
+    "getGroupInfo" => GetGroupInfo(platform_proto::GetGroupInfoRequest) => get_group_info,
+    "getGroupInfos" => GetGroupInfos(platform_proto::GetGroupInfosRequest) => get_group_infos,
+    "getGroupActions" => GetGroupActions(platform_proto::GetGroupActionsRequest) => get_group_actions,
+    "getGroupActionSigners" => GetGroupActionSigners(platform_proto::GetGroupActionSignersRequest) => get_group_action_signers,
+}
+
+/// Serialize a gRPC response into its JSON-RPC result, encoding bytes as base64 strings.
+pub fn response_to_json<T: serde::Serialize>(response: &T) -> DapiResult<Value> {
+    base64_bytes::to_value(response)
+        .map_err(|e| DapiError::Internal(format!("Failed to serialize response: {}", e)))
+}
@@ -108,6 +108,7 @@ impl TimeoutLayer {
             "/org.dash.platform.dapi.v0.Core/subscribeToMasternodeList",
             "/org.dash.platform.dapi.v0.Platform/waitForStateTransitionResult",
             "/org.dash.platform.dapi.v0.Platform/subscribePlatformEvents",
+            "/org.dash.platform.dapi.v0.Platform/subscribeDocuments",
         ];
 
         // Check if this is a known streaming method
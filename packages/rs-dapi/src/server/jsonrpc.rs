@@ -7,10 +7,10 @@ use tokio::net::TcpListener;
 use tower_http::cors::CorsLayer;
 use tracing::info;
 
-use crate::error::DAPIResult;
+use crate::error::{DAPIResult, DapiError};
 use crate::logging::middleware::AccessLogLayer;
 use crate::metrics::MetricsLayer;
-use crate::protocol::{JsonRpcCall, JsonRpcRequest};
+use crate::protocol::{JsonRpcCall, JsonRpcRequest, JsonRpcResponse, JsonRpcTranslator};
 
 use dapi_grpc::core::v0::core_server::Core;
 use dapi_grpc::platform::v0::platform_server::Platform;
@@ -67,7 +67,7 @@ async fn handle_jsonrpc_request(
             let error_response = state.translator.error_response(e, id.clone());
             return respond_with_method(
                 crate::metrics::MethodLabel::from_owned(requested_method),
-                Json(serde_json::to_value(error_response).unwrap_or_default()),
+                json_rpc_body(&state.translator, error_response),
             );
         }
     };
@@ -87,7 +87,7 @@ async fn handle_jsonrpc_request(
                     let error_response = state.translator.error_response(e, id.clone());
                     return respond_with_method(
                         method_label,
-                        Json(serde_json::to_value(error_response).unwrap_or_default()),
+                        json_rpc_body(&state.translator, error_response),
                     );
                 }
             };
@@ -99,13 +99,13 @@ async fn handle_jsonrpc_request(
             {
                 Ok(json_rpc_response) => respond_with_method(
                     method_label.clone(),
-                    Json(serde_json::to_value(json_rpc_response).unwrap_or_default()),
+                    json_rpc_body(&state.translator, json_rpc_response),
                 ),
                 Err(e) => {
                     let error_response = state.translator.error_response(e, id.clone());
                     respond_with_method(
                         method_label,
-                        Json(serde_json::to_value(error_response).unwrap_or_default()),
+                        json_rpc_body(&state.translator, error_response),
                     )
                 }
             }
@@ -128,16 +128,13 @@ async fn handle_jsonrpc_request(
                     let ok = state
                         .translator
                         .ok_response(serde_json::json!(txid), id.clone());
-                    respond_with_method(
-                        method_label,
-                        Json(serde_json::to_value(ok).unwrap_or_default()),
-                    )
+                    respond_with_method(method_label, json_rpc_body(&state.translator, ok))
                 }
                 Err(e) => {
                     let error_response = state.translator.error_response(e, id.clone());
                     respond_with_method(
                         method_label,
-                        Json(serde_json::to_value(error_response).unwrap_or_default()),
+                        json_rpc_body(&state.translator, error_response),
                     )
                 }
             }
@@ -161,7 +158,7 @@ async fn handle_jsonrpc_request(
                     let error_response = state.translator.error_response(e, id.clone());
                     return respond_with_method(
                         method_label,
-                        Json(serde_json::to_value(error_response).unwrap_or_default()),
+                        json_rpc_body(&state.translator, error_response),
                     );
                 }
             };
@@ -172,10 +169,7 @@ async fn handle_jsonrpc_request(
             let ok = state
                 .translator
                 .ok_response(serde_json::json!(best_block_hash_hex), id.clone());
-            respond_with_method(
-                method_label,
-                Json(serde_json::to_value(ok).unwrap_or_default()),
-            )
+            respond_with_method(method_label, json_rpc_body(&state.translator, ok))
         }
         JsonRpcCall::CoreGetBlockHash { height } => {
             let result = state.core_service.core_client.get_block_hash(height).await;
@@ -188,7 +182,7 @@ async fn handle_jsonrpc_request(
                         crate::metrics::MethodLabel::from_owned(
                             "CoreClient::get_block_hash".to_string(),
                         ),
-                        Json(serde_json::to_value(ok).unwrap_or_default()),
+                        json_rpc_body(&state.translator, ok),
                     )
                 }
                 Err(e) => {
@@ -197,11 +191,47 @@ async fn handle_jsonrpc_request(
                         crate::metrics::MethodLabel::from_owned(
                             "CoreClient::get_block_hash".to_string(),
                         ),
-                        Json(serde_json::to_value(error_response).unwrap_or_default()),
+                        json_rpc_body(&state.translator, error_response),
                     )
                 }
             }
         }
+        JsonRpcCall::Platform(platform_call) => {
+            let (method_label, result) = platform_call.execute(&state.platform_service).await;
+            let json_rpc_response = match result {
+                Ok(value) => state.translator.ok_response(value, id.clone()),
+                Err(e) => state.translator.error_response(e, id.clone()),
+            };
+            respond_with_method(
+                method_label,
+                json_rpc_body(&state.translator, json_rpc_response),
+            )
+        }
+    }
+}
+
+/// Serialize a JSON-RPC response, replacing it with an internal error when serialization fails.
+fn json_rpc_body(translator: &JsonRpcTranslator, response: JsonRpcResponse) -> Json<Value> {
+    match serde_json::to_value(&response) {
+        Ok(value) => Json(value),
+        Err(e) => {
+            let error_response = translator.error_response(
+                DapiError::Internal(format!("Failed to serialize response: {}", e)),
+                response.id,
+            );
+            let error = error_response.error.map(|error| {
+                serde_json::json!({
+                    "code": error.code,
+                    "message": error.message,
+                    "data": error.data,
+                })
+            });
+            Json(serde_json::json!({
+                "jsonrpc": error_response.jsonrpc,
+                "error": error,
+                "id": error_response.id,
+            }))
+        }
     }
 }
 
@@ -7,8 +7,9 @@ use crate::services::streaming_service::StreamingServiceImpl;
 use dapi_grpc::core::v0::{
     BlockHeadersWithChainLocksRequest, BlockHeadersWithChainLocksResponse,
     BroadcastTransactionRequest, BroadcastTransactionResponse, GetBestBlockHeightRequest,
-    GetBestBlockHeightResponse, GetBlockRequest, GetBlockResponse, GetBlockchainStatusRequest,
-    GetBlockchainStatusResponse, GetEstimatedTransactionFeeRequest,
+    GetBestBlockHeightResponse, GetBlockFilterHeadersRequest, GetBlockFilterHeadersResponse,
+    GetBlockFiltersRequest, GetBlockFiltersResponse, GetBlockRequest, GetBlockResponse,
+    GetBlockchainStatusRequest, GetBlockchainStatusResponse, GetEstimatedTransactionFeeRequest,
     GetEstimatedTransactionFeeResponse, GetMasternodeStatusRequest, GetMasternodeStatusResponse,
     GetTransactionRequest, GetTransactionResponse, MasternodeListRequest, MasternodeListResponse,
     TransactionsWithProofsRequest, TransactionsWithProofsResponse, core_server::Core,
@@ -540,6 +541,53 @@ impl Core for CoreServiceImpl {
         result
     }
 
+    /// Return BIP158 compact block filters for a range of blocks.
+    async fn get_block_filters(
+        &self,
+        request: Request<GetBlockFiltersRequest>,
+    ) -> Result<Response<GetBlockFiltersResponse>, Status> {
+        trace!("Received get_block_filters request");
+        let method = type_name_of_val(request.get_ref());
+        let start_height = request.get_ref().start_height;
+        let count = request.get_ref().count;
+        let result = self.streaming_service.get_block_filters_impl(request).await;
+
+        match &result {
+            Ok(resp) => info!(
+                method,
+                start_height,
+                count,
+                filters = resp.get_ref().filters.len(),
+                "request succeeded"
+            ),
+            Err(status) => warn!(method, start_height, count, error = %status, "request failed"),
+        }
+
+        result
+    }
+
+    /// Return the BIP157 filter header chain for a range of blocks.
+    async fn get_block_filter_headers(
+        &self,
+        request: Request<GetBlockFilterHeadersRequest>,
+    ) -> Result<Response<GetBlockFilterHeadersResponse>, Status> {
+        trace!("Received get_block_filter_headers request");
+        let method = type_name_of_val(request.get_ref());
+        let start_height = request.get_ref().start_height;
+        let count = request.get_ref().count;
+        let result = self
+            .streaming_service
+            .get_block_filter_headers_impl(request)
+            .await;
+
+        match &result {
+            Ok(_) => info!(method, start_height, count, "request succeeded"),
+            Err(status) => warn!(method, start_height, count, error = %status, "request failed"),
+        }
+
+        result
+    }
+
     /// Stream block headers with optional chain locks, selecting optimal delivery mode.
     async fn subscribe_to_block_headers_with_chain_locks(
         &self,
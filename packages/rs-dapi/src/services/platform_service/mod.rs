@@ -4,14 +4,17 @@
 mod broadcast_state_transition;
 mod error_mapping;
 mod get_status;
+mod subscribe_documents;
 mod wait_for_state_transition_result;
 
 use dapi_grpc::platform::v0::platform_server::Platform;
 use dapi_grpc::platform::v0::{
     BroadcastStateTransitionRequest, BroadcastStateTransitionResponse, GetStatusRequest,
-    GetStatusResponse, WaitForStateTransitionResultRequest, WaitForStateTransitionResultResponse,
+    GetStatusResponse, SubscribeDocumentsRequest, WaitForStateTransitionResultRequest,
+    WaitForStateTransitionResultResponse,
 };
 use dapi_grpc::tonic::{Request, Response, Status};
+use dash_event_bus::EventMux;
 use futures::FutureExt;
 use std::any::type_name_of_val;
 use std::future::Future;
@@ -22,6 +25,7 @@ use tokio::time::sleep;
 use tracing::{info, trace, warn};
 
 pub use error_mapping::TenderdashStatus;
+use subscribe_documents::{DocumentsResponseStream, DriveDocumentsConnector};
 
 /// Macro to generate Platform trait method implementations that delegate to DriveClient
 ///
@@ -99,6 +103,8 @@ pub struct PlatformServiceImpl {
     pub config: Arc<Config>,
     pub platform_cache: crate::cache::LruResponseCache,
     pub subscriber_manager: Arc<crate::services::streaming_service::SubscriberManager>,
+    /// Fans client document subscriptions into a bounded set of Drive streams
+    documents_mux: EventMux<DriveDocumentsConnector>,
     #[allow(dead_code)]
     // workers - dropping will cancel all spawned tasks
     workers: Workers,
@@ -146,6 +152,7 @@ impl PlatformServiceImpl {
             .await;
 
         let platform_cache_bytes = config.dapi.platform_cache_bytes;
+        let documents_mux = subscribe_documents::documents_mux(drive_client.clone(), &config);
 
         Self {
             drive_client,
@@ -158,6 +165,7 @@ impl PlatformServiceImpl {
                 invalidation_subscription,
             ),
             subscriber_manager,
+            documents_mux,
             workers,
         }
     }
@@ -165,6 +173,8 @@ impl PlatformServiceImpl {
 
 #[async_trait::async_trait]
 impl Platform for PlatformServiceImpl {
+    type subscribeDocumentsStream = DocumentsResponseStream;
+
     // Manually implemented methods
 
     /// Get the status of the whole system
@@ -247,6 +257,29 @@ impl Platform for PlatformServiceImpl {
         }
     }
 
+    /// Subscribe to document transitions of committed blocks
+    ///
+    /// Drive matches the document transitions; equal subscriptions share one Drive stream.
+    /// See [`PlatformServiceImpl::subscribe_documents_impl`] for implementation details.
+    async fn subscribe_documents(
+        &self,
+        request: Request<SubscribeDocumentsRequest>,
+    ) -> Result<Response<Self::subscribeDocumentsStream>, Status> {
+        let method = type_name_of_val(request.get_ref());
+        trace!(method, "Received subscribe_documents request");
+        let stream = self.subscribe_documents_impl(request.into_inner());
+        info!(method, "subscription started");
+
+        Ok(Response::new(stream))
+    }
+
+    // Responses only depend on the committed state, so they are cached until the next block
+    drive_method!(
+        simulate_state_transition,
+        dapi_grpc::platform::v0::SimulateStateTransitionRequest,
+        dapi_grpc::platform::v0::SimulateStateTransitionResponse
+    );
+
     // Identity-related methods
     drive_method!(
         get_identity,
@@ -275,6 +308,12 @@ impl Platform for PlatformServiceImpl {
         dapi_grpc::platform::v0::GetIdentityContractNonceResponse
     );
 
+    drive_method!(
+        get_identity_state_transitions,
+        dapi_grpc::platform::v0::GetIdentityStateTransitionsRequest,
+        dapi_grpc::platform::v0::GetIdentityStateTransitionsResponse
+    );
+
     drive_method!(
         get_identity_balance,
         dapi_grpc::platform::v0::GetIdentityBalanceRequest,
@@ -344,6 +383,12 @@ impl Platform for PlatformServiceImpl {
         dapi_grpc::platform::v0::GetDocumentsResponse
     );
 
+    drive_method!(
+        get_documents_aggregate,
+        dapi_grpc::platform::v0::GetDocumentsAggregateRequest,
+        dapi_grpc::platform::v0::GetDocumentsAggregateResponse
+    );
+
     // System methods
     drive_method!(
         get_consensus_params,
@@ -375,6 +420,12 @@ impl Platform for PlatformServiceImpl {
         dapi_grpc::platform::v0::GetFinalizedEpochInfosResponse
     );
 
+    drive_method!(
+        get_block_state_transitions,
+        dapi_grpc::platform::v0::GetBlockStateTransitionsRequest,
+        dapi_grpc::platform::v0::GetBlockStateTransitionsResponse
+    );
+
     drive_method!(
         get_path_elements,
         dapi_grpc::platform::v0::GetPathElementsRequest,
@@ -431,6 +482,12 @@ impl Platform for PlatformServiceImpl {
         dapi_grpc::platform::v0::GetVotePollsByEndDateResponse
     );
 
+    drive_method!(
+        get_yes_no_abstain_vote_poll_state,
+        dapi_grpc::platform::v0::GetYesNoAbstainVotePollStateRequest,
+        dapi_grpc::platform::v0::GetYesNoAbstainVotePollStateResponse
+    );
+
     // Token balance methods
     drive_method!(
         get_identity_token_balances,
@@ -495,6 +552,36 @@ impl Platform for PlatformServiceImpl {
         dapi_grpc::platform::v0::GetTokenTotalSupplyResponse
     );
 
+    drive_method!(
+        get_token_holders,
+        dapi_grpc::platform::v0::GetTokenHoldersRequest,
+        dapi_grpc::platform::v0::GetTokenHoldersResponse
+    );
+
+    drive_method!(
+        get_token_order_book,
+        dapi_grpc::platform::v0::GetTokenOrderBookRequest,
+        dapi_grpc::platform::v0::GetTokenOrderBookResponse
+    );
+
+    drive_method!(
+        get_token_vestings,
+        dapi_grpc::platform::v0::GetTokenVestingsRequest,
+        dapi_grpc::platform::v0::GetTokenVestingsResponse
+    );
+
+    drive_method!(
+        get_token_allowances,
+        dapi_grpc::platform::v0::GetTokenAllowancesRequest,
+        dapi_grpc::platform::v0::GetTokenAllowancesResponse
+    );
+
+    drive_method!(
+        get_token_events,
+        dapi_grpc::platform::v0::GetTokenEventsRequest,
+        dapi_grpc::platform::v0::GetTokenEventsResponse
+    );
+
     // Group methods
     drive_method!(
         get_group_info,
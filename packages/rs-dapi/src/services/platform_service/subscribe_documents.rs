@@ -0,0 +1,296 @@
+//! Multiplexed `subscribeDocuments` streams.
+//!
+//! Client subscriptions are not proxied one to one. They go through an [`EventMux`], so equal
+//! subscription requests share a single Drive stream and all Drive streams are spread over a
+//! bounded number of upstream workers. Upstream workers that lose Drive are reconnected and
+//! resubscribed by the multiplexer; a client that can not keep up is disconnected instead of
+//! stalling the other subscribers of the same Drive stream.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::hash::{Hash, Hasher};
+use std::pin::Pin;
+
+use dapi_grpc::Message;
+use dapi_grpc::platform::v0::{SubscribeDocumentsRequest, SubscribeDocumentsResponse};
+use dapi_grpc::tonic::{Code, Status};
+use dash_event_bus::event_mux::{
+    BoxFuture, ConnectResult, EventMux, EventMuxConfig, MuxSubscription, UpstreamCommand,
+    UpstreamConnection, UpstreamConnector, UpstreamEvent,
+};
+use futures::Stream;
+use tokio::sync::mpsc;
+use tokio::task::{AbortHandle, JoinSet};
+use tracing::{debug, trace, warn};
+
+use crate::clients::drive_client::DriveClient;
+use crate::config::Config;
+
+use super::PlatformServiceImpl;
+
+/// Capacity of the command and event queues of one upstream worker
+const UPSTREAM_QUEUE_CAPACITY: usize = 256;
+
+/// Response stream returned to `subscribeDocuments` clients
+pub type DocumentsResponseStream =
+    Pin<Box<dyn Stream<Item = Result<SubscribeDocumentsResponse, Status>> + Send>>;
+
+/// Event delivered by Drive for a document subscription; an error ends the client stream
+pub type DocumentsEvent = Result<SubscribeDocumentsResponse, Status>;
+
+/// Document subscription request, compared by its encoded bytes so that equal requests
+/// share one Drive stream
+#[derive(Debug, Clone)]
+pub struct DocumentsFilter {
+    request: SubscribeDocumentsRequest,
+    encoded: Vec<u8>,
+}
+
+impl DocumentsFilter {
+    /// Wrap a client subscription request
+    pub fn new(request: SubscribeDocumentsRequest) -> Self {
+        let encoded = request.encode_to_vec();
+        Self { request, encoded }
+    }
+}
+
+impl PartialEq for DocumentsFilter {
+    fn eq(&self, other: &Self) -> bool {
+        self.encoded == other.encoded
+    }
+}
+
+impl Eq for DocumentsFilter {}
+
+impl Hash for DocumentsFilter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.encoded.hash(state)
+    }
+}
+
+/// Opens upstream workers that serve document subscriptions from Drive
+pub struct DriveDocumentsConnector {
+    drive_client: DriveClient,
+}
+
+impl UpstreamConnector for DriveDocumentsConnector {
+    type Filter = DocumentsFilter;
+    type Event = DocumentsEvent;
+    type Error = Infallible;
+
+    fn connect(&self) -> BoxFuture<'_, ConnectResult<Self::Filter, Self::Event, Self::Error>> {
+        let drive_client = self.drive_client.clone();
+        Box::pin(async move {
+            let (commands_tx, commands_rx) = mpsc::channel(UPSTREAM_QUEUE_CAPACITY);
+            let (events_tx, events_rx) = mpsc::channel(UPSTREAM_QUEUE_CAPACITY);
+            tokio::spawn(serve_upstream(drive_client, commands_rx, events_tx));
+
+            Ok(UpstreamConnection {
+                commands: commands_tx,
+                events: events_rx,
+            })
+        })
+    }
+}
+
+/// Create the document subscription multiplexer configured for this rs-dapi instance
+pub fn documents_mux(
+    drive_client: DriveClient,
+    config: &Config,
+) -> EventMux<DriveDocumentsConnector> {
+    EventMux::new(
+        DriveDocumentsConnector { drive_client },
+        EventMuxConfig {
+            max_upstreams: config.dapi.drive.subscription_max_upstreams,
+            subscriber_capacity: config.dapi.drive.subscription_subscriber_capacity,
+            ..Default::default()
+        },
+    )
+}
+
+/// Whether a Drive stream error means Drive itself was lost, rather than the subscription
+/// being rejected
+fn is_upstream_loss(status: &Status) -> bool {
+    matches!(
+        status.code(),
+        Code::Unavailable
+            | Code::Cancelled
+            | Code::Aborted
+            | Code::DeadlineExceeded
+            | Code::Unknown
+    )
+}
+
+/// Pump a single Drive document stream into the upstream worker events.
+///
+/// Returns when Drive was lost, so the worker can drop the connection and let the
+/// multiplexer reconnect and resubscribe.
+async fn pump_subscription(
+    drive_client: DriveClient,
+    id: u64,
+    filter: DocumentsFilter,
+    events: mpsc::Sender<UpstreamEvent<DocumentsEvent>>,
+) {
+    let result = async {
+        let mut stream = drive_client
+            .get_client()
+            .subscribe_documents(filter.request)
+            .await?
+            .into_inner();
+        while let Some(response) = stream.message().await? {
+            let event = UpstreamEvent {
+                subscription_id: id,
+                event: Ok(response),
+            };
+            if events.send(event).await.is_err() {
+                return Ok(());
+            }
+        }
+        Err(Status::unavailable(
+            "Drive closed the document subscription",
+        ))
+    }
+    .await;
+
+    let Err(status) = result else {
+        return;
+    };
+
+    if is_upstream_loss(&status) {
+        warn!(subscription_id = id, error = %status, "Drive document subscription lost");
+        return;
+    }
+
+    // The subscription was rejected, e.g. because of an invalid filter; its subscribers
+    // receive the error and the worker keeps serving the other subscriptions
+    debug!(subscription_id = id, error = %status, "Drive rejected document subscription");
+    let _ = events
+        .send(UpstreamEvent {
+            subscription_id: id,
+            event: Err(status),
+        })
+        .await;
+    std::future::pending::<()>().await
+}
+
+/// Serve one upstream worker: open a Drive stream for every subscription assigned to it and
+/// forward their responses, until Drive is lost or the multiplexer drops the worker.
+async fn serve_upstream(
+    drive_client: DriveClient,
+    mut commands: mpsc::Receiver<UpstreamCommand<DocumentsFilter>>,
+    events: mpsc::Sender<UpstreamEvent<DocumentsEvent>>,
+) {
+    let mut subscriptions: HashMap<u64, AbortHandle> = HashMap::new();
+    let mut streams = JoinSet::new();
+
+    loop {
+        tokio::select! {
+            command = commands.recv() => match command {
+                Some(UpstreamCommand::Subscribe { id, filter }) => {
+                    trace!(subscription_id = id, "Opening Drive document subscription");
+                    let handle = streams.spawn(pump_subscription(
+                        drive_client.clone(),
+                        id,
+                        filter,
+                        events.clone(),
+                    ));
+                    if let Some(previous) = subscriptions.insert(id, handle) {
+                        previous.abort();
+                    }
+                }
+                Some(UpstreamCommand::Unsubscribe { id }) => {
+                    trace!(subscription_id = id, "Closing Drive document subscription");
+                    if let Some(handle) = subscriptions.remove(&id) {
+                        handle.abort();
+                    }
+                }
+                // The multiplexer dropped this worker
+                None => return,
+            },
+            Some(joined) = streams.join_next() => {
+                // Aborted subscriptions were already removed; any other finished stream
+                // means Drive was lost, so drop all streams and let the multiplexer reconnect
+                if !joined.is_err_and(|error| error.is_cancelled()) {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Turn a multiplexer subscription into the client response stream.
+///
+/// The stream ends after the first error; it also ends with an error when the multiplexer
+/// disconnected the client for not keeping up with the event rate.
+fn into_response_stream(subscription: MuxSubscription<DocumentsEvent>) -> DocumentsResponseStream {
+    Box::pin(futures::stream::unfold(
+        Some(subscription),
+        |subscription| async move {
+            let mut subscription = subscription?;
+            match subscription.recv().await {
+                Some(Ok(response)) => Some((Ok(response), Some(subscription))),
+                Some(Err(status)) => Some((Err(status), None)),
+                None => Some((
+                    Err(Status::resource_exhausted(
+                        "document subscription closed because the client did not keep up with the event rate",
+                    )),
+                    None,
+                )),
+            }
+        },
+    ))
+}
+
+impl PlatformServiceImpl {
+    /// Subscribe a client to document transitions through the shared multiplexer
+    pub(super) fn subscribe_documents_impl(
+        &self,
+        request: SubscribeDocumentsRequest,
+    ) -> DocumentsResponseStream {
+        let subscription = self.documents_mux.subscribe(DocumentsFilter::new(request));
+        trace!(
+            subscriber_id = subscription.id(),
+            subscribers = self.documents_mux.subscriber_count(),
+            upstream_subscriptions = self.documents_mux.upstream_subscription_count(),
+            "Document subscription added"
+        );
+        into_response_stream(subscription)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dapi_grpc::platform::v0::subscribe_documents_request::{
+        SubscribeDocumentsRequestV0, Version,
+    };
+
+    fn request(document_type: &str) -> SubscribeDocumentsRequest {
+        SubscribeDocumentsRequest {
+            version: Some(Version::V0(SubscribeDocumentsRequestV0 {
+                data_contract_id: vec![1; 32],
+                document_type: document_type.to_string(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    #[test]
+    fn equal_requests_are_equal_filters() {
+        assert_eq!(
+            DocumentsFilter::new(request("note")),
+            DocumentsFilter::new(request("note"))
+        );
+        assert_ne!(
+            DocumentsFilter::new(request("note")),
+            DocumentsFilter::new(request("profile"))
+        );
+    }
+
+    #[test]
+    fn rejected_subscriptions_are_not_upstream_loss() {
+        assert!(is_upstream_loss(&Status::unavailable("down")));
+        assert!(!is_upstream_loss(&Status::invalid_argument("bad filter")));
+        assert!(!is_upstream_loss(&Status::not_found("unknown contract")));
+    }
+}
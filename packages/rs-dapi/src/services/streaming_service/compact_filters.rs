@@ -0,0 +1,294 @@
+//! BIP157/158 compact block filters.
+//!
+//! Filters and filter headers are read from Core's `getblockfilter`, which requires Dash Core to
+//! run with `-blockfilterindex`. Core keeps the index up to date across reorgs, so filters are
+//! never rebuilt from blocks here.
+
+use dapi_grpc::core::v0::get_block_filters_response::BlockFilter as BlockFilterResponse;
+use dapi_grpc::core::v0::{
+    GetBlockFilterHeadersRequest, GetBlockFilterHeadersResponse, GetBlockFiltersRequest,
+    GetBlockFiltersResponse,
+};
+use dapi_grpc::tonic::{Request, Response, Status};
+use dashcore_rpc::dashcore::BlockHash;
+use dashcore_rpc::dashcore::hash_types::FilterHeader;
+use dashcore_rpc::dashcore::hashes::{Hash, sha256d};
+use tracing::trace;
+
+use crate::clients::CoreClient;
+use crate::error::{DAPIResult, DapiError};
+use crate::services::streaming_service::StreamingServiceImpl;
+
+/// Maximum number of filters returned by a single request, as in BIP157 `getcfilters`
+pub(crate) const MAX_FILTERS_PER_REQUEST: u32 = 1000;
+
+/// Maximum number of filter hashes returned by a single request, as in BIP157 `getcfheaders`
+pub(crate) const MAX_FILTER_HEADERS_PER_REQUEST: u32 = 2000;
+
+/// A BIP158 basic filter of a block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactBlockFilter {
+    pub height: u32,
+    pub block_hash: BlockHash,
+    pub filter: Vec<u8>,
+    pub header: FilterHeader,
+}
+
+impl CompactBlockFilter {
+    /// Double SHA256 of the serialized filter
+    pub fn filter_hash(&self) -> [u8; 32] {
+        sha256d::Hash::hash(&self.filter).to_byte_array()
+    }
+}
+
+/// Serves BIP158 compact block filters and BIP157 filter headers from Core's filter index.
+pub struct CompactFilterIndex {
+    core_client: CoreClient,
+}
+
+impl CompactFilterIndex {
+    pub fn new(core_client: CoreClient) -> Self {
+        Self { core_client }
+    }
+
+    /// Filters of `count` blocks starting at `start_height`.
+    pub async fn filters(
+        &self,
+        start_height: u32,
+        count: u32,
+    ) -> DAPIResult<Vec<CompactBlockFilter>> {
+        let mut filters = Vec::with_capacity(count as usize);
+
+        for height in start_height..start_height.saturating_add(count) {
+            let block_hash = self.core_client.get_block_hash(height).await?;
+            let (filter, header) = self.block_filter(height, block_hash).await?;
+
+            filters.push(CompactBlockFilter {
+                height,
+                block_hash,
+                filter,
+                header,
+            });
+        }
+
+        Ok(filters)
+    }
+
+    /// Filter header of the block preceding `start_height` and the filter hashes of `count`
+    /// blocks starting at `start_height`, as in BIP157 `cfheaders`.
+    pub async fn filter_headers(
+        &self,
+        start_height: u32,
+        count: u32,
+    ) -> DAPIResult<(FilterHeader, Vec<[u8; 32]>)> {
+        let previous_header = match start_height.checked_sub(1) {
+            None => FilterHeader::all_zeros(),
+            Some(previous_height) => {
+                let previous_block_hash = self.core_client.get_block_hash(previous_height).await?;
+                let (_, header) = self
+                    .block_filter(previous_height, previous_block_hash)
+                    .await?;
+                header
+            }
+        };
+
+        let filter_hashes = self
+            .filters(start_height, count)
+            .await?
+            .iter()
+            .map(|filter| filter.filter_hash())
+            .collect();
+
+        Ok((previous_header, filter_hashes))
+    }
+
+    async fn block_filter(
+        &self,
+        height: u32,
+        block_hash: BlockHash,
+    ) -> DAPIResult<(Vec<u8>, FilterHeader)> {
+        trace!(height, %block_hash, "compact_filters=get_block_filter");
+
+        self.core_client
+            .get_block_filter(block_hash)
+            .await
+            .map_err(|err| {
+                DapiError::Unavailable(format!(
+                    "filter of block {} is not available: {}",
+                    height, err
+                ))
+            })
+    }
+}
+
+impl StreamingServiceImpl {
+    pub async fn get_block_filters_impl(
+        &self,
+        request: Request<GetBlockFiltersRequest>,
+    ) -> Result<Response<GetBlockFiltersResponse>, Status> {
+        let GetBlockFiltersRequest {
+            start_height,
+            count,
+        } = request.into_inner();
+
+        validate_range(start_height, count, MAX_FILTERS_PER_REQUEST)?;
+
+        let filters = self
+            .compact_filter_index
+            .filters(start_height, count)
+            .await
+            .map_err(Status::from)?
+            .into_iter()
+            .map(|filter| BlockFilterResponse {
+                height: filter.height,
+                block_hash: filter.block_hash.to_byte_array().to_vec(),
+                filter: filter.filter,
+            })
+            .collect();
+
+        Ok(Response::new(GetBlockFiltersResponse { filters }))
+    }
+
+    pub async fn get_block_filter_headers_impl(
+        &self,
+        request: Request<GetBlockFilterHeadersRequest>,
+    ) -> Result<Response<GetBlockFilterHeadersResponse>, Status> {
+        let GetBlockFilterHeadersRequest {
+            start_height,
+            count,
+        } = request.into_inner();
+
+        validate_range(start_height, count, MAX_FILTER_HEADERS_PER_REQUEST)?;
+
+        let (previous_filter_header, filter_hashes) = self
+            .compact_filter_index
+            .filter_headers(start_height, count)
+            .await
+            .map_err(Status::from)?;
+
+        Ok(Response::new(GetBlockFilterHeadersResponse {
+            previous_filter_header: previous_filter_header.to_byte_array().to_vec(),
+            filter_hashes: filter_hashes
+                .into_iter()
+                .map(|hash| hash.to_vec())
+                .collect(),
+        }))
+    }
+}
+
+fn validate_range(start_height: u32, count: u32, max_count: u32) -> Result<(), Status> {
+    if count == 0 {
+        return Err(Status::invalid_argument("count must be greater than 0"));
+    }
+
+    if count > max_count {
+        return Err(Status::invalid_argument(format!(
+            "count must not exceed {}",
+            max_count
+        )));
+    }
+
+    if start_height.checked_add(count).is_none() {
+        return Err(Status::invalid_argument("requested range is out of bounds"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::core_client::parse_block_filter;
+    use dashcore_rpc::dashcore::Block;
+    use dashcore_rpc::dashcore::bip158::{self, BlockFilter};
+    use dashcore_rpc::dashcore::consensus::encode::deserialize;
+
+    /// Testnet genesis block from the BIP158 test vectors
+    const GENESIS_BLOCK_HEX: &str = "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4adae5494dffff001d1aa4ae180101000000010000000000000000000000000000000000000000000000000000000000000000ffffffff4d04ffff001d0104455468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b206f66207365636f6e64206261696c6f757420666f722062616e6b73ffffffff0100f2052a01000000434104678afdb0fe5548271967f1a67130b7105cd6a828e03909a67962e0ea1f61deb649f6bc3f4cef38c4f35504e51ec112de5c384df7ba0b8d578a4c702b6bf11d5fac00000000";
+    const GENESIS_BLOCK_HASH: &str =
+        "000000000933ea01ad0ee984209779baaec3ced90fa3f408719526f8d77f4943";
+    const GENESIS_BASIC_FILTER: &str = "019dfca8";
+    const GENESIS_BASIC_HEADER: &str =
+        "21584579b7eb08997773e5aeff3a7f932700042d0ed2a6129012b7d7ae81b750";
+
+    #[test]
+    fn should_match_bip158_test_vector() {
+        let block: Block =
+            deserialize(&hex::decode(GENESIS_BLOCK_HEX).unwrap()).expect("expected a block");
+        assert_eq!(block.block_hash().to_string(), GENESIS_BLOCK_HASH);
+
+        // The block only spends a coinbase input
+        let expected_filter = BlockFilter::new_script_filter(&block, |outpoint| {
+            Err(bip158::Error::UtxoMissing(*outpoint))
+        })
+        .expect("expected to build the filter");
+        assert_eq!(hex::encode(&expected_filter.content), GENESIS_BASIC_FILTER);
+
+        // The filter and header as returned by Core's `getblockfilter`
+        let (filter, header) = parse_block_filter(&serde_json::json!({
+            "filter": GENESIS_BASIC_FILTER,
+            "header": GENESIS_BASIC_HEADER,
+        }))
+        .expect("expected to parse the response");
+
+        assert_eq!(filter, expected_filter.content);
+        assert_eq!(
+            header,
+            expected_filter.filter_header(&FilterHeader::all_zeros())
+        );
+
+        // Clients chain the served filter hashes into the same header
+        let compact_filter = CompactBlockFilter {
+            height: 0,
+            block_hash: block.block_hash(),
+            filter,
+            header,
+        };
+        let mut chained = compact_filter.filter_hash().to_vec();
+        chained.extend_from_slice(FilterHeader::all_zeros().as_byte_array());
+
+        assert_eq!(
+            FilterHeader::from_byte_array(sha256d::Hash::hash(&chained).to_byte_array()),
+            header
+        );
+
+        // Block hashes are served in their internal byte order
+        assert_eq!(
+            compact_filter.block_hash.to_byte_array().to_vec(),
+            hex::decode(GENESIS_BLOCK_HASH)
+                .unwrap()
+                .into_iter()
+                .rev()
+                .collect::<Vec<u8>>()
+        );
+    }
+
+    #[test]
+    fn parse_block_filter_should_reject_malformed_responses() {
+        assert!(
+            parse_block_filter(&serde_json::json!({ "header": GENESIS_BASIC_HEADER })).is_err()
+        );
+        assert!(
+            parse_block_filter(&serde_json::json!({
+                "filter": "not hex",
+                "header": GENESIS_BASIC_HEADER,
+            }))
+            .is_err()
+        );
+        assert!(
+            parse_block_filter(&serde_json::json!({
+                "filter": GENESIS_BASIC_FILTER,
+                "header": "00",
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn validate_range_should_reject_empty_and_oversized_ranges() {
+        assert!(validate_range(0, 0, MAX_FILTERS_PER_REQUEST).is_err());
+        assert!(validate_range(0, MAX_FILTERS_PER_REQUEST + 1, MAX_FILTERS_PER_REQUEST).is_err());
+        assert!(validate_range(u32::MAX, 2, MAX_FILTERS_PER_REQUEST).is_err());
+        assert!(validate_range(100, MAX_FILTERS_PER_REQUEST, MAX_FILTERS_PER_REQUEST).is_ok());
+    }
+}
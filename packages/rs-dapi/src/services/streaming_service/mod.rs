@@ -3,6 +3,7 @@
 
 mod block_header_stream;
 mod bloom;
+mod compact_filters;
 mod masternode_list_stream;
 mod masternode_list_sync;
 mod subscriber_manager;
@@ -20,6 +21,7 @@ use tokio::sync::broadcast::error::RecvError;
 use tokio::time::{Duration, sleep};
 use tracing::{debug, trace};
 
+pub(crate) use compact_filters::CompactFilterIndex;
 pub(crate) use masternode_list_sync::MasternodeListSync;
 pub(crate) use subscriber_manager::{
     FilterType, StreamingEvent, SubscriberManager, SubscriptionHandle,
@@ -39,6 +41,7 @@ pub struct StreamingServiceImpl {
     pub zmq_listener: Arc<ZmqListener>,
     pub subscriber_manager: Arc<SubscriberManager>,
     pub masternode_list_sync: Arc<MasternodeListSync>,
+    pub compact_filter_index: Arc<CompactFilterIndex>,
     /// Background workers; aborted when the last reference is dropped
     pub workers: Workers,
 }
@@ -85,6 +88,7 @@ impl StreamingServiceImpl {
         ));
         masternode_list_sync.spawn_initial_sync();
         masternode_list_sync.start_chain_lock_listener(subscriber_manager.clone());
+        let compact_filter_index = Arc::new(CompactFilterIndex::new(core_client.clone()));
 
         // Prepare background workers set
         let workers = Workers::new();
@@ -127,6 +131,7 @@ impl StreamingServiceImpl {
             zmq_listener,
             subscriber_manager,
             masternode_list_sync,
+            compact_filter_index,
             workers,
         })
     }
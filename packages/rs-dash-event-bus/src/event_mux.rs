@@ -0,0 +1,802 @@
+//! Upstream multiplexer for Platform events.
+//!
+//! [`EventMux`] fans many downstream subscribers into a bounded set of upstream
+//! streams (for example drive-abci bi-di gRPC event streams):
+//!
+//! - subscriptions with equal filters share a single upstream subscription,
+//! - at most [`EventMuxConfig::max_upstreams`] upstream streams are opened,
+//! - lost upstream streams are reconnected with exponential backoff and all
+//!   subscriptions assigned to them are re-sent,
+//! - every subscriber has its own bounded queue; a subscriber that cannot keep up
+//!   is disconnected instead of stalling the shared upstream stream.
+//!
+//! The multiplexer is transport-agnostic: an [`UpstreamConnector`] opens a pair of
+//! channels carrying [`UpstreamCommand`]s up and [`UpstreamEvent`]s down, so the
+//! gRPC plumbing stays in the service that owns the client.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Debug, Display};
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::task::JoinSet;
+
+const DEFAULT_MAX_UPSTREAMS: usize = 2;
+const DEFAULT_SUBSCRIBER_CAPACITY: usize = 256;
+const DEFAULT_RECONNECT_INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+const DEFAULT_RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Boxed future returned by [`UpstreamConnector::connect`].
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Result of opening an upstream stream.
+pub type ConnectResult<F, E, Err> = Result<UpstreamConnection<F, E>, Err>;
+
+/// Receiving end of the per-upstream command queue.
+type CommandReceiver<F> = mpsc::UnboundedReceiver<UpstreamCommand<F>>;
+
+/// Command sent to an upstream stream.
+///
+/// Subscription ids are allocated by the multiplexer and are unique for the
+/// lifetime of the [`EventMux`], so they can be used verbatim as upstream
+/// client subscription ids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpstreamCommand<F> {
+    /// Start delivering events matching `filter`, tagged with `id`.
+    Subscribe { id: u64, filter: F },
+    /// Stop delivering events for subscription `id`.
+    Unsubscribe { id: u64 },
+}
+
+/// Event received from an upstream stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpstreamEvent<E> {
+    /// Id of the upstream subscription, as sent in [`UpstreamCommand::Subscribe`].
+    pub subscription_id: u64,
+    /// Event payload.
+    pub event: E,
+}
+
+/// A single connected upstream stream.
+///
+/// The stream is considered lost when `events` is closed or when `commands`
+/// no longer accepts messages.
+pub struct UpstreamConnection<F, E> {
+    /// Commands forwarded to the upstream.
+    pub commands: mpsc::Sender<UpstreamCommand<F>>,
+    /// Events produced by the upstream.
+    pub events: mpsc::Receiver<UpstreamEvent<E>>,
+}
+
+/// Opens upstream streams on behalf of [`EventMux`].
+pub trait UpstreamConnector: Send + Sync + 'static {
+    /// Subscription filter; equal filters are de-duplicated into one upstream subscription.
+    type Filter: Clone + Eq + Hash + Debug + Send + Sync + 'static;
+    /// Event delivered to subscribers.
+    type Event: Clone + Send + 'static;
+    /// Connection error.
+    type Error: Display + Send + 'static;
+
+    /// Open a new upstream stream.
+    fn connect(&self) -> BoxFuture<'_, ConnectResult<Self::Filter, Self::Event, Self::Error>>;
+}
+
+/// Configuration of the [`EventMux`].
+#[derive(Debug, Clone)]
+pub struct EventMuxConfig {
+    /// Maximum number of upstream streams opened concurrently.
+    pub max_upstreams: usize,
+    /// Capacity of each downstream subscriber queue.
+    pub subscriber_capacity: usize,
+    /// Delay before the first reconnect attempt after an upstream is lost.
+    pub reconnect_initial_backoff: Duration,
+    /// Upper bound for the reconnect delay; the delay doubles on each failed attempt.
+    pub reconnect_max_backoff: Duration,
+}
+
+impl Default for EventMuxConfig {
+    fn default() -> Self {
+        Self {
+            max_upstreams: DEFAULT_MAX_UPSTREAMS,
+            subscriber_capacity: DEFAULT_SUBSCRIBER_CAPACITY,
+            reconnect_initial_backoff: DEFAULT_RECONNECT_INITIAL_BACKOFF,
+            reconnect_max_backoff: DEFAULT_RECONNECT_MAX_BACKOFF,
+        }
+    }
+}
+
+/// Upstream subscription shared by all subscribers with an equal filter.
+struct UpstreamSubscription<F, E> {
+    filter: F,
+    slot: usize,
+    subscribers: BTreeMap<u64, mpsc::Sender<E>>,
+}
+
+/// Per-upstream bookkeeping.
+struct Slot<F> {
+    commands: mpsc::UnboundedSender<UpstreamCommand<F>>,
+    started: bool,
+    connected: bool,
+}
+
+struct MuxState<F, E> {
+    next_subscriber_id: u64,
+    next_upstream_id: u64,
+    by_filter: HashMap<F, u64>,
+    upstream_subs: BTreeMap<u64, UpstreamSubscription<F, E>>,
+    /// subscriber id -> upstream subscription id
+    subscribers: HashMap<u64, u64>,
+    slots: Vec<Slot<F>>,
+}
+
+/// State shared between the mux handle, subscription handles and upstream workers.
+struct Shared<F, E> {
+    state: Mutex<MuxState<F, E>>,
+}
+
+impl<F, E> Shared<F, E>
+where
+    F: Clone + Eq + Hash + Debug,
+    E: Clone,
+{
+    fn lock(&self) -> MutexGuard<'_, MuxState<F, E>> {
+        // State is kept consistent before any call that could panic, so a poisoned
+        // lock is still safe to use.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Detach a subscriber; drops the upstream subscription when it was the last one.
+    fn remove_subscriber(&self, subscriber_id: u64) {
+        let mut state = self.lock();
+        let Some(upstream_id) = state.subscribers.remove(&subscriber_id) else {
+            return;
+        };
+        tracing::debug!(subscriber_id, upstream_id, "event_mux: removed subscriber");
+        state.detach(upstream_id, subscriber_id);
+    }
+
+    /// Deliver an upstream event to all subscribers of its subscription.
+    fn dispatch(&self, slot: usize, event: UpstreamEvent<E>) {
+        let mut state = self.lock();
+        let Some(sub) = state.upstream_subs.get(&event.subscription_id) else {
+            tracing::trace!(
+                slot,
+                subscription_id = event.subscription_id,
+                "event_mux: event for unknown subscription, ignoring"
+            );
+            return;
+        };
+
+        let mut dead = Vec::new();
+        for (subscriber_id, sender) in sub.subscribers.iter() {
+            match sender.try_send(event.event.clone()) {
+                Ok(()) => {}
+                Err(TrySendError::Full(_)) => {
+                    tracing::warn!(
+                        subscriber_id,
+                        "event_mux: subscriber queue full, removing laggy subscriber to protect others"
+                    );
+                    dead.push(*subscriber_id);
+                }
+                Err(TrySendError::Closed(_)) => dead.push(*subscriber_id),
+            }
+        }
+
+        for subscriber_id in dead {
+            if state.subscribers.remove(&subscriber_id).is_some() {
+                state.detach(event.subscription_id, subscriber_id);
+            }
+        }
+    }
+}
+
+impl<F, E> MuxState<F, E>
+where
+    F: Clone + Eq + Hash + Debug,
+{
+    /// Remove `subscriber_id` from upstream subscription `upstream_id`,
+    /// unsubscribing upstream when no subscribers are left.
+    fn detach(&mut self, upstream_id: u64, subscriber_id: u64) {
+        let Some(sub) = self.upstream_subs.get_mut(&upstream_id) else {
+            return;
+        };
+        sub.subscribers.remove(&subscriber_id);
+        if !sub.subscribers.is_empty() {
+            return;
+        }
+
+        let slot = sub.slot;
+        if let Some(sub) = self.upstream_subs.remove(&upstream_id) {
+            self.by_filter.remove(&sub.filter);
+        }
+        // The worker may already be gone if the mux is shutting down.
+        let _ = self.slots[slot]
+            .commands
+            .send(UpstreamCommand::Unsubscribe { id: upstream_id });
+        tracing::debug!(
+            upstream_id,
+            slot,
+            "event_mux: removed upstream subscription"
+        );
+    }
+
+    /// Pick the least loaded upstream slot.
+    fn least_loaded_slot(&self) -> usize {
+        let mut load = vec![0usize; self.slots.len()];
+        for sub in self.upstream_subs.values() {
+            load[sub.slot] += 1;
+        }
+        load.iter()
+            .enumerate()
+            .min_by_key(|(_, n)| **n)
+            .map(|(slot, _)| slot)
+            .unwrap_or_default()
+    }
+
+    /// Subscriptions currently assigned to `slot`, in id order.
+    fn subscriptions_for_slot(&self, slot: usize) -> Vec<(u64, F)> {
+        self.upstream_subs
+            .iter()
+            .filter(|(_, sub)| sub.slot == slot)
+            .map(|(id, sub)| (*id, sub.filter.clone()))
+            .collect()
+    }
+}
+
+/// Upstream Platform events multiplexer.
+///
+/// Cloning is cheap; all clones share the same upstream streams. Upstream
+/// workers are stopped when the last clone is dropped.
+pub struct EventMux<C: UpstreamConnector> {
+    inner: Arc<MuxInner<C>>,
+}
+
+struct MuxInner<C: UpstreamConnector> {
+    shared: Arc<Shared<C::Filter, C::Event>>,
+    connector: Arc<C>,
+    config: EventMuxConfig,
+    receivers: Mutex<Vec<Option<CommandReceiver<C::Filter>>>>,
+    workers: Mutex<JoinSet<()>>,
+}
+
+impl<C: UpstreamConnector> Clone for EventMux<C> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<C: UpstreamConnector> EventMux<C> {
+    /// Create a new multiplexer. Upstream streams are opened lazily, on first use.
+    pub fn new(connector: C, config: EventMuxConfig) -> Self {
+        let max_upstreams = config.max_upstreams.max(1);
+        let mut slots = Vec::with_capacity(max_upstreams);
+        let mut receivers = Vec::with_capacity(max_upstreams);
+        for _ in 0..max_upstreams {
+            let (tx, rx) = mpsc::unbounded_channel();
+            slots.push(Slot {
+                commands: tx,
+                started: false,
+                connected: false,
+            });
+            receivers.push(Some(rx));
+        }
+
+        let state = MuxState {
+            next_subscriber_id: 0,
+            next_upstream_id: 0,
+            by_filter: HashMap::new(),
+            upstream_subs: BTreeMap::new(),
+            subscribers: HashMap::new(),
+            slots,
+        };
+
+        Self {
+            inner: Arc::new(MuxInner {
+                shared: Arc::new(Shared {
+                    state: Mutex::new(state),
+                }),
+                connector: Arc::new(connector),
+                config,
+                receivers: Mutex::new(receivers),
+                workers: Mutex::new(JoinSet::new()),
+            }),
+        }
+    }
+
+    /// Subscribe to events matching `filter`.
+    ///
+    /// If another subscriber already uses an equal filter, the existing upstream
+    /// subscription is reused. Must be called from within a Tokio runtime.
+    pub fn subscribe(&self, filter: C::Filter) -> MuxSubscription<C::Event> {
+        let (tx, rx) = mpsc::channel(self.inner.config.subscriber_capacity.max(1));
+        let shared = &self.inner.shared;
+
+        let (subscriber_id, spawn_slot) = {
+            let mut state = shared.lock();
+            let subscriber_id = state.next_subscriber_id;
+            state.next_subscriber_id += 1;
+
+            let (upstream_id, slot) = match state.by_filter.get(&filter) {
+                Some(upstream_id) => {
+                    let upstream_id = *upstream_id;
+                    let sub = state
+                        .upstream_subs
+                        .get_mut(&upstream_id)
+                        .expect("by_filter and upstream_subs are kept in sync");
+                    sub.subscribers.insert(subscriber_id, tx);
+                    tracing::debug!(
+                        subscriber_id,
+                        upstream_id,
+                        "event_mux: reusing upstream subscription"
+                    );
+                    (upstream_id, sub.slot)
+                }
+                None => {
+                    let upstream_id = state.next_upstream_id;
+                    state.next_upstream_id += 1;
+                    let slot = state.least_loaded_slot();
+
+                    state.by_filter.insert(filter.clone(), upstream_id);
+                    state.upstream_subs.insert(
+                        upstream_id,
+                        UpstreamSubscription {
+                            filter: filter.clone(),
+                            slot,
+                            subscribers: BTreeMap::from([(subscriber_id, tx)]),
+                        },
+                    );
+                    let _ = state.slots[slot].commands.send(UpstreamCommand::Subscribe {
+                        id: upstream_id,
+                        filter,
+                    });
+                    tracing::debug!(
+                        subscriber_id,
+                        upstream_id,
+                        slot,
+                        "event_mux: added upstream subscription"
+                    );
+                    (upstream_id, slot)
+                }
+            };
+            state.subscribers.insert(subscriber_id, upstream_id);
+
+            let spawn_slot = !state.slots[slot].started;
+            state.slots[slot].started = true;
+            (subscriber_id, spawn_slot.then_some(slot))
+        };
+
+        if let Some(slot) = spawn_slot {
+            self.spawn_worker(slot);
+        }
+
+        MuxSubscription {
+            id: subscriber_id,
+            rx,
+            shared: Arc::clone(shared) as Arc<dyn Unsubscribe>,
+        }
+    }
+
+    /// Number of active downstream subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        self.inner.shared.lock().subscribers.len()
+    }
+
+    /// Number of distinct upstream subscriptions.
+    pub fn upstream_subscription_count(&self) -> usize {
+        self.inner.shared.lock().upstream_subs.len()
+    }
+
+    /// Number of upstream streams currently connected.
+    pub fn connected_upstreams(&self) -> usize {
+        self.inner
+            .shared
+            .lock()
+            .slots
+            .iter()
+            .filter(|slot| slot.connected)
+            .count()
+    }
+
+    fn spawn_worker(&self, slot: usize) {
+        let commands = self
+            .inner
+            .receivers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())[slot]
+            .take();
+        let Some(commands) = commands else {
+            return;
+        };
+
+        let worker = UpstreamWorker {
+            slot,
+            connector: Arc::clone(&self.inner.connector),
+            shared: Arc::clone(&self.inner.shared),
+            commands,
+            initial_backoff: self.inner.config.reconnect_initial_backoff,
+            max_backoff: self
+                .inner
+                .config
+                .reconnect_max_backoff
+                .max(self.inner.config.reconnect_initial_backoff),
+        };
+
+        self.inner
+            .workers
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .spawn(worker.run());
+        tracing::debug!(slot, "event_mux: started upstream worker");
+    }
+}
+
+/// Drives a single upstream stream: connects, re-subscribes and pumps events.
+struct UpstreamWorker<C: UpstreamConnector> {
+    slot: usize,
+    connector: Arc<C>,
+    shared: Arc<Shared<C::Filter, C::Event>>,
+    commands: CommandReceiver<C::Filter>,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl<C: UpstreamConnector> UpstreamWorker<C> {
+    async fn run(mut self) {
+        let mut backoff = self.initial_backoff;
+        loop {
+            match self.connector.connect().await {
+                Ok(connection) => {
+                    backoff = self.initial_backoff;
+                    self.serve(connection).await;
+                    self.set_connected(false);
+                    tracing::warn!(slot = self.slot, "event_mux: upstream stream lost");
+                }
+                Err(error) => {
+                    tracing::warn!(
+                        slot = self.slot,
+                        %error,
+                        ?backoff,
+                        "event_mux: failed to connect upstream"
+                    );
+                }
+            }
+
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(self.max_backoff);
+        }
+    }
+
+    /// Serve a connected upstream until it is lost.
+    async fn serve(&mut self, connection: UpstreamConnection<C::Filter, C::Event>) {
+        let UpstreamConnection {
+            commands: upstream,
+            mut events,
+        } = connection;
+
+        // Queued commands are superseded by the current state; drain them under the
+        // state lock so that nothing is sent twice or missed.
+        let resubscribe = {
+            let mut state = self.shared.lock();
+            while self.commands.try_recv().is_ok() {}
+            state.slots[self.slot].connected = true;
+            state.subscriptions_for_slot(self.slot)
+        };
+        tracing::debug!(
+            slot = self.slot,
+            subscriptions = resubscribe.len(),
+            "event_mux: upstream connected, resubscribing"
+        );
+
+        for (id, filter) in resubscribe {
+            if upstream
+                .send(UpstreamCommand::Subscribe { id, filter })
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+
+        loop {
+            tokio::select! {
+                command = self.commands.recv() => {
+                    let Some(command) = command else { return };
+                    tracing::trace!(slot = self.slot, ?command, "event_mux: forwarding command upstream");
+                    if upstream.send(command).await.is_err() {
+                        return;
+                    }
+                }
+                event = events.recv() => {
+                    let Some(event) = event else { return };
+                    self.shared.dispatch(self.slot, event);
+                }
+            }
+        }
+    }
+
+    fn set_connected(&self, connected: bool) {
+        self.shared.lock().slots[self.slot].connected = connected;
+    }
+}
+
+/// Type-erased removal of subscribers, so that [`MuxSubscription`] does not
+/// depend on the connector type.
+trait Unsubscribe: Send + Sync {
+    fn unsubscribe(&self, subscriber_id: u64);
+}
+
+impl<F, E> Unsubscribe for Shared<F, E>
+where
+    F: Clone + Eq + Hash + Debug + Send,
+    E: Clone + Send,
+{
+    fn unsubscribe(&self, subscriber_id: u64) {
+        self.remove_subscriber(subscriber_id)
+    }
+}
+
+/// Downstream subscription created by [`EventMux::subscribe`].
+///
+/// Dropping the handle unsubscribes; the upstream subscription is removed
+/// once its last subscriber is gone.
+pub struct MuxSubscription<E> {
+    id: u64,
+    rx: mpsc::Receiver<E>,
+    shared: Arc<dyn Unsubscribe>,
+}
+
+impl<E> MuxSubscription<E> {
+    /// Get the unique ID of this subscriber.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Receive the next event.
+    ///
+    /// Returns `None` when the subscriber was disconnected, e.g. because it
+    /// did not keep up with the upstream event rate.
+    pub async fn recv(&mut self) -> Option<E> {
+        self.rx.recv().await
+    }
+}
+
+impl<E> Drop for MuxSubscription<E> {
+    fn drop(&mut self) {
+        self.shared.unsubscribe(self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::time::timeout;
+
+    const WAIT: Duration = Duration::from_millis(500);
+
+    /// Upstream side of a mock connection, handed to the test.
+    struct MockUpstream {
+        commands: mpsc::Receiver<UpstreamCommand<String>>,
+        events: mpsc::Sender<UpstreamEvent<u32>>,
+    }
+
+    impl MockUpstream {
+        async fn next_command(&mut self) -> UpstreamCommand<String> {
+            timeout(WAIT, self.commands.recv())
+                .await
+                .expect("command expected")
+                .expect("upstream command channel closed")
+        }
+    }
+
+    struct MockConnector {
+        connects: Arc<AtomicUsize>,
+        upstreams: mpsc::UnboundedSender<MockUpstream>,
+    }
+
+    impl UpstreamConnector for MockConnector {
+        type Filter = String;
+        type Event = u32;
+        type Error = String;
+
+        fn connect(&self) -> BoxFuture<'_, ConnectResult<String, u32, String>> {
+            Box::pin(async move {
+                self.connects.fetch_add(1, Ordering::SeqCst);
+                let (cmd_tx, cmd_rx) = mpsc::channel(16);
+                let (evt_tx, evt_rx) = mpsc::channel(16);
+                self.upstreams
+                    .send(MockUpstream {
+                        commands: cmd_rx,
+                        events: evt_tx,
+                    })
+                    .map_err(|_| "test finished".to_string())?;
+                Ok(UpstreamConnection {
+                    commands: cmd_tx,
+                    events: evt_rx,
+                })
+            })
+        }
+    }
+
+    fn mux(
+        config: EventMuxConfig,
+    ) -> (
+        EventMux<MockConnector>,
+        mpsc::UnboundedReceiver<MockUpstream>,
+        Arc<AtomicUsize>,
+    ) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let connects = Arc::new(AtomicUsize::new(0));
+        let connector = MockConnector {
+            connects: Arc::clone(&connects),
+            upstreams: tx,
+        };
+        (EventMux::new(connector, config), rx, connects)
+    }
+
+    fn test_config() -> EventMuxConfig {
+        EventMuxConfig {
+            max_upstreams: 1,
+            subscriber_capacity: 4,
+            reconnect_initial_backoff: Duration::from_millis(10),
+            reconnect_max_backoff: Duration::from_millis(20),
+        }
+    }
+
+    async fn next_upstream(rx: &mut mpsc::UnboundedReceiver<MockUpstream>) -> MockUpstream {
+        timeout(WAIT, rx.recv())
+            .await
+            .expect("upstream connection expected")
+            .unwrap()
+    }
+
+    async fn wait_until(mut cond: impl FnMut() -> bool) {
+        for _ in 0..50 {
+            if cond() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(cond(), "condition not met in time");
+    }
+
+    #[tokio::test]
+    async fn equal_filters_share_upstream_subscription() {
+        let (mux, mut upstreams, _) = mux(test_config());
+        let mut a = mux.subscribe("blocks".to_string());
+        let mut b = mux.subscribe("blocks".to_string());
+
+        let mut upstream = next_upstream(&mut upstreams).await;
+        assert_eq!(
+            upstream.next_command().await,
+            UpstreamCommand::Subscribe {
+                id: 0,
+                filter: "blocks".to_string()
+            }
+        );
+        assert_eq!(mux.subscriber_count(), 2);
+        assert_eq!(mux.upstream_subscription_count(), 1);
+
+        upstream
+            .events
+            .send(UpstreamEvent {
+                subscription_id: 0,
+                event: 7,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(timeout(WAIT, a.recv()).await.unwrap(), Some(7));
+        assert_eq!(timeout(WAIT, b.recv()).await.unwrap(), Some(7));
+
+        drop(a);
+        assert_eq!(mux.upstream_subscription_count(), 1);
+        drop(b);
+        assert_eq!(mux.upstream_subscription_count(), 0);
+        assert_eq!(
+            upstream.next_command().await,
+            UpstreamCommand::Unsubscribe { id: 0 }
+        );
+    }
+
+    #[tokio::test]
+    async fn subscriptions_spread_over_upstreams() {
+        let config = EventMuxConfig {
+            max_upstreams: 2,
+            ..test_config()
+        };
+        let (mux, mut upstreams, connects) = mux(config);
+        let _a = mux.subscribe("a".to_string());
+        let _b = mux.subscribe("b".to_string());
+        let _c = mux.subscribe("c".to_string());
+
+        let _first = next_upstream(&mut upstreams).await;
+        let _second = next_upstream(&mut upstreams).await;
+        assert!(
+            timeout(Duration::from_millis(50), upstreams.recv())
+                .await
+                .is_err(),
+            "no more than max_upstreams streams should be opened"
+        );
+        assert_eq!(connects.load(Ordering::SeqCst), 2);
+        wait_until(|| mux.connected_upstreams() == 2).await;
+    }
+
+    #[tokio::test]
+    async fn resubscribes_after_upstream_loss() {
+        let (mux, mut upstreams, connects) = mux(test_config());
+        let mut sub = mux.subscribe("blocks".to_string());
+        let _other = mux.subscribe("txs".to_string());
+
+        let mut upstream = next_upstream(&mut upstreams).await;
+        upstream.next_command().await;
+        upstream.next_command().await;
+        drop(upstream);
+
+        let mut upstream = next_upstream(&mut upstreams).await;
+        assert_eq!(connects.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            upstream.next_command().await,
+            UpstreamCommand::Subscribe {
+                id: 0,
+                filter: "blocks".to_string()
+            }
+        );
+        assert_eq!(
+            upstream.next_command().await,
+            UpstreamCommand::Subscribe {
+                id: 1,
+                filter: "txs".to_string()
+            }
+        );
+
+        upstream
+            .events
+            .send(UpstreamEvent {
+                subscription_id: 0,
+                event: 42,
+            })
+            .await
+            .unwrap();
+        assert_eq!(timeout(WAIT, sub.recv()).await.unwrap(), Some(42));
+    }
+
+    #[tokio::test]
+    async fn laggy_subscriber_is_disconnected() {
+        let (mux, mut upstreams, _) = mux(test_config());
+        let mut slow = mux.subscribe("blocks".to_string());
+        let mut fast = mux.subscribe("blocks".to_string());
+
+        let mut upstream = next_upstream(&mut upstreams).await;
+        upstream.next_command().await;
+
+        let capacity = test_config().subscriber_capacity as u32;
+        for i in 0..=capacity {
+            upstream
+                .events
+                .send(UpstreamEvent {
+                    subscription_id: 0,
+                    event: i,
+                })
+                .await
+                .unwrap();
+            assert_eq!(timeout(WAIT, fast.recv()).await.unwrap(), Some(i));
+        }
+
+        wait_until(|| mux.subscriber_count() == 1).await;
+        for i in 0..capacity {
+            assert_eq!(slow.recv().await, Some(i));
+        }
+        assert_eq!(slow.recv().await, None);
+        assert_eq!(mux.upstream_subscription_count(), 1);
+    }
+}
@@ -4,5 +4,7 @@
 //! - `event_mux`: upstream bi-di gRPC multiplexer for Platform events
 
 pub mod event_bus;
+pub mod event_mux;
 
 pub use event_bus::{EventBus, Filter, SubscriptionHandle};
+pub use event_mux::{EventMux, EventMuxConfig, MuxSubscription, UpstreamConnector};
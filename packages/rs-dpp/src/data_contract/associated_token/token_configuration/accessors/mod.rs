@@ -8,6 +8,7 @@ use crate::data_contract::associated_token::token_configuration::TokenConfigurat
 use crate::data_contract::associated_token::token_configuration_convention::TokenConfigurationConvention;
 use crate::data_contract::associated_token::token_distribution_rules::TokenDistributionRules;
 use crate::data_contract::associated_token::token_keeps_history_rules::TokenKeepsHistoryRules;
+use crate::data_contract::associated_token::token_marketplace_rules::TokenMarketplaceRules;
 use crate::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
 use crate::data_contract::change_control_rules::ChangeControlRules;
 use crate::data_contract::GroupContractPosition;
@@ -95,6 +96,13 @@ impl TokenConfigurationV0Getters for TokenConfiguration {
         }
     }
 
+    /// Returns the marketplace rules.
+    fn marketplace_rules(&self) -> &TokenMarketplaceRules {
+        match self {
+            TokenConfiguration::V0(v0) => v0.marketplace_rules(),
+        }
+    }
+
     /// Returns the manual minting rules.
     fn manual_minting_rules(&self) -> &ChangeControlRules {
         match self {
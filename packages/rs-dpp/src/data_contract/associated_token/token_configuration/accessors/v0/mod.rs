@@ -2,6 +2,7 @@ use crate::balances::credits::TokenAmount;
 use crate::data_contract::associated_token::token_configuration_convention::TokenConfigurationConvention;
 use crate::data_contract::associated_token::token_distribution_rules::TokenDistributionRules;
 use crate::data_contract::associated_token::token_keeps_history_rules::TokenKeepsHistoryRules;
+use crate::data_contract::associated_token::token_marketplace_rules::TokenMarketplaceRules;
 use crate::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
 use crate::data_contract::change_control_rules::ChangeControlRules;
 use crate::data_contract::GroupContractPosition;
@@ -44,6 +45,9 @@ pub trait TokenConfigurationV0Getters {
     /// Returns a mutable reference to the distribution rules.
     fn distribution_rules_mut(&mut self) -> &mut TokenDistributionRules;
 
+    /// Returns the marketplace rules.
+    fn marketplace_rules(&self) -> &TokenMarketplaceRules;
+
     /// Returns the manual minting rules.
     fn manual_minting_rules(&self) -> &ChangeControlRules;
 
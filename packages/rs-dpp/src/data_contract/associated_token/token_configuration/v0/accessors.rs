@@ -9,6 +9,7 @@ use crate::data_contract::associated_token::token_distribution_rules::accessors:
 use crate::data_contract::associated_token::token_distribution_rules::TokenDistributionRules;
 use crate::data_contract::associated_token::token_keeps_history_rules::TokenKeepsHistoryRules;
 use crate::data_contract::associated_token::token_marketplace_rules::accessors::v0::TokenMarketplaceRulesV0Getters;
+use crate::data_contract::associated_token::token_marketplace_rules::TokenMarketplaceRules;
 use crate::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
 use crate::data_contract::change_control_rules::ChangeControlRules;
 use crate::data_contract::GroupContractPosition;
@@ -74,6 +75,11 @@ impl TokenConfigurationV0Getters for TokenConfigurationV0 {
         &mut self.distribution_rules
     }
 
+    /// Returns the marketplace rules.
+    fn marketplace_rules(&self) -> &TokenMarketplaceRules {
+        &self.marketplace_rules
+    }
+
     /// Returns the manual minting rules.
     fn manual_minting_rules(&self) -> &ChangeControlRules {
         &self.manual_minting_rules
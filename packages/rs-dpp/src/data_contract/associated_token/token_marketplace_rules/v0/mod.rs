@@ -1,10 +1,15 @@
 mod accessors;
 
+use crate::consensus::basic::{BasicError, UnsupportedFeatureError};
+use crate::consensus::ConsensusError;
 use crate::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
 use crate::data_contract::change_control_rules::v0::ChangeControlRulesV0;
 use crate::data_contract::change_control_rules::ChangeControlRules;
+use crate::tokens::allowed_currency::AllowedCurrency;
+use crate::validation::SimpleConsensusValidationResult;
 use bincode::Encode;
 use platform_serialization::de::Decode;
+use platform_version::version::PlatformVersion;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -14,6 +19,53 @@ use std::fmt;
 pub enum TokenTradeMode {
     #[default]
     NotTradeable,
+    /// The token can be traded on the platform order book with limit orders
+    /// quoted in any of the allowed currencies.
+    #[serde(rename_all = "camelCase")]
+    OrderBook {
+        allowed_currencies: Vec<AllowedCurrency>,
+    },
+}
+
+impl TokenTradeMode {
+    /// Whether limit orders quoted in `currency` can be placed for the token.
+    pub fn allows_order_book_trading_in(&self, currency: &AllowedCurrency) -> bool {
+        match self {
+            TokenTradeMode::NotTradeable => false,
+            TokenTradeMode::OrderBook { allowed_currencies } => {
+                allowed_currencies.contains(currency)
+            }
+        }
+    }
+
+    /// Validates that the trade mode is available in the given platform version.
+    ///
+    /// Order book trading is only available from the platform version that allows token
+    /// order book transitions.
+    pub fn validate_supported(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> SimpleConsensusValidationResult {
+        let order_book_supported = platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .batch_state_transition
+            .token_place_order_transition_structure_validation
+            .is_some();
+
+        match self {
+            TokenTradeMode::OrderBook { .. } if !order_book_supported => {
+                SimpleConsensusValidationResult::new_with_error(ConsensusError::BasicError(
+                    BasicError::UnsupportedFeatureError(UnsupportedFeatureError::new(
+                        "token order book".to_string(),
+                        platform_version.protocol_version,
+                    )),
+                ))
+            }
+            _ => SimpleConsensusValidationResult::default(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Decode, Encode, Debug, Clone, PartialEq, Eq)]
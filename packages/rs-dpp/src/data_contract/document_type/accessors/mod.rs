@@ -9,6 +9,7 @@ use crate::data_contract::document_type::{DocumentType, DocumentTypeMutRef, Docu
 use platform_value::{Identifier, Value};
 
 use crate::data_contract::document_type::restricted_creation::CreationRestrictionMode;
+use crate::data_contract::document_type::time_to_live::DocumentTimeToLive;
 #[cfg(feature = "validation")]
 use crate::data_contract::document_type::validator::StatelessJsonSchemaLazyValidator;
 use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
@@ -642,6 +643,13 @@ impl DocumentTypeV1Getters for DocumentType {
             DocumentType::V1(v1) => v1.all_external_token_costs_contract_tokens(),
         }
     }
+
+    fn documents_time_to_live(&self) -> Option<DocumentTimeToLive> {
+        match self {
+            DocumentType::V0(_) => None,
+            DocumentType::V1(v1) => v1.documents_time_to_live(),
+        }
+    }
 }
 
 impl DocumentTypeV1Getters for DocumentTypeRef<'_> {
@@ -702,6 +710,13 @@ impl DocumentTypeV1Getters for DocumentTypeRef<'_> {
             DocumentTypeRef::V1(v1) => v1.all_external_token_costs_contract_tokens(),
         }
     }
+
+    fn documents_time_to_live(&self) -> Option<DocumentTimeToLive> {
+        match self {
+            DocumentTypeRef::V0(_) => None,
+            DocumentTypeRef::V1(v1) => v1.documents_time_to_live(),
+        }
+    }
 }
 
 impl DocumentTypeV1Getters for DocumentTypeMutRef<'_> {
@@ -762,4 +777,11 @@ impl DocumentTypeV1Getters for DocumentTypeMutRef<'_> {
             DocumentTypeMutRef::V1(v1) => v1.all_external_token_costs_contract_tokens(),
         }
     }
+
+    fn documents_time_to_live(&self) -> Option<DocumentTimeToLive> {
+        match self {
+            DocumentTypeMutRef::V0(_) => None,
+            DocumentTypeMutRef::V1(v1) => v1.documents_time_to_live(),
+        }
+    }
 }
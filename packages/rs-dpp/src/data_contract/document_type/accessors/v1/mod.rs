@@ -1,3 +1,4 @@
+use crate::data_contract::document_type::time_to_live::DocumentTimeToLive;
 use crate::data_contract::TokenContractPosition;
 use crate::tokens::token_amount_on_contract_token::DocumentActionTokenCost;
 use platform_value::Identifier;
@@ -55,6 +56,13 @@ pub trait DocumentTypeV1Getters {
     fn all_external_token_costs_contract_tokens(
         &self,
     ) -> BTreeMap<Identifier, BTreeSet<TokenContractPosition>>;
+
+    /// Returns how long documents of this type live before they are removed, if they expire.
+    ///
+    /// # Returns
+    /// - `Some(DocumentTimeToLive)` if documents of this type expire.
+    /// - `None` if documents of this type live until they are deleted.
+    fn documents_time_to_live(&self) -> Option<DocumentTimeToLive>;
 }
 
 /// Trait providing setters for assigning token costs to different document operations.
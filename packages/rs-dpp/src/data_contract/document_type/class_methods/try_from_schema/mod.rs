@@ -21,6 +21,7 @@ mod v1;
 const NOT_ALLOWED_SYSTEM_PROPERTIES: [&str; 1] = ["$id"];
 
 const MAX_INDEXED_STRING_PROPERTY_LENGTH: u16 = 63;
+const MAX_FULL_TEXT_INDEXED_STRING_PROPERTY_LENGTH: u16 = 1024;
 const MAX_INDEXED_BYTE_ARRAY_PROPERTY_LENGTH: u16 = 255;
 const MAX_INDEXED_ARRAY_ITEMS: usize = 1024;
 
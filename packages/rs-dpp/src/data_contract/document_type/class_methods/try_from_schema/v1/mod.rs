@@ -1,8 +1,8 @@
 #[cfg(feature = "validation")]
 use crate::consensus::basic::data_contract::{
-    DuplicateIndexNameError, InvalidIndexPropertyTypeError, InvalidIndexedPropertyConstraintError,
-    SystemPropertyIndexAlreadyPresentError, UndefinedIndexPropertyError,
-    UniqueIndicesLimitReachedError,
+    DuplicateIndexError, DuplicateIndexNameError, InvalidIndexPropertyTypeError,
+    InvalidIndexedPropertyConstraintError, SystemPropertyIndexAlreadyPresentError,
+    UndefinedIndexPropertyError, UniqueIndicesLimitReachedError,
 };
 #[cfg(feature = "validation")]
 use crate::consensus::ConsensusError;
@@ -29,6 +29,8 @@ use crate::consensus::basic::data_contract::ContestedUniqueIndexWithUniqueIndexE
 #[cfg(any(test, feature = "validation"))]
 use crate::consensus::basic::data_contract::InvalidDocumentTypeNameError;
 #[cfg(feature = "validation")]
+use crate::consensus::basic::data_contract::InvalidDocumentTypeTimeToLiveError;
+#[cfg(feature = "validation")]
 use crate::consensus::basic::data_contract::RedundantDocumentPaidForByTokenWithContractId;
 #[cfg(feature = "validation")]
 use crate::consensus::basic::data_contract::TokenPaymentByBurningOnlyAllowedOnInternalTokenError;
@@ -38,6 +40,8 @@ use crate::consensus::basic::document::MissingPositionsInDocumentTypePropertiesE
 use crate::consensus::basic::token::InvalidTokenPositionError;
 #[cfg(feature = "validation")]
 use crate::consensus::basic::BasicError;
+#[cfg(feature = "validation")]
+use crate::consensus::basic::UnsupportedFeatureError;
 use crate::data_contract::config::v0::DataContractConfigGettersV0;
 use crate::data_contract::config::DataContractConfig;
 use crate::data_contract::document_type::class_methods::try_from_schema::{
@@ -45,16 +49,17 @@ use crate::data_contract::document_type::class_methods::try_from_schema::{
 };
 #[cfg(feature = "validation")]
 use crate::data_contract::document_type::class_methods::try_from_schema::{
-    MAX_INDEXED_BYTE_ARRAY_PROPERTY_LENGTH, MAX_INDEXED_STRING_PROPERTY_LENGTH,
-    NOT_ALLOWED_SYSTEM_PROPERTIES,
+    MAX_FULL_TEXT_INDEXED_STRING_PROPERTY_LENGTH, MAX_INDEXED_BYTE_ARRAY_PROPERTY_LENGTH,
+    MAX_INDEXED_STRING_PROPERTY_LENGTH, NOT_ALLOWED_SYSTEM_PROPERTIES,
 };
 use crate::data_contract::document_type::class_methods::{
     consensus_or_protocol_data_contract_error, consensus_or_protocol_value_error,
 };
 use crate::data_contract::document_type::property_names::{
     CAN_BE_DELETED, CREATION_RESTRICTION_MODE, DOCUMENTS_KEEP_HISTORY, DOCUMENTS_MUTABLE,
-    TRADE_MODE, TRANSFERABLE,
+    DOCUMENTS_TIME_TO_LIVE, TRADE_MODE, TRANSFERABLE,
 };
+use crate::data_contract::document_type::time_to_live::DocumentTimeToLive;
 use crate::data_contract::document_type::token_costs::v0::TokenCostsV0;
 use crate::data_contract::document_type::v1::DocumentTypeV1;
 use crate::data_contract::document_type::{property_names, DocumentType};
@@ -258,6 +263,73 @@ impl DocumentTypeV1 {
             property_names::TRANSIENT,
         );
 
+        // Do documents of this type expire?
+        let documents_time_to_live = schema
+            .get_optional_value(DOCUMENTS_TIME_TO_LIVE)
+            .and_then(|time_to_live_value| {
+                time_to_live_value
+                    .map(|time_to_live_value| {
+                        match time_to_live_value
+                            .get_optional_integer::<u64>(DocumentTimeToLive::BLOCK_TIME_KEY)?
+                        {
+                            Some(interval) => Ok(DocumentTimeToLive::BlockTime(interval)),
+                            None => time_to_live_value
+                                .get_integer::<u64>(DocumentTimeToLive::BLOCK_HEIGHT_KEY)
+                                .map(DocumentTimeToLive::BlockHeight),
+                        }
+                    })
+                    .transpose()
+            })
+            .map_err(consensus_or_protocol_value_error)?;
+
+        #[cfg(feature = "validation")]
+        if full_validation {
+            if let Some(time_to_live) = documents_time_to_live {
+                // Expired documents are only removed from the platform version that allows them
+                if platform_version
+                    .drive_abci
+                    .validation_and_processing
+                    .state_transitions
+                    .documents_time_to_live
+                    .is_none()
+                {
+                    return Err(ProtocolError::ConsensusError(Box::new(
+                        UnsupportedFeatureError::new(
+                            "documents time to live".to_string(),
+                            platform_version.protocol_version,
+                        )
+                        .into(),
+                    )));
+                }
+
+                // Expired documents are deleted, which is not possible when history is kept
+                if documents_keep_history {
+                    return Err(ProtocolError::ConsensusError(Box::new(
+                        InvalidDocumentTypeTimeToLiveError::new(
+                            name.to_string(),
+                            "documents that keep history can not expire".to_string(),
+                        )
+                        .into(),
+                    )));
+                }
+
+                // The expiration is computed from the creation of the document
+                if !required_fields.contains(time_to_live.required_creation_property()) {
+                    return Err(ProtocolError::ConsensusError(Box::new(
+                        InvalidDocumentTypeTimeToLiveError::new(
+                            name.to_string(),
+                            format!(
+                                "{} must be required for documents to expire after {}",
+                                time_to_live.required_creation_property(),
+                                time_to_live
+                            ),
+                        )
+                        .into(),
+                    )));
+                }
+            }
+        }
+
         // Based on the property name, determine the type
         for (property_key, property_value) in property_values {
             // TODO: It's very inefficient. It must be done in one iteration and flattened properties
@@ -305,6 +377,9 @@ impl DocumentTypeV1 {
         #[cfg(feature = "validation")]
         let mut contested_indices_count = 0;
 
+        #[cfg(feature = "validation")]
+        let mut full_text_indexed_properties: HashSet<String> = HashSet::new();
+
         let indices: BTreeMap<String, Index> = index_values
             .map(|index_values| {
                 index_values
@@ -426,6 +501,38 @@ impl DocumentTypeV1 {
                                 )));
                             }
 
+                            // Only one full text index is allowed per property, their terms
+                            // are stored under the property name
+                            if index.full_text {
+                                if platform_version
+                                    .drive_abci
+                                    .validation_and_processing
+                                    .state_transitions
+                                    .full_text_indexes
+                                    .is_none()
+                                {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        UnsupportedFeatureError::new(
+                                            "full text indexes".to_string(),
+                                            platform_version.protocol_version,
+                                        )
+                                        .into(),
+                                    )));
+                                }
+
+                                let property_name = index
+                                    .properties
+                                    .first()
+                                    .map(|property| property.name.clone())
+                                    .unwrap_or_default();
+                                if !full_text_indexed_properties.insert(property_name) {
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        DuplicateIndexError::new(name.to_string(), index.name)
+                                            .into(),
+                                    )));
+                                }
+                            }
+
                             // Validate indexed properties
                             index.properties.iter().try_for_each(|index_property| {
                                 // Do not allow to index already indexed system properties
@@ -464,6 +571,45 @@ impl DocumentTypeV1 {
                                             ))
                                         })?;
 
+                                    // Full text indexes split strings into terms so they
+                                    // accept longer strings, but nothing else
+                                    if index.full_text {
+                                        return match &property_definition.property_type {
+                                            DocumentPropertyType::String(sizes)
+                                                if sizes.max_length.is_none_or(|max_length| {
+                                                    max_length
+                                                        > MAX_FULL_TEXT_INDEXED_STRING_PROPERTY_LENGTH
+                                                }) =>
+                                            {
+                                                Err(ProtocolError::ConsensusError(Box::new(
+                                                    InvalidIndexedPropertyConstraintError::new(
+                                                        name.to_owned(),
+                                                        index.name.to_owned(),
+                                                        index_property.name.to_owned(),
+                                                        "maxLength".to_string(),
+                                                        format!(
+                                                            "should be less or equal {}",
+                                                            MAX_FULL_TEXT_INDEXED_STRING_PROPERTY_LENGTH
+                                                        ),
+                                                    )
+                                                    .into(),
+                                                )))
+                                            }
+                                            DocumentPropertyType::String(_) => Ok(()),
+                                            property_type => {
+                                                Err(ProtocolError::ConsensusError(Box::new(
+                                                    InvalidIndexPropertyTypeError::new(
+                                                        name.to_owned(),
+                                                        index.name.to_owned(),
+                                                        index_property.name.to_owned(),
+                                                        property_type.name(),
+                                                    )
+                                                    .into(),
+                                                )))
+                                            }
+                                        };
+                                    }
+
                                     // Validate indexed property type
                                     match &property_definition.property_type {
                                         // Array and objects aren't supported for indexing yet
@@ -522,6 +668,17 @@ impl DocumentTypeV1 {
                                         }
                                         _ => Ok(()),
                                     }
+                                } else if index.full_text {
+                                    // System properties are never strings
+                                    Err(ProtocolError::ConsensusError(Box::new(
+                                        InvalidIndexPropertyTypeError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                            index_property.name.to_owned(),
+                                            "system property".to_string(),
+                                        )
+                                        .into(),
+                                    )))
                                 } else {
                                     Ok(())
                                 }
@@ -684,6 +841,7 @@ impl DocumentTypeV1 {
             #[cfg(feature = "validation")]
             json_schema_validator,
             token_costs,
+            documents_time_to_live,
         })
     }
 }
@@ -895,4 +1053,309 @@ mod tests {
             );
         }
     }
+
+    mod documents_time_to_live {
+        use super::*;
+
+        fn try_from_schema_with_time_to_live(
+            schema: Value,
+            platform_version: &PlatformVersion,
+        ) -> Result<DocumentTypeV1, ProtocolError> {
+            let config = DataContractConfig::default_for_version(platform_version)
+                .expect("should create a default config");
+
+            DocumentTypeV1::try_from_schema(
+                Identifier::new([1; 32]),
+                1,
+                config.version(),
+                "expiring",
+                schema,
+                None,
+                &BTreeMap::new(),
+                &config,
+                true,
+                &mut vec![],
+                platform_version,
+            )
+        }
+
+        #[test]
+        fn should_parse_block_time_and_block_height_time_to_live() {
+            let platform_version = PlatformVersion::latest();
+
+            let schema = platform_value!({
+                "type": "object",
+                "properties": {
+                    "message": {
+                        "type": "string",
+                        "position": 0
+                    }
+                },
+                "required": ["$createdAt"],
+                "documentsTimeToLive": {
+                    "blockTime": 86400000u64
+                },
+                "additionalProperties": false
+            });
+
+            let document_type = try_from_schema_with_time_to_live(schema, platform_version)
+                .expect("should be valid");
+
+            assert_eq!(
+                document_type.documents_time_to_live,
+                Some(DocumentTimeToLive::BlockTime(86400000))
+            );
+
+            let schema = platform_value!({
+                "type": "object",
+                "properties": {
+                    "message": {
+                        "type": "string",
+                        "position": 0
+                    }
+                },
+                "required": ["$createdAtBlockHeight"],
+                "documentsTimeToLive": {
+                    "blockHeight": 100u64
+                },
+                "additionalProperties": false
+            });
+
+            let document_type = try_from_schema_with_time_to_live(schema, platform_version)
+                .expect("should be valid");
+
+            assert_eq!(
+                document_type.documents_time_to_live,
+                Some(DocumentTimeToLive::BlockHeight(100))
+            );
+        }
+
+        #[test]
+        fn should_require_the_creation_property() {
+            let platform_version = PlatformVersion::latest();
+
+            let schema = platform_value!({
+                "type": "object",
+                "properties": {
+                    "message": {
+                        "type": "string",
+                        "position": 0
+                    }
+                },
+                "required": ["$createdAt"],
+                "documentsTimeToLive": {
+                    "blockHeight": 100u64
+                },
+                "additionalProperties": false
+            });
+
+            let result = try_from_schema_with_time_to_live(schema, platform_version);
+
+            assert_matches!(
+                result,
+                Err(ProtocolError::ConsensusError(boxed)) => {
+                    assert_matches!(
+                        boxed.as_ref(),
+                        ConsensusError::BasicError(
+                            BasicError::InvalidDocumentTypeTimeToLiveError(e)
+                        ) if e.reason() == "$createdAtBlockHeight must be required for documents to expire after 100 blocks"
+                    )
+                }
+            );
+        }
+
+        #[test]
+        fn should_not_allow_documents_that_keep_history_to_expire() {
+            let platform_version = PlatformVersion::latest();
+
+            let schema = platform_value!({
+                "type": "object",
+                "properties": {
+                    "message": {
+                        "type": "string",
+                        "position": 0
+                    }
+                },
+                "required": ["$createdAt"],
+                "documentsKeepHistory": true,
+                "documentsTimeToLive": {
+                    "blockTime": 86400000u64
+                },
+                "additionalProperties": false
+            });
+
+            let result = try_from_schema_with_time_to_live(schema, platform_version);
+
+            assert_matches!(
+                result,
+                Err(ProtocolError::ConsensusError(boxed)) => {
+                    assert_matches!(
+                        boxed.as_ref(),
+                        ConsensusError::BasicError(
+                            BasicError::InvalidDocumentTypeTimeToLiveError(_)
+                        )
+                    )
+                }
+            );
+        }
+
+        #[test]
+        fn should_not_allow_documents_to_expire_before_protocol_version_11() {
+            let platform_version = PlatformVersion::get(10).expect("expected platform version 10");
+
+            let schema = platform_value!({
+                "type": "object",
+                "properties": {
+                    "message": {
+                        "type": "string",
+                        "position": 0
+                    }
+                },
+                "required": ["$createdAt"],
+                "documentsTimeToLive": {
+                    "blockTime": 86400000u64
+                },
+                "additionalProperties": false
+            });
+
+            let result = try_from_schema_with_time_to_live(schema, platform_version);
+
+            assert_matches!(
+                result,
+                Err(ProtocolError::ConsensusError(boxed)) => {
+                    assert_matches!(
+                        boxed.as_ref(),
+                        ConsensusError::BasicError(BasicError::UnsupportedFeatureError(_))
+                    )
+                }
+            );
+        }
+    }
+
+    mod full_text_indexes {
+        use super::*;
+
+        fn try_from_schema_with_indices(
+            schema: Value,
+            platform_version: &PlatformVersion,
+        ) -> Result<DocumentTypeV1, ProtocolError> {
+            let config = DataContractConfig::default_for_version(platform_version)
+                .expect("should create a default config");
+
+            DocumentTypeV1::try_from_schema(
+                Identifier::new([1; 32]),
+                1,
+                config.version(),
+                "listing",
+                schema,
+                None,
+                &BTreeMap::new(),
+                &config,
+                true,
+                &mut vec![],
+                platform_version,
+            )
+        }
+
+        #[test]
+        fn should_keep_full_text_indexes_out_of_the_index_structure() {
+            let platform_version = PlatformVersion::latest();
+
+            let schema = platform_value!({
+                "type": "object",
+                "properties": {
+                    "description": {
+                        "type": "string",
+                        "maxLength": 512,
+                        "position": 0
+                    }
+                },
+                "indices": [
+                    {
+                        "name": "descriptionSearch",
+                        "properties": [{"description": "asc"}],
+                        "fullText": true
+                    }
+                ],
+                "additionalProperties": false
+            });
+
+            let document_type =
+                try_from_schema_with_indices(schema, platform_version).expect("should be valid");
+
+            assert!(document_type.indices["descriptionSearch"].full_text);
+            assert!(document_type.index_structure.sub_levels().is_empty());
+        }
+
+        #[test]
+        fn should_only_allow_full_text_indexes_on_strings() {
+            let platform_version = PlatformVersion::latest();
+
+            let schema = platform_value!({
+                "type": "object",
+                "properties": {
+                    "price": {
+                        "type": "integer",
+                        "position": 0
+                    }
+                },
+                "indices": [
+                    {
+                        "name": "priceSearch",
+                        "properties": [{"price": "asc"}],
+                        "fullText": true
+                    }
+                ],
+                "additionalProperties": false
+            });
+
+            let result = try_from_schema_with_indices(schema, platform_version);
+
+            assert_matches!(
+                result,
+                Err(ProtocolError::ConsensusError(boxed)) => {
+                    assert_matches!(
+                        boxed.as_ref(),
+                        ConsensusError::BasicError(BasicError::InvalidIndexPropertyTypeError(_))
+                    )
+                }
+            );
+        }
+
+        #[test]
+        fn should_not_allow_full_text_indexes_before_protocol_version_11() {
+            let platform_version = PlatformVersion::get(10).expect("expected platform version 10");
+
+            let schema = platform_value!({
+                "type": "object",
+                "properties": {
+                    "description": {
+                        "type": "string",
+                        "maxLength": 512,
+                        "position": 0
+                    }
+                },
+                "indices": [
+                    {
+                        "name": "descriptionSearch",
+                        "properties": [{"description": "asc"}],
+                        "fullText": true
+                    }
+                ],
+                "additionalProperties": false
+            });
+
+            let result = try_from_schema_with_indices(schema, platform_version);
+
+            assert_matches!(
+                result,
+                Err(ProtocolError::ConsensusError(boxed)) => {
+                    assert_matches!(
+                        boxed.as_ref(),
+                        ConsensusError::BasicError(BasicError::UnsupportedFeatureError(_))
+                    )
+                }
+            );
+        }
+    }
 }
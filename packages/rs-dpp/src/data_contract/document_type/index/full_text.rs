@@ -0,0 +1,82 @@
+use std::collections::BTreeSet;
+
+/// The maximum number of distinct terms of a single value that get referenced in a full text
+/// index, terms after that are not searchable.
+pub const MAX_FULL_TEXT_TERMS_PER_VALUE: usize = 64;
+
+/// The maximum length in bytes of a full text term, longer terms are not searchable.
+pub const MAX_FULL_TEXT_TERM_LENGTH: usize = 64;
+
+/// Splits a text into the terms that a full text index references the document under.
+///
+/// The text is split on ASCII whitespace and ASCII punctuation and ASCII letters are lowercased.
+/// Non ASCII characters are kept as they are so that tokenization never depends on unicode
+/// tables. Terms are returned in order of first appearance without duplicates, terms longer
+/// than [`MAX_FULL_TEXT_TERM_LENGTH`] are skipped and at most [`MAX_FULL_TEXT_TERMS_PER_VALUE`]
+/// terms are returned.
+pub fn full_text_terms(text: &str) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    text.split(|c: char| c.is_ascii_whitespace() || c.is_ascii_punctuation())
+        .filter(|term| !term.is_empty() && term.len() <= MAX_FULL_TEXT_TERM_LENGTH)
+        .map(|term| term.to_ascii_lowercase())
+        .filter(|term| seen.insert(term.clone()))
+        .take(MAX_FULL_TEXT_TERMS_PER_VALUE)
+        .collect()
+}
+
+/// Normalizes a searched word the same way terms are normalized when they are indexed.
+///
+/// Returns `None` if the word would not be a single searchable term.
+pub fn full_text_search_term(word: &str) -> Option<String> {
+    let mut terms = full_text_terms(word);
+    if terms.len() == 1 {
+        terms.pop()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_split_lowercase_and_deduplicate_terms() {
+        let terms = full_text_terms("Red bike, almost new! red BIKE for sale.");
+
+        assert_eq!(terms, vec!["red", "bike", "almost", "new", "for", "sale"]);
+    }
+
+    #[test]
+    fn should_keep_non_ascii_characters_in_terms() {
+        let terms = full_text_terms("Café crème");
+
+        assert_eq!(terms, vec!["café", "crème"]);
+    }
+
+    #[test]
+    fn should_skip_terms_that_are_too_long() {
+        let long_term = "a".repeat(MAX_FULL_TEXT_TERM_LENGTH + 1);
+        let terms = full_text_terms(&format!("short {}", long_term));
+
+        assert_eq!(terms, vec!["short"]);
+    }
+
+    #[test]
+    fn should_limit_the_number_of_terms() {
+        let text = (0..MAX_FULL_TEXT_TERMS_PER_VALUE + 10)
+            .map(|i| format!("term{}", i))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        assert_eq!(full_text_terms(&text).len(), MAX_FULL_TEXT_TERMS_PER_VALUE);
+    }
+
+    #[test]
+    fn should_only_accept_single_search_terms() {
+        assert_eq!(full_text_search_term("Bike"), Some("bike".to_string()));
+        assert_eq!(full_text_search_term(" bike. "), Some("bike".to_string()));
+        assert_eq!(full_text_search_term("red bike"), None);
+        assert_eq!(full_text_search_term("..."), None);
+    }
+}
@@ -29,6 +29,7 @@ use std::fmt;
 use std::sync::OnceLock;
 use std::{collections::BTreeMap, convert::TryFrom};
 
+pub mod full_text;
 pub mod random_index;
 
 #[repr(u8)]
@@ -295,6 +296,9 @@ pub struct Index {
     pub null_searchable: bool,
     /// Contested indexes are useful when a resource is considered valuable
     pub contested_index: Option<ContestedIndexInformation>,
+    /// Full text indexes split the value of their single string property into terms
+    /// and reference the document under each of them, instead of under the whole value
+    pub full_text: bool,
 }
 
 impl Index {
@@ -393,6 +397,11 @@ impl Index {
         in_field_name: Option<&str>,
         order_by: &[&str],
     ) -> Option<u16> {
+        // Full text indexes only answer term searches, never property comparisons
+        if self.full_text {
+            return None;
+        }
+
         // Here we are trying to figure out if the Index matches the order by
         // To do so we take the index and go backwards as we need the order by clauses to be
         // continuous, but they do not need to be at the end.
@@ -468,6 +477,7 @@ impl TryFrom<&[(Value, Value)]> for Index {
         let mut null_searchable = true;
         let mut name = None;
         let mut contested_index = None;
+        let mut full_text = false;
         let mut index_properties: Vec<IndexProperty> = Vec::new();
 
         for (key_value, value_value) in index_type_value_map {
@@ -494,6 +504,11 @@ impl TryFrom<&[(Value, Value)]> for Index {
                         null_searchable = value_value.as_bool().expect("confirmed as bool");
                     }
                 }
+                "fullText" => {
+                    if value_value.is_bool() {
+                        full_text = value_value.as_bool().expect("confirmed as bool");
+                    }
+                }
                 "contested" => {
                     let contested_properties_value_map = value_value.to_map()?;
 
@@ -618,6 +633,18 @@ impl TryFrom<&[(Value, Value)]> for Index {
             ));
         }
 
+        if full_text && unique {
+            return Err(DataContractError::InvalidContractStructure(
+                "full text indexes can not be unique".to_string(),
+            ));
+        }
+
+        if full_text && index_properties.len() != 1 {
+            return Err(DataContractError::InvalidContractStructure(
+                "full text indexes must have exactly one property".to_string(),
+            ));
+        }
+
         // if the index didn't have a name let's make one
         let name = name.unwrap_or_else(|| Alphanumeric.sample_string(&mut rand::thread_rng(), 24));
 
@@ -627,6 +654,7 @@ impl TryFrom<&[(Value, Value)]> for Index {
             unique,
             null_searchable,
             contested_index,
+            full_text,
         })
     }
 }
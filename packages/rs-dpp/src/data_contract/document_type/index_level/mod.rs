@@ -170,6 +170,10 @@ impl IndexLevel {
 
         for index_to_borrow in indices {
             let index = index_to_borrow.borrow();
+            // Full text indexes are not stored in the index tree, their terms have their own tree
+            if index.full_text {
+                continue;
+            }
             let mut current_level = &mut index_level;
             let mut properties_iter = index.properties.iter().peekable();
 
@@ -282,6 +286,7 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            full_text: false,
         }];
 
         let old_index_structure =
@@ -309,6 +314,7 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            full_text: false,
         }];
 
         let new_indices = vec![
@@ -321,6 +327,7 @@ mod tests {
                 unique: false,
                 null_searchable: true,
                 contested_index: None,
+                full_text: false,
             },
             Index {
                 name: "test2".to_string(),
@@ -331,6 +338,7 @@ mod tests {
                 unique: false,
                 null_searchable: true,
                 contested_index: None,
+                full_text: false,
             },
         ];
 
@@ -367,6 +375,7 @@ mod tests {
                 unique: false,
                 null_searchable: true,
                 contested_index: None,
+                full_text: false,
             },
             Index {
                 name: "test2".to_string(),
@@ -377,6 +386,7 @@ mod tests {
                 unique: false,
                 null_searchable: true,
                 contested_index: None,
+                full_text: false,
             },
         ];
 
@@ -389,6 +399,7 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            full_text: false,
         }];
 
         let old_index_structure =
@@ -423,6 +434,7 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            full_text: false,
         }];
 
         let new_indices = vec![Index {
@@ -440,6 +452,7 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            full_text: false,
         }];
 
         let old_index_structure =
@@ -480,6 +493,7 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            full_text: false,
         }];
 
         let new_indices = vec![Index {
@@ -491,6 +505,7 @@ mod tests {
             unique: false,
             null_searchable: true,
             contested_index: None,
+            full_text: false,
         }];
 
         let old_index_structure =
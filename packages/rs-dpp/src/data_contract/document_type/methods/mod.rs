@@ -50,10 +50,30 @@ pub trait DocumentTypeBasicMethods: DocumentTypeV0Getters {
     fn top_level_indices(&self) -> Vec<&IndexProperty> {
         self.indexes()
             .values()
+            .filter(|index| !index.full_text)
             .filter_map(|index| index.properties.first())
             .collect()
     }
 
+    /// The indexes whose single property is split into searchable terms
+    fn full_text_indexes(&self) -> Vec<&Index> {
+        self.indexes()
+            .values()
+            .filter(|index| index.full_text)
+            .collect()
+    }
+
+    /// The full text index on a property, if there is one
+    fn full_text_index_for_property(&self, property_name: &str) -> Option<&Index> {
+        self.indexes().values().find(|index| {
+            index.full_text
+                && index
+                    .properties
+                    .first()
+                    .is_some_and(|property| property.name == property_name)
+        })
+    }
+
     // This should normally just be 1 item, however we keep a vec in case we want to change things
     //  in the future.
     fn top_level_indices_of_contested_unique_indexes(&self) -> Vec<&IndexProperty> {
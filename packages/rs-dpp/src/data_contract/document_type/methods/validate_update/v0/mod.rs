@@ -1,6 +1,11 @@
-use crate::consensus::basic::data_contract::IncompatibleDocumentTypeSchemaError;
+use crate::consensus::basic::data_contract::{
+    DataContractInvalidIndexDefinitionUpdateError, IncompatibleDocumentTypeSchemaError,
+};
 use crate::consensus::state::data_contract::document_type_update_error::DocumentTypeUpdateError;
-use crate::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use crate::data_contract::document_type::accessors::{
+    DocumentTypeV0Getters, DocumentTypeV1Getters,
+};
+use crate::data_contract::document_type::methods::DocumentTypeBasicMethods;
 use crate::data_contract::document_type::schema::validate_schema_compatibility;
 use crate::data_contract::document_type::DocumentTypeRef;
 use crate::data_contract::errors::DataContractError;
@@ -31,10 +36,45 @@ impl DocumentTypeRef<'_> {
             return Ok(result);
         }
 
+        // Full text indexes are not part of the index structure but can not change either
+        let result = self.validate_full_text_indexes(new_document_type);
+
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
         // Validate schema compatibility
         self.validate_schema(new_document_type, platform_version)
     }
 
+    fn validate_full_text_indexes(
+        &self,
+        new_document_type: DocumentTypeRef,
+    ) -> SimpleConsensusValidationResult {
+        let old_indexes = self.full_text_indexes();
+        let new_indexes = new_document_type.full_text_indexes();
+
+        let changed_index = old_indexes
+            .iter()
+            .find(|index| !new_indexes.contains(index))
+            .or_else(|| {
+                new_indexes
+                    .iter()
+                    .find(|index| !old_indexes.contains(index))
+            });
+
+        match changed_index {
+            Some(index) => SimpleConsensusValidationResult::new_with_error(
+                DataContractInvalidIndexDefinitionUpdateError::new(
+                    self.name().to_string(),
+                    index.name.clone(),
+                )
+                .into(),
+            ),
+            None => SimpleConsensusValidationResult::new(),
+        }
+    }
+
     fn validate_config(
         &self,
         new_document_type: DocumentTypeRef,
@@ -178,6 +218,21 @@ impl DocumentTypeRef<'_> {
             );
         }
 
+        if new_document_type.documents_time_to_live() != self.documents_time_to_live() {
+            return SimpleConsensusValidationResult::new_with_error(
+                DocumentTypeUpdateError::new(
+                    self.data_contract_id(),
+                    self.name(),
+                    format!(
+                        "document type can not change the time to live of its documents: changing from {:?} to {:?}",
+                        self.documents_time_to_live(),
+                        new_document_type.documents_time_to_live()
+                    ),
+                )
+                    .into(),
+            );
+        }
+
         SimpleConsensusValidationResult::new()
     }
 
@@ -949,6 +1004,87 @@ mod tests {
                 )] if e.additional_message() == "document type can not change the security level requirement for its updates: changing from MASTER to CRITICAL"
             );
         }
+
+        #[test]
+        fn should_return_invalid_result_when_documents_time_to_live_is_changed() {
+            let platform_version = PlatformVersion::latest();
+            let data_contract_id = Identifier::random();
+            let document_type_name = "test";
+
+            let schema = platform_value!({
+                "type": "object",
+                "properties": {
+                    "test": {
+                        "type": "string",
+                        "position": 0,
+                    }
+                },
+                "required": ["$createdAt"],
+                "documentsTimeToLive": {
+                    "blockTime": 60000u64,
+                },
+                "additionalProperties": false,
+            });
+
+            let config = DataContractConfig::default_for_version(platform_version)
+                .expect("should create a default config");
+
+            let old_document_type = DocumentType::try_from_schema(
+                data_contract_id,
+                1,
+                config.version(),
+                document_type_name,
+                schema,
+                None,
+                &BTreeMap::new(),
+                &config,
+                false,
+                &mut Vec::new(),
+                platform_version,
+            )
+            .expect("failed to create old document type");
+
+            let schema = platform_value!({
+                "type": "object",
+                "properties": {
+                    "test": {
+                        "type": "string",
+                        "position": 0,
+                    }
+                },
+                "required": ["$createdAt"],
+                "documentsTimeToLive": {
+                    "blockTime": 120000u64,
+                },
+                "additionalProperties": false,
+            });
+
+            let new_document_type = DocumentType::try_from_schema(
+                data_contract_id,
+                1,
+                config.version(),
+                document_type_name,
+                schema,
+                None,
+                &BTreeMap::new(),
+                &config,
+                false,
+                &mut Vec::new(),
+                platform_version,
+            )
+            .expect("failed to create new document type");
+
+            let result = old_document_type
+                .as_ref()
+                .validate_config(new_document_type.as_ref());
+
+            assert_matches!(
+                result.errors.as_slice(),
+                [ConsensusError::StateError(
+                    StateError::DocumentTypeUpdateError(e)
+                )] if e.additional_message() == "document type can not change the time to live of its documents: changing from Some(BlockTime(60000)) to Some(BlockTime(120000))"
+            );
+        }
     }
 
     mod validate_schema {
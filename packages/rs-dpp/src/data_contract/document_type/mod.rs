@@ -14,6 +14,7 @@ pub use index_level::IndexType;
 pub mod random_document;
 pub mod restricted_creation;
 pub mod schema;
+pub mod time_to_live;
 
 mod token_costs;
 pub mod v0;
@@ -47,6 +48,7 @@ pub(crate) mod property_names {
     pub const TRADE_MODE: &str = "tradeMode";
 
     pub const CREATION_RESTRICTION_MODE: &str = "creationRestrictionMode";
+    pub const DOCUMENTS_TIME_TO_LIVE: &str = "documentsTimeToLive";
     pub const SECURITY_LEVEL_REQUIREMENT: &str = "signatureSecurityLevelRequirement";
     pub const REQUIRES_IDENTITY_ENCRYPTION_BOUNDED_KEY: &str =
         "requiresIdentityEncryptionBoundedKey";
@@ -0,0 +1,132 @@
+use crate::block::block_info::BlockInfo;
+use crate::document::{property_names, Document, DocumentV0Getters};
+use crate::prelude::{BlockHeight, BlockHeightInterval, TimestampMillis, TimestampMillisInterval};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// How long documents of a document type live before they are removed by the platform.
+///
+/// The expiration of a document is always derived from the moment it was created, so documents
+/// with a time to live must have the matching creation system property required.
+#[derive(Debug, PartialEq, Clone, Copy, Eq)]
+pub enum DocumentTimeToLive {
+    /// Documents expire this many milliseconds after their `$createdAt` block time
+    BlockTime(TimestampMillisInterval),
+    /// Documents expire this many blocks after their `$createdAtBlockHeight`
+    BlockHeight(BlockHeightInterval),
+}
+
+impl DocumentTimeToLive {
+    /// The schema key inside `documentsTimeToLive` for a time based time to live
+    pub const BLOCK_TIME_KEY: &'static str = "blockTime";
+    /// The schema key inside `documentsTimeToLive` for a height based time to live
+    pub const BLOCK_HEIGHT_KEY: &'static str = "blockHeight";
+
+    /// The system property that must be required for this time to live to be computable
+    pub fn required_creation_property(&self) -> &'static str {
+        match self {
+            DocumentTimeToLive::BlockTime(_) => property_names::CREATED_AT,
+            DocumentTimeToLive::BlockHeight(_) => property_names::CREATED_AT_BLOCK_HEIGHT,
+        }
+    }
+
+    /// The block time (for time based) or block height (for height based) at which the document
+    /// expires, `None` if the document is missing its creation property.
+    pub fn expires_at(&self, document: &Document) -> Option<u64> {
+        match self {
+            DocumentTimeToLive::BlockTime(interval) => document
+                .created_at()
+                .map(|created_at| created_at.saturating_add(*interval)),
+            DocumentTimeToLive::BlockHeight(interval) => document
+                .created_at_block_height()
+                .map(|created_at_block_height| created_at_block_height.saturating_add(*interval)),
+        }
+    }
+
+    /// Has the document expired at the given block time
+    ///
+    /// Height based documents can not be evaluated against a time and are never considered
+    /// expired here.
+    pub fn is_expired_at_time(&self, document: &Document, block_time_ms: TimestampMillis) -> bool {
+        match self {
+            DocumentTimeToLive::BlockTime(_) => self
+                .expires_at(document)
+                .is_some_and(|expires_at| expires_at <= block_time_ms),
+            DocumentTimeToLive::BlockHeight(_) => false,
+        }
+    }
+
+    /// Has the document expired at the given block height
+    ///
+    /// Time based documents can not be evaluated against a height and are never considered
+    /// expired here.
+    pub fn is_expired_at_height(&self, document: &Document, block_height: BlockHeight) -> bool {
+        match self {
+            DocumentTimeToLive::BlockTime(_) => false,
+            DocumentTimeToLive::BlockHeight(_) => self
+                .expires_at(document)
+                .is_some_and(|expires_at| expires_at <= block_height),
+        }
+    }
+
+    /// Has the document expired by the given block
+    pub fn is_expired(&self, document: &Document, block_info: &BlockInfo) -> bool {
+        self.is_expired_at_time(document, block_info.time_ms)
+            || self.is_expired_at_height(document, block_info.height)
+    }
+}
+
+impl Display for DocumentTimeToLive {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentTimeToLive::BlockTime(interval) => write!(f, "{} ms", interval),
+            DocumentTimeToLive::BlockHeight(interval) => write!(f, "{} blocks", interval),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::DocumentV0;
+
+    fn document_created_at(time_ms: u64, height: u64) -> Document {
+        DocumentV0 {
+            created_at: Some(time_ms),
+            created_at_block_height: Some(height),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    #[test]
+    fn block_time_ttl_should_expire_after_interval() {
+        let ttl = DocumentTimeToLive::BlockTime(1_000);
+        let document = document_created_at(5_000, 10);
+
+        assert_eq!(ttl.expires_at(&document), Some(6_000));
+        assert!(!ttl.is_expired_at_time(&document, 5_999));
+        assert!(ttl.is_expired_at_time(&document, 6_000));
+        assert!(!ttl.is_expired_at_height(&document, u64::MAX));
+    }
+
+    #[test]
+    fn block_height_ttl_should_expire_after_interval() {
+        let ttl = DocumentTimeToLive::BlockHeight(5);
+        let document = document_created_at(5_000, 10);
+
+        assert_eq!(ttl.expires_at(&document), Some(15));
+        assert!(!ttl.is_expired_at_height(&document, 14));
+        assert!(ttl.is_expired_at_height(&document, 15));
+        assert!(!ttl.is_expired_at_time(&document, u64::MAX));
+    }
+
+    #[test]
+    fn document_without_creation_property_should_never_expire() {
+        let ttl = DocumentTimeToLive::BlockTime(1_000);
+        let document: Document = DocumentV0::default().into();
+
+        assert_eq!(ttl.expires_at(&document), None);
+        assert!(!ttl.is_expired_at_time(&document, u64::MAX));
+    }
+}
@@ -8,6 +8,7 @@ use crate::data_contract::document_type::property::DocumentProperty;
 use platform_value::{Identifier, Value};
 
 use crate::data_contract::document_type::restricted_creation::CreationRestrictionMode;
+use crate::data_contract::document_type::time_to_live::DocumentTimeToLive;
 use crate::data_contract::document_type::token_costs::accessors::TokenCostGettersV0;
 use crate::data_contract::document_type::v1::DocumentTypeV1;
 #[cfg(feature = "validation")]
@@ -195,4 +196,8 @@ impl DocumentTypeV1Getters for DocumentTypeV1 {
 
         map
     }
+
+    fn documents_time_to_live(&self) -> Option<DocumentTimeToLive> {
+        self.documents_time_to_live
+    }
 }
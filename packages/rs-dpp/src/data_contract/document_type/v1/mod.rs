@@ -11,6 +11,7 @@ use crate::data_contract::document_type::methods::{
     DocumentTypeBasicMethods, DocumentTypeV0Methods,
 };
 use crate::data_contract::document_type::restricted_creation::CreationRestrictionMode;
+use crate::data_contract::document_type::time_to_live::DocumentTimeToLive;
 use crate::data_contract::document_type::token_costs::accessors::TokenCostSettersV0;
 use crate::data_contract::document_type::token_costs::TokenCosts;
 use crate::data_contract::document_type::v0::DocumentTypeV0;
@@ -68,6 +69,8 @@ pub struct DocumentTypeV1 {
     pub(in crate::data_contract) json_schema_validator: StatelessJsonSchemaLazyValidator,
     /// The token costs associated with state transitions on this document type
     pub(in crate::data_contract) token_costs: TokenCosts,
+    /// How long documents live before they are removed, if they expire
+    pub(in crate::data_contract) documents_time_to_live: Option<DocumentTimeToLive>,
 }
 
 impl DocumentTypeBasicMethods for DocumentTypeV1 {}
@@ -128,6 +131,7 @@ impl From<DocumentTypeV0> for DocumentTypeV1 {
             #[cfg(feature = "validation")]
             json_schema_validator: value.json_schema_validator,
             token_costs: TokenCosts::V0(Default::default()),
+            documents_time_to_live: None,
         }
     }
 }
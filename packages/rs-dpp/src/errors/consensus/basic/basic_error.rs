@@ -16,9 +16,10 @@ use crate::consensus::basic::data_contract::{
     IncompatibleDataContractSchemaError, IncompatibleDocumentTypeSchemaError,
     IncompatibleRe2PatternError, InvalidCompoundIndexError, InvalidDataContractIdError,
     InvalidDataContractVersionError, InvalidDocumentTypeNameError,
-    InvalidDocumentTypeRequiredSecurityLevelError, InvalidIndexPropertyTypeError,
-    InvalidIndexedPropertyConstraintError, InvalidKeywordCharacterError,
-    InvalidTokenBaseSupplyError, InvalidTokenDistributionFunctionDivideByZeroError,
+    InvalidDocumentTypeRequiredSecurityLevelError, InvalidDocumentTypeTimeToLiveError,
+    InvalidIndexPropertyTypeError, InvalidIndexedPropertyConstraintError,
+    InvalidKeywordCharacterError, InvalidTokenBaseSupplyError,
+    InvalidTokenDistributionFunctionDivideByZeroError,
     InvalidTokenDistributionFunctionIncoherenceError,
     InvalidTokenDistributionFunctionInvalidParameterError,
     InvalidTokenDistributionFunctionInvalidParameterTupleError, InvalidTokenLanguageCodeError,
@@ -92,8 +93,10 @@ use crate::consensus::basic::token::{
     InvalidTokenConfigUpdateNoChangeError, InvalidTokenDistributionBlockIntervalTooShortError,
     InvalidTokenDistributionTimeIntervalNotMinuteAlignedError,
     InvalidTokenDistributionTimeIntervalTooShortError, InvalidTokenIdError,
-    InvalidTokenNoteTooBigError, InvalidTokenPositionError, MissingDefaultLocalizationError,
-    TokenNoteOnlyAllowedWhenProposerError, TokenTransferToOurselfError,
+    InvalidTokenNoteTooBigError, InvalidTokenOrderPriceError, InvalidTokenPositionError,
+    InvalidTokenVestingScheduleError, MissingDefaultLocalizationError, TokenApproveToOurselfError,
+    TokenNoteOnlyAllowedWhenProposerError, TokenOrderQuotedInSameTokenError,
+    TokenTransferToOurselfError,
 };
 use crate::consensus::basic::unsupported_version_error::UnsupportedVersionError;
 use crate::consensus::basic::value_error::ValueError;
@@ -592,6 +595,21 @@ pub enum BasicError {
 
     #[error(transparent)]
     InvalidKeyPurposeForContractBoundsError(InvalidKeyPurposeForContractBoundsError),
+
+    #[error(transparent)]
+    InvalidTokenOrderPriceError(InvalidTokenOrderPriceError),
+
+    #[error(transparent)]
+    TokenOrderQuotedInSameTokenError(TokenOrderQuotedInSameTokenError),
+
+    #[error(transparent)]
+    InvalidDocumentTypeTimeToLiveError(InvalidDocumentTypeTimeToLiveError),
+
+    #[error(transparent)]
+    InvalidTokenVestingScheduleError(InvalidTokenVestingScheduleError),
+
+    #[error(transparent)]
+    TokenApproveToOurselfError(TokenApproveToOurselfError),
 }
 
 impl From<BasicError> for ConsensusError {
@@ -0,0 +1,44 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Document type '{document_type_name}' has an invalid time to live: {reason}")]
+#[platform_serialize(unversioned)]
+pub struct InvalidDocumentTypeTimeToLiveError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    document_type_name: String,
+    reason: String,
+}
+
+impl InvalidDocumentTypeTimeToLiveError {
+    pub fn new(document_type_name: String, reason: String) -> Self {
+        Self {
+            document_type_name,
+            reason,
+        }
+    }
+
+    pub fn document_type_name(&self) -> &str {
+        &self.document_type_name
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl From<InvalidDocumentTypeTimeToLiveError> for ConsensusError {
+    fn from(err: InvalidDocumentTypeTimeToLiveError) -> Self {
+        Self::BasicError(BasicError::InvalidDocumentTypeTimeToLiveError(err))
+    }
+}
@@ -27,6 +27,7 @@ mod invalid_data_contract_version_error;
 mod invalid_description_length_error;
 mod invalid_document_type_name_error;
 mod invalid_document_type_required_security_level;
+mod invalid_document_type_time_to_live_error;
 mod invalid_index_property_type_error;
 mod invalid_indexed_property_constraint_error;
 mod invalid_json_schema_ref_error;
@@ -73,6 +74,7 @@ pub use invalid_compound_index_error::*;
 pub use invalid_data_contract_id_error::*;
 pub use invalid_data_contract_version_error::*;
 pub use invalid_document_type_required_security_level::*;
+pub use invalid_document_type_time_to_live_error::*;
 pub use invalid_index_property_type_error::*;
 pub use invalid_indexed_property_constraint_error::*;
 pub use invalid_json_schema_ref_error::*;
@@ -0,0 +1,68 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "Invalid order price {} per token for {} tokens of token {}, the price must be at least 1 and the order value must not exceed {}",
+    price_per_token,
+    amount,
+    token_id,
+    max_order_value
+)]
+#[platform_serialize(unversioned)]
+pub struct InvalidTokenOrderPriceError {
+    token_id: Identifier,
+    price_per_token: u64,
+    amount: u64,
+    max_order_value: u64,
+}
+
+impl InvalidTokenOrderPriceError {
+    /// Creates a new `InvalidTokenOrderPriceError`.
+    pub fn new(
+        token_id: Identifier,
+        price_per_token: u64,
+        amount: u64,
+        max_order_value: u64,
+    ) -> Self {
+        Self {
+            token_id,
+            price_per_token,
+            amount,
+            max_order_value,
+        }
+    }
+
+    /// Returns the token of the order.
+    pub fn token_id(&self) -> Identifier {
+        self.token_id
+    }
+
+    /// Returns the invalid price per token.
+    pub fn price_per_token(&self) -> u64 {
+        self.price_per_token
+    }
+
+    /// Returns the amount of tokens of the order.
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the maximum allowed value of an order.
+    pub fn max_order_value(&self) -> u64 {
+        self.max_order_value
+    }
+}
+
+impl From<InvalidTokenOrderPriceError> for ConsensusError {
+    fn from(err: InvalidTokenOrderPriceError) -> Self {
+        Self::BasicError(BasicError::InvalidTokenOrderPriceError(err))
+    }
+}
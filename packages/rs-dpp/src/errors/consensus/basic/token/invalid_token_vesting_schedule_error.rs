@@ -0,0 +1,37 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Invalid vesting schedule for token {}: {}", token_id, reason)]
+#[platform_serialize(unversioned)]
+pub struct InvalidTokenVestingScheduleError {
+    token_id: Identifier,
+    reason: String,
+}
+
+impl InvalidTokenVestingScheduleError {
+    pub fn new(token_id: Identifier, reason: String) -> Self {
+        Self { token_id, reason }
+    }
+
+    pub fn token_id(&self) -> Identifier {
+        self.token_id
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl From<InvalidTokenVestingScheduleError> for ConsensusError {
+    fn from(err: InvalidTokenVestingScheduleError) -> Self {
+        Self::BasicError(BasicError::InvalidTokenVestingScheduleError(err))
+    }
+}
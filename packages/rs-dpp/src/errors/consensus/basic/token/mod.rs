@@ -9,9 +9,13 @@ mod invalid_token_distribution_time_interval_not_minute_aligned_error;
 mod invalid_token_distribution_time_interval_too_short_error;
 mod invalid_token_id_error;
 mod invalid_token_note_too_big_error;
+mod invalid_token_order_price_error;
 mod invalid_token_position_error;
+mod invalid_token_vesting_schedule_error;
 mod missing_default_localization;
+mod token_approve_to_ourselves_error;
 mod token_note_only_allowed_on_proposer_error;
+mod token_order_quoted_in_same_token_error;
 mod token_transfer_to_ourselves_error;
 
 pub use choosing_token_mint_recipient_not_allowed_error::*;
@@ -25,7 +29,11 @@ pub use invalid_token_distribution_time_interval_not_minute_aligned_error::*;
 pub use invalid_token_distribution_time_interval_too_short_error::*;
 pub use invalid_token_id_error::*;
 pub use invalid_token_note_too_big_error::*;
+pub use invalid_token_order_price_error::*;
 pub use invalid_token_position_error::*;
+pub use invalid_token_vesting_schedule_error::*;
 pub use missing_default_localization::*;
+pub use token_approve_to_ourselves_error::*;
 pub use token_note_only_allowed_on_proposer_error::*;
+pub use token_order_quoted_in_same_token_error::*;
 pub use token_transfer_to_ourselves_error::*;
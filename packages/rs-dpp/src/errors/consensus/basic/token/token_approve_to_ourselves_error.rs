@@ -0,0 +1,44 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "An identity can not approve itself to spend its own tokens. Token ID: {}, Identity ID: {}",
+    token_id,
+    identity_id
+)]
+#[platform_serialize(unversioned)]
+pub struct TokenApproveToOurselfError {
+    token_id: Identifier,
+    identity_id: Identifier,
+}
+
+impl TokenApproveToOurselfError {
+    pub fn new(token_id: Identifier, identity_id: Identifier) -> Self {
+        Self {
+            token_id,
+            identity_id,
+        }
+    }
+
+    pub fn token_id(&self) -> &Identifier {
+        &self.token_id
+    }
+
+    pub fn identity_id(&self) -> &Identifier {
+        &self.identity_id
+    }
+}
+
+impl From<TokenApproveToOurselfError> for ConsensusError {
+    fn from(err: TokenApproveToOurselfError) -> Self {
+        Self::BasicError(BasicError::TokenApproveToOurselfError(err))
+    }
+}
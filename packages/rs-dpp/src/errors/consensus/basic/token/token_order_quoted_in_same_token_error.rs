@@ -0,0 +1,32 @@
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("An order on token {} can not be quoted in the same token", token_id)]
+#[platform_serialize(unversioned)]
+pub struct TokenOrderQuotedInSameTokenError {
+    token_id: Identifier,
+}
+
+impl TokenOrderQuotedInSameTokenError {
+    pub fn new(token_id: Identifier) -> Self {
+        Self { token_id }
+    }
+
+    pub fn token_id(&self) -> Identifier {
+        self.token_id
+    }
+}
+
+impl From<TokenOrderQuotedInSameTokenError> for ConsensusError {
+    fn from(err: TokenOrderQuotedInSameTokenError) -> Self {
+        Self::BasicError(BasicError::TokenOrderQuotedInSameTokenError(err))
+    }
+}
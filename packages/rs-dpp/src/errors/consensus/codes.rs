@@ -120,6 +120,7 @@ impl ErrorWithCode for BasicError {
             Self::InvalidTokenDistributionTimeIntervalTooShortError(_) => 10273,
             Self::InvalidTokenDistributionTimeIntervalNotMinuteAlignedError(_) => 10274,
             Self::RedundantDocumentPaidForByTokenWithContractId(_) => 10275,
+            Self::InvalidDocumentTypeTimeToLiveError(_) => 10276,
 
             // Group Errors: 10350-10399
             Self::GroupPositionDoesNotExistError(_) => 10350,
@@ -166,6 +167,10 @@ impl ErrorWithCode for BasicError {
             Self::InvalidTokenAmountError(_) => 10458,
             Self::InvalidTokenNoteTooBigError(_) => 10459,
             Self::TokenNoteOnlyAllowedWhenProposerError(_) => 10460,
+            Self::InvalidTokenOrderPriceError(_) => 10461,
+            Self::TokenOrderQuotedInSameTokenError(_) => 10462,
+            Self::InvalidTokenVestingScheduleError(_) => 10463,
+            Self::TokenApproveToOurselfError(_) => 10464,
 
             // Identity Errors: 10500-10599
             Self::DuplicatedIdentityPublicKeyBasicError(_) => 10500,
@@ -255,6 +260,10 @@ impl ErrorWithCode for StateError {
             Self::IdentityMemberOfGroupNotFoundError(_) => 40007,
             Self::DataContractNotFoundError(_) => 40008,
             Self::InvalidTokenPositionStateError(_) => 40009,
+            Self::DataContractNotDeletableError(_) => 40010,
+            Self::DataContractDeletePermissionError(_) => 40011,
+            Self::DataContractHasActiveTokensOrGroupsError(_) => 40012,
+            Self::DataContractHasDocumentsError(_) => 40013,
 
             // Document Errors: 40100-40199
             Self::DocumentAlreadyPresentError { .. } => 40100,
@@ -303,6 +312,11 @@ impl ErrorWithCode for StateError {
             Self::MasternodeVoteAlreadyPresentError(_) => 40304,
             Self::MasternodeIncorrectVotingAddressError(_) => 40305,
             Self::MasternodeIncorrectVoterIdentityIdError(_) => 40306,
+            Self::YesNoAbstainVotePollNotOpenError(_) => 40307,
+            Self::InvalidYesNoAbstainVotePollQuestionError(_) => 40308,
+            Self::UnauthorizedVotePollCreatorError(_) => 40309,
+            Self::VotePollAlreadyExistsError(_) => 40310,
+            Self::MasternodeNotEligibleForVotePollError(_) => 40311,
 
             // Prefunded specialized balances Errors: 40400-40499
             Self::PrefundedSpecializedBalanceInsufficientError(_) => 40400,
@@ -334,6 +348,16 @@ impl ErrorWithCode for StateError {
             Self::TokenDirectPurchaseUserPriceTooLow(_) => 40719,
             Self::TokenAmountUnderMinimumSaleAmount(_) => 40720,
             Self::TokenNotForDirectSale(_) => 40721,
+            Self::TokenOrderBookTradingNotAllowedError(_) => 40722,
+            Self::TokenOrderNotFoundError(_) => 40723,
+            Self::TokenOrderNotOwnedError(_) => 40724,
+            Self::TokenVestingNotFoundError(_) => 40725,
+            Self::TokenVestingNothingToClaimError(_) => 40726,
+            Self::TokenAllowanceNotFoundError(_) => 40727,
+            Self::TokenAllowanceExceededError(_) => 40728,
+            Self::TokenAllowanceExpiredError(_) => 40729,
+            Self::TokenOrderSelfTradeError(_) => 40730,
+            Self::TokenOrderFillLimitExceededError(_) => 40731,
 
             // Group errors: 40800-40899
             Self::IdentityNotMemberOfGroupError(_) => 40800,
@@ -0,0 +1,49 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Data Contract {data_contract_id} can not be deleted while it defines {token_count} token(s) and {group_count} group(s)")]
+#[platform_serialize(unversioned)]
+pub struct DataContractHasActiveTokensOrGroupsError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    data_contract_id: Identifier,
+    token_count: u16,
+    group_count: u16,
+}
+
+impl DataContractHasActiveTokensOrGroupsError {
+    pub fn new(data_contract_id: Identifier, token_count: u16, group_count: u16) -> Self {
+        Self {
+            data_contract_id,
+            token_count,
+            group_count,
+        }
+    }
+
+    pub fn data_contract_id(&self) -> &Identifier {
+        &self.data_contract_id
+    }
+    pub fn token_count(&self) -> u16 {
+        self.token_count
+    }
+    pub fn group_count(&self) -> u16 {
+        self.group_count
+    }
+}
+
+impl From<DataContractHasActiveTokensOrGroupsError> for ConsensusError {
+    fn from(err: DataContractHasActiveTokensOrGroupsError) -> Self {
+        Self::StateError(StateError::DataContractHasActiveTokensOrGroupsError(err))
+    }
+}
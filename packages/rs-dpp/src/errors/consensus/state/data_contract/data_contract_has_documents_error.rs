@@ -0,0 +1,44 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Data Contract {data_contract_id} can not be deleted while documents of type {document_type_name} still exist")]
+#[platform_serialize(unversioned)]
+pub struct DataContractHasDocumentsError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    data_contract_id: Identifier,
+    document_type_name: String,
+}
+
+impl DataContractHasDocumentsError {
+    pub fn new(data_contract_id: Identifier, document_type_name: String) -> Self {
+        Self {
+            data_contract_id,
+            document_type_name,
+        }
+    }
+
+    pub fn data_contract_id(&self) -> &Identifier {
+        &self.data_contract_id
+    }
+    pub fn document_type_name(&self) -> &String {
+        &self.document_type_name
+    }
+}
+
+impl From<DataContractHasDocumentsError> for ConsensusError {
+    fn from(err: DataContractHasDocumentsError) -> Self {
+        Self::StateError(StateError::DataContractHasDocumentsError(err))
+    }
+}
@@ -1,6 +1,10 @@
 pub mod data_contract_already_present_error;
 pub mod data_contract_config_update_error;
+pub mod data_contract_delete_permission_error;
+pub mod data_contract_has_active_tokens_or_groups_error;
+pub mod data_contract_has_documents_error;
 pub mod data_contract_is_readonly_error;
+pub mod data_contract_not_deletable_error;
 pub mod data_contract_not_found_error;
 pub mod data_contract_update_action_not_allowed_error;
 pub mod data_contract_update_permission_error;
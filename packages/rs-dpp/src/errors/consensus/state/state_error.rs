@@ -25,6 +25,10 @@ use crate::consensus::state::identity::missing_identity_public_key_ids_error::Mi
 use crate::consensus::state::identity::{IdentityAlreadyExistsError, IdentityInsufficientBalanceError, RecipientIdentityDoesNotExistError};
 use crate::consensus::ConsensusError;
 use crate::consensus::state::data_contract::data_contract_not_found_error::DataContractNotFoundError;
+use crate::consensus::state::data_contract::data_contract_not_deletable_error::DataContractNotDeletableError;
+use crate::consensus::state::data_contract::data_contract_delete_permission_error::DataContractDeletePermissionError;
+use crate::consensus::state::data_contract::data_contract_has_active_tokens_or_groups_error::DataContractHasActiveTokensOrGroupsError;
+use crate::consensus::state::data_contract::data_contract_has_documents_error::DataContractHasDocumentsError;
 use crate::consensus::state::data_contract::data_contract_update_action_not_allowed_error::DataContractUpdateActionNotAllowedError;
 use crate::consensus::state::data_contract::data_contract_update_permission_error::DataContractUpdatePermissionError;
 use crate::consensus::state::data_contract::document_type_update_error::DocumentTypeUpdateError;
@@ -44,7 +48,7 @@ use crate::consensus::state::identity::missing_transfer_key_error::MissingTransf
 use crate::consensus::state::identity::no_transfer_key_for_core_withdrawal_available_error::NoTransferKeyForCoreWithdrawalAvailableError;
 use crate::consensus::state::prefunded_specialized_balances::prefunded_specialized_balance_insufficient_error::PrefundedSpecializedBalanceInsufficientError;
 use crate::consensus::state::prefunded_specialized_balances::prefunded_specialized_balance_not_found_error::PrefundedSpecializedBalanceNotFoundError;
-use crate::consensus::state::token::{IdentityDoesNotHaveEnoughTokenBalanceError, IdentityTokenAccountFrozenError, IdentityTokenAccountNotFrozenError, InvalidGroupPositionError, NewAuthorizedActionTakerGroupDoesNotExistError, NewAuthorizedActionTakerIdentityDoesNotExistError, NewAuthorizedActionTakerMainGroupNotSetError, NewTokensDestinationIdentityDoesNotExistError, TokenMintPastMaxSupplyError, TokenSettingMaxSupplyToLessThanCurrentSupplyError, UnauthorizedTokenActionError, IdentityTokenAccountAlreadyFrozenError, TokenAlreadyPausedError, TokenIsPausedError, TokenNotPausedError, InvalidTokenClaimPropertyMismatch, InvalidTokenClaimNoCurrentRewards, InvalidTokenClaimWrongClaimant, PreProgrammedDistributionTimestampInPastError, TokenTransferRecipientIdentityNotExistError, IdentityHasNotAgreedToPayRequiredTokenAmountError, RequiredTokenPaymentInfoNotSetError, IdentityTryingToPayWithWrongTokenError, TokenDirectPurchaseUserPriceTooLow, TokenAmountUnderMinimumSaleAmount, TokenNotForDirectSale, InvalidTokenPositionStateError};
+use crate::consensus::state::token::{IdentityDoesNotHaveEnoughTokenBalanceError, IdentityTokenAccountFrozenError, IdentityTokenAccountNotFrozenError, InvalidGroupPositionError, NewAuthorizedActionTakerGroupDoesNotExistError, NewAuthorizedActionTakerIdentityDoesNotExistError, NewAuthorizedActionTakerMainGroupNotSetError, NewTokensDestinationIdentityDoesNotExistError, TokenMintPastMaxSupplyError, TokenSettingMaxSupplyToLessThanCurrentSupplyError, UnauthorizedTokenActionError, IdentityTokenAccountAlreadyFrozenError, TokenAlreadyPausedError, TokenIsPausedError, TokenNotPausedError, InvalidTokenClaimPropertyMismatch, InvalidTokenClaimNoCurrentRewards, InvalidTokenClaimWrongClaimant, PreProgrammedDistributionTimestampInPastError, TokenTransferRecipientIdentityNotExistError, IdentityHasNotAgreedToPayRequiredTokenAmountError, RequiredTokenPaymentInfoNotSetError, IdentityTryingToPayWithWrongTokenError, TokenDirectPurchaseUserPriceTooLow, TokenAmountUnderMinimumSaleAmount, TokenNotForDirectSale, InvalidTokenPositionStateError, TokenOrderBookTradingNotAllowedError, TokenOrderNotFoundError, TokenOrderNotOwnedError, TokenVestingNotFoundError, TokenVestingNothingToClaimError, TokenAllowanceNotFoundError, TokenAllowanceExceededError, TokenAllowanceExpiredError, TokenOrderSelfTradeError, TokenOrderFillLimitExceededError};
 use crate::consensus::state::voting::masternode_incorrect_voter_identity_id_error::MasternodeIncorrectVoterIdentityIdError;
 use crate::consensus::state::voting::masternode_incorrect_voting_address_error::MasternodeIncorrectVotingAddressError;
 use crate::consensus::state::voting::masternode_not_found_error::MasternodeNotFoundError;
@@ -52,6 +56,11 @@ use crate::consensus::state::voting::masternode_vote_already_present_error::Mast
 use crate::consensus::state::voting::masternode_voted_too_many_times::MasternodeVotedTooManyTimesError;
 use crate::consensus::state::voting::vote_poll_not_available_for_voting_error::VotePollNotAvailableForVotingError;
 use crate::consensus::state::voting::vote_poll_not_found_error::VotePollNotFoundError;
+use crate::consensus::state::voting::yes_no_abstain_vote_poll_not_open_error::YesNoAbstainVotePollNotOpenError;
+use crate::consensus::state::voting::invalid_yes_no_abstain_vote_poll_question_error::InvalidYesNoAbstainVotePollQuestionError;
+use crate::consensus::state::voting::masternode_not_eligible_for_vote_poll_error::MasternodeNotEligibleForVotePollError;
+use crate::consensus::state::voting::unauthorized_vote_poll_creator_error::UnauthorizedVotePollCreatorError;
+use crate::consensus::state::voting::vote_poll_already_exists_error::VotePollAlreadyExistsError;
 
 use super::document::document_timestamps_are_equal_error::DocumentTimestampsAreEqualError;
 
@@ -321,6 +330,63 @@ pub enum StateError {
 
     #[error(transparent)]
     InvalidTokenPositionStateError(InvalidTokenPositionStateError),
+
+    #[error(transparent)]
+    TokenOrderBookTradingNotAllowedError(TokenOrderBookTradingNotAllowedError),
+
+    #[error(transparent)]
+    TokenOrderNotFoundError(TokenOrderNotFoundError),
+
+    #[error(transparent)]
+    TokenOrderNotOwnedError(TokenOrderNotOwnedError),
+
+    #[error(transparent)]
+    DataContractNotDeletableError(DataContractNotDeletableError),
+
+    #[error(transparent)]
+    DataContractDeletePermissionError(DataContractDeletePermissionError),
+
+    #[error(transparent)]
+    DataContractHasActiveTokensOrGroupsError(DataContractHasActiveTokensOrGroupsError),
+
+    #[error(transparent)]
+    DataContractHasDocumentsError(DataContractHasDocumentsError),
+
+    #[error(transparent)]
+    YesNoAbstainVotePollNotOpenError(YesNoAbstainVotePollNotOpenError),
+
+    #[error(transparent)]
+    InvalidYesNoAbstainVotePollQuestionError(InvalidYesNoAbstainVotePollQuestionError),
+
+    #[error(transparent)]
+    TokenVestingNotFoundError(TokenVestingNotFoundError),
+
+    #[error(transparent)]
+    TokenVestingNothingToClaimError(TokenVestingNothingToClaimError),
+
+    #[error(transparent)]
+    TokenAllowanceNotFoundError(TokenAllowanceNotFoundError),
+
+    #[error(transparent)]
+    TokenAllowanceExceededError(TokenAllowanceExceededError),
+
+    #[error(transparent)]
+    TokenAllowanceExpiredError(TokenAllowanceExpiredError),
+
+    #[error(transparent)]
+    TokenOrderSelfTradeError(TokenOrderSelfTradeError),
+
+    #[error(transparent)]
+    TokenOrderFillLimitExceededError(TokenOrderFillLimitExceededError),
+
+    #[error(transparent)]
+    UnauthorizedVotePollCreatorError(UnauthorizedVotePollCreatorError),
+
+    #[error(transparent)]
+    VotePollAlreadyExistsError(VotePollAlreadyExistsError),
+
+    #[error(transparent)]
+    MasternodeNotEligibleForVotePollError(MasternodeNotEligibleForVotePollError),
 }
 
 impl From<StateError> for ConsensusError {
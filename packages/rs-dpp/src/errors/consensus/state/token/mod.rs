@@ -15,6 +15,9 @@ mod new_authorized_action_taker_main_group_not_set_error;
 mod new_tokens_destination_identity_does_not_exist_error;
 mod pre_programmed_distribution_timestamp_in_past_error;
 mod required_token_payment_info_not_set_error;
+mod token_allowance_exceeded_error;
+mod token_allowance_expired_error;
+mod token_allowance_not_found_error;
 mod token_already_paused_error;
 mod token_amount_under_minimum_sale_amount;
 mod token_direct_purchase_user_price_too_low;
@@ -22,8 +25,15 @@ mod token_is_paused_error;
 mod token_mint_past_max_supply_error;
 mod token_not_for_direct_sale;
 mod token_not_paused_error;
+mod token_order_book_trading_not_allowed_error;
+mod token_order_fill_limit_exceeded_error;
+mod token_order_not_found_error;
+mod token_order_not_owned_error;
+mod token_order_self_trade_error;
 mod token_setting_max_supply_to_less_than_current_supply_error;
 mod token_transfer_recipient_identity_not_exist_error;
+mod token_vesting_not_found_error;
+mod token_vesting_nothing_to_claim_error;
 mod unauthorized_token_action_error;
 
 pub use identity_does_not_have_enough_token_balance_error::*;
@@ -43,6 +53,9 @@ pub use new_authorized_action_taker_main_group_not_set_error::*;
 pub use new_tokens_destination_identity_does_not_exist_error::*;
 pub use pre_programmed_distribution_timestamp_in_past_error::*;
 pub use required_token_payment_info_not_set_error::*;
+pub use token_allowance_exceeded_error::*;
+pub use token_allowance_expired_error::*;
+pub use token_allowance_not_found_error::*;
 pub use token_already_paused_error::*;
 pub use token_amount_under_minimum_sale_amount::*;
 pub use token_direct_purchase_user_price_too_low::*;
@@ -50,6 +63,13 @@ pub use token_is_paused_error::*;
 pub use token_mint_past_max_supply_error::*;
 pub use token_not_for_direct_sale::*;
 pub use token_not_paused_error::*;
+pub use token_order_book_trading_not_allowed_error::*;
+pub use token_order_fill_limit_exceeded_error::*;
+pub use token_order_not_found_error::*;
+pub use token_order_not_owned_error::*;
+pub use token_order_self_trade_error::*;
 pub use token_setting_max_supply_to_less_than_current_supply_error::*;
 pub use token_transfer_recipient_identity_not_exist_error::*;
+pub use token_vesting_not_found_error::*;
+pub use token_vesting_nothing_to_claim_error::*;
 pub use unauthorized_token_action_error::*;
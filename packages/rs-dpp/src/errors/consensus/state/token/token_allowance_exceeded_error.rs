@@ -0,0 +1,66 @@
+use crate::balances::credits::TokenAmount;
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Allowance of {spender} on token {token_id} of identity {owner_id} is {allowance}, but {requested_amount} was requested.")]
+#[platform_serialize(unversioned)]
+pub struct TokenAllowanceExceededError {
+    token_id: Identifier,
+    owner_id: Identifier,
+    spender: TokenAllowanceSpender,
+    allowance: TokenAmount,
+    requested_amount: TokenAmount,
+}
+
+impl TokenAllowanceExceededError {
+    pub fn new(
+        token_id: Identifier,
+        owner_id: Identifier,
+        spender: TokenAllowanceSpender,
+        allowance: TokenAmount,
+        requested_amount: TokenAmount,
+    ) -> Self {
+        Self {
+            token_id,
+            owner_id,
+            spender,
+            allowance,
+            requested_amount,
+        }
+    }
+
+    pub fn token_id(&self) -> &Identifier {
+        &self.token_id
+    }
+
+    pub fn owner_id(&self) -> &Identifier {
+        &self.owner_id
+    }
+
+    pub fn spender(&self) -> &TokenAllowanceSpender {
+        &self.spender
+    }
+
+    pub fn allowance(&self) -> TokenAmount {
+        self.allowance
+    }
+
+    pub fn requested_amount(&self) -> TokenAmount {
+        self.requested_amount
+    }
+}
+
+impl From<TokenAllowanceExceededError> for ConsensusError {
+    fn from(err: TokenAllowanceExceededError) -> Self {
+        Self::StateError(StateError::TokenAllowanceExceededError(err))
+    }
+}
@@ -0,0 +1,66 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::prelude::TimestampMillis;
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Allowance of {spender} on token {token_id} of identity {owner_id} expires at {expires_at}, which is not after {current_moment}.")]
+#[platform_serialize(unversioned)]
+pub struct TokenAllowanceExpiredError {
+    token_id: Identifier,
+    owner_id: Identifier,
+    spender: TokenAllowanceSpender,
+    expires_at: TimestampMillis,
+    current_moment: TimestampMillis,
+}
+
+impl TokenAllowanceExpiredError {
+    pub fn new(
+        token_id: Identifier,
+        owner_id: Identifier,
+        spender: TokenAllowanceSpender,
+        expires_at: TimestampMillis,
+        current_moment: TimestampMillis,
+    ) -> Self {
+        Self {
+            token_id,
+            owner_id,
+            spender,
+            expires_at,
+            current_moment,
+        }
+    }
+
+    pub fn token_id(&self) -> &Identifier {
+        &self.token_id
+    }
+
+    pub fn owner_id(&self) -> &Identifier {
+        &self.owner_id
+    }
+
+    pub fn spender(&self) -> &TokenAllowanceSpender {
+        &self.spender
+    }
+
+    pub fn expires_at(&self) -> TimestampMillis {
+        self.expires_at
+    }
+
+    pub fn current_moment(&self) -> TimestampMillis {
+        self.current_moment
+    }
+}
+
+impl From<TokenAllowanceExpiredError> for ConsensusError {
+    fn from(err: TokenAllowanceExpiredError) -> Self {
+        Self::StateError(StateError::TokenAllowanceExpiredError(err))
+    }
+}
@@ -0,0 +1,47 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity {owner_id} has not approved {spender} to spend token {token_id}.")]
+#[platform_serialize(unversioned)]
+pub struct TokenAllowanceNotFoundError {
+    token_id: Identifier,
+    owner_id: Identifier,
+    spender: TokenAllowanceSpender,
+}
+
+impl TokenAllowanceNotFoundError {
+    pub fn new(token_id: Identifier, owner_id: Identifier, spender: TokenAllowanceSpender) -> Self {
+        Self {
+            token_id,
+            owner_id,
+            spender,
+        }
+    }
+
+    pub fn token_id(&self) -> &Identifier {
+        &self.token_id
+    }
+
+    pub fn owner_id(&self) -> &Identifier {
+        &self.owner_id
+    }
+
+    pub fn spender(&self) -> &TokenAllowanceSpender {
+        &self.spender
+    }
+}
+
+impl From<TokenAllowanceNotFoundError> for ConsensusError {
+    fn from(err: TokenAllowanceNotFoundError) -> Self {
+        Self::StateError(StateError::TokenAllowanceNotFoundError(err))
+    }
+}
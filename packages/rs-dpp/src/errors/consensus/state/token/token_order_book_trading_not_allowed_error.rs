@@ -0,0 +1,41 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::tokens::allowed_currency::AllowedCurrency;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Token {token_id} can not be traded on the order book against {quote_currency}.")]
+#[platform_serialize(unversioned)]
+pub struct TokenOrderBookTradingNotAllowedError {
+    token_id: Identifier,
+    quote_currency: AllowedCurrency,
+}
+
+impl TokenOrderBookTradingNotAllowedError {
+    pub fn new(token_id: Identifier, quote_currency: AllowedCurrency) -> Self {
+        Self {
+            token_id,
+            quote_currency,
+        }
+    }
+
+    pub fn token_id(&self) -> &Identifier {
+        &self.token_id
+    }
+
+    pub fn quote_currency(&self) -> &AllowedCurrency {
+        &self.quote_currency
+    }
+}
+
+impl From<TokenOrderBookTradingNotAllowedError> for ConsensusError {
+    fn from(err: TokenOrderBookTradingNotAllowedError) -> Self {
+        Self::StateError(StateError::TokenOrderBookTradingNotAllowedError(err))
+    }
+}
@@ -0,0 +1,42 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "Order on token {token_id} would fill more than {max_fills} resting orders, place a smaller order or use a less aggressive price."
+)]
+#[platform_serialize(unversioned)]
+pub struct TokenOrderFillLimitExceededError {
+    token_id: Identifier,
+    max_fills: u16,
+}
+
+impl TokenOrderFillLimitExceededError {
+    pub fn new(token_id: Identifier, max_fills: u16) -> Self {
+        Self {
+            token_id,
+            max_fills,
+        }
+    }
+
+    pub fn token_id(&self) -> &Identifier {
+        &self.token_id
+    }
+
+    pub fn max_fills(&self) -> u16 {
+        self.max_fills
+    }
+}
+
+impl From<TokenOrderFillLimitExceededError> for ConsensusError {
+    fn from(err: TokenOrderFillLimitExceededError) -> Self {
+        Self::StateError(StateError::TokenOrderFillLimitExceededError(err))
+    }
+}
@@ -0,0 +1,37 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Order {order_id} was not found on the order book of token {token_id}.")]
+#[platform_serialize(unversioned)]
+pub struct TokenOrderNotFoundError {
+    token_id: Identifier,
+    order_id: Identifier,
+}
+
+impl TokenOrderNotFoundError {
+    pub fn new(token_id: Identifier, order_id: Identifier) -> Self {
+        Self { token_id, order_id }
+    }
+
+    pub fn token_id(&self) -> &Identifier {
+        &self.token_id
+    }
+
+    pub fn order_id(&self) -> &Identifier {
+        &self.order_id
+    }
+}
+
+impl From<TokenOrderNotFoundError> for ConsensusError {
+    fn from(err: TokenOrderNotFoundError) -> Self {
+        Self::StateError(StateError::TokenOrderNotFoundError(err))
+    }
+}
@@ -0,0 +1,59 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "Identity {identity_id} can not cancel order {order_id} of token {token_id}, it is owned by {owner_id}."
+)]
+#[platform_serialize(unversioned)]
+pub struct TokenOrderNotOwnedError {
+    token_id: Identifier,
+    order_id: Identifier,
+    identity_id: Identifier,
+    owner_id: Identifier,
+}
+
+impl TokenOrderNotOwnedError {
+    pub fn new(
+        token_id: Identifier,
+        order_id: Identifier,
+        identity_id: Identifier,
+        owner_id: Identifier,
+    ) -> Self {
+        Self {
+            token_id,
+            order_id,
+            identity_id,
+            owner_id,
+        }
+    }
+
+    pub fn token_id(&self) -> &Identifier {
+        &self.token_id
+    }
+
+    pub fn order_id(&self) -> &Identifier {
+        &self.order_id
+    }
+
+    pub fn identity_id(&self) -> &Identifier {
+        &self.identity_id
+    }
+
+    pub fn owner_id(&self) -> &Identifier {
+        &self.owner_id
+    }
+}
+
+impl From<TokenOrderNotOwnedError> for ConsensusError {
+    fn from(err: TokenOrderNotOwnedError) -> Self {
+        Self::StateError(StateError::TokenOrderNotOwnedError(err))
+    }
+}
@@ -0,0 +1,52 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error(
+    "Order of identity {identity_id} on token {token_id} would trade against its own resting order {resting_order_id}."
+)]
+#[platform_serialize(unversioned)]
+pub struct TokenOrderSelfTradeError {
+    token_id: Identifier,
+    identity_id: Identifier,
+    resting_order_id: Identifier,
+}
+
+impl TokenOrderSelfTradeError {
+    pub fn new(
+        token_id: Identifier,
+        identity_id: Identifier,
+        resting_order_id: Identifier,
+    ) -> Self {
+        Self {
+            token_id,
+            identity_id,
+            resting_order_id,
+        }
+    }
+
+    pub fn token_id(&self) -> &Identifier {
+        &self.token_id
+    }
+
+    pub fn identity_id(&self) -> &Identifier {
+        &self.identity_id
+    }
+
+    pub fn resting_order_id(&self) -> &Identifier {
+        &self.resting_order_id
+    }
+}
+
+impl From<TokenOrderSelfTradeError> for ConsensusError {
+    fn from(err: TokenOrderSelfTradeError) -> Self {
+        Self::StateError(StateError::TokenOrderSelfTradeError(err))
+    }
+}
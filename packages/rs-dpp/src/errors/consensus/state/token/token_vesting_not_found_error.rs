@@ -0,0 +1,46 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Vesting {vesting_id} of token {token_id} was not found for recipient {recipient_id}.")]
+#[platform_serialize(unversioned)]
+pub struct TokenVestingNotFoundError {
+    token_id: Identifier,
+    recipient_id: Identifier,
+    vesting_id: Identifier,
+}
+
+impl TokenVestingNotFoundError {
+    pub fn new(token_id: Identifier, recipient_id: Identifier, vesting_id: Identifier) -> Self {
+        Self {
+            token_id,
+            recipient_id,
+            vesting_id,
+        }
+    }
+
+    pub fn token_id(&self) -> &Identifier {
+        &self.token_id
+    }
+
+    pub fn recipient_id(&self) -> &Identifier {
+        &self.recipient_id
+    }
+
+    pub fn vesting_id(&self) -> &Identifier {
+        &self.vesting_id
+    }
+}
+
+impl From<TokenVestingNotFoundError> for ConsensusError {
+    fn from(err: TokenVestingNotFoundError) -> Self {
+        Self::StateError(StateError::TokenVestingNotFoundError(err))
+    }
+}
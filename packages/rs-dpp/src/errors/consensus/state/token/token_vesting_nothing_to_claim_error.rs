@@ -0,0 +1,51 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::prelude::TimestampMillis;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Nothing has vested yet to be claimed from vesting {vesting_id} of token {token_id} at {current_moment}.")]
+#[platform_serialize(unversioned)]
+pub struct TokenVestingNothingToClaimError {
+    token_id: Identifier,
+    vesting_id: Identifier,
+    current_moment: TimestampMillis,
+}
+
+impl TokenVestingNothingToClaimError {
+    pub fn new(
+        token_id: Identifier,
+        vesting_id: Identifier,
+        current_moment: TimestampMillis,
+    ) -> Self {
+        Self {
+            token_id,
+            vesting_id,
+            current_moment,
+        }
+    }
+
+    pub fn token_id(&self) -> &Identifier {
+        &self.token_id
+    }
+
+    pub fn vesting_id(&self) -> &Identifier {
+        &self.vesting_id
+    }
+
+    pub fn current_moment(&self) -> TimestampMillis {
+        self.current_moment
+    }
+}
+
+impl From<TokenVestingNothingToClaimError> for ConsensusError {
+    fn from(err: TokenVestingNothingToClaimError) -> Self {
+        Self::StateError(StateError::TokenVestingNothingToClaimError(err))
+    }
+}
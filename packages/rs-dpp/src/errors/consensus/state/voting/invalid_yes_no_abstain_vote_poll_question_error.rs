@@ -0,0 +1,44 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Yes/no/abstain vote poll question has {question_length} characters, it must have between 1 and {max_length} characters")]
+#[platform_serialize(unversioned)]
+pub struct InvalidYesNoAbstainVotePollQuestionError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    question_length: u32,
+    max_length: u16,
+}
+
+impl InvalidYesNoAbstainVotePollQuestionError {
+    pub fn new(question_length: u32, max_length: u16) -> Self {
+        Self {
+            question_length,
+            max_length,
+        }
+    }
+
+    pub fn question_length(&self) -> u32 {
+        self.question_length
+    }
+
+    pub fn max_length(&self) -> u16 {
+        self.max_length
+    }
+}
+
+impl From<InvalidYesNoAbstainVotePollQuestionError> for ConsensusError {
+    fn from(err: InvalidYesNoAbstainVotePollQuestionError) -> Self {
+        Self::StateError(StateError::InvalidYesNoAbstainVotePollQuestionError(err))
+    }
+}
@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Masternode {pro_tx_hash} was not in the masternode list when vote poll {vote_poll_id} was created and can not vote on it")]
+#[platform_serialize(unversioned)]
+pub struct MasternodeNotEligibleForVotePollError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pro_tx_hash: Identifier,
+    vote_poll_id: Identifier,
+}
+
+impl MasternodeNotEligibleForVotePollError {
+    pub fn new(pro_tx_hash: Identifier, vote_poll_id: Identifier) -> Self {
+        Self {
+            pro_tx_hash,
+            vote_poll_id,
+        }
+    }
+
+    pub fn pro_tx_hash(&self) -> Identifier {
+        self.pro_tx_hash
+    }
+
+    pub fn vote_poll_id(&self) -> Identifier {
+        self.vote_poll_id
+    }
+}
+
+impl From<MasternodeNotEligibleForVotePollError> for ConsensusError {
+    fn from(err: MasternodeNotEligibleForVotePollError) -> Self {
+        Self::StateError(StateError::MasternodeNotEligibleForVotePollError(err))
+    }
+}
@@ -1,7 +1,12 @@
+pub mod invalid_yes_no_abstain_vote_poll_question_error;
 pub mod masternode_incorrect_voter_identity_id_error;
 pub mod masternode_incorrect_voting_address_error;
+pub mod masternode_not_eligible_for_vote_poll_error;
 pub mod masternode_not_found_error;
 pub mod masternode_vote_already_present_error;
 pub mod masternode_voted_too_many_times;
+pub mod unauthorized_vote_poll_creator_error;
+pub mod vote_poll_already_exists_error;
 pub mod vote_poll_not_available_for_voting_error;
 pub mod vote_poll_not_found_error;
+pub mod yes_no_abstain_vote_poll_not_open_error;
@@ -0,0 +1,51 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity {creator_id} can not create vote poll {vote_poll_id}, the poll subject {subject_id} must be the creator or a data contract owned by the creator")]
+#[platform_serialize(unversioned)]
+pub struct UnauthorizedVotePollCreatorError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    vote_poll_id: Identifier,
+    creator_id: Identifier,
+    subject_id: Identifier,
+}
+
+impl UnauthorizedVotePollCreatorError {
+    pub fn new(vote_poll_id: Identifier, creator_id: Identifier, subject_id: Identifier) -> Self {
+        Self {
+            vote_poll_id,
+            creator_id,
+            subject_id,
+        }
+    }
+
+    pub fn vote_poll_id(&self) -> Identifier {
+        self.vote_poll_id
+    }
+
+    pub fn creator_id(&self) -> Identifier {
+        self.creator_id
+    }
+
+    pub fn subject_id(&self) -> Identifier {
+        self.subject_id
+    }
+}
+
+impl From<UnauthorizedVotePollCreatorError> for ConsensusError {
+    fn from(err: UnauthorizedVotePollCreatorError) -> Self {
+        Self::StateError(StateError::UnauthorizedVotePollCreatorError(err))
+    }
+}
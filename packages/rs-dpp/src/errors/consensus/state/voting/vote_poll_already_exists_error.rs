@@ -0,0 +1,37 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Vote poll {vote_poll_id} already exists")]
+#[platform_serialize(unversioned)]
+pub struct VotePollAlreadyExistsError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    vote_poll_id: Identifier,
+}
+
+impl VotePollAlreadyExistsError {
+    pub fn new(vote_poll_id: Identifier) -> Self {
+        Self { vote_poll_id }
+    }
+
+    pub fn vote_poll_id(&self) -> Identifier {
+        self.vote_poll_id
+    }
+}
+
+impl From<VotePollAlreadyExistsError> for ConsensusError {
+    fn from(err: VotePollAlreadyExistsError) -> Self {
+        Self::StateError(StateError::VotePollAlreadyExistsError(err))
+    }
+}
@@ -0,0 +1,63 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::prelude::TimestampMillis;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Yes/no/abstain vote poll {vote_poll_id} ending at {end_time_ms} is not open for voting at {current_time_ms}, polls must end in the future and at most {max_duration_ms} ms from now")]
+#[platform_serialize(unversioned)]
+pub struct YesNoAbstainVotePollNotOpenError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    vote_poll_id: Identifier,
+    end_time_ms: TimestampMillis,
+    current_time_ms: TimestampMillis,
+    max_duration_ms: u64,
+}
+
+impl YesNoAbstainVotePollNotOpenError {
+    pub fn new(
+        vote_poll_id: Identifier,
+        end_time_ms: TimestampMillis,
+        current_time_ms: TimestampMillis,
+        max_duration_ms: u64,
+    ) -> Self {
+        Self {
+            vote_poll_id,
+            end_time_ms,
+            current_time_ms,
+            max_duration_ms,
+        }
+    }
+
+    pub fn vote_poll_id(&self) -> Identifier {
+        self.vote_poll_id
+    }
+
+    pub fn end_time_ms(&self) -> TimestampMillis {
+        self.end_time_ms
+    }
+
+    pub fn current_time_ms(&self) -> TimestampMillis {
+        self.current_time_ms
+    }
+
+    pub fn max_duration_ms(&self) -> u64 {
+        self.max_duration_ms
+    }
+}
+
+impl From<YesNoAbstainVotePollNotOpenError> for ConsensusError {
+    fn from(err: YesNoAbstainVotePollNotOpenError) -> Self {
+        Self::StateError(StateError::YesNoAbstainVotePollNotOpenError(err))
+    }
+}
@@ -12,6 +12,8 @@ mod identity;
 pub mod identity_public_key;
 
 pub mod state_transition;
+#[cfg(feature = "state-transitions")]
+pub mod state_transition_record;
 
 mod credits_converter;
 pub mod errors;
@@ -0,0 +1,121 @@
+use crate::fee::Credits;
+use crate::identity::state_transition_record::v0::{
+    IdentityStateTransitionRecordV0, IdentityStateTransitionRecordV0Getters,
+};
+use crate::prelude::{BlockHeight, TimestampMillis};
+use crate::state_transition::StateTransitionType;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_version::version::PlatformVersion;
+use platform_versioning::PlatformVersioned;
+
+pub mod v0;
+
+/// A state transition owned by an identity that was executed in a block.
+///
+/// Records are kept from protocol version 11, state transitions that were not paid for are
+/// never recorded.
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformDeserialize,
+    PlatformSerialize,
+    PlatformVersioned,
+    From,
+    PartialEq,
+    Eq,
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+pub enum IdentityStateTransitionRecord {
+    V0(IdentityStateTransitionRecordV0),
+}
+
+impl IdentityStateTransitionRecord {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        state_transition_hash: [u8; 32],
+        state_transition_type: StateTransitionType,
+        block_height: BlockHeight,
+        block_time_ms: TimestampMillis,
+        index_in_block: u32,
+        fee_paid: Credits,
+        error_code: Option<u32>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Self, ProtocolError> {
+        match platform_version
+            .dpp
+            .identity_versions
+            .identity_state_transition_record_structure_version
+        {
+            0 => Ok(IdentityStateTransitionRecord::V0(
+                IdentityStateTransitionRecordV0 {
+                    state_transition_hash,
+                    state_transition_type,
+                    block_height,
+                    block_time_ms,
+                    index_in_block,
+                    fee_paid,
+                    error_code,
+                },
+            )),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "IdentityStateTransitionRecord::new".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+
+    /// Whether the state transition was executed without a consensus error.
+    pub fn is_successful(&self) -> bool {
+        self.error_code().is_none()
+    }
+}
+
+impl IdentityStateTransitionRecordV0Getters for IdentityStateTransitionRecord {
+    fn state_transition_hash(&self) -> [u8; 32] {
+        match self {
+            IdentityStateTransitionRecord::V0(record) => record.state_transition_hash(),
+        }
+    }
+
+    fn state_transition_type(&self) -> StateTransitionType {
+        match self {
+            IdentityStateTransitionRecord::V0(record) => record.state_transition_type(),
+        }
+    }
+
+    fn block_height(&self) -> BlockHeight {
+        match self {
+            IdentityStateTransitionRecord::V0(record) => record.block_height(),
+        }
+    }
+
+    fn block_time_ms(&self) -> TimestampMillis {
+        match self {
+            IdentityStateTransitionRecord::V0(record) => record.block_time_ms(),
+        }
+    }
+
+    fn index_in_block(&self) -> u32 {
+        match self {
+            IdentityStateTransitionRecord::V0(record) => record.index_in_block(),
+        }
+    }
+
+    fn fee_paid(&self) -> Credits {
+        match self {
+            IdentityStateTransitionRecord::V0(record) => record.fee_paid(),
+        }
+    }
+
+    fn error_code(&self) -> Option<u32> {
+        match self {
+            IdentityStateTransitionRecord::V0(record) => record.error_code(),
+        }
+    }
+}
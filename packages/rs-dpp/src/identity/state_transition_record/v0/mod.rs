@@ -0,0 +1,76 @@
+use crate::fee::Credits;
+use crate::prelude::{BlockHeight, TimestampMillis};
+use crate::state_transition::StateTransitionType;
+use bincode::{Decode, Encode};
+
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Eq)]
+/// A state transition owned by an identity that was executed in a block
+pub struct IdentityStateTransitionRecordV0 {
+    /// The hash of the serialized state transition
+    pub state_transition_hash: [u8; 32],
+    /// The type of the state transition
+    pub state_transition_type: StateTransitionType,
+    /// The height of the block the state transition was executed in
+    pub block_height: BlockHeight,
+    /// The time of the block the state transition was executed in
+    pub block_time_ms: TimestampMillis,
+    /// The position of the state transition within the block
+    pub index_in_block: u32,
+    /// The storage and processing fees paid for the state transition
+    pub fee_paid: Credits,
+    /// The code of the consensus error the state transition failed with, if any
+    pub error_code: Option<u32>,
+}
+
+pub trait IdentityStateTransitionRecordV0Getters {
+    /// Gets the hash of the serialized state transition.
+    fn state_transition_hash(&self) -> [u8; 32];
+
+    /// Gets the type of the state transition.
+    fn state_transition_type(&self) -> StateTransitionType;
+
+    /// Gets the height of the block the state transition was executed in.
+    fn block_height(&self) -> BlockHeight;
+
+    /// Gets the time of the block the state transition was executed in.
+    fn block_time_ms(&self) -> TimestampMillis;
+
+    /// Gets the position of the state transition within the block.
+    fn index_in_block(&self) -> u32;
+
+    /// Gets the storage and processing fees paid for the state transition.
+    fn fee_paid(&self) -> Credits;
+
+    /// Gets the code of the consensus error the state transition failed with.
+    fn error_code(&self) -> Option<u32>;
+}
+
+impl IdentityStateTransitionRecordV0Getters for IdentityStateTransitionRecordV0 {
+    fn state_transition_hash(&self) -> [u8; 32] {
+        self.state_transition_hash
+    }
+
+    fn state_transition_type(&self) -> StateTransitionType {
+        self.state_transition_type
+    }
+
+    fn block_height(&self) -> BlockHeight {
+        self.block_height
+    }
+
+    fn block_time_ms(&self) -> TimestampMillis {
+        self.block_time_ms
+    }
+
+    fn index_in_block(&self) -> u32 {
+        self.index_in_block
+    }
+
+    fn fee_paid(&self) -> Credits {
+        self.fee_paid
+    }
+
+    fn error_code(&self) -> Option<u32> {
+        self.error_code
+    }
+}
@@ -83,6 +83,9 @@ use crate::state_transition::data_contract_create_transition::accessors::DataCon
 use crate::state_transition::data_contract_create_transition::{
     DataContractCreateTransition, DataContractCreateTransitionSignable,
 };
+use crate::state_transition::data_contract_delete_transition::{
+    DataContractDeleteTransition, DataContractDeleteTransitionSignable,
+};
 use crate::state_transition::data_contract_update_transition::accessors::DataContractUpdateTransitionAccessorsV0;
 use crate::state_transition::data_contract_update_transition::{
     DataContractUpdateTransition, DataContractUpdateTransitionSignable,
@@ -112,10 +115,15 @@ use crate::state_transition::identity_topup_transition::{
 use crate::state_transition::identity_update_transition::{
     IdentityUpdateTransition, IdentityUpdateTransitionSignable,
 };
+use crate::state_transition::masternode_vote_transition::accessors::MasternodeVoteTransitionAccessorsV0;
 use crate::state_transition::masternode_vote_transition::MasternodeVoteTransition;
 use crate::state_transition::masternode_vote_transition::MasternodeVoteTransitionSignable;
 #[cfg(feature = "state-transition-signing")]
 use crate::state_transition::state_transitions::document::batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use crate::state_transition::vote_poll_create_transition::{
+    VotePollCreateTransition, VotePollCreateTransitionSignable,
+};
+use crate::voting::votes::Vote;
 use state_transitions::document::batch_transition::batched_transition::token_transition::TokenTransition;
 pub use state_transitions::*;
 
@@ -134,6 +142,8 @@ macro_rules! call_method {
             StateTransition::IdentityUpdate(st) => st.$method($args),
             StateTransition::IdentityCreditTransfer(st) => st.$method($args),
             StateTransition::MasternodeVote(st) => st.$method($args),
+            StateTransition::DataContractDelete(st) => st.$method($args),
+            StateTransition::VotePollCreate(st) => st.$method($args),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -147,6 +157,8 @@ macro_rules! call_method {
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
             StateTransition::MasternodeVote(st) => st.$method(),
+            StateTransition::DataContractDelete(st) => st.$method(),
+            StateTransition::VotePollCreate(st) => st.$method(),
         }
     };
 }
@@ -163,6 +175,8 @@ macro_rules! call_getter_method_identity_signed {
             StateTransition::IdentityUpdate(st) => Some(st.$method($args)),
             StateTransition::IdentityCreditTransfer(st) => Some(st.$method($args)),
             StateTransition::MasternodeVote(st) => Some(st.$method($args)),
+            StateTransition::DataContractDelete(st) => Some(st.$method($args)),
+            StateTransition::VotePollCreate(st) => Some(st.$method($args)),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -176,6 +190,8 @@ macro_rules! call_getter_method_identity_signed {
             StateTransition::IdentityUpdate(st) => Some(st.$method()),
             StateTransition::IdentityCreditTransfer(st) => Some(st.$method()),
             StateTransition::MasternodeVote(st) => Some(st.$method()),
+            StateTransition::DataContractDelete(st) => Some(st.$method()),
+            StateTransition::VotePollCreate(st) => Some(st.$method()),
         }
     };
 }
@@ -192,6 +208,8 @@ macro_rules! call_method_identity_signed {
             StateTransition::IdentityUpdate(st) => st.$method($args),
             StateTransition::IdentityCreditTransfer(st) => st.$method($args),
             StateTransition::MasternodeVote(st) => st.$method($args),
+            StateTransition::DataContractDelete(st) => st.$method($args),
+            StateTransition::VotePollCreate(st) => st.$method($args),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -205,6 +223,8 @@ macro_rules! call_method_identity_signed {
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
             StateTransition::MasternodeVote(st) => st.$method(),
+            StateTransition::DataContractDelete(st) => st.$method(),
+            StateTransition::VotePollCreate(st) => st.$method(),
         }
     };
 }
@@ -226,6 +246,8 @@ macro_rules! call_errorable_method_identity_signed {
             StateTransition::IdentityUpdate(st) => st.$method($( $arg ),*),
             StateTransition::IdentityCreditTransfer(st) => st.$method($( $arg ),*),
             StateTransition::MasternodeVote(st) => st.$method($( $arg ),*),
+            StateTransition::DataContractDelete(st) => st.$method($( $arg ),*),
+            StateTransition::VotePollCreate(st) => st.$method($( $arg ),*),
         }
     };
     ($state_transition:expr, $method:ident) => {
@@ -243,6 +265,8 @@ macro_rules! call_errorable_method_identity_signed {
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
             StateTransition::MasternodeVote(st) => st.$method(),
+            StateTransition::DataContractDelete(st) => st.$method(),
+            StateTransition::VotePollCreate(st) => st.$method(),
         }
     };
 }
@@ -275,6 +299,8 @@ pub enum StateTransition {
     IdentityUpdate(IdentityUpdateTransition),
     IdentityCreditTransfer(IdentityCreditTransferTransition),
     MasternodeVote(MasternodeVoteTransition),
+    DataContractDelete(DataContractDeleteTransition),
+    VotePollCreate(VotePollCreateTransition),
 }
 
 impl OptionallyAssetLockProved for StateTransition {
@@ -345,8 +371,16 @@ impl StateTransition {
             | StateTransition::IdentityTopUp(_)
             | StateTransition::IdentityCreditWithdrawal(_)
             | StateTransition::IdentityUpdate(_)
-            | StateTransition::IdentityCreditTransfer(_)
-            | StateTransition::MasternodeVote(_) => ALL_VERSIONS,
+            | StateTransition::IdentityCreditTransfer(_) => ALL_VERSIONS,
+            StateTransition::MasternodeVote(masternode_vote_transition) => {
+                match masternode_vote_transition.vote() {
+                    Vote::ResourceVote(_) => ALL_VERSIONS,
+                    Vote::YesNoAbstainVote(_) => 11..=LATEST_VERSION,
+                }
+            }
+            StateTransition::DataContractDelete(_) | StateTransition::VotePollCreate(_) => {
+                11..=LATEST_VERSION
+            }
         }
     }
 
@@ -440,6 +474,22 @@ impl StateTransition {
                         BatchedTransitionRef::Token(
                             TokenTransition::SetPriceForDirectPurchase(_),
                         ) => "SetPriceForDirectPurchase",
+                        BatchedTransitionRef::Token(TokenTransition::PlaceOrder(_)) => {
+                            "TokenPlaceOrder"
+                        }
+                        BatchedTransitionRef::Token(TokenTransition::CancelOrder(_)) => {
+                            "TokenCancelOrder"
+                        }
+                        BatchedTransitionRef::Token(TokenTransition::VestingTransfer(_)) => {
+                            "TokenVestingTransfer"
+                        }
+                        BatchedTransitionRef::Token(TokenTransition::ClaimVested(_)) => {
+                            "TokenClaimVested"
+                        }
+                        BatchedTransitionRef::Token(TokenTransition::Approve(_)) => "TokenApprove",
+                        BatchedTransitionRef::Token(TokenTransition::TransferFrom(_)) => {
+                            "TokenTransferFrom"
+                        }
                     };
                     document_transition_types.push(document_transition_name);
                 }
@@ -451,6 +501,8 @@ impl StateTransition {
             Self::IdentityUpdate(_) => "IdentityUpdate".to_string(),
             Self::IdentityCreditTransfer(_) => "IdentityCreditTransfer".to_string(),
             Self::MasternodeVote(_) => "MasternodeVote".to_string(),
+            Self::DataContractDelete(_) => "DataContractDelete".to_string(),
+            Self::VotePollCreate(_) => "VotePollCreate".to_string(),
         }
     }
 
@@ -491,6 +543,11 @@ impl StateTransition {
         call_method!(self, owner_id)
     }
 
+    /// returns the type of the state transition
+    pub fn state_transition_type(&self) -> StateTransitionType {
+        call_method!(self, state_transition_type)
+    }
+
     /// returns the unique identifiers for the state transition
     pub fn unique_identifiers(&self) -> Vec<String> {
         call_method!(self, unique_identifiers)
@@ -615,6 +672,14 @@ impl StateTransition {
                 st.verify_public_key_level_and_purpose(identity_public_key, options)?;
                 st.verify_public_key_is_enabled(identity_public_key)?;
             }
+            StateTransition::DataContractDelete(st) => {
+                st.verify_public_key_level_and_purpose(identity_public_key, options)?;
+                st.verify_public_key_is_enabled(identity_public_key)?;
+            }
+            StateTransition::VotePollCreate(st) => {
+                st.verify_public_key_level_and_purpose(identity_public_key, options)?;
+                st.verify_public_key_is_enabled(identity_public_key)?;
+            }
         }
         let data = self.signable_bytes()?;
         self.set_signature(signer.sign(identity_public_key, data.as_slice())?);
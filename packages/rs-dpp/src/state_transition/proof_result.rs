@@ -4,9 +4,11 @@ use crate::data_contract::DataContract;
 use crate::document::Document;
 use crate::group::group_action_status::GroupActionStatus;
 use crate::identity::{Identity, PartialIdentity};
+use crate::tokens::allowance::TokenAllowance;
 use crate::tokens::info::IdentityTokenInfo;
 use crate::tokens::status::TokenStatus;
 use crate::tokens::token_pricing_schedule::TokenPricingSchedule;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
 use crate::voting::votes::Vote;
 use platform_value::Identifier;
 use std::collections::BTreeMap;
@@ -14,11 +16,13 @@ use std::collections::BTreeMap;
 #[derive(Debug, strum::Display, derive_more::TryInto)]
 pub enum StateTransitionProofResult {
     VerifiedDataContract(DataContract),
+    VerifiedDataContractAbsence(Identifier),
     VerifiedIdentity(Identity),
     VerifiedTokenBalanceAbsence(Identifier),
     VerifiedTokenBalance(Identifier, TokenAmount),
     VerifiedTokenIdentityInfo(Identifier, IdentityTokenInfo),
     VerifiedTokenPricingSchedule(Identifier, Option<TokenPricingSchedule>),
+    VerifiedTokenAllowance(Identifier, Option<TokenAllowance>),
     VerifiedTokenStatus(TokenStatus),
     VerifiedTokenIdentitiesBalances(BTreeMap<Identifier, TokenAmount>),
     VerifiedPartialIdentity(PartialIdentity),
@@ -39,4 +43,5 @@ pub enum StateTransitionProofResult {
     ),
     VerifiedMasternodeVote(Vote),
     VerifiedNextDistribution(Vote),
+    VerifiedYesNoAbstainVotePoll(YesNoAbstainVotePoll),
 }
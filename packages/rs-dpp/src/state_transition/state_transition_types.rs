@@ -29,6 +29,8 @@ pub enum StateTransitionType {
     IdentityCreditWithdrawal = 6,
     IdentityCreditTransfer = 7,
     MasternodeVote = 8,
+    DataContractDelete = 9,
+    VotePollCreate = 10,
 }
 
 impl std::fmt::Display for StateTransitionType {
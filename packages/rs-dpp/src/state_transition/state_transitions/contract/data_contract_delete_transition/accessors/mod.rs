@@ -0,0 +1,20 @@
+mod v0;
+
+use crate::prelude::IdentityNonce;
+use crate::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use platform_value::Identifier;
+pub use v0::*;
+
+impl DataContractDeleteTransitionAccessorsV0 for DataContractDeleteTransition {
+    fn data_contract_id(&self) -> Identifier {
+        match self {
+            DataContractDeleteTransition::V0(transition) => transition.data_contract_id,
+        }
+    }
+
+    fn identity_contract_nonce(&self) -> IdentityNonce {
+        match self {
+            DataContractDeleteTransition::V0(transition) => transition.identity_contract_nonce,
+        }
+    }
+}
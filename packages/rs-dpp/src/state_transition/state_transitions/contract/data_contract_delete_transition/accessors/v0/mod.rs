@@ -0,0 +1,9 @@
+use crate::prelude::IdentityNonce;
+use platform_value::Identifier;
+
+pub trait DataContractDeleteTransitionAccessorsV0 {
+    /// The id of the data contract being deleted
+    fn data_contract_id(&self) -> Identifier;
+    /// The identity contract nonce of the owner for the deleted data contract
+    fn identity_contract_nonce(&self) -> IdentityNonce;
+}
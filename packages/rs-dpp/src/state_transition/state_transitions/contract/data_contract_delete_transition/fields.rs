@@ -0,0 +1,13 @@
+use crate::state_transition::state_transitions;
+
+pub use state_transitions::common_fields::property_names::{
+    IDENTITY_CONTRACT_NONCE, SIGNATURE, SIGNATURE_PUBLIC_KEY_ID,
+    STATE_TRANSITION_PROTOCOL_VERSION, TRANSITION_TYPE, USER_FEE_INCREASE,
+};
+
+pub const DATA_CONTRACT_ID: &str = "dataContractId";
+pub const OWNER_ID: &str = "ownerId";
+
+pub const IDENTIFIER_FIELDS: [&str; 2] = [DATA_CONTRACT_ID, OWNER_ID];
+pub const BINARY_FIELDS: [&str; 1] = [SIGNATURE];
+pub const U32_FIELDS: [&str; 1] = [STATE_TRANSITION_PROTOCOL_VERSION];
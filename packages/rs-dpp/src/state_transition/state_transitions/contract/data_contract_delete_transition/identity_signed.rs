@@ -0,0 +1,28 @@
+use crate::identity::{KeyID, Purpose, SecurityLevel};
+
+use crate::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use crate::state_transition::StateTransitionIdentitySigned;
+
+impl StateTransitionIdentitySigned for DataContractDeleteTransition {
+    fn signature_public_key_id(&self) -> KeyID {
+        match self {
+            DataContractDeleteTransition::V0(transition) => transition.signature_public_key_id(),
+        }
+    }
+
+    fn set_signature_public_key_id(&mut self, key_id: KeyID) {
+        match self {
+            DataContractDeleteTransition::V0(transition) => {
+                transition.set_signature_public_key_id(key_id)
+            }
+        }
+    }
+
+    fn security_level_requirement(&self, purpose: Purpose) -> Vec<SecurityLevel> {
+        match self {
+            DataContractDeleteTransition::V0(transition) => {
+                transition.security_level_requirement(purpose)
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+mod v0;
+
+pub use v0::*;
+
+use crate::identity::signer::Signer;
+use crate::identity::{KeyID, PartialIdentity};
+use crate::state_transition::data_contract_delete_transition::{
+    DataContractDeleteTransition, DataContractDeleteTransitionV0,
+};
+use crate::state_transition::StateTransition;
+use crate::version::FeatureVersion;
+use crate::ProtocolError;
+
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use platform_value::Identifier;
+use platform_version::version::PlatformVersion;
+
+impl DataContractDeleteTransitionMethodsV0 for DataContractDeleteTransition {
+    fn new_from_data_contract_id<S: Signer>(
+        data_contract_id: Identifier,
+        identity: &PartialIdentity,
+        key_id: KeyID,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .contract_delete_state_transition
+                .default_current_version,
+        ) {
+            0 => DataContractDeleteTransitionV0::new_from_data_contract_id(
+                data_contract_id,
+                identity,
+                key_id,
+                identity_contract_nonce,
+                user_fee_increase,
+                signer,
+                platform_version,
+                feature_version,
+            ),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown DataContractDeleteTransition version for new_from_data_contract_id {v}"
+            ))),
+        }
+    }
+}
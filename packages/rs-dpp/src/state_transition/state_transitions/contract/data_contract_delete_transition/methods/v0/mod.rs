@@ -0,0 +1,36 @@
+use crate::identity::signer::Signer;
+use crate::identity::{KeyID, PartialIdentity};
+
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use crate::state_transition::StateTransition;
+use crate::version::FeatureVersion;
+use crate::ProtocolError;
+use platform_value::Identifier;
+use platform_version::version::PlatformVersion;
+
+pub trait DataContractDeleteTransitionMethodsV0 {
+    /// Creates a new signed `DataContractDeleteTransition` for the given `data_contract_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_contract_id` - The id of the data contract to delete.
+    /// * `identity` - A reference to the `PartialIdentity` of the contract owner.
+    /// * `key_id` - The `KeyID` (public key identifier) to be used for signing the transition.
+    /// * `signer` - A reference to the `Signer` object that will sign the transition.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<StateTransition, ProtocolError>` - If successful, returns the signed state transition.
+    ///   In case of any error, a relevant `ProtocolError` is returned.
+    #[allow(clippy::too_many_arguments)]
+    fn new_from_data_contract_id<S: Signer>(
+        data_contract_id: Identifier,
+        identity: &PartialIdentity,
+        key_id: KeyID,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+}
@@ -0,0 +1,133 @@
+use crate::state_transition::StateTransitionFieldTypes;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+
+use platform_versioning::PlatformVersioned;
+
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+pub mod accessors;
+mod fields;
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+pub mod methods;
+mod state_transition_like;
+mod v0;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+pub use fields::*;
+
+use crate::identity::state_transition::OptionallyAssetLockProved;
+pub use v0::*;
+
+pub type DataContractDeleteTransitionLatest = DataContractDeleteTransitionV0;
+
+/// Removes a data contract that was created with `can_be_deleted` set in its config.
+///
+/// Documents are never removed along with the contract: the contract must be empty, so its
+/// documents have to be deleted with document delete transitions beforehand. Available from
+/// protocol version 11.
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformDeserialize,
+    PlatformSerialize,
+    PlatformSignable,
+    PlatformVersioned,
+    From,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(tag = "$version")
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+#[platform_version_path_bounds(
+    "dpp.state_transition_serialization_versions.contract_delete_state_transition"
+)]
+pub enum DataContractDeleteTransition {
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "0"))]
+    V0(DataContractDeleteTransitionV0),
+}
+
+impl StateTransitionFieldTypes for DataContractDeleteTransition {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE, SIGNATURE_PUBLIC_KEY_ID]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![DATA_CONTRACT_ID, OWNER_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+}
+
+impl OptionallyAssetLockProved for DataContractDeleteTransition {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+    use crate::state_transition::data_contract_delete_transition::accessors::DataContractDeleteTransitionAccessorsV0;
+    use crate::state_transition::{StateTransitionLike, StateTransitionType};
+    use platform_value::Identifier;
+
+    fn get_test_transition() -> DataContractDeleteTransition {
+        DataContractDeleteTransitionV0 {
+            owner_id: Identifier::random(),
+            data_contract_id: Identifier::random(),
+            identity_contract_nonce: 2,
+            user_fee_increase: 0,
+            signature_public_key_id: 1,
+            signature: [1u8; 65].to_vec().into(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn should_return_transition_type() {
+        let transition = get_test_transition();
+        assert_eq!(
+            StateTransitionType::DataContractDelete,
+            transition.state_transition_type()
+        );
+    }
+
+    #[test]
+    fn should_modify_only_the_deleted_contract() {
+        let transition = get_test_transition();
+        assert_eq!(
+            transition.modified_data_ids(),
+            vec![transition.data_contract_id()]
+        );
+    }
+
+    #[test]
+    fn is_data_contract_state_transition() {
+        let transition = get_test_transition();
+        assert!(transition.is_data_contract_state_transition());
+        assert!(!transition.is_document_state_transition());
+        assert!(!transition.is_identity_state_transition());
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize() {
+        let transition = get_test_transition();
+        let serialized = transition
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+        let deserialized = DataContractDeleteTransition::deserialize_from_bytes(&serialized)
+            .expect("expected to deserialize");
+        assert_eq!(transition, deserialized);
+    }
+}
@@ -0,0 +1,18 @@
+use crate::identity::SecurityLevel::CRITICAL;
+use crate::identity::{KeyID, Purpose, SecurityLevel};
+use crate::state_transition::data_contract_delete_transition::DataContractDeleteTransitionV0;
+use crate::state_transition::StateTransitionIdentitySigned;
+
+impl StateTransitionIdentitySigned for DataContractDeleteTransitionV0 {
+    fn signature_public_key_id(&self) -> KeyID {
+        self.signature_public_key_id
+    }
+
+    fn set_signature_public_key_id(&mut self, key_id: KeyID) {
+        self.signature_public_key_id = key_id
+    }
+
+    fn security_level_requirement(&self, _purpose: Purpose) -> Vec<SecurityLevel> {
+        vec![CRITICAL]
+    }
+}
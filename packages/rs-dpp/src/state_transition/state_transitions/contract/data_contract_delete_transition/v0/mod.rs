@@ -0,0 +1,58 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+pub(super) mod v0_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use platform_value::{BinaryData, Identifier};
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+use bincode::{Decode, Encode};
+use platform_serialization_derive::PlatformSignable;
+
+use crate::identity::KeyID;
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use crate::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use crate::state_transition::StateTransition;
+
+#[derive(Debug, Clone, Encode, Decode, PartialEq, PlatformSignable)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct DataContractDeleteTransitionV0 {
+    /// The owner of the data contract, only they can delete it
+    pub owner_id: Identifier,
+    /// The data contract to delete
+    pub data_contract_id: Identifier,
+    #[cfg_attr(
+        feature = "state-transition-serde-conversion",
+        serde(rename = "$identity-contract-nonce")
+    )]
+    pub identity_contract_nonce: IdentityNonce,
+    pub user_fee_increase: UserFeeIncrease,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
+
+impl From<DataContractDeleteTransitionV0> for StateTransition {
+    fn from(value: DataContractDeleteTransitionV0) -> Self {
+        let transition: DataContractDeleteTransition = value.into();
+        transition.into()
+    }
+}
+
+impl From<&DataContractDeleteTransitionV0> for StateTransition {
+    fn from(value: &DataContractDeleteTransitionV0) -> Self {
+        let transition: DataContractDeleteTransition = value.clone().into();
+        transition.into()
+    }
+}
@@ -0,0 +1,11 @@
+use crate::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for DataContractDeleteTransition {
+    fn feature_version(&self) -> FeatureVersion {
+        match self {
+            DataContractDeleteTransition::V0(v0) => v0.feature_version(),
+        }
+    }
+}
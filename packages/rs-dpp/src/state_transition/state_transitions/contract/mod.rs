@@ -1,4 +1,5 @@
 mod common_fields;
 
 pub mod data_contract_create_transition;
+pub mod data_contract_delete_transition;
 pub mod data_contract_update_transition;
@@ -5,7 +5,7 @@ use derive_more::{Display, From};
 use serde::{Deserialize, Serialize};
 use bincode::{Encode, Decode};
 use crate::prelude::{IdentityNonce, Revision};
-use crate::state_transition::batch_transition::{DocumentCreateTransition, DocumentDeleteTransition, DocumentReplaceTransition, TokenBurnTransition, TokenConfigUpdateTransition, TokenDestroyFrozenFundsTransition, TokenEmergencyActionTransition, TokenFreezeTransition, TokenMintTransition, TokenClaimTransition, TokenTransferTransition, TokenUnfreezeTransition, TokenDirectPurchaseTransition, TokenSetPriceForDirectPurchaseTransition};
+use crate::state_transition::batch_transition::{DocumentCreateTransition, DocumentDeleteTransition, DocumentReplaceTransition, TokenBurnTransition, TokenConfigUpdateTransition, TokenDestroyFrozenFundsTransition, TokenEmergencyActionTransition, TokenFreezeTransition, TokenMintTransition, TokenClaimTransition, TokenTransferTransition, TokenUnfreezeTransition, TokenDirectPurchaseTransition, TokenSetPriceForDirectPurchaseTransition, TokenPlaceOrderTransition, TokenCancelOrderTransition, TokenVestingTransferTransition, TokenClaimVestedTransition, TokenApproveTransition, TokenTransferFromTransition};
 use crate::state_transition::batch_transition::batched_transition::{DocumentPurchaseTransition, DocumentTransferTransition, DocumentUpdatePriceTransition};
 use crate::state_transition::batch_transition::batched_transition::document_purchase_transition::v0::v0_methods::DocumentPurchaseTransitionV0Methods;
 use crate::state_transition::batch_transition::batched_transition::document_transfer_transition::v0::v0_methods::DocumentTransferTransitionV0Methods;
@@ -129,6 +129,30 @@ impl BatchTransitionResolversV0 for DocumentTransition {
     ) -> Option<&TokenSetPriceForDirectPurchaseTransition> {
         None
     }
+
+    fn as_transition_token_place_order(&self) -> Option<&TokenPlaceOrderTransition> {
+        None
+    }
+
+    fn as_transition_token_cancel_order(&self) -> Option<&TokenCancelOrderTransition> {
+        None
+    }
+
+    fn as_transition_token_vesting_transfer(&self) -> Option<&TokenVestingTransferTransition> {
+        None
+    }
+
+    fn as_transition_token_claim_vested(&self) -> Option<&TokenClaimVestedTransition> {
+        None
+    }
+
+    fn as_transition_token_approve(&self) -> Option<&TokenApproveTransition> {
+        None
+    }
+
+    fn as_transition_token_transfer_from(&self) -> Option<&TokenTransferFromTransition> {
+        None
+    }
 }
 
 pub trait DocumentTransitionV0Methods {
@@ -9,6 +9,10 @@ use crate::state_transition::batch_transition::{
     TokenEmergencyActionTransition, TokenFreezeTransition, TokenMintTransition,
     TokenSetPriceForDirectPurchaseTransition, TokenTransferTransition, TokenUnfreezeTransition,
 };
+use crate::state_transition::batch_transition::{
+    TokenApproveTransition, TokenCancelOrderTransition, TokenClaimVestedTransition,
+    TokenPlaceOrderTransition, TokenTransferFromTransition, TokenVestingTransferTransition,
+};
 
 impl BatchTransitionResolversV0 for BatchedTransition {
     fn as_transition_create(&self) -> Option<&DocumentCreateTransition> {
@@ -128,6 +132,48 @@ impl BatchTransitionResolversV0 for BatchedTransition {
             }
         }
     }
+
+    fn as_transition_token_place_order(&self) -> Option<&TokenPlaceOrderTransition> {
+        match self {
+            BatchedTransition::Document(_) => None,
+            BatchedTransition::Token(token) => token.as_transition_token_place_order(),
+        }
+    }
+
+    fn as_transition_token_cancel_order(&self) -> Option<&TokenCancelOrderTransition> {
+        match self {
+            BatchedTransition::Document(_) => None,
+            BatchedTransition::Token(token) => token.as_transition_token_cancel_order(),
+        }
+    }
+
+    fn as_transition_token_vesting_transfer(&self) -> Option<&TokenVestingTransferTransition> {
+        match self {
+            BatchedTransition::Document(_) => None,
+            BatchedTransition::Token(token) => token.as_transition_token_vesting_transfer(),
+        }
+    }
+
+    fn as_transition_token_claim_vested(&self) -> Option<&TokenClaimVestedTransition> {
+        match self {
+            BatchedTransition::Document(_) => None,
+            BatchedTransition::Token(token) => token.as_transition_token_claim_vested(),
+        }
+    }
+
+    fn as_transition_token_approve(&self) -> Option<&TokenApproveTransition> {
+        match self {
+            BatchedTransition::Document(_) => None,
+            BatchedTransition::Token(token) => token.as_transition_token_approve(),
+        }
+    }
+
+    fn as_transition_token_transfer_from(&self) -> Option<&TokenTransferFromTransition> {
+        match self {
+            BatchedTransition::Document(_) => None,
+            BatchedTransition::Token(token) => token.as_transition_token_transfer_from(),
+        }
+    }
 }
 
 impl BatchTransitionResolversV0 for BatchedTransitionRef<'_> {
@@ -248,4 +294,46 @@ impl BatchTransitionResolversV0 for BatchedTransitionRef<'_> {
             }
         }
     }
+
+    fn as_transition_token_place_order(&self) -> Option<&TokenPlaceOrderTransition> {
+        match self {
+            BatchedTransitionRef::Document(_) => None,
+            BatchedTransitionRef::Token(token) => token.as_transition_token_place_order(),
+        }
+    }
+
+    fn as_transition_token_cancel_order(&self) -> Option<&TokenCancelOrderTransition> {
+        match self {
+            BatchedTransitionRef::Document(_) => None,
+            BatchedTransitionRef::Token(token) => token.as_transition_token_cancel_order(),
+        }
+    }
+
+    fn as_transition_token_vesting_transfer(&self) -> Option<&TokenVestingTransferTransition> {
+        match self {
+            BatchedTransitionRef::Document(_) => None,
+            BatchedTransitionRef::Token(token) => token.as_transition_token_vesting_transfer(),
+        }
+    }
+
+    fn as_transition_token_claim_vested(&self) -> Option<&TokenClaimVestedTransition> {
+        match self {
+            BatchedTransitionRef::Document(_) => None,
+            BatchedTransitionRef::Token(token) => token.as_transition_token_claim_vested(),
+        }
+    }
+
+    fn as_transition_token_approve(&self) -> Option<&TokenApproveTransition> {
+        match self {
+            BatchedTransitionRef::Document(_) => None,
+            BatchedTransitionRef::Token(token) => token.as_transition_token_approve(),
+        }
+    }
+
+    fn as_transition_token_transfer_from(&self) -> Option<&TokenTransferFromTransition> {
+        match self {
+            BatchedTransitionRef::Document(_) => None,
+            BatchedTransitionRef::Token(token) => token.as_transition_token_transfer_from(),
+        }
+    }
 }
@@ -0,0 +1,34 @@
+pub mod v0;
+mod v0_methods;
+pub mod validate_structure;
+
+use bincode::{Decode, Encode};
+use derive_more::{Display, From};
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+pub use v0::TokenApproveTransitionV0;
+
+/// Represents a versioned transition for approving a spender to transfer tokens on behalf
+/// of their owner.
+///
+/// The approval replaces any allowance the owner already gave the spender, approving an
+/// amount of 0 revokes it.
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Display, From)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize)
+)]
+pub enum TokenApproveTransition {
+    /// Version 0 of the token approve transition.
+    ///
+    /// This version includes the base token transition, the spender, the amount it
+    /// can transfer, an optional expiry and an optional public note.
+    #[display("V0({})", "_0")]
+    V0(TokenApproveTransitionV0),
+}
+
+impl Default for TokenApproveTransition {
+    fn default() -> Self {
+        TokenApproveTransition::V0(TokenApproveTransitionV0::default()) // since only v0
+    }
+}
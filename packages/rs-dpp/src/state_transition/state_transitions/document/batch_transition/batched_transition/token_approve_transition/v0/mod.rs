@@ -0,0 +1,56 @@
+pub mod v0_methods;
+
+use crate::balances::credits::TokenAmount;
+use crate::prelude::TimestampMillis;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+use bincode::{Decode, Encode};
+use platform_value::Identifier;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The Identifier fields in [`TokenApproveTransition`]
+pub use super::super::document_base_transition::IDENTIFIER_FIELDS;
+
+#[derive(Debug, Clone, Encode, Decode, PartialEq)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TokenApproveTransitionV0 {
+    /// Document Base Transition
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(flatten))]
+    pub base: TokenBaseTransition,
+    /// Who will be able to transfer tokens on behalf of the owner.
+    pub spender: TokenAllowanceSpender,
+    /// How many tokens the spender can transfer, 0 revokes the allowance.
+    pub amount: TokenAmount,
+    /// When the allowance stops being usable, if ever.
+    pub expires_at: Option<TimestampMillis>,
+    /// The public note
+    pub public_note: Option<String>,
+}
+
+impl Default for TokenApproveTransitionV0 {
+    fn default() -> Self {
+        TokenApproveTransitionV0 {
+            base: TokenBaseTransition::default(),
+            spender: TokenAllowanceSpender::Identity(Identifier::default()),
+            amount: 0,
+            expires_at: None,
+            public_note: None,
+        }
+    }
+}
+
+impl fmt::Display for TokenApproveTransitionV0 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Token Approve, base: {}, spender: {}, amount: {}, expires_at: {:?}",
+            self.base, self.spender, self.amount, self.expires_at
+        )
+    }
+}
@@ -0,0 +1,81 @@
+use crate::balances::credits::TokenAmount;
+use crate::prelude::TimestampMillis;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::state_transition::batch_transition::token_approve_transition::TokenApproveTransitionV0;
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+
+impl TokenBaseTransitionAccessors for TokenApproveTransitionV0 {
+    fn base(&self) -> &TokenBaseTransition {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut TokenBaseTransition {
+        &mut self.base
+    }
+
+    fn set_base(&mut self, base: TokenBaseTransition) {
+        self.base = base;
+    }
+}
+
+pub trait TokenApproveTransitionV0Methods: TokenBaseTransitionAccessors {
+    fn spender(&self) -> TokenAllowanceSpender;
+
+    fn set_spender(&mut self, spender: TokenAllowanceSpender);
+
+    fn amount(&self) -> TokenAmount;
+
+    fn set_amount(&mut self, amount: TokenAmount);
+
+    fn expires_at(&self) -> Option<TimestampMillis>;
+
+    fn set_expires_at(&mut self, expires_at: Option<TimestampMillis>);
+
+    /// Returns the `public_note` field of the `TokenApproveTransitionV0`.
+    fn public_note(&self) -> Option<&String>;
+
+    /// Returns the owned `public_note` field of the `TokenApproveTransitionV0`.
+    fn public_note_owned(self) -> Option<String>;
+
+    /// Sets the value of the `public_note` field in the `TokenApproveTransitionV0`.
+    fn set_public_note(&mut self, public_note: Option<String>);
+}
+
+impl TokenApproveTransitionV0Methods for TokenApproveTransitionV0 {
+    fn spender(&self) -> TokenAllowanceSpender {
+        self.spender
+    }
+
+    fn set_spender(&mut self, spender: TokenAllowanceSpender) {
+        self.spender = spender;
+    }
+
+    fn amount(&self) -> TokenAmount {
+        self.amount
+    }
+
+    fn set_amount(&mut self, amount: TokenAmount) {
+        self.amount = amount;
+    }
+
+    fn expires_at(&self) -> Option<TimestampMillis> {
+        self.expires_at
+    }
+
+    fn set_expires_at(&mut self, expires_at: Option<TimestampMillis>) {
+        self.expires_at = expires_at;
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        self.public_note.as_ref()
+    }
+
+    fn public_note_owned(self) -> Option<String> {
+        self.public_note
+    }
+
+    fn set_public_note(&mut self, public_note: Option<String>) {
+        self.public_note = public_note;
+    }
+}
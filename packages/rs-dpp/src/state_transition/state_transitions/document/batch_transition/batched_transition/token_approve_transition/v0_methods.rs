@@ -0,0 +1,83 @@
+use crate::balances::credits::TokenAmount;
+use crate::prelude::TimestampMillis;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::state_transition::batch_transition::token_approve_transition::v0::v0_methods::TokenApproveTransitionV0Methods;
+use crate::state_transition::batch_transition::TokenApproveTransition;
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+
+impl TokenBaseTransitionAccessors for TokenApproveTransition {
+    fn base(&self) -> &TokenBaseTransition {
+        match self {
+            TokenApproveTransition::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_mut(&mut self) -> &mut TokenBaseTransition {
+        match self {
+            TokenApproveTransition::V0(v0) => &mut v0.base,
+        }
+    }
+
+    fn set_base(&mut self, base: TokenBaseTransition) {
+        match self {
+            TokenApproveTransition::V0(v0) => v0.base = base,
+        }
+    }
+}
+
+impl TokenApproveTransitionV0Methods for TokenApproveTransition {
+    fn spender(&self) -> TokenAllowanceSpender {
+        match self {
+            TokenApproveTransition::V0(v0) => v0.spender(),
+        }
+    }
+
+    fn set_spender(&mut self, spender: TokenAllowanceSpender) {
+        match self {
+            TokenApproveTransition::V0(v0) => v0.set_spender(spender),
+        }
+    }
+
+    fn amount(&self) -> TokenAmount {
+        match self {
+            TokenApproveTransition::V0(v0) => v0.amount(),
+        }
+    }
+
+    fn set_amount(&mut self, amount: TokenAmount) {
+        match self {
+            TokenApproveTransition::V0(v0) => v0.set_amount(amount),
+        }
+    }
+
+    fn expires_at(&self) -> Option<TimestampMillis> {
+        match self {
+            TokenApproveTransition::V0(v0) => v0.expires_at(),
+        }
+    }
+
+    fn set_expires_at(&mut self, expires_at: Option<TimestampMillis>) {
+        match self {
+            TokenApproveTransition::V0(v0) => v0.set_expires_at(expires_at),
+        }
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        match self {
+            TokenApproveTransition::V0(v0) => v0.public_note(),
+        }
+    }
+
+    fn public_note_owned(self) -> Option<String> {
+        match self {
+            TokenApproveTransition::V0(v0) => v0.public_note_owned(),
+        }
+    }
+
+    fn set_public_note(&mut self, public_note: Option<String>) {
+        match self {
+            TokenApproveTransition::V0(v0) => v0.set_public_note(public_note),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::consensus::basic::token::{
+    InvalidTokenAmountError, InvalidTokenNoteTooBigError, TokenApproveToOurselfError,
+};
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::data_contract::associated_token::token_perpetual_distribution::distribution_function::MAX_DISTRIBUTION_PARAM;
+use crate::state_transition::batch_transition::token_approve_transition::v0::v0_methods::TokenApproveTransitionV0Methods;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::v0::v0_methods::TokenBaseTransitionV0Methods;
+use crate::state_transition::batch_transition::TokenApproveTransition;
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+use crate::tokens::MAX_TOKEN_NOTE_LEN;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use platform_value::Identifier;
+
+pub(super) trait TokenApproveTransitionActionStructureValidationV0 {
+    fn validate_structure_v0(
+        &self,
+        owner_id: Identifier,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError>;
+}
+impl TokenApproveTransitionActionStructureValidationV0 for TokenApproveTransition {
+    fn validate_structure_v0(
+        &self,
+        owner_id: Identifier,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError> {
+        // An amount of 0 is allowed, it revokes the allowance
+        if self.amount() > MAX_DISTRIBUTION_PARAM {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::InvalidTokenAmountError(
+                    InvalidTokenAmountError::new(MAX_DISTRIBUTION_PARAM, self.amount()),
+                )),
+            ));
+        }
+
+        if self.spender() == TokenAllowanceSpender::Identity(owner_id) {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::TokenApproveToOurselfError(
+                    TokenApproveToOurselfError::new(self.base().token_id(), owner_id),
+                )),
+            ));
+        }
+
+        if let Some(public_note) = self.public_note() {
+            if public_note.len() > MAX_TOKEN_NOTE_LEN {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    ConsensusError::BasicError(BasicError::InvalidTokenNoteTooBigError(
+                        InvalidTokenNoteTooBigError::new(
+                            MAX_TOKEN_NOTE_LEN as u32,
+                            "public_note",
+                            public_note.len() as u32,
+                        ),
+                    )),
+                ));
+            }
+        }
+
+        Ok(SimpleConsensusValidationResult::default())
+    }
+}
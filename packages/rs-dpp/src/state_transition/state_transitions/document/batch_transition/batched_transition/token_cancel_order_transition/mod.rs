@@ -0,0 +1,31 @@
+pub mod v0;
+mod v0_methods;
+pub mod validate_structure;
+
+use bincode::{Decode, Encode};
+use derive_more::{Display, From};
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+pub use v0::TokenCancelOrderTransitionV0;
+
+/// Represents a versioned transition for cancelling a resting limit order on the token
+/// order book.
+///
+/// Only the owner of an order can cancel it. The unfilled part of the order is removed
+/// from the book and its escrow is returned to the owner.
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Display, From)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize)
+)]
+pub enum TokenCancelOrderTransition {
+    /// Version 0 of the token cancel order transition.
+    #[display("V0({})", "_0")]
+    V0(TokenCancelOrderTransitionV0),
+}
+
+impl Default for TokenCancelOrderTransition {
+    fn default() -> Self {
+        TokenCancelOrderTransition::V0(TokenCancelOrderTransitionV0::default()) // since only v0
+    }
+}
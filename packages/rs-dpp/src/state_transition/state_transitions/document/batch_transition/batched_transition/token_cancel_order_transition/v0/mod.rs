@@ -0,0 +1,35 @@
+pub mod v0_methods;
+
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use bincode::{Decode, Encode};
+use platform_value::Identifier;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The Identifier fields in [`TokenCancelOrderTransition`]
+pub use super::super::document_base_transition::IDENTIFIER_FIELDS;
+
+#[derive(Debug, Clone, Default, Encode, Decode, PartialEq)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TokenCancelOrderTransitionV0 {
+    /// Document Base Transition
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(flatten))]
+    pub base: TokenBaseTransition,
+    /// The id of the order to cancel.
+    pub order_id: Identifier,
+}
+
+impl fmt::Display for TokenCancelOrderTransitionV0 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Token CancelOrder, base: {}, order id: {}",
+            self.base, self.order_id
+        )
+    }
+}
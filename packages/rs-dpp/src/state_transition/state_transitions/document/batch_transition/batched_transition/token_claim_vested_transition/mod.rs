@@ -0,0 +1,30 @@
+pub mod v0;
+mod v0_methods;
+pub mod validate_structure;
+
+use bincode::{Decode, Encode};
+use derive_more::{Display, From};
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+pub use v0::TokenClaimVestedTransitionV0;
+
+/// Represents a versioned transition for claiming vested tokens.
+///
+/// Only the recipient of a vesting can claim from it. Everything that has vested and
+/// has not been claimed yet is moved from escrow to the recipient's balance.
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Display, From)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize)
+)]
+pub enum TokenClaimVestedTransition {
+    /// Version 0 of the token claim vested transition.
+    #[display("V0({})", "_0")]
+    V0(TokenClaimVestedTransitionV0),
+}
+
+impl Default for TokenClaimVestedTransition {
+    fn default() -> Self {
+        TokenClaimVestedTransition::V0(TokenClaimVestedTransitionV0::default()) // since only v0
+    }
+}
@@ -0,0 +1,35 @@
+pub mod v0_methods;
+
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use bincode::{Decode, Encode};
+use platform_value::Identifier;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The Identifier fields in [`TokenClaimVestedTransition`]
+pub use super::super::document_base_transition::IDENTIFIER_FIELDS;
+
+#[derive(Debug, Clone, Default, Encode, Decode, PartialEq)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TokenClaimVestedTransitionV0 {
+    /// Document Base Transition
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(flatten))]
+    pub base: TokenBaseTransition,
+    /// The id of the vesting to claim from.
+    pub vesting_id: Identifier,
+}
+
+impl fmt::Display for TokenClaimVestedTransitionV0 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Token ClaimVested, base: {}, vesting id: {}",
+            self.base, self.vesting_id
+        )
+    }
+}
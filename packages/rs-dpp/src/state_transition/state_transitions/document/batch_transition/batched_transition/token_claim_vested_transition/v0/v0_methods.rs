@@ -0,0 +1,34 @@
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::state_transition::batch_transition::token_claim_vested_transition::TokenClaimVestedTransitionV0;
+use platform_value::Identifier;
+
+impl TokenBaseTransitionAccessors for TokenClaimVestedTransitionV0 {
+    fn base(&self) -> &TokenBaseTransition {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut TokenBaseTransition {
+        &mut self.base
+    }
+
+    fn set_base(&mut self, base: TokenBaseTransition) {
+        self.base = base;
+    }
+}
+
+pub trait TokenClaimVestedTransitionV0Methods: TokenBaseTransitionAccessors {
+    fn vesting_id(&self) -> Identifier;
+
+    fn set_vesting_id(&mut self, vesting_id: Identifier);
+}
+
+impl TokenClaimVestedTransitionV0Methods for TokenClaimVestedTransitionV0 {
+    fn vesting_id(&self) -> Identifier {
+        self.vesting_id
+    }
+
+    fn set_vesting_id(&mut self, vesting_id: Identifier) {
+        self.vesting_id = vesting_id;
+    }
+}
@@ -0,0 +1,39 @@
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::state_transition::batch_transition::token_claim_vested_transition::v0::v0_methods::TokenClaimVestedTransitionV0Methods;
+use crate::state_transition::batch_transition::TokenClaimVestedTransition;
+use platform_value::Identifier;
+
+impl TokenBaseTransitionAccessors for TokenClaimVestedTransition {
+    fn base(&self) -> &TokenBaseTransition {
+        match self {
+            TokenClaimVestedTransition::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_mut(&mut self) -> &mut TokenBaseTransition {
+        match self {
+            TokenClaimVestedTransition::V0(v0) => &mut v0.base,
+        }
+    }
+
+    fn set_base(&mut self, base: TokenBaseTransition) {
+        match self {
+            TokenClaimVestedTransition::V0(v0) => v0.base = base,
+        }
+    }
+}
+
+impl TokenClaimVestedTransitionV0Methods for TokenClaimVestedTransition {
+    fn vesting_id(&self) -> Identifier {
+        match self {
+            TokenClaimVestedTransition::V0(v0) => v0.vesting_id(),
+        }
+    }
+
+    fn set_vesting_id(&mut self, vesting_id: Identifier) {
+        match self {
+            TokenClaimVestedTransition::V0(v0) => v0.set_vesting_id(vesting_id),
+        }
+    }
+}
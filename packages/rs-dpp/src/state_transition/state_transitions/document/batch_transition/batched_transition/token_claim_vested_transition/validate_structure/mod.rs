@@ -0,0 +1,45 @@
+use crate::consensus::basic::{BasicError, UnsupportedFeatureError};
+use crate::consensus::ConsensusError;
+use crate::state_transition::batch_transition::TokenClaimVestedTransition;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use platform_version::version::PlatformVersion;
+
+pub trait TokenClaimVestedTransitionStructureValidation {
+    fn validate_structure(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError>;
+}
+
+impl TokenClaimVestedTransitionStructureValidation for TokenClaimVestedTransition {
+    fn validate_structure(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .batch_state_transition
+            .token_claim_vested_transition_structure_validation
+        {
+            // A claim vested transition only references a vesting by its id, there is
+            // nothing more to validate without state
+            Some(0) => Ok(SimpleConsensusValidationResult::default()),
+            Some(version) => Err(ProtocolError::UnknownVersionMismatch {
+                method: "TokenClaimVestedTransition::validate_structure".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+            None => Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::UnsupportedFeatureError(
+                    UnsupportedFeatureError::new(
+                        "token vesting".to_string(),
+                        platform_version.protocol_version,
+                    ),
+                )),
+            )),
+        }
+    }
+}
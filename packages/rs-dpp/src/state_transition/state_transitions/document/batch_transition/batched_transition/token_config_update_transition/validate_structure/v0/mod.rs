@@ -47,6 +47,15 @@ impl TokenConfigUpdateTransitionStructureValidationV0 for TokenConfigUpdateTrans
             ));
         }
 
+        if let TokenConfigurationChangeItem::MarketplaceTradeMode(trade_mode) =
+            self.update_token_configuration_item()
+        {
+            let validation_result = trade_mode.validate_supported(platform_version);
+            if !validation_result.is_valid() {
+                return Ok(validation_result);
+            }
+        }
+
         if let Some(public_note) = self.public_note() {
             if public_note.len() > MAX_TOKEN_NOTE_LEN {
                 return Ok(SimpleConsensusValidationResult::new_with_error(
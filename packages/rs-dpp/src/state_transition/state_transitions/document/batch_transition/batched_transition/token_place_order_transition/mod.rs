@@ -0,0 +1,37 @@
+pub mod v0;
+mod v0_methods;
+pub mod validate_structure;
+
+use bincode::{Decode, Encode};
+use derive_more::{Display, From};
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+pub use v0::TokenPlaceOrderTransitionV0;
+
+/// Represents a versioned transition for placing a limit order on the token order book.
+///
+/// The order is matched against resting orders on the opposite side of the book at the
+/// time it is placed. Whatever is not filled rests on the book until it is matched or
+/// cancelled by its owner.
+///
+/// Placed orders and their fills are not recorded in the token history contract, whatever
+/// the history settings of the token are.
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Display, From)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize)
+)]
+pub enum TokenPlaceOrderTransition {
+    /// Version 0 of the token place order transition.
+    ///
+    /// This version includes the base token transition, the side of the order,
+    /// the currency it is quoted in, the amount of tokens and the limit price per token.
+    #[display("V0({})", "_0")]
+    V0(TokenPlaceOrderTransitionV0),
+}
+
+impl Default for TokenPlaceOrderTransition {
+    fn default() -> Self {
+        TokenPlaceOrderTransition::V0(TokenPlaceOrderTransitionV0::default()) // since only v0
+    }
+}
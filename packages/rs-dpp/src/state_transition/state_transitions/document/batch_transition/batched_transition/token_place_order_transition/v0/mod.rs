@@ -0,0 +1,44 @@
+pub mod v0_methods;
+
+use crate::balances::credits::TokenAmount;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::tokens::allowed_currency::AllowedCurrency;
+use crate::tokens::order_book::TokenOrderSide;
+use bincode::{Decode, Encode};
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The Identifier fields in [`TokenPlaceOrderTransition`]
+pub use super::super::document_base_transition::IDENTIFIER_FIELDS;
+
+#[derive(Debug, Clone, Default, Encode, Decode, PartialEq)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TokenPlaceOrderTransitionV0 {
+    /// Document Base Transition
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(flatten))]
+    pub base: TokenBaseTransition,
+    /// Whether we buy or sell the token.
+    pub side: TokenOrderSide,
+    /// The currency the token is priced in.
+    pub quote_currency: AllowedCurrency,
+    /// How many tokens should we buy or sell.
+    pub amount: TokenAmount,
+    /// The limit price of a single token in the smallest unit of the quote currency.
+    /// Buy orders never pay more and sell orders never receive less than this price.
+    pub price_per_token: u64,
+}
+
+impl fmt::Display for TokenPlaceOrderTransitionV0 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Token PlaceOrder, base: {}, side: {}, amount: {}, price per token: {} in {}",
+            self.base, self.side, self.amount, self.price_per_token, self.quote_currency
+        )
+    }
+}
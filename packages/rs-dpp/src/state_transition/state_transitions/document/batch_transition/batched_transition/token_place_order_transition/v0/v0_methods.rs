@@ -0,0 +1,72 @@
+use crate::balances::credits::TokenAmount;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::state_transition::batch_transition::token_place_order_transition::TokenPlaceOrderTransitionV0;
+use crate::tokens::allowed_currency::AllowedCurrency;
+use crate::tokens::order_book::TokenOrderSide;
+
+impl TokenBaseTransitionAccessors for TokenPlaceOrderTransitionV0 {
+    fn base(&self) -> &TokenBaseTransition {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut TokenBaseTransition {
+        &mut self.base
+    }
+
+    fn set_base(&mut self, base: TokenBaseTransition) {
+        self.base = base;
+    }
+}
+
+pub trait TokenPlaceOrderTransitionV0Methods: TokenBaseTransitionAccessors {
+    fn side(&self) -> TokenOrderSide;
+
+    fn set_side(&mut self, side: TokenOrderSide);
+
+    fn quote_currency(&self) -> AllowedCurrency;
+
+    fn set_quote_currency(&mut self, quote_currency: AllowedCurrency);
+
+    fn amount(&self) -> TokenAmount;
+
+    fn set_amount(&mut self, amount: TokenAmount);
+
+    fn price_per_token(&self) -> u64;
+
+    fn set_price_per_token(&mut self, price_per_token: u64);
+}
+
+impl TokenPlaceOrderTransitionV0Methods for TokenPlaceOrderTransitionV0 {
+    fn side(&self) -> TokenOrderSide {
+        self.side
+    }
+
+    fn set_side(&mut self, side: TokenOrderSide) {
+        self.side = side;
+    }
+
+    fn quote_currency(&self) -> AllowedCurrency {
+        self.quote_currency
+    }
+
+    fn set_quote_currency(&mut self, quote_currency: AllowedCurrency) {
+        self.quote_currency = quote_currency;
+    }
+
+    fn amount(&self) -> TokenAmount {
+        self.amount
+    }
+
+    fn set_amount(&mut self, amount: TokenAmount) {
+        self.amount = amount;
+    }
+
+    fn price_per_token(&self) -> u64 {
+        self.price_per_token
+    }
+
+    fn set_price_per_token(&mut self, price_per_token: u64) {
+        self.price_per_token = price_per_token;
+    }
+}
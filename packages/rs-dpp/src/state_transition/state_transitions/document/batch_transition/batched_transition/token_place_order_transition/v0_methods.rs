@@ -0,0 +1,77 @@
+use crate::balances::credits::TokenAmount;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::state_transition::batch_transition::token_place_order_transition::v0::v0_methods::TokenPlaceOrderTransitionV0Methods;
+use crate::state_transition::batch_transition::TokenPlaceOrderTransition;
+use crate::tokens::allowed_currency::AllowedCurrency;
+use crate::tokens::order_book::TokenOrderSide;
+
+impl TokenBaseTransitionAccessors for TokenPlaceOrderTransition {
+    fn base(&self) -> &TokenBaseTransition {
+        match self {
+            TokenPlaceOrderTransition::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_mut(&mut self) -> &mut TokenBaseTransition {
+        match self {
+            TokenPlaceOrderTransition::V0(v0) => &mut v0.base,
+        }
+    }
+
+    fn set_base(&mut self, base: TokenBaseTransition) {
+        match self {
+            TokenPlaceOrderTransition::V0(v0) => v0.base = base,
+        }
+    }
+}
+
+impl TokenPlaceOrderTransitionV0Methods for TokenPlaceOrderTransition {
+    fn side(&self) -> TokenOrderSide {
+        match self {
+            TokenPlaceOrderTransition::V0(v0) => v0.side(),
+        }
+    }
+
+    fn set_side(&mut self, side: TokenOrderSide) {
+        match self {
+            TokenPlaceOrderTransition::V0(v0) => v0.set_side(side),
+        }
+    }
+
+    fn quote_currency(&self) -> AllowedCurrency {
+        match self {
+            TokenPlaceOrderTransition::V0(v0) => v0.quote_currency(),
+        }
+    }
+
+    fn set_quote_currency(&mut self, quote_currency: AllowedCurrency) {
+        match self {
+            TokenPlaceOrderTransition::V0(v0) => v0.set_quote_currency(quote_currency),
+        }
+    }
+
+    fn amount(&self) -> TokenAmount {
+        match self {
+            TokenPlaceOrderTransition::V0(v0) => v0.amount(),
+        }
+    }
+
+    fn set_amount(&mut self, amount: TokenAmount) {
+        match self {
+            TokenPlaceOrderTransition::V0(v0) => v0.set_amount(amount),
+        }
+    }
+
+    fn price_per_token(&self) -> u64 {
+        match self {
+            TokenPlaceOrderTransition::V0(v0) => v0.price_per_token(),
+        }
+    }
+
+    fn set_price_per_token(&mut self, price_per_token: u64) {
+        match self {
+            TokenPlaceOrderTransition::V0(v0) => v0.set_price_per_token(price_per_token),
+        }
+    }
+}
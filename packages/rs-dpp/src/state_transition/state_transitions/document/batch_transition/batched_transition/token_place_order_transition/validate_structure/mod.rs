@@ -0,0 +1,46 @@
+use crate::consensus::basic::{BasicError, UnsupportedFeatureError};
+use crate::consensus::ConsensusError;
+use crate::state_transition::batch_transition::token_place_order_transition::validate_structure::v0::TokenPlaceOrderTransitionActionStructureValidationV0;
+use crate::state_transition::batch_transition::TokenPlaceOrderTransition;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use platform_version::version::PlatformVersion;
+
+mod v0;
+
+pub trait TokenPlaceOrderTransitionStructureValidation {
+    fn validate_structure(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError>;
+}
+
+impl TokenPlaceOrderTransitionStructureValidation for TokenPlaceOrderTransition {
+    fn validate_structure(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .batch_state_transition
+            .token_place_order_transition_structure_validation
+        {
+            Some(0) => self.validate_structure_v0(),
+            Some(version) => Err(ProtocolError::UnknownVersionMismatch {
+                method: "TokenPlaceOrderTransition::validate_structure".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+            None => Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::UnsupportedFeatureError(
+                    UnsupportedFeatureError::new(
+                        "token order book".to_string(),
+                        platform_version.protocol_version,
+                    ),
+                )),
+            )),
+        }
+    }
+}
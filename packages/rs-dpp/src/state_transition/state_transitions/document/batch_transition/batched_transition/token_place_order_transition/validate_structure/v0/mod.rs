@@ -0,0 +1,58 @@
+use crate::consensus::basic::token::{
+    InvalidTokenAmountError, InvalidTokenOrderPriceError, TokenOrderQuotedInSameTokenError,
+};
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::data_contract::associated_token::token_perpetual_distribution::distribution_function::MAX_DISTRIBUTION_PARAM;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::v0::v0_methods::TokenBaseTransitionV0Methods;
+use crate::state_transition::batch_transition::token_place_order_transition::v0::v0_methods::TokenPlaceOrderTransitionV0Methods;
+use crate::state_transition::batch_transition::TokenPlaceOrderTransition;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+
+pub(super) trait TokenPlaceOrderTransitionActionStructureValidationV0 {
+    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, ProtocolError>;
+}
+impl TokenPlaceOrderTransitionActionStructureValidationV0 for TokenPlaceOrderTransition {
+    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, ProtocolError> {
+        if self.amount() > MAX_DISTRIBUTION_PARAM || self.amount() == 0 {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::InvalidTokenAmountError(
+                    InvalidTokenAmountError::new(MAX_DISTRIBUTION_PARAM, self.amount()),
+                )),
+            ));
+        }
+
+        // The escrowed value of a buy order and the proceeds of a sell order must both
+        // fit in a signed balance.
+        let max_order_value = i64::MAX as u64;
+        let order_value_is_valid = self
+            .amount()
+            .checked_mul(self.price_per_token())
+            .is_some_and(|order_value| order_value <= max_order_value);
+
+        if self.price_per_token() == 0 || !order_value_is_valid {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::InvalidTokenOrderPriceError(
+                    InvalidTokenOrderPriceError::new(
+                        self.base().token_id(),
+                        self.price_per_token(),
+                        self.amount(),
+                        max_order_value,
+                    ),
+                )),
+            ));
+        }
+
+        if self.quote_currency().token_id() == Some(self.base().token_id()) {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::TokenOrderQuotedInSameTokenError(
+                    TokenOrderQuotedInSameTokenError::new(self.base().token_id()),
+                )),
+            ));
+        }
+
+        Ok(SimpleConsensusValidationResult::default())
+    }
+}
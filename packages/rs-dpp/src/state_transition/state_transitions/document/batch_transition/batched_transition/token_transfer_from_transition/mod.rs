@@ -0,0 +1,35 @@
+pub mod v0;
+mod v0_methods;
+pub mod validate_structure;
+
+use bincode::{Decode, Encode};
+use derive_more::{Display, From};
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+pub use v0::TokenTransferFromTransitionV0;
+
+/// Represents a versioned transition for a spender transferring tokens on behalf of their
+/// owner, within the allowance the owner approved.
+///
+/// The spender is the identity sending the transition, or the group the transition is a
+/// group action of.
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Display, From)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize)
+)]
+pub enum TokenTransferFromTransition {
+    /// Version 0 of the token transfer from transition.
+    ///
+    /// This version includes the base token transition, the owner of the tokens, the
+    /// recipient, the amount to transfer and an optional public note.
+    #[display("V0({})", "_0")]
+    V0(TokenTransferFromTransitionV0),
+}
+
+impl Default for TokenTransferFromTransition {
+    fn default() -> Self {
+        TokenTransferFromTransition::V0(TokenTransferFromTransitionV0::default())
+        // since only v0
+    }
+}
@@ -0,0 +1,42 @@
+pub mod v0_methods;
+
+use crate::balances::credits::TokenAmount;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use bincode::{Decode, Encode};
+use platform_value::Identifier;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The Identifier fields in [`TokenTransferFromTransition`]
+pub use super::super::document_base_transition::IDENTIFIER_FIELDS;
+
+#[derive(Debug, Clone, Default, Encode, Decode, PartialEq)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TokenTransferFromTransitionV0 {
+    /// Document Base Transition
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(flatten))]
+    pub base: TokenBaseTransition,
+    /// The identity holding the tokens, that approved the spender.
+    pub from_identity_id: Identifier,
+    /// The identity receiving the tokens.
+    pub recipient_id: Identifier,
+    /// How many tokens should be transferred.
+    pub amount: TokenAmount,
+    /// The public note
+    pub public_note: Option<String>,
+}
+
+impl fmt::Display for TokenTransferFromTransitionV0 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Token TransferFrom, base: {}, from: {}, recipient: {}, amount: {}",
+            self.base, self.from_identity_id, self.recipient_id, self.amount
+        )
+    }
+}
@@ -0,0 +1,104 @@
+use platform_value::Identifier;
+use crate::balances::credits::TokenAmount;
+use crate::state_transition::batch_transition::batched_transition::multi_party_action::AllowedAsMultiPartyAction;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::state_transition::batch_transition::token_base_transition::v0::v0_methods::TokenBaseTransitionV0Methods;
+use crate::state_transition::batch_transition::token_transfer_from_transition::TokenTransferFromTransitionV0;
+use crate::state_transition::batch_transition::TokenTransferFromTransition;
+
+impl TokenBaseTransitionAccessors for TokenTransferFromTransitionV0 {
+    fn base(&self) -> &TokenBaseTransition {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut TokenBaseTransition {
+        &mut self.base
+    }
+
+    fn set_base(&mut self, base: TokenBaseTransition) {
+        self.base = base;
+    }
+}
+
+pub trait TokenTransferFromTransitionV0Methods: TokenBaseTransitionAccessors {
+    fn from_identity_id(&self) -> Identifier;
+
+    fn set_from_identity_id(&mut self, from_identity_id: Identifier);
+
+    fn recipient_id(&self) -> Identifier;
+
+    fn set_recipient_id(&mut self, recipient_id: Identifier);
+
+    fn amount(&self) -> TokenAmount;
+
+    fn set_amount(&mut self, amount: TokenAmount);
+
+    /// Returns the `public_note` field of the `TokenTransferFromTransitionV0`.
+    fn public_note(&self) -> Option<&String>;
+
+    /// Returns the owned `public_note` field of the `TokenTransferFromTransitionV0`.
+    fn public_note_owned(self) -> Option<String>;
+
+    /// Sets the value of the `public_note` field in the `TokenTransferFromTransitionV0`.
+    fn set_public_note(&mut self, public_note: Option<String>);
+}
+
+impl TokenTransferFromTransitionV0Methods for TokenTransferFromTransitionV0 {
+    fn from_identity_id(&self) -> Identifier {
+        self.from_identity_id
+    }
+
+    fn set_from_identity_id(&mut self, from_identity_id: Identifier) {
+        self.from_identity_id = from_identity_id;
+    }
+
+    fn recipient_id(&self) -> Identifier {
+        self.recipient_id
+    }
+
+    fn set_recipient_id(&mut self, recipient_id: Identifier) {
+        self.recipient_id = recipient_id;
+    }
+
+    fn amount(&self) -> TokenAmount {
+        self.amount
+    }
+
+    fn set_amount(&mut self, amount: TokenAmount) {
+        self.amount = amount;
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        self.public_note.as_ref()
+    }
+
+    fn public_note_owned(self) -> Option<String> {
+        self.public_note
+    }
+
+    fn set_public_note(&mut self, public_note: Option<String>) {
+        self.public_note = public_note;
+    }
+}
+
+impl AllowedAsMultiPartyAction for TokenTransferFromTransitionV0 {
+    fn calculate_action_id(&self, owner_id: Identifier) -> Identifier {
+        let TokenTransferFromTransitionV0 {
+            base,
+            from_identity_id,
+            recipient_id,
+            amount,
+            ..
+        } = self;
+
+        TokenTransferFromTransition::calculate_action_id_with_fields(
+            base.token_id().as_bytes(),
+            owner_id.as_bytes(),
+            base.identity_contract_nonce(),
+            from_identity_id.as_bytes(),
+            recipient_id.as_bytes(),
+            *amount,
+        )
+    }
+}
@@ -0,0 +1,114 @@
+use platform_value::Identifier;
+use crate::balances::credits::TokenAmount;
+use crate::prelude::IdentityNonce;
+use crate::state_transition::batch_transition::batched_transition::multi_party_action::AllowedAsMultiPartyAction;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::state_transition::batch_transition::token_transfer_from_transition::v0::v0_methods::TokenTransferFromTransitionV0Methods;
+use crate::state_transition::batch_transition::TokenTransferFromTransition;
+use crate::util::hash::hash_double;
+
+impl TokenBaseTransitionAccessors for TokenTransferFromTransition {
+    fn base(&self) -> &TokenBaseTransition {
+        match self {
+            TokenTransferFromTransition::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_mut(&mut self) -> &mut TokenBaseTransition {
+        match self {
+            TokenTransferFromTransition::V0(v0) => &mut v0.base,
+        }
+    }
+
+    fn set_base(&mut self, base: TokenBaseTransition) {
+        match self {
+            TokenTransferFromTransition::V0(v0) => v0.base = base,
+        }
+    }
+}
+
+impl TokenTransferFromTransitionV0Methods for TokenTransferFromTransition {
+    fn from_identity_id(&self) -> Identifier {
+        match self {
+            TokenTransferFromTransition::V0(v0) => v0.from_identity_id(),
+        }
+    }
+
+    fn set_from_identity_id(&mut self, from_identity_id: Identifier) {
+        match self {
+            TokenTransferFromTransition::V0(v0) => v0.set_from_identity_id(from_identity_id),
+        }
+    }
+
+    fn recipient_id(&self) -> Identifier {
+        match self {
+            TokenTransferFromTransition::V0(v0) => v0.recipient_id(),
+        }
+    }
+
+    fn set_recipient_id(&mut self, recipient_id: Identifier) {
+        match self {
+            TokenTransferFromTransition::V0(v0) => v0.set_recipient_id(recipient_id),
+        }
+    }
+
+    fn amount(&self) -> TokenAmount {
+        match self {
+            TokenTransferFromTransition::V0(v0) => v0.amount(),
+        }
+    }
+
+    fn set_amount(&mut self, amount: TokenAmount) {
+        match self {
+            TokenTransferFromTransition::V0(v0) => v0.set_amount(amount),
+        }
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        match self {
+            TokenTransferFromTransition::V0(v0) => v0.public_note(),
+        }
+    }
+
+    fn public_note_owned(self) -> Option<String> {
+        match self {
+            TokenTransferFromTransition::V0(v0) => v0.public_note_owned(),
+        }
+    }
+
+    fn set_public_note(&mut self, public_note: Option<String>) {
+        match self {
+            TokenTransferFromTransition::V0(v0) => v0.set_public_note(public_note),
+        }
+    }
+}
+
+impl AllowedAsMultiPartyAction for TokenTransferFromTransition {
+    fn calculate_action_id(&self, owner_id: Identifier) -> Identifier {
+        match self {
+            TokenTransferFromTransition::V0(v0) => v0.calculate_action_id(owner_id),
+        }
+    }
+}
+
+impl TokenTransferFromTransition {
+    pub fn calculate_action_id_with_fields(
+        token_id: &[u8; 32],
+        owner_id: &[u8; 32],
+        identity_contract_nonce: IdentityNonce,
+        from_identity_id: &[u8; 32],
+        recipient_id: &[u8; 32],
+        amount: TokenAmount,
+    ) -> Identifier {
+        let mut bytes = b"action_token_transfer_from".to_vec();
+        bytes.extend_from_slice(token_id);
+        bytes.extend_from_slice(owner_id);
+        bytes.extend_from_slice(&identity_contract_nonce.to_be_bytes());
+        bytes.extend_from_slice(from_identity_id);
+        bytes.extend_from_slice(recipient_id);
+        bytes.extend_from_slice(&amount.to_be_bytes());
+
+        hash_double(bytes).into()
+    }
+}
@@ -0,0 +1,46 @@
+use crate::consensus::basic::{BasicError, UnsupportedFeatureError};
+use crate::consensus::ConsensusError;
+use crate::state_transition::batch_transition::token_transfer_from_transition::validate_structure::v0::TokenTransferFromTransitionActionStructureValidationV0;
+use crate::state_transition::batch_transition::TokenTransferFromTransition;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use platform_version::version::PlatformVersion;
+
+mod v0;
+
+pub trait TokenTransferFromTransitionStructureValidation {
+    fn validate_structure(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError>;
+}
+
+impl TokenTransferFromTransitionStructureValidation for TokenTransferFromTransition {
+    fn validate_structure(
+        &self,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .batch_state_transition
+            .token_transfer_from_transition_structure_validation
+        {
+            Some(0) => self.validate_structure_v0(),
+            Some(version) => Err(ProtocolError::UnknownVersionMismatch {
+                method: "TokenTransferFromTransition::validate_structure".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+            None => Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::UnsupportedFeatureError(
+                    UnsupportedFeatureError::new(
+                        "token allowances".to_string(),
+                        platform_version.protocol_version,
+                    ),
+                )),
+            )),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::consensus::basic::token::{
+    InvalidTokenAmountError, InvalidTokenNoteTooBigError, TokenTransferToOurselfError,
+};
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::data_contract::associated_token::token_perpetual_distribution::distribution_function::MAX_DISTRIBUTION_PARAM;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::v0::v0_methods::TokenBaseTransitionV0Methods;
+use crate::state_transition::batch_transition::token_transfer_from_transition::v0::v0_methods::TokenTransferFromTransitionV0Methods;
+use crate::state_transition::batch_transition::TokenTransferFromTransition;
+use crate::tokens::MAX_TOKEN_NOTE_LEN;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+
+pub(super) trait TokenTransferFromTransitionActionStructureValidationV0 {
+    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, ProtocolError>;
+}
+impl TokenTransferFromTransitionActionStructureValidationV0 for TokenTransferFromTransition {
+    fn validate_structure_v0(&self) -> Result<SimpleConsensusValidationResult, ProtocolError> {
+        if self.amount() > MAX_DISTRIBUTION_PARAM || self.amount() == 0 {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::InvalidTokenAmountError(
+                    InvalidTokenAmountError::new(MAX_DISTRIBUTION_PARAM, self.amount()),
+                )),
+            ));
+        }
+
+        if self.recipient_id() == self.from_identity_id() {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::TokenTransferToOurselfError(
+                    TokenTransferToOurselfError::new(
+                        self.base().token_id(),
+                        self.from_identity_id(),
+                    ),
+                )),
+            ));
+        }
+
+        if let Some(public_note) = self.public_note() {
+            if public_note.len() > MAX_TOKEN_NOTE_LEN {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    ConsensusError::BasicError(BasicError::InvalidTokenNoteTooBigError(
+                        InvalidTokenNoteTooBigError::new(
+                            MAX_TOKEN_NOTE_LEN as u32,
+                            "public_note",
+                            public_note.len() as u32,
+                        ),
+                    )),
+                ));
+            }
+        }
+
+        Ok(SimpleConsensusValidationResult::default())
+    }
+}
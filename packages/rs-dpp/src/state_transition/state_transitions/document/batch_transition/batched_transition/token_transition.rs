@@ -18,7 +18,7 @@ use crate::data_contract::document_type::DocumentTypeRef;
 use crate::document::Document;
 use crate::prelude::IdentityNonce;
 use crate::ProtocolError;
-use crate::state_transition::batch_transition::{DocumentCreateTransition, DocumentDeleteTransition, DocumentReplaceTransition, TokenBurnTransition, TokenConfigUpdateTransition, TokenDestroyFrozenFundsTransition, TokenEmergencyActionTransition, TokenFreezeTransition, TokenMintTransition, TokenClaimTransition, TokenTransferTransition, TokenSetPriceForDirectPurchaseTransition};
+use crate::state_transition::batch_transition::{DocumentCreateTransition, DocumentDeleteTransition, DocumentReplaceTransition, TokenBurnTransition, TokenConfigUpdateTransition, TokenDestroyFrozenFundsTransition, TokenEmergencyActionTransition, TokenFreezeTransition, TokenMintTransition, TokenClaimTransition, TokenTransferTransition, TokenSetPriceForDirectPurchaseTransition, TokenPlaceOrderTransition, TokenCancelOrderTransition, TokenVestingTransferTransition, TokenClaimVestedTransition, TokenApproveTransition, TokenTransferFromTransition};
 use crate::state_transition::batch_transition::batched_transition::{DocumentPurchaseTransition, DocumentTransferTransition};
 use crate::state_transition::batch_transition::batched_transition::multi_party_action::AllowedAsMultiPartyAction;
 use crate::state_transition::batch_transition::batched_transition::token_unfreeze_transition::TokenUnfreezeTransition;
@@ -36,6 +36,12 @@ use crate::state_transition::batch_transition::token_claim_transition::v0::v0_me
 use crate::state_transition::batch_transition::token_direct_purchase_transition::TokenDirectPurchaseTransition;
 use crate::state_transition::batch_transition::token_direct_purchase_transition::v0::v0_methods::TokenDirectPurchaseTransitionV0Methods;
 use crate::state_transition::batch_transition::token_set_price_for_direct_purchase_transition::v0::v0_methods::TokenSetPriceForDirectPurchaseTransitionV0Methods;
+use crate::state_transition::batch_transition::token_place_order_transition::v0::v0_methods::TokenPlaceOrderTransitionV0Methods;
+use crate::state_transition::batch_transition::token_cancel_order_transition::v0::v0_methods::TokenCancelOrderTransitionV0Methods;
+use crate::state_transition::batch_transition::token_vesting_transfer_transition::v0::v0_methods::TokenVestingTransferTransitionV0Methods;
+use crate::state_transition::batch_transition::token_claim_vested_transition::v0::v0_methods::TokenClaimVestedTransitionV0Methods;
+use crate::state_transition::batch_transition::token_approve_transition::v0::v0_methods::TokenApproveTransitionV0Methods;
+use crate::state_transition::batch_transition::token_transfer_from_transition::v0::v0_methods::TokenTransferFromTransitionV0Methods;
 use crate::state_transition::batch_transition::token_transfer_transition::v0::v0_methods::TokenTransferTransitionV0Methods;
 use crate::state_transition::batch_transition::token_unfreeze_transition::v0::v0_methods::TokenUnfreezeTransitionV0Methods;
 use crate::tokens::token_event::TokenEvent;
@@ -83,6 +89,24 @@ pub enum TokenTransition {
 
     #[display("TokenSetPriceForDirectPurchaseTransition({})", "_0")]
     SetPriceForDirectPurchase(TokenSetPriceForDirectPurchaseTransition),
+
+    #[display("TokenPlaceOrderTransition({})", "_0")]
+    PlaceOrder(TokenPlaceOrderTransition),
+
+    #[display("TokenCancelOrderTransition({})", "_0")]
+    CancelOrder(TokenCancelOrderTransition),
+
+    #[display("TokenVestingTransferTransition({})", "_0")]
+    VestingTransfer(TokenVestingTransferTransition),
+
+    #[display("TokenClaimVestedTransition({})", "_0")]
+    ClaimVested(TokenClaimVestedTransition),
+
+    #[display("TokenApproveTransition({})", "_0")]
+    Approve(TokenApproveTransition),
+
+    #[display("TokenTransferFromTransition({})", "_0")]
+    TransferFrom(TokenTransferFromTransition),
 }
 
 impl BatchTransitionResolversV0 for TokenTransition {
@@ -195,6 +219,54 @@ impl BatchTransitionResolversV0 for TokenTransition {
             None
         }
     }
+
+    fn as_transition_token_place_order(&self) -> Option<&TokenPlaceOrderTransition> {
+        if let Self::PlaceOrder(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    fn as_transition_token_cancel_order(&self) -> Option<&TokenCancelOrderTransition> {
+        if let Self::CancelOrder(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    fn as_transition_token_vesting_transfer(&self) -> Option<&TokenVestingTransferTransition> {
+        if let Self::VestingTransfer(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    fn as_transition_token_claim_vested(&self) -> Option<&TokenClaimVestedTransition> {
+        if let Self::ClaimVested(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    fn as_transition_token_approve(&self) -> Option<&TokenApproveTransition> {
+        if let Self::Approve(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
+
+    fn as_transition_token_transfer_from(&self) -> Option<&TokenTransferFromTransition> {
+        if let Self::TransferFrom(ref t) = self {
+            Some(t)
+        } else {
+            None
+        }
+    }
 }
 
 pub trait TokenTransitionV0Methods {
@@ -259,6 +331,12 @@ impl TokenTransitionV0Methods for TokenTransition {
             TokenTransition::ConfigUpdate(t) => t.base(),
             TokenTransition::DirectPurchase(t) => t.base(),
             TokenTransition::SetPriceForDirectPurchase(t) => t.base(),
+            TokenTransition::PlaceOrder(t) => t.base(),
+            TokenTransition::CancelOrder(t) => t.base(),
+            TokenTransition::VestingTransfer(t) => t.base(),
+            TokenTransition::ClaimVested(t) => t.base(),
+            TokenTransition::Approve(t) => t.base(),
+            TokenTransition::TransferFrom(t) => t.base(),
         }
     }
 
@@ -275,6 +353,12 @@ impl TokenTransitionV0Methods for TokenTransition {
             TokenTransition::ConfigUpdate(t) => t.base_mut(),
             TokenTransition::DirectPurchase(t) => t.base_mut(),
             TokenTransition::SetPriceForDirectPurchase(t) => t.base_mut(),
+            TokenTransition::PlaceOrder(t) => t.base_mut(),
+            TokenTransition::CancelOrder(t) => t.base_mut(),
+            TokenTransition::VestingTransfer(t) => t.base_mut(),
+            TokenTransition::ClaimVested(t) => t.base_mut(),
+            TokenTransition::Approve(t) => t.base_mut(),
+            TokenTransition::TransferFrom(t) => t.base_mut(),
         }
     }
 
@@ -295,6 +379,12 @@ impl TokenTransitionV0Methods for TokenTransition {
             TokenTransition::ConfigUpdate(t) => Some(t.calculate_action_id(owner_id)),
             TokenTransition::DirectPurchase(_) => None,
             TokenTransition::SetPriceForDirectPurchase(t) => Some(t.calculate_action_id(owner_id)),
+            TokenTransition::PlaceOrder(_) => None,
+            TokenTransition::CancelOrder(_) => None,
+            TokenTransition::VestingTransfer(_) => None,
+            TokenTransition::ClaimVested(_) => None,
+            TokenTransition::Approve(_) => None,
+            TokenTransition::TransferFrom(t) => Some(t.calculate_action_id(owner_id)),
         }
     }
 
@@ -307,10 +397,16 @@ impl TokenTransitionV0Methods for TokenTransition {
             | TokenTransition::DestroyFrozenFunds(_)
             | TokenTransition::EmergencyAction(_)
             | TokenTransition::ConfigUpdate(_)
-            | TokenTransition::SetPriceForDirectPurchase(_) => true,
+            | TokenTransition::SetPriceForDirectPurchase(_)
+            | TokenTransition::TransferFrom(_) => true,
             TokenTransition::Transfer(_)
             | TokenTransition::Claim(_)
-            | TokenTransition::DirectPurchase(_) => false,
+            | TokenTransition::DirectPurchase(_)
+            | TokenTransition::PlaceOrder(_)
+            | TokenTransition::CancelOrder(_)
+            | TokenTransition::VestingTransfer(_)
+            | TokenTransition::ClaimVested(_)
+            | TokenTransition::Approve(_) => false,
         }
     }
 
@@ -348,6 +444,12 @@ impl TokenTransitionV0Methods for TokenTransition {
             TokenTransition::Claim(_) => "claim",
             TokenTransition::DirectPurchase(_) => "directPurchase",
             TokenTransition::SetPriceForDirectPurchase(_) => "directPricing",
+            TokenTransition::PlaceOrder(_) => "placeOrder",
+            TokenTransition::CancelOrder(_) => "cancelOrder",
+            TokenTransition::VestingTransfer(_) => "vestingTransfer",
+            TokenTransition::ClaimVested(_) => "claimVested",
+            TokenTransition::Approve(_) => "approve",
+            TokenTransition::TransferFrom(_) => "transferFrom",
         }
     }
 
@@ -488,6 +590,35 @@ impl TokenTransitionV0Methods for TokenTransition {
                     set_price_transition.public_note().cloned(),
                 )
             }
+            TokenTransition::PlaceOrder(place_order) => TokenEvent::PlaceOrder(
+                place_order.side(),
+                place_order.quote_currency(),
+                place_order.amount(),
+                place_order.price_per_token(),
+            ),
+            TokenTransition::CancelOrder(cancel_order) => {
+                TokenEvent::CancelOrder(cancel_order.order_id())
+            }
+            TokenTransition::VestingTransfer(vesting_transfer) => TokenEvent::VestingTransfer(
+                vesting_transfer.recipient_id(),
+                vesting_transfer.amount(),
+                vesting_transfer.public_note().cloned(),
+            ),
+            TokenTransition::ClaimVested(claim_vested) => {
+                TokenEvent::ClaimVested(claim_vested.vesting_id())
+            }
+            TokenTransition::Approve(approve) => TokenEvent::Approve(
+                approve.spender(),
+                approve.amount(),
+                approve.expires_at(),
+                approve.public_note().cloned(),
+            ),
+            TokenTransition::TransferFrom(transfer_from) => TokenEvent::TransferFrom(
+                transfer_from.from_identity_id(),
+                transfer_from.recipient_id(),
+                transfer_from.amount(),
+                transfer_from.public_note().cloned(),
+            ),
         })
     }
 }
@@ -45,6 +45,24 @@ pub enum TokenTransitionActionType {
 
     /// Indicates that the transition sets or updates the price for direct token purchases.
     SetPriceForDirectPurchase,
+
+    /// Indicates that the transition places a limit order on the token order book.
+    PlaceOrder,
+
+    /// Indicates that the transition cancels a resting limit order on the token order book.
+    CancelOrder,
+
+    /// Indicates that the transition locks tokens for a recipient under a vesting schedule.
+    VestingTransfer,
+
+    /// Indicates that the transition claims tokens that have vested.
+    ClaimVested,
+
+    /// Indicates that the transition approves a spender to transfer tokens of the owner.
+    Approve,
+
+    /// Indicates that the transition transfers tokens on behalf of their owner.
+    TransferFrom,
 }
 
 impl fmt::Display for TokenTransitionActionType {
@@ -61,6 +79,12 @@ impl fmt::Display for TokenTransitionActionType {
             TokenTransitionActionType::ConfigUpdate => "ConfigUpdate",
             TokenTransitionActionType::DirectPurchase => "DirectPurchase",
             TokenTransitionActionType::SetPriceForDirectPurchase => "SetPriceForDirectPurchase",
+            TokenTransitionActionType::PlaceOrder => "PlaceOrder",
+            TokenTransitionActionType::CancelOrder => "CancelOrder",
+            TokenTransitionActionType::VestingTransfer => "VestingTransfer",
+            TokenTransitionActionType::ClaimVested => "ClaimVested",
+            TokenTransitionActionType::Approve => "Approve",
+            TokenTransitionActionType::TransferFrom => "TransferFrom",
         };
         write!(f, "{}", action_str)
     }
@@ -86,6 +110,12 @@ impl TokenTransitionActionTypeGetter for TokenTransition {
                 TokenTransitionActionType::SetPriceForDirectPurchase
             }
             TokenTransition::DirectPurchase(_) => TokenTransitionActionType::DirectPurchase,
+            TokenTransition::PlaceOrder(_) => TokenTransitionActionType::PlaceOrder,
+            TokenTransition::CancelOrder(_) => TokenTransitionActionType::CancelOrder,
+            TokenTransition::VestingTransfer(_) => TokenTransitionActionType::VestingTransfer,
+            TokenTransition::ClaimVested(_) => TokenTransitionActionType::ClaimVested,
+            TokenTransition::Approve(_) => TokenTransitionActionType::Approve,
+            TokenTransition::TransferFrom(_) => TokenTransitionActionType::TransferFrom,
         }
     }
 }
@@ -112,6 +142,14 @@ impl TryFrom<&str> for TokenTransitionActionType {
             "set_price_for_direct_purchase" | "setPriceForDirectPurchase" => {
                 Ok(TokenTransitionActionType::SetPriceForDirectPurchase)
             }
+            "place_order" | "placeOrder" => Ok(TokenTransitionActionType::PlaceOrder),
+            "cancel_order" | "cancelOrder" => Ok(TokenTransitionActionType::CancelOrder),
+            "vesting_transfer" | "vestingTransfer" => {
+                Ok(TokenTransitionActionType::VestingTransfer)
+            }
+            "claim_vested" | "claimVested" => Ok(TokenTransitionActionType::ClaimVested),
+            "approve" => Ok(TokenTransitionActionType::Approve),
+            "transfer_from" | "transferFrom" => Ok(TokenTransitionActionType::TransferFrom),
             action_type => Err(ProtocolError::Generic(format!(
                 "unknown token transition action type {action_type}"
             ))),
@@ -0,0 +1,34 @@
+pub mod v0;
+mod v0_methods;
+pub mod validate_structure;
+
+use bincode::{Decode, Encode};
+use derive_more::{Display, From};
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+pub use v0::TokenVestingTransferTransitionV0;
+
+/// Represents a versioned transition for transferring tokens under a vesting schedule.
+///
+/// The transferred amount is locked in escrow and the recipient claims it over time,
+/// as it vests according to the schedule.
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Display, From)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize)
+)]
+pub enum TokenVestingTransferTransition {
+    /// Version 0 of the token vesting transfer transition.
+    ///
+    /// This version includes the base token transition, the recipient, the amount
+    /// of tokens to lock, the vesting schedule and an optional public note.
+    #[display("V0({})", "_0")]
+    V0(TokenVestingTransferTransitionV0),
+}
+
+impl Default for TokenVestingTransferTransition {
+    fn default() -> Self {
+        TokenVestingTransferTransition::V0(TokenVestingTransferTransitionV0::default())
+        // since only v0
+    }
+}
@@ -0,0 +1,43 @@
+pub mod v0_methods;
+
+use crate::balances::credits::TokenAmount;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::tokens::vesting::schedule::TokenVestingSchedule;
+use bincode::{Decode, Encode};
+use platform_value::Identifier;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The Identifier fields in [`TokenVestingTransferTransition`]
+pub use super::super::document_base_transition::IDENTIFIER_FIELDS;
+
+#[derive(Debug, Clone, Default, Encode, Decode, PartialEq)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct TokenVestingTransferTransitionV0 {
+    /// Document Base Transition
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(flatten))]
+    pub base: TokenBaseTransition,
+    /// The identity that will be able to claim the tokens as they vest.
+    pub recipient_id: Identifier,
+    /// How many tokens should be locked.
+    pub amount: TokenAmount,
+    /// How the tokens vest over time, starting when the transition is executed.
+    pub schedule: TokenVestingSchedule,
+    /// The public note
+    pub public_note: Option<String>,
+}
+
+impl fmt::Display for TokenVestingTransferTransitionV0 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Token VestingTransfer, base: {}, recipient: {}, amount: {}, schedule: {}",
+            self.base, self.recipient_id, self.amount, self.schedule
+        )
+    }
+}
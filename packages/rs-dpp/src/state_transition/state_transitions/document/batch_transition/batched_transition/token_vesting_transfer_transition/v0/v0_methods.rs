@@ -0,0 +1,81 @@
+use crate::balances::credits::TokenAmount;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::state_transition::batch_transition::token_vesting_transfer_transition::TokenVestingTransferTransitionV0;
+use crate::tokens::vesting::schedule::TokenVestingSchedule;
+use platform_value::Identifier;
+
+impl TokenBaseTransitionAccessors for TokenVestingTransferTransitionV0 {
+    fn base(&self) -> &TokenBaseTransition {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut TokenBaseTransition {
+        &mut self.base
+    }
+
+    fn set_base(&mut self, base: TokenBaseTransition) {
+        self.base = base;
+    }
+}
+
+pub trait TokenVestingTransferTransitionV0Methods: TokenBaseTransitionAccessors {
+    fn recipient_id(&self) -> Identifier;
+
+    fn set_recipient_id(&mut self, recipient_id: Identifier);
+
+    fn amount(&self) -> TokenAmount;
+
+    fn set_amount(&mut self, amount: TokenAmount);
+
+    fn schedule(&self) -> &TokenVestingSchedule;
+
+    fn set_schedule(&mut self, schedule: TokenVestingSchedule);
+
+    /// Returns the `public_note` field of the `TokenVestingTransferTransitionV0`.
+    fn public_note(&self) -> Option<&String>;
+
+    /// Returns the owned `public_note` field of the `TokenVestingTransferTransitionV0`.
+    fn public_note_owned(self) -> Option<String>;
+
+    /// Sets the value of the `public_note` field in the `TokenVestingTransferTransitionV0`.
+    fn set_public_note(&mut self, public_note: Option<String>);
+}
+
+impl TokenVestingTransferTransitionV0Methods for TokenVestingTransferTransitionV0 {
+    fn recipient_id(&self) -> Identifier {
+        self.recipient_id
+    }
+
+    fn set_recipient_id(&mut self, recipient_id: Identifier) {
+        self.recipient_id = recipient_id;
+    }
+
+    fn amount(&self) -> TokenAmount {
+        self.amount
+    }
+
+    fn set_amount(&mut self, amount: TokenAmount) {
+        self.amount = amount;
+    }
+
+    fn schedule(&self) -> &TokenVestingSchedule {
+        &self.schedule
+    }
+
+    fn set_schedule(&mut self, schedule: TokenVestingSchedule) {
+        self.schedule = schedule;
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        self.public_note.as_ref()
+    }
+
+    fn public_note_owned(self) -> Option<String> {
+        self.public_note
+    }
+
+    fn set_public_note(&mut self, public_note: Option<String>) {
+        self.public_note = public_note;
+    }
+}
@@ -0,0 +1,83 @@
+use crate::balances::credits::TokenAmount;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
+use crate::state_transition::batch_transition::token_vesting_transfer_transition::v0::v0_methods::TokenVestingTransferTransitionV0Methods;
+use crate::state_transition::batch_transition::TokenVestingTransferTransition;
+use crate::tokens::vesting::schedule::TokenVestingSchedule;
+use platform_value::Identifier;
+
+impl TokenBaseTransitionAccessors for TokenVestingTransferTransition {
+    fn base(&self) -> &TokenBaseTransition {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_mut(&mut self) -> &mut TokenBaseTransition {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => &mut v0.base,
+        }
+    }
+
+    fn set_base(&mut self, base: TokenBaseTransition) {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => v0.base = base,
+        }
+    }
+}
+
+impl TokenVestingTransferTransitionV0Methods for TokenVestingTransferTransition {
+    fn recipient_id(&self) -> Identifier {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => v0.recipient_id(),
+        }
+    }
+
+    fn set_recipient_id(&mut self, recipient_id: Identifier) {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => v0.set_recipient_id(recipient_id),
+        }
+    }
+
+    fn amount(&self) -> TokenAmount {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => v0.amount(),
+        }
+    }
+
+    fn set_amount(&mut self, amount: TokenAmount) {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => v0.set_amount(amount),
+        }
+    }
+
+    fn schedule(&self) -> &TokenVestingSchedule {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => v0.schedule(),
+        }
+    }
+
+    fn set_schedule(&mut self, schedule: TokenVestingSchedule) {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => v0.set_schedule(schedule),
+        }
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => v0.public_note(),
+        }
+    }
+
+    fn public_note_owned(self) -> Option<String> {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => v0.public_note_owned(),
+        }
+    }
+
+    fn set_public_note(&mut self, public_note: Option<String>) {
+        match self {
+            TokenVestingTransferTransition::V0(v0) => v0.set_public_note(public_note),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::consensus::basic::{BasicError, UnsupportedFeatureError};
+use crate::consensus::ConsensusError;
+use crate::state_transition::batch_transition::token_vesting_transfer_transition::validate_structure::v0::TokenVestingTransferTransitionActionStructureValidationV0;
+use crate::state_transition::batch_transition::TokenVestingTransferTransition;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use platform_value::Identifier;
+use platform_version::version::PlatformVersion;
+
+mod v0;
+
+pub trait TokenVestingTransferTransitionStructureValidation {
+    fn validate_structure(
+        &self,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError>;
+}
+
+impl TokenVestingTransferTransitionStructureValidation for TokenVestingTransferTransition {
+    fn validate_structure(
+        &self,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .batch_state_transition
+            .token_vesting_transfer_transition_structure_validation
+        {
+            Some(0) => self.validate_structure_v0(owner_id, platform_version),
+            Some(version) => Err(ProtocolError::UnknownVersionMismatch {
+                method: "TokenVestingTransferTransition::validate_structure".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+            None => Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::UnsupportedFeatureError(
+                    UnsupportedFeatureError::new(
+                        "token vesting".to_string(),
+                        platform_version.protocol_version,
+                    ),
+                )),
+            )),
+        }
+    }
+}
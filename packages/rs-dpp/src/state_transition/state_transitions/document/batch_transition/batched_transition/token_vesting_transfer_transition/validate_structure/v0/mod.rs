@@ -0,0 +1,105 @@
+use crate::consensus::basic::token::{
+    InvalidTokenAmountError, InvalidTokenNoteTooBigError, InvalidTokenVestingScheduleError,
+    TokenTransferToOurselfError,
+};
+use crate::consensus::basic::BasicError;
+use crate::consensus::ConsensusError;
+use crate::data_contract::associated_token::token_perpetual_distribution::distribution_function::MAX_DISTRIBUTION_PARAM;
+use crate::state_transition::batch_transition::token_base_transition::token_base_transition_accessors::TokenBaseTransitionAccessors;
+use crate::state_transition::batch_transition::token_base_transition::v0::v0_methods::TokenBaseTransitionV0Methods;
+use crate::state_transition::batch_transition::token_vesting_transfer_transition::v0::v0_methods::TokenVestingTransferTransitionV0Methods;
+use crate::state_transition::batch_transition::TokenVestingTransferTransition;
+use crate::tokens::vesting::schedule::MAX_TOKEN_VESTING_INTERVALS;
+use crate::tokens::MAX_TOKEN_NOTE_LEN;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use platform_value::Identifier;
+use platform_version::version::PlatformVersion;
+
+pub(super) trait TokenVestingTransferTransitionActionStructureValidationV0 {
+    fn validate_structure_v0(
+        &self,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError>;
+}
+impl TokenVestingTransferTransitionActionStructureValidationV0 for TokenVestingTransferTransition {
+    fn validate_structure_v0(
+        &self,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, ProtocolError> {
+        if self.amount() > MAX_DISTRIBUTION_PARAM || self.amount() == 0 {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::InvalidTokenAmountError(
+                    InvalidTokenAmountError::new(MAX_DISTRIBUTION_PARAM, self.amount()),
+                )),
+            ));
+        }
+
+        if self.recipient_id() == owner_id {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::TokenTransferToOurselfError(
+                    TokenTransferToOurselfError::new(self.base().token_id(), owner_id),
+                )),
+            ));
+        }
+
+        if let Some(public_note) = self.public_note() {
+            if public_note.len() > MAX_TOKEN_NOTE_LEN {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    ConsensusError::BasicError(BasicError::InvalidTokenNoteTooBigError(
+                        InvalidTokenNoteTooBigError::new(
+                            MAX_TOKEN_NOTE_LEN as u32,
+                            "public_note",
+                            public_note.len() as u32,
+                        ),
+                    )),
+                ));
+            }
+        }
+
+        let schedule = self.schedule();
+
+        if schedule.interval == 0 {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::InvalidTokenVestingScheduleError(
+                    InvalidTokenVestingScheduleError::new(
+                        self.base().token_id(),
+                        "the release interval must be greater than 0".to_string(),
+                    ),
+                )),
+            ));
+        }
+
+        let release_function_result = schedule.release_function.validate(0, platform_version)?;
+
+        if !release_function_result.is_valid() {
+            return Ok(release_function_result);
+        }
+
+        // Schedules that would not release everything within the maximum number of
+        // intervals are rejected instead of silently vesting the rest at the end.
+        let releases_everything = match schedule.released_amount(MAX_TOKEN_VESTING_INTERVALS) {
+            Ok(released_amount) => released_amount >= self.amount(),
+            Err(ProtocolError::Overflow(_)) => true,
+            Err(e) => return Err(e),
+        };
+
+        if !releases_everything {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::BasicError(BasicError::InvalidTokenVestingScheduleError(
+                    InvalidTokenVestingScheduleError::new(
+                        self.base().token_id(),
+                        format!(
+                            "the release function must release the full amount within {} intervals",
+                            MAX_TOKEN_VESTING_INTERVALS
+                        ),
+                    ),
+                )),
+            ));
+        }
+
+        Ok(SimpleConsensusValidationResult::default())
+    }
+}
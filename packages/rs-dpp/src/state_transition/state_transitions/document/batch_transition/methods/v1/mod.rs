@@ -13,17 +13,25 @@ use crate::identity::signer::Signer;
 #[cfg(feature = "state-transition-signing")]
 use crate::identity::IdentityPublicKey;
 #[cfg(feature = "state-transition-signing")]
-use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use crate::prelude::{IdentityNonce, TimestampMillis, UserFeeIncrease};
 use crate::state_transition::batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
 #[cfg(feature = "state-transition-signing")]
 use crate::state_transition::batch_transition::methods::StateTransitionCreationOptions;
 #[cfg(feature = "state-transition-signing")]
 use crate::state_transition::StateTransition;
 #[cfg(feature = "state-transition-signing")]
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+#[cfg(feature = "state-transition-signing")]
+use crate::tokens::allowed_currency::AllowedCurrency;
+#[cfg(feature = "state-transition-signing")]
 use crate::tokens::emergency_action::TokenEmergencyAction;
 #[cfg(feature = "state-transition-signing")]
+use crate::tokens::order_book::TokenOrderSide;
+#[cfg(feature = "state-transition-signing")]
 use crate::tokens::token_pricing_schedule::TokenPricingSchedule;
 #[cfg(feature = "state-transition-signing")]
+use crate::tokens::vesting::schedule::TokenVestingSchedule;
+#[cfg(feature = "state-transition-signing")]
 use crate::tokens::{PrivateEncryptedNote, SharedEncryptedNote};
 #[cfg(feature = "state-transition-signing")]
 use crate::ProtocolError;
@@ -415,4 +423,209 @@ pub trait DocumentsBatchTransitionMethodsV1: DocumentsBatchTransitionAccessorsV0
         platform_version: &PlatformVersion,
         options: Option<StateTransitionCreationOptions>,
     ) -> Result<StateTransition, ProtocolError>;
+
+    /// Creates a `StateTransition` to place a limit order on the token order book.
+    ///
+    /// The order is matched against resting orders on the opposite side of the book
+    /// and whatever is not filled rests on the book until it is matched or cancelled.
+    ///
+    /// # Parameters
+    /// - `token_id`: ID of the token being traded.
+    /// - `owner_id`: ID of the identity placing the order.
+    /// - `data_contract_id`: The contract ID associated with the token.
+    /// - `token_contract_position`: The token's index within the contract.
+    /// - `side`: Whether the order buys or sells the token.
+    /// - `quote_currency`: The currency the token is priced in.
+    /// - `amount`: Number of tokens to buy or sell.
+    /// - `price_per_token`: The limit price of a single token in the quote currency.
+    /// - `identity_public_key`: Public key used for signing.
+    /// - `identity_contract_nonce`: Nonce to prevent replay.
+    /// - `user_fee_increase`: Fee adjustment parameter.
+    /// - `signer`: Object implementing the signer trait that must contain the private key for the identity public key.
+    #[cfg(feature = "state-transition-signing")]
+    #[allow(clippy::too_many_arguments)]
+    fn new_token_place_order_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        side: TokenOrderSide,
+        quote_currency: AllowedCurrency,
+        amount: TokenAmount,
+        price_per_token: u64,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Creates a `StateTransition` to cancel a resting limit order on the token order book.
+    ///
+    /// # Parameters
+    /// - `token_id`: ID of the token being traded.
+    /// - `owner_id`: ID of the identity that placed the order.
+    /// - `data_contract_id`: The contract ID associated with the token.
+    /// - `token_contract_position`: The token's index within the contract.
+    /// - `order_id`: The order to cancel.
+    /// - `identity_public_key`: Public key used for signing.
+    /// - `identity_contract_nonce`: Nonce to prevent replay.
+    /// - `user_fee_increase`: Fee adjustment parameter.
+    /// - `signer`: Object implementing the signer trait that must contain the private key for the identity public key.
+    #[cfg(feature = "state-transition-signing")]
+    #[allow(clippy::too_many_arguments)]
+    fn new_token_cancel_order_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        order_id: Identifier,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Creates a `StateTransition` to transfer tokens under a vesting schedule.
+    ///
+    /// The amount is locked in escrow when the transition is executed and the recipient
+    /// claims it as it vests.
+    ///
+    /// # Parameters
+    /// - `token_id`: ID of the token being transferred.
+    /// - `owner_id`: ID of the identity locking the tokens.
+    /// - `data_contract_id`: The contract ID associated with the token.
+    /// - `token_contract_position`: The token's index within the contract.
+    /// - `recipient_id`: ID of the identity that can claim the tokens.
+    /// - `amount`: Number of tokens to lock.
+    /// - `schedule`: How the tokens vest over time.
+    /// - `public_note`: Optional public note.
+    /// - `identity_public_key`: Public key used for signing.
+    /// - `identity_contract_nonce`: Nonce to prevent replay.
+    /// - `user_fee_increase`: Fee adjustment parameter.
+    /// - `signer`: Object implementing the signer trait that must contain the private key for the identity public key.
+    #[cfg(feature = "state-transition-signing")]
+    #[allow(clippy::too_many_arguments)]
+    fn new_token_vesting_transfer_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        recipient_id: Identifier,
+        amount: TokenAmount,
+        schedule: TokenVestingSchedule,
+        public_note: Option<String>,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Creates a `StateTransition` to claim vested tokens.
+    ///
+    /// # Parameters
+    /// - `token_id`: ID of the vested token.
+    /// - `owner_id`: ID of the recipient of the vesting.
+    /// - `data_contract_id`: The contract ID associated with the token.
+    /// - `token_contract_position`: The token's index within the contract.
+    /// - `vesting_id`: The vesting to claim from.
+    /// - `identity_public_key`: Public key used for signing.
+    /// - `identity_contract_nonce`: Nonce to prevent replay.
+    /// - `user_fee_increase`: Fee adjustment parameter.
+    /// - `signer`: Object implementing the signer trait that must contain the private key for the identity public key.
+    #[cfg(feature = "state-transition-signing")]
+    #[allow(clippy::too_many_arguments)]
+    fn new_token_claim_vested_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        vesting_id: Identifier,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Creates a `StateTransition` to allow a spender to transfer tokens on the owner's behalf.
+    ///
+    /// Any existing allowance for the same spender is replaced, an amount of 0 revokes it.
+    ///
+    /// # Parameters
+    /// - `token_id`: ID of the token.
+    /// - `owner_id`: ID of the identity granting the allowance.
+    /// - `data_contract_id`: The contract ID associated with the token.
+    /// - `token_contract_position`: The token's index within the contract.
+    /// - `spender`: The identity or group allowed to spend the tokens.
+    /// - `amount`: Maximum number of tokens the spender may transfer.
+    /// - `expires_at`: Optional time after which the allowance can no longer be used.
+    /// - `public_note`: Optional public note.
+    /// - `identity_public_key`: Public key used for signing.
+    /// - `identity_contract_nonce`: Nonce to prevent replay.
+    /// - `user_fee_increase`: Fee adjustment parameter.
+    /// - `signer`: Object implementing the signer trait that must contain the private key for the identity public key.
+    #[cfg(feature = "state-transition-signing")]
+    #[allow(clippy::too_many_arguments)]
+    fn new_token_approve_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        spender: TokenAllowanceSpender,
+        amount: TokenAmount,
+        expires_at: Option<TimestampMillis>,
+        public_note: Option<String>,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError>;
+
+    /// Creates a `StateTransition` to transfer tokens out of another identity's balance
+    /// using an allowance it granted.
+    ///
+    /// When `using_group_info` is set the allowance granted to that group is used.
+    ///
+    /// # Parameters
+    /// - `token_id`: ID of the token being transferred.
+    /// - `owner_id`: ID of the identity spending the allowance.
+    /// - `data_contract_id`: The contract ID associated with the token.
+    /// - `token_contract_position`: The token's index within the contract.
+    /// - `from_identity_id`: ID of the identity that granted the allowance.
+    /// - `recipient_id`: ID of the identity receiving the tokens.
+    /// - `amount`: Number of tokens to transfer.
+    /// - `public_note`: Optional public note.
+    /// - `using_group_info`: Optional group multi-signature info.
+    /// - `identity_public_key`: Public key used for signing.
+    /// - `identity_contract_nonce`: Nonce to prevent replay.
+    /// - `user_fee_increase`: Fee adjustment parameter.
+    /// - `signer`: Object implementing the signer trait that must contain the private key for the identity public key.
+    #[cfg(feature = "state-transition-signing")]
+    #[allow(clippy::too_many_arguments)]
+    fn new_token_transfer_from_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        from_identity_id: Identifier,
+        recipient_id: Identifier,
+        amount: TokenAmount,
+        public_note: Option<String>,
+        using_group_info: Option<GroupStateTransitionInfoStatus>,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError>;
 }
@@ -15,9 +15,12 @@ pub use self::batched_transition::{
     document_base_transition, document_create_transition,
     document_create_transition::DocumentCreateTransition, document_delete_transition,
     document_delete_transition::DocumentDeleteTransition, document_replace_transition,
-    document_replace_transition::DocumentReplaceTransition, token_base_transition,
-    token_burn_transition, token_burn_transition::TokenBurnTransition, token_claim_transition,
-    token_claim_transition::TokenClaimTransition, token_config_update_transition,
+    document_replace_transition::DocumentReplaceTransition, token_approve_transition,
+    token_approve_transition::TokenApproveTransition, token_base_transition, token_burn_transition,
+    token_burn_transition::TokenBurnTransition, token_cancel_order_transition,
+    token_cancel_order_transition::TokenCancelOrderTransition, token_claim_transition,
+    token_claim_transition::TokenClaimTransition, token_claim_vested_transition,
+    token_claim_vested_transition::TokenClaimVestedTransition, token_config_update_transition,
     token_config_update_transition::TokenConfigUpdateTransition,
     token_destroy_frozen_funds_transition,
     token_destroy_frozen_funds_transition::TokenDestroyFrozenFundsTransition,
@@ -26,10 +29,15 @@ pub use self::batched_transition::{
     token_emergency_action_transition,
     token_emergency_action_transition::TokenEmergencyActionTransition, token_freeze_transition,
     token_freeze_transition::TokenFreezeTransition, token_mint_transition,
-    token_mint_transition::TokenMintTransition, token_set_price_for_direct_purchase_transition,
+    token_mint_transition::TokenMintTransition, token_place_order_transition,
+    token_place_order_transition::TokenPlaceOrderTransition,
+    token_set_price_for_direct_purchase_transition,
     token_set_price_for_direct_purchase_transition::TokenSetPriceForDirectPurchaseTransition,
+    token_transfer_from_transition, token_transfer_from_transition::TokenTransferFromTransition,
     token_transfer_transition, token_transfer_transition::TokenTransferTransition,
     token_unfreeze_transition, token_unfreeze_transition::TokenUnfreezeTransition,
+    token_vesting_transfer_transition,
+    token_vesting_transfer_transition::TokenVestingTransferTransition,
 };
 
 use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
@@ -9,6 +9,10 @@ use crate::state_transition::batch_transition::{
     TokenDestroyFrozenFundsTransition, TokenEmergencyActionTransition, TokenFreezeTransition,
     TokenMintTransition, TokenSetPriceForDirectPurchaseTransition, TokenTransferTransition,
 };
+use crate::state_transition::batch_transition::{
+    TokenApproveTransition, TokenCancelOrderTransition, TokenClaimVestedTransition,
+    TokenPlaceOrderTransition, TokenTransferFromTransition, TokenVestingTransferTransition,
+};
 
 pub trait BatchTransitionResolversV0 {
     fn as_transition_create(&self) -> Option<&DocumentCreateTransition>;
@@ -33,4 +37,10 @@ pub trait BatchTransitionResolversV0 {
     fn as_transition_token_set_price_for_direct_purchase(
         &self,
     ) -> Option<&TokenSetPriceForDirectPurchaseTransition>;
+    fn as_transition_token_place_order(&self) -> Option<&TokenPlaceOrderTransition>;
+    fn as_transition_token_cancel_order(&self) -> Option<&TokenCancelOrderTransition>;
+    fn as_transition_token_vesting_transfer(&self) -> Option<&TokenVestingTransferTransition>;
+    fn as_transition_token_claim_vested(&self) -> Option<&TokenClaimVestedTransition>;
+    fn as_transition_token_approve(&self) -> Option<&TokenApproveTransition>;
+    fn as_transition_token_transfer_from(&self) -> Option<&TokenTransferFromTransition>;
 }
@@ -9,11 +9,19 @@ use crate::prelude::IdentityPublicKey;
 #[cfg(feature = "state-transition-signing")]
 use crate::prelude::UserFeeIncrease;
 #[cfg(feature = "state-transition-signing")]
+use crate::prelude::TimestampMillis;
+#[cfg(feature = "state-transition-signing")]
 use crate::state_transition::batch_transition::batched_transition::BatchedTransition;
 use crate::state_transition::batch_transition::BatchTransitionV1;
 #[cfg(feature = "state-transition-signing")]
 use crate::state_transition::batch_transition::{TokenDirectPurchaseTransition, TokenSetPriceForDirectPurchaseTransition};
 #[cfg(feature = "state-transition-signing")]
+use crate::state_transition::batch_transition::{TokenCancelOrderTransition, TokenPlaceOrderTransition};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::batch_transition::{TokenClaimVestedTransition, TokenVestingTransferTransition};
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::batch_transition::{TokenApproveTransition, TokenTransferFromTransition};
+#[cfg(feature = "state-transition-signing")]
 use crate::state_transition::batch_transition::{TokenClaimTransition, TokenBurnTransition, TokenConfigUpdateTransition, TokenDestroyFrozenFundsTransition, TokenEmergencyActionTransition, TokenFreezeTransition, TokenMintTransition, TokenTransferTransition, TokenUnfreezeTransition};
 #[cfg(feature = "state-transition-signing")]
 use crate::state_transition::batch_transition::BatchTransition;
@@ -63,6 +71,18 @@ use crate::state_transition::batch_transition::token_freeze_transition::TokenFre
 #[cfg(feature = "state-transition-signing")]
 use crate::state_transition::batch_transition::token_mint_transition::TokenMintTransitionV0;
 #[cfg(feature = "state-transition-signing")]
+use crate::state_transition::batch_transition::token_place_order_transition::TokenPlaceOrderTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::batch_transition::token_cancel_order_transition::TokenCancelOrderTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::batch_transition::token_vesting_transfer_transition::TokenVestingTransferTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::batch_transition::token_claim_vested_transition::TokenClaimVestedTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::batch_transition::token_approve_transition::TokenApproveTransitionV0;
+#[cfg(feature = "state-transition-signing")]
+use crate::state_transition::batch_transition::token_transfer_from_transition::TokenTransferFromTransitionV0;
+#[cfg(feature = "state-transition-signing")]
 use crate::state_transition::batch_transition::token_set_price_for_direct_purchase_transition::TokenSetPriceForDirectPurchaseTransitionV0;
 #[cfg(feature = "state-transition-signing")]
 use crate::state_transition::batch_transition::token_transfer_transition::TokenTransferTransitionV0;
@@ -71,11 +91,19 @@ use crate::state_transition::batch_transition::token_unfreeze_transition::TokenU
 #[cfg(feature = "state-transition-signing")]
 use crate::state_transition::GetDataContractSecurityLevelRequirementFn;
 #[cfg(feature = "state-transition-signing")]
+use crate::tokens::allowed_currency::AllowedCurrency;
+#[cfg(feature = "state-transition-signing")]
 use crate::tokens::emergency_action::TokenEmergencyAction;
 #[cfg(feature = "state-transition-signing")]
+use crate::tokens::order_book::TokenOrderSide;
+#[cfg(feature = "state-transition-signing")]
 use crate::tokens::{PrivateEncryptedNote, SharedEncryptedNote};
 #[cfg(feature = "state-transition-signing")]
 use crate::tokens::token_pricing_schedule::TokenPricingSchedule;
+#[cfg(feature = "state-transition-signing")]
+use crate::tokens::vesting::schedule::TokenVestingSchedule;
+#[cfg(feature = "state-transition-signing")]
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
 
 impl DocumentsBatchTransitionMethodsV1 for BatchTransitionV1 {
     #[cfg(feature = "state-transition-signing")]
@@ -871,4 +899,361 @@ impl DocumentsBatchTransitionMethodsV1 for BatchTransitionV1 {
         }
         Ok(state_transition)
     }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_token_place_order_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        side: TokenOrderSide,
+        quote_currency: AllowedCurrency,
+        amount: TokenAmount,
+        price_per_token: u64,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let place_order_transition = TokenPlaceOrderTransition::V0(TokenPlaceOrderTransitionV0 {
+            base: TokenBaseTransition::V0(TokenBaseTransitionV0 {
+                identity_contract_nonce,
+                token_contract_position,
+                data_contract_id,
+                token_id,
+                using_group_info: None,
+            }),
+            side,
+            quote_currency,
+            amount,
+            price_per_token,
+        });
+
+        let batch_transition: BatchTransition = BatchTransitionV1 {
+            owner_id,
+            transitions: vec![BatchedTransition::Token(place_order_transition.into())],
+            user_fee_increase,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+        let mut state_transition: StateTransition = batch_transition.into();
+        if let Some(options) = options {
+            state_transition.sign_external_with_options(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+                options.signing_options,
+            )?;
+        } else {
+            state_transition.sign_external(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+            )?;
+        }
+        Ok(state_transition)
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_token_cancel_order_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        order_id: Identifier,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let cancel_order_transition =
+            TokenCancelOrderTransition::V0(TokenCancelOrderTransitionV0 {
+                base: TokenBaseTransition::V0(TokenBaseTransitionV0 {
+                    identity_contract_nonce,
+                    token_contract_position,
+                    data_contract_id,
+                    token_id,
+                    using_group_info: None,
+                }),
+                order_id,
+            });
+
+        let batch_transition: BatchTransition = BatchTransitionV1 {
+            owner_id,
+            transitions: vec![BatchedTransition::Token(cancel_order_transition.into())],
+            user_fee_increase,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+        let mut state_transition: StateTransition = batch_transition.into();
+        if let Some(options) = options {
+            state_transition.sign_external_with_options(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+                options.signing_options,
+            )?;
+        } else {
+            state_transition.sign_external(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+            )?;
+        }
+        Ok(state_transition)
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_token_vesting_transfer_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        recipient_id: Identifier,
+        amount: TokenAmount,
+        schedule: TokenVestingSchedule,
+        public_note: Option<String>,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let vesting_transfer_transition =
+            TokenVestingTransferTransition::V0(TokenVestingTransferTransitionV0 {
+                base: TokenBaseTransition::V0(TokenBaseTransitionV0 {
+                    identity_contract_nonce,
+                    token_contract_position,
+                    data_contract_id,
+                    token_id,
+                    using_group_info: None,
+                }),
+                recipient_id,
+                amount,
+                schedule,
+                public_note,
+            });
+
+        let batch_transition: BatchTransition = BatchTransitionV1 {
+            owner_id,
+            transitions: vec![BatchedTransition::Token(vesting_transfer_transition.into())],
+            user_fee_increase,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+        let mut state_transition: StateTransition = batch_transition.into();
+        if let Some(options) = options {
+            state_transition.sign_external_with_options(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+                options.signing_options,
+            )?;
+        } else {
+            state_transition.sign_external(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+            )?;
+        }
+        Ok(state_transition)
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_token_claim_vested_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        vesting_id: Identifier,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let claim_vested_transition =
+            TokenClaimVestedTransition::V0(TokenClaimVestedTransitionV0 {
+                base: TokenBaseTransition::V0(TokenBaseTransitionV0 {
+                    identity_contract_nonce,
+                    token_contract_position,
+                    data_contract_id,
+                    token_id,
+                    using_group_info: None,
+                }),
+                vesting_id,
+            });
+
+        let batch_transition: BatchTransition = BatchTransitionV1 {
+            owner_id,
+            transitions: vec![BatchedTransition::Token(claim_vested_transition.into())],
+            user_fee_increase,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+        let mut state_transition: StateTransition = batch_transition.into();
+        if let Some(options) = options {
+            state_transition.sign_external_with_options(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+                options.signing_options,
+            )?;
+        } else {
+            state_transition.sign_external(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+            )?;
+        }
+        Ok(state_transition)
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_token_approve_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        spender: TokenAllowanceSpender,
+        amount: TokenAmount,
+        expires_at: Option<TimestampMillis>,
+        public_note: Option<String>,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let approve_transition = TokenApproveTransition::V0(TokenApproveTransitionV0 {
+            base: TokenBaseTransition::V0(TokenBaseTransitionV0 {
+                identity_contract_nonce,
+                token_contract_position,
+                data_contract_id,
+                token_id,
+                using_group_info: None,
+            }),
+            spender,
+            amount,
+            expires_at,
+            public_note,
+        });
+
+        let batch_transition: BatchTransition = BatchTransitionV1 {
+            owner_id,
+            transitions: vec![BatchedTransition::Token(approve_transition.into())],
+            user_fee_increase,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+        let mut state_transition: StateTransition = batch_transition.into();
+        if let Some(options) = options {
+            state_transition.sign_external_with_options(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+                options.signing_options,
+            )?;
+        } else {
+            state_transition.sign_external(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+            )?;
+        }
+        Ok(state_transition)
+    }
+
+    #[cfg(feature = "state-transition-signing")]
+    fn new_token_transfer_from_transition<S: Signer>(
+        token_id: Identifier,
+        owner_id: Identifier,
+        data_contract_id: Identifier,
+        token_contract_position: u16,
+        from_identity_id: Identifier,
+        recipient_id: Identifier,
+        amount: TokenAmount,
+        public_note: Option<String>,
+        using_group_info: Option<GroupStateTransitionInfoStatus>,
+        identity_public_key: &IdentityPublicKey,
+        identity_contract_nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        options: Option<StateTransitionCreationOptions>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let mut transfer_from_transition =
+            TokenTransferFromTransition::V0(TokenTransferFromTransitionV0 {
+                base: TokenBaseTransition::V0(TokenBaseTransitionV0 {
+                    identity_contract_nonce,
+                    token_contract_position,
+                    data_contract_id,
+                    token_id,
+                    using_group_info: None,
+                }),
+                from_identity_id,
+                recipient_id,
+                amount,
+                public_note,
+            });
+
+        if let Some(using_group_info_status) = using_group_info {
+            match using_group_info_status {
+                GroupStateTransitionInfoStatus::GroupStateTransitionInfoProposer(
+                    group_contract_position,
+                ) => {
+                    let action_id = transfer_from_transition.calculate_action_id(owner_id);
+                    transfer_from_transition
+                        .base_mut()
+                        .set_using_group_info(Some(GroupStateTransitionInfo {
+                            group_contract_position,
+                            action_id,
+                            action_is_proposer: true,
+                        }))
+                }
+                GroupStateTransitionInfoStatus::GroupStateTransitionInfoOtherSigner(info) => {
+                    transfer_from_transition
+                        .base_mut()
+                        .set_using_group_info(Some(info))
+                }
+            }
+        }
+
+        let batch_transition: BatchTransition = BatchTransitionV1 {
+            owner_id,
+            transitions: vec![BatchedTransition::Token(transfer_from_transition.into())],
+            user_fee_increase,
+            signature_public_key_id: 0,
+            signature: Default::default(),
+        }
+        .into();
+        let mut state_transition: StateTransition = batch_transition.into();
+        if let Some(options) = options {
+            state_transition.sign_external_with_options(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+                options.signing_options,
+            )?;
+        } else {
+            state_transition.sign_external(
+                identity_public_key,
+                signer,
+                None::<GetDataContractSecurityLevelRequirementFn>,
+            )?;
+        }
+        Ok(state_transition)
+    }
 }
@@ -29,7 +29,13 @@ use crate::state_transition::batch_transition::token_mint_transition::validate_s
 use crate::state_transition::batch_transition::token_claim_transition::validate_structure::TokenClaimTransitionStructureValidation;
 use crate::state_transition::batch_transition::token_direct_purchase_transition::validate_structure::TokenDirectPurchaseTransitionStructureValidation;
 use crate::state_transition::batch_transition::token_set_price_for_direct_purchase_transition::validate_structure::TokenSetPriceForDirectPurchaseTransitionStructureValidation;
+use crate::state_transition::batch_transition::token_place_order_transition::validate_structure::TokenPlaceOrderTransitionStructureValidation;
+use crate::state_transition::batch_transition::token_cancel_order_transition::validate_structure::TokenCancelOrderTransitionStructureValidation;
 use crate::state_transition::batch_transition::token_transfer_transition::validate_structure::TokenTransferTransitionStructureValidation;
+use crate::state_transition::batch_transition::token_vesting_transfer_transition::validate_structure::TokenVestingTransferTransitionStructureValidation;
+use crate::state_transition::batch_transition::token_claim_vested_transition::validate_structure::TokenClaimVestedTransitionStructureValidation;
+use crate::state_transition::batch_transition::token_approve_transition::validate_structure::TokenApproveTransitionStructureValidation;
+use crate::state_transition::batch_transition::token_transfer_from_transition::validate_structure::TokenTransferFromTransitionStructureValidation;
 use crate::state_transition::batch_transition::token_unfreeze_transition::validate_structure::TokenUnfreezeTransitionStructureValidation;
 use crate::state_transition::state_transitions::document::batch_transition::batched_transition::document_transition::{DocumentTransition, DocumentTransitionV0Methods};
 use crate::state_transition::StateTransitionLike;
@@ -180,6 +186,27 @@ impl BatchTransition {
                 ) => {
                     set_price_for_direct_purchase_transition.validate_structure(platform_version)?
                 }
+                TokenTransition::PlaceOrder(place_order_transition) => {
+                    place_order_transition.validate_structure(platform_version)?
+                }
+                // Whether the order exists and belongs to the owner is checked against state
+                TokenTransition::CancelOrder(cancel_order_transition) => {
+                    cancel_order_transition.validate_structure(platform_version)?
+                }
+                TokenTransition::VestingTransfer(vesting_transfer_transition) => {
+                    vesting_transfer_transition
+                        .validate_structure(self.owner_id(), platform_version)?
+                }
+                // Whether the vesting exists for the owner is checked against state
+                TokenTransition::ClaimVested(claim_vested_transition) => {
+                    claim_vested_transition.validate_structure(platform_version)?
+                }
+                TokenTransition::Approve(approve_transition) => {
+                    approve_transition.validate_structure(self.owner_id(), platform_version)?
+                }
+                TokenTransition::TransferFrom(transfer_from_transition) => {
+                    transfer_from_transition.validate_structure(platform_version)?
+                }
             };
 
             if !consensus_result.is_valid() {
@@ -6,3 +6,4 @@ pub mod identity_topup_transition;
 pub mod identity_update_transition;
 pub mod masternode_vote_transition;
 pub mod public_key_in_creation;
+pub mod vote_poll_create_transition;
@@ -0,0 +1,27 @@
+mod v0;
+
+use crate::prelude::IdentityNonce;
+use crate::state_transition::vote_poll_create_transition::VotePollCreateTransition;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use platform_value::Identifier;
+pub use v0::*;
+
+impl VotePollCreateTransitionAccessorsV0 for VotePollCreateTransition {
+    fn identity_id(&self) -> Identifier {
+        match self {
+            VotePollCreateTransition::V0(transition) => transition.identity_id,
+        }
+    }
+
+    fn vote_poll(&self) -> &YesNoAbstainVotePoll {
+        match self {
+            VotePollCreateTransition::V0(transition) => &transition.vote_poll,
+        }
+    }
+
+    fn nonce(&self) -> IdentityNonce {
+        match self {
+            VotePollCreateTransition::V0(transition) => transition.nonce,
+        }
+    }
+}
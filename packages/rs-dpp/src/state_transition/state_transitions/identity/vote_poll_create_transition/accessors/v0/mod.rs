@@ -0,0 +1,12 @@
+use crate::prelude::IdentityNonce;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use platform_value::Identifier;
+
+pub trait VotePollCreateTransitionAccessorsV0 {
+    /// The identity creating the vote poll
+    fn identity_id(&self) -> Identifier;
+    /// The vote poll being created
+    fn vote_poll(&self) -> &YesNoAbstainVotePoll;
+    /// The identity nonce of the creator
+    fn nonce(&self) -> IdentityNonce;
+}
@@ -0,0 +1,14 @@
+use crate::state_transition::state_transitions;
+
+pub use state_transitions::common_fields::property_names::{
+    IDENTITY_NONCE, SIGNATURE, SIGNATURE_PUBLIC_KEY_ID, STATE_TRANSITION_PROTOCOL_VERSION,
+    TRANSITION_TYPE, USER_FEE_INCREASE,
+};
+pub use state_transitions::identity::common_fields::property_names::IDENTITY_ID;
+
+pub const VOTE_POLL: &str = "votePoll";
+pub const VOTE_POLL_SUBJECT_ID: &str = "votePoll.subjectId";
+
+pub const IDENTIFIER_FIELDS: [&str; 2] = [IDENTITY_ID, VOTE_POLL_SUBJECT_ID];
+pub const BINARY_FIELDS: [&str; 1] = [SIGNATURE];
+pub const U32_FIELDS: [&str; 1] = [STATE_TRANSITION_PROTOCOL_VERSION];
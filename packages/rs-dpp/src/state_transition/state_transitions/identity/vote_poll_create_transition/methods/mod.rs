@@ -0,0 +1,51 @@
+mod v0;
+
+pub use v0::*;
+
+use crate::identity::signer::Signer;
+use crate::identity::{KeyID, PartialIdentity};
+use crate::state_transition::vote_poll_create_transition::{
+    VotePollCreateTransition, VotePollCreateTransitionV0,
+};
+use crate::state_transition::StateTransition;
+use crate::version::FeatureVersion;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use crate::ProtocolError;
+
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use platform_version::version::PlatformVersion;
+
+impl VotePollCreateTransitionMethodsV0 for VotePollCreateTransition {
+    fn new_from_vote_poll<S: Signer>(
+        vote_poll: YesNoAbstainVotePoll,
+        identity: &PartialIdentity,
+        key_id: KeyID,
+        nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        match feature_version.unwrap_or(
+            platform_version
+                .dpp
+                .state_transition_serialization_versions
+                .vote_poll_create_state_transition
+                .default_current_version,
+        ) {
+            0 => VotePollCreateTransitionV0::new_from_vote_poll(
+                vote_poll,
+                identity,
+                key_id,
+                nonce,
+                user_fee_increase,
+                signer,
+                platform_version,
+                feature_version,
+            ),
+            v => Err(ProtocolError::UnknownVersionError(format!(
+                "Unknown VotePollCreateTransition version for new_from_vote_poll {v}"
+            ))),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::identity::signer::Signer;
+use crate::identity::{KeyID, PartialIdentity};
+
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use crate::state_transition::StateTransition;
+use crate::version::FeatureVersion;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use crate::ProtocolError;
+use platform_version::version::PlatformVersion;
+
+pub trait VotePollCreateTransitionMethodsV0 {
+    /// Creates a new signed `VotePollCreateTransition` opening the given `vote_poll`.
+    ///
+    /// # Arguments
+    ///
+    /// * `vote_poll` - The yes/no/abstain vote poll to open.
+    /// * `identity` - A reference to the `PartialIdentity` creating the poll, it must be the
+    ///   poll subject or own the data contract that is the poll subject.
+    /// * `key_id` - The `KeyID` (public key identifier) to be used for signing the transition.
+    /// * `nonce` - The next identity nonce of the creator.
+    /// * `signer` - A reference to the `Signer` object that will sign the transition.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<StateTransition, ProtocolError>` - If successful, returns the signed state transition.
+    ///   In case of any error, a relevant `ProtocolError` is returned.
+    #[allow(clippy::too_many_arguments)]
+    fn new_from_vote_poll<S: Signer>(
+        vote_poll: YesNoAbstainVotePoll,
+        identity: &PartialIdentity,
+        key_id: KeyID,
+        nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        platform_version: &PlatformVersion,
+        feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError>;
+}
@@ -0,0 +1,140 @@
+use crate::state_transition::StateTransitionFieldTypes;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize, PlatformSignable};
+
+use platform_versioning::PlatformVersioned;
+
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+pub mod accessors;
+mod fields;
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+pub mod methods;
+mod state_transition_like;
+mod v0;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+pub use fields::*;
+
+use crate::identity::state_transition::OptionallyAssetLockProved;
+pub use v0::*;
+
+pub type VotePollCreateTransitionLatest = VotePollCreateTransitionV0;
+
+/// Opens a yes/no/abstain vote poll for masternodes.
+///
+/// The creator must be the poll subject, or own the data contract that is the poll subject.
+/// The masternodes that may vote on the poll are the ones in the masternode list when it is
+/// created. Available from protocol version 11.
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformDeserialize,
+    PlatformSerialize,
+    PlatformSignable,
+    PlatformVersioned,
+    From,
+    PartialEq,
+)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(tag = "$version")
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+#[platform_version_path_bounds(
+    "dpp.state_transition_serialization_versions.vote_poll_create_state_transition"
+)]
+pub enum VotePollCreateTransition {
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "0"))]
+    V0(VotePollCreateTransitionV0),
+}
+
+impl StateTransitionFieldTypes for VotePollCreateTransition {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE, SIGNATURE_PUBLIC_KEY_ID]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![IDENTITY_ID, VOTE_POLL_SUBJECT_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+}
+
+impl OptionallyAssetLockProved for VotePollCreateTransition {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::serialization::{PlatformDeserializable, PlatformSerializable};
+    use crate::state_transition::vote_poll_create_transition::accessors::VotePollCreateTransitionAccessorsV0;
+    use crate::state_transition::{StateTransitionLike, StateTransitionType};
+    use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+    use platform_value::Identifier;
+
+    fn get_test_transition() -> VotePollCreateTransition {
+        VotePollCreateTransitionV0 {
+            identity_id: Identifier::random(),
+            vote_poll: YesNoAbstainVotePoll {
+                subject_id: Identifier::random(),
+                question: "Should the contract be upgraded?".to_string(),
+                end_time_ms: 1_000_000,
+            },
+            nonce: 2,
+            user_fee_increase: 0,
+            signature_public_key_id: 1,
+            signature: [1u8; 65].to_vec().into(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn should_return_transition_type() {
+        let transition = get_test_transition();
+        assert_eq!(
+            StateTransitionType::VotePollCreate,
+            transition.state_transition_type()
+        );
+    }
+
+    #[test]
+    fn should_be_owned_by_the_creator() {
+        let transition = get_test_transition();
+        assert_eq!(transition.owner_id(), transition.identity_id());
+        assert_eq!(
+            transition.modified_data_ids(),
+            vec![transition.identity_id()]
+        );
+    }
+
+    #[test]
+    fn is_voting_state_transition() {
+        let transition = get_test_transition();
+        assert!(transition.is_voting_state_transition());
+        assert!(!transition.is_identity_state_transition());
+        assert!(!transition.is_document_state_transition());
+        assert!(!transition.is_data_contract_state_transition());
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize() {
+        let transition = get_test_transition();
+        let serialized = transition
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+        let deserialized = VotePollCreateTransition::deserialize_from_bytes(&serialized)
+            .expect("expected to deserialize");
+        assert_eq!(transition, deserialized);
+    }
+}
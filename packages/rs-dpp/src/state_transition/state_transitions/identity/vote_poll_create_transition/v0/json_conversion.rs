@@ -0,0 +1,4 @@
+use crate::state_transition::vote_poll_create_transition::VotePollCreateTransitionV0;
+use crate::state_transition::StateTransitionJsonConvert;
+
+impl StateTransitionJsonConvert<'_> for VotePollCreateTransitionV0 {}
@@ -0,0 +1,55 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+pub(super) mod v0_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use platform_value::{BinaryData, Identifier};
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+use bincode::{Decode, Encode};
+use platform_serialization_derive::PlatformSignable;
+
+use crate::identity::KeyID;
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use crate::state_transition::vote_poll_create_transition::VotePollCreateTransition;
+use crate::state_transition::StateTransition;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+
+#[derive(Debug, Clone, Encode, Decode, PartialEq, PlatformSignable)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct VotePollCreateTransitionV0 {
+    /// The identity creating the poll, it pays for it and must be allowed to ask about its subject
+    pub identity_id: Identifier,
+    /// The poll to open for masternode votes
+    pub vote_poll: YesNoAbstainVotePoll,
+    pub nonce: IdentityNonce,
+    pub user_fee_increase: UserFeeIncrease,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
+
+impl From<VotePollCreateTransitionV0> for StateTransition {
+    fn from(value: VotePollCreateTransitionV0) -> Self {
+        let transition: VotePollCreateTransition = value.into();
+        transition.into()
+    }
+}
+
+impl From<&VotePollCreateTransitionV0> for StateTransition {
+    fn from(value: &VotePollCreateTransitionV0) -> Self {
+        let transition: VotePollCreateTransition = value.clone().into();
+        transition.into()
+    }
+}
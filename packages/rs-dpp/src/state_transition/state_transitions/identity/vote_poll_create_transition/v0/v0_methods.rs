@@ -0,0 +1,50 @@
+use crate::identity::signer::Signer;
+use crate::identity::{KeyID, PartialIdentity};
+use crate::serialization::Signable;
+
+use crate::prelude::{IdentityNonce, UserFeeIncrease};
+use crate::state_transition::vote_poll_create_transition::methods::VotePollCreateTransitionMethodsV0;
+use crate::state_transition::vote_poll_create_transition::{
+    VotePollCreateTransition, VotePollCreateTransitionV0,
+};
+use crate::state_transition::StateTransition;
+use crate::version::FeatureVersion;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use crate::{NonConsensusError, ProtocolError};
+use platform_version::version::PlatformVersion;
+
+impl VotePollCreateTransitionMethodsV0 for VotePollCreateTransitionV0 {
+    fn new_from_vote_poll<S: Signer>(
+        vote_poll: YesNoAbstainVotePoll,
+        identity: &PartialIdentity,
+        key_id: KeyID,
+        nonce: IdentityNonce,
+        user_fee_increase: UserFeeIncrease,
+        signer: &S,
+        _platform_version: &PlatformVersion,
+        _feature_version: Option<FeatureVersion>,
+    ) -> Result<StateTransition, ProtocolError> {
+        let transition = VotePollCreateTransition::V0(VotePollCreateTransitionV0 {
+            identity_id: identity.id,
+            vote_poll,
+            nonce,
+            user_fee_increase,
+            signature_public_key_id: key_id,
+            signature: Default::default(),
+        });
+
+        let mut state_transition: StateTransition = transition.into();
+        let value = state_transition.signable_bytes()?;
+        let public_key =
+            identity
+                .loaded_public_keys
+                .get(&key_id)
+                .ok_or(ProtocolError::NonConsensusError(
+                    NonConsensusError::StateTransitionCreationError(
+                        "public key did not exist".to_string(),
+                    ),
+                ))?;
+        state_transition.set_signature(signer.sign(public_key, &value)?);
+        Ok(state_transition)
+    }
+}
@@ -0,0 +1,9 @@
+use crate::state_transition::vote_poll_create_transition::VotePollCreateTransitionV0;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for VotePollCreateTransitionV0 {
+    fn feature_version(&self) -> FeatureVersion {
+        0
+    }
+}
@@ -0,0 +1,11 @@
+use crate::state_transition::vote_poll_create_transition::VotePollCreateTransition;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for VotePollCreateTransition {
+    fn feature_version(&self) -> FeatureVersion {
+        match self {
+            VotePollCreateTransition::V0(v0) => v0.feature_version(),
+        }
+    }
+}
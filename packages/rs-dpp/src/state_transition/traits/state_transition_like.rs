@@ -18,11 +18,15 @@ pub const IDENTITY_TRANSITION_TYPE: [StateTransitionType; 5] = [
     StateTransitionType::IdentityCreditWithdrawal,
 ];
 
-pub const VOTING_TRANSITION_TYPE: [StateTransitionType; 1] = [StateTransitionType::MasternodeVote];
+pub const VOTING_TRANSITION_TYPE: [StateTransitionType; 2] = [
+    StateTransitionType::MasternodeVote,
+    StateTransitionType::VotePollCreate,
+];
 
-pub const DATA_CONTRACT_TRANSITION_TYPES: [StateTransitionType; 2] = [
+pub const DATA_CONTRACT_TRANSITION_TYPES: [StateTransitionType; 3] = [
     StateTransitionType::DataContractCreate,
     StateTransitionType::DataContractUpdate,
+    StateTransitionType::DataContractDelete,
 ];
 
 /// The StateTransitionLike represents set of methods that are shared for all types of State Transition.
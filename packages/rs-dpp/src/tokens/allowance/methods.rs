@@ -0,0 +1,44 @@
+use crate::balances::credits::TokenAmount;
+use crate::prelude::TimestampMillis;
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+use crate::tokens::allowance::v0::TokenAllowanceV0Accessors;
+use crate::tokens::allowance::TokenAllowance;
+use platform_value::Identifier;
+
+impl TokenAllowanceV0Accessors for TokenAllowance {
+    fn token_id(&self) -> Identifier {
+        match self {
+            TokenAllowance::V0(allowance) => allowance.token_id(),
+        }
+    }
+
+    fn owner_id(&self) -> Identifier {
+        match self {
+            TokenAllowance::V0(allowance) => allowance.owner_id(),
+        }
+    }
+
+    fn spender(&self) -> TokenAllowanceSpender {
+        match self {
+            TokenAllowance::V0(allowance) => allowance.spender(),
+        }
+    }
+
+    fn amount(&self) -> TokenAmount {
+        match self {
+            TokenAllowance::V0(allowance) => allowance.amount(),
+        }
+    }
+
+    fn set_amount(&mut self, amount: TokenAmount) {
+        match self {
+            TokenAllowance::V0(allowance) => allowance.set_amount(amount),
+        }
+    }
+
+    fn expires_at(&self) -> Option<TimestampMillis> {
+        match self {
+            TokenAllowance::V0(allowance) => allowance.expires_at(),
+        }
+    }
+}
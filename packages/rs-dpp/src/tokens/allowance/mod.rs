@@ -0,0 +1,72 @@
+use crate::balances::credits::TokenAmount;
+use crate::prelude::TimestampMillis;
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+use crate::tokens::allowance::v0::{TokenAllowanceV0, TokenAllowanceV0Accessors};
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use platform_version::version::PlatformVersion;
+use platform_versioning::PlatformVersioned;
+
+mod methods;
+pub mod spender;
+pub mod v0;
+
+/// Tokens an owner allows a spender to transfer on their behalf.
+///
+/// Every transfer made by the spender from the owner lowers the allowance, the owner
+/// can replace or revoke it at any time.
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformDeserialize,
+    PlatformSerialize,
+    PlatformVersioned,
+    From,
+    PartialEq,
+    Eq,
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+pub enum TokenAllowance {
+    V0(TokenAllowanceV0),
+}
+
+impl TokenAllowance {
+    pub fn new(
+        token_id: Identifier,
+        owner_id: Identifier,
+        spender: TokenAllowanceSpender,
+        amount: TokenAmount,
+        expires_at: Option<TimestampMillis>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Self, ProtocolError> {
+        match platform_version
+            .dpp
+            .token_versions
+            .token_allowance_default_structure_version
+        {
+            0 => Ok(TokenAllowance::V0(TokenAllowanceV0 {
+                token_id,
+                owner_id,
+                spender,
+                amount,
+                expires_at,
+            })),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "TokenAllowance::new".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+
+    /// Whether the allowance can no longer be spent at `current_moment`.
+    pub fn is_expired(&self, current_moment: TimestampMillis) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| current_moment >= expires_at)
+    }
+}
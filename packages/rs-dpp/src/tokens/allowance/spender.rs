@@ -0,0 +1,67 @@
+use crate::data_contract::GroupContractPosition;
+use bincode::{Decode, Encode};
+use platform_value::Identifier;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Who is allowed to spend tokens on behalf of their owner.
+#[derive(
+    Serialize, Deserialize, Encode, Decode, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum TokenAllowanceSpender {
+    /// A single identity spends the allowance by itself
+    Identity(Identifier),
+    /// A group of the token contract spends the allowance through group actions
+    Group(GroupContractPosition),
+}
+
+impl TokenAllowanceSpender {
+    const IDENTITY_KEY_PREFIX: u8 = 0;
+    const GROUP_KEY_PREFIX: u8 = 1;
+
+    /// The key under which an allowance for this spender is stored.
+    pub fn to_key(&self) -> Vec<u8> {
+        match self {
+            TokenAllowanceSpender::Identity(identity_id) => {
+                let mut key = Vec::with_capacity(33);
+                key.push(Self::IDENTITY_KEY_PREFIX);
+                key.extend_from_slice(identity_id.as_slice());
+                key
+            }
+            TokenAllowanceSpender::Group(group_contract_position) => {
+                let mut key = Vec::with_capacity(3);
+                key.push(Self::GROUP_KEY_PREFIX);
+                key.extend_from_slice(&group_contract_position.to_be_bytes());
+                key
+            }
+        }
+    }
+
+    /// The spender the storage key belongs to, if it is a valid key.
+    pub fn from_key(key: &[u8]) -> Option<Self> {
+        match key.split_first() {
+            Some((&Self::IDENTITY_KEY_PREFIX, identity_id)) => Identifier::from_bytes(identity_id)
+                .ok()
+                .map(TokenAllowanceSpender::Identity),
+            Some((&Self::GROUP_KEY_PREFIX, group_contract_position)) => {
+                let group_contract_position: [u8; 2] = group_contract_position.try_into().ok()?;
+                Some(TokenAllowanceSpender::Group(
+                    GroupContractPosition::from_be_bytes(group_contract_position),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TokenAllowanceSpender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenAllowanceSpender::Identity(identity_id) => write!(f, "identity {}", identity_id),
+            TokenAllowanceSpender::Group(group_contract_position) => {
+                write!(f, "group at position {}", group_contract_position)
+            }
+        }
+    }
+}
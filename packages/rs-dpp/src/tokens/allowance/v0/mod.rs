@@ -0,0 +1,66 @@
+use crate::balances::credits::TokenAmount;
+use crate::prelude::TimestampMillis;
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+use bincode::{Decode, Encode};
+use platform_value::Identifier;
+
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Eq)]
+/// Tokens an owner allows a spender to transfer on their behalf
+pub struct TokenAllowanceV0 {
+    /// The token the allowance is for
+    pub token_id: Identifier,
+    /// The identity holding the tokens
+    pub owner_id: Identifier,
+    /// Who can transfer the tokens of the owner
+    pub spender: TokenAllowanceSpender,
+    /// The amount of tokens the spender can still transfer
+    pub amount: TokenAmount,
+    /// When the allowance stops being usable, if ever
+    pub expires_at: Option<TimestampMillis>,
+}
+
+pub trait TokenAllowanceV0Accessors {
+    /// Gets the token the allowance is for.
+    fn token_id(&self) -> Identifier;
+
+    /// Gets the identity holding the tokens.
+    fn owner_id(&self) -> Identifier;
+
+    /// Gets who can transfer the tokens of the owner.
+    fn spender(&self) -> TokenAllowanceSpender;
+
+    /// Gets the amount of tokens the spender can still transfer.
+    fn amount(&self) -> TokenAmount;
+
+    /// Sets the amount of tokens the spender can still transfer.
+    fn set_amount(&mut self, amount: TokenAmount);
+
+    /// Gets when the allowance expires.
+    fn expires_at(&self) -> Option<TimestampMillis>;
+}
+
+impl TokenAllowanceV0Accessors for TokenAllowanceV0 {
+    fn token_id(&self) -> Identifier {
+        self.token_id
+    }
+
+    fn owner_id(&self) -> Identifier {
+        self.owner_id
+    }
+
+    fn spender(&self) -> TokenAllowanceSpender {
+        self.spender
+    }
+
+    fn amount(&self) -> TokenAmount {
+        self.amount
+    }
+
+    fn set_amount(&mut self, amount: TokenAmount) {
+        self.amount = amount;
+    }
+
+    fn expires_at(&self) -> Option<TimestampMillis> {
+        self.expires_at
+    }
+}
@@ -1,7 +1,68 @@
+use crate::data_contract::TokenContractPosition;
+use crate::tokens::calculate_token_id;
+use bincode::{Decode, Encode};
 use platform_value::Identifier;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+/// A currency a token can be traded against on the platform order book.
+#[derive(
+    Serialize,
+    Deserialize,
+    Decode,
+    Encode,
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
+#[serde(rename_all = "camelCase")]
 pub enum AllowedCurrency {
+    /// Trading against Dash credits.
+    #[default]
     TradingInDash,
-    OnContract(Identifier, String),
+    /// Trading against another token, identified by its contract and position in that contract.
+    OnContract(Identifier, TokenContractPosition),
+}
+
+impl AllowedCurrency {
+    /// The token id of the quote currency, `None` when trading in Dash credits.
+    pub fn token_id(&self) -> Option<Identifier> {
+        match self {
+            AllowedCurrency::TradingInDash => None,
+            AllowedCurrency::OnContract(contract_id, token_position) => Some(Identifier::new(
+                calculate_token_id(contract_id.as_bytes(), *token_position),
+            )),
+        }
+    }
+
+    /// A stable byte key identifying the currency, used to separate order books in storage.
+    ///
+    /// Dash credits are `[0]`, tokens are `[1]` followed by the 32 byte token id.
+    pub fn storage_key(&self) -> Vec<u8> {
+        match self.token_id() {
+            None => vec![0],
+            Some(token_id) => {
+                let mut key = Vec::with_capacity(33);
+                key.push(1);
+                key.extend_from_slice(token_id.as_slice());
+                key
+            }
+        }
+    }
+}
+
+impl fmt::Display for AllowedCurrency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AllowedCurrency::TradingInDash => write!(f, "Dash"),
+            AllowedCurrency::OnContract(contract_id, token_position) => {
+                write!(f, "token {} of contract {}", token_position, contract_id)
+            }
+        }
+    }
 }
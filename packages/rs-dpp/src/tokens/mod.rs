@@ -4,17 +4,20 @@ use crate::prelude::{
 };
 use crate::util::hash::hash_double;
 
+pub mod allowance;
 pub mod allowed_currency;
 pub mod contract_info;
 pub mod emergency_action;
 pub mod errors;
 pub mod gas_fees_paid_by;
 pub mod info;
+pub mod order_book;
 pub mod status;
 pub mod token_amount_on_contract_token;
 pub mod token_event;
 pub mod token_payment_info;
 pub mod token_pricing_schedule;
+pub mod vesting;
 
 pub const MAX_TOKEN_NOTE_LEN: usize = 2048;
 pub type SharedEncryptedNote = (SenderKeyIndex, RecipientKeyIndex, Vec<u8>);
@@ -0,0 +1,68 @@
+use crate::balances::credits::TokenAmount;
+use crate::prelude::TimestampMillis;
+use crate::tokens::allowed_currency::AllowedCurrency;
+use crate::tokens::order_book::v0::TokenOrderV0Accessors;
+use crate::tokens::order_book::{TokenOrder, TokenOrderSide};
+use platform_value::Identifier;
+
+impl TokenOrderV0Accessors for TokenOrder {
+    fn id(&self) -> Identifier {
+        match self {
+            TokenOrder::V0(order) => order.id(),
+        }
+    }
+
+    fn owner_id(&self) -> Identifier {
+        match self {
+            TokenOrder::V0(order) => order.owner_id(),
+        }
+    }
+
+    fn token_id(&self) -> Identifier {
+        match self {
+            TokenOrder::V0(order) => order.token_id(),
+        }
+    }
+
+    fn side(&self) -> TokenOrderSide {
+        match self {
+            TokenOrder::V0(order) => order.side(),
+        }
+    }
+
+    fn quote_currency(&self) -> AllowedCurrency {
+        match self {
+            TokenOrder::V0(order) => order.quote_currency(),
+        }
+    }
+
+    fn price_per_token(&self) -> u64 {
+        match self {
+            TokenOrder::V0(order) => order.price_per_token(),
+        }
+    }
+
+    fn original_amount(&self) -> TokenAmount {
+        match self {
+            TokenOrder::V0(order) => order.original_amount(),
+        }
+    }
+
+    fn remaining_amount(&self) -> TokenAmount {
+        match self {
+            TokenOrder::V0(order) => order.remaining_amount(),
+        }
+    }
+
+    fn set_remaining_amount(&mut self, remaining_amount: TokenAmount) {
+        match self {
+            TokenOrder::V0(order) => order.set_remaining_amount(remaining_amount),
+        }
+    }
+
+    fn created_at(&self) -> TimestampMillis {
+        match self {
+            TokenOrder::V0(order) => order.created_at(),
+        }
+    }
+}
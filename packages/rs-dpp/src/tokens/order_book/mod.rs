@@ -0,0 +1,154 @@
+use crate::balances::credits::TokenAmount;
+use crate::prelude::{IdentityNonce, TimestampMillis};
+use crate::tokens::allowed_currency::AllowedCurrency;
+use crate::tokens::order_book::v0::TokenOrderV0;
+use crate::util::hash::hash_double;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use platform_version::version::PlatformVersion;
+use platform_versioning::PlatformVersioned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+mod methods;
+pub mod v0;
+
+/// The side of a limit order on the token order book.
+#[derive(
+    Serialize,
+    Deserialize,
+    Decode,
+    Encode,
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum TokenOrderSide {
+    /// Buying the token, paying with the quote currency.
+    #[default]
+    Buy = 0,
+    /// Selling the token, receiving the quote currency.
+    Sell = 1,
+}
+
+impl TokenOrderSide {
+    /// The side an order has to be on to match an order on this side.
+    pub fn opposite(&self) -> Self {
+        match self {
+            TokenOrderSide::Buy => TokenOrderSide::Sell,
+            TokenOrderSide::Sell => TokenOrderSide::Buy,
+        }
+    }
+
+    /// Whether an order on this side with `limit_price` accepts trading at `price`.
+    pub fn accepts_price(&self, limit_price: u64, price: u64) -> bool {
+        match self {
+            TokenOrderSide::Buy => price <= limit_price,
+            TokenOrderSide::Sell => price >= limit_price,
+        }
+    }
+}
+
+impl fmt::Display for TokenOrderSide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenOrderSide::Buy => write!(f, "Buy"),
+            TokenOrderSide::Sell => write!(f, "Sell"),
+        }
+    }
+}
+
+/// A resting limit order on the token order book.
+///
+/// While an order rests on the book, its owner's side of the trade is held in escrow:
+/// the remaining tokens for sell orders, and the remaining amount multiplied by the
+/// price per token in the quote currency for buy orders.
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformDeserialize,
+    PlatformSerialize,
+    PlatformVersioned,
+    From,
+    PartialEq,
+    Eq,
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+pub enum TokenOrder {
+    V0(TokenOrderV0),
+}
+
+impl TokenOrder {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Identifier,
+        owner_id: Identifier,
+        token_id: Identifier,
+        side: TokenOrderSide,
+        quote_currency: AllowedCurrency,
+        price_per_token: u64,
+        amount: TokenAmount,
+        created_at: TimestampMillis,
+        platform_version: &PlatformVersion,
+    ) -> Result<Self, ProtocolError> {
+        match platform_version
+            .dpp
+            .token_versions
+            .token_order_default_structure_version
+        {
+            0 => Ok(TokenOrder::V0(TokenOrderV0 {
+                id,
+                owner_id,
+                token_id,
+                side,
+                quote_currency,
+                price_per_token,
+                original_amount: amount,
+                remaining_amount: amount,
+                created_at,
+            })),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "TokenOrder::new".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+
+    /// Calculates the id of an order placed by `owner_id` on the book of `token_id`.
+    ///
+    /// The identity contract nonce of the placing transition makes the id unique.
+    pub fn calculate_order_id(
+        owner_id: &Identifier,
+        token_id: &Identifier,
+        identity_contract_nonce: IdentityNonce,
+    ) -> Identifier {
+        let mut bytes = b"token_order".to_vec();
+        bytes.extend_from_slice(owner_id.as_slice());
+        bytes.extend_from_slice(token_id.as_slice());
+        bytes.extend_from_slice(&identity_contract_nonce.to_be_bytes());
+        Identifier::new(hash_double(bytes))
+    }
+
+    /// The identity holding the escrowed funds of all resting orders on the book of `token_id`.
+    ///
+    /// No keys exist for this identity, funds only leave it through order matching and
+    /// cancellation.
+    pub fn escrow_identity_id(token_id: &Identifier) -> Identifier {
+        let mut bytes = b"token_order_book_escrow".to_vec();
+        bytes.extend_from_slice(token_id.as_slice());
+        Identifier::new(hash_double(bytes))
+    }
+}
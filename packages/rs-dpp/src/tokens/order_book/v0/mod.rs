@@ -0,0 +1,103 @@
+use crate::balances::credits::TokenAmount;
+use crate::prelude::TimestampMillis;
+use crate::tokens::allowed_currency::AllowedCurrency;
+use crate::tokens::order_book::TokenOrderSide;
+use bincode::{Decode, Encode};
+use platform_value::Identifier;
+
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Eq)]
+/// A resting limit order
+pub struct TokenOrderV0 {
+    /// The id of the order
+    pub id: Identifier,
+    /// The identity that placed the order
+    pub owner_id: Identifier,
+    /// The token being bought or sold
+    pub token_id: Identifier,
+    /// Whether the order buys or sells the token
+    pub side: TokenOrderSide,
+    /// The currency the token is priced in
+    pub quote_currency: AllowedCurrency,
+    /// The limit price of a single token in the smallest unit of the quote currency
+    pub price_per_token: u64,
+    /// The amount of tokens the order was placed for
+    pub original_amount: TokenAmount,
+    /// The amount of tokens that have not been filled yet
+    pub remaining_amount: TokenAmount,
+    /// When the order was placed
+    pub created_at: TimestampMillis,
+}
+
+pub trait TokenOrderV0Accessors {
+    /// Gets the id of the order.
+    fn id(&self) -> Identifier;
+
+    /// Gets the identity that placed the order.
+    fn owner_id(&self) -> Identifier;
+
+    /// Gets the token being traded.
+    fn token_id(&self) -> Identifier;
+
+    /// Gets the side of the order.
+    fn side(&self) -> TokenOrderSide;
+
+    /// Gets the currency the token is priced in.
+    fn quote_currency(&self) -> AllowedCurrency;
+
+    /// Gets the limit price of a single token.
+    fn price_per_token(&self) -> u64;
+
+    /// Gets the amount the order was placed for.
+    fn original_amount(&self) -> TokenAmount;
+
+    /// Gets the amount that has not been filled yet.
+    fn remaining_amount(&self) -> TokenAmount;
+
+    /// Sets the amount that has not been filled yet.
+    fn set_remaining_amount(&mut self, remaining_amount: TokenAmount);
+
+    /// Gets when the order was placed.
+    fn created_at(&self) -> TimestampMillis;
+}
+
+impl TokenOrderV0Accessors for TokenOrderV0 {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn owner_id(&self) -> Identifier {
+        self.owner_id
+    }
+
+    fn token_id(&self) -> Identifier {
+        self.token_id
+    }
+
+    fn side(&self) -> TokenOrderSide {
+        self.side
+    }
+
+    fn quote_currency(&self) -> AllowedCurrency {
+        self.quote_currency
+    }
+
+    fn price_per_token(&self) -> u64 {
+        self.price_per_token
+    }
+
+    fn original_amount(&self) -> TokenAmount {
+        self.original_amount
+    }
+
+    fn remaining_amount(&self) -> TokenAmount {
+        self.remaining_amount
+    }
+
+    fn set_remaining_amount(&mut self, remaining_amount: TokenAmount) {
+        self.remaining_amount = remaining_amount;
+    }
+
+    fn created_at(&self) -> TimestampMillis {
+        self.created_at
+    }
+}
@@ -5,13 +5,15 @@ use crate::data_contract::associated_token::token_configuration_item::TokenConfi
 use crate::data_contract::associated_token::token_distribution_key::TokenDistributionTypeWithResolvedRecipient;
 use crate::data_contract::associated_token::token_perpetual_distribution::distribution_recipient::TokenDistributionResolvedRecipient;
 use crate::data_contract::document_type::DocumentTypeRef;
-use crate::document::{Document, DocumentV0};
+use crate::document::{Document, DocumentV0, DocumentV0Getters};
 use crate::fee::Credits;
 use crate::prelude::{
     DataContract, DerivationEncryptionKeyIndex, IdentityNonce, RootEncryptionKeyIndex,
+    TimestampMillis,
 };
 use bincode::{Decode, Encode};
 use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::btreemap_extensions::BTreeValueMapHelper;
 use platform_value::Identifier;
 use platform_version::version::PlatformVersion;
 use std::collections::BTreeMap;
@@ -24,8 +26,11 @@ pub type TokenEventPersonalEncryptedNote = Option<(
     DerivationEncryptionKeyIndex,
     Vec<u8>,
 )>;
-use crate::serialization::PlatformSerializableWithPlatformVersion;
+use crate::serialization::{PlatformDeserializable, PlatformSerializableWithPlatformVersion};
+use crate::tokens::allowance::spender::TokenAllowanceSpender;
+use crate::tokens::allowed_currency::AllowedCurrency;
 use crate::tokens::emergency_action::TokenEmergencyAction;
+use crate::tokens::order_book::TokenOrderSide;
 use crate::tokens::token_pricing_schedule::TokenPricingSchedule;
 use crate::tokens::SharedEncryptedNote;
 use crate::ProtocolError;
@@ -42,6 +47,15 @@ pub type PurchaserIdentifier = Identifier;
 /// Alias representing the identity whose tokens are subject to freezing or unfreezing.
 pub type FrozenIdentifier = Identifier;
 
+/// Alias representing a limit order on the token order book.
+pub type OrderIdentifier = Identifier;
+
+/// Alias representing tokens locked for a recipient under a vesting schedule.
+pub type VestingIdentifier = Identifier;
+
+/// Alias representing the identity whose allowance is spent in a transfer-from.
+pub type AllowanceOwnerIdentifier = Identifier;
+
 /// Represents a recorded token-related operation for use in historical documents and group actions.
 ///
 /// `TokenEvent` is designed to encapsulate a single logical token operation,
@@ -140,6 +154,57 @@ pub enum TokenEvent {
     /// - `TokenAmount`: The amount of tokens purchased.
     /// - `Credits`: The number of credits paid.
     DirectPurchase(TokenAmount, Credits),
+
+    /// Event representing a limit order being placed on the token order book.
+    ///
+    /// - `TokenOrderSide`: Whether the order buys or sells the token.
+    /// - `AllowedCurrency`: The currency the order is quoted in.
+    /// - `TokenAmount`: The amount of tokens of the order.
+    /// - `u64`: The limit price per token in the quote currency.
+    PlaceOrder(TokenOrderSide, AllowedCurrency, TokenAmount, u64),
+
+    /// Event representing a resting limit order being cancelled by its owner.
+    ///
+    /// - `OrderIdentifier`: The cancelled order.
+    CancelOrder(OrderIdentifier),
+
+    /// Event representing tokens being locked for a recipient under a vesting schedule.
+    ///
+    /// - `RecipientIdentifier`: The identity that can claim the tokens as they vest.
+    /// - `TokenAmount`: The amount of tokens locked.
+    /// - `TokenEventPublicNote`: Optional note.
+    VestingTransfer(RecipientIdentifier, TokenAmount, TokenEventPublicNote),
+
+    /// Event representing a recipient claiming vested tokens.
+    ///
+    /// - `VestingIdentifier`: The vesting claimed from.
+    ClaimVested(VestingIdentifier),
+
+    /// Event representing an owner allowing a spender to transfer tokens on their behalf.
+    ///
+    /// - `TokenAllowanceSpender`: The identity or group allowed to spend the tokens.
+    /// - `TokenAmount`: The allowed amount, 0 revokes the allowance.
+    /// - `Option<TimestampMillis>`: When the allowance expires, if ever.
+    /// - `TokenEventPublicNote`: Optional note.
+    Approve(
+        TokenAllowanceSpender,
+        TokenAmount,
+        Option<TimestampMillis>,
+        TokenEventPublicNote,
+    ),
+
+    /// Event representing a spender transferring tokens using an allowance.
+    ///
+    /// - `AllowanceOwnerIdentifier`: The identity the tokens are taken from.
+    /// - `RecipientIdentifier`: The identity receiving the tokens.
+    /// - `TokenAmount`: The amount of tokens transferred.
+    /// - `TokenEventPublicNote`: Optional note.
+    TransferFrom(
+        AllowanceOwnerIdentifier,
+        RecipientIdentifier,
+        TokenAmount,
+        TokenEventPublicNote,
+    ),
 }
 
 impl fmt::Display for TokenEvent {
@@ -197,6 +262,41 @@ impl fmt::Display for TokenEvent {
             TokenEvent::DirectPurchase(amount, credits) => {
                 write!(f, "Direct purchase of {} for {} credits", amount, credits)
             }
+            TokenEvent::PlaceOrder(side, quote_currency, amount, price_per_token) => {
+                write!(
+                    f,
+                    "{} order of {} at {} per token in {}",
+                    side, amount, price_per_token, quote_currency
+                )
+            }
+            TokenEvent::CancelOrder(order_id) => write!(f, "Cancel order {}", order_id),
+            TokenEvent::VestingTransfer(recipient, amount, note) => {
+                write!(f, "Vesting transfer of {} to {}", amount, recipient)?;
+                if let Some(n) = note {
+                    write!(f, " (note: {})", n)?;
+                }
+                Ok(())
+            }
+            TokenEvent::ClaimVested(vesting_id) => {
+                write!(f, "Claim vested tokens of {}", vesting_id)
+            }
+            TokenEvent::Approve(spender, amount, expires_at, note) => {
+                write!(f, "Approve {} tokens for {}", amount, spender)?;
+                if let Some(expires_at) = expires_at {
+                    write!(f, " until {}", expires_at)?;
+                }
+                if let Some(n) = note {
+                    write!(f, " (note: {})", n)?;
+                }
+                Ok(())
+            }
+            TokenEvent::TransferFrom(from_id, recipient, amount, note) => {
+                write!(f, "Transfer {} from {} to {}", amount, from_id, recipient)?;
+                if let Some(n) = note {
+                    write!(f, " (note: {})", n)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -222,6 +322,12 @@ impl TokenEvent {
             TokenEvent::ConfigUpdate(..) => "configUpdate",
             TokenEvent::DirectPurchase(..) => "directPurchase",
             TokenEvent::ChangePriceForDirectPurchase(..) => "directPricing",
+            TokenEvent::PlaceOrder(..) => "placeOrder",
+            TokenEvent::CancelOrder(..) => "cancelOrder",
+            TokenEvent::VestingTransfer(..) => "vestingTransfer",
+            TokenEvent::ClaimVested(..) => "claimVested",
+            TokenEvent::Approve(..) => "approve",
+            TokenEvent::TransferFrom(..) => "transferFrom",
         }
     }
 
@@ -237,7 +343,10 @@ impl TokenEvent {
             | TokenEvent::Claim(_, _, Some(note))
             | TokenEvent::EmergencyAction(_, Some(note))
             | TokenEvent::ConfigUpdate(_, Some(note))
-            | TokenEvent::ChangePriceForDirectPurchase(_, Some(note)) => Some(note),
+            | TokenEvent::ChangePriceForDirectPurchase(_, Some(note))
+            | TokenEvent::VestingTransfer(_, _, Some(note))
+            | TokenEvent::Approve(_, _, _, Some(note))
+            | TokenEvent::TransferFrom(_, _, _, Some(note)) => Some(note),
             _ => None,
         }
     }
@@ -437,6 +546,63 @@ impl TokenEvent {
                 ("tokenAmount".to_string(), amount.into()),
                 ("purchaseCost".to_string(), total_cost.into()),
             ]),
+            TokenEvent::PlaceOrder(side, quote_currency, amount, price_per_token) => {
+                BTreeMap::from([
+                    ("tokenId".to_string(), token_id.into()),
+                    ("side".to_string(), (side as u8).into()),
+                    (
+                        "quoteCurrency".to_string(),
+                        quote_currency.storage_key().into(),
+                    ),
+                    ("amount".to_string(), amount.into()),
+                    ("pricePerToken".to_string(), price_per_token.into()),
+                ])
+            }
+            TokenEvent::CancelOrder(order_id) => BTreeMap::from([
+                ("tokenId".to_string(), token_id.into()),
+                ("orderId".to_string(), order_id.into()),
+            ]),
+            TokenEvent::VestingTransfer(recipient_id, amount, public_note) => {
+                let mut properties = BTreeMap::from([
+                    ("tokenId".to_string(), token_id.into()),
+                    ("recipientId".to_string(), recipient_id.into()),
+                    ("amount".to_string(), amount.into()),
+                ]);
+                if let Some(note) = public_note {
+                    properties.insert("note".to_string(), note.into());
+                }
+                properties
+            }
+            TokenEvent::ClaimVested(vesting_id) => BTreeMap::from([
+                ("tokenId".to_string(), token_id.into()),
+                ("vestingId".to_string(), vesting_id.into()),
+            ]),
+            TokenEvent::Approve(spender, amount, expires_at, public_note) => {
+                let mut properties = BTreeMap::from([
+                    ("tokenId".to_string(), token_id.into()),
+                    ("spender".to_string(), spender.to_key().into()),
+                    ("amount".to_string(), amount.into()),
+                ]);
+                if let Some(expires_at) = expires_at {
+                    properties.insert("expiresAt".to_string(), expires_at.into());
+                }
+                if let Some(note) = public_note {
+                    properties.insert("note".to_string(), note.into());
+                }
+                properties
+            }
+            TokenEvent::TransferFrom(from_id, recipient_id, amount, public_note) => {
+                let mut properties = BTreeMap::from([
+                    ("tokenId".to_string(), token_id.into()),
+                    ("fromId".to_string(), from_id.into()),
+                    ("recipientId".to_string(), recipient_id.into()),
+                    ("amount".to_string(), amount.into()),
+                ]);
+                if let Some(note) = public_note {
+                    properties.insert("note".to_string(), note.into());
+                }
+                properties
+            }
         };
 
         let document: Document = DocumentV0 {
@@ -459,4 +625,166 @@ impl TokenEvent {
 
         Ok(document)
     }
+
+    /// Rebuilds the event recorded in a historical document of the token history contract.
+    ///
+    /// This is the reverse of [`TokenEvent::build_historical_document_owned`], the document type
+    /// name tells which kind of event the document holds. Order placements can not be rebuilt as
+    /// their document only keeps the storage key of the quote currency.
+    pub fn from_historical_document(
+        document_type_name: &str,
+        document: &Document,
+    ) -> Result<Self, ProtocolError> {
+        let properties = document.properties();
+
+        let event = match document_type_name {
+            "mint" => TokenEvent::Mint(
+                properties.get_integer("amount")?,
+                properties.get_identifier("recipientId")?,
+                properties.get_optional_string("note")?,
+            ),
+            "burn" => TokenEvent::Burn(
+                properties.get_integer("amount")?,
+                properties.get_identifier("burnFromId")?,
+                properties.get_optional_string("note")?,
+            ),
+            "freeze" => TokenEvent::Freeze(
+                properties.get_identifier("frozenIdentityId")?,
+                properties.get_optional_string("note")?,
+            ),
+            "unfreeze" => TokenEvent::Unfreeze(
+                properties.get_identifier("frozenIdentityId")?,
+                properties.get_optional_string("note")?,
+            ),
+            "destroyFrozenFunds" => TokenEvent::DestroyFrozenFunds(
+                properties.get_identifier("frozenIdentityId")?,
+                properties.get_integer("destroyedAmount")?,
+                properties.get_optional_string("note")?,
+            ),
+            "transfer" => {
+                let shared_encrypted_note = properties
+                    .get_optional_bytes("encryptedSharedNote")?
+                    .map(|note| -> Result<_, ProtocolError> {
+                        Ok((
+                            properties.get_integer("senderKeyIndex")?,
+                            properties.get_integer("recipientKeyIndex")?,
+                            note,
+                        ))
+                    })
+                    .transpose()?;
+                let personal_encrypted_note = properties
+                    .get_optional_bytes("encryptedPersonalNote")?
+                    .map(|note| -> Result<_, ProtocolError> {
+                        Ok((
+                            properties.get_integer("rootEncryptionKeyIndex")?,
+                            properties.get_integer("derivationEncryptionKeyIndex")?,
+                            note,
+                        ))
+                    })
+                    .transpose()?;
+                TokenEvent::Transfer(
+                    properties.get_identifier("toIdentityId")?,
+                    properties.get_optional_string("publicNote")?,
+                    shared_encrypted_note,
+                    personal_encrypted_note,
+                    properties.get_integer("amount")?,
+                )
+            }
+            "claim" => {
+                let recipient_id = properties.get_identifier("recipientId")?;
+                let distribution_type: u8 = properties.get_integer("distributionType")?;
+                let recipient_type: u8 = properties.get_integer("recipientType")?;
+                let recipient = match (distribution_type, recipient_type) {
+                    (0, _) => {
+                        TokenDistributionTypeWithResolvedRecipient::PreProgrammed(recipient_id)
+                    }
+                    (1, 0) => TokenDistributionTypeWithResolvedRecipient::Perpetual(
+                        TokenDistributionResolvedRecipient::ContractOwnerIdentity(recipient_id),
+                    ),
+                    (1, 1) => TokenDistributionTypeWithResolvedRecipient::Perpetual(
+                        TokenDistributionResolvedRecipient::Identity(recipient_id),
+                    ),
+                    (1, 2) => TokenDistributionTypeWithResolvedRecipient::Perpetual(
+                        TokenDistributionResolvedRecipient::Evonode(recipient_id),
+                    ),
+                    _ => {
+                        return Err(ProtocolError::CorruptedSerialization(format!(
+                            "unknown claim distribution type {} with recipient type {}",
+                            distribution_type, recipient_type
+                        )))
+                    }
+                };
+                TokenEvent::Claim(
+                    recipient,
+                    properties.get_integer("amount")?,
+                    properties.get_optional_string("note")?,
+                )
+            }
+            "emergencyAction" => {
+                let action = match properties.get_integer::<u8>("action")? {
+                    0 => TokenEmergencyAction::Pause,
+                    1 => TokenEmergencyAction::Resume,
+                    action => {
+                        return Err(ProtocolError::CorruptedSerialization(format!(
+                            "unknown token emergency action {}",
+                            action
+                        )))
+                    }
+                };
+                TokenEvent::EmergencyAction(action, properties.get_optional_string("note")?)
+            }
+            "configUpdate" => TokenEvent::ConfigUpdate(
+                TokenConfigurationChangeItem::deserialize_from_bytes(
+                    &properties.get_bytes("changeItem")?,
+                )?,
+                properties.get_optional_string("note")?,
+            ),
+            "directPricing" => TokenEvent::ChangePriceForDirectPurchase(
+                properties
+                    .get_optional_bytes("priceSchedule")?
+                    .map(|bytes| TokenPricingSchedule::deserialize_from_bytes(&bytes))
+                    .transpose()?,
+                properties.get_optional_string("note")?,
+            ),
+            "directPurchase" => TokenEvent::DirectPurchase(
+                properties.get_integer("tokenAmount")?,
+                properties.get_integer("purchaseCost")?,
+            ),
+            "cancelOrder" => TokenEvent::CancelOrder(properties.get_identifier("orderId")?),
+            "vestingTransfer" => TokenEvent::VestingTransfer(
+                properties.get_identifier("recipientId")?,
+                properties.get_integer("amount")?,
+                properties.get_optional_string("note")?,
+            ),
+            "claimVested" => TokenEvent::ClaimVested(properties.get_identifier("vestingId")?),
+            "approve" => {
+                let spender_key = properties.get_bytes("spender")?;
+                let spender = TokenAllowanceSpender::from_key(&spender_key).ok_or(
+                    ProtocolError::CorruptedSerialization(
+                        "invalid allowance spender in approve history document".to_string(),
+                    ),
+                )?;
+                TokenEvent::Approve(
+                    spender,
+                    properties.get_integer("amount")?,
+                    properties.get_optional_integer("expiresAt")?,
+                    properties.get_optional_string("note")?,
+                )
+            }
+            "transferFrom" => TokenEvent::TransferFrom(
+                properties.get_identifier("fromId")?,
+                properties.get_identifier("recipientId")?,
+                properties.get_integer("amount")?,
+                properties.get_optional_string("note")?,
+            ),
+            document_type_name => {
+                return Err(ProtocolError::NotSupported(format!(
+                    "token events can not be rebuilt from {} history documents",
+                    document_type_name
+                )))
+            }
+        };
+
+        Ok(event)
+    }
 }
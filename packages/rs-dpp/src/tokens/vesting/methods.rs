@@ -0,0 +1,62 @@
+use crate::balances::credits::TokenAmount;
+use crate::prelude::TimestampMillis;
+use crate::tokens::vesting::schedule::TokenVestingSchedule;
+use crate::tokens::vesting::v0::TokenVestingV0Accessors;
+use crate::tokens::vesting::TokenVesting;
+use platform_value::Identifier;
+
+impl TokenVestingV0Accessors for TokenVesting {
+    fn id(&self) -> Identifier {
+        match self {
+            TokenVesting::V0(vesting) => vesting.id(),
+        }
+    }
+
+    fn token_id(&self) -> Identifier {
+        match self {
+            TokenVesting::V0(vesting) => vesting.token_id(),
+        }
+    }
+
+    fn grantor_id(&self) -> Identifier {
+        match self {
+            TokenVesting::V0(vesting) => vesting.grantor_id(),
+        }
+    }
+
+    fn recipient_id(&self) -> Identifier {
+        match self {
+            TokenVesting::V0(vesting) => vesting.recipient_id(),
+        }
+    }
+
+    fn total_amount(&self) -> TokenAmount {
+        match self {
+            TokenVesting::V0(vesting) => vesting.total_amount(),
+        }
+    }
+
+    fn claimed_amount(&self) -> TokenAmount {
+        match self {
+            TokenVesting::V0(vesting) => vesting.claimed_amount(),
+        }
+    }
+
+    fn set_claimed_amount(&mut self, claimed_amount: TokenAmount) {
+        match self {
+            TokenVesting::V0(vesting) => vesting.set_claimed_amount(claimed_amount),
+        }
+    }
+
+    fn schedule(&self) -> &TokenVestingSchedule {
+        match self {
+            TokenVesting::V0(vesting) => vesting.schedule(),
+        }
+    }
+
+    fn start_moment(&self) -> TimestampMillis {
+        match self {
+            TokenVesting::V0(vesting) => vesting.start_moment(),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::balances::credits::TokenAmount;
+use crate::prelude::{IdentityNonce, TimestampMillis};
+use crate::tokens::vesting::schedule::TokenVestingSchedule;
+use crate::tokens::vesting::v0::{TokenVestingV0, TokenVestingV0Accessors};
+use crate::util::hash::hash_double;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use platform_version::version::PlatformVersion;
+use platform_versioning::PlatformVersioned;
+
+mod methods;
+pub mod schedule;
+pub mod v0;
+
+/// Tokens locked for a recipient by a vesting transfer.
+///
+/// The locked tokens are held in escrow until the recipient claims them. The recipient
+/// can claim whatever has vested according to the schedule and has not been claimed yet.
+#[derive(
+    Debug,
+    Clone,
+    Encode,
+    Decode,
+    PlatformDeserialize,
+    PlatformSerialize,
+    PlatformVersioned,
+    From,
+    PartialEq,
+    Eq,
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+pub enum TokenVesting {
+    V0(TokenVestingV0),
+}
+
+impl TokenVesting {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Identifier,
+        token_id: Identifier,
+        grantor_id: Identifier,
+        recipient_id: Identifier,
+        amount: TokenAmount,
+        schedule: TokenVestingSchedule,
+        start_moment: TimestampMillis,
+        platform_version: &PlatformVersion,
+    ) -> Result<Self, ProtocolError> {
+        match platform_version
+            .dpp
+            .token_versions
+            .token_vesting_default_structure_version
+        {
+            0 => Ok(TokenVesting::V0(TokenVestingV0 {
+                id,
+                token_id,
+                grantor_id,
+                recipient_id,
+                total_amount: amount,
+                claimed_amount: 0,
+                schedule,
+                start_moment,
+            })),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "TokenVesting::new".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+
+    /// The amount the recipient can claim at `current_moment`.
+    pub fn claimable_amount(
+        &self,
+        current_moment: TimestampMillis,
+    ) -> Result<TokenAmount, ProtocolError> {
+        let vested_amount = self.schedule().vested_amount(
+            self.total_amount(),
+            self.start_moment(),
+            current_moment,
+        )?;
+        Ok(vested_amount.saturating_sub(self.claimed_amount()))
+    }
+
+    /// Whether every locked token has been claimed.
+    pub fn is_fully_claimed(&self) -> bool {
+        self.claimed_amount() >= self.total_amount()
+    }
+
+    /// Calculates the id of a vesting created by `grantor_id` for `token_id`.
+    ///
+    /// The identity contract nonce of the vesting transfer makes the id unique.
+    pub fn calculate_vesting_id(
+        grantor_id: &Identifier,
+        token_id: &Identifier,
+        identity_contract_nonce: IdentityNonce,
+    ) -> Identifier {
+        let mut bytes = b"token_vesting".to_vec();
+        bytes.extend_from_slice(grantor_id.as_slice());
+        bytes.extend_from_slice(token_id.as_slice());
+        bytes.extend_from_slice(&identity_contract_nonce.to_be_bytes());
+        Identifier::new(hash_double(bytes))
+    }
+
+    /// The identity holding the locked tokens of all vestings of `token_id`.
+    ///
+    /// No keys exist for this identity, funds only leave it when recipients claim.
+    pub fn escrow_identity_id(token_id: &Identifier) -> Identifier {
+        let mut bytes = b"token_vesting_escrow".to_vec();
+        bytes.extend_from_slice(token_id.as_slice());
+        Identifier::new(hash_double(bytes))
+    }
+}
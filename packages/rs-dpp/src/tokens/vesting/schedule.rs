@@ -0,0 +1,146 @@
+use crate::balances::credits::TokenAmount;
+use crate::block::epoch::EpochIndex;
+use crate::data_contract::associated_token::token_perpetual_distribution::distribution_function::reward_ratio::RewardRatio;
+use crate::data_contract::associated_token::token_perpetual_distribution::distribution_function::DistributionFunction;
+use crate::data_contract::associated_token::token_perpetual_distribution::reward_distribution_moment::RewardDistributionMoment;
+use crate::prelude::TimestampMillis;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// The maximum number of release intervals a vesting schedule can span.
+///
+/// Whatever has not been released by the release function after this many intervals
+/// is considered vested. This bounds the cost of evaluating a schedule.
+pub const MAX_TOKEN_VESTING_INTERVALS: u64 = 4096;
+
+/// Describes how tokens locked by a vesting transfer become claimable over time.
+///
+/// Nothing can be claimed before the cliff has passed. From then on, every full
+/// `interval` elapsed since the start of the vesting releases the amount given by the
+/// release function for that interval, the intervals that passed during the cliff are
+/// released at once when the cliff ends. A `FixedAmount` release function gives a
+/// linear schedule.
+#[derive(Serialize, Deserialize, Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenVestingSchedule {
+    /// How long after the start nothing can be claimed, in milliseconds
+    pub cliff_duration: u64,
+    /// The length of a release interval, in milliseconds
+    pub interval: u64,
+    /// The amount released for each elapsed interval, evaluated with the index of the
+    /// interval starting at 1
+    pub release_function: DistributionFunction,
+}
+
+impl Default for TokenVestingSchedule {
+    fn default() -> Self {
+        TokenVestingSchedule {
+            cliff_duration: 0,
+            interval: 0,
+            release_function: DistributionFunction::FixedAmount { amount: 0 },
+        }
+    }
+}
+
+impl TokenVestingSchedule {
+    /// The total amount released by the release function over the first `intervals`
+    /// intervals of the schedule.
+    pub fn released_amount(&self, intervals: u64) -> Result<TokenAmount, ProtocolError> {
+        self.release_function.evaluate_interval(
+            RewardDistributionMoment::TimeBasedMoment(0),
+            RewardDistributionMoment::TimeBasedMoment(0),
+            RewardDistributionMoment::TimeBasedMoment(intervals),
+            RewardDistributionMoment::TimeBasedMoment(1),
+            None::<fn(RangeInclusive<EpochIndex>) -> Option<RewardRatio>>,
+        )
+    }
+
+    /// The part of `total_amount` that has vested at `current_moment` for a vesting that
+    /// started at `start_moment`.
+    pub fn vested_amount(
+        &self,
+        total_amount: TokenAmount,
+        start_moment: TimestampMillis,
+        current_moment: TimestampMillis,
+    ) -> Result<TokenAmount, ProtocolError> {
+        if self.interval == 0 {
+            return Err(ProtocolError::InvalidDistributionStep(
+                "vested_amount: vesting interval cannot be zero",
+            ));
+        }
+
+        if current_moment < start_moment.saturating_add(self.cliff_duration) {
+            return Ok(0);
+        }
+
+        let elapsed_intervals = (current_moment - start_moment) / self.interval;
+
+        if elapsed_intervals >= MAX_TOKEN_VESTING_INTERVALS {
+            return Ok(total_amount);
+        }
+
+        match self.released_amount(elapsed_intervals) {
+            Ok(released_amount) => Ok(released_amount.min(total_amount)),
+            // The release function went past what can be represented, so it went past the total
+            Err(ProtocolError::Overflow(_)) => Ok(total_amount),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl fmt::Display for TokenVestingSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cliff of {}ms, then {} every {}ms",
+            self.cliff_duration, self.release_function, self.interval
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linear_schedule() -> TokenVestingSchedule {
+        TokenVestingSchedule {
+            cliff_duration: 30,
+            interval: 10,
+            release_function: DistributionFunction::FixedAmount { amount: 100 },
+        }
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        let schedule = linear_schedule();
+        assert_eq!(schedule.vested_amount(1000, 5000, 5000).unwrap(), 0);
+        assert_eq!(schedule.vested_amount(1000, 5000, 5029).unwrap(), 0);
+    }
+
+    #[test]
+    fn intervals_of_the_cliff_vest_at_once() {
+        let schedule = linear_schedule();
+        assert_eq!(schedule.vested_amount(1000, 5000, 5030).unwrap(), 300);
+        assert_eq!(schedule.vested_amount(1000, 5000, 5039).unwrap(), 300);
+        assert_eq!(schedule.vested_amount(1000, 5000, 5040).unwrap(), 400);
+    }
+
+    #[test]
+    fn vested_amount_is_capped_at_the_total() {
+        let schedule = linear_schedule();
+        assert_eq!(schedule.vested_amount(1000, 5000, 5100).unwrap(), 1000);
+        assert_eq!(schedule.vested_amount(1000, 5000, u64::MAX).unwrap(), 1000);
+    }
+
+    #[test]
+    fn zero_interval_is_an_error() {
+        let schedule = TokenVestingSchedule {
+            interval: 0,
+            ..linear_schedule()
+        };
+        assert!(schedule.vested_amount(1000, 5000, 6000).is_err());
+    }
+}
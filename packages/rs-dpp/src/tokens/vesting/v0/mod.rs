@@ -0,0 +1,93 @@
+use crate::balances::credits::TokenAmount;
+use crate::prelude::TimestampMillis;
+use crate::tokens::vesting::schedule::TokenVestingSchedule;
+use bincode::{Decode, Encode};
+use platform_value::Identifier;
+
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Eq)]
+/// Tokens locked for a recipient under a vesting schedule
+pub struct TokenVestingV0 {
+    /// The id of the vesting
+    pub id: Identifier,
+    /// The token being vested
+    pub token_id: Identifier,
+    /// The identity that locked the tokens
+    pub grantor_id: Identifier,
+    /// The identity that can claim the tokens as they vest
+    pub recipient_id: Identifier,
+    /// The amount of tokens locked by the vesting transfer
+    pub total_amount: TokenAmount,
+    /// The amount of tokens the recipient has already claimed
+    pub claimed_amount: TokenAmount,
+    /// How the tokens vest over time
+    pub schedule: TokenVestingSchedule,
+    /// When the vesting transfer was executed
+    pub start_moment: TimestampMillis,
+}
+
+pub trait TokenVestingV0Accessors {
+    /// Gets the id of the vesting.
+    fn id(&self) -> Identifier;
+
+    /// Gets the token being vested.
+    fn token_id(&self) -> Identifier;
+
+    /// Gets the identity that locked the tokens.
+    fn grantor_id(&self) -> Identifier;
+
+    /// Gets the identity that can claim the tokens.
+    fn recipient_id(&self) -> Identifier;
+
+    /// Gets the amount of tokens locked by the vesting transfer.
+    fn total_amount(&self) -> TokenAmount;
+
+    /// Gets the amount of tokens already claimed.
+    fn claimed_amount(&self) -> TokenAmount;
+
+    /// Sets the amount of tokens already claimed.
+    fn set_claimed_amount(&mut self, claimed_amount: TokenAmount);
+
+    /// Gets the vesting schedule.
+    fn schedule(&self) -> &TokenVestingSchedule;
+
+    /// Gets when the vesting started.
+    fn start_moment(&self) -> TimestampMillis;
+}
+
+impl TokenVestingV0Accessors for TokenVestingV0 {
+    fn id(&self) -> Identifier {
+        self.id
+    }
+
+    fn token_id(&self) -> Identifier {
+        self.token_id
+    }
+
+    fn grantor_id(&self) -> Identifier {
+        self.grantor_id
+    }
+
+    fn recipient_id(&self) -> Identifier {
+        self.recipient_id
+    }
+
+    fn total_amount(&self) -> TokenAmount {
+        self.total_amount
+    }
+
+    fn claimed_amount(&self) -> TokenAmount {
+        self.claimed_amount
+    }
+
+    fn set_claimed_amount(&mut self, claimed_amount: TokenAmount) {
+        self.claimed_amount = claimed_amount;
+    }
+
+    fn schedule(&self) -> &TokenVestingSchedule {
+        &self.schedule
+    }
+
+    fn start_moment(&self) -> TimestampMillis {
+        self.start_moment
+    }
+}
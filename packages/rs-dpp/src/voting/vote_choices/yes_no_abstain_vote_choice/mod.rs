@@ -1,10 +1,11 @@
 use bincode::{Decode, Encode};
-#[cfg(feature = "state-transition-serde-conversion")]
+#[cfg(feature = "vote-serde-conversion")]
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[cfg_attr(
-    feature = "state-transition-serde-conversion",
+    feature = "vote-serde-conversion",
     derive(Serialize, Deserialize),
     serde(rename_all = "camelCase")
 )]
@@ -14,3 +15,13 @@ pub enum YesNoAbstainVoteChoice {
     #[default]
     ABSTAIN,
 }
+
+impl fmt::Display for YesNoAbstainVoteChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            YesNoAbstainVoteChoice::YES => write!(f, "Yes"),
+            YesNoAbstainVoteChoice::NO => write!(f, "No"),
+            YesNoAbstainVoteChoice::ABSTAIN => write!(f, "Abstain"),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+mod v0;
+
+use crate::block::block_info::BlockInfo;
+use crate::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use derive_more::From;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_version::version::PlatformVersion;
+use std::fmt;
+pub use v0::{YesNoAbstainVotePollStoredInfoV0, YesNoAbstainVotePollStoredInfoV0Getters};
+
+/// The sum of the vote strengths given to each choice of a yes/no/abstain vote poll.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Encode, Decode)]
+pub struct YesNoAbstainVoteTally {
+    pub yes: u32,
+    pub no: u32,
+    pub abstain: u32,
+}
+
+impl YesNoAbstainVoteTally {
+    /// Adds a vote of the given strength to the tally
+    pub fn add_vote(
+        &mut self,
+        vote_choice: YesNoAbstainVoteChoice,
+        strength: u8,
+    ) -> Result<(), ProtocolError> {
+        let counter = match vote_choice {
+            YesNoAbstainVoteChoice::YES => &mut self.yes,
+            YesNoAbstainVoteChoice::NO => &mut self.no,
+            YesNoAbstainVoteChoice::ABSTAIN => &mut self.abstain,
+        };
+        *counter = counter
+            .checked_add(strength as u32)
+            .ok_or(ProtocolError::Overflow("vote tally overflow"))?;
+        Ok(())
+    }
+
+    /// A poll passes when more strength voted yes than no, abstaining votes are not counted
+    pub fn passed(&self) -> bool {
+        self.yes > self.no
+    }
+}
+
+impl fmt::Display for YesNoAbstainVoteTally {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "YesNoAbstainVoteTally {{ yes: {}, no: {}, abstain: {} }}",
+            self.yes, self.no, self.abstain
+        )
+    }
+}
+
+/// Represents the stored result of a yes/no/abstain vote poll once it has ended.
+#[derive(Debug, PartialEq, Clone, From, Encode, Decode, PlatformSerialize, PlatformDeserialize)]
+#[platform_serialize(unversioned)]
+pub enum YesNoAbstainVotePollStoredInfo {
+    /// V0.
+    V0(YesNoAbstainVotePollStoredInfoV0),
+}
+
+impl fmt::Display for YesNoAbstainVotePollStoredInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            YesNoAbstainVotePollStoredInfo::V0(info) => write!(f, "V0({})", info),
+        }
+    }
+}
+
+impl YesNoAbstainVotePollStoredInfo {
+    pub fn new(
+        vote_poll: YesNoAbstainVotePoll,
+        vote_tally: YesNoAbstainVoteTally,
+        finalization_block: BlockInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<YesNoAbstainVotePollStoredInfo, ProtocolError> {
+        match platform_version
+            .dpp
+            .voting_versions
+            .yes_no_abstain_vote_poll_stored_info_version
+        {
+            0 => Ok(YesNoAbstainVotePollStoredInfoV0 {
+                vote_poll,
+                vote_tally,
+                finalization_block,
+            }
+            .into()),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "YesNoAbstainVotePollStoredInfo::new".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+}
+
+impl YesNoAbstainVotePollStoredInfoV0Getters for YesNoAbstainVotePollStoredInfo {
+    fn vote_poll(&self) -> &YesNoAbstainVotePoll {
+        match self {
+            YesNoAbstainVotePollStoredInfo::V0(v0) => v0.vote_poll(),
+        }
+    }
+
+    fn vote_tally(&self) -> YesNoAbstainVoteTally {
+        match self {
+            YesNoAbstainVotePollStoredInfo::V0(v0) => v0.vote_tally(),
+        }
+    }
+
+    fn finalization_block(&self) -> BlockInfo {
+        match self {
+            YesNoAbstainVotePollStoredInfo::V0(v0) => v0.finalization_block(),
+        }
+    }
+}
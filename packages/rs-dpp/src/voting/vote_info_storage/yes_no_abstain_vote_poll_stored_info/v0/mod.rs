@@ -0,0 +1,48 @@
+use crate::block::block_info::BlockInfo;
+use crate::voting::vote_info_storage::yes_no_abstain_vote_poll_stored_info::YesNoAbstainVoteTally;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use bincode::{Decode, Encode};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone, Default, Encode, Decode)]
+pub struct YesNoAbstainVotePollStoredInfoV0 {
+    /// The vote poll that ended
+    pub vote_poll: YesNoAbstainVotePoll,
+    /// The final tally of the votes
+    pub vote_tally: YesNoAbstainVoteTally,
+    /// The block in which the votes were tallied
+    pub finalization_block: BlockInfo,
+}
+
+impl fmt::Display for YesNoAbstainVotePollStoredInfoV0 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "YesNoAbstainVotePollStoredInfoV0 {{ vote_poll: {}, vote_tally: {}, finalization_block: {} }}",
+            self.vote_poll, self.vote_tally, self.finalization_block
+        )
+    }
+}
+
+pub trait YesNoAbstainVotePollStoredInfoV0Getters {
+    /// The vote poll that ended
+    fn vote_poll(&self) -> &YesNoAbstainVotePoll;
+    /// The final tally of the votes
+    fn vote_tally(&self) -> YesNoAbstainVoteTally;
+    /// The block in which the votes were tallied
+    fn finalization_block(&self) -> BlockInfo;
+}
+
+impl YesNoAbstainVotePollStoredInfoV0Getters for YesNoAbstainVotePollStoredInfoV0 {
+    fn vote_poll(&self) -> &YesNoAbstainVotePoll {
+        &self.vote_poll
+    }
+
+    fn vote_tally(&self) -> YesNoAbstainVoteTally {
+        self.vote_tally
+    }
+
+    fn finalization_block(&self) -> BlockInfo {
+        self.finalization_block
+    }
+}
@@ -1,4 +1,5 @@
 use crate::voting::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePoll;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
 use crate::ProtocolError;
 use bincode::{Decode, Encode};
 use derive_more::From;
@@ -9,6 +10,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 pub mod contested_document_resource_vote_poll;
+pub mod yes_no_abstain_vote_poll;
 
 #[derive(Debug, Clone, Encode, Decode, PlatformSerialize, PlatformDeserialize, PartialEq, From)]
 #[cfg_attr(
@@ -20,6 +22,7 @@ pub mod contested_document_resource_vote_poll;
 #[platform_serialize(limit = 100000)]
 pub enum VotePoll {
     ContestedDocumentResourceVotePoll(ContestedDocumentResourceVotePoll),
+    YesNoAbstainVotePoll(YesNoAbstainVotePoll),
 }
 
 impl fmt::Display for VotePoll {
@@ -28,6 +31,9 @@ impl fmt::Display for VotePoll {
             VotePoll::ContestedDocumentResourceVotePoll(poll) => {
                 write!(f, "ContestedDocumentResourceVotePoll({})", poll)
             }
+            VotePoll::YesNoAbstainVotePoll(poll) => {
+                write!(f, "YesNoAbstainVotePoll({})", poll)
+            }
         }
     }
 }
@@ -46,6 +52,8 @@ impl VotePoll {
                     contested_document_resource_vote_poll.specialized_balance_id()?,
                 ))
             }
+            // Governance polls are not prefunded, masternodes vote on them for free
+            VotePoll::YesNoAbstainVotePoll(_) => Ok(None),
         }
     }
 
@@ -54,6 +62,9 @@ impl VotePoll {
             VotePoll::ContestedDocumentResourceVotePoll(contested_document_resource_vote_poll) => {
                 contested_document_resource_vote_poll.unique_id()
             }
+            VotePoll::YesNoAbstainVotePoll(yes_no_abstain_vote_poll) => {
+                yes_no_abstain_vote_poll.unique_id()
+            }
         }
     }
 }
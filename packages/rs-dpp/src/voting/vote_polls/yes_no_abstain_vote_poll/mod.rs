@@ -0,0 +1,63 @@
+use crate::prelude::TimestampMillis;
+use crate::serialization::PlatformSerializable;
+use crate::util::hash::hash_double;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+#[cfg(feature = "vote-serde-conversion")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A poll asking masternodes a question that can be answered with yes, no or abstain.
+///
+/// The poll is fully described by its content, two polls with the same subject, question and
+/// end time are the same poll. It is created with a vote poll create transition, which also
+/// records the masternodes allowed to vote on it, and is tallied once its end time has passed.
+#[derive(Debug, Clone, Encode, Decode, PlatformSerialize, PlatformDeserialize, PartialEq)]
+#[cfg_attr(
+    feature = "vote-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[platform_serialize(unversioned)] //versioned directly, no need to use platform_version
+#[platform_serialize(limit = 100000)]
+pub struct YesNoAbstainVotePoll {
+    /// What the poll is about, this can be a contract, an identity or any other identifier
+    pub subject_id: Identifier,
+    /// The question being asked
+    pub question: String,
+    /// The time at which voting stops and the votes are tallied
+    pub end_time_ms: TimestampMillis,
+}
+
+impl fmt::Display for YesNoAbstainVotePoll {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "YesNoAbstainVotePoll {{ subject_id: {}, question: {}, end_time_ms: {} }}",
+            self.subject_id, self.question, self.end_time_ms
+        )
+    }
+}
+
+impl Default for YesNoAbstainVotePoll {
+    fn default() -> Self {
+        YesNoAbstainVotePoll {
+            subject_id: Default::default(),
+            question: "".to_string(),
+            end_time_ms: 0,
+        }
+    }
+}
+
+impl YesNoAbstainVotePoll {
+    pub fn sha256_2_hash(&self) -> Result<[u8; 32], ProtocolError> {
+        let encoded = self.serialize_to_bytes()?;
+        Ok(hash_double(encoded))
+    }
+
+    pub fn unique_id(&self) -> Result<Identifier, ProtocolError> {
+        self.sha256_2_hash().map(Identifier::new)
+    }
+}
@@ -1,7 +1,10 @@
 pub mod resource_vote;
+pub mod yes_no_abstain_vote;
 
 use crate::voting::votes::resource_vote::accessors::v0::ResourceVoteGettersV0;
 use crate::voting::votes::resource_vote::ResourceVote;
+use crate::voting::votes::yes_no_abstain_vote::accessors::v0::YesNoAbstainVoteGettersV0;
+use crate::voting::votes::yes_no_abstain_vote::YesNoAbstainVote;
 use crate::ProtocolError;
 use bincode::{Decode, Encode};
 use derive_more::From;
@@ -19,6 +22,7 @@ use serde::{Deserialize, Serialize};
 #[platform_serialize(limit = 15000, unversioned)]
 pub enum Vote {
     ResourceVote(ResourceVote),
+    YesNoAbstainVote(YesNoAbstainVote),
 }
 
 impl Default for Vote {
@@ -31,11 +35,15 @@ impl Vote {
     pub fn specialized_balance_id(&self) -> Result<Option<Identifier>, ProtocolError> {
         match self {
             Vote::ResourceVote(resource_vote) => resource_vote.vote_poll().specialized_balance_id(),
+            Vote::YesNoAbstainVote(_) => Ok(None),
         }
     }
     pub fn vote_poll_unique_id(&self) -> Result<Identifier, ProtocolError> {
         match self {
             Vote::ResourceVote(resource_vote) => resource_vote.vote_poll().unique_id(),
+            Vote::YesNoAbstainVote(yes_no_abstain_vote) => {
+                yes_no_abstain_vote.vote_poll().unique_id()
+            }
         }
     }
 }
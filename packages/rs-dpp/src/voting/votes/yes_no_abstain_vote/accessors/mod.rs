@@ -0,0 +1,26 @@
+use crate::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use crate::voting::votes::yes_no_abstain_vote::accessors::v0::YesNoAbstainVoteGettersV0;
+use crate::voting::votes::yes_no_abstain_vote::YesNoAbstainVote;
+
+pub mod v0;
+
+impl YesNoAbstainVoteGettersV0 for YesNoAbstainVote {
+    fn vote_poll(&self) -> &YesNoAbstainVotePoll {
+        match self {
+            YesNoAbstainVote::V0(v0) => &v0.vote_poll,
+        }
+    }
+
+    fn vote_poll_owned(self) -> YesNoAbstainVotePoll {
+        match self {
+            YesNoAbstainVote::V0(v0) => v0.vote_poll,
+        }
+    }
+
+    fn vote_choice(&self) -> YesNoAbstainVoteChoice {
+        match self {
+            YesNoAbstainVote::V0(v0) => v0.vote_choice,
+        }
+    }
+}
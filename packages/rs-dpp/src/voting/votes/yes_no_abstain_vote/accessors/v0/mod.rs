@@ -0,0 +1,14 @@
+use crate::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+
+/// Trait for getters in Yes No Abstain Vote
+pub trait YesNoAbstainVoteGettersV0 {
+    /// The vote poll
+    fn vote_poll(&self) -> &YesNoAbstainVotePoll;
+
+    /// The vote poll as owned
+    fn vote_poll_owned(self) -> YesNoAbstainVotePoll;
+
+    /// The choice made in the vote
+    fn vote_choice(&self) -> YesNoAbstainVoteChoice;
+}
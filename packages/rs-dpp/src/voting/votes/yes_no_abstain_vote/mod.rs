@@ -0,0 +1,27 @@
+use crate::voting::votes::yes_no_abstain_vote::v0::YesNoAbstainVoteV0;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+#[cfg(feature = "vote-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+pub mod accessors;
+pub mod v0;
+
+#[derive(Debug, Clone, Encode, Decode, PlatformSerialize, PlatformDeserialize, PartialEq)]
+#[cfg_attr(
+    feature = "vote-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(tag = "$version")
+)]
+#[platform_serialize(limit = 15000, unversioned)]
+pub enum YesNoAbstainVote {
+    #[cfg_attr(feature = "vote-serde-conversion", serde(rename = "0"))]
+    V0(YesNoAbstainVoteV0),
+}
+
+impl Default for YesNoAbstainVote {
+    fn default() -> Self {
+        Self::V0(YesNoAbstainVoteV0::default())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use crate::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+#[cfg(feature = "vote-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, Default, Encode, Decode, PlatformDeserialize, PlatformSerialize, PartialEq,
+)]
+#[cfg_attr(
+    feature = "vote-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[platform_serialize(unversioned)]
+pub struct YesNoAbstainVoteV0 {
+    pub vote_poll: YesNoAbstainVotePoll,
+    pub vote_choice: YesNoAbstainVoteChoice,
+}
+
+impl YesNoAbstainVoteV0 {
+    pub fn vote_poll_unique_id(&self) -> Result<Identifier, ProtocolError> {
+        self.vote_poll.unique_id()
+    }
+}
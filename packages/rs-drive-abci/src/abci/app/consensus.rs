@@ -5,6 +5,7 @@ use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::execution::types::block_execution_context::BlockExecutionContext;
 use crate::platform_types::platform::Platform;
+use crate::replay::{RecordedRequest, RequestRecorder};
 use crate::rpc::core::CoreRPCLike;
 use dpp::version::PlatformVersion;
 use drive::grovedb::Transaction;
@@ -23,6 +24,8 @@ pub struct ConsensusAbciApplication<'a, C> {
     transaction: RwLock<Option<Transaction<'a>>>,
     /// The current block execution context
     block_execution_context: RwLock<Option<BlockExecutionContext>>,
+    /// Records block execution requests, if enabled
+    request_recorder: Option<RequestRecorder>,
 }
 
 impl<'a, C> ConsensusAbciApplication<'a, C> {
@@ -32,6 +35,19 @@ impl<'a, C> ConsensusAbciApplication<'a, C> {
             platform,
             transaction: Default::default(),
             block_execution_context: Default::default(),
+            request_recorder: None,
+        }
+    }
+
+    /// Record block execution requests with the recorder, so they can be replayed
+    pub fn with_request_recorder(mut self, recorder: RequestRecorder) -> Self {
+        self.request_recorder = Some(recorder);
+        self
+    }
+
+    fn record(&self, request: impl FnOnce() -> RecordedRequest) {
+        if let Some(recorder) = &self.request_recorder {
+            recorder.record(request());
         }
     }
 }
@@ -126,6 +142,8 @@ where
         &self,
         request: proto::RequestFinalizeBlock,
     ) -> Result<proto::ResponseFinalizeBlock, proto::ResponseException> {
+        self.record(|| RecordedRequest::FinalizeBlock(request.clone()));
+
         handler::finalize_block(self, request).map_err(error_into_exception)
     }
 
@@ -133,6 +151,8 @@ where
         &self,
         request: proto::RequestPrepareProposal,
     ) -> Result<proto::ResponsePrepareProposal, proto::ResponseException> {
+        self.record(|| RecordedRequest::PrepareProposal(request.clone()));
+
         handler::prepare_proposal(self, request).map_err(error_into_exception)
     }
 
@@ -140,6 +160,8 @@ where
         &self,
         request: proto::RequestProcessProposal,
     ) -> Result<proto::ResponseProcessProposal, proto::ResponseException> {
+        self.record(|| RecordedRequest::ProcessProposal(request.clone()));
+
         handler::process_proposal(self, request).map_err(error_into_exception)
     }
 
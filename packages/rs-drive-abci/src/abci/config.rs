@@ -2,6 +2,7 @@
 
 use crate::utils::from_opt_str_or_number;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 // We allow changes in the ABCI configuration, but there should be a social process
 // involved in making this change.
@@ -37,6 +38,14 @@ pub struct AbciConfig {
     /// Maximum time limit (in ms) to process state transitions to prepare proposal
     #[serde(default, deserialize_with = "from_opt_str_or_number")]
     pub proposer_tx_processing_time_limit: Option<u16>,
+
+    /// Directory to record consensus requests in
+    ///
+    /// Prepare proposal, process proposal and finalize block requests are written
+    /// into recordings that can be re-executed with `drive-abci replay`.
+    /// If not set, requests are not recorded.
+    #[serde(default)]
+    pub request_recording_dir: Option<PathBuf>,
 }
 
 impl AbciConfig {
@@ -58,6 +67,7 @@ impl Default for AbciConfig {
             chain_id: "chain_id".to_string(),
             log: Default::default(),
             proposer_tx_processing_time_limit: Default::default(),
+            request_recording_dir: None,
         }
     }
 }
@@ -3,10 +3,12 @@ use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::execution::types::block_execution_context::v0::BlockExecutionContextV0Getters;
 use crate::platform_types::cleaned_abci_messages::finalized_block_cleaned_request::v0::FinalizeBlockCleanedRequest;
+use crate::platform_types::document_transitions_event::DocumentTransitionsEvent;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::rpc::core::CoreRPCLike;
 use dpp::dashcore::Network;
 use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use tenderdash_abci::proto::abci as proto;
 
 pub fn finalize_block<'a, A, C>(
@@ -45,6 +47,34 @@ where
 
     let block_height = request_finalize_block.height;
 
+    // Original documents are read from the last committed state, so the event for document
+    // subscribers has to be collected before the block is committed
+    let document_transitions_event = if block_execution_context
+        .executed_batch_transitions()
+        .is_empty()
+    {
+        None
+    } else {
+        match DocumentTransitionsEvent::new(
+            block_height,
+            block_execution_context
+                .executed_batch_transitions()
+                .to_vec(),
+            &app.platform().drive,
+            platform_version,
+        ) {
+            Ok(event) => Some(event),
+            Err(error) => {
+                tracing::error!(
+                    ?error,
+                    block_height,
+                    "failed to collect document transitions for subscribers"
+                );
+                None
+            }
+        }
+    };
+
     let block_finalization_outcome = app.platform().finalize_block_proposal(
         request_finalize_block,
         block_execution_context,
@@ -96,5 +126,13 @@ where
         .committed_block_height_guard
         .store(block_height, Ordering::Relaxed);
 
+    if let Some(event) = document_transitions_event {
+        // An error only means there are no subscribers left
+        let _ = app
+            .platform()
+            .document_transitions_events
+            .send(Arc::new(event));
+    }
+
     Ok(proto::ResponseFinalizeBlock { retain_height: 0 })
 }
@@ -189,6 +189,16 @@ where
     let storage_fees = state_transitions_result.aggregated_fees().storage_fee;
     let processing_fees = state_transitions_result.aggregated_fees().processing_fee;
 
+    if let Some(rejection_archiver) = app.platform().rejection_archiver.as_ref() {
+        rejection_archiver.archive_rejected_state_transitions(
+            request.height as u64,
+            request.round as u32,
+            state_transitions_result.execution_results(),
+            &request.txs,
+            platform_version,
+        );
+    }
+
     let mut tx_results = Vec::new();
     let mut tx_records = Vec::new();
 
@@ -272,6 +272,16 @@ where
     let invalid_unpaid_tx_count = state_transition_results.invalid_unpaid_count();
     let unexpected_execution_results = failed_tx_count + invalid_unpaid_tx_count;
 
+    if let Some(rejection_archiver) = app.platform().rejection_archiver.as_ref() {
+        rejection_archiver.archive_rejected_state_transitions(
+            request.height as u64,
+            request.round as u32,
+            state_transition_results.execution_results(),
+            &request.txs,
+            platform_version,
+        );
+    }
+
     let storage_fees = state_transition_results.aggregated_fees().storage_fee;
     let processing_fees = state_transition_results.aggregated_fees().processing_fee;
 
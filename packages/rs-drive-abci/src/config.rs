@@ -108,6 +108,15 @@ pub struct ExecutionConfig {
         deserialize_with = "from_str_or_number"
     )]
     pub epoch_time_length_s: u64,
+
+    /// How many of the most recent blocks should keep their executed state transitions
+    /// for block explorer queries, `0` disables it.
+    /// The retained blocks are not part of the state, so it may differ between nodes
+    #[serde(
+        default = "ExecutionConfig::default_block_state_transitions_retention",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub block_state_transitions_retention: u64,
 }
 
 /// Configuration of Dash Platform.
@@ -182,6 +191,9 @@ pub struct PlatformConfig {
     /// Path to store rejected / invalid items (like transactions).
     /// Used mainly for debugging.
     ///
+    /// State transitions rejected during block execution are archived
+    /// in the [crate::rejections] format and can be inspected with `drive-abci rejections`.
+    ///
     /// If not set, rejected and invalid items will not be stored.
     pub rejections_path: Option<PathBuf>,
 
@@ -623,6 +635,10 @@ impl ExecutionConfig {
     fn default_epoch_time_length_s() -> u64 {
         788400
     }
+
+    fn default_block_state_transitions_retention() -> u64 {
+        0
+    }
 }
 
 impl PlatformConfig {
@@ -673,6 +689,8 @@ impl Default for ExecutionConfig {
             verify_sum_trees: ExecutionConfig::default_verify_sum_trees(),
             verify_token_sum_trees: ExecutionConfig::default_verify_token_sum_trees(),
             epoch_time_length_s: ExecutionConfig::default_epoch_time_length_s(),
+            block_state_transitions_retention:
+                ExecutionConfig::default_block_state_transitions_retention(),
         }
     }
 }
@@ -727,7 +745,7 @@ impl PlatformConfig {
             core: Default::default(),
             execution: Default::default(),
             db_path: PathBuf::from("/var/lib/dash-platform/data"),
-            rejections_path: Some(PathBuf::from("/var/log/dash/rejected")),
+            rejections_path: None,
             #[cfg(feature = "testing-config")]
             testing_configs: PlatformTestConfig::default(),
             tokio_console_enabled: false,
@@ -769,7 +787,7 @@ impl PlatformConfig {
             core: Default::default(),
             execution: Default::default(),
             db_path: PathBuf::from("/var/lib/dash-platform/data"),
-            rejections_path: Some(PathBuf::from("/var/log/dash/rejected")),
+            rejections_path: None,
             #[cfg(feature = "testing-config")]
             testing_configs: PlatformTestConfig::default(),
             tokio_console_enabled: false,
@@ -811,7 +829,7 @@ impl PlatformConfig {
             core: Default::default(),
             execution: Default::default(),
             db_path: PathBuf::from("/var/lib/dash-platform/data"),
-            rejections_path: Some(PathBuf::from("/var/log/dash/rejected")),
+            rejections_path: None,
             #[cfg(feature = "testing-config")]
             testing_configs: PlatformTestConfig::default(),
             prometheus_bind_address: None,
@@ -853,7 +871,7 @@ impl PlatformConfig {
             core: Default::default(),
             execution: Default::default(),
             db_path: PathBuf::from("/var/lib/dash-platform/data"),
-            rejections_path: Some(PathBuf::from("/var/log/dash/rejected")),
+            rejections_path: None,
             #[cfg(feature = "testing-config")]
             testing_configs: PlatformTestConfig::default(),
             prometheus_bind_address: None,
@@ -20,6 +20,8 @@ use crate::execution::types::state_transition_container::v0::{
 #[cfg(test)]
 use crate::execution::validation::state_transition::processor::process_state_transition;
 #[cfg(test)]
+use crate::execution::validation::state_transition::ValidationMode;
+#[cfg(test)]
 use dpp::serialization::PlatformDeserializable;
 #[cfg(test)]
 use dpp::state_transition::StateTransition;
@@ -57,6 +59,7 @@ where
             &platform_ref,
             self.state.load().last_block_info(),
             state_transition,
+            ValidationMode::Validator,
             Some(transaction),
         )?;
 
@@ -25,6 +25,7 @@ use crate::execution::types::block_state_info::v0::{
 };
 
 use crate::platform_types::block_execution_outcome;
+use crate::platform_types::block_state_transitions::BlockStateTransitions;
 use crate::platform_types::cleaned_abci_messages::cleaned_block::v0::CleanedBlock;
 use crate::platform_types::cleaned_abci_messages::finalized_block_cleaned_request::v0::FinalizeBlockCleanedRequest;
 
@@ -209,6 +210,35 @@ where
             )?;
         }
 
+        // Keep the executed state transitions for block explorers, the blocks that fell out
+        // of the retention window are removed at the same time. They are node-local data, so
+        // a failure must not fail the block
+        let block_state_transitions_retention =
+            self.config.execution.block_state_transitions_retention;
+
+        if block_state_transitions_retention > 0 {
+            let block_state_transitions = BlockStateTransitions {
+                block_height: height,
+                block_time_ms: to_commit_block_info.time_ms,
+                state_transitions: block_execution_context
+                    .executed_state_transitions()
+                    .to_vec(),
+            };
+
+            if let Err(error) = self.store_block_state_transitions(
+                &block_state_transitions,
+                height.checked_sub(block_state_transitions_retention),
+                Some(transaction),
+                platform_version,
+            ) {
+                tracing::error!(
+                    ?error,
+                    block_height = height,
+                    "failed to store the executed state transitions of the block"
+                );
+            }
+        }
+
         // Update platform (drive abci) state
 
         let extended_block_info = ExtendedBlockInfoV0 {
@@ -21,6 +21,8 @@ use crate::execution::types::{block_execution_context, block_state_info};
 use crate::metrics::HistogramTiming;
 use crate::platform_types::block_execution_outcome;
 use crate::platform_types::block_proposal;
+use crate::platform_types::block_state_transitions::ExecutedStateTransition;
+use crate::platform_types::document_transitions_event::ExecutedBatchTransition;
 use crate::platform_types::epoch_info::v0::{EpochInfoV0Getters, EpochInfoV0Methods};
 use crate::platform_types::epoch_info::EpochInfo;
 use crate::platform_types::platform::Platform;
@@ -329,6 +331,50 @@ where
             timer,
         )?;
 
+        // Record the paid state transitions in the histories of their owners, who pay for them
+        let identity_state_transition_history_fees = self.store_identity_state_transition_history(
+            raw_state_transitions,
+            state_transitions_result.execution_results(),
+            &block_info,
+            &block_platform_state,
+            transaction,
+            platform_version,
+        )?;
+
+        // Keep the executed batch transitions for document subscribers, they are published
+        // once the block is committed
+        let executed_batch_transitions = if self.document_transitions_events.receiver_count() > 0 {
+            ExecutedBatchTransition::from_execution_results(
+                raw_state_transitions,
+                state_transitions_result.execution_results(),
+            )
+        } else {
+            vec![]
+        };
+
+        // Keep the state transitions with their results for block explorers, they are stored
+        // once the block is finalized. They are node-local data, so a failure must not fail
+        // the block
+        let executed_state_transitions = if self.config.execution.block_state_transitions_retention
+            > 0
+        {
+            ExecutedStateTransition::from_execution_results(
+                    raw_state_transitions,
+                    state_transitions_result.execution_results(),
+                    platform_version,
+                )
+                .unwrap_or_else(|error| {
+                    tracing::error!(
+                        ?error,
+                        block_height = block_info.height,
+                        "failed to collect the executed state transitions of the block, they will not be stored"
+                    );
+                    vec![]
+                })
+        } else {
+            vec![]
+        };
+
         // Pool withdrawals into transactions queue
 
         // Takes queued withdrawals, creates untiled withdrawal transaction payload, saves them to queue
@@ -352,6 +398,15 @@ where
             platform_version,
         )?;
 
+        // Remove documents whose time to live has been reached, their storage is refunded to
+        // their owners who pay for the processing of the removals
+        let expired_documents_fees = self.remove_expired_documents(
+            &block_info,
+            &block_platform_state,
+            transaction,
+            platform_version,
+        )?;
+
         // Create a new block execution context
 
         let mut block_execution_context: BlockExecutionContext =
@@ -361,11 +416,21 @@ where
                 unsigned_withdrawal_transactions: unsigned_withdrawal_transaction_bytes,
                 block_platform_state,
                 proposer_results: None,
+                executed_batch_transitions,
+                executed_state_transitions,
             }
             .into();
 
         // while we have the state transitions executed, we now need to process the block fees
-        let block_fees_v0: BlockFeesV0 = state_transitions_result.aggregated_fees().clone().into();
+        let mut aggregated_fees = state_transitions_result.aggregated_fees().clone();
+
+        // the removals of expired documents are paid like the state transitions of the block
+        aggregated_fees.checked_add_assign(expired_documents_fees)?;
+
+        // so are the records of the state transition histories of identities
+        aggregated_fees.checked_add_assign(identity_state_transition_history_fees)?;
+
+        let block_fees_v0: BlockFeesV0 = aggregated_fees.into();
 
         // Process fees
         let processed_block_fees = self.process_block_fees_and_validate_sum_trees(
@@ -5,3 +5,6 @@ pub(in crate::execution) mod validator_set_update;
 
 /// Updating the drive cache happens as the final part of block finalization
 pub(in crate::execution) mod update_drive_cache;
+
+/// Removing documents whose time to live has been reached
+pub(in crate::execution) mod remove_expired_documents;
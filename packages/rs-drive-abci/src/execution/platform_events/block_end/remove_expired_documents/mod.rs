@@ -0,0 +1,52 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Removes the documents whose time to live has been reached and refunds their storage to
+    /// their owners, minus the processing fees of the removals.
+    ///
+    /// # Returns
+    /// * `Ok(FeeResult)`: The fees of the removals, they must be added to the fees of the block
+    ///   so that the refunds are deducted from the epoch storage pools and the processing fees
+    ///   are distributed.
+    /// * `Err(Error::Execution(ExecutionError::UnknownVersionMismatch))`: If the platform version
+    ///   does not match known versions.
+    pub(in crate::execution) fn remove_expired_documents(
+        &self,
+        block_info: &BlockInfo,
+        block_platform_state: &PlatformState,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .block_end
+            .remove_expired_documents
+        {
+            0 => self.remove_expired_documents_v0(
+                block_info,
+                block_platform_state,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "remove_expired_documents".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
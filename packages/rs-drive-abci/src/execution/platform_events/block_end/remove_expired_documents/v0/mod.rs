@@ -0,0 +1,69 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use drive::drive::identity::update::apply_balance_change_outcome::ApplyBalanceChangeOutcomeV0Methods;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn remove_expired_documents_v0(
+        &self,
+        block_info: &BlockInfo,
+        block_platform_state: &PlatformState,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let fee_results = self.drive.remove_expired_documents(
+            block_info,
+            Some(block_platform_state.previous_fee_versions()),
+            Some(transaction),
+            platform_version,
+        )?;
+
+        let removed_documents = fee_results.len();
+
+        let mut aggregated_fees = FeeResult::default();
+
+        for fee_result in fee_results {
+            // The storage of a document is refunded to its owner, who pays for the processing
+            // of its removal out of that refund like for a delete transition
+            let Some(owner_id) = fee_result
+                .fee_refunds
+                .iter()
+                .next()
+                .map(|(identity_id, _)| Identifier::new(*identity_id))
+            else {
+                // Nobody can be charged for a document without owned storage
+                continue;
+            };
+
+            let balance_change = fee_result.into_balance_change(owner_id);
+
+            let outcome = self.drive.apply_balance_change_from_fee_to_identity(
+                balance_change,
+                Some(transaction),
+                platform_version,
+            )?;
+
+            aggregated_fees.checked_add_assign(outcome.actual_fee_paid_owned())?;
+        }
+
+        if removed_documents > 0 {
+            tracing::debug!(
+                removed_documents,
+                "removed expired documents at block {}",
+                block_info.height
+            );
+        }
+
+        Ok(aggregated_fees)
+    }
+}
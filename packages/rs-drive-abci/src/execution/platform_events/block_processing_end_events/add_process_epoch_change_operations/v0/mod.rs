@@ -273,6 +273,8 @@ mod tests {
                 unsigned_withdrawal_transactions: Default::default(),
                 block_platform_state,
                 proposer_results: None,
+                executed_batch_transitions: vec![],
+                executed_state_transitions: vec![],
             };
 
             let mut batch = vec![];
@@ -272,6 +272,8 @@ mod tests {
                 unsigned_withdrawal_transactions: Default::default(),
                 block_platform_state,
                 proposer_results: None,
+                executed_batch_transitions: vec![],
+                executed_state_transitions: vec![],
             };
 
             let storage_fee_distribution_outcome = platform
@@ -5,6 +5,7 @@ use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
 use dpp::block::block_info::BlockInfo;
 use dpp::dashcore::hashes::Hash;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::data_contracts::SystemDataContract;
 use dpp::fee::Credits;
 use dpp::platform_value::Identifier;
@@ -101,6 +102,10 @@ impl<C> Platform<C> {
             self.transition_to_version_9(block_info, transaction, platform_version)?;
         }
 
+        if previous_protocol_version < 11 && platform_version.protocol_version >= 11 {
+            self.transition_to_version_11(block_info, transaction, platform_version)?;
+        }
+
         Ok(())
     }
 
@@ -290,12 +295,10 @@ impl<C> Platform<C> {
                     .map_err(drive::error::Error::from)?;
                 let vote_poll =
                     VotePoll::deserialize_from_bytes(&contested_document_resource_vote_poll_bytes)?;
-                match vote_poll {
-                    VotePoll::ContestedDocumentResourceVotePoll(contested) => {
-                        contested.specialized_balance_id().map_err(Error::Protocol)
-                    }
-                }
+                // yes/no/abstain vote polls don't have a specialized balance
+                vote_poll.specialized_balance_id().map_err(Error::Protocol)
             })
+            .filter_map(Result::transpose)
             .collect::<Result<HashSet<Identifier>, Error>>()?;
 
         // let's get the non-active ones
@@ -521,4 +524,55 @@ impl<C> Platform<C> {
 
         Ok(())
     }
+
+    /// Updates the token history contract to the version indexing events by block height.
+    ///
+    /// The new indexes are added to the stored contract and every event recorded before the
+    /// upgrade is indexed, so that block height queries also return older events.
+    ///
+    /// # Parameters
+    ///
+    /// * `block_info`: The block in which the upgrade happens.
+    /// * `transaction`: A reference to the transaction context in which the changes should be applied.
+    /// * `platform_version`: The current platform version containing the updated protocol version and relevant configuration details.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())`: If the transition to version 11 was successful.
+    /// * `Err(Error)`: If there was an issue updating the contract or indexing its documents.
+    fn transition_to_version_11(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let token_history_contract =
+            load_system_data_contract(SystemDataContract::TokenHistory, platform_version)?;
+
+        self.drive.update_contract(
+            &token_history_contract,
+            *block_info,
+            true,
+            Some(transaction),
+            platform_version,
+            None,
+        )?;
+
+        for document_type_name in token_history_contract.document_types().keys() {
+            let indexed_documents = self.drive.add_indices_for_existing_documents(
+                &token_history_contract,
+                document_type_name,
+                Some(transaction),
+                platform_version,
+            )?;
+
+            tracing::info!(
+                document_type_name = document_type_name.as_str(),
+                indexed_documents,
+                "Indexed token history documents by block height"
+            );
+        }
+
+        Ok(())
+    }
 }
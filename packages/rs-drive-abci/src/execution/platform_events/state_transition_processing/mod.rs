@@ -1,4 +1,6 @@
 mod decode_raw_state_transitions;
 mod execute_event;
 mod process_raw_state_transitions;
+mod simulate_state_transition;
+mod store_identity_state_transition_history;
 mod validate_fees_of_event;
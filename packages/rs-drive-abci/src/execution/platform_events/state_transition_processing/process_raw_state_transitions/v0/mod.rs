@@ -12,6 +12,7 @@ use crate::execution::types::state_transition_container::v0::{
     SuccessfullyDecodedStateTransition,
 };
 use crate::execution::validation::state_transition::processor::process_state_transition;
+use crate::execution::validation::state_transition::ValidationMode;
 use crate::metrics::{state_transition_execution_histogram, HistogramTiming};
 use crate::platform_types::event_execution_result::EventExecutionResult;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
@@ -124,6 +125,7 @@ where
                             &platform_ref,
                             block_info,
                             state_transition,
+                            ValidationMode::Validator,
                             Some(transaction),
                         )
                         .map(|validation_result| {
@@ -0,0 +1,56 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+use crate::rpc::core::CoreRPCLike;
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Simulates the execution of a raw state transition against the last committed state.
+    ///
+    /// The state transition goes through the same validation as in a block and its fees are
+    /// estimated, but nothing is written to the state. Like in check tx, fees are estimated
+    /// without applying the operations, so storage refunds are not known in advance.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw_state_transition` - The serialized state transition to simulate.
+    /// * `platform_state` - The state of the last committed block.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<StateTransitionExecutionResult, Error>` - The result the state transition would
+    ///   have if it was executed in the next block, with estimated fees.
+    pub fn simulate_state_transition(
+        &self,
+        raw_state_transition: &[u8],
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateTransitionExecutionResult, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .state_transition_processing
+            .simulate_state_transition
+        {
+            0 => self.simulate_state_transition_v0(
+                raw_state_transition,
+                platform_state,
+                platform_version,
+            ),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "simulate_state_transition".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::execution_event::ExecutionEvent;
+use crate::execution::types::state_transition_container::v0::{
+    DecodedStateTransition, InvalidStateTransition, InvalidWithProtocolErrorStateTransition,
+    SuccessfullyDecodedStateTransition,
+};
+use crate::execution::validation::state_transition::processor::process_state_transition;
+use crate::execution::validation::state_transition::ValidationMode;
+use crate::platform_types::platform::{Platform, PlatformRef};
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+use crate::rpc::core::CoreRPCLike;
+use dpp::fee::fee_result::FeeResult;
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn simulate_state_transition_v0(
+        &self,
+        raw_state_transition: &[u8],
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateTransitionExecutionResult, Error> {
+        let platform_ref = PlatformRef {
+            drive: &self.drive,
+            state: platform_state,
+            config: &self.config,
+            core_rpc: &self.core_rpc,
+        };
+
+        let block_info = platform_state.last_block_info();
+
+        let raw_state_transitions = vec![raw_state_transition];
+        let mut decoded_state_transitions: Vec<DecodedStateTransition> = self
+            .decode_raw_state_transitions(&raw_state_transitions, platform_version)?
+            .into();
+
+        if decoded_state_transitions.len() != 1 {
+            return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "expected exactly one decoded state transition",
+            )));
+        }
+
+        let state_transition = match decoded_state_transitions.remove(0) {
+            DecodedStateTransition::SuccessfullyDecoded(SuccessfullyDecodedStateTransition {
+                decoded,
+                ..
+            }) => decoded,
+            DecodedStateTransition::InvalidEncoding(InvalidStateTransition { error, .. }) => {
+                return Ok(StateTransitionExecutionResult::UnpaidConsensusError(error));
+            }
+            DecodedStateTransition::FailedToDecode(InvalidWithProtocolErrorStateTransition {
+                error,
+                ..
+            }) => {
+                return Err(error.into());
+            }
+        };
+
+        // Without a transaction the validation reads the last committed state, the simulation
+        // mode validates like a validator without altering caches
+        let mut validation_result = process_state_transition(
+            &platform_ref,
+            block_info,
+            state_transition,
+            ValidationMode::Simulation,
+            None,
+        )?;
+
+        if validation_result.data.is_none() {
+            // An invalid result without an execution event can't be paid for
+            return Ok(StateTransitionExecutionResult::UnpaidConsensusError(
+                validation_result.errors.remove(0),
+            ));
+        }
+
+        let (execution_event, consensus_errors) = validation_result.into_data_and_errors()?;
+
+        let first_consensus_error = consensus_errors.into_iter().next();
+
+        // Fees are estimated the same way they are validated before executing the event
+        let fee_result = match &execution_event {
+            ExecutionEvent::PaidFromAssetLock { .. } | ExecutionEvent::Paid { .. } => {
+                let mut fee_validation_result = self.validate_fees_of_event(
+                    &execution_event,
+                    block_info,
+                    None,
+                    platform_version,
+                    platform_state.previous_fee_versions(),
+                )?;
+
+                if !fee_validation_result.is_valid() {
+                    let error = first_consensus_error
+                        .unwrap_or_else(|| fee_validation_result.errors.remove(0));
+
+                    return Ok(StateTransitionExecutionResult::UnpaidConsensusError(error));
+                }
+
+                let mut fee_result = fee_validation_result.into_data()?;
+
+                if let ExecutionEvent::Paid {
+                    additional_fixed_fee_cost: Some(additional_fixed_fee_cost),
+                    ..
+                } = &execution_event
+                {
+                    fee_result.processing_fee = fee_result
+                        .processing_fee
+                        .saturating_add(*additional_fixed_fee_cost);
+                }
+
+                fee_result
+            }
+            ExecutionEvent::PaidFromAssetLockWithoutIdentity {
+                processing_fees, ..
+            } => FeeResult::default_with_fees(0, *processing_fees),
+            ExecutionEvent::PaidFixedCost {
+                fees_to_add_to_pool,
+                ..
+            } => {
+                if let Some(error) = first_consensus_error {
+                    return Ok(StateTransitionExecutionResult::UnpaidConsensusError(error));
+                }
+
+                FeeResult::default_with_fees(0, *fees_to_add_to_pool)
+            }
+            ExecutionEvent::Free { .. } => {
+                if let Some(error) = first_consensus_error {
+                    return Ok(StateTransitionExecutionResult::UnpaidConsensusError(error));
+                }
+
+                FeeResult::default()
+            }
+        };
+
+        match first_consensus_error {
+            Some(error) => Ok(StateTransitionExecutionResult::PaidConsensusError(
+                error, fee_result,
+            )),
+            None => Ok(StateTransitionExecutionResult::SuccessfulExecution(
+                None, fee_result,
+            )),
+        }
+    }
+}
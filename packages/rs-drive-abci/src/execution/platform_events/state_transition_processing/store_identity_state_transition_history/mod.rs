@@ -0,0 +1,71 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Records the paid state transitions of a block in the histories of the identities
+    /// owning them.
+    ///
+    /// State transitions that were not paid for are not recorded, as they are not part of the
+    /// block state. The storage of every record is paid by the identity owning it. The history
+    /// is only stored from protocol version 11.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw_state_transitions` - The serialized state transitions of the block.
+    /// * `execution_results` - The execution results of the state transitions, in block order.
+    /// * `block_info` - Information about the current block.
+    /// * `block_platform_state` - The state of the platform for the block.
+    /// * `transaction` - The transaction of the block.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    /// * `Ok(FeeResult)`: The fees paid for the records, they must be added to the fees of the
+    ///   block so that their storage is distributed to the epoch storage pools.
+    /// * `Err(Error::Execution(ExecutionError::UnknownVersionMismatch))`: If the platform version
+    ///   does not match known versions.
+    pub(in crate::execution) fn store_identity_state_transition_history(
+        &self,
+        raw_state_transitions: &[Vec<u8>],
+        execution_results: &[StateTransitionExecutionResult],
+        block_info: &BlockInfo,
+        block_platform_state: &PlatformState,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .state_transition_processing
+            .store_identity_state_transition_history
+        {
+            None => Ok(FeeResult::default()),
+            Some(0) => self.store_identity_state_transition_history_v0(
+                raw_state_transitions,
+                execution_results,
+                block_info,
+                block_platform_state,
+                transaction,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "store_identity_state_transition_history".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::fee::fee_result::FeeResult;
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::serialization::PlatformDeserializable;
+use dpp::state_transition::StateTransition;
+use dpp::util::hash::hash_single;
+use dpp::version::PlatformVersion;
+use drive::drive::identity::update::apply_balance_change_outcome::ApplyBalanceChangeOutcomeV0Methods;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn store_identity_state_transition_history_v0(
+        &self,
+        raw_state_transitions: &[Vec<u8>],
+        execution_results: &[StateTransitionExecutionResult],
+        block_info: &BlockInfo,
+        block_platform_state: &PlatformState,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let previous_fee_versions = Some(block_platform_state.previous_fee_versions());
+
+        let mut aggregated_fees = FeeResult::default();
+
+        for (index_in_block, (raw_state_transition, execution_result)) in raw_state_transitions
+            .iter()
+            .zip(execution_results)
+            .enumerate()
+        {
+            let (fee_result, error_code) = match execution_result {
+                StateTransitionExecutionResult::SuccessfulExecution(_, fee_result) => {
+                    (fee_result, None)
+                }
+                StateTransitionExecutionResult::PaidConsensusError(error, fee_result) => {
+                    (fee_result, Some(error.code()))
+                }
+                StateTransitionExecutionResult::UnpaidConsensusError(_)
+                | StateTransitionExecutionResult::InternalError(_)
+                | StateTransitionExecutionResult::NotExecuted(_) => continue,
+            };
+
+            // Paid state transitions were decoded during execution
+            let state_transition = StateTransition::deserialize_from_bytes(raw_state_transition)?;
+
+            let record = IdentityStateTransitionRecord::new(
+                hash_single(raw_state_transition),
+                state_transition.state_transition_type(),
+                block_info.height,
+                block_info.time_ms,
+                index_in_block as u32,
+                fee_result.total_base_fee(),
+                error_code,
+                platform_version,
+            )?;
+
+            let owner_id = state_transition.owner_id();
+
+            // The owner of a state transition pays for the storage of its record, state
+            // transitions of identities that don't exist or can't pay for it are not recorded
+            let Some(balance) = self.drive.fetch_identity_balance(
+                owner_id.to_buffer(),
+                Some(transaction),
+                platform_version,
+            )?
+            else {
+                continue;
+            };
+
+            let estimated_fee_result = self.drive.add_identity_state_transition_record(
+                owner_id,
+                &record,
+                block_info,
+                false,
+                previous_fee_versions,
+                Some(transaction),
+                platform_version,
+            )?;
+
+            if balance < estimated_fee_result.total_base_fee() {
+                continue;
+            }
+
+            let record_fee_result = self.drive.add_identity_state_transition_record(
+                owner_id,
+                &record,
+                block_info,
+                true,
+                previous_fee_versions,
+                Some(transaction),
+                platform_version,
+            )?;
+
+            let outcome = self.drive.apply_balance_change_from_fee_to_identity(
+                record_fee_result.into_balance_change(owner_id),
+                Some(transaction),
+                platform_version,
+            )?;
+
+            aggregated_fees.checked_add_assign(outcome.actual_fee_paid_owned())?;
+        }
+
+        Ok(aggregated_fees)
+    }
+}
@@ -4,7 +4,9 @@ use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
 use crate::rpc::core::CoreRPCLike;
 use dpp::block::block_info::BlockInfo;
+use dpp::dashcore::hashes::Hash;
 use dpp::dashcore::Network::Testnet;
+use dpp::dashcore::ProTxHash;
 use dpp::document::DocumentV0Getters;
 use dpp::prelude::TimestampMillis;
 use dpp::version::PlatformVersion;
@@ -12,6 +14,10 @@ use dpp::voting::contender_structs::FinalizedContender;
 use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
 use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice::TowardsIdentity;
 use dpp::voting::vote_info_storage::contested_document_vote_poll_winner_info::ContestedDocumentVotePollWinnerInfo;
+use dpp::voting::vote_info_storage::yes_no_abstain_vote_poll_stored_info::{
+    YesNoAbstainVotePollStoredInfo, YesNoAbstainVoteTally,
+};
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
 use drive::drive::votes::paths::vote_end_date_queries_tree_path_vec;
 use drive::drive::votes::resolved::vote_polls::resolve::VotePollResolver;
 use drive::drive::votes::resolved::vote_polls::{ResolvedVotePoll, ResolvedVotePollWithVotes};
@@ -175,6 +181,18 @@ where
                                 identifiers_voting_for_contenders,
                             ))
                         }
+                        ResolvedVotePoll::YesNoAbstainVotePoll(yes_no_abstain_vote_poll) => {
+                            let vote_tally = self.tally_votes_for_yes_no_abstain_vote_poll(
+                                block_platform_state,
+                                &yes_no_abstain_vote_poll,
+                                transaction,
+                                platform_version,
+                            )?;
+                            Ok(ResolvedVotePollWithVotes::YesNoAbstainVotePollWithVotes(
+                                yes_no_abstain_vote_poll,
+                                vote_tally,
+                            ))
+                        }
                     }
                 }).collect::<Result<Vec<ResolvedVotePollWithVotes>, Error>>()?;
                 Ok((end_date, vote_polls_with_votes))
@@ -300,6 +318,30 @@ where
                             )?;
                             Ok(ResolvedVotePollWithVotes::ContestedDocumentResourceVotePollWithContractInfoAndVotes(resolved_contested_document_resource_vote_poll, identifiers_voting_for_contenders))
                         }
+                        ResolvedVotePoll::YesNoAbstainVotePoll(yes_no_abstain_vote_poll) => {
+                            let vote_tally = self.tally_votes_for_yes_no_abstain_vote_poll(
+                                block_platform_state,
+                                &yes_no_abstain_vote_poll,
+                                transaction,
+                                platform_version,
+                            )?;
+                            // We keep the result so that it can be queried after the poll ended
+                            let stored_info = YesNoAbstainVotePollStoredInfo::new(
+                                yes_no_abstain_vote_poll.clone(),
+                                vote_tally,
+                                *block_info,
+                                platform_version,
+                            )?;
+                            self.drive.insert_stored_info_for_yes_no_abstain_vote_poll(
+                                stored_info,
+                                transaction,
+                                platform_version,
+                            )?;
+                            Ok(ResolvedVotePollWithVotes::YesNoAbstainVotePollWithVotes(
+                                yes_no_abstain_vote_poll,
+                                vote_tally,
+                            ))
+                        }
                     }
                 }).collect::<Result<Vec<ResolvedVotePollWithVotes>, Error>>()?;
                 Ok((end_date, vote_polls_with_votes))
@@ -320,4 +362,31 @@ where
 
         Ok(())
     }
+
+    /// Tallies the votes cast on a yes/no/abstain vote poll, only masternodes that are still
+    /// in the masternode list are counted
+    fn tally_votes_for_yes_no_abstain_vote_poll(
+        &self,
+        block_platform_state: &PlatformState,
+        vote_poll: &YesNoAbstainVotePoll,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<YesNoAbstainVoteTally, Error> {
+        let vote_poll_state = self.drive.fetch_yes_no_abstain_vote_poll_state(
+            vote_poll.unique_id()?,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )?;
+
+        let masternode_list = block_platform_state.full_masternode_list();
+
+        let mut vote_tally = YesNoAbstainVoteTally::default();
+        for (pro_tx_hash, vote) in vote_poll_state.votes {
+            if masternode_list.contains_key(&ProTxHash::from_byte_array(pro_tx_hash.to_buffer())) {
+                vote_tally.add_vote(vote.vote_choice, vote.strength);
+            }
+        }
+        Ok(vote_tally)
+    }
 }
@@ -6,6 +6,7 @@ use dpp::identifier::Identifier;
 use dpp::prelude::TimestampMillis;
 use dpp::version::PlatformVersion;
 use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
 use drive::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfo;
 use drive::drive::votes::resolved::vote_polls::ResolvedVotePollWithVotes;
 use drive::grovedb::TransactionArg;
@@ -34,17 +35,44 @@ where
             &BTreeMap<ResourceVoteChoice, Vec<Identifier>>,
         )> = Vec::new();
 
+        let mut yes_no_abstain_polls: Vec<(&YesNoAbstainVotePoll, &TimestampMillis)> = Vec::new();
+
         // Iterate over the vote polls and match on the enum variant
         for (end_date, vote_polls_for_time) in vote_polls {
             for vote_poll in vote_polls_for_time {
                 match vote_poll {
                     ResolvedVotePollWithVotes::ContestedDocumentResourceVotePollWithContractInfoAndVotes(contested_poll, vote_info) => {
                         contested_polls.push((contested_poll, end_date, vote_info));
-                    } // Add more match arms here for other types of vote polls in the future
+                    }
+                    ResolvedVotePollWithVotes::YesNoAbstainVotePollWithVotes(yes_no_abstain_poll, _) => {
+                        yes_no_abstain_polls.push((yes_no_abstain_poll, end_date));
+                    }
                 }
             }
         }
 
+        if !yes_no_abstain_polls.is_empty() {
+            // Yes/no/abstain polls share the end date trees with contested polls, we remove them
+            // first in their own batch so that the contested clean up sees the trees it expects
+            let mut operations = vec![];
+            self.drive
+                .remove_yes_no_abstain_vote_poll_end_date_query_operations(
+                    &yes_no_abstain_polls,
+                    &mut operations,
+                    transaction,
+                    platform_version,
+                )?;
+            if !operations.is_empty() {
+                self.drive.apply_batch_low_level_drive_operations(
+                    None,
+                    transaction,
+                    operations,
+                    &mut vec![],
+                    &platform_version.drive,
+                )?;
+            }
+        }
+
         if !contested_polls.is_empty() {
             // Call the function to clean up contested document resource vote polls
             self.clean_up_after_contested_resources_vote_polls_end(
@@ -0,0 +1,34 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::block_state_transitions::BlockStateTransitions;
+use crate::platform_types::platform::Platform;
+use dpp::prelude::BlockHeight;
+use dpp::version::PlatformVersion;
+use drive::query::TransactionArg;
+
+mod v0;
+
+impl<C> Platform<C> {
+    /// Fetches the executed state transitions of a block from aux storage,
+    /// `None` if they were not retained
+    pub fn fetch_block_state_transitions(
+        &self,
+        block_height: BlockHeight,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<BlockStateTransitions>, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .platform_state_storage
+            .fetch_block_state_transitions
+        {
+            0 => self.fetch_block_state_transitions_v0(block_height, transaction, platform_version),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "fetch_block_state_transitions".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::error::Error;
+use crate::platform_types::block_state_transitions::BlockStateTransitions;
+use crate::platform_types::platform::Platform;
+use dpp::prelude::BlockHeight;
+use dpp::version::PlatformVersion;
+use drive::query::TransactionArg;
+
+impl<C> Platform<C> {
+    pub(super) fn fetch_block_state_transitions_v0(
+        &self,
+        block_height: BlockHeight,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<BlockStateTransitions>, Error> {
+        self.drive
+            .fetch_block_state_transitions_bytes(block_height, transaction, platform_version)
+            .map_err(Error::Drive)?
+            .map(|bytes| BlockStateTransitions::deserialize_from_bytes(&bytes))
+            .transpose()
+    }
+}
@@ -1,2 +1,4 @@
+mod fetch_block_state_transitions;
 mod fetch_platform_state;
+mod store_block_state_transitions;
 mod store_platform_state;
@@ -0,0 +1,41 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::block_state_transitions::BlockStateTransitions;
+use crate::platform_types::platform::Platform;
+use dpp::prelude::BlockHeight;
+use dpp::version::PlatformVersion;
+use drive::query::TransactionArg;
+
+impl<C> Platform<C> {
+    /// Stores the executed state transitions of a block in aux storage and removes
+    /// the ones of every block up to `pruned_up_to_block_height`, that fell out of the
+    /// retention window
+    pub fn store_block_state_transitions(
+        &self,
+        block_state_transitions: &BlockStateTransitions,
+        pruned_up_to_block_height: Option<BlockHeight>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .platform_state_storage
+            .store_block_state_transitions
+        {
+            0 => self.store_block_state_transitions_v0(
+                block_state_transitions,
+                pruned_up_to_block_height,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "store_block_state_transitions".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
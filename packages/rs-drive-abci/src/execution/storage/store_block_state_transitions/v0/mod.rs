@@ -0,0 +1,26 @@
+use crate::error::Error;
+use crate::platform_types::block_state_transitions::BlockStateTransitions;
+use crate::platform_types::platform::Platform;
+use dpp::prelude::BlockHeight;
+use dpp::version::PlatformVersion;
+use drive::query::TransactionArg;
+
+impl<C> Platform<C> {
+    pub(super) fn store_block_state_transitions_v0(
+        &self,
+        block_state_transitions: &BlockStateTransitions,
+        pruned_up_to_block_height: Option<BlockHeight>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        self.drive
+            .store_block_state_transitions_bytes(
+                block_state_transitions.block_height,
+                &block_state_transitions.serialize_to_bytes()?,
+                pruned_up_to_block_height,
+                transaction,
+                platform_version,
+            )
+            .map_err(Error::Drive)
+    }
+}
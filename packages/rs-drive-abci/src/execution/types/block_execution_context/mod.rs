@@ -7,6 +7,8 @@ use crate::execution::types::block_execution_context::v0::{
     BlockExecutionContextV0OwnedGetters, BlockExecutionContextV0Setters,
 };
 use crate::execution::types::block_state_info::BlockStateInfo;
+use crate::platform_types::block_state_transitions::ExecutedStateTransition;
+use crate::platform_types::document_transitions_event::ExecutedBatchTransition;
 use crate::platform_types::epoch_info::EpochInfo;
 use crate::platform_types::platform_state::PlatformState;
 use crate::platform_types::withdrawal::unsigned_withdrawal_txs::v0::UnsignedWithdrawalTxs;
@@ -59,6 +61,18 @@ impl BlockExecutionContextV0Getters for BlockExecutionContext {
             BlockExecutionContext::V0(v0) => v0.proposer_results.as_ref(),
         }
     }
+
+    fn executed_batch_transitions(&self) -> &[ExecutedBatchTransition] {
+        match self {
+            BlockExecutionContext::V0(v0) => &v0.executed_batch_transitions,
+        }
+    }
+
+    fn executed_state_transitions(&self) -> &[ExecutedStateTransition] {
+        match self {
+            BlockExecutionContext::V0(v0) => &v0.executed_state_transitions,
+        }
+    }
 }
 
 impl BlockExecutionContextV0Setters for BlockExecutionContext {
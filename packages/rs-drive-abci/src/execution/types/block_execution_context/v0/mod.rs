@@ -1,5 +1,8 @@
 use crate::execution::types::block_state_info::BlockStateInfo;
 
+use crate::platform_types::block_state_transitions::ExecutedStateTransition;
+use crate::platform_types::document_transitions_event::ExecutedBatchTransition;
+
 use crate::platform_types::epoch_info::EpochInfo;
 use crate::platform_types::platform_state::PlatformState;
 use crate::platform_types::withdrawal::unsigned_withdrawal_txs::v0::UnsignedWithdrawalTxs;
@@ -19,6 +22,12 @@ pub struct BlockExecutionContextV0 {
     pub block_platform_state: PlatformState,
     /// The response prepare proposal if proposed by us
     pub proposer_results: Option<ResponsePrepareProposal>,
+    /// Successfully executed batch transitions, only collected when there are document
+    /// subscribers
+    pub executed_batch_transitions: Vec<ExecutedBatchTransition>,
+    /// The state transitions of the block with their execution results, only collected when
+    /// block state transitions are retained
+    pub executed_state_transitions: Vec<ExecutedStateTransition>,
 }
 /// A trait defining getter methods for interacting with a BlockExecutionContextV0.
 pub trait BlockExecutionContextV0Getters {
@@ -36,6 +45,12 @@ pub trait BlockExecutionContextV0Getters {
 
     /// Returns a reference of the proposer_results field.
     fn proposer_results(&self) -> Option<&ResponsePrepareProposal>;
+
+    /// Returns a reference of the executed_batch_transitions field.
+    fn executed_batch_transitions(&self) -> &[ExecutedBatchTransition];
+
+    /// Returns a reference of the executed_state_transitions field.
+    fn executed_state_transitions(&self) -> &[ExecutedStateTransition];
 }
 
 /// A trait defining setter methods for interacting with a BlockExecutionContextV0.
@@ -114,6 +129,16 @@ impl BlockExecutionContextV0Getters for BlockExecutionContextV0 {
     fn proposer_results(&self) -> Option<&ResponsePrepareProposal> {
         self.proposer_results.as_ref()
     }
+
+    /// Returns a reference to the executed_batch_transitions field.
+    fn executed_batch_transitions(&self) -> &[ExecutedBatchTransition] {
+        &self.executed_batch_transitions
+    }
+
+    /// Returns a reference to the executed_state_transitions field.
+    fn executed_state_transitions(&self) -> &[ExecutedStateTransition] {
+        &self.executed_state_transitions
+    }
 }
 
 impl BlockExecutionContextV0Setters for BlockExecutionContextV0 {
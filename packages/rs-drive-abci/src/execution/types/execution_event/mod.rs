@@ -186,16 +186,26 @@ impl ExecutionEvent<'_> {
                     )))
                 }
             }
-            StateTransitionAction::MasternodeVoteAction(_) => {
+            StateTransitionAction::MasternodeVoteAction(masternode_vote_action) => {
+                // Votes on polls without a specialized balance (yes/no/abstain polls) are free
+                let fees_to_add_to_pool = if masternode_vote_action
+                    .vote_ref()
+                    .specialized_balance_id()?
+                    .is_some()
+                {
+                    platform_version
+                        .fee_version
+                        .vote_resolution_fund_fees
+                        .contested_document_single_vote_cost
+                } else {
+                    0
+                };
                 let operations =
                     action.into_high_level_drive_operations(epoch, platform_version)?;
 
                 Ok(ExecutionEvent::PaidFixedCost {
                     operations,
-                    fees_to_add_to_pool: platform_version
-                        .fee_version
-                        .vote_resolution_fund_fees
-                        .contested_document_single_vote_cost,
+                    fees_to_add_to_pool,
                 })
             }
             StateTransitionAction::DataContractCreateAction(data_contract_create_action) => {
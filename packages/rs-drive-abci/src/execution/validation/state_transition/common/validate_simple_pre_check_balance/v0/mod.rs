@@ -45,6 +45,22 @@ impl ValidateSimplePreCheckBalanceV0 for StateTransition {
                             .registration_cost(platform_version)?,
                     )
             }
+            StateTransition::DataContractDelete(_) => {
+                // deleting a contract only needs to cover the nonce bump and the removals,
+                // storage is refunded afterwards
+                platform_version
+                    .fee_version
+                    .state_transition_min_fees
+                    .contract_update
+            }
+            StateTransition::VotePollCreate(_) => {
+                // creating a poll stores a snapshot of the masternode list, the actual cost is
+                // charged on execution
+                platform_version
+                    .fee_version
+                    .state_transition_min_fees
+                    .contract_update
+            }
             StateTransition::Batch(_) => {
                 platform_version
                     .fee_version
@@ -3,6 +3,7 @@ pub(crate) mod v0;
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
 use crate::execution::types::execution_event::ExecutionEvent;
+use crate::execution::validation::state_transition::ValidationMode;
 use crate::platform_types::platform::PlatformRef;
 use crate::rpc::core::CoreRPCLike;
 use dpp::block::block_info::BlockInfo;
@@ -29,10 +30,14 @@ use drive::grovedb::TransactionArg;
 /// Validate state verifies that there are no state based conflicts, for example that a document
 /// with a unique index isn't already taken.
 ///
+/// Blocks are processed in the validator mode, simulations use a mode that validates the same
+/// way without altering caches.
+///
 pub(in crate::execution) fn process_state_transition<'a, C: CoreRPCLike>(
     platform: &'a PlatformRef<C>,
     block_info: &BlockInfo,
     state_transition: StateTransition,
+    validation_mode: ValidationMode,
     transaction: TransactionArg,
 ) -> Result<ConsensusValidationResult<ExecutionEvent<'a>>, Error> {
     let platform_version = platform.state.current_platform_version()?;
@@ -45,6 +50,7 @@ pub(in crate::execution) fn process_state_transition<'a, C: CoreRPCLike>(
             platform,
             block_info,
             state_transition,
+            validation_mode,
             transaction,
             platform_version,
         ),
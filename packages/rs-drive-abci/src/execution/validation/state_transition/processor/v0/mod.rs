@@ -34,6 +34,7 @@ pub(super) fn process_state_transition_v0<'a, C: CoreRPCLike>(
     platform: &'a PlatformRef<C>,
     block_info: &BlockInfo,
     state_transition: StateTransition,
+    validation_mode: ValidationMode,
     transaction: TransactionArg,
     platform_version: &PlatformVersion,
 ) -> Result<ConsensusValidationResult<ExecutionEvent<'a>>, Error> {
@@ -197,7 +198,7 @@ pub(super) fn process_state_transition_v0<'a, C: CoreRPCLike>(
         let state_transition_action_result = state_transition.transform_into_action(
             platform,
             block_info,
-            ValidationMode::Validator,
+            validation_mode,
             &mut state_transition_execution_context,
             transaction,
         )?;
@@ -245,7 +246,7 @@ pub(super) fn process_state_transition_v0<'a, C: CoreRPCLike>(
     let result = state_transition.validate_state(
         action,
         platform,
-        ValidationMode::Validator,
+        validation_mode,
         block_info,
         &mut state_transition_execution_context,
         transaction,
@@ -519,7 +520,9 @@ impl StateTransitionBasicStructureValidationV0 for StateTransition {
         platform_version: &PlatformVersion,
     ) -> Result<SimpleConsensusValidationResult, Error> {
         match self {
-            StateTransition::MasternodeVote(_) => {
+            StateTransition::MasternodeVote(_)
+            | StateTransition::DataContractDelete(_)
+            | StateTransition::VotePollCreate(_) => {
                 // no basic structure validation
                 Ok(SimpleConsensusValidationResult::new())
             }
@@ -599,7 +602,9 @@ impl StateTransitionBasicStructureValidationV0 for StateTransition {
             | StateTransition::IdentityCreditWithdrawal(_)
             | StateTransition::IdentityUpdate(_)
             | StateTransition::IdentityCreditTransfer(_) => true,
-            StateTransition::MasternodeVote(_) => false,
+            StateTransition::MasternodeVote(_)
+            | StateTransition::DataContractDelete(_)
+            | StateTransition::VotePollCreate(_) => false,
         }
     }
 }
@@ -635,6 +640,13 @@ impl StateTransitionNonceValidationV0 for StateTransition {
                 execution_context,
                 platform_version,
             ),
+            StateTransition::DataContractDelete(st) => st.validate_nonces(
+                platform,
+                block_info,
+                tx,
+                execution_context,
+                platform_version,
+            ),
             StateTransition::IdentityUpdate(st) => st.validate_nonces(
                 platform,
                 block_info,
@@ -663,6 +675,13 @@ impl StateTransitionNonceValidationV0 for StateTransition {
                 execution_context,
                 platform_version,
             ),
+            StateTransition::VotePollCreate(st) => st.validate_nonces(
+                platform,
+                block_info,
+                tx,
+                execution_context,
+                platform_version,
+            ),
             _ => Ok(SimpleConsensusValidationResult::new()),
         }
     }
@@ -681,6 +700,7 @@ impl StateTransitionHasNonceValidationV0 for StateTransition {
                     StateTransition::Batch(_)
                         | StateTransition::DataContractCreate(_)
                         | StateTransition::DataContractUpdate(_)
+                        | StateTransition::DataContractDelete(_)
                         | StateTransition::IdentityUpdate(_)
                         | StateTransition::IdentityCreditTransfer(_)
                         | StateTransition::IdentityCreditWithdrawal(_)
@@ -695,10 +715,12 @@ impl StateTransitionHasNonceValidationV0 for StateTransition {
                     StateTransition::Batch(_)
                     | StateTransition::DataContractCreate(_)
                     | StateTransition::DataContractUpdate(_)
+                    | StateTransition::DataContractDelete(_)
                     | StateTransition::IdentityUpdate(_)
                     | StateTransition::IdentityCreditTransfer(_)
                     | StateTransition::IdentityCreditWithdrawal(_)
-                    | StateTransition::MasternodeVote(_) => true,
+                    | StateTransition::MasternodeVote(_)
+                    | StateTransition::VotePollCreate(_) => true,
                     StateTransition::IdentityCreate(_) | StateTransition::IdentityTopUp(_) => false,
                 };
 
@@ -731,6 +753,8 @@ impl StateTransitionIdentityBalanceValidationV0 for StateTransition {
             }
             StateTransition::DataContractCreate(_)
             | StateTransition::DataContractUpdate(_)
+            | StateTransition::DataContractDelete(_)
+            | StateTransition::VotePollCreate(_)
             | StateTransition::IdentityUpdate(_) => {
                 self.validate_simple_pre_check_minimum_balance(identity, platform_version)
             }
@@ -747,6 +771,8 @@ impl StateTransitionIdentityBalanceValidationV0 for StateTransition {
                 | StateTransition::IdentityCreditWithdrawal(_)
                 | StateTransition::DataContractCreate(_)
                 | StateTransition::DataContractUpdate(_)
+                | StateTransition::DataContractDelete(_)
+                | StateTransition::VotePollCreate(_)
                 | StateTransition::Batch(_)
                 | StateTransition::IdentityUpdate(_)
         )
@@ -913,6 +939,8 @@ impl StateTransitionIdentityBasedSignatureValidationV0 for StateTransition {
         match self {
             StateTransition::DataContractCreate(_)
             | StateTransition::DataContractUpdate(_)
+            | StateTransition::DataContractDelete(_)
+            | StateTransition::VotePollCreate(_)
             | StateTransition::IdentityCreditTransfer(_)
             | StateTransition::Batch(_) => {
                 //Basic signature verification
@@ -1040,6 +1068,15 @@ impl StateTransitionStateValidationV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            // The replay attack is prevented by identity data contract nonce
+            StateTransition::DataContractDelete(st) => st.validate_state(
+                action,
+                platform,
+                validation_mode,
+                block_info,
+                execution_context,
+                tx,
+            ),
             StateTransition::IdentityCreate(st) => {
                 let action =
                     action.ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
@@ -1113,6 +1150,15 @@ impl StateTransitionStateValidationV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            // The replay attack is prevented by identity nonce
+            StateTransition::VotePollCreate(st) => st.validate_state(
+                action,
+                platform,
+                validation_mode,
+                block_info,
+                execution_context,
+                tx,
+            ),
         }
     }
 }
@@ -1123,12 +1169,14 @@ impl StateTransitionIsAllowedValidationV0 for StateTransition {
             StateTransition::Batch(st) => st.has_is_allowed_validation(platform_version),
             StateTransition::DataContractCreate(_)
             | StateTransition::DataContractUpdate(_)
+            | StateTransition::DataContractDelete(_)
             | StateTransition::IdentityCreate(_)
             | StateTransition::IdentityTopUp(_)
             | StateTransition::IdentityCreditWithdrawal(_)
             | StateTransition::IdentityUpdate(_)
             | StateTransition::IdentityCreditTransfer(_)
-            | StateTransition::MasternodeVote(_) => Ok(false),
+            | StateTransition::MasternodeVote(_)
+            | StateTransition::VotePollCreate(_) => Ok(false),
         }
     }
 
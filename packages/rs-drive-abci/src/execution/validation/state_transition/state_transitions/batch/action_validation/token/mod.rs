@@ -1,12 +1,18 @@
+pub(crate) mod token_approve_transition_action;
 pub(crate) mod token_base_transition_action;
 pub(crate) mod token_burn_transition_action;
+pub(crate) mod token_cancel_order_transition_action;
 pub(crate) mod token_claim_transition_action;
+pub(crate) mod token_claim_vested_transition_action;
 pub(crate) mod token_config_update_transition_action;
 pub(crate) mod token_destroy_frozen_funds_transition_action;
 pub(crate) mod token_direct_purchase_transition_action;
 pub(crate) mod token_emergency_action_transition_action;
 pub(crate) mod token_freeze_transition_action;
 pub(crate) mod token_mint_transition_action;
+pub(crate) mod token_place_order_transition_action;
 pub(crate) mod token_set_price_for_direct_purchase_transition_action;
+pub(crate) mod token_transfer_from_transition_action;
 pub(crate) mod token_transfer_transition_action;
 pub(crate) mod token_unfreeze_transition_action;
+pub(crate) mod token_vesting_transfer_transition_action;
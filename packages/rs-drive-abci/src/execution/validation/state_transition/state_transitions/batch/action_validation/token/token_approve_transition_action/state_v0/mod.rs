@@ -0,0 +1,116 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::consensus::ConsensusError;
+use dpp::consensus::state::identity::RecipientIdentityDoesNotExistError;
+use dpp::consensus::state::state_error::StateError;
+use dpp::consensus::state::token::{InvalidGroupPositionError, TokenIsPausedError};
+use dpp::data_contract::accessors::v1::DataContractV1Getters;
+use dpp::prelude::Identifier;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::status::v0::TokenStatusV0Accessors;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::batch::batched_transition::token_transition::token_approve_transition_action::{TokenApproveTransitionAction, TokenApproveTransitionActionAccessorsV0};
+use dpp::version::PlatformVersion;
+use drive::query::TransactionArg;
+use crate::error::Error;
+use crate::execution::types::execution_operation::ValidationOperation;
+use crate::execution::types::state_transition_execution_context::{StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0};
+use crate::execution::validation::state_transition::batch::action_validation::token::token_base_transition_action::TokenBaseTransitionActionValidation;
+use crate::execution::validation::state_transition::common::validate_identity_exists::validate_identity_exists;
+use crate::platform_types::platform::PlatformStateRef;
+
+pub(in crate::execution::validation::state_transition::state_transitions::batch::action_validation) trait TokenApproveTransitionActionStateValidationV0 {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl TokenApproveTransitionActionStateValidationV0 for TokenApproveTransitionAction {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let validation_result = self.base().validate_state(
+            platform,
+            owner_id,
+            block_info,
+            execution_context,
+            transaction,
+            platform_version,
+        )?;
+
+        if !validation_result.is_valid() {
+            return Ok(validation_result);
+        }
+
+        // We need to verify that the token is not paused
+        let (token_status, fee_result) = platform.drive.fetch_token_status_with_costs(
+            self.token_id().to_buffer(),
+            block_info,
+            true,
+            transaction,
+            platform_version,
+        )?;
+
+        execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+        if let Some(status) = token_status {
+            if status.paused() {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    ConsensusError::StateError(StateError::TokenIsPausedError(
+                        TokenIsPausedError::new(self.token_id()),
+                    )),
+                ));
+            }
+        }
+
+        // Revoking an allowance never needs the spender to still exist
+        if self.amount() == 0 {
+            return Ok(SimpleConsensusValidationResult::new());
+        }
+
+        match self.spender() {
+            TokenAllowanceSpender::Identity(spender_id) => {
+                let spender_exists = validate_identity_exists(
+                    platform.drive,
+                    &spender_id,
+                    execution_context,
+                    transaction,
+                    platform_version,
+                )?;
+
+                if !spender_exists {
+                    return Ok(SimpleConsensusValidationResult::new_with_error(
+                        ConsensusError::StateError(StateError::RecipientIdentityDoesNotExistError(
+                            RecipientIdentityDoesNotExistError::new(spender_id),
+                        )),
+                    ));
+                }
+            }
+            TokenAllowanceSpender::Group(group_contract_position) => {
+                let groups = self.data_contract_fetch_info_ref().contract.groups();
+                if !groups.contains_key(&group_contract_position) {
+                    return Ok(SimpleConsensusValidationResult::new_with_error(
+                        ConsensusError::StateError(StateError::InvalidGroupPositionError(
+                            InvalidGroupPositionError::new(
+                                groups.keys().last().copied(),
+                                group_contract_position,
+                            ),
+                        )),
+                    ));
+                }
+            }
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
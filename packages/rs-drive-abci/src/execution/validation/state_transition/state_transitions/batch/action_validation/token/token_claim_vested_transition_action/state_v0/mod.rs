@@ -0,0 +1,112 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::consensus::ConsensusError;
+use dpp::consensus::state::state_error::StateError;
+use dpp::consensus::state::token::{IdentityTokenAccountFrozenError, TokenIsPausedError};
+use dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
+use dpp::prelude::Identifier;
+use dpp::tokens::info::v0::IdentityTokenInfoV0Accessors;
+use dpp::tokens::status::v0::TokenStatusV0Accessors;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::state_transition_action::batch::batched_transition::token_transition::token_claim_vested_transition_action::{TokenClaimVestedTransitionAction, TokenClaimVestedTransitionActionAccessorsV0};
+use dpp::version::PlatformVersion;
+use drive::query::TransactionArg;
+use drive::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionActionAccessorsV0;
+use crate::error::Error;
+use crate::execution::types::execution_operation::ValidationOperation;
+use crate::execution::types::state_transition_execution_context::{StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0};
+use crate::execution::validation::state_transition::batch::action_validation::token::token_base_transition_action::TokenBaseTransitionActionValidation;
+use crate::platform_types::platform::PlatformStateRef;
+
+pub(in crate::execution::validation::state_transition::state_transitions::batch::action_validation) trait TokenClaimVestedTransitionActionStateValidationV0 {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl TokenClaimVestedTransitionActionStateValidationV0 for TokenClaimVestedTransitionAction {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        // The existence of the vesting and the amount that vested were already checked when
+        // transforming the transition into an action
+        let validation_result = self.base().validate_state(
+            platform,
+            owner_id,
+            block_info,
+            execution_context,
+            transaction,
+            platform_version,
+        )?;
+
+        if !validation_result.is_valid() {
+            return Ok(validation_result);
+        }
+
+        // Vested tokens can only be released to a frozen account if the token allows it
+        if !self
+            .base()
+            .token_configuration()?
+            .is_allowed_transfer_to_frozen_balance()
+        {
+            let (info, fee_result) = platform.drive.fetch_identity_token_info_with_costs(
+                self.token_id().to_buffer(),
+                owner_id.to_buffer(),
+                block_info,
+                true,
+                transaction,
+                platform_version,
+            )?;
+
+            execution_context
+                .add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+            if let Some(info) = info {
+                if info.frozen() {
+                    return Ok(SimpleConsensusValidationResult::new_with_error(
+                        ConsensusError::StateError(StateError::IdentityTokenAccountFrozenError(
+                            IdentityTokenAccountFrozenError::new(
+                                self.token_id(),
+                                owner_id,
+                                "claimVested".to_string(),
+                            ),
+                        )),
+                    ));
+                }
+            };
+        }
+
+        // We need to verify that the token is not paused
+        let (token_status, fee_result) = platform.drive.fetch_token_status_with_costs(
+            self.token_id().to_buffer(),
+            block_info,
+            true,
+            transaction,
+            platform_version,
+        )?;
+
+        execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+        if let Some(status) = token_status {
+            if status.paused() {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    ConsensusError::StateError(StateError::TokenIsPausedError(
+                        TokenIsPausedError::new(self.token_id()),
+                    )),
+                ));
+            }
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
@@ -0,0 +1,234 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::consensus::ConsensusError;
+use dpp::consensus::basic::BasicError;
+use dpp::consensus::basic::overflow_error::OverflowError;
+use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
+use dpp::consensus::state::state_error::StateError;
+use dpp::consensus::state::token::{IdentityDoesNotHaveEnoughTokenBalanceError, IdentityTokenAccountFrozenError, TokenIsPausedError, TokenOrderBookTradingNotAllowedError};
+use dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
+use dpp::data_contract::associated_token::token_marketplace_rules::accessors::v0::TokenMarketplaceRulesV0Getters;
+use dpp::prelude::Identifier;
+use dpp::tokens::info::v0::IdentityTokenInfoV0Accessors;
+use dpp::tokens::order_book::TokenOrderSide;
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+use dpp::tokens::status::v0::TokenStatusV0Accessors;
+use dpp::validation::SimpleConsensusValidationResult;
+use dpp::ProtocolError;
+use drive::state_transition_action::batch::batched_transition::token_transition::token_place_order_transition_action::{TokenPlaceOrderTransitionAction, TokenPlaceOrderTransitionActionAccessorsV0};
+use dpp::version::PlatformVersion;
+use drive::query::TransactionArg;
+use drive::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionActionAccessorsV0;
+use crate::error::Error;
+use crate::execution::types::execution_operation::{RetrieveIdentityInfo, ValidationOperation};
+use crate::execution::types::state_transition_execution_context::{StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0};
+use crate::execution::validation::state_transition::batch::action_validation::token::token_base_transition_action::TokenBaseTransitionActionValidation;
+use crate::platform_types::platform::PlatformStateRef;
+
+pub(in crate::execution::validation::state_transition::state_transitions::batch::action_validation) trait TokenPlaceOrderTransitionActionStateValidationV0 {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error>;
+}
+impl TokenPlaceOrderTransitionActionStateValidationV0 for TokenPlaceOrderTransitionAction {
+    fn validate_state_v0(
+        &self,
+        platform: &PlatformStateRef,
+        owner_id: Identifier,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let validation_result = self.base().validate_state(
+            platform,
+            owner_id,
+            block_info,
+            execution_context,
+            transaction,
+            platform_version,
+        )?;
+
+        if !validation_result.is_valid() {
+            return Ok(validation_result);
+        }
+
+        let order = self.order();
+
+        // The token must be tradeable on the order book in the quote currency
+        if !self
+            .base()
+            .token_configuration()?
+            .marketplace_rules()
+            .trade_mode()
+            .allows_order_book_trading_in(&order.quote_currency())
+        {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::StateError(StateError::TokenOrderBookTradingNotAllowedError(
+                    TokenOrderBookTradingNotAllowedError::new(
+                        self.token_id(),
+                        order.quote_currency(),
+                    ),
+                )),
+            ));
+        }
+
+        // We need to verify that the token is not paused
+        let (token_status, fee_result) = platform.drive.fetch_token_status_with_costs(
+            self.token_id().to_buffer(),
+            block_info,
+            true,
+            transaction,
+            platform_version,
+        )?;
+
+        execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+        if let Some(status) = token_status {
+            if status.paused() {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    ConsensusError::StateError(StateError::TokenIsPausedError(
+                        TokenIsPausedError::new(self.token_id()),
+                    )),
+                ));
+            }
+        }
+
+        // We need to verify that our token account is not frozen
+        let (info, fee_result) = platform.drive.fetch_identity_token_info_with_costs(
+            self.token_id().to_buffer(),
+            owner_id.to_buffer(),
+            block_info,
+            true,
+            transaction,
+            platform_version,
+        )?;
+
+        execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+        if let Some(info) = info {
+            if info.frozen() {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    ConsensusError::StateError(StateError::IdentityTokenAccountFrozenError(
+                        IdentityTokenAccountFrozenError::new(
+                            self.token_id(),
+                            owner_id,
+                            "place order".to_string(),
+                        ),
+                    )),
+                ));
+            }
+        };
+
+        // We need to verify that we can pay for what we are placing the order for
+        match order.side() {
+            TokenOrderSide::Sell => {
+                let balance = platform
+                    .drive
+                    .fetch_identity_token_balance(
+                        self.token_id().to_buffer(),
+                        owner_id.to_buffer(),
+                        transaction,
+                        platform_version,
+                    )?
+                    .unwrap_or_default();
+
+                execution_context.add_operation(ValidationOperation::RetrieveIdentityTokenBalance);
+
+                if balance < order.original_amount() {
+                    return Ok(SimpleConsensusValidationResult::new_with_error(
+                        ConsensusError::StateError(
+                            StateError::IdentityDoesNotHaveEnoughTokenBalanceError(
+                                IdentityDoesNotHaveEnoughTokenBalanceError::new(
+                                    self.token_id(),
+                                    owner_id,
+                                    order.original_amount(),
+                                    balance,
+                                    "place order".to_string(),
+                                ),
+                            ),
+                        ),
+                    ));
+                }
+            }
+            TokenOrderSide::Buy => {
+                let required_quote_amount = match self.required_quote_amount() {
+                    Ok(required_quote_amount) => required_quote_amount,
+                    Err(ProtocolError::Overflow(e)) => {
+                        return Ok(SimpleConsensusValidationResult::new_with_error(
+                            ConsensusError::BasicError(BasicError::OverflowError(
+                                OverflowError::new(e.to_owned()),
+                            )),
+                        ))
+                    }
+                    Err(e) => return Err(e.into()),
+                };
+
+                match order.quote_currency().token_id() {
+                    None => {
+                        let balance = platform
+                            .drive
+                            .fetch_identity_balance(
+                                owner_id.to_buffer(),
+                                transaction,
+                                platform_version,
+                            )?
+                            .unwrap_or_default();
+
+                        execution_context.add_operation(ValidationOperation::RetrieveIdentity(
+                            RetrieveIdentityInfo::only_balance(),
+                        ));
+
+                        if balance < required_quote_amount {
+                            return Ok(SimpleConsensusValidationResult::new_with_error(
+                                IdentityInsufficientBalanceError::new(
+                                    owner_id,
+                                    balance,
+                                    required_quote_amount,
+                                )
+                                .into(),
+                            ));
+                        }
+                    }
+                    Some(quote_token_id) => {
+                        let balance = platform
+                            .drive
+                            .fetch_identity_token_balance(
+                                quote_token_id.to_buffer(),
+                                owner_id.to_buffer(),
+                                transaction,
+                                platform_version,
+                            )?
+                            .unwrap_or_default();
+
+                        execution_context
+                            .add_operation(ValidationOperation::RetrieveIdentityTokenBalance);
+
+                        if balance < required_quote_amount {
+                            return Ok(SimpleConsensusValidationResult::new_with_error(
+                                ConsensusError::StateError(
+                                    StateError::IdentityDoesNotHaveEnoughTokenBalanceError(
+                                        IdentityDoesNotHaveEnoughTokenBalanceError::new(
+                                            quote_token_id,
+                                            owner_id,
+                                            required_quote_amount,
+                                            balance,
+                                            "place order".to_string(),
+                                        ),
+                                    ),
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+}
@@ -47,6 +47,7 @@ impl ValidationMode {
             ValidationMode::CheckTx => false,
             ValidationMode::RecheckTx => false,
             ValidationMode::Validator => true,
+            ValidationMode::Simulation => true,
             ValidationMode::NoValidation => false,
         }
     }
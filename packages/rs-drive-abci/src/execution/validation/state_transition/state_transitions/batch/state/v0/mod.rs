@@ -32,6 +32,12 @@ use crate::execution::validation::state_transition::batch::action_validation::to
 use crate::execution::validation::state_transition::batch::action_validation::token::token_set_price_for_direct_purchase_transition_action::TokenSetPriceForDirectPurchaseTransitionActionValidation;
 use crate::execution::validation::state_transition::batch::action_validation::token::token_transfer_transition_action::TokenTransferTransitionActionValidation;
 use crate::execution::validation::state_transition::batch::action_validation::token::token_unfreeze_transition_action::TokenUnfreezeTransitionActionValidation;
+use crate::execution::validation::state_transition::batch::action_validation::token::token_place_order_transition_action::TokenPlaceOrderTransitionActionValidation;
+use crate::execution::validation::state_transition::batch::action_validation::token::token_cancel_order_transition_action::TokenCancelOrderTransitionActionValidation;
+use crate::execution::validation::state_transition::batch::action_validation::token::token_vesting_transfer_transition_action::TokenVestingTransferTransitionActionValidation;
+use crate::execution::validation::state_transition::batch::action_validation::token::token_claim_vested_transition_action::TokenClaimVestedTransitionActionValidation;
+use crate::execution::validation::state_transition::batch::action_validation::token::token_approve_transition_action::TokenApproveTransitionActionValidation;
+use crate::execution::validation::state_transition::batch::action_validation::token::token_transfer_from_transition_action::TokenTransferFromTransitionActionValidation;
 use crate::execution::validation::state_transition::batch::data_triggers::{data_trigger_bindings_list, DataTriggerExecutionContext, DataTriggerExecutor};
 use crate::platform_types::platform::{PlatformStateRef};
 use crate::execution::validation::state_transition::state_transitions::batch::transformer::v0::BatchTransitionTransformerV0;
@@ -251,6 +257,65 @@ impl DocumentsBatchStateTransitionStateValidationV0 for BatchTransition {
                         transaction,
                         platform_version,
                     )?,
+                    TokenTransitionAction::PlaceOrderAction(place_order_action) => {
+                        place_order_action.validate_state(
+                            platform,
+                            owner_id,
+                            block_info,
+                            execution_context,
+                            transaction,
+                            platform_version,
+                        )?
+                    }
+                    TokenTransitionAction::CancelOrderAction(cancel_order_action) => {
+                        cancel_order_action.validate_state(
+                            platform,
+                            owner_id,
+                            block_info,
+                            execution_context,
+                            transaction,
+                            platform_version,
+                        )?
+                    }
+                    TokenTransitionAction::VestingTransferAction(vesting_transfer_action) => {
+                        vesting_transfer_action.validate_state(
+                            platform,
+                            owner_id,
+                            block_info,
+                            execution_context,
+                            transaction,
+                            platform_version,
+                        )?
+                    }
+                    TokenTransitionAction::ClaimVestedAction(claim_vested_action) => {
+                        claim_vested_action.validate_state(
+                            platform,
+                            owner_id,
+                            block_info,
+                            execution_context,
+                            transaction,
+                            platform_version,
+                        )?
+                    }
+                    TokenTransitionAction::ApproveAction(approve_action) => approve_action
+                        .validate_state(
+                            platform,
+                            owner_id,
+                            block_info,
+                            execution_context,
+                            transaction,
+                            platform_version,
+                        )?,
+                    TokenTransitionAction::TransferFromAction(transfer_from_action) => {
+                        transfer_from_action.validate_state(
+                            platform,
+                            owner_id,
+                            block_info,
+                            execution_context,
+                            transaction,
+                            platform_version,
+                        )?
+                    }
                 },
                 BatchedTransitionAction::BumpIdentityDataContractNonce(_) => {
                     return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
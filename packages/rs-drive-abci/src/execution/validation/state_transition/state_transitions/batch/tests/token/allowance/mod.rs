@@ -0,0 +1,923 @@
+use super::*;
+
+mod token_allowance_tests {
+    use super::*;
+    use crate::platform_types::state_transitions_processing_result::StateTransitionsProcessingResult;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::TempPlatform;
+    use dpp::prelude::{DataContract, Identity, IdentityPublicKey, TimestampMillis};
+    use dpp::state_transition::StateTransition;
+    use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+    use dpp::tokens::allowance::v0::TokenAllowanceV0Accessors;
+    use dpp::tokens::emergency_action::TokenEmergencyAction;
+    use simple_signer::signer::SimpleSigner;
+
+    type TestIdentity = (Identity, SimpleSigner, IdentityPublicKey);
+
+    fn process_transition(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+        transition: StateTransition,
+        block_info: &BlockInfo,
+        platform_version: &PlatformVersion,
+    ) -> StateTransitionsProcessingResult {
+        let serialized_transition = transition
+            .serialize_to_bytes()
+            .expect("expected documents batch serialized state transition");
+
+        let platform_state = platform.state.load();
+        let transaction = platform.drive.grove.start_transaction();
+
+        let processing_result = platform
+            .platform
+            .process_raw_state_transitions(
+                &[serialized_transition],
+                &platform_state,
+                block_info,
+                &transaction,
+                platform_version,
+                false,
+                None,
+            )
+            .expect("expected to process state transition");
+
+        platform
+            .drive
+            .grove
+            .commit_transaction(transaction)
+            .unwrap()
+            .expect("expected to commit transaction");
+
+        processing_result
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn approve(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+        contract: &DataContract,
+        token_id: Identifier,
+        (identity, signer, key): &TestIdentity,
+        spender_id: Identifier,
+        amount: u64,
+        expires_at: Option<TimestampMillis>,
+        nonce: u64,
+        platform_version: &PlatformVersion,
+    ) -> StateTransitionsProcessingResult {
+        let approve_transition = BatchTransition::new_token_approve_transition(
+            token_id,
+            identity.id(),
+            contract.id(),
+            0,
+            TokenAllowanceSpender::Identity(spender_id),
+            amount,
+            expires_at,
+            None,
+            key,
+            nonce,
+            0,
+            signer,
+            platform_version,
+            None,
+        )
+        .expect("expect to create documents batch transition");
+
+        process_transition(
+            platform,
+            approve_transition,
+            &BlockInfo::default(),
+            platform_version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn transfer_from(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+        contract: &DataContract,
+        token_id: Identifier,
+        (identity, signer, key): &TestIdentity,
+        from_identity_id: Identifier,
+        recipient_id: Identifier,
+        amount: u64,
+        nonce: u64,
+        block_info: &BlockInfo,
+        platform_version: &PlatformVersion,
+    ) -> StateTransitionsProcessingResult {
+        let transfer_from_transition = BatchTransition::new_token_transfer_from_transition(
+            token_id,
+            identity.id(),
+            contract.id(),
+            0,
+            from_identity_id,
+            recipient_id,
+            amount,
+            None,
+            None,
+            key,
+            nonce,
+            0,
+            signer,
+            platform_version,
+            None,
+        )
+        .expect("expect to create documents batch transition");
+
+        process_transition(
+            platform,
+            transfer_from_transition,
+            block_info,
+            platform_version,
+        )
+    }
+
+    fn token_balance(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        token_id: Identifier,
+        identity_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Option<u64> {
+        platform
+            .drive
+            .fetch_identity_token_balance(
+                token_id.to_buffer(),
+                identity_id.to_buffer(),
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch token balance")
+    }
+
+    fn allowance_amount(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        token_id: Identifier,
+        owner_id: Identifier,
+        spender_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Option<u64> {
+        platform
+            .drive
+            .fetch_token_allowance(
+                token_id.to_buffer(),
+                owner_id.to_buffer(),
+                TokenAllowanceSpender::Identity(spender_id),
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch token allowance")
+            .map(|allowance| allowance.amount())
+    }
+
+    /// Sets up a token owned by the first identity along with a spender and a recipient.
+    fn setup_token_with_spender(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+        token_configuration_modification: Option<impl FnOnce(&mut TokenConfiguration)>,
+        platform_version: &PlatformVersion,
+    ) -> (
+        DataContract,
+        Identifier,
+        TestIdentity,
+        TestIdentity,
+        Identity,
+    ) {
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let owner = setup_identity(platform, rng.gen(), dash_to_credits!(0.5));
+
+        let spender = setup_identity(platform, rng.gen(), dash_to_credits!(0.5));
+
+        let (recipient, _, _) = setup_identity(platform, rng.gen(), dash_to_credits!(0.5));
+
+        let (contract, token_id) = create_token_contract_with_owner_identity(
+            platform,
+            owner.0.id(),
+            token_configuration_modification,
+            None,
+            None,
+            None,
+            platform_version,
+        );
+
+        (contract, token_id, owner, spender, recipient)
+    }
+
+    #[test]
+    fn test_token_transfer_from_decrements_owner_balance_and_allowance() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (contract, token_id, owner, spender, recipient) = setup_token_with_spender(
+            &mut platform,
+            None::<fn(&mut TokenConfiguration)>,
+            platform_version,
+        );
+
+        let processing_result = approve(
+            &mut platform,
+            &contract,
+            token_id,
+            &owner,
+            spender.0.id(),
+            1000,
+            None,
+            2,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        let processing_result = transfer_from(
+            &mut platform,
+            &contract,
+            token_id,
+            &spender,
+            owner.0.id(),
+            recipient.id(),
+            300,
+            2,
+            &BlockInfo::default(),
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, owner.0.id(), platform_version),
+            Some(100000 - 300)
+        );
+        assert_eq!(
+            token_balance(&platform, token_id, recipient.id(), platform_version),
+            Some(300)
+        );
+        // The spender never holds the tokens itself
+        assert_eq!(
+            token_balance(&platform, token_id, spender.0.id(), platform_version),
+            None
+        );
+        assert_eq!(
+            allowance_amount(
+                &platform,
+                token_id,
+                owner.0.id(),
+                spender.0.id(),
+                platform_version
+            ),
+            Some(700)
+        );
+    }
+
+    #[test]
+    fn test_token_transfer_from_exhausts_allowance() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (contract, token_id, owner, spender, recipient) = setup_token_with_spender(
+            &mut platform,
+            None::<fn(&mut TokenConfiguration)>,
+            platform_version,
+        );
+
+        let processing_result = approve(
+            &mut platform,
+            &contract,
+            token_id,
+            &owner,
+            spender.0.id(),
+            500,
+            None,
+            2,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        // More than the allowance can't be spent
+        let processing_result = transfer_from(
+            &mut platform,
+            &contract,
+            token_id,
+            &spender,
+            owner.0.id(),
+            recipient.id(),
+            501,
+            2,
+            &BlockInfo::default(),
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::TokenAllowanceExceededError(_)),
+                _
+            )]
+        );
+
+        let processing_result = transfer_from(
+            &mut platform,
+            &contract,
+            token_id,
+            &spender,
+            owner.0.id(),
+            recipient.id(),
+            500,
+            3,
+            &BlockInfo::default(),
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        // A fully spent allowance is removed
+        assert_eq!(
+            allowance_amount(
+                &platform,
+                token_id,
+                owner.0.id(),
+                spender.0.id(),
+                platform_version
+            ),
+            None
+        );
+
+        let processing_result = transfer_from(
+            &mut platform,
+            &contract,
+            token_id,
+            &spender,
+            owner.0.id(),
+            recipient.id(),
+            1,
+            4,
+            &BlockInfo::default(),
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::TokenAllowanceNotFoundError(_)),
+                _
+            )]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, owner.0.id(), platform_version),
+            Some(100000 - 500)
+        );
+        assert_eq!(
+            token_balance(&platform, token_id, recipient.id(), platform_version),
+            Some(500)
+        );
+    }
+
+    #[test]
+    fn test_token_transfer_from_allowance_expires_at_its_expiry_time() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (contract, token_id, owner, spender, recipient) = setup_token_with_spender(
+            &mut platform,
+            None::<fn(&mut TokenConfiguration)>,
+            platform_version,
+        );
+
+        let expires_at = 10_000;
+
+        let processing_result = approve(
+            &mut platform,
+            &contract,
+            token_id,
+            &owner,
+            spender.0.id(),
+            1000,
+            Some(expires_at),
+            2,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        // The last millisecond before the expiry can still spend the allowance
+        let processing_result = transfer_from(
+            &mut platform,
+            &contract,
+            token_id,
+            &spender,
+            owner.0.id(),
+            recipient.id(),
+            100,
+            2,
+            &BlockInfo {
+                time_ms: expires_at - 1,
+                ..Default::default()
+            },
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        let processing_result = transfer_from(
+            &mut platform,
+            &contract,
+            token_id,
+            &spender,
+            owner.0.id(),
+            recipient.id(),
+            100,
+            3,
+            &BlockInfo {
+                time_ms: expires_at,
+                ..Default::default()
+            },
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::TokenAllowanceExpiredError(_)),
+                _
+            )]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, recipient.id(), platform_version),
+            Some(100)
+        );
+        assert_eq!(
+            allowance_amount(
+                &platform,
+                token_id,
+                owner.0.id(),
+                spender.0.id(),
+                platform_version
+            ),
+            Some(900)
+        );
+    }
+
+    #[test]
+    fn test_token_approve_zero_revokes_allowance() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (contract, token_id, owner, spender, recipient) = setup_token_with_spender(
+            &mut platform,
+            None::<fn(&mut TokenConfiguration)>,
+            platform_version,
+        );
+
+        let processing_result = approve(
+            &mut platform,
+            &contract,
+            token_id,
+            &owner,
+            spender.0.id(),
+            1000,
+            None,
+            2,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        let processing_result = approve(
+            &mut platform,
+            &contract,
+            token_id,
+            &owner,
+            spender.0.id(),
+            0,
+            None,
+            3,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        assert_eq!(
+            allowance_amount(
+                &platform,
+                token_id,
+                owner.0.id(),
+                spender.0.id(),
+                platform_version
+            ),
+            None
+        );
+
+        let processing_result = transfer_from(
+            &mut platform,
+            &contract,
+            token_id,
+            &spender,
+            owner.0.id(),
+            recipient.id(),
+            100,
+            2,
+            &BlockInfo::default(),
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::TokenAllowanceNotFoundError(_)),
+                _
+            )]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, owner.0.id(), platform_version),
+            Some(100000)
+        );
+    }
+
+    #[test]
+    fn test_token_transfer_from_by_non_spender_fails() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (contract, token_id, owner, spender, recipient) = setup_token_with_spender(
+            &mut platform,
+            None::<fn(&mut TokenConfiguration)>,
+            platform_version,
+        );
+
+        let mut rng = StdRng::seed_from_u64(3948);
+
+        let other_identity = setup_identity(&mut platform, rng.gen(), dash_to_credits!(0.5));
+
+        let processing_result = approve(
+            &mut platform,
+            &contract,
+            token_id,
+            &owner,
+            spender.0.id(),
+            1000,
+            None,
+            2,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        let processing_result = transfer_from(
+            &mut platform,
+            &contract,
+            token_id,
+            &other_identity,
+            owner.0.id(),
+            recipient.id(),
+            100,
+            2,
+            &BlockInfo::default(),
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::TokenAllowanceNotFoundError(_)),
+                _
+            )]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, owner.0.id(), platform_version),
+            Some(100000)
+        );
+        assert_eq!(
+            token_balance(&platform, token_id, recipient.id(), platform_version),
+            None
+        );
+        assert_eq!(
+            allowance_amount(
+                &platform,
+                token_id,
+                owner.0.id(),
+                spender.0.id(),
+                platform_version
+            ),
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn test_token_transfer_from_frozen_owner_fails() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (contract, token_id, owner, spender, recipient) = setup_token_with_spender(
+            &mut platform,
+            Some(|token_configuration: &mut TokenConfiguration| {
+                token_configuration.set_freeze_rules(ChangeControlRules::V0(
+                    ChangeControlRulesV0 {
+                        authorized_to_make_change: AuthorizedActionTakers::ContractOwner,
+                        admin_action_takers: AuthorizedActionTakers::NoOne,
+                        changing_authorized_action_takers_to_no_one_allowed: false,
+                        changing_admin_action_takers_to_no_one_allowed: false,
+                        self_changing_admin_action_takers_allowed: false,
+                    },
+                ));
+            }),
+            platform_version,
+        );
+
+        let processing_result = approve(
+            &mut platform,
+            &contract,
+            token_id,
+            &owner,
+            spender.0.id(),
+            1000,
+            None,
+            2,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        let (owner_identity, owner_signer, owner_key) = &owner;
+
+        let freeze_transition = BatchTransition::new_token_freeze_transition(
+            token_id,
+            owner_identity.id(),
+            contract.id(),
+            0,
+            owner_identity.id(),
+            None,
+            None,
+            owner_key,
+            3,
+            0,
+            owner_signer,
+            platform_version,
+            None,
+        )
+        .expect("expect to create documents batch transition");
+
+        let processing_result = process_transition(
+            &mut platform,
+            freeze_transition,
+            &BlockInfo::default(),
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        let processing_result = transfer_from(
+            &mut platform,
+            &contract,
+            token_id,
+            &spender,
+            owner.0.id(),
+            recipient.id(),
+            100,
+            2,
+            &BlockInfo::default(),
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::IdentityTokenAccountFrozenError(_)),
+                _
+            )]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, owner.0.id(), platform_version),
+            Some(100000)
+        );
+        assert_eq!(
+            allowance_amount(
+                &platform,
+                token_id,
+                owner.0.id(),
+                spender.0.id(),
+                platform_version
+            ),
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn test_token_transfer_from_paused_token_fails() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (contract, token_id, owner, spender, recipient) = setup_token_with_spender(
+            &mut platform,
+            Some(|token_configuration: &mut TokenConfiguration| {
+                token_configuration.set_start_as_paused(false);
+                token_configuration.set_emergency_action_rules(ChangeControlRules::V0(
+                    ChangeControlRulesV0 {
+                        authorized_to_make_change: AuthorizedActionTakers::ContractOwner,
+                        admin_action_takers: AuthorizedActionTakers::ContractOwner,
+                        changing_authorized_action_takers_to_no_one_allowed: false,
+                        changing_admin_action_takers_to_no_one_allowed: false,
+                        self_changing_admin_action_takers_allowed: false,
+                    },
+                ));
+            }),
+            platform_version,
+        );
+
+        let processing_result = approve(
+            &mut platform,
+            &contract,
+            token_id,
+            &owner,
+            spender.0.id(),
+            1000,
+            None,
+            2,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        let (owner_identity, owner_signer, owner_key) = &owner;
+
+        let pause_transition = BatchTransition::new_token_emergency_action_transition(
+            token_id,
+            owner_identity.id(),
+            contract.id(),
+            0,
+            TokenEmergencyAction::Pause,
+            None,
+            None,
+            owner_key,
+            3,
+            0,
+            owner_signer,
+            platform_version,
+            None,
+        )
+        .expect("expect to create documents batch transition");
+
+        let processing_result = process_transition(
+            &mut platform,
+            pause_transition,
+            &BlockInfo::default(),
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        let processing_result = transfer_from(
+            &mut platform,
+            &contract,
+            token_id,
+            &spender,
+            owner.0.id(),
+            recipient.id(),
+            100,
+            2,
+            &BlockInfo::default(),
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::TokenIsPausedError(_)),
+                _
+            )]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, owner.0.id(), platform_version),
+            Some(100000)
+        );
+        assert_eq!(
+            allowance_amount(
+                &platform,
+                token_id,
+                owner.0.id(),
+                spender.0.id(),
+                platform_version
+            ),
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn test_token_allowances_not_available_before_protocol_version_11() {
+        let platform_version = PlatformVersion::get(10).expect("expected platform version 10");
+        let mut platform = TestPlatformBuilder::new()
+            .with_initial_protocol_version(10)
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (contract, token_id, owner, spender, recipient) = setup_token_with_spender(
+            &mut platform,
+            None::<fn(&mut TokenConfiguration)>,
+            platform_version,
+        );
+
+        let processing_result = approve(
+            &mut platform,
+            &contract,
+            token_id,
+            &owner,
+            spender.0.id(),
+            1000,
+            None,
+            2,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::UnpaidConsensusError(
+                ConsensusError::BasicError(BasicError::UnsupportedFeatureError(_))
+            )]
+        );
+
+        let processing_result = transfer_from(
+            &mut platform,
+            &contract,
+            token_id,
+            &spender,
+            owner.0.id(),
+            recipient.id(),
+            100,
+            2,
+            &BlockInfo::default(),
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::UnpaidConsensusError(
+                ConsensusError::BasicError(BasicError::UnsupportedFeatureError(_))
+            )]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, owner.0.id(), platform_version),
+            Some(100000)
+        );
+    }
+}
@@ -1,10 +1,13 @@
+mod allowance;
 mod burn;
 mod config_update;
 mod direct_selling;
 mod distribution;
 mod freeze;
 mod mint;
+mod order_book;
 mod transfer;
+mod vesting;
 
 use super::*;
 use crate::execution::validation::state_transition::tests::create_token_contract_with_owner_identity;
@@ -0,0 +1,910 @@
+use super::*;
+
+mod token_order_book_tests {
+    use super::*;
+    use crate::platform_types::state_transitions_processing_result::StateTransitionsProcessingResult;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::TempPlatform;
+    use dpp::data_contract::associated_token::token_marketplace_rules::accessors::v0::TokenMarketplaceRulesV0Setters;
+    use dpp::data_contract::associated_token::token_marketplace_rules::v0::TokenTradeMode;
+    use dpp::prelude::{DataContract, Identity, IdentityPublicKey};
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::StateTransition;
+    use dpp::tokens::allowed_currency::AllowedCurrency;
+    use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+    use dpp::tokens::order_book::{TokenOrder, TokenOrderSide};
+    use simple_signer::signer::SimpleSigner;
+
+    type Trader = (Identity, SimpleSigner, IdentityPublicKey);
+
+    fn create_order_book_token(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> (DataContract, Identifier) {
+        create_token_contract_with_owner_identity(
+            platform,
+            owner_id,
+            Some(|token_configuration: &mut TokenConfiguration| {
+                let TokenConfiguration::V0(token_configuration) = token_configuration;
+                token_configuration
+                    .marketplace_rules
+                    .set_trade_mode(TokenTradeMode::OrderBook {
+                        allowed_currencies: vec![AllowedCurrency::TradingInDash],
+                    });
+            }),
+            None,
+            None,
+            None,
+            platform_version,
+        )
+    }
+
+    fn process_at_time(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+        state_transition: StateTransition,
+        time_ms: u64,
+        platform_version: &PlatformVersion,
+    ) -> StateTransitionsProcessingResult {
+        let serialized_state_transition = state_transition
+            .serialize_to_bytes()
+            .expect("expected to serialize state transition");
+
+        let platform_state = platform.state.load();
+        let transaction = platform.drive.grove.start_transaction();
+
+        let processing_result = platform
+            .platform
+            .process_raw_state_transitions(
+                &[serialized_state_transition],
+                &platform_state,
+                &BlockInfo {
+                    time_ms,
+                    ..Default::default()
+                },
+                &transaction,
+                platform_version,
+                false,
+                None,
+            )
+            .expect("expected to process state transition");
+
+        platform
+            .drive
+            .grove
+            .commit_transaction(transaction)
+            .unwrap()
+            .expect("expected to commit transaction");
+
+        processing_result
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn place_order(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+        contract: &DataContract,
+        token_id: Identifier,
+        (identity, signer, key): &Trader,
+        side: TokenOrderSide,
+        amount: u64,
+        price_per_token: u64,
+        identity_contract_nonce: u64,
+        time_ms: u64,
+        platform_version: &PlatformVersion,
+    ) -> StateTransitionsProcessingResult {
+        let transition = BatchTransition::new_token_place_order_transition(
+            token_id,
+            identity.id(),
+            contract.id(),
+            0,
+            side,
+            AllowedCurrency::TradingInDash,
+            amount,
+            price_per_token,
+            key,
+            identity_contract_nonce,
+            0,
+            signer,
+            platform_version,
+            None,
+        )
+        .expect("expected to create place order transition");
+
+        process_at_time(platform, transition, time_ms, platform_version)
+    }
+
+    fn token_balance(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        token_id: Identifier,
+        identity_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> u64 {
+        platform
+            .drive
+            .fetch_identity_token_balance(
+                token_id.to_buffer(),
+                identity_id.to_buffer(),
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch token balance")
+            .unwrap_or_default()
+    }
+
+    fn credit_balance(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        identity_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> u64 {
+        platform
+            .drive
+            .fetch_identity_balance(identity_id.to_buffer(), None, platform_version)
+            .expect("expected to fetch credit balance")
+            .expect("expected identity to exist")
+    }
+
+    fn resting_orders(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        token_id: Identifier,
+        side: TokenOrderSide,
+        platform_version: &PlatformVersion,
+    ) -> Vec<TokenOrder> {
+        platform
+            .drive
+            .fetch_token_order_book_orders(
+                token_id.to_buffer(),
+                side,
+                &AllowedCurrency::TradingInDash,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch order book")
+    }
+
+    #[test]
+    fn test_token_order_full_fill() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let seller = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+        let buyer = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+
+        let (contract, token_id) =
+            create_order_book_token(&mut platform, seller.0.id(), platform_version);
+
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &seller,
+            TokenOrderSide::Sell,
+            10,
+            1000,
+            2,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(..)]
+        );
+
+        let escrow_id = TokenOrder::escrow_identity_id(&token_id);
+
+        assert_eq!(
+            token_balance(&platform, token_id, seller.0.id(), platform_version),
+            99990
+        );
+        assert_eq!(
+            token_balance(&platform, token_id, escrow_id, platform_version),
+            10
+        );
+
+        let seller_credits_before_fill = credit_balance(&platform, seller.0.id(), platform_version);
+
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &buyer,
+            TokenOrderSide::Buy,
+            10,
+            1000,
+            2,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(..)]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, buyer.0.id(), platform_version),
+            10
+        );
+        assert_eq!(
+            token_balance(&platform, token_id, escrow_id, platform_version),
+            0
+        );
+        assert_eq!(
+            credit_balance(&platform, seller.0.id(), platform_version),
+            seller_credits_before_fill + 10 * 1000
+        );
+
+        assert!(
+            resting_orders(&platform, token_id, TokenOrderSide::Sell, platform_version).is_empty()
+        );
+        assert!(
+            resting_orders(&platform, token_id, TokenOrderSide::Buy, platform_version).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_token_order_partial_fill() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let seller = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+        let buyer = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+
+        let (contract, token_id) =
+            create_order_book_token(&mut platform, seller.0.id(), platform_version);
+
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &seller,
+            TokenOrderSide::Sell,
+            10,
+            1000,
+            2,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(..)]
+        );
+
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &buyer,
+            TokenOrderSide::Buy,
+            4,
+            1000,
+            2,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(..)]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, buyer.0.id(), platform_version),
+            4
+        );
+
+        let sell_orders =
+            resting_orders(&platform, token_id, TokenOrderSide::Sell, platform_version);
+        assert_eq!(sell_orders.len(), 1);
+        assert_eq!(
+            sell_orders[0].id(),
+            TokenOrder::calculate_order_id(&seller.0.id(), &token_id, 2)
+        );
+        assert_eq!(sell_orders[0].original_amount(), 10);
+        assert_eq!(sell_orders[0].remaining_amount(), 6);
+
+        // The buy order was filled completely and does not rest on the book
+        assert!(
+            resting_orders(&platform, token_id, TokenOrderSide::Buy, platform_version).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_token_buy_order_rests_on_book_with_escrowed_credits() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let seller = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+        let buyer = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+
+        let (contract, token_id) =
+            create_order_book_token(&mut platform, seller.0.id(), platform_version);
+
+        let buyer_credits_before = credit_balance(&platform, buyer.0.id(), platform_version);
+
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &buyer,
+            TokenOrderSide::Buy,
+            5,
+            dash_to_credits!(0.01),
+            2,
+            0,
+            platform_version,
+        );
+
+        let [StateTransitionExecutionResult::SuccessfulExecution(_, fee_result)] =
+            processing_result.execution_results().as_slice()
+        else {
+            panic!("expected the buy order to be placed");
+        };
+
+        // The buyer paid the escrow of the order on top of the fees
+        let spent =
+            buyer_credits_before - credit_balance(&platform, buyer.0.id(), platform_version);
+        let escrow = 5 * dash_to_credits!(0.01);
+        assert!(spent >= escrow);
+        assert!(spent - escrow <= fee_result.total_base_fee());
+
+        let buy_orders = resting_orders(&platform, token_id, TokenOrderSide::Buy, platform_version);
+        assert_eq!(buy_orders.len(), 1);
+        assert_eq!(buy_orders[0].owner_id(), buyer.0.id());
+        assert_eq!(buy_orders[0].remaining_amount(), 5);
+    }
+
+    #[test]
+    fn test_token_cancel_order_refunds_escrow() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let seller = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+        let buyer = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+
+        let (contract, token_id) =
+            create_order_book_token(&mut platform, seller.0.id(), platform_version);
+
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &seller,
+            TokenOrderSide::Sell,
+            10,
+            1000,
+            2,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(..)]
+        );
+
+        // Part of the order is filled before it is cancelled
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &buyer,
+            TokenOrderSide::Buy,
+            3,
+            1000,
+            2,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(..)]
+        );
+
+        let order_id = TokenOrder::calculate_order_id(&seller.0.id(), &token_id, 2);
+
+        // Only the owner of the order can cancel it
+        let cancel_transition = BatchTransition::new_token_cancel_order_transition(
+            token_id,
+            buyer.0.id(),
+            contract.id(),
+            0,
+            order_id,
+            &buyer.2,
+            3,
+            0,
+            &buyer.1,
+            platform_version,
+            None,
+        )
+        .expect("expected to create cancel order transition");
+
+        let processing_result =
+            process_at_time(&mut platform, cancel_transition, 0, platform_version);
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::TokenOrderNotOwnedError(_)),
+                _
+            )]
+        );
+
+        let cancel_transition = BatchTransition::new_token_cancel_order_transition(
+            token_id,
+            seller.0.id(),
+            contract.id(),
+            0,
+            order_id,
+            &seller.2,
+            3,
+            0,
+            &seller.1,
+            platform_version,
+            None,
+        )
+        .expect("expected to create cancel order transition");
+
+        let processing_result =
+            process_at_time(&mut platform, cancel_transition, 0, platform_version);
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(..)]
+        );
+
+        // The 7 unfilled tokens are back with the seller
+        assert_eq!(
+            token_balance(&platform, token_id, seller.0.id(), platform_version),
+            99997
+        );
+        assert_eq!(
+            token_balance(
+                &platform,
+                token_id,
+                TokenOrder::escrow_identity_id(&token_id),
+                platform_version
+            ),
+            0
+        );
+        assert!(platform
+            .drive
+            .fetch_token_order(
+                token_id.to_buffer(),
+                order_id.to_buffer(),
+                None,
+                platform_version
+            )
+            .expect("expected to fetch order")
+            .is_none());
+    }
+
+    #[test]
+    fn test_token_sell_order_without_enough_tokens() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let owner = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+        let seller = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+
+        let (contract, token_id) =
+            create_order_book_token(&mut platform, owner.0.id(), platform_version);
+
+        add_tokens_to_identity(&platform, token_id, seller.0.id(), 5);
+
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &seller,
+            TokenOrderSide::Sell,
+            6,
+            1000,
+            2,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::IdentityDoesNotHaveEnoughTokenBalanceError(
+                    _
+                )),
+                _
+            )]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, seller.0.id(), platform_version),
+            5
+        );
+        assert!(
+            resting_orders(&platform, token_id, TokenOrderSide::Sell, platform_version).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_token_buy_order_without_enough_credits() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let seller = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+        let buyer = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+
+        let (contract, token_id) =
+            create_order_book_token(&mut platform, seller.0.id(), platform_version);
+
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &buyer,
+            TokenOrderSide::Buy,
+            10,
+            dash_to_credits!(1.0),
+            2,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::IdentityInsufficientBalanceError(_)),
+                _
+            )]
+        );
+
+        assert!(
+            resting_orders(&platform, token_id, TokenOrderSide::Buy, platform_version).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_token_order_price_time_priority() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let seller = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+        let buyer = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+
+        let (contract, token_id) =
+            create_order_book_token(&mut platform, seller.0.id(), platform_version);
+
+        // (price, placed at) of the sell orders, placed with nonces 2, 3 and 4
+        for (nonce, (price, time_ms)) in [(1000, 1000), (900, 2000), (900, 3000)]
+            .into_iter()
+            .enumerate()
+        {
+            let processing_result = place_order(
+                &mut platform,
+                &contract,
+                token_id,
+                &seller,
+                TokenOrderSide::Sell,
+                5,
+                price,
+                nonce as u64 + 2,
+                time_ms,
+                platform_version,
+            );
+
+            assert_matches!(
+                processing_result.execution_results().as_slice(),
+                [StateTransitionExecutionResult::SuccessfulExecution(..)]
+            );
+        }
+
+        let seller_credits_before_fill = credit_balance(&platform, seller.0.id(), platform_version);
+
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &buyer,
+            TokenOrderSide::Buy,
+            7,
+            1000,
+            2,
+            4000,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(..)]
+        );
+
+        // The cheapest orders are filled first, the oldest of them before the newer one, and
+        // every fill trades at the price of the resting order
+        assert_eq!(
+            token_balance(&platform, token_id, buyer.0.id(), platform_version),
+            7
+        );
+        assert_eq!(
+            credit_balance(&platform, seller.0.id(), platform_version),
+            seller_credits_before_fill + 7 * 900
+        );
+
+        let sell_orders =
+            resting_orders(&platform, token_id, TokenOrderSide::Sell, platform_version);
+        let remaining: Vec<_> = sell_orders
+            .iter()
+            .map(|order| (order.id(), order.remaining_amount()))
+            .collect();
+        assert_eq!(
+            remaining,
+            vec![
+                (
+                    TokenOrder::calculate_order_id(&seller.0.id(), &token_id, 4),
+                    3
+                ),
+                (
+                    TokenOrder::calculate_order_id(&seller.0.id(), &token_id, 2),
+                    5
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_token_order_fill_limit_boundary() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let seller = setup_identity(&mut platform, rng.gen(), dash_to_credits!(10.0));
+        let buyer = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+
+        let (contract, token_id) =
+            create_order_book_token(&mut platform, seller.0.id(), platform_version);
+
+        let max_fills = platform_version.system_limits.max_token_order_book_fills as u64;
+
+        let mut seller_nonce = 2;
+        let mut place_sell_orders = |platform: &mut TempPlatform<MockCoreRPCLike>, count: u64| {
+            for _ in 0..count {
+                let processing_result = place_order(
+                    platform,
+                    &contract,
+                    token_id,
+                    &seller,
+                    TokenOrderSide::Sell,
+                    1,
+                    1000,
+                    seller_nonce,
+                    0,
+                    platform_version,
+                );
+                seller_nonce += 1;
+
+                assert_matches!(
+                    processing_result.execution_results().as_slice(),
+                    [StateTransitionExecutionResult::SuccessfulExecution(..)]
+                );
+            }
+        };
+
+        place_sell_orders(&mut platform, max_fills + 1);
+
+        // Exactly the maximum amount of resting orders can be filled
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &buyer,
+            TokenOrderSide::Buy,
+            max_fills,
+            1000,
+            2,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(..)]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, buyer.0.id(), platform_version),
+            max_fills
+        );
+        assert_eq!(
+            resting_orders(&platform, token_id, TokenOrderSide::Sell, platform_version).len(),
+            1
+        );
+
+        place_sell_orders(&mut platform, max_fills);
+
+        // One more would leave the rest of the order crossing the book
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &buyer,
+            TokenOrderSide::Buy,
+            max_fills + 1,
+            1000,
+            3,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::TokenOrderFillLimitExceededError(_)),
+                _
+            )]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, buyer.0.id(), platform_version),
+            max_fills
+        );
+        assert_eq!(
+            resting_orders(&platform, token_id, TokenOrderSide::Sell, platform_version).len(),
+            max_fills as usize + 1
+        );
+        assert!(
+            resting_orders(&platform, token_id, TokenOrderSide::Buy, platform_version).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_token_order_crossing_own_order_is_rejected() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let seller = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+        let other_seller = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+
+        let (contract, token_id) =
+            create_order_book_token(&mut platform, seller.0.id(), platform_version);
+
+        add_tokens_to_identity(&platform, token_id, other_seller.0.id(), 10);
+
+        // The own order is the best priced one on the book, another seller rests behind it
+        for (trader, price) in [(&seller, 900), (&other_seller, 1000)] {
+            let processing_result = place_order(
+                &mut platform,
+                &contract,
+                token_id,
+                trader,
+                TokenOrderSide::Sell,
+                10,
+                price,
+                2,
+                0,
+                platform_version,
+            );
+
+            assert_matches!(
+                processing_result.execution_results().as_slice(),
+                [StateTransitionExecutionResult::SuccessfulExecution(..)]
+            );
+        }
+
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &seller,
+            TokenOrderSide::Buy,
+            5,
+            1000,
+            3,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [PaidConsensusError(
+                ConsensusError::StateError(StateError::TokenOrderSelfTradeError(_)),
+                _
+            )]
+        );
+
+        // Nothing traded and the book is not left crossed
+        assert!(
+            resting_orders(&platform, token_id, TokenOrderSide::Buy, platform_version).is_empty()
+        );
+        let sell_orders =
+            resting_orders(&platform, token_id, TokenOrderSide::Sell, platform_version);
+        assert_eq!(sell_orders.len(), 2);
+        assert!(sell_orders
+            .iter()
+            .all(|order| order.remaining_amount() == order.original_amount()));
+    }
+
+    #[test]
+    fn test_token_order_not_available_before_protocol_version_11() {
+        let platform_version = PlatformVersion::get(10).expect("expected platform version 10");
+        let mut platform = TestPlatformBuilder::new()
+            .with_initial_protocol_version(10)
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let seller = setup_identity(&mut platform, rng.gen(), dash_to_credits!(1.0));
+
+        let (contract, token_id) =
+            create_order_book_token(&mut platform, seller.0.id(), platform_version);
+
+        let processing_result = place_order(
+            &mut platform,
+            &contract,
+            token_id,
+            &seller,
+            TokenOrderSide::Sell,
+            10,
+            1000,
+            2,
+            0,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::UnpaidConsensusError(
+                ConsensusError::BasicError(BasicError::UnsupportedFeatureError(_))
+            )]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, seller.0.id(), platform_version),
+            100000
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use super::*;
+
+mod token_vesting_tests {
+    use super::*;
+    use crate::platform_types::state_transitions_processing_result::StateTransitionsProcessingResult;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::TempPlatform;
+    use dpp::data_contract::associated_token::token_perpetual_distribution::distribution_function::DistributionFunction;
+    use dpp::prelude::{DataContract, Identity, IdentityPublicKey};
+    use dpp::tokens::vesting::schedule::TokenVestingSchedule;
+    use simple_signer::signer::SimpleSigner;
+
+    #[allow(clippy::too_many_arguments)]
+    fn vesting_transfer(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+        contract: &DataContract,
+        token_id: Identifier,
+        (identity, signer, key): &(Identity, SimpleSigner, IdentityPublicKey),
+        recipient_id: Identifier,
+        amount: u64,
+        nonce: u64,
+        platform_version: &PlatformVersion,
+    ) -> StateTransitionsProcessingResult {
+        let vesting_transfer_transition = BatchTransition::new_token_vesting_transfer_transition(
+            token_id,
+            identity.id(),
+            contract.id(),
+            0,
+            recipient_id,
+            amount,
+            TokenVestingSchedule {
+                cliff_duration: 0,
+                interval: 1000,
+                release_function: DistributionFunction::FixedAmount { amount: 100 },
+            },
+            None,
+            key,
+            nonce,
+            0,
+            signer,
+            platform_version,
+            None,
+        )
+        .expect("expect to create documents batch transition");
+
+        let vesting_transfer_serialized_transition = vesting_transfer_transition
+            .serialize_to_bytes()
+            .expect("expected documents batch serialized state transition");
+
+        let platform_state = platform.state.load();
+        let transaction = platform.drive.grove.start_transaction();
+
+        let processing_result = platform
+            .platform
+            .process_raw_state_transitions(
+                &[vesting_transfer_serialized_transition],
+                &platform_state,
+                &BlockInfo::default(),
+                &transaction,
+                platform_version,
+                false,
+                None,
+            )
+            .expect("expected to process state transition");
+
+        platform
+            .drive
+            .grove
+            .commit_transaction(transaction)
+            .unwrap()
+            .expect("expected to commit transaction");
+
+        processing_result
+    }
+
+    fn token_balance(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        token_id: Identifier,
+        identity_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Option<u64> {
+        platform
+            .drive
+            .fetch_identity_token_balance(
+                token_id.to_buffer(),
+                identity_id.to_buffer(),
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch token balance")
+    }
+
+    #[test]
+    fn test_token_vesting_transfer_escrows_the_amount() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let owner = setup_identity(&mut platform, rng.gen(), dash_to_credits!(0.5));
+
+        let (recipient, _, _) = setup_identity(&mut platform, rng.gen(), dash_to_credits!(0.5));
+
+        let (contract, token_id) = create_token_contract_with_owner_identity(
+            &mut platform,
+            owner.0.id(),
+            None::<fn(&mut TokenConfiguration)>,
+            None,
+            None,
+            None,
+            platform_version,
+        );
+
+        let processing_result = vesting_transfer(
+            &mut platform,
+            &contract,
+            token_id,
+            &owner,
+            recipient.id(),
+            1000,
+            2,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::SuccessfulExecution(_, _)]
+        );
+
+        // The vested amount leaves the owner but only reaches the recipient once claimed
+        assert_eq!(
+            token_balance(&platform, token_id, owner.0.id(), platform_version),
+            Some(100000 - 1000)
+        );
+        assert_eq!(
+            token_balance(&platform, token_id, recipient.id(), platform_version),
+            None
+        );
+    }
+
+    #[test]
+    fn test_token_vesting_not_available_before_protocol_version_11() {
+        let platform_version = PlatformVersion::get(10).expect("expected platform version 10");
+        let mut platform = TestPlatformBuilder::new()
+            .with_initial_protocol_version(10)
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut rng = StdRng::seed_from_u64(49853);
+
+        let owner = setup_identity(&mut platform, rng.gen(), dash_to_credits!(0.5));
+
+        let (recipient, _, _) = setup_identity(&mut platform, rng.gen(), dash_to_credits!(0.5));
+
+        let (contract, token_id) = create_token_contract_with_owner_identity(
+            &mut platform,
+            owner.0.id(),
+            None::<fn(&mut TokenConfiguration)>,
+            None,
+            None,
+            None,
+            platform_version,
+        );
+
+        let processing_result = vesting_transfer(
+            &mut platform,
+            &contract,
+            token_id,
+            &owner,
+            recipient.id(),
+            1000,
+            2,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [StateTransitionExecutionResult::UnpaidConsensusError(
+                ConsensusError::BasicError(BasicError::UnsupportedFeatureError(_))
+            )]
+        );
+
+        assert_eq!(
+            token_balance(&platform, token_id, owner.0.id(), platform_version),
+            Some(100000)
+        );
+    }
+}
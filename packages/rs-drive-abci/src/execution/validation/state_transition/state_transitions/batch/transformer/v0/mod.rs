@@ -62,6 +62,12 @@ use drive::state_transition_action::batch::batched_transition::token_transition:
 use drive::state_transition_action::batch::batched_transition::token_transition::token_claim_transition_action::TokenClaimTransitionAction;
 use drive::state_transition_action::batch::batched_transition::token_transition::token_direct_purchase_transition_action::TokenDirectPurchaseTransitionAction;
 use drive::state_transition_action::batch::batched_transition::token_transition::token_set_price_for_direct_purchase_transition_action::TokenSetPriceForDirectPurchaseTransitionAction;
+use drive::state_transition_action::batch::batched_transition::token_transition::token_place_order_transition_action::TokenPlaceOrderTransitionAction;
+use drive::state_transition_action::batch::batched_transition::token_transition::token_cancel_order_transition_action::TokenCancelOrderTransitionAction;
+use drive::state_transition_action::batch::batched_transition::token_transition::token_vesting_transfer_transition_action::TokenVestingTransferTransitionAction;
+use drive::state_transition_action::batch::batched_transition::token_transition::token_claim_vested_transition_action::TokenClaimVestedTransitionAction;
+use drive::state_transition_action::batch::batched_transition::token_transition::token_approve_transition_action::TokenApproveTransitionAction;
+use drive::state_transition_action::batch::batched_transition::token_transition::token_transfer_from_transition_action::TokenTransferFromTransitionAction;
 use drive::state_transition_action::batch::batched_transition::token_transition::token_transfer_transition_action::TokenTransferTransitionAction;
 use drive::state_transition_action::batch::batched_transition::token_transition::token_unfreeze_transition_action::TokenUnfreezeTransitionAction;
 use drive::state_transition_action::batch::batched_transition::token_transition::TokenTransitionAction;
@@ -629,6 +635,66 @@ impl BatchTransitionInternalTransformerV0 for BatchTransition {
                     Ok(data_contract_fetch_info.clone())
                 }, platform_version)?;
 
+                execution_context
+                    .add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+                Ok(batched_action)
+            }
+            TokenTransition::PlaceOrder(place_order) => {
+                let (batched_action, fee_result) = TokenPlaceOrderTransitionAction::try_from_borrowed_token_place_order_transition_with_contract_lookup(drive, owner_id, place_order, approximate_for_costs, transaction, block_info, user_fee_increase, |_identifier| {
+                    Ok(data_contract_fetch_info.clone())
+                }, platform_version)?;
+
+                execution_context
+                    .add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+                Ok(batched_action)
+            }
+            TokenTransition::CancelOrder(cancel_order) => {
+                let (batched_action, fee_result) = TokenCancelOrderTransitionAction::try_from_borrowed_token_cancel_order_transition_with_contract_lookup(drive, owner_id, cancel_order, approximate_for_costs, transaction, block_info, user_fee_increase, |_identifier| {
+                    Ok(data_contract_fetch_info.clone())
+                }, platform_version)?;
+
+                execution_context
+                    .add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+                Ok(batched_action)
+            }
+            TokenTransition::VestingTransfer(vesting_transfer) => {
+                let (batched_action, fee_result) = TokenVestingTransferTransitionAction::try_from_borrowed_token_vesting_transfer_transition_with_contract_lookup(drive, owner_id, vesting_transfer, approximate_for_costs, transaction, block_info, user_fee_increase, |_identifier| {
+                    Ok(data_contract_fetch_info.clone())
+                }, platform_version)?;
+
+                execution_context
+                    .add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+                Ok(batched_action)
+            }
+            TokenTransition::ClaimVested(claim_vested) => {
+                let (batched_action, fee_result) = TokenClaimVestedTransitionAction::try_from_borrowed_token_claim_vested_transition_with_contract_lookup(drive, owner_id, claim_vested, approximate_for_costs, transaction, block_info, user_fee_increase, |_identifier| {
+                    Ok(data_contract_fetch_info.clone())
+                }, platform_version)?;
+
+                execution_context
+                    .add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+                Ok(batched_action)
+            }
+            TokenTransition::Approve(approve) => {
+                let (batched_action, fee_result) = TokenApproveTransitionAction::try_from_borrowed_token_approve_transition_with_contract_lookup(drive, owner_id, approve, approximate_for_costs, transaction, block_info, user_fee_increase, |_identifier| {
+                    Ok(data_contract_fetch_info.clone())
+                }, platform_version)?;
+
+                execution_context
+                    .add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
+
+                Ok(batched_action)
+            }
+            TokenTransition::TransferFrom(transfer_from) => {
+                let (batched_action, fee_result) = TokenTransferFromTransitionAction::try_from_borrowed_token_transfer_from_transition_with_contract_lookup(drive, owner_id, transfer_from, approximate_for_costs, transaction, block_info, user_fee_increase, |_identifier| {
+                    Ok(data_contract_fetch_info.clone())
+                }, platform_version)?;
+
                 execution_context
                     .add_operation(ValidationOperation::PrecalculatedOperation(fee_result));
 
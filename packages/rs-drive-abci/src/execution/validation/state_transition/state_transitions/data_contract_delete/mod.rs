@@ -0,0 +1,376 @@
+mod identity_contract_nonce;
+mod state;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use dpp::validation::ConsensusValidationResult;
+
+use drive::grovedb::TransactionArg;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+
+use drive::state_transition_action::StateTransitionAction;
+
+use crate::execution::validation::state_transition::data_contract_delete::state::v0::DataContractDeleteStateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+use crate::execution::validation::state_transition::ValidationMode;
+use crate::platform_types::platform::PlatformRef;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::rpc::core::CoreRPCLike;
+
+impl StateTransitionActionTransformerV0 for DataContractDeleteTransition {
+    fn transform_into_action<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        validation_mode: ValidationMode,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .contract_delete_state_transition
+            .transform_into_action
+        {
+            0 => self.transform_into_action_v0(
+                platform,
+                block_info,
+                validation_mode,
+                execution_context,
+                tx,
+                platform_version,
+            ),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "data contract delete transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{ExecutionConfig, PlatformConfig, PlatformTestConfig, ValidatorSetConfig};
+    use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+    use crate::execution::validation::state_transition::processor::v0::StateTransitionStateValidationV0;
+    use crate::execution::validation::state_transition::ValidationMode;
+    use crate::platform_types::platform::PlatformRef;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use dpp::assert_state_consensus_errors;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::consensus::ConsensusError;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::config::v0::DataContractConfigSettersV0;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::data_contract::DataContract;
+    use dpp::document::DocumentV0Getters;
+    use dpp::identifier::Identifier;
+    use dpp::platform_value::BinaryData;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::data_contract_delete_transition::{
+        DataContractDeleteTransition, DataContractDeleteTransitionV0,
+    };
+    use dpp::state_transition::errors::StateTransitionError;
+    use dpp::state_transition::StateTransition;
+    use dpp::tests::fixtures::get_data_contract_fixture;
+    use dpp::version::PlatformVersion;
+    use dpp::ProtocolError;
+    use drive::state_transition_action::StateTransitionAction;
+    use drive::util::object_size_info::DocumentInfo::DocumentRefInfo;
+    use drive::util::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use platform_version::DefaultForPlatformVersion;
+
+    fn setup_test(can_be_deleted: bool) -> (DataContract, TempPlatform<MockCoreRPCLike>) {
+        let platform_version = PlatformVersion::latest();
+        let mut data_contract =
+            get_data_contract_fixture(None, 0, platform_version.protocol_version)
+                .data_contract_owned();
+
+        data_contract
+            .config_mut()
+            .set_can_be_deleted(can_be_deleted);
+
+        let config = PlatformConfig {
+            validator_set: ValidatorSetConfig {
+                quorum_size: 10,
+                ..Default::default()
+            },
+            execution: ExecutionConfig {
+                verify_sum_trees: true,
+
+                ..Default::default()
+            },
+            block_spacing_ms: 300,
+            testing_configs: PlatformTestConfig::default_minimal_verifications(),
+            ..Default::default()
+        };
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        platform
+            .drive
+            .apply_contract(
+                &data_contract,
+                BlockInfo::default(),
+                true,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("to apply contract");
+
+        (data_contract, platform)
+    }
+
+    fn delete_transition(
+        data_contract: &DataContract,
+        owner_id: Identifier,
+    ) -> DataContractDeleteTransition {
+        DataContractDeleteTransitionV0 {
+            owner_id,
+            data_contract_id: data_contract.id(),
+            identity_contract_nonce: 1,
+            user_fee_increase: 0,
+            signature_public_key_id: 0,
+            signature: BinaryData::new(vec![0; 65]),
+        }
+        .into()
+    }
+
+    fn validate_state(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        state_transition: &DataContractDeleteTransition,
+    ) -> dpp::validation::ConsensusValidationResult<StateTransitionAction> {
+        let platform_version = PlatformVersion::latest();
+        let state = platform.state.load();
+
+        let platform_ref = PlatformRef {
+            drive: &platform.drive,
+            state: &state,
+            config: &platform.config,
+            core_rpc: &platform.core_rpc,
+        };
+
+        let mut execution_context =
+            StateTransitionExecutionContext::default_for_platform_version(platform_version)
+                .expect("expected a platform version");
+
+        state_transition
+            .validate_state(
+                None,
+                &platform_ref,
+                ValidationMode::Validator,
+                &BlockInfo::default(),
+                &mut execution_context,
+                None,
+            )
+            .expect("state transition to be validated")
+    }
+
+    #[test]
+    fn should_delete_an_empty_deletable_contract() {
+        let platform_version = PlatformVersion::latest();
+        let (data_contract, platform) = setup_test(true);
+
+        let state_transition = delete_transition(&data_contract, data_contract.owner_id());
+
+        let result = validate_state(&platform, &state_transition);
+
+        assert!(result.is_valid());
+        let Some(StateTransitionAction::DataContractDeleteAction(action)) = result.data else {
+            panic!("expected a data contract delete action");
+        };
+
+        platform
+            .drive
+            .delete_contract(
+                action.data_contract_ref(),
+                BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to delete the contract");
+
+        let fetched_contract = platform
+            .drive
+            .fetch_contract(
+                data_contract.id().to_buffer(),
+                None,
+                None,
+                None,
+                platform_version,
+            )
+            .unwrap()
+            .expect("expected to fetch");
+
+        assert!(fetched_contract.is_none());
+    }
+
+    #[test]
+    fn should_return_error_if_contract_is_not_deletable() {
+        let (data_contract, platform) = setup_test(false);
+
+        let state_transition = delete_transition(&data_contract, data_contract.owner_id());
+
+        let result = validate_state(&platform, &state_transition);
+
+        assert!(!result.is_valid());
+        assert_state_consensus_errors!(result, StateError::DataContractNotDeletableError, 1);
+        assert!(matches!(
+            result.data,
+            Some(StateTransitionAction::BumpIdentityDataContractNonceAction(
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn should_return_error_if_identity_is_not_the_owner() {
+        let (data_contract, platform) = setup_test(true);
+
+        let state_transition = delete_transition(&data_contract, Identifier::random());
+
+        let result = validate_state(&platform, &state_transition);
+
+        assert!(!result.is_valid());
+        assert_state_consensus_errors!(result, StateError::DataContractDeletePermissionError, 1);
+    }
+
+    #[test]
+    fn should_delete_a_contract_whose_documents_were_all_deleted() {
+        let platform_version = PlatformVersion::latest();
+        let (data_contract, platform) = setup_test(true);
+
+        let document_type = data_contract
+            .document_type_for_name("indexedDocument")
+            .expect("expected a document type");
+
+        let documents = (0..10)
+            .map(|seed| {
+                document_type
+                    .random_document(Some(seed), platform_version)
+                    .expect("expected a random document")
+            })
+            .collect::<Vec<_>>();
+
+        for document in &documents {
+            platform
+                .drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((document, None)),
+                            owner_id: Some(document.owner_id().to_buffer()),
+                        },
+                        contract: &data_contract,
+                        document_type,
+                    },
+                    false,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                    None,
+                )
+                .expect("expected to insert a document");
+        }
+
+        let state_transition = delete_transition(&data_contract, data_contract.owner_id());
+
+        let result = validate_state(&platform, &state_transition);
+
+        assert_state_consensus_errors!(result, StateError::DataContractHasDocumentsError, 1);
+
+        for document in &documents {
+            platform
+                .drive
+                .delete_document_for_contract(
+                    document.id(),
+                    &data_contract,
+                    "indexedDocument",
+                    BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                    None,
+                )
+                .expect("expected to delete a document");
+        }
+
+        let result = validate_state(&platform, &state_transition);
+
+        assert!(result.is_valid());
+        let Some(StateTransitionAction::DataContractDeleteAction(action)) = result.data else {
+            panic!("expected a data contract delete action");
+        };
+
+        // the index trees still hold the empty subtrees left behind by the document deletions
+        platform
+            .drive
+            .delete_contract(
+                action.data_contract_ref(),
+                BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to delete the contract");
+
+        let fetched_contract = platform
+            .drive
+            .fetch_contract(
+                data_contract.id().to_buffer(),
+                None,
+                None,
+                None,
+                platform_version,
+            )
+            .unwrap()
+            .expect("expected to fetch");
+
+        assert!(fetched_contract.is_none());
+    }
+
+    #[test]
+    fn should_not_allow_contract_deletion_before_protocol_version_11() {
+        let (data_contract, _platform) = setup_test(true);
+
+        let state_transition: StateTransition =
+            delete_transition(&data_contract, data_contract.owner_id()).into();
+
+        let bytes = state_transition
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+
+        let platform_version =
+            PlatformVersion::get(10).expect("expected protocol version 10 to exist");
+
+        let result = StateTransition::deserialize_from_bytes_in_version(&bytes, platform_version);
+
+        assert!(matches!(
+            result,
+            Err(ProtocolError::StateTransitionError(
+                StateTransitionError::StateTransitionIsNotActiveError { .. }
+            ))
+        ));
+
+        let platform_version =
+            PlatformVersion::get(11).expect("expected protocol version 11 to exist");
+
+        StateTransition::deserialize_from_bytes_in_version(&bytes, platform_version)
+            .expect("expected contract deletion to be active");
+    }
+}
@@ -0,0 +1,298 @@
+use crate::error::Error;
+use crate::platform_types::platform::PlatformRef;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+
+use dpp::consensus::basic::document::DataContractNotPresentError;
+use dpp::consensus::basic::BasicError;
+use dpp::consensus::state::data_contract::data_contract_delete_permission_error::DataContractDeletePermissionError;
+use dpp::consensus::state::data_contract::data_contract_has_active_tokens_or_groups_error::DataContractHasActiveTokensOrGroupsError;
+use dpp::consensus::state::data_contract::data_contract_has_documents_error::DataContractHasDocumentsError;
+use dpp::consensus::state::data_contract::data_contract_not_deletable_error::DataContractNotDeletableError;
+use dpp::consensus::state::state_error::StateError;
+use dpp::consensus::ConsensusError;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::accessors::v1::DataContractV1Getters;
+use dpp::data_contract::config::v0::DataContractConfigGettersV0;
+use dpp::data_contract::DataContract;
+use dpp::fee::fee_result::FeeResult;
+
+use crate::error::execution::ExecutionError;
+use crate::execution::validation::state_transition::ValidationMode;
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::data_contract_delete_transition::accessors::DataContractDeleteTransitionAccessorsV0;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+use dpp::state_transition::StateTransitionLike;
+use dpp::version::PlatformVersion;
+use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use drive::drive::votes::paths::{
+    vote_contested_resource_active_polls_contract_document_tree_path_vec,
+    CONTESTED_DOCUMENT_STORAGE_TREE_KEY,
+};
+use drive::drive::Drive;
+use drive::grovedb::query_result_type::QueryResultType;
+use drive::grovedb::{PathQuery, Query, SizedQuery, TransactionArg};
+use drive::query::DriveDocumentQuery;
+use drive::state_transition_action::contract::data_contract_delete::DataContractDeleteTransitionAction;
+use drive::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceAction;
+
+use crate::execution::types::execution_operation::ValidationOperation;
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+use drive::state_transition_action::StateTransitionAction;
+
+pub(in crate::execution::validation::state_transition::state_transitions::data_contract_delete) trait DataContractDeleteStateTransitionStateValidationV0 {
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        validation_mode: ValidationMode,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+
+    fn transform_into_action_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        validation_mode: ValidationMode,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+}
+
+impl DataContractDeleteStateTransitionStateValidationV0 for DataContractDeleteTransition {
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        validation_mode: ValidationMode,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let action = self.transform_into_action_v0(
+            platform,
+            block_info,
+            validation_mode,
+            execution_context,
+            tx,
+            platform_version,
+        )?;
+
+        if !action.is_valid() {
+            return Ok(action);
+        }
+
+        let data_contract = match action.data.as_ref() {
+            Some(StateTransitionAction::DataContractDeleteAction(action)) => {
+                Some(action.data_contract_ref())
+            }
+            _ => None,
+        }
+        .ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
+            "we should always have a data contract at this point in data contract delete",
+        )))?;
+
+        let data_contract_id = data_contract.id();
+
+        // Only the owner of the contract may delete it
+        if data_contract.owner_id() != self.owner_id() {
+            return Ok(self.bump_nonce_with_error(
+                StateError::DataContractDeletePermissionError(
+                    DataContractDeletePermissionError::new(data_contract_id, self.owner_id()),
+                )
+                .into(),
+            ));
+        }
+
+        if !data_contract.config().can_be_deleted() {
+            return Ok(self.bump_nonce_with_error(
+                StateError::DataContractNotDeletableError(DataContractNotDeletableError::new(
+                    data_contract_id,
+                ))
+                .into(),
+            ));
+        }
+
+        // Token balances and group actions are not removed with the contract, so a contract
+        // defining any of them must stay
+        let token_count = data_contract.tokens().len();
+        let group_count = data_contract.groups().len();
+
+        if token_count > 0 || group_count > 0 {
+            return Ok(self.bump_nonce_with_error(
+                StateError::DataContractHasActiveTokensOrGroupsError(
+                    DataContractHasActiveTokensOrGroupsError::new(
+                        data_contract_id,
+                        token_count as u16,
+                        group_count as u16,
+                    ),
+                )
+                .into(),
+            ));
+        }
+
+        if let Some(document_type_name) = first_document_type_with_documents(
+            platform.drive,
+            data_contract,
+            block_info,
+            execution_context,
+            tx,
+            platform_version,
+        )? {
+            return Ok(self.bump_nonce_with_error(
+                StateError::DataContractHasDocumentsError(DataContractHasDocumentsError::new(
+                    data_contract_id,
+                    document_type_name,
+                ))
+                .into(),
+            ));
+        }
+
+        Ok(action)
+    }
+
+    fn transform_into_action_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        validation_mode: ValidationMode,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let add_to_cache_if_pulled = validation_mode.can_alter_cache();
+
+        let data_contract_fetch_info = platform.drive.get_contract_with_fetch_info_and_fee(
+            self.data_contract_id().to_buffer(),
+            Some(&block_info.epoch),
+            add_to_cache_if_pulled,
+            tx,
+            platform_version,
+        )?;
+
+        let fee = data_contract_fetch_info.0.ok_or(Error::Execution(
+            ExecutionError::CorruptedCodeExecution(
+                "fee must exist in validate state for data contract delete transition",
+            ),
+        ))?;
+
+        // We add the cost for fetching the contract even if the contract doesn't exist or was in cache
+        execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+
+        let Some(contract_fetch_info) = data_contract_fetch_info.1 else {
+            return Ok(self.bump_nonce_with_error(
+                BasicError::DataContractNotPresentError(DataContractNotPresentError::new(
+                    self.data_contract_id(),
+                ))
+                .into(),
+            ));
+        };
+
+        let action = DataContractDeleteTransitionAction::from_borrowed_transition(
+            self,
+            (*contract_fetch_info.contract).clone(),
+        );
+
+        let action: StateTransitionAction = action.into();
+        Ok(action.into())
+    }
+}
+
+trait DataContractDeleteTransitionBumpNonce {
+    fn bump_nonce_with_error(
+        &self,
+        error: ConsensusError,
+    ) -> ConsensusValidationResult<StateTransitionAction>;
+}
+
+impl DataContractDeleteTransitionBumpNonce for DataContractDeleteTransition {
+    fn bump_nonce_with_error(
+        &self,
+        error: ConsensusError,
+    ) -> ConsensusValidationResult<StateTransitionAction> {
+        let bump_action = StateTransitionAction::BumpIdentityDataContractNonceAction(
+            BumpIdentityDataContractNonceAction::from_borrowed_data_contract_delete_transition(
+                self,
+            ),
+        );
+
+        ConsensusValidationResult::new_with_data_and_errors(bump_action, vec![error])
+    }
+}
+
+/// Returns the name of the first document type that still holds documents, including
+/// contested documents that are still waiting on a vote.
+fn first_document_type_with_documents(
+    drive: &Drive,
+    data_contract: &DataContract,
+    block_info: &BlockInfo,
+    execution_context: &mut StateTransitionExecutionContext,
+    tx: TransactionArg,
+    platform_version: &PlatformVersion,
+) -> Result<Option<String>, Error> {
+    for (document_type_name, document_type) in data_contract.document_types() {
+        let query =
+            DriveDocumentQuery::all_items_query(data_contract, document_type.as_ref(), Some(1));
+
+        let outcome = drive.query_documents(
+            query,
+            Some(&block_info.epoch),
+            false,
+            tx,
+            Some(platform_version.protocol_version),
+        )?;
+
+        execution_context.add_operation(ValidationOperation::PrecalculatedOperation(
+            FeeResult::new_from_processing_fee(outcome.cost()),
+        ));
+
+        if !outcome.documents().is_empty() {
+            return Ok(Some(document_type_name.clone()));
+        }
+    }
+
+    for document_type_name in data_contract.document_types_with_contested_indexes().keys() {
+        let mut path = vote_contested_resource_active_polls_contract_document_tree_path_vec(
+            data_contract.id_ref().as_bytes(),
+            document_type_name,
+        );
+        path.push(vec![CONTESTED_DOCUMENT_STORAGE_TREE_KEY]);
+
+        let path_query = PathQuery::new(
+            path,
+            SizedQuery::new(Query::new_range_full(), Some(1), None),
+        );
+
+        let mut drive_operations = vec![];
+
+        let (elements, _) = drive.grove_get_raw_path_query(
+            &path_query,
+            tx,
+            QueryResultType::QueryKeyElementPairResultType,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        let fee = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            drive.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+
+        execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+
+        if !elements.elements.is_empty() {
+            return Ok(Some(document_type_name.clone()));
+        }
+    }
+
+    Ok(None)
+}
@@ -6,6 +6,7 @@ use dpp::consensus::basic::data_contract::{
 use dpp::dashcore::Network;
 use dpp::data_contract::associated_token::token_configuration::accessors::v0::TokenConfigurationV0Getters;
 use dpp::data_contract::associated_token::token_distribution_rules::accessors::v0::TokenDistributionRulesV0Getters;
+use dpp::data_contract::associated_token::token_marketplace_rules::accessors::v0::TokenMarketplaceRulesV0Getters;
 use dpp::data_contract::associated_token::token_perpetual_distribution::methods::v0::TokenPerpetualDistributionV0Accessors;
 use dpp::data_contract::change_control_rules::authorized_action_takers::AuthorizedActionTakers;
 use dpp::data_contract::TokenContractPosition;
@@ -73,6 +74,14 @@ impl DataContractUpdateStateTransitionBasicStructureValidationV0 for DataContrac
                 return Ok(validation_result);
             }
 
+            let validation_result = token_configuration
+                .marketplace_rules()
+                .trade_mode()
+                .validate_supported(platform_version);
+            if !validation_result.is_valid() {
+                return Ok(validation_result);
+            }
+
             if let Some(perpetual_distribution) = token_configuration
                 .distribution_rules()
                 .perpetual_distribution()
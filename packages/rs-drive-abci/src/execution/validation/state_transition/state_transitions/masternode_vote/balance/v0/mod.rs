@@ -8,7 +8,6 @@ use dpp::prelude::ConsensusValidationResult;
 use dpp::state_transition::masternode_vote_transition::accessors::MasternodeVoteTransitionAccessorsV0;
 use dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
 
-use crate::error::execution::ExecutionError;
 use crate::execution::types::execution_operation::ValidationOperation;
 use crate::execution::types::state_transition_execution_context::{
     StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
@@ -41,15 +40,15 @@ impl MasternodeVoteTransitionBalanceValidationV0 for MasternodeVoteTransition {
         ConsensusValidationResult<BTreeMap<PrefundedSpecializedBalanceIdentifier, Credits>>,
         Error,
     > {
-        execution_context.add_operation(ValidationOperation::RetrievePrefundedSpecializedBalance);
-
         let vote = self.vote();
 
-        let balance_id = vote.specialized_balance_id()?.ok_or(Error::Execution(
-            ExecutionError::CorruptedCodeExecution(
-                "In this version there should always be a specialized balance id",
-            ),
-        ))?;
+        let Some(balance_id) = vote.specialized_balance_id()? else {
+            // Yes/no/abstain vote polls are not prefunded, voting on them is free
+            return Ok(ConsensusValidationResult::new_with_data(BTreeMap::new()));
+        };
+
+        execution_context.add_operation(ValidationOperation::RetrievePrefundedSpecializedBalance);
+
         let maybe_balance = drive.fetch_prefunded_specialized_balance(
             balance_id.to_buffer(),
             tx,
@@ -59,7 +59,7 @@ impl StateTransitionStateValidationV0 for MasternodeVoteTransition {
         action: Option<StateTransitionAction>,
         platform: &PlatformRef<C>,
         _validation_mode: ValidationMode,
-        _block_info: &BlockInfo,
+        block_info: &BlockInfo,
         _execution_context: &mut StateTransitionExecutionContext,
         tx: TransactionArg,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
@@ -71,7 +71,7 @@ impl StateTransitionStateValidationV0 for MasternodeVoteTransition {
             .masternode_vote_state_transition
             .state
         {
-            0 => self.validate_state_v0(action, platform, tx, platform_version),
+            0 => self.validate_state_v0(action, platform, block_info, tx, platform_version),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "masternode votes state transition: validate_state".to_string(),
                 known_versions: vec![0],
@@ -159,7 +159,7 @@ mod tests {
     };
     use dpp::fee::Credits;
     use drive::drive::Drive;
-    use crate::execution::validation::state_transition::state_transitions::tests::{create_dpns_identity_name_contest, verify_dpns_name_contest, perform_vote, setup_masternode_voting_identity, get_proved_vote_states, get_vote_states, perform_votes_multi};
+    use crate::execution::validation::state_transition::state_transitions::tests::{create_dpns_identity_name_contest, verify_dpns_name_contest, perform_vote, setup_masternode_voting_identity, get_proved_vote_states, get_vote_states, perform_votes_multi, create_yes_no_abstain_vote_poll};
     use dapi_grpc::platform::v0::get_contested_resource_vote_state_response::get_contested_resource_vote_state_response_v0::{finished_vote_info, FinishedVoteInfo};
     use dpp::voting::vote_info_storage::contested_document_vote_poll_winner_info::ContestedDocumentVotePollWinnerInfo;
     use dapi_grpc::platform::v0::get_vote_polls_by_end_date_request::get_vote_polls_by_end_date_request_v0;
@@ -11402,5 +11402,422 @@ mod tests {
                 }
             }
         }
+
+        mod yes_no_abstain_vote_polls {
+            use super::*;
+            use dapi_grpc::platform::v0::get_yes_no_abstain_vote_poll_state_request::GetYesNoAbstainVotePollStateRequestV0;
+            use dapi_grpc::platform::v0::get_yes_no_abstain_vote_poll_state_response::get_yes_no_abstain_vote_poll_state_response_v0::{FinalTally, YesNoAbstainVotePollState};
+            use dapi_grpc::platform::v0::get_yes_no_abstain_vote_poll_state_response::{
+                get_yes_no_abstain_vote_poll_state_response_v0,
+                GetYesNoAbstainVotePollStateResponseV0,
+            };
+            use dapi_grpc::platform::v0::{
+                get_yes_no_abstain_vote_poll_state_request,
+                get_yes_no_abstain_vote_poll_state_response, GetYesNoAbstainVotePollStateRequest,
+                GetYesNoAbstainVotePollStateResponse,
+            };
+            use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+            use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult::{
+                InternalError, SuccessfulExecution, UnpaidConsensusError,
+            };
+            use assert_matches::assert_matches;
+            use dpp::consensus::state::state_error::StateError;
+            use dpp::consensus::ConsensusError;
+            use dpp::identity::IdentityPublicKey;
+            use dpp::prelude::IdentityNonce;
+            use dpp::serialization::PlatformSerializable;
+            use dpp::state_transition::masternode_vote_transition::methods::MasternodeVoteTransitionMethodsV0;
+            use dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
+            use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+            use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+            use dpp::voting::votes::yes_no_abstain_vote::v0::YesNoAbstainVoteV0;
+            use dpp::voting::votes::yes_no_abstain_vote::YesNoAbstainVote;
+            use dpp::voting::votes::Vote;
+            use simple_signer::signer::SimpleSigner;
+
+            #[allow(clippy::too_many_arguments)]
+            fn perform_yes_no_abstain_vote(
+                platform: &mut TempPlatform<MockCoreRPCLike>,
+                platform_state: &PlatformState,
+                vote_poll: &YesNoAbstainVotePoll,
+                vote_choice: YesNoAbstainVoteChoice,
+                signer: &SimpleSigner,
+                pro_tx_hash: Identifier,
+                voting_key: &IdentityPublicKey,
+                nonce: IdentityNonce,
+                platform_version: &PlatformVersion,
+            ) -> StateTransitionExecutionResult {
+                let vote = Vote::YesNoAbstainVote(YesNoAbstainVote::V0(YesNoAbstainVoteV0 {
+                    vote_poll: vote_poll.clone(),
+                    vote_choice,
+                }));
+
+                let masternode_vote_transition =
+                    MasternodeVoteTransition::try_from_vote_with_signer(
+                        vote,
+                        signer,
+                        pro_tx_hash,
+                        voting_key,
+                        nonce,
+                        platform_version,
+                        None,
+                    )
+                    .expect("expected to make transition vote");
+
+                let masternode_vote_serialized_transition = masternode_vote_transition
+                    .serialize_to_bytes()
+                    .expect("expected masternode vote serialized state transition");
+
+                let transaction = platform.drive.grove.start_transaction();
+
+                let processing_result = platform
+                    .platform
+                    .process_raw_state_transitions(
+                        &[masternode_vote_serialized_transition],
+                        platform_state,
+                        &BlockInfo::default(),
+                        &transaction,
+                        platform_version,
+                        false,
+                        None,
+                    )
+                    .expect("expected to process state transition");
+
+                platform
+                    .drive
+                    .grove
+                    .commit_transaction(transaction)
+                    .unwrap()
+                    .expect("expected to commit transaction");
+
+                processing_result.into_execution_results().remove(0)
+            }
+
+            fn get_yes_no_abstain_vote_poll_state(
+                platform: &TempPlatform<MockCoreRPCLike>,
+                platform_state: &PlatformState,
+                vote_poll: &YesNoAbstainVotePoll,
+                platform_version: &PlatformVersion,
+            ) -> YesNoAbstainVotePollState {
+                let GetYesNoAbstainVotePollStateResponse { version } = platform
+                    .query_yes_no_abstain_vote_poll_state(
+                        GetYesNoAbstainVotePollStateRequest {
+                            version: Some(get_yes_no_abstain_vote_poll_state_request::Version::V0(
+                                GetYesNoAbstainVotePollStateRequestV0 {
+                                    vote_poll_id: vote_poll
+                                        .unique_id()
+                                        .expect("expected a unique id")
+                                        .to_vec(),
+                                    prove: false,
+                                },
+                            )),
+                        },
+                        platform_state,
+                        platform_version,
+                    )
+                    .expect("expected to execute query")
+                    .into_data()
+                    .expect("expected query to be valid");
+
+                let get_yes_no_abstain_vote_poll_state_response::Version::V0(
+                    GetYesNoAbstainVotePollStateResponseV0 {
+                        metadata: _,
+                        result,
+                    },
+                ) = version.expect("expected a version");
+
+                let Some(get_yes_no_abstain_vote_poll_state_response_v0::Result::VotePollState(
+                    vote_poll_state,
+                )) = result
+                else {
+                    panic!("expected a vote poll state")
+                };
+
+                vote_poll_state
+            }
+
+            #[test]
+            fn test_yes_no_abstain_votes_are_tallied_after_end_date() {
+                let platform_version = PlatformVersion::latest();
+                let mut platform = TestPlatformBuilder::new()
+                    .with_latest_protocol_version()
+                    .build_with_mock_rpc()
+                    .set_genesis_state();
+
+                let choices = [
+                    YesNoAbstainVoteChoice::YES,
+                    YesNoAbstainVoteChoice::YES,
+                    YesNoAbstainVoteChoice::NO,
+                    YesNoAbstainVoteChoice::ABSTAIN,
+                ];
+
+                let voters = (0..choices.len())
+                    .map(|i| {
+                        setup_masternode_voting_identity(
+                            &mut platform,
+                            20 + i as u64,
+                            platform_version,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                let vote_poll =
+                    create_yes_no_abstain_vote_poll(&mut platform, 1, 1_000_000, platform_version);
+
+                let platform_state = platform.state.load();
+
+                for (vote_choice, (pro_tx_hash, _masternode, signer, voting_key)) in
+                    choices.into_iter().zip(voters)
+                {
+                    let execution_result = perform_yes_no_abstain_vote(
+                        &mut platform,
+                        &platform_state,
+                        &vote_poll,
+                        vote_choice,
+                        &signer,
+                        pro_tx_hash,
+                        &voting_key,
+                        1,
+                        platform_version,
+                    );
+
+                    assert_matches!(execution_result, SuccessfulExecution(..));
+                }
+
+                let platform_state = platform.state.load();
+
+                let vote_poll_state = get_yes_no_abstain_vote_poll_state(
+                    &platform,
+                    &platform_state,
+                    &vote_poll,
+                    platform_version,
+                );
+
+                assert_eq!(vote_poll_state.votes.len(), 4);
+                assert!(vote_poll_state.serialized_vote_poll.is_some());
+                assert_eq!(vote_poll_state.final_tally, None);
+
+                let block_info = BlockInfo {
+                    time_ms: 1_000_001,
+                    height: 100,
+                    core_height: 42,
+                    epoch: Default::default(),
+                };
+
+                let transaction = platform.drive.grove.start_transaction();
+
+                platform
+                    .check_for_ended_vote_polls(
+                        &platform_state,
+                        &platform_state,
+                        &block_info,
+                        Some(&transaction),
+                        platform_version,
+                    )
+                    .expect("expected to check for ended vote polls");
+
+                platform
+                    .drive
+                    .grove
+                    .commit_transaction(transaction)
+                    .unwrap()
+                    .expect("expected to commit transaction");
+
+                let vote_poll_state = get_yes_no_abstain_vote_poll_state(
+                    &platform,
+                    &platform_state,
+                    &vote_poll,
+                    platform_version,
+                );
+
+                assert_eq!(
+                    vote_poll_state.final_tally,
+                    Some(FinalTally {
+                        yes: 2,
+                        no: 1,
+                        abstain: 1,
+                        finished_at_block_height: 100,
+                        finished_at_time_ms: 1_000_001,
+                    })
+                );
+            }
+
+            #[test]
+            fn test_yes_no_abstain_vote_on_poll_that_was_not_created_should_return_error() {
+                let platform_version = PlatformVersion::latest();
+                let mut platform = TestPlatformBuilder::new()
+                    .with_latest_protocol_version()
+                    .build_with_mock_rpc()
+                    .set_genesis_state();
+
+                let (pro_tx_hash, _masternode, signer, voting_key) =
+                    setup_masternode_voting_identity(&mut platform, 10, platform_version);
+
+                let platform_state = platform.state.load();
+
+                let vote_poll = YesNoAbstainVotePoll {
+                    subject_id: Identifier::new([3; 32]),
+                    question: "Should the minimum masternode vote fee be lowered?".to_string(),
+                    end_time_ms: 1_000_000,
+                };
+
+                let execution_result = perform_yes_no_abstain_vote(
+                    &mut platform,
+                    &platform_state,
+                    &vote_poll,
+                    YesNoAbstainVoteChoice::YES,
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    1,
+                    platform_version,
+                );
+
+                assert_matches!(
+                    execution_result,
+                    UnpaidConsensusError(ConsensusError::StateError(
+                        StateError::VotePollNotFoundError(_)
+                    ))
+                );
+            }
+
+            #[test]
+            fn test_yes_no_abstain_vote_by_masternode_added_after_poll_creation_should_return_error(
+            ) {
+                let platform_version = PlatformVersion::latest();
+                let mut platform = TestPlatformBuilder::new()
+                    .with_latest_protocol_version()
+                    .build_with_mock_rpc()
+                    .set_genesis_state();
+
+                let vote_poll =
+                    create_yes_no_abstain_vote_poll(&mut platform, 1, 1_000_000, platform_version);
+
+                // This masternode joined the list after the eligible voters were recorded
+                let (pro_tx_hash, _masternode, signer, voting_key) =
+                    setup_masternode_voting_identity(&mut platform, 10, platform_version);
+
+                let platform_state = platform.state.load();
+
+                let execution_result = perform_yes_no_abstain_vote(
+                    &mut platform,
+                    &platform_state,
+                    &vote_poll,
+                    YesNoAbstainVoteChoice::YES,
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    1,
+                    platform_version,
+                );
+
+                assert_matches!(
+                    execution_result,
+                    UnpaidConsensusError(ConsensusError::StateError(
+                        StateError::MasternodeNotEligibleForVotePollError(_)
+                    ))
+                );
+            }
+
+            #[test]
+            fn test_yes_no_abstain_vote_before_protocol_version_11_should_return_error() {
+                let platform_version = PlatformVersion::get(10).expect("expected version 10");
+                let mut platform = TestPlatformBuilder::new()
+                    .with_initial_protocol_version(10)
+                    .build_with_mock_rpc()
+                    .set_genesis_state();
+
+                let (pro_tx_hash, _masternode, signer, voting_key) =
+                    setup_masternode_voting_identity(&mut platform, 10, platform_version);
+
+                let platform_state = platform.state.load();
+
+                let vote_poll = YesNoAbstainVotePoll {
+                    subject_id: Identifier::new([3; 32]),
+                    question: "Should the minimum masternode vote fee be lowered?".to_string(),
+                    end_time_ms: 1_000_000,
+                };
+
+                let execution_result = perform_yes_no_abstain_vote(
+                    &mut platform,
+                    &platform_state,
+                    &vote_poll,
+                    YesNoAbstainVoteChoice::YES,
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    1,
+                    platform_version,
+                );
+
+                // The transition is not active before protocol version 11 and can't be decoded
+                assert_matches!(execution_result, InternalError(_));
+            }
+
+            #[test]
+            fn test_yes_no_abstain_vote_again_same_choice_should_return_error() {
+                let platform_version = PlatformVersion::latest();
+                let mut platform = TestPlatformBuilder::new()
+                    .with_latest_protocol_version()
+                    .build_with_mock_rpc()
+                    .set_genesis_state();
+
+                let (pro_tx_hash, _masternode, signer, voting_key) =
+                    setup_masternode_voting_identity(&mut platform, 10, platform_version);
+
+                let vote_poll =
+                    create_yes_no_abstain_vote_poll(&mut platform, 1, 1_000_000, platform_version);
+
+                let platform_state = platform.state.load();
+
+                let execution_result = perform_yes_no_abstain_vote(
+                    &mut platform,
+                    &platform_state,
+                    &vote_poll,
+                    YesNoAbstainVoteChoice::NO,
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    1,
+                    platform_version,
+                );
+
+                assert_matches!(execution_result, SuccessfulExecution(..));
+
+                let execution_result = perform_yes_no_abstain_vote(
+                    &mut platform,
+                    &platform_state,
+                    &vote_poll,
+                    YesNoAbstainVoteChoice::NO,
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    2,
+                    platform_version,
+                );
+
+                assert_matches!(
+                    execution_result,
+                    UnpaidConsensusError(ConsensusError::StateError(
+                        StateError::MasternodeVoteAlreadyPresentError(_)
+                    ))
+                );
+
+                // Changing the vote is allowed
+                let execution_result = perform_yes_no_abstain_vote(
+                    &mut platform,
+                    &platform_state,
+                    &vote_poll,
+                    YesNoAbstainVoteChoice::YES,
+                    &signer,
+                    pro_tx_hash,
+                    &voting_key,
+                    3,
+                    platform_version,
+                );
+
+                assert_matches!(execution_result, SuccessfulExecution(..));
+            }
+        }
     }
 }
@@ -3,8 +3,10 @@ use crate::platform_types::platform::PlatformRef;
 use dpp::consensus::state::state_error::StateError;
 use dpp::consensus::state::voting::vote_poll_not_available_for_voting_error::VotePollNotAvailableForVotingError;
 use dpp::consensus::state::voting::vote_poll_not_found_error::VotePollNotFoundError;
+use dpp::consensus::state::voting::yes_no_abstain_vote_poll_not_open_error::YesNoAbstainVotePollNotOpenError;
 use dpp::consensus::ConsensusError;
 
+use dpp::block::block_info::BlockInfo;
 use dpp::prelude::ConsensusValidationResult;
 use dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
 
@@ -13,6 +15,7 @@ use dpp::version::PlatformVersion;
 use dpp::voting::vote_info_storage::contested_document_vote_poll_stored_info::{
     ContestedDocumentVotePollStatus, ContestedDocumentVotePollStoredInfoV0Getters,
 };
+use dpp::voting::votes::yes_no_abstain_vote::accessors::v0::YesNoAbstainVoteGettersV0;
 use drive::drive::votes::resolved::vote_polls::ResolvedVotePoll;
 use drive::drive::votes::resolved::votes::resolved_resource_vote::accessors::v0::ResolvedResourceVoteGettersV0;
 use drive::drive::votes::resolved::votes::ResolvedVote;
@@ -25,6 +28,7 @@ pub(in crate::execution::validation::state_transition::state_transitions::master
         &self,
         action: Option<StateTransitionAction>,
         platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
         tx: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
@@ -35,6 +39,7 @@ impl MasternodeVoteStateTransitionStateValidationV0 for MasternodeVoteTransition
         &self,
         action: Option<StateTransitionAction>,
         platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
         tx: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
@@ -92,8 +97,42 @@ impl MasternodeVoteStateTransitionStateValidationV0 for MasternodeVoteTransition
                             }
                         }
                     }
+                    ResolvedVotePoll::YesNoAbstainVotePoll(_) => {
+                        Ok(ConsensusValidationResult::new_with_error(
+                            ConsensusError::StateError(StateError::VotePollNotFoundError(
+                                VotePollNotFoundError::new(vote_poll.into()),
+                            )),
+                        ))
+                    }
                 }
             }
+            ResolvedVote::ResolvedYesNoAbstainVote(yes_no_abstain_vote) => {
+                let vote_poll = yes_no_abstain_vote.vote_poll();
+                let max_duration_ms = platform_version
+                    .dpp
+                    .validation
+                    .voting
+                    .yes_no_abstain_vote_poll_max_duration_ms;
+
+                // The question and the end time were validated when the poll was created, it
+                // only has to still be open
+                if vote_poll.end_time_ms <= block_info.time_ms {
+                    return Ok(ConsensusValidationResult::new_with_error(
+                        ConsensusError::StateError(StateError::YesNoAbstainVotePollNotOpenError(
+                            YesNoAbstainVotePollNotOpenError::new(
+                                vote_poll.unique_id()?,
+                                vote_poll.end_time_ms,
+                                block_info.time_ms,
+                                max_duration_ms,
+                            ),
+                        )),
+                    ));
+                }
+
+                Ok(ConsensusValidationResult::new_with_data(
+                    masternode_vote_action.into(),
+                ))
+            }
         }
     }
 }
@@ -1,9 +1,11 @@
 use crate::error::Error;
 use crate::platform_types::platform::PlatformRef;
 use dpp::consensus::state::state_error::StateError;
+use dpp::consensus::state::voting::masternode_not_eligible_for_vote_poll_error::MasternodeNotEligibleForVotePollError;
 use dpp::consensus::state::voting::masternode_not_found_error::MasternodeNotFoundError;
 use dpp::consensus::state::voting::masternode_vote_already_present_error::MasternodeVoteAlreadyPresentError;
 use dpp::consensus::state::voting::masternode_voted_too_many_times::MasternodeVotedTooManyTimesError;
+use dpp::consensus::state::voting::vote_poll_not_found_error::VotePollNotFoundError;
 use dpp::consensus::ConsensusError;
 use dpp::dashcore::hashes::Hash;
 use dpp::dashcore::ProTxHash;
@@ -19,8 +21,11 @@ use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use dpp::version::PlatformVersion;
 use dpp::voting::vote_polls::VotePoll;
 use dpp::voting::votes::resource_vote::accessors::v0::ResourceVoteGettersV0;
+use dpp::voting::votes::yes_no_abstain_vote::accessors::v0::YesNoAbstainVoteGettersV0;
 use dpp::voting::votes::Vote;
+use drive::drive::votes::paths::vote_decisions_tree_path;
 use drive::grovedb::TransactionArg;
+use drive::util::grove_operations::DirectQueryType;
 
 pub(in crate::execution::validation::state_transition::state_transitions::masternode_vote) trait MasternodeVoteStateTransitionTransformIntoActionValidationV0
 {
@@ -103,6 +108,57 @@ impl MasternodeVoteStateTransitionTransformIntoActionValidationV0 for Masternode
                                 }
                             }
                         }
+                        VotePoll::YesNoAbstainVotePoll(_) => {
+                            // Yes/no/abstain vote polls can only be voted on with a yes/no/abstain vote
+                            return Ok(ConsensusValidationResult::new_with_error(
+                                ConsensusError::StateError(StateError::VotePollNotFoundError(
+                                    VotePollNotFoundError::new(resource_vote.vote_poll().clone()),
+                                )),
+                            ));
+                        }
+                    }
+                }
+                Vote::YesNoAbstainVote(yes_no_abstain_vote) => {
+                    let vote_poll = yes_no_abstain_vote.vote_poll();
+                    let maybe_existing_vote = platform.drive.fetch_identity_yes_no_abstain_vote(
+                        self.pro_tx_hash(),
+                        vote_poll.unique_id()?,
+                        tx,
+                        &mut vec![],
+                        platform_version,
+                    )?;
+                    if let Some(existing_vote) = maybe_existing_vote {
+                        let votes_allowed_per_masternode = platform_version
+                            .dpp
+                            .validation
+                            .voting
+                            .votes_allowed_per_masternode;
+                        if existing_vote.vote_choice == yes_no_abstain_vote.vote_choice() {
+                            // We are submitting a vote for something we already have
+                            return Ok(ConsensusValidationResult::new_with_error(
+                                ConsensusError::StateError(
+                                    StateError::MasternodeVoteAlreadyPresentError(
+                                        MasternodeVoteAlreadyPresentError::new(
+                                            self.pro_tx_hash(),
+                                            VotePoll::YesNoAbstainVotePoll(vote_poll.clone()),
+                                        ),
+                                    ),
+                                ),
+                            ));
+                        } else if existing_vote.identity_vote_times >= votes_allowed_per_masternode
+                        {
+                            return Ok(ConsensusValidationResult::new_with_error(
+                                ConsensusError::StateError(
+                                    StateError::MasternodeVotedTooManyTimesError(
+                                        MasternodeVotedTooManyTimesError::new(
+                                            self.pro_tx_hash(),
+                                            existing_vote.identity_vote_times,
+                                            votes_allowed_per_masternode,
+                                        ),
+                                    ),
+                                ),
+                            ));
+                        }
                     }
                 }
             }
@@ -118,9 +174,55 @@ impl MasternodeVoteStateTransitionTransformIntoActionValidationV0 for Masternode
             ));
         };
 
-        let strength = match masternode.node_type {
-            MasternodeType::Regular => 1,
-            MasternodeType::Evo => 4,
+        let strength = match self.vote() {
+            Vote::ResourceVote(_) => match masternode.node_type {
+                MasternodeType::Regular => 1,
+                MasternodeType::Evo => 4,
+            },
+            // Only the masternodes in the masternode list when the poll was created may vote
+            // on it, with the strength they had at that time
+            Vote::YesNoAbstainVote(yes_no_abstain_vote) => {
+                let vote_poll = yes_no_abstain_vote.vote_poll();
+                let vote_poll_id = vote_poll.unique_id()?;
+                let maybe_strength = platform
+                    .drive
+                    .fetch_yes_no_abstain_vote_poll_voter_strength(
+                        self.pro_tx_hash(),
+                        vote_poll_id,
+                        tx,
+                        &mut vec![],
+                        platform_version,
+                    )?;
+                match maybe_strength {
+                    Some(strength) => strength,
+                    None => {
+                        let vote_poll_exists = platform
+                            .drive
+                            .grove_get_raw_optional(
+                                (&vote_decisions_tree_path()).into(),
+                                vote_poll_id.as_slice(),
+                                DirectQueryType::StatefulDirectQuery,
+                                tx,
+                                &mut vec![],
+                                &platform_version.drive,
+                            )?
+                            .is_some();
+                        let error: ConsensusError = if vote_poll_exists {
+                            MasternodeNotEligibleForVotePollError::new(
+                                self.pro_tx_hash(),
+                                vote_poll_id,
+                            )
+                            .into()
+                        } else {
+                            VotePollNotFoundError::new(VotePoll::YesNoAbstainVotePoll(
+                                vote_poll.clone(),
+                            ))
+                            .into()
+                        };
+                        return Ok(ConsensusValidationResult::new_with_error(error));
+                    }
+                }
+            }
         };
 
         Ok(ConsensusValidationResult::new_with_data(
@@ -22,9 +22,15 @@ pub mod data_contract_create;
 /// Module for updating an existing data contract entity.
 pub mod data_contract_update;
 
+/// Module for deleting an existing data contract entity.
+pub mod data_contract_delete;
+
 /// Module for voting from a masternode.
 pub mod masternode_vote;
 
+/// Module for creating a yes/no/abstain vote poll.
+pub mod vote_poll_create;
+
 /// The validation mode we are using
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ValidationMode {
@@ -34,6 +40,9 @@ pub enum ValidationMode {
     RecheckTx,
     /// The validation during block execution by a proposer or validator
     Validator,
+    /// Validating a state transition against the committed state without executing it, as
+    /// a validator would but without altering caches
+    Simulation,
     /// A validation mode used to get the action with no validation
     NoValidation,
 }
@@ -45,6 +54,7 @@ impl ValidationMode {
             ValidationMode::CheckTx => false,
             ValidationMode::RecheckTx => false,
             ValidationMode::Validator => true,
+            ValidationMode::Simulation => false,
             ValidationMode::NoValidation => false,
         }
     }
@@ -117,6 +127,9 @@ pub(in crate::execution) mod tests {
     use dpp::voting::votes::resource_vote::ResourceVote;
     use dpp::voting::votes::resource_vote::v0::ResourceVoteV0;
     use dpp::voting::votes::Vote;
+    use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+    use dpp::state_transition::vote_poll_create_transition::methods::VotePollCreateTransitionMethodsV0;
+    use dpp::state_transition::vote_poll_create_transition::VotePollCreateTransition;
     use drive::util::object_size_info::DataContractResolvedInfo;
     use drive::drive::votes::resolved::vote_polls::contested_document_resource_vote_poll::ContestedDocumentResourceVotePollWithContractInfoAllowBorrowed;
     use drive::query::vote_poll_vote_state_query::ContestedDocumentVotePollDriveQueryResultType::DocumentsAndVoteTally;
@@ -525,6 +538,8 @@ pub(in crate::execution) mod tests {
             unsigned_withdrawal_transactions: Default::default(),
             block_platform_state: platform_state.clone(),
             proposer_results: None,
+            executed_batch_transitions: vec![],
+            executed_state_transitions: vec![],
         });
 
         // Process fees
@@ -774,6 +789,51 @@ pub(in crate::execution) mod tests {
         platform.state.store(Arc::new(platform_state));
     }
 
+    /// Creates a yes/no/abstain vote poll about a new identity, the masternodes that can vote on
+    /// it are the ones in the masternode list at this point
+    pub(in crate::execution) fn create_yes_no_abstain_vote_poll(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+        seed: u64,
+        end_time_ms: TimestampMillis,
+        platform_version: &PlatformVersion,
+    ) -> YesNoAbstainVotePoll {
+        let (identity, signer, key) = setup_identity(platform, seed, dash_to_credits!(1.0));
+
+        let vote_poll = YesNoAbstainVotePoll {
+            subject_id: identity.id(),
+            question: "Should the minimum masternode vote fee be lowered?".to_string(),
+            end_time_ms,
+        };
+
+        let vote_poll_create_transition = VotePollCreateTransition::new_from_vote_poll(
+            vote_poll.clone(),
+            &identity.into_partial_identity_info(),
+            key.id(),
+            1,
+            0,
+            &signer,
+            platform_version,
+            None,
+        )
+        .expect("expected to create a vote poll create transition");
+
+        let platform_state = platform.state.load();
+
+        let processing_result = process_test_state_transition(
+            platform,
+            vote_poll_create_transition,
+            &platform_state,
+            platform_version,
+        );
+
+        assert_matches!(
+            processing_result.execution_results().as_slice(),
+            [SuccessfulExecution(..)]
+        );
+
+        vote_poll
+    }
+
     pub(in crate::execution) enum IdentityTestInfo<'a> {
         Given {
             identity: &'a Identity,
@@ -0,0 +1,399 @@
+mod nonce;
+mod state;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::state_transition::vote_poll_create_transition::VotePollCreateTransition;
+use dpp::validation::ConsensusValidationResult;
+
+use drive::grovedb::TransactionArg;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+
+use drive::state_transition_action::StateTransitionAction;
+
+use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+use crate::execution::validation::state_transition::vote_poll_create::state::v0::VotePollCreateStateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::ValidationMode;
+use crate::platform_types::platform::PlatformRef;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::rpc::core::CoreRPCLike;
+
+impl StateTransitionActionTransformerV0 for VotePollCreateTransition {
+    fn transform_into_action<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        _block_info: &BlockInfo,
+        _validation_mode: ValidationMode,
+        _execution_context: &mut StateTransitionExecutionContext,
+        _tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .vote_poll_create_state_transition
+            .transform_into_action
+        {
+            0 => self.transform_into_action_v0(platform),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "vote poll create transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execution::validation::state_transition::state_transitions::tests::{
+        process_test_state_transition, setup_identity, setup_masternode_voting_identity,
+    };
+    use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+    use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult::{
+        PaidConsensusError, SuccessfulExecution,
+    };
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use assert_matches::assert_matches;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::consensus::ConsensusError;
+    use dpp::dash_to_credits;
+    use dpp::identifier::Identifier;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+    use dpp::identity::{Identity, IdentityPublicKey, TimestampMillis};
+    use dpp::prelude::IdentityNonce;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::vote_poll_create_transition::methods::VotePollCreateTransitionMethodsV0;
+    use dpp::state_transition::vote_poll_create_transition::VotePollCreateTransition;
+    use dpp::state_transition::errors::StateTransitionError;
+    use dpp::state_transition::StateTransition;
+    use dpp::tests::fixtures::get_data_contract_fixture;
+    use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+    use dpp::ProtocolError;
+    use platform_version::version::PlatformVersion;
+    use simple_signer::signer::SimpleSigner;
+
+    fn vote_poll(subject_id: Identifier, end_time_ms: TimestampMillis) -> YesNoAbstainVotePoll {
+        YesNoAbstainVotePoll {
+            subject_id,
+            question: "Should the minimum masternode vote fee be lowered?".to_string(),
+            end_time_ms,
+        }
+    }
+
+    fn vote_poll_create_transition(
+        vote_poll: &YesNoAbstainVotePoll,
+        identity: &Identity,
+        signer: &SimpleSigner,
+        key: &IdentityPublicKey,
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+    ) -> StateTransition {
+        VotePollCreateTransition::new_from_vote_poll(
+            vote_poll.clone(),
+            &identity.clone().into_partial_identity_info(),
+            key.id(),
+            nonce,
+            0,
+            signer,
+            platform_version,
+            None,
+        )
+        .expect("expected to create a vote poll create transition")
+    }
+
+    fn process(
+        platform: &mut TempPlatform<MockCoreRPCLike>,
+        state_transition: StateTransition,
+        platform_version: &PlatformVersion,
+    ) -> StateTransitionExecutionResult {
+        let platform_state = platform.state.load();
+
+        process_test_state_transition(
+            platform,
+            state_transition,
+            &platform_state,
+            platform_version,
+        )
+        .into_execution_results()
+        .remove(0)
+    }
+
+    #[test]
+    fn should_create_a_vote_poll_about_the_creator_with_the_current_masternodes_as_voters() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (pro_tx_hash, _, _, _) =
+            setup_masternode_voting_identity(&mut platform, 10, platform_version);
+
+        let (identity, signer, key) = setup_identity(&mut platform, 1, dash_to_credits!(1.0));
+
+        let vote_poll = vote_poll(identity.id(), 1_000_000);
+
+        let execution_result = process(
+            &mut platform,
+            vote_poll_create_transition(&vote_poll, &identity, &signer, &key, 1, platform_version),
+            platform_version,
+        );
+
+        assert_matches!(execution_result, SuccessfulExecution(..));
+
+        let vote_poll_id = vote_poll.unique_id().expect("expected a unique id");
+
+        let vote_poll_state = platform
+            .drive
+            .fetch_yes_no_abstain_vote_poll_state(vote_poll_id, None, &mut vec![], platform_version)
+            .expect("expected to fetch the vote poll state");
+
+        assert_eq!(vote_poll_state.vote_poll, Some(vote_poll));
+        assert!(vote_poll_state.votes.is_empty());
+
+        let strength = platform
+            .drive
+            .fetch_yes_no_abstain_vote_poll_voter_strength(
+                pro_tx_hash,
+                vote_poll_id,
+                None,
+                &mut vec![],
+                platform_version,
+            )
+            .expect("expected to fetch the voter strength");
+
+        assert_eq!(strength, Some(1));
+
+        // A masternode joining afterwards is not eligible
+        let (late_pro_tx_hash, _, _, _) =
+            setup_masternode_voting_identity(&mut platform, 11, platform_version);
+
+        let strength = platform
+            .drive
+            .fetch_yes_no_abstain_vote_poll_voter_strength(
+                late_pro_tx_hash,
+                vote_poll_id,
+                None,
+                &mut vec![],
+                platform_version,
+            )
+            .expect("expected to fetch the voter strength");
+
+        assert_eq!(strength, None);
+    }
+
+    #[test]
+    fn should_create_a_vote_poll_about_a_data_contract_owned_by_the_creator() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (identity, signer, key) = setup_identity(&mut platform, 1, dash_to_credits!(1.0));
+
+        let data_contract =
+            get_data_contract_fixture(Some(identity.id()), 0, platform_version.protocol_version)
+                .data_contract_owned();
+
+        platform
+            .drive
+            .apply_contract(
+                &data_contract,
+                BlockInfo::default(),
+                true,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract");
+
+        let vote_poll = vote_poll(data_contract.id(), 1_000_000);
+
+        let execution_result = process(
+            &mut platform,
+            vote_poll_create_transition(&vote_poll, &identity, &signer, &key, 1, platform_version),
+            platform_version,
+        );
+
+        assert_matches!(execution_result, SuccessfulExecution(..));
+    }
+
+    #[test]
+    fn should_not_create_a_vote_poll_about_someone_else() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (identity, signer, key) = setup_identity(&mut platform, 1, dash_to_credits!(1.0));
+        let (other_identity, _, _) = setup_identity(&mut platform, 2, dash_to_credits!(1.0));
+
+        let data_contract = get_data_contract_fixture(
+            Some(other_identity.id()),
+            0,
+            platform_version.protocol_version,
+        )
+        .data_contract_owned();
+
+        platform
+            .drive
+            .apply_contract(
+                &data_contract,
+                BlockInfo::default(),
+                true,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract");
+
+        for (nonce, subject_id) in [(1, other_identity.id()), (2, data_contract.id())] {
+            let execution_result = process(
+                &mut platform,
+                vote_poll_create_transition(
+                    &vote_poll(subject_id, 1_000_000),
+                    &identity,
+                    &signer,
+                    &key,
+                    nonce,
+                    platform_version,
+                ),
+                platform_version,
+            );
+
+            assert_matches!(
+                execution_result,
+                PaidConsensusError(
+                    ConsensusError::StateError(StateError::UnauthorizedVotePollCreatorError(_)),
+                    _
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn should_not_create_the_same_vote_poll_twice() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (identity, signer, key) = setup_identity(&mut platform, 1, dash_to_credits!(1.0));
+
+        let vote_poll = vote_poll(identity.id(), 1_000_000);
+
+        let execution_result = process(
+            &mut platform,
+            vote_poll_create_transition(&vote_poll, &identity, &signer, &key, 1, platform_version),
+            platform_version,
+        );
+
+        assert_matches!(execution_result, SuccessfulExecution(..));
+
+        let execution_result = process(
+            &mut platform,
+            vote_poll_create_transition(&vote_poll, &identity, &signer, &key, 2, platform_version),
+            platform_version,
+        );
+
+        assert_matches!(
+            execution_result,
+            PaidConsensusError(
+                ConsensusError::StateError(StateError::VotePollAlreadyExistsError(_)),
+                _
+            )
+        );
+    }
+
+    #[test]
+    fn should_not_create_a_vote_poll_that_is_not_open() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (identity, signer, key) = setup_identity(&mut platform, 1, dash_to_credits!(1.0));
+
+        let max_duration_ms = platform_version
+            .dpp
+            .validation
+            .voting
+            .yes_no_abstain_vote_poll_max_duration_ms;
+
+        // The transitions are processed at time 0, so a poll ending at 0 is already closed
+        for (nonce, end_time_ms) in [(1, 0), (2, max_duration_ms + 1)] {
+            let execution_result = process(
+                &mut platform,
+                vote_poll_create_transition(
+                    &vote_poll(identity.id(), end_time_ms),
+                    &identity,
+                    &signer,
+                    &key,
+                    nonce,
+                    platform_version,
+                ),
+                platform_version,
+            );
+
+            assert_matches!(
+                execution_result,
+                PaidConsensusError(
+                    ConsensusError::StateError(StateError::YesNoAbstainVotePollNotOpenError(_)),
+                    _
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn should_not_allow_vote_poll_creation_before_protocol_version_11() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_latest_protocol_version()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let (identity, signer, key) = setup_identity(&mut platform, 1, dash_to_credits!(1.0));
+
+        let state_transition = vote_poll_create_transition(
+            &vote_poll(identity.id(), 1_000_000),
+            &identity,
+            &signer,
+            &key,
+            1,
+            platform_version,
+        );
+
+        let bytes = state_transition
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+
+        let platform_version =
+            PlatformVersion::get(10).expect("expected protocol version 10 to exist");
+
+        let result = StateTransition::deserialize_from_bytes_in_version(&bytes, platform_version);
+
+        assert_matches!(
+            result,
+            Err(ProtocolError::StateTransitionError(
+                StateTransitionError::StateTransitionIsNotActiveError { .. }
+            ))
+        );
+
+        StateTransition::deserialize_from_bytes_in_version(&bytes, PlatformVersion::latest())
+            .expect("expected vote poll creation to be active");
+    }
+}
@@ -0,0 +1,57 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::execution::validation::state_transition::processor::v0::StateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::vote_poll_create::state::v0::VotePollCreateStateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::ValidationMode;
+use crate::platform_types::platform::PlatformRef;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::state_transition::vote_poll_create_transition::VotePollCreateTransition;
+use dpp::validation::ConsensusValidationResult;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::StateTransitionAction;
+
+pub(crate) mod v0;
+
+impl StateTransitionStateValidationV0 for VotePollCreateTransition {
+    fn validate_state<C: CoreRPCLike>(
+        &self,
+        action: Option<StateTransitionAction>,
+        platform: &PlatformRef<C>,
+        validation_mode: ValidationMode,
+        block_info: &BlockInfo,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version = platform.state.current_platform_version()?;
+
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .vote_poll_create_state_transition
+            .state
+        {
+            0 => {
+                if action.is_some() {
+                    return Err(Error::Execution(ExecutionError::CorruptedCodeExecution("vote poll create is calling validate state, and the action is already known. It should not be known at this point")));
+                }
+                self.validate_state_v0(
+                    platform,
+                    block_info,
+                    validation_mode,
+                    execution_context,
+                    tx,
+                    platform_version,
+                )
+            }
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "vote poll create transition: validate_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
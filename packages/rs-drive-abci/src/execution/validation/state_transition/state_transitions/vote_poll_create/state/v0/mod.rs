@@ -0,0 +1,215 @@
+use crate::error::Error;
+use crate::platform_types::platform::PlatformRef;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+
+use dpp::consensus::state::state_error::StateError;
+use dpp::consensus::state::voting::invalid_yes_no_abstain_vote_poll_question_error::InvalidYesNoAbstainVotePollQuestionError;
+use dpp::consensus::state::voting::unauthorized_vote_poll_creator_error::UnauthorizedVotePollCreatorError;
+use dpp::consensus::state::voting::vote_poll_already_exists_error::VotePollAlreadyExistsError;
+use dpp::consensus::state::voting::yes_no_abstain_vote_poll_not_open_error::YesNoAbstainVotePollNotOpenError;
+use dpp::consensus::ConsensusError;
+use dpp::dashcore::hashes::Hash;
+use dpp::dashcore_rpc::dashcore_rpc_json::MasternodeType;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::vote_poll_create_transition::accessors::VotePollCreateTransitionAccessorsV0;
+use dpp::state_transition::vote_poll_create_transition::VotePollCreateTransition;
+use dpp::version::PlatformVersion;
+use drive::drive::votes::paths::vote_decisions_tree_path;
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::identity::vote_poll_create::VotePollCreateTransitionAction;
+use drive::state_transition_action::system::bump_identity_nonce_action::BumpIdentityNonceAction;
+use drive::util::grove_operations::DirectQueryType;
+use std::collections::BTreeMap;
+
+use crate::error::execution::ExecutionError;
+use crate::execution::types::execution_operation::ValidationOperation;
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+use crate::execution::validation::state_transition::ValidationMode;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use drive::state_transition_action::StateTransitionAction;
+
+pub(in crate::execution::validation::state_transition::state_transitions::vote_poll_create) trait VotePollCreateStateTransitionStateValidationV0
+{
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        validation_mode: ValidationMode,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+
+    fn transform_into_action_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+}
+
+impl VotePollCreateStateTransitionStateValidationV0 for VotePollCreateTransition {
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        block_info: &BlockInfo,
+        validation_mode: ValidationMode,
+        execution_context: &mut StateTransitionExecutionContext,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let vote_poll = self.vote_poll();
+        let vote_poll_id = vote_poll.unique_id()?;
+        let voting_validation = &platform_version.dpp.validation.voting;
+
+        let question_length = vote_poll.question.chars().count();
+        if question_length == 0
+            || question_length
+                > voting_validation.yes_no_abstain_vote_poll_max_question_length as usize
+        {
+            return Ok(self.bump_nonce_with_error(
+                StateError::InvalidYesNoAbstainVotePollQuestionError(
+                    InvalidYesNoAbstainVotePollQuestionError::new(
+                        question_length as u32,
+                        voting_validation.yes_no_abstain_vote_poll_max_question_length,
+                    ),
+                )
+                .into(),
+            ));
+        }
+
+        // The poll must end in the future and must not end too far in the future
+        let max_duration_ms = voting_validation.yes_no_abstain_vote_poll_max_duration_ms;
+        if vote_poll.end_time_ms <= block_info.time_ms
+            || vote_poll.end_time_ms > block_info.time_ms.saturating_add(max_duration_ms)
+        {
+            return Ok(self.bump_nonce_with_error(
+                StateError::YesNoAbstainVotePollNotOpenError(
+                    YesNoAbstainVotePollNotOpenError::new(
+                        vote_poll_id,
+                        vote_poll.end_time_ms,
+                        block_info.time_ms,
+                        max_duration_ms,
+                    ),
+                )
+                .into(),
+            ));
+        }
+
+        // Only the subject of the poll, or the owner of the data contract that is the subject,
+        // may open a poll about it
+        if vote_poll.subject_id != self.identity_id() {
+            let (fee, maybe_contract_fetch_info) =
+                platform.drive.get_contract_with_fetch_info_and_fee(
+                    vote_poll.subject_id.to_buffer(),
+                    Some(&block_info.epoch),
+                    validation_mode.can_alter_cache(),
+                    tx,
+                    platform_version,
+                )?;
+
+            let fee = fee.ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "fee must exist in validate state for vote poll create transition",
+            )))?;
+
+            execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+
+            let is_contract_owner = maybe_contract_fetch_info
+                .is_some_and(|fetch_info| fetch_info.contract.owner_id() == self.identity_id());
+
+            if !is_contract_owner {
+                return Ok(self.bump_nonce_with_error(
+                    StateError::UnauthorizedVotePollCreatorError(
+                        UnauthorizedVotePollCreatorError::new(
+                            vote_poll_id,
+                            self.identity_id(),
+                            vote_poll.subject_id,
+                        ),
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        let mut drive_operations = vec![];
+
+        let existing_vote_poll = platform.drive.grove_get_raw_optional(
+            (&vote_decisions_tree_path()).into(),
+            vote_poll_id.as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            tx,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        let fee = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            platform.drive.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+
+        execution_context.add_operation(ValidationOperation::PrecalculatedOperation(fee));
+
+        if existing_vote_poll.is_some() {
+            return Ok(self.bump_nonce_with_error(
+                StateError::VotePollAlreadyExistsError(VotePollAlreadyExistsError::new(
+                    vote_poll_id,
+                ))
+                .into(),
+            ));
+        }
+
+        self.transform_into_action_v0(platform)
+    }
+
+    fn transform_into_action_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        // The masternodes that may vote on the poll are fixed when it is created
+        let eligible_voters = platform
+            .state
+            .full_masternode_list()
+            .iter()
+            .map(|(pro_tx_hash, masternode)| {
+                let strength = match masternode.node_type {
+                    MasternodeType::Regular => 1,
+                    MasternodeType::Evo => 4,
+                };
+                (pro_tx_hash.to_byte_array(), strength)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let action =
+            VotePollCreateTransitionAction::from_borrowed_transition(self, eligible_voters);
+
+        let action: StateTransitionAction = action.into();
+        Ok(action.into())
+    }
+}
+
+trait VotePollCreateTransitionBumpNonce {
+    fn bump_nonce_with_error(
+        &self,
+        error: ConsensusError,
+    ) -> ConsensusValidationResult<StateTransitionAction>;
+}
+
+impl VotePollCreateTransitionBumpNonce for VotePollCreateTransition {
+    fn bump_nonce_with_error(
+        &self,
+        error: ConsensusError,
+    ) -> ConsensusValidationResult<StateTransitionAction> {
+        let bump_action = StateTransitionAction::BumpIdentityNonceAction(
+            BumpIdentityNonceAction::from_borrowed_vote_poll_create_transition(self),
+        );
+
+        ConsensusValidationResult::new_with_data_and_errors(bump_action, vec![error])
+    }
+}
@@ -64,6 +64,13 @@ impl StateTransitionActionTransformerV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            StateTransition::DataContractDelete(st) => st.transform_into_action(
+                platform,
+                block_info,
+                validation_mode,
+                execution_context,
+                tx,
+            ),
             StateTransition::IdentityCreate(st) => {
                 let signable_bytes = self.signable_bytes()?;
                 st.transform_into_action_for_identity_create_transition(
@@ -119,6 +126,13 @@ impl StateTransitionActionTransformerV0 for StateTransition {
                 execution_context,
                 tx,
             ),
+            StateTransition::VotePollCreate(st) => st.transform_into_action(
+                platform,
+                block_info,
+                validation_mode,
+                execution_context,
+                tx,
+            ),
         }
     }
 }
@@ -52,3 +52,9 @@ pub mod utils;
 
 /// Drive server
 pub mod server;
+
+/// Replay of recorded block execution requests
+pub mod replay;
+
+/// Archive of rejected state transitions
+pub mod rejections;
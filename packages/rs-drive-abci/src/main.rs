@@ -7,19 +7,26 @@ use dapi_grpc::platform::v0::get_status_request;
 use dapi_grpc::platform::v0::get_status_request::GetStatusRequestV0;
 use dapi_grpc::platform::v0::platform_client::PlatformClient;
 use dapi_grpc::tonic::transport::Uri;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::platform_value::string_encoding::Encoding;
+use dpp::prelude::Identifier;
 use dpp::version::PlatformVersion;
 use drive_abci::config::{FromEnv, PlatformConfig};
 use drive_abci::core::wait_for_core_to_sync::v0::wait_for_core_to_sync_v0;
 use drive_abci::logging::{LogBuilder, LogConfig, LogDestination, Loggers};
 use drive_abci::metrics::Prometheus;
 use drive_abci::platform_types::platform::Platform;
+use drive_abci::rejections::{
+    revalidate_rejected_state_transition, RejectionArchive, RejectionFilter,
+};
+use drive_abci::replay::ReplayCoreRPC;
 use drive_abci::rpc::core::DefaultCoreRPC;
-use drive_abci::{logging, server};
+use drive_abci::{logging, replay, server};
 use itertools::Itertools;
-use std::fs::remove_file;
+use std::fs::{copy, create_dir_all, read_dir, remove_file};
 #[cfg(all(tokio_unstable, feature = "console"))]
 use std::net::SocketAddr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -63,6 +70,76 @@ enum Commands {
     /// Print current software version
     #[command()]
     Version,
+
+    /// Replay recorded block execution requests.
+    ///
+    /// Re-executes a recorded sequence of `PrepareProposal`, `ProcessProposal` and `FinalizeBlock`
+    /// requests on top of a GroveDB snapshot and reports app hash divergence per block.
+    ///
+    /// Core RPC configured for the node is used for requests that read from Dash Core.
+    /// Transactions, like signed withdrawals, are never broadcast during replay.
+    #[command()]
+    Replay {
+        /// Path to the GroveDB snapshot the recording starts from.
+        #[arg(long, value_hint = clap::ValueHint::DirPath)]
+        snapshot: PathBuf,
+
+        /// Path to the file with recorded ABCI requests.
+        #[arg(long, value_hint = clap::ValueHint::FilePath)]
+        requests: PathBuf,
+
+        /// Copy the snapshot into this directory and replay there.
+        ///
+        /// If not set, the snapshot is copied into a temporary directory that is removed
+        /// after replay. The snapshot itself is never modified.
+        #[arg(long, value_hint = clap::ValueHint::DirPath)]
+        work_dir: Option<PathBuf>,
+
+        /// Stop at the first block whose app hash diverges.
+        #[arg(long)]
+        stop_on_divergence: bool,
+    },
+
+    /// Inspect state transitions rejected during block execution.
+    ///
+    /// Rejected state transitions are archived in `REJECTIONS_PATH`.
+    #[command()]
+    Rejections {
+        #[command(subcommand)]
+        command: RejectionsCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum RejectionsCommand {
+    /// List archived rejected state transitions.
+    #[command()]
+    List {
+        /// Only list state transitions rejected with this consensus error code.
+        #[arg(long)]
+        error_code: Option<u32>,
+
+        /// Only list state transitions owned by this identity (base58).
+        #[arg(long)]
+        identity: Option<String>,
+
+        /// Only list state transitions proposed at or after this block height.
+        #[arg(long)]
+        from_height: Option<u64>,
+
+        /// Only list state transitions proposed at or before this block height.
+        #[arg(long)]
+        to_height: Option<u64>,
+    },
+
+    /// Re-validate an archived state transition against the current state.
+    ///
+    /// Core RPC configured for the node is used to validate asset lock proofs.
+    #[command()]
+    Revalidate {
+        /// Id of the archived state transition, as shown by `list`.
+        id: u64,
+    },
 }
 
 /// Server that accepts connections from Tenderdash, and
@@ -153,6 +230,13 @@ impl Cli {
             Commands::Status => runtime.block_on(check_status(&config))?,
             Commands::Verify => verify_grovedb(&config.db_path, true)?,
             Commands::Version => print_version(),
+            Commands::Replay {
+                snapshot,
+                requests,
+                work_dir,
+                stop_on_divergence,
+            } => replay(config, snapshot, requests, work_dir, stop_on_divergence)?,
+            Commands::Rejections { command } => inspect_rejections(config, command)?,
         };
 
         Ok(())
@@ -387,6 +471,196 @@ fn verify_grovedb(db_path: &PathBuf, force: bool) -> Result<(), String> {
     }
 }
 
+/// Replay recorded ABCI requests on top of a GroveDB snapshot.
+///
+/// Returns an error if any of the replayed blocks has diverged.
+fn replay(
+    mut config: PlatformConfig,
+    snapshot: PathBuf,
+    requests: PathBuf,
+    work_dir: Option<PathBuf>,
+    stop_on_divergence: bool,
+) -> Result<(), String> {
+    // Removed when replay is finished; declared first so it outlives the platform
+    let temp_dir;
+
+    let db_path = match work_dir {
+        Some(work_dir) => {
+            if work_dir.exists() {
+                return Err(format!(
+                    "replay work directory {} already exists",
+                    work_dir.display()
+                ));
+            }
+            work_dir
+        }
+        None => {
+            temp_dir = tempfile::tempdir()
+                .map_err(|e| format!("cannot create replay work directory: {e}"))?;
+            temp_dir.path().join("db")
+        }
+    };
+
+    copy_dir(&snapshot, &db_path).map_err(|e| {
+        format!(
+            "cannot copy snapshot {} to {}: {e}",
+            snapshot.display(),
+            db_path.display()
+        )
+    })?;
+
+    let recorded = replay::read_recording_file(&requests).map_err(|e| e.to_string())?;
+
+    tracing::info!(
+        requests = recorded.len(),
+        db_path = db_path.display().to_string(),
+        "replaying recorded requests"
+    );
+
+    let core_rpc = DefaultCoreRPC::open(
+        config.core.consensus_rpc.url().as_str(),
+        config.core.consensus_rpc.username.clone(),
+        config.core.consensus_rpc.password.clone(),
+    )
+    .map_err(|e| format!("cannot connect to core rpc: {e}"))?;
+
+    // Replay must not leave traces outside of the work directory
+    config.rejections_path = None;
+    config.abci.request_recording_dir = None;
+
+    let platform: Platform<ReplayCoreRPC<DefaultCoreRPC>> =
+        Platform::open_with_client(db_path, Some(config), ReplayCoreRPC::new(core_rpc), None)
+            .map_err(|e| format!("cannot open platform: {e}"))?;
+
+    let reports = replay::replay_requests(&platform, recorded, stop_on_divergence)
+        .map_err(|e| e.to_string())?;
+
+    for report in &reports {
+        println!("{report}");
+    }
+
+    let diverged = reports
+        .iter()
+        .filter(|report| report.is_divergent())
+        .count();
+
+    if diverged > 0 {
+        return Err(format!(
+            "{diverged} of {} replayed blocks diverged",
+            reports.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Inspect archived rejected state transitions.
+fn inspect_rejections(config: PlatformConfig, command: RejectionsCommand) -> Result<(), String> {
+    let rejections_path = config
+        .rejections_path
+        .clone()
+        .ok_or("rejections path is not configured (REJECTIONS_PATH)")?;
+
+    let archive = RejectionArchive::new(rejections_path);
+
+    match command {
+        RejectionsCommand::List {
+            error_code,
+            identity,
+            from_height,
+            to_height,
+        } => {
+            let owner_id = identity
+                .map(|identity| Identifier::from_string(&identity, Encoding::Base58))
+                .transpose()
+                .map_err(|e| format!("invalid identity id: {e}"))?;
+
+            let filter = RejectionFilter {
+                error_code,
+                owner_id,
+                min_block_height: from_height,
+                max_block_height: to_height,
+            };
+
+            for entry in archive.entries(&filter).map_err(|e| e.to_string())? {
+                let record = archive.read(&entry).map_err(|e| e.to_string())?;
+
+                let owner_id = record
+                    .owner_id()
+                    .map(|owner_id| Identifier::new(owner_id).to_string(Encoding::Base58))
+                    .unwrap_or_else(|| "-".to_string());
+
+                let fee = record
+                    .fee_estimate()
+                    .map(|fee| {
+                        format!(
+                            "processing {} storage {}",
+                            fee.processing_fee, fee.storage_fee
+                        )
+                    })
+                    .unwrap_or_else(|| "unpaid".to_string());
+
+                println!(
+                    "{}\ttx {}\theight {} round {}\tcode {}\towner {}\tfee {}\t{}",
+                    entry.id,
+                    hex::encode_upper(entry.tx_hash),
+                    record.block_height(),
+                    record.round(),
+                    record.error_code(),
+                    owner_id,
+                    fee,
+                    record.error_message()
+                );
+            }
+        }
+        RejectionsCommand::Revalidate { id } => {
+            let record = archive.get(id).map_err(|e| e.to_string())?;
+
+            let core_rpc = DefaultCoreRPC::open(
+                config.core.consensus_rpc.url().as_str(),
+                config.core.consensus_rpc.username.clone(),
+                config.core.consensus_rpc.password.clone(),
+            )
+            .map_err(|e| format!("cannot connect to core rpc: {e}"))?;
+
+            let platform: Platform<DefaultCoreRPC> =
+                Platform::open_with_client(config.db_path.clone(), Some(config), core_rpc, None)
+                    .map_err(|e| format!("cannot open platform: {e}"))?;
+
+            let errors = revalidate_rejected_state_transition(&platform, &record)
+                .map_err(|e| e.to_string())?;
+
+            if errors.is_empty() {
+                println!("state transition {id} is valid against the current state");
+            } else {
+                for error in errors {
+                    println!("code {}\t{}", error.code(), error);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Recursively copy directory content.
+fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    create_dir_all(to)?;
+
+    for entry in read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            copy(entry.path(), target)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Print current software version.
 fn print_version() {
     println!("{}", env!("CARGO_PKG_VERSION"));
@@ -0,0 +1,185 @@
+use crate::error::Error;
+use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+use dpp::bincode::{config, Decode, Encode};
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::ConsensusError;
+use dpp::fee::fee_result::FeeResult;
+use dpp::fee::Credits;
+use dpp::prelude::{BlockHeight, TimestampMillis};
+use dpp::serialization::PlatformSerializableWithPlatformVersion;
+use dpp::version::PlatformVersion;
+use dpp::ProtocolError;
+
+/// How a state transition included in a block was executed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub enum ExecutedStateTransitionStatus {
+    /// Executed and applied to the state
+    Success,
+    /// Failed validation but was charged fees
+    PaidConsensusError,
+    /// Failed validation without paying fees
+    UnpaidConsensusError,
+    /// Failed because of an internal error
+    InternalError,
+    /// Was not executed
+    NotExecuted,
+}
+
+/// The fees paid by an executed state transition
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct ExecutedStateTransitionFees {
+    /// The storage fee
+    pub storage_fee: Credits,
+    /// The processing fee
+    pub processing_fee: Credits,
+    /// The refunds per identity, summed over all epochs
+    pub fee_refunds: Vec<([u8; 32], Credits)>,
+    /// The bytes removed from the system
+    pub removed_bytes_from_system: u32,
+}
+
+impl From<&FeeResult> for ExecutedStateTransitionFees {
+    fn from(fee_result: &FeeResult) -> Self {
+        Self {
+            storage_fee: fee_result.storage_fee,
+            processing_fee: fee_result.processing_fee,
+            fee_refunds: fee_result
+                .fee_refunds
+                .iter()
+                .map(|(identity_id, credits_per_epoch)| {
+                    (*identity_id, credits_per_epoch.values().sum::<Credits>())
+                })
+                .collect(),
+            removed_bytes_from_system: fee_result.removed_bytes_from_system,
+        }
+    }
+}
+
+/// The consensus error an executed state transition failed with
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct ExecutedStateTransitionError {
+    /// The consensus error code
+    pub code: u32,
+    /// The error message
+    pub message: String,
+    /// The serialized consensus error
+    pub data: Vec<u8>,
+}
+
+impl ExecutedStateTransitionError {
+    fn from_consensus_error(
+        error: &ConsensusError,
+        platform_version: &PlatformVersion,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            code: error.code(),
+            message: error.to_string(),
+            data: error
+                .serialize_to_bytes_with_platform_version(platform_version)
+                .map_err(Error::Protocol)?,
+        })
+    }
+}
+
+/// A state transition included in a block with the result of its execution
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct ExecutedStateTransition {
+    /// The state transition as it was included in the block
+    pub raw_state_transition: Vec<u8>,
+    /// The execution status
+    pub status: ExecutedStateTransitionStatus,
+    /// The fees paid, `None` if the state transition was not paid for
+    pub fees: Option<ExecutedStateTransitionFees>,
+    /// The consensus error the state transition failed with
+    pub error: Option<ExecutedStateTransitionError>,
+}
+
+impl ExecutedStateTransition {
+    /// Pairs the raw state transitions of a block with their execution results,
+    /// `execution_results` must be in the same order as `raw_state_transitions`.
+    pub fn from_execution_results(
+        raw_state_transitions: &[Vec<u8>],
+        execution_results: &[StateTransitionExecutionResult],
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<Self>, Error> {
+        raw_state_transitions
+            .iter()
+            .zip(execution_results)
+            .map(|(raw_state_transition, execution_result)| {
+                let (status, fees, error) = match execution_result {
+                    StateTransitionExecutionResult::SuccessfulExecution(_, fee_result) => (
+                        ExecutedStateTransitionStatus::Success,
+                        Some(fee_result.into()),
+                        None,
+                    ),
+                    StateTransitionExecutionResult::PaidConsensusError(error, fee_result) => (
+                        ExecutedStateTransitionStatus::PaidConsensusError,
+                        Some(fee_result.into()),
+                        Some(ExecutedStateTransitionError::from_consensus_error(
+                            error,
+                            platform_version,
+                        )?),
+                    ),
+                    StateTransitionExecutionResult::UnpaidConsensusError(error) => (
+                        ExecutedStateTransitionStatus::UnpaidConsensusError,
+                        None,
+                        Some(ExecutedStateTransitionError::from_consensus_error(
+                            error,
+                            platform_version,
+                        )?),
+                    ),
+                    StateTransitionExecutionResult::InternalError(_) => {
+                        (ExecutedStateTransitionStatus::InternalError, None, None)
+                    }
+                    StateTransitionExecutionResult::NotExecuted(_) => {
+                        (ExecutedStateTransitionStatus::NotExecuted, None, None)
+                    }
+                };
+
+                Ok(ExecutedStateTransition {
+                    raw_state_transition: raw_state_transition.clone(),
+                    status,
+                    fees,
+                    error,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The executed state transitions of a block, kept for block explorer queries
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct BlockStateTransitions {
+    /// The block height
+    pub block_height: BlockHeight,
+    /// The block time
+    pub block_time_ms: TimestampMillis,
+    /// The executed state transitions in block order
+    pub state_transitions: Vec<ExecutedStateTransition>,
+}
+
+impl BlockStateTransitions {
+    /// Serializes the block state transitions for storage
+    pub fn serialize_to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let config = config::standard().with_big_endian().with_no_limit();
+        dpp::bincode::encode_to_vec(self, config).map_err(|e| {
+            Error::Protocol(ProtocolError::PlatformSerializationError(format!(
+                "unable to serialize BlockStateTransitions: {}",
+                e
+            )))
+        })
+    }
+
+    /// Deserializes stored block state transitions
+    pub fn deserialize_from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let config = config::standard().with_big_endian().with_no_limit();
+        dpp::bincode::decode_from_slice(bytes, config)
+            .map(|(block_state_transitions, _)| block_state_transitions)
+            .map_err(|e| {
+                Error::Protocol(ProtocolError::PlatformDeserializationError(format!(
+                    "unable to deserialize BlockStateTransitions: {}",
+                    e
+                )))
+            })
+    }
+}
@@ -0,0 +1,155 @@
+use crate::error::Error;
+use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::document::Document;
+use dpp::identifier::Identifier;
+use dpp::serialization::PlatformDeserializable;
+use dpp::state_transition::batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
+use dpp::state_transition::batch_transition::batched_transition::document_transition::{
+    DocumentTransition, DocumentTransitionV0Methods,
+};
+use dpp::state_transition::batch_transition::batched_transition::BatchedTransitionRef;
+use dpp::state_transition::batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
+use dpp::state_transition::batch_transition::BatchTransition;
+use dpp::state_transition::StateTransition;
+use dpp::version::PlatformVersion;
+use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use drive::drive::Drive;
+use drive::query::DriveDocumentQuery;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// How many blocks of document transitions are kept for subscribers that are late to read them
+pub const DOCUMENT_TRANSITIONS_EVENTS_CAPACITY: usize = 64;
+
+/// Publishes the document transitions of every committed block to document subscribers
+pub type DocumentTransitionsEventSender = broadcast::Sender<Arc<DocumentTransitionsEvent>>;
+
+/// A batch state transition that was successfully executed in a block
+#[derive(Debug, Clone)]
+pub struct ExecutedBatchTransition {
+    /// The state transition as it was included in the block
+    pub raw_state_transition: Vec<u8>,
+    /// The deserialized batch transition
+    pub batch_transition: BatchTransition,
+}
+
+impl ExecutedBatchTransition {
+    /// Picks the successfully executed batch transitions out of the raw state transitions
+    /// of a block, `execution_results` must be in the same order as `raw_state_transitions`.
+    pub fn from_execution_results(
+        raw_state_transitions: &[Vec<u8>],
+        execution_results: &[StateTransitionExecutionResult],
+    ) -> Vec<Self> {
+        raw_state_transitions
+            .iter()
+            .zip(execution_results)
+            .filter_map(|(raw_state_transition, execution_result)| {
+                if !matches!(
+                    execution_result,
+                    StateTransitionExecutionResult::SuccessfulExecution(..)
+                ) {
+                    return None;
+                }
+
+                match StateTransition::deserialize_from_bytes(raw_state_transition) {
+                    Ok(StateTransition::Batch(batch_transition)) => Some(ExecutedBatchTransition {
+                        raw_state_transition: raw_state_transition.clone(),
+                        batch_transition,
+                    }),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+/// The document transitions executed in a committed block
+#[derive(Debug, Clone)]
+pub struct DocumentTransitionsEvent {
+    /// The height of the block
+    pub block_height: u64,
+    /// The successfully executed batch transitions of the block, in block order
+    pub batch_transitions: Vec<ExecutedBatchTransition>,
+    /// The documents as they were before the block, by document id, for every
+    /// document transition other than a creation
+    pub original_documents: BTreeMap<Identifier, Document>,
+}
+
+impl DocumentTransitionsEvent {
+    /// Creates the event of a block.
+    ///
+    /// This must be called before the block transaction is committed, the original documents
+    /// are read from the last committed state.
+    pub fn new(
+        block_height: u64,
+        batch_transitions: Vec<ExecutedBatchTransition>,
+        drive: &Drive,
+        platform_version: &PlatformVersion,
+    ) -> Result<Self, Error> {
+        let mut original_documents = BTreeMap::new();
+
+        for executed in &batch_transitions {
+            for transition in executed.batch_transition.transitions_iter() {
+                let BatchedTransitionRef::Document(document_transition) = transition else {
+                    continue;
+                };
+
+                if matches!(document_transition, DocumentTransition::Create(_)) {
+                    continue;
+                }
+
+                let base = document_transition.base();
+
+                if original_documents.contains_key(&base.id()) {
+                    continue;
+                }
+
+                let Some(contract) = drive.get_contract_with_fetch_info(
+                    base.data_contract_id().to_buffer(),
+                    false,
+                    None,
+                    platform_version,
+                )?
+                else {
+                    continue;
+                };
+
+                let Ok(document_type) = contract
+                    .contract
+                    .document_type_for_name(base.document_type_name())
+                else {
+                    continue;
+                };
+
+                let query = DriveDocumentQuery::new_primary_key_single_item_query(
+                    &contract.contract,
+                    document_type,
+                    base.id(),
+                );
+
+                let original_document = drive
+                    .query_documents(
+                        query,
+                        None,
+                        false,
+                        None,
+                        Some(platform_version.protocol_version),
+                    )?
+                    .documents_owned()
+                    .pop();
+
+                if let Some(original_document) = original_document {
+                    original_documents.insert(base.id(), original_document);
+                }
+            }
+        }
+
+        Ok(Self {
+            block_height,
+            batch_transitions,
+            original_documents,
+        })
+    }
+}
@@ -2,10 +2,14 @@
 pub mod block_execution_outcome;
 /// The block proposal
 pub mod block_proposal;
+/// The executed state transitions of recent blocks kept for block explorer queries
+pub mod block_state_transitions;
 /// A clean version of the the requst to finalize a block
 pub mod cleaned_abci_messages;
 /// The commit
 pub mod commit;
+/// The document transitions of committed blocks published to document subscribers
+pub mod document_transitions_event;
 /// Epoch
 pub mod epoch_info;
 /// The execution event result
@@ -4,10 +4,14 @@ mod mock;
 use crate::config::PlatformConfig;
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::rejections::RejectionArchiver;
 use crate::rpc::core::{CoreRPCLike, DefaultCoreRPC};
 use drive::drive::Drive;
 use std::fmt::{Debug, Formatter};
 
+use crate::platform_types::document_transitions_event::{
+    DocumentTransitionsEventSender, DOCUMENT_TRANSITIONS_EVENTS_CAPACITY,
+};
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
 use arc_swap::ArcSwap;
@@ -17,6 +21,7 @@ use dpp::version::{PlatformVersion, PlatformVersionCurrentVersion};
 use std::path::Path;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
+use tokio::sync::broadcast;
 
 // @append_only
 /// Platform is not versioned as it holds the main logic, we could not switch from one structure
@@ -36,6 +41,10 @@ pub struct Platform<C> {
     pub config: PlatformConfig,
     /// Core RPC Client
     pub core_rpc: C,
+    /// Document transitions of committed blocks, for document subscriptions
+    pub document_transitions_events: DocumentTransitionsEventSender,
+    /// Archiver of rejected state transitions, if [PlatformConfig::rejections_path] is set
+    pub rejection_archiver: Option<RejectionArchiver>,
 }
 
 // @append_only
@@ -198,12 +207,21 @@ impl<C> Platform<C> {
 
         PlatformVersion::set_current(platform_version);
 
+        let rejection_archiver = config
+            .rejections_path
+            .as_ref()
+            .map(RejectionArchiver::start)
+            .transpose()
+            .map_err(ExecutionError::from)?;
+
         let platform: Platform<C> = Platform {
             drive,
             state: ArcSwap::new(Arc::new(platform_state)),
             committed_block_height_guard: AtomicU64::from(height),
             config,
             core_rpc,
+            document_transitions_events: broadcast::channel(DOCUMENT_TRANSITIONS_EVENTS_CAPACITY).0,
+            rejection_archiver,
         };
 
         Ok(platform)
@@ -230,12 +248,21 @@ impl<C> Platform<C> {
 
         PlatformVersion::set_current(PlatformVersion::get(current_protocol_version_in_consensus)?);
 
+        let rejection_archiver = config
+            .rejections_path
+            .as_ref()
+            .map(RejectionArchiver::start)
+            .transpose()
+            .map_err(ExecutionError::from)?;
+
         Ok(Platform {
             drive,
             state: ArcSwap::new(Arc::new(platform_state)),
             committed_block_height_guard: AtomicU64::from(height),
             config,
             core_rpc,
+            document_transitions_events: broadcast::channel(DOCUMENT_TRANSITIONS_EVENTS_CAPACITY).0,
+            rejection_archiver,
         })
     }
 }
@@ -0,0 +1,503 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_documents_aggregate_request::get_documents_aggregate_request_v0::AggregateFunction;
+use dapi_grpc::platform::v0::get_documents_aggregate_request::GetDocumentsAggregateRequestV0;
+use dapi_grpc::platform::v0::get_documents_aggregate_response::{
+    get_documents_aggregate_response_v0, GetDocumentsAggregateResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::identifier::Identifier;
+use dpp::platform_value::Value;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+use drive::query::{DocumentAggregateFunction, DriveDocumentAggregateQuery, DriveDocumentQuery};
+
+impl<C> Platform<C> {
+    pub(super) fn query_documents_aggregate_v0(
+        &self,
+        GetDocumentsAggregateRequestV0 {
+            data_contract_id,
+            document_type: document_type_name,
+            r#where,
+            order_by,
+            function,
+            sum_property,
+            limit,
+            start_after,
+            prove,
+        }: GetDocumentsAggregateRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetDocumentsAggregateResponseV0>, Error> {
+        let contract_id: Identifier = check_validation_result_with_data!(data_contract_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let function = match AggregateFunction::try_from(function) {
+            Ok(AggregateFunction::Count) => DocumentAggregateFunction::Count,
+            Ok(AggregateFunction::Sum) => {
+                if sum_property.is_empty() {
+                    return Ok(QueryValidationResult::new_with_error(
+                        QueryError::InvalidArgument(
+                            "sum_property must be set for sum aggregates".to_string(),
+                        ),
+                    ));
+                }
+                DocumentAggregateFunction::Sum(sum_property)
+            }
+            Err(_) => {
+                return Ok(QueryValidationResult::new_with_error(
+                    QueryError::InvalidArgument(format!("unknown aggregate function {}", function)),
+                ));
+            }
+        };
+
+        let start_after: Option<[u8; 32]> = if start_after.is_empty() {
+            None
+        } else {
+            Some(check_validation_result_with_data!(start_after
+                .try_into()
+                .map_err(|_| QueryError::Query(
+                    QuerySyntaxError::InvalidStartAfter(
+                        "start after should be a 32 byte identifier".to_string()
+                    )
+                ))))
+        };
+
+        // Checked for both paths: without proofs the limit is not used, but a request
+        // must not be accepted or rejected depending on the prove flag
+        if limit > self.config.drive.max_query_limit as u32 {
+            return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                QuerySyntaxError::InvalidLimit(format!(
+                    "limit {} out of bounds, max is {}",
+                    limit, self.config.drive.max_query_limit
+                )),
+            )));
+        }
+
+        let (_, contract) = self.drive.get_contract_with_fetch_info_and_fee(
+            contract_id.to_buffer(),
+            None,
+            true,
+            None,
+            platform_version,
+        )?;
+
+        let contract = check_validation_result_with_data!(contract.ok_or(QueryError::Query(
+            QuerySyntaxError::DataContractNotFound(
+                "contract not found when querying from value with contract info",
+            )
+        )));
+
+        let contract_ref = &contract.contract;
+
+        let document_type = check_validation_result_with_data!(contract_ref
+            .document_type_for_name(document_type_name.as_str())
+            .map_err(|_| QueryError::InvalidArgument(format!(
+                "document type {} not found for contract {}",
+                document_type_name, contract_id
+            ))));
+
+        let where_clause = if r#where.is_empty() {
+            Value::Null
+        } else {
+            check_validation_result_with_data!(ciborium::de::from_reader(r#where.as_slice())
+                .map_err(|_| {
+                    QueryError::Query(QuerySyntaxError::DeserializationError(
+                        "unable to decode 'where' query from cbor".to_string(),
+                    ))
+                }))
+        };
+
+        let order_by = if !order_by.is_empty() {
+            check_validation_result_with_data!(ciborium::de::from_reader(order_by.as_slice())
+                .map_err(|_| {
+                    QueryError::Query(QuerySyntaxError::DeserializationError(
+                        "unable to decode 'order_by' query from cbor".to_string(),
+                    ))
+                }))
+        } else {
+            None
+        };
+
+        let drive_query =
+            check_validation_result_with_data!(DriveDocumentQuery::from_decomposed_values(
+                where_clause,
+                order_by,
+                None,
+                None,
+                true,
+                None,
+                contract_ref,
+                document_type,
+                &self.config.drive,
+            ));
+
+        let aggregate_query = check_validation_result_with_data!(DriveDocumentAggregateQuery::new(
+            drive_query,
+            function
+        )
+        .map_err(|e| match e {
+            drive::error::Error::Query(query_error) => QueryError::Query(query_error),
+            e => QueryError::InvalidArgument(e.to_string()),
+        }));
+
+        let response = if prove {
+            let limit = if limit == 0 {
+                self.config.drive.default_query_limit
+            } else {
+                limit as u16
+            };
+
+            let proof = match aggregate_query.execute_with_proof(
+                &self.drive,
+                start_after,
+                limit,
+                None,
+                None,
+                platform_version,
+            ) {
+                Ok(result) => result.0,
+                Err(drive::error::Error::Query(query_error)) => {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        query_error,
+                    )));
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            GetDocumentsAggregateResponseV0 {
+                result: Some(get_documents_aggregate_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let outcome = match self.drive.query_documents_aggregate(
+                &aggregate_query,
+                start_after,
+                None,
+                None,
+                platform_version,
+            ) {
+                Ok(result) => result.0,
+                Err(drive::error::Error::Query(query_error)) => {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        query_error,
+                    )));
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            GetDocumentsAggregateResponseV0 {
+                result: Some(get_documents_aggregate_response_v0::Result::Aggregate(
+                    get_documents_aggregate_response_v0::DocumentsAggregate {
+                        count: outcome.count,
+                        sum: outcome.sum,
+                        next_start_after: outcome
+                            .next_start_after
+                            .map(|id| id.to_vec())
+                            .unwrap_or_default(),
+                    },
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::{
+        assert_invalid_identifier, setup_platform, store_data_contract, store_document,
+    };
+    use dpp::dashcore::Network;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::document::{Document, DocumentV0};
+    use dpp::tests::fixtures::get_data_contract_fixture;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::BTreeMap;
+
+    fn count_request(
+        data_contract_id: Vec<u8>,
+        document_type: &str,
+    ) -> GetDocumentsAggregateRequestV0 {
+        GetDocumentsAggregateRequestV0 {
+            data_contract_id,
+            document_type: document_type.to_string(),
+            r#where: vec![],
+            order_by: vec![],
+            function: AggregateFunction::Count as i32,
+            sum_property: String::new(),
+            limit: 0,
+            start_after: vec![],
+            prove: false,
+        }
+    }
+
+    #[test]
+    fn test_invalid_document_id() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let request = count_request(vec![0; 8], "niceDocument");
+
+        let result = platform
+            .query_documents_aggregate_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert_invalid_identifier(result);
+    }
+
+    #[test]
+    fn test_invalid_start_after() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let mut request = count_request(vec![0; 32], "niceDocument");
+        request.start_after = vec![0; 8];
+
+        let result = platform
+            .query_documents_aggregate_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::Query(QuerySyntaxError::InvalidStartAfter(msg))] if msg == "start after should be a 32 byte identifier"
+        ));
+    }
+
+    #[test]
+    fn test_limit_above_max_query_limit() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let max_query_limit = platform.config.drive.max_query_limit as u32;
+
+        for prove in [false, true] {
+            let mut request = count_request(vec![0; 32], "niceDocument");
+            request.limit = max_query_limit + 1;
+            request.prove = prove;
+
+            let result = platform
+                .query_documents_aggregate_v0(request, &state, version)
+                .expect("expected query to succeed");
+
+            assert!(matches!(
+                result.errors.as_slice(),
+                [QueryError::Query(QuerySyntaxError::InvalidLimit(_))]
+            ));
+        }
+    }
+
+    #[test]
+    fn test_sum_requires_property() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let mut request = count_request(vec![0; 32], "niceDocument");
+        request.function = AggregateFunction::Sum as i32;
+
+        let result = platform
+            .query_documents_aggregate_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg == "sum_property must be set for sum aggregates"
+        ));
+    }
+
+    #[test]
+    fn test_sum_of_non_integer_property() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let mut request = count_request(
+            created_data_contract.data_contract().id().to_vec(),
+            "niceDocument",
+        );
+        request.function = AggregateFunction::Sum as i32;
+        request.sum_property = "name".to_string();
+
+        let result = platform
+            .query_documents_aggregate_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::Query(
+                QuerySyntaxError::InvalidAggregateProperty(_)
+            )]
+        ));
+    }
+
+    #[test]
+    fn test_documents_count() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let platform_version = PlatformVersion::latest();
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let document_type_name = "niceDocument";
+        let document_type = created_data_contract
+            .data_contract()
+            .document_type_for_name(document_type_name)
+            .expect("expected document type");
+
+        let mut std_rng = StdRng::seed_from_u64(393);
+        for _i in 0..20 {
+            let random_document = document_type
+                .random_document_with_rng(&mut std_rng, platform_version)
+                .expect("expected to get random document");
+            store_document(
+                &platform,
+                created_data_contract.data_contract(),
+                document_type,
+                &random_document,
+                platform_version,
+            );
+        }
+
+        let request = count_request(
+            created_data_contract.data_contract().id().to_vec(),
+            document_type_name,
+        );
+
+        let result = platform
+            .query_documents_aggregate_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        let Some(GetDocumentsAggregateResponseV0 {
+            result:
+                Some(get_documents_aggregate_response_v0::Result::Aggregate(
+                    get_documents_aggregate_response_v0::DocumentsAggregate {
+                        count,
+                        sum,
+                        next_start_after,
+                    },
+                )),
+            metadata: Some(_),
+        }) = result.data
+        else {
+            panic!("expected aggregate")
+        };
+
+        assert_eq!(count, 20);
+        assert_eq!(sum, 0);
+        assert!(next_start_after.is_empty());
+    }
+
+    #[test]
+    fn test_documents_sum_proof() {
+        let (platform, state, version) = setup_platform(Some((1, 1)), Network::Testnet, None);
+
+        let platform_version = PlatformVersion::latest();
+        let withdrawals = platform
+            .drive
+            .cache
+            .system_data_contracts
+            .load_withdrawals();
+
+        let document_type_name = "withdrawal";
+        let document_type = withdrawals
+            .document_type_for_name(document_type_name)
+            .expect("expected document type");
+
+        let mut std_rng = StdRng::seed_from_u64(393);
+
+        for i in 0..15 {
+            let document: Document = DocumentV0 {
+                id: Identifier::random_with_rng(&mut std_rng),
+                owner_id: Identifier::random_with_rng(&mut std_rng),
+                properties: {
+                    let mut properties = BTreeMap::new();
+                    properties.insert("status".to_string(), Value::I64(0));
+                    properties.insert("pooling".to_string(), Value::I64(0));
+                    properties.insert("coreFeePerByte".to_string(), Value::I64(1));
+                    properties.insert("amount".to_string(), Value::I64(1000 + i));
+                    properties.insert("outputScript".to_string(), Value::Bytes(vec![]));
+                    properties
+                },
+                revision: Some(1),
+                created_at: Some(1730028481000 + i as u64),
+                updated_at: Some(1730028481000 + i as u64),
+                transferred_at: None,
+                created_at_block_height: None,
+                updated_at_block_height: None,
+                transferred_at_block_height: None,
+                created_at_core_block_height: None,
+                updated_at_core_block_height: None,
+                transferred_at_core_block_height: None,
+                creator_id: None,
+            }
+            .into();
+            store_document(
+                &platform,
+                &withdrawals,
+                document_type,
+                &document,
+                platform_version,
+            );
+        }
+
+        let mut request = count_request(withdrawals.id().to_vec(), document_type_name);
+        request.function = AggregateFunction::Sum as i32;
+        request.sum_property = "amount".to_string();
+        request.limit = 10;
+        request.prove = true;
+
+        let aggregate_query = DriveDocumentAggregateQuery::new(
+            DriveDocumentQuery::all_items_query(&withdrawals, document_type, None),
+            DocumentAggregateFunction::Sum("amount".to_string()),
+        )
+        .expect("expected aggregate query");
+
+        let mut total_count = 0;
+        let mut total_sum = 0;
+        let mut start_after = None;
+        loop {
+            let mut request = request.clone();
+            request.start_after = start_after
+                .map(|id: [u8; 32]| id.to_vec())
+                .unwrap_or_default();
+
+            let result = platform
+                .query_documents_aggregate_v0(request, &state, version)
+                .expect("expected query to succeed");
+
+            let Some(GetDocumentsAggregateResponseV0 {
+                result: Some(get_documents_aggregate_response_v0::Result::Proof(proof)),
+                metadata: Some(_),
+            }) = result.data
+            else {
+                panic!("expected proof")
+            };
+
+            let (_, outcome) = aggregate_query
+                .verify_aggregate_proof(&proof.grovedb_proof, start_after, 10, platform_version)
+                .expect("expected to verify proof");
+
+            total_count += outcome.count;
+            total_sum += outcome.sum;
+
+            match outcome.next_start_after {
+                Some(next) => start_after = Some(next.to_buffer()),
+                None => break,
+            }
+        }
+
+        assert_eq!(total_count, 15);
+        assert_eq!(total_sum, (0..15).map(|i| 1000 + i).sum::<i64>());
+    }
+}
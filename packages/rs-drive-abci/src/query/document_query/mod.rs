@@ -8,8 +8,12 @@ use dapi_grpc::platform::v0::get_documents_response::Version as ResponseVersion;
 use dapi_grpc::platform::v0::{GetDocumentsRequest, GetDocumentsResponse};
 use dpp::version::PlatformVersion;
 
+mod aggregate;
+mod subscription;
 mod v0;
 
+pub use subscription::DocumentSubscription;
+
 impl<C> Platform<C> {
     /// Querying of documents
     pub fn query_documents(
@@ -0,0 +1,98 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::document_transitions_event::DocumentTransitionsEvent;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::subscribe_documents_request::Version as RequestVersion;
+use dapi_grpc::platform::v0::subscribe_documents_response::Version as ResponseVersion;
+use dapi_grpc::platform::v0::{SubscribeDocumentsRequest, SubscribeDocumentsResponse};
+use dpp::version::PlatformVersion;
+use drive::drive::contract::DataContractFetchInfo;
+use drive::query::filter::{DocumentActionMatchClauses, DriveDocumentQueryFilter};
+use std::sync::Arc;
+
+mod v0;
+
+/// A validated subscription to the document transitions of a document type
+#[derive(Debug, Clone)]
+pub struct DocumentSubscription {
+    /// The contract of the watched document type
+    pub contract: Arc<DataContractFetchInfo>,
+    /// The watched document type
+    pub document_type_name: String,
+    /// The clauses document transitions must match
+    pub action_clauses: DocumentActionMatchClauses,
+    /// Whether every matched state transition is sent with a proof of its execution
+    pub prove: bool,
+}
+
+impl DocumentSubscription {
+    /// The filter evaluating document transitions for this subscription
+    pub fn filter(&self) -> DriveDocumentQueryFilter<'_> {
+        DriveDocumentQueryFilter {
+            contract: &self.contract.contract,
+            document_type_name: self.document_type_name.clone(),
+            action_clauses: self.action_clauses.clone(),
+        }
+    }
+}
+
+impl<C> Platform<C> {
+    /// Validates a document subscription request
+    pub fn query_document_subscription(
+        &self,
+        SubscribeDocumentsRequest { version }: SubscribeDocumentsRequest,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<DocumentSubscription>, Error> {
+        let Some(version) = version else {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::DecodingError("could not decode document subscription".to_string()),
+            ));
+        };
+
+        let feature_version_bounds = &platform_version.drive_abci.query.document_subscription;
+
+        let feature_version = match &version {
+            RequestVersion::V0(_) => 0,
+        };
+        if !feature_version_bounds.check_version(feature_version) {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::UnsupportedQueryVersion(
+                    "document_subscription".to_string(),
+                    feature_version_bounds.min_version,
+                    feature_version_bounds.max_version,
+                    platform_version.protocol_version,
+                    feature_version,
+                ),
+            ));
+        }
+        match version {
+            RequestVersion::V0(request_v0) => {
+                self.query_document_subscription_v0(request_v0, platform_state, platform_version)
+            }
+        }
+    }
+
+    /// Builds the response of a subscription for the document transitions of a committed
+    /// block, `None` when no document transition of the block matches
+    pub fn document_subscription_response(
+        &self,
+        subscription: &DocumentSubscription,
+        event: &DocumentTransitionsEvent,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<SubscribeDocumentsResponse>, Error> {
+        let response_v0 = self.document_subscription_response_v0(
+            subscription,
+            event,
+            platform_state,
+            platform_version,
+        )?;
+
+        Ok(response_v0.map(|response_v0| SubscribeDocumentsResponse {
+            version: Some(ResponseVersion::V0(response_v0)),
+        }))
+    }
+}
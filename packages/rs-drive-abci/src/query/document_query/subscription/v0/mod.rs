@@ -0,0 +1,582 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::document_transitions_event::DocumentTransitionsEvent;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::document_query::subscription::DocumentSubscription;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::subscribe_documents_request::subscribe_documents_request_v0::DocumentAction;
+use dapi_grpc::platform::v0::subscribe_documents_request::SubscribeDocumentsRequestV0;
+use dapi_grpc::platform::v0::subscribe_documents_response::subscribe_documents_response_v0::MatchedStateTransition;
+use dapi_grpc::platform::v0::subscribe_documents_response::SubscribeDocumentsResponseV0;
+use dapi_grpc::platform::v0::Proof;
+use dpp::check_validation_result_with_data;
+use dpp::identifier::Identifier;
+use dpp::platform_value::Value;
+use dpp::state_transition::batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
+use dpp::state_transition::batch_transition::batched_transition::document_transition::DocumentTransitionV0Methods;
+use dpp::state_transition::batch_transition::batched_transition::BatchedTransitionRef;
+use dpp::state_transition::batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
+use dpp::state_transition::batch_transition::BatchTransition;
+use dpp::state_transition::{StateTransition, StateTransitionLike};
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+use drive::query::filter::{DocumentActionMatchClauses, TransitionCheckResult};
+use drive::query::{InternalClauses, ValueClause};
+
+/// Decodes CBOR where clauses, empty bytes are no clauses
+fn decode_where_clauses(cbor: &[u8], field_name: &str) -> Result<InternalClauses, QueryError> {
+    if cbor.is_empty() {
+        return Ok(InternalClauses::default());
+    }
+
+    let where_clause: Value = ciborium::de::from_reader(cbor).map_err(|_| {
+        QueryError::Query(QuerySyntaxError::DeserializationError(format!(
+            "unable to decode '{}' from cbor",
+            field_name
+        )))
+    })?;
+
+    InternalClauses::from_where_clauses_value(where_clause).map_err(|error| match error {
+        drive::error::Error::Query(query_error) => QueryError::Query(query_error),
+        error => QueryError::from(error),
+    })
+}
+
+/// CBOR loses identifier and integer types, restores the types of the transition values
+/// the clause is compared with, values that can't be converted are left for validation to reject
+fn value_clause_with_transition_value_type(
+    mut value_clause: ValueClause,
+    action: DocumentAction,
+) -> ValueClause {
+    let convert = |value: Value| match action {
+        DocumentAction::UpdatePrice => value.to_integer::<u64>().map(Value::U64).unwrap_or(value),
+        _ => value.to_identifier().map(Value::from).unwrap_or(value),
+    };
+
+    value_clause.value = match value_clause.value {
+        Value::Array(values) => Value::Array(values.into_iter().map(convert).collect()),
+        value => convert(value),
+    };
+
+    value_clause
+}
+
+impl<C> Platform<C> {
+    pub(super) fn query_document_subscription_v0(
+        &self,
+        SubscribeDocumentsRequestV0 {
+            data_contract_id,
+            document_type: document_type_name,
+            action,
+            new_document_where,
+            original_document_where,
+            value_clause,
+            prove,
+        }: SubscribeDocumentsRequestV0,
+        _platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<DocumentSubscription>, Error> {
+        let contract_id: Identifier = check_validation_result_with_data!(data_contract_id
+            .try_into()
+            .map_err(|_| QueryError::InvalidArgument(
+                "id must be a valid identifier (32 bytes long)".to_string()
+            )));
+
+        let action =
+            check_validation_result_with_data!(DocumentAction::try_from(action).map_err(|_| {
+                QueryError::InvalidArgument(format!("unknown document action {}", action))
+            }));
+
+        if !new_document_where.is_empty()
+            && !matches!(action, DocumentAction::Create | DocumentAction::Replace)
+        {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::InvalidArgument(
+                    "new_document_where is only used by create and replace subscriptions"
+                        .to_string(),
+                ),
+            ));
+        }
+
+        if !original_document_where.is_empty() && action == DocumentAction::Create {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::InvalidArgument(
+                    "original_document_where is not used by create subscriptions".to_string(),
+                ),
+            ));
+        }
+
+        if !value_clause.is_empty()
+            && !matches!(
+                action,
+                DocumentAction::Transfer | DocumentAction::UpdatePrice | DocumentAction::Purchase
+            )
+        {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::InvalidArgument(
+                    "value_clause is only used by transfer, update price and purchase subscriptions"
+                        .to_string(),
+                ),
+            ));
+        }
+
+        let (_, contract) = self.drive.get_contract_with_fetch_info_and_fee(
+            contract_id.to_buffer(),
+            None,
+            true,
+            None,
+            platform_version,
+        )?;
+
+        let contract = check_validation_result_with_data!(contract.ok_or(QueryError::Query(
+            QuerySyntaxError::DataContractNotFound(
+                "contract not found when subscribing to documents",
+            )
+        )));
+
+        let new_document_clauses = check_validation_result_with_data!(decode_where_clauses(
+            &new_document_where,
+            "new_document_where"
+        ));
+
+        let original_document_clauses = check_validation_result_with_data!(decode_where_clauses(
+            &original_document_where,
+            "original_document_where"
+        ));
+
+        let value_clause = if value_clause.is_empty() {
+            None
+        } else {
+            let components: Value = check_validation_result_with_data!(ciborium::de::from_reader(
+                value_clause.as_slice()
+            )
+            .map_err(|_| {
+                QueryError::Query(QuerySyntaxError::DeserializationError(
+                    "unable to decode 'value_clause' from cbor".to_string(),
+                ))
+            }));
+
+            let Value::Array(components) = components else {
+                return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                    QuerySyntaxError::InvalidWhereClauseComponents("value clause must be an array"),
+                )));
+            };
+
+            let value_clause =
+                check_validation_result_with_data!(ValueClause::from_components(&components));
+
+            Some(value_clause_with_transition_value_type(
+                value_clause,
+                action,
+            ))
+        };
+
+        let action_clauses = match action {
+            DocumentAction::Create => DocumentActionMatchClauses::Create {
+                new_document_clauses,
+            },
+            DocumentAction::Replace => DocumentActionMatchClauses::Replace {
+                original_document_clauses,
+                new_document_clauses,
+            },
+            DocumentAction::Delete => DocumentActionMatchClauses::Delete {
+                original_document_clauses,
+            },
+            DocumentAction::Transfer => DocumentActionMatchClauses::Transfer {
+                original_document_clauses,
+                owner_clause: value_clause,
+            },
+            DocumentAction::UpdatePrice => DocumentActionMatchClauses::UpdatePrice {
+                original_document_clauses,
+                price_clause: value_clause,
+            },
+            DocumentAction::Purchase => DocumentActionMatchClauses::Purchase {
+                original_document_clauses,
+                owner_clause: value_clause,
+            },
+        };
+
+        let subscription = DocumentSubscription {
+            contract,
+            document_type_name,
+            action_clauses,
+            prove,
+        };
+
+        let validation_result = subscription.filter().validate();
+
+        if !validation_result.is_valid() {
+            return Ok(QueryValidationResult::new_with_errors(
+                validation_result
+                    .errors
+                    .into_iter()
+                    .map(QueryError::Query)
+                    .collect(),
+            ));
+        }
+
+        Ok(QueryValidationResult::new_with_data(subscription))
+    }
+
+    pub(super) fn document_subscription_response_v0(
+        &self,
+        subscription: &DocumentSubscription,
+        event: &DocumentTransitionsEvent,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<SubscribeDocumentsResponseV0>, Error> {
+        let filter = subscription.filter();
+
+        let mut state_transitions = Vec::new();
+
+        for executed in &event.batch_transitions {
+            let batch_owner_value: Value = executed.batch_transition.owner_id().into();
+
+            let transition_indices: Vec<u32> = executed
+                .batch_transition
+                .transitions_iter()
+                .enumerate()
+                .filter_map(|(index, transition)| {
+                    let BatchedTransitionRef::Document(document_transition) = transition else {
+                        return None;
+                    };
+
+                    let matches = match filter
+                        .matches_document_transition(document_transition, Some(&batch_owner_value))
+                    {
+                        TransitionCheckResult::Pass => true,
+                        TransitionCheckResult::Fail => false,
+                        TransitionCheckResult::NeedsOriginal => event
+                            .original_documents
+                            .get(&document_transition.base().id())
+                            .is_some_and(|original| filter.matches_original_document(original)),
+                    };
+
+                    matches.then_some(index as u32)
+                })
+                .collect();
+
+            if transition_indices.is_empty() {
+                continue;
+            }
+
+            // A proof that can't be made doesn't hold back the matched state transition
+            let (proof, proof_error) = if subscription.prove {
+                match self.prove_executed_batch_transition(
+                    &executed.batch_transition,
+                    platform_state,
+                    platform_version,
+                ) {
+                    Ok(proof) => (Some(proof), String::new()),
+                    Err(error) => {
+                        tracing::warn!(
+                            block_height = event.block_height,
+                            "unable to prove a state transition matched by a document subscription: {}",
+                            error
+                        );
+                        (None, error.to_string())
+                    }
+                }
+            } else {
+                (None, String::new())
+            };
+
+            state_transitions.push(MatchedStateTransition {
+                state_transition: executed.raw_state_transition.clone(),
+                transition_indices,
+                proof,
+                proof_error,
+            });
+        }
+
+        if state_transitions.is_empty() {
+            return Ok(None);
+        }
+
+        // Proofs are made against the latest committed state, which is the state of a later
+        // block when the subscriber is behind
+        let proof_height = if subscription.prove {
+            platform_state.last_committed_block_height()
+        } else {
+            0
+        };
+
+        Ok(Some(SubscribeDocumentsResponseV0 {
+            block_height: event.block_height,
+            state_transitions,
+            metadata: Some(self.response_metadata_v0(platform_state)),
+            proof_height,
+            error: String::new(),
+        }))
+    }
+
+    /// Proves the execution of a batch state transition against the latest committed state
+    fn prove_executed_batch_transition(
+        &self,
+        batch_transition: &BatchTransition,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<Proof, Error> {
+        let mut result = self.drive.prove_state_transition(
+            &StateTransition::Batch(batch_transition.clone()),
+            None,
+            platform_version,
+        )?;
+
+        if let Some(error) = result.errors.pop() {
+            return Err(Error::Drive(drive::error::Error::Proof(error)));
+        }
+
+        Ok(self.response_proof_v0(platform_state, result.into_data()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::{assert_invalid_identifier, setup_platform, store_data_contract};
+    use ciborium::value::Value as CborValue;
+    use dpp::dashcore::Network;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::platform_value::platform_value;
+    use dpp::tests::fixtures::get_data_contract_fixture;
+    use drive::query::WhereOperator;
+    use std::collections::BTreeMap;
+
+    fn subscription_request(
+        data_contract_id: Vec<u8>,
+        document_type: &str,
+        action: DocumentAction,
+    ) -> SubscribeDocumentsRequestV0 {
+        SubscribeDocumentsRequestV0 {
+            data_contract_id,
+            document_type: document_type.to_string(),
+            action: action as i32,
+            new_document_where: vec![],
+            original_document_where: vec![],
+            value_clause: vec![],
+            prove: false,
+        }
+    }
+
+    fn serialize_value_to_cbor(value: Value) -> Vec<u8> {
+        let cbor_value: CborValue = value.try_into().expect("expected to convert to cbor");
+
+        let mut serialized = Vec::new();
+        ciborium::ser::into_writer(&cbor_value, &mut serialized)
+            .expect("expected to serialize cbor");
+
+        serialized
+    }
+
+    #[test]
+    fn test_invalid_data_contract_id() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let request = subscription_request(vec![0; 8], "niceDocument", DocumentAction::Create);
+
+        let result = platform
+            .query_document_subscription_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert_invalid_identifier(result);
+    }
+
+    #[test]
+    fn test_unknown_action() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let mut request = subscription_request(vec![0; 32], "niceDocument", DocumentAction::Create);
+        request.action = 42;
+
+        let result = platform
+            .query_document_subscription_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg == "unknown document action 42"
+        ));
+    }
+
+    #[test]
+    fn test_clause_not_used_by_action() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let mut request = subscription_request(vec![0; 32], "niceDocument", DocumentAction::Delete);
+        request.new_document_where =
+            serialize_value_to_cbor(platform_value!([["name", "==", "dash"]]));
+
+        let result = platform
+            .query_document_subscription_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)]
+                if msg == "new_document_where is only used by create and replace subscriptions"
+        ));
+    }
+
+    #[test]
+    fn test_data_contract_not_found() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let request = subscription_request(vec![0; 32], "niceDocument", DocumentAction::Create);
+
+        let result = platform
+            .query_document_subscription_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::Query(QuerySyntaxError::DataContractNotFound(_))]
+        ));
+    }
+
+    #[test]
+    fn test_create_subscription_with_where_clauses() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let mut request = subscription_request(
+            created_data_contract.data_contract().id().to_vec(),
+            "niceDocument",
+            DocumentAction::Create,
+        );
+        request.new_document_where =
+            serialize_value_to_cbor(platform_value!([["name", "==", "dash"]]));
+
+        let result = platform
+            .query_document_subscription_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        let subscription = result.into_data().expect("expected a valid subscription");
+
+        let DocumentActionMatchClauses::Create {
+            new_document_clauses,
+        } = &subscription.action_clauses
+        else {
+            panic!("expected create clauses");
+        };
+
+        let name_clause = new_document_clauses
+            .equal_clauses
+            .get("name")
+            .expect("expected name clause");
+
+        assert_eq!(name_clause.operator, WhereOperator::Equal);
+        assert_eq!(name_clause.value, Value::Text("dash".to_string()));
+    }
+
+    #[test]
+    fn test_transfer_subscription_with_value_clause() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let recipient_id = Identifier::new([3; 32]);
+
+        let mut request = subscription_request(
+            created_data_contract.data_contract().id().to_vec(),
+            "niceDocument",
+            DocumentAction::Transfer,
+        );
+        request.value_clause = serialize_value_to_cbor(
+            ValueClause {
+                operator: WhereOperator::Equal,
+                value: recipient_id.into(),
+            }
+            .into(),
+        );
+
+        let result = platform
+            .query_document_subscription_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        let subscription = result.into_data().expect("expected a valid subscription");
+
+        let DocumentActionMatchClauses::Transfer {
+            owner_clause: Some(owner_clause),
+            ..
+        } = &subscription.action_clauses
+        else {
+            panic!("expected transfer clauses with an owner clause");
+        };
+
+        assert_eq!(
+            owner_clause.value,
+            Value::Identifier(recipient_id.to_buffer())
+        );
+    }
+
+    #[test]
+    fn test_update_price_subscription_with_value_clause() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let mut request = subscription_request(
+            created_data_contract.data_contract().id().to_vec(),
+            "niceDocument",
+            DocumentAction::UpdatePrice,
+        );
+        request.value_clause = serialize_value_to_cbor(platform_value!(["<=", 1000u64]));
+
+        let result = platform
+            .query_document_subscription_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        let subscription = result.into_data().expect("expected a valid subscription");
+
+        let DocumentActionMatchClauses::UpdatePrice {
+            price_clause: Some(price_clause),
+            ..
+        } = &subscription.action_clauses
+        else {
+            panic!("expected update price clauses with a price clause");
+        };
+
+        assert!(price_clause.matches_value(&Value::U64(500)));
+        assert!(!price_clause.matches_value(&Value::U64(1500)));
+    }
+
+    #[test]
+    fn test_no_response_for_block_without_matches() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let created_data_contract = get_data_contract_fixture(None, 0, version.protocol_version);
+        store_data_contract(&platform, created_data_contract.data_contract(), version);
+
+        let request = subscription_request(
+            created_data_contract.data_contract().id().to_vec(),
+            "niceDocument",
+            DocumentAction::Create,
+        );
+
+        let subscription = platform
+            .query_document_subscription_v0(request, &state, version)
+            .expect("expected query to succeed")
+            .into_data()
+            .expect("expected a valid subscription");
+
+        let event = DocumentTransitionsEvent {
+            block_height: 10,
+            batch_transitions: vec![],
+            original_documents: BTreeMap::new(),
+        };
+
+        let response = platform
+            .document_subscription_response_v0(&subscription, &event, &state, version)
+            .expect("expected to build response");
+
+        assert!(response.is_none());
+    }
+}
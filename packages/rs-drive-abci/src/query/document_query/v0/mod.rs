@@ -1,6 +1,7 @@
 use crate::error::query::QueryError;
 use crate::error::Error;
 use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
 use crate::query::QueryValidationResult;
 use dapi_grpc::platform::v0::get_documents_request::get_documents_request_v0::Start;
@@ -150,12 +151,26 @@ impl<C> Platform<C> {
                 metadata: Some(self.response_metadata_v0(platform_state)),
             }
         } else {
-            let results = match drive_query.execute_raw_results_no_proof(
-                &self.drive,
-                None,
-                None,
-                platform_version,
-            ) {
+            // Documents whose time to live was reached by the last committed block are hidden
+            // until a block end removes them
+            let results = match platform_state.last_committed_block_time_ms() {
+                Some(block_time_ms) => drive_query.execute_raw_results_no_proof_excluding_expired(
+                    &self.drive,
+                    block_time_ms,
+                    Some(platform_state.last_committed_block_height()),
+                    None,
+                    None,
+                    platform_version,
+                ),
+                None => drive_query.execute_raw_results_no_proof(
+                    &self.drive,
+                    None,
+                    None,
+                    platform_version,
+                ),
+            };
+
+            let results = match results {
                 Ok(result) => result.0,
                 Err(drive::error::Error::Query(query_error)) => {
                     return Ok(QueryValidationResult::new_with_error(QueryError::Query(
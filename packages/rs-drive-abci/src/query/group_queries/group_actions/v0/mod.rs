@@ -4,7 +4,7 @@ use crate::platform_types::platform::Platform;
 use crate::platform_types::platform_state::PlatformState;
 use crate::query::QueryValidationResult;
 use dapi_grpc::platform::v0::get_group_actions_request::GetGroupActionsRequestV0;
-use dapi_grpc::platform::v0::get_group_actions_response::get_group_actions_response_v0::{emergency_action_event, group_action_event, token_event, BurnEvent, DestroyFrozenFundsEvent, EmergencyActionEvent, FreezeEvent, GroupActionEntry, GroupActionEvent, GroupActions, MintEvent, TokenConfigUpdateEvent, TokenEvent as TokenEventResponse, UnfreezeEvent, UpdateDirectPurchasePriceEvent};
+use dapi_grpc::platform::v0::get_group_actions_response::get_group_actions_response_v0::{emergency_action_event, group_action_event, token_event, BurnEvent, DestroyFrozenFundsEvent, EmergencyActionEvent, FreezeEvent, GroupActionEntry, GroupActionEvent, GroupActions, MintEvent, TokenConfigUpdateEvent, TokenEvent as TokenEventResponse, TransferFromEvent, UnfreezeEvent, UpdateDirectPurchasePriceEvent};
 use dapi_grpc::platform::v0::get_group_actions_response::{
     get_group_actions_response_v0, GetGroupActionsResponseV0,
 };
@@ -221,7 +221,17 @@ impl<C> Platform<C> {
                                                 })),
                                             })
                                         }
-                                        TokenEvent::Transfer(..) | TokenEvent::DirectPurchase(..) | TokenEvent::Claim(..) => {
+                                        TokenEvent::TransferFrom(from_id, recipient_id, amount, public_note) => {
+                                            group_action_event::EventType::TokenEvent(TokenEventResponse {
+                                                r#type: Some(token_event::Type::TransferFrom(TransferFromEvent {
+                                                    from_id: from_id.to_vec(),
+                                                    recipient_id: recipient_id.to_vec(),
+                                                    amount,
+                                                    public_note,
+                                                })),
+                                            })
+                                        }
+                                        TokenEvent::Transfer(..) | TokenEvent::DirectPurchase(..) | TokenEvent::Claim(..) | TokenEvent::PlaceOrder(..) | TokenEvent::CancelOrder(..) | TokenEvent::VestingTransfer(..) | TokenEvent::ClaimVested(..) | TokenEvent::Approve(..) => {
                                             return None;
                                         },
                                     },
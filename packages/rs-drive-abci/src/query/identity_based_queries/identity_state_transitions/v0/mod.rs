@@ -0,0 +1,278 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_identity_state_transitions_request::get_identity_state_transitions_request_v0::StartAfter;
+use dapi_grpc::platform::v0::get_identity_state_transitions_request::GetIdentityStateTransitionsRequestV0;
+use dapi_grpc::platform::v0::get_identity_state_transitions_response::get_identity_state_transitions_response_v0::{
+    IdentityStateTransitionEntry, IdentityStateTransitions,
+};
+use dapi_grpc::platform::v0::get_identity_state_transitions_response::{
+    get_identity_state_transitions_response_v0, GetIdentityStateTransitionsResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::identity::state_transition_record::v0::IdentityStateTransitionRecordV0Getters;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+
+impl<C> Platform<C> {
+    pub(super) fn query_identity_state_transitions_v0(
+        &self,
+        GetIdentityStateTransitionsRequestV0 {
+            identity_id,
+            start_after,
+            count,
+            order_ascending,
+            prove,
+        }: GetIdentityStateTransitionsRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetIdentityStateTransitionsResponseV0>, Error> {
+        if platform_version
+            .drive_abci
+            .methods
+            .state_transition_processing
+            .store_identity_state_transition_history
+            .is_none()
+        {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::NotServiceable(
+                    "the state transition history of identities is stored from protocol version 11"
+                        .to_string(),
+                ),
+            ));
+        }
+
+        let config = &self.config.drive;
+        let identity_id: [u8; 32] =
+            check_validation_result_with_data!(identity_id.try_into().map_err(|_| {
+                QueryError::InvalidArgument(
+                    "identity_id must be a valid identifier (32 bytes long)".to_string(),
+                )
+            }));
+
+        let start_after = start_after.map(
+            |StartAfter {
+                 block_height,
+                 index_in_block,
+             }| (block_height, index_in_block),
+        );
+
+        let limit = count
+            .map_or(Some(config.default_query_limit), |limit_value| {
+                if limit_value == 0
+                    || limit_value > u16::MAX as u32
+                    || limit_value as u16 > config.default_query_limit
+                {
+                    None
+                } else {
+                    Some(limit_value as u16)
+                }
+            })
+            .ok_or(drive::error::Error::Query(QuerySyntaxError::InvalidLimit(
+                format!("limit greater than max limit {}", config.max_query_limit),
+            )))?;
+
+        let response = if prove {
+            let proof = check_validation_result_with_data!(self
+                .drive
+                .prove_identity_state_transition_records(
+                    identity_id,
+                    start_after,
+                    Some(limit),
+                    order_ascending,
+                    None,
+                    platform_version,
+                ));
+
+            GetIdentityStateTransitionsResponseV0 {
+                result: Some(get_identity_state_transitions_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let state_transitions = self
+                .drive
+                .fetch_identity_state_transition_records(
+                    identity_id,
+                    start_after,
+                    Some(limit),
+                    order_ascending,
+                    None,
+                    platform_version,
+                )?
+                .into_iter()
+                .map(|record| IdentityStateTransitionEntry {
+                    state_transition_hash: record.state_transition_hash().to_vec(),
+                    state_transition_type: record.state_transition_type() as u32,
+                    block_height: record.block_height(),
+                    block_time_ms: record.block_time_ms(),
+                    index_in_block: record.index_in_block(),
+                    fee_paid: record.fee_paid(),
+                    error_code: record.error_code(),
+                })
+                .collect();
+
+            GetIdentityStateTransitionsResponseV0 {
+                result: Some(
+                    get_identity_state_transitions_response_v0::Result::StateTransitions(
+                        IdentityStateTransitions { state_transitions },
+                    ),
+                ),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::setup_platform;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::dashcore::Network;
+    use dpp::identifier::Identifier;
+    use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+    use dpp::state_transition::StateTransitionType;
+    use drive::drive::Drive;
+
+    #[test]
+    fn test_query_not_serviceable_before_protocol_version_11() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, Some(10));
+
+        let request = GetIdentityStateTransitionsRequestV0 {
+            identity_id: vec![1; 32],
+            start_after: None,
+            count: None,
+            order_ascending: true,
+            prove: false,
+        };
+
+        let result = platform
+            .query_identity_state_transitions_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::NotServiceable(_)]
+        ));
+    }
+
+    #[test]
+    fn test_identity_state_transitions() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let identity_id = Identifier::new([1; 32]);
+
+        let records: Vec<_> = [(1, 0, None), (1, 3, Some(10100)), (2, 1, None)]
+            .into_iter()
+            .map(|(block_height, index_in_block, error_code)| {
+                let record = IdentityStateTransitionRecord::new(
+                    [index_in_block as u8; 32],
+                    StateTransitionType::IdentityUpdate,
+                    block_height,
+                    block_height * 1000,
+                    index_in_block,
+                    5_000,
+                    error_code,
+                    version,
+                )
+                .expect("expected a record");
+                (identity_id, record)
+            })
+            .collect();
+
+        for (identity_id, record) in &records {
+            platform
+                .drive
+                .add_identity_state_transition_record(
+                    *identity_id,
+                    record,
+                    &BlockInfo::default(),
+                    true,
+                    None,
+                    None,
+                    version,
+                )
+                .expect("expected to add record");
+        }
+
+        let request = GetIdentityStateTransitionsRequestV0 {
+            identity_id: identity_id.to_vec(),
+            start_after: Some(StartAfter {
+                block_height: 2,
+                index_in_block: 1,
+            }),
+            count: Some(1),
+            order_ascending: false,
+            prove: false,
+        };
+
+        let result = platform
+            .query_identity_state_transitions_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        let Some(get_identity_state_transitions_response_v0::Result::StateTransitions(
+            IdentityStateTransitions { state_transitions },
+        )) = result.into_data().expect("expected a response").result
+        else {
+            panic!("expected state transitions");
+        };
+
+        assert_eq!(
+            state_transitions,
+            vec![IdentityStateTransitionEntry {
+                state_transition_hash: vec![3; 32],
+                state_transition_type: StateTransitionType::IdentityUpdate as u32,
+                block_height: 1,
+                block_time_ms: 1000,
+                index_in_block: 3,
+                fee_paid: 5_000,
+                error_code: Some(10100),
+            }]
+        );
+
+        let request = GetIdentityStateTransitionsRequestV0 {
+            identity_id: identity_id.to_vec(),
+            start_after: None,
+            count: None,
+            order_ascending: true,
+            prove: true,
+        };
+
+        let result = platform
+            .query_identity_state_transitions_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        let Some(get_identity_state_transitions_response_v0::Result::Proof(proof)) =
+            result.into_data().expect("expected a response").result
+        else {
+            panic!("expected a proof");
+        };
+
+        let (_, proved): (_, Vec<IdentityStateTransitionRecord>) =
+            Drive::verify_identity_state_transition_records(
+                &proof.grovedb_proof,
+                identity_id.to_buffer(),
+                None,
+                Some(platform.config.drive.default_query_limit),
+                true,
+                false,
+                version,
+            )
+            .expect("expected to verify proof");
+
+        assert_eq!(
+            proved,
+            records
+                .into_iter()
+                .map(|(_, record)| record)
+                .collect::<Vec<_>>()
+        );
+    }
+}
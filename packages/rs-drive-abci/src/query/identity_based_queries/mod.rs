@@ -7,4 +7,5 @@ mod identity_by_non_unique_public_key_hash;
 mod identity_by_unique_public_key_hash;
 mod identity_contract_nonce;
 mod identity_nonce;
+mod identity_state_transitions;
 mod keys;
@@ -1,9 +1,11 @@
 use crate::error::query::QueryError;
 use crate::error::Error;
 use crate::metrics::{abci_response_code_metric_label, query_duration_metric};
+use crate::platform_types::document_transitions_event::DocumentTransitionsEvent;
 use crate::platform_types::platform::Platform;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
+use crate::query::document_query::DocumentSubscription;
 use crate::query::QueryValidationResult;
 use crate::rpc::core::DefaultCoreRPC;
 use crate::utils::spawn_blocking_task_with_name_if_supported;
@@ -11,8 +13,10 @@ use async_trait::async_trait;
 use dapi_grpc::drive::v0::drive_internal_server::DriveInternal;
 use dapi_grpc::drive::v0::{GetProofsRequest, GetProofsResponse};
 use dapi_grpc::platform::v0::platform_server::Platform as PlatformService;
+use dapi_grpc::platform::v0::subscribe_documents_response::{self, SubscribeDocumentsResponseV0};
 use dapi_grpc::platform::v0::{
-    BroadcastStateTransitionRequest, BroadcastStateTransitionResponse, GetConsensusParamsRequest,
+    BroadcastStateTransitionRequest, BroadcastStateTransitionResponse,
+    GetBlockStateTransitionsRequest, GetBlockStateTransitionsResponse, GetConsensusParamsRequest,
     GetConsensusParamsResponse, GetContestedResourceIdentityVotesRequest,
     GetContestedResourceIdentityVotesResponse, GetContestedResourceVoteStateRequest,
     GetContestedResourceVoteStateResponse, GetContestedResourceVotersForIdentityRequest,
@@ -20,7 +24,8 @@ use dapi_grpc::platform::v0::{
     GetContestedResourcesResponse, GetCurrentQuorumsInfoRequest, GetCurrentQuorumsInfoResponse,
     GetDataContractHistoryRequest, GetDataContractHistoryResponse, GetDataContractRequest,
     GetDataContractResponse, GetDataContractsRequest, GetDataContractsResponse,
-    GetDocumentsRequest, GetDocumentsResponse, GetEpochsInfoRequest, GetEpochsInfoResponse,
+    GetDocumentsAggregateRequest, GetDocumentsAggregateResponse, GetDocumentsRequest,
+    GetDocumentsResponse, GetEpochsInfoRequest, GetEpochsInfoResponse,
     GetEvonodesProposedEpochBlocksByIdsRequest, GetEvonodesProposedEpochBlocksByRangeRequest,
     GetEvonodesProposedEpochBlocksResponse, GetFinalizedEpochInfosRequest,
     GetFinalizedEpochInfosResponse, GetGroupActionSignersRequest, GetGroupActionSignersResponse,
@@ -35,19 +40,27 @@ use dapi_grpc::platform::v0::{
     GetIdentityByPublicKeyHashRequest, GetIdentityByPublicKeyHashResponse,
     GetIdentityContractNonceRequest, GetIdentityContractNonceResponse, GetIdentityKeysRequest,
     GetIdentityKeysResponse, GetIdentityNonceRequest, GetIdentityNonceResponse, GetIdentityRequest,
-    GetIdentityResponse, GetIdentityTokenBalancesRequest, GetIdentityTokenBalancesResponse,
+    GetIdentityResponse, GetIdentityStateTransitionsRequest, GetIdentityStateTransitionsResponse,
+    GetIdentityTokenBalancesRequest, GetIdentityTokenBalancesResponse,
     GetIdentityTokenInfosRequest, GetIdentityTokenInfosResponse, GetPathElementsRequest,
     GetPathElementsResponse, GetPrefundedSpecializedBalanceRequest,
     GetPrefundedSpecializedBalanceResponse, GetProtocolVersionUpgradeStateRequest,
     GetProtocolVersionUpgradeStateResponse, GetProtocolVersionUpgradeVoteStatusRequest,
     GetProtocolVersionUpgradeVoteStatusResponse, GetStatusRequest, GetStatusResponse,
-    GetTokenContractInfoRequest, GetTokenContractInfoResponse, GetTokenDirectPurchasePricesRequest,
-    GetTokenDirectPurchasePricesResponse, GetTokenPerpetualDistributionLastClaimRequest,
+    GetTokenAllowancesRequest, GetTokenAllowancesResponse, GetTokenContractInfoRequest,
+    GetTokenContractInfoResponse, GetTokenDirectPurchasePricesRequest,
+    GetTokenDirectPurchasePricesResponse, GetTokenEventsRequest, GetTokenEventsResponse,
+    GetTokenHoldersRequest, GetTokenHoldersResponse, GetTokenOrderBookRequest,
+    GetTokenOrderBookResponse, GetTokenPerpetualDistributionLastClaimRequest,
     GetTokenPerpetualDistributionLastClaimResponse, GetTokenPreProgrammedDistributionsRequest,
     GetTokenPreProgrammedDistributionsResponse, GetTokenStatusesRequest, GetTokenStatusesResponse,
-    GetTokenTotalSupplyRequest, GetTokenTotalSupplyResponse, GetTotalCreditsInPlatformRequest,
-    GetTotalCreditsInPlatformResponse, GetVotePollsByEndDateRequest, GetVotePollsByEndDateResponse,
-    WaitForStateTransitionResultRequest, WaitForStateTransitionResultResponse,
+    GetTokenTotalSupplyRequest, GetTokenTotalSupplyResponse, GetTokenVestingsRequest,
+    GetTokenVestingsResponse, GetTotalCreditsInPlatformRequest, GetTotalCreditsInPlatformResponse,
+    GetVotePollsByEndDateRequest, GetVotePollsByEndDateResponse,
+    GetYesNoAbstainVotePollStateRequest, GetYesNoAbstainVotePollStateResponse,
+    SimulateStateTransitionRequest, SimulateStateTransitionResponse, SubscribeDocumentsRequest,
+    SubscribeDocumentsResponse, WaitForStateTransitionResultRequest,
+    WaitForStateTransitionResultResponse,
 };
 use dapi_grpc::tonic::{Code, Request, Response, Status};
 use dpp::version::PlatformVersion;
@@ -56,8 +69,13 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::thread::sleep;
 use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
 use tracing::Instrument;
 
+/// How many responses are buffered for a document subscriber that is late to read them
+const DOCUMENT_SUBSCRIPTION_RESPONSES_CAPACITY: usize = 16;
+
 /// Service to handle platform queries
 pub struct QueryService {
     platform: Arc<Platform<DefaultCoreRPC>>,
@@ -254,6 +272,8 @@ fn respond_with_unimplemented<RS>(name: &str) -> Result<Response<RS>, Status> {
 
 #[async_trait]
 impl PlatformService for QueryService {
+    type subscribeDocumentsStream = ReceiverStream<Result<SubscribeDocumentsResponse, Status>>;
+
     async fn broadcast_state_transition(
         &self,
         _request: Request<BroadcastStateTransitionRequest>,
@@ -321,6 +341,18 @@ impl PlatformService for QueryService {
         .await
     }
 
+    async fn get_identity_state_transitions(
+        &self,
+        request: Request<GetIdentityStateTransitionsRequest>,
+    ) -> Result<Response<GetIdentityStateTransitionsResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_identity_state_transitions,
+            "get_identity_state_transitions",
+        )
+        .await
+    }
+
     async fn get_identity_balance(
         &self,
         request: Request<GetIdentityBalanceRequest>,
@@ -393,6 +425,46 @@ impl PlatformService for QueryService {
         .await
     }
 
+    async fn get_documents_aggregate(
+        &self,
+        request: Request<GetDocumentsAggregateRequest>,
+    ) -> Result<Response<GetDocumentsAggregateResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_documents_aggregate,
+            "get_documents_aggregate",
+        )
+        .await
+    }
+
+    async fn subscribe_documents(
+        &self,
+        request: Request<SubscribeDocumentsRequest>,
+    ) -> Result<Response<Self::subscribeDocumentsStream>, Status> {
+        // Subscribe before validating so blocks committed in the meantime are not missed
+        let events = self.platform.document_transitions_events.subscribe();
+
+        let subscription = self
+            .handle_blocking_query(
+                request,
+                Platform::<DefaultCoreRPC>::query_document_subscription,
+                "subscribe_documents",
+            )
+            .await?
+            .into_inner();
+
+        let (sender, receiver) = mpsc::channel(DOCUMENT_SUBSCRIPTION_RESPONSES_CAPACITY);
+
+        tokio::spawn(stream_document_subscription(
+            Arc::clone(&self.platform),
+            Arc::new(subscription),
+            events,
+            sender,
+        ));
+
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
     async fn get_identity_by_public_key_hash(
         &self,
         request: Request<GetIdentityByPublicKeyHashRequest>,
@@ -424,6 +496,18 @@ impl PlatformService for QueryService {
         respond_with_unimplemented("wait_for_state_transition_result")
     }
 
+    async fn simulate_state_transition(
+        &self,
+        request: Request<SimulateStateTransitionRequest>,
+    ) -> Result<Response<SimulateStateTransitionResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_simulate_state_transition,
+            "simulate_state_transition",
+        )
+        .await
+    }
+
     async fn get_consensus_params(
         &self,
         _request: Request<GetConsensusParamsRequest>,
@@ -539,6 +623,18 @@ impl PlatformService for QueryService {
         .await
     }
 
+    async fn get_yes_no_abstain_vote_poll_state(
+        &self,
+        request: Request<GetYesNoAbstainVotePollStateRequest>,
+    ) -> Result<Response<GetYesNoAbstainVotePollStateResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_yes_no_abstain_vote_poll_state,
+            "get_yes_no_abstain_vote_poll_state",
+        )
+        .await
+    }
+
     async fn get_prefunded_specialized_balance(
         &self,
         request: Request<GetPrefundedSpecializedBalanceRequest>,
@@ -707,6 +803,66 @@ impl PlatformService for QueryService {
         .await
     }
 
+    async fn get_token_holders(
+        &self,
+        request: Request<GetTokenHoldersRequest>,
+    ) -> Result<Response<GetTokenHoldersResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_token_holders,
+            "get_token_holders",
+        )
+        .await
+    }
+
+    async fn get_token_order_book(
+        &self,
+        request: Request<GetTokenOrderBookRequest>,
+    ) -> Result<Response<GetTokenOrderBookResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_token_order_book,
+            "get_token_order_book",
+        )
+        .await
+    }
+
+    async fn get_token_vestings(
+        &self,
+        request: Request<GetTokenVestingsRequest>,
+    ) -> Result<Response<GetTokenVestingsResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_token_vestings,
+            "get_token_vestings",
+        )
+        .await
+    }
+
+    async fn get_token_allowances(
+        &self,
+        request: Request<GetTokenAllowancesRequest>,
+    ) -> Result<Response<GetTokenAllowancesResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_token_allowances,
+            "get_token_allowances",
+        )
+        .await
+    }
+
+    async fn get_token_events(
+        &self,
+        request: Request<GetTokenEventsRequest>,
+    ) -> Result<Response<GetTokenEventsResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_token_events,
+            "get_token_events",
+        )
+        .await
+    }
+
     async fn get_group_info(
         &self,
         request: Request<GetGroupInfoRequest>,
@@ -802,6 +958,18 @@ impl PlatformService for QueryService {
         )
         .await
     }
+
+    async fn get_block_state_transitions(
+        &self,
+        request: Request<GetBlockStateTransitionsRequest>,
+    ) -> Result<Response<GetBlockStateTransitionsResponse>, Status> {
+        self.handle_blocking_query(
+            request,
+            Platform::<DefaultCoreRPC>::query_block_state_transitions,
+            "get_block_state_transitions",
+        )
+        .await
+    }
 }
 
 #[async_trait]
@@ -819,6 +987,94 @@ impl DriveInternal for QueryService {
     }
 }
 
+/// Sends the matching document transitions of every committed block to a subscriber until
+/// it disconnects or falls too far behind, a block that can't be matched is sent with its error
+async fn stream_document_subscription(
+    platform: Arc<Platform<DefaultCoreRPC>>,
+    subscription: Arc<DocumentSubscription>,
+    mut events: broadcast::Receiver<Arc<DocumentTransitionsEvent>>,
+    sender: mpsc::Sender<Result<SubscribeDocumentsResponse, Status>>,
+) {
+    loop {
+        let event = tokio::select! {
+            _ = sender.closed() => break,
+            event = events.recv() => event,
+        };
+
+        let event = match event {
+            Ok(event) => event,
+            Err(broadcast::error::RecvError::Lagged(skipped_blocks)) => {
+                let _ = sender
+                    .send(Err(Status::resource_exhausted(format!(
+                        "document subscriber is too slow, {} blocks were skipped",
+                        skipped_blocks
+                    ))))
+                    .await;
+                break;
+            }
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+
+        let block_height = event.block_height;
+        let platform = Arc::clone(&platform);
+        let subscription = Arc::clone(&subscription);
+
+        let response =
+            match spawn_blocking_task_with_name_if_supported("document_subscription", move || {
+                let platform_state = platform.state.load();
+
+                let platform_version = platform_state
+                    .current_platform_version()
+                    .map_err(|_| Status::unavailable("platform is not initialized"))?;
+
+                platform
+                    .document_subscription_response(
+                        &subscription,
+                        &event,
+                        &platform_state,
+                        platform_version,
+                    )
+                    .map_err(error_into_status)
+            }) {
+                Ok(handle) => handle.await.unwrap_or_else(|error| {
+                    Err(Status::internal(format!(
+                        "document subscription thread failed: {}",
+                        error
+                    )))
+                }),
+                Err(error) => Err(error.into()),
+            };
+
+        match response {
+            Ok(None) => continue,
+            Ok(Some(response)) => {
+                if sender.send(Ok(response)).await.is_err() {
+                    break;
+                }
+            }
+            Err(status) => {
+                // The subscriber is told about the block it misses and keeps receiving the
+                // following ones
+                tracing::error!(block_height, "document subscription failed: {}", status);
+                let response = SubscribeDocumentsResponse {
+                    version: Some(subscribe_documents_response::Version::V0(
+                        SubscribeDocumentsResponseV0 {
+                            block_height,
+                            state_transitions: vec![],
+                            metadata: None,
+                            proof_height: 0,
+                            error: status.message().to_string(),
+                        },
+                    )),
+                };
+                if sender.send(Ok(response)).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
 fn query_error_into_status(error: QueryError) -> Status {
     match error {
         QueryError::NotFound(message) => Status::not_found(message),
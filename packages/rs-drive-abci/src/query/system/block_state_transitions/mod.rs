@@ -0,0 +1,67 @@
+mod v0;
+
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use crate::rpc::core::CoreRPCLike;
+use dapi_grpc::platform::v0::get_block_state_transitions_request::Version as RequestVersion;
+use dapi_grpc::platform::v0::get_block_state_transitions_response::Version as ResponseVersion;
+use dapi_grpc::platform::v0::{GetBlockStateTransitionsRequest, GetBlockStateTransitionsResponse};
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Querying of the state transitions executed in a recent block with their results
+    pub fn query_block_state_transitions(
+        &self,
+        GetBlockStateTransitionsRequest { version }: GetBlockStateTransitionsRequest,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetBlockStateTransitionsResponse>, Error> {
+        let Some(version) = version else {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::DecodingError(
+                    "could not decode block state transitions query".to_string(),
+                ),
+            ));
+        };
+
+        let feature_version_bounds = &platform_version
+            .drive_abci
+            .query
+            .system
+            .block_state_transitions;
+
+        let feature_version = match &version {
+            RequestVersion::V0(_) => 0,
+        };
+        if !feature_version_bounds.check_version(feature_version) {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::UnsupportedQueryVersion(
+                    "block_state_transitions".to_string(),
+                    feature_version_bounds.min_version,
+                    feature_version_bounds.max_version,
+                    platform_version.protocol_version,
+                    feature_version,
+                ),
+            ));
+        }
+        match version {
+            RequestVersion::V0(request_v0) => {
+                let result = self.query_block_state_transitions_v0(
+                    request_v0,
+                    platform_state,
+                    platform_version,
+                )?;
+
+                Ok(result.map(|response_v0| GetBlockStateTransitionsResponse {
+                    version: Some(ResponseVersion::V0(response_v0)),
+                }))
+            }
+        }
+    }
+}
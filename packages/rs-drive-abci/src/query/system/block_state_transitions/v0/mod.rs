@@ -0,0 +1,287 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::block_state_transitions::{
+    BlockStateTransitions, ExecutedStateTransition, ExecutedStateTransitionError,
+    ExecutedStateTransitionFees, ExecutedStateTransitionStatus,
+};
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_block_state_transitions_request::GetBlockStateTransitionsRequestV0;
+use dapi_grpc::platform::v0::get_block_state_transitions_response::get_block_state_transitions_response_v0::{
+    ExecutedStateTransition as ExecutedStateTransitionProto, ExecutionStatus, FeeRefund,
+    FeeResult,
+};
+use dapi_grpc::platform::v0::get_block_state_transitions_response::GetBlockStateTransitionsResponseV0;
+use dapi_grpc::platform::v0::StateTransitionBroadcastError;
+use dpp::version::PlatformVersion;
+
+impl From<ExecutedStateTransitionStatus> for ExecutionStatus {
+    fn from(status: ExecutedStateTransitionStatus) -> Self {
+        match status {
+            ExecutedStateTransitionStatus::Success => ExecutionStatus::Success,
+            ExecutedStateTransitionStatus::PaidConsensusError => {
+                ExecutionStatus::PaidConsensusError
+            }
+            ExecutedStateTransitionStatus::UnpaidConsensusError => {
+                ExecutionStatus::UnpaidConsensusError
+            }
+            ExecutedStateTransitionStatus::InternalError => ExecutionStatus::InternalError,
+            ExecutedStateTransitionStatus::NotExecuted => ExecutionStatus::NotExecuted,
+        }
+    }
+}
+
+impl From<ExecutedStateTransitionFees> for FeeResult {
+    fn from(fees: ExecutedStateTransitionFees) -> Self {
+        FeeResult {
+            storage_fee: fees.storage_fee,
+            processing_fee: fees.processing_fee,
+            fee_refunds: fees
+                .fee_refunds
+                .into_iter()
+                .map(|(identity_id, credits)| FeeRefund {
+                    identity_id: identity_id.to_vec(),
+                    credits,
+                })
+                .collect(),
+            removed_bytes_from_system: fees.removed_bytes_from_system,
+        }
+    }
+}
+
+impl From<ExecutedStateTransitionError> for StateTransitionBroadcastError {
+    fn from(error: ExecutedStateTransitionError) -> Self {
+        StateTransitionBroadcastError {
+            code: error.code,
+            message: error.message,
+            data: error.data,
+        }
+    }
+}
+
+impl From<ExecutedStateTransition> for ExecutedStateTransitionProto {
+    fn from(executed_state_transition: ExecutedStateTransition) -> Self {
+        ExecutedStateTransitionProto {
+            state_transition: executed_state_transition.raw_state_transition,
+            status: ExecutionStatus::from(executed_state_transition.status) as i32,
+            fee_result: executed_state_transition.fees.map(FeeResult::from),
+            error: executed_state_transition
+                .error
+                .map(StateTransitionBroadcastError::from),
+        }
+    }
+}
+
+impl<C> Platform<C> {
+    pub(super) fn query_block_state_transitions_v0(
+        &self,
+        GetBlockStateTransitionsRequestV0 { height }: GetBlockStateTransitionsRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetBlockStateTransitionsResponseV0>, Error> {
+        if self.config.execution.block_state_transitions_retention == 0 {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::NotServiceable(
+                    "this node does not retain the state transitions of blocks".to_string(),
+                ),
+            ));
+        }
+
+        let Some(BlockStateTransitions {
+            block_height,
+            block_time_ms,
+            state_transitions,
+        }) = self.fetch_block_state_transitions(height, None, platform_version)?
+        else {
+            return Ok(QueryValidationResult::new_with_error(QueryError::NotFound(
+                format!(
+                    "the state transitions of block {} are not retained by this node",
+                    height
+                ),
+            )));
+        };
+
+        let response = GetBlockStateTransitionsResponseV0 {
+            block_height,
+            block_time_ms,
+            state_transitions: state_transitions
+                .into_iter()
+                .map(ExecutedStateTransitionProto::from)
+                .collect(),
+            metadata: Some(self.response_metadata_v0(platform_state)),
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PlatformConfig;
+    use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+    use crate::query::tests::setup_platform;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::dashcore::Network;
+
+    #[test]
+    fn test_query_not_serviceable_without_retention() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let request = GetBlockStateTransitionsRequestV0 { height: 1 };
+
+        let result = platform
+            .query_block_state_transitions_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::NotServiceable(_)]
+        ));
+    }
+
+    #[test]
+    fn test_block_state_transitions() {
+        let mut config = PlatformConfig::default_for_network(Network::Testnet);
+        config.execution.block_state_transitions_retention = 2;
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config)
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let state = platform.platform.state.load_full();
+        let version = state
+            .current_platform_version()
+            .expect("expected a platform version");
+
+        let executed_state_transitions = vec![
+            ExecutedStateTransition {
+                raw_state_transition: vec![1; 10],
+                status: ExecutedStateTransitionStatus::Success,
+                fees: Some(ExecutedStateTransitionFees {
+                    storage_fee: 1_000,
+                    processing_fee: 500,
+                    fee_refunds: vec![([2; 32], 100)],
+                    removed_bytes_from_system: 0,
+                }),
+                error: None,
+            },
+            ExecutedStateTransition {
+                raw_state_transition: vec![3; 10],
+                status: ExecutedStateTransitionStatus::UnpaidConsensusError,
+                fees: None,
+                error: Some(ExecutedStateTransitionError {
+                    code: 10100,
+                    message: "invalid".to_string(),
+                    data: vec![4],
+                }),
+            },
+        ];
+
+        for block_height in 1..=3 {
+            platform
+                .store_block_state_transitions(
+                    &BlockStateTransitions {
+                        block_height,
+                        block_time_ms: block_height * 1000,
+                        state_transitions: executed_state_transitions.clone(),
+                    },
+                    block_height.checked_sub(2),
+                    None,
+                    version,
+                )
+                .expect("expected to store block state transitions");
+        }
+
+        let request = GetBlockStateTransitionsRequestV0 { height: 3 };
+
+        let response = platform
+            .query_block_state_transitions_v0(request, &state, version)
+            .expect("expected query to succeed")
+            .into_data()
+            .expect("expected a response");
+
+        assert_eq!(response.block_height, 3);
+        assert_eq!(response.block_time_ms, 3000);
+        assert_eq!(
+            response.state_transitions,
+            vec![
+                ExecutedStateTransitionProto {
+                    state_transition: vec![1; 10],
+                    status: ExecutionStatus::Success as i32,
+                    fee_result: Some(FeeResult {
+                        storage_fee: 1_000,
+                        processing_fee: 500,
+                        fee_refunds: vec![FeeRefund {
+                            identity_id: vec![2; 32],
+                            credits: 100,
+                        }],
+                        removed_bytes_from_system: 0,
+                    }),
+                    error: None,
+                },
+                ExecutedStateTransitionProto {
+                    state_transition: vec![3; 10],
+                    status: ExecutionStatus::UnpaidConsensusError as i32,
+                    fee_result: None,
+                    error: Some(StateTransitionBroadcastError {
+                        code: 10100,
+                        message: "invalid".to_string(),
+                        data: vec![4],
+                    }),
+                },
+            ]
+        );
+
+        // The first block fell out of the retention window
+        let request = GetBlockStateTransitionsRequestV0 { height: 1 };
+
+        let result = platform
+            .query_block_state_transitions_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::NotFound(_)]
+        ));
+
+        // Shrinking the retention window removes every block that fell out of it
+        platform
+            .store_block_state_transitions(
+                &BlockStateTransitions {
+                    block_height: 4,
+                    block_time_ms: 4000,
+                    state_transitions: executed_state_transitions,
+                },
+                Some(3),
+                None,
+                version,
+            )
+            .expect("expected to store block state transitions");
+
+        for height in 2..=3 {
+            let request = GetBlockStateTransitionsRequestV0 { height };
+
+            let result = platform
+                .query_block_state_transitions_v0(request, &state, version)
+                .expect("expected query to succeed");
+
+            assert!(matches!(
+                result.errors.as_slice(),
+                [QueryError::NotFound(_)]
+            ));
+        }
+
+        let request = GetBlockStateTransitionsRequestV0 { height: 4 };
+
+        let response = platform
+            .query_block_state_transitions_v0(request, &state, version)
+            .expect("expected query to succeed")
+            .into_data()
+            .expect("expected a response");
+
+        assert_eq!(response.block_height, 4);
+    }
+}
@@ -1,7 +1,9 @@
+mod block_state_transitions;
 mod current_quorums_info;
 mod epoch_infos;
 mod finalized_epoch_infos;
 mod path_elements;
+mod simulate_state_transition;
 mod status;
 mod total_credits_in_platform;
 mod version_upgrade_state;
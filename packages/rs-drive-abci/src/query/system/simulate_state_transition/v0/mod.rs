@@ -0,0 +1,231 @@
+use crate::error::execution::ExecutionError;
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+use crate::query::QueryValidationResult;
+use crate::rpc::core::CoreRPCLike;
+use dapi_grpc::platform::v0::simulate_state_transition_request::SimulateStateTransitionRequestV0;
+use dapi_grpc::platform::v0::simulate_state_transition_response::simulate_state_transition_response_v0::{
+    FeeRefund, FeeResult as FeeResultProto,
+};
+use dapi_grpc::platform::v0::simulate_state_transition_response::SimulateStateTransitionResponseV0;
+use dapi_grpc::platform::v0::StateTransitionBroadcastError;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::ConsensusError;
+use dpp::fee::fee_result::FeeResult;
+use dpp::fee::Credits;
+use dpp::identity::state_transition::OptionallyAssetLockProved;
+use dpp::serialization::{PlatformDeserializable, PlatformSerializableWithPlatformVersion};
+use dpp::state_transition::StateTransition;
+use dpp::version::PlatformVersion;
+
+fn fee_result_to_proto(fee_result: FeeResult) -> FeeResultProto {
+    let FeeResult {
+        storage_fee,
+        processing_fee,
+        fee_refunds,
+        removed_bytes_from_system,
+    } = fee_result;
+
+    FeeResultProto {
+        storage_fee,
+        processing_fee,
+        fee_refunds: fee_refunds
+            .into_iter()
+            .map(|(identity_id, credits_per_epoch)| FeeRefund {
+                identity_id: identity_id.to_vec(),
+                credits: credits_per_epoch.values().sum::<Credits>(),
+            })
+            .collect(),
+        removed_bytes_from_system,
+    }
+}
+
+fn consensus_error_to_proto(
+    error: ConsensusError,
+    platform_version: &PlatformVersion,
+) -> Result<StateTransitionBroadcastError, Error> {
+    Ok(StateTransitionBroadcastError {
+        code: error.code(),
+        message: error.to_string(),
+        data: error
+            .serialize_to_bytes_with_platform_version(platform_version)
+            .map_err(Error::Protocol)?,
+    })
+}
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn query_simulate_state_transition_v0(
+        &self,
+        SimulateStateTransitionRequestV0 { state_transition }: SimulateStateTransitionRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<SimulateStateTransitionResponseV0>, Error> {
+        if state_transition.is_empty() {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::InvalidArgument("state_transition must not be empty".to_string()),
+            ));
+        }
+
+        // Validating an asset lock proof can require a call to Core, and the proof is not
+        // marked as used by a simulation, so these state transitions could be simulated over
+        // and over at the cost of the node
+        if StateTransition::deserialize_from_bytes(&state_transition)
+            .is_ok_and(|decoded| decoded.optional_asset_lock_proof().is_some())
+        {
+            return Ok(QueryValidationResult::new_with_error(
+                QueryError::InvalidArgument(
+                    "state transitions paid with an asset lock can't be simulated".to_string(),
+                ),
+            ));
+        }
+
+        let execution_result =
+            self.simulate_state_transition(&state_transition, platform_state, platform_version)?;
+
+        let (fee_result, error) = match execution_result {
+            StateTransitionExecutionResult::SuccessfulExecution(_, fee_result) => {
+                (Some(fee_result), None)
+            }
+            StateTransitionExecutionResult::PaidConsensusError(error, fee_result) => {
+                (Some(fee_result), Some(error))
+            }
+            StateTransitionExecutionResult::UnpaidConsensusError(error) => (None, Some(error)),
+            StateTransitionExecutionResult::InternalError(_)
+            | StateTransitionExecutionResult::NotExecuted(_) => {
+                return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "state transition simulation must return a fee or a consensus error",
+                )));
+            }
+        };
+
+        let response = SimulateStateTransitionResponseV0 {
+            fee_result: fee_result.map(fee_result_to_proto),
+            error: error
+                .map(|error| consensus_error_to_proto(error, platform_version))
+                .transpose()?,
+            metadata: Some(self.response_metadata_v0(platform_state)),
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::setup_platform;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::dashcore::Network;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::{Identity, IdentityPublicKey, IdentityV0};
+    use dpp::native_bls::NativeBlsModule;
+    use dpp::prelude::Identifier;
+    use dpp::serialization::PlatformSerializable;
+    use dpp::tests::fixtures::get_dashpay_contract_fixture;
+    use platform_version::TryIntoPlatformVersioned;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_empty_state_transition() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let request = SimulateStateTransitionRequestV0 {
+            state_transition: vec![],
+        };
+
+        let result = platform
+            .query_simulate_state_transition_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::InvalidArgument(msg)] if msg == "state_transition must not be empty"
+        ));
+    }
+
+    #[test]
+    fn test_undecodable_state_transition_is_not_paid() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let request = SimulateStateTransitionRequestV0 {
+            state_transition: vec![0xff; 32],
+        };
+
+        let result = platform
+            .query_simulate_state_transition_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        let response = result.into_data().expect("expected a response");
+
+        assert!(response.fee_result.is_none());
+        assert!(response.error.is_some());
+    }
+
+    #[test]
+    fn test_data_contract_create_is_simulated_without_being_applied() {
+        let (platform, state, version) = setup_platform(None, Network::Testnet, None);
+
+        let (key, private_key) =
+            IdentityPublicKey::random_ecdsa_critical_level_authentication_key(1, Some(1), version)
+                .expect("expected to get key pair");
+
+        let identity: Identity = IdentityV0 {
+            id: Identifier::new([5; 32]),
+            public_keys: BTreeMap::from([(1, key.clone())]),
+            balance: 25_000_000_000,
+            revision: 0,
+        }
+        .into();
+
+        let identity_id = identity.id();
+
+        platform
+            .drive
+            .add_new_identity(identity, false, &BlockInfo::default(), true, None, version)
+            .expect("expected to insert identity");
+
+        let dashpay = get_dashpay_contract_fixture(Some(identity_id), 1, version.protocol_version);
+        let contract_id = dashpay.data_contract().id();
+
+        let mut create_contract_state_transition: StateTransition = dashpay
+            .try_into_platform_versioned(version)
+            .expect("expected a state transition");
+        create_contract_state_transition
+            .sign(&key, private_key.as_slice(), &NativeBlsModule)
+            .expect("expected to sign transition");
+
+        let request = SimulateStateTransitionRequestV0 {
+            state_transition: create_contract_state_transition
+                .serialize_to_bytes()
+                .expect("expected to serialize state transition"),
+        };
+
+        let result = platform
+            .query_simulate_state_transition_v0(request, &state, version)
+            .expect("expected query to succeed");
+
+        let response = result.into_data().expect("expected a response");
+
+        assert_eq!(response.error, None);
+
+        let fee_result = response.fee_result.expect("expected a fee result");
+
+        assert!(fee_result.storage_fee > 0);
+        assert!(fee_result.processing_fee > 0);
+
+        let contract = platform
+            .drive
+            .fetch_contract(contract_id.to_buffer(), None, None, None, version)
+            .value
+            .expect("expected to fetch contract");
+
+        assert!(contract.is_none());
+    }
+}
@@ -2,9 +2,14 @@ mod identities_token_balances;
 mod identities_token_infos;
 mod identity_token_balances;
 mod identity_token_infos;
+mod token_allowances;
 mod token_contract_info;
 mod token_direct_purchase_prices;
+mod token_events;
+mod token_holders;
+mod token_order_book;
 mod token_perpetual_distribution_last_claim;
 mod token_pre_programmed_distributions;
 mod token_status;
 mod token_total_supply;
+mod token_vestings;
@@ -0,0 +1,142 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_token_allowances_request::get_token_allowances_request_v0::allowance_spender::Spender;
+use dapi_grpc::platform::v0::get_token_allowances_request::get_token_allowances_request_v0::AllowanceSpender;
+use dapi_grpc::platform::v0::get_token_allowances_request::GetTokenAllowancesRequestV0;
+use dapi_grpc::platform::v0::get_token_allowances_response::get_token_allowances_response_v0::TokenAllowances;
+use dapi_grpc::platform::v0::get_token_allowances_response::{
+    get_token_allowances_response_v0, GetTokenAllowancesResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::data_contract::GroupContractPosition;
+use dpp::identifier::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+
+impl<C> Platform<C> {
+    pub(super) fn query_token_allowances_v0(
+        &self,
+        GetTokenAllowancesRequestV0 {
+            token_id,
+            owner_id,
+            start_after,
+            count,
+            prove,
+        }: GetTokenAllowancesRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetTokenAllowancesResponseV0>, Error> {
+        let config = &self.config.drive;
+        let token_id: [u8; 32] =
+            check_validation_result_with_data!(token_id.try_into().map_err(|_| {
+                QueryError::InvalidArgument(
+                    "token_id must be a valid identifier (32 bytes long)".to_string(),
+                )
+            }));
+
+        let owner_id: [u8; 32] =
+            check_validation_result_with_data!(owner_id.try_into().map_err(|_| {
+                QueryError::InvalidArgument(
+                    "owner_id must be a valid identifier (32 bytes long)".to_string(),
+                )
+            }));
+
+        let start_after: Option<TokenAllowanceSpender> = match start_after {
+            None => None,
+            Some(AllowanceSpender { spender: None }) => {
+                return Ok(QueryValidationResult::new_with_error(
+                    QueryError::InvalidArgument("start_after must have a spender".to_string()),
+                ));
+            }
+            Some(AllowanceSpender {
+                spender: Some(Spender::IdentityId(identity_id)),
+            }) => Some(TokenAllowanceSpender::Identity(
+                check_validation_result_with_data!(Identifier::from_bytes(&identity_id).map_err(
+                    |_| {
+                        QueryError::InvalidArgument(
+                            "start_after identity_id must be a valid identifier (32 bytes long)"
+                                .to_string(),
+                        )
+                    }
+                )),
+            )),
+            Some(AllowanceSpender {
+                spender: Some(Spender::GroupContractPosition(group_contract_position)),
+            }) => {
+                if group_contract_position > GroupContractPosition::MAX as u32 {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        QuerySyntaxError::InvalidParameter(format!(
+                            "group contract position {} can not be over u16::MAX",
+                            group_contract_position
+                        )),
+                    )));
+                }
+                Some(TokenAllowanceSpender::Group(
+                    group_contract_position as GroupContractPosition,
+                ))
+            }
+        };
+
+        let limit = count
+            .map_or(Some(config.default_query_limit), |limit_value| {
+                if limit_value == 0
+                    || limit_value > u16::MAX as u32
+                    || limit_value as u16 > config.default_query_limit
+                {
+                    None
+                } else {
+                    Some(limit_value as u16)
+                }
+            })
+            .ok_or(drive::error::Error::Query(QuerySyntaxError::InvalidLimit(
+                format!("limit greater than max limit {}", config.max_query_limit),
+            )))?;
+
+        let response = if prove {
+            let proof = check_validation_result_with_data!(self.drive.prove_token_allowances(
+                token_id,
+                owner_id,
+                start_after,
+                Some(limit),
+                None,
+                platform_version,
+            ));
+
+            GetTokenAllowancesResponseV0 {
+                result: Some(get_token_allowances_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let allowances = self
+                .drive
+                .fetch_token_allowances(
+                    token_id,
+                    owner_id,
+                    start_after,
+                    Some(limit),
+                    None,
+                    platform_version,
+                )?
+                .into_iter()
+                .map(|allowance| allowance.serialize_consume_to_bytes())
+                .collect::<Result<Vec<Vec<u8>>, _>>()?;
+
+            GetTokenAllowancesResponseV0 {
+                result: Some(get_token_allowances_response_v0::Result::Allowances(
+                    TokenAllowances { allowances },
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
@@ -0,0 +1,535 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_token_events_request::get_token_events_request_v0::{
+    BlockRange, Filter, StartAfter, TokenEventType,
+};
+use dapi_grpc::platform::v0::get_token_events_request::GetTokenEventsRequestV0;
+use dapi_grpc::platform::v0::get_token_events_response::get_token_events_response_v0::{
+    TokenEventEntry, TokenEvents,
+};
+use dapi_grpc::platform::v0::get_token_events_response::{
+    get_token_events_response_v0, GetTokenEventsResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::document::DocumentV0Getters;
+use dpp::identifier::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::tokens::token_event::TokenEvent;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+use drive::query::token_event_history_query::{
+    TokenEventHistoryDriveQuery, TokenEventHistoryFilter, TokenEventHistoryStartAfter,
+    TokenEventHistoryType,
+};
+
+impl<C> Platform<C> {
+    pub(super) fn query_token_events_v0(
+        &self,
+        GetTokenEventsRequestV0 {
+            token_id,
+            event_type,
+            filter,
+            start_after,
+            count,
+            order_ascending,
+            prove,
+        }: GetTokenEventsRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetTokenEventsResponseV0>, Error> {
+        let config = &self.config.drive;
+        let token_id: Identifier =
+            check_validation_result_with_data!(token_id.try_into().map_err(|_| {
+                QueryError::InvalidArgument(
+                    "token_id must be a valid identifier (32 bytes long)".to_string(),
+                )
+            }));
+
+        let event_type = match event_type {
+            None => None,
+            Some(event_type) => Some(event_history_type(check_validation_result_with_data!(
+                TokenEventType::try_from(event_type).map_err(|_| {
+                    QueryError::InvalidArgument(format!("unknown token event type {}", event_type))
+                })
+            ))),
+        };
+
+        let filter = match filter {
+            None => None,
+            Some(Filter::IdentityId(identity_id)) => Some(TokenEventHistoryFilter::ByIdentity(
+                check_validation_result_with_data!(identity_id.try_into().map_err(|_| {
+                    QueryError::InvalidArgument(
+                        "identity_id must be a valid identifier (32 bytes long)".to_string(),
+                    )
+                })),
+            )),
+            Some(Filter::BlockRange(BlockRange {
+                start_height,
+                end_height,
+            })) => Some(TokenEventHistoryFilter::ByBlockHeightRange {
+                start_height,
+                end_height,
+            }),
+        };
+
+        let start_after = match start_after {
+            None => None,
+            Some(StartAfter {
+                document_id,
+                event_type,
+                created_at,
+                created_at_block_height,
+            }) => Some(TokenEventHistoryStartAfter {
+                event_type: event_history_type(check_validation_result_with_data!(
+                    TokenEventType::try_from(event_type).map_err(|_| {
+                        QueryError::InvalidArgument(format!(
+                            "unknown token event type {}",
+                            event_type
+                        ))
+                    })
+                )),
+                document_id: check_validation_result_with_data!(document_id.try_into().map_err(
+                    |_| {
+                        QueryError::InvalidArgument(
+                            "start_after document_id must be a valid identifier (32 bytes long)"
+                                .to_string(),
+                        )
+                    }
+                )),
+                created_at,
+                created_at_block_height,
+            }),
+        };
+
+        let limit = count
+            .map_or(Some(config.default_query_limit), |limit_value| {
+                if limit_value == 0
+                    || limit_value > u16::MAX as u32
+                    || limit_value as u16 > config.max_query_limit
+                {
+                    None
+                } else {
+                    Some(limit_value as u16)
+                }
+            })
+            .ok_or(drive::error::Error::Query(QuerySyntaxError::InvalidLimit(
+                format!("limit greater than max limit {}", config.max_query_limit),
+            )))?;
+
+        let history_query = TokenEventHistoryDriveQuery {
+            token_id,
+            event_type,
+            filter,
+            start_after,
+            limit,
+            order_ascending,
+        };
+
+        let token_history_contract = self.drive.cache.system_data_contracts.load_token_history();
+
+        let response = if prove {
+            let proof = match history_query.execute_with_proof(
+                &self.drive,
+                &token_history_contract,
+                None,
+                platform_version,
+            ) {
+                Ok(proof) => proof,
+                Err(drive::error::Error::Query(query_error)) => {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        query_error,
+                    )));
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            GetTokenEventsResponseV0 {
+                result: Some(get_token_events_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let events = match history_query.execute_no_proof(
+                &self.drive,
+                &token_history_contract,
+                None,
+                platform_version,
+            ) {
+                Ok(events) => events,
+                Err(drive::error::Error::Query(query_error)) => {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        query_error,
+                    )));
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            let events = events
+                .into_iter()
+                .map(|(event_type, document)| {
+                    let event = TokenEvent::from_historical_document(
+                        event_type.document_type_name(),
+                        &document,
+                    )?;
+                    Ok(TokenEventEntry {
+                        document_id: document.id().to_vec(),
+                        owner_id: document.owner_id().to_vec(),
+                        created_at: document.created_at().unwrap_or_default(),
+                        created_at_block_height: document
+                            .created_at_block_height()
+                            .unwrap_or_default(),
+                        event: event.serialize_consume_to_bytes()?,
+                        event_type: token_event_type(event_type) as i32,
+                    })
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            GetTokenEventsResponseV0 {
+                result: Some(get_token_events_response_v0::Result::Events(TokenEvents {
+                    events,
+                })),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
+
+fn event_history_type(event_type: TokenEventType) -> TokenEventHistoryType {
+    match event_type {
+        TokenEventType::Mint => TokenEventHistoryType::Mint,
+        TokenEventType::Burn => TokenEventHistoryType::Burn,
+        TokenEventType::Freeze => TokenEventHistoryType::Freeze,
+        TokenEventType::Unfreeze => TokenEventHistoryType::Unfreeze,
+        TokenEventType::DestroyFrozenFunds => TokenEventHistoryType::DestroyFrozenFunds,
+        TokenEventType::Transfer => TokenEventHistoryType::Transfer,
+        TokenEventType::Claim => TokenEventHistoryType::Claim,
+        TokenEventType::EmergencyAction => TokenEventHistoryType::EmergencyAction,
+        TokenEventType::ConfigUpdate => TokenEventHistoryType::ConfigUpdate,
+        TokenEventType::DirectPricing => TokenEventHistoryType::DirectPricing,
+        TokenEventType::DirectPurchase => TokenEventHistoryType::DirectPurchase,
+    }
+}
+
+fn token_event_type(event_type: TokenEventHistoryType) -> TokenEventType {
+    match event_type {
+        TokenEventHistoryType::Mint => TokenEventType::Mint,
+        TokenEventHistoryType::Burn => TokenEventType::Burn,
+        TokenEventHistoryType::Freeze => TokenEventType::Freeze,
+        TokenEventHistoryType::Unfreeze => TokenEventType::Unfreeze,
+        TokenEventHistoryType::DestroyFrozenFunds => TokenEventType::DestroyFrozenFunds,
+        TokenEventHistoryType::Transfer => TokenEventType::Transfer,
+        TokenEventHistoryType::Claim => TokenEventType::Claim,
+        TokenEventHistoryType::EmergencyAction => TokenEventType::EmergencyAction,
+        TokenEventHistoryType::ConfigUpdate => TokenEventType::ConfigUpdate,
+        TokenEventHistoryType::DirectPricing => TokenEventType::DirectPricing,
+        TokenEventHistoryType::DirectPurchase => TokenEventType::DirectPurchase,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::tests::setup_platform;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::TempPlatform;
+    use dapi_grpc::platform::v0::get_token_events_response::get_token_events_response_v0::Result as TokenEventsResult;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::dashcore::Network;
+    use dpp::prelude::{BlockHeight, IdentityNonce};
+    use drive::error::Error as DriveError;
+    use platform_version::version::PlatformVersion;
+
+    const TOKEN_ID: [u8; 32] = [1; 32];
+    const OWNER_ID: [u8; 32] = [2; 32];
+    const RECIPIENT_ID: [u8; 32] = [3; 32];
+
+    fn add_event(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        owner_nonce: IdentityNonce,
+        event: TokenEvent,
+        height: BlockHeight,
+        platform_version: &PlatformVersion,
+    ) {
+        let operations = platform
+            .drive
+            .add_token_transaction_history_operations(
+                Identifier::new(TOKEN_ID),
+                Identifier::new(OWNER_ID),
+                owner_nonce,
+                event,
+                &BlockInfo {
+                    time_ms: height * 1000,
+                    height,
+                    ..Default::default()
+                },
+                &mut None,
+                None,
+                platform_version,
+            )
+            .expect("expected history operations");
+
+        platform
+            .drive
+            .apply_batch_low_level_drive_operations(
+                None,
+                None,
+                operations,
+                &mut vec![],
+                &platform_version.drive,
+            )
+            .expect("expected to apply history operations");
+    }
+
+    fn request(
+        event_type: Option<TokenEventType>,
+        filter: Option<Filter>,
+        start_after: Option<StartAfter>,
+        count: Option<u32>,
+        prove: bool,
+    ) -> GetTokenEventsRequestV0 {
+        GetTokenEventsRequestV0 {
+            token_id: TOKEN_ID.to_vec(),
+            event_type: event_type.map(|event_type| event_type as i32),
+            filter,
+            start_after,
+            count,
+            order_ascending: true,
+            prove,
+        }
+    }
+
+    fn events(response: GetTokenEventsResponseV0) -> Vec<TokenEventEntry> {
+        match response.result {
+            Some(TokenEventsResult::Events(TokenEvents { events })) => events,
+            result => panic!("expected events, got {:?}", result),
+        }
+    }
+
+    fn mint(amount: u64) -> TokenEvent {
+        TokenEvent::Mint(amount, Identifier::new(OWNER_ID), None)
+    }
+
+    fn transfer(amount: u64) -> TokenEvent {
+        TokenEvent::Transfer(Identifier::new(RECIPIENT_ID), None, None, None, amount)
+    }
+
+    #[test]
+    fn test_identity_filter_includes_received_transfers() {
+        let (platform, state, version) = setup_platform(Some((1, 1)), Network::Testnet, None);
+
+        add_event(&platform, 1, mint(100), 1, version);
+        add_event(&platform, 2, transfer(10), 2, version);
+
+        let result = platform
+            .query_token_events_v0(
+                request(
+                    None,
+                    Some(Filter::IdentityId(RECIPIENT_ID.to_vec())),
+                    None,
+                    None,
+                    false,
+                ),
+                &state,
+                version,
+            )
+            .expect("expected query to succeed");
+
+        let events = events(result.into_data().expect("expected data"));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, TokenEventType::Transfer as i32);
+        assert_eq!(events[0].owner_id, OWNER_ID.to_vec());
+    }
+
+    #[test]
+    fn test_block_range_filter() {
+        let (platform, state, version) = setup_platform(Some((1, 1)), Network::Testnet, None);
+
+        add_event(&platform, 1, mint(100), 1, version);
+        add_event(&platform, 2, transfer(10), 2, version);
+        add_event(&platform, 3, mint(50), 3, version);
+
+        let result = platform
+            .query_token_events_v0(
+                request(
+                    None,
+                    Some(Filter::BlockRange(BlockRange {
+                        start_height: 2,
+                        end_height: 4,
+                    })),
+                    None,
+                    None,
+                    false,
+                ),
+                &state,
+                version,
+            )
+            .expect("expected query to succeed");
+
+        let heights: Vec<_> = events(result.into_data().expect("expected data"))
+            .into_iter()
+            .map(|event| event.created_at_block_height)
+            .collect();
+        assert_eq!(heights, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_pages_over_events_of_every_type() {
+        let (platform, state, version) = setup_platform(Some((1, 1)), Network::Testnet, None);
+
+        add_event(&platform, 1, transfer(10), 2, version);
+        add_event(&platform, 2, mint(100), 2, version);
+        add_event(&platform, 3, mint(50), 3, version);
+
+        let block_range = Filter::BlockRange(BlockRange {
+            start_height: 1,
+            end_height: 10,
+        });
+
+        let mut start_after = None;
+        let mut pages = vec![];
+        loop {
+            let result = platform
+                .query_token_events_v0(
+                    request(
+                        None,
+                        Some(block_range.clone()),
+                        start_after.clone(),
+                        Some(1),
+                        false,
+                    ),
+                    &state,
+                    version,
+                )
+                .expect("expected query to succeed");
+            let page = events(result.into_data().expect("expected data"));
+            let Some(last) = page.last() else {
+                break;
+            };
+            start_after = Some(StartAfter {
+                document_id: last.document_id.clone(),
+                event_type: last.event_type,
+                created_at: last.created_at,
+                created_at_block_height: last.created_at_block_height,
+            });
+            pages.push((last.created_at_block_height, last.event_type));
+        }
+
+        // Events at the same height are ordered by type
+        assert_eq!(
+            pages,
+            vec![
+                (2, TokenEventType::Mint as i32),
+                (2, TokenEventType::Transfer as i32),
+                (3, TokenEventType::Mint as i32),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_proved_events_match_unproved_events() {
+        let (platform, state, version) = setup_platform(Some((1, 1)), Network::Testnet, None);
+
+        add_event(&platform, 1, mint(100), 1, version);
+        add_event(&platform, 2, transfer(10), 2, version);
+
+        let result = platform
+            .query_token_events_v0(request(None, None, None, None, true), &state, version)
+            .expect("expected query to succeed");
+
+        let proof = match result.into_data().expect("expected data").result {
+            Some(TokenEventsResult::Proof(proof)) => proof,
+            result => panic!("expected a proof, got {:?}", result),
+        };
+
+        let history_query = TokenEventHistoryDriveQuery {
+            token_id: Identifier::new(TOKEN_ID),
+            event_type: None,
+            filter: None,
+            start_after: None,
+            limit: platform.config.drive.default_query_limit,
+            order_ascending: true,
+        };
+
+        let (_, events) = history_query
+            .verify_proof(
+                &platform
+                    .drive
+                    .cache
+                    .system_data_contracts
+                    .load_token_history(),
+                &proof.grovedb_proof,
+                version,
+            )
+            .expect("expected proof to verify");
+
+        let event_types: Vec<_> = events.iter().map(|(event_type, _)| *event_type).collect();
+        assert_eq!(
+            event_types,
+            vec![TokenEventHistoryType::Mint, TokenEventHistoryType::Transfer]
+        );
+    }
+
+    #[test]
+    fn test_start_after_of_another_event_type_is_rejected() {
+        let (platform, state, version) = setup_platform(Some((1, 1)), Network::Testnet, None);
+
+        let result = platform
+            .query_token_events_v0(
+                request(
+                    Some(TokenEventType::Mint),
+                    None,
+                    Some(StartAfter {
+                        document_id: vec![4; 32],
+                        event_type: TokenEventType::Burn as i32,
+                        created_at: 0,
+                        created_at_block_height: 0,
+                    }),
+                    None,
+                    false,
+                ),
+                &state,
+                version,
+            )
+            .expect("expected query to succeed");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [QueryError::Query(QuerySyntaxError::InvalidStartAfter(_))]
+        ));
+    }
+
+    #[test]
+    fn test_limit_is_checked_against_max_query_limit() {
+        let (platform, state, version) = setup_platform(Some((1, 1)), Network::Testnet, None);
+
+        let max_query_limit = platform.config.drive.max_query_limit as u32;
+
+        platform
+            .query_token_events_v0(
+                request(None, None, None, Some(max_query_limit), false),
+                &state,
+                version,
+            )
+            .expect("expected the max query limit to be accepted");
+
+        let result = platform.query_token_events_v0(
+            request(None, None, None, Some(max_query_limit + 1), false),
+            &state,
+            version,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::Drive(DriveError::Query(
+                QuerySyntaxError::InvalidLimit(_)
+            )))
+        ));
+    }
+}
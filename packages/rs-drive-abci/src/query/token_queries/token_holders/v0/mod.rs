@@ -0,0 +1,115 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_token_holders_request::GetTokenHoldersRequestV0;
+use dapi_grpc::platform::v0::get_token_holders_response::get_token_holders_response_v0::{
+    TokenHolderEntry, TokenHolders,
+};
+use dapi_grpc::platform::v0::get_token_holders_response::{
+    get_token_holders_response_v0, GetTokenHoldersResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+
+impl<C> Platform<C> {
+    pub(super) fn query_token_holders_v0(
+        &self,
+        GetTokenHoldersRequestV0 {
+            token_id,
+            start_at_identity_id,
+            count,
+            prove,
+            order_by_balance,
+        }: GetTokenHoldersRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetTokenHoldersResponseV0>, Error> {
+        let config = &self.config.drive;
+        let token_id: [u8; 32] =
+            check_validation_result_with_data!(token_id.try_into().map_err(|_| {
+                QueryError::InvalidArgument(
+                    "token_id must be a valid identifier (32 bytes long)".to_string(),
+                )
+            }));
+
+        let limit = count
+            .map_or(Some(config.default_query_limit), |limit_value| {
+                if limit_value == 0
+                    || limit_value > u16::MAX as u32
+                    || limit_value as u16 > config.default_query_limit
+                {
+                    None
+                } else {
+                    Some(limit_value as u16)
+                }
+            })
+            .ok_or(drive::error::Error::Query(QuerySyntaxError::InvalidLimit(
+                format!("limit greater than max limit {}", config.max_query_limit),
+            )))?;
+
+        let start_at = match start_at_identity_id {
+            None => None,
+            Some(start_at_identity_id) => {
+                let start_identity_id: [u8; 32] =
+                    check_validation_result_with_data!(start_at_identity_id
+                        .start_identity_id
+                        .try_into()
+                        .map_err(|_| {
+                            QueryError::InvalidArgument(
+                                "start_identity_id must be a valid identifier (32 bytes long)"
+                                    .to_string(),
+                            )
+                        }));
+                Some((
+                    start_identity_id,
+                    start_at_identity_id.start_identity_id_included,
+                ))
+            }
+        };
+
+        let response = if prove {
+            let proof = check_validation_result_with_data!(self.drive.prove_token_holders(
+                token_id,
+                start_at,
+                limit,
+                None,
+                platform_version,
+            ));
+
+            GetTokenHoldersResponseV0 {
+                result: Some(get_token_holders_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let mut token_holders: Vec<TokenHolderEntry> = self
+                .drive
+                .fetch_token_holders(token_id, start_at, limit, None, platform_version)?
+                .into_iter()
+                .map(|(identity_id, balance)| TokenHolderEntry {
+                    identity_id: identity_id.to_vec(),
+                    balance,
+                })
+                .collect();
+
+            if order_by_balance {
+                // Stable, so holders with equal balances stay in identity id order
+                token_holders.sort_by(|a, b| b.balance.cmp(&a.balance));
+            }
+
+            GetTokenHoldersResponseV0 {
+                result: Some(get_token_holders_response_v0::Result::TokenHolders(
+                    TokenHolders { token_holders },
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
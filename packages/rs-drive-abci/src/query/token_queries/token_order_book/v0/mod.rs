@@ -0,0 +1,139 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_token_order_book_request::{
+    GetTokenOrderBookRequestV0, OrderSide,
+};
+use dapi_grpc::platform::v0::get_token_order_book_response::get_token_order_book_response_v0::{
+    TokenOrderEntry, TokenOrders,
+};
+use dapi_grpc::platform::v0::get_token_order_book_response::{
+    get_token_order_book_response_v0, GetTokenOrderBookResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::identifier::Identifier;
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+use dpp::tokens::order_book::TokenOrderSide;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+
+impl<C> Platform<C> {
+    pub(super) fn query_token_order_book_v0(
+        &self,
+        GetTokenOrderBookRequestV0 {
+            token_id,
+            side,
+            quote_token,
+            count,
+            prove,
+        }: GetTokenOrderBookRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetTokenOrderBookResponseV0>, Error> {
+        let config = &self.config.drive;
+        let token_id: [u8; 32] =
+            check_validation_result_with_data!(token_id.try_into().map_err(|_| {
+                QueryError::InvalidArgument(
+                    "token_id must be a valid identifier (32 bytes long)".to_string(),
+                )
+            }));
+
+        let side = match check_validation_result_with_data!(OrderSide::try_from(side)
+            .map_err(|_| QueryError::InvalidArgument("side must be Buy or Sell".to_string())))
+        {
+            OrderSide::Buy => TokenOrderSide::Buy,
+            OrderSide::Sell => TokenOrderSide::Sell,
+        };
+
+        let quote_currency = match quote_token {
+            None => AllowedCurrency::TradingInDash,
+            Some(quote_token) => {
+                let contract_id: [u8; 32] = check_validation_result_with_data!(quote_token
+                    .contract_id
+                    .try_into()
+                    .map_err(|_| {
+                        QueryError::InvalidArgument(
+                            "quote token contract_id must be a valid identifier (32 bytes long)"
+                                .to_string(),
+                        )
+                    }));
+                let token_position =
+                    check_validation_result_with_data!(u16::try_from(quote_token.token_position)
+                        .map_err(|_| {
+                            QueryError::InvalidArgument(
+                                "quote token position must fit in 16 bits".to_string(),
+                            )
+                        }));
+                AllowedCurrency::OnContract(Identifier::new(contract_id), token_position)
+            }
+        };
+
+        let limit = count
+            .map_or(Some(config.default_query_limit), |limit_value| {
+                if limit_value == 0
+                    || limit_value > u16::MAX as u32
+                    || limit_value as u16 > config.default_query_limit
+                {
+                    None
+                } else {
+                    Some(limit_value as u16)
+                }
+            })
+            .ok_or(drive::error::Error::Query(QuerySyntaxError::InvalidLimit(
+                format!("limit greater than max limit {}", config.max_query_limit),
+            )))?;
+
+        let response = if prove {
+            let proof =
+                check_validation_result_with_data!(self.drive.prove_token_order_book_orders(
+                    token_id,
+                    side,
+                    &quote_currency,
+                    Some(limit),
+                    None,
+                    platform_version,
+                ));
+
+            GetTokenOrderBookResponseV0 {
+                result: Some(get_token_order_book_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let orders = self
+                .drive
+                .fetch_token_order_book_orders(
+                    token_id,
+                    side,
+                    &quote_currency,
+                    Some(limit),
+                    None,
+                    platform_version,
+                )?
+                .into_iter()
+                .map(|order| TokenOrderEntry {
+                    order_id: order.id().to_vec(),
+                    owner_id: order.owner_id().to_vec(),
+                    price_per_token: order.price_per_token(),
+                    original_amount: order.original_amount(),
+                    remaining_amount: order.remaining_amount(),
+                    created_at: order.created_at(),
+                })
+                .collect();
+
+            GetTokenOrderBookResponseV0 {
+                result: Some(get_token_order_book_response_v0::Result::Orders(
+                    TokenOrders { orders },
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
@@ -0,0 +1,112 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_token_vestings_request::GetTokenVestingsRequestV0;
+use dapi_grpc::platform::v0::get_token_vestings_response::get_token_vestings_response_v0::TokenVestings;
+use dapi_grpc::platform::v0::get_token_vestings_response::{
+    get_token_vestings_response_v0, GetTokenVestingsResponseV0,
+};
+use dpp::check_validation_result_with_data;
+use dpp::serialization::PlatformSerializable;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
+
+impl<C> Platform<C> {
+    pub(super) fn query_token_vestings_v0(
+        &self,
+        GetTokenVestingsRequestV0 {
+            token_id,
+            recipient_id,
+            start_after,
+            count,
+            prove,
+        }: GetTokenVestingsRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetTokenVestingsResponseV0>, Error> {
+        let config = &self.config.drive;
+        let token_id: [u8; 32] =
+            check_validation_result_with_data!(token_id.try_into().map_err(|_| {
+                QueryError::InvalidArgument(
+                    "token_id must be a valid identifier (32 bytes long)".to_string(),
+                )
+            }));
+
+        let recipient_id: [u8; 32] =
+            check_validation_result_with_data!(recipient_id.try_into().map_err(|_| {
+                QueryError::InvalidArgument(
+                    "recipient_id must be a valid identifier (32 bytes long)".to_string(),
+                )
+            }));
+
+        let start_after: Option<[u8; 32]> = match start_after {
+            None => None,
+            Some(start_after) => Some(check_validation_result_with_data!(start_after
+                .try_into()
+                .map_err(|_| {
+                    QueryError::InvalidArgument(
+                        "start_after must be a valid identifier (32 bytes long)".to_string(),
+                    )
+                }))),
+        };
+
+        let limit = count
+            .map_or(Some(config.default_query_limit), |limit_value| {
+                if limit_value == 0
+                    || limit_value > u16::MAX as u32
+                    || limit_value as u16 > config.default_query_limit
+                {
+                    None
+                } else {
+                    Some(limit_value as u16)
+                }
+            })
+            .ok_or(drive::error::Error::Query(QuerySyntaxError::InvalidLimit(
+                format!("limit greater than max limit {}", config.max_query_limit),
+            )))?;
+
+        let response = if prove {
+            let proof = check_validation_result_with_data!(self.drive.prove_token_vestings(
+                token_id,
+                recipient_id,
+                start_after,
+                Some(limit),
+                None,
+                platform_version,
+            ));
+
+            GetTokenVestingsResponseV0 {
+                result: Some(get_token_vestings_response_v0::Result::Proof(
+                    self.response_proof_v0(platform_state, proof),
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let vestings = self
+                .drive
+                .fetch_token_vestings(
+                    token_id,
+                    recipient_id,
+                    start_after,
+                    Some(limit),
+                    None,
+                    platform_version,
+                )?
+                .into_iter()
+                .map(|vesting| vesting.serialize_consume_to_bytes())
+                .collect::<Result<Vec<Vec<u8>>, _>>()?;
+
+            GetTokenVestingsResponseV0 {
+                result: Some(get_token_vestings_response_v0::Result::Vestings(
+                    TokenVestings { vestings },
+                )),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
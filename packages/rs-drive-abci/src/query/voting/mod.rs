@@ -3,3 +3,4 @@ mod contested_resource_vote_state;
 mod contested_resource_voters_for_identity;
 mod contested_resources;
 mod vote_polls_by_end_date_query;
+mod yes_no_abstain_vote_poll_state;
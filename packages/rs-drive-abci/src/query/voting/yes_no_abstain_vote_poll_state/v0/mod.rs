@@ -0,0 +1,107 @@
+use crate::error::query::QueryError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::PlatformState;
+use crate::query::QueryValidationResult;
+use dapi_grpc::platform::v0::get_yes_no_abstain_vote_poll_state_request::GetYesNoAbstainVotePollStateRequestV0;
+use dapi_grpc::platform::v0::get_yes_no_abstain_vote_poll_state_response::get_yes_no_abstain_vote_poll_state_response_v0::yes_no_abstain_vote::VoteChoiceType;
+use dapi_grpc::platform::v0::get_yes_no_abstain_vote_poll_state_response::get_yes_no_abstain_vote_poll_state_response_v0::{FinalTally, YesNoAbstainVote, YesNoAbstainVotePollState};
+use dapi_grpc::platform::v0::get_yes_no_abstain_vote_poll_state_response::{get_yes_no_abstain_vote_poll_state_response_v0, GetYesNoAbstainVotePollStateResponseV0};
+use dpp::check_validation_result_with_data;
+use dpp::identifier::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::validation::ValidationResult;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use dpp::voting::vote_info_storage::yes_no_abstain_vote_poll_stored_info::YesNoAbstainVotePollStoredInfoV0Getters;
+use dpp::voting::vote_polls::VotePoll;
+
+impl<C> Platform<C> {
+    pub(super) fn query_yes_no_abstain_vote_poll_state_v0(
+        &self,
+        GetYesNoAbstainVotePollStateRequestV0 {
+            vote_poll_id,
+            prove,
+        }: GetYesNoAbstainVotePollStateRequestV0,
+        platform_state: &PlatformState,
+        platform_version: &PlatformVersion,
+    ) -> Result<QueryValidationResult<GetYesNoAbstainVotePollStateResponseV0>, Error> {
+        let vote_poll_id: Identifier =
+            check_validation_result_with_data!(vote_poll_id.try_into().map_err(|_| {
+                QueryError::InvalidArgument(
+                    "vote_poll_id must be a valid identifier (32 bytes long)".to_string(),
+                )
+            }));
+
+        let response = if prove {
+            let proof = check_validation_result_with_data!(self
+                .drive
+                .prove_yes_no_abstain_vote_poll_state(vote_poll_id, None, platform_version,));
+
+            GetYesNoAbstainVotePollStateResponseV0 {
+                result: Some(
+                    get_yes_no_abstain_vote_poll_state_response_v0::Result::Proof(
+                        self.response_proof_v0(platform_state, proof),
+                    ),
+                ),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        } else {
+            let vote_poll_state = self.drive.fetch_yes_no_abstain_vote_poll_state(
+                vote_poll_id,
+                None,
+                &mut vec![],
+                platform_version,
+            )?;
+
+            let serialized_vote_poll = vote_poll_state
+                .vote_poll
+                .map(|vote_poll| VotePoll::YesNoAbstainVotePoll(vote_poll).serialize_to_bytes())
+                .transpose()?;
+
+            let votes = vote_poll_state
+                .votes
+                .into_iter()
+                .map(|(pro_tx_hash, vote)| {
+                    let vote_choice = match vote.vote_choice {
+                        YesNoAbstainVoteChoice::YES => VoteChoiceType::Yes,
+                        YesNoAbstainVoteChoice::NO => VoteChoiceType::No,
+                        YesNoAbstainVoteChoice::ABSTAIN => VoteChoiceType::Abstain,
+                    };
+                    YesNoAbstainVote {
+                        pro_tx_hash: pro_tx_hash.to_vec(),
+                        vote_choice: vote_choice.into(),
+                        strength: vote.strength as u32,
+                    }
+                })
+                .collect();
+
+            let final_tally = vote_poll_state.stored_info.map(|stored_info| {
+                let vote_tally = stored_info.vote_tally();
+                let finalization_block = stored_info.finalization_block();
+                FinalTally {
+                    yes: vote_tally.yes,
+                    no: vote_tally.no,
+                    abstain: vote_tally.abstain,
+                    finished_at_block_height: finalization_block.height,
+                    finished_at_time_ms: finalization_block.time_ms,
+                }
+            });
+
+            GetYesNoAbstainVotePollStateResponseV0 {
+                result: Some(
+                    get_yes_no_abstain_vote_poll_state_response_v0::Result::VotePollState(
+                        YesNoAbstainVotePollState {
+                            serialized_vote_poll,
+                            votes,
+                            final_tally,
+                        },
+                    ),
+                ),
+                metadata: Some(self.response_metadata_v0(platform_state)),
+            }
+        };
+
+        Ok(QueryValidationResult::new_with_data(response))
+    }
+}
@@ -0,0 +1,680 @@
+//! Archive of state transitions rejected during block execution.
+//!
+//! Rejected state transitions are stored in [PlatformConfig::rejections_path] using two files:
+//!
+//! * `rejected_state_transitions.v0.dat` - bincode encoded, versioned
+//!   [RejectedStateTransitionRecord]s appended one after another;
+//! * `rejected_state_transitions.v0.idx` - fixed size [RejectionIndexEntry]s pointing to the records,
+//!   so the archive can be filtered by block height, error code and identity without decoding
+//!   the records.
+//!
+//! Block execution hands rejected state transitions over to a [RejectionArchiver], which writes
+//! them in a background thread. Every state transition is archived once, keyed by the hash of
+//! the transaction, even if it is rejected again in another round or block.
+//!
+//! [PlatformConfig::rejections_path]: crate::config::PlatformConfig::rejections_path
+
+/// Archived record types
+pub mod record;
+
+use crate::error::Error;
+use crate::execution::check_tx::CheckTxLevel;
+use crate::platform_types::platform::{Platform, PlatformRef};
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::state_transitions_processing_result::StateTransitionExecutionResult;
+use crate::rejections::record::v0::{
+    RejectedStateTransitionFeeEstimate, RejectedStateTransitionRecordV0,
+};
+use crate::rejections::record::RejectedStateTransitionRecord;
+use crate::rpc::core::CoreRPCLike;
+use bincode::config;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::ConsensusError;
+use dpp::prelude::{BlockHeight, Identifier};
+use dpp::serialization::{PlatformDeserializable, PlatformSerializableWithPlatformVersion};
+use dpp::state_transition::StateTransition;
+use dpp::util::hash::hash_single;
+use dpp::version::PlatformVersion;
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DATA_FILE_NAME: &str = "rejected_state_transitions.v0.dat";
+const INDEX_FILE_NAME: &str = "rejected_state_transitions.v0.idx";
+
+/// Size of the serialized [RejectionIndexEntry]
+const INDEX_ENTRY_SIZE: usize = 8 + 4 + 8 + 4 + 1 + 32 + 32;
+
+/// Rejection archive errors
+#[derive(Debug, thiserror::Error)]
+pub enum RejectionArchiveError {
+    /// Archive files can't be read or written
+    #[error("rejection archive io: {0}")]
+    Io(#[from] std::io::Error),
+    /// Record can't be encoded
+    #[error("cannot encode rejected state transition: {0}")]
+    Encode(#[from] bincode::error::EncodeError),
+    /// Record can't be decoded
+    #[error("cannot decode rejected state transition: {0}")]
+    Decode(#[from] bincode::error::DecodeError),
+    /// Requested record is not in the archive
+    #[error("rejected state transition {0} not found")]
+    NotFound(u64),
+}
+
+/// Index entry of an archived rejected state transition
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RejectionIndexEntry {
+    /// Position of the entry in the archive
+    pub id: u64,
+    /// Offset of the record in the data file
+    offset: u64,
+    /// Length of the record in the data file
+    length: u32,
+    /// Height of the block the state transition was proposed in
+    pub block_height: BlockHeight,
+    /// Consensus error code
+    pub error_code: u32,
+    /// Identity that owns the state transition
+    pub owner_id: Option<[u8; 32]>,
+    /// Hash of the transaction, as used by Tenderdash
+    pub tx_hash: [u8; 32],
+}
+
+impl RejectionIndexEntry {
+    fn to_bytes(self) -> [u8; INDEX_ENTRY_SIZE] {
+        let mut bytes = [0u8; INDEX_ENTRY_SIZE];
+        bytes[0..8].copy_from_slice(&self.offset.to_be_bytes());
+        bytes[8..12].copy_from_slice(&self.length.to_be_bytes());
+        bytes[12..20].copy_from_slice(&self.block_height.to_be_bytes());
+        bytes[20..24].copy_from_slice(&self.error_code.to_be_bytes());
+        if let Some(owner_id) = self.owner_id {
+            bytes[24] = 1;
+            bytes[25..57].copy_from_slice(&owner_id);
+        }
+        bytes[57..89].copy_from_slice(&self.tx_hash);
+        bytes
+    }
+
+    fn from_bytes(id: u64, bytes: &[u8]) -> Self {
+        let owner_id = if bytes[24] == 1 {
+            let mut owner_id = [0u8; 32];
+            owner_id.copy_from_slice(&bytes[25..57]);
+            Some(owner_id)
+        } else {
+            None
+        };
+
+        Self {
+            id,
+            offset: u64::from_be_bytes(bytes[0..8].try_into().expect("8 bytes")),
+            length: u32::from_be_bytes(bytes[8..12].try_into().expect("4 bytes")),
+            block_height: u64::from_be_bytes(bytes[12..20].try_into().expect("8 bytes")),
+            error_code: u32::from_be_bytes(bytes[20..24].try_into().expect("4 bytes")),
+            owner_id,
+            tx_hash: bytes[57..89].try_into().expect("32 bytes"),
+        }
+    }
+}
+
+/// Filter for archived rejected state transitions
+#[derive(Clone, Debug, Default)]
+pub struct RejectionFilter {
+    /// Only include state transitions rejected with this consensus error code
+    pub error_code: Option<u32>,
+    /// Only include state transitions owned by this identity
+    pub owner_id: Option<Identifier>,
+    /// Only include state transitions proposed at or after this height
+    pub min_block_height: Option<BlockHeight>,
+    /// Only include state transitions proposed at or before this height
+    pub max_block_height: Option<BlockHeight>,
+}
+
+impl RejectionFilter {
+    /// Whether the index entry matches the filter
+    pub fn matches(&self, entry: &RejectionIndexEntry) -> bool {
+        self.error_code
+            .is_none_or(|error_code| entry.error_code == error_code)
+            && self
+                .owner_id
+                .is_none_or(|owner_id| entry.owner_id == Some(owner_id.to_buffer()))
+            && self
+                .min_block_height
+                .is_none_or(|height| entry.block_height >= height)
+            && self
+                .max_block_height
+                .is_none_or(|height| entry.block_height <= height)
+    }
+}
+
+/// Append-only archive of rejected state transitions
+#[derive(Clone, Debug)]
+pub struct RejectionArchive {
+    path: PathBuf,
+}
+
+impl RejectionArchive {
+    /// Create archive stored in the given directory
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Directory the archive is stored in
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append records to the archive.
+    ///
+    /// Records are synced to disk before the index entries pointing to them are written,
+    /// and the index is synced before returning, so archived records survive a crash.
+    pub fn append(
+        &self,
+        records: &[RejectedStateTransitionRecord],
+    ) -> Result<(), RejectionArchiveError> {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        fs::create_dir_all(&self.path)?;
+
+        let mut data_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.path.join(DATA_FILE_NAME))?;
+        let mut index_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.path.join(INDEX_FILE_NAME))?;
+
+        let mut offset = data_file.seek(SeekFrom::End(0))?;
+
+        let config = config::standard().with_big_endian().with_no_limit();
+
+        let mut index = Vec::with_capacity(records.len() * INDEX_ENTRY_SIZE);
+
+        for record in records {
+            let bytes = bincode::encode_to_vec(record, config)?;
+
+            data_file.write_all(&bytes)?;
+
+            let entry = RejectionIndexEntry {
+                id: 0,
+                offset,
+                length: bytes.len() as u32,
+                block_height: record.block_height(),
+                error_code: record.error_code(),
+                owner_id: record.owner_id(),
+                tx_hash: hash_single(record.state_transition()),
+            };
+
+            index.extend_from_slice(&entry.to_bytes());
+
+            offset += bytes.len() as u64;
+        }
+
+        // Index entries are written after the records are on disk, so an interrupted write
+        // never leaves entries pointing to incomplete data
+        data_file.sync_all()?;
+
+        index_file.write_all(&index)?;
+        index_file.sync_all()?;
+
+        Ok(())
+    }
+
+    /// Index entries matching the filter, in the order they were archived
+    pub fn entries(
+        &self,
+        filter: &RejectionFilter,
+    ) -> Result<Vec<RejectionIndexEntry>, RejectionArchiveError> {
+        let index = match fs::read(self.path.join(INDEX_FILE_NAME)) {
+            Ok(index) => index,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        // A trailing partial entry is left by an interrupted write and is ignored
+        Ok(index
+            .chunks_exact(INDEX_ENTRY_SIZE)
+            .enumerate()
+            .map(|(id, bytes)| RejectionIndexEntry::from_bytes(id as u64, bytes))
+            .filter(|entry| filter.matches(entry))
+            .collect())
+    }
+
+    /// Read the record the index entry points to
+    pub fn read(
+        &self,
+        entry: &RejectionIndexEntry,
+    ) -> Result<RejectedStateTransitionRecord, RejectionArchiveError> {
+        let mut data_file = File::open(self.path.join(DATA_FILE_NAME))?;
+        data_file.seek(SeekFrom::Start(entry.offset))?;
+
+        let mut bytes = vec![0u8; entry.length as usize];
+        data_file.read_exact(&mut bytes)?;
+
+        let config = config::standard().with_big_endian().with_no_limit();
+
+        Ok(bincode::decode_from_slice(&bytes, config)?.0)
+    }
+
+    /// Read the record with the given id
+    pub fn get(&self, id: u64) -> Result<RejectedStateTransitionRecord, RejectionArchiveError> {
+        let entry = self
+            .entries(&RejectionFilter::default())?
+            .into_iter()
+            .nth(id as usize)
+            .ok_or(RejectionArchiveError::NotFound(id))?;
+
+        self.read(&entry)
+    }
+}
+
+/// State transitions rejected in a block proposal, waiting to be archived
+struct RejectedBatch {
+    block_height: BlockHeight,
+    round: u32,
+    rejected_at: u64,
+    rejected: Vec<(
+        Vec<u8>,
+        ConsensusError,
+        Option<RejectedStateTransitionFeeEstimate>,
+    )>,
+    platform_version: &'static PlatformVersion,
+}
+
+/// Archives state transitions rejected during block execution in a background thread.
+///
+/// Archiving is a debugging aid, so it never blocks or interrupts block execution:
+/// failures are logged and the state transitions are dropped.
+pub struct RejectionArchiver {
+    sender: Option<mpsc::Sender<RejectedBatch>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl RejectionArchiver {
+    /// Start archiving into the archive stored in the given directory
+    pub fn start(path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let archive = RejectionArchive::new(path);
+
+        let (sender, receiver) = mpsc::channel::<RejectedBatch>();
+
+        let worker = std::thread::Builder::new()
+            .name("rejection-archiver".to_string())
+            .spawn(move || archive_batches(archive, receiver))?;
+
+        Ok(Self {
+            sender: Some(sender),
+            worker: Some(worker),
+        })
+    }
+
+    /// Queue state transitions rejected with consensus errors during block execution
+    /// to be archived
+    pub(crate) fn archive_rejected_state_transitions(
+        &self,
+        block_height: BlockHeight,
+        round: u32,
+        execution_results: &[StateTransitionExecutionResult],
+        raw_state_transitions: &[Vec<u8>],
+        platform_version: &'static PlatformVersion,
+    ) {
+        let rejected = execution_results
+            .iter()
+            .zip(raw_state_transitions)
+            .filter_map(|(execution_result, raw_state_transition)| {
+                let (consensus_error, fee_estimate) = match execution_result {
+                    StateTransitionExecutionResult::PaidConsensusError(
+                        consensus_error,
+                        fee_result,
+                    ) => (
+                        consensus_error,
+                        Some(RejectedStateTransitionFeeEstimate {
+                            storage_fee: fee_result.storage_fee,
+                            processing_fee: fee_result.processing_fee,
+                        }),
+                    ),
+                    StateTransitionExecutionResult::UnpaidConsensusError(consensus_error) => {
+                        (consensus_error, None)
+                    }
+                    _ => return None,
+                };
+
+                Some((
+                    raw_state_transition.clone(),
+                    consensus_error.clone(),
+                    fee_estimate,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        if rejected.is_empty() {
+            return;
+        }
+
+        let rejected_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("expected system time to be after unix epoch time")
+            .as_millis() as u64;
+
+        let batch = RejectedBatch {
+            block_height,
+            round,
+            rejected_at,
+            rejected,
+            platform_version,
+        };
+
+        if let Some(sender) = &self.sender {
+            if sender.send(batch).is_err() {
+                tracing::warn!(
+                    "rejection archiver is stopped, rejected state transitions at height {} are not archived",
+                    block_height
+                );
+            }
+        }
+    }
+}
+
+impl Drop for RejectionArchiver {
+    fn drop(&mut self) {
+        // Closing the channel lets the worker archive queued state transitions and exit
+        self.sender.take();
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// Archive batches received from the channel, skipping already archived transactions
+fn archive_batches(archive: RejectionArchive, receiver: mpsc::Receiver<RejectedBatch>) {
+    let mut archived = match archive.entries(&RejectionFilter::default()) {
+        Ok(entries) => entries
+            .into_iter()
+            .map(|entry| entry.tx_hash)
+            .collect::<HashSet<_>>(),
+        Err(e) => {
+            tracing::warn!(
+                error = ?e,
+                path = archive.path().display().to_string(),
+                "cannot read rejection archive, previously archived state transitions can be archived again"
+            );
+            HashSet::new()
+        }
+    };
+
+    for batch in receiver {
+        let records = batch
+            .rejected
+            .into_iter()
+            .filter(|(raw_state_transition, _, _)| {
+                archived.insert(hash_single(raw_state_transition))
+            })
+            .map(|(raw_state_transition, consensus_error, fee_estimate)| {
+                rejected_state_transition_record(
+                    raw_state_transition,
+                    &consensus_error,
+                    fee_estimate,
+                    batch.block_height,
+                    batch.round,
+                    batch.rejected_at,
+                    batch.platform_version,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if let Err(e) = archive.append(&records) {
+            tracing::warn!(
+                error = ?e,
+                path = archive.path().display().to_string(),
+                "cannot archive {} rejected state transitions at height {}",
+                records.len(),
+                batch.block_height
+            );
+        }
+    }
+}
+
+fn rejected_state_transition_record(
+    raw_state_transition: Vec<u8>,
+    consensus_error: &ConsensusError,
+    fee_estimate: Option<RejectedStateTransitionFeeEstimate>,
+    block_height: BlockHeight,
+    round: u32,
+    rejected_at: u64,
+    platform_version: &PlatformVersion,
+) -> RejectedStateTransitionRecord {
+    let owner_id = StateTransition::deserialize_from_bytes(&raw_state_transition)
+        .ok()
+        .map(|state_transition| state_transition.owner_id().to_buffer());
+
+    RejectedStateTransitionRecordV0 {
+        state_transition: raw_state_transition,
+        block_height,
+        round,
+        protocol_version: platform_version.protocol_version,
+        rejected_at,
+        error_code: consensus_error.code(),
+        error_message: consensus_error.to_string(),
+        consensus_error: consensus_error
+            .serialize_to_bytes_with_platform_version(platform_version)
+            .unwrap_or_default(),
+        owner_id,
+        fee_estimate,
+    }
+    .into()
+}
+
+/// Re-validate an archived state transition against the current platform state.
+///
+/// The state transition goes through the same checks as a new transition entering the mempool.
+/// Returns consensus errors, which are empty if the state transition is valid now.
+pub fn revalidate_rejected_state_transition<C>(
+    platform: &Platform<C>,
+    record: &RejectedStateTransitionRecord,
+) -> Result<Vec<ConsensusError>, Error>
+where
+    C: CoreRPCLike,
+{
+    let platform_state = platform.state.load();
+
+    let platform_ref = PlatformRef {
+        drive: &platform.drive,
+        state: &platform_state,
+        config: &platform.config,
+        core_rpc: &platform.core_rpc,
+    };
+
+    let platform_version = platform_state.current_platform_version()?;
+
+    let validation_result = platform.check_tx(
+        record.state_transition(),
+        CheckTxLevel::FirstTimeCheck,
+        &platform_ref,
+        platform_version,
+    )?;
+
+    Ok(validation_result.errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use dpp::consensus::basic::decode::SerializedObjectParsingError;
+
+    fn record(
+        block_height: BlockHeight,
+        error_code: u32,
+        owner_id: Option<[u8; 32]>,
+    ) -> RejectedStateTransitionRecord {
+        RejectedStateTransitionRecordV0 {
+            state_transition: vec![block_height as u8; 10],
+            block_height,
+            round: 0,
+            protocol_version: 1,
+            rejected_at: 0,
+            error_code,
+            error_message: "rejected".to_string(),
+            consensus_error: vec![],
+            owner_id,
+            fee_estimate: Some(RejectedStateTransitionFeeEstimate {
+                storage_fee: 0,
+                processing_fee: 1000,
+            }),
+        }
+        .into()
+    }
+
+    #[test]
+    fn should_return_nothing_for_empty_archive() {
+        let dir = tempfile::tempdir().expect("expected to create temp dir");
+
+        let archive = RejectionArchive::new(dir.path().join("rejected"));
+
+        assert!(archive
+            .entries(&RejectionFilter::default())
+            .expect("expected to read entries")
+            .is_empty());
+        assert_matches!(archive.get(0), Err(RejectionArchiveError::NotFound(0)));
+    }
+
+    #[test]
+    fn should_append_and_filter_records() {
+        let dir = tempfile::tempdir().expect("expected to create temp dir");
+
+        let archive = RejectionArchive::new(dir.path());
+
+        let records = vec![
+            record(1, 1000, Some([1; 32])),
+            record(2, 2000, None),
+            record(3, 1000, Some([2; 32])),
+        ];
+
+        archive
+            .append(&records[..2])
+            .expect("expected to append records");
+        archive
+            .append(&records[2..])
+            .expect("expected to append records");
+
+        let all = archive
+            .entries(&RejectionFilter::default())
+            .expect("expected to read entries");
+
+        assert_eq!(all.len(), 3);
+        for (entry, record) in all.iter().zip(&records) {
+            assert_eq!(
+                &archive.read(entry).expect("expected to read record"),
+                record
+            );
+        }
+
+        let by_code = archive
+            .entries(&RejectionFilter {
+                error_code: Some(1000),
+                ..Default::default()
+            })
+            .expect("expected to read entries");
+
+        assert_eq!(
+            by_code.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+            vec![0, 2]
+        );
+
+        let by_identity = archive
+            .entries(&RejectionFilter {
+                owner_id: Some(Identifier::new([2; 32])),
+                ..Default::default()
+            })
+            .expect("expected to read entries");
+
+        assert_eq!(by_identity.len(), 1);
+        assert_eq!(
+            archive.get(by_identity[0].id).expect("expected record"),
+            records[2]
+        );
+
+        let by_height = archive
+            .entries(&RejectionFilter {
+                min_block_height: Some(2),
+                max_block_height: Some(2),
+                ..Default::default()
+            })
+            .expect("expected to read entries");
+
+        assert_eq!(by_height.len(), 1);
+        assert_eq!(by_height[0].error_code, 2000);
+    }
+
+    #[test]
+    fn should_ignore_partially_written_index_entry() {
+        let dir = tempfile::tempdir().expect("expected to create temp dir");
+
+        let archive = RejectionArchive::new(dir.path());
+
+        archive
+            .append(&[record(1, 1000, None)])
+            .expect("expected to append records");
+
+        let mut index_file = OpenOptions::new()
+            .append(true)
+            .open(dir.path().join(INDEX_FILE_NAME))
+            .expect("expected to open index");
+        index_file
+            .write_all(&[1, 2, 3])
+            .expect("expected to write partial entry");
+
+        assert_eq!(
+            archive
+                .entries(&RejectionFilter::default())
+                .expect("expected to read entries")
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn archiver_should_archive_each_transaction_once() {
+        let dir = tempfile::tempdir().expect("expected to create temp dir");
+
+        let rejected = || {
+            StateTransitionExecutionResult::UnpaidConsensusError(
+                SerializedObjectParsingError::new("invalid".to_string()).into(),
+            )
+        };
+
+        // The same transaction is rejected in prepare proposal, in process proposal
+        // of the next round and again after a restart
+        for round in 0..3 {
+            let archiver = RejectionArchiver::start(dir.path()).expect("expected to start");
+
+            archiver.archive_rejected_state_transitions(
+                1,
+                round,
+                &[rejected(), rejected(), rejected()],
+                &[vec![1; 10], vec![2; 10], vec![1; 10]],
+                PlatformVersion::latest(),
+            );
+        }
+
+        let entries = RejectionArchive::new(dir.path())
+            .entries(&RejectionFilter::default())
+            .expect("expected to read entries");
+
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| entry.tx_hash)
+                .collect::<Vec<_>>(),
+            vec![hash_single([1; 10]), hash_single([2; 10])]
+        );
+        assert!(entries.iter().all(|entry| entry.owner_id.is_none()));
+    }
+}
@@ -0,0 +1,67 @@
+/// Version 0
+pub mod v0;
+
+use crate::rejections::record::v0::{
+    RejectedStateTransitionFeeEstimate, RejectedStateTransitionRecordV0,
+};
+use bincode::{Decode, Encode};
+use derive_more::From;
+use dpp::prelude::BlockHeight;
+
+/// Archived rejected state transition
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, From)]
+pub enum RejectedStateTransitionRecord {
+    /// Version 0
+    V0(RejectedStateTransitionRecordV0),
+}
+
+impl RejectedStateTransitionRecord {
+    /// Serialized state transition
+    pub fn state_transition(&self) -> &[u8] {
+        match self {
+            RejectedStateTransitionRecord::V0(v0) => &v0.state_transition,
+        }
+    }
+
+    /// Height of the block the state transition was proposed in
+    pub fn block_height(&self) -> BlockHeight {
+        match self {
+            RejectedStateTransitionRecord::V0(v0) => v0.block_height,
+        }
+    }
+
+    /// Round of the block proposal
+    pub fn round(&self) -> u32 {
+        match self {
+            RejectedStateTransitionRecord::V0(v0) => v0.round,
+        }
+    }
+
+    /// Consensus error code
+    pub fn error_code(&self) -> u32 {
+        match self {
+            RejectedStateTransitionRecord::V0(v0) => v0.error_code,
+        }
+    }
+
+    /// Human readable consensus error
+    pub fn error_message(&self) -> &str {
+        match self {
+            RejectedStateTransitionRecord::V0(v0) => &v0.error_message,
+        }
+    }
+
+    /// Identity that owns the state transition
+    pub fn owner_id(&self) -> Option<[u8; 32]> {
+        match self {
+            RejectedStateTransitionRecord::V0(v0) => v0.owner_id,
+        }
+    }
+
+    /// Fees charged for the failed execution
+    pub fn fee_estimate(&self) -> Option<RejectedStateTransitionFeeEstimate> {
+        match self {
+            RejectedStateTransitionRecord::V0(v0) => v0.fee_estimate,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use bincode::{Decode, Encode};
+use dpp::fee::Credits;
+use dpp::prelude::{BlockHeight, TimestampMillis};
+
+/// Fees calculated for a rejected state transition
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct RejectedStateTransitionFeeEstimate {
+    /// Storage fee
+    pub storage_fee: Credits,
+    /// Processing fee
+    pub processing_fee: Credits,
+}
+
+/// Rejected state transition record, version 0
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct RejectedStateTransitionRecordV0 {
+    /// Serialized state transition, as received in the block proposal
+    pub state_transition: Vec<u8>,
+    /// Height of the block the state transition was proposed in
+    pub block_height: BlockHeight,
+    /// Round of the block proposal
+    pub round: u32,
+    /// Protocol version used to execute the state transition
+    pub protocol_version: u32,
+    /// Local time the state transition was rejected at
+    pub rejected_at: TimestampMillis,
+    /// Consensus error code
+    pub error_code: u32,
+    /// Human readable consensus error
+    pub error_message: String,
+    /// Consensus error serialized with the protocol version the state transition was executed with
+    pub consensus_error: Vec<u8>,
+    /// Identity that owns the state transition, if the state transition could be deserialized
+    pub owner_id: Option<[u8; 32]>,
+    /// Fees charged for the failed execution, if the owner paid for it
+    pub fee_estimate: Option<RejectedStateTransitionFeeEstimate>,
+}
@@ -0,0 +1,703 @@
+//! Deterministic replay of recorded block execution requests.
+//!
+//! A recording is a sequence of `RequestPrepareProposal`, `RequestProcessProposal` and
+//! `RequestFinalizeBlock` messages, as received from Tenderdash. Replaying a recording on top of
+//! the GroveDB snapshot it was captured from re-executes every block through the same ABCI
+//! handlers the consensus server uses, and compares the app hash computed for each block
+//! with the one committed in the finalized block header.
+//!
+//! ## Recording format
+//!
+//! The file starts with the [RECORDING_MAGIC] bytes followed by a single [RECORDING_VERSION] byte.
+//! Each record consists of one byte identifying the request kind, followed by the protobuf encoded,
+//! length-delimited request.
+//!
+//! ## Recording
+//!
+//! The consensus server records requests with [RequestRecorder] when
+//! [AbciConfig::request_recording_dir] is set.
+//!
+//! [AbciConfig::request_recording_dir]: crate::abci::config::AbciConfig::request_recording_dir
+
+use crate::abci::app::ConsensusAbciApplication;
+use crate::abci::handler;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::{CoreHeight, CoreRPCLike};
+use dpp::dashcore::ephemerealdata::chain_lock::ChainLock;
+use dpp::dashcore::hashes::Hash;
+use dpp::dashcore::{Block, BlockHash, Header, InstantLock, QuorumHash, Transaction, Txid};
+use dpp::dashcore_rpc::dashcore_rpc_json::{
+    AssetUnlockStatusResult, ExtendedQuorumListResult, GetChainTipsResult, MasternodeListDiff,
+    MnSyncStatus, QuorumInfoResult, QuorumType, SoftforkInfo,
+};
+use dpp::dashcore_rpc::json::GetRawTransactionResult;
+use dpp::dashcore_rpc::Error as CoreRPCError;
+use dpp::prelude::TimestampMillis;
+use prost::Message;
+use serde_json::Value;
+use std::fmt::{Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use tenderdash_abci::proto::abci as proto;
+use tenderdash_abci::proto::abci::response_process_proposal::ProposalStatus;
+
+/// Bytes every recording file starts with
+pub const RECORDING_MAGIC: &[u8; 4] = b"DABR";
+
+/// Version of the recording format
+pub const RECORDING_VERSION: u8 = 0;
+
+const PREPARE_PROPOSAL_KIND: u8 = 1;
+const PROCESS_PROPOSAL_KIND: u8 = 2;
+const FINALIZE_BLOCK_KIND: u8 = 3;
+
+/// Replay errors
+#[derive(Debug, thiserror::Error)]
+pub enum ReplayError {
+    /// Recording can't be read or written
+    #[error("recording io: {0}")]
+    Io(#[from] std::io::Error),
+    /// Request in the recording can't be decoded
+    #[error("cannot decode recorded request: {0}")]
+    Decode(#[from] prost::DecodeError),
+    /// Recording is not in the expected format
+    #[error("invalid recording: {0}")]
+    InvalidRecording(String),
+    /// Finalize block request was recorded without a preceding proposal for the same height
+    #[error("finalize block at height {height} has no preceding proposal in the recording")]
+    MissingProposal {
+        /// Height of the finalized block
+        height: u64,
+    },
+    /// Replayed proposal was rejected
+    #[error("proposal at height {height}, round {round} was rejected")]
+    ProposalRejected {
+        /// Height of the proposal
+        height: u64,
+        /// Round of the proposal
+        round: u32,
+    },
+    /// ABCI handler failed
+    #[error("block execution at height {height} failed: {source}")]
+    Execution {
+        /// Height of the block
+        height: u64,
+        /// Handler error
+        source: Error,
+    },
+}
+
+/// A single recorded ABCI request
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecordedRequest {
+    /// Prepare proposal request
+    PrepareProposal(proto::RequestPrepareProposal),
+    /// Process proposal request
+    ProcessProposal(proto::RequestProcessProposal),
+    /// Finalize block request
+    FinalizeBlock(proto::RequestFinalizeBlock),
+}
+
+impl RecordedRequest {
+    /// Height of the block the request belongs to
+    pub fn height(&self) -> u64 {
+        match self {
+            RecordedRequest::PrepareProposal(request) => request.height as u64,
+            RecordedRequest::ProcessProposal(request) => request.height as u64,
+            RecordedRequest::FinalizeBlock(request) => request.height as u64,
+        }
+    }
+}
+
+/// Writes ABCI requests into a recording
+pub struct RecordingWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> RecordingWriter<W> {
+    /// Create new recording writer and write the recording header
+    pub fn new(mut inner: W) -> Result<Self, ReplayError> {
+        inner.write_all(RECORDING_MAGIC)?;
+        inner.write_all(&[RECORDING_VERSION])?;
+
+        Ok(Self { inner })
+    }
+
+    /// Append a request to the recording
+    pub fn write(&mut self, request: &RecordedRequest) -> Result<(), ReplayError> {
+        let (kind, encoded) = match request {
+            RecordedRequest::PrepareProposal(request) => (
+                PREPARE_PROPOSAL_KIND,
+                request.encode_length_delimited_to_vec(),
+            ),
+            RecordedRequest::ProcessProposal(request) => (
+                PROCESS_PROPOSAL_KIND,
+                request.encode_length_delimited_to_vec(),
+            ),
+            RecordedRequest::FinalizeBlock(request) => (
+                FINALIZE_BLOCK_KIND,
+                request.encode_length_delimited_to_vec(),
+            ),
+        };
+
+        self.inner.write_all(&[kind])?;
+        self.inner.write_all(&encoded)?;
+
+        Ok(())
+    }
+
+    /// Flush and return the underlying writer
+    pub fn finish(mut self) -> Result<W, ReplayError> {
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+}
+
+/// Records consensus requests into a recording in a background thread.
+///
+/// Recording is a debugging aid, so it never blocks or interrupts block execution:
+/// the recording is written by a dedicated thread and stops with a warning
+/// if it can't be written.
+///
+/// A new recording file named after the height of the first recorded request is created in
+/// the recording directory every time the recorder is started, so the recording can be replayed
+/// on top of a snapshot taken at that height.
+pub struct RequestRecorder {
+    sender: Option<mpsc::Sender<RecordedRequest>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl RequestRecorder {
+    /// Start recording requests into the given directory
+    pub fn start(dir: impl Into<PathBuf>) -> Result<Self, ReplayError> {
+        let dir = dir.into();
+
+        let (sender, receiver) = mpsc::channel::<RecordedRequest>();
+
+        let worker = std::thread::Builder::new()
+            .name("request-recorder".to_string())
+            .spawn(move || {
+                if let Err(e) = write_recording(&dir, receiver) {
+                    tracing::warn!(
+                        error = %e,
+                        dir = dir.display().to_string(),
+                        "consensus request recording stopped"
+                    );
+                }
+            })?;
+
+        Ok(Self {
+            sender: Some(sender),
+            worker: Some(worker),
+        })
+    }
+
+    /// Queue a request to be recorded
+    pub fn record(&self, request: RecordedRequest) {
+        if let Some(sender) = &self.sender {
+            // The worker is gone only if the recording failed, which was already logged
+            let _ = sender.send(request);
+        }
+    }
+}
+
+impl Drop for RequestRecorder {
+    fn drop(&mut self) {
+        // Closing the channel lets the worker write queued requests and finish the recording
+        self.sender.take();
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// Write requests received from the channel into a new recording file
+fn write_recording(
+    dir: &Path,
+    receiver: mpsc::Receiver<RecordedRequest>,
+) -> Result<(), ReplayError> {
+    let Ok(first_request) = receiver.recv() else {
+        return Ok(());
+    };
+
+    fs::create_dir_all(dir)?;
+
+    let path = recording_file_path(dir, first_request.height());
+
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)?;
+
+    tracing::info!(
+        path = path.display().to_string(),
+        "recording consensus requests"
+    );
+
+    let mut writer = RecordingWriter::new(BufWriter::new(file))?;
+
+    writer.write(&first_request)?;
+
+    for request in receiver {
+        let finalized = matches!(request, RecordedRequest::FinalizeBlock(_));
+
+        writer.write(&request)?;
+
+        // Make every finalized block durable, so a crashed node leaves a replayable recording
+        if finalized {
+            writer.inner.flush()?;
+        }
+    }
+
+    writer
+        .finish()?
+        .into_inner()
+        .map_err(|e| e.into_error())?
+        .sync_all()?;
+
+    Ok(())
+}
+
+/// Path of a new recording starting at the given height
+fn recording_file_path(dir: &Path, height: u64) -> PathBuf {
+    let path = dir.join(format!("requests-from-{height}.dabr"));
+
+    if !path.exists() {
+        return path;
+    }
+
+    // The node was restarted at the same height; keep the previous recording
+    (1..)
+        .map(|n| dir.join(format!("requests-from-{height}.{n}.dabr")))
+        .find(|path| !path.exists())
+        .expect("expected a free recording file name")
+}
+
+/// Decode all requests from a recording
+pub fn decode_recording(mut bytes: &[u8]) -> Result<Vec<RecordedRequest>, ReplayError> {
+    if bytes.len() <= RECORDING_MAGIC.len() || !bytes.starts_with(RECORDING_MAGIC) {
+        return Err(ReplayError::InvalidRecording(
+            "missing recording header".to_string(),
+        ));
+    }
+
+    let version = bytes[RECORDING_MAGIC.len()];
+    if version != RECORDING_VERSION {
+        return Err(ReplayError::InvalidRecording(format!(
+            "unsupported recording version {version}, expected {RECORDING_VERSION}"
+        )));
+    }
+
+    bytes = &bytes[RECORDING_MAGIC.len() + 1..];
+
+    let mut requests = Vec::new();
+
+    while let Some((&kind, rest)) = bytes.split_first() {
+        bytes = rest;
+
+        let request = match kind {
+            PREPARE_PROPOSAL_KIND => RecordedRequest::PrepareProposal(
+                proto::RequestPrepareProposal::decode_length_delimited(&mut bytes)?,
+            ),
+            PROCESS_PROPOSAL_KIND => RecordedRequest::ProcessProposal(
+                proto::RequestProcessProposal::decode_length_delimited(&mut bytes)?,
+            ),
+            FINALIZE_BLOCK_KIND => RecordedRequest::FinalizeBlock(
+                proto::RequestFinalizeBlock::decode_length_delimited(&mut bytes)?,
+            ),
+            kind => {
+                return Err(ReplayError::InvalidRecording(format!(
+                    "unknown request kind {kind}"
+                )))
+            }
+        };
+
+        requests.push(request);
+    }
+
+    Ok(requests)
+}
+
+/// Read and decode all requests from a recording file
+pub fn read_recording_file(path: &Path) -> Result<Vec<RecordedRequest>, ReplayError> {
+    let bytes = std::fs::read(path)?;
+
+    decode_recording(&bytes)
+}
+
+/// Outcome of a single replayed block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockReplayReport {
+    /// Block height
+    pub height: u64,
+    /// Round in which the block was finalized
+    pub round: u32,
+    /// App hash committed in the recorded block header
+    pub expected_app_hash: Vec<u8>,
+    /// App hash computed during replay
+    pub computed_app_hash: Vec<u8>,
+}
+
+impl BlockReplayReport {
+    /// Whether the computed app hash differs from the recorded one
+    pub fn is_divergent(&self) -> bool {
+        self.expected_app_hash != self.computed_app_hash
+    }
+}
+
+impl Display for BlockReplayReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_divergent() {
+            write!(
+                f,
+                "height {} round {}: app hash diverged, expected {} computed {}",
+                self.height,
+                self.round,
+                hex::encode(&self.expected_app_hash),
+                hex::encode(&self.computed_app_hash)
+            )
+        } else {
+            write!(
+                f,
+                "height {} round {}: app hash {} matches",
+                self.height,
+                self.round,
+                hex::encode(&self.computed_app_hash)
+            )
+        }
+    }
+}
+
+/// Re-execute recorded requests against the platform.
+///
+/// Requests must start right after the last block committed in the platform state.
+/// Returns reports for all finalized blocks. If `stop_on_divergence` is set, replay stops after
+/// the first block whose app hash differs from the recorded one.
+pub fn replay_requests<C, I>(
+    platform: &Platform<C>,
+    requests: I,
+    stop_on_divergence: bool,
+) -> Result<Vec<BlockReplayReport>, ReplayError>
+where
+    C: CoreRPCLike,
+    I: IntoIterator<Item = RecordedRequest>,
+{
+    let app = ConsensusAbciApplication::new(platform);
+
+    let mut reports = Vec::new();
+    // Height and app hash computed by the last replayed proposal
+    let mut computed: Option<(u64, Vec<u8>)> = None;
+
+    for request in requests {
+        let height = request.height();
+
+        match request {
+            RecordedRequest::PrepareProposal(request) => {
+                let response = handler::prepare_proposal(&app, request)
+                    .map_err(|source| ReplayError::Execution { height, source })?;
+
+                computed = Some((height, response.app_hash));
+            }
+            RecordedRequest::ProcessProposal(request) => {
+                let round = request.round as u32;
+
+                let response = handler::process_proposal(&app, request)
+                    .map_err(|source| ReplayError::Execution { height, source })?;
+
+                if response.status() != ProposalStatus::Accept {
+                    return Err(ReplayError::ProposalRejected { height, round });
+                }
+
+                computed = Some((height, response.app_hash));
+            }
+            RecordedRequest::FinalizeBlock(request) => {
+                let round = request.round as u32;
+
+                let computed_app_hash = match computed.take() {
+                    Some((computed_height, app_hash)) if computed_height == height => app_hash,
+                    _ => return Err(ReplayError::MissingProposal { height }),
+                };
+
+                let expected_app_hash = request
+                    .block
+                    .as_ref()
+                    .and_then(|block| block.header.as_ref())
+                    .map(|header| header.app_hash.clone())
+                    .unwrap_or_default();
+
+                handler::finalize_block(&app, request)
+                    .map_err(|source| ReplayError::Execution { height, source })?;
+
+                let report = BlockReplayReport {
+                    height,
+                    round,
+                    expected_app_hash,
+                    computed_app_hash,
+                };
+
+                let diverged = report.is_divergent();
+
+                reports.push(report);
+
+                if diverged && stop_on_divergence {
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(reports)
+}
+
+/// Core RPC used during replay.
+///
+/// Reads are served by the wrapped Core RPC, but transactions are never broadcast,
+/// so replaying blocks that sign withdrawals doesn't send them to the network again.
+#[derive(Debug)]
+pub struct ReplayCoreRPC<C> {
+    inner: C,
+}
+
+impl<C> ReplayCoreRPC<C> {
+    /// Wrap Core RPC used to serve reads during replay
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+}
+
+impl<C: CoreRPCLike> CoreRPCLike for ReplayCoreRPC<C> {
+    fn get_block_hash(&self, height: CoreHeight) -> Result<BlockHash, CoreRPCError> {
+        self.inner.get_block_hash(height)
+    }
+
+    fn get_block_header(&self, block_hash: &BlockHash) -> Result<Header, CoreRPCError> {
+        self.inner.get_block_header(block_hash)
+    }
+
+    fn get_block_time_from_height(
+        &self,
+        height: CoreHeight,
+    ) -> Result<TimestampMillis, CoreRPCError> {
+        self.inner.get_block_time_from_height(height)
+    }
+
+    fn get_best_chain_lock(&self) -> Result<ChainLock, CoreRPCError> {
+        self.inner.get_best_chain_lock()
+    }
+
+    fn submit_chain_lock(&self, chain_lock: &ChainLock) -> Result<u32, CoreRPCError> {
+        self.inner.submit_chain_lock(chain_lock)
+    }
+
+    fn get_transaction(&self, tx_id: &Txid) -> Result<Transaction, CoreRPCError> {
+        self.inner.get_transaction(tx_id)
+    }
+
+    fn get_asset_unlock_statuses(
+        &self,
+        indices: &[u64],
+        core_chain_locked_height: u32,
+    ) -> Result<Vec<AssetUnlockStatusResult>, CoreRPCError> {
+        self.inner
+            .get_asset_unlock_statuses(indices, core_chain_locked_height)
+    }
+
+    fn get_transaction_extended_info(
+        &self,
+        tx_id: &Txid,
+    ) -> Result<GetRawTransactionResult, CoreRPCError> {
+        self.inner.get_transaction_extended_info(tx_id)
+    }
+
+    fn get_fork_info(&self, name: &str) -> Result<Option<SoftforkInfo>, CoreRPCError> {
+        self.inner.get_fork_info(name)
+    }
+
+    fn get_block(&self, block_hash: &BlockHash) -> Result<Block, CoreRPCError> {
+        self.inner.get_block(block_hash)
+    }
+
+    fn get_block_json(&self, block_hash: &BlockHash) -> Result<Value, CoreRPCError> {
+        self.inner.get_block_json(block_hash)
+    }
+
+    fn get_chain_tips(&self) -> Result<GetChainTipsResult, CoreRPCError> {
+        self.inner.get_chain_tips()
+    }
+
+    fn get_quorum_listextended(
+        &self,
+        height: Option<CoreHeight>,
+    ) -> Result<ExtendedQuorumListResult, CoreRPCError> {
+        self.inner.get_quorum_listextended(height)
+    }
+
+    fn get_quorum_info(
+        &self,
+        quorum_type: QuorumType,
+        hash: &QuorumHash,
+        include_secret_key_share: Option<bool>,
+    ) -> Result<QuorumInfoResult, CoreRPCError> {
+        self.inner
+            .get_quorum_info(quorum_type, hash, include_secret_key_share)
+    }
+
+    fn get_protx_diff_with_masternodes(
+        &self,
+        base_block: Option<u32>,
+        block: u32,
+    ) -> Result<MasternodeListDiff, CoreRPCError> {
+        self.inner
+            .get_protx_diff_with_masternodes(base_block, block)
+    }
+
+    fn verify_instant_lock(
+        &self,
+        instant_lock: &InstantLock,
+        max_height: Option<u32>,
+    ) -> Result<bool, CoreRPCError> {
+        self.inner.verify_instant_lock(instant_lock, max_height)
+    }
+
+    fn verify_chain_lock(&self, chain_lock: &ChainLock) -> Result<bool, CoreRPCError> {
+        self.inner.verify_chain_lock(chain_lock)
+    }
+
+    fn masternode_sync_status(&self) -> Result<MnSyncStatus, CoreRPCError> {
+        self.inner.masternode_sync_status()
+    }
+
+    fn send_raw_transaction(&self, transaction: &[u8]) -> Result<Txid, CoreRPCError> {
+        let tx_id = Txid::hash(transaction);
+
+        tracing::debug!(
+            tx_id = tx_id.to_string(),
+            "transaction is not broadcast during replay"
+        );
+
+        Ok(tx_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+
+    #[test]
+    fn recording_should_roundtrip() {
+        let requests = vec![
+            RecordedRequest::PrepareProposal(proto::RequestPrepareProposal {
+                height: 5,
+                round: 1,
+                txs: vec![vec![1, 2, 3]],
+                ..Default::default()
+            }),
+            RecordedRequest::ProcessProposal(proto::RequestProcessProposal {
+                height: 5,
+                round: 1,
+                txs: vec![vec![1, 2, 3]],
+                ..Default::default()
+            }),
+            RecordedRequest::FinalizeBlock(proto::RequestFinalizeBlock {
+                height: 5,
+                round: 1,
+                ..Default::default()
+            }),
+        ];
+
+        let mut writer = RecordingWriter::new(Vec::new()).expect("expected to write header");
+        for request in &requests {
+            writer.write(request).expect("expected to write request");
+        }
+        let bytes = writer.finish().expect("expected to finish recording");
+
+        let decoded = decode_recording(&bytes).expect("expected to decode recording");
+
+        assert_eq!(decoded, requests);
+        assert!(decoded.iter().all(|request| request.height() == 5));
+    }
+
+    #[test]
+    fn recording_without_header_should_be_rejected() {
+        assert_matches!(
+            decode_recording(&[PREPARE_PROPOSAL_KIND, 0]),
+            Err(ReplayError::InvalidRecording(_))
+        );
+    }
+
+    #[test]
+    fn recording_with_unknown_request_kind_should_be_rejected() {
+        let mut bytes = RecordingWriter::new(Vec::new())
+            .expect("expected to write header")
+            .finish()
+            .expect("expected to finish recording");
+        bytes.push(42);
+
+        assert_matches!(
+            decode_recording(&bytes),
+            Err(ReplayError::InvalidRecording(_))
+        );
+    }
+
+    #[test]
+    fn divergence_should_be_reported() {
+        let report = BlockReplayReport {
+            height: 1,
+            round: 0,
+            expected_app_hash: vec![1; 32],
+            computed_app_hash: vec![2; 32],
+        };
+
+        assert!(report.is_divergent());
+        assert!(report.to_string().contains("diverged"));
+    }
+
+    #[test]
+    fn recorder_should_write_replayable_recording() {
+        let dir = tempfile::tempdir().expect("expected to create temp dir");
+
+        let requests = vec![
+            RecordedRequest::PrepareProposal(proto::RequestPrepareProposal {
+                height: 7,
+                ..Default::default()
+            }),
+            RecordedRequest::FinalizeBlock(proto::RequestFinalizeBlock {
+                height: 7,
+                ..Default::default()
+            }),
+        ];
+
+        for _ in 0..2 {
+            let recorder = RequestRecorder::start(dir.path()).expect("expected to start recorder");
+            for request in &requests {
+                recorder.record(request.clone());
+            }
+        }
+
+        for file_name in ["requests-from-7.dabr", "requests-from-7.1.dabr"] {
+            let recorded = read_recording_file(&dir.path().join(file_name))
+                .expect("expected to read recording");
+
+            assert_eq!(recorded, requests);
+        }
+    }
+
+    #[test]
+    fn replay_core_rpc_should_not_broadcast_transactions() {
+        let core_rpc = ReplayCoreRPC::new(crate::rpc::core::MockCoreRPCLike::new());
+
+        assert_eq!(
+            core_rpc
+                .send_raw_transaction(&[1, 2, 3])
+                .expect("expected transaction to be accepted"),
+            Txid::hash(&[1, 2, 3])
+        );
+    }
+}
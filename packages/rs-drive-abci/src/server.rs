@@ -6,6 +6,7 @@ use crate::abci::app::ConsensusAbciApplication;
 use crate::config::PlatformConfig;
 use crate::platform_types::platform::Platform;
 use crate::query::QueryService;
+use crate::replay::RequestRecorder;
 use crate::rpc::core::DefaultCoreRPC;
 use std::sync::Arc;
 use tokio::runtime::Runtime;
@@ -70,7 +71,14 @@ pub fn start(
 
     // Start blocking ABCI socket-server that process consensus requests sequentially
 
-    let app = ConsensusAbciApplication::new(platform.as_ref());
+    let mut app = ConsensusAbciApplication::new(platform.as_ref());
+
+    if let Some(recording_dir) = &config.abci.request_recording_dir {
+        let recorder =
+            RequestRecorder::start(recording_dir).expect("failed to start request recorder");
+
+        app = app.with_request_recorder(recorder);
+    }
 
     let server = tenderdash_abci::ServerBuilder::new(app, &config.abci.consensus_bind_address)
         .with_cancel_token(cancel.clone())
@@ -108,6 +108,8 @@ pub(crate) fn process_epoch_change(
         unsigned_withdrawal_transactions: UnsignedWithdrawalTxs::default(),
         block_platform_state: platform_state,
         proposer_results: None,
+        executed_batch_transitions: vec![],
+        executed_state_transitions: vec![],
     }
     .into();
 
@@ -645,7 +645,14 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                             ResolvedVotePoll::ContestedDocumentResourceVotePollWithContractInfo(
                                 contested_document_resource_vote_poll,
                             ) => contested_document_resource_vote_poll.contract.as_ref(),
+                            ResolvedVotePoll::YesNoAbstainVotePoll(_) => {
+                                panic!(
+                                    "resource votes can not be cast on yes/no/abstain vote polls"
+                                )
+                            }
                         },
+                        // Strategies don't cast yes/no/abstain votes
+                        ResolvedVote::ResolvedYesNoAbstainVote(_) => continue,
                     };
 
                     let vote: Vote = masternode_vote_action.vote_ref().clone().into();
@@ -674,6 +681,25 @@ pub(crate) fn verify_state_transitions_were_or_were_not_executed(
                         assert_eq!(&executed_vote, &vote);
                     }
                 }
+                StateTransitionAction::DataContractDeleteAction(data_contract_delete) => {
+                    // we expect the contract to be absent once the transition was executed
+                    let (root_hash, contract) = Drive::verify_contract(
+                        &response_proof.grovedb_proof,
+                        None,
+                        false,
+                        true,
+                        data_contract_delete.data_contract_ref().id().into_buffer(),
+                        platform_version,
+                    )
+                    .expect("expected to verify contract absence");
+                    assert_eq!(&root_hash, expected_root_hash);
+
+                    if *was_executed {
+                        assert!(contract.is_none());
+                    }
+                }
+                // Strategies don't create yes/no/abstain vote polls
+                StateTransitionAction::VotePollCreateAction(_) => {}
                 StateTransitionAction::BumpIdentityNonceAction(_) => {}
                 StateTransitionAction::BumpIdentityDataContractNonceAction(_) => {}
                 StateTransitionAction::PartiallyUseAssetLockAction(_) => {}
@@ -1,11 +1,18 @@
+pub mod document_aggregate;
 pub mod groups;
+pub mod identity_state_transitions;
 pub mod identity_token_balance;
+pub mod token_allowances;
 pub mod token_contract_info;
 pub mod token_direct_purchase;
+pub mod token_events;
 pub mod token_info;
+pub mod token_order_book;
 pub mod token_perpetual_distribution_last_claim;
 pub mod token_status;
 pub mod token_total_supply;
+pub mod token_vestings;
+pub mod yes_no_abstain_vote_poll_state;
 
 use crate::from_request::TryFromRequest;
 use crate::verify::verify_tenderdash_proof;
@@ -29,6 +36,7 @@ use dpp::block::block_info::BlockInfo;
 use dpp::block::epoch::{EpochIndex, MAX_EPOCH};
 use dpp::block::extended_epoch_info::ExtendedEpochInfo;
 use dpp::core_subsidy::NetworkCoreSubsidy;
+use dpp::data_contract::document_type::accessors::DocumentTypeV1Getters;
 use dpp::dashcore::hashes::Hash;
 use dpp::dashcore::{Network, ProTxHash};
 use dpp::document::{Document, DocumentV0Getters};
@@ -1445,8 +1453,18 @@ where
             .verify_proof(&proof.grovedb_proof, platform_version)
             .map_drive_error(proof, mtd)?;
 
+        // Documents whose time to live was reached stay in the state until a block end removes
+        // them, they are left out like the platform does for queries without proofs
+        let time_to_live = request.document_type.documents_time_to_live();
+
         let documents = documents
             .into_iter()
+            .filter(|d| {
+                time_to_live.is_none_or(|time_to_live| {
+                    !time_to_live.is_expired_at_time(d, mtd.time_ms)
+                        && !time_to_live.is_expired_at_height(d, mtd.height)
+                })
+            })
             .map(|d| (d.id(), Some(d)))
             .collect::<Documents>();
 
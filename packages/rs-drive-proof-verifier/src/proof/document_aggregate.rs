@@ -0,0 +1,62 @@
+use crate::error::MapGroveDbError;
+use crate::types::document_aggregate::DocumentsAggregate;
+use crate::verify::verify_tenderdash_proof;
+use crate::{ContextProvider, Error, FromProof};
+use dapi_grpc::platform::v0::{GetDocumentsAggregateResponse, Proof, ResponseMetadata};
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::dashcore::Network;
+use dpp::version::PlatformVersion;
+use drive::query::DriveDocumentAggregateQuery;
+
+/// Verifies a single proved range of documents.
+///
+/// The request must convert into an aggregate query whose document query already
+/// describes the proved range, that is, starts after the last document of the previous
+/// range and has the limit sent to the node.
+impl<'dq, Q> FromProof<Q> for DocumentsAggregate
+where
+    Q: TryInto<DriveDocumentAggregateQuery<'dq>> + Clone + 'dq,
+    Q::Error: std::fmt::Display,
+{
+    type Request = Q;
+    type Response = GetDocumentsAggregateResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let request: DriveDocumentAggregateQuery<'dq> =
+            request
+                .clone()
+                .try_into()
+                .map_err(|e: Q::Error| Error::RequestError {
+                    error: e.to_string(),
+                })?;
+
+        let limit = request.query.limit.ok_or(Error::RequestError {
+            error: "limit must be set for proved aggregate queries".to_string(),
+        })?;
+
+        // Parse response to read proof and metadata
+        let proof = response.proof().or(Err(Error::NoProofInResult))?;
+
+        let mtd = response.metadata().or(Err(Error::EmptyResponseMetadata))?;
+
+        let (root_hash, outcome) = request
+            .verify_aggregate_proof(&proof.grovedb_proof, None, limit, platform_version)
+            .map_drive_error(proof, mtd)?;
+
+        verify_tenderdash_proof(proof, mtd, &root_hash, provider)?;
+
+        Ok((Some(outcome.into()), mtd.clone(), proof.clone()))
+    }
+}
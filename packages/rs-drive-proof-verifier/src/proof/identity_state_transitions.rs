@@ -0,0 +1,94 @@
+use crate::error::MapGroveDbError;
+use crate::types::identity_state_transitions::IdentityStateTransitions;
+use crate::verify::verify_tenderdash_proof;
+use crate::{ContextProvider, Error, FromProof};
+use dapi_grpc::platform::v0::{
+    get_identity_state_transitions_request, GetIdentityStateTransitionsRequest,
+    GetIdentityStateTransitionsResponse, Proof, ResponseMetadata,
+};
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::dashcore::Network;
+use dpp::identity::state_transition_record::v0::IdentityStateTransitionRecordV0Getters;
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::version::PlatformVersion;
+use drive::config::DEFAULT_QUERY_LIMIT;
+use drive::drive::Drive;
+
+impl FromProof<GetIdentityStateTransitionsRequest> for IdentityStateTransitions {
+    type Request = GetIdentityStateTransitionsRequest;
+    type Response = GetIdentityStateTransitionsResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let (identity_id, start_after, limit, order_ascending) =
+            match request.version.ok_or(Error::EmptyVersion)? {
+                get_identity_state_transitions_request::Version::V0(v0) => {
+                    let identity_id =
+                        <[u8; 32]>::try_from(v0.identity_id.as_slice()).map_err(|error| {
+                            Error::RequestError {
+                                error: error.to_string(),
+                            }
+                        })?;
+
+                    let start_after = v0
+                        .start_after
+                        .map(|start_after| (start_after.block_height, start_after.index_in_block));
+
+                    // Platform applies its default limit when none is requested
+                    let limit = v0
+                        .count
+                        .map(|count| count as u16)
+                        .unwrap_or(DEFAULT_QUERY_LIMIT);
+
+                    (identity_id, start_after, limit, v0.order_ascending)
+                }
+            };
+
+        let metadata = response
+            .metadata()
+            .or(Err(Error::EmptyResponseMetadata))?
+            .clone();
+
+        let proof = response.proof_owned().or(Err(Error::NoProofInResult))?;
+
+        let (root_hash, result) = Drive::verify_identity_state_transition_records(
+            &proof.grovedb_proof,
+            identity_id,
+            start_after,
+            Some(limit),
+            order_ascending,
+            false,
+            platform_version,
+        )
+        .map(
+            |(root_hash, records): (_, Vec<IdentityStateTransitionRecord>)| {
+                let state_transitions = records
+                    .into_iter()
+                    .map(|record| {
+                        (
+                            (record.block_height(), record.index_in_block()),
+                            Some(record),
+                        )
+                    })
+                    .collect::<IdentityStateTransitions>();
+                (root_hash, state_transitions)
+            },
+        )
+        .map_drive_error(&proof, &metadata)?;
+
+        verify_tenderdash_proof(&proof, &metadata, &root_hash, provider)?;
+
+        Ok((Some(result), metadata, proof))
+    }
+}
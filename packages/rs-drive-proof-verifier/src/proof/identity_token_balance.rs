@@ -1,16 +1,24 @@
 use crate::error::MapGroveDbError;
-use crate::types::identity_token_balance::{IdentitiesTokenBalances, IdentityTokenBalances};
+use crate::types::identity_token_balance::{
+    IdentitiesTokenBalances, IdentityTokenBalances, TokenHolders,
+};
 use crate::verify::verify_tenderdash_proof;
 use crate::{ContextProvider, Error, FromProof};
 use dapi_grpc::platform::v0::{
     get_identities_token_balances_request, get_identity_token_balances_request,
-    GetIdentitiesTokenBalancesRequest, GetIdentitiesTokenBalancesResponse,
-    GetIdentityTokenBalancesRequest, GetIdentityTokenBalancesResponse, Proof, ResponseMetadata,
+    get_token_holders_request, GetIdentitiesTokenBalancesRequest,
+    GetIdentitiesTokenBalancesResponse, GetIdentityTokenBalancesRequest,
+    GetIdentityTokenBalancesResponse, GetTokenHoldersRequest, GetTokenHoldersResponse, Proof,
+    ResponseMetadata,
 };
 use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::balances::credits::TokenAmount;
 use dpp::dashcore::Network;
+use dpp::identifier::Identifier;
 use dpp::version::PlatformVersion;
+use drive::config::DEFAULT_QUERY_LIMIT;
 use drive::drive::Drive;
+use indexmap::IndexMap;
 
 impl FromProof<GetIdentityTokenBalancesRequest> for IdentityTokenBalances {
     type Request = GetIdentityTokenBalancesRequest;
@@ -130,3 +138,89 @@ impl FromProof<GetIdentitiesTokenBalancesRequest> for IdentitiesTokenBalances {
         Ok((Some(result), metadata, proof))
     }
 }
+
+impl FromProof<GetTokenHoldersRequest> for TokenHolders {
+    type Request = GetTokenHoldersRequest;
+    type Response = GetTokenHoldersResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let (token_id, start_at, limit, order_by_balance) = match request
+            .version
+            .ok_or(Error::EmptyVersion)?
+        {
+            get_token_holders_request::Version::V0(v0) => {
+                let token_id = <[u8; 32]>::try_from(v0.token_id.as_slice()).map_err(|error| {
+                    Error::RequestError {
+                        error: error.to_string(),
+                    }
+                })?;
+
+                let start_at = v0
+                    .start_at_identity_id
+                    .map(|start_at| {
+                        <[u8; 32]>::try_from(start_at.start_identity_id.as_slice())
+                            .map(|identity_id| (identity_id, start_at.start_identity_id_included))
+                            .map_err(|_| Error::RequestError {
+                                error: "can't convert start_identity_id to [u8; 32]".to_string(),
+                            })
+                    })
+                    .transpose()?;
+
+                // Platform applies its default limit when none is requested
+                let limit = v0
+                    .count
+                    .map(|count| count as u16)
+                    .unwrap_or(DEFAULT_QUERY_LIMIT);
+
+                (token_id, start_at, limit, v0.order_by_balance)
+            }
+        };
+
+        let metadata = response
+            .metadata()
+            .or(Err(Error::EmptyResponseMetadata))?
+            .clone();
+
+        let proof = response.proof_owned().or(Err(Error::NoProofInResult))?;
+
+        let (root_hash, result) = Drive::verify_token_holders(
+            &proof.grovedb_proof,
+            token_id,
+            start_at,
+            limit,
+            false,
+            platform_version,
+        )
+        // Make value optional
+        .map(
+            |(root_hash, mut result): (_, IndexMap<Identifier, TokenAmount>)| {
+                if order_by_balance {
+                    // Stable, so holders with equal balances stay in identity id order
+                    result.sort_by(|_, a, _, b| b.cmp(a));
+                }
+                let optional_value_map = result
+                    .into_iter()
+                    .map(|(identity_id, balance)| (identity_id, Some(balance)))
+                    .collect::<TokenHolders>();
+                (root_hash, optional_value_map)
+            },
+        )
+        .map_drive_error(&proof, &metadata)?;
+
+        verify_tenderdash_proof(&proof, &metadata, &root_hash, provider)?;
+
+        Ok((Some(result), metadata, proof))
+    }
+}
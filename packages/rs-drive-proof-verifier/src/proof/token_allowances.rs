@@ -0,0 +1,119 @@
+use crate::error::MapGroveDbError;
+use crate::types::token_allowances::TokenAllowances;
+use crate::verify::verify_tenderdash_proof;
+use crate::{ContextProvider, Error, FromProof};
+use dapi_grpc::platform::v0::get_token_allowances_request::get_token_allowances_request_v0::allowance_spender::Spender;
+use dapi_grpc::platform::v0::{
+    get_token_allowances_request, GetTokenAllowancesRequest, GetTokenAllowancesResponse, Proof,
+    ResponseMetadata,
+};
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::dashcore::Network;
+use dpp::data_contract::GroupContractPosition;
+use dpp::identifier::Identifier;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::v0::TokenAllowanceV0Accessors;
+use dpp::tokens::allowance::TokenAllowance;
+use dpp::version::PlatformVersion;
+use drive::config::DEFAULT_QUERY_LIMIT;
+use drive::drive::Drive;
+
+impl FromProof<GetTokenAllowancesRequest> for TokenAllowances {
+    type Request = GetTokenAllowancesRequest;
+    type Response = GetTokenAllowancesResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let (token_id, owner_id, start_after, limit) = match request
+            .version
+            .ok_or(Error::EmptyVersion)?
+        {
+            get_token_allowances_request::Version::V0(v0) => {
+                let token_id = <[u8; 32]>::try_from(v0.token_id.as_slice()).map_err(|error| {
+                    Error::RequestError {
+                        error: error.to_string(),
+                    }
+                })?;
+
+                let owner_id = <[u8; 32]>::try_from(v0.owner_id.as_slice()).map_err(|error| {
+                    Error::RequestError {
+                        error: error.to_string(),
+                    }
+                })?;
+
+                let start_after = v0
+                        .start_after
+                        .map(|start_after| match start_after.spender {
+                            Some(Spender::IdentityId(identity_id)) => {
+                                Identifier::from_bytes(&identity_id)
+                                    .map(TokenAllowanceSpender::Identity)
+                                    .map_err(|_| Error::RequestError {
+                                        error: "can't convert start_after identity_id to [u8; 32]"
+                                            .to_string(),
+                                    })
+                            }
+                            Some(Spender::GroupContractPosition(group_contract_position)) => {
+                                GroupContractPosition::try_from(group_contract_position)
+                                    .map(TokenAllowanceSpender::Group)
+                                    .map_err(|_| Error::RequestError {
+                                        error: "start_after group_contract_position can not be over u16::MAX"
+                                            .to_string(),
+                                    })
+                            }
+                            None => Err(Error::RequestError {
+                                error: "start_after must have a spender".to_string(),
+                            }),
+                        })
+                        .transpose()?;
+
+                // Platform applies its default limit when none is requested
+                let limit = v0
+                    .count
+                    .map(|count| count as u16)
+                    .unwrap_or(DEFAULT_QUERY_LIMIT);
+
+                (token_id, owner_id, start_after, limit)
+            }
+        };
+
+        let metadata = response
+            .metadata()
+            .or(Err(Error::EmptyResponseMetadata))?
+            .clone();
+
+        let proof = response.proof_owned().or(Err(Error::NoProofInResult))?;
+
+        let (root_hash, result) = Drive::verify_token_allowances(
+            &proof.grovedb_proof,
+            token_id,
+            owner_id,
+            start_after,
+            Some(limit),
+            false,
+            platform_version,
+        )
+        .map(|(root_hash, allowances): (_, Vec<TokenAllowance>)| {
+            let allowances = allowances
+                .into_iter()
+                .map(|allowance| (allowance.spender(), Some(allowance)))
+                .collect::<TokenAllowances>();
+            (root_hash, allowances)
+        })
+        .map_drive_error(&proof, &metadata)?;
+
+        verify_tenderdash_proof(&proof, &metadata, &root_hash, provider)?;
+
+        Ok((Some(result), metadata, proof))
+    }
+}
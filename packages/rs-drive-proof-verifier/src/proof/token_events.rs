@@ -0,0 +1,171 @@
+use crate::error::MapGroveDbError;
+use crate::types::token_events::{TokenEventRecord, TokenEvents};
+use crate::verify::verify_tenderdash_proof;
+use crate::{ContextProvider, Error, FromProof};
+use dapi_grpc::platform::v0::get_token_events_request::get_token_events_request_v0::{
+    BlockRange, Filter, TokenEventType,
+};
+use dapi_grpc::platform::v0::{
+    get_token_events_request, GetTokenEventsRequest, GetTokenEventsResponse, Proof,
+    ResponseMetadata,
+};
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::dashcore::Network;
+use dpp::data_contracts::SystemDataContract;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::identifier::Identifier;
+use dpp::system_data_contracts::load_system_data_contract;
+use dpp::tokens::token_event::TokenEvent;
+use dpp::version::PlatformVersion;
+use drive::config::DEFAULT_QUERY_LIMIT;
+use drive::query::token_event_history_query::{
+    TokenEventHistoryDriveQuery, TokenEventHistoryFilter, TokenEventHistoryStartAfter,
+    TokenEventHistoryType,
+};
+
+impl FromProof<GetTokenEventsRequest> for TokenEvents {
+    type Request = GetTokenEventsRequest;
+    type Response = GetTokenEventsResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let history_query = match request.version.ok_or(Error::EmptyVersion)? {
+            get_token_events_request::Version::V0(v0) => {
+                let token_id =
+                    Identifier::from_bytes(&v0.token_id).map_err(|error| Error::RequestError {
+                        error: error.to_string(),
+                    })?;
+
+                let event_type = v0.event_type.map(event_history_type).transpose()?;
+
+                let filter = match v0.filter {
+                    None => None,
+                    Some(Filter::IdentityId(identity_id)) => {
+                        Some(TokenEventHistoryFilter::ByIdentity(
+                            Identifier::from_bytes(&identity_id).map_err(|_| {
+                                Error::RequestError {
+                                    error: "can't convert identity_id to [u8; 32]".to_string(),
+                                }
+                            })?,
+                        ))
+                    }
+                    Some(Filter::BlockRange(BlockRange {
+                        start_height,
+                        end_height,
+                    })) => Some(TokenEventHistoryFilter::ByBlockHeightRange {
+                        start_height,
+                        end_height,
+                    }),
+                };
+
+                let start_after =
+                    v0.start_after
+                        .map(|start_after| {
+                            Ok::<_, Error>(TokenEventHistoryStartAfter {
+                                event_type: event_history_type(start_after.event_type)?,
+                                document_id: Identifier::from_bytes(&start_after.document_id)
+                                    .map_err(|_| Error::RequestError {
+                                        error: "can't convert start_after document_id to [u8; 32]"
+                                            .to_string(),
+                                    })?,
+                                created_at: start_after.created_at,
+                                created_at_block_height: start_after.created_at_block_height,
+                            })
+                        })
+                        .transpose()?;
+
+                // Platform applies its default limit when none is requested
+                let limit = v0
+                    .count
+                    .map(|count| count as u16)
+                    .unwrap_or(DEFAULT_QUERY_LIMIT);
+
+                TokenEventHistoryDriveQuery {
+                    token_id,
+                    event_type,
+                    filter,
+                    start_after,
+                    limit,
+                    order_ascending: v0.order_ascending,
+                }
+            }
+        };
+
+        let metadata = response
+            .metadata()
+            .or(Err(Error::EmptyResponseMetadata))?
+            .clone();
+
+        let proof = response.proof_owned().or(Err(Error::NoProofInResult))?;
+
+        let token_history_contract =
+            load_system_data_contract(SystemDataContract::TokenHistory, platform_version)?;
+
+        let (root_hash, documents) = history_query
+            .verify_proof(
+                &token_history_contract,
+                &proof.grovedb_proof,
+                platform_version,
+            )
+            .map_drive_error(&proof, &metadata)?;
+
+        verify_tenderdash_proof(&proof, &metadata, &root_hash, provider)?;
+
+        let events = documents
+            .into_iter()
+            .map(
+                |(event_type, document): (TokenEventHistoryType, Document)| {
+                    let event = TokenEvent::from_historical_document(
+                        event_type.document_type_name(),
+                        &document,
+                    )?;
+                    Ok((
+                        document.id(),
+                        Some(TokenEventRecord {
+                            event_type,
+                            owner_id: document.owner_id(),
+                            created_at: document.created_at().unwrap_or_default(),
+                            created_at_block_height: document
+                                .created_at_block_height()
+                                .unwrap_or_default(),
+                            event,
+                        }),
+                    ))
+                },
+            )
+            .collect::<Result<TokenEvents, Error>>()?;
+
+        Ok((Some(events), metadata, proof))
+    }
+}
+
+fn event_history_type(event_type: i32) -> Result<TokenEventHistoryType, Error> {
+    let event_type = TokenEventType::try_from(event_type).map_err(|_| Error::RequestError {
+        error: format!("unknown token event type {}", event_type),
+    })?;
+
+    Ok(match event_type {
+        TokenEventType::Mint => TokenEventHistoryType::Mint,
+        TokenEventType::Burn => TokenEventHistoryType::Burn,
+        TokenEventType::Freeze => TokenEventHistoryType::Freeze,
+        TokenEventType::Unfreeze => TokenEventHistoryType::Unfreeze,
+        TokenEventType::DestroyFrozenFunds => TokenEventHistoryType::DestroyFrozenFunds,
+        TokenEventType::Transfer => TokenEventHistoryType::Transfer,
+        TokenEventType::Claim => TokenEventHistoryType::Claim,
+        TokenEventType::EmergencyAction => TokenEventHistoryType::EmergencyAction,
+        TokenEventType::ConfigUpdate => TokenEventHistoryType::ConfigUpdate,
+        TokenEventType::DirectPricing => TokenEventHistoryType::DirectPricing,
+        TokenEventType::DirectPurchase => TokenEventHistoryType::DirectPurchase,
+    })
+}
@@ -0,0 +1,112 @@
+use crate::error::MapGroveDbError;
+use crate::types::token_order_book::TokenOrderBookOrders;
+use crate::verify::verify_tenderdash_proof;
+use crate::{ContextProvider, Error, FromProof};
+use dapi_grpc::platform::v0::get_token_order_book_request::OrderSide;
+use dapi_grpc::platform::v0::{
+    get_token_order_book_request, GetTokenOrderBookRequest, GetTokenOrderBookResponse, Proof,
+    ResponseMetadata,
+};
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::dashcore::Network;
+use dpp::identifier::Identifier;
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+use dpp::tokens::order_book::{TokenOrder, TokenOrderSide};
+use dpp::version::PlatformVersion;
+use drive::config::DEFAULT_QUERY_LIMIT;
+use drive::drive::Drive;
+
+impl FromProof<GetTokenOrderBookRequest> for TokenOrderBookOrders {
+    type Request = GetTokenOrderBookRequest;
+    type Response = GetTokenOrderBookResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let (token_id, side, quote_currency, limit) = match request
+            .version
+            .ok_or(Error::EmptyVersion)?
+        {
+            get_token_order_book_request::Version::V0(v0) => {
+                let token_id = <[u8; 32]>::try_from(v0.token_id.as_slice()).map_err(|error| {
+                    Error::RequestError {
+                        error: error.to_string(),
+                    }
+                })?;
+
+                let side = match OrderSide::try_from(v0.side).map_err(|_| Error::RequestError {
+                    error: "side must be Buy or Sell".to_string(),
+                })? {
+                    OrderSide::Buy => TokenOrderSide::Buy,
+                    OrderSide::Sell => TokenOrderSide::Sell,
+                };
+
+                let quote_currency = match v0.quote_token {
+                    None => AllowedCurrency::TradingInDash,
+                    Some(quote_token) => {
+                        let contract_id = <[u8; 32]>::try_from(quote_token.contract_id.as_slice())
+                            .map_err(|_| Error::RequestError {
+                                error: "can't convert quote contract_id to [u8; 32]".to_string(),
+                            })?;
+                        let token_position =
+                            u16::try_from(quote_token.token_position).map_err(|_| {
+                                Error::RequestError {
+                                    error: "quote token position must fit in 16 bits".to_string(),
+                                }
+                            })?;
+                        AllowedCurrency::OnContract(Identifier::new(contract_id), token_position)
+                    }
+                };
+
+                // Platform applies its default limit when none is requested
+                let limit = v0
+                    .count
+                    .map(|count| count as u16)
+                    .unwrap_or(DEFAULT_QUERY_LIMIT);
+
+                (token_id, side, quote_currency, limit)
+            }
+        };
+
+        let metadata = response
+            .metadata()
+            .or(Err(Error::EmptyResponseMetadata))?
+            .clone();
+
+        let proof = response.proof_owned().or(Err(Error::NoProofInResult))?;
+
+        let (root_hash, result) = Drive::verify_token_order_book_orders(
+            &proof.grovedb_proof,
+            token_id,
+            side,
+            &quote_currency,
+            Some(limit),
+            false,
+            platform_version,
+        )
+        // Key orders by their id, keeping price-time priority
+        .map(|(root_hash, orders): (_, Vec<TokenOrder>)| {
+            let orders = orders
+                .into_iter()
+                .map(|order| (order.id(), Some(order)))
+                .collect::<TokenOrderBookOrders>();
+            (root_hash, orders)
+        })
+        .map_drive_error(&proof, &metadata)?;
+
+        verify_tenderdash_proof(&proof, &metadata, &root_hash, provider)?;
+
+        Ok((Some(result), metadata, proof))
+    }
+}
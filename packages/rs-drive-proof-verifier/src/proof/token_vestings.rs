@@ -0,0 +1,101 @@
+use crate::error::MapGroveDbError;
+use crate::types::token_vestings::TokenVestings;
+use crate::verify::verify_tenderdash_proof;
+use crate::{ContextProvider, Error, FromProof};
+use dapi_grpc::platform::v0::{
+    get_token_vestings_request, GetTokenVestingsRequest, GetTokenVestingsResponse, Proof,
+    ResponseMetadata,
+};
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::dashcore::Network;
+use dpp::tokens::vesting::v0::TokenVestingV0Accessors;
+use dpp::tokens::vesting::TokenVesting;
+use dpp::version::PlatformVersion;
+use drive::config::DEFAULT_QUERY_LIMIT;
+use drive::drive::Drive;
+
+impl FromProof<GetTokenVestingsRequest> for TokenVestings {
+    type Request = GetTokenVestingsRequest;
+    type Response = GetTokenVestingsResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let (token_id, recipient_id, start_after, limit) =
+            match request.version.ok_or(Error::EmptyVersion)? {
+                get_token_vestings_request::Version::V0(v0) => {
+                    let token_id =
+                        <[u8; 32]>::try_from(v0.token_id.as_slice()).map_err(|error| {
+                            Error::RequestError {
+                                error: error.to_string(),
+                            }
+                        })?;
+
+                    let recipient_id =
+                        <[u8; 32]>::try_from(v0.recipient_id.as_slice()).map_err(|error| {
+                            Error::RequestError {
+                                error: error.to_string(),
+                            }
+                        })?;
+
+                    let start_after = v0
+                        .start_after
+                        .map(|start_after| {
+                            <[u8; 32]>::try_from(start_after.as_slice()).map_err(|_| {
+                                Error::RequestError {
+                                    error: "can't convert start_after to [u8; 32]".to_string(),
+                                }
+                            })
+                        })
+                        .transpose()?;
+
+                    // Platform applies its default limit when none is requested
+                    let limit = v0
+                        .count
+                        .map(|count| count as u16)
+                        .unwrap_or(DEFAULT_QUERY_LIMIT);
+
+                    (token_id, recipient_id, start_after, limit)
+                }
+            };
+
+        let metadata = response
+            .metadata()
+            .or(Err(Error::EmptyResponseMetadata))?
+            .clone();
+
+        let proof = response.proof_owned().or(Err(Error::NoProofInResult))?;
+
+        let (root_hash, result) = Drive::verify_token_vestings(
+            &proof.grovedb_proof,
+            token_id,
+            recipient_id,
+            start_after,
+            Some(limit),
+            false,
+            platform_version,
+        )
+        .map(|(root_hash, vestings): (_, Vec<TokenVesting>)| {
+            let vestings = vestings
+                .into_iter()
+                .map(|vesting| (vesting.id(), Some(vesting)))
+                .collect::<TokenVestings>();
+            (root_hash, vestings)
+        })
+        .map_drive_error(&proof, &metadata)?;
+
+        verify_tenderdash_proof(&proof, &metadata, &root_hash, provider)?;
+
+        Ok((Some(result), metadata, proof))
+    }
+}
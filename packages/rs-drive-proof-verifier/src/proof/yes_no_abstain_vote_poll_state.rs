@@ -0,0 +1,64 @@
+use crate::error::MapGroveDbError;
+use crate::verify::verify_tenderdash_proof;
+use crate::{ContextProvider, Error, FromProof};
+use dapi_grpc::platform::v0::{
+    get_yes_no_abstain_vote_poll_state_request, GetYesNoAbstainVotePollStateRequest,
+    GetYesNoAbstainVotePollStateResponse, Proof, ResponseMetadata,
+};
+use dapi_grpc::platform::VersionedGrpcResponse;
+use dpp::dashcore::Network;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use drive::drive::votes::yes_no_abstain_vote_poll_state::YesNoAbstainVotePollState;
+use drive::drive::Drive;
+
+impl FromProof<GetYesNoAbstainVotePollStateRequest> for YesNoAbstainVotePollState {
+    type Request = GetYesNoAbstainVotePollStateRequest;
+    type Response = GetYesNoAbstainVotePollStateResponse;
+
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        _network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let response: Self::Response = response.into();
+
+        let vote_poll_id = match request.version.ok_or(Error::EmptyVersion)? {
+            get_yes_no_abstain_vote_poll_state_request::Version::V0(v0) => {
+                Identifier::from_bytes(&v0.vote_poll_id).map_err(|error| Error::RequestError {
+                    error: error.to_string(),
+                })?
+            }
+        };
+
+        let metadata = response
+            .metadata()
+            .or(Err(Error::EmptyResponseMetadata))?
+            .clone();
+
+        let proof = response.proof_owned().or(Err(Error::NoProofInResult))?;
+
+        let (root_hash, vote_poll_state) = Drive::verify_yes_no_abstain_vote_poll_state_proof(
+            &proof.grovedb_proof,
+            vote_poll_id,
+            false,
+            platform_version,
+        )
+        .map_drive_error(&proof, &metadata)?;
+
+        verify_tenderdash_proof(&proof, &metadata, &root_hash, provider)?;
+
+        // A poll nobody voted on is not known to the platform
+        if vote_poll_state.vote_poll.is_none() {
+            return Ok((None, metadata, proof));
+        }
+
+        Ok((Some(vote_poll_state), metadata, proof))
+    }
+}
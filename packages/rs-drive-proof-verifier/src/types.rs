@@ -5,18 +5,32 @@
 //! In this case, the [FromProof](crate::FromProof) trait is implemented for dedicated object type
 //! defined in this module.
 
+/// Block state transitions
+pub mod block_state_transitions;
+/// Document count and sum
+pub mod document_aggregate;
 /// Evonode status
 pub mod evonode_status;
 /// Groups
 pub mod groups;
+/// Identity state transitions
+pub mod identity_state_transitions;
 /// Identity token balance
 pub mod identity_token_balance;
+/// Token allowances
+pub mod token_allowances;
 /// Token contract info
 pub mod token_contract_info;
+/// Token events
+pub mod token_events;
 /// Token info
 pub mod token_info;
+/// Token order book
+pub mod token_order_book;
 /// Token status
 pub mod token_status;
+/// Token vestings
+pub mod token_vestings;
 
 use dpp::block::block_info::BlockInfo;
 use dpp::core_types::validator_set::ValidatorSet;
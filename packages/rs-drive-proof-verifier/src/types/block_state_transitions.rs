@@ -0,0 +1,184 @@
+//! State transitions executed in a block, with their results and fees
+
+use crate::Error;
+use dapi_grpc::platform::v0::get_block_state_transitions_response::get_block_state_transitions_response_v0::{
+    self, ExecutionStatus,
+};
+use dapi_grpc::platform::v0::{get_block_state_transitions_response, GetBlockStateTransitionsResponse};
+use dpp::fee::Credits;
+use dpp::prelude::{BlockHeight, Identifier, TimestampMillis};
+use dpp::serialization::PlatformDeserializable;
+use dpp::state_transition::StateTransition;
+
+#[cfg(feature = "mocks")]
+use {
+    bincode::{Decode, Encode},
+    dpp::{version as platform_version, ProtocolError},
+    platform_serialization_derive::{PlatformDeserialize, PlatformSerialize},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "mocks", derive(Encode, Decode))]
+/// How a state transition included in a block was executed
+pub enum ExecutedStateTransitionStatus {
+    /// Executed and applied to the state
+    Success,
+    /// Failed validation but was charged fees
+    PaidConsensusError,
+    /// Failed validation without paying fees
+    UnpaidConsensusError,
+    /// Failed because of an internal error
+    InternalError,
+    /// Was not executed
+    NotExecuted,
+}
+
+impl From<ExecutionStatus> for ExecutedStateTransitionStatus {
+    fn from(status: ExecutionStatus) -> Self {
+        match status {
+            ExecutionStatus::Success => Self::Success,
+            ExecutionStatus::PaidConsensusError => Self::PaidConsensusError,
+            ExecutionStatus::UnpaidConsensusError => Self::UnpaidConsensusError,
+            ExecutionStatus::InternalError => Self::InternalError,
+            ExecutionStatus::NotExecuted => Self::NotExecuted,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "mocks", derive(Encode, Decode))]
+/// The fees paid by an executed state transition
+pub struct ExecutedStateTransitionFees {
+    /// The storage fee
+    pub storage_fee: Credits,
+    /// The processing fee
+    pub processing_fee: Credits,
+    /// The refunds per identity, summed over all epochs
+    pub fee_refunds: Vec<(Identifier, Credits)>,
+    /// The bytes removed from the system
+    pub removed_bytes_from_system: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "mocks", derive(Encode, Decode))]
+/// The consensus error an executed state transition failed with
+pub struct ExecutedStateTransitionError {
+    /// The consensus error code
+    pub code: u32,
+    /// The error message
+    pub message: String,
+    /// The serialized consensus error
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "mocks", derive(Encode, Decode))]
+/// A state transition included in a block with the result of its execution
+pub struct ExecutedStateTransition {
+    /// The serialized state transition, as it was included in the block
+    pub raw_state_transition: Vec<u8>,
+    /// The execution status
+    pub status: ExecutedStateTransitionStatus,
+    /// The fees paid, `None` if the state transition was not paid for
+    pub fees: Option<ExecutedStateTransitionFees>,
+    /// The consensus error the state transition failed with
+    pub error: Option<ExecutedStateTransitionError>,
+}
+
+impl ExecutedStateTransition {
+    /// Deserializes the state transition
+    pub fn state_transition(&self) -> Result<StateTransition, Error> {
+        StateTransition::deserialize_from_bytes(&self.raw_state_transition).map_err(|e| {
+            Error::ProtocolError {
+                error: e.to_string(),
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "mocks",
+    derive(Encode, Decode, PlatformSerialize, PlatformDeserialize),
+    platform_serialize(unversioned)
+)]
+/// The state transitions executed in a block
+pub struct BlockStateTransitions {
+    /// The block height
+    pub block_height: BlockHeight,
+    /// The block time
+    pub block_time_ms: TimestampMillis,
+    /// The executed state transitions in block order
+    pub state_transitions: Vec<ExecutedStateTransition>,
+}
+
+impl TryFrom<get_block_state_transitions_response_v0::ExecutedStateTransition>
+    for ExecutedStateTransition
+{
+    type Error = Error;
+
+    fn try_from(
+        executed_state_transition: get_block_state_transitions_response_v0::ExecutedStateTransition,
+    ) -> Result<Self, Self::Error> {
+        let status = ExecutionStatus::try_from(executed_state_transition.status).map_err(|e| {
+            Error::ProtocolError {
+                error: format!("invalid execution status: {}", e),
+            }
+        })?;
+
+        let fees = executed_state_transition
+            .fee_result
+            .map(|fee_result| {
+                let fee_refunds = fee_result
+                    .fee_refunds
+                    .into_iter()
+                    .map(|fee_refund| {
+                        Identifier::from_bytes(&fee_refund.identity_id)
+                            .map(|identity_id| (identity_id, fee_refund.credits))
+                            .map_err(|e| Error::ProtocolError {
+                                error: format!("invalid refunded identity id: {}", e),
+                            })
+                    })
+                    .collect::<Result<_, Error>>()?;
+
+                Ok::<_, Error>(ExecutedStateTransitionFees {
+                    storage_fee: fee_result.storage_fee,
+                    processing_fee: fee_result.processing_fee,
+                    fee_refunds,
+                    removed_bytes_from_system: fee_result.removed_bytes_from_system,
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            raw_state_transition: executed_state_transition.state_transition,
+            status: status.into(),
+            fees,
+            error: executed_state_transition
+                .error
+                .map(|error| ExecutedStateTransitionError {
+                    code: error.code,
+                    message: error.message,
+                    data: error.data,
+                }),
+        })
+    }
+}
+
+impl TryFrom<GetBlockStateTransitionsResponse> for BlockStateTransitions {
+    type Error = Error;
+
+    fn try_from(response: GetBlockStateTransitionsResponse) -> Result<Self, Self::Error> {
+        match response.version.ok_or(Error::EmptyVersion)? {
+            get_block_state_transitions_response::Version::V0(v0) => Ok(Self {
+                block_height: v0.block_height,
+                block_time_ms: v0.block_time_ms,
+                state_transitions: v0
+                    .state_transitions
+                    .into_iter()
+                    .map(ExecutedStateTransition::try_from)
+                    .collect::<Result<_, Error>>()?,
+            }),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Count and sum of documents matching a document query.
+
+use dpp::identifier::Identifier;
+use drive::query::DocumentAggregateOutcome;
+
+#[cfg(feature = "mocks")]
+use {
+    bincode::{Decode, Encode},
+    dpp::{version as platform_version, ProtocolError},
+    platform_serialization_derive::{PlatformDeserialize, PlatformSerialize},
+};
+
+/// Aggregate of documents matching a document query.
+///
+/// Covers a single range of documents; when `next_start_after` is set, more documents
+/// may match and the query should continue after that document.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "mocks",
+    derive(Encode, Decode, PlatformSerialize, PlatformDeserialize),
+    platform_serialize(unversioned)
+)]
+pub struct DocumentsAggregate {
+    /// Number of documents
+    pub count: u64,
+    /// Sum of the aggregated property, 0 when counting
+    pub sum: i64,
+    /// Last document of the range, if more documents may match
+    pub next_start_after: Option<Identifier>,
+}
+
+impl From<DocumentAggregateOutcome> for DocumentsAggregate {
+    fn from(outcome: DocumentAggregateOutcome) -> Self {
+        Self {
+            count: outcome.count,
+            sum: outcome.sum,
+            next_start_after: outcome.next_start_after,
+        }
+    }
+}
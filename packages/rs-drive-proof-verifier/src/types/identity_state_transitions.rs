@@ -0,0 +1,8 @@
+use crate::types::RetrievedObjects;
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::prelude::BlockHeight;
+
+/// State transitions recorded in the history of an identity, in the requested order
+/// Block height and index in the block to state transition record
+pub type IdentityStateTransitions =
+    RetrievedObjects<(BlockHeight, u32), IdentityStateTransitionRecord>;
@@ -50,3 +50,27 @@ impl FromIterator<(Identifier, Option<TokenAmount>)> for IdentitiesTokenBalances
             .into()
     }
 }
+
+/// Holders of one specific token with their balances, ordered by identity ID or by balance
+#[derive(Debug, Default, Clone, derive_more::From)]
+pub struct TokenHolders(
+    /// Identity ID to token balance
+    #[from]
+    pub RetrievedObjects<Identifier, TokenAmount>,
+);
+
+impl Deref for TokenHolders {
+    type Target = RetrievedObjects<Identifier, TokenAmount>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromIterator<(Identifier, Option<TokenAmount>)> for TokenHolders {
+    fn from_iter<T: IntoIterator<Item = (Identifier, Option<TokenAmount>)>>(iter: T) -> Self {
+        iter.into_iter()
+            .collect::<RetrievedObjects<Identifier, TokenAmount>>()
+            .into()
+    }
+}
@@ -0,0 +1,7 @@
+use crate::types::RetrievedObjects;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
+
+/// Allowances an owner granted for a token, ordered by spender
+/// Spender to allowance
+pub type TokenAllowances = RetrievedObjects<TokenAllowanceSpender, TokenAllowance>;
@@ -0,0 +1,49 @@
+use crate::types::RetrievedObjects;
+use dpp::identifier::Identifier;
+use dpp::prelude::{BlockHeight, TimestampMillis};
+use dpp::tokens::token_event::TokenEvent;
+use drive::query::token_event_history_query::{TokenEventHistoryStartAfter, TokenEventHistoryType};
+
+#[cfg(feature = "mocks")]
+use {
+    bincode::{Decode, Encode},
+    dpp::{version as platform_version, ProtocolError},
+    platform_serialization_derive::{PlatformDeserialize, PlatformSerialize},
+};
+
+/// A token event kept in the token history contract
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "mocks",
+    derive(Encode, Decode, PlatformSerialize, PlatformDeserialize),
+    platform_serialize(unversioned)
+)]
+pub struct TokenEventRecord {
+    /// The kind of the event
+    pub event_type: TokenEventHistoryType,
+    /// Identity that performed the action
+    pub owner_id: Identifier,
+    /// Time of the block the event was recorded in
+    pub created_at: TimestampMillis,
+    /// Height of the block the event was recorded in
+    pub created_at_block_height: BlockHeight,
+    /// The event itself
+    pub event: TokenEvent,
+}
+
+impl TokenEventRecord {
+    /// The cursor continuing a query after this event, which is held in the document with
+    /// `document_id`
+    pub fn start_after(&self, document_id: Identifier) -> TokenEventHistoryStartAfter {
+        TokenEventHistoryStartAfter {
+            event_type: self.event_type,
+            document_id,
+            created_at: self.created_at,
+            created_at_block_height: self.created_at_block_height,
+        }
+    }
+}
+
+/// Historical events of a token, in the requested order
+/// History document ID to event
+pub type TokenEvents = RetrievedObjects<Identifier, TokenEventRecord>;
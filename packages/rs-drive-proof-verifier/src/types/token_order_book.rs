@@ -0,0 +1,7 @@
+use crate::types::RetrievedObjects;
+use dpp::identifier::Identifier;
+use dpp::tokens::order_book::TokenOrder;
+
+/// Resting orders of one side of a token order book, in price-time priority
+/// Order ID to order
+pub type TokenOrderBookOrders = RetrievedObjects<Identifier, TokenOrder>;
@@ -0,0 +1,7 @@
+use crate::types::RetrievedObjects;
+use dpp::identifier::Identifier;
+use dpp::tokens::vesting::TokenVesting;
+
+/// Vestings of a token locked for a recipient, ordered by vesting id
+/// Vesting ID to vesting
+pub type TokenVestings = RetrievedObjects<Identifier, TokenVesting>;
@@ -1,3 +1,4 @@
+use crate::types::block_state_transitions::BlockStateTransitions;
 use crate::types::evonode_status::EvoNodeStatus;
 use crate::types::CurrentQuorumsInfo;
 use crate::Error;
@@ -295,3 +296,32 @@ impl FromUnproved<platform::GetStatusRequest> for EvoNodeStatus {
         Ok((Some(status), Default::default()))
     }
 }
+
+impl FromUnproved<platform::GetBlockStateTransitionsRequest> for BlockStateTransitions {
+    type Request = platform::GetBlockStateTransitionsRequest;
+    type Response = platform::GetBlockStateTransitionsResponse;
+
+    fn maybe_from_unproved_with_metadata<I: Into<Self::Request>, O: Into<Self::Response>>(
+        _request: I,
+        response: O,
+        _network: Network,
+        _platform_version: &PlatformVersion,
+    ) -> Result<(Option<Self>, ResponseMetadata), Error>
+    where
+        Self: Sized,
+    {
+        let response: platform::GetBlockStateTransitionsResponse = response.into();
+
+        let metadata = match &response.version {
+            Some(platform::get_block_state_transitions_response::Version::V0(ref v0)) => {
+                v0.metadata.clone()
+            }
+            None => None,
+        }
+        .ok_or(Error::EmptyResponseMetadata)?;
+
+        let block_state_transitions = Self::try_from(response)?;
+
+        Ok((Some(block_state_transitions), metadata))
+    }
+}
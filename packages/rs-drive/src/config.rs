@@ -16,6 +16,11 @@ pub const DEFAULT_VERIFY_GROVE_ON_STARTUP: bool = false;
 pub const DEFAULT_QUERY_LIMIT: u16 = 100;
 /// The default max query limit
 pub const DEFAULT_MAX_QUERY_LIMIT: u16 = 100;
+/// The default max number of documents scanned by a single aggregate query
+///
+/// Aggregates are computed by scanning the matching documents, so the cost of a request
+/// grows linearly with this value.
+pub const DEFAULT_MAX_AGGREGATE_QUERY_DOCUMENTS: u32 = 10_000;
 /// Default maximum number of contracts in cache
 pub const DEFAULT_DATA_CONTRACTS_CACHE_SIZE: u64 = 500;
 
@@ -71,6 +76,17 @@ pub struct DriveConfig {
     )]
     pub max_query_limit: u16,
 
+    /// The maximum number of documents scanned by a single aggregate (count / sum) query
+    /// executed without proofs; larger result sets are continued in subsequent requests
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default = "default_max_aggregate_query_documents",
+            deserialize_with = "from_str_or_number"
+        )
+    )]
+    pub max_aggregate_query_documents: u32,
+
     /// Default genesis time
     #[cfg_attr(feature = "serde", serde(default))]
     pub default_genesis_time: Option<u64>,
@@ -179,6 +195,10 @@ fn default_max_query_limit() -> u16 {
     DEFAULT_MAX_QUERY_LIMIT
 }
 
+fn default_max_aggregate_query_documents() -> u32 {
+    DEFAULT_MAX_AGGREGATE_QUERY_DOCUMENTS
+}
+
 fn default_data_contracts_cache_size() -> u64 {
     DEFAULT_DATA_CONTRACTS_CACHE_SIZE
 }
@@ -198,6 +218,7 @@ impl Default for DriveConfig {
             default_query_limit: DEFAULT_QUERY_LIMIT,
             epochs_per_era: DEFAULT_EPOCHS_PER_ERA,
             max_query_limit: DEFAULT_MAX_QUERY_LIMIT,
+            max_aggregate_query_documents: DEFAULT_MAX_AGGREGATE_QUERY_DOCUMENTS,
             default_genesis_time: None,
             data_contracts_global_cache_size: DEFAULT_DATA_CONTRACTS_CACHE_SIZE,
             data_contracts_block_cache_size: DEFAULT_DATA_CONTRACTS_CACHE_SIZE,
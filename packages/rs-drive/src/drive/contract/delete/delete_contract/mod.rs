@@ -0,0 +1,97 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::DataContract;
+use dpp::fee::fee_result::FeeResult;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Deletes a data contract and all of its (empty) subtrees.
+    ///
+    /// The contract must not hold any documents, as drive never deletes non-empty trees;
+    /// this is enforced during state validation of the delete transition and documents are
+    /// not removed in cascade. Empty index subtrees left behind by document deletions are
+    /// removed with the contract, as are its Keyword Search documents. Storage removed
+    /// here is refunded to the contract owner through the storage flags of the removed elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The `DataContract` to be deleted.
+    /// * `block_info` - Information about the block in which the contract is deleted.
+    /// * `apply` - Whether the deletion should be applied (`true`) or only estimated (`false`).
+    /// * `transaction` - The transaction to use.
+    /// * `platform_version` - The platform version used to select the method version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FeeResult, Error>` - The fee result of the deletion, including refunds.
+    pub fn delete_contract(
+        &self,
+        contract: &DataContract,
+        block_info: BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .delete
+            .delete_contract
+        {
+            0 => {
+                self.delete_contract_v0(contract, block_info, apply, transaction, platform_version)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "delete_contract".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// The low level operations for deleting a data contract.
+    ///
+    /// Operations are ordered bottom-up so that every tree is empty by the time
+    /// its own deletion is evaluated against the current batch.
+    pub(crate) fn delete_contract_operations(
+        &self,
+        contract: &DataContract,
+        block_info: &BlockInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .delete
+            .delete_contract
+        {
+            0 => self.delete_contract_operations_v0(
+                contract,
+                block_info,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "delete_contract_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
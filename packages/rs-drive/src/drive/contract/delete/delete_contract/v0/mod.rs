@@ -0,0 +1,446 @@
+use crate::drive::contract::paths;
+use crate::drive::document::paths::{
+    contract_document_type_full_text_indexes_path, FULL_TEXT_INDEXES_TREE_KEY,
+};
+use crate::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use crate::drive::votes::paths::{
+    vote_contested_resource_active_polls_contract_document_tree_path,
+    vote_contested_resource_active_polls_contract_tree_path,
+    vote_contested_resource_active_polls_tree_path, CONTESTED_DOCUMENT_INDEXES_TREE_KEY,
+    CONTESTED_DOCUMENT_STORAGE_TREE_KEY,
+};
+use crate::drive::{contract_documents_path, Drive};
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::query::{DriveDocumentQuery, WhereClause, WhereOperator};
+use crate::util::grove_operations::BatchDeleteApplyType;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::accessors::v1::DataContractV1Getters;
+use dpp::data_contract::config::v0::DataContractConfigGettersV0;
+use dpp::data_contract::document_type::methods::DocumentTypeBasicMethods;
+use dpp::data_contract::DataContract;
+use dpp::document::DocumentV0Getters;
+use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
+use dpp::platform_value::Value;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{EstimatedLayerInformation, MaybeTree, PathQuery, Query, SizedQuery};
+use grovedb::{TransactionArg, TreeType};
+use std::collections::{HashMap, HashSet};
+
+impl Drive {
+    /// Deletes a contract.
+    #[inline(always)]
+    pub(super) fn delete_contract_v0(
+        &self,
+        contract: &DataContract,
+        block_info: BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        let mut estimated_costs_only_with_layer_info = if apply {
+            None::<HashMap<KeyInfoPath, EstimatedLayerInformation>>
+        } else {
+            Some(HashMap::new())
+        };
+
+        let batch_operations = self.delete_contract_operations_v0(
+            contract,
+            &block_info,
+            &mut estimated_costs_only_with_layer_info,
+            transaction,
+            platform_version,
+        )?;
+
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+            None,
+        )
+    }
+
+    /// The operations for deleting a contract.
+    /// These remove the document type trees, the contract itself (and its history),
+    /// the contested resource trees and the contract's keyword search documents.
+    pub(super) fn delete_contract_operations_v0(
+        &self,
+        contract: &DataContract,
+        block_info: &BlockInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut batch_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        let tree_delete_apply_type = if estimated_costs_only_with_layer_info.is_none() {
+            BatchDeleteApplyType::StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some(MaybeTree::Tree(TreeType::NormalTree)),
+            }
+        } else {
+            BatchDeleteApplyType::StatelessBatchDelete {
+                in_tree_type: TreeType::NormalTree,
+                estimated_key_size: 32,
+                estimated_value_size: 0,
+            }
+        };
+
+        let item_delete_apply_type = if estimated_costs_only_with_layer_info.is_none() {
+            BatchDeleteApplyType::StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some(MaybeTree::NotTree),
+            }
+        } else {
+            BatchDeleteApplyType::StatelessBatchDelete {
+                in_tree_type: TreeType::NormalTree,
+                estimated_key_size: 1,
+                estimated_value_size: platform_version.dpp.contract_versions.max_serialized_size,
+            }
+        };
+
+        let contract_id = contract.id_ref().as_bytes();
+
+        // First the document types, each one holding its primary key tree and top level indices
+        let contract_documents_path = contract_documents_path(contract_id);
+
+        for (type_key, document_type) in contract.document_types().iter() {
+            let type_path = [
+                contract_documents_path[0],
+                contract_documents_path[1],
+                contract_documents_path[2],
+                type_key.as_bytes(),
+            ];
+
+            self.batch_delete(
+                (&type_path).into(),
+                &[0],
+                tree_delete_apply_type,
+                transaction,
+                &mut batch_operations,
+                &platform_version.drive,
+            )?;
+
+            let mut index_cache: HashSet<&[u8]> = HashSet::new();
+            for index in document_type.as_ref().top_level_indices() {
+                let index_bytes = index.name.as_bytes();
+                if index_cache.insert(index_bytes) {
+                    self.delete_index_tree_operations_v0(
+                        type_path.iter().map(|segment| segment.to_vec()).collect(),
+                        index_bytes,
+                        tree_delete_apply_type,
+                        transaction,
+                        &mut batch_operations,
+                        platform_version,
+                    )?;
+                }
+            }
+
+            // the term trees are already gone with the documents, only the property trees remain
+            let full_text_indexes = document_type.as_ref().full_text_indexes();
+            if !full_text_indexes.is_empty() {
+                let full_text_indexes_path =
+                    contract_document_type_full_text_indexes_path(contract_id, type_key);
+
+                for index in full_text_indexes {
+                    if let Some(property) = index.properties.first() {
+                        self.batch_delete(
+                            (&full_text_indexes_path).into(),
+                            property.name.as_bytes(),
+                            tree_delete_apply_type,
+                            transaction,
+                            &mut batch_operations,
+                            &platform_version.drive,
+                        )?;
+                    }
+                }
+
+                self.batch_delete(
+                    (&type_path).into(),
+                    &[FULL_TEXT_INDEXES_TREE_KEY],
+                    tree_delete_apply_type,
+                    transaction,
+                    &mut batch_operations,
+                    &platform_version.drive,
+                )?;
+            }
+
+            self.batch_delete(
+                (&contract_documents_path).into(),
+                type_key.as_bytes(),
+                tree_delete_apply_type,
+                transaction,
+                &mut batch_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        let contract_root_path = paths::contract_root_path(contract_id);
+
+        // the documents
+        self.batch_delete(
+            (&contract_root_path).into(),
+            &[1],
+            tree_delete_apply_type,
+            transaction,
+            &mut batch_operations,
+            &platform_version.drive,
+        )?;
+
+        // the contract itself
+        if contract.config().keeps_history() {
+            let contract_keeping_history_storage_path =
+                paths::contract_keeping_history_root_path_vec(contract_id);
+
+            if estimated_costs_only_with_layer_info.is_none() {
+                // this removes every historical revision as well as the reference to the latest one
+                let history_path_query = PathQuery::new(
+                    contract_keeping_history_storage_path,
+                    SizedQuery::new(Query::new_range_full(), Some(u16::MAX), None),
+                );
+
+                self.batch_delete_items_in_path_query(
+                    &history_path_query,
+                    true,
+                    item_delete_apply_type,
+                    transaction,
+                    &mut batch_operations,
+                    &platform_version.drive,
+                )?;
+            }
+
+            self.batch_delete(
+                (&contract_root_path).into(),
+                &[0],
+                tree_delete_apply_type,
+                transaction,
+                &mut batch_operations,
+                &platform_version.drive,
+            )?;
+        } else {
+            self.batch_delete(
+                (&contract_root_path).into(),
+                &[0],
+                item_delete_apply_type,
+                transaction,
+                &mut batch_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        self.batch_delete(
+            (&paths::all_contracts_global_root_path()).into(),
+            contract_id,
+            tree_delete_apply_type,
+            transaction,
+            &mut batch_operations,
+            &platform_version.drive,
+        )?;
+
+        // If the contract has contested indexes it also has a tree in the contested active polls
+        let document_types_with_contested_indexes =
+            contract.document_types_with_contested_indexes();
+
+        if !document_types_with_contested_indexes.is_empty() {
+            let contested_contract_path =
+                vote_contested_resource_active_polls_contract_tree_path(contract_id);
+
+            for type_key in document_types_with_contested_indexes.keys() {
+                let type_path = vote_contested_resource_active_polls_contract_document_tree_path(
+                    contract_id,
+                    type_key.as_str(),
+                );
+
+                for key in [
+                    CONTESTED_DOCUMENT_STORAGE_TREE_KEY,
+                    CONTESTED_DOCUMENT_INDEXES_TREE_KEY,
+                ] {
+                    self.batch_delete(
+                        (&type_path).into(),
+                        &[key],
+                        tree_delete_apply_type,
+                        transaction,
+                        &mut batch_operations,
+                        &platform_version.drive,
+                    )?;
+                }
+
+                self.batch_delete(
+                    (&contested_contract_path).into(),
+                    type_key.as_bytes(),
+                    tree_delete_apply_type,
+                    transaction,
+                    &mut batch_operations,
+                    &platform_version.drive,
+                )?;
+            }
+
+            self.batch_delete(
+                (&vote_contested_resource_active_polls_tree_path()).into(),
+                contract_id,
+                tree_delete_apply_type,
+                transaction,
+                &mut batch_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        // Finally the documents describing the contract in the Keyword Search contract
+        let mut keyword_search_document_types = vec![];
+        if !contract.keywords().is_empty() {
+            keyword_search_document_types.push("contractKeywords");
+        }
+        if contract.description().is_some() {
+            keyword_search_document_types.extend(["shortDescription", "fullDescription"]);
+        }
+
+        for document_type_name in keyword_search_document_types {
+            self.delete_keyword_search_documents_operations_v0(
+                contract.id(),
+                document_type_name,
+                block_info,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                &mut batch_operations,
+                platform_version,
+            )?;
+        }
+
+        Ok(batch_operations)
+    }
+
+    /// Deletes an index tree of a document type along with the value subtrees that
+    /// document deletions left behind.
+    ///
+    /// Removing the last reference of an index value only removes the trees above it while
+    /// they are empty, so sibling trees of a shared index prefix can outlive the documents.
+    /// As the contract holds no documents any more, only empty trees are found here and
+    /// they are deleted bottom-up, the fetches being paid as part of the deletion.
+    fn delete_index_tree_operations_v0(
+        &self,
+        path: Vec<Vec<u8>>,
+        key: &[u8],
+        apply_type: BatchDeleteApplyType,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        if matches!(apply_type, BatchDeleteApplyType::StatefulBatchDelete { .. }) {
+            let mut subtree_path = path.clone();
+            subtree_path.push(key.to_vec());
+
+            let path_query = PathQuery::new(
+                subtree_path.clone(),
+                SizedQuery::new(Query::new_range_full(), None, None),
+            );
+
+            let (elements, _) = self.grove_get_raw_path_query(
+                &path_query,
+                transaction,
+                QueryResultType::QueryKeyElementPairResultType,
+                batch_operations,
+                &platform_version.drive,
+            )?;
+
+            for (child_key, element) in elements.to_key_elements() {
+                if !element.is_any_tree() {
+                    return Err(Error::Drive(DriveError::CorruptedContractIndexes(
+                        "a document reference remains in an index of a contract being deleted"
+                            .to_string(),
+                    )));
+                }
+
+                self.delete_index_tree_operations_v0(
+                    subtree_path.clone(),
+                    &child_key,
+                    apply_type,
+                    transaction,
+                    batch_operations,
+                    platform_version,
+                )?;
+            }
+        }
+
+        self.batch_delete(
+            path.as_slice().into(),
+            key,
+            apply_type,
+            transaction,
+            batch_operations,
+            &platform_version.drive,
+        )
+    }
+
+    /// Deletes the documents of a Keyword Search contract document type that belong to the
+    /// contract, paying for the query that finds them.
+    #[allow(clippy::too_many_arguments)]
+    fn delete_keyword_search_documents_operations_v0(
+        &self,
+        contract_id: Identifier,
+        document_type_name: &str,
+        block_info: &BlockInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let keyword_search_contract = self.cache.system_data_contracts.load_keyword_search();
+        let document_type = keyword_search_contract.document_type_for_name(document_type_name)?;
+
+        let mut query =
+            DriveDocumentQuery::all_items_query(&keyword_search_contract, document_type, None);
+        query.internal_clauses.equal_clauses.insert(
+            "contractId".to_string(),
+            WhereClause {
+                field: "contractId".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(contract_id.to_buffer()),
+            },
+        );
+
+        let query_outcome = self.query_documents(
+            query,
+            Some(&block_info.epoch),
+            false,
+            transaction,
+            Some(platform_version.protocol_version),
+        )?;
+
+        batch_operations.push(LowLevelDriveOperation::PreCalculatedFeeResult(
+            FeeResult::new_from_processing_fee(query_outcome.cost()),
+        ));
+
+        for document in query_outcome.documents() {
+            batch_operations.extend(self.force_delete_document_for_contract_operations(
+                document.id(),
+                &keyword_search_contract,
+                document_type,
+                None,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            )?);
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
 use crate::drive::contract::paths;
+use crate::drive::document::paths::{
+    contract_document_type_full_text_indexes_path, FULL_TEXT_INDEXES_TREE_KEY,
+};
 
 use crate::drive::{contract_documents_path, votes, Drive, RootTree};
 use crate::util::object_size_info::DriveKeyInfo::{Key, KeyRef};
@@ -309,6 +312,35 @@ impl Drive {
                     index_cache.insert(index_bytes);
                 }
             }
+
+            // full text indexes reference documents by term, under one tree per property
+            let full_text_indexes = document_type.as_ref().full_text_indexes();
+            if !full_text_indexes.is_empty() {
+                self.batch_insert_empty_tree(
+                    type_path,
+                    Key(vec![FULL_TEXT_INDEXES_TREE_KEY]),
+                    storage_flags.as_ref(),
+                    &mut batch_operations,
+                    &platform_version.drive,
+                )?;
+
+                let full_text_indexes_path = contract_document_type_full_text_indexes_path(
+                    contract.id_ref().as_bytes(),
+                    type_key,
+                );
+
+                for index in full_text_indexes {
+                    if let Some(property) = index.properties.first() {
+                        self.batch_insert_empty_tree(
+                            full_text_indexes_path,
+                            KeyRef(property.name.as_bytes()),
+                            storage_flags.as_ref(),
+                            &mut batch_operations,
+                            &platform_version.drive,
+                        )?;
+                    }
+                }
+            }
         }
 
         if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
@@ -8,6 +8,8 @@ mod apply;
 #[cfg(feature = "server")]
 mod contract_fetch_info;
 #[cfg(feature = "server")]
+mod delete;
+#[cfg(feature = "server")]
 mod estimation_costs;
 #[cfg(feature = "server")]
 mod get_fetch;
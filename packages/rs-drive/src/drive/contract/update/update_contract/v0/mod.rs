@@ -1,3 +1,6 @@
+use crate::drive::document::paths::{
+    contract_document_type_full_text_indexes_path, FULL_TEXT_INDEXES_TREE_KEY,
+};
 use crate::drive::{contract_documents_path, Drive};
 use crate::error::drive::DriveError;
 use crate::error::Error;
@@ -373,6 +376,35 @@ impl Drive {
                         index_cache.insert(index_bytes);
                     }
                 }
+
+                // full text indexes reference documents by term, under one tree per property
+                let full_text_indexes = document_type.as_ref().full_text_indexes();
+                if !full_text_indexes.is_empty() {
+                    self.batch_insert_empty_tree(
+                        type_path,
+                        KeyRef(&[FULL_TEXT_INDEXES_TREE_KEY]),
+                        storage_flags.as_ref().map(|flags| flags.as_ref()),
+                        &mut batch_operations,
+                        drive_version,
+                    )?;
+
+                    let full_text_indexes_path = contract_document_type_full_text_indexes_path(
+                        contract.id_ref().as_bytes(),
+                        type_key,
+                    );
+
+                    for index in full_text_indexes {
+                        if let Some(property) = index.properties.first() {
+                            self.batch_insert_empty_tree(
+                                full_text_indexes_path,
+                                KeyRef(property.name.as_bytes()),
+                                storage_flags.as_ref().map(|flags| flags.as_ref()),
+                                &mut batch_operations,
+                                drive_version,
+                            )?;
+                        }
+                    }
+                }
             }
         }
         Ok(batch_operations)
@@ -18,7 +18,9 @@ use dpp::document::Document;
 use crate::drive::Drive;
 use crate::util::grove_operations::DirectQueryType;
 use crate::util::grove_operations::QueryTarget::QueryTargetValue;
-use crate::util::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+use crate::util::object_size_info::{
+    DocumentAndContractInfo, DocumentInfoV0Methods, OwnedDocumentInfo,
+};
 
 use crate::error::drive::DriveError;
 
@@ -74,7 +76,7 @@ impl Drive {
         document_id: Identifier,
         contract: &DataContract,
         document_type: DocumentTypeRef,
-        previous_batch_operations: Option<&mut Vec<LowLevelDriveOperation>>,
+        mut previous_batch_operations: Option<&mut Vec<LowLevelDriveOperation>>,
         estimated_costs_only_with_layer_info: &mut Option<
             HashMap<KeyInfoPath, EstimatedLayerInformation>,
         >,
@@ -178,6 +180,31 @@ impl Drive {
             &mut batch_operations,
             platform_version,
         )?;
+
+        if let Some(document) = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_borrowed_document()
+        {
+            batch_operations.extend(self.remove_document_expiration_operations(
+                document,
+                contract.id_ref().as_bytes(),
+                document_type,
+                platform_version,
+            )?);
+
+            batch_operations.extend(self.update_full_text_index_references_operations(
+                Some(document),
+                None,
+                contract.id_ref().as_bytes(),
+                document_type,
+                &mut previous_batch_operations,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            )?);
+        }
+
         Ok(batch_operations)
     }
 }
@@ -0,0 +1,57 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::storage_flags::StorageFlags;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::Document;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Gathers the operations needed to register a document in the expiration queue of its
+    /// document type's time to live.
+    ///
+    /// No operations are returned if the document type has no time to live. The queue entry
+    /// carries the storage flags of the document so its storage is refunded to the owner as well.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn add_document_expiration_operations(
+        &self,
+        document: &Document,
+        contract_id: &[u8; 32],
+        document_type: DocumentTypeRef,
+        storage_flags: Option<&StorageFlags>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .expiration
+            .add_document_expiration_operations
+        {
+            0 => self.add_document_expiration_operations_v0(
+                document,
+                contract_id,
+                document_type,
+                storage_flags,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "add_document_expiration_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
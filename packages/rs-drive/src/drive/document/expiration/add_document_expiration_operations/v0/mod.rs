@@ -0,0 +1,139 @@
+use crate::drive::document::expiration::{
+    document_expiration_entry_key, document_expiration_queue_key,
+    document_expiration_queue_path_vec, document_expirations_path, document_expirations_path_vec,
+    DOCUMENT_EXPIRATIONS_KEY, DOCUMENT_EXPIRATION_KEY_SIZE,
+};
+use crate::drive::system::{misc_path, misc_path_vec};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::BatchInsertTreeApplyType;
+use crate::util::object_size_info::{DriveKeyInfo, PathInfo};
+use crate::util::storage_flags::StorageFlags;
+use dpp::data_contract::document_type::accessors::{DocumentTypeV0Getters, DocumentTypeV1Getters};
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, EstimatedLevel};
+use grovedb::EstimatedLayerSizes::{AllItems, AllSubtrees};
+use grovedb::EstimatedSumTrees::NoSumTrees;
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg, TreeType};
+use std::collections::HashMap;
+
+impl Drive {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn add_document_expiration_operations_v0(
+        &self,
+        document: &Document,
+        contract_id: &[u8; 32],
+        document_type: DocumentTypeRef,
+        storage_flags: Option<&StorageFlags>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations = vec![];
+
+        let Some(time_to_live) = document_type.documents_time_to_live() else {
+            return Ok(drive_operations);
+        };
+
+        // The creation property is required by the document type, so this only happens when
+        // estimating with a document that was generated without it
+        let Some(expires_at) = time_to_live.expires_at(document) else {
+            return Ok(drive_operations);
+        };
+
+        let queue_key = document_expiration_queue_key(&time_to_live);
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_document_expirations_v0(
+                queue_key,
+                document_type.name().len() as u32,
+                estimated_costs_only_with_layer_info,
+            );
+        }
+
+        let apply_type = if estimated_costs_only_with_layer_info.is_none() {
+            BatchInsertTreeApplyType::StatefulBatchInsertTree
+        } else {
+            BatchInsertTreeApplyType::StatelessBatchInsertTree {
+                in_tree_type: TreeType::NormalTree,
+                tree_type: TreeType::NormalTree,
+                flags_len: 0,
+            }
+        };
+
+        // The expiration trees are created lazily, with the first document that expires
+        let trees_to_create = [
+            (misc_path_vec(), DOCUMENT_EXPIRATIONS_KEY.to_vec()),
+            (document_expirations_path_vec(), vec![queue_key]),
+        ];
+
+        for (path, key) in trees_to_create {
+            self.batch_insert_empty_tree_if_not_exists(
+                DriveKeyInfo::Key(key).add_path_info::<0>(PathInfo::PathAsVec(path)),
+                TreeType::NormalTree,
+                None,
+                apply_type,
+                transaction,
+                &mut None,
+                &mut drive_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        drive_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+            document_expiration_queue_path_vec(queue_key),
+            document_expiration_entry_key(expires_at, contract_id, document.id_ref().as_bytes()),
+            Element::new_item_with_flags(
+                document_type.name().as_bytes().to_vec(),
+                StorageFlags::map_to_some_element_flags(storage_flags),
+            ),
+        ));
+
+        Ok(drive_operations)
+    }
+
+    /// Adds estimated storage costs for the misc tree, the expiration queues and the queue a
+    /// document is registered in.
+    fn add_estimation_costs_for_document_expirations_v0(
+        queue_key: u8,
+        document_type_name_len: u32,
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+    ) {
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(misc_path()),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(2, false),
+                estimated_layer_sizes: AllSubtrees(1, NoSumTrees, None),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(document_expirations_path()),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: ApproximateElements(2),
+                estimated_layer_sizes: AllSubtrees(1, NoSumTrees, None),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(document_expiration_queue_path_vec(queue_key)),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(10, false),
+                estimated_layer_sizes: AllItems(
+                    DOCUMENT_EXPIRATION_KEY_SIZE as u8,
+                    document_type_name_len,
+                    None,
+                ),
+            },
+        );
+    }
+}
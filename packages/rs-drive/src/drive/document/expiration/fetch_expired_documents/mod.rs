@@ -0,0 +1,60 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+/// A document whose time to live has been reached, as registered in an expiration queue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpiredDocument {
+    /// The id of the contract of the document
+    pub contract_id: [u8; 32],
+    /// The name of the document type of the document
+    pub document_type_name: String,
+    /// The id of the document
+    pub document_id: [u8; 32],
+    /// The key of the expiration queue the document is registered in
+    pub queue_key: u8,
+    /// The key of the document in its expiration queue
+    pub entry_key: Vec<u8>,
+}
+
+impl Drive {
+    /// Fetches the documents that have expired by the given block, oldest expiration first.
+    ///
+    /// Time based expirations are returned before height based ones, at most `limit` documents
+    /// are returned overall.
+    pub fn fetch_expired_documents(
+        &self,
+        block_info: &BlockInfo,
+        limit: u16,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<ExpiredDocument>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .expiration
+            .fetch_expired_documents
+        {
+            0 => self.fetch_expired_documents_v0(
+                block_info,
+                limit,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_expired_documents".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
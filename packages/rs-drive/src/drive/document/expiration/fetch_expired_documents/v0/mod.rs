@@ -0,0 +1,108 @@
+use crate::drive::document::expiration::{
+    document_expiration_queue_path_vec, ExpiredDocument, DOCUMENT_EXPIRATIONS_BY_BLOCK_HEIGHT_KEY,
+    DOCUMENT_EXPIRATIONS_BY_BLOCK_TIME_KEY, DOCUMENT_EXPIRATION_KEY_SIZE,
+};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::query::Query;
+use crate::util::common::encode::encode_u64;
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::Element::Item;
+use grovedb::{PathQuery, SizedQuery, TransactionArg};
+
+impl Drive {
+    pub(super) fn fetch_expired_documents_v0(
+        &self,
+        block_info: &BlockInfo,
+        limit: u16,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<ExpiredDocument>, Error> {
+        let mut expired_documents = vec![];
+
+        for (queue_key, now) in [
+            (DOCUMENT_EXPIRATIONS_BY_BLOCK_TIME_KEY, block_info.time_ms),
+            (DOCUMENT_EXPIRATIONS_BY_BLOCK_HEIGHT_KEY, block_info.height),
+        ] {
+            let remaining = limit.saturating_sub(expired_documents.len() as u16);
+
+            if remaining == 0 {
+                break;
+            }
+
+            let mut query = Query::new();
+
+            // Everything that expires at or before now
+            query.insert_range_to(..encode_u64(now.saturating_add(1)));
+
+            let path_query = PathQuery::new(
+                document_expiration_queue_path_vec(queue_key),
+                SizedQuery::new(query, Some(remaining), None),
+            );
+
+            let key_elements = match self.grove_get_raw_path_query(
+                &path_query,
+                transaction,
+                QueryResultType::QueryKeyElementPairResultType,
+                drive_operations,
+                &platform_version.drive,
+            ) {
+                Ok((elements, _)) => elements.to_key_elements(),
+                // The queues are only created with the first document that expires
+                Err(Error::GroveDB(e))
+                    if matches!(
+                        e.as_ref(),
+                        grovedb::Error::PathKeyNotFound(_)
+                            | grovedb::Error::PathNotFound(_)
+                            | grovedb::Error::PathParentLayerNotFound(_)
+                    ) =>
+                {
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            for (entry_key, element) in key_elements {
+                let Item(document_type_name_bytes, _) = element else {
+                    return Err(Error::Drive(DriveError::CorruptedDriveState(
+                        "document expiration queue should contain only items".to_string(),
+                    )));
+                };
+
+                if entry_key.len() != DOCUMENT_EXPIRATION_KEY_SIZE {
+                    return Err(Error::Drive(DriveError::CorruptedDriveState(
+                        "document expiration queue key has an invalid size".to_string(),
+                    )));
+                }
+
+                let document_type_name =
+                    String::from_utf8(document_type_name_bytes).map_err(|_| {
+                        Error::Drive(DriveError::CorruptedDriveState(
+                            "document expiration queue should contain document type names"
+                                .to_string(),
+                        ))
+                    })?;
+
+                let mut contract_id = [0u8; 32];
+                contract_id.copy_from_slice(&entry_key[8..40]);
+                let mut document_id = [0u8; 32];
+                document_id.copy_from_slice(&entry_key[40..72]);
+
+                expired_documents.push(ExpiredDocument {
+                    contract_id,
+                    document_type_name,
+                    document_id,
+                    queue_key,
+                    entry_key,
+                });
+            }
+        }
+
+        Ok(expired_documents)
+    }
+}
@@ -0,0 +1,361 @@
+//! Document Expiration
+//!
+//! Documents of document types with a time to live are registered in an expiration queue when
+//! they are inserted. At the end of every block the documents whose expiration has been reached
+//! are removed, refunding their storage to their owners.
+//!
+//! The queues live in the misc tree, one for time based and one for height based expirations:
+//!
+//! ```text
+//!   Misc
+//!    └── DOCUMENT_EXPIRATIONS_KEY
+//!         ├── DOCUMENT_EXPIRATIONS_BY_BLOCK_TIME_KEY
+//!         │    └── encode_u64(expires_at_time) + contract_id + document_id => document type name
+//!         └── DOCUMENT_EXPIRATIONS_BY_BLOCK_HEIGHT_KEY
+//!              └── encode_u64(expires_at_height) + contract_id + document_id => document type name
+//! ```
+
+mod add_document_expiration_operations;
+mod fetch_expired_documents;
+mod remove_document_expiration_operations;
+mod remove_expired_documents;
+
+pub use fetch_expired_documents::ExpiredDocument;
+
+use crate::drive::RootTree;
+use crate::util::common::encode::encode_u64;
+use dpp::data_contract::document_type::time_to_live::DocumentTimeToLive;
+
+/// Key in the misc tree of the tree holding the document expiration queues
+pub const DOCUMENT_EXPIRATIONS_KEY: &[u8; 1] = b"E";
+
+/// Key of the queue of documents expiring at a block time
+pub const DOCUMENT_EXPIRATIONS_BY_BLOCK_TIME_KEY: u8 = 0;
+
+/// Key of the queue of documents expiring at a block height
+pub const DOCUMENT_EXPIRATIONS_BY_BLOCK_HEIGHT_KEY: u8 = 1;
+
+/// The size of a key in an expiration queue, the expiration followed by the contract and
+/// document ids
+pub const DOCUMENT_EXPIRATION_KEY_SIZE: usize = 8 + 32 + 32;
+
+/// The path of the tree holding the document expiration queues
+pub fn document_expirations_path() -> [&'static [u8]; 2] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Misc),
+        DOCUMENT_EXPIRATIONS_KEY,
+    ]
+}
+
+/// The path of the tree holding the document expiration queues as a vector
+pub fn document_expirations_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        DOCUMENT_EXPIRATIONS_KEY.to_vec(),
+    ]
+}
+
+/// The key of the expiration queue used for a time to live
+pub fn document_expiration_queue_key(time_to_live: &DocumentTimeToLive) -> u8 {
+    match time_to_live {
+        DocumentTimeToLive::BlockTime(_) => DOCUMENT_EXPIRATIONS_BY_BLOCK_TIME_KEY,
+        DocumentTimeToLive::BlockHeight(_) => DOCUMENT_EXPIRATIONS_BY_BLOCK_HEIGHT_KEY,
+    }
+}
+
+/// The path of an expiration queue as a vector
+pub fn document_expiration_queue_path_vec(queue_key: u8) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        DOCUMENT_EXPIRATIONS_KEY.to_vec(),
+        vec![queue_key],
+    ]
+}
+
+/// The key of a document in an expiration queue
+pub fn document_expiration_entry_key(
+    expires_at: u64,
+    contract_id: &[u8; 32],
+    document_id: &[u8; 32],
+) -> Vec<u8> {
+    let mut key = Vec::with_capacity(DOCUMENT_EXPIRATION_KEY_SIZE);
+    key.extend(encode_u64(expires_at));
+    key.extend_from_slice(contract_id);
+    key.extend_from_slice(document_id);
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::document::query::QueryDocumentsOutcomeV0Methods;
+    use crate::drive::Drive;
+    use crate::query::DriveDocumentQuery;
+    use crate::util::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::util::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::util::storage_flags::StorageFlags;
+    use crate::util::test_helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::DataContractFactory;
+    use dpp::document::specialized_document_factory::SpecializedDocumentFactory;
+    use dpp::document::{Document, DocumentV0Getters, DocumentV0Setters};
+    use dpp::platform_value::{platform_value, Identifier};
+    use platform_version::version::PlatformVersion;
+    use serde_json::json;
+    use std::borrow::Cow;
+
+    const OWNER_ID: [u8; 32] = [2u8; 32];
+
+    /// Applies a contract whose messages expire 1000 ms after they were created
+    fn setup_expiring_messages_contract(
+        drive: &Drive,
+        platform_version: &PlatformVersion,
+    ) -> SpecializedDocumentFactory {
+        let documents = platform_value!({
+            "message": {
+                "type": "object",
+                "properties": {
+                    "text": {
+                        "type": "string",
+                        "maxLength": 100,
+                        "position": 0,
+                    }
+                },
+                "required": ["$createdAt"],
+                "documentsTimeToLive": {
+                    "blockTime": 1000u64
+                },
+                "additionalProperties": false
+            }
+        });
+
+        let factory = DataContractFactory::new(platform_version.protocol_version)
+            .expect("expected to create factory");
+
+        let contract = factory
+            .create_with_value_config(Identifier::new(OWNER_ID), 0, documents, None, None)
+            .expect("data in fixture should be correct")
+            .data_contract_owned();
+
+        drive
+            .apply_contract(
+                &contract,
+                BlockInfo::default(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+                platform_version,
+            )
+            .expect("should apply contract");
+
+        SpecializedDocumentFactory::new(platform_version.protocol_version, contract)
+            .expect("expected to create document factory")
+    }
+
+    fn insert_message(
+        drive: &Drive,
+        document_factory: &SpecializedDocumentFactory,
+        created_at: u64,
+        platform_version: &PlatformVersion,
+    ) -> Document {
+        let owner_id = Identifier::new(OWNER_ID);
+
+        let document_type = document_factory
+            .data_contract()
+            .document_type_for_name("message")
+            .expect("expected document type");
+
+        let mut document = document_factory
+            .create_document(
+                owner_id,
+                "message".to_string(),
+                json!({ "text": "hi" }).into(),
+            )
+            .expect("should create a document");
+
+        document.set_created_at(Some(created_at));
+
+        let storage_flags = Some(Cow::Owned(StorageFlags::SingleEpochOwned(0, OWNER_ID)));
+
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((&document, storage_flags)),
+                        owner_id: Some(OWNER_ID),
+                    },
+                    contract: document_factory.data_contract(),
+                    document_type,
+                },
+                false,
+                BlockInfo::default_with_time(created_at),
+                true,
+                None,
+                platform_version,
+                None,
+            )
+            .expect("should create document");
+
+        document
+    }
+
+    #[test]
+    fn should_remove_expired_documents_and_refund_their_owner() {
+        let drive = setup_drive_with_initial_state_structure(None);
+
+        let platform_version = PlatformVersion::latest();
+
+        let document_factory = setup_expiring_messages_contract(&drive, platform_version);
+
+        let document_type = document_factory
+            .data_contract()
+            .document_type_for_name("message")
+            .expect("expected document type");
+
+        insert_message(&drive, &document_factory, 5000, platform_version);
+
+        // Not expired yet
+        let fee_results = drive
+            .remove_expired_documents(
+                &BlockInfo::default_with_time(5999),
+                None,
+                None,
+                platform_version,
+            )
+            .expect("should remove expired documents");
+
+        assert!(fee_results.is_empty());
+
+        // Queries at a block time after the expiration no longer see the document
+        let mut query = DriveDocumentQuery::all_items_query(
+            document_factory.data_contract(),
+            document_type,
+            None,
+        );
+        query.block_time_ms = Some(6000);
+
+        let outcome = drive
+            .query_documents(query, None, false, None, None)
+            .expect("should query documents");
+
+        assert!(outcome.documents().is_empty());
+
+        // Expired
+        let fee_results = drive
+            .remove_expired_documents(
+                &BlockInfo::default_with_time(6000),
+                None,
+                None,
+                platform_version,
+            )
+            .expect("should remove expired documents");
+
+        assert_eq!(fee_results.len(), 1);
+
+        let refund = fee_results[0]
+            .fee_refunds
+            .calculate_refunds_amount_for_identity(Identifier::new(OWNER_ID))
+            .expect("expected a refund for the owner");
+
+        assert!(refund > 0);
+        assert!(fee_results[0].processing_fee > 0);
+
+        let query = DriveDocumentQuery::all_items_query(
+            document_factory.data_contract(),
+            document_type,
+            None,
+        );
+
+        let outcome = drive
+            .query_documents(query, None, false, None, None)
+            .expect("should query documents");
+
+        assert!(outcome.documents().is_empty());
+
+        let expired_documents = drive
+            .fetch_expired_documents(
+                &BlockInfo::default_with_time(u64::MAX - 1),
+                10,
+                None,
+                &mut vec![],
+                platform_version,
+            )
+            .expect("should fetch expired documents");
+
+        assert!(expired_documents.is_empty());
+    }
+
+    #[test]
+    fn should_leave_out_expired_documents_before_applying_the_limit() {
+        let drive = setup_drive_with_initial_state_structure(None);
+
+        let platform_version = PlatformVersion::latest();
+
+        let document_factory = setup_expiring_messages_contract(&drive, platform_version);
+
+        let document_type = document_factory
+            .data_contract()
+            .document_type_for_name("message")
+            .expect("expected document type");
+
+        for created_at in [1000, 1100, 1200, 1300] {
+            insert_message(&drive, &document_factory, created_at, platform_version);
+        }
+
+        let live_document = insert_message(&drive, &document_factory, 5000, platform_version);
+
+        // The expired documents were not removed yet, they must not use up the limit
+        let mut query = DriveDocumentQuery::all_items_query(
+            document_factory.data_contract(),
+            document_type,
+            Some(1),
+        );
+        query.block_time_ms = Some(5500);
+
+        let outcome = drive
+            .query_documents(query, None, false, None, None)
+            .expect("should query documents");
+
+        let ids = outcome
+            .documents()
+            .iter()
+            .map(|document| document.id())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids, vec![live_document.id()]);
+    }
+
+    #[test]
+    fn should_not_remove_expired_documents_before_protocol_version_11() {
+        let drive = setup_drive_with_initial_state_structure(None);
+
+        let platform_version = PlatformVersion::latest();
+
+        let document_factory = setup_expiring_messages_contract(&drive, platform_version);
+
+        insert_message(&drive, &document_factory, 5000, platform_version);
+
+        let fee_results = drive
+            .remove_expired_documents(
+                &BlockInfo::default_with_time(6000),
+                None,
+                None,
+                PlatformVersion::get(10).expect("expected platform version 10"),
+            )
+            .expect("should remove expired documents");
+
+        assert!(fee_results.is_empty());
+
+        let fee_results = drive
+            .remove_expired_documents(
+                &BlockInfo::default_with_time(6000),
+                None,
+                None,
+                platform_version,
+            )
+            .expect("should remove expired documents");
+
+        assert_eq!(fee_results.len(), 1);
+    }
+}
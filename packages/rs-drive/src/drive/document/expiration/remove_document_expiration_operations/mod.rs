@@ -0,0 +1,42 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::Document;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Gathers the operations needed to remove a document from the expiration queue of its
+    /// document type's time to live.
+    ///
+    /// No operations are returned if the document type has no time to live.
+    pub(crate) fn remove_document_expiration_operations(
+        &self,
+        document: &Document,
+        contract_id: &[u8; 32],
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .expiration
+            .remove_document_expiration_operations
+        {
+            0 => Ok(self.remove_document_expiration_operations_v0(
+                document,
+                contract_id,
+                document_type,
+            )),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "remove_document_expiration_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::drive::document::expiration::{
+    document_expiration_entry_key, document_expiration_queue_key,
+    document_expiration_queue_path_vec,
+};
+use crate::drive::Drive;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::data_contract::document_type::accessors::DocumentTypeV1Getters;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::{Document, DocumentV0Getters};
+use grovedb::batch::QualifiedGroveDbOp;
+
+impl Drive {
+    pub(super) fn remove_document_expiration_operations_v0(
+        &self,
+        document: &Document,
+        contract_id: &[u8; 32],
+        document_type: DocumentTypeRef,
+    ) -> Vec<LowLevelDriveOperation> {
+        let Some(time_to_live) = document_type.documents_time_to_live() else {
+            return vec![];
+        };
+
+        let Some(expires_at) = time_to_live.expires_at(document) else {
+            return vec![];
+        };
+
+        vec![LowLevelDriveOperation::GroveOperation(
+            QualifiedGroveDbOp::delete_op(
+                document_expiration_queue_path_vec(document_expiration_queue_key(&time_to_live)),
+                document_expiration_entry_key(
+                    expires_at,
+                    contract_id,
+                    document.id_ref().as_bytes(),
+                ),
+            ),
+        )]
+    }
+}
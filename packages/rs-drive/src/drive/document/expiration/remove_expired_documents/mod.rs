@@ -0,0 +1,49 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::default_costs::CachedEpochIndexFeeVersions;
+use dpp::fee::fee_result::FeeResult;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Removes the documents whose time to live has been reached by the given block.
+    ///
+    /// At most `max_expired_documents_removed_per_block` documents are removed, the rest are
+    /// picked up by the following blocks. Queue entries of documents that no longer exist, for
+    /// example because their contract was deleted, are removed as well.
+    ///
+    /// # Returns
+    /// The fee result of every removed document, its refunds are owed to the owner of the
+    /// document who pays its processing fee.
+    pub fn remove_expired_documents(
+        &self,
+        block_info: &BlockInfo,
+        previous_fee_versions: Option<&CachedEpochIndexFeeVersions>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<FeeResult>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .expiration
+            .remove_expired_documents
+        {
+            0 => self.remove_expired_documents_v0(
+                block_info,
+                previous_fee_versions,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "remove_expired_documents".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
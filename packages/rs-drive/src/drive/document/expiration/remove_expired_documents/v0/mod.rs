@@ -0,0 +1,116 @@
+use crate::drive::document::expiration::{document_expiration_queue_path_vec, ExpiredDocument};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::fee::default_costs::CachedEpochIndexFeeVersions;
+use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::batch::QualifiedGroveDbOp;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn remove_expired_documents_v0(
+        &self,
+        block_info: &BlockInfo,
+        previous_fee_versions: Option<&CachedEpochIndexFeeVersions>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<FeeResult>, Error> {
+        // Reading the queue is bounded by the limit and done for the platform, it is not charged
+        let expired_documents = self.fetch_expired_documents(
+            block_info,
+            platform_version
+                .system_limits
+                .max_expired_documents_removed_per_block,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )?;
+
+        expired_documents
+            .into_iter()
+            .map(|expired_document| {
+                let batch_operations = self.remove_expired_document_operations_v0(
+                    &expired_document,
+                    transaction,
+                    platform_version,
+                )?;
+
+                let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+                // Every document is applied on its own so that documents of the same type see
+                // the index changes made by the previous removals
+                self.apply_batch_low_level_drive_operations(
+                    None,
+                    transaction,
+                    batch_operations,
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?;
+
+                Drive::calculate_fee(
+                    None,
+                    Some(drive_operations),
+                    &block_info.epoch,
+                    self.config.epochs_per_era,
+                    platform_version,
+                    previous_fee_versions,
+                )
+            })
+            .collect()
+    }
+
+    fn remove_expired_document_operations_v0(
+        &self,
+        expired_document: &ExpiredDocument,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let queue_entry_removal = || {
+            vec![LowLevelDriveOperation::GroveOperation(
+                QualifiedGroveDbOp::delete_op(
+                    document_expiration_queue_path_vec(expired_document.queue_key),
+                    expired_document.entry_key.clone(),
+                ),
+            )]
+        };
+
+        let Some(contract_fetch_info) = self.get_contract_with_fetch_info(
+            expired_document.contract_id,
+            false,
+            transaction,
+            platform_version,
+        )?
+        else {
+            return Ok(queue_entry_removal());
+        };
+
+        let contract = &contract_fetch_info.contract;
+
+        let Some(document_type) =
+            contract.document_type_optional_for_name(&expired_document.document_type_name)
+        else {
+            return Ok(queue_entry_removal());
+        };
+
+        // Removing the document also removes its queue entry
+        match self.force_delete_document_for_contract_operations(
+            Identifier::new(expired_document.document_id),
+            contract,
+            document_type,
+            None,
+            &mut None,
+            transaction,
+            platform_version,
+        ) {
+            Err(Error::Drive(DriveError::DeletingDocumentThatDoesNotExist(_))) => {
+                Ok(queue_entry_removal())
+            }
+            result => result,
+        }
+    }
+}
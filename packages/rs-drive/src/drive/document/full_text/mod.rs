@@ -0,0 +1,17 @@
+//! Document Full Text Indexes
+//!
+//! Full text indexes reference a document under every term of the indexed string property
+//! instead of under the whole value. The terms live next to the primary key and index trees of
+//! the document type:
+//!
+//! ```text
+//!   document type
+//!    ├── 0 (primary key tree)
+//!    ├── FULL_TEXT_INDEXES_TREE_KEY
+//!    │    └── property name
+//!    │         └── term
+//!    │              └── document id => reference to the document
+//!    └── index names ...
+//! ```
+
+mod update_full_text_index_references_operations;
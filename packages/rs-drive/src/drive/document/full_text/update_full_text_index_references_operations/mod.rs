@@ -0,0 +1,60 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::storage_flags::StorageFlags;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::Document;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Gathers the operations needed to move the full text index references of a document from
+    /// the terms of its old version to the terms of its new version.
+    ///
+    /// Inserting a document passes no old document and deleting a document passes no new
+    /// document. Terms both versions share are left untouched. No operations are returned if the
+    /// document type has no full text indexes.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn update_full_text_index_references_operations(
+        &self,
+        old_document: Option<&Document>,
+        new_document: Option<(&Document, Option<&StorageFlags>)>,
+        contract_id: &[u8; 32],
+        document_type: DocumentTypeRef,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .full_text
+            .update_full_text_index_references_operations
+        {
+            0 => self.update_full_text_index_references_operations_v0(
+                old_document,
+                new_document,
+                contract_id,
+                document_type,
+                previous_batch_operations,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "update_full_text_index_references_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::drive::constants::CONTRACT_DOCUMENTS_PATH_HEIGHT;
+use crate::drive::document::paths::{
+    contract_document_type_full_text_indexes_path,
+    contract_document_type_full_text_property_path_vec,
+};
+use crate::drive::document::{document_reference_size, make_document_reference};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::BatchInsertTreeApplyType;
+use crate::util::object_size_info::{DriveKeyInfo, PathInfo};
+use crate::util::storage_flags::StorageFlags;
+use crate::util::type_constants::DEFAULT_HASH_SIZE_U8;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::full_text::{full_text_terms, MAX_FULL_TEXT_TERM_LENGTH};
+use dpp::data_contract::document_type::methods::DocumentTypeBasicMethods;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, PotentiallyAtMaxElements};
+use grovedb::EstimatedLayerSizes::{AllReference, AllSubtrees};
+use grovedb::EstimatedSumTrees::NoSumTrees;
+use grovedb::{EstimatedLayerInformation, MaybeTree, TransactionArg, TreeType};
+use std::collections::HashMap;
+
+impl Drive {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn update_full_text_index_references_operations_v0(
+        &self,
+        old_document: Option<&Document>,
+        new_document: Option<(&Document, Option<&StorageFlags>)>,
+        contract_id: &[u8; 32],
+        document_type: DocumentTypeRef,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut batch_operations = vec![];
+
+        let full_text_indexes = document_type.full_text_indexes();
+        if full_text_indexes.is_empty() {
+            return Ok(batch_operations);
+        }
+
+        let storage_flags = new_document.and_then(|(_, storage_flags)| storage_flags);
+        let flags_len = storage_flags.map(|flags| flags.serialized_size());
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_path(contract_document_type_full_text_indexes_path(
+                    contract_id,
+                    document_type.name(),
+                )),
+                EstimatedLayerInformation {
+                    tree_type: TreeType::NormalTree,
+                    estimated_layer_count: ApproximateElements(full_text_indexes.len() as u32),
+                    estimated_layer_sizes: AllSubtrees(DEFAULT_HASH_SIZE_U8, NoSumTrees, flags_len),
+                },
+            );
+        }
+
+        let insert_apply_type = if estimated_costs_only_with_layer_info.is_none() {
+            BatchInsertTreeApplyType::StatefulBatchInsertTree
+        } else {
+            BatchInsertTreeApplyType::StatelessBatchInsertTree {
+                in_tree_type: TreeType::NormalTree,
+                tree_type: TreeType::NormalTree,
+                flags_len: flags_len.unwrap_or_default(),
+            }
+        };
+
+        for index in full_text_indexes {
+            let Some(property) = index.properties.first() else {
+                continue;
+            };
+
+            let terms_of = |document: Option<&Document>| {
+                document
+                    .and_then(|document| document.properties().get(&property.name))
+                    .and_then(|value| value.as_text())
+                    .map(full_text_terms)
+                    .unwrap_or_default()
+            };
+
+            let old_terms = terms_of(old_document);
+            let new_terms = terms_of(new_document.map(|(document, _)| document));
+
+            let property_path = contract_document_type_full_text_property_path_vec(
+                contract_id,
+                document_type.name(),
+                &property.name,
+            );
+
+            if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info
+            {
+                estimated_costs_only_with_layer_info.insert(
+                    KeyInfoPath::from_known_owned_path(property_path.clone()),
+                    EstimatedLayerInformation {
+                        tree_type: TreeType::NormalTree,
+                        estimated_layer_count: PotentiallyAtMaxElements,
+                        estimated_layer_sizes: AllSubtrees(
+                            MAX_FULL_TEXT_TERM_LENGTH as u8,
+                            NoSumTrees,
+                            flags_len,
+                        ),
+                    },
+                );
+            }
+
+            if let Some(old_document) = old_document {
+                for term in old_terms.iter().filter(|term| !new_terms.contains(term)) {
+                    let mut term_path = property_path.clone();
+                    term_path.push(term.as_bytes().to_vec());
+                    let key_info_path = KeyInfoPath::from_known_owned_path(term_path);
+
+                    let delete_apply_type = Self::stateless_delete_of_non_tree_for_costs(
+                        AllReference(
+                            DEFAULT_HASH_SIZE_U8,
+                            document_reference_size(document_type),
+                            flags_len,
+                        ),
+                        &key_info_path,
+                        // we know we are not deleting a tree
+                        Some(MaybeTree::NotTree),
+                        estimated_costs_only_with_layer_info,
+                        platform_version,
+                    )?;
+
+                    // Empty term trees are removed, the property tree always stays
+                    self.batch_delete_up_tree_while_empty(
+                        key_info_path,
+                        old_document.id_ref().as_slice(),
+                        Some(CONTRACT_DOCUMENTS_PATH_HEIGHT + 1),
+                        delete_apply_type,
+                        transaction,
+                        previous_batch_operations,
+                        &mut batch_operations,
+                        &platform_version.drive,
+                    )?;
+                }
+            }
+
+            if let Some((new_document, storage_flags)) = new_document {
+                for term in new_terms.iter().filter(|term| !old_terms.contains(term)) {
+                    self.batch_insert_empty_tree_if_not_exists(
+                        DriveKeyInfo::KeyRef(term.as_bytes())
+                            .add_path_info::<0>(PathInfo::PathAsVec(property_path.clone())),
+                        TreeType::NormalTree,
+                        storage_flags,
+                        insert_apply_type,
+                        transaction,
+                        previous_batch_operations,
+                        &mut batch_operations,
+                        &platform_version.drive,
+                    )?;
+
+                    let mut term_path = property_path.clone();
+                    term_path.push(term.as_bytes().to_vec());
+
+                    if let Some(estimated_costs_only_with_layer_info) =
+                        estimated_costs_only_with_layer_info
+                    {
+                        estimated_costs_only_with_layer_info.insert(
+                            KeyInfoPath::from_known_owned_path(term_path.clone()),
+                            EstimatedLayerInformation {
+                                tree_type: TreeType::NormalTree,
+                                estimated_layer_count: PotentiallyAtMaxElements,
+                                estimated_layer_sizes: AllReference(
+                                    DEFAULT_HASH_SIZE_U8,
+                                    document_reference_size(document_type),
+                                    flags_len,
+                                ),
+                            },
+                        );
+                    }
+
+                    batch_operations.push(
+                        LowLevelDriveOperation::insert_for_known_path_key_element(
+                            term_path,
+                            new_document.id().to_vec(),
+                            make_document_reference(new_document, document_type, storage_flags),
+                        ),
+                    );
+                }
+            }
+        }
+
+        Ok(batch_operations)
+    }
+}
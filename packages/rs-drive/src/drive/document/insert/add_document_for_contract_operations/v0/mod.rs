@@ -122,6 +122,34 @@ impl Drive {
             platform_version,
         )?;
 
+        if let Some((document, storage_flags)) = document_and_contract_info
+            .owned_document_info
+            .document_info
+            .get_borrowed_document_and_storage_flags()
+        {
+            // documents of a document type with a time to live are queued for their removal
+            batch_operations.extend(self.add_document_expiration_operations(
+                document,
+                document_and_contract_info.contract.id_ref().as_bytes(),
+                document_and_contract_info.document_type,
+                storage_flags,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            )?);
+
+            batch_operations.extend(self.update_full_text_index_references_operations(
+                None,
+                Some((document, storage_flags)),
+                document_and_contract_info.contract.id_ref().as_bytes(),
+                document_and_contract_info.document_type,
+                previous_batch_operations,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            )?);
+        }
+
         Ok(batch_operations)
     }
 }
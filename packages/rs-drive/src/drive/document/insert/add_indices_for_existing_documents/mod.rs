@@ -0,0 +1,57 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::DataContract;
+
+use dpp::version::PlatformVersion;
+
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Adds the index entries of every document already stored for a document type.
+    ///
+    /// This is used when a system contract gains new indexes during a protocol upgrade, the
+    /// documents stored before the upgrade would otherwise be missing from them. Only
+    /// non-unique indexes can be added this way, entries that already exist are overwritten
+    /// with the same reference. References are stored without storage flags, so the documents
+    /// must not be mutable or deletable.
+    ///
+    /// # Parameters
+    /// * `contract`: The contract with the new indexes.
+    /// * `document_type_name`: The name of the document type whose documents are indexed.
+    /// * `transaction`: The transaction argument.
+    /// * `platform_version`: The platform version to select the correct function version to run.
+    ///
+    /// # Returns
+    /// * `Ok(u64)` with the number of documents that were indexed.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub fn add_indices_for_existing_documents(
+        &self,
+        contract: &DataContract,
+        document_type_name: &str,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<u64, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .insert
+            .add_indices_for_existing_documents
+        {
+            0 => self.add_indices_for_existing_documents_v0(
+                contract,
+                document_type_name,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "add_indices_for_existing_documents".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
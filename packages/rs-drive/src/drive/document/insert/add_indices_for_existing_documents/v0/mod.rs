@@ -0,0 +1,95 @@
+use crate::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::query::DriveDocumentQuery;
+use crate::util::object_size_info::DocumentInfo::DocumentRefInfo;
+use crate::util::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::document::DocumentV0Getters;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Adds the index entries of every document already stored for a document type.
+    #[inline(always)]
+    pub(super) fn add_indices_for_existing_documents_v0(
+        &self,
+        contract: &DataContract,
+        document_type_name: &str,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<u64, Error> {
+        let document_type = contract.document_type_for_name(document_type_name)?;
+
+        let mut indexed_documents = 0;
+        let mut start_after = None;
+
+        loop {
+            // Documents are walked in primary key order, one page at a time
+            let mut query = DriveDocumentQuery::all_items_query(
+                contract,
+                document_type,
+                Some(self.config.default_query_limit),
+            );
+            query.start_at = start_after;
+            query.start_at_included = false;
+
+            let documents = self
+                .query_documents(
+                    query,
+                    None,
+                    false,
+                    transaction,
+                    Some(platform_version.protocol_version),
+                )?
+                .documents_owned();
+
+            let Some(last_document) = documents.last() else {
+                break;
+            };
+            start_after = Some(last_document.id().to_buffer());
+
+            let page_size = documents.len();
+
+            for document in &documents {
+                let document_and_contract_info = DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((document, None)),
+                        owner_id: Some(document.owner_id().to_buffer()),
+                    },
+                    contract,
+                    document_type,
+                };
+
+                let mut batch_operations = vec![];
+
+                self.add_indices_for_top_index_level_for_contract_operations(
+                    &document_and_contract_info,
+                    &mut None,
+                    &mut None,
+                    transaction,
+                    &mut batch_operations,
+                    platform_version,
+                )?;
+
+                self.apply_batch_low_level_drive_operations(
+                    None,
+                    transaction,
+                    batch_operations,
+                    &mut vec![],
+                    &platform_version.drive,
+                )?;
+            }
+
+            indexed_documents += page_size as u64;
+
+            if page_size < self.config.default_query_limit as usize {
+                break;
+            }
+        }
+
+        Ok(indexed_documents)
+    }
+}
@@ -23,6 +23,10 @@ mod add_document_for_contract_operations;
 // This module contains functionality for adding a document to primary storage
 mod add_document_to_primary_storage;
 
+// Module: add_indices_for_existing_documents
+// This module contains functionality for indexing documents stored before their indexes existed
+mod add_indices_for_existing_documents;
+
 // Module: add_indices_for_index_level_for_contract_operations
 // This module contains functionality for adding indices for an index level for contract operations
 mod add_indices_for_index_level_for_contract_operations;
@@ -25,6 +25,12 @@ use grovedb::Element;
 mod delete;
 #[cfg(feature = "server")]
 mod estimation_costs;
+/// Document expiration module
+#[cfg(feature = "server")]
+pub mod expiration;
+/// Document full text indexes module
+#[cfg(feature = "server")]
+pub mod full_text;
 #[cfg(feature = "server")]
 mod index_uniqueness;
 #[cfg(any(feature = "server", feature = "fixtures-and-mocks"))]
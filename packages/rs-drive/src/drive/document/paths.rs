@@ -40,6 +40,42 @@ pub(crate) fn contract_document_type_path_vec(
     ]
 }
 
+#[cfg(any(feature = "server", feature = "verify"))]
+/// The key of the tree under a document type that holds the terms of its full text indexes.
+pub const FULL_TEXT_INDEXES_TREE_KEY: u8 = 1;
+
+#[cfg(any(feature = "server", feature = "verify"))]
+/// Returns the path to the full text indexes tree of a contract document type.
+pub(crate) fn contract_document_type_full_text_indexes_path<'a>(
+    contract_id: &'a [u8],
+    document_type_name: &'a str,
+) -> [&'a [u8]; 5] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::DataContractDocuments),
+        contract_id,
+        &[1],
+        document_type_name.as_bytes(),
+        &[FULL_TEXT_INDEXES_TREE_KEY],
+    ]
+}
+
+#[cfg(any(feature = "server", feature = "verify"))]
+/// Returns the path to the terms of a full text indexed property of a contract document type.
+pub(crate) fn contract_document_type_full_text_property_path_vec(
+    contract_id: &[u8],
+    document_type_name: &str,
+    property_name: &str,
+) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::DataContractDocuments as u8],
+        contract_id.to_vec(),
+        vec![1u8],
+        document_type_name.as_bytes().to_vec(),
+        vec![FULL_TEXT_INDEXES_TREE_KEY],
+        property_name.as_bytes().to_vec(),
+    ]
+}
+
 #[cfg(feature = "server")]
 /// Returns the path to the primary keys of a contract document type.
 pub(crate) fn contract_documents_primary_key_path<'a>(
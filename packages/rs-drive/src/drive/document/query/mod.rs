@@ -6,6 +6,7 @@
 /// query of the vote state
 pub mod query_contested_documents_vote_state;
 mod query_documents;
+mod query_documents_aggregate;
 mod query_documents_with_flags;
 
 /// query of the contested documents in their storage
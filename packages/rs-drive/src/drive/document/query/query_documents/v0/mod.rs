@@ -82,12 +82,24 @@ impl Drive {
             return Ok(QueryDocumentsOutcomeV0::default());
         }
         let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
-        let (items, skipped) = query.execute_raw_results_no_proof_internal(
-            self,
-            transaction,
-            &mut drive_operations,
-            platform_version,
-        )?;
+        // Documents whose time to live was reached stay until a block end removes them, until
+        // then they are hidden from queries made at a known block time
+        let (items, skipped) = match query.block_time_ms {
+            Some(block_time_ms) => query.execute_raw_results_no_proof_excluding_expired_internal(
+                self,
+                block_time_ms,
+                None,
+                transaction,
+                &mut drive_operations,
+                platform_version,
+            )?,
+            None => query.execute_raw_results_no_proof_internal(
+                self,
+                transaction,
+                &mut drive_operations,
+                platform_version,
+            )?,
+        };
         let documents = items
             .into_iter()
             .map(|serialized| {
@@ -0,0 +1,59 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::query::{DocumentAggregateOutcome, DriveDocumentAggregateQuery};
+use dpp::block::epoch::Epoch;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+mod v0;
+
+impl Drive {
+    /// Computes the count or sum of documents matching an aggregate query, without proof.
+    ///
+    /// Matching documents are scanned in ranges of `max_query_limit` documents, starting
+    /// after `start_after` if provided. At most `max_aggregate_query_documents` documents
+    /// are scanned; if more documents match, the returned outcome holds the id of the last
+    /// scanned document in `next_start_after`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The [DriveDocumentAggregateQuery] being executed.
+    /// * `start_after` - Continue the aggregation after this document.
+    /// * `epoch` - An `Option<&Epoch>`. If provided, it will be used to calculate the processing fee.
+    /// * `transaction` - The `TransactionArg` holding the transaction data.
+    /// * `platform_version` - A reference to the `PlatformVersion` object specifying the version of functions to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(DocumentAggregateOutcome, u64), Error>` - The aggregate and the processing cost.
+    pub fn query_documents_aggregate(
+        &self,
+        query: &DriveDocumentAggregateQuery,
+        start_after: Option<[u8; 32]>,
+        epoch: Option<&Epoch>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(DocumentAggregateOutcome, u64), Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .query
+            .query_documents_aggregate
+        {
+            0 => self.query_documents_aggregate_v0(
+                query,
+                start_after,
+                epoch,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "query_documents_aggregate".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
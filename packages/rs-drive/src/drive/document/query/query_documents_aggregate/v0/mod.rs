@@ -0,0 +1,78 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::query::{DocumentAggregateOutcome, DriveDocumentAggregateQuery};
+use dpp::block::epoch::Epoch;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::Document;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    #[inline(always)]
+    pub(super) fn query_documents_aggregate_v0(
+        &self,
+        query: &DriveDocumentAggregateQuery,
+        start_after: Option<[u8; 32]>,
+        epoch: Option<&Epoch>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(DocumentAggregateOutcome, u64), Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let range_size = self.config.max_query_limit.max(1);
+        let max_documents = self.config.max_aggregate_query_documents.max(1) as u64;
+
+        let mut outcome = DocumentAggregateOutcome::default();
+        let mut start_after = start_after;
+
+        loop {
+            let remaining = max_documents - outcome.count;
+            let limit = range_size.min(remaining.min(u16::MAX as u64) as u16);
+
+            let range_query = query.range_query(start_after, Some(limit));
+            let (items, _) = range_query.execute_raw_results_no_proof_internal(
+                self,
+                transaction,
+                &mut drive_operations,
+                platform_version,
+            )?;
+            let documents = items
+                .into_iter()
+                .map(|serialized| {
+                    Document::from_bytes(
+                        serialized.as_slice(),
+                        query.query.document_type,
+                        platform_version,
+                    )
+                    .map_err(Error::from)
+                })
+                .collect::<Result<Vec<Document>, Error>>()?;
+
+            let range_outcome = query.aggregate_documents(&documents, Some(limit))?;
+            outcome.merge(range_outcome)?;
+
+            match outcome.next_start_after {
+                Some(last) if outcome.count < max_documents => {
+                    start_after = Some(last.to_buffer());
+                }
+                _ => break,
+            }
+        }
+
+        let cost = if let Some(epoch) = epoch {
+            let fee_result = Drive::calculate_fee(
+                None,
+                Some(drive_operations),
+                epoch,
+                self.config.epochs_per_era,
+                platform_version,
+                None,
+            )?;
+            fee_result.processing_fee
+        } else {
+            0
+        };
+
+        Ok((outcome, cost))
+    }
+}
@@ -171,7 +171,12 @@ impl Drive {
 
         let mut batch_insertion_cache: HashSet<Vec<Vec<u8>>> = HashSet::new();
         // fourth we need to store a reference to the document for each index
-        for index in document_type.indexes().values() {
+        // full text indexes keep their references under terms, they are updated separately
+        for index in document_type
+            .indexes()
+            .values()
+            .filter(|index| !index.full_text)
+        {
             // at this point the contract path is to the contract documents
             // for each index the top index component will already have been added
             // when the contract itself was created
@@ -477,6 +482,18 @@ impl Drive {
                 }
             }
         }
+
+        batch_operations.extend(self.update_full_text_index_references_operations(
+            old_document_info.get_borrowed_document(),
+            Some((document, storage_flags)),
+            contract.id_ref().as_bytes(),
+            document_type,
+            previous_batch_operations,
+            estimated_costs_only_with_layer_info,
+            transaction,
+            platform_version,
+        )?);
+
         Ok(batch_operations)
     }
 }
@@ -46,6 +46,10 @@ pub mod update;
 #[cfg(any(feature = "server", feature = "verify"))]
 pub mod identity_and_non_unique_public_key_hash_double_proof;
 
+/// Module related to the state transition history of identities
+#[cfg(any(feature = "server", feature = "verify"))]
+pub mod state_transition_history;
+
 use crate::drive::identity::contract_info::ContractInfoStructure;
 use crate::error::drive::DriveError;
 use crate::error::Error;
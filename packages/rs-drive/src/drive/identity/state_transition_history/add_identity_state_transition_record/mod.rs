@@ -0,0 +1,67 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::default_costs::CachedEpochIndexFeeVersions;
+use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Records a state transition in the history of the identity owning it.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The identity owning the state transition.
+    /// * `record` - The record of the state transition.
+    /// * `block_info` - Information about the current block, used to calculate the fees.
+    /// * `apply` - Whether to apply the record or only estimate its fees.
+    /// * `previous_fee_versions` - The fee versions of previous epochs.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    /// The fees of the record, owed by the identity owning the state transition.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_identity_state_transition_record(
+        &self,
+        identity_id: Identifier,
+        record: &IdentityStateTransitionRecord,
+        block_info: &BlockInfo,
+        apply: bool,
+        previous_fee_versions: Option<&CachedEpochIndexFeeVersions>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .state_transition_history
+            .add_identity_state_transition_record
+        {
+            0 => self.add_identity_state_transition_record_v0(
+                identity_id,
+                record,
+                block_info,
+                apply,
+                previous_fee_versions,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "add_identity_state_transition_record".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
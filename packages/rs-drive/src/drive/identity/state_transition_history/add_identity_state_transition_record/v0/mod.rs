@@ -0,0 +1,60 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::default_costs::CachedEpochIndexFeeVersions;
+use dpp::fee::fee_result::FeeResult;
+use dpp::identifier::Identifier;
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn add_identity_state_transition_record_v0(
+        &self,
+        identity_id: Identifier,
+        record: &IdentityStateTransitionRecord,
+        block_info: &BlockInfo,
+        apply: bool,
+        previous_fee_versions: Option<&CachedEpochIndexFeeVersions>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let mut estimated_costs_only_with_layer_info = if apply {
+            None::<HashMap<KeyInfoPath, EstimatedLayerInformation>>
+        } else {
+            Some(HashMap::new())
+        };
+
+        let batch_operations = self.add_identity_state_transition_record_operations(
+            identity_id.to_buffer(),
+            record,
+            &mut None,
+            &mut estimated_costs_only_with_layer_info,
+            transaction,
+            platform_version,
+        )?;
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+            previous_fee_versions,
+        )
+    }
+}
@@ -0,0 +1,53 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::HashMap;
+
+impl Drive {
+    /// Gathers the operations needed to record a state transition in the history of the
+    /// identity owning it.
+    ///
+    /// The history trees are created with the first state transition recorded for an identity.
+    /// `previous_batch_operations` are checked as well, so several state transitions of the same
+    /// identity can be recorded in one batch.
+    pub(crate) fn add_identity_state_transition_record_operations(
+        &self,
+        identity_id: [u8; 32],
+        record: &IdentityStateTransitionRecord,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .state_transition_history
+            .add_identity_state_transition_record_operations
+        {
+            0 => self.add_identity_state_transition_record_operations_v0(
+                identity_id,
+                record,
+                previous_batch_operations,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "add_identity_state_transition_record_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::drive::identity::state_transition_history::{
+    identity_state_transition_histories_path, identity_state_transition_histories_path_vec,
+    identity_state_transition_history_path_vec, identity_state_transition_record_key,
+    IDENTITY_STATE_TRANSITION_HISTORY_KEY, IDENTITY_STATE_TRANSITION_RECORD_KEY_SIZE,
+};
+use crate::drive::system::{misc_path, misc_path_vec};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::BatchInsertTreeApplyType;
+use crate::util::object_size_info::{DriveKeyInfo, PathInfo};
+use dpp::identity::state_transition_record::v0::IdentityStateTransitionRecordV0Getters;
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::serialization::PlatformSerializable;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, EstimatedLevel};
+use grovedb::EstimatedLayerSizes::{AllItems, AllSubtrees};
+use grovedb::EstimatedSumTrees::NoSumTrees;
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg, TreeType};
+use std::collections::HashMap;
+
+/// The approximate size of a serialized state transition record
+const ESTIMATED_IDENTITY_STATE_TRANSITION_RECORD_SIZE: u32 = 80;
+
+impl Drive {
+    pub(super) fn add_identity_state_transition_record_operations_v0(
+        &self,
+        identity_id: [u8; 32],
+        record: &IdentityStateTransitionRecord,
+        previous_batch_operations: &mut Option<&mut Vec<LowLevelDriveOperation>>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations = vec![];
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_identity_state_transition_history_v0(
+                identity_id,
+                estimated_costs_only_with_layer_info,
+            );
+        }
+
+        let apply_type = if estimated_costs_only_with_layer_info.is_none() {
+            BatchInsertTreeApplyType::StatefulBatchInsertTree
+        } else {
+            BatchInsertTreeApplyType::StatelessBatchInsertTree {
+                in_tree_type: TreeType::NormalTree,
+                tree_type: TreeType::NormalTree,
+                flags_len: 0,
+            }
+        };
+
+        // The history trees are created lazily, with the first state transition of an identity
+        let trees_to_create = [
+            (
+                misc_path_vec(),
+                IDENTITY_STATE_TRANSITION_HISTORY_KEY.to_vec(),
+            ),
+            (
+                identity_state_transition_histories_path_vec(),
+                identity_id.to_vec(),
+            ),
+        ];
+
+        for (path, key) in trees_to_create {
+            self.batch_insert_empty_tree_if_not_exists(
+                DriveKeyInfo::Key(key).add_path_info::<0>(PathInfo::PathAsVec(path)),
+                TreeType::NormalTree,
+                None,
+                apply_type,
+                transaction,
+                previous_batch_operations,
+                &mut drive_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        drive_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+            identity_state_transition_history_path_vec(identity_id),
+            identity_state_transition_record_key(record.block_height(), record.index_in_block()),
+            Element::new_item(record.serialize_to_bytes()?),
+        ));
+
+        Ok(drive_operations)
+    }
+
+    /// Adds estimated storage costs for the misc tree, the histories of all identities and the
+    /// history of the identity a state transition is recorded for.
+    fn add_estimation_costs_for_identity_state_transition_history_v0(
+        identity_id: [u8; 32],
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+    ) {
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(misc_path()),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(2, false),
+                estimated_layer_sizes: AllSubtrees(1, NoSumTrees, None),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(identity_state_transition_histories_path()),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: ApproximateElements(1_000_000),
+                estimated_layer_sizes: AllSubtrees(32, NoSumTrees, None),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(identity_state_transition_history_path_vec(
+                identity_id,
+            )),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(10, false),
+                estimated_layer_sizes: AllItems(
+                    IDENTITY_STATE_TRANSITION_RECORD_KEY_SIZE as u8,
+                    ESTIMATED_IDENTITY_STATE_TRANSITION_RECORD_SIZE,
+                    None,
+                ),
+            },
+        );
+    }
+}
@@ -0,0 +1,62 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::prelude::BlockHeight;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the state transitions recorded in the history of an identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The ID of the identity.
+    /// * `start_after` - An optional block height and index in the block to start after, for pagination.
+    /// * `limit` - An optional maximum number of state transitions to fetch.
+    /// * `order_ascending` - Whether to fetch the oldest state transitions first.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<IdentityStateTransitionRecord>, Error>` - The recorded state transitions in
+    ///   the requested order, empty if the identity has no history.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn fetch_identity_state_transition_records(
+        &self,
+        identity_id: [u8; 32],
+        start_after: Option<(BlockHeight, u32)>,
+        limit: Option<u16>,
+        order_ascending: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<IdentityStateTransitionRecord>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .state_transition_history
+            .fetch_identity_state_transition_records
+        {
+            0 => self.fetch_identity_state_transition_records_v0(
+                identity_id,
+                start_after,
+                limit,
+                order_ascending,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_identity_state_transition_records".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
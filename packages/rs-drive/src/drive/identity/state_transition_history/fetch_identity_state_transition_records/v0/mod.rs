@@ -0,0 +1,64 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::prelude::BlockHeight;
+use dpp::serialization::PlatformDeserializable;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::Element::Item;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn fetch_identity_state_transition_records_v0(
+        &self,
+        identity_id: [u8; 32],
+        start_after: Option<(BlockHeight, u32)>,
+        limit: Option<u16>,
+        order_ascending: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<IdentityStateTransitionRecord>, Error> {
+        let path_query = Self::identity_state_transition_records_query(
+            identity_id,
+            start_after,
+            limit,
+            order_ascending,
+        );
+
+        let elements = match self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryElementResultType,
+            &mut vec![],
+            &platform_version.drive,
+        ) {
+            Ok((elements, _)) => elements,
+            // The history trees are only created with the first state transition of an identity
+            Err(Error::GroveDB(e))
+                if matches!(
+                    e.as_ref(),
+                    grovedb::Error::PathKeyNotFound(_)
+                        | grovedb::Error::PathNotFound(_)
+                        | grovedb::Error::PathParentLayerNotFound(_)
+                ) =>
+            {
+                return Ok(vec![]);
+            }
+            Err(e) => return Err(e),
+        };
+
+        elements
+            .to_elements()
+            .into_iter()
+            .map(|element| match element {
+                Item(record_bytes, _) => Ok(IdentityStateTransitionRecord::deserialize_from_bytes(
+                    &record_bytes,
+                )?),
+                _ => Err(Error::Drive(DriveError::CorruptedDriveState(
+                    "identity state transition history should contain only items".to_string(),
+                ))),
+            })
+            .collect()
+    }
+}
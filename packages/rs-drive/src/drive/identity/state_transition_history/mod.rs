@@ -0,0 +1,108 @@
+//! Identity State Transition History
+//!
+//! From protocol version 11 every paid state transition of a block is recorded under the
+//! identity owning it, so wallets can list the activity of an identity. The identity pays for
+//! the storage of the record, state transitions of identities that can't pay are not recorded.
+//!
+//! The history lives in the misc tree, with one tree per identity created with its first
+//! recorded state transition:
+//!
+//! ```text
+//!   Misc
+//!    └── IDENTITY_STATE_TRANSITION_HISTORY_KEY
+//!         └── identity_id
+//!              └── encode_u64(block_height) + index_in_block => IdentityStateTransitionRecord
+//! ```
+
+#[cfg(feature = "server")]
+mod add_identity_state_transition_record;
+#[cfg(feature = "server")]
+mod add_identity_state_transition_record_operations;
+#[cfg(feature = "server")]
+mod fetch_identity_state_transition_records;
+#[cfg(feature = "server")]
+mod prove_identity_state_transition_records;
+
+use crate::drive::Drive;
+use crate::drive::RootTree;
+use crate::query::Query;
+use crate::util::common::encode::encode_u64;
+use dpp::prelude::BlockHeight;
+use grovedb::{PathQuery, SizedQuery};
+
+/// Key in the misc tree of the tree holding the state transition history of every identity
+pub const IDENTITY_STATE_TRANSITION_HISTORY_KEY: &[u8; 1] = b"H";
+
+/// The size of a key in the history of an identity, the block height followed by the index of
+/// the state transition in the block
+pub const IDENTITY_STATE_TRANSITION_RECORD_KEY_SIZE: usize = 8 + 4;
+
+/// The path of the tree holding the state transition history of every identity
+pub fn identity_state_transition_histories_path() -> [&'static [u8]; 2] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Misc),
+        IDENTITY_STATE_TRANSITION_HISTORY_KEY,
+    ]
+}
+
+/// The path of the tree holding the state transition history of every identity as a vector
+pub fn identity_state_transition_histories_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        IDENTITY_STATE_TRANSITION_HISTORY_KEY.to_vec(),
+    ]
+}
+
+/// The path of the state transition history of an identity as a vector
+pub fn identity_state_transition_history_path_vec(identity_id: [u8; 32]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        IDENTITY_STATE_TRANSITION_HISTORY_KEY.to_vec(),
+        identity_id.to_vec(),
+    ]
+}
+
+/// The key of a state transition in the history of an identity, ordering the history by
+/// execution
+pub fn identity_state_transition_record_key(
+    block_height: BlockHeight,
+    index_in_block: u32,
+) -> Vec<u8> {
+    let mut key = Vec::with_capacity(IDENTITY_STATE_TRANSITION_RECORD_KEY_SIZE);
+    key.extend(encode_u64(block_height));
+    key.extend_from_slice(&index_in_block.to_be_bytes());
+    key
+}
+
+impl Drive {
+    /// The query getting the state transitions of an identity in execution order.
+    ///
+    /// `start_after` is the block height and index in the block of the state transition the
+    /// query starts after, in the requested order.
+    pub fn identity_state_transition_records_query(
+        identity_id: [u8; 32],
+        start_after: Option<(BlockHeight, u32)>,
+        limit: Option<u16>,
+        order_ascending: bool,
+    ) -> PathQuery {
+        let mut query = Query::new_with_direction(order_ascending);
+
+        match start_after {
+            Some((block_height, index_in_block)) => {
+                let start_key = identity_state_transition_record_key(block_height, index_in_block);
+
+                if order_ascending {
+                    query.insert_range_after(start_key..);
+                } else {
+                    query.insert_range_to(..start_key);
+                }
+            }
+            None => query.insert_all(),
+        }
+
+        PathQuery::new(
+            identity_state_transition_history_path_vec(identity_id),
+            SizedQuery::new(query, limit, None),
+        )
+    }
+}
@@ -0,0 +1,60 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::prelude::BlockHeight;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the state transitions recorded in the history of an identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_id` - The ID of the identity.
+    /// * `start_after` - An optional block height and index in the block to start after, for pagination.
+    /// * `limit` - An optional maximum number of state transitions in the proved range.
+    /// * `order_ascending` - Whether to prove the oldest state transitions first.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - A grovedb proof, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn prove_identity_state_transition_records(
+        &self,
+        identity_id: [u8; 32],
+        start_after: Option<(BlockHeight, u32)>,
+        limit: Option<u16>,
+        order_ascending: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .identity
+            .state_transition_history
+            .prove_identity_state_transition_records
+        {
+            0 => self.prove_identity_state_transition_records_v0(
+                identity_id,
+                start_after,
+                limit,
+                order_ascending,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_identity_state_transition_records".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::prelude::BlockHeight;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn prove_identity_state_transition_records_v0(
+        &self,
+        identity_id: [u8; 32],
+        start_after: Option<(BlockHeight, u32)>,
+        limit: Option<u16>,
+        order_ascending: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query = Self::identity_state_transition_records_query(
+            identity_id,
+            start_after,
+            limit,
+            order_ascending,
+        );
+        self.grove_get_proved_path_query(
+            &path_query,
+            transaction,
+            &mut Vec::new(),
+            &platform_version.drive,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::identifier::Identifier;
+    use dpp::identity::state_transition_record::v0::IdentityStateTransitionRecordV0Getters;
+    use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+    use dpp::state_transition::StateTransitionType;
+    use dpp::version::PlatformVersion;
+
+    fn record(
+        block_height: BlockHeight,
+        index_in_block: u32,
+        error_code: Option<u32>,
+        platform_version: &PlatformVersion,
+    ) -> IdentityStateTransitionRecord {
+        IdentityStateTransitionRecord::new(
+            [block_height as u8; 32],
+            StateTransitionType::Batch,
+            block_height,
+            block_height * 1000,
+            index_in_block,
+            1_000_000,
+            error_code,
+            platform_version,
+        )
+        .expect("expected a record")
+    }
+
+    #[test]
+    fn should_prove_identity_state_transition_records_in_pages() {
+        let drive = setup_drive_with_initial_state_structure(None);
+
+        let platform_version = PlatformVersion::latest();
+
+        let identity_id = Identifier::new([1; 32]);
+        let other_identity_id = Identifier::new([2; 32]);
+
+        let records = vec![
+            (identity_id, record(1, 0, None, platform_version)),
+            (other_identity_id, record(1, 1, None, platform_version)),
+            (identity_id, record(1, 2, Some(10100), platform_version)),
+            (identity_id, record(2, 0, None, platform_version)),
+        ];
+
+        let fee_results: Vec<_> = records
+            .iter()
+            .map(|(identity_id, record)| {
+                let estimated_fee_result = drive
+                    .add_identity_state_transition_record(
+                        *identity_id,
+                        record,
+                        &BlockInfo::default(),
+                        false,
+                        None,
+                        None,
+                        platform_version,
+                    )
+                    .expect("expected to estimate the record fees");
+
+                let fee_result = drive
+                    .add_identity_state_transition_record(
+                        *identity_id,
+                        record,
+                        &BlockInfo::default(),
+                        true,
+                        None,
+                        None,
+                        platform_version,
+                    )
+                    .expect("expected to add record");
+
+                assert!(fee_result.storage_fee > 0);
+                assert!(estimated_fee_result.storage_fee > 0);
+
+                fee_result
+            })
+            .collect();
+
+        // The first record of an identity pays for the creation of its history
+        assert!(fee_results[0].storage_fee > fee_results[3].storage_fee);
+
+        let identity_records: Vec<_> = records
+            .iter()
+            .filter(|(owner_id, _)| owner_id == &identity_id)
+            .map(|(_, record)| record.clone())
+            .collect();
+
+        let fetched = drive
+            .fetch_identity_state_transition_records(
+                identity_id.to_buffer(),
+                None,
+                None,
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch records");
+
+        assert_eq!(fetched, identity_records);
+
+        let start_after = Some((1, 0));
+
+        let proof = drive
+            .prove_identity_state_transition_records(
+                identity_id.to_buffer(),
+                start_after,
+                Some(1),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to prove records");
+
+        let (_, proved): (_, Vec<IdentityStateTransitionRecord>) =
+            Drive::verify_identity_state_transition_records(
+                &proof,
+                identity_id.to_buffer(),
+                start_after,
+                Some(1),
+                true,
+                false,
+                platform_version,
+            )
+            .expect("expected to verify proof");
+
+        assert_eq!(proved, vec![identity_records[1].clone()]);
+        assert!(!proved[0].is_successful());
+
+        let proof = drive
+            .prove_identity_state_transition_records(
+                identity_id.to_buffer(),
+                None,
+                None,
+                false,
+                None,
+                platform_version,
+            )
+            .expect("expected to prove records");
+
+        let (_, proved): (_, Vec<IdentityStateTransitionRecord>) =
+            Drive::verify_identity_state_transition_records(
+                &proof,
+                identity_id.to_buffer(),
+                None,
+                None,
+                false,
+                false,
+                platform_version,
+            )
+            .expect("expected to verify proof");
+
+        assert_eq!(
+            proved
+                .iter()
+                .map(|record| (record.block_height(), record.index_in_block()))
+                .collect::<Vec<_>>(),
+            vec![(2, 0), (1, 2), (1, 0)]
+        );
+    }
+
+    #[test]
+    fn should_prove_absent_identity_state_transition_history() {
+        let drive = setup_drive_with_initial_state_structure(None);
+
+        let platform_version = PlatformVersion::latest();
+
+        let fetched = drive
+            .fetch_identity_state_transition_records(
+                [3; 32],
+                None,
+                None,
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch records");
+
+        assert!(fetched.is_empty());
+
+        let proof = drive
+            .prove_identity_state_transition_records(
+                [3; 32],
+                None,
+                None,
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to prove records");
+
+        let (_, proved): (_, Vec<IdentityStateTransitionRecord>) =
+            Drive::verify_identity_state_transition_records(
+                &proof,
+                [3; 32],
+                None,
+                None,
+                true,
+                false,
+                platform_version,
+            )
+            .expect("expected to verify proof");
+
+        assert!(proved.is_empty());
+    }
+}
@@ -0,0 +1,32 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::prelude::BlockHeight;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+mod v0;
+
+impl Drive {
+    /// Fetches the state transitions executed in a block from grovedb aux storage
+    pub fn fetch_block_state_transitions_bytes(
+        &self,
+        block_height: BlockHeight,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .platform_state
+            .fetch_block_state_transitions_bytes
+        {
+            0 => self.fetch_block_state_transitions_bytes_v0(block_height, transaction),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_block_state_transitions_bytes".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
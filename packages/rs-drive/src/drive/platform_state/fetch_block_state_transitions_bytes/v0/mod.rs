@@ -0,0 +1,18 @@
+use crate::drive::platform_state::block_state_transitions_key;
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::prelude::BlockHeight;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn fetch_block_state_transitions_bytes_v0(
+        &self,
+        block_height: BlockHeight,
+        transaction: TransactionArg,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        self.grove
+            .get_aux(block_state_transitions_key(block_height), transaction)
+            .unwrap()
+            .map_err(Error::from)
+    }
+}
@@ -1,4 +1,24 @@
+mod fetch_block_state_transitions_bytes;
 mod fetch_platform_state_bytes;
+mod store_block_state_transitions_bytes;
 mod store_platform_state_bytes;
 
+use dpp::prelude::BlockHeight;
+
 const PLATFORM_STATE_KEY: &[u8; 11] = b"saved_state";
+
+const BLOCK_STATE_TRANSITIONS_KEY_PREFIX: &[u8; 23] = b"block_state_transitions";
+
+/// The maximum number of blocks whose state transitions are removed when a block is stored,
+/// the remaining ones are removed with the following blocks
+const MAX_PRUNED_BLOCK_STATE_TRANSITIONS_PER_BLOCK: u64 = 100;
+
+/// The aux storage key of the lowest block height whose state transitions may still be stored
+const LOWEST_BLOCK_STATE_TRANSITIONS_HEIGHT_KEY: &[u8; 30] = b"lowest_block_state_transitions";
+
+/// The aux storage key of the state transitions executed in a block
+fn block_state_transitions_key(block_height: BlockHeight) -> Vec<u8> {
+    let mut key = BLOCK_STATE_TRANSITIONS_KEY_PREFIX.to_vec();
+    key.extend_from_slice(&block_height.to_be_bytes());
+    key
+}
@@ -0,0 +1,45 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::prelude::BlockHeight;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Store the state transitions executed in a block in grovedb aux storage.
+    ///
+    /// Aux storage is not part of the state, so it can differ between nodes. The state
+    /// transitions of every block up to `pruned_up_to_block_height` are removed, if any, to
+    /// bound the storage used, even when the retention window shrinks between blocks. At most
+    /// `MAX_PRUNED_BLOCK_STATE_TRANSITIONS_PER_BLOCK` blocks are removed per call, a larger
+    /// backlog is removed by the following calls.
+    pub fn store_block_state_transitions_bytes(
+        &self,
+        block_height: BlockHeight,
+        block_state_transitions_bytes: &[u8],
+        pruned_up_to_block_height: Option<BlockHeight>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .platform_state
+            .store_block_state_transitions_bytes
+        {
+            0 => self.store_block_state_transitions_bytes_v0(
+                block_height,
+                block_state_transitions_bytes,
+                pruned_up_to_block_height,
+                transaction,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "store_block_state_transitions_bytes".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::drive::platform_state::{
+    block_state_transitions_key, LOWEST_BLOCK_STATE_TRANSITIONS_HEIGHT_KEY,
+    MAX_PRUNED_BLOCK_STATE_TRANSITIONS_PER_BLOCK,
+};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::prelude::BlockHeight;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn store_block_state_transitions_bytes_v0(
+        &self,
+        block_height: BlockHeight,
+        block_state_transitions_bytes: &[u8],
+        pruned_up_to_block_height: Option<BlockHeight>,
+        transaction: TransactionArg,
+    ) -> Result<(), Error> {
+        self.grove
+            .put_aux(
+                block_state_transitions_key(block_height),
+                block_state_transitions_bytes,
+                None,
+                transaction,
+            )
+            .unwrap()
+            .map_err(Error::from)?;
+
+        let stored_lowest_block_height = self
+            .grove
+            .get_aux(LOWEST_BLOCK_STATE_TRANSITIONS_HEIGHT_KEY, transaction)
+            .unwrap()
+            .map_err(Error::from)?
+            .map(|bytes| {
+                bytes
+                    .try_into()
+                    .map(BlockHeight::from_be_bytes)
+                    .map_err(|_| {
+                        Error::Drive(DriveError::CorruptedSerialization(
+                            "lowest block state transitions height must be 8 bytes".to_string(),
+                        ))
+                    })
+            })
+            .transpose()?;
+
+        // The first stored block is the lowest one until blocks are pruned
+        let mut lowest_block_height = stored_lowest_block_height.unwrap_or(block_height);
+
+        if let Some(pruned_up_to_block_height) = pruned_up_to_block_height {
+            // The retention window can shrink between blocks, so every block that fell out of
+            // it since the last pruning is removed. The deletions are capped per block, a large
+            // gap is removed over the following blocks
+            let pruned_up_to_block_height = pruned_up_to_block_height.min(
+                lowest_block_height
+                    .saturating_add(MAX_PRUNED_BLOCK_STATE_TRANSITIONS_PER_BLOCK - 1),
+            );
+
+            for pruned_block_height in lowest_block_height..=pruned_up_to_block_height {
+                self.grove
+                    .delete_aux(
+                        block_state_transitions_key(pruned_block_height),
+                        None,
+                        transaction,
+                    )
+                    .unwrap()
+                    .map_err(Error::from)?;
+            }
+
+            lowest_block_height =
+                lowest_block_height.max(pruned_up_to_block_height.saturating_add(1));
+        }
+
+        if stored_lowest_block_height != Some(lowest_block_height) {
+            self.grove
+                .put_aux(
+                    LOWEST_BLOCK_STATE_TRANSITIONS_HEIGHT_KEY,
+                    &lowest_block_height.to_be_bytes(),
+                    None,
+                    transaction,
+                )
+                .unwrap()
+                .map_err(Error::from)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::version::PlatformVersion;
+
+    fn lowest_block_height(drive: &Drive) -> BlockHeight {
+        let bytes = drive
+            .grove
+            .get_aux(LOWEST_BLOCK_STATE_TRANSITIONS_HEIGHT_KEY, None)
+            .unwrap()
+            .expect("expected to get the lowest height")
+            .expect("expected the lowest height to be stored");
+
+        BlockHeight::from_be_bytes(bytes.try_into().expect("expected 8 bytes"))
+    }
+
+    fn is_stored(drive: &Drive, block_height: BlockHeight) -> bool {
+        drive
+            .fetch_block_state_transitions_bytes(block_height, None, PlatformVersion::latest())
+            .expect("expected to fetch")
+            .is_some()
+    }
+
+    #[test]
+    fn should_prune_a_large_gap_over_several_blocks() {
+        let drive = setup_drive_with_initial_state_structure(None);
+
+        for block_height in 1..=3 {
+            drive
+                .store_block_state_transitions_bytes_v0(block_height, &[1], None, None)
+                .expect("expected to store");
+        }
+
+        // Storing was disabled for a while, everything up to block 490 fell out of the window
+        drive
+            .store_block_state_transitions_bytes_v0(500, &[2], Some(490), None)
+            .expect("expected to store");
+
+        assert!((1..=3).all(|block_height| !is_stored(&drive, block_height)));
+        assert!(is_stored(&drive, 500));
+        assert_eq!(
+            lowest_block_height(&drive),
+            1 + MAX_PRUNED_BLOCK_STATE_TRANSITIONS_PER_BLOCK
+        );
+
+        // The rest of the gap is removed with the following blocks
+        for block_height in 501..=510 {
+            drive
+                .store_block_state_transitions_bytes_v0(
+                    block_height,
+                    &[3],
+                    Some(block_height - 10),
+                    None,
+                )
+                .expect("expected to store");
+        }
+
+        assert_eq!(lowest_block_height(&drive), 501);
+        assert!(!is_stored(&drive, 500));
+        assert!((501..=510).all(|block_height| is_stored(&drive, block_height)));
+    }
+}
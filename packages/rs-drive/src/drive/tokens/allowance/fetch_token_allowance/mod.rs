@@ -0,0 +1,131 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the allowance an owner granted a spender for a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token.
+    /// * `owner_id` - The ID of the identity that granted the allowance.
+    /// * `spender` - The identity or group the allowance was granted to.
+    /// * `transaction` - The current transaction.
+    /// * `platform_version` - The platform version to use.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<TokenAllowance>, Error>` - The allowance if one was granted, or an error.
+    pub fn fetch_token_allowance(
+        &self,
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        spender: TokenAllowanceSpender,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TokenAllowance>, Error> {
+        match platform_version.drive.methods.token.fetch.token_allowance {
+            0 => self.fetch_token_allowance_v0(
+                token_id,
+                owner_id,
+                spender,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_allowance".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Fetches an allowance with costs (if `apply = true`) and returns associated fee result.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fetch_token_allowance_with_costs(
+        &self,
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        spender: TokenAllowanceSpender,
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Option<TokenAllowance>, FeeResult), Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let value = self.fetch_token_allowance_operations(
+            token_id,
+            owner_id,
+            spender,
+            apply,
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )?;
+
+        let fees = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+
+        Ok((value, fees))
+    }
+
+    /// Creates the operations to get the allowance an owner granted a spender for a token.
+    /// If `apply` is false, the operations are stateless and only used for cost estimation.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token.
+    /// * `owner_id` - The ID of the identity that granted the allowance.
+    /// * `spender` - The identity or group the allowance was granted to.
+    /// * `apply` - Whether to fetch actual stateful data (true) or just estimate costs (false).
+    /// * `transaction` - The current transaction.
+    /// * `drive_operations` - The drive operations vector to populate.
+    /// * `platform_version` - The platform version to use.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<TokenAllowance>, Error>` - The allowance if one was granted, or an error.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fetch_token_allowance_operations(
+        &self,
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        spender: TokenAllowanceSpender,
+        apply: bool,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TokenAllowance>, Error> {
+        match platform_version.drive.methods.token.fetch.token_allowance {
+            0 => self.fetch_token_allowance_operations_v0(
+                token_id,
+                owner_id,
+                spender,
+                apply,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_allowance_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::drive::tokens::paths::token_owner_allowances_path;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use crate::util::grove_operations::QueryTarget::QueryTargetValue;
+use dpp::serialization::PlatformDeserializable;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
+use dpp::version::PlatformVersion;
+use grovedb::Element::Item;
+use grovedb::{TransactionArg, TreeType};
+
+/// The estimated size of a serialized allowance, used for cost estimation.
+const ESTIMATED_SERIALIZED_TOKEN_ALLOWANCE_SIZE: u32 = 120;
+
+impl Drive {
+    pub(super) fn fetch_token_allowance_v0(
+        &self,
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        spender: TokenAllowanceSpender,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TokenAllowance>, Error> {
+        self.fetch_token_allowance_operations_v0(
+            token_id,
+            owner_id,
+            spender,
+            true,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn fetch_token_allowance_operations_v0(
+        &self,
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        spender: TokenAllowanceSpender,
+        apply: bool,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TokenAllowance>, Error> {
+        let direct_query_type = if apply {
+            DirectQueryType::StatefulDirectQuery
+        } else {
+            DirectQueryType::StatelessDirectQuery {
+                in_tree_type: TreeType::NormalTree,
+                query_target: QueryTargetValue(ESTIMATED_SERIALIZED_TOKEN_ALLOWANCE_SIZE),
+            }
+        };
+
+        let allowances_path = token_owner_allowances_path(&token_id, &owner_id);
+
+        match self.grove_get_raw_optional(
+            (&allowances_path).into(),
+            &spender.to_key(),
+            direct_query_type,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        ) {
+            Ok(Some(Item(allowance_bytes, _))) => Ok(Some(TokenAllowance::deserialize_from_bytes(
+                allowance_bytes.as_slice(),
+            )?)),
+
+            Ok(None) => Ok(None),
+            // The allowance trees are only created with the first allowance granted by an owner
+            Err(Error::GroveDB(e))
+                if matches!(
+                    e.as_ref(),
+                    grovedb::Error::PathKeyNotFound(_)
+                        | grovedb::Error::PathNotFound(_)
+                        | grovedb::Error::PathParentLayerNotFound(_)
+                ) =>
+            {
+                Ok(None)
+            }
+
+            Ok(Some(_)) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "token allowance was present but was not an item",
+            ))),
+
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the allowances an owner granted for a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token.
+    /// * `owner_id` - The ID of the identity that granted the allowances.
+    /// * `start_after` - An optional spender to start after, for pagination.
+    /// * `limit` - An optional maximum number of allowances to return.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<TokenAllowance>, Error>` - The allowances ordered by their spender, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn fetch_token_allowances(
+        &self,
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        start_after: Option<TokenAllowanceSpender>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenAllowance>, Error> {
+        match platform_version.drive.methods.token.fetch.token_allowances {
+            0 => self.fetch_token_allowances_v0(
+                token_id,
+                owner_id,
+                start_after,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_allowances".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Creates the low-level operations needed to fetch the allowances an owner granted for a
+    /// token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token.
+    /// * `owner_id` - The ID of the identity that granted the allowances.
+    /// * `start_after` - An optional spender to start after, for pagination.
+    /// * `limit` - An optional maximum number of allowances to return.
+    /// * `transaction` - The current transaction context.
+    /// * `drive_operations` - A vector to store the created low-level drive operations.
+    /// * `platform_version` - The platform version to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<TokenAllowance>, Error>` - The allowances ordered by their spender, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fetch_token_allowances_operations(
+        &self,
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        start_after: Option<TokenAllowanceSpender>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenAllowance>, Error> {
+        match platform_version.drive.methods.token.fetch.token_allowances {
+            0 => self.fetch_token_allowances_operations_v0(
+                token_id,
+                owner_id,
+                start_after,
+                limit,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_allowances_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::serialization::PlatformDeserializable;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::Element::Item;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn fetch_token_allowances_v0(
+        &self,
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        start_after: Option<TokenAllowanceSpender>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenAllowance>, Error> {
+        self.fetch_token_allowances_operations_v0(
+            token_id,
+            owner_id,
+            start_after,
+            limit,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn fetch_token_allowances_operations_v0(
+        &self,
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        start_after: Option<TokenAllowanceSpender>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenAllowance>, Error> {
+        let path_query =
+            Self::token_allowances_for_owner_query(token_id, owner_id, start_after, limit);
+
+        let elements = match self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryElementResultType,
+            drive_operations,
+            &platform_version.drive,
+        ) {
+            Ok((elements, _)) => elements,
+            // The allowance trees are only created with the first allowance granted by an owner
+            Err(Error::GroveDB(e))
+                if matches!(
+                    e.as_ref(),
+                    grovedb::Error::PathKeyNotFound(_)
+                        | grovedb::Error::PathNotFound(_)
+                        | grovedb::Error::PathParentLayerNotFound(_)
+                ) =>
+            {
+                return Ok(vec![]);
+            }
+            Err(e) => return Err(e),
+        };
+
+        elements
+            .to_elements()
+            .into_iter()
+            .map(|element| match element {
+                Item(allowance_bytes, _) => {
+                    Ok(TokenAllowance::deserialize_from_bytes(&allowance_bytes)?)
+                }
+                _ => Err(Error::Drive(DriveError::CorruptedDriveState(
+                    "token allowances tree should contain only items".to_string(),
+                ))),
+            })
+            .collect()
+    }
+}
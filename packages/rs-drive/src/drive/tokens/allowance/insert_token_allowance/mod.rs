@@ -0,0 +1,41 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::tokens::allowance::TokenAllowance;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use platform_version::version::PlatformVersion;
+use std::collections::HashMap;
+
+impl Drive {
+    /// Gathers the operations needed to store a new allowance an owner granted for a token.
+    ///
+    /// The allowance trees of the token and of the owner are created if this is their first
+    /// allowance.
+    pub fn token_insert_allowance_operations(
+        &self,
+        allowance: &TokenAllowance,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version.drive.methods.token.update.insert_allowance {
+            0 => self.token_insert_allowance_operations_v0(
+                allowance,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "token_insert_allowance_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::drive::tokens::paths::{
+    token_allowances_path_vec, token_allowances_root_path_vec, token_owner_allowances_path_vec,
+    tokens_root_path_vec, TOKEN_ALLOWANCES_KEY,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::BatchInsertTreeApplyType;
+use crate::util::object_size_info::{DriveKeyInfo, PathInfo};
+use dpp::serialization::PlatformSerializable;
+use dpp::tokens::allowance::v0::TokenAllowanceV0Accessors;
+use dpp::tokens::allowance::TokenAllowance;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg, TreeType};
+use platform_version::version::PlatformVersion;
+use std::collections::HashMap;
+
+impl Drive {
+    pub(super) fn token_insert_allowance_operations_v0(
+        &self,
+        allowance: &TokenAllowance,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations = vec![];
+
+        let token_id = allowance.token_id().to_buffer();
+        let owner_id = allowance.owner_id().to_buffer();
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_token_allowances(
+                token_id,
+                owner_id,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        let apply_type = if estimated_costs_only_with_layer_info.is_none() {
+            BatchInsertTreeApplyType::StatefulBatchInsertTree
+        } else {
+            BatchInsertTreeApplyType::StatelessBatchInsertTree {
+                in_tree_type: TreeType::NormalTree,
+                tree_type: TreeType::NormalTree,
+                flags_len: 0,
+            }
+        };
+
+        // The allowance trees are created lazily, with the first allowance granted by an owner
+        let trees_to_create = [
+            (tokens_root_path_vec(), vec![TOKEN_ALLOWANCES_KEY]),
+            (token_allowances_root_path_vec(), token_id.to_vec()),
+            (token_allowances_path_vec(token_id), owner_id.to_vec()),
+        ];
+
+        for (path, key) in trees_to_create {
+            self.batch_insert_empty_tree_if_not_exists(
+                DriveKeyInfo::Key(key).add_path_info::<0>(PathInfo::PathAsVec(path)),
+                TreeType::NormalTree,
+                None,
+                apply_type,
+                transaction,
+                &mut None,
+                &mut drive_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        drive_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+            token_owner_allowances_path_vec(token_id, owner_id),
+            allowance.spender().to_key(),
+            Element::new_item(allowance.serialize_to_bytes()?),
+        ));
+
+        Ok(drive_operations)
+    }
+}
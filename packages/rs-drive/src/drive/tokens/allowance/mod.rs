@@ -0,0 +1,13 @@
+#[cfg(feature = "server")]
+mod fetch_token_allowance;
+#[cfg(feature = "server")]
+mod fetch_token_allowances;
+#[cfg(feature = "server")]
+mod insert_token_allowance;
+#[cfg(feature = "server")]
+mod prove_token_allowances;
+mod queries;
+#[cfg(feature = "server")]
+mod remove_token_allowance;
+#[cfg(feature = "server")]
+mod update_token_allowance;
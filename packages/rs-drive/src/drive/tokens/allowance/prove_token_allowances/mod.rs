@@ -0,0 +1,54 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the allowances an owner granted for a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token.
+    /// * `owner_id` - The ID of the identity that granted the allowances.
+    /// * `start_after` - An optional spender to start after, for pagination.
+    /// * `limit` - An optional maximum number of allowances in the proved range.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - A grovedb proof, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn prove_token_allowances(
+        &self,
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        start_after: Option<TokenAllowanceSpender>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version.drive.methods.token.prove.token_allowances {
+            0 => self.prove_token_allowances_v0(
+                token_id,
+                owner_id,
+                start_after,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_token_allowances".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn prove_token_allowances_v0(
+        &self,
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        start_after: Option<TokenAllowanceSpender>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query =
+            Self::token_allowances_for_owner_query(token_id, owner_id, start_after, limit);
+        self.grove_get_proved_path_query(
+            &path_query,
+            transaction,
+            &mut Vec::new(),
+            &platform_version.drive,
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use crate::drive::tokens::paths::token_owner_allowances_path_vec;
+use crate::drive::Drive;
+use crate::query::Query;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use grovedb::{PathQuery, SizedQuery};
+
+impl Drive {
+    /// The query getting the allowance an owner granted a spender for a token
+    pub fn token_allowance_query(
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        spender: TokenAllowanceSpender,
+    ) -> PathQuery {
+        let allowances_path = token_owner_allowances_path_vec(token_id, owner_id);
+
+        let mut query = Query::new();
+
+        query.insert_key(spender.to_key());
+
+        PathQuery::new(allowances_path, SizedQuery::new(query, Some(1), None))
+    }
+
+    /// The query getting the allowances an owner granted for a token
+    ///
+    /// Allowances are returned ordered by their spender, identities first then groups, starting
+    /// after `start_after` if it is set.
+    pub fn token_allowances_for_owner_query(
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        start_after: Option<TokenAllowanceSpender>,
+        limit: Option<u16>,
+    ) -> PathQuery {
+        let allowances_path = token_owner_allowances_path_vec(token_id, owner_id);
+
+        let mut query = Query::new();
+
+        match start_after {
+            Some(start_after) => query.insert_range_after(start_after.to_key()..),
+            None => query.insert_all(),
+        }
+
+        PathQuery::new(allowances_path, SizedQuery::new(query, limit, None))
+    }
+}
@@ -0,0 +1,40 @@
+use crate::drive::tokens::paths::token_owner_allowances_path_vec;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::tokens::allowance::v0::TokenAllowanceV0Accessors;
+use dpp::tokens::allowance::TokenAllowance;
+use grovedb::batch::{KeyInfoPath, QualifiedGroveDbOp};
+use grovedb::EstimatedLayerInformation;
+use platform_version::version::PlatformVersion;
+use std::collections::HashMap;
+
+impl Drive {
+    pub(super) fn token_remove_allowance_operations_v0(
+        &self,
+        allowance: &TokenAllowance,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let token_id = allowance.token_id().to_buffer();
+        let owner_id = allowance.owner_id().to_buffer();
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_token_allowances(
+                token_id,
+                owner_id,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(vec![LowLevelDriveOperation::GroveOperation(
+            QualifiedGroveDbOp::delete_op(
+                token_owner_allowances_path_vec(token_id, owner_id),
+                allowance.spender().to_key(),
+            ),
+        )])
+    }
+}
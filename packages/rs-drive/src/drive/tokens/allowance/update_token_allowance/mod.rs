@@ -0,0 +1,37 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::tokens::allowance::TokenAllowance;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerInformation;
+use platform_version::version::PlatformVersion;
+use std::collections::HashMap;
+
+impl Drive {
+    /// Gathers the operations needed to replace an existing allowance, after it was spent from or
+    /// granted again.
+    pub fn token_update_allowance_operations(
+        &self,
+        allowance: &TokenAllowance,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version.drive.methods.token.update.update_allowance {
+            0 => self.token_update_allowance_operations_v0(
+                allowance,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "token_update_allowance_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::balances::credits::SumTokenAmount;
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::{BTreeMap, HashMap};
+
+impl Drive {
+    /// The operations for moving a token between many identities at once.
+    ///
+    /// Every identity's balance is changed at most once, by its net change, which is what lets
+    /// a single batch settle several trades touching the same balance. The changes must sum up
+    /// to zero, as no tokens are created or destroyed.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the Token.
+    /// * `balance_changes` - The net change of the balance of each identity.
+    /// * `estimated_costs_only_with_layer_info` - Estimation info, only set when estimating costs.
+    /// * `transaction` - The transaction information related to the operation.
+    /// * `platform_version` - The platform version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<LowLevelDriveOperation>, Error>` - The operations if successful, or an error.
+    pub fn token_apply_balance_changes_operations(
+        &self,
+        token_id: [u8; 32],
+        balance_changes: BTreeMap<Identifier, SumTokenAmount>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .token
+            .update
+            .apply_balance_changes
+        {
+            0 => self.token_apply_balance_changes_operations_v0(
+                token_id,
+                balance_changes,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "token_apply_balance_changes_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
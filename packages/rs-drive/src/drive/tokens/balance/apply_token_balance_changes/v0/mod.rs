@@ -0,0 +1,71 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::balances::credits::{SumTokenAmount, TokenAmount};
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+use dpp::ProtocolError;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::{BTreeMap, HashMap};
+
+impl Drive {
+    pub(super) fn token_apply_balance_changes_operations_v0(
+        &self,
+        token_id: [u8; 32],
+        balance_changes: BTreeMap<Identifier, SumTokenAmount>,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let net_change = balance_changes
+            .values()
+            .try_fold(0 as SumTokenAmount, |total, change| {
+                total.checked_add(*change)
+            })
+            .ok_or(Error::Protocol(Box::new(ProtocolError::Overflow(
+                "token balance changes overflow",
+            ))))?;
+
+        if net_change != 0 {
+            return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                "token balance changes must not create or destroy tokens",
+            )));
+        }
+
+        let mut drive_operations = vec![];
+
+        for (identity_id, change) in balance_changes {
+            let amount = TokenAmount::try_from(change.unsigned_abs()).map_err(|_| {
+                Error::Protocol(Box::new(ProtocolError::Overflow(
+                    "token balance change does not fit a token amount",
+                )))
+            })?;
+
+            if change < 0 {
+                drive_operations.extend(self.remove_from_identity_token_balance_operations(
+                    token_id,
+                    identity_id.to_buffer(),
+                    amount,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                )?);
+            } else if change > 0 {
+                drive_operations.extend(self.add_to_identity_token_balance_operations(
+                    token_id,
+                    identity_id.to_buffer(),
+                    amount,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                )?);
+            }
+        }
+
+        Ok(drive_operations)
+    }
+}
@@ -0,0 +1,99 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::balances::credits::TokenAmount;
+use dpp::identifier::Identifier;
+use dpp::prelude::StartAtIncluded;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Fetches the balances of the holders of a token, ordered by identity id.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token whose holders are being queried.
+    /// * `start_at` - An optional identity id to start at, with a flag telling whether it is included.
+    /// * `limit` - The maximum number of holders to return.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<BTreeMap<Identifier, TokenAmount>, Error>` - A map of identity IDs to their balances, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn fetch_token_holders(
+        &self,
+        token_id: [u8; 32],
+        start_at: Option<([u8; 32], StartAtIncluded)>,
+        limit: u16,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Identifier, TokenAmount>, Error> {
+        match platform_version.drive.methods.token.fetch.token_holders {
+            0 => self.fetch_token_holders_v0(
+                token_id,
+                start_at,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_holders".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Creates the low-level operations needed to fetch the balances of the holders of a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token whose holders are being queried.
+    /// * `start_at` - An optional identity id to start at, with a flag telling whether it is included.
+    /// * `limit` - The maximum number of holders to return.
+    /// * `transaction` - The current transaction context.
+    /// * `drive_operations` - A vector to store the created low-level drive operations.
+    /// * `platform_version` - The platform version to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<BTreeMap<Identifier, TokenAmount>, Error>` - A map of identity IDs to their balances, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn fetch_token_holders_operations(
+        &self,
+        token_id: [u8; 32],
+        start_at: Option<([u8; 32], StartAtIncluded)>,
+        limit: u16,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Identifier, TokenAmount>, Error> {
+        match platform_version.drive.methods.token.fetch.token_holders {
+            0 => self.fetch_token_holders_operations_v0(
+                token_id,
+                start_at,
+                limit,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_holders_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::balances::credits::TokenAmount;
+use dpp::identifier::Identifier;
+use dpp::prelude::StartAtIncluded;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::Element::SumItem;
+use grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+impl Drive {
+    pub(super) fn fetch_token_holders_v0(
+        &self,
+        token_id: [u8; 32],
+        start_at: Option<([u8; 32], StartAtIncluded)>,
+        limit: u16,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Identifier, TokenAmount>, Error> {
+        self.fetch_token_holders_operations_v0(
+            token_id,
+            start_at,
+            limit,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    pub(super) fn fetch_token_holders_operations_v0(
+        &self,
+        token_id: [u8; 32],
+        start_at: Option<([u8; 32], StartAtIncluded)>,
+        limit: u16,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Identifier, TokenAmount>, Error> {
+        let path_query = Self::token_holders_query(token_id, start_at, limit);
+        let escrow_identity_ids = Self::token_escrow_identity_ids(token_id);
+
+        self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryKeyElementPairResultType,
+            drive_operations,
+            &platform_version.drive,
+        )?
+        .0
+        .to_key_elements_btree_map()
+        .into_iter()
+        .filter(|(key, _)| !escrow_identity_ids.iter().any(|escrow| key == escrow))
+        .take(limit as usize)
+        .map(|(key, element)| {
+            let identity_id: Identifier = key.try_into().map_err(|_| {
+                Error::Drive(DriveError::CorruptedDriveState(
+                    "identity id not 32 bytes".to_string(),
+                ))
+            })?;
+            match element {
+                SumItem(value, ..) => Ok((identity_id, value as TokenAmount)),
+                _ => Err(Error::Drive(DriveError::CorruptedDriveState(
+                    "token tree for balances should contain only sum items".to_string(),
+                ))),
+            }
+        })
+        .collect()
+    }
+}
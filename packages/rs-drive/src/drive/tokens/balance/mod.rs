@@ -1,15 +1,21 @@
 #[cfg(feature = "server")]
 mod add_to_previous_token_balance;
 #[cfg(feature = "server")]
+mod apply_token_balance_changes;
+#[cfg(feature = "server")]
 mod fetch_identities_token_balances;
 #[cfg(feature = "server")]
 mod fetch_identity_token_balance;
 #[cfg(feature = "server")]
 mod fetch_identity_token_balances;
 #[cfg(feature = "server")]
+mod fetch_token_holders;
+#[cfg(feature = "server")]
 mod prove_identities_token_balances;
 #[cfg(feature = "server")]
 mod prove_identity_token_balances;
+#[cfg(feature = "server")]
+mod prove_token_holders;
 mod queries;
 #[cfg(feature = "server")]
 mod remove_from_identity_token_balance;
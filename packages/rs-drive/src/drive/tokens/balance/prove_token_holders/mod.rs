@@ -0,0 +1,96 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::prelude::StartAtIncluded;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the balances of the holders of a token, ordered by identity id.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token whose holders are being queried.
+    /// * `start_at` - An optional identity id to start at, with a flag telling whether it is included.
+    /// * `limit` - The maximum number of holders to prove.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - The proof of the token holders' balances, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn prove_token_holders(
+        &self,
+        token_id: [u8; 32],
+        start_at: Option<([u8; 32], StartAtIncluded)>,
+        limit: u16,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version.drive.methods.token.prove.token_holders {
+            0 => self.prove_token_holders_v0(
+                token_id,
+                start_at,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_token_holders".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Creates the low-level operations needed to prove the balances of the holders of a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token whose holders are being queried.
+    /// * `start_at` - An optional identity id to start at, with a flag telling whether it is included.
+    /// * `limit` - The maximum number of holders to prove.
+    /// * `transaction` - The current transaction context.
+    /// * `drive_operations` - A vector to store the created low-level drive operations.
+    /// * `platform_version` - The platform version to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - The proof of the token holders' balances, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn prove_token_holders_operations(
+        &self,
+        token_id: [u8; 32],
+        start_at: Option<([u8; 32], StartAtIncluded)>,
+        limit: u16,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version.drive.methods.token.prove.token_holders {
+            0 => self.prove_token_holders_operations_v0(
+                token_id,
+                start_at,
+                limit,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_token_holders_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::prelude::StartAtIncluded;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn prove_token_holders_v0(
+        &self,
+        token_id: [u8; 32],
+        start_at: Option<([u8; 32], StartAtIncluded)>,
+        limit: u16,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        self.prove_token_holders_operations_v0(
+            token_id,
+            start_at,
+            limit,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    pub(super) fn prove_token_holders_operations_v0(
+        &self,
+        token_id: [u8; 32],
+        start_at: Option<([u8; 32], StartAtIncluded)>,
+        limit: u16,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query = Self::token_holders_query(token_id, start_at, limit);
+
+        self.grove_get_proved_path_query(
+            &path_query,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::balances::credits::TokenAmount;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::accessors::v1::DataContractV1Getters;
+    use dpp::data_contract::associated_token::token_configuration::v0::TokenConfigurationV0;
+    use dpp::data_contract::associated_token::token_configuration::TokenConfiguration;
+    use dpp::data_contract::config::v0::DataContractConfigV0;
+    use dpp::data_contract::config::DataContractConfig;
+    use dpp::data_contract::v1::DataContractV1;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::Identity;
+    use dpp::prelude::DataContract;
+    use dpp::tokens::order_book::TokenOrder;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn should_prove_token_holders_in_pages_without_escrow() {
+        let drive = setup_drive_with_initial_state_structure(None);
+
+        let platform_version = PlatformVersion::latest();
+
+        let contract = DataContract::V1(DataContractV1 {
+            id: Default::default(),
+            version: 0,
+            owner_id: Default::default(),
+            document_types: Default::default(),
+            config: DataContractConfig::V0(DataContractConfigV0 {
+                can_be_deleted: false,
+                readonly: false,
+                keeps_history: false,
+                documents_keep_history_contract_default: false,
+                documents_mutable_contract_default: false,
+                documents_can_be_deleted_contract_default: false,
+                requires_identity_encryption_bounded_key: None,
+                requires_identity_decryption_bounded_key: None,
+            }),
+            schema_defs: None,
+            created_at: None,
+            updated_at: None,
+            created_at_block_height: None,
+            updated_at_block_height: None,
+            created_at_epoch: None,
+            updated_at_epoch: None,
+            groups: Default::default(),
+            tokens: BTreeMap::from([(
+                0,
+                TokenConfiguration::V0(TokenConfigurationV0::default_most_restrictive()),
+            )]),
+            keywords: Vec::new(),
+            description: None,
+        });
+        let token_id = contract.token_id(0).expect("expected token at position 0");
+
+        drive
+            .insert_contract(
+                &contract,
+                BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to insert contract");
+
+        let mut expected_holders = BTreeMap::new();
+        for i in 0..3u64 {
+            let identity = Identity::random_identity(3, Some(14 + i), platform_version)
+                .expect("expected a platform identity");
+
+            drive
+                .add_new_identity(
+                    identity.clone(),
+                    false,
+                    &BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to add an identity");
+
+            drive
+                .token_mint(
+                    token_id.to_buffer(),
+                    identity.id().to_buffer(),
+                    1000 * (i + 1),
+                    true,
+                    false,
+                    &BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to mint token");
+
+            expected_holders.insert(identity.id().to_buffer(), 1000 * (i + 1));
+        }
+
+        // Escrowed balances of resting orders are not held by a token holder
+        drive
+            .token_mint(
+                token_id.to_buffer(),
+                TokenOrder::escrow_identity_id(&token_id).to_buffer(),
+                500,
+                true,
+                false,
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to mint token to the order book escrow");
+
+        let proof = drive
+            .prove_token_holders_v0(token_id.to_buffer(), None, 2, None, platform_version)
+            .expect("should not error when proving token holders");
+
+        let first_page: BTreeMap<[u8; 32], TokenAmount> = Drive::verify_token_holders(
+            proof.as_slice(),
+            token_id.to_buffer(),
+            None,
+            2,
+            false,
+            platform_version,
+        )
+        .expect("expect that this be verified")
+        .1;
+
+        assert_eq!(first_page.len(), 2);
+
+        let last_identity_id = *first_page.keys().last().expect("expected a holder");
+
+        let proof = drive
+            .prove_token_holders_v0(
+                token_id.to_buffer(),
+                Some((last_identity_id, false)),
+                2,
+                None,
+                platform_version,
+            )
+            .expect("should not error when proving token holders");
+
+        let second_page: BTreeMap<[u8; 32], TokenAmount> = Drive::verify_token_holders(
+            proof.as_slice(),
+            token_id.to_buffer(),
+            Some((last_identity_id, false)),
+            2,
+            false,
+            platform_version,
+        )
+        .expect("expect that this be verified")
+        .1;
+
+        assert_eq!(second_page.len(), 1);
+
+        let all_holders: BTreeMap<[u8; 32], TokenAmount> =
+            first_page.into_iter().chain(second_page).collect();
+
+        assert_eq!(all_holders, expected_holders);
+    }
+}
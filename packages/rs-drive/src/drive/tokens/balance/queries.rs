@@ -3,6 +3,10 @@ use crate::drive::tokens::paths::{token_balances_path_vec, token_balances_root_p
 use crate::drive::Drive;
 use crate::error::Error;
 use crate::query::{Query, QueryItem};
+use dpp::identifier::Identifier;
+use dpp::prelude::StartAtIncluded;
+use dpp::tokens::order_book::TokenOrder;
+use dpp::tokens::vesting::TokenVesting;
 use grovedb::{PathQuery, SizedQuery};
 use platform_version::version::PlatformVersion;
 use std::ops::RangeFull;
@@ -37,6 +41,35 @@ impl Drive {
         }
     }
 
+    /// The identities holding escrowed balances of a token on behalf of other identities.
+    ///
+    /// They appear in the token balances tree but are not holders of the token.
+    pub fn token_escrow_identity_ids(token_id: [u8; 32]) -> [[u8; 32]; 2] {
+        let token_id = Identifier::new(token_id);
+        [
+            TokenOrder::escrow_identity_id(&token_id).to_buffer(),
+            TokenVesting::escrow_identity_id(&token_id).to_buffer(),
+        ]
+    }
+
+    /// The query getting the balances of all holders of a token, ordered by identity id
+    ///
+    /// The query asks for enough extra balances to still return `limit` holders once the
+    /// [`Drive::token_escrow_identity_ids`] are filtered out of the result.
+    pub fn token_holders_query(
+        token_id: [u8; 32],
+        start_at: Option<([u8; 32], StartAtIncluded)>,
+        limit: u16,
+    ) -> PathQuery {
+        let escrow_identity_count = Self::token_escrow_identity_ids(token_id).len() as u16;
+        Self::token_balances_for_range_query(
+            token_id,
+            start_at,
+            true,
+            limit.saturating_add(escrow_identity_count),
+        )
+    }
+
     /// The query getting token balances for a single identity and many tokens
     pub fn token_balances_for_identity_id_query(
         token_ids: &[[u8; 32]],
@@ -0,0 +1,53 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::version::drive_versions::DriveVersion;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerInformation;
+use std::collections::HashMap;
+
+impl Drive {
+    /// Adds estimation costs for changes to the allowances an owner granted for a token based on
+    /// the provided drive version.
+    ///
+    /// # Parameters
+    /// - `token_id`: A 32-byte identifier for the token whose allowances are being estimated.
+    /// - `owner_id`: A 32-byte identifier for the identity that granted the allowances.
+    /// - `estimated_costs_only_with_layer_info`: A mutable reference to a HashMap that holds
+    ///   `KeyInfoPath` and `EstimatedLayerInformation` for each allowance layer.
+    /// - `drive_version`: The version of the drive to determine which estimation logic to apply.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the operation is successful.
+    /// - `Err(DriveError::UnknownVersionMismatch)` if the provided `drive_version` does not match
+    ///   any known supported versions.
+    pub(crate) fn add_estimation_costs_for_token_allowances(
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        drive_version: &DriveVersion,
+    ) -> Result<(), Error> {
+        match drive_version
+            .methods
+            .identity
+            .cost_estimation
+            .for_token_allowances
+        {
+            0 => {
+                Self::add_estimation_costs_for_token_allowances_v0(
+                    token_id,
+                    owner_id,
+                    estimated_costs_only_with_layer_info,
+                );
+                Ok(())
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "add_estimation_costs_for_token_allowances".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::drive::Drive;
+
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, EstimatedLevel};
+use grovedb::EstimatedLayerSizes::{AllItems, AllSubtrees};
+use grovedb::{EstimatedLayerInformation, TreeType};
+
+use crate::drive::tokens::paths::{
+    token_allowances_path, token_allowances_root_path, token_owner_allowances_path,
+    tokens_root_path,
+};
+use crate::util::type_constants::DEFAULT_HASH_SIZE_U8;
+use grovedb::EstimatedSumTrees::{NoSumTrees, SomeSumTrees};
+use std::collections::HashMap;
+
+/// The estimated size of a serialized allowance
+const ESTIMATED_SERIALIZED_TOKEN_ALLOWANCE_SIZE: u32 = 120;
+
+/// The size of a spender key, a prefix byte followed by an identifier
+const SPENDER_KEY_SIZE_U8: u8 = 33;
+
+impl Drive {
+    /// Adds estimated storage costs related to the allowances an owner granted for a token (v0).
+    ///
+    /// The following layers are estimated:
+    ///
+    /// 1. **Top-Level Structure (`/`)**: the root, 2 levels above the token trees.
+    /// 2. **Token Root Path (`/tokens`)**: a mixture of normal and sum trees.
+    /// 3. **Allowances Root (`/tokens/allowances`)**: one subtree per token with allowances.
+    /// 4. **Token Allowances (`/tokens/allowances/token_id`)**: one subtree per owner.
+    /// 5. **Owner Allowances**: items holding serialized allowances, usually only a few.
+    pub(super) fn add_estimation_costs_for_token_allowances_v0(
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+    ) {
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path([]),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(2, false),
+                estimated_layer_sizes: AllSubtrees(1, NoSumTrees, None),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(tokens_root_path()),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(2, false),
+                estimated_layer_sizes: AllSubtrees(
+                    1,
+                    SomeSumTrees {
+                        sum_trees_weight: 1,
+                        big_sum_trees_weight: 1,
+                        count_trees_weight: 0,
+                        count_sum_trees_weight: 0,
+                        non_sum_trees_weight: 1,
+                    },
+                    None,
+                ),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(token_allowances_root_path()),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(10, false),
+                estimated_layer_sizes: AllSubtrees(DEFAULT_HASH_SIZE_U8, NoSumTrees, None),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(token_allowances_path(&token_id)),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(10, false),
+                estimated_layer_sizes: AllSubtrees(DEFAULT_HASH_SIZE_U8, NoSumTrees, None),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(token_owner_allowances_path(&token_id, &owner_id)),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: ApproximateElements(4),
+                estimated_layer_sizes: AllItems(
+                    SPENDER_KEY_SIZE_U8,
+                    ESTIMATED_SERIALIZED_TOKEN_ALLOWANCE_SIZE,
+                    None,
+                ),
+            },
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::drive::Drive;
+
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, EstimatedLevel};
+use grovedb::EstimatedLayerSizes::{AllItems, AllSubtrees};
+use grovedb::{EstimatedLayerInformation, TreeType};
+
+use crate::drive::tokens::paths::{
+    token_order_book_path, token_order_book_path_vec, token_order_book_root_path,
+    token_orders_by_id_path, tokens_root_path,
+};
+use crate::util::type_constants::DEFAULT_HASH_SIZE_U8;
+use grovedb::EstimatedSumTrees::{NoSumTrees, SomeSumTrees};
+use std::collections::HashMap;
+
+/// The estimated size of a serialized order
+const ESTIMATED_SERIALIZED_TOKEN_ORDER_SIZE: u32 = 180;
+
+/// The size of the key of an order in a book, price, creation time and order id
+const TOKEN_ORDER_BOOK_ENTRY_KEY_SIZE: u8 = 48;
+
+impl Drive {
+    /// Adds estimated storage costs related to the order book of a token (v0).
+    ///
+    /// The following layers are estimated:
+    ///
+    /// 1. **Top-Level Structure (`/`)**: the root, 2 levels above the token trees.
+    /// 2. **Token Root Path (`/tokens`)**: a mixture of normal and sum trees.
+    /// 3. **Order Book Root (`/tokens/order_book`)**: one subtree per traded token.
+    /// 4. **Token Order Book (`/tokens/order_book/token_id`)**: the orders by id and a few books.
+    /// 5. **Orders By Id** and **Book**: items holding serialized orders.
+    pub(super) fn add_estimation_costs_for_token_order_book_v0(
+        token_id: [u8; 32],
+        book_key: &[u8],
+        estimated_costs_only_with_layer_info: &mut HashMap<KeyInfoPath, EstimatedLayerInformation>,
+    ) {
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path([]),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(2, false),
+                estimated_layer_sizes: AllSubtrees(1, NoSumTrees, None),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(tokens_root_path()),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(2, false),
+                estimated_layer_sizes: AllSubtrees(
+                    1,
+                    SomeSumTrees {
+                        sum_trees_weight: 1,
+                        big_sum_trees_weight: 1,
+                        count_trees_weight: 0,
+                        count_sum_trees_weight: 0,
+                        non_sum_trees_weight: 1,
+                    },
+                    None,
+                ),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(token_order_book_root_path()),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(10, false),
+                estimated_layer_sizes: AllSubtrees(DEFAULT_HASH_SIZE_U8, NoSumTrees, None),
+            },
+        );
+
+        // The orders by id tree and a handful of books, keys are at most 34 bytes
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(token_order_book_path(&token_id)),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: ApproximateElements(4),
+                estimated_layer_sizes: AllSubtrees(34, NoSumTrees, None),
+            },
+        );
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_path(token_orders_by_id_path(&token_id)),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(10, false),
+                estimated_layer_sizes: AllItems(
+                    DEFAULT_HASH_SIZE_U8,
+                    ESTIMATED_SERIALIZED_TOKEN_ORDER_SIZE,
+                    None,
+                ),
+            },
+        );
+
+        let mut book_path = token_order_book_path_vec(token_id);
+        book_path.push(book_key.to_vec());
+
+        estimated_costs_only_with_layer_info.insert(
+            KeyInfoPath::from_known_owned_path(book_path),
+            EstimatedLayerInformation {
+                tree_type: TreeType::NormalTree,
+                estimated_layer_count: EstimatedLevel(10, false),
+                estimated_layer_sizes: AllItems(
+                    TOKEN_ORDER_BOOK_ENTRY_KEY_SIZE,
+                    ESTIMATED_SERIALIZED_TOKEN_ORDER_SIZE,
+                    None,
+                ),
+            },
+        );
+    }
+}
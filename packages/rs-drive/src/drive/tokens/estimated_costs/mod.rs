@@ -24,3 +24,12 @@ pub mod for_token_direct_selling_prices;
 
 /// Module for handling operations related to token contract infos
 pub mod for_token_contract_infos;
+
+/// Module for handling operations related to token order books
+pub mod for_token_order_book;
+
+/// Module for handling operations related to token vestings
+pub mod for_token_vestings;
+
+/// Module for handling operations related to token allowances
+pub mod for_token_allowances;
@@ -48,11 +48,17 @@ pub mod unfreeze;
 #[cfg(feature = "server")]
 pub mod calculate_total_tokens_balance;
 
+/// Allowance module, tokens owners allow spenders to transfer on their behalf
+pub mod allowance;
 mod contract_info;
 mod direct_purchase;
 /// Distribution module
 pub mod distribution;
+/// Order book module, resting limit orders of tokens
+pub mod order_book;
 /// Token paths
 pub mod paths;
 /// Token status module, like if the token is paused
 pub mod status;
+/// Vesting module, tokens locked for recipients under vesting schedules
+pub mod vesting;
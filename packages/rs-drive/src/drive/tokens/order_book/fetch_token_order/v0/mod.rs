@@ -0,0 +1,87 @@
+use crate::drive::tokens::paths::token_orders_by_id_path;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use crate::util::grove_operations::QueryTarget::QueryTargetValue;
+use dpp::serialization::PlatformDeserializable;
+use dpp::tokens::order_book::TokenOrder;
+use dpp::version::PlatformVersion;
+use grovedb::Element::Item;
+use grovedb::{TransactionArg, TreeType};
+
+/// The estimated size of a serialized order, used for cost estimation.
+const ESTIMATED_SERIALIZED_TOKEN_ORDER_SIZE: u32 = 180;
+
+impl Drive {
+    pub(super) fn fetch_token_order_v0(
+        &self,
+        token_id: [u8; 32],
+        order_id: [u8; 32],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TokenOrder>, Error> {
+        self.fetch_token_order_operations_v0(
+            token_id,
+            order_id,
+            true,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    pub(super) fn fetch_token_order_operations_v0(
+        &self,
+        token_id: [u8; 32],
+        order_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TokenOrder>, Error> {
+        let direct_query_type = if apply {
+            DirectQueryType::StatefulDirectQuery
+        } else {
+            DirectQueryType::StatelessDirectQuery {
+                in_tree_type: TreeType::NormalTree,
+                query_target: QueryTargetValue(ESTIMATED_SERIALIZED_TOKEN_ORDER_SIZE),
+            }
+        };
+
+        let orders_path = token_orders_by_id_path(&token_id);
+
+        match self.grove_get_raw_optional(
+            (&orders_path).into(),
+            &order_id,
+            direct_query_type,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        ) {
+            Ok(Some(Item(order_bytes, _))) => Ok(Some(TokenOrder::deserialize_from_bytes(
+                order_bytes.as_slice(),
+            )?)),
+
+            Ok(None) => Ok(None),
+            // The order book trees of a token are only created with its first order
+            Err(Error::GroveDB(e))
+                if matches!(
+                    e.as_ref(),
+                    grovedb::Error::PathKeyNotFound(_)
+                        | grovedb::Error::PathNotFound(_)
+                        | grovedb::Error::PathParentLayerNotFound(_)
+                ) =>
+            {
+                Ok(None)
+            }
+
+            Ok(Some(_)) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "token order was present but was not an item",
+            ))),
+
+            Err(e) => Err(e),
+        }
+    }
+}
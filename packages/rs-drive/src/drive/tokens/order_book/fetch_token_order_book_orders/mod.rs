@@ -0,0 +1,117 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::{TokenOrder, TokenOrderSide};
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the best resting orders of one side of a token order book.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token whose book is being queried.
+    /// * `side` - The side of the book, buy or sell orders.
+    /// * `quote_currency` - The currency the orders are priced in.
+    /// * `limit` - An optional maximum number of orders to return.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<TokenOrder>, Error>` - The orders in price-time priority, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn fetch_token_order_book_orders(
+        &self,
+        token_id: [u8; 32],
+        side: TokenOrderSide,
+        quote_currency: &AllowedCurrency,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenOrder>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .token
+            .fetch
+            .token_order_book_orders
+        {
+            0 => self.fetch_token_order_book_orders_v0(
+                token_id,
+                side,
+                quote_currency,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_order_book_orders".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Creates the low-level operations needed to fetch the best resting orders of one side of a
+    /// token order book.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token whose book is being queried.
+    /// * `side` - The side of the book, buy or sell orders.
+    /// * `quote_currency` - The currency the orders are priced in.
+    /// * `limit` - An optional maximum number of orders to return.
+    /// * `transaction` - The current transaction context.
+    /// * `drive_operations` - A vector to store the created low-level drive operations.
+    /// * `platform_version` - The platform version to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<TokenOrder>, Error>` - The orders in price-time priority, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fetch_token_order_book_orders_operations(
+        &self,
+        token_id: [u8; 32],
+        side: TokenOrderSide,
+        quote_currency: &AllowedCurrency,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenOrder>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .token
+            .fetch
+            .token_order_book_orders
+        {
+            0 => self.fetch_token_order_book_orders_operations_v0(
+                token_id,
+                side,
+                quote_currency,
+                limit,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_order_book_orders_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::serialization::PlatformDeserializable;
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::{TokenOrder, TokenOrderSide};
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::Element::Item;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn fetch_token_order_book_orders_v0(
+        &self,
+        token_id: [u8; 32],
+        side: TokenOrderSide,
+        quote_currency: &AllowedCurrency,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenOrder>, Error> {
+        self.fetch_token_order_book_orders_operations_v0(
+            token_id,
+            side,
+            quote_currency,
+            limit,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn fetch_token_order_book_orders_operations_v0(
+        &self,
+        token_id: [u8; 32],
+        side: TokenOrderSide,
+        quote_currency: &AllowedCurrency,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenOrder>, Error> {
+        let path_query = Self::token_order_book_orders_query(token_id, side, quote_currency, limit);
+
+        let elements = match self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryElementResultType,
+            drive_operations,
+            &platform_version.drive,
+        ) {
+            Ok((elements, _)) => elements,
+            // The book is only created with its first order
+            Err(Error::GroveDB(e))
+                if matches!(
+                    e.as_ref(),
+                    grovedb::Error::PathKeyNotFound(_)
+                        | grovedb::Error::PathNotFound(_)
+                        | grovedb::Error::PathParentLayerNotFound(_)
+                ) =>
+            {
+                return Ok(vec![]);
+            }
+            Err(e) => return Err(e),
+        };
+
+        elements
+            .to_elements()
+            .into_iter()
+            .map(|element| match element {
+                Item(order_bytes, _) => Ok(TokenOrder::deserialize_from_bytes(&order_bytes)?),
+                _ => Err(Error::Drive(DriveError::CorruptedDriveState(
+                    "token order book should contain only items".to_string(),
+                ))),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,41 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::tokens::order_book::TokenOrder;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use platform_version::version::PlatformVersion;
+use std::collections::HashMap;
+
+impl Drive {
+    /// Gathers the operations needed to rest an order on the order book of its token.
+    ///
+    /// The order is stored by its id and in the book of its side and quote currency, the
+    /// order book trees of the token are created if this is its first order.
+    pub fn token_insert_order_operations(
+        &self,
+        order: &TokenOrder,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version.drive.methods.token.update.insert_order {
+            0 => self.token_insert_order_operations_v0(
+                order,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "token_insert_order_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
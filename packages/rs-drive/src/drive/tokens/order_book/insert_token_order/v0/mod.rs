@@ -0,0 +1,99 @@
+use crate::drive::tokens::paths::{
+    token_order_book_entry_key, token_order_book_path_vec, token_order_book_root_path_vec,
+    token_order_book_side_key, token_order_book_side_path_vec, token_orders_by_id_path_vec,
+    tokens_root_path_vec, TOKEN_ORDERS_BY_ID_KEY, TOKEN_ORDER_BOOK_KEY,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::BatchInsertTreeApplyType;
+use crate::util::object_size_info::{DriveKeyInfo, PathInfo};
+use dpp::serialization::PlatformSerializable;
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+use dpp::tokens::order_book::TokenOrder;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg, TreeType};
+use platform_version::version::PlatformVersion;
+use std::collections::HashMap;
+
+impl Drive {
+    pub(super) fn token_insert_order_operations_v0(
+        &self,
+        order: &TokenOrder,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations = vec![];
+
+        let token_id = order.token_id().to_buffer();
+        let quote_currency = order.quote_currency();
+        let book_key = token_order_book_side_key(order.side(), &quote_currency);
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_token_order_book(
+                token_id,
+                &book_key,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        let apply_type = if estimated_costs_only_with_layer_info.is_none() {
+            BatchInsertTreeApplyType::StatefulBatchInsertTree
+        } else {
+            BatchInsertTreeApplyType::StatelessBatchInsertTree {
+                in_tree_type: TreeType::NormalTree,
+                tree_type: TreeType::NormalTree,
+                flags_len: 0,
+            }
+        };
+
+        // The order book trees are created lazily, with the first order placed on a token
+        let trees_to_create = [
+            (tokens_root_path_vec(), vec![TOKEN_ORDER_BOOK_KEY]),
+            (token_order_book_root_path_vec(), token_id.to_vec()),
+            (
+                token_order_book_path_vec(token_id),
+                vec![TOKEN_ORDERS_BY_ID_KEY],
+            ),
+            (token_order_book_path_vec(token_id), book_key),
+        ];
+
+        for (path, key) in trees_to_create {
+            self.batch_insert_empty_tree_if_not_exists(
+                DriveKeyInfo::Key(key).add_path_info::<0>(PathInfo::PathAsVec(path)),
+                TreeType::NormalTree,
+                None,
+                apply_type,
+                transaction,
+                &mut None,
+                &mut drive_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        let serialized_order = order.serialize_to_bytes()?;
+
+        drive_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+            token_orders_by_id_path_vec(token_id),
+            order.id().to_vec(),
+            Element::new_item(serialized_order.clone()),
+        ));
+
+        drive_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+            token_order_book_side_path_vec(token_id, order.side(), &quote_currency),
+            token_order_book_entry_key(
+                order.side(),
+                order.price_per_token(),
+                order.created_at(),
+                order.id().as_bytes(),
+            ),
+            Element::new_item(serialized_order),
+        ));
+
+        Ok(drive_operations)
+    }
+}
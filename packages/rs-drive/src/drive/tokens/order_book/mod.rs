@@ -0,0 +1,13 @@
+#[cfg(feature = "server")]
+mod fetch_token_order;
+#[cfg(feature = "server")]
+mod fetch_token_order_book_orders;
+#[cfg(feature = "server")]
+mod insert_token_order;
+#[cfg(feature = "server")]
+mod prove_token_order_book_orders;
+mod queries;
+#[cfg(feature = "server")]
+mod remove_token_order;
+#[cfg(feature = "server")]
+mod update_token_order;
@@ -0,0 +1,61 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::TokenOrderSide;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the best resting orders of one side of a token order book.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token whose book is being proved.
+    /// * `side` - The side of the book, buy or sell orders.
+    /// * `quote_currency` - The currency the orders are priced in.
+    /// * `limit` - An optional maximum number of orders in the proved range.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - A grovedb proof, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn prove_token_order_book_orders(
+        &self,
+        token_id: [u8; 32],
+        side: TokenOrderSide,
+        quote_currency: &AllowedCurrency,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .token
+            .prove
+            .token_order_book_orders
+        {
+            0 => self.prove_token_order_book_orders_v0(
+                token_id,
+                side,
+                quote_currency,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_token_order_book_orders".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::TokenOrderSide;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn prove_token_order_book_orders_v0(
+        &self,
+        token_id: [u8; 32],
+        side: TokenOrderSide,
+        quote_currency: &AllowedCurrency,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query = Self::token_order_book_orders_query(token_id, side, quote_currency, limit);
+        self.grove_get_proved_path_query(
+            &path_query,
+            transaction,
+            &mut Vec::new(),
+            &platform_version.drive,
+        )
+    }
+}
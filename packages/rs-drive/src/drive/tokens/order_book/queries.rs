@@ -0,0 +1,37 @@
+use crate::drive::tokens::paths::{token_order_book_side_path_vec, token_orders_by_id_path_vec};
+use crate::drive::Drive;
+use crate::query::Query;
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::TokenOrderSide;
+use grovedb::{PathQuery, SizedQuery};
+
+impl Drive {
+    /// The query getting a single order of a token by its id
+    pub fn token_order_query(token_id: [u8; 32], order_id: [u8; 32]) -> PathQuery {
+        let orders_path = token_orders_by_id_path_vec(token_id);
+
+        let mut query = Query::new();
+
+        query.insert_key(order_id.to_vec());
+
+        PathQuery::new(orders_path, SizedQuery::new(query, Some(1), None))
+    }
+
+    /// The query getting the best resting orders of one side of a token order book
+    ///
+    /// Orders are returned in price-time priority, the best priced and oldest order first.
+    pub fn token_order_book_orders_query(
+        token_id: [u8; 32],
+        side: TokenOrderSide,
+        quote_currency: &AllowedCurrency,
+        limit: Option<u16>,
+    ) -> PathQuery {
+        let book_path = token_order_book_side_path_vec(token_id, side, quote_currency);
+
+        let mut query = Query::new();
+
+        query.insert_all();
+
+        PathQuery::new(book_path, SizedQuery::new(query, limit, None))
+    }
+}
@@ -0,0 +1,37 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::tokens::order_book::TokenOrder;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerInformation;
+use platform_version::version::PlatformVersion;
+use std::collections::HashMap;
+
+impl Drive {
+    /// Gathers the operations needed to take an order off the order book of its token, once it
+    /// was completely filled or cancelled.
+    pub fn token_remove_order_operations(
+        &self,
+        order: &TokenOrder,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version.drive.methods.token.update.remove_order {
+            0 => self.token_remove_order_operations_v0(
+                order,
+                estimated_costs_only_with_layer_info,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "token_remove_order_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::drive::tokens::paths::{
+    token_order_book_entry_key, token_order_book_side_key, token_order_book_side_path_vec,
+    token_orders_by_id_path_vec,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::serialization::PlatformSerializable;
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+use dpp::tokens::order_book::TokenOrder;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, EstimatedLayerInformation};
+use platform_version::version::PlatformVersion;
+use std::collections::HashMap;
+
+impl Drive {
+    pub(super) fn token_update_order_operations_v0(
+        &self,
+        order: &TokenOrder,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let token_id = order.token_id().to_buffer();
+        let quote_currency = order.quote_currency();
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_token_order_book(
+                token_id,
+                &token_order_book_side_key(order.side(), &quote_currency),
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        let serialized_order = order.serialize_to_bytes()?;
+
+        // Neither the price nor the creation time change, so the order keeps its place in the book
+        Ok(vec![
+            LowLevelDriveOperation::replace_for_known_path_key_element(
+                token_orders_by_id_path_vec(token_id),
+                order.id().to_vec(),
+                Element::new_item(serialized_order.clone()),
+            ),
+            LowLevelDriveOperation::replace_for_known_path_key_element(
+                token_order_book_side_path_vec(token_id, order.side(), &quote_currency),
+                token_order_book_entry_key(
+                    order.side(),
+                    order.price_per_token(),
+                    order.created_at(),
+                    order.id().as_bytes(),
+                ),
+                Element::new_item(serialized_order),
+            ),
+        ])
+    }
+}
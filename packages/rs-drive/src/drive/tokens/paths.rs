@@ -6,6 +6,8 @@ use dpp::data_contract::associated_token::token_perpetual_distribution::reward_d
 use dpp::data_contract::associated_token::token_perpetual_distribution::reward_distribution_type::RewardDistributionType;
 use dpp::data_contract::associated_token::token_perpetual_distribution::TokenPerpetualDistribution;
 use dpp::prelude::{BlockHeight, TimestampMillis};
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::TokenOrderSide;
 
 // ROOT TOKEN LEVEL
 
@@ -19,6 +21,12 @@ pub const TOKEN_CONTRACT_INFO_KEY: u8 = 160;
 pub const TOKEN_BALANCES_KEY: u8 = 128;
 /// Key that sets the pricing schedule for directly buying the token.
 pub const TOKEN_DIRECT_SELL_PRICE_KEY: u8 = 92;
+/// Key for the on-chain limit order books of tokens.
+pub const TOKEN_ORDER_BOOK_KEY: u8 = 224;
+/// Key for tokens locked for recipients under vesting schedules.
+pub const TOKEN_VESTINGS_KEY: u8 = 112;
+/// Key for the allowances owners granted to spenders of their tokens.
+pub const TOKEN_ALLOWANCES_KEY: u8 = 176;
 
 /// Key for token distributions sub level
 pub const TOKEN_DISTRIBUTIONS_KEY: u8 = 32;
@@ -27,8 +35,10 @@ pub const TOKEN_DISTRIBUTIONS_KEY: u8 = 32;
 //                                                       TOKEN_BALANCES_KEY
 //                                           /                                                       \
 //                             TOKEN_STATUS_INFO_KEY                                   TOKEN_IDENTITY_INFO_KEY
-//                              /             \                                                    /
-//           TOKEN_DISTRIBUTIONS_KEY    TOKEN_DIRECT_SELL_PRICE_KEY                  TOKEN_CONTRACT_INFO_KEY
+//                              /             \                                         /                    \
+//           TOKEN_DISTRIBUTIONS_KEY    TOKEN_DIRECT_SELL_PRICE_KEY       TOKEN_CONTRACT_INFO_KEY     TOKEN_ORDER_BOOK_KEY
+//                                                   \                                   \
+//                                           TOKEN_VESTINGS_KEY                  TOKEN_ALLOWANCES_KEY
 
 // The token distribution Tree level
 
@@ -63,6 +73,13 @@ pub const TOKEN_PERPETUAL_DISTRIBUTIONS_INFO_KEY: u8 = 128;
 /// Key for the perpetual distribution last claim for identities key.
 pub const TOKEN_PERPETUAL_DISTRIBUTIONS_FOR_IDENTITIES_LAST_CLAIM_KEY: u8 = 192;
 
+// Inside the order book tree every token has its own subtree, in which all orders are kept by
+// their id under TOKEN_ORDERS_BY_ID_KEY, and each book (a side and a quote currency) is a
+// sibling subtree keyed by the side byte followed by the quote currency storage key.
+
+/// Key for the orders of a token, keyed by order id.
+pub const TOKEN_ORDERS_BY_ID_KEY: u8 = 128;
+
 /// Key for the perpetual distribution last claim for identities key.
 pub const TOKEN_PRE_PROGRAMMED_DISTRIBUTIONS_FOR_IDENTITIES_LAST_CLAIM_KEY: u8 = 192;
 
@@ -105,6 +122,214 @@ pub fn token_direct_purchase_root_path_vec() -> Vec<Vec<u8>> {
     ]
 }
 
+/// The root path of the token order book tree
+pub fn token_order_book_root_path() -> [&'static [u8]; 2] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Tokens),
+        &[TOKEN_ORDER_BOOK_KEY],
+    ]
+}
+
+/// The root path of the token order book tree
+pub fn token_order_book_root_path_vec() -> Vec<Vec<u8>> {
+    vec![vec![RootTree::Tokens as u8], vec![TOKEN_ORDER_BOOK_KEY]]
+}
+
+/// The path of the order book tree of a single token
+pub fn token_order_book_path(token_id: &[u8; 32]) -> [&[u8]; 3] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Tokens),
+        &[TOKEN_ORDER_BOOK_KEY],
+        token_id,
+    ]
+}
+
+/// The path of the order book tree of a single token as a vector
+pub fn token_order_book_path_vec(token_id: [u8; 32]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Tokens as u8],
+        vec![TOKEN_ORDER_BOOK_KEY],
+        token_id.to_vec(),
+    ]
+}
+
+/// The path of the tree holding all orders of a token keyed by their id
+pub fn token_orders_by_id_path(token_id: &[u8; 32]) -> [&[u8]; 4] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Tokens),
+        &[TOKEN_ORDER_BOOK_KEY],
+        token_id,
+        &[TOKEN_ORDERS_BY_ID_KEY],
+    ]
+}
+
+/// The path of the tree holding all orders of a token keyed by their id as a vector
+pub fn token_orders_by_id_path_vec(token_id: [u8; 32]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Tokens as u8],
+        vec![TOKEN_ORDER_BOOK_KEY],
+        token_id.to_vec(),
+        vec![TOKEN_ORDERS_BY_ID_KEY],
+    ]
+}
+
+/// The key of a single book (one side quoted in one currency) inside the order book tree of a token
+pub fn token_order_book_side_key(
+    side: TokenOrderSide,
+    quote_currency: &AllowedCurrency,
+) -> Vec<u8> {
+    let mut key = vec![side as u8];
+    key.extend(quote_currency.storage_key());
+    key
+}
+
+/// The path of a single book (one side quoted in one currency) of a token as a vector
+pub fn token_order_book_side_path_vec(
+    token_id: [u8; 32],
+    side: TokenOrderSide,
+    quote_currency: &AllowedCurrency,
+) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Tokens as u8],
+        vec![TOKEN_ORDER_BOOK_KEY],
+        token_id.to_vec(),
+        token_order_book_side_key(side, quote_currency),
+    ]
+}
+
+/// The key of an order inside its book.
+///
+/// Keys sort by price from the best to the worst price for the side of the book, then by
+/// creation time and finally by order id, so iterating a book in ascending order gives
+/// price-time priority.
+pub fn token_order_book_entry_key(
+    side: TokenOrderSide,
+    price_per_token: u64,
+    created_at: TimestampMillis,
+    order_id: &[u8; 32],
+) -> Vec<u8> {
+    let price_key = match side {
+        TokenOrderSide::Buy => u64::MAX - price_per_token,
+        TokenOrderSide::Sell => price_per_token,
+    };
+    let mut key = Vec::with_capacity(48);
+    key.extend_from_slice(&price_key.to_be_bytes());
+    key.extend_from_slice(&created_at.to_be_bytes());
+    key.extend_from_slice(order_id);
+    key
+}
+
+/// The root path of the token vestings tree
+pub fn token_vestings_root_path() -> [&'static [u8]; 2] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Tokens),
+        &[TOKEN_VESTINGS_KEY],
+    ]
+}
+
+/// The root path of the token vestings tree
+pub fn token_vestings_root_path_vec() -> Vec<Vec<u8>> {
+    vec![vec![RootTree::Tokens as u8], vec![TOKEN_VESTINGS_KEY]]
+}
+
+/// The path of the vestings tree of a single token, holding one subtree per recipient
+pub fn token_vestings_path(token_id: &[u8; 32]) -> [&[u8]; 3] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Tokens),
+        &[TOKEN_VESTINGS_KEY],
+        token_id,
+    ]
+}
+
+/// The path of the vestings tree of a single token as a vector
+pub fn token_vestings_path_vec(token_id: [u8; 32]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Tokens as u8],
+        vec![TOKEN_VESTINGS_KEY],
+        token_id.to_vec(),
+    ]
+}
+
+/// The path of the tree holding the vestings of a token for a recipient, keyed by vesting id
+pub fn token_recipient_vestings_path<'a>(
+    token_id: &'a [u8; 32],
+    recipient_id: &'a [u8; 32],
+) -> [&'a [u8]; 4] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Tokens),
+        &[TOKEN_VESTINGS_KEY],
+        token_id,
+        recipient_id,
+    ]
+}
+
+/// The path of the tree holding the vestings of a token for a recipient as a vector
+pub fn token_recipient_vestings_path_vec(
+    token_id: [u8; 32],
+    recipient_id: [u8; 32],
+) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Tokens as u8],
+        vec![TOKEN_VESTINGS_KEY],
+        token_id.to_vec(),
+        recipient_id.to_vec(),
+    ]
+}
+
+/// The root path of the token allowances tree
+pub fn token_allowances_root_path() -> [&'static [u8]; 2] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Tokens),
+        &[TOKEN_ALLOWANCES_KEY],
+    ]
+}
+
+/// The root path of the token allowances tree
+pub fn token_allowances_root_path_vec() -> Vec<Vec<u8>> {
+    vec![vec![RootTree::Tokens as u8], vec![TOKEN_ALLOWANCES_KEY]]
+}
+
+/// The path of the allowances tree of a single token, holding one subtree per owner
+pub fn token_allowances_path(token_id: &[u8; 32]) -> [&[u8]; 3] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Tokens),
+        &[TOKEN_ALLOWANCES_KEY],
+        token_id,
+    ]
+}
+
+/// The path of the allowances tree of a single token as a vector
+pub fn token_allowances_path_vec(token_id: [u8; 32]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Tokens as u8],
+        vec![TOKEN_ALLOWANCES_KEY],
+        token_id.to_vec(),
+    ]
+}
+
+/// The path of the tree holding the allowances an owner granted for a token, keyed by spender
+pub fn token_owner_allowances_path<'a>(
+    token_id: &'a [u8; 32],
+    owner_id: &'a [u8; 32],
+) -> [&'a [u8]; 4] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Tokens),
+        &[TOKEN_ALLOWANCES_KEY],
+        token_id,
+        owner_id,
+    ]
+}
+
+/// The path of the tree holding the allowances an owner granted for a token as a vector
+pub fn token_owner_allowances_path_vec(token_id: [u8; 32], owner_id: [u8; 32]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Tokens as u8],
+        vec![TOKEN_ALLOWANCES_KEY],
+        token_id.to_vec(),
+        owner_id.to_vec(),
+    ]
+}
+
 /// Returns the root path for token identity information as a fixed-size array of byte slices.
 pub fn token_identity_infos_root_path() -> [&'static [u8]; 2] {
     [
@@ -0,0 +1,130 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::tokens::vesting::TokenVesting;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches a vesting of a token for a recipient by its id.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token.
+    /// * `recipient_id` - The ID of the identity the tokens are locked for.
+    /// * `vesting_id` - The ID of the vesting.
+    /// * `transaction` - The current transaction.
+    /// * `platform_version` - The platform version to use.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<TokenVesting>, Error>` - The vesting if it still holds unclaimed tokens, or an error.
+    pub fn fetch_token_vesting(
+        &self,
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        vesting_id: [u8; 32],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TokenVesting>, Error> {
+        match platform_version.drive.methods.token.fetch.token_vesting {
+            0 => self.fetch_token_vesting_v0(
+                token_id,
+                recipient_id,
+                vesting_id,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_vesting".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Fetches a vesting with costs (if `apply = true`) and returns associated fee result.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fetch_token_vesting_with_costs(
+        &self,
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        vesting_id: [u8; 32],
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Option<TokenVesting>, FeeResult), Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        let value = self.fetch_token_vesting_operations(
+            token_id,
+            recipient_id,
+            vesting_id,
+            apply,
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )?;
+
+        let fees = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+
+        Ok((value, fees))
+    }
+
+    /// Creates the operations to get a vesting of a token for a recipient by its id.
+    /// If `apply` is false, the operations are stateless and only used for cost estimation.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token.
+    /// * `recipient_id` - The ID of the identity the tokens are locked for.
+    /// * `vesting_id` - The ID of the vesting.
+    /// * `apply` - Whether to fetch actual stateful data (true) or just estimate costs (false).
+    /// * `transaction` - The current transaction.
+    /// * `drive_operations` - The drive operations vector to populate.
+    /// * `platform_version` - The platform version to use.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<TokenVesting>, Error>` - The vesting if it still holds unclaimed tokens, or an error.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fetch_token_vesting_operations(
+        &self,
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        vesting_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TokenVesting>, Error> {
+        match platform_version.drive.methods.token.fetch.token_vesting {
+            0 => self.fetch_token_vesting_operations_v0(
+                token_id,
+                recipient_id,
+                vesting_id,
+                apply,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_vesting_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::drive::tokens::paths::token_recipient_vestings_path;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use crate::util::grove_operations::QueryTarget::QueryTargetValue;
+use dpp::serialization::PlatformDeserializable;
+use dpp::tokens::vesting::TokenVesting;
+use dpp::version::PlatformVersion;
+use grovedb::Element::Item;
+use grovedb::{TransactionArg, TreeType};
+
+/// The estimated size of a serialized vesting, used for cost estimation.
+const ESTIMATED_SERIALIZED_TOKEN_VESTING_SIZE: u32 = 200;
+
+impl Drive {
+    pub(super) fn fetch_token_vesting_v0(
+        &self,
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        vesting_id: [u8; 32],
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TokenVesting>, Error> {
+        self.fetch_token_vesting_operations_v0(
+            token_id,
+            recipient_id,
+            vesting_id,
+            true,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn fetch_token_vesting_operations_v0(
+        &self,
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        vesting_id: [u8; 32],
+        apply: bool,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<TokenVesting>, Error> {
+        let direct_query_type = if apply {
+            DirectQueryType::StatefulDirectQuery
+        } else {
+            DirectQueryType::StatelessDirectQuery {
+                in_tree_type: TreeType::NormalTree,
+                query_target: QueryTargetValue(ESTIMATED_SERIALIZED_TOKEN_VESTING_SIZE),
+            }
+        };
+
+        let vestings_path = token_recipient_vestings_path(&token_id, &recipient_id);
+
+        match self.grove_get_raw_optional(
+            (&vestings_path).into(),
+            &vesting_id,
+            direct_query_type,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        ) {
+            Ok(Some(Item(vesting_bytes, _))) => Ok(Some(TokenVesting::deserialize_from_bytes(
+                vesting_bytes.as_slice(),
+            )?)),
+
+            Ok(None) => Ok(None),
+            // The vesting trees are only created with the first vesting for a recipient
+            Err(Error::GroveDB(e))
+                if matches!(
+                    e.as_ref(),
+                    grovedb::Error::PathKeyNotFound(_)
+                        | grovedb::Error::PathNotFound(_)
+                        | grovedb::Error::PathParentLayerNotFound(_)
+                ) =>
+            {
+                Ok(None)
+            }
+
+            Ok(Some(_)) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "token vesting was present but was not an item",
+            ))),
+
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::tokens::vesting::TokenVesting;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the vestings of a token locked for a recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token.
+    /// * `recipient_id` - The ID of the identity the tokens are locked for.
+    /// * `start_after` - An optional vesting ID to start after, for pagination.
+    /// * `limit` - An optional maximum number of vestings to return.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<TokenVesting>, Error>` - The vestings ordered by their ID, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn fetch_token_vestings(
+        &self,
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        start_after: Option<[u8; 32]>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenVesting>, Error> {
+        match platform_version.drive.methods.token.fetch.token_vestings {
+            0 => self.fetch_token_vestings_v0(
+                token_id,
+                recipient_id,
+                start_after,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_vestings".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Creates the low-level operations needed to fetch the vestings of a token locked for a
+    /// recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token.
+    /// * `recipient_id` - The ID of the identity the tokens are locked for.
+    /// * `start_after` - An optional vesting ID to start after, for pagination.
+    /// * `limit` - An optional maximum number of vestings to return.
+    /// * `transaction` - The current transaction context.
+    /// * `drive_operations` - A vector to store the created low-level drive operations.
+    /// * `platform_version` - The platform version to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<TokenVesting>, Error>` - The vestings ordered by their ID, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fetch_token_vestings_operations(
+        &self,
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        start_after: Option<[u8; 32]>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenVesting>, Error> {
+        match platform_version.drive.methods.token.fetch.token_vestings {
+            0 => self.fetch_token_vestings_operations_v0(
+                token_id,
+                recipient_id,
+                start_after,
+                limit,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_token_vestings_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
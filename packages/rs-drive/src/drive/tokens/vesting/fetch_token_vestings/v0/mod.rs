@@ -0,0 +1,80 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::serialization::PlatformDeserializable;
+use dpp::tokens::vesting::TokenVesting;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::Element::Item;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn fetch_token_vestings_v0(
+        &self,
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        start_after: Option<[u8; 32]>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenVesting>, Error> {
+        self.fetch_token_vestings_operations_v0(
+            token_id,
+            recipient_id,
+            start_after,
+            limit,
+            transaction,
+            &mut vec![],
+            platform_version,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn fetch_token_vestings_operations_v0(
+        &self,
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        start_after: Option<[u8; 32]>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<TokenVesting>, Error> {
+        let path_query =
+            Self::token_vestings_for_recipient_query(token_id, recipient_id, start_after, limit);
+
+        let elements = match self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryElementResultType,
+            drive_operations,
+            &platform_version.drive,
+        ) {
+            Ok((elements, _)) => elements,
+            // The vesting trees are only created with the first vesting for a recipient
+            Err(Error::GroveDB(e))
+                if matches!(
+                    e.as_ref(),
+                    grovedb::Error::PathKeyNotFound(_)
+                        | grovedb::Error::PathNotFound(_)
+                        | grovedb::Error::PathParentLayerNotFound(_)
+                ) =>
+            {
+                return Ok(vec![]);
+            }
+            Err(e) => return Err(e),
+        };
+
+        elements
+            .to_elements()
+            .into_iter()
+            .map(|element| match element {
+                Item(vesting_bytes, _) => Ok(TokenVesting::deserialize_from_bytes(&vesting_bytes)?),
+                _ => Err(Error::Drive(DriveError::CorruptedDriveState(
+                    "token vestings tree should contain only items".to_string(),
+                ))),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,13 @@
+#[cfg(feature = "server")]
+mod fetch_token_vesting;
+#[cfg(feature = "server")]
+mod fetch_token_vestings;
+#[cfg(feature = "server")]
+mod insert_token_vesting;
+#[cfg(feature = "server")]
+mod prove_token_vestings;
+mod queries;
+#[cfg(feature = "server")]
+mod remove_token_vesting;
+#[cfg(feature = "server")]
+mod update_token_vesting;
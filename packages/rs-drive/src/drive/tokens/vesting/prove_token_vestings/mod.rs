@@ -0,0 +1,53 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the vestings of a token locked for a recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The ID of the token.
+    /// * `recipient_id` - The ID of the identity the tokens are locked for.
+    /// * `start_after` - An optional vesting ID to start after, for pagination.
+    /// * `limit` - An optional maximum number of vestings in the proved range.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - A grovedb proof, or an error.
+    ///
+    /// # Errors
+    ///
+    /// * `DriveError::UnknownVersionMismatch` - If the platform version does not support the requested operation.
+    pub fn prove_token_vestings(
+        &self,
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        start_after: Option<[u8; 32]>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version.drive.methods.token.prove.token_vestings {
+            0 => self.prove_token_vestings_v0(
+                token_id,
+                recipient_id,
+                start_after,
+                limit,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_token_vestings".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
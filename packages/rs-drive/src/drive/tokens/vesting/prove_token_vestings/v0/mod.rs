@@ -0,0 +1,25 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    pub(super) fn prove_token_vestings_v0(
+        &self,
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        start_after: Option<[u8; 32]>,
+        limit: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query =
+            Self::token_vestings_for_recipient_query(token_id, recipient_id, start_after, limit);
+        self.grove_get_proved_path_query(
+            &path_query,
+            transaction,
+            &mut Vec::new(),
+            &platform_version.drive,
+        )
+    }
+}
@@ -0,0 +1,42 @@
+use crate::drive::tokens::paths::token_recipient_vestings_path_vec;
+use crate::drive::Drive;
+use crate::query::Query;
+use grovedb::{PathQuery, SizedQuery};
+
+impl Drive {
+    /// The query getting a single vesting of a token for a recipient by its id
+    pub fn token_vesting_query(
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        vesting_id: [u8; 32],
+    ) -> PathQuery {
+        let vestings_path = token_recipient_vestings_path_vec(token_id, recipient_id);
+
+        let mut query = Query::new();
+
+        query.insert_key(vesting_id.to_vec());
+
+        PathQuery::new(vestings_path, SizedQuery::new(query, Some(1), None))
+    }
+
+    /// The query getting the vestings of a token locked for a recipient
+    ///
+    /// Vestings are returned ordered by their id, starting after `start_after` if it is set.
+    pub fn token_vestings_for_recipient_query(
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        start_after: Option<[u8; 32]>,
+        limit: Option<u16>,
+    ) -> PathQuery {
+        let vestings_path = token_recipient_vestings_path_vec(token_id, recipient_id);
+
+        let mut query = Query::new();
+
+        match start_after {
+            Some(start_after) => query.insert_range_after(start_after.to_vec()..),
+            None => query.insert_all(),
+        }
+
+        PathQuery::new(vestings_path, SizedQuery::new(query, limit, None))
+    }
+}
@@ -0,0 +1,42 @@
+use crate::drive::tokens::paths::token_recipient_vestings_path_vec;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::serialization::PlatformSerializable;
+use dpp::tokens::vesting::v0::TokenVestingV0Accessors;
+use dpp::tokens::vesting::TokenVesting;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{Element, EstimatedLayerInformation};
+use platform_version::version::PlatformVersion;
+use std::collections::HashMap;
+
+impl Drive {
+    pub(super) fn token_update_vesting_operations_v0(
+        &self,
+        vesting: &TokenVesting,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let token_id = vesting.token_id().to_buffer();
+        let recipient_id = vesting.recipient_id().to_buffer();
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_token_vestings(
+                token_id,
+                recipient_id,
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(vec![
+            LowLevelDriveOperation::replace_for_known_path_key_element(
+                token_recipient_vestings_path_vec(token_id, recipient_id),
+                vesting.id().to_vec(),
+                Element::new_item(vesting.serialize_to_bytes()?),
+            ),
+        ])
+    }
+}
@@ -6,3 +6,4 @@ mod remove_contested_resource_vote_poll_info_operations;
 mod remove_contested_resource_vote_poll_top_level_index_operations;
 mod remove_contested_resource_vote_poll_votes_operations;
 mod remove_specific_votes_given_by_identity;
+mod remove_yes_no_abstain_vote_poll_end_date_query_operations;
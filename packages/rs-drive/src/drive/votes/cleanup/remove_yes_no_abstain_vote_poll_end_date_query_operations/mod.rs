@@ -0,0 +1,46 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::identity::TimestampMillis;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Removes ended yes/no/abstain vote polls from the end date queries.
+    ///
+    /// The end date tree of a time is only removed if it holds no other vote polls, as contested
+    /// resource vote polls can end at the same time and are cleaned up separately.
+    pub fn remove_yes_no_abstain_vote_poll_end_date_query_operations(
+        &self,
+        vote_polls: &[(&YesNoAbstainVotePoll, &TimestampMillis)],
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .cleanup
+            .remove_yes_no_abstain_vote_poll_end_date_query_operations
+        {
+            0 => self.remove_yes_no_abstain_vote_poll_end_date_query_operations_v0(
+                vote_polls,
+                batch_operations,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "remove_yes_no_abstain_vote_poll_end_date_query_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
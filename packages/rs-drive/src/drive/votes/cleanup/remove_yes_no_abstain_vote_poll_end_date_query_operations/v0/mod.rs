@@ -0,0 +1,82 @@
+use crate::drive::votes::paths::{
+    vote_contested_resource_end_date_queries_at_time_tree_path_vec,
+    vote_end_date_queries_tree_path_vec,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::query::VotePollsByEndDateDriveQuery;
+use crate::util::common::encode::encode_u64;
+use crate::util::grove_operations::BatchDeleteApplyType;
+use dpp::identifier::Identifier;
+use dpp::identity::TimestampMillis;
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use grovedb::{MaybeTree, TransactionArg};
+use platform_version::version::PlatformVersion;
+use std::collections::BTreeMap;
+
+impl Drive {
+    pub(super) fn remove_yes_no_abstain_vote_poll_end_date_query_operations_v0(
+        &self,
+        vote_polls: &[(&YesNoAbstainVotePoll, &TimestampMillis)],
+        batch_operations: &mut Vec<LowLevelDriveOperation>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let delete_apply_type = BatchDeleteApplyType::StatefulBatchDelete {
+            is_known_to_be_subtree_with_sum: Some(MaybeTree::NotTree),
+        };
+
+        let mut by_end_date: BTreeMap<TimestampMillis, Vec<Identifier>> = BTreeMap::new();
+
+        for (vote_poll, end_date) in vote_polls {
+            by_end_date
+                .entry(**end_date)
+                .or_default()
+                .push(vote_poll.unique_id()?);
+        }
+
+        for (end_date, unique_ids) in by_end_date {
+            let time_path =
+                vote_contested_resource_end_date_queries_at_time_tree_path_vec(end_date);
+
+            let count = unique_ids.len();
+
+            for unique_id in unique_ids {
+                self.batch_delete(
+                    time_path.as_slice().into(),
+                    unique_id.as_bytes(),
+                    delete_apply_type,
+                    transaction,
+                    batch_operations,
+                    &platform_version.drive,
+                )?;
+            }
+
+            // Other vote polls might end at the same time, in which case the tree must stay
+            let total_count =
+                VotePollsByEndDateDriveQuery::execute_no_proof_for_specialized_end_time_query_only_check_end_time(
+                    end_date,
+                    (count as u16).saturating_add(1),
+                    self,
+                    transaction,
+                    &mut vec![],
+                    platform_version,
+                )?
+                .len();
+
+            if total_count <= count {
+                self.batch_delete(
+                    vote_end_date_queries_tree_path_vec().as_slice().into(),
+                    encode_u64(end_date).as_slice(),
+                    delete_apply_type,
+                    transaction,
+                    batch_operations,
+                    &platform_version.drive,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+mod v0;
+
+use crate::drive::votes::storage_form::yes_no_abstain_vote_storage_form::YesNoAbstainVoteStorageForm;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::platform_value::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the vote a masternode cast on a specific yes/no/abstain vote poll.
+    pub fn fetch_identity_yes_no_abstain_vote(
+        &self,
+        masternode_pro_tx_hash: Identifier,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<YesNoAbstainVoteStorageForm>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .fetch
+            .fetch_identity_yes_no_abstain_vote
+        {
+            0 => self.fetch_identity_yes_no_abstain_vote_v0(
+                masternode_pro_tx_hash,
+                vote_poll_id,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_identity_yes_no_abstain_vote".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
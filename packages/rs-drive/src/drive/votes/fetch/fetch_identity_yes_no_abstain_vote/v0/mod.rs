@@ -0,0 +1,36 @@
+use crate::drive::votes::paths::vote_decisions_poll_votes_path_vec;
+use crate::drive::votes::storage_form::yes_no_abstain_vote_storage_form::YesNoAbstainVoteStorageForm;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use dpp::identifier::Identifier;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn fetch_identity_yes_no_abstain_vote_v0(
+        &self,
+        masternode_pro_tx_hash: Identifier,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<YesNoAbstainVoteStorageForm>, Error> {
+        let path = vote_decisions_poll_votes_path_vec(vote_poll_id.as_bytes());
+
+        // The votes tree only exists once the poll was created
+        self.grove_get_raw_optional(
+            path.as_slice().into(),
+            masternode_pro_tx_hash.as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        )?
+        .map(|element| {
+            YesNoAbstainVoteStorageForm::deserialize_from_bytes(&element.into_item_bytes()?)
+        })
+        .transpose()
+    }
+}
@@ -0,0 +1,42 @@
+mod v0;
+
+use crate::drive::votes::yes_no_abstain_vote_poll_state::YesNoAbstainVotePollState;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::platform_value::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the state of a yes/no/abstain vote poll, the poll itself, the votes cast on it and
+    /// the tallied result if it has already ended.
+    pub fn fetch_yes_no_abstain_vote_poll_state(
+        &self,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<YesNoAbstainVotePollState, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .fetch
+            .fetch_yes_no_abstain_vote_poll_state
+        {
+            0 => self.fetch_yes_no_abstain_vote_poll_state_v0(
+                vote_poll_id,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_yes_no_abstain_vote_poll_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
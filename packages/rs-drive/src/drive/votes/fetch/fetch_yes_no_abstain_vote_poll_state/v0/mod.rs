@@ -0,0 +1,46 @@
+use crate::drive::votes::paths::vote_decisions_tree_path;
+use crate::drive::votes::yes_no_abstain_vote_poll_state::YesNoAbstainVotePollState;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use dpp::identifier::Identifier;
+use grovedb::query_result_type::QueryResultType::QueryPathKeyElementTrioResultType;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn fetch_yes_no_abstain_vote_poll_state_v0(
+        &self,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<YesNoAbstainVotePollState, Error> {
+        // The poll only has a tree once it was created
+        let poll_tree = self.grove_get_raw_optional(
+            (&vote_decisions_tree_path()).into(),
+            vote_poll_id.as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        )?;
+
+        if poll_tree.is_none() {
+            return Ok(YesNoAbstainVotePollState::default());
+        }
+
+        let path_query = Self::yes_no_abstain_vote_poll_state_query(vote_poll_id.to_buffer());
+
+        let (results, _) = self.grove_get_raw_path_query(
+            &path_query,
+            transaction,
+            QueryPathKeyElementTrioResultType,
+            drive_operations,
+            &platform_version.drive,
+        )?;
+
+        YesNoAbstainVotePollState::try_from_path_key_elements(results.to_path_key_elements())
+    }
+}
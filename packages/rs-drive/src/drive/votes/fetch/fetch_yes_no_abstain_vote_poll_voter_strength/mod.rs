@@ -0,0 +1,45 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::platform_value::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches the vote strength of a masternode on a yes/no/abstain vote poll.
+    ///
+    /// Returns `None` if the masternode was not in the masternode list when the poll was
+    /// created, or if the poll does not exist.
+    pub fn fetch_yes_no_abstain_vote_poll_voter_strength(
+        &self,
+        masternode_pro_tx_hash: Identifier,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .fetch
+            .fetch_yes_no_abstain_vote_poll_voter_strength
+        {
+            0 => self.fetch_yes_no_abstain_vote_poll_voter_strength_v0(
+                masternode_pro_tx_hash,
+                vote_poll_id,
+                transaction,
+                drive_operations,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_yes_no_abstain_vote_poll_voter_strength".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
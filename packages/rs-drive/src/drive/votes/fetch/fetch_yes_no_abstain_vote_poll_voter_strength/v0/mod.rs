@@ -0,0 +1,41 @@
+use crate::drive::votes::paths::vote_decisions_poll_eligible_voters_path_vec;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use dpp::identifier::Identifier;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn fetch_yes_no_abstain_vote_poll_voter_strength_v0(
+        &self,
+        masternode_pro_tx_hash: Identifier,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<u8>, Error> {
+        let path = vote_decisions_poll_eligible_voters_path_vec(vote_poll_id.as_bytes());
+
+        self.grove_get_raw_optional(
+            path.as_slice().into(),
+            masternode_pro_tx_hash.as_slice(),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        )?
+        .map(|element| {
+            let bytes = element.into_item_bytes()?;
+            match bytes.as_slice() {
+                [strength] => Ok(*strength),
+                _ => Err(Error::Drive(DriveError::CorruptedElementType(
+                    "eligible voter of a yes/no/abstain vote poll must store a single strength byte",
+                ))),
+            }
+        })
+        .transpose()
+    }
+}
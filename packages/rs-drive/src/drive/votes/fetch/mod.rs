@@ -1,3 +1,7 @@
 mod fetch_contested_document_vote_poll_stored_info;
 mod fetch_identities_voting_for_contenders;
 mod fetch_identity_contested_resource_vote;
+mod fetch_identity_yes_no_abstain_vote;
+mod fetch_yes_no_abstain_vote_poll_state;
+mod fetch_yes_no_abstain_vote_poll_voter_strength;
+mod prove_yes_no_abstain_vote_poll_state;
@@ -0,0 +1,48 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::platform_value::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the state of a yes/no/abstain vote poll, the poll itself, the votes cast on it and
+    /// the tallied result if it has already ended.
+    ///
+    /// # Arguments
+    ///
+    /// * `vote_poll_id` - The unique id of the vote poll.
+    /// * `transaction` - The current transaction context.
+    /// * `platform_version` - The version of the platform to use for compatibility checks.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - A grovedb proof, or an error.
+    pub fn prove_yes_no_abstain_vote_poll_state(
+        &self,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .fetch
+            .prove_yes_no_abstain_vote_poll_state
+        {
+            0 => self.prove_yes_no_abstain_vote_poll_state_v0(
+                vote_poll_id,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_yes_no_abstain_vote_poll_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::identifier::Identifier;
+use grovedb::TransactionArg;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn prove_yes_no_abstain_vote_poll_state_v0(
+        &self,
+        vote_poll_id: Identifier,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let path_query = Self::yes_no_abstain_vote_poll_state_query(vote_poll_id.to_buffer());
+        self.grove_get_proved_path_query(
+            &path_query,
+            transaction,
+            &mut Vec::new(),
+            &platform_version.drive,
+        )
+    }
+}
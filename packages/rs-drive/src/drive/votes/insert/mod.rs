@@ -1,3 +1,4 @@
 mod contested_resource;
 mod register_identity_vote;
 mod vote_poll;
+mod yes_no_abstain;
@@ -83,8 +83,6 @@ impl Drive {
     /// - `voter_pro_tx_hash`: A 32-byte array representing the ProRegTx hash of the voter.
     /// - `vote`: The vote to be registered, detailing the decision made.
     /// - `block_info`: Reference to current block information.
-    /// - `estimated_costs_only_with_layer_info`: A mutable reference to an optional HashMap that, if provided,
-    ///   will be filled with estimated cost and layer information required for processing the vote.
     /// - `transaction`: Contextual transaction arguments that may influence the generation of operations.
     /// - `platform_version`: Reference to the platform version to ensure compatibility of the operation generation method.
     ///
@@ -99,12 +97,14 @@ impl Drive {
     /// - The platform version is unknown or unsupported, resulting in a version mismatch error.
     /// - There are issues generating the necessary operations due to transaction inconsistencies or blockchain state errors.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn register_identity_vote_operations(
         &self,
         voter_pro_tx_hash: [u8; 32],
         strength: u8,
         vote: ResolvedVote,
         previous_resource_vote_choice_to_remove: Option<(ResourceVoteChoice, PreviousVoteCount)>,
+        block_info: &BlockInfo,
         transaction: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<LowLevelDriveOperation>, Error> {
@@ -120,6 +120,7 @@ impl Drive {
                 strength,
                 vote,
                 previous_resource_vote_choice_to_remove,
+                block_info,
                 transaction,
                 platform_version,
             ),
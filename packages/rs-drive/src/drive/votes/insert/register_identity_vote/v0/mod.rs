@@ -2,12 +2,14 @@ use crate::drive::votes::resolved::vote_polls::ResolvedVotePoll;
 use crate::drive::votes::resolved::votes::resolved_resource_vote::accessors::v0::ResolvedResourceVoteGettersV0;
 use crate::drive::votes::resolved::votes::ResolvedVote;
 use crate::drive::Drive;
+use crate::error::drive::DriveError;
 use crate::error::Error;
 use crate::fees::op::LowLevelDriveOperation;
 use crate::state_transition_action::identity::masternode_vote::v0::PreviousVoteCount;
 use dpp::block::block_info::BlockInfo;
 use dpp::fee::fee_result::FeeResult;
 use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dpp::voting::votes::yes_no_abstain_vote::accessors::v0::YesNoAbstainVoteGettersV0;
 use grovedb::TransactionArg;
 use platform_version::version::PlatformVersion;
 
@@ -39,17 +41,36 @@ impl Drive {
                         transaction,
                         platform_version,
                     ),
+                    ResolvedVotePoll::YesNoAbstainVotePoll(_) => {
+                        Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                            "resource votes can not be cast on yes/no/abstain vote polls",
+                        )))
+                    }
                 }
             }
+            ResolvedVote::ResolvedYesNoAbstainVote(yes_no_abstain_vote) => {
+                let vote_choice = yes_no_abstain_vote.vote_choice();
+                self.register_yes_no_abstain_identity_vote(
+                    voter_pro_tx_hash,
+                    strength,
+                    yes_no_abstain_vote.vote_poll_owned(),
+                    vote_choice,
+                    block_info,
+                    transaction,
+                    platform_version,
+                )
+            }
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn register_identity_vote_operations_v0(
         &self,
         voter_pro_tx_hash: [u8; 32],
         strength: u8,
         vote: ResolvedVote,
         previous_resource_vote_choice_to_remove: Option<(ResourceVoteChoice, PreviousVoteCount)>,
+        block_info: &BlockInfo,
         transaction: TransactionArg,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<LowLevelDriveOperation>, Error> {
@@ -68,8 +89,25 @@ impl Drive {
                         transaction,
                         platform_version,
                     ),
+                    ResolvedVotePoll::YesNoAbstainVotePoll(_) => {
+                        Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                            "resource votes can not be cast on yes/no/abstain vote polls",
+                        )))
+                    }
                 }
             }
+            ResolvedVote::ResolvedYesNoAbstainVote(yes_no_abstain_vote) => {
+                let vote_choice = yes_no_abstain_vote.vote_choice();
+                self.register_yes_no_abstain_identity_vote_operations(
+                    voter_pro_tx_hash,
+                    strength,
+                    yes_no_abstain_vote.vote_poll_owned(),
+                    vote_choice,
+                    block_info,
+                    transaction,
+                    platform_version,
+                )
+            }
         }
     }
 }
@@ -0,0 +1,113 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::fee::fee_result::FeeResult;
+
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use grovedb::batch::KeyInfoPath;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use std::collections::{BTreeMap, HashMap};
+
+impl Drive {
+    /// Creates a yes/no/abstain vote poll in the decisions tree.
+    ///
+    /// The poll is stored along with an empty votes tree and the masternodes that are allowed to
+    /// vote on it with their vote strength, and it is registered in the end date queries so that
+    /// it gets tallied once it ends. The storage is paid by the creator of the poll.
+    ///
+    /// # Parameters
+    ///
+    /// - `creator_identity_id`: The identity creating the poll.
+    /// - `vote_poll`: The yes/no/abstain vote poll being created.
+    /// - `eligible_voters`: The pro_tx_hashes of the masternodes allowed to vote with their strength,
+    ///   masternodes have 1, evonodes have 4.
+    /// - `block_info`: Reference to the block information at the time of the creation.
+    /// - `apply`: If false only the cost of the creation is estimated.
+    /// - `transaction`: Transaction arguments providing context for this operation.
+    /// - `platform_version`: Reference to the platform version against which the operation is executed.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` that, on success, includes the `FeeResult` of the creation.
+    /// On failure, it returns an `Error`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_yes_no_abstain_vote_poll(
+        &self,
+        creator_identity_id: [u8; 32],
+        vote_poll: YesNoAbstainVotePoll,
+        eligible_voters: BTreeMap<[u8; 32], u8>,
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .insert
+            .create_yes_no_abstain_vote_poll
+        {
+            0 => self.create_yes_no_abstain_vote_poll_v0(
+                creator_identity_id,
+                vote_poll,
+                eligible_voters,
+                block_info,
+                apply,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "create_yes_no_abstain_vote_poll".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Returns the operations of creating a yes/no/abstain vote poll, see
+    /// [`Drive::create_yes_no_abstain_vote_poll`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_yes_no_abstain_vote_poll_operations(
+        &self,
+        creator_identity_id: [u8; 32],
+        vote_poll: YesNoAbstainVotePoll,
+        eligible_voters: BTreeMap<[u8; 32], u8>,
+        block_info: &BlockInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .insert
+            .create_yes_no_abstain_vote_poll
+        {
+            0 => self.create_yes_no_abstain_vote_poll_operations_v0(
+                creator_identity_id,
+                vote_poll,
+                eligible_voters,
+                block_info,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "create_yes_no_abstain_vote_poll_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::drive::votes::paths::{
+    vote_decisions_poll_eligible_voters_path_vec, vote_decisions_poll_path,
+    vote_decisions_poll_path_vec, vote_decisions_tree_path, vote_decisions_tree_path_vec,
+    vote_root_path_vec, YES_NO_ABSTAIN_POLL_ELIGIBLE_VOTERS_TREE_KEY, YES_NO_ABSTAIN_POLL_INFO_KEY,
+    YES_NO_ABSTAIN_POLL_VOTES_TREE_KEY,
+};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::object_size_info::DriveKeyInfo;
+use crate::util::storage_flags::StorageFlags;
+use crate::util::type_constants::DEFAULT_HASH_SIZE_U8;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::serialization::PlatformSerializable;
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use dpp::voting::vote_polls::VotePoll;
+use grovedb::batch::KeyInfoPath;
+use grovedb::EstimatedLayerCount::{ApproximateElements, EstimatedLevel, PotentiallyAtMaxElements};
+use grovedb::EstimatedLayerSizes::{AllItems, AllSubtrees, Mix};
+use grovedb::EstimatedSumTrees::NoSumTrees;
+use grovedb::{Element, EstimatedLayerInformation, TransactionArg, TreeType};
+use platform_version::version::PlatformVersion;
+use std::collections::{BTreeMap, HashMap};
+
+impl Drive {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn create_yes_no_abstain_vote_poll_v0(
+        &self,
+        creator_identity_id: [u8; 32],
+        vote_poll: YesNoAbstainVotePoll,
+        eligible_voters: BTreeMap<[u8; 32], u8>,
+        block_info: &BlockInfo,
+        apply: bool,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let mut estimated_costs_only_with_layer_info = if apply {
+            None::<HashMap<KeyInfoPath, EstimatedLayerInformation>>
+        } else {
+            Some(HashMap::new())
+        };
+
+        let batch_operations = self.create_yes_no_abstain_vote_poll_operations_v0(
+            creator_identity_id,
+            vote_poll,
+            eligible_voters,
+            block_info,
+            &mut estimated_costs_only_with_layer_info,
+            transaction,
+            platform_version,
+        )?;
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        self.apply_batch_low_level_drive_operations(
+            estimated_costs_only_with_layer_info,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        let fees = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+        Ok(fees)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn create_yes_no_abstain_vote_poll_operations_v0(
+        &self,
+        creator_identity_id: [u8; 32],
+        vote_poll: YesNoAbstainVotePoll,
+        eligible_voters: BTreeMap<[u8; 32], u8>,
+        block_info: &BlockInfo,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        // The creation will have been verified as valid by rs-drive-abci, so the poll does not
+        // exist yet and nothing needs to be read from state
+
+        let poll_id = vote_poll.unique_id()?.to_buffer();
+
+        let storage_flags =
+            StorageFlags::new_single_epoch(block_info.epoch.index, Some(creator_identity_id));
+
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(vote_root_path_vec()),
+                EstimatedLayerInformation {
+                    tree_type: TreeType::NormalTree,
+                    estimated_layer_count: EstimatedLevel(2, false),
+                    estimated_layer_sizes: AllSubtrees(1, NoSumTrees, None),
+                },
+            );
+
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(vote_decisions_tree_path_vec()),
+                EstimatedLayerInformation {
+                    tree_type: TreeType::NormalTree,
+                    estimated_layer_count: PotentiallyAtMaxElements,
+                    estimated_layer_sizes: AllSubtrees(
+                        DEFAULT_HASH_SIZE_U8,
+                        NoSumTrees,
+                        Some(StorageFlags::approximate_size(true, None)),
+                    ),
+                },
+            );
+
+            // The poll info, the votes, the result and the eligible voters
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(vote_decisions_poll_path_vec(&poll_id)),
+                EstimatedLayerInformation {
+                    tree_type: TreeType::NormalTree,
+                    estimated_layer_count: EstimatedLevel(2, false),
+                    estimated_layer_sizes: Mix {
+                        subtrees_size: Some((
+                            1,
+                            NoSumTrees,
+                            Some(StorageFlags::approximate_size(true, None)),
+                            2,
+                        )),
+                        items_size: Some((
+                            1,
+                            256,
+                            Some(StorageFlags::approximate_size(true, None)),
+                            2,
+                        )),
+                        references_size: None,
+                    },
+                },
+            );
+
+            estimated_costs_only_with_layer_info.insert(
+                KeyInfoPath::from_known_owned_path(vote_decisions_poll_eligible_voters_path_vec(
+                    &poll_id,
+                )),
+                EstimatedLayerInformation {
+                    tree_type: TreeType::NormalTree,
+                    estimated_layer_count: ApproximateElements(eligible_voters.len() as u32),
+                    estimated_layer_sizes: AllItems(
+                        DEFAULT_HASH_SIZE_U8,
+                        1,
+                        Some(StorageFlags::approximate_size(true, None)),
+                    ),
+                },
+            );
+        }
+
+        self.batch_insert_empty_tree(
+            vote_decisions_tree_path(),
+            DriveKeyInfo::Key(poll_id.to_vec()),
+            Some(&storage_flags),
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        let end_time_ms = vote_poll.end_time_ms;
+        let vote_poll = VotePoll::YesNoAbstainVotePoll(vote_poll);
+
+        drive_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+            vote_decisions_poll_path_vec(&poll_id),
+            vec![YES_NO_ABSTAIN_POLL_INFO_KEY],
+            Element::new_item_with_flags(
+                vote_poll.serialize_to_bytes()?,
+                StorageFlags::map_to_some_element_flags(Some(&storage_flags)),
+            ),
+        ));
+
+        self.batch_insert_empty_tree(
+            vote_decisions_poll_path(&poll_id),
+            DriveKeyInfo::Key(vec![YES_NO_ABSTAIN_POLL_VOTES_TREE_KEY]),
+            Some(&storage_flags),
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        // The masternodes that can vote are the ones in the masternode list at creation, so that
+        // masternodes registered while the poll is open can not tip the result
+        self.batch_insert_empty_tree(
+            vote_decisions_poll_path(&poll_id),
+            DriveKeyInfo::Key(vec![YES_NO_ABSTAIN_POLL_ELIGIBLE_VOTERS_TREE_KEY]),
+            Some(&storage_flags),
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        let eligible_voters_path = vote_decisions_poll_eligible_voters_path_vec(&poll_id);
+
+        for (pro_tx_hash, strength) in eligible_voters {
+            drive_operations.push(LowLevelDriveOperation::insert_for_known_path_key_element(
+                eligible_voters_path.clone(),
+                pro_tx_hash.to_vec(),
+                Element::new_item_with_flags(
+                    vec![strength],
+                    StorageFlags::map_to_some_element_flags(Some(&storage_flags)),
+                ),
+            ));
+        }
+
+        self.add_vote_poll_end_date_query_operations(
+            Some(creator_identity_id),
+            vote_poll,
+            end_time_ms,
+            block_info,
+            estimated_costs_only_with_layer_info,
+            &mut None,
+            &mut drive_operations,
+            transaction,
+            platform_version,
+        )?;
+
+        Ok(drive_operations)
+    }
+}
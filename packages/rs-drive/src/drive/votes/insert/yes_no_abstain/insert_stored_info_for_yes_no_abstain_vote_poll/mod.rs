@@ -0,0 +1,65 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_info_storage::yes_no_abstain_vote_poll_stored_info::YesNoAbstainVotePollStoredInfo;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Inserts the tallied result of a finished yes/no/abstain vote poll that can later be queried
+    pub fn insert_stored_info_for_yes_no_abstain_vote_poll(
+        &self,
+        stored_info: YesNoAbstainVotePollStoredInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .insert
+            .insert_stored_info_for_yes_no_abstain_vote_poll
+        {
+            0 => self.insert_stored_info_for_yes_no_abstain_vote_poll_v0(
+                stored_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "insert_stored_info_for_yes_no_abstain_vote_poll".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Returns the operations of inserting the tallied result of a finished yes/no/abstain vote poll
+    pub fn insert_stored_info_for_yes_no_abstain_vote_poll_operations(
+        &self,
+        stored_info: YesNoAbstainVotePollStoredInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .insert
+            .insert_stored_info_for_yes_no_abstain_vote_poll
+        {
+            0 => self.insert_stored_info_for_yes_no_abstain_vote_poll_operations_v0(
+                stored_info,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "insert_stored_info_for_yes_no_abstain_vote_poll_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
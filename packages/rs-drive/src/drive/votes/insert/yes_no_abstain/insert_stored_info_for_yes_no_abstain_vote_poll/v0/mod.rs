@@ -0,0 +1,58 @@
+use crate::drive::votes::paths::{vote_decisions_poll_path_vec, YES_NO_ABSTAIN_POLL_RESULT_KEY};
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::object_size_info::PathKeyElementInfo::PathKeyElement;
+use dpp::serialization::PlatformSerializable;
+use dpp::voting::vote_info_storage::yes_no_abstain_vote_poll_stored_info::{
+    YesNoAbstainVotePollStoredInfo, YesNoAbstainVotePollStoredInfoV0Getters,
+};
+use grovedb::{Element, TransactionArg};
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn insert_stored_info_for_yes_no_abstain_vote_poll_v0(
+        &self,
+        stored_info: YesNoAbstainVotePollStoredInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let batch_operations = self.insert_stored_info_for_yes_no_abstain_vote_poll_operations_v0(
+            stored_info,
+            platform_version,
+        )?;
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        self.apply_batch_low_level_drive_operations(
+            None,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Ok(())
+    }
+
+    pub(super) fn insert_stored_info_for_yes_no_abstain_vote_poll_operations_v0(
+        &self,
+        stored_info: YesNoAbstainVotePollStoredInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        let poll_id = stored_info.vote_poll().unique_id()?.to_buffer();
+
+        self.batch_insert::<0>(
+            PathKeyElement((
+                vote_decisions_poll_path_vec(&poll_id),
+                vec![YES_NO_ABSTAIN_POLL_RESULT_KEY],
+                Element::new_item(stored_info.serialize_consume_to_bytes()?),
+            )),
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        Ok(drive_operations)
+    }
+}
@@ -0,0 +1,3 @@
+mod create_yes_no_abstain_vote_poll;
+mod insert_stored_info_for_yes_no_abstain_vote_poll;
+mod register_yes_no_abstain_identity_vote;
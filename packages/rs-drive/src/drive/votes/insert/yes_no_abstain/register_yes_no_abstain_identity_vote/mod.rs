@@ -0,0 +1,121 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::fee::fee_result::FeeResult;
+
+use crate::fees::op::LowLevelDriveOperation;
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Registers a vote of a masternode on a yes/no/abstain vote poll.
+    ///
+    /// The poll must have been created beforehand with [`Drive::create_yes_no_abstain_vote_poll`].
+    /// A masternode that already voted on the poll has its previous vote replaced.
+    ///
+    /// # Parameters
+    ///
+    /// - `voter_pro_tx_hash`: A 32-byte array representing the ProRegTx hash of the voter.
+    /// - `strength`: the strength of the vote, masternodes have 1, evonodes have 4
+    /// - `vote_poll`: The yes/no/abstain vote poll being voted on.
+    /// - `vote_choice`: The choice made by the voter.
+    /// - `block_info`: Reference to the block information at the time of the vote.
+    /// - `transaction`: Transaction arguments providing context for this operation.
+    /// - `platform_version`: Reference to the platform version against which the operation is executed.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` that, on success, includes the `FeeResult` detailing any fees applied as a result of the vote.
+    /// On failure, it returns an `Error`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_yes_no_abstain_identity_vote(
+        &self,
+        voter_pro_tx_hash: [u8; 32],
+        strength: u8,
+        vote_poll: YesNoAbstainVotePoll,
+        vote_choice: YesNoAbstainVoteChoice,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .insert
+            .register_yes_no_abstain_identity_vote
+        {
+            0 => self.register_yes_no_abstain_identity_vote_v0(
+                voter_pro_tx_hash,
+                strength,
+                vote_poll,
+                vote_choice,
+                block_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "register_yes_no_abstain_identity_vote".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+
+    /// Gathers the operations needed to register a vote of a masternode on a yes/no/abstain vote poll.
+    ///
+    /// # Parameters
+    ///
+    /// - `voter_pro_tx_hash`: A 32-byte array representing the ProRegTx hash of the voter.
+    /// - `strength`: the strength of the vote, masternodes have 1, evonodes have 4
+    /// - `vote_poll`: The yes/no/abstain vote poll being voted on.
+    /// - `vote_choice`: The choice made by the voter.
+    /// - `block_info`: Reference to the block information at the time of the vote.
+    /// - `transaction`: Transaction arguments providing context for this operation.
+    /// - `platform_version`: Reference to the platform version against which the operation is executed.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with the low level drive operations on success or an `Error` on failure.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_yes_no_abstain_identity_vote_operations(
+        &self,
+        voter_pro_tx_hash: [u8; 32],
+        strength: u8,
+        vote_poll: YesNoAbstainVotePoll,
+        vote_choice: YesNoAbstainVoteChoice,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .vote
+            .insert
+            .register_yes_no_abstain_identity_vote
+        {
+            0 => self.register_yes_no_abstain_identity_vote_operations_v0(
+                voter_pro_tx_hash,
+                strength,
+                vote_poll,
+                vote_choice,
+                block_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "register_yes_no_abstain_identity_vote_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
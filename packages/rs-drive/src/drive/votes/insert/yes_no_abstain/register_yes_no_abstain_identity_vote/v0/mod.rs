@@ -0,0 +1,124 @@
+use crate::drive::votes::paths::vote_decisions_poll_votes_path_vec;
+use crate::drive::votes::storage_form::yes_no_abstain_vote_storage_form::YesNoAbstainVoteStorageForm;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::fees::op::LowLevelDriveOperation;
+use crate::util::grove_operations::DirectQueryType;
+use crate::util::object_size_info::PathKeyElementInfo::PathKeyElement;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::serialization::PlatformSerializable;
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use grovedb::{Element, TransactionArg};
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn register_yes_no_abstain_identity_vote_v0(
+        &self,
+        voter_pro_tx_hash: [u8; 32],
+        strength: u8,
+        vote_poll: YesNoAbstainVotePoll,
+        vote_choice: YesNoAbstainVoteChoice,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let batch_operations = self.register_yes_no_abstain_identity_vote_operations_v0(
+            voter_pro_tx_hash,
+            strength,
+            vote_poll,
+            vote_choice,
+            block_info,
+            transaction,
+            platform_version,
+        )?;
+
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+        self.apply_batch_low_level_drive_operations(
+            None,
+            transaction,
+            batch_operations,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        let fees = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            self.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+        Ok(fees)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn register_yes_no_abstain_identity_vote_operations_v0(
+        &self,
+        voter_pro_tx_hash: [u8; 32],
+        strength: u8,
+        vote_poll: YesNoAbstainVotePoll,
+        vote_choice: YesNoAbstainVoteChoice,
+        _block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        // We do not have to do estimated costs because voting does not use them and is fixed cost
+        let mut drive_operations: Vec<LowLevelDriveOperation> = vec![];
+
+        // The vote at this point will have been verified as valid by rs-drive-abci
+
+        let poll_id = vote_poll.unique_id()?.to_buffer();
+
+        // The poll was created by a vote poll create transition, its existence and the
+        // eligibility of the voter will have been checked by rs-drive-abci
+        let previous_vote = self
+            .grove_get_raw_optional(
+                vote_decisions_poll_votes_path_vec(&poll_id)
+                    .as_slice()
+                    .into(),
+                voter_pro_tx_hash.as_slice(),
+                DirectQueryType::StatefulDirectQuery,
+                transaction,
+                &mut drive_operations,
+                &platform_version.drive,
+            )?
+            .map(|element| {
+                YesNoAbstainVoteStorageForm::deserialize_from_bytes(&element.into_item_bytes()?)
+            })
+            .transpose()?;
+
+        let storage_form = YesNoAbstainVoteStorageForm {
+            vote_choice,
+            strength,
+            identity_vote_times: previous_vote
+                .map(|previous_vote| previous_vote.identity_vote_times.saturating_add(1))
+                .unwrap_or(1),
+        };
+
+        let path_key_element = PathKeyElement::<0>((
+            vote_decisions_poll_votes_path_vec(&poll_id),
+            voter_pro_tx_hash.to_vec(),
+            Element::new_item(storage_form.serialize_to_bytes()?),
+        ));
+
+        if previous_vote.is_some() {
+            self.batch_replace(
+                path_key_element,
+                &mut drive_operations,
+                &platform_version.drive,
+            )?;
+        } else {
+            self.batch_insert(
+                path_key_element,
+                &mut drive_operations,
+                &platform_version.drive,
+            )?;
+        }
+
+        Ok(drive_operations)
+    }
+}
@@ -38,6 +38,10 @@ pub mod tree_path_storage_form;
 #[cfg(feature = "server")]
 mod fetch;
 
+#[cfg(any(feature = "server", feature = "verify"))]
+/// The state of yes/no/abstain vote polls
+pub mod yes_no_abstain_vote_poll_state;
+
 /// A trait to convert the vote to a tree path usable in grovedb
 pub trait TreePath {
     /// The tree path function
@@ -48,6 +52,9 @@ impl TreePath for Vote {
     fn tree_path<'a>(&'a self, contract: &'a DataContract) -> Result<Vec<&'a [u8]>, ProtocolError> {
         match self {
             Vote::ResourceVote(resource_vote) => resource_vote.tree_path(contract),
+            Vote::YesNoAbstainVote(_) => Err(ProtocolError::VoteError(
+                "yes/no/abstain votes are not stored under a contract".to_string(),
+            )),
         }
     }
 }
@@ -92,6 +99,9 @@ impl TreePath for ResourceVote {
                 }
                 Ok(path)
             }
+            VotePoll::YesNoAbstainVotePoll(_) => Err(ProtocolError::VoteError(
+                "resource votes can not be cast on yes/no/abstain vote polls".to_string(),
+            )),
         }
     }
 }
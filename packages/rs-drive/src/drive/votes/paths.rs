@@ -5,6 +5,10 @@
 ///
 /// |- End date Queries [key: "e"]
 /// |- Decisions [key: "d"]
+///    |- Yes/No/Abstain poll [key: poll unique id]
+///       |- Poll info [key: 0]
+///       |- Votes [key: 1]
+///       |- Result [key: 2]
 /// |- Contested Resource [key: "c"]
 ///    |- Active polls [key: "p"]
 ///    |- Identifier Votes Query [key: "i"]
@@ -63,6 +67,19 @@ pub const CONTESTED_DOCUMENT_INDEXES_TREE_KEY: u8 = 1;
 /// The tree key for storage
 pub const VOTING_STORAGE_TREE_KEY: u8 = 1;
 
+/// The key of the item storing the yes/no/abstain vote poll itself
+pub const YES_NO_ABSTAIN_POLL_INFO_KEY: u8 = 0;
+
+/// The tree key for the votes cast on a yes/no/abstain vote poll, keyed by voter
+pub const YES_NO_ABSTAIN_POLL_VOTES_TREE_KEY: u8 = 1;
+
+/// The key of the item storing the tallied result of a finished yes/no/abstain vote poll
+pub const YES_NO_ABSTAIN_POLL_RESULT_KEY: u8 = 2;
+
+/// The tree key for the masternodes allowed to vote on a yes/no/abstain vote poll, keyed by
+/// pro_tx_hash with their vote strength, as they were when the poll was created
+pub const YES_NO_ABSTAIN_POLL_ELIGIBLE_VOTERS_TREE_KEY: u8 = 3;
+
 /// Convenience methods to be easily able to get a path when we know the vote poll
 pub trait VotePollPaths {
     /// The root path, under this there should be the documents area and the contract itself
@@ -315,6 +332,44 @@ pub fn vote_decisions_tree_path_vec() -> Vec<Vec<u8>> {
     ]
 }
 
+/// the path of a single yes/no/abstain poll in the decisions tree
+pub fn vote_decisions_poll_path(poll_id: &[u8; 32]) -> [&[u8]; 3] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::Votes),
+        &[VOTE_DECISIONS_TREE_KEY as u8],
+        poll_id,
+    ]
+}
+
+/// the path of a single yes/no/abstain poll in the decisions tree as a vec
+pub fn vote_decisions_poll_path_vec(poll_id: &[u8; 32]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Votes as u8],
+        vec![VOTE_DECISIONS_TREE_KEY as u8],
+        poll_id.to_vec(),
+    ]
+}
+
+/// the path of the votes of a single yes/no/abstain poll as a vec
+pub fn vote_decisions_poll_votes_path_vec(poll_id: &[u8; 32]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Votes as u8],
+        vec![VOTE_DECISIONS_TREE_KEY as u8],
+        poll_id.to_vec(),
+        vec![YES_NO_ABSTAIN_POLL_VOTES_TREE_KEY],
+    ]
+}
+
+/// the path of the eligible voters of a single yes/no/abstain poll as a vec
+pub fn vote_decisions_poll_eligible_voters_path_vec(poll_id: &[u8; 32]) -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Votes as u8],
+        vec![VOTE_DECISIONS_TREE_KEY as u8],
+        poll_id.to_vec(),
+        vec![YES_NO_ABSTAIN_POLL_ELIGIBLE_VOTERS_TREE_KEY],
+    ]
+}
+
 /// the contested resource tree path of the voting branch
 pub fn vote_contested_resource_tree_path<'a>() -> [&'a [u8]; 2] {
     [
@@ -2,6 +2,8 @@ use crate::drive::votes::resolved::vote_polls::contested_document_resource_vote_
 use derive_more::From;
 use dpp::identifier::Identifier;
 use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dpp::voting::vote_info_storage::yes_no_abstain_vote_poll_stored_info::YesNoAbstainVoteTally;
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
 use dpp::voting::vote_polls::VotePoll;
 use dpp::ProtocolError;
 use std::collections::BTreeMap;
@@ -20,6 +22,8 @@ pub enum ResolvedVotePoll {
     ContestedDocumentResourceVotePollWithContractInfo(
         ContestedDocumentResourceVotePollWithContractInfo,
     ),
+    /// A yes/no/abstain vote poll, it does not reference any contract so it is already resolved.
+    YesNoAbstainVotePoll(YesNoAbstainVotePoll),
 }
 
 impl From<&ResolvedVotePoll> for VotePoll {
@@ -30,6 +34,9 @@ impl From<&ResolvedVotePoll> for VotePoll {
             ) => VotePoll::ContestedDocumentResourceVotePoll(
                 contested_document_resource_vote_poll.into(),
             ),
+            ResolvedVotePoll::YesNoAbstainVotePoll(yes_no_abstain_vote_poll) => {
+                VotePoll::YesNoAbstainVotePoll(yes_no_abstain_vote_poll.clone())
+            }
         }
     }
 }
@@ -42,6 +49,8 @@ pub enum ResolvedVotePollWithVotes {
         ContestedDocumentResourceVotePollWithContractInfo,
         BTreeMap<ResourceVoteChoice, Vec<Identifier>>,
     ),
+    /// A yes/no/abstain vote poll with the tally of the votes of masternodes still in the list.
+    YesNoAbstainVotePollWithVotes(YesNoAbstainVotePoll, YesNoAbstainVoteTally),
 }
 
 impl ResolvedVotePoll {
@@ -63,6 +72,7 @@ impl ResolvedVotePoll {
             ) => Ok(Some(
                 contested_document_resource_vote_poll.specialized_balance_id()?,
             )),
+            ResolvedVotePoll::YesNoAbstainVotePoll(_) => Ok(None),
         }
     }
 
@@ -81,6 +91,9 @@ impl ResolvedVotePoll {
             ResolvedVotePoll::ContestedDocumentResourceVotePollWithContractInfo(
                 contested_document_resource_vote_poll,
             ) => contested_document_resource_vote_poll.unique_id(),
+            ResolvedVotePoll::YesNoAbstainVotePoll(yes_no_abstain_vote_poll) => {
+                yes_no_abstain_vote_poll.unique_id()
+            }
         }
     }
 }
@@ -63,6 +63,9 @@ impl VotePollResolver for VotePoll {
                     ),
                 )
             }
+            VotePoll::YesNoAbstainVotePoll(yes_no_abstain_vote_poll) => Ok(
+                ResolvedVotePoll::YesNoAbstainVotePoll(yes_no_abstain_vote_poll.clone()),
+            ),
         }
     }
 
@@ -84,6 +87,9 @@ impl VotePollResolver for VotePoll {
                     ),
                 )
             }
+            VotePoll::YesNoAbstainVotePoll(yes_no_abstain_vote_poll) => Ok(
+                ResolvedVotePoll::YesNoAbstainVotePoll(yes_no_abstain_vote_poll),
+            ),
         }
     }
 }
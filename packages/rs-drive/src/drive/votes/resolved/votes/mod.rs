@@ -10,6 +10,7 @@ use dpp::identifier::Identifier;
 use dpp::voting::vote_polls::VotePoll;
 use dpp::voting::votes::resource_vote::v0::ResourceVoteV0;
 use dpp::voting::votes::resource_vote::ResourceVote;
+use dpp::voting::votes::yes_no_abstain_vote::YesNoAbstainVote;
 use dpp::voting::votes::Vote;
 use dpp::ProtocolError;
 
@@ -18,6 +19,8 @@ use dpp::ProtocolError;
 pub enum ResolvedVote {
     /// A resolved vote for a specific resource.
     ResolvedResourceVote(ResolvedResourceVote),
+    /// A yes/no/abstain vote, its vote poll does not need any resolution.
+    ResolvedYesNoAbstainVote(YesNoAbstainVote),
 }
 
 impl ResolvedVote {
@@ -37,6 +40,7 @@ impl ResolvedVote {
             ResolvedVote::ResolvedResourceVote(resource_vote) => {
                 resource_vote.vote_poll().specialized_balance_id()
             }
+            ResolvedVote::ResolvedYesNoAbstainVote(_) => Ok(None),
         }
     }
 }
@@ -57,10 +61,21 @@ impl From<ResolvedVote> for Vote {
                                 ),
                                 resource_vote_choice: vote_choice,
                             })),
+                            ResolvedVotePoll::YesNoAbstainVotePoll(yes_no_abstain_vote_poll) => {
+                                Self::ResourceVote(ResourceVote::V0(ResourceVoteV0 {
+                                    vote_poll: VotePoll::YesNoAbstainVotePoll(
+                                        yes_no_abstain_vote_poll,
+                                    ),
+                                    resource_vote_choice: vote_choice,
+                                }))
+                            }
                         }
                     }
                 }
             }
+            ResolvedVote::ResolvedYesNoAbstainVote(yes_no_abstain_vote) => {
+                Self::YesNoAbstainVote(yes_no_abstain_vote)
+            }
         }
     }
 }
@@ -53,6 +53,9 @@ impl VoteResolver for Vote {
             Vote::ResourceVote(resource_vote) => Ok(ResolvedVote::ResolvedResourceVote(
                 resource_vote.resolve(drive, transaction, platform_version)?,
             )),
+            Vote::YesNoAbstainVote(yes_no_abstain_vote) => Ok(
+                ResolvedVote::ResolvedYesNoAbstainVote(yes_no_abstain_vote.clone()),
+            ),
         }
     }
 
@@ -66,6 +69,9 @@ impl VoteResolver for Vote {
             Vote::ResourceVote(resource_vote) => Ok(ResolvedVote::ResolvedResourceVote(
                 resource_vote.resolve_owned(drive, transaction, platform_version)?,
             )),
+            Vote::YesNoAbstainVote(yes_no_abstain_vote) => {
+                Ok(ResolvedVote::ResolvedYesNoAbstainVote(yes_no_abstain_vote))
+            }
         }
     }
 }
@@ -6,3 +6,6 @@ pub mod vote_storage_form;
 
 /// Module for handling the storage form of the reference of the contested document resources.
 pub mod contested_document_resource_reference_storage_form;
+
+/// Module for handling the storage form of a vote on a yes/no/abstain vote poll.
+pub mod yes_no_abstain_vote_storage_form;
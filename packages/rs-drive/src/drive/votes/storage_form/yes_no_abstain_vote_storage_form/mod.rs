@@ -0,0 +1,50 @@
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use bincode::{Decode, Encode};
+use dpp::voting::vote_choices::yes_no_abstain_vote_choice::YesNoAbstainVoteChoice;
+
+/// Represents the storage form of a masternode vote on a yes/no/abstain vote poll.
+///
+/// It is stored as an item under the votes tree of the poll, keyed by the pro_tx_hash of the voter.
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+pub struct YesNoAbstainVoteStorageForm {
+    /// The choice of the voter
+    pub vote_choice: YesNoAbstainVoteChoice,
+
+    /// The strength of the vote, masternodes have 1, evonodes have 4
+    pub strength: u8,
+
+    /// The amount of times the identity has voted
+    pub identity_vote_times: u16,
+}
+
+impl YesNoAbstainVoteStorageForm {
+    /// Serializes the storage form to bytes
+    pub fn serialize_to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let config = bincode::config::standard()
+            .with_big_endian()
+            .with_no_limit();
+        bincode::encode_to_vec(self, config).map_err(|e| {
+            Error::Drive(DriveError::CorruptedSerialization(format!(
+                "can not encode yes/no/abstain vote: {}",
+                e
+            )))
+        })
+    }
+
+    /// Deserializes the storage form from bytes
+    pub fn deserialize_from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let config = bincode::config::standard()
+            .with_big_endian()
+            .with_no_limit();
+        bincode::decode_from_slice(bytes, config)
+            .map(|(storage_form, _)| storage_form)
+            .map_err(|e| {
+                Error::Drive(DriveError::CorruptedSerialization(format!(
+                    "serialization of yes/no/abstain vote {} is corrupted: {}",
+                    hex::encode(bytes),
+                    e
+                )))
+            })
+    }
+}
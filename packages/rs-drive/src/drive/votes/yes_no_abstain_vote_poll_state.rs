@@ -0,0 +1,112 @@
+use crate::drive::votes::paths::{
+    vote_decisions_poll_path_vec, vote_decisions_poll_votes_path_vec, YES_NO_ABSTAIN_POLL_INFO_KEY,
+    YES_NO_ABSTAIN_POLL_RESULT_KEY, YES_NO_ABSTAIN_POLL_VOTES_TREE_KEY,
+};
+use crate::drive::votes::storage_form::yes_no_abstain_vote_storage_form::YesNoAbstainVoteStorageForm;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::query::Query;
+use dpp::identifier::Identifier;
+use dpp::serialization::PlatformDeserializable;
+use dpp::voting::vote_info_storage::yes_no_abstain_vote_poll_stored_info::YesNoAbstainVotePollStoredInfo;
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use dpp::voting::vote_polls::VotePoll;
+use grovedb::query_result_type::Path;
+use grovedb::{Element, PathQuery, QueryItem, SizedQuery};
+use std::collections::BTreeMap;
+
+/// The state of a yes/no/abstain vote poll as stored in the decisions tree.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct YesNoAbstainVotePollState {
+    /// The vote poll, this is `None` if the poll was never created
+    pub vote_poll: Option<YesNoAbstainVotePoll>,
+    /// The votes cast on the poll, keyed by the pro_tx_hash of the voter
+    pub votes: BTreeMap<Identifier, YesNoAbstainVoteStorageForm>,
+    /// The tallied result, only present once the poll has ended
+    pub stored_info: Option<YesNoAbstainVotePollStoredInfo>,
+}
+
+impl Drive {
+    /// The query getting the poll info, the votes and the result of a yes/no/abstain vote poll
+    pub fn yes_no_abstain_vote_poll_state_query(vote_poll_id: [u8; 32]) -> PathQuery {
+        let poll_path = vote_decisions_poll_path_vec(&vote_poll_id);
+
+        let mut query = Query::new();
+
+        query.insert_key(vec![YES_NO_ABSTAIN_POLL_INFO_KEY]);
+        query.insert_key(vec![YES_NO_ABSTAIN_POLL_VOTES_TREE_KEY]);
+        query.insert_key(vec![YES_NO_ABSTAIN_POLL_RESULT_KEY]);
+
+        query.add_conditional_subquery(
+            QueryItem::Key(vec![YES_NO_ABSTAIN_POLL_VOTES_TREE_KEY]),
+            None,
+            Some(Query::new_range_full()),
+        );
+
+        PathQuery::new(poll_path, SizedQuery::new(query, None, None))
+    }
+
+    /// The query getting the info item of a yes/no/abstain vote poll
+    pub fn yes_no_abstain_vote_poll_info_query(vote_poll_id: [u8; 32]) -> PathQuery {
+        let poll_path = vote_decisions_poll_path_vec(&vote_poll_id);
+
+        let mut query = Query::new();
+        query.insert_key(vec![YES_NO_ABSTAIN_POLL_INFO_KEY]);
+
+        PathQuery::new(poll_path, SizedQuery::new(query, Some(1), None))
+    }
+
+    /// The query getting the vote a masternode cast on a yes/no/abstain vote poll
+    pub fn yes_no_abstain_identity_vote_query(
+        vote_poll_id: [u8; 32],
+        masternode_pro_tx_hash: [u8; 32],
+    ) -> PathQuery {
+        let votes_path = vote_decisions_poll_votes_path_vec(&vote_poll_id);
+
+        let mut query = Query::new();
+        query.insert_key(masternode_pro_tx_hash.to_vec());
+
+        PathQuery::new(votes_path, SizedQuery::new(query, Some(1), None))
+    }
+}
+
+impl YesNoAbstainVotePollState {
+    /// Builds the poll state from the elements returned by the yes/no/abstain vote poll state query
+    pub fn try_from_path_key_elements(
+        path_key_elements: impl IntoIterator<Item = (Path, Vec<u8>, Element)>,
+    ) -> Result<Self, Error> {
+        let mut state = YesNoAbstainVotePollState::default();
+        for (path, key, element) in path_key_elements {
+            if element.is_any_tree() {
+                continue;
+            }
+            let bytes = element.into_item_bytes()?;
+            // The votes are one level deeper than the poll info and result
+            if path.last().map(|last| last.as_slice())
+                == Some([YES_NO_ABSTAIN_POLL_VOTES_TREE_KEY].as_slice())
+            {
+                let voter_id = Identifier::from_bytes(&key)?;
+                state.votes.insert(
+                    voter_id,
+                    YesNoAbstainVoteStorageForm::deserialize_from_bytes(&bytes)?,
+                );
+            } else if key == [YES_NO_ABSTAIN_POLL_INFO_KEY] {
+                let VotePoll::YesNoAbstainVotePoll(vote_poll) =
+                    VotePoll::deserialize_from_bytes(&bytes)?
+                else {
+                    return Err(Error::Drive(DriveError::CorruptedDriveState(
+                        "poll stored in the decisions tree must be a yes/no/abstain vote poll"
+                            .to_string(),
+                    )));
+                };
+                state.vote_poll = Some(vote_poll);
+            } else if key == [YES_NO_ABSTAIN_POLL_RESULT_KEY] {
+                state.stored_info = Some(YesNoAbstainVotePollStoredInfo::deserialize_from_bytes(
+                    &bytes,
+                )?);
+            }
+        }
+        Ok(state)
+    }
+}
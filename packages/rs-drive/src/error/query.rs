@@ -144,4 +144,16 @@ pub enum QuerySyntaxError {
     /// Missing index values for query
     #[error("incorrect index values error: {0}")]
     IndexValuesError(String),
+
+    /// Invalid aggregate property error
+    #[error("invalid aggregate property error: {0}")]
+    InvalidAggregateProperty(String),
+
+    /// Aggregate overflow error
+    #[error("aggregate overflow error: {0}")]
+    AggregateOverflow(String),
+
+    /// Invalid start after error
+    #[error("invalid start after error: {0}")]
+    InvalidStartAfter(String),
 }
@@ -23,6 +23,7 @@ use dpp::state_transition::identity_credit_withdrawal_transition::accessors::Ide
 use dpp::state_transition::identity_topup_transition::accessors::IdentityTopUpTransitionAccessorsV0;
 use dpp::state_transition::identity_update_transition::accessors::IdentityUpdateTransitionAccessorsV0;
 use dpp::state_transition::masternode_vote_transition::accessors::MasternodeVoteTransitionAccessorsV0;
+use dpp::state_transition::vote_poll_create_transition::accessors::VotePollCreateTransitionAccessorsV0;
 use dpp::state_transition::{StateTransition, StateTransitionLike};
 use dpp::voting::votes::resource_vote::accessors::v0::ResourceVoteGettersV0;
 use dpp::voting::votes::Vote;
@@ -51,6 +52,9 @@ impl Drive {
             StateTransition::DataContractUpdate(st) => {
                 contract_ids_to_non_historical_path_query(&st.modified_data_ids())
             }
+            StateTransition::DataContractDelete(st) => {
+                contract_ids_to_non_historical_path_query(&st.modified_data_ids())
+            }
             StateTransition::Batch(st) => {
                 if st.transitions_len() > 1 {
                     return Ok(ProofCreationResult::new_with_error(
@@ -197,8 +201,19 @@ impl Drive {
                         path_query.query.limit = None;
                         path_query
                     }
+                    Vote::YesNoAbstainVote(_) => {
+                        let vote_poll_id = st.vote().vote_poll_unique_id()?;
+                        Drive::yes_no_abstain_identity_vote_query(
+                            vote_poll_id.to_buffer(),
+                            pro_tx_hash.to_buffer(),
+                        )
+                    }
                 }
             }
+            StateTransition::VotePollCreate(st) => {
+                let vote_poll_id = st.vote_poll().unique_id()?;
+                Drive::yes_no_abstain_vote_poll_info_query(vote_poll_id.to_buffer())
+            }
         };
 
         let proof = self.grove_get_proved_path_query(
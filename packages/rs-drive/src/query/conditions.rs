@@ -6,6 +6,9 @@ use crate::error::Error;
 use crate::query::{QuerySyntaxSimpleValidationResult, QuerySyntaxValidationResult};
 #[cfg(any(feature = "server", feature = "verify"))]
 use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::full_text::{full_text_search_term, full_text_terms};
+#[cfg(any(feature = "server", feature = "verify"))]
+use dpp::data_contract::document_type::methods::DocumentTypeBasicMethods;
 use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
 use dpp::data_contract::document_type::{DocumentPropertyType, DocumentType, DocumentTypeRef};
 use dpp::document::document_methods::DocumentMethodsV0;
@@ -19,8 +22,8 @@ use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Display;
 use WhereOperator::{
-    Between, BetweenExcludeBounds, BetweenExcludeLeft, BetweenExcludeRight, Equal, GreaterThan,
-    GreaterThanOrEquals, In, LessThan, LessThanOrEquals, StartsWith,
+    Between, BetweenExcludeBounds, BetweenExcludeLeft, BetweenExcludeRight, Contains, Equal,
+    GreaterThan, GreaterThanOrEquals, In, LessThan, LessThanOrEquals, StartsWith,
 };
 
 /// Converts SQL values to CBOR.
@@ -73,6 +76,8 @@ pub enum WhereOperator {
     In,
     /// Starts with
     StartsWith,
+    /// Contains a word, only for properties with a full text index
+    Contains,
 }
 
 impl WhereOperator {
@@ -90,6 +95,7 @@ impl WhereOperator {
             BetweenExcludeRight => false,
             In => false,
             StartsWith => false,
+            Contains => false,
         }
     }
 
@@ -119,6 +125,9 @@ impl WhereOperator {
             StartsWith => Err(Error::Query(QuerySyntaxError::InvalidWhereClauseOrder(
                 "Startswith clause order invalid",
             ))),
+            Contains => Err(Error::Query(QuerySyntaxError::InvalidWhereClauseOrder(
+                "Contains clause order invalid",
+            ))),
         }
     }
 }
@@ -129,9 +138,8 @@ impl WhereOperator {
         match self {
             Equal => false,
             GreaterThan | GreaterThanOrEquals | LessThan | LessThanOrEquals | Between
-            | BetweenExcludeBounds | BetweenExcludeLeft | BetweenExcludeRight | In | StartsWith => {
-                true
-            }
+            | BetweenExcludeBounds | BetweenExcludeLeft | BetweenExcludeRight | In | StartsWith
+            | Contains => true,
         }
     }
 
@@ -158,6 +166,7 @@ impl WhereOperator {
             | "between_exclude_right" => Some(BetweenExcludeRight),
             "In" | "in" => Some(In),
             "StartsWith" | "startsWith" | "startswith" | "starts_with" => Some(StartsWith),
+            "Contains" | "contains" => Some(Contains),
             &_ => None,
         }
     }
@@ -232,6 +241,11 @@ impl WhereOperator {
                 (Value::Text(text), Value::Text(prefix)) => text.starts_with(prefix.as_str()),
                 _ => false,
             },
+            Contains => match (left_value, right_value) {
+                (Value::Text(text), Value::Text(word)) => full_text_search_term(word)
+                    .is_some_and(|term| full_text_terms(text).contains(&term)),
+                _ => false,
+            },
         }
     }
 
@@ -241,7 +255,7 @@ impl WhereOperator {
         match self {
             Equal => true,
             In => matches!(value, Value::Array(_) | Value::Bytes(_)),
-            StartsWith => matches!(value, Value::Text(_)),
+            StartsWith | Contains => matches!(value, Value::Text(_)),
             GreaterThan | GreaterThanOrEquals | LessThan | LessThanOrEquals => {
                 match property_type {
                     DocumentPropertyType::F64 => is_numeric_value(value),
@@ -307,6 +321,7 @@ impl Display for WhereOperator {
             BetweenExcludeRight => "BetweenExcludeRight",
             In => "In",
             StartsWith => "StartsWith",
+            Contains => "Contains",
         };
 
         write!(f, "{}", s)
@@ -632,6 +647,7 @@ impl<'a> WhereClause {
                 LessThan => true,
                 LessThanOrEquals => true,
                 StartsWith => false,
+                Contains => false,
                 Between => false,
                 BetweenExcludeBounds => false,
                 BetweenExcludeRight => false,
@@ -649,6 +665,7 @@ impl<'a> WhereClause {
                 LessThan => false,
                 LessThanOrEquals => false,
                 StartsWith => true,
+                Contains => true,
                 Between => true,
                 BetweenExcludeBounds => true,
                 BetweenExcludeRight => true,
@@ -1168,6 +1185,13 @@ impl<'a> WhereClause {
                     }
                 }
             }
+            Contains => {
+                return Err(Error::Query(
+                    QuerySyntaxError::InvalidWhereClauseComponents(
+                        "contains clauses are only queried through their full text index",
+                    ),
+                ));
+            }
         }
         Ok(query)
     }
@@ -1424,6 +1448,30 @@ impl<'a> WhereClause {
             }
         }
 
+        // Check contains is against a full text index and searches for a single term
+        if self.operator == Contains {
+            if document_type
+                .full_text_index_for_property(&self.field)
+                .is_none()
+            {
+                return QuerySyntaxSimpleValidationResult::new_with_error(
+                    QuerySyntaxError::WhereClauseOnNonIndexedProperty(format!(
+                        "contains clause on field {} requires a full text index",
+                        self.field
+                    )),
+                );
+            }
+            if let Value::Text(word) = &self.value {
+                if full_text_search_term(word).is_none() {
+                    return QuerySyntaxSimpleValidationResult::new_with_error(
+                        QuerySyntaxError::InvalidWhereClauseComponents(
+                            "contains clause must search for a single word",
+                        ),
+                    );
+                }
+            }
+        }
+
         // Check in clause values
         if self.operator == In {
             // Ensure array value, length bounds and no duplicates
@@ -1600,6 +1648,40 @@ impl ValueClause {
     pub fn matches_value(&self, value: &Value) -> bool {
         self.operator.eval(value, &self.value)
     }
+
+    /// Returns a `ValueClause` given a list of clause components
+    pub fn from_components(clause_components: &[Value]) -> Result<Self, Error> {
+        let [operator_value, value] = clause_components else {
+            return Err(Error::Query(
+                QuerySyntaxError::InvalidWhereClauseComponents(
+                    "value clauses should have exactly 2 components",
+                ),
+            ));
+        };
+
+        let operator_string = operator_value.as_text().ok_or(Error::Query(
+            QuerySyntaxError::InvalidWhereClauseComponents(
+                "first field of value clause component should be a string",
+            ),
+        ))?;
+
+        let operator = WhereOperator::from_string(operator_string).ok_or({
+            Error::Query(QuerySyntaxError::InvalidWhereClauseComponents(
+                "first field of value clause component should be a known operator",
+            ))
+        })?;
+
+        Ok(ValueClause {
+            operator,
+            value: value.clone(),
+        })
+    }
+}
+
+impl From<ValueClause> for Value {
+    fn from(value: ValueClause) -> Self {
+        Value::Array(vec![value.operator.into(), value.value])
+    }
 }
 
 /// Returns the set of allowed operators for a given property type
@@ -1633,6 +1715,7 @@ pub fn allowed_ops_for_type(property_type: &DocumentPropertyType) -> &'static [W
             Equal,
             In,
             StartsWith,
+            Contains,
             GreaterThan,
             GreaterThanOrEquals,
             LessThan,
@@ -1705,8 +1788,8 @@ mod tests {
     use crate::error::query::QuerySyntaxError;
     use crate::query::conditions::WhereClause;
     use crate::query::conditions::{
-        Between, BetweenExcludeBounds, BetweenExcludeLeft, BetweenExcludeRight, Equal, GreaterThan,
-        GreaterThanOrEquals, In, LessThan, LessThanOrEquals, ValueClause,
+        Between, BetweenExcludeBounds, BetweenExcludeLeft, BetweenExcludeRight, Contains, Equal,
+        GreaterThan, GreaterThanOrEquals, In, LessThan, LessThanOrEquals, ValueClause,
     };
     use crate::query::InternalClauses;
     use dpp::data_contract::accessors::v0::DataContractV0Getters;
@@ -2090,4 +2173,36 @@ mod tests {
         let res = clause.validate_against_schema(doc_type);
         assert!(res.is_valid());
     }
+
+    #[test]
+    fn validate_rejects_contains_without_full_text_index() {
+        let fixture = get_data_contract_fixture(None, 0, LATEST_PLATFORM_VERSION.protocol_version);
+        let contract = fixture.data_contract_owned();
+        let doc_type = contract
+            .document_type_for_name("niceDocument")
+            .expect("doc type exists");
+
+        let clause = WhereClause {
+            field: "name".to_string(),
+            operator: Contains,
+            value: Value::Text("bike".to_string()),
+        };
+        let res = clause.validate_against_schema(doc_type);
+        assert!(matches!(
+            res.first_error(),
+            Some(QuerySyntaxError::WhereClauseOnNonIndexedProperty(_))
+        ));
+    }
+
+    #[test]
+    fn value_clause_contains_matches_whole_terms() {
+        let clause = ValueClause {
+            operator: Contains,
+            value: Value::Text("Bike".to_string()),
+        };
+
+        assert!(clause.matches_value(&Value::Text("Red bike, almost new".to_string())));
+        assert!(!clause.matches_value(&Value::Text("Red bikes".to_string())));
+        assert!(!clause.matches_value(&Value::U64(1)));
+    }
 }
@@ -0,0 +1,183 @@
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use crate::query::DriveDocumentQuery;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::identifier::Identifier;
+
+#[cfg(feature = "server")]
+use crate::drive::Drive;
+#[cfg(feature = "server")]
+use dpp::block::block_info::BlockInfo;
+#[cfg(feature = "server")]
+use dpp::version::PlatformVersion;
+#[cfg(feature = "server")]
+use grovedb::TransactionArg;
+
+/// Aggregate function applied to the documents matched by a [DriveDocumentQuery]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DocumentAggregateFunction {
+    /// Number of matching documents
+    Count,
+    /// Sum of an integer property over matching documents
+    Sum(String),
+}
+
+/// Aggregate computed over a contiguous range of matching documents
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct DocumentAggregateOutcome {
+    /// Number of documents covered
+    pub count: u64,
+    /// Sum of the aggregated property, always 0 for [DocumentAggregateFunction::Count]
+    pub sum: i64,
+    /// The last covered document when more documents match the query;
+    /// aggregation continues after it
+    pub next_start_after: Option<Identifier>,
+}
+
+impl DocumentAggregateOutcome {
+    /// Merge the outcome of the following range of documents into this one.
+    pub fn merge(&mut self, next: DocumentAggregateOutcome) -> Result<(), Error> {
+        self.count = self.count.checked_add(next.count).ok_or_else(|| {
+            Error::Query(QuerySyntaxError::AggregateOverflow(
+                "document count overflow".to_string(),
+            ))
+        })?;
+        self.sum = self.sum.checked_add(next.sum).ok_or_else(|| {
+            Error::Query(QuerySyntaxError::AggregateOverflow(
+                "document sum overflow".to_string(),
+            ))
+        })?;
+        self.next_start_after = next.next_start_after;
+        Ok(())
+    }
+}
+
+/// Count or sum of documents matching a [DriveDocumentQuery]
+///
+/// Aggregates are computed over index ranges: without proofs the node scans up to
+/// `DriveConfig::max_aggregate_query_documents` documents per request, with proofs a
+/// single request covers at most `limit` documents which are verified and aggregated
+/// by the client. In both cases `next_start_after` tells where to continue.
+///
+/// Indexes don't keep counts or sums, so every matching document is read: the cost of
+/// an aggregate is linear in the number of matching documents, for the node and, with
+/// proofs, for the client. Large collections should be narrowed with where clauses.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DriveDocumentAggregateQuery<'a> {
+    /// Query selecting the aggregated documents
+    pub query: DriveDocumentQuery<'a>,
+    /// Aggregate function
+    pub function: DocumentAggregateFunction,
+}
+
+impl<'a> DriveDocumentAggregateQuery<'a> {
+    /// Creates an aggregate query, validating the aggregated property.
+    pub fn new(
+        query: DriveDocumentQuery<'a>,
+        function: DocumentAggregateFunction,
+    ) -> Result<Self, Error> {
+        if query.offset.is_some() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "offset is not supported for aggregate queries".to_string(),
+            )));
+        }
+
+        if let DocumentAggregateFunction::Sum(property_name) = &function {
+            let property = query
+                .document_type
+                .flattened_properties()
+                .get(property_name)
+                .ok_or_else(|| {
+                    Error::Query(QuerySyntaxError::InvalidAggregateProperty(format!(
+                        "property {} is not defined in document type {}",
+                        property_name,
+                        query.document_type.name()
+                    )))
+                })?;
+            if !property.property_type.is_integer() {
+                return Err(Error::Query(QuerySyntaxError::InvalidAggregateProperty(
+                    format!("property {} is not an integer", property_name),
+                )));
+            }
+        }
+
+        Ok(Self { query, function })
+    }
+
+    /// The document query for a range starting after `start_after`,
+    /// or at the start of the original query if `None`.
+    pub fn range_query(
+        &self,
+        start_after: Option<[u8; 32]>,
+        limit: Option<u16>,
+    ) -> DriveDocumentQuery<'a> {
+        let mut query = self.query.clone();
+        if let Some(start_after) = start_after {
+            query.start_at = Some(start_after);
+            query.start_at_included = false;
+        }
+        query.limit = limit;
+        query
+    }
+
+    /// Aggregates a range of documents, as returned by [Self::range_query] with `limit`.
+    pub fn aggregate_documents(
+        &self,
+        documents: &[Document],
+        limit: Option<u16>,
+    ) -> Result<DocumentAggregateOutcome, Error> {
+        let mut sum: i64 = 0;
+        if let DocumentAggregateFunction::Sum(property_name) = &self.function {
+            for document in documents {
+                // Documents without the (optional) property don't contribute
+                let Some(value) = document.get(property_name) else {
+                    continue;
+                };
+                let value: i64 = value.to_integer().map_err(|e| {
+                    Error::Query(QuerySyntaxError::AggregateOverflow(format!(
+                        "property {} of document {} can't be summed: {}",
+                        property_name,
+                        document.id(),
+                        e
+                    )))
+                })?;
+                sum = sum.checked_add(value).ok_or_else(|| {
+                    Error::Query(QuerySyntaxError::AggregateOverflow(format!(
+                        "sum of property {} overflows",
+                        property_name
+                    )))
+                })?;
+            }
+        }
+
+        let next_start_after = match limit {
+            Some(limit) if documents.len() >= limit as usize => {
+                documents.last().map(|document| document.id())
+            }
+            _ => None,
+        };
+
+        Ok(DocumentAggregateOutcome {
+            count: documents.len() as u64,
+            sum,
+            next_start_after,
+        })
+    }
+
+    #[cfg(feature = "server")]
+    /// Executes the aggregate query with proof for a single range of at most `limit`
+    /// documents and returns the proof and fee.
+    pub fn execute_with_proof(
+        &self,
+        drive: &Drive,
+        start_after: Option<[u8; 32]>,
+        limit: u16,
+        block_info: Option<BlockInfo>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<u8>, u64), Error> {
+        self.range_query(start_after, Some(limit))
+            .execute_with_proof(drive, block_info, transaction, platform_version)
+    }
+}
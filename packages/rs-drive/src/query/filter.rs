@@ -534,7 +534,7 @@ impl DriveDocumentQueryFilter<'_> {
                             }
                             _ => false,
                         },
-                        WhereOperator::StartsWith => false,
+                        WhereOperator::StartsWith | WhereOperator::Contains => false,
                     };
                     if ok {
                         QuerySyntaxSimpleValidationResult::new()
@@ -3,6 +3,9 @@ use std::sync::Arc;
 #[cfg(any(feature = "server", feature = "verify"))]
 pub use {
     conditions::{ValueClause, WhereClause, WhereOperator},
+    document_aggregate_query::{
+        DocumentAggregateFunction, DocumentAggregateOutcome, DriveDocumentAggregateQuery,
+    },
     grovedb::{PathQuery, Query, QueryItem, SizedQuery},
     ordering::OrderClause,
     single_document_drive_query::SingleDocumentDriveQuery,
@@ -15,12 +18,17 @@ pub use {
 use {
     crate::{
         drive::contract::paths::DataContractPaths,
+        drive::document::paths::contract_document_type_full_text_property_path_vec,
         error::{drive::DriveError, query::QuerySyntaxError, Error},
     },
     dpp::{
         data_contract::{
             accessors::v0::DataContractV0Getters,
-            document_type::{accessors::DocumentTypeV0Getters, methods::DocumentTypeV0Methods},
+            document_type::full_text::full_text_search_term,
+            document_type::{
+                accessors::DocumentTypeV0Getters,
+                methods::{DocumentTypeBasicMethods, DocumentTypeV0Methods},
+            },
             document_type::{DocumentTypeRef, Index, IndexProperty},
             DataContract,
         },
@@ -56,6 +64,8 @@ use dpp::validation::{SimpleValidationResult, ValidationResult};
 use {
     crate::{drive::Drive, fees::op::LowLevelDriveOperation},
     dpp::block::block_info::BlockInfo,
+    dpp::data_contract::document_type::accessors::DocumentTypeV1Getters,
+    dpp::prelude::{BlockHeight, TimestampMillis},
 };
 // Crate-local unconditional imports
 use crate::config::DriveConfig;
@@ -147,9 +157,15 @@ pub mod identity_token_balance_drive_query;
 #[cfg(any(feature = "server", feature = "verify"))]
 pub mod identity_token_info_drive_query;
 
+/// Count and sum queries over documents
+#[cfg(any(feature = "server", feature = "verify"))]
+pub mod document_aggregate_query;
 /// Document subscription filtering
 #[cfg(any(feature = "server", feature = "verify"))]
 pub mod filter;
+/// A query to get the historical events of a token
+#[cfg(any(feature = "server", feature = "verify"))]
+pub mod token_event_history_query;
 /// A query to get the token's status
 #[cfg(any(feature = "server", feature = "verify"))]
 pub mod token_status_drive_query;
@@ -232,6 +248,33 @@ impl InternalClauses {
             && self.primary_key_equal_clause.is_none()
     }
 
+    #[cfg(any(feature = "server", feature = "verify"))]
+    /// Parses an array of where clause components and returns them as type `InternalClauses`.
+    ///
+    /// A `Value::Null` is read as no where clauses.
+    pub fn from_where_clauses_value(where_clause: Value) -> Result<Self, Error> {
+        let all_where_clauses: Vec<WhereClause> = match where_clause {
+            Value::Null => Ok(vec![]),
+            Value::Array(clauses) => clauses
+                .iter()
+                .map(|where_clause| {
+                    if let Value::Array(clauses_components) = where_clause {
+                        WhereClause::from_components(clauses_components)
+                    } else {
+                        Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                            "where clause must be an array",
+                        )))
+                    }
+                })
+                .collect::<Result<Vec<WhereClause>, Error>>(),
+            _ => Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
+                "where clause must be an array",
+            ))),
+        }?;
+
+        InternalClauses::extract_from_clauses(all_where_clauses)
+    }
+
     #[cfg(any(feature = "server", feature = "verify"))]
     /// Extracts the `WhereClause`s and returns them as type `InternalClauses`.
     pub fn extract_from_clauses(all_where_clauses: Vec<WhereClause>) -> Result<Self, Error> {
@@ -764,26 +807,7 @@ impl<'a> DriveDocumentQuery<'a> {
                 config.max_query_limit
             ))))?;
 
-        let all_where_clauses: Vec<WhereClause> = match where_clause {
-            Value::Null => Ok(vec![]),
-            Value::Array(clauses) => clauses
-                .iter()
-                .map(|where_clause| {
-                    if let Value::Array(clauses_components) = where_clause {
-                        WhereClause::from_components(clauses_components)
-                    } else {
-                        Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
-                            "where clause must be an array",
-                        )))
-                    }
-                })
-                .collect::<Result<Vec<WhereClause>, Error>>(),
-            _ => Err(Error::Query(QuerySyntaxError::InvalidFormatWhereClause(
-                "where clause must be an array",
-            ))),
-        }?;
-
-        let internal_clauses = InternalClauses::extract_from_clauses(all_where_clauses)?;
+        let internal_clauses = InternalClauses::from_where_clauses_value(where_clause)?;
 
         let order_by: IndexMap<String, OrderClause> = order_by
             .map_or(vec![], |id_cbor| {
@@ -1912,6 +1936,86 @@ impl<'a> DriveDocumentQuery<'a> {
         Ok(())
     }
 
+    #[cfg(any(feature = "server", feature = "verify"))]
+    /// Returns a path query over the documents referenced under the term of a contains clause
+    /// in a full text index, ordered by document id.
+    fn get_full_text_path_query(
+        &self,
+        contains_clause: &WhereClause,
+        starts_at_document: Option<(Document, bool)>,
+    ) -> Result<PathQuery, Error> {
+        if !self.internal_clauses.equal_clauses.is_empty()
+            || self.internal_clauses.in_clause.is_some()
+        {
+            return Err(Error::Query(
+                QuerySyntaxError::InvalidWhereClauseComponents(
+                    "a contains clause can not be combined with other clauses",
+                ),
+            ));
+        }
+
+        if self
+            .document_type
+            .full_text_index_for_property(&contains_clause.field)
+            .is_none()
+        {
+            return Err(Error::Query(
+                QuerySyntaxError::WhereClauseOnNonIndexedProperty(format!(
+                    "contains clause on field {} requires a full text index",
+                    contains_clause.field
+                )),
+            ));
+        }
+
+        let term = contains_clause
+            .value
+            .as_text()
+            .and_then(full_text_search_term)
+            .ok_or(Error::Query(
+                QuerySyntaxError::InvalidWhereClauseComponents(
+                    "contains clause must search for a single word",
+                ),
+            ))?;
+
+        let left_to_right = match self.order_by.iter().next() {
+            None => true,
+            Some((field, order_clause)) if field == "$id" && self.order_by.len() == 1 => {
+                order_clause.ascending
+            }
+            Some(_) => {
+                return Err(Error::Query(QuerySyntaxError::InvalidOrderByProperties(
+                    "a contains clause can only be ordered by $id",
+                )));
+            }
+        };
+
+        let mut path = contract_document_type_full_text_property_path_vec(
+            self.contract.id_ref().as_bytes(),
+            self.document_type.name(),
+            &contains_clause.field,
+        );
+        path.push(term.into_bytes());
+
+        let mut query = Query::new_with_direction(left_to_right);
+        match starts_at_document {
+            None => query.insert_all(),
+            Some((document, included)) => {
+                let starts_at_key = document.id().to_vec();
+                match (left_to_right, included) {
+                    (true, true) => query.insert_range_from(starts_at_key..),
+                    (true, false) => query.insert_range_after(starts_at_key..),
+                    (false, true) => query.insert_range_to_inclusive(..=starts_at_key),
+                    (false, false) => query.insert_range_to(..starts_at_key),
+                }
+            }
+        }
+
+        Ok(PathQuery::new(
+            path,
+            SizedQuery::new(query, self.limit, self.offset),
+        ))
+    }
+
     #[cfg(any(feature = "server", feature = "verify"))]
     /// Returns a path query for non-primary keys given a document type path and starting document.
     pub fn get_non_primary_key_path_query(
@@ -1920,6 +2024,13 @@ impl<'a> DriveDocumentQuery<'a> {
         starts_at_document: Option<(Document, bool)>,
         platform_version: &PlatformVersion,
     ) -> Result<PathQuery, Error> {
+        // contains clauses are answered by the term trees of full text indexes
+        if let Some(range_clause) = &self.internal_clauses.range_clause {
+            if range_clause.operator == WhereOperator::Contains {
+                return self.get_full_text_path_query(range_clause, starts_at_document);
+            }
+        }
+
         let index = self.find_best_index(platform_version)?;
         let ordered_clauses: Vec<&WhereClause> = index
             .properties
@@ -2260,6 +2371,124 @@ impl<'a> DriveDocumentQuery<'a> {
         Ok((items, skipped, cost))
     }
 
+    #[cfg(feature = "server")]
+    /// Executes a query with no proof, leaving out the documents whose time to live was reached
+    /// at the given block time and height, and returns the items, skipped items, and fee.
+    pub fn execute_raw_results_no_proof_excluding_expired(
+        &self,
+        drive: &Drive,
+        block_time_ms: TimestampMillis,
+        block_height: Option<BlockHeight>,
+        block_info: Option<BlockInfo>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<Vec<u8>>, u16, u64), Error> {
+        let mut drive_operations = vec![];
+        let (items, skipped) = self.execute_raw_results_no_proof_excluding_expired_internal(
+            drive,
+            block_time_ms,
+            block_height,
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )?;
+        let cost = if let Some(block_info) = block_info {
+            let fee_result = Drive::calculate_fee(
+                None,
+                Some(drive_operations),
+                &block_info.epoch,
+                drive.config.epochs_per_era,
+                platform_version,
+                None,
+            )?;
+            fee_result.processing_fee
+        } else {
+            0
+        };
+        Ok((items, skipped, cost))
+    }
+
+    #[cfg(feature = "server")]
+    /// Executes an internal query with no proof, leaving out expired documents, and returns the
+    /// values and skipped items.
+    ///
+    /// Expired documents stay in the state until they are removed at the end of a block, and
+    /// only a limited amount of them is removed per block. They are left out before the limit
+    /// is applied: the query continues after the last fetched document until the limit is
+    /// reached or there are no more documents.
+    pub(crate) fn execute_raw_results_no_proof_excluding_expired_internal(
+        &self,
+        drive: &Drive,
+        block_time_ms: TimestampMillis,
+        block_height: Option<BlockHeight>,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<Vec<u8>>, u16), Error> {
+        let Some(time_to_live) = self.document_type.documents_time_to_live() else {
+            return self.execute_raw_results_no_proof_internal(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            );
+        };
+
+        let mut query = self.clone();
+        let mut items = vec![];
+        let mut skipped: u16 = 0;
+
+        loop {
+            let (page, page_skipped) = query.execute_raw_results_no_proof_internal(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            )?;
+            skipped = skipped.saturating_add(page_skipped);
+
+            let page_len = page.len();
+            let mut last_document_id = None;
+
+            for serialized in page {
+                let document = Document::from_bytes(
+                    serialized.as_slice(),
+                    self.document_type,
+                    platform_version,
+                )?;
+                last_document_id = Some(document.id().to_buffer());
+
+                let expired = time_to_live.is_expired_at_time(&document, block_time_ms)
+                    || block_height.is_some_and(|block_height| {
+                        time_to_live.is_expired_at_height(&document, block_height)
+                    });
+
+                if !expired {
+                    items.push(serialized);
+                }
+            }
+
+            // Without a limit or with a page that is not full there is nothing left to fetch
+            let (Some(limit), Some(page_limit), Some(last_document_id)) =
+                (self.limit, query.limit, last_document_id)
+            else {
+                break;
+            };
+
+            if page_len < page_limit as usize || items.len() >= limit as usize {
+                break;
+            }
+
+            query.limit = Some(limit - items.len() as u16);
+            query.start_at = Some(last_document_id);
+            query.start_at_included = false;
+            // The offset was already applied to the first page
+            query.offset = None;
+        }
+
+        Ok((items, skipped))
+    }
+
     #[cfg(feature = "server")]
     /// Executes an internal query with no proof and returns the values and skipped items.
     pub(crate) fn execute_raw_results_no_proof_internal(
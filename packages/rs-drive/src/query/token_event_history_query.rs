@@ -0,0 +1,794 @@
+use crate::error::query::QuerySyntaxError;
+use crate::error::Error;
+use crate::query::{DriveDocumentQuery, InternalClauses, OrderClause, WhereClause, WhereOperator};
+use bincode::{Decode, Encode};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::identifier::Identifier;
+use dpp::platform_value::Value;
+use dpp::prelude::{BlockHeight, DataContract, TimestampMillis};
+use indexmap::IndexMap;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(feature = "server")]
+use crate::drive::document::query::QueryDocumentsOutcomeV0Methods;
+#[cfg(feature = "server")]
+use crate::drive::Drive;
+#[cfg(any(feature = "server", feature = "verify"))]
+use crate::error::drive::DriveError;
+#[cfg(feature = "verify")]
+use crate::error::proof::ProofError;
+#[cfg(feature = "verify")]
+use crate::verify::RootHash;
+#[cfg(feature = "server")]
+use grovedb::TransactionArg;
+#[cfg(any(feature = "server", feature = "verify"))]
+use platform_version::version::PlatformVersion;
+
+/// The kind of token event, each one is kept in its own document type of the token history
+/// contract
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Encode, Decode)]
+pub enum TokenEventHistoryType {
+    /// Tokens were minted
+    Mint,
+    /// Tokens were burned
+    Burn,
+    /// An identity was frozen
+    Freeze,
+    /// An identity was unfrozen
+    Unfreeze,
+    /// Funds of a frozen identity were destroyed
+    DestroyFrozenFunds,
+    /// Tokens were transferred
+    Transfer,
+    /// Distributed tokens were claimed
+    Claim,
+    /// The token was paused or resumed
+    EmergencyAction,
+    /// The token configuration was updated
+    ConfigUpdate,
+    /// The direct purchase price was changed
+    DirectPricing,
+    /// Tokens were bought at the direct purchase price
+    DirectPurchase,
+}
+
+impl TokenEventHistoryType {
+    /// Every kind of token event, events of different kinds recorded at the same position are
+    /// returned in this order
+    pub const ALL: [TokenEventHistoryType; 11] = [
+        TokenEventHistoryType::Mint,
+        TokenEventHistoryType::Burn,
+        TokenEventHistoryType::Freeze,
+        TokenEventHistoryType::Unfreeze,
+        TokenEventHistoryType::DestroyFrozenFunds,
+        TokenEventHistoryType::Transfer,
+        TokenEventHistoryType::Claim,
+        TokenEventHistoryType::EmergencyAction,
+        TokenEventHistoryType::ConfigUpdate,
+        TokenEventHistoryType::DirectPricing,
+        TokenEventHistoryType::DirectPurchase,
+    ];
+
+    /// The name of the document type holding this kind of event in the token history contract
+    pub fn document_type_name(&self) -> &'static str {
+        match self {
+            TokenEventHistoryType::Mint => "mint",
+            TokenEventHistoryType::Burn => "burn",
+            TokenEventHistoryType::Freeze => "freeze",
+            TokenEventHistoryType::Unfreeze => "unfreeze",
+            TokenEventHistoryType::DestroyFrozenFunds => "destroyFrozenFunds",
+            TokenEventHistoryType::Transfer => "transfer",
+            TokenEventHistoryType::Claim => "claim",
+            TokenEventHistoryType::EmergencyAction => "emergencyAction",
+            TokenEventHistoryType::ConfigUpdate => "configUpdate",
+            TokenEventHistoryType::DirectPricing => "directPricing",
+            TokenEventHistoryType::DirectPurchase => "directPurchase",
+        }
+    }
+
+    /// The document fields holding an identity involved in this kind of event besides the
+    /// identity that performed it
+    fn involved_identity_fields(&self) -> &'static [&'static str] {
+        match self {
+            TokenEventHistoryType::Mint | TokenEventHistoryType::Claim => &["recipientId"],
+            TokenEventHistoryType::Burn => &["burnFromId"],
+            TokenEventHistoryType::Transfer => &["toIdentityId"],
+            TokenEventHistoryType::Freeze
+            | TokenEventHistoryType::Unfreeze
+            | TokenEventHistoryType::DestroyFrozenFunds => &["frozenIdentityId"],
+            TokenEventHistoryType::EmergencyAction
+            | TokenEventHistoryType::ConfigUpdate
+            | TokenEventHistoryType::DirectPricing
+            | TokenEventHistoryType::DirectPurchase => &[],
+        }
+    }
+}
+
+/// Restricts the token events returned by a [`TokenEventHistoryDriveQuery`]
+#[derive(Debug, PartialEq, Eq, Clone, Encode, Decode)]
+pub enum TokenEventHistoryFilter {
+    /// Only events performed by this identity or involving it, such as transfers it received
+    ByIdentity(Identifier),
+    /// Only events recorded in a block with a height in this range, the start is inclusive and
+    /// the end exclusive
+    ByBlockHeightRange {
+        /// Start of the range
+        start_height: BlockHeight,
+        /// End of the range
+        end_height: BlockHeight,
+    },
+}
+
+/// The last event of the previous page of a [`TokenEventHistoryDriveQuery`]
+#[derive(Debug, PartialEq, Eq, Clone, Encode, Decode)]
+pub struct TokenEventHistoryStartAfter {
+    /// the kind of the event
+    pub event_type: TokenEventHistoryType,
+    /// the id of the history document holding the event
+    pub document_id: Identifier,
+    /// the time of the block the event was recorded in
+    pub created_at: TimestampMillis,
+    /// the height of the block the event was recorded in
+    pub created_at_block_height: BlockHeight,
+}
+
+/// Token event history drive query struct
+///
+/// Events are ordered by the height of the block they were recorded in when filtering by a
+/// block height range and by the block time otherwise. Events recorded at the same position are
+/// ordered by their type, following [`TokenEventHistoryType::ALL`], and then by document id.
+///
+/// Each event type, and each identity field when filtering by identity, is queried separately,
+/// every one of these document queries is limited to the query limit and their results are
+/// merged.
+#[derive(Debug, PartialEq, Clone, Encode, Decode)]
+pub struct TokenEventHistoryDriveQuery {
+    /// the token id
+    pub token_id: Identifier,
+    /// the kind of events to return, events of every kind are returned when not set
+    pub event_type: Option<TokenEventHistoryType>,
+    /// an optional restriction of the returned events
+    pub filter: Option<TokenEventHistoryFilter>,
+    /// only return events ordered after this one
+    pub start_after: Option<TokenEventHistoryStartAfter>,
+    /// the maximum number of events to return
+    pub limit: u16,
+    /// whether the oldest events come first
+    pub order_ascending: bool,
+}
+
+impl TokenEventHistoryDriveQuery {
+    /// Whether events are ordered by block height rather than by block time
+    fn orders_by_block_height(&self) -> bool {
+        matches!(
+            self.filter,
+            Some(TokenEventHistoryFilter::ByBlockHeightRange { .. })
+        )
+    }
+
+    /// The field events are ordered by
+    fn order_field(&self) -> &'static str {
+        if self.orders_by_block_height() {
+            "$createdAtBlockHeight"
+        } else {
+            "$createdAt"
+        }
+    }
+
+    /// The event types that are queried
+    fn event_types(&self) -> Vec<TokenEventHistoryType> {
+        match self.event_type {
+            Some(event_type) => vec![event_type],
+            None => TokenEventHistoryType::ALL.to_vec(),
+        }
+    }
+
+    /// The position of an event in the order of the query
+    fn event_key(
+        &self,
+        event_type: TokenEventHistoryType,
+        document: &Document,
+    ) -> (u64, TokenEventHistoryType, Identifier) {
+        let position = if self.orders_by_block_height() {
+            document.created_at_block_height()
+        } else {
+            document.created_at()
+        };
+        (position.unwrap_or_default(), event_type, document.id())
+    }
+
+    /// The half open range of positions an event type is queried over, `None` when no events
+    /// of this type can be returned
+    fn position_range(
+        &self,
+        event_type: TokenEventHistoryType,
+    ) -> Option<(Option<u64>, Option<u64>)> {
+        let (mut lower, mut upper) = match &self.filter {
+            Some(TokenEventHistoryFilter::ByBlockHeightRange {
+                start_height,
+                end_height,
+            }) => (Some(*start_height), Some(*end_height)),
+            _ => (None, None),
+        };
+
+        // Events of the cursor type continue from the cursor document itself, events of other
+        // types recorded at the cursor position are only left when their type is ordered after
+        // the cursor type
+        if let Some(start_after) = &self.start_after {
+            if start_after.event_type != event_type {
+                let position = if self.orders_by_block_height() {
+                    start_after.created_at_block_height
+                } else {
+                    start_after.created_at
+                };
+                let includes_position =
+                    (event_type > start_after.event_type) == self.order_ascending;
+                if self.order_ascending {
+                    let start = if includes_position {
+                        position
+                    } else {
+                        position.checked_add(1)?
+                    };
+                    lower = Some(lower.map_or(start, |lower| lower.max(start)));
+                } else {
+                    let end = if includes_position {
+                        position.saturating_add(1)
+                    } else {
+                        position
+                    };
+                    upper = Some(upper.map_or(end, |upper| upper.min(end)));
+                }
+            }
+        }
+
+        match (lower, upper) {
+            (Some(lower), Some(upper)) if lower >= upper => None,
+            (None, Some(0)) => None,
+            range => Some(range),
+        }
+    }
+
+    /// Builds the document queries over the token history contract, in the order their results
+    /// and proofs are given.
+    pub fn document_queries<'a>(
+        &self,
+        token_history_contract: &'a DataContract,
+    ) -> Result<Vec<(TokenEventHistoryType, DriveDocumentQuery<'a>)>, Error> {
+        if let Some(TokenEventHistoryFilter::ByBlockHeightRange {
+            start_height,
+            end_height,
+        }) = &self.filter
+        {
+            if start_height >= end_height {
+                return Err(Error::Query(QuerySyntaxError::InvalidBetweenClause(
+                    "block height range start must be before its end",
+                )));
+            }
+        }
+
+        if let (Some(event_type), Some(start_after)) = (self.event_type, &self.start_after) {
+            if start_after.event_type != event_type {
+                return Err(Error::Query(QuerySyntaxError::InvalidStartAfter(
+                    "start after event must be of the requested event type".to_string(),
+                )));
+            }
+        }
+
+        let order_field = self.order_field();
+
+        let mut queries = vec![];
+
+        for event_type in self.event_types() {
+            let Some((lower, upper)) = self.position_range(event_type) else {
+                continue;
+            };
+
+            let document_type = token_history_contract
+                .document_type_for_name(event_type.document_type_name())
+                .map_err(|_| {
+                    Error::Query(QuerySyntaxError::DocumentTypeNotFound(
+                        "token history contract is missing the event document type",
+                    ))
+                })?;
+
+            let range_clause = match (lower, upper) {
+                (Some(lower), Some(upper)) => Some(WhereClause {
+                    field: order_field.to_string(),
+                    operator: WhereOperator::BetweenExcludeRight,
+                    value: Value::Array(vec![Value::U64(lower), Value::U64(upper)]),
+                }),
+                (Some(lower), None) => Some(WhereClause {
+                    field: order_field.to_string(),
+                    operator: WhereOperator::GreaterThanOrEquals,
+                    value: Value::U64(lower),
+                }),
+                (None, Some(upper)) => Some(WhereClause {
+                    field: order_field.to_string(),
+                    operator: WhereOperator::LessThan,
+                    value: Value::U64(upper),
+                }),
+                (None, None) => None,
+            };
+
+            let identity_fields = match &self.filter {
+                Some(TokenEventHistoryFilter::ByIdentity(identity_id)) => {
+                    std::iter::once("$ownerId")
+                        .chain(event_type.involved_identity_fields().iter().copied())
+                        .map(|field| Some((field, *identity_id)))
+                        .collect()
+                }
+                _ => vec![None],
+            };
+
+            for identity_field in identity_fields {
+                let mut equal_clauses = BTreeMap::from([(
+                    "tokenId".to_string(),
+                    WhereClause {
+                        field: "tokenId".to_string(),
+                        operator: WhereOperator::Equal,
+                        value: Value::Identifier(self.token_id.to_buffer()),
+                    },
+                )]);
+
+                if let Some((field, identity_id)) = identity_field {
+                    equal_clauses.insert(
+                        field.to_string(),
+                        WhereClause {
+                            field: field.to_string(),
+                            operator: WhereOperator::Equal,
+                            value: Value::Identifier(identity_id.to_buffer()),
+                        },
+                    );
+                }
+
+                let start_at = self
+                    .start_after
+                    .as_ref()
+                    .filter(|start_after| start_after.event_type == event_type)
+                    .map(|start_after| start_after.document_id.to_buffer());
+
+                queries.push((
+                    event_type,
+                    DriveDocumentQuery {
+                        contract: token_history_contract,
+                        document_type,
+                        internal_clauses: InternalClauses {
+                            primary_key_in_clause: None,
+                            primary_key_equal_clause: None,
+                            in_clause: None,
+                            range_clause: range_clause.clone(),
+                            equal_clauses,
+                        },
+                        offset: None,
+                        limit: Some(self.limit),
+                        order_by: IndexMap::from([(
+                            order_field.to_string(),
+                            OrderClause {
+                                field: order_field.to_string(),
+                                ascending: self.order_ascending,
+                            },
+                        )]),
+                        start_at,
+                        start_at_included: false,
+                        block_time_ms: None,
+                    },
+                ));
+            }
+        }
+
+        Ok(queries)
+    }
+
+    /// Merges the documents returned by each document query into the events of the page.
+    ///
+    /// Every document query returns the first events of its own part of the history, so the
+    /// first `limit` events of their union are the first `limit` events of the whole history.
+    pub fn merge_results(
+        &self,
+        results: Vec<(TokenEventHistoryType, Vec<Document>)>,
+    ) -> Vec<(TokenEventHistoryType, Document)> {
+        let mut seen = BTreeSet::new();
+        let mut events: Vec<_> = results
+            .into_iter()
+            .flat_map(|(event_type, documents)| {
+                documents
+                    .into_iter()
+                    .map(move |document| (event_type, document))
+            })
+            .filter(|(event_type, document)| seen.insert((*event_type, document.id())))
+            .map(|(event_type, document)| (self.event_key(event_type, &document), document))
+            .collect();
+
+        events.sort_by(|(a, _), (b, _)| {
+            if self.order_ascending {
+                a.cmp(b)
+            } else {
+                b.cmp(a)
+            }
+        });
+
+        events
+            .into_iter()
+            .take(self.limit as usize)
+            .map(|((_, event_type, _), document)| (event_type, document))
+            .collect()
+    }
+
+    #[cfg(feature = "server")]
+    /// Executes the query and returns the events of the page.
+    pub fn execute_no_proof(
+        &self,
+        drive: &Drive,
+        token_history_contract: &DataContract,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<(TokenEventHistoryType, Document)>, Error> {
+        let results = self
+            .document_queries(token_history_contract)?
+            .into_iter()
+            .map(|(event_type, document_query)| {
+                drive
+                    .query_documents(
+                        document_query,
+                        None,
+                        false,
+                        transaction,
+                        Some(platform_version.protocol_version),
+                    )
+                    .map(|outcome| (event_type, outcome.documents_owned()))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(self.merge_results(results))
+    }
+
+    #[cfg(feature = "server")]
+    /// Executes the query and returns the proofs of every document query, encoded together in
+    /// the order of [`Self::document_queries`].
+    pub fn execute_with_proof(
+        &self,
+        drive: &Drive,
+        token_history_contract: &DataContract,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        // Queries with a limit can't be merged into a single path query
+        let proofs = self
+            .document_queries(token_history_contract)?
+            .into_iter()
+            .map(|(_, document_query)| {
+                document_query
+                    .execute_with_proof(drive, None, transaction, platform_version)
+                    .map(|(proof, _)| proof)
+            })
+            .collect::<Result<Vec<Vec<u8>>, Error>>()?;
+
+        bincode::encode_to_vec(proofs, bincode::config::standard()).map_err(|e| {
+            Error::Drive(DriveError::CorruptedSerialization(format!(
+                "can not encode token event history proofs: {}",
+                e
+            )))
+        })
+    }
+
+    #[cfg(feature = "verify")]
+    /// Verifies the proofs returned by [`Self::execute_with_proof`] and returns the root hash
+    /// they all lead to together with the events of the page.
+    pub fn verify_proof(
+        &self,
+        token_history_contract: &DataContract,
+        proof: &[u8],
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<(TokenEventHistoryType, Document)>), Error> {
+        let (proofs, _): (Vec<Vec<u8>>, usize) =
+            bincode::decode_from_slice(proof, bincode::config::standard()).map_err(|e| {
+                Error::Drive(DriveError::CorruptedSerialization(format!(
+                    "token event history proofs are corrupted: {}",
+                    e
+                )))
+            })?;
+
+        let document_queries = self.document_queries(token_history_contract)?;
+
+        if proofs.len() != document_queries.len() {
+            return Err(Error::Proof(ProofError::CorruptedProof(format!(
+                "expected {} token event history proofs, got {}",
+                document_queries.len(),
+                proofs.len()
+            ))));
+        }
+
+        let mut root_hash = None;
+        let mut results = Vec::with_capacity(proofs.len());
+
+        for ((event_type, document_query), proof) in document_queries.into_iter().zip(proofs) {
+            let (proof_root_hash, documents) =
+                document_query.verify_proof(&proof, platform_version)?;
+            if root_hash.is_some_and(|root_hash| root_hash != proof_root_hash) {
+                return Err(Error::Proof(ProofError::CorruptedProof(
+                    "token event history proofs lead to different root hashes".to_string(),
+                )));
+            }
+            root_hash = Some(proof_root_hash);
+            results.push((event_type, documents));
+        }
+
+        let root_hash = root_hash.ok_or(Error::Proof(ProofError::CorruptedProof(
+            "token event history query has no document queries to prove".to_string(),
+        )))?;
+
+        Ok((root_hash, self.merge_results(results)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::document::DocumentV0;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use dpp::version::PlatformVersion;
+
+    fn query(
+        event_type: Option<TokenEventHistoryType>,
+        filter: Option<TokenEventHistoryFilter>,
+        start_after: Option<TokenEventHistoryStartAfter>,
+        limit: u16,
+        order_ascending: bool,
+    ) -> TokenEventHistoryDriveQuery {
+        TokenEventHistoryDriveQuery {
+            token_id: Identifier::new([1; 32]),
+            event_type,
+            filter,
+            start_after,
+            limit,
+            order_ascending,
+        }
+    }
+
+    fn document(id: u8, height: BlockHeight) -> Document {
+        DocumentV0 {
+            id: Identifier::new([id; 32]),
+            created_at: Some(height * 1000),
+            created_at_block_height: Some(height),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    fn token_history_contract() -> DataContract {
+        load_system_data_contract(SystemDataContract::TokenHistory, PlatformVersion::latest())
+            .expect("expected to load the token history contract")
+    }
+
+    #[test]
+    fn should_query_every_event_type_when_none_is_given() {
+        let contract = token_history_contract();
+
+        let queries = query(None, None, None, 10, true)
+            .document_queries(&contract)
+            .expect("expected document queries");
+
+        let event_types: Vec<_> = queries.iter().map(|(event_type, _)| *event_type).collect();
+        assert_eq!(event_types, TokenEventHistoryType::ALL.to_vec());
+    }
+
+    #[test]
+    fn should_query_received_events_when_filtering_by_identity() {
+        let contract = token_history_contract();
+        let identity_id = Identifier::new([2; 32]);
+
+        let queries = query(
+            Some(TokenEventHistoryType::Transfer),
+            Some(TokenEventHistoryFilter::ByIdentity(identity_id)),
+            None,
+            10,
+            true,
+        )
+        .document_queries(&contract)
+        .expect("expected document queries");
+
+        let fields: Vec<_> = queries
+            .iter()
+            .map(|(_, document_query)| {
+                document_query
+                    .internal_clauses
+                    .equal_clauses
+                    .keys()
+                    .find(|field| field.as_str() != "tokenId")
+                    .cloned()
+                    .expect("expected an identity clause")
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            vec!["$ownerId".to_string(), "toIdentityId".to_string()]
+        );
+    }
+
+    #[test]
+    fn should_query_by_block_height_range() {
+        let contract = token_history_contract();
+
+        let queries = query(
+            Some(TokenEventHistoryType::Mint),
+            Some(TokenEventHistoryFilter::ByBlockHeightRange {
+                start_height: 5,
+                end_height: 9,
+            }),
+            None,
+            10,
+            true,
+        )
+        .document_queries(&contract)
+        .expect("expected document queries");
+
+        assert_eq!(queries.len(), 1);
+        let range_clause = queries[0]
+            .1
+            .internal_clauses
+            .range_clause
+            .clone()
+            .expect("expected a range clause");
+        assert_eq!(range_clause.field, "$createdAtBlockHeight");
+        assert_eq!(range_clause.operator, WhereOperator::BetweenExcludeRight);
+        assert_eq!(
+            range_clause.value,
+            Value::Array(vec![Value::U64(5), Value::U64(9)])
+        );
+    }
+
+    #[test]
+    fn should_reject_an_empty_block_height_range() {
+        let contract = token_history_contract();
+
+        let result = query(
+            None,
+            Some(TokenEventHistoryFilter::ByBlockHeightRange {
+                start_height: 9,
+                end_height: 9,
+            }),
+            None,
+            10,
+            true,
+        )
+        .document_queries(&contract);
+
+        assert!(matches!(
+            result,
+            Err(Error::Query(QuerySyntaxError::InvalidBetweenClause(_)))
+        ));
+    }
+
+    #[test]
+    fn should_continue_other_event_types_from_the_cursor_position() {
+        let contract = token_history_contract();
+
+        let queries = query(
+            None,
+            Some(TokenEventHistoryFilter::ByBlockHeightRange {
+                start_height: 5,
+                end_height: 9,
+            }),
+            Some(TokenEventHistoryStartAfter {
+                event_type: TokenEventHistoryType::Transfer,
+                document_id: Identifier::new([3; 32]),
+                created_at: 8000,
+                created_at_block_height: 8,
+            }),
+            10,
+            true,
+        )
+        .document_queries(&contract)
+        .expect("expected document queries");
+
+        // Types ordered before transfers have no events left at height 8
+        let event_types: Vec<_> = queries.iter().map(|(event_type, _)| *event_type).collect();
+        assert_eq!(
+            event_types,
+            TokenEventHistoryType::ALL[TokenEventHistoryType::ALL
+                .iter()
+                .position(|event_type| *event_type == TokenEventHistoryType::Transfer)
+                .unwrap()..]
+                .to_vec()
+        );
+
+        let (_, transfer_query) = &queries[0];
+        assert_eq!(transfer_query.start_at, Some([3; 32]));
+        assert!(!transfer_query.start_at_included);
+
+        let (_, claim_query) = &queries[1];
+        assert_eq!(claim_query.start_at, None);
+        assert_eq!(
+            claim_query
+                .internal_clauses
+                .range_clause
+                .as_ref()
+                .map(|clause| clause.value.clone()),
+            Some(Value::Array(vec![Value::U64(8), Value::U64(9)]))
+        );
+    }
+
+    #[test]
+    fn should_reject_a_cursor_of_another_event_type() {
+        let contract = token_history_contract();
+
+        let result = query(
+            Some(TokenEventHistoryType::Mint),
+            None,
+            Some(TokenEventHistoryStartAfter {
+                event_type: TokenEventHistoryType::Burn,
+                document_id: Identifier::new([3; 32]),
+                created_at: 8000,
+                created_at_block_height: 8,
+            }),
+            10,
+            true,
+        )
+        .document_queries(&contract);
+
+        assert!(matches!(
+            result,
+            Err(Error::Query(QuerySyntaxError::InvalidStartAfter(_)))
+        ));
+    }
+
+    #[test]
+    fn should_merge_results_by_position_type_and_id() {
+        let history_query = query(None, None, None, 3, true);
+
+        let events = history_query.merge_results(vec![
+            (
+                TokenEventHistoryType::Mint,
+                vec![document(1, 2), document(2, 4)],
+            ),
+            (
+                TokenEventHistoryType::Transfer,
+                vec![document(3, 1), document(4, 2)],
+            ),
+            // The same transfer returned by a second identity field is only kept once
+            (TokenEventHistoryType::Transfer, vec![document(3, 1)]),
+        ]);
+
+        let ids: Vec<_> = events
+            .iter()
+            .map(|(event_type, document)| (*event_type, document.id()))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                (TokenEventHistoryType::Transfer, Identifier::new([3; 32])),
+                (TokenEventHistoryType::Mint, Identifier::new([1; 32])),
+                (TokenEventHistoryType::Transfer, Identifier::new([4; 32])),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_merge_results_in_descending_order() {
+        let history_query = query(None, None, None, 2, false);
+
+        let events = history_query.merge_results(vec![
+            (
+                TokenEventHistoryType::Mint,
+                vec![document(2, 4), document(1, 2)],
+            ),
+            (
+                TokenEventHistoryType::Transfer,
+                vec![document(4, 2), document(3, 1)],
+            ),
+        ]);
+
+        let ids: Vec<_> = events
+            .iter()
+            .map(|(event_type, document)| (*event_type, document.id()))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                (TokenEventHistoryType::Mint, Identifier::new([2; 32])),
+                (TokenEventHistoryType::Transfer, Identifier::new([4; 32])),
+            ]
+        );
+    }
+}
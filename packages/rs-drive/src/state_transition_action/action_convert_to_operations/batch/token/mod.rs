@@ -1,12 +1,18 @@
+mod token_approve_transition;
 mod token_burn_transition;
+mod token_cancel_order_transition;
 mod token_claim_transition;
+mod token_claim_vested_transition;
 mod token_config_update_transition;
 mod token_destroy_frozen_funds_transition;
 mod token_direct_purchase_transition;
 mod token_emergency_action_transition;
 mod token_freeze_transition;
 mod token_mint_transition;
+mod token_place_order_transition;
 mod token_set_price_for_direct_purchase_transition;
+mod token_transfer_from_transition;
 mod token_transfer_transition;
 mod token_transition;
 mod token_unfreeze_transition;
+mod token_vesting_transfer_transition;
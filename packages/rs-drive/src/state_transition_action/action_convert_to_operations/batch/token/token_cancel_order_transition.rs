@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+use dpp::block::epoch::Epoch;
+use dpp::identifier::Identifier;
+use dpp::tokens::order_book::{TokenOrder, TokenOrderSide};
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+use dpp::ProtocolError;
+use platform_version::version::PlatformVersion;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::state_transition_action::action_convert_to_operations::batch::DriveHighLevelBatchOperationConverter;
+use crate::state_transition_action::action_convert_to_operations::batch::token::token_place_order_transition::{credit_operations_for_balance_changes, sum_token_amount};
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionActionAccessorsV0;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_cancel_order_transition_action::{TokenCancelOrderTransitionAction, TokenCancelOrderTransitionActionAccessorsV0};
+use crate::util::batch::{DriveOperation, IdentityOperationType};
+use crate::util::batch::drive_op_batch::TokenOperationType;
+use crate::util::batch::DriveOperation::{IdentityOperation, TokenOperation};
+
+impl DriveHighLevelBatchOperationConverter for TokenCancelOrderTransitionAction {
+    fn into_high_level_batch_drive_operations<'b>(
+        self,
+        _epoch: &Epoch,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'b>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_transitions
+            .convert_to_high_level_operations
+            .token_cancel_order_transition
+        {
+            0 => {
+                let data_contract_id = self.base().data_contract_id();
+
+                let identity_contract_nonce = self.base().identity_contract_nonce();
+
+                let mut ops = vec![IdentityOperation(
+                    IdentityOperationType::UpdateIdentityContractNonce {
+                        identity_id: owner_id.into_buffer(),
+                        contract_id: data_contract_id.into_buffer(),
+                        nonce: identity_contract_nonce,
+                    },
+                )];
+
+                let order = self.order();
+                let escrow_id = TokenOrder::escrow_identity_id(&order.token_id());
+
+                // The escrow of what is left of the order is given back to its owner
+                let (token_id, amount) = match order.side() {
+                    TokenOrderSide::Sell => (Some(order.token_id()), order.remaining_amount()),
+                    TokenOrderSide::Buy => (
+                        order.quote_currency().token_id(),
+                        order
+                            .remaining_amount()
+                            .checked_mul(order.price_per_token())
+                            .ok_or(ProtocolError::Overflow("token order escrow overflow"))?,
+                    ),
+                };
+
+                if amount > 0 {
+                    let amount = sum_token_amount(amount)?;
+                    let balance_changes =
+                        BTreeMap::from([(escrow_id, -amount), (order.owner_id(), amount)]);
+                    match token_id {
+                        Some(token_id) => {
+                            ops.push(TokenOperation(
+                                TokenOperationType::TokenApplyBalanceChanges {
+                                    token_id,
+                                    balance_changes,
+                                },
+                            ));
+                        }
+                        None => {
+                            ops.extend(credit_operations_for_balance_changes(
+                                escrow_id,
+                                balance_changes,
+                            )?);
+                        }
+                    }
+                }
+
+                ops.push(TokenOperation(TokenOperationType::TokenRemoveOrder {
+                    order: order.clone(),
+                }));
+
+                Ok(ops)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method:
+                    "TokenCancelOrderTransitionAction::into_high_level_document_drive_operations"
+                        .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use dpp::block::epoch::Epoch;
+use dpp::identifier::Identifier;
+use dpp::tokens::vesting::TokenVesting;
+use platform_version::version::PlatformVersion;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::state_transition_action::action_convert_to_operations::batch::DriveHighLevelBatchOperationConverter;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionActionAccessorsV0;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_claim_vested_transition_action::{TokenClaimVestedTransitionAction, TokenClaimVestedTransitionActionAccessorsV0};
+use crate::util::batch::{DriveOperation, IdentityOperationType};
+use crate::util::batch::drive_op_batch::TokenOperationType;
+use crate::util::batch::DriveOperation::{IdentityOperation, TokenOperation};
+
+impl DriveHighLevelBatchOperationConverter for TokenClaimVestedTransitionAction {
+    fn into_high_level_batch_drive_operations<'b>(
+        self,
+        _epoch: &Epoch,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'b>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_transitions
+            .convert_to_high_level_operations
+            .token_claim_vested_transition
+        {
+            0 => {
+                let data_contract_id = self.base().data_contract_id();
+
+                let identity_contract_nonce = self.base().identity_contract_nonce();
+
+                let token_id = self.token_id();
+
+                let mut ops = vec![IdentityOperation(
+                    IdentityOperationType::UpdateIdentityContractNonce {
+                        identity_id: owner_id.into_buffer(),
+                        contract_id: data_contract_id.into_buffer(),
+                        nonce: identity_contract_nonce,
+                    },
+                )];
+
+                ops.push(TokenOperation(TokenOperationType::TokenTransfer {
+                    token_id,
+                    sender_id: TokenVesting::escrow_identity_id(&token_id),
+                    recipient_id: owner_id,
+                    amount: self.amount(),
+                }));
+
+                let vesting = self.claimed_vesting();
+
+                // A vesting is kept until every locked token was claimed
+                if vesting.is_fully_claimed() {
+                    ops.push(TokenOperation(TokenOperationType::TokenRemoveVesting {
+                        vesting,
+                    }));
+                } else {
+                    ops.push(TokenOperation(TokenOperationType::TokenUpdateVesting {
+                        vesting,
+                    }));
+                }
+
+                Ok(ops)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method:
+                    "TokenClaimVestedTransitionAction::into_high_level_document_drive_operations"
+                        .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
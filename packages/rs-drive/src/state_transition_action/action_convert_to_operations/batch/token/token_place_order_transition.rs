@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+use dpp::balances::credits::SumTokenAmount;
+use dpp::block::epoch::Epoch;
+use dpp::identifier::Identifier;
+use dpp::tokens::order_book::{TokenOrder, TokenOrderSide};
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+use dpp::ProtocolError;
+use platform_version::version::PlatformVersion;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::state_transition_action::action_convert_to_operations::batch::DriveHighLevelBatchOperationConverter;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionActionAccessorsV0;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_place_order_transition_action::{TokenPlaceOrderTransitionAction, TokenPlaceOrderTransitionActionAccessorsV0};
+use crate::util::batch::{DriveOperation, IdentityOperationType, SystemOperationType};
+use crate::util::batch::drive_op_batch::TokenOperationType;
+use crate::util::batch::DriveOperation::{IdentityOperation, SystemOperation, TokenOperation};
+
+/// Converts an order amount into a signed balance change
+pub(super) fn sum_token_amount(amount: u64) -> Result<SumTokenAmount, Error> {
+    SumTokenAmount::try_from(amount)
+        .map_err(|_| ProtocolError::Overflow("token order amount overflow").into())
+}
+
+/// Adds a change to the net balance change of an identity
+fn add_balance_change(
+    balance_changes: &mut BTreeMap<Identifier, SumTokenAmount>,
+    identity_id: Identifier,
+    change: SumTokenAmount,
+) -> Result<(), Error> {
+    let balance_change = balance_changes.entry(identity_id).or_default();
+    *balance_change = balance_change
+        .checked_add(change)
+        .ok_or(ProtocolError::Overflow(
+            "token order balance change overflow",
+        ))?;
+    Ok(())
+}
+
+impl DriveHighLevelBatchOperationConverter for TokenPlaceOrderTransitionAction {
+    fn into_high_level_batch_drive_operations<'b>(
+        self,
+        _epoch: &Epoch,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'b>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_transitions
+            .convert_to_high_level_operations
+            .token_place_order_transition
+        {
+            0 => {
+                let data_contract_id = self.base().data_contract_id();
+
+                let identity_contract_nonce = self.base().identity_contract_nonce();
+
+                let mut ops = vec![IdentityOperation(
+                    IdentityOperationType::UpdateIdentityContractNonce {
+                        identity_id: owner_id.into_buffer(),
+                        contract_id: data_contract_id.into_buffer(),
+                        nonce: identity_contract_nonce,
+                    },
+                )];
+
+                let token_id = self.token_id();
+                let order = self.order();
+                let escrow_id = TokenOrder::escrow_identity_id(&token_id);
+
+                // Every identity can appear in several fills, all balances are therefore
+                // changed once by their net amount
+                let mut base_changes = BTreeMap::new();
+                let mut quote_changes = BTreeMap::new();
+
+                for fill in self.fills() {
+                    let amount = sum_token_amount(fill.amount)?;
+                    let quote_amount = sum_token_amount(fill.quote_amount()?)?;
+                    let maker_id = fill.maker_order.owner_id();
+                    match order.side() {
+                        TokenOrderSide::Sell => {
+                            add_balance_change(&mut base_changes, owner_id, -amount)?;
+                            add_balance_change(&mut base_changes, maker_id, amount)?;
+                            add_balance_change(&mut quote_changes, escrow_id, -quote_amount)?;
+                            add_balance_change(&mut quote_changes, owner_id, quote_amount)?;
+                        }
+                        TokenOrderSide::Buy => {
+                            add_balance_change(&mut base_changes, escrow_id, -amount)?;
+                            add_balance_change(&mut base_changes, owner_id, amount)?;
+                            add_balance_change(&mut quote_changes, owner_id, -quote_amount)?;
+                            add_balance_change(&mut quote_changes, maker_id, quote_amount)?;
+                        }
+                    }
+                }
+
+                // What is left of the order rests on the book and is escrowed
+                if order.remaining_amount() > 0 {
+                    match order.side() {
+                        TokenOrderSide::Sell => {
+                            let amount = sum_token_amount(order.remaining_amount())?;
+                            add_balance_change(&mut base_changes, owner_id, -amount)?;
+                            add_balance_change(&mut base_changes, escrow_id, amount)?;
+                        }
+                        TokenOrderSide::Buy => {
+                            let escrow_amount = sum_token_amount(
+                                order
+                                    .remaining_amount()
+                                    .checked_mul(order.price_per_token())
+                                    .ok_or(ProtocolError::Overflow(
+                                        "token order escrow overflow",
+                                    ))?,
+                            )?;
+                            add_balance_change(&mut quote_changes, owner_id, -escrow_amount)?;
+                            add_balance_change(&mut quote_changes, escrow_id, escrow_amount)?;
+                        }
+                    }
+                }
+
+                base_changes.retain(|_, change| *change != 0);
+                quote_changes.retain(|_, change| *change != 0);
+
+                if !base_changes.is_empty() {
+                    ops.push(TokenOperation(
+                        TokenOperationType::TokenApplyBalanceChanges {
+                            token_id,
+                            balance_changes: base_changes,
+                        },
+                    ));
+                }
+
+                match order.quote_currency().token_id() {
+                    Some(quote_token_id) => {
+                        if !quote_changes.is_empty() {
+                            ops.push(TokenOperation(
+                                TokenOperationType::TokenApplyBalanceChanges {
+                                    token_id: quote_token_id,
+                                    balance_changes: quote_changes,
+                                },
+                            ));
+                        }
+                    }
+                    None => {
+                        ops.extend(credit_operations_for_balance_changes(
+                            escrow_id,
+                            quote_changes,
+                        )?);
+                    }
+                }
+
+                for fill in self.fills() {
+                    let mut maker_order = fill.maker_order.clone();
+                    let remaining_amount = maker_order
+                        .remaining_amount()
+                        .checked_sub(fill.amount)
+                        .ok_or(Error::Drive(DriveError::CorruptedCodeExecution(
+                            "token order fill is bigger than the remaining amount of the order",
+                        )))?;
+                    if remaining_amount == 0 {
+                        ops.push(TokenOperation(TokenOperationType::TokenRemoveOrder {
+                            order: maker_order,
+                        }));
+                    } else {
+                        maker_order.set_remaining_amount(remaining_amount);
+                        ops.push(TokenOperation(TokenOperationType::TokenUpdateOrder {
+                            order: maker_order,
+                        }));
+                    }
+                }
+
+                if order.remaining_amount() > 0 {
+                    ops.push(TokenOperation(TokenOperationType::TokenInsertOrder {
+                        order: order.clone(),
+                    }));
+                }
+
+                Ok(ops)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method:
+                    "TokenPlaceOrderTransitionAction::into_high_level_document_drive_operations"
+                        .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+/// Turns net changes of Dash credits into identity balance operations.
+///
+/// Credits escrowed by resting buy orders are not held by any identity, the change of the
+/// escrow is therefore taken out of or given back to the system credits.
+pub(super) fn credit_operations_for_balance_changes<'b>(
+    escrow_id: Identifier,
+    balance_changes: BTreeMap<Identifier, SumTokenAmount>,
+) -> Result<Vec<DriveOperation<'b>>, Error> {
+    let mut ops = vec![];
+    for (identity_id, change) in balance_changes {
+        let amount = u64::try_from(change.unsigned_abs()).map_err(|_| {
+            Error::Drive(DriveError::CorruptedCodeExecution(
+                "credit balance change does not fit in credits",
+            ))
+        })?;
+        match (identity_id == escrow_id, change > 0) {
+            (true, true) => ops.push(SystemOperation(
+                SystemOperationType::RemoveFromSystemCredits { amount },
+            )),
+            (true, false) => ops.push(SystemOperation(SystemOperationType::AddToSystemCredits {
+                amount,
+            })),
+            (false, true) => ops.push(IdentityOperation(
+                IdentityOperationType::AddToIdentityBalance {
+                    identity_id: identity_id.to_buffer(),
+                    added_balance: amount,
+                },
+            )),
+            (false, false) => ops.push(IdentityOperation(
+                IdentityOperationType::RemoveFromIdentityBalance {
+                    identity_id: identity_id.to_buffer(),
+                    balance_to_remove: amount,
+                },
+            )),
+        }
+    }
+    Ok(ops)
+}
@@ -0,0 +1,116 @@
+use dpp::block::epoch::Epoch;
+use dpp::group::action_event::GroupActionEvent;
+use dpp::group::group_action::GroupAction;
+use dpp::group::group_action::v0::GroupActionV0;
+use dpp::group::GroupStateTransitionResolvedInfo;
+use dpp::identifier::Identifier;
+use dpp::tokens::token_event::TokenEvent;
+use platform_version::version::PlatformVersion;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::state_transition_action::action_convert_to_operations::batch::DriveHighLevelBatchOperationConverter;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionActionAccessorsV0;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_transfer_from_transition_action::{TokenTransferFromTransitionAction, TokenTransferFromTransitionActionAccessorsV0};
+use crate::util::batch::{DriveOperation, IdentityOperationType};
+use crate::util::batch::drive_op_batch::{GroupOperationType, TokenOperationType};
+use crate::util::batch::DriveOperation::{GroupOperation, IdentityOperation, TokenOperation};
+
+impl DriveHighLevelBatchOperationConverter for TokenTransferFromTransitionAction {
+    fn into_high_level_batch_drive_operations<'b>(
+        self,
+        _epoch: &Epoch,
+        owner_id: Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'b>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_transitions
+            .convert_to_high_level_operations
+            .token_transfer_from_transition
+        {
+            0 => {
+                let data_contract_id = self.base().data_contract_id();
+
+                let identity_contract_nonce = self.base().identity_contract_nonce();
+
+                let mut ops = vec![IdentityOperation(
+                    IdentityOperationType::UpdateIdentityContractNonce {
+                        identity_id: owner_id.into_buffer(),
+                        contract_id: data_contract_id.into_buffer(),
+                        nonce: identity_contract_nonce,
+                    },
+                )];
+
+                if let Some(GroupStateTransitionResolvedInfo {
+                    group_contract_position,
+                    action_id,
+                    action_is_proposer,
+                    signer_power,
+                    ..
+                }) = self.base().store_in_group()
+                {
+                    let event = TokenEvent::TransferFrom(
+                        self.from_identity_id(),
+                        self.recipient_id(),
+                        self.amount(),
+                        self.public_note().cloned(),
+                    );
+
+                    let initialize_with_insert_action_info = if *action_is_proposer {
+                        Some(GroupAction::V0(GroupActionV0 {
+                            contract_id: self.base().data_contract_id(),
+                            proposer_id: owner_id,
+                            token_contract_position: self.base().token_position(),
+                            event: GroupActionEvent::TokenEvent(event),
+                        }))
+                    } else {
+                        None
+                    };
+
+                    ops.push(GroupOperation(GroupOperationType::AddGroupAction {
+                        contract_id: data_contract_id,
+                        group_contract_position: *group_contract_position,
+                        initialize_with_insert_action_info,
+                        action_id: *action_id,
+                        signer_identity_id: owner_id,
+                        signer_power: *signer_power,
+                        closes_group_action: self.base().perform_action(),
+                    }));
+                }
+
+                if self.base().perform_action() {
+                    ops.push(TokenOperation(TokenOperationType::TokenTransfer {
+                        token_id: self.token_id(),
+                        sender_id: self.from_identity_id(),
+                        recipient_id: self.recipient_id(),
+                        amount: self.amount(),
+                    }));
+
+                    // An allowance is kept until it was fully spent
+                    match self.remaining_allowance() {
+                        Some(allowance) => {
+                            ops.push(TokenOperation(TokenOperationType::TokenUpdateAllowance {
+                                allowance,
+                            }))
+                        }
+                        None => {
+                            ops.push(TokenOperation(TokenOperationType::TokenRemoveAllowance {
+                                allowance: self.allowance().clone(),
+                            }))
+                        }
+                    }
+                }
+
+                Ok(ops)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method:
+                    "TokenTransferFromTransitionAction::into_high_level_document_drive_operations"
+                        .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
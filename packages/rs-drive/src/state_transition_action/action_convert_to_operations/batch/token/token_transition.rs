@@ -17,6 +17,14 @@ use crate::state_transition_action::batch::batched_transition::token_transition:
 use crate::state_transition_action::batch::batched_transition::token_transition::token_set_price_for_direct_purchase_transition_action::TokenSetPriceForDirectPurchaseTransitionActionAccessorsV0;
 use crate::state_transition_action::batch::batched_transition::token_transition::token_transfer_transition_action::TokenTransferTransitionActionAccessorsV0;
 use crate::state_transition_action::batch::batched_transition::token_transition::token_unfreeze_transition_action::TokenUnfreezeTransitionActionAccessorsV0;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_place_order_transition_action::TokenPlaceOrderTransitionActionAccessorsV0;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_cancel_order_transition_action::TokenCancelOrderTransitionActionAccessorsV0;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_vesting_transfer_transition_action::TokenVestingTransferTransitionActionAccessorsV0;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_claim_vested_transition_action::TokenClaimVestedTransitionActionAccessorsV0;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_approve_transition_action::TokenApproveTransitionActionAccessorsV0;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_transfer_from_transition_action::TokenTransferFromTransitionActionAccessorsV0;
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+use dpp::tokens::vesting::v0::TokenVestingV0Accessors;
 
 impl DriveHighLevelBatchOperationConverter for TokenTransitionAction {
     fn into_high_level_batch_drive_operations<'b>(
@@ -64,6 +72,19 @@ impl DriveHighLevelBatchOperationConverter for TokenTransitionAction {
             TokenTransitionAction::SetPriceForDirectPurchaseAction(set_price) => {
                 set_price.into_high_level_batch_drive_operations(epoch, owner_id, platform_version)
             }
+            TokenTransitionAction::PlaceOrderAction(place_order) => place_order
+                .into_high_level_batch_drive_operations(epoch, owner_id, platform_version),
+            TokenTransitionAction::CancelOrderAction(cancel_order) => cancel_order
+                .into_high_level_batch_drive_operations(epoch, owner_id, platform_version),
+            TokenTransitionAction::VestingTransferAction(vesting_transfer) => vesting_transfer
+                .into_high_level_batch_drive_operations(epoch, owner_id, platform_version),
+            TokenTransitionAction::ClaimVestedAction(claim_vested) => claim_vested
+                .into_high_level_batch_drive_operations(epoch, owner_id, platform_version),
+            TokenTransitionAction::ApproveAction(approve) => {
+                approve.into_high_level_batch_drive_operations(epoch, owner_id, platform_version)
+            }
+            TokenTransitionAction::TransferFromAction(transfer_from) => transfer_from
+                .into_high_level_batch_drive_operations(epoch, owner_id, platform_version),
         }
     }
 }
@@ -135,6 +156,42 @@ impl TokenTransitionAction {
                     set_price_action.public_note().cloned(),
                 )
             }
+            TokenTransitionAction::PlaceOrderAction(place_order_action) => {
+                let order = place_order_action.order();
+                TokenEvent::PlaceOrder(
+                    order.side(),
+                    order.quote_currency(),
+                    order.original_amount(),
+                    order.price_per_token(),
+                )
+            }
+            TokenTransitionAction::CancelOrderAction(cancel_order_action) => {
+                TokenEvent::CancelOrder(cancel_order_action.order().id())
+            }
+            TokenTransitionAction::VestingTransferAction(vesting_transfer_action) => {
+                TokenEvent::VestingTransfer(
+                    vesting_transfer_action.recipient_id(),
+                    vesting_transfer_action.amount(),
+                    vesting_transfer_action.public_note().cloned(),
+                )
+            }
+            TokenTransitionAction::ClaimVestedAction(claim_vested_action) => {
+                TokenEvent::ClaimVested(claim_vested_action.vesting().id())
+            }
+            TokenTransitionAction::ApproveAction(approve_action) => TokenEvent::Approve(
+                approve_action.spender(),
+                approve_action.amount(),
+                approve_action.expires_at(),
+                approve_action.public_note().cloned(),
+            ),
+            TokenTransitionAction::TransferFromAction(transfer_from_action) => {
+                TokenEvent::TransferFrom(
+                    transfer_from_action.from_identity_id(),
+                    transfer_from_action.recipient_id(),
+                    transfer_from_action.amount(),
+                    transfer_from_action.public_note().cloned(),
+                )
+            }
         }
     }
 }
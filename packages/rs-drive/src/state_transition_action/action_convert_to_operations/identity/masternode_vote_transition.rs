@@ -8,7 +8,6 @@ use crate::state_transition_action::identity::masternode_vote::MasternodeVoteTra
 use crate::util::batch::drive_op_batch::PrefundedSpecializedBalanceOperationType;
 use dpp::block::epoch::Epoch;
 use dpp::version::PlatformVersion;
-use dpp::ProtocolError;
 
 impl DriveHighLevelOperationConverter for MasternodeVoteTransitionAction {
     fn into_high_level_drive_operations<'a>(
@@ -31,9 +30,9 @@ impl DriveHighLevelOperationConverter for MasternodeVoteTransitionAction {
                 let previous_resource_vote_choice_to_remove =
                     self.take_previous_resource_vote_choice_to_remove();
                 let vote = self.vote_owned();
-                let prefunded_specialized_balance_id = vote.specialized_balance_id()?.ok_or(Error::Protocol(Box::new(ProtocolError::VoteError("vote does not have a specialized balance from where it can use to pay for processing (this should have been caught during validation)".to_string()))))?;
+                let prefunded_specialized_balance_id = vote.specialized_balance_id()?;
 
-                let drive_operations = vec![
+                let mut drive_operations = vec![
                     IdentityOperation(IdentityOperationType::UpdateIdentityNonce {
                         identity_id: voter_identity_id.into_buffer(),
                         nonce,
@@ -45,8 +44,12 @@ impl DriveHighLevelOperationConverter for MasternodeVoteTransitionAction {
                         vote,
                         previous_resource_vote_choice_to_remove,
                     }),
-                    // Casting a vote has a fixed cost
-                    PrefundedSpecializedBalanceOperation(
+                ];
+
+                // Casting a vote on a prefunded vote poll has a fixed cost, yes/no/abstain vote
+                // polls are not prefunded and voting on them is free
+                if let Some(prefunded_specialized_balance_id) = prefunded_specialized_balance_id {
+                    drive_operations.push(PrefundedSpecializedBalanceOperation(
                         PrefundedSpecializedBalanceOperationType::DeductFromPrefundedBalance {
                             prefunded_specialized_balance_id,
                             remove_balance: platform_version
@@ -54,8 +57,9 @@ impl DriveHighLevelOperationConverter for MasternodeVoteTransitionAction {
                                 .vote_resolution_fund_fees
                                 .contested_document_single_vote_cost,
                         },
-                    ),
-                ];
+                    ));
+                }
+
                 Ok(drive_operations)
             }
             version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
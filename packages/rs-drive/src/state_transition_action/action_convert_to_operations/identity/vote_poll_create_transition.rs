@@ -0,0 +1,48 @@
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::state_transition_action::action_convert_to_operations::DriveHighLevelOperationConverter;
+use crate::state_transition_action::identity::vote_poll_create::VotePollCreateTransitionAction;
+use crate::util::batch::DriveOperation::IdentityOperation;
+use crate::util::batch::{DriveOperation, IdentityOperationType};
+use dpp::block::epoch::Epoch;
+use dpp::version::PlatformVersion;
+
+impl DriveHighLevelOperationConverter for VotePollCreateTransitionAction {
+    fn into_high_level_drive_operations<'a>(
+        self,
+        _epoch: &Epoch,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'a>>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_transitions
+            .convert_to_high_level_operations
+            .vote_poll_create_transition
+        {
+            0 => {
+                let creator_identity_id = self.identity_id().into_buffer();
+                let nonce = self.nonce();
+                let (vote_poll, eligible_voters) = self.vote_poll_and_eligible_voters_owned();
+
+                Ok(vec![
+                    IdentityOperation(IdentityOperationType::UpdateIdentityNonce {
+                        identity_id: creator_identity_id,
+                        nonce,
+                    }),
+                    IdentityOperation(IdentityOperationType::CreateYesNoAbstainVotePoll {
+                        creator_identity_id,
+                        vote_poll,
+                        eligible_voters,
+                    }),
+                ])
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "VotePollCreateTransitionAction::into_high_level_drive_operations"
+                    .to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
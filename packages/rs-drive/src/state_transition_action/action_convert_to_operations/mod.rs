@@ -39,6 +39,10 @@ impl DriveHighLevelOperationConverter for StateTransitionAction {
                 data_contract_update_transition
                     .into_high_level_drive_operations(epoch, platform_version)
             }
+            StateTransitionAction::DataContractDeleteAction(data_contract_delete_transition) => {
+                data_contract_delete_transition
+                    .into_high_level_drive_operations(epoch, platform_version)
+            }
             StateTransitionAction::BatchAction(documents_batch_transition) => {
                 documents_batch_transition.into_high_level_drive_operations(epoch, platform_version)
             }
@@ -62,6 +66,10 @@ impl DriveHighLevelOperationConverter for StateTransitionAction {
             StateTransitionAction::MasternodeVoteAction(masternode_vote_transition) => {
                 masternode_vote_transition.into_high_level_drive_operations(epoch, platform_version)
             }
+            StateTransitionAction::VotePollCreateAction(vote_poll_create_transition) => {
+                vote_poll_create_transition
+                    .into_high_level_drive_operations(epoch, platform_version)
+            }
             StateTransitionAction::BumpIdentityNonceAction(bump_identity_nonce_transition) => {
                 bump_identity_nonce_transition
                     .into_high_level_drive_operations(epoch, platform_version)
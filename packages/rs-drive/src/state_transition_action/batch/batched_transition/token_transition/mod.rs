@@ -29,6 +29,21 @@ pub mod token_direct_purchase_transition_action;
 /// token_set_price_for_direct_purchase_transition_action
 pub mod token_set_price_for_direct_purchase_transition_action;
 
+/// token_cancel_order_transition_action
+pub mod token_cancel_order_transition_action;
+/// token_place_order_transition_action
+pub mod token_place_order_transition_action;
+
+/// token_claim_vested_transition_action
+pub mod token_claim_vested_transition_action;
+/// token_vesting_transfer_transition_action
+pub mod token_vesting_transfer_transition_action;
+
+/// token_approve_transition_action
+pub mod token_approve_transition_action;
+/// token_transfer_from_transition_action
+pub mod token_transfer_from_transition_action;
+
 use derive_more::From;
 use dpp::block::block_info::BlockInfo;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
@@ -56,6 +71,12 @@ use crate::state_transition_action::batch::batched_transition::token_transition:
 use crate::state_transition_action::batch::batched_transition::token_transition::token_claim_transition_action::{TokenClaimTransitionAction, TokenClaimTransitionActionAccessorsV0};
 use crate::state_transition_action::batch::batched_transition::token_transition::token_direct_purchase_transition_action::{TokenDirectPurchaseTransitionAction, TokenDirectPurchaseTransitionActionAccessorsV0};
 use crate::state_transition_action::batch::batched_transition::token_transition::token_set_price_for_direct_purchase_transition_action::{TokenSetPriceForDirectPurchaseTransitionAction, TokenSetPriceForDirectPurchaseTransitionActionAccessorsV0};
+use crate::state_transition_action::batch::batched_transition::token_transition::token_place_order_transition_action::{TokenPlaceOrderTransitionAction, TokenPlaceOrderTransitionActionAccessorsV0};
+use crate::state_transition_action::batch::batched_transition::token_transition::token_cancel_order_transition_action::{TokenCancelOrderTransitionAction, TokenCancelOrderTransitionActionAccessorsV0};
+use crate::state_transition_action::batch::batched_transition::token_transition::token_vesting_transfer_transition_action::{TokenVestingTransferTransitionAction, TokenVestingTransferTransitionActionAccessorsV0};
+use crate::state_transition_action::batch::batched_transition::token_transition::token_claim_vested_transition_action::{TokenClaimVestedTransitionAction, TokenClaimVestedTransitionActionAccessorsV0};
+use crate::state_transition_action::batch::batched_transition::token_transition::token_approve_transition_action::{TokenApproveTransitionAction, TokenApproveTransitionActionAccessorsV0};
+use crate::state_transition_action::batch::batched_transition::token_transition::token_transfer_from_transition_action::{TokenTransferFromTransitionAction, TokenTransferFromTransitionActionAccessorsV0};
 
 /// token action
 #[derive(Debug, Clone, From)]
@@ -82,6 +103,18 @@ pub enum TokenTransitionAction {
     DirectPurchaseAction(TokenDirectPurchaseTransitionAction),
     /// sets the price for direct purchase of the token
     SetPriceForDirectPurchaseAction(TokenSetPriceForDirectPurchaseTransitionAction),
+    /// places a limit order on the order book of the token
+    PlaceOrderAction(TokenPlaceOrderTransitionAction),
+    /// cancels a resting order on the order book of the token
+    CancelOrderAction(TokenCancelOrderTransitionAction),
+    /// locks tokens for a recipient under a vesting schedule
+    VestingTransferAction(TokenVestingTransferTransitionAction),
+    /// claims tokens that have vested
+    ClaimVestedAction(TokenClaimVestedTransitionAction),
+    /// allows a spender to transfer tokens on behalf of the owner
+    ApproveAction(TokenApproveTransitionAction),
+    /// transfers tokens on behalf of their owner within an allowance
+    TransferFromAction(TokenTransferFromTransitionAction),
 }
 
 impl TokenTransitionAction {
@@ -99,6 +132,12 @@ impl TokenTransitionAction {
             TokenTransitionAction::ConfigUpdateAction(action) => action.base(),
             TokenTransitionAction::DirectPurchaseAction(action) => action.base(),
             TokenTransitionAction::SetPriceForDirectPurchaseAction(action) => action.base(),
+            TokenTransitionAction::PlaceOrderAction(action) => action.base(),
+            TokenTransitionAction::CancelOrderAction(action) => action.base(),
+            TokenTransitionAction::VestingTransferAction(action) => action.base(),
+            TokenTransitionAction::ClaimVestedAction(action) => action.base(),
+            TokenTransitionAction::ApproveAction(action) => action.base(),
+            TokenTransitionAction::TransferFromAction(action) => action.base(),
         }
     }
 
@@ -116,6 +155,12 @@ impl TokenTransitionAction {
             TokenTransitionAction::ConfigUpdateAction(action) => action.base_owned(),
             TokenTransitionAction::DirectPurchaseAction(action) => action.base_owned(),
             TokenTransitionAction::SetPriceForDirectPurchaseAction(action) => action.base_owned(),
+            TokenTransitionAction::PlaceOrderAction(action) => action.base_owned(),
+            TokenTransitionAction::CancelOrderAction(action) => action.base_owned(),
+            TokenTransitionAction::VestingTransferAction(action) => action.base_owned(),
+            TokenTransitionAction::ClaimVestedAction(action) => action.base_owned(),
+            TokenTransitionAction::ApproveAction(action) => action.base_owned(),
+            TokenTransitionAction::TransferFromAction(action) => action.base_owned(),
         }
     }
 
@@ -133,6 +178,12 @@ impl TokenTransitionAction {
             TokenTransitionAction::ConfigUpdateAction(_) => "configUpdate",
             TokenTransitionAction::DirectPurchaseAction(_) => "directPurchase",
             TokenTransitionAction::SetPriceForDirectPurchaseAction(_) => "directPricing",
+            TokenTransitionAction::PlaceOrderAction(_) => "placeOrder",
+            TokenTransitionAction::CancelOrderAction(_) => "cancelOrder",
+            TokenTransitionAction::VestingTransferAction(_) => "vestingTransfer",
+            TokenTransitionAction::ClaimVestedAction(_) => "claimVested",
+            TokenTransitionAction::ApproveAction(_) => "approve",
+            TokenTransitionAction::TransferFromAction(_) => "transferFrom",
         }
     }
 
@@ -196,6 +247,18 @@ impl TokenTransitionAction {
             TokenTransitionAction::SetPriceForDirectPurchaseAction(_) => {
                 Ok(keeps_history.keeps_direct_pricing_history())
             }
+            // Order book actions are never kept in history: the document types of the token
+            // history contract are fixed by its released system contract version, and adding
+            // some would require a system contract upgrade. Resting orders can be queried from
+            // the order book itself and fills are reflected in the token balances.
+            TokenTransitionAction::PlaceOrderAction(_) => Ok(false),
+            TokenTransitionAction::CancelOrderAction(_) => Ok(false),
+            // nor for vesting actions
+            TokenTransitionAction::VestingTransferAction(_) => Ok(false),
+            TokenTransitionAction::ClaimVestedAction(_) => Ok(false),
+            // nor for allowance actions
+            TokenTransitionAction::ApproveAction(_) => Ok(false),
+            TokenTransitionAction::TransferFromAction(_) => Ok(false),
         }
     }
 }
@@ -0,0 +1,49 @@
+use derive_more::From;
+use dpp::tokens::allowance::TokenAllowance;
+
+/// transformer module for token approve transition action
+pub mod transformer;
+mod v0;
+
+pub use v0::*;
+
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionAction;
+
+/// Token approve transition action
+#[derive(Debug, Clone, From)]
+pub enum TokenApproveTransitionAction {
+    /// v0
+    V0(TokenApproveTransitionActionV0),
+}
+
+impl TokenApproveTransitionActionAccessorsV0 for TokenApproveTransitionAction {
+    fn base(&self) -> &TokenBaseTransitionAction {
+        match self {
+            TokenApproveTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> TokenBaseTransitionAction {
+        match self {
+            TokenApproveTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn allowance(&self) -> &TokenAllowance {
+        match self {
+            TokenApproveTransitionAction::V0(v0) => &v0.allowance,
+        }
+    }
+
+    fn previous_allowance(&self) -> Option<&TokenAllowance> {
+        match self {
+            TokenApproveTransitionAction::V0(v0) => v0.previous_allowance.as_ref(),
+        }
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        match self {
+            TokenApproveTransitionAction::V0(v0) => v0.public_note.as_ref(),
+        }
+    }
+}
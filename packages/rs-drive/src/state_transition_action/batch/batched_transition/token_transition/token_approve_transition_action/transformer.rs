@@ -0,0 +1,69 @@
+use dpp::platform_value::Identifier;
+use dpp::ProtocolError;
+use grovedb::TransactionArg;
+use std::sync::Arc;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::prelude::{ConsensusValidationResult, UserFeeIncrease};
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_approve_transition_action::{TokenApproveTransitionActionV0, TokenApproveTransitionAction};
+use dpp::state_transition::batch_transition::token_approve_transition::TokenApproveTransition;
+use platform_version::version::PlatformVersion;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::state_transition_action::batch::BatchedTransitionAction;
+
+/// Implement methods to transform a `TokenApproveTransition` into a `TokenApproveTransitionAction`.
+impl TokenApproveTransitionAction {
+    /// Transform a borrowed `TokenApproveTransition` into a `TokenApproveTransitionAction` using the provided data contract lookup.
+    ///
+    /// # Arguments
+    ///
+    /// * `drive` - A reference to the `Drive` instance used for accessing the system.
+    /// * `owner_id` - The identifier of the identity granting the allowance.
+    /// * `value` - A reference to a `TokenApproveTransition`.
+    /// * `approximate_without_state_for_costs` - A flag indicating whether to approximate state costs without full state.
+    /// * `transaction` - The transaction argument used for state changes.
+    /// * `block_info` - Information about the current block.
+    /// * `user_fee_increase` - The fee increase of the state transition.
+    /// * `get_data_contract` - A closure that fetches the `DataContractFetchInfo` given a contract ID.
+    /// * `platform_version` - The platform version for the context in which the transition is being executed.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(ConsensusValidationResult<BatchedTransitionAction>, FeeResult), Error>` - A `TokenApproveTransitionAction` if successful, otherwise an error.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_from_borrowed_token_approve_transition_with_contract_lookup(
+        drive: &Drive,
+        owner_id: Identifier,
+        value: &TokenApproveTransition,
+        approximate_without_state_for_costs: bool,
+        transaction: TransactionArg,
+        block_info: &BlockInfo,
+        user_fee_increase: UserFeeIncrease,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+        platform_version: &PlatformVersion,
+    ) -> Result<
+        (
+            ConsensusValidationResult<BatchedTransitionAction>,
+            FeeResult,
+        ),
+        Error,
+    > {
+        match value {
+            TokenApproveTransition::V0(v0) => {
+                TokenApproveTransitionActionV0::try_from_borrowed_token_approve_transition_with_contract_lookup(
+                    drive,
+                    owner_id,
+                    v0,
+                    approximate_without_state_for_costs,
+                    transaction,
+                    block_info,
+                    user_fee_increase,
+                    get_data_contract,
+                    platform_version,
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+mod transformer;
+
+use std::sync::Arc;
+use dpp::balances::credits::TokenAmount;
+use dpp::identifier::Identifier;
+use dpp::prelude::TimestampMillis;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
+use dpp::tokens::allowance::v0::TokenAllowanceV0Accessors;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::{TokenBaseTransitionAction, TokenBaseTransitionActionAccessorsV0};
+
+/// Token approve transition action v0
+#[derive(Debug, Clone)]
+pub struct TokenApproveTransitionActionV0 {
+    /// Base token transition action
+    pub base: TokenBaseTransitionAction,
+    /// The allowance granted, an amount of 0 revokes it
+    pub allowance: TokenAllowance,
+    /// The allowance previously granted to the same spender, if any
+    pub previous_allowance: Option<TokenAllowance>,
+    /// A public note
+    pub public_note: Option<String>,
+}
+
+/// Accessors for `TokenApproveTransitionActionV0`
+pub trait TokenApproveTransitionActionAccessorsV0 {
+    /// Returns a reference to the base token transition action
+    fn base(&self) -> &TokenBaseTransitionAction;
+
+    /// Consumes self and returns the base token transition action
+    fn base_owned(self) -> TokenBaseTransitionAction;
+
+    /// Returns the allowance granted
+    fn allowance(&self) -> &TokenAllowance;
+
+    /// Returns the allowance previously granted to the same spender, if any
+    fn previous_allowance(&self) -> Option<&TokenAllowance>;
+
+    /// Returns the public note
+    fn public_note(&self) -> Option<&String>;
+
+    /// Returns the identity or group allowed to spend the tokens
+    fn spender(&self) -> TokenAllowanceSpender {
+        self.allowance().spender()
+    }
+
+    /// Returns the allowed amount
+    fn amount(&self) -> TokenAmount {
+        self.allowance().amount()
+    }
+
+    /// Returns when the allowance expires, if ever
+    fn expires_at(&self) -> Option<TimestampMillis> {
+        self.allowance().expires_at()
+    }
+
+    /// Returns the token position in the contract
+    fn token_position(&self) -> u16 {
+        self.base().token_position()
+    }
+
+    /// Returns the token ID
+    fn token_id(&self) -> Identifier {
+        self.base().token_id()
+    }
+
+    /// Returns the data contract ID
+    fn data_contract_id(&self) -> Identifier {
+        self.base().data_contract_id()
+    }
+
+    /// Returns a reference to the data contract fetch info
+    fn data_contract_fetch_info_ref(&self) -> &Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info_ref()
+    }
+
+    /// Returns the data contract fetch info
+    fn data_contract_fetch_info(&self) -> Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info()
+    }
+}
+
+impl TokenApproveTransitionActionAccessorsV0 for TokenApproveTransitionActionV0 {
+    fn base(&self) -> &TokenBaseTransitionAction {
+        &self.base
+    }
+
+    fn base_owned(self) -> TokenBaseTransitionAction {
+        self.base
+    }
+
+    fn allowance(&self) -> &TokenAllowance {
+        &self.allowance
+    }
+
+    fn previous_allowance(&self) -> Option<&TokenAllowance> {
+        self.previous_allowance.as_ref()
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        self.public_note.as_ref()
+    }
+}
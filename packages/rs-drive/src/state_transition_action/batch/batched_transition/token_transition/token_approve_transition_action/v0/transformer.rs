@@ -0,0 +1,150 @@
+use std::sync::Arc;
+use grovedb::TransactionArg;
+use dpp::block::block_info::BlockInfo;
+use dpp::identifier::Identifier;
+use dpp::state_transition::batch_transition::token_approve_transition::v0::TokenApproveTransitionV0;
+use dpp::ProtocolError;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionAction;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_approve_transition_action::v0::TokenApproveTransitionActionV0;
+use dpp::fee::fee_result::FeeResult;
+use dpp::prelude::{ConsensusValidationResult, UserFeeIncrease};
+use dpp::state_transition::batch_transition::token_base_transition::v0::v0_methods::TokenBaseTransitionV0Methods;
+use dpp::tokens::allowance::TokenAllowance;
+use platform_version::version::PlatformVersion;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::state_transition_action::batch::batched_transition::token_transition::TokenTransitionAction;
+use crate::state_transition_action::batch::BatchedTransitionAction;
+use crate::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceAction;
+
+impl TokenApproveTransitionActionV0 {
+    /// Converts a borrowed `TokenApproveTransitionV0` into a `TokenApproveTransitionActionV0` using the provided contract lookup.
+    ///
+    /// The allowance currently granted to the same spender is fetched, as it is replaced, or
+    /// removed if the new amount is 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `drive` - A reference to the `Drive` instance that handles data storage and retrieval.
+    /// * `owner_id` - The identifier of the identity granting the allowance.
+    /// * `value` - A reference to the `TokenApproveTransitionV0` struct containing the transition data.
+    /// * `approximate_without_state_for_costs` - A flag to indicate whether costs should be approximated without full
+    ///   state consideration.
+    /// * `transaction` - The transaction context, which includes the necessary state and other details for the transition.
+    /// * `block_info` - Information about the current block (e.g., epoch) to help calculate transaction fees.
+    /// * `user_fee_increase` - The fee increase of the state transition, used if we only bump the nonce.
+    /// * `get_data_contract` - A closure function that takes a contract identifier and returns a `DataContractFetchInfo`.
+    /// * `platform_version` - A reference to the platform version to ensure the transition respects version-specific logic.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(ConsensusValidationResult<BatchedTransitionAction>, FeeResult), Error>` - Returns a tuple containing the constructed
+    ///   action and a `FeeResult` if successful.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_from_borrowed_token_approve_transition_with_contract_lookup(
+        drive: &Drive,
+        owner_id: Identifier,
+        value: &TokenApproveTransitionV0,
+        approximate_without_state_for_costs: bool,
+        transaction: TransactionArg,
+        block_info: &BlockInfo,
+        user_fee_increase: UserFeeIncrease,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+        platform_version: &PlatformVersion,
+    ) -> Result<
+        (
+            ConsensusValidationResult<BatchedTransitionAction>,
+            FeeResult,
+        ),
+        Error,
+    > {
+        let TokenApproveTransitionV0 {
+            base,
+            spender,
+            amount,
+            expires_at,
+            public_note,
+        } = value;
+
+        let mut drive_operations = vec![];
+
+        let base_action_validation_result =
+            TokenBaseTransitionAction::try_from_borrowed_base_transition_with_contract_lookup(
+                drive,
+                owner_id,
+                base,
+                approximate_without_state_for_costs,
+                transaction,
+                &mut drive_operations,
+                get_data_contract,
+                platform_version,
+            )?;
+
+        let mut fee_result = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            drive.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+
+        let (base_action, _change_note) = match base_action_validation_result.is_valid() {
+            true => base_action_validation_result.into_data()?,
+            false => {
+                let bump_action =
+                    BumpIdentityDataContractNonceAction::from_borrowed_token_base_transition(
+                        base,
+                        owner_id,
+                        user_fee_increase,
+                    );
+                let batched_action =
+                    BatchedTransitionAction::BumpIdentityDataContractNonce(bump_action);
+
+                return Ok((
+                    ConsensusValidationResult::new_with_data_and_errors(
+                        batched_action,
+                        base_action_validation_result.errors,
+                    ),
+                    fee_result,
+                ));
+            }
+        };
+
+        let (previous_allowance, fetch_allowance_fee) = drive.fetch_token_allowance_with_costs(
+            base.token_id().to_buffer(),
+            owner_id.to_buffer(),
+            *spender,
+            block_info,
+            true,
+            transaction,
+            platform_version,
+        )?;
+
+        fee_result.checked_add_assign(fetch_allowance_fee)?;
+
+        let allowance = TokenAllowance::new(
+            base.token_id(),
+            owner_id,
+            *spender,
+            *amount,
+            *expires_at,
+            platform_version,
+        )?;
+
+        Ok((
+            BatchedTransitionAction::TokenAction(TokenTransitionAction::ApproveAction(
+                TokenApproveTransitionActionV0 {
+                    base: base_action,
+                    allowance,
+                    previous_allowance,
+                    public_note: public_note.clone(),
+                }
+                .into(),
+            ))
+            .into(),
+            fee_result,
+        ))
+    }
+}
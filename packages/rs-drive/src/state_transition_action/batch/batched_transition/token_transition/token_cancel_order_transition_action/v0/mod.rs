@@ -0,0 +1,67 @@
+mod transformer;
+
+use std::sync::Arc;
+use dpp::identifier::Identifier;
+use dpp::tokens::order_book::TokenOrder;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::{TokenBaseTransitionAction, TokenBaseTransitionActionAccessorsV0};
+
+/// Token cancel order transition action v0
+#[derive(Debug, Clone)]
+pub struct TokenCancelOrderTransitionActionV0 {
+    /// Base token transition action
+    pub base: TokenBaseTransitionAction,
+    /// The resting order being cancelled
+    pub order: TokenOrder,
+}
+
+/// Accessors for `TokenCancelOrderTransitionActionV0`
+pub trait TokenCancelOrderTransitionActionAccessorsV0 {
+    /// Returns a reference to the base token transition action
+    fn base(&self) -> &TokenBaseTransitionAction;
+
+    /// Consumes self and returns the base token transition action
+    fn base_owned(self) -> TokenBaseTransitionAction;
+
+    /// Returns the resting order being cancelled
+    fn order(&self) -> &TokenOrder;
+
+    /// Returns the token position in the contract
+    fn token_position(&self) -> u16 {
+        self.base().token_position()
+    }
+
+    /// Returns the token ID
+    fn token_id(&self) -> Identifier {
+        self.base().token_id()
+    }
+
+    /// Returns the data contract ID
+    fn data_contract_id(&self) -> Identifier {
+        self.base().data_contract_id()
+    }
+
+    /// Returns a reference to the data contract fetch info
+    fn data_contract_fetch_info_ref(&self) -> &Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info_ref()
+    }
+
+    /// Returns the data contract fetch info
+    fn data_contract_fetch_info(&self) -> Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info()
+    }
+}
+
+impl TokenCancelOrderTransitionActionAccessorsV0 for TokenCancelOrderTransitionActionV0 {
+    fn base(&self) -> &TokenBaseTransitionAction {
+        &self.base
+    }
+
+    fn base_owned(self) -> TokenBaseTransitionAction {
+        self.base
+    }
+
+    fn order(&self) -> &TokenOrder {
+        &self.order
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+use grovedb::TransactionArg;
+use dpp::block::block_info::BlockInfo;
+use dpp::consensus::ConsensusError;
+use dpp::consensus::state::state_error::StateError;
+use dpp::consensus::state::token::{TokenOrderNotFoundError, TokenOrderNotOwnedError};
+use dpp::identifier::Identifier;
+use dpp::state_transition::batch_transition::token_cancel_order_transition::v0::TokenCancelOrderTransitionV0;
+use dpp::ProtocolError;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionAction;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_cancel_order_transition_action::v0::TokenCancelOrderTransitionActionV0;
+use dpp::fee::fee_result::FeeResult;
+use dpp::prelude::{ConsensusValidationResult, UserFeeIncrease};
+use dpp::state_transition::batch_transition::token_base_transition::v0::v0_methods::TokenBaseTransitionV0Methods;
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+use platform_version::version::PlatformVersion;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::state_transition_action::batch::batched_transition::token_transition::TokenTransitionAction;
+use crate::state_transition_action::batch::BatchedTransitionAction;
+use crate::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceAction;
+
+impl TokenCancelOrderTransitionActionV0 {
+    /// Converts a borrowed `TokenCancelOrderTransitionV0` into a `TokenCancelOrderTransitionActionV0` using the provided contract lookup.
+    ///
+    /// The order must be resting on the book of the token and must have been placed by `owner_id`,
+    /// otherwise only the identity contract nonce is bumped.
+    ///
+    /// # Arguments
+    ///
+    /// * `drive` - A reference to the `Drive` instance that handles data storage and retrieval.
+    /// * `owner_id` - The identifier of the identity cancelling the order.
+    /// * `value` - A reference to the `TokenCancelOrderTransitionV0` struct containing the transition data.
+    /// * `approximate_without_state_for_costs` - A flag to indicate whether costs should be approximated without full
+    ///   state consideration.
+    /// * `transaction` - The transaction context, which includes the necessary state and other details for the transition.
+    /// * `block_info` - Information about the current block (e.g., epoch) to help calculate transaction fees.
+    /// * `user_fee_increase` - The fee increase of the state transition, used if we only bump the nonce.
+    /// * `get_data_contract` - A closure function that takes a contract identifier and returns a `DataContractFetchInfo`.
+    /// * `platform_version` - A reference to the platform version to ensure the transition respects version-specific logic.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(ConsensusValidationResult<BatchedTransitionAction>, FeeResult), Error>` - Returns a tuple containing the constructed
+    ///   action and a `FeeResult` if successful.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_from_borrowed_token_cancel_order_transition_with_contract_lookup(
+        drive: &Drive,
+        owner_id: Identifier,
+        value: &TokenCancelOrderTransitionV0,
+        approximate_without_state_for_costs: bool,
+        transaction: TransactionArg,
+        block_info: &BlockInfo,
+        user_fee_increase: UserFeeIncrease,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+        platform_version: &PlatformVersion,
+    ) -> Result<
+        (
+            ConsensusValidationResult<BatchedTransitionAction>,
+            FeeResult,
+        ),
+        Error,
+    > {
+        let TokenCancelOrderTransitionV0 { base, order_id } = value;
+
+        let mut drive_operations = vec![];
+
+        let base_action_validation_result =
+            TokenBaseTransitionAction::try_from_borrowed_base_transition_with_contract_lookup(
+                drive,
+                owner_id,
+                base,
+                approximate_without_state_for_costs,
+                transaction,
+                &mut drive_operations,
+                get_data_contract,
+                platform_version,
+            )?;
+
+        let mut fee_result = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            drive.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+
+        let (base_action, _change_note) = match base_action_validation_result.is_valid() {
+            true => base_action_validation_result.into_data()?,
+            false => {
+                let bump_action =
+                    BumpIdentityDataContractNonceAction::from_borrowed_token_base_transition(
+                        base,
+                        owner_id,
+                        user_fee_increase,
+                    );
+                let batched_action =
+                    BatchedTransitionAction::BumpIdentityDataContractNonce(bump_action);
+
+                return Ok((
+                    ConsensusValidationResult::new_with_data_and_errors(
+                        batched_action,
+                        base_action_validation_result.errors,
+                    ),
+                    fee_result,
+                ));
+            }
+        };
+
+        let (order, fetch_order_fee) = drive.fetch_token_order_with_costs(
+            base.token_id().to_buffer(),
+            order_id.to_buffer(),
+            block_info,
+            true,
+            transaction,
+            platform_version,
+        )?;
+
+        fee_result.checked_add_assign(fetch_order_fee)?;
+
+        let error = match &order {
+            None => Some(ConsensusError::StateError(
+                StateError::TokenOrderNotFoundError(TokenOrderNotFoundError::new(
+                    base.token_id(),
+                    *order_id,
+                )),
+            )),
+            Some(order) if order.owner_id() != owner_id => Some(ConsensusError::StateError(
+                StateError::TokenOrderNotOwnedError(TokenOrderNotOwnedError::new(
+                    base.token_id(),
+                    *order_id,
+                    owner_id,
+                    order.owner_id(),
+                )),
+            )),
+            Some(_) => None,
+        };
+
+        if let Some(error) = error {
+            let bump_action =
+                BumpIdentityDataContractNonceAction::from_borrowed_token_base_transition(
+                    base,
+                    owner_id,
+                    user_fee_increase,
+                );
+            let batched_action =
+                BatchedTransitionAction::BumpIdentityDataContractNonce(bump_action);
+
+            return Ok((
+                ConsensusValidationResult::new_with_data_and_errors(batched_action, vec![error]),
+                fee_result,
+            ));
+        }
+
+        let order = order.expect("order was checked to be present above");
+
+        Ok((
+            BatchedTransitionAction::TokenAction(TokenTransitionAction::CancelOrderAction(
+                TokenCancelOrderTransitionActionV0 {
+                    base: base_action,
+                    order,
+                }
+                .into(),
+            ))
+            .into(),
+            fee_result,
+        ))
+    }
+}
@@ -0,0 +1,44 @@
+use derive_more::From;
+use dpp::balances::credits::TokenAmount;
+use dpp::tokens::vesting::TokenVesting;
+
+/// transformer module for token claim vested transition action
+pub mod transformer;
+mod v0;
+
+pub use v0::*;
+
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionAction;
+
+/// Token claim vested transition action
+#[derive(Debug, Clone, From)]
+pub enum TokenClaimVestedTransitionAction {
+    /// v0
+    V0(TokenClaimVestedTransitionActionV0),
+}
+
+impl TokenClaimVestedTransitionActionAccessorsV0 for TokenClaimVestedTransitionAction {
+    fn base(&self) -> &TokenBaseTransitionAction {
+        match self {
+            TokenClaimVestedTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> TokenBaseTransitionAction {
+        match self {
+            TokenClaimVestedTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn vesting(&self) -> &TokenVesting {
+        match self {
+            TokenClaimVestedTransitionAction::V0(v0) => &v0.vesting,
+        }
+    }
+
+    fn amount(&self) -> TokenAmount {
+        match self {
+            TokenClaimVestedTransitionAction::V0(v0) => v0.amount,
+        }
+    }
+}
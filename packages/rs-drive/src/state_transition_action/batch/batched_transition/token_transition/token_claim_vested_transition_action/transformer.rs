@@ -0,0 +1,69 @@
+use dpp::platform_value::Identifier;
+use dpp::ProtocolError;
+use grovedb::TransactionArg;
+use std::sync::Arc;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::prelude::{ConsensusValidationResult, UserFeeIncrease};
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_claim_vested_transition_action::{TokenClaimVestedTransitionActionV0, TokenClaimVestedTransitionAction};
+use dpp::state_transition::batch_transition::token_claim_vested_transition::TokenClaimVestedTransition;
+use platform_version::version::PlatformVersion;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::state_transition_action::batch::BatchedTransitionAction;
+
+/// Implement methods to transform a `TokenClaimVestedTransition` into a `TokenClaimVestedTransitionAction`.
+impl TokenClaimVestedTransitionAction {
+    /// Transform a borrowed `TokenClaimVestedTransition` into a `TokenClaimVestedTransitionAction` using the provided data contract lookup.
+    ///
+    /// # Arguments
+    ///
+    /// * `drive` - A reference to the `Drive` instance used for accessing the system.
+    /// * `owner_id` - The identifier of the recipient claiming the vested tokens.
+    /// * `value` - A reference to a `TokenClaimVestedTransition`.
+    /// * `approximate_without_state_for_costs` - A flag indicating whether to approximate state costs without full state.
+    /// * `transaction` - The transaction argument used for state changes.
+    /// * `block_info` - Information about the current block.
+    /// * `user_fee_increase` - The fee increase of the state transition.
+    /// * `get_data_contract` - A closure that fetches the `DataContractFetchInfo` given a contract ID.
+    /// * `platform_version` - The platform version for the context in which the transition is being executed.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(ConsensusValidationResult<BatchedTransitionAction>, FeeResult), Error>` - A `TokenClaimVestedTransitionAction` if successful, otherwise an error.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_from_borrowed_token_claim_vested_transition_with_contract_lookup(
+        drive: &Drive,
+        owner_id: Identifier,
+        value: &TokenClaimVestedTransition,
+        approximate_without_state_for_costs: bool,
+        transaction: TransactionArg,
+        block_info: &BlockInfo,
+        user_fee_increase: UserFeeIncrease,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+        platform_version: &PlatformVersion,
+    ) -> Result<
+        (
+            ConsensusValidationResult<BatchedTransitionAction>,
+            FeeResult,
+        ),
+        Error,
+    > {
+        match value {
+            TokenClaimVestedTransition::V0(v0) => {
+                TokenClaimVestedTransitionActionV0::try_from_borrowed_token_claim_vested_transition_with_contract_lookup(
+                    drive,
+                    owner_id,
+                    v0,
+                    approximate_without_state_for_costs,
+                    transaction,
+                    block_info,
+                    user_fee_increase,
+                    get_data_contract,
+                    platform_version,
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+mod transformer;
+
+use std::sync::Arc;
+use dpp::balances::credits::TokenAmount;
+use dpp::identifier::Identifier;
+use dpp::tokens::vesting::TokenVesting;
+use dpp::tokens::vesting::v0::TokenVestingV0Accessors;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::{TokenBaseTransitionAction, TokenBaseTransitionActionAccessorsV0};
+
+/// Token claim vested transition action v0
+#[derive(Debug, Clone)]
+pub struct TokenClaimVestedTransitionActionV0 {
+    /// Base token transition action
+    pub base: TokenBaseTransitionAction,
+    /// The vesting claimed from, as it was before the claim
+    pub vesting: TokenVesting,
+    /// The amount of tokens that vested since the last claim and are released to the recipient
+    pub amount: TokenAmount,
+}
+
+/// Accessors for `TokenClaimVestedTransitionActionV0`
+pub trait TokenClaimVestedTransitionActionAccessorsV0 {
+    /// Returns a reference to the base token transition action
+    fn base(&self) -> &TokenBaseTransitionAction;
+
+    /// Consumes self and returns the base token transition action
+    fn base_owned(self) -> TokenBaseTransitionAction;
+
+    /// Returns the vesting claimed from, as it was before the claim
+    fn vesting(&self) -> &TokenVesting;
+
+    /// Returns the amount of tokens released to the recipient
+    fn amount(&self) -> TokenAmount;
+
+    /// Returns the vesting claimed from, with the released amount marked as claimed
+    fn claimed_vesting(&self) -> TokenVesting {
+        let mut vesting = self.vesting().clone();
+        vesting.set_claimed_amount(vesting.claimed_amount().saturating_add(self.amount()));
+        vesting
+    }
+
+    /// Returns the token position in the contract
+    fn token_position(&self) -> u16 {
+        self.base().token_position()
+    }
+
+    /// Returns the token ID
+    fn token_id(&self) -> Identifier {
+        self.base().token_id()
+    }
+
+    /// Returns the data contract ID
+    fn data_contract_id(&self) -> Identifier {
+        self.base().data_contract_id()
+    }
+
+    /// Returns a reference to the data contract fetch info
+    fn data_contract_fetch_info_ref(&self) -> &Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info_ref()
+    }
+
+    /// Returns the data contract fetch info
+    fn data_contract_fetch_info(&self) -> Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info()
+    }
+}
+
+impl TokenClaimVestedTransitionActionAccessorsV0 for TokenClaimVestedTransitionActionV0 {
+    fn base(&self) -> &TokenBaseTransitionAction {
+        &self.base
+    }
+
+    fn base_owned(self) -> TokenBaseTransitionAction {
+        self.base
+    }
+
+    fn vesting(&self) -> &TokenVesting {
+        &self.vesting
+    }
+
+    fn amount(&self) -> TokenAmount {
+        self.amount
+    }
+}
@@ -0,0 +1,43 @@
+use derive_more::From;
+use dpp::tokens::order_book::TokenOrder;
+
+/// transformer module for token place order transition action
+pub mod transformer;
+mod v0;
+
+pub use v0::*;
+
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionAction;
+
+/// Token place order transition action
+#[derive(Debug, Clone, From)]
+pub enum TokenPlaceOrderTransitionAction {
+    /// v0
+    V0(TokenPlaceOrderTransitionActionV0),
+}
+
+impl TokenPlaceOrderTransitionActionAccessorsV0 for TokenPlaceOrderTransitionAction {
+    fn base(&self) -> &TokenBaseTransitionAction {
+        match self {
+            TokenPlaceOrderTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> TokenBaseTransitionAction {
+        match self {
+            TokenPlaceOrderTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn order(&self) -> &TokenOrder {
+        match self {
+            TokenPlaceOrderTransitionAction::V0(v0) => &v0.order,
+        }
+    }
+
+    fn fills(&self) -> &[TokenOrderFill] {
+        match self {
+            TokenPlaceOrderTransitionAction::V0(v0) => &v0.fills,
+        }
+    }
+}
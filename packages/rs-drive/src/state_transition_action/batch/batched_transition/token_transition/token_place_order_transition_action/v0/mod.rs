@@ -0,0 +1,119 @@
+mod transformer;
+
+use std::sync::Arc;
+use dpp::balances::credits::TokenAmount;
+use dpp::identifier::Identifier;
+use dpp::tokens::order_book::TokenOrder;
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+use dpp::ProtocolError;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::{TokenBaseTransitionAction, TokenBaseTransitionActionAccessorsV0};
+
+/// A resting order matched by a newly placed order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenOrderFill {
+    /// The resting order as it was before being matched
+    pub maker_order: TokenOrder,
+    /// The amount of tokens traded, at the price of the resting order
+    pub amount: TokenAmount,
+}
+
+impl TokenOrderFill {
+    /// The amount of the quote currency paid for the traded tokens
+    pub fn quote_amount(&self) -> Result<u64, ProtocolError> {
+        self.amount
+            .checked_mul(self.maker_order.price_per_token())
+            .ok_or(ProtocolError::Overflow(
+                "token order fill quote amount overflow",
+            ))
+    }
+}
+
+/// Token place order transition action v0
+#[derive(Debug, Clone)]
+pub struct TokenPlaceOrderTransitionActionV0 {
+    /// Base token transition action
+    pub base: TokenBaseTransitionAction,
+    /// The placed order, its remaining amount is what is left to rest on the book after matching
+    pub order: TokenOrder,
+    /// The resting orders the placed order was matched against, in price-time priority
+    pub fills: Vec<TokenOrderFill>,
+}
+
+/// Accessors for `TokenPlaceOrderTransitionActionV0`
+pub trait TokenPlaceOrderTransitionActionAccessorsV0 {
+    /// Returns a reference to the base token transition action
+    fn base(&self) -> &TokenBaseTransitionAction;
+
+    /// Consumes self and returns the base token transition action
+    fn base_owned(self) -> TokenBaseTransitionAction;
+
+    /// Returns the placed order
+    fn order(&self) -> &TokenOrder;
+
+    /// Returns the resting orders that were matched
+    fn fills(&self) -> &[TokenOrderFill];
+
+    /// Returns the amount of tokens that were traded immediately
+    fn filled_amount(&self) -> TokenAmount {
+        self.fills().iter().map(|fill| fill.amount).sum()
+    }
+
+    /// Returns the amount of the quote currency the placing identity pays when buying, the
+    /// price of all fills plus the escrow of the rest of the order at its limit price
+    fn required_quote_amount(&self) -> Result<u64, ProtocolError> {
+        let escrow = self
+            .order()
+            .remaining_amount()
+            .checked_mul(self.order().price_per_token())
+            .ok_or(ProtocolError::Overflow("token order escrow overflow"))?;
+        self.fills().iter().try_fold(escrow, |total, fill| {
+            total
+                .checked_add(fill.quote_amount()?)
+                .ok_or(ProtocolError::Overflow("token order quote amount overflow"))
+        })
+    }
+
+    /// Returns the token position in the contract
+    fn token_position(&self) -> u16 {
+        self.base().token_position()
+    }
+
+    /// Returns the token ID
+    fn token_id(&self) -> Identifier {
+        self.base().token_id()
+    }
+
+    /// Returns the data contract ID
+    fn data_contract_id(&self) -> Identifier {
+        self.base().data_contract_id()
+    }
+
+    /// Returns a reference to the data contract fetch info
+    fn data_contract_fetch_info_ref(&self) -> &Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info_ref()
+    }
+
+    /// Returns the data contract fetch info
+    fn data_contract_fetch_info(&self) -> Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info()
+    }
+}
+
+impl TokenPlaceOrderTransitionActionAccessorsV0 for TokenPlaceOrderTransitionActionV0 {
+    fn base(&self) -> &TokenBaseTransitionAction {
+        &self.base
+    }
+
+    fn base_owned(self) -> TokenBaseTransitionAction {
+        self.base
+    }
+
+    fn order(&self) -> &TokenOrder {
+        &self.order
+    }
+
+    fn fills(&self) -> &[TokenOrderFill] {
+        &self.fills
+    }
+}
@@ -0,0 +1,224 @@
+use std::sync::Arc;
+use grovedb::TransactionArg;
+use dpp::block::block_info::BlockInfo;
+use dpp::identifier::Identifier;
+use dpp::state_transition::batch_transition::token_place_order_transition::v0::TokenPlaceOrderTransitionV0;
+use dpp::consensus::ConsensusError;
+use dpp::consensus::state::token::{TokenOrderFillLimitExceededError, TokenOrderSelfTradeError};
+use dpp::ProtocolError;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionAction;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_place_order_transition_action::v0::{TokenOrderFill, TokenPlaceOrderTransitionActionV0};
+use dpp::fee::fee_result::FeeResult;
+use dpp::prelude::{ConsensusValidationResult, UserFeeIncrease};
+use dpp::state_transition::batch_transition::token_base_transition::v0::v0_methods::TokenBaseTransitionV0Methods;
+use dpp::tokens::order_book::TokenOrder;
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
+use platform_version::version::PlatformVersion;
+use crate::drive::Drive;
+use crate::error::Error;
+use crate::state_transition_action::batch::batched_transition::token_transition::TokenTransitionAction;
+use crate::state_transition_action::batch::BatchedTransitionAction;
+use crate::state_transition_action::system::bump_identity_data_contract_nonce_action::BumpIdentityDataContractNonceAction;
+
+impl TokenPlaceOrderTransitionActionV0 {
+    /// Converts a borrowed `TokenPlaceOrderTransitionV0` into a `TokenPlaceOrderTransitionActionV0` using the provided contract lookup.
+    ///
+    /// The placed order is matched against the resting orders of the opposite side of the book
+    /// in price-time priority. Every fill trades at the price of the resting order, and at most
+    /// `max_token_order_book_fills` resting orders are matched; whatever is not filled rests on
+    /// the book at the limit price of the placed order.
+    ///
+    /// An order that would cross a resting order of its own owner, or that would still cross
+    /// the book after `max_token_order_book_fills` fills, is rejected and only the identity
+    /// contract nonce is bumped, so the book is never left crossed.
+    ///
+    /// # Arguments
+    ///
+    /// * `drive` - A reference to the `Drive` instance that handles data storage and retrieval.
+    /// * `owner_id` - The identifier of the identity placing the order.
+    /// * `value` - A reference to the `TokenPlaceOrderTransitionV0` struct containing the transition data.
+    /// * `approximate_without_state_for_costs` - A flag to indicate whether costs should be approximated without full
+    ///   state consideration.
+    /// * `transaction` - The transaction context, which includes the necessary state and other details for the transition.
+    /// * `block_info` - Information about the current block (e.g., epoch) to help calculate transaction fees.
+    /// * `user_fee_increase` - The fee increase of the state transition, used if we only bump the nonce.
+    /// * `get_data_contract` - A closure function that takes a contract identifier and returns a `DataContractFetchInfo`.
+    /// * `platform_version` - A reference to the platform version to ensure the transition respects version-specific logic.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(ConsensusValidationResult<BatchedTransitionAction>, FeeResult), Error>` - Returns a tuple containing the constructed
+    ///   action and a `FeeResult` if successful.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_from_borrowed_token_place_order_transition_with_contract_lookup(
+        drive: &Drive,
+        owner_id: Identifier,
+        value: &TokenPlaceOrderTransitionV0,
+        approximate_without_state_for_costs: bool,
+        transaction: TransactionArg,
+        block_info: &BlockInfo,
+        user_fee_increase: UserFeeIncrease,
+        get_data_contract: impl Fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>,
+        platform_version: &PlatformVersion,
+    ) -> Result<
+        (
+            ConsensusValidationResult<BatchedTransitionAction>,
+            FeeResult,
+        ),
+        Error,
+    > {
+        let TokenPlaceOrderTransitionV0 {
+            base,
+            side,
+            quote_currency,
+            amount,
+            price_per_token,
+        } = value;
+
+        let mut drive_operations = vec![];
+
+        let base_action_validation_result =
+            TokenBaseTransitionAction::try_from_borrowed_base_transition_with_contract_lookup(
+                drive,
+                owner_id,
+                base,
+                approximate_without_state_for_costs,
+                transaction,
+                &mut drive_operations,
+                get_data_contract,
+                platform_version,
+            )?;
+
+        let (base_action, _change_note) = match base_action_validation_result.is_valid() {
+            true => base_action_validation_result.into_data()?,
+            false => {
+                let fee_result = Drive::calculate_fee(
+                    None,
+                    Some(drive_operations),
+                    &block_info.epoch,
+                    drive.config.epochs_per_era,
+                    platform_version,
+                    None,
+                )?;
+
+                let bump_action =
+                    BumpIdentityDataContractNonceAction::from_borrowed_token_base_transition(
+                        base,
+                        owner_id,
+                        user_fee_increase,
+                    );
+                let batched_action =
+                    BatchedTransitionAction::BumpIdentityDataContractNonce(bump_action);
+
+                return Ok((
+                    ConsensusValidationResult::new_with_data_and_errors(
+                        batched_action,
+                        base_action_validation_result.errors,
+                    ),
+                    fee_result,
+                ));
+            }
+        };
+
+        let token_id = base.token_id();
+
+        let max_fills = platform_version.system_limits.max_token_order_book_fills;
+
+        // The book of the opposite side is sorted best price first, so we can stop matching at
+        // the first resting order whose price we do not accept. One more resting order than can
+        // be filled is fetched to know whether what is left of the order would still cross the
+        // book.
+        let resting_orders = drive.fetch_token_order_book_orders_operations(
+            token_id.to_buffer(),
+            side.opposite(),
+            quote_currency,
+            Some(max_fills.saturating_add(1)),
+            transaction,
+            &mut drive_operations,
+            platform_version,
+        )?;
+
+        let fee_result = Drive::calculate_fee(
+            None,
+            Some(drive_operations),
+            &block_info.epoch,
+            drive.config.epochs_per_era,
+            platform_version,
+            None,
+        )?;
+
+        let mut remaining_amount = *amount;
+        let mut fills = vec![];
+        let mut error: Option<ConsensusError> = None;
+
+        for resting_order in resting_orders {
+            if remaining_amount == 0
+                || !side.accepts_price(*price_per_token, resting_order.price_per_token())
+            {
+                break;
+            }
+            // An identity never trades against itself. Skipping its own resting order would
+            // leave the book crossed, so the order is rejected instead.
+            if resting_order.owner_id() == owner_id {
+                error = Some(
+                    TokenOrderSelfTradeError::new(token_id, owner_id, resting_order.id()).into(),
+                );
+                break;
+            }
+            // What is left of the order would rest on the book while crossing this order
+            if fills.len() == max_fills as usize {
+                error = Some(TokenOrderFillLimitExceededError::new(token_id, max_fills).into());
+                break;
+            }
+            let fill_amount = remaining_amount.min(resting_order.remaining_amount());
+            remaining_amount -= fill_amount;
+            fills.push(TokenOrderFill {
+                maker_order: resting_order,
+                amount: fill_amount,
+            });
+        }
+
+        if let Some(error) = error {
+            let bump_action =
+                BumpIdentityDataContractNonceAction::from_borrowed_token_base_transition(
+                    base,
+                    owner_id,
+                    user_fee_increase,
+                );
+            let batched_action =
+                BatchedTransitionAction::BumpIdentityDataContractNonce(bump_action);
+
+            return Ok((
+                ConsensusValidationResult::new_with_data_and_errors(batched_action, vec![error]),
+                fee_result,
+            ));
+        }
+
+        let mut order = TokenOrder::new(
+            TokenOrder::calculate_order_id(&owner_id, &token_id, base.identity_contract_nonce()),
+            owner_id,
+            token_id,
+            *side,
+            *quote_currency,
+            *price_per_token,
+            *amount,
+            block_info.time_ms,
+            platform_version,
+        )?;
+        order.set_remaining_amount(remaining_amount);
+
+        Ok((
+            BatchedTransitionAction::TokenAction(TokenTransitionAction::PlaceOrderAction(
+                TokenPlaceOrderTransitionActionV0 {
+                    base: base_action,
+                    order,
+                    fills,
+                }
+                .into(),
+            ))
+            .into(),
+            fee_result,
+        ))
+    }
+}
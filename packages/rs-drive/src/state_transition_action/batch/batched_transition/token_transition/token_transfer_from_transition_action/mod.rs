@@ -0,0 +1,57 @@
+use derive_more::From;
+use dpp::balances::credits::TokenAmount;
+use dpp::identifier::Identifier;
+use dpp::tokens::allowance::TokenAllowance;
+
+/// transformer module for token transfer from transition action
+pub mod transformer;
+mod v0;
+
+pub use v0::*;
+
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionAction;
+
+/// Token transfer from transition action
+#[derive(Debug, Clone, From)]
+pub enum TokenTransferFromTransitionAction {
+    /// v0
+    V0(TokenTransferFromTransitionActionV0),
+}
+
+impl TokenTransferFromTransitionActionAccessorsV0 for TokenTransferFromTransitionAction {
+    fn base(&self) -> &TokenBaseTransitionAction {
+        match self {
+            TokenTransferFromTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> TokenBaseTransitionAction {
+        match self {
+            TokenTransferFromTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn allowance(&self) -> &TokenAllowance {
+        match self {
+            TokenTransferFromTransitionAction::V0(v0) => &v0.allowance,
+        }
+    }
+
+    fn recipient_id(&self) -> Identifier {
+        match self {
+            TokenTransferFromTransitionAction::V0(v0) => v0.recipient_id,
+        }
+    }
+
+    fn amount(&self) -> TokenAmount {
+        match self {
+            TokenTransferFromTransitionAction::V0(v0) => v0.amount,
+        }
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        match self {
+            TokenTransferFromTransitionAction::V0(v0) => v0.public_note.as_ref(),
+        }
+    }
+}
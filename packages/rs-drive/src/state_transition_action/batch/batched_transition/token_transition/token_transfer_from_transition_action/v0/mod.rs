@@ -0,0 +1,119 @@
+mod transformer;
+
+use std::sync::Arc;
+use dpp::balances::credits::TokenAmount;
+use dpp::identifier::Identifier;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
+use dpp::tokens::allowance::v0::TokenAllowanceV0Accessors;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::{TokenBaseTransitionAction, TokenBaseTransitionActionAccessorsV0};
+
+/// Token transfer from transition action v0
+#[derive(Debug, Clone)]
+pub struct TokenTransferFromTransitionActionV0 {
+    /// Base token transition action
+    pub base: TokenBaseTransitionAction,
+    /// The allowance spent from, as it was before the transfer
+    pub allowance: TokenAllowance,
+    /// The identity receiving the tokens
+    pub recipient_id: Identifier,
+    /// The amount of tokens transferred
+    pub amount: TokenAmount,
+    /// A public note
+    pub public_note: Option<String>,
+}
+
+/// Accessors for `TokenTransferFromTransitionActionV0`
+pub trait TokenTransferFromTransitionActionAccessorsV0 {
+    /// Returns a reference to the base token transition action
+    fn base(&self) -> &TokenBaseTransitionAction;
+
+    /// Consumes self and returns the base token transition action
+    fn base_owned(self) -> TokenBaseTransitionAction;
+
+    /// Returns the allowance spent from, as it was before the transfer
+    fn allowance(&self) -> &TokenAllowance;
+
+    /// Returns the identity receiving the tokens
+    fn recipient_id(&self) -> Identifier;
+
+    /// Returns the amount of tokens transferred
+    fn amount(&self) -> TokenAmount;
+
+    /// Returns the public note
+    fn public_note(&self) -> Option<&String>;
+
+    /// Returns the identity the tokens are taken from
+    fn from_identity_id(&self) -> Identifier {
+        self.allowance().owner_id()
+    }
+
+    /// Returns the identity or group spending the allowance
+    fn spender(&self) -> TokenAllowanceSpender {
+        self.allowance().spender()
+    }
+
+    /// Returns the allowance spent from with the transferred amount deducted, or `None` if
+    /// nothing remains of it
+    fn remaining_allowance(&self) -> Option<TokenAllowance> {
+        let remaining = self.allowance().amount().saturating_sub(self.amount());
+        if remaining == 0 {
+            return None;
+        }
+        let mut allowance = self.allowance().clone();
+        allowance.set_amount(remaining);
+        Some(allowance)
+    }
+
+    /// Returns the token position in the contract
+    fn token_position(&self) -> u16 {
+        self.base().token_position()
+    }
+
+    /// Returns the token ID
+    fn token_id(&self) -> Identifier {
+        self.base().token_id()
+    }
+
+    /// Returns the data contract ID
+    fn data_contract_id(&self) -> Identifier {
+        self.base().data_contract_id()
+    }
+
+    /// Returns a reference to the data contract fetch info
+    fn data_contract_fetch_info_ref(&self) -> &Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info_ref()
+    }
+
+    /// Returns the data contract fetch info
+    fn data_contract_fetch_info(&self) -> Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info()
+    }
+}
+
+impl TokenTransferFromTransitionActionAccessorsV0 for TokenTransferFromTransitionActionV0 {
+    fn base(&self) -> &TokenBaseTransitionAction {
+        &self.base
+    }
+
+    fn base_owned(self) -> TokenBaseTransitionAction {
+        self.base
+    }
+
+    fn allowance(&self) -> &TokenAllowance {
+        &self.allowance
+    }
+
+    fn recipient_id(&self) -> Identifier {
+        self.recipient_id
+    }
+
+    fn amount(&self) -> TokenAmount {
+        self.amount
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        self.public_note.as_ref()
+    }
+}
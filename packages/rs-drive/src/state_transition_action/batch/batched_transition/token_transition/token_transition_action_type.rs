@@ -25,6 +25,14 @@ impl TokenTransitionActionTypeGetter for TokenTransitionAction {
             TokenTransitionAction::SetPriceForDirectPurchaseAction(_) => {
                 TokenTransitionActionType::SetPriceForDirectPurchase
             }
+            TokenTransitionAction::PlaceOrderAction(_) => TokenTransitionActionType::PlaceOrder,
+            TokenTransitionAction::CancelOrderAction(_) => TokenTransitionActionType::CancelOrder,
+            TokenTransitionAction::VestingTransferAction(_) => {
+                TokenTransitionActionType::VestingTransfer
+            }
+            TokenTransitionAction::ClaimVestedAction(_) => TokenTransitionActionType::ClaimVested,
+            TokenTransitionAction::ApproveAction(_) => TokenTransitionActionType::Approve,
+            TokenTransitionAction::TransferFromAction(_) => TokenTransitionActionType::TransferFrom,
         }
     }
 }
@@ -0,0 +1,43 @@
+use derive_more::From;
+use dpp::tokens::vesting::TokenVesting;
+
+/// transformer module for token vesting transfer transition action
+pub mod transformer;
+mod v0;
+
+pub use v0::*;
+
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::TokenBaseTransitionAction;
+
+/// Token vesting transfer transition action
+#[derive(Debug, Clone, From)]
+pub enum TokenVestingTransferTransitionAction {
+    /// v0
+    V0(TokenVestingTransferTransitionActionV0),
+}
+
+impl TokenVestingTransferTransitionActionAccessorsV0 for TokenVestingTransferTransitionAction {
+    fn base(&self) -> &TokenBaseTransitionAction {
+        match self {
+            TokenVestingTransferTransitionAction::V0(v0) => &v0.base,
+        }
+    }
+
+    fn base_owned(self) -> TokenBaseTransitionAction {
+        match self {
+            TokenVestingTransferTransitionAction::V0(v0) => v0.base,
+        }
+    }
+
+    fn vesting(&self) -> &TokenVesting {
+        match self {
+            TokenVestingTransferTransitionAction::V0(v0) => &v0.vesting,
+        }
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        match self {
+            TokenVestingTransferTransitionAction::V0(v0) => v0.public_note.as_ref(),
+        }
+    }
+}
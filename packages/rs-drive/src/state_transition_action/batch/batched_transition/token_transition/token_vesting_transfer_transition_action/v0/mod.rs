@@ -0,0 +1,88 @@
+mod transformer;
+
+use std::sync::Arc;
+use dpp::balances::credits::TokenAmount;
+use dpp::identifier::Identifier;
+use dpp::tokens::vesting::TokenVesting;
+use dpp::tokens::vesting::v0::TokenVestingV0Accessors;
+use crate::drive::contract::DataContractFetchInfo;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_base_transition_action::{TokenBaseTransitionAction, TokenBaseTransitionActionAccessorsV0};
+
+/// Token vesting transfer transition action v0
+#[derive(Debug, Clone)]
+pub struct TokenVestingTransferTransitionActionV0 {
+    /// Base token transition action
+    pub base: TokenBaseTransitionAction,
+    /// The vesting created for the recipient, nothing of it has been claimed yet
+    pub vesting: TokenVesting,
+    /// A public note
+    pub public_note: Option<String>,
+}
+
+/// Accessors for `TokenVestingTransferTransitionActionV0`
+pub trait TokenVestingTransferTransitionActionAccessorsV0 {
+    /// Returns a reference to the base token transition action
+    fn base(&self) -> &TokenBaseTransitionAction;
+
+    /// Consumes self and returns the base token transition action
+    fn base_owned(self) -> TokenBaseTransitionAction;
+
+    /// Returns the created vesting
+    fn vesting(&self) -> &TokenVesting;
+
+    /// Returns the public note
+    fn public_note(&self) -> Option<&String>;
+
+    /// Returns the identity the tokens are locked for
+    fn recipient_id(&self) -> Identifier {
+        self.vesting().recipient_id()
+    }
+
+    /// Returns the amount of tokens locked
+    fn amount(&self) -> TokenAmount {
+        self.vesting().total_amount()
+    }
+
+    /// Returns the token position in the contract
+    fn token_position(&self) -> u16 {
+        self.base().token_position()
+    }
+
+    /// Returns the token ID
+    fn token_id(&self) -> Identifier {
+        self.base().token_id()
+    }
+
+    /// Returns the data contract ID
+    fn data_contract_id(&self) -> Identifier {
+        self.base().data_contract_id()
+    }
+
+    /// Returns a reference to the data contract fetch info
+    fn data_contract_fetch_info_ref(&self) -> &Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info_ref()
+    }
+
+    /// Returns the data contract fetch info
+    fn data_contract_fetch_info(&self) -> Arc<DataContractFetchInfo> {
+        self.base().data_contract_fetch_info()
+    }
+}
+
+impl TokenVestingTransferTransitionActionAccessorsV0 for TokenVestingTransferTransitionActionV0 {
+    fn base(&self) -> &TokenBaseTransitionAction {
+        &self.base
+    }
+
+    fn base_owned(self) -> TokenBaseTransitionAction {
+        self.base
+    }
+
+    fn vesting(&self) -> &TokenVesting {
+        &self.vesting
+    }
+
+    fn public_note(&self) -> Option<&String> {
+        self.public_note.as_ref()
+    }
+}
@@ -8,6 +8,10 @@ use crate::state_transition_action::batch::batched_transition::document_transiti
 use crate::state_transition_action::batch::batched_transition::document_transition::document_purchase_transition_action::DocumentPurchaseTransitionActionAccessorsV0;
 use crate::state_transition_action::batch::batched_transition::token_transition::token_direct_purchase_transition_action::TokenDirectPurchaseTransitionActionAccessorsV0;
 use crate::state_transition_action::batch::batched_transition::token_transition::TokenTransitionAction;
+use crate::state_transition_action::batch::batched_transition::token_transition::token_place_order_transition_action::TokenPlaceOrderTransitionActionAccessorsV0;
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::TokenOrderSide;
+use dpp::tokens::order_book::v0::TokenOrderV0Accessors;
 
 /// action v0
 #[derive(Default, Debug, Clone)]
@@ -44,6 +48,15 @@ impl BatchTransitionActionV0 {
                 BatchedTransitionAction::TokenAction(
                     TokenTransitionAction::DirectPurchaseAction(token_purchase),
                 ) => Some(token_purchase.total_agreed_price()),
+                // Buying on the order book with Dash takes the credits out of the balance
+                BatchedTransitionAction::TokenAction(TokenTransitionAction::PlaceOrderAction(
+                    place_order,
+                )) if place_order.order().side() == TokenOrderSide::Buy
+                    && place_order.order().quote_currency() == AllowedCurrency::TradingInDash =>
+                {
+                    // An overflow can never be paid for
+                    Some(place_order.required_quote_amount().unwrap_or(Credits::MAX))
+                }
                 _ => None,
             })
             .fold((None, false), |(acc, _), price| match acc {
@@ -0,0 +1,47 @@
+/// transformer
+pub mod transformer;
+/// v0
+pub mod v0;
+
+use crate::state_transition_action::contract::data_contract_delete::v0::DataContractDeleteTransitionActionV0;
+use derive_more::From;
+use dpp::data_contract::DataContract;
+use dpp::prelude::{IdentityNonce, UserFeeIncrease};
+
+/// data contract delete transition action
+#[derive(Debug, Clone, From)]
+pub enum DataContractDeleteTransitionAction {
+    /// v0
+    V0(DataContractDeleteTransitionActionV0),
+}
+
+impl DataContractDeleteTransitionAction {
+    /// data contract being deleted
+    pub fn data_contract(self) -> DataContract {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => transition.data_contract,
+        }
+    }
+    /// data contract being deleted ref
+    pub fn data_contract_ref(&self) -> &DataContract {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => &transition.data_contract,
+        }
+    }
+
+    /// identity contract nonce
+    pub fn identity_contract_nonce(&self) -> IdentityNonce {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => {
+                transition.identity_contract_nonce
+            }
+        }
+    }
+
+    /// fee multiplier
+    pub fn user_fee_increase(&self) -> UserFeeIncrease {
+        match self {
+            DataContractDeleteTransitionAction::V0(transition) => transition.user_fee_increase,
+        }
+    }
+}
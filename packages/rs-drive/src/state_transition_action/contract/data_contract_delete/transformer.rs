@@ -0,0 +1,20 @@
+use crate::state_transition_action::contract::data_contract_delete::v0::DataContractDeleteTransitionActionV0;
+use crate::state_transition_action::contract::data_contract_delete::DataContractDeleteTransitionAction;
+use dpp::data_contract::DataContract;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
+
+impl DataContractDeleteTransitionAction {
+    /// transforms the borrowed DataContractDeleteTransition into a DataContractDeleteTransitionAction
+    /// the data contract must have been fetched and validated as deletable beforehand
+    pub fn from_borrowed_transition(
+        value: &DataContractDeleteTransition,
+        data_contract: DataContract,
+    ) -> Self {
+        match value {
+            DataContractDeleteTransition::V0(v0) => {
+                DataContractDeleteTransitionActionV0::from_borrowed_transition(v0, data_contract)
+                    .into()
+            }
+        }
+    }
+}
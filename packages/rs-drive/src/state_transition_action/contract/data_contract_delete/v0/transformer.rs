@@ -0,0 +1,16 @@
+use crate::state_transition_action::contract::data_contract_delete::v0::DataContractDeleteTransitionActionV0;
+use dpp::data_contract::DataContract;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransitionV0;
+
+impl DataContractDeleteTransitionActionV0 {
+    pub(in crate::state_transition_action::contract::data_contract_delete) fn from_borrowed_transition(
+        value: &DataContractDeleteTransitionV0,
+        data_contract: DataContract,
+    ) -> Self {
+        DataContractDeleteTransitionActionV0 {
+            data_contract,
+            identity_contract_nonce: value.identity_contract_nonce,
+            user_fee_increase: value.user_fee_increase,
+        }
+    }
+}
@@ -1,4 +1,6 @@
 /// create
 pub mod data_contract_create;
+/// delete
+pub mod data_contract_delete;
 /// update
 pub mod data_contract_update;
@@ -10,3 +10,5 @@ pub mod identity_topup;
 pub mod identity_update;
 /// masternode votes
 pub mod masternode_vote;
+/// vote poll creation
+pub mod vote_poll_create;
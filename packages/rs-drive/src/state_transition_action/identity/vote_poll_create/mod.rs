@@ -0,0 +1,66 @@
+/// transformer
+pub mod transformer;
+/// v0
+pub mod v0;
+
+use crate::state_transition_action::identity::vote_poll_create::v0::VotePollCreateTransitionActionV0;
+use derive_more::From;
+use dpp::platform_value::Identifier;
+use dpp::prelude::{IdentityNonce, UserFeeIncrease};
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use std::collections::BTreeMap;
+
+/// vote poll create transition action
+#[derive(Debug, Clone, From)]
+pub enum VotePollCreateTransitionAction {
+    /// v0
+    V0(VotePollCreateTransitionActionV0),
+}
+
+impl VotePollCreateTransitionAction {
+    /// the identity creating the poll
+    pub fn identity_id(&self) -> Identifier {
+        match self {
+            VotePollCreateTransitionAction::V0(transition) => transition.identity_id,
+        }
+    }
+
+    /// the vote poll being created
+    pub fn vote_poll(&self) -> &YesNoAbstainVotePoll {
+        match self {
+            VotePollCreateTransitionAction::V0(transition) => &transition.vote_poll,
+        }
+    }
+
+    /// the masternodes allowed to vote on the poll with their vote strength
+    pub fn eligible_voters(&self) -> &BTreeMap<[u8; 32], u8> {
+        match self {
+            VotePollCreateTransitionAction::V0(transition) => &transition.eligible_voters,
+        }
+    }
+
+    /// the vote poll and the eligible voters as owned
+    pub fn vote_poll_and_eligible_voters_owned(
+        self,
+    ) -> (YesNoAbstainVotePoll, BTreeMap<[u8; 32], u8>) {
+        match self {
+            VotePollCreateTransitionAction::V0(transition) => {
+                (transition.vote_poll, transition.eligible_voters)
+            }
+        }
+    }
+
+    /// identity nonce
+    pub fn nonce(&self) -> IdentityNonce {
+        match self {
+            VotePollCreateTransitionAction::V0(transition) => transition.nonce,
+        }
+    }
+
+    /// fee multiplier
+    pub fn user_fee_increase(&self) -> UserFeeIncrease {
+        match self {
+            VotePollCreateTransitionAction::V0(transition) => transition.user_fee_increase,
+        }
+    }
+}
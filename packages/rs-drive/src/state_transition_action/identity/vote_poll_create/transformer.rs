@@ -0,0 +1,20 @@
+use crate::state_transition_action::identity::vote_poll_create::v0::VotePollCreateTransitionActionV0;
+use crate::state_transition_action::identity::vote_poll_create::VotePollCreateTransitionAction;
+use dpp::state_transition::vote_poll_create_transition::VotePollCreateTransition;
+use std::collections::BTreeMap;
+
+impl VotePollCreateTransitionAction {
+    /// transforms the borrowed VotePollCreateTransition into a VotePollCreateTransitionAction
+    /// the eligible voters are the masternodes of the current masternode list with their strength
+    pub fn from_borrowed_transition(
+        value: &VotePollCreateTransition,
+        eligible_voters: BTreeMap<[u8; 32], u8>,
+    ) -> Self {
+        match value {
+            VotePollCreateTransition::V0(v0) => {
+                VotePollCreateTransitionActionV0::from_borrowed_transition(v0, eligible_voters)
+                    .into()
+            }
+        }
+    }
+}
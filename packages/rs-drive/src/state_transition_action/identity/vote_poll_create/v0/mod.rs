@@ -0,0 +1,23 @@
+/// transformer
+pub mod transformer;
+
+use dpp::platform_value::Identifier;
+use dpp::prelude::{IdentityNonce, UserFeeIncrease};
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
+use std::collections::BTreeMap;
+
+/// vote poll create transition action v0
+#[derive(Debug, Clone)]
+pub struct VotePollCreateTransitionActionV0 {
+    /// the identity creating the poll
+    pub identity_id: Identifier,
+    /// the vote poll being created
+    pub vote_poll: YesNoAbstainVotePoll,
+    /// the masternodes allowed to vote on the poll with their vote strength, taken from the
+    /// masternode list at creation
+    pub eligible_voters: BTreeMap<[u8; 32], u8>,
+    /// identity nonce
+    pub nonce: IdentityNonce,
+    /// fee multiplier
+    pub user_fee_increase: UserFeeIncrease,
+}
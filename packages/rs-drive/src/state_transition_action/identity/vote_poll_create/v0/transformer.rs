@@ -0,0 +1,18 @@
+use crate::state_transition_action::identity::vote_poll_create::v0::VotePollCreateTransitionActionV0;
+use dpp::state_transition::vote_poll_create_transition::VotePollCreateTransitionV0;
+use std::collections::BTreeMap;
+
+impl VotePollCreateTransitionActionV0 {
+    pub(in crate::state_transition_action::identity::vote_poll_create) fn from_borrowed_transition(
+        value: &VotePollCreateTransitionV0,
+        eligible_voters: BTreeMap<[u8; 32], u8>,
+    ) -> Self {
+        VotePollCreateTransitionActionV0 {
+            identity_id: value.identity_id,
+            vote_poll: value.vote_poll.clone(),
+            eligible_voters,
+            nonce: value.nonce,
+            user_fee_increase: value.user_fee_increase,
+        }
+    }
+}
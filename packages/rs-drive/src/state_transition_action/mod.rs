@@ -12,6 +12,7 @@ pub mod batch;
 
 use crate::state_transition_action::batch::BatchTransitionAction;
 use crate::state_transition_action::contract::data_contract_create::DataContractCreateTransitionAction;
+use crate::state_transition_action::contract::data_contract_delete::DataContractDeleteTransitionAction;
 use crate::state_transition_action::contract::data_contract_update::DataContractUpdateTransitionAction;
 use crate::state_transition_action::identity::identity_create::IdentityCreateTransitionAction;
 use crate::state_transition_action::identity::identity_credit_transfer::IdentityCreditTransferTransitionAction;
@@ -19,6 +20,7 @@ use crate::state_transition_action::identity::identity_credit_withdrawal::Identi
 use crate::state_transition_action::identity::identity_topup::IdentityTopUpTransitionAction;
 use crate::state_transition_action::identity::identity_update::IdentityUpdateTransitionAction;
 use crate::state_transition_action::identity::masternode_vote::MasternodeVoteTransitionAction;
+use crate::state_transition_action::identity::vote_poll_create::VotePollCreateTransitionAction;
 use crate::state_transition_action::system::bump_identity_data_contract_nonce_action::{
     BumpIdentityDataContractNonceAction, BumpIdentityDataContractNonceActionAccessorsV0,
 };
@@ -39,6 +41,8 @@ pub enum StateTransitionAction {
     DataContractCreateAction(DataContractCreateTransitionAction),
     /// data contract update
     DataContractUpdateAction(DataContractUpdateTransitionAction),
+    /// data contract delete
+    DataContractDeleteAction(DataContractDeleteTransitionAction),
     /// batch
     BatchAction(BatchTransitionAction),
     /// identity create
@@ -53,6 +57,8 @@ pub enum StateTransitionAction {
     IdentityCreditTransferAction(IdentityCreditTransferTransitionAction),
     /// masternode vote action
     MasternodeVoteAction(MasternodeVoteTransitionAction),
+    /// vote poll create action
+    VotePollCreateAction(VotePollCreateTransitionAction),
     /// bump identity nonce action
     /// this can only come in this form from identity state transitions that do not use asset locks
     /// it will also only happen if the state validation fails
@@ -72,6 +78,7 @@ impl StateTransitionAction {
         match self {
             StateTransitionAction::DataContractCreateAction(action) => action.user_fee_increase(),
             StateTransitionAction::DataContractUpdateAction(action) => action.user_fee_increase(),
+            StateTransitionAction::DataContractDeleteAction(action) => action.user_fee_increase(),
             StateTransitionAction::BatchAction(action) => action.user_fee_increase(),
             StateTransitionAction::IdentityCreateAction(action) => action.user_fee_increase(),
             StateTransitionAction::IdentityTopUpAction(action) => action.user_fee_increase(),
@@ -92,6 +99,7 @@ impl StateTransitionAction {
             StateTransitionAction::MasternodeVoteAction(_) => {
                 UserFeeIncrease::default() // 0 (or none)
             }
+            StateTransitionAction::VotePollCreateAction(action) => action.user_fee_increase(),
         }
     }
 }
@@ -5,6 +5,7 @@ use dpp::state_transition::batch_transition::batched_transition::BatchedTransiti
 use dpp::state_transition::batch_transition::batched_transition::document_transition::DocumentTransitionV0Methods;
 use dpp::state_transition::batch_transition::batched_transition::token_transition::TokenTransitionV0Methods;
 use dpp::state_transition::data_contract_update_transition::DataContractUpdateTransition;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransition;
 use dpp::state_transition::batch_transition::document_base_transition::DocumentBaseTransition;
 use dpp::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
 use crate::error::Error;
@@ -249,4 +250,15 @@ impl BumpIdentityDataContractNonceAction {
             }
         }
     }
+
+    /// from borrowed data contract delete
+    pub fn from_borrowed_data_contract_delete_transition(
+        value: &DataContractDeleteTransition,
+    ) -> Self {
+        match value {
+            DataContractDeleteTransition::V0(v0) => {
+                BumpIdentityDataContractNonceActionV0::from_borrowed_data_contract_delete(v0).into()
+            }
+        }
+    }
 }
@@ -3,6 +3,7 @@ use dpp::platform_value::Identifier;
 use dpp::prelude::UserFeeIncrease;
 use dpp::state_transition::batch_transition::document_base_transition::DocumentBaseTransition;
 use dpp::state_transition::data_contract_update_transition::DataContractUpdateTransitionV0;
+use dpp::state_transition::data_contract_delete_transition::DataContractDeleteTransitionV0;
 use dpp::state_transition::batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
 use dpp::state_transition::batch_transition::token_base_transition::TokenBaseTransition;
 use dpp::state_transition::batch_transition::token_base_transition::v0::v0_methods::TokenBaseTransitionV0Methods;
@@ -189,4 +190,21 @@ impl BumpIdentityDataContractNonceActionV0 {
             user_fee_increase: *user_fee_increase,
         }
     }
+
+    /// from borrowed data contract delete
+    pub fn from_borrowed_data_contract_delete(value: &DataContractDeleteTransitionV0) -> Self {
+        let DataContractDeleteTransitionV0 {
+            owner_id,
+            data_contract_id,
+            identity_contract_nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        BumpIdentityDataContractNonceActionV0 {
+            identity_id: *owner_id,
+            data_contract_id: *data_contract_id,
+            identity_contract_nonce: *identity_contract_nonce,
+            user_fee_increase: *user_fee_increase,
+        }
+    }
 }
@@ -9,6 +9,7 @@ use dpp::state_transition::data_contract_create_transition::DataContractCreateTr
 use dpp::state_transition::identity_credit_transfer_transition::IdentityCreditTransferTransition;
 use dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
 use dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
+use dpp::state_transition::vote_poll_create_transition::VotePollCreateTransition;
 
 impl BumpIdentityNonceAction {
     /// from identity update
@@ -169,4 +170,13 @@ impl BumpIdentityNonceAction {
             }
         }
     }
+
+    /// from borrowed vote poll create transition
+    pub fn from_borrowed_vote_poll_create_transition(value: &VotePollCreateTransition) -> Self {
+        match value {
+            VotePollCreateTransition::V0(v0) => {
+                BumpIdentityNonceActionV0::from_borrowed_vote_poll_create(v0).into()
+            }
+        }
+    }
 }
@@ -9,6 +9,7 @@ use dpp::state_transition::identity_credit_transfer_transition::v0::IdentityCred
 use dpp::state_transition::identity_credit_withdrawal_transition::accessors::IdentityCreditWithdrawalTransitionAccessorsV0;
 use dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
 use dpp::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
+use dpp::state_transition::vote_poll_create_transition::VotePollCreateTransitionV0;
 use dpp::state_transition::StateTransitionLike;
 
 impl BumpIdentityNonceActionV0 {
@@ -253,4 +254,19 @@ impl BumpIdentityNonceActionV0 {
             user_fee_increase: *user_fee_increase,
         }
     }
+
+    /// from borrowed vote poll create
+    pub fn from_borrowed_vote_poll_create(value: &VotePollCreateTransitionV0) -> Self {
+        let VotePollCreateTransitionV0 {
+            identity_id,
+            nonce,
+            user_fee_increase,
+            ..
+        } = value;
+        BumpIdentityNonceActionV0 {
+            identity_id: *identity_id,
+            identity_nonce: *nonce,
+            user_fee_increase: *user_fee_increase,
+        }
+    }
 }
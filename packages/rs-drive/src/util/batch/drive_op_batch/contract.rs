@@ -44,6 +44,11 @@ pub enum DataContractOperationType<'a> {
         /// Storage flags for the contract
         storage_flags: Option<Cow<'a, StorageFlags>>,
     },
+    /// Deletes a contract along with its empty document trees.
+    DeleteContract {
+        /// The contract
+        contract: Cow<'a, DataContract>,
+    },
 }
 
 impl DriveLowLevelOperationConverter for DataContractOperationType<'_> {
@@ -82,6 +87,14 @@ impl DriveLowLevelOperationConverter for DataContractOperationType<'_> {
                 transaction,
                 platform_version,
             ),
+            DataContractOperationType::DeleteContract { contract } => drive
+                .delete_contract_operations(
+                    contract.borrow(),
+                    block_info,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                ),
         }
     }
 }
@@ -113,7 +126,8 @@ impl DataContractOperationType<'_> {
     fn finalization_tasks_v0(&self) -> Result<Option<Vec<DriveOperationFinalizeTask>>, Error> {
         let tasks = match self {
             Self::ApplyContractWithSerialization { contract, .. }
-            | Self::ApplyContract { contract, .. } => {
+            | Self::ApplyContract { contract, .. }
+            | Self::DeleteContract { contract } => {
                 vec![DriveOperationFinalizeTask::RemoveDataContractFromCache {
                     contract_id: contract.id(),
                 }]
@@ -11,9 +11,10 @@ use crate::drive::votes::resolved::votes::ResolvedVote;
 use crate::state_transition_action::identity::masternode_vote::v0::PreviousVoteCount;
 use dpp::version::PlatformVersion;
 use dpp::voting::vote_choices::resource_vote_choice::ResourceVoteChoice;
+use dpp::voting::vote_polls::yes_no_abstain_vote_poll::YesNoAbstainVotePoll;
 use grovedb::batch::KeyInfoPath;
 use grovedb::{EstimatedLayerInformation, TransactionArg};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Operations on Identities
 #[allow(clippy::large_enum_variant)]
@@ -89,6 +90,15 @@ pub enum IdentityOperationType {
         /// Remove previous contested resource vote choice
         previous_resource_vote_choice_to_remove: Option<(ResourceVoteChoice, PreviousVoteCount)>,
     },
+    /// Creates a yes/no/abstain vote poll paid by an identity.
+    CreateYesNoAbstainVotePoll {
+        /// The identity creating the poll
+        creator_identity_id: [u8; 32],
+        /// The poll to create
+        vote_poll: YesNoAbstainVotePoll,
+        /// The masternodes allowed to vote on the poll with their vote strength
+        eligible_voters: BTreeMap<[u8; 32], u8>,
+    },
     /// Updates an identities nonce for a specific contract.
     UpdateIdentityNonce {
         /// The revision id
@@ -204,17 +214,31 @@ impl DriveLowLevelOperationConverter for IdentityOperationType {
                 vote,
                 previous_resource_vote_choice_to_remove,
             } => {
-                // No need to have estimated_costs_only_with_layer_info and block_info here
+                // No need to have estimated_costs_only_with_layer_info here
                 // This is because voting is a special operation with a fixed cost
                 drive.register_identity_vote_operations(
                     voter_pro_tx_hash,
                     strength,
                     vote,
                     previous_resource_vote_choice_to_remove,
+                    block_info,
                     transaction,
                     platform_version,
                 )
             }
+            IdentityOperationType::CreateYesNoAbstainVotePoll {
+                creator_identity_id,
+                vote_poll,
+                eligible_voters,
+            } => drive.create_yes_no_abstain_vote_poll_operations(
+                creator_identity_id,
+                vote_poll,
+                eligible_voters,
+                block_info,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
             IdentityOperationType::UpdateIdentityContractNonce {
                 identity_id,
                 contract_id,
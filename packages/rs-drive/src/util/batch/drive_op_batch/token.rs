@@ -2,16 +2,19 @@ use crate::drive::Drive;
 use crate::error::Error;
 use crate::fees::op::LowLevelDriveOperation;
 use crate::util::batch::drive_op_batch::DriveLowLevelOperationConverter;
-use dpp::balances::credits::TokenAmount;
+use dpp::balances::credits::{SumTokenAmount, TokenAmount};
 use dpp::block::block_info::BlockInfo;
 use dpp::identifier::Identifier;
 use dpp::prelude::{IdentityNonce, TimestampMillis};
+use dpp::tokens::order_book::TokenOrder;
 use dpp::tokens::status::TokenStatus;
 use dpp::tokens::token_event::TokenEvent;
+use dpp::tokens::allowance::TokenAllowance;
+use dpp::tokens::vesting::TokenVesting;
 use grovedb::batch::KeyInfoPath;
 use grovedb::{EstimatedLayerInformation, TransactionArg};
 use platform_version::version::PlatformVersion;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use dpp::data_contract::associated_token::token_perpetual_distribution::reward_distribution_moment::RewardDistributionMoment;
 use dpp::tokens::token_pricing_schedule::TokenPricingSchedule;
 
@@ -124,6 +127,59 @@ pub enum TokenOperationType {
         /// None means it's not currently for sale
         price: Option<TokenPricingSchedule>,
     },
+    /// Rests an order on the order book of its token
+    TokenInsertOrder {
+        /// The order
+        order: TokenOrder,
+    },
+    /// Replaces a resting order after it was partially filled
+    TokenUpdateOrder {
+        /// The order with its new remaining amount
+        order: TokenOrder,
+    },
+    /// Takes an order off the order book of its token
+    TokenRemoveOrder {
+        /// The order
+        order: TokenOrder,
+    },
+    /// Changes the balances of many identities of a token at once, by their net change.
+    /// The changes must sum up to zero.
+    TokenApplyBalanceChanges {
+        /// The token id
+        token_id: Identifier,
+        /// The net change of the balance of each identity
+        balance_changes: BTreeMap<Identifier, SumTokenAmount>,
+    },
+    /// Stores a new vesting of a token for its recipient
+    TokenInsertVesting {
+        /// The vesting
+        vesting: TokenVesting,
+    },
+    /// Replaces a vesting after part of it was claimed
+    TokenUpdateVesting {
+        /// The vesting with its new claimed amount
+        vesting: TokenVesting,
+    },
+    /// Deletes a vesting once all of its tokens were claimed
+    TokenRemoveVesting {
+        /// The vesting
+        vesting: TokenVesting,
+    },
+    /// Stores a new allowance of a token owner for a spender
+    TokenInsertAllowance {
+        /// The allowance
+        allowance: TokenAllowance,
+    },
+    /// Replaces an allowance after it was changed or partially spent
+    TokenUpdateAllowance {
+        /// The allowance with its new amount
+        allowance: TokenAllowance,
+    },
+    /// Deletes an allowance once it was revoked or fully spent
+    TokenRemoveAllowance {
+        /// The allowance
+        allowance: TokenAllowance,
+    },
 }
 
 impl DriveLowLevelOperationConverter for TokenOperationType {
@@ -307,6 +363,94 @@ impl DriveLowLevelOperationConverter for TokenOperationType {
                 )?;
                 Ok(batch_operations)
             }
+            TokenOperationType::TokenInsertOrder { order } => {
+                let batch_operations = drive.token_insert_order_operations(
+                    &order,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                )?;
+                Ok(batch_operations)
+            }
+            TokenOperationType::TokenUpdateOrder { order } => {
+                let batch_operations = drive.token_update_order_operations(
+                    &order,
+                    estimated_costs_only_with_layer_info,
+                    platform_version,
+                )?;
+                Ok(batch_operations)
+            }
+            TokenOperationType::TokenRemoveOrder { order } => {
+                let batch_operations = drive.token_remove_order_operations(
+                    &order,
+                    estimated_costs_only_with_layer_info,
+                    platform_version,
+                )?;
+                Ok(batch_operations)
+            }
+            TokenOperationType::TokenApplyBalanceChanges {
+                token_id,
+                balance_changes,
+            } => {
+                let batch_operations = drive.token_apply_balance_changes_operations(
+                    token_id.to_buffer(),
+                    balance_changes,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                )?;
+                Ok(batch_operations)
+            }
+            TokenOperationType::TokenInsertVesting { vesting } => {
+                let batch_operations = drive.token_insert_vesting_operations(
+                    &vesting,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                )?;
+                Ok(batch_operations)
+            }
+            TokenOperationType::TokenUpdateVesting { vesting } => {
+                let batch_operations = drive.token_update_vesting_operations(
+                    &vesting,
+                    estimated_costs_only_with_layer_info,
+                    platform_version,
+                )?;
+                Ok(batch_operations)
+            }
+            TokenOperationType::TokenRemoveVesting { vesting } => {
+                let batch_operations = drive.token_remove_vesting_operations(
+                    &vesting,
+                    estimated_costs_only_with_layer_info,
+                    platform_version,
+                )?;
+                Ok(batch_operations)
+            }
+            TokenOperationType::TokenInsertAllowance { allowance } => {
+                let batch_operations = drive.token_insert_allowance_operations(
+                    &allowance,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                )?;
+                Ok(batch_operations)
+            }
+            TokenOperationType::TokenUpdateAllowance { allowance } => {
+                let batch_operations = drive.token_update_allowance_operations(
+                    &allowance,
+                    estimated_costs_only_with_layer_info,
+                    platform_version,
+                )?;
+                Ok(batch_operations)
+            }
+            TokenOperationType::TokenRemoveAllowance { allowance } => {
+                let batch_operations = drive.token_remove_allowance_operations(
+                    &allowance,
+                    estimated_costs_only_with_layer_info,
+                    platform_version,
+                )?;
+                Ok(batch_operations)
+            }
         }
     }
 }
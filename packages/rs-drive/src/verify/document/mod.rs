@@ -1,3 +1,4 @@
+mod verify_aggregate_proof;
 mod verify_proof;
 mod verify_proof_keep_serialized;
 mod verify_start_at_document_in_proof;
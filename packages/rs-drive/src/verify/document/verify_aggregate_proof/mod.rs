@@ -0,0 +1,54 @@
+mod v0;
+
+use crate::error::drive::DriveError;
+use crate::verify::RootHash;
+
+use crate::error::Error;
+use crate::query::{DocumentAggregateOutcome, DriveDocumentAggregateQuery};
+
+use dpp::version::PlatformVersion;
+
+impl DriveDocumentAggregateQuery<'_> {
+    /// Verifies a proof for a range of documents and aggregates them.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - A byte slice representing the proof to be verified.
+    /// * `start_after` - The document the proved range starts after, if any.
+    /// * `limit` - The maximum number of documents in the proved range.
+    /// * `platform_version` - The platform version against which to verify the proof.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the root hash and the aggregate of the proved documents;
+    /// `next_start_after` is set when the range was full and more documents may match.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` variant if:
+    /// 1. The proof verification fails.
+    /// 2. A deserialization error occurs when parsing the serialized document(s).
+    /// 3. The aggregated property can't be summed.
+    pub fn verify_aggregate_proof(
+        &self,
+        proof: &[u8],
+        start_after: Option<[u8; 32]>,
+        limit: u16,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, DocumentAggregateOutcome), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .document
+            .verify_aggregate_proof
+        {
+            0 => self.verify_aggregate_proof_v0(proof, start_after, limit, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_aggregate_proof".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
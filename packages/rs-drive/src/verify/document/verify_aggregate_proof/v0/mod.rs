@@ -0,0 +1,22 @@
+use crate::verify::RootHash;
+
+use crate::error::Error;
+use crate::query::{DocumentAggregateOutcome, DriveDocumentAggregateQuery};
+
+use dpp::version::PlatformVersion;
+
+impl DriveDocumentAggregateQuery<'_> {
+    #[inline(always)]
+    pub(super) fn verify_aggregate_proof_v0(
+        &self,
+        proof: &[u8],
+        start_after: Option<[u8; 32]>,
+        limit: u16,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, DocumentAggregateOutcome), Error> {
+        let range_query = self.range_query(start_after, Some(limit));
+        let (root_hash, documents) = range_query.verify_proof(proof, platform_version)?;
+        let outcome = self.aggregate_documents(&documents, Some(limit))?;
+        Ok((root_hash, outcome))
+    }
+}
@@ -13,3 +13,4 @@ mod verify_identity_ids_by_unique_public_key_hashes;
 mod verify_identity_keys_by_identity_id;
 mod verify_identity_nonce;
 mod verify_identity_revision_for_identity_id;
+mod verify_identity_state_transition_records;
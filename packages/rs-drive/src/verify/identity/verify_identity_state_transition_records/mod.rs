@@ -0,0 +1,80 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::prelude::BlockHeight;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the state transitions recorded in the history of an identity.
+    ///
+    /// State transitions are proved in execution order, or its reverse; the proof covers at most
+    /// `limit` state transitions starting after `start_after`.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the state transitions.
+    /// - `identity_id`: A 32-byte array representing the identity owning the state transitions.
+    /// - `start_after`: An optional block height and index in the block the proved range starts after.
+    /// - `limit`: An optional maximum number of state transitions in the proved range.
+    /// - `order_ascending`: A boolean flag indicating whether the oldest state transitions were proved first.
+    /// - `verify_subset_of_proof`: A boolean flag indicating whether the proof being verified is a
+    ///   subset of a larger proof.
+    /// - `platform_version`: The version of the platform against which the proof is verified.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(RootHash, T), Error>`: If the verification is successful:
+    ///   - `RootHash`: The root hash of the GroveDB, representing the state of the database.
+    ///   - `T`: A collection of the proved state transition records, in the requested order.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an `Error` if any of the following occur:
+    ///
+    /// - The provided proof is invalid.
+    /// - A proved record can not be deserialized.
+    /// - The provided platform version is unknown or unsupported.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_identity_state_transition_records<
+        T: FromIterator<IdentityStateTransitionRecord>,
+    >(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        start_after: Option<(BlockHeight, u32)>,
+        limit: Option<u16>,
+        order_ascending: bool,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .identity
+            .verify_identity_state_transition_records
+        {
+            0 => Self::verify_identity_state_transition_records_v0(
+                proof,
+                identity_id,
+                start_after,
+                limit,
+                order_ascending,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_identity_state_transition_records".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::prelude::BlockHeight;
+use dpp::serialization::PlatformDeserializable;
+use grovedb::Element::Item;
+use grovedb::GroveDb;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn verify_identity_state_transition_records_v0<
+        T: FromIterator<IdentityStateTransitionRecord>,
+    >(
+        proof: &[u8],
+        identity_id: [u8; 32],
+        start_after: Option<(BlockHeight, u32)>,
+        limit: Option<u16>,
+        order_ascending: bool,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        let path_query = Self::identity_state_transition_records_query(
+            identity_id,
+            start_after,
+            limit,
+            order_ascending,
+        );
+        let (root_hash, proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query(proof, &path_query, &platform_version.drive.grove_version)?
+        } else {
+            GroveDb::verify_query(proof, &path_query, &platform_version.drive.grove_version)?
+        };
+        let records = proved_key_values
+            .into_iter()
+            .filter_map(|(_, _, element)| match element {
+                Some(Item(record_bytes, _)) => Some(
+                    IdentityStateTransitionRecord::deserialize_from_bytes(&record_bytes).map_err(
+                        |e| {
+                            Error::Proof(ProofError::CorruptedProof(format!(
+                                "could not deserialize identity state transition record: {}",
+                                e
+                            )))
+                        },
+                    ),
+                ),
+                None => None,
+                Some(element) => Some(Err(Error::Proof(ProofError::IncorrectProof(format!(
+                    "identity state transition record should be in an item, however a {} was returned",
+                    element.type_str()
+                ))))),
+            })
+            .collect::<Result<T, Error>>()?;
+        Ok((root_hash, records))
+    }
+}
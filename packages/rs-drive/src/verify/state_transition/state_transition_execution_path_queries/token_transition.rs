@@ -13,9 +13,11 @@ use dpp::prelude::DataContract;
 use dpp::state_transition::batch_transition::batched_transition::token_transition::{
     TokenTransition, TokenTransitionV0Methods,
 };
+use dpp::state_transition::batch_transition::token_approve_transition::v0::v0_methods::TokenApproveTransitionV0Methods;
 use dpp::state_transition::batch_transition::token_base_transition::v0::v0_methods::TokenBaseTransitionV0Methods;
 use dpp::state_transition::batch_transition::token_freeze_transition::v0::v0_methods::TokenFreezeTransitionV0Methods;
 use dpp::state_transition::batch_transition::token_mint_transition::v0::v0_methods::TokenMintTransitionV0Methods;
+use dpp::state_transition::batch_transition::token_transfer_from_transition::v0::v0_methods::TokenTransferFromTransitionV0Methods;
 use dpp::state_transition::batch_transition::token_transfer_transition::v0::v0_methods::TokenTransferTransitionV0Methods;
 use dpp::state_transition::batch_transition::token_unfreeze_transition::v0::v0_methods::TokenUnfreezeTransitionV0Methods;
 use dpp::system_data_contracts::load_system_data_contract;
@@ -152,6 +154,28 @@ impl TryTransitionIntoPathQuery for TokenTransition {
                     Drive::token_direct_purchase_price_query(token_id.to_buffer())
                 }
             }
+            TokenTransition::PlaceOrder(_)
+            | TokenTransition::CancelOrder(_)
+            | TokenTransition::VestingTransfer(_)
+            | TokenTransition::ClaimVested(_) => Drive::token_balance_for_identity_id_query(
+                token_id.to_buffer(),
+                owner_id.to_buffer(),
+            ),
+            TokenTransition::Approve(token_approve_transition) => Drive::token_allowance_query(
+                token_id.to_buffer(),
+                owner_id.to_buffer(),
+                token_approve_transition.spender(),
+            ),
+            TokenTransition::TransferFrom(token_transfer_from_transition) => {
+                let identity_ids = [
+                    token_transfer_from_transition
+                        .from_identity_id()
+                        .to_buffer(),
+                    token_transfer_from_transition.recipient_id().to_buffer(),
+                ];
+
+                Drive::token_balances_for_identity_ids_query(token_id.into_buffer(), &identity_ids)
+            }
             TokenTransition::DestroyFrozenFunds(_)
             | TokenTransition::EmergencyAction(_)
             | TokenTransition::ConfigUpdate(_)
@@ -14,9 +14,11 @@ use dpp::fee::Credits;
 use dpp::group::group_action_status::GroupActionStatus;
 use dpp::identity::PartialIdentity;
 use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::serialization::PlatformDeserializable;
 use dpp::prelude::Identifier;
 use dpp::state_transition::data_contract_create_transition::accessors::DataContractCreateTransitionAccessorsV0;
 use dpp::state_transition::data_contract_update_transition::accessors::DataContractUpdateTransitionAccessorsV0;
+use dpp::state_transition::data_contract_delete_transition::accessors::DataContractDeleteTransitionAccessorsV0;
 use dpp::state_transition::batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
 use dpp::state_transition::batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
 use dpp::state_transition::batch_transition::document_create_transition::v0::v0_methods::DocumentCreateTransitionV0Methods;
@@ -34,23 +36,29 @@ use dpp::state_transition::batch_transition::batched_transition::document_transf
 use dpp::state_transition::batch_transition::batched_transition::document_transition::{DocumentTransition, DocumentTransitionV0Methods};
 use dpp::state_transition::batch_transition::batched_transition::document_update_price_transition::v0::v0_methods::DocumentUpdatePriceTransitionV0Methods;
 use dpp::state_transition::batch_transition::batched_transition::token_transition::{TokenTransition, TokenTransitionV0Methods};
+use dpp::state_transition::batch_transition::token_approve_transition::v0::v0_methods::TokenApproveTransitionV0Methods;
 use dpp::state_transition::batch_transition::token_base_transition::v0::v0_methods::TokenBaseTransitionV0Methods;
 use dpp::state_transition::batch_transition::token_freeze_transition::v0::v0_methods::TokenFreezeTransitionV0Methods;
 use dpp::state_transition::batch_transition::token_mint_transition::v0::v0_methods::TokenMintTransitionV0Methods;
+use dpp::state_transition::batch_transition::token_transfer_from_transition::v0::v0_methods::TokenTransferFromTransitionV0Methods;
 use dpp::state_transition::batch_transition::token_transfer_transition::v0::v0_methods::TokenTransferTransitionV0Methods;
 use dpp::state_transition::batch_transition::token_unfreeze_transition::v0::v0_methods::TokenUnfreezeTransitionV0Methods;
 use dpp::state_transition::masternode_vote_transition::accessors::MasternodeVoteTransitionAccessorsV0;
+use dpp::state_transition::vote_poll_create_transition::accessors::VotePollCreateTransitionAccessorsV0;
 use dpp::state_transition::proof_result::StateTransitionProofResult;
-use dpp::state_transition::proof_result::StateTransitionProofResult::{VerifiedBalanceTransfer, VerifiedDataContract, VerifiedDocuments, VerifiedIdentity, VerifiedMasternodeVote, VerifiedPartialIdentity, VerifiedTokenActionWithDocument, VerifiedTokenBalance, VerifiedTokenGroupActionWithDocument, VerifiedTokenGroupActionWithTokenBalance, VerifiedTokenGroupActionWithTokenIdentityInfo, VerifiedTokenGroupActionWithTokenPricingSchedule, VerifiedTokenIdentitiesBalances, VerifiedTokenIdentityInfo, VerifiedTokenPricingSchedule};
+use dpp::state_transition::proof_result::StateTransitionProofResult::{VerifiedBalanceTransfer, VerifiedDataContract, VerifiedDataContractAbsence, VerifiedDocuments, VerifiedIdentity, VerifiedMasternodeVote, VerifiedPartialIdentity, VerifiedTokenActionWithDocument, VerifiedTokenAllowance, VerifiedTokenBalance, VerifiedTokenBalanceAbsence, VerifiedTokenGroupActionWithDocument, VerifiedTokenGroupActionWithTokenBalance, VerifiedTokenGroupActionWithTokenIdentityInfo, VerifiedTokenGroupActionWithTokenPricingSchedule, VerifiedTokenIdentitiesBalances, VerifiedTokenIdentityInfo, VerifiedTokenPricingSchedule, VerifiedYesNoAbstainVotePoll};
 use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
 use dpp::tokens::info::v0::IdentityTokenInfoV0Accessors;
 use dpp::voting::vote_polls::VotePoll;
 use dpp::voting::votes::resource_vote::accessors::v0::ResourceVoteGettersV0;
 use dpp::voting::votes::Vote;
+use dpp::voting::votes::yes_no_abstain_vote::accessors::v0::YesNoAbstainVoteGettersV0;
 use platform_version::TryIntoPlatformVersioned;
 use platform_version::version::PlatformVersion;
+use grovedb::GroveDb;
 use crate::drive::Drive;
 use crate::drive::identity::key::fetch::IdentityKeysRequest;
+use crate::drive::votes::storage_form::yes_no_abstain_vote_storage_form::YesNoAbstainVoteStorageForm;
 use crate::verify::RootHash;
 use crate::error::Error;
 use crate::error::proof::ProofError;
@@ -110,6 +118,22 @@ impl Drive {
                 }
                 Ok((root_hash, VerifiedDataContract(contract)))
             }
+            StateTransition::DataContractDelete(data_contract_delete) => {
+                // we expect the contract to no longer exist
+                let data_contract_id = data_contract_delete.data_contract_id();
+                let (root_hash, contract) = Drive::verify_contract(
+                    proof,
+                    None,
+                    false,
+                    true,
+                    data_contract_id.into_buffer(),
+                    platform_version,
+                )?;
+                if contract.is_some() {
+                    return Err(Error::Proof(ProofError::IncorrectProof(format!("proof contained contract with id {} expected to be deleted because of state transition (delete)", data_contract_id))));
+                }
+                Ok((root_hash, VerifiedDataContractAbsence(data_contract_id)))
+            }
             StateTransition::Batch(documents_batch_transition) => {
                 if documents_batch_transition.transitions_len() > 1 {
                     return Err(Error::Proof(ProofError::InvalidTransition(format!("version {} does not support more than one document in a document batch transition", platform_version.protocol_version))));
@@ -787,6 +811,103 @@ impl Drive {
                                     ))
                                 }
                             }
+                            TokenTransition::PlaceOrder(_)
+                            | TokenTransition::CancelOrder(_)
+                            | TokenTransition::VestingTransfer(_)
+                            | TokenTransition::ClaimVested(_) => {
+                                // Order book and vesting actions never keep history, we prove
+                                // the balance of the token of the owner instead
+                                let (root_hash, balance) =
+                                    Drive::verify_token_balance_for_identity_id(
+                                        proof,
+                                        token_id.into_buffer(),
+                                        owner_id.into_buffer(),
+                                        false,
+                                        platform_version,
+                                    )?;
+                                match balance {
+                                    Some(balance) => {
+                                        Ok((root_hash, VerifiedTokenBalance(owner_id, balance)))
+                                    }
+                                    None => Ok((root_hash, VerifiedTokenBalanceAbsence(owner_id))),
+                                }
+                            }
+                            TokenTransition::Approve(token_approve_transition) => {
+                                // Allowance actions never keep history, we prove the allowance
+                                // instead, it is absent if it was revoked
+                                let (root_hash, allowance) = Drive::verify_token_allowance(
+                                    proof,
+                                    token_id.into_buffer(),
+                                    owner_id.into_buffer(),
+                                    token_approve_transition.spender(),
+                                    false,
+                                    platform_version,
+                                )?;
+                                Ok((root_hash, VerifiedTokenAllowance(owner_id, allowance)))
+                            }
+                            TokenTransition::TransferFrom(token_transfer_from_transition) => {
+                                let from_identity_id =
+                                    token_transfer_from_transition.from_identity_id();
+                                let recipient_id = token_transfer_from_transition.recipient_id();
+                                if let Some(group_state_transition_info) =
+                                    token_transition.base().using_group_info()
+                                {
+                                    let (_root_hash, status, sum_power) =
+                                        Drive::verify_action_signer_and_total_power(
+                                            proof,
+                                            data_contract_id,
+                                            group_state_transition_info.group_contract_position,
+                                            None,
+                                            group_state_transition_info.action_id,
+                                            owner_id,
+                                            true,
+                                            platform_version,
+                                        )?;
+
+                                    let (root_hash, balance) =
+                                        Drive::verify_token_balance_for_identity_id(
+                                            proof,
+                                            token_id.into_buffer(),
+                                            recipient_id.into_buffer(),
+                                            true,
+                                            platform_version,
+                                        )?;
+                                    if status == GroupActionStatus::ActionClosed
+                                        && balance.is_none()
+                                    {
+                                        return Err(Error::Proof(ProofError::IncorrectProof(
+                                            format!("proof did not contain token balance for identity {} expected to exist because of state transition (token transfer from)", recipient_id))));
+                                    };
+
+                                    Ok((
+                                        root_hash,
+                                        VerifiedTokenGroupActionWithTokenBalance(
+                                            sum_power, status, balance,
+                                        ),
+                                    ))
+                                } else {
+                                    let identity_ids =
+                                        [from_identity_id.to_buffer(), recipient_id.to_buffer()];
+                                    let (root_hash, balances): (
+                                        RootHash,
+                                        BTreeMap<Identifier, Option<TokenAmount>>,
+                                    ) = Drive::verify_token_balances_for_identity_ids(
+                                        proof,
+                                        token_id.into_buffer(),
+                                        &identity_ids,
+                                        false,
+                                        platform_version,
+                                    )?;
+
+                                    let balances = balances.into_iter().map(|(id, maybe_balance)| {
+                                            let balance = maybe_balance.ok_or(Error::Proof(ProofError::IncorrectProof(
+                                                format!("proof did not contain token balance for identity {} expected to exist because of state transition (token transfer from)", id))))?;
+                                            Ok((id, balance))
+                                        }).collect::<Result<_, Error>>()?;
+
+                                    Ok((root_hash, VerifiedTokenIdentitiesBalances(balances)))
+                                }
+                            }
                             TokenTransition::DestroyFrozenFunds(_)
                             | TokenTransition::EmergencyAction(_)
                             | TokenTransition::ConfigUpdate(_)
@@ -921,33 +1042,102 @@ impl Drive {
             StateTransition::MasternodeVote(masternode_vote) => {
                 let pro_tx_hash = masternode_vote.pro_tx_hash();
                 let vote = masternode_vote.vote();
-                let contract = match vote {
-                    Vote::ResourceVote(resource_vote) => match resource_vote.vote_poll() {
-                        VotePoll::ContestedDocumentResourceVotePoll(
-                            contested_document_resource_vote_poll,
-                        ) => known_contracts_provider_fn(
-                            &contested_document_resource_vote_poll.contract_id,
-                        )?
-                        .ok_or(Error::Proof(
-                            ProofError::UnknownContract(format!(
-                                "unknown contract with id {} in resource vote verification",
-                                contested_document_resource_vote_poll.contract_id
-                            )),
-                        ))?,
-                    },
-                };
+                match vote {
+                    Vote::ResourceVote(resource_vote) => {
+                        let contract = match resource_vote.vote_poll() {
+                            VotePoll::ContestedDocumentResourceVotePoll(
+                                contested_document_resource_vote_poll,
+                            ) => known_contracts_provider_fn(
+                                &contested_document_resource_vote_poll.contract_id,
+                            )?
+                            .ok_or(Error::Proof(
+                                ProofError::UnknownContract(format!(
+                                    "unknown contract with id {} in resource vote verification",
+                                    contested_document_resource_vote_poll.contract_id
+                                )),
+                            ))?,
+                            VotePoll::YesNoAbstainVotePoll(_) => {
+                                return Err(Error::Proof(ProofError::IncorrectProof(
+                                    "resource votes can not be cast on yes/no/abstain vote polls"
+                                        .to_string(),
+                                )));
+                            }
+                        };
 
-                // we expect to get a vote that matches the state transition
-                let (root_hash, vote) = Drive::verify_masternode_vote(
+                        // we expect to get a vote that matches the state transition
+                        let (root_hash, vote) = Drive::verify_masternode_vote(
+                            proof,
+                            pro_tx_hash.to_buffer(),
+                            vote,
+                            &contract,
+                            false,
+                            platform_version,
+                        )?;
+                        let vote = vote.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain actual vote for masternode {} expected to exist because of state transition (masternode vote)", masternode_vote.pro_tx_hash()))))?;
+                        Ok((root_hash, VerifiedMasternodeVote(vote)))
+                    }
+                    Vote::YesNoAbstainVote(yes_no_abstain_vote) => {
+                        let path_query = Drive::yes_no_abstain_identity_vote_query(
+                            vote.vote_poll_unique_id()?.to_buffer(),
+                            pro_tx_hash.to_buffer(),
+                        );
+                        let (root_hash, mut proved_key_values) =
+                            GroveDb::verify_query_with_absence_proof(
+                                proof,
+                                &path_query,
+                                &platform_version.drive.grove_version,
+                            )?;
+                        if proved_key_values.len() != 1 {
+                            return Err(Error::Proof(ProofError::TooManyElements(
+                                "expected one masternode vote",
+                            )));
+                        }
+                        let (_, _, maybe_element) = proved_key_values.remove(0);
+                        let element = maybe_element.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain actual vote for masternode {} expected to exist because of state transition (masternode vote)", masternode_vote.pro_tx_hash()))))?;
+                        let storage_form = YesNoAbstainVoteStorageForm::deserialize_from_bytes(
+                            &element.into_item_bytes()?,
+                        )?;
+                        if storage_form.vote_choice != yes_no_abstain_vote.vote_choice() {
+                            return Err(Error::Proof(ProofError::IncorrectProof(format!(
+                                "proved vote choice {} does not match the vote choice that was sent {}",
+                                storage_form.vote_choice,
+                                yes_no_abstain_vote.vote_choice()
+                            ))));
+                        }
+                        Ok((root_hash, VerifiedMasternodeVote(vote.clone())))
+                    }
+                }
+            }
+            StateTransition::VotePollCreate(vote_poll_create) => {
+                let vote_poll = vote_poll_create.vote_poll();
+                let path_query =
+                    Drive::yes_no_abstain_vote_poll_info_query(vote_poll.unique_id()?.to_buffer());
+                let (root_hash, mut proved_key_values) = GroveDb::verify_query_with_absence_proof(
                     proof,
-                    pro_tx_hash.to_buffer(),
-                    vote,
-                    &contract,
-                    false,
-                    platform_version,
+                    &path_query,
+                    &platform_version.drive.grove_version,
                 )?;
-                let vote = vote.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain actual vote for masternode {} expected to exist because of state transition (masternode vote)", masternode_vote.pro_tx_hash()))))?;
-                Ok((root_hash, VerifiedMasternodeVote(vote)))
+                if proved_key_values.len() != 1 {
+                    return Err(Error::Proof(ProofError::TooManyElements(
+                        "expected one vote poll",
+                    )));
+                }
+                let (_, _, maybe_element) = proved_key_values.remove(0);
+                let element = maybe_element.ok_or(Error::Proof(ProofError::IncorrectProof(format!("proof did not contain the vote poll {} expected to exist because of state transition (vote poll create)", vote_poll.unique_id()?))))?;
+                let VotePoll::YesNoAbstainVotePoll(proved_vote_poll) =
+                    VotePoll::deserialize_from_bytes(&element.into_item_bytes()?)?
+                else {
+                    return Err(Error::Proof(ProofError::IncorrectProof(
+                        "proved vote poll is not a yes/no/abstain vote poll".to_string(),
+                    )));
+                };
+                if &proved_vote_poll != vote_poll {
+                    return Err(Error::Proof(ProofError::IncorrectProof(format!(
+                        "proved vote poll {} does not match the vote poll that was sent {}",
+                        proved_vote_poll, vote_poll
+                    ))));
+                }
+                Ok((root_hash, VerifiedYesNoAbstainVotePoll(proved_vote_poll)))
             }
         }
     }
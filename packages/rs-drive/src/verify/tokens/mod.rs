@@ -1,14 +1,19 @@
+mod verify_token_allowance;
+mod verify_token_allowances;
 mod verify_token_balance_for_identity_id;
 mod verify_token_balances_for_identity_id;
 mod verify_token_balances_for_identity_ids;
 mod verify_token_contract_info;
 mod verify_token_direct_selling_price;
 mod verify_token_direct_selling_prices;
+mod verify_token_holders;
 mod verify_token_info_for_identity_id;
 mod verify_token_infos_for_identity_id;
 mod verify_token_infos_for_identity_ids;
+mod verify_token_order_book_orders;
 mod verify_token_perpetual_distribution_last_paid_time;
 mod verify_token_pre_programmed_distributions;
 mod verify_token_status;
 mod verify_token_statuses;
 mod verify_token_total_supply_and_aggregated_identity_balance;
+mod verify_token_vestings;
@@ -0,0 +1,67 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
+
+use crate::verify::RootHash;
+
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies a Merkle proof for the allowance an owner granted a spender for a token.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice containing the serialized Merkle proof generated by GroveDB.
+    /// - `token_id`: The 32-byte identifier of the token.
+    /// - `owner_id`: The 32-byte identifier of the identity that granted the allowance.
+    /// - `spender`: The identity or group the allowance was granted to.
+    /// - `verify_subset_of_proof`: If `true`, verifies a subset proof instead of a full proof.
+    /// - `platform_version`: The platform version object used to determine the correct method version.
+    ///
+    /// # Returns
+    ///
+    /// On success, returns a [`Result`] containing a tuple:
+    /// - [`RootHash`]: The Merkle root hash resulting from the proof.
+    /// - `Option<TokenAllowance>`: The allowance, or `None` if it is proved absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if:
+    /// - The proof is malformed or fails verification.
+    /// - The proved allowance can not be deserialized.
+    /// - An unsupported version is encountered for this method.
+    pub fn verify_token_allowance(
+        proof: &[u8],
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        spender: TokenAllowanceSpender,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<TokenAllowance>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .token
+            .verify_token_allowance
+        {
+            0 => Self::verify_token_allowance_v0(
+                proof,
+                token_id,
+                owner_id,
+                spender,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_token_allowance".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
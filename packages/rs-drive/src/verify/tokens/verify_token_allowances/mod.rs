@@ -0,0 +1,78 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the allowances an owner granted for a token.
+    ///
+    /// Allowances are proved ordered by their spender; the proof covers at most `limit`
+    /// allowances starting after `start_after`.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the allowances.
+    /// - `token_id`: A 32-byte array representing the unique identifier of the token.
+    /// - `owner_id`: A 32-byte array representing the identity that granted the allowances.
+    /// - `start_after`: An optional spender the proved range starts after.
+    /// - `limit`: An optional maximum number of allowances in the proved range.
+    /// - `verify_subset_of_proof`: A boolean flag indicating whether the proof being verified is a
+    ///   subset of a larger proof.
+    /// - `platform_version`: The version of the platform against which the proof is verified.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(RootHash, T), Error>`: If the verification is successful:
+    ///   - `RootHash`: The root hash of the GroveDB, representing the state of the database.
+    ///   - `T`: A collection of the proved allowances, ordered by their spender.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an `Error` if any of the following occur:
+    ///
+    /// - The provided proof is invalid.
+    /// - A proved allowance can not be deserialized.
+    /// - The provided platform version is unknown or unsupported.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_token_allowances<T: FromIterator<TokenAllowance>>(
+        proof: &[u8],
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        start_after: Option<TokenAllowanceSpender>,
+        limit: Option<u16>,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .token
+            .verify_token_allowances
+        {
+            0 => Self::verify_token_allowances_v0(
+                proof,
+                token_id,
+                owner_id,
+                start_after,
+                limit,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_token_allowances".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
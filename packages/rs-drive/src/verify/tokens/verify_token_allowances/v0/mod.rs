@@ -0,0 +1,53 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::serialization::PlatformDeserializable;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
+use grovedb::Element::Item;
+use grovedb::GroveDb;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn verify_token_allowances_v0<T: FromIterator<TokenAllowance>>(
+        proof: &[u8],
+        token_id: [u8; 32],
+        owner_id: [u8; 32],
+        start_after: Option<TokenAllowanceSpender>,
+        limit: Option<u16>,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        let path_query =
+            Self::token_allowances_for_owner_query(token_id, owner_id, start_after, limit);
+        let (root_hash, proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query(proof, &path_query, &platform_version.drive.grove_version)?
+        } else {
+            GroveDb::verify_query(proof, &path_query, &platform_version.drive.grove_version)?
+        };
+        let allowances = proved_key_values
+            .into_iter()
+            .filter_map(|(_, _, element)| match element {
+                Some(Item(allowance_bytes, _)) => Some(
+                    TokenAllowance::deserialize_from_bytes(&allowance_bytes).map_err(|e| {
+                        Error::Proof(ProofError::CorruptedProof(format!(
+                            "could not deserialize token allowance: {}",
+                            e
+                        )))
+                    }),
+                ),
+                None => None,
+                Some(element) => Some(Err(Error::Proof(ProofError::IncorrectProof(format!(
+                    "token allowance should be in an item, however a {} was returned",
+                    element.type_str()
+                ))))),
+            })
+            .collect::<Result<T, Error>>()?;
+        Ok((root_hash, allowances))
+    }
+}
@@ -0,0 +1,75 @@
+mod v0;
+
+use crate::drive::Drive;
+use dpp::balances::credits::TokenAmount;
+use dpp::prelude::StartAtIncluded;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the balances of the holders of a token.
+    ///
+    /// Holders are ordered by identity id; the proof covers at most `limit` holders starting at
+    /// `start_at`, so all holders can be verified page by page.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the token holders' balances.
+    /// - `token_id`: A 32-byte array representing the unique identifier of the token.
+    /// - `start_at`: An optional identity id the proved range starts at, combined with a
+    ///   [`StartAtIncluded`] flag to indicate whether it is inclusive.
+    /// - `limit`: The maximum number of holders in the proved range.
+    /// - `verify_subset_of_proof`: A boolean flag indicating whether the proof being verified is a
+    ///   subset of a larger proof.
+    /// - `platform_version`: The version of the platform against which the proof is verified.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(RootHash, T), Error>`: If the verification is successful:
+    ///   - `RootHash`: The root hash of the GroveDB, representing the state of the database.
+    ///   - `T`: A collection of identity IDs and their token balances, in identity id order.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an `Error` if any of the following occur:
+    ///
+    /// - The provided proof is invalid.
+    /// - A proved balance is not a sum item or is negative.
+    /// - The provided platform version is unknown or unsupported.
+    pub fn verify_token_holders<T: FromIterator<(I, TokenAmount)>, I: From<[u8; 32]>>(
+        proof: &[u8],
+        token_id: [u8; 32],
+        start_at: Option<([u8; 32], StartAtIncluded)>,
+        limit: u16,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .token
+            .verify_token_holders
+        {
+            0 => Self::verify_token_holders_v0(
+                proof,
+                token_id,
+                start_at,
+                limit,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_token_holders".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::balances::credits::TokenAmount;
+use dpp::prelude::StartAtIncluded;
+use grovedb::Element::SumItem;
+use grovedb::GroveDb;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn verify_token_holders_v0<T: FromIterator<(I, TokenAmount)>, I: From<[u8; 32]>>(
+        proof: &[u8],
+        token_id: [u8; 32],
+        start_at: Option<([u8; 32], StartAtIncluded)>,
+        limit: u16,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        let path_query = Self::token_holders_query(token_id, start_at, limit);
+        let (root_hash, proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query(proof, &path_query, &platform_version.drive.grove_version)?
+        } else {
+            GroveDb::verify_query(proof, &path_query, &platform_version.drive.grove_version)?
+        };
+        let escrow_identity_ids = Self::token_escrow_identity_ids(token_id);
+        let values = proved_key_values
+            .into_iter()
+            .filter(|(_, key, _)| !escrow_identity_ids.iter().any(|escrow| key == escrow))
+            .take(limit as usize)
+            .filter_map(|(_, key, element)| {
+                let identity_id: [u8; 32] = match key.try_into() {
+                    Ok(identity_id) => identity_id,
+                    Err(_) => {
+                        return Some(Err(Error::Proof(ProofError::IncorrectValueSize(
+                            "identity id size",
+                        ))))
+                    }
+                };
+                match element {
+                    Some(SumItem(value, ..)) => Some(
+                        TokenAmount::try_from(value)
+                            .map(|balance| (identity_id.into(), balance))
+                            .map_err(|_| {
+                                Error::Proof(ProofError::IncorrectValueSize("balance was negative"))
+                            }),
+                    ),
+                    None => None,
+                    Some(element) => Some(Err(Error::Proof(ProofError::IncorrectProof(format!(
+                        "token balance should be in a sum item, however a {} was returned",
+                        element.type_str()
+                    ))))),
+                }
+            })
+            .collect::<Result<T, Error>>()?;
+        Ok((root_hash, values))
+    }
+}
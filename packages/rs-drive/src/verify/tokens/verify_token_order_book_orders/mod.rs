@@ -0,0 +1,77 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::{TokenOrder, TokenOrderSide};
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the resting orders of one side of a token order book.
+    ///
+    /// Orders are proved in price-time priority, the best priced and oldest order first; the
+    /// proof covers at most `limit` orders.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the orders.
+    /// - `token_id`: A 32-byte array representing the unique identifier of the token.
+    /// - `side`: Whether the buy or the sell side of the book is proved.
+    /// - `quote_currency`: The currency the proved orders are quoted in.
+    /// - `limit`: An optional maximum number of orders in the proved range.
+    /// - `verify_subset_of_proof`: A boolean flag indicating whether the proof being verified is a
+    ///   subset of a larger proof.
+    /// - `platform_version`: The version of the platform against which the proof is verified.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(RootHash, T), Error>`: If the verification is successful:
+    ///   - `RootHash`: The root hash of the GroveDB, representing the state of the database.
+    ///   - `T`: A collection of the proved orders, in price-time priority.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an `Error` if any of the following occur:
+    ///
+    /// - The provided proof is invalid.
+    /// - A proved order can not be deserialized.
+    /// - The provided platform version is unknown or unsupported.
+    pub fn verify_token_order_book_orders<T: FromIterator<TokenOrder>>(
+        proof: &[u8],
+        token_id: [u8; 32],
+        side: TokenOrderSide,
+        quote_currency: &AllowedCurrency,
+        limit: Option<u16>,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .token
+            .verify_token_order_book_orders
+        {
+            0 => Self::verify_token_order_book_orders_v0(
+                proof,
+                token_id,
+                side,
+                quote_currency,
+                limit,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_token_order_book_orders".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::serialization::PlatformDeserializable;
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::{TokenOrder, TokenOrderSide};
+use grovedb::Element::Item;
+use grovedb::GroveDb;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn verify_token_order_book_orders_v0<T: FromIterator<TokenOrder>>(
+        proof: &[u8],
+        token_id: [u8; 32],
+        side: TokenOrderSide,
+        quote_currency: &AllowedCurrency,
+        limit: Option<u16>,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        let path_query = Self::token_order_book_orders_query(token_id, side, quote_currency, limit);
+        let (root_hash, proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query(proof, &path_query, &platform_version.drive.grove_version)?
+        } else {
+            GroveDb::verify_query(proof, &path_query, &platform_version.drive.grove_version)?
+        };
+        let orders = proved_key_values
+            .into_iter()
+            .filter_map(|(_, _, element)| match element {
+                Some(Item(order_bytes, _)) => Some(
+                    TokenOrder::deserialize_from_bytes(&order_bytes).map_err(|e| {
+                        Error::Proof(ProofError::CorruptedProof(format!(
+                            "could not deserialize token order: {}",
+                            e
+                        )))
+                    }),
+                ),
+                None => None,
+                Some(element) => Some(Err(Error::Proof(ProofError::IncorrectProof(format!(
+                    "token order should be in an item, however a {} was returned",
+                    element.type_str()
+                ))))),
+            })
+            .collect::<Result<T, Error>>()?;
+        Ok((root_hash, orders))
+    }
+}
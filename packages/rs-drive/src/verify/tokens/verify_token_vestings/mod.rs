@@ -0,0 +1,77 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::tokens::vesting::TokenVesting;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the vestings of a token locked for a recipient.
+    ///
+    /// Vestings are proved ordered by their id; the proof covers at most `limit` vestings
+    /// starting after `start_after`.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the vestings.
+    /// - `token_id`: A 32-byte array representing the unique identifier of the token.
+    /// - `recipient_id`: A 32-byte array representing the identity the tokens are locked for.
+    /// - `start_after`: An optional vesting id the proved range starts after.
+    /// - `limit`: An optional maximum number of vestings in the proved range.
+    /// - `verify_subset_of_proof`: A boolean flag indicating whether the proof being verified is a
+    ///   subset of a larger proof.
+    /// - `platform_version`: The version of the platform against which the proof is verified.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(RootHash, T), Error>`: If the verification is successful:
+    ///   - `RootHash`: The root hash of the GroveDB, representing the state of the database.
+    ///   - `T`: A collection of the proved vestings, ordered by their id.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an `Error` if any of the following occur:
+    ///
+    /// - The provided proof is invalid.
+    /// - A proved vesting can not be deserialized.
+    /// - The provided platform version is unknown or unsupported.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_token_vestings<T: FromIterator<TokenVesting>>(
+        proof: &[u8],
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        start_after: Option<[u8; 32]>,
+        limit: Option<u16>,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .token
+            .verify_token_vestings
+        {
+            0 => Self::verify_token_vestings_v0(
+                proof,
+                token_id,
+                recipient_id,
+                start_after,
+                limit,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_token_vestings".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
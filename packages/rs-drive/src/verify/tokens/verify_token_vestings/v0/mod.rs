@@ -0,0 +1,52 @@
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::serialization::PlatformDeserializable;
+use dpp::tokens::vesting::TokenVesting;
+use grovedb::Element::Item;
+use grovedb::GroveDb;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn verify_token_vestings_v0<T: FromIterator<TokenVesting>>(
+        proof: &[u8],
+        token_id: [u8; 32],
+        recipient_id: [u8; 32],
+        start_after: Option<[u8; 32]>,
+        limit: Option<u16>,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, T), Error> {
+        let path_query =
+            Self::token_vestings_for_recipient_query(token_id, recipient_id, start_after, limit);
+        let (root_hash, proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query(proof, &path_query, &platform_version.drive.grove_version)?
+        } else {
+            GroveDb::verify_query(proof, &path_query, &platform_version.drive.grove_version)?
+        };
+        let vestings = proved_key_values
+            .into_iter()
+            .filter_map(|(_, _, element)| match element {
+                Some(Item(vesting_bytes, _)) => Some(
+                    TokenVesting::deserialize_from_bytes(&vesting_bytes).map_err(|e| {
+                        Error::Proof(ProofError::CorruptedProof(format!(
+                            "could not deserialize token vesting: {}",
+                            e
+                        )))
+                    }),
+                ),
+                None => None,
+                Some(element) => Some(Err(Error::Proof(ProofError::IncorrectProof(format!(
+                    "token vesting should be in an item, however a {} was returned",
+                    element.type_str()
+                ))))),
+            })
+            .collect::<Result<T, Error>>()?;
+        Ok((root_hash, vestings))
+    }
+}
@@ -7,3 +7,4 @@ mod verify_specialized_balance;
 mod verify_vote_poll_vote_state_proof;
 mod verify_vote_poll_votes_proof;
 mod verify_vote_polls_end_date_query;
+mod verify_yes_no_abstain_vote_poll_state_proof;
@@ -0,0 +1,66 @@
+mod v0;
+
+use crate::drive::votes::yes_no_abstain_vote_poll_state::YesNoAbstainVotePollState;
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::identifier::Identifier;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the state of a yes/no/abstain vote poll.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof of the vote poll state.
+    /// - `vote_poll_id`: The unique id of the yes/no/abstain vote poll.
+    /// - `verify_subset_of_proof`: A boolean flag indicating whether the proof being verified is a
+    ///   subset of a larger proof.
+    /// - `platform_version`: The version of the platform against which the proof is verified.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(RootHash, YesNoAbstainVotePollState), Error>`: If the verification is successful:
+    ///   - `RootHash`: The root hash of the GroveDB, representing the state of the database.
+    ///   - `YesNoAbstainVotePollState`: The poll, the votes cast on it and its result if it has ended.
+    ///     A poll nobody voted on yet is proved as an empty state.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an `Error` if any of the following occur:
+    ///
+    /// - The provided proof is invalid.
+    /// - A proved element can not be deserialized.
+    /// - The provided platform version is unknown or unsupported.
+    pub fn verify_yes_no_abstain_vote_poll_state_proof(
+        proof: &[u8],
+        vote_poll_id: Identifier,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, YesNoAbstainVotePollState), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .voting
+            .verify_yes_no_abstain_vote_poll_state_proof
+        {
+            0 => Self::verify_yes_no_abstain_vote_poll_state_proof_v0(
+                proof,
+                vote_poll_id,
+                verify_subset_of_proof,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_yes_no_abstain_vote_poll_state_proof".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
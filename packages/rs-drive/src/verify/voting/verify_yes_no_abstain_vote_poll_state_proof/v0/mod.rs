@@ -0,0 +1,32 @@
+use crate::drive::votes::yes_no_abstain_vote_poll_state::YesNoAbstainVotePollState;
+use crate::drive::Drive;
+
+use crate::error::Error;
+
+use crate::verify::RootHash;
+
+use dpp::identifier::Identifier;
+use grovedb::GroveDb;
+use platform_version::version::PlatformVersion;
+
+impl Drive {
+    pub(super) fn verify_yes_no_abstain_vote_poll_state_proof_v0(
+        proof: &[u8],
+        vote_poll_id: Identifier,
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, YesNoAbstainVotePollState), Error> {
+        let path_query = Self::yes_no_abstain_vote_poll_state_query(vote_poll_id.to_buffer());
+        let (root_hash, proved_path_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query(proof, &path_query, &platform_version.drive.grove_version)?
+        } else {
+            GroveDb::verify_query(proof, &path_query, &platform_version.drive.grove_version)?
+        };
+        let state = YesNoAbstainVotePollState::try_from_path_key_elements(
+            proved_path_key_values
+                .into_iter()
+                .filter_map(|(path, key, element)| element.map(|element| (path, key, element))),
+        )?;
+        Ok((root_hash, state))
+    }
+}
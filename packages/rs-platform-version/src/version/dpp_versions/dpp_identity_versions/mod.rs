@@ -7,6 +7,7 @@ pub struct DPPIdentityVersions {
     /// This is the structure of the Identity as it is defined for code paths
     pub identity_structure_version: FeatureVersion,
     pub identity_key_structure_version: FeatureVersion,
+    pub identity_state_transition_record_structure_version: FeatureVersion,
     pub identity_key_type_method_versions: IdentityKeyTypeMethodVersions,
 }
 
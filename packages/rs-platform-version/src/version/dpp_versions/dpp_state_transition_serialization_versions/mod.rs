@@ -12,8 +12,10 @@ pub struct DPPStateTransitionSerializationVersions {
     pub identity_credit_withdrawal_state_transition: FeatureVersionBounds,
     pub identity_credit_transfer_state_transition: FeatureVersionBounds,
     pub masternode_vote_state_transition: FeatureVersionBounds,
+    pub vote_poll_create_state_transition: FeatureVersionBounds,
     pub contract_create_state_transition: FeatureVersionBounds,
     pub contract_update_state_transition: FeatureVersionBounds,
+    pub contract_delete_state_transition: FeatureVersionBounds,
     pub batch_state_transition: FeatureVersionBounds,
     pub document_base_state_transition: FeatureVersionBounds,
     pub document_create_state_transition: DocumentFeatureVersionBounds,
@@ -40,6 +40,11 @@ pub const STATE_TRANSITION_SERIALIZATION_VERSIONS_V1: DPPStateTransitionSerializ
             max_version: 0,
             default_current_version: 0,
         },
+        vote_poll_create_state_transition: FeatureVersionBounds {
+            min_version: 0,
+            max_version: 0,
+            default_current_version: 0,
+        },
         contract_create_state_transition: FeatureVersionBounds {
             min_version: 0,
             max_version: 0,
@@ -50,6 +55,11 @@ pub const STATE_TRANSITION_SERIALIZATION_VERSIONS_V1: DPPStateTransitionSerializ
             max_version: 0,
             default_current_version: 0,
         },
+        contract_delete_state_transition: FeatureVersionBounds {
+            min_version: 0,
+            max_version: 0,
+            default_current_version: 0,
+        },
         batch_state_transition: FeatureVersionBounds {
             min_version: 0,
             max_version: 0,
@@ -7,4 +7,7 @@ pub struct DPPTokenVersions {
     pub identity_token_info_default_structure_version: FeatureVersion,
     pub identity_token_status_default_structure_version: FeatureVersion,
     pub token_contract_info_default_structure_version: FeatureVersion,
+    pub token_order_default_structure_version: FeatureVersion,
+    pub token_vesting_default_structure_version: FeatureVersion,
+    pub token_allowance_default_structure_version: FeatureVersion,
 }
@@ -4,4 +4,7 @@ pub const TOKEN_VERSIONS_V1: DPPTokenVersions = DPPTokenVersions {
     identity_token_info_default_structure_version: 0,
     identity_token_status_default_structure_version: 0,
     token_contract_info_default_structure_version: 0,
+    token_order_default_structure_version: 0,
+    token_vesting_default_structure_version: 0,
+    token_allowance_default_structure_version: 0,
 };
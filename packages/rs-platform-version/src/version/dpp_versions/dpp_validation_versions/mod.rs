@@ -2,6 +2,7 @@ use versioned_feature_core::FeatureVersion;
 
 pub mod v1;
 pub mod v2;
+pub mod v3;
 
 #[derive(Clone, Debug, Default)]
 pub struct DPPValidationVersions {
@@ -32,6 +33,10 @@ pub struct VotingValidationVersions {
     pub allow_other_contenders_time_testing_ms: u64,
     /// How many votes do we allow from the same masternode?
     pub votes_allowed_per_masternode: u16,
+    /// The maximum length of the question asked by a yes/no/abstain vote poll
+    pub yes_no_abstain_vote_poll_max_question_length: u16,
+    /// How far in the future a yes/no/abstain vote poll is allowed to end
+    pub yes_no_abstain_vote_poll_max_duration_ms: u64,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -30,5 +30,7 @@ pub const DPP_VALIDATION_VERSIONS_V2: DPPValidationVersions = DPPValidationVersi
         allow_other_contenders_time_mainnet_ms: 604_800_000, // 1 week in ms
         allow_other_contenders_time_testing_ms: 2_700_000,   //45 minutes
         votes_allowed_per_masternode: 5,
+        yes_no_abstain_vote_poll_max_question_length: 0, // yes/no/abstain vote polls are not available before protocol version 11
+        yes_no_abstain_vote_poll_max_duration_ms: 0,
     },
 };
@@ -0,0 +1,37 @@
+use crate::version::dpp_versions::dpp_validation_versions::{
+    DPPValidationVersions, DataContractValidationVersions, DocumentTypeValidationVersions,
+    JsonSchemaValidatorVersions, VotingValidationVersions,
+};
+
+// In this version we introduce the yes/no/abstain vote poll limits.
+pub const DPP_VALIDATION_VERSIONS_V3: DPPValidationVersions = DPPValidationVersions {
+    json_schema_validator: JsonSchemaValidatorVersions {
+        new: 0,
+        validate: 0,
+        compile: 0,
+        compile_and_validate: 0,
+    },
+    data_contract: DataContractValidationVersions {
+        validate: 0,
+        validate_config_update: 0,
+        validate_token_config_update: 0,
+        validate_index_definitions: 0,
+        validate_index_naming_duplicates: 0,
+        validate_not_defined_properties: 0,
+        validate_property_definition: 0,
+        validate_token_config_groups_exist: 0,
+        validate_localizations: 0,
+    },
+    document_type: DocumentTypeValidationVersions {
+        validate_update: 0,
+        contested_index_limit: 1,
+        unique_index_limit: 10,
+    },
+    voting: VotingValidationVersions {
+        allow_other_contenders_time_mainnet_ms: 604_800_000, // 1 week in ms
+        allow_other_contenders_time_testing_ms: 2_700_000,   //45 minutes
+        votes_allowed_per_masternode: 5,
+        yes_no_abstain_vote_poll_max_question_length: 256,
+        yes_no_abstain_vote_poll_max_duration_ms: 2_592_000_000, // 30 days in ms
+    },
+};
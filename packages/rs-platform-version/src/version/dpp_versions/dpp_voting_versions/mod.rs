@@ -8,4 +8,5 @@ pub struct DPPVotingVersions {
     pub default_vote_poll_time_duration_mainnet_ms: u64,
     pub default_vote_poll_time_duration_test_network_ms: u64,
     pub contested_document_vote_poll_stored_info_version: FeatureVersion,
+    pub yes_no_abstain_vote_poll_stored_info_version: FeatureVersion,
 }
@@ -6,6 +6,7 @@ pub mod v3;
 pub mod v4;
 pub mod v5;
 pub mod v6;
+pub mod v7;
 
 #[derive(Clone, Debug, Default)]
 pub struct DriveAbciMethodVersions {
@@ -32,6 +33,8 @@ pub struct DriveAbciMethodVersions {
 pub struct DriveAbciPlatformStateStorageMethodVersions {
     pub fetch_platform_state: FeatureVersion,
     pub store_platform_state: FeatureVersion,
+    pub fetch_block_state_transitions: FeatureVersion,
+    pub store_block_state_transitions: FeatureVersion,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -131,6 +134,7 @@ pub struct DriveAbciBlockEndMethodVersions {
     pub update_state_cache: FeatureVersion,
     pub update_drive_cache: FeatureVersion,
     pub validator_set_update: FeatureVersion,
+    pub remove_expired_documents: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -172,4 +176,6 @@ pub struct DriveAbciStateTransitionProcessingMethodVersions {
     pub process_raw_state_transitions: FeatureVersion,
     pub decode_raw_state_transitions: FeatureVersion,
     pub validate_fees_of_event: FeatureVersion,
+    pub simulate_state_transition: FeatureVersion,
+    pub store_identity_state_transition_history: OptionalFeatureVersion,
 }
@@ -8,6 +8,8 @@ pub struct DriveAbciQueryVersions {
     pub response_metadata: FeatureVersion,
     pub proofs_query: FeatureVersion,
     pub document_query: FeatureVersionBounds,
+    pub document_aggregate_query: FeatureVersionBounds,
+    pub document_subscription: FeatureVersionBounds,
     pub prefunded_specialized_balances: DriveAbciQueryPrefundedSpecializedBalancesVersions,
     pub identity_based_queries: DriveAbciQueryIdentityVersions,
     pub token_queries: DriveAbciQueryTokenVersions,
@@ -35,6 +37,11 @@ pub struct DriveAbciQueryTokenVersions {
     pub token_pre_programmed_distributions: FeatureVersionBounds,
     pub token_perpetual_distribution_last_claim: FeatureVersionBounds,
     pub token_contract_info: FeatureVersionBounds,
+    pub token_holders: FeatureVersionBounds,
+    pub token_order_book: FeatureVersionBounds,
+    pub token_vestings: FeatureVersionBounds,
+    pub token_allowances: FeatureVersionBounds,
+    pub token_events: FeatureVersionBounds,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -57,6 +64,7 @@ pub struct DriveAbciQueryIdentityVersions {
     pub balance_and_revision: FeatureVersionBounds,
     pub identity_by_unique_public_key_hash: FeatureVersionBounds,
     pub identity_by_non_unique_public_key_hash: FeatureVersionBounds,
+    pub identity_state_transitions: FeatureVersionBounds,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -72,6 +80,7 @@ pub struct DriveAbciQueryVotingVersions {
     pub contested_resource_voters_for_identity: FeatureVersionBounds,
     pub contested_resource_identity_vote_status: FeatureVersionBounds,
     pub contested_resources: FeatureVersionBounds,
+    pub yes_no_abstain_vote_poll_state: FeatureVersionBounds,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -91,4 +100,6 @@ pub struct DriveAbciQuerySystemVersions {
     pub path_elements: FeatureVersionBounds,
     pub total_credits_in_platform: FeatureVersionBounds,
     pub finalized_epoch_infos: FeatureVersionBounds,
+    pub simulate_state_transition: FeatureVersionBounds,
+    pub block_state_transitions: FeatureVersionBounds,
 }
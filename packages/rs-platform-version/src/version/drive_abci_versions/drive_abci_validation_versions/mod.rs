@@ -4,6 +4,7 @@ pub mod v3;
 pub mod v4;
 pub mod v5;
 pub mod v6;
+pub mod v7;
 
 use versioned_feature_core::{FeatureVersion, OptionalFeatureVersion};
 
@@ -45,8 +46,14 @@ pub struct DriveAbciStateTransitionValidationVersions {
     pub identity_credit_withdrawal_state_transition_purpose_matches_requirements: FeatureVersion,
     pub identity_credit_transfer_state_transition: DriveAbciStateTransitionValidationVersion,
     pub masternode_vote_state_transition: DriveAbciStateTransitionValidationVersion,
+    pub vote_poll_create_state_transition: DriveAbciStateTransitionValidationVersion,
     pub contract_create_state_transition: DriveAbciStateTransitionValidationVersion,
     pub contract_update_state_transition: DriveAbciStateTransitionValidationVersion,
+    pub contract_delete_state_transition: DriveAbciStateTransitionValidationVersion,
+    /// Document types with a time to live are not allowed before this is set
+    pub documents_time_to_live: OptionalFeatureVersion,
+    /// Full text indexes are not allowed before this is set
+    pub full_text_indexes: OptionalFeatureVersion,
     pub batch_state_transition: DriveAbciDocumentsStateTransitionValidationVersions,
 }
 
@@ -127,6 +134,24 @@ pub struct DriveAbciDocumentsStateTransitionValidationVersions {
     pub token_direct_purchase_transition_state_validation: FeatureVersion,
     pub token_set_price_for_direct_purchase_transition_structure_validation: FeatureVersion,
     pub token_set_price_for_direct_purchase_transition_state_validation: FeatureVersion,
+    /// Token order book transitions are not allowed before this is set
+    pub token_place_order_transition_structure_validation: OptionalFeatureVersion,
+    pub token_place_order_transition_state_validation: FeatureVersion,
+    /// Token order book transitions are not allowed before this is set
+    pub token_cancel_order_transition_structure_validation: OptionalFeatureVersion,
+    pub token_cancel_order_transition_state_validation: FeatureVersion,
+    /// Token vesting transitions are not allowed before this is set
+    pub token_vesting_transfer_transition_structure_validation: OptionalFeatureVersion,
+    pub token_vesting_transfer_transition_state_validation: FeatureVersion,
+    /// Token vesting transitions are not allowed before this is set
+    pub token_claim_vested_transition_structure_validation: OptionalFeatureVersion,
+    pub token_claim_vested_transition_state_validation: FeatureVersion,
+    /// Token allowance transitions are not allowed before this is set
+    pub token_approve_transition_structure_validation: OptionalFeatureVersion,
+    pub token_approve_transition_state_validation: FeatureVersion,
+    /// Token allowance transitions are not allowed before this is set
+    pub token_transfer_from_transition_structure_validation: OptionalFeatureVersion,
+    pub token_transfer_from_transition_state_validation: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
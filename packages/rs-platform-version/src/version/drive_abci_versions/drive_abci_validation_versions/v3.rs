@@ -81,6 +81,15 @@ pub const DRIVE_ABCI_VALIDATION_VERSIONS_V3: DriveAbciValidationVersions =
                 state: 0,
                 transform_into_action: 0,
             },
+            vote_poll_create_state_transition: DriveAbciStateTransitionValidationVersion {
+                basic_structure: None,
+                advanced_structure: None,
+                identity_signatures: None,
+                advanced_minimum_balance_pre_check: None,
+                nonce: Some(0),
+                state: 0,
+                transform_into_action: 0,
+            },
             contract_create_state_transition: DriveAbciStateTransitionValidationVersion {
                 basic_structure: None,
                 advanced_structure: Some(0),
@@ -99,6 +108,17 @@ pub const DRIVE_ABCI_VALIDATION_VERSIONS_V3: DriveAbciValidationVersions =
                 state: 0,
                 transform_into_action: 0,
             },
+            contract_delete_state_transition: DriveAbciStateTransitionValidationVersion {
+                basic_structure: None,
+                advanced_structure: None,
+                identity_signatures: None,
+                advanced_minimum_balance_pre_check: None,
+                nonce: Some(0),
+                state: 0,
+                transform_into_action: 0,
+            },
+            documents_time_to_live: None,
+            full_text_indexes: None,
             batch_state_transition: DriveAbciDocumentsStateTransitionValidationVersions {
                 balance_pre_check: 0,
                 basic_structure: 0,
@@ -157,6 +177,18 @@ pub const DRIVE_ABCI_VALIDATION_VERSIONS_V3: DriveAbciValidationVersions =
                 token_direct_purchase_transition_state_validation: 0,
                 token_set_price_for_direct_purchase_transition_structure_validation: 0,
                 token_set_price_for_direct_purchase_transition_state_validation: 0,
+                token_place_order_transition_structure_validation: None,
+                token_place_order_transition_state_validation: 0,
+                token_cancel_order_transition_structure_validation: None,
+                token_cancel_order_transition_state_validation: 0,
+                token_vesting_transfer_transition_structure_validation: None,
+                token_vesting_transfer_transition_state_validation: 0,
+                token_claim_vested_transition_structure_validation: None,
+                token_claim_vested_transition_state_validation: 0,
+                token_approve_transition_structure_validation: None,
+                token_approve_transition_state_validation: 0,
+                token_transfer_from_transition_structure_validation: None,
+                token_transfer_from_transition_state_validation: 0,
             },
         },
         has_nonce_validation: 0,
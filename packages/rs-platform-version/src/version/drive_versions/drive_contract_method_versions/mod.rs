@@ -9,6 +9,7 @@ pub struct DriveContractMethodVersions {
     pub apply: DriveContractApplyMethodVersions,
     pub insert: DriveContractInsertMethodVersions,
     pub update: DriveContractUpdateMethodVersions,
+    pub delete: DriveContractDeleteMethodVersions,
     pub costs: DriveContractCostsMethodVersions,
     pub get: DriveContractGetMethodVersions,
 }
@@ -41,6 +42,11 @@ pub struct DriveContractUpdateMethodVersions {
     pub update_keywords: FeatureVersion,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct DriveContractDeleteMethodVersions {
+    pub delete_contract: FeatureVersion,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct DriveContractGetMethodVersions {
     pub fetch_contract: FeatureVersion,
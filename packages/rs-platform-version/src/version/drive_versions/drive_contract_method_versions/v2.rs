@@ -1,6 +1,7 @@
 use crate::version::drive_versions::drive_contract_method_versions::{
     DriveContractApplyMethodVersions, DriveContractCostsMethodVersions,
-    DriveContractGetMethodVersions, DriveContractInsertMethodVersions, DriveContractMethodVersions,
+    DriveContractDeleteMethodVersions, DriveContractGetMethodVersions,
+    DriveContractInsertMethodVersions, DriveContractMethodVersions,
     DriveContractProveMethodVersions, DriveContractUpdateMethodVersions,
 };
 
@@ -26,6 +27,7 @@ pub const DRIVE_CONTRACT_METHOD_VERSIONS_V2: DriveContractMethodVersions =
             update_description: 0,
             update_keywords: 0,
         },
+        delete: DriveContractDeleteMethodVersions { delete_contract: 0 },
         costs: DriveContractCostsMethodVersions {
             add_estimation_costs_for_contract_insertion: 0,
         },
@@ -12,6 +12,8 @@ pub struct DriveDocumentMethodVersions {
     pub update: DriveDocumentUpdateMethodVersions,
     pub estimation_costs: DriveDocumentEstimationCostsMethodVersions,
     pub index_uniqueness: DriveDocumentIndexUniquenessMethodVersions,
+    pub expiration: DriveDocumentExpirationMethodVersions,
+    pub full_text: DriveDocumentFullTextMethodVersions,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -20,6 +22,7 @@ pub struct DriveDocumentQueryMethodVersions {
     pub query_contested_documents: FeatureVersion,
     pub query_contested_documents_vote_state: FeatureVersion,
     pub query_documents_with_flags: FeatureVersion,
+    pub query_documents_aggregate: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -36,6 +39,7 @@ pub struct DriveDocumentInsertMethodVersions {
     pub add_document_for_contract_apply_and_add_to_operations: FeatureVersion,
     pub add_document_for_contract_operations: FeatureVersion,
     pub add_document_to_primary_storage: FeatureVersion,
+    pub add_indices_for_existing_documents: FeatureVersion,
     pub add_indices_for_index_level_for_contract_operations: FeatureVersion,
     pub add_indices_for_top_index_level_for_contract_operations: FeatureVersion,
     pub add_reference_for_index_level_for_contract_operations: FeatureVersion,
@@ -87,3 +91,16 @@ pub struct DriveDocumentIndexUniquenessMethodVersions {
     pub validate_document_purchase_transition_action_uniqueness: FeatureVersion,
     pub validate_document_update_price_transition_action_uniqueness: FeatureVersion,
 }
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveDocumentExpirationMethodVersions {
+    pub add_document_expiration_operations: FeatureVersion,
+    pub remove_document_expiration_operations: FeatureVersion,
+    pub fetch_expired_documents: FeatureVersion,
+    pub remove_expired_documents: FeatureVersion,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveDocumentFullTextMethodVersions {
+    pub update_full_text_index_references_operations: FeatureVersion,
+}
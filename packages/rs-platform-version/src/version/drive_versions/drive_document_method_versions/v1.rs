@@ -1,5 +1,6 @@
 use crate::version::drive_versions::drive_document_method_versions::{
     DriveDocumentDeleteMethodVersions, DriveDocumentEstimationCostsMethodVersions,
+    DriveDocumentExpirationMethodVersions, DriveDocumentFullTextMethodVersions,
     DriveDocumentIndexUniquenessMethodVersions, DriveDocumentInsertContestedMethodVersions,
     DriveDocumentInsertMethodVersions, DriveDocumentMethodVersions,
     DriveDocumentQueryMethodVersions, DriveDocumentUpdateMethodVersions,
@@ -12,6 +13,7 @@ pub const DRIVE_DOCUMENT_METHOD_VERSIONS_V1: DriveDocumentMethodVersions =
             query_contested_documents: 0,
             query_contested_documents_vote_state: 0,
             query_documents_with_flags: 0,
+            query_documents_aggregate: 0,
         },
         delete: DriveDocumentDeleteMethodVersions {
             add_estimation_costs_for_remove_document_to_primary_storage: 0,
@@ -32,6 +34,7 @@ pub const DRIVE_DOCUMENT_METHOD_VERSIONS_V1: DriveDocumentMethodVersions =
             add_document_for_contract_apply_and_add_to_operations: 0,
             add_document_for_contract_operations: 0,
             add_document_to_primary_storage: 0,
+            add_indices_for_existing_documents: 0,
             add_indices_for_index_level_for_contract_operations: 0,
             add_indices_for_top_index_level_for_contract_operations: 0,
             add_reference_for_index_level_for_contract_operations: 0,
@@ -67,4 +70,13 @@ pub const DRIVE_DOCUMENT_METHOD_VERSIONS_V1: DriveDocumentMethodVersions =
             validate_document_purchase_transition_action_uniqueness: 0,
             validate_document_update_price_transition_action_uniqueness: 0,
         },
+        expiration: DriveDocumentExpirationMethodVersions {
+            add_document_expiration_operations: 0,
+            remove_document_expiration_operations: 0,
+            fetch_expired_documents: 0,
+            remove_expired_documents: 0,
+        },
+        full_text: DriveDocumentFullTextMethodVersions {
+            update_full_text_index_references_operations: 0,
+        },
     };
@@ -12,6 +12,15 @@ pub struct DriveIdentityMethodVersions {
     pub contract_info: DriveIdentityContractInfoMethodVersions,
     pub cost_estimation: DriveIdentityCostEstimationMethodVersions,
     pub withdrawals: DriveIdentityWithdrawalMethodVersions,
+    pub state_transition_history: DriveIdentityStateTransitionHistoryMethodVersions,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveIdentityStateTransitionHistoryMethodVersions {
+    pub add_identity_state_transition_record_operations: FeatureVersion,
+    pub add_identity_state_transition_record: FeatureVersion,
+    pub fetch_identity_state_transition_records: FeatureVersion,
+    pub prove_identity_state_transition_records: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -75,6 +84,9 @@ pub struct DriveIdentityCostEstimationMethodVersions {
     pub for_token_perpetual_distribution: FeatureVersion,
     pub for_token_selling_prices: FeatureVersion,
     pub for_token_contract_infos: FeatureVersion,
+    pub for_token_order_book: FeatureVersion,
+    pub for_token_vestings: FeatureVersion,
+    pub for_token_allowances: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -6,9 +6,9 @@ use crate::version::drive_versions::drive_identity_method_versions::{
     DriveIdentityKeyHashesToIdentityInsertMethodVersions, DriveIdentityKeysFetchMethodVersions,
     DriveIdentityKeysInsertMethodVersions, DriveIdentityKeysMethodVersions,
     DriveIdentityKeysProveMethodVersions, DriveIdentityMethodVersions,
-    DriveIdentityProveMethodVersions, DriveIdentityUpdateMethodVersions,
-    DriveIdentityWithdrawalDocumentMethodVersions, DriveIdentityWithdrawalMethodVersions,
-    DriveIdentityWithdrawalTransactionIndexMethodVersions,
+    DriveIdentityProveMethodVersions, DriveIdentityStateTransitionHistoryMethodVersions,
+    DriveIdentityUpdateMethodVersions, DriveIdentityWithdrawalDocumentMethodVersions,
+    DriveIdentityWithdrawalMethodVersions, DriveIdentityWithdrawalTransactionIndexMethodVersions,
     DriveIdentityWithdrawalTransactionMethodVersions,
     DriveIdentityWithdrawalTransactionQueueMethodVersions,
 };
@@ -138,6 +138,9 @@ pub const DRIVE_IDENTITY_METHOD_VERSIONS_V1: DriveIdentityMethodVersions =
             for_root_token_ms_interval_distribution: 0,
             for_token_selling_prices: 0,
             for_token_contract_infos: 0,
+            for_token_order_book: 0,
+            for_token_vestings: 0,
+            for_token_allowances: 0,
         },
         withdrawals: DriveIdentityWithdrawalMethodVersions {
             document: DriveIdentityWithdrawalDocumentMethodVersions {
@@ -158,4 +161,10 @@ pub const DRIVE_IDENTITY_METHOD_VERSIONS_V1: DriveIdentityMethodVersions =
             },
             calculate_current_withdrawal_limit: 0,
         },
+        state_transition_history: DriveIdentityStateTransitionHistoryMethodVersions {
+            add_identity_state_transition_record_operations: 0,
+            add_identity_state_transition_record: 0,
+            fetch_identity_state_transition_records: 0,
+            prove_identity_state_transition_records: 0,
+        },
     };
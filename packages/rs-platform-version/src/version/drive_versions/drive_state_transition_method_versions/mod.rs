@@ -14,6 +14,7 @@ pub struct DriveStateTransitionMethodVersions {
 pub struct DriveStateTransitionActionConvertToHighLevelOperationsMethodVersions {
     pub data_contract_create_transition: FeatureVersion,
     pub data_contract_update_transition: FeatureVersion,
+    pub data_contract_delete_transition: FeatureVersion,
     pub document_create_transition: FeatureVersion,
     pub document_delete_transition: FeatureVersion,
     pub document_purchase_transition: FeatureVersion,
@@ -30,6 +31,7 @@ pub struct DriveStateTransitionActionConvertToHighLevelOperationsMethodVersions
     pub identity_top_up_transition: FeatureVersion,
     pub identity_update_transition: FeatureVersion,
     pub masternode_vote_transition: FeatureVersion,
+    pub vote_poll_create_transition: FeatureVersion,
     pub bump_identity_data_contract_nonce: FeatureVersion,
     pub bump_identity_nonce: FeatureVersion,
     pub partially_use_asset_lock: FeatureVersion,
@@ -41,6 +43,12 @@ pub struct DriveStateTransitionActionConvertToHighLevelOperationsMethodVersions
     pub token_claim_transition: FeatureVersion,
     pub token_direct_purchase_transition: FeatureVersion,
     pub token_set_price_for_direct_purchase_transition: FeatureVersion,
+    pub token_place_order_transition: FeatureVersion,
+    pub token_cancel_order_transition: FeatureVersion,
+    pub token_vesting_transfer_transition: FeatureVersion,
+    pub token_claim_vested_transition: FeatureVersion,
+    pub token_approve_transition: FeatureVersion,
+    pub token_transfer_from_transition: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -16,6 +16,7 @@ pub const DRIVE_STATE_TRANSITION_METHOD_VERSIONS_V1: DriveStateTransitionMethodV
             DriveStateTransitionActionConvertToHighLevelOperationsMethodVersions {
                 data_contract_create_transition: 0,
                 data_contract_update_transition: 0,
+                data_contract_delete_transition: 0,
                 document_create_transition: 0,
                 document_delete_transition: 0,
                 document_purchase_transition: 0,
@@ -32,6 +33,7 @@ pub const DRIVE_STATE_TRANSITION_METHOD_VERSIONS_V1: DriveStateTransitionMethodV
                 identity_top_up_transition: 0,
                 identity_update_transition: 0,
                 masternode_vote_transition: 0,
+                vote_poll_create_transition: 0,
                 bump_identity_data_contract_nonce: 0,
                 bump_identity_nonce: 0,
                 partially_use_asset_lock: 0,
@@ -43,5 +45,11 @@ pub const DRIVE_STATE_TRANSITION_METHOD_VERSIONS_V1: DriveStateTransitionMethodV
                 token_claim_transition: 0,
                 token_direct_purchase_transition: 0,
                 token_set_price_for_direct_purchase_transition: 0,
+                token_place_order_transition: 0,
+                token_cancel_order_transition: 0,
+                token_vesting_transfer_transition: 0,
+                token_claim_vested_transition: 0,
+                token_approve_transition: 0,
+                token_transfer_from_transition: 0,
             },
     };
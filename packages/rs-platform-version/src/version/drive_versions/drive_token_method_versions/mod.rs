@@ -37,6 +37,13 @@ pub struct DriveTokenFetchMethodVersions {
     pub token_direct_purchase_price: FeatureVersion,
     pub token_direct_purchase_prices: FeatureVersion,
     pub token_contract_info: FeatureVersion,
+    pub token_holders: FeatureVersion,
+    pub token_order: FeatureVersion,
+    pub token_order_book_orders: FeatureVersion,
+    pub token_vesting: FeatureVersion,
+    pub token_vestings: FeatureVersion,
+    pub token_allowance: FeatureVersion,
+    pub token_allowances: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -53,6 +60,10 @@ pub struct DriveTokenProveMethodVersions {
     pub token_direct_purchase_prices: FeatureVersion,
     pub perpetual_distribution_last_paid_time: FeatureVersion,
     pub token_contract_info: FeatureVersion,
+    pub token_holders: FeatureVersion,
+    pub token_order_book_orders: FeatureVersion,
+    pub token_vestings: FeatureVersion,
+    pub token_allowances: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -71,4 +82,14 @@ pub struct DriveTokenUpdateMethodVersions {
     pub unfreeze: FeatureVersion,
     pub apply_status: FeatureVersion,
     pub perpetual_distribution_next_event_for_identity_id: FeatureVersion,
+    pub insert_order: FeatureVersion,
+    pub update_order: FeatureVersion,
+    pub remove_order: FeatureVersion,
+    pub insert_vesting: FeatureVersion,
+    pub update_vesting: FeatureVersion,
+    pub remove_vesting: FeatureVersion,
+    pub insert_allowance: FeatureVersion,
+    pub update_allowance: FeatureVersion,
+    pub remove_allowance: FeatureVersion,
+    pub apply_balance_changes: FeatureVersion,
 }
@@ -21,6 +21,13 @@ pub const DRIVE_TOKEN_METHOD_VERSIONS_V1: DriveTokenMethodVersions = DriveTokenM
         token_direct_purchase_price: 0,
         token_direct_purchase_prices: 0,
         token_contract_info: 0,
+        token_holders: 0,
+        token_order: 0,
+        token_order_book_orders: 0,
+        token_vesting: 0,
+        token_vestings: 0,
+        token_allowance: 0,
+        token_allowances: 0,
     },
     prove: DriveTokenProveMethodVersions {
         identity_token_balance: 0,
@@ -35,6 +42,10 @@ pub const DRIVE_TOKEN_METHOD_VERSIONS_V1: DriveTokenMethodVersions = DriveTokenM
         token_direct_purchase_prices: 0,
         perpetual_distribution_last_paid_time: 0,
         token_contract_info: 0,
+        token_holders: 0,
+        token_order_book_orders: 0,
+        token_vestings: 0,
+        token_allowances: 0,
     },
     update: DriveTokenUpdateMethodVersions {
         create_token_trees: 0,
@@ -51,6 +62,16 @@ pub const DRIVE_TOKEN_METHOD_VERSIONS_V1: DriveTokenMethodVersions = DriveTokenM
         unfreeze: 0,
         apply_status: 0,
         perpetual_distribution_next_event_for_identity_id: 0,
+        insert_order: 0,
+        update_order: 0,
+        remove_order: 0,
+        insert_vesting: 0,
+        update_vesting: 0,
+        remove_vesting: 0,
+        insert_allowance: 0,
+        update_allowance: 0,
+        remove_allowance: 0,
+        apply_balance_changes: 0,
     },
     calculate_total_tokens_balance: 0,
     distribution: DriveTokenDistributionMethodVersions {
@@ -27,6 +27,7 @@ pub struct DriveVerifyDocumentMethodVersions {
     pub verify_proof: FeatureVersion,
     pub verify_proof_keep_serialized: FeatureVersion,
     pub verify_start_at_document_in_proof: FeatureVersion,
+    pub verify_aggregate_proof: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -45,6 +46,7 @@ pub struct DriveVerifyIdentityMethodVersions {
     pub verify_identity_revision_for_identity_id: FeatureVersion,
     pub verify_full_identity_by_non_unique_public_key_hash: FeatureVersion,
     pub verify_identity_id_by_non_unique_public_key_hash: FeatureVersion,
+    pub verify_identity_state_transition_records: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -72,6 +74,11 @@ pub struct DriveVerifyTokenMethodVersions {
     pub verify_token_direct_selling_prices: FeatureVersion,
     pub verify_token_perpetual_distribution_last_paid_time: FeatureVersion,
     pub verify_token_contract_info: FeatureVersion,
+    pub verify_token_holders: FeatureVersion,
+    pub verify_token_order_book_orders: FeatureVersion,
+    pub verify_token_vestings: FeatureVersion,
+    pub verify_token_allowance: FeatureVersion,
+    pub verify_token_allowances: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -83,6 +90,7 @@ pub struct DriveVerifyVoteMethodVersions {
     pub verify_contests_proof: FeatureVersion,
     pub verify_vote_polls_by_end_date_proof: FeatureVersion,
     pub verify_specialized_balance: FeatureVersion,
+    pub verify_yes_no_abstain_vote_poll_state_proof: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -15,6 +15,7 @@ pub const DRIVE_VERIFY_METHOD_VERSIONS_V1: DriveVerifyMethodVersions = DriveVeri
         verify_proof: 0,
         verify_proof_keep_serialized: 0,
         verify_start_at_document_in_proof: 0,
+        verify_aggregate_proof: 0,
     },
     identity: DriveVerifyIdentityMethodVersions {
         verify_full_identities_by_public_key_hashes: 0,
@@ -31,6 +32,7 @@ pub const DRIVE_VERIFY_METHOD_VERSIONS_V1: DriveVerifyMethodVersions = DriveVeri
         verify_identity_revision_for_identity_id: 0,
         verify_full_identity_by_non_unique_public_key_hash: 0,
         verify_identity_id_by_non_unique_public_key_hash: 0,
+        verify_identity_state_transition_records: 0,
     },
     group: DriveVerifyGroupMethodVersions {
         verify_group_info: 0,
@@ -54,6 +56,11 @@ pub const DRIVE_VERIFY_METHOD_VERSIONS_V1: DriveVerifyMethodVersions = DriveVeri
         verify_token_direct_selling_prices: 0,
         verify_token_perpetual_distribution_last_paid_time: 0,
         verify_token_contract_info: 0,
+        verify_token_holders: 0,
+        verify_token_order_book_orders: 0,
+        verify_token_vestings: 0,
+        verify_token_allowance: 0,
+        verify_token_allowances: 0,
     },
     single_document: DriveVerifySingleDocumentMethodVersions {
         verify_proof: 0,
@@ -76,6 +83,7 @@ pub const DRIVE_VERIFY_METHOD_VERSIONS_V1: DriveVerifyMethodVersions = DriveVeri
         verify_contests_proof: 0,
         verify_vote_polls_by_end_date_proof: 0,
         verify_specialized_balance: 0,
+        verify_yes_no_abstain_vote_poll_state_proof: 0,
     },
     state_transition: DriveVerifyStateTransitionMethodVersions {
         verify_state_transition_was_executed_with_proof: 0,
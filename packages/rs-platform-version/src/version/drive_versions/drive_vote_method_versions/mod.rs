@@ -18,6 +18,10 @@ pub struct DriveVoteFetchMethodVersions {
     pub fetch_identities_voting_for_contenders: FeatureVersion,
     pub fetch_contested_document_vote_poll_stored_info: FeatureVersion,
     pub fetch_identity_contested_resource_vote: FeatureVersion,
+    pub fetch_yes_no_abstain_vote_poll_state: FeatureVersion,
+    pub fetch_identity_yes_no_abstain_vote: FeatureVersion,
+    pub prove_yes_no_abstain_vote_poll_state: FeatureVersion,
+    pub fetch_yes_no_abstain_vote_poll_voter_strength: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -40,11 +44,15 @@ pub struct DriveVoteCleanupMethodVersions {
     pub remove_contested_resource_vote_poll_contenders_operations: FeatureVersion,
     pub remove_contested_resource_top_level_index_operations: FeatureVersion,
     pub remove_contested_resource_info_operations: FeatureVersion,
+    pub remove_yes_no_abstain_vote_poll_end_date_query_operations: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct DriveVoteInsertMethodVersions {
     pub register_identity_vote: FeatureVersion,
+    pub register_yes_no_abstain_identity_vote: FeatureVersion,
+    pub create_yes_no_abstain_vote_poll: FeatureVersion,
+    pub insert_stored_info_for_yes_no_abstain_vote_poll: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -7,6 +7,9 @@ use crate::version::drive_versions::drive_vote_method_versions::{
 pub const DRIVE_VOTE_METHOD_VERSIONS_V2: DriveVoteMethodVersions = DriveVoteMethodVersions {
     insert: DriveVoteInsertMethodVersions {
         register_identity_vote: 0,
+        register_yes_no_abstain_identity_vote: 0,
+        create_yes_no_abstain_vote_poll: 0,
+        insert_stored_info_for_yes_no_abstain_vote_poll: 0,
     },
     contested_resource_insert: DriveVoteContestedResourceInsertMethodVersions {
         register_contested_resource_identity_vote: 0,
@@ -23,6 +26,7 @@ pub const DRIVE_VOTE_METHOD_VERSIONS_V2: DriveVoteMethodVersions = DriveVoteMeth
         remove_contested_resource_vote_poll_contenders_operations: 1,
         remove_contested_resource_top_level_index_operations: 0,
         remove_contested_resource_info_operations: 0,
+        remove_yes_no_abstain_vote_poll_end_date_query_operations: 0,
     },
     setup: DriveVoteSetupMethodVersions {
         add_initial_vote_tree_main_structure_operations: 0,
@@ -34,5 +38,9 @@ pub const DRIVE_VOTE_METHOD_VERSIONS_V2: DriveVoteMethodVersions = DriveVoteMeth
         fetch_identities_voting_for_contenders: 0,
         fetch_contested_document_vote_poll_stored_info: 0,
         fetch_identity_contested_resource_vote: 0,
+        fetch_yes_no_abstain_vote_poll_state: 0,
+        fetch_identity_yes_no_abstain_vote: 0,
+        prove_yes_no_abstain_vote_poll_state: 0,
+        fetch_yes_no_abstain_vote_poll_voter_strength: 0,
     },
 };
@@ -67,6 +67,8 @@ pub struct DriveMethodVersions {
 pub struct DrivePlatformStateMethodVersions {
     pub fetch_platform_state_bytes: FeatureVersion,
     pub store_platform_state_bytes: FeatureVersion,
+    pub fetch_block_state_transitions_bytes: FeatureVersion,
+    pub store_block_state_transitions_bytes: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
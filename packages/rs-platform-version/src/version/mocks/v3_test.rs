@@ -139,6 +139,8 @@ pub const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                 process_raw_state_transitions: 0,
                 decode_raw_state_transitions: 0,
                 validate_fees_of_event: 0,
+                simulate_state_transition: 0,
+                store_identity_state_transition_history: None,
             },
             epoch: DriveAbciEpochMethodVersions {
                 gather_epoch_info: 0,
@@ -151,10 +153,13 @@ pub const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                 update_state_cache: 0,
                 update_drive_cache: 0,
                 validator_set_update: 0,
+                remove_expired_documents: 0,
             },
             platform_state_storage: DriveAbciPlatformStateStorageMethodVersions {
                 fetch_platform_state: 0,
                 store_platform_state: 0,
+                fetch_block_state_transitions: 0,
+                store_block_state_transitions: 0,
             },
         },
         validation_and_processing: DRIVE_ABCI_VALIDATION_VERSIONS_V3,
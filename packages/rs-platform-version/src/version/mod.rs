@@ -1,6 +1,6 @@
 mod protocol_version;
 
-use crate::version::v10::PROTOCOL_VERSION_10;
+use crate::version::v11::PROTOCOL_VERSION_11;
 pub use protocol_version::*;
 use std::ops::RangeInclusive;
 
@@ -16,6 +16,7 @@ pub mod system_data_contract_versions;
 mod system_limits;
 pub mod v1;
 pub mod v10;
+pub mod v11;
 pub mod v2;
 pub mod v3;
 pub mod v4;
@@ -29,5 +30,5 @@ pub type ProtocolVersion = u32;
 
 pub const ALL_VERSIONS: RangeInclusive<ProtocolVersion> = 1..=LATEST_VERSION;
 
-pub const LATEST_VERSION: ProtocolVersion = PROTOCOL_VERSION_10;
+pub const LATEST_VERSION: ProtocolVersion = PROTOCOL_VERSION_11;
 pub const INITIAL_PROTOCOL_VERSION: ProtocolVersion = 1;
@@ -0,0 +1,13 @@
+use crate::version::system_data_contract_versions::SystemDataContractVersions;
+
+pub const SYSTEM_DATA_CONTRACT_VERSIONS_V2: SystemDataContractVersions =
+    SystemDataContractVersions {
+        withdrawals: 1,
+        dpns: 1,
+        dashpay: 1,
+        masternode_reward_shares: 1,
+        feature_flags: 1,
+        wallet: 1,
+        token_history: 2, // changed to index events by block height
+        keyword_search: 1,
+    };
@@ -1,4 +1,5 @@
 pub mod v1;
+pub mod v2;
 
 #[derive(Clone, Debug, Default)]
 pub struct SystemLimits {
@@ -18,4 +19,9 @@ pub struct SystemLimits {
     // For other distributions we much calculate at each cycle the rewards, so we don't want to
     // do this that much
     pub max_token_redemption_cycles: u32,
+    // The max amount of resting orders a single order placed on a token order book can fill
+    pub max_token_order_book_fills: u16,
+    // The max amount of expired documents that are removed at the end of a single block,
+    // the rest is removed in the following blocks
+    pub max_expired_documents_removed_per_block: u16,
 }
@@ -10,4 +10,6 @@ pub const SYSTEM_LIMITS_V1: SystemLimits = SystemLimits {
     max_withdrawal_amount: 50_000_000_000_000, //500 Dash
     max_contract_group_size: 256,
     max_token_redemption_cycles: 128,
+    max_token_order_book_fills: 0, // the token order book is not available before protocol version 11
+    max_expired_documents_removed_per_block: 0, // documents don't expire before protocol version 11
 };
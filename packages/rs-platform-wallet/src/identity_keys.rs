@@ -0,0 +1,357 @@
+//! Derivation of identity keys from the wallet seed
+//!
+//! Identity keys follow DIP-9 feature paths as specified by DIP-13:
+//!
+//! - authentication keys: `m/9'/coin_type'/5'/0'/key_type'/identity_index'/key_index'`
+//! - registration funding keys: `m/9'/coin_type'/5'/1'/index`
+//! - top up funding keys: `m/9'/coin_type'/5'/2'/index`
+//!
+//! The key index of an authentication key is the key id of the public key registered on Platform,
+//! so any identity key can be re-derived from the seed knowing only the identity index.
+
+use crate::PlatformWalletError;
+use dpp::dashcore::hashes::{hash160, Hash};
+use dpp::dashcore::secp256k1::{PublicKey, Secp256k1, SecretKey};
+use dpp::dashcore::signer;
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::identity_public_key::v0::IdentityPublicKeyV0;
+use dpp::identity::signer::Signer;
+use dpp::identity::{IdentityPublicKey, KeyID, KeyType, Purpose, SecurityLevel};
+use dpp::platform_value::BinaryData;
+use dpp::ProtocolError;
+use key_wallet::bip32::{ChildNumber, DerivationPath, ExtendedPrivKey};
+use key_wallet::Network;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// DIP-9 purpose of feature derivation paths
+pub const FEATURE_PURPOSE: u32 = 9;
+
+/// DIP-9 feature index of identities
+pub const FEATURE_IDENTITIES: u32 = 5;
+
+/// DIP-13 sub feature of identity authentication keys
+pub const IDENTITY_AUTHENTICATION_SUB_FEATURE: u32 = 0;
+
+/// DIP-13 sub feature of identity registration funding keys
+pub const IDENTITY_REGISTRATION_SUB_FEATURE: u32 = 1;
+
+/// DIP-13 sub feature of identity top up funding keys
+pub const IDENTITY_TOP_UP_SUB_FEATURE: u32 = 2;
+
+/// DIP-13 key type of ECDSA authentication keys
+const ECDSA_KEY_TYPE: u32 = 0;
+
+/// Purpose and security level of an identity key to derive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdentityKeySpec {
+    /// Purpose of the key
+    pub purpose: Purpose,
+    /// Security level of the key
+    pub security_level: SecurityLevel,
+}
+
+impl IdentityKeySpec {
+    /// Create a new key spec
+    pub fn new(purpose: Purpose, security_level: SecurityLevel) -> Self {
+        Self {
+            purpose,
+            security_level,
+        }
+    }
+
+    /// Keys registered with a new identity: master, critical and high authentication keys
+    pub fn default_identity_keys() -> Vec<Self> {
+        vec![
+            Self::new(Purpose::AUTHENTICATION, SecurityLevel::MASTER),
+            Self::new(Purpose::AUTHENTICATION, SecurityLevel::CRITICAL),
+            Self::new(Purpose::AUTHENTICATION, SecurityLevel::HIGH),
+        ]
+    }
+}
+
+/// Derives identity keys from the wallet seed
+#[derive(Clone)]
+pub struct IdentityKeyDerivation {
+    master: ExtendedPrivKey,
+    network: Network,
+}
+
+impl IdentityKeyDerivation {
+    /// Create a key derivation from the wallet seed
+    pub fn from_seed(seed: &[u8], network: Network) -> Result<Self, PlatformWalletError> {
+        let master = ExtendedPrivKey::new_master(network, seed)
+            .map_err(|e| PlatformWalletError::KeyDerivation(e.to_string()))?;
+
+        Ok(Self { master, network })
+    }
+
+    /// Network the keys are derived for
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Path of an identity authentication key
+    pub fn authentication_key_path(
+        &self,
+        identity_index: u32,
+        key_index: KeyID,
+    ) -> Result<DerivationPath, PlatformWalletError> {
+        self.identities_path(&[
+            (IDENTITY_AUTHENTICATION_SUB_FEATURE, true),
+            (ECDSA_KEY_TYPE, true),
+            (identity_index, true),
+            (key_index, true),
+        ])
+    }
+
+    /// Path of the key funding the registration asset lock of an identity
+    pub fn registration_funding_key_path(
+        &self,
+        index: u32,
+    ) -> Result<DerivationPath, PlatformWalletError> {
+        self.identities_path(&[(IDENTITY_REGISTRATION_SUB_FEATURE, true), (index, false)])
+    }
+
+    /// Path of the key funding a top up asset lock
+    pub fn top_up_funding_key_path(
+        &self,
+        index: u32,
+    ) -> Result<DerivationPath, PlatformWalletError> {
+        self.identities_path(&[(IDENTITY_TOP_UP_SUB_FEATURE, true), (index, false)])
+    }
+
+    /// Private key of an identity authentication key
+    pub fn authentication_private_key(
+        &self,
+        identity_index: u32,
+        key_index: KeyID,
+    ) -> Result<[u8; 32], PlatformWalletError> {
+        self.derive(&self.authentication_key_path(identity_index, key_index)?)
+    }
+
+    /// Private key of the registration asset lock output
+    pub fn registration_funding_private_key(
+        &self,
+        index: u32,
+    ) -> Result<[u8; 32], PlatformWalletError> {
+        self.derive(&self.registration_funding_key_path(index)?)
+    }
+
+    /// Private key of a top up asset lock output
+    pub fn top_up_funding_private_key(&self, index: u32) -> Result<[u8; 32], PlatformWalletError> {
+        self.derive(&self.top_up_funding_key_path(index)?)
+    }
+
+    /// Derive an identity public key with id `key_index`, together with its private key
+    pub fn identity_public_key(
+        &self,
+        identity_index: u32,
+        key_index: KeyID,
+        spec: IdentityKeySpec,
+    ) -> Result<(IdentityPublicKey, [u8; 32]), PlatformWalletError> {
+        let private_key = self.authentication_private_key(identity_index, key_index)?;
+
+        let public_key = IdentityPublicKeyV0 {
+            id: key_index,
+            purpose: spec.purpose,
+            security_level: spec.security_level,
+            contract_bounds: None,
+            key_type: KeyType::ECDSA_SECP256K1,
+            read_only: false,
+            data: public_key_bytes(&private_key)?.to_vec().into(),
+            disabled_at: None,
+        };
+
+        Ok((IdentityPublicKey::V0(public_key), private_key))
+    }
+
+    /// Hash160 of the first (master) authentication key of an identity.
+    ///
+    /// Used to look up identities registered by this wallet.
+    pub fn identity_master_key_hash(
+        &self,
+        identity_index: u32,
+    ) -> Result<[u8; 20], PlatformWalletError> {
+        let private_key = self.authentication_private_key(identity_index, 0)?;
+
+        Ok(hash160::Hash::hash(&public_key_bytes(&private_key)?).to_byte_array())
+    }
+
+    fn identities_path(
+        &self,
+        children: &[(u32, bool)],
+    ) -> Result<DerivationPath, PlatformWalletError> {
+        let coin_type = match self.network {
+            Network::Dash => 5,
+            _ => 1,
+        };
+
+        [
+            (FEATURE_PURPOSE, true),
+            (coin_type, true),
+            (FEATURE_IDENTITIES, true),
+        ]
+        .iter()
+        .chain(children)
+        .map(|&(index, hardened)| {
+            if hardened {
+                ChildNumber::from_hardened_idx(index)
+            } else {
+                ChildNumber::from_normal_idx(index)
+            }
+            .map_err(|e| PlatformWalletError::KeyDerivation(e.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(DerivationPath::from)
+    }
+
+    fn derive(&self, path: &DerivationPath) -> Result<[u8; 32], PlatformWalletError> {
+        let secp = Secp256k1::new();
+
+        self.master
+            .derive_priv(&secp, path)
+            .map(|key| key.private_key.secret_bytes())
+            .map_err(|e| PlatformWalletError::KeyDerivation(format!("{}: {}", path, e)))
+    }
+}
+
+impl fmt::Debug for IdentityKeyDerivation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IdentityKeyDerivation")
+            .field("network", &self.network)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Compressed public key of a secp256k1 private key
+fn public_key_bytes(private_key: &[u8; 32]) -> Result<[u8; 33], PlatformWalletError> {
+    let secret_key = SecretKey::from_byte_array(private_key)
+        .map_err(|e| PlatformWalletError::KeyDerivation(e.to_string()))?;
+
+    Ok(PublicKey::from_secret_key(&Secp256k1::new(), &secret_key).serialize())
+}
+
+/// Signer holding private keys of wallet identities, indexed by public key data
+#[derive(Default, Clone)]
+pub struct IdentitySigner {
+    keys: BTreeMap<Vec<u8>, [u8; 32]>,
+}
+
+impl IdentitySigner {
+    /// Create an empty signer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the private key of an identity public key
+    pub fn add_key(&mut self, public_key: &IdentityPublicKey, private_key: [u8; 32]) {
+        self.keys.insert(public_key.data().to_vec(), private_key);
+    }
+}
+
+impl fmt::Debug for IdentitySigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IdentitySigner")
+            .field("keys", &self.keys.len())
+            .finish()
+    }
+}
+
+impl Signer for IdentitySigner {
+    fn sign(
+        &self,
+        identity_public_key: &IdentityPublicKey,
+        data: &[u8],
+    ) -> Result<BinaryData, ProtocolError> {
+        if identity_public_key.key_type() != KeyType::ECDSA_SECP256K1 {
+            return Err(ProtocolError::Generic(format!(
+                "identity signer only supports ECDSA_SECP256K1 keys, got {:?}",
+                identity_public_key.key_type()
+            )));
+        }
+
+        let private_key = self
+            .keys
+            .get(identity_public_key.data().as_slice())
+            .ok_or_else(|| {
+                ProtocolError::Generic(format!(
+                    "private key of identity public key {} not found",
+                    identity_public_key.id()
+                ))
+            })?;
+
+        Ok(signer::sign(data, private_key)?.to_vec().into())
+    }
+
+    fn can_sign_with(&self, identity_public_key: &IdentityPublicKey) -> bool {
+        identity_public_key.key_type() == KeyType::ECDSA_SECP256K1
+            && self
+                .keys
+                .contains_key(identity_public_key.data().as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: [u8; 64] = [7u8; 64];
+
+    #[test]
+    fn test_authentication_key_path() {
+        let mainnet = IdentityKeyDerivation::from_seed(&SEED, Network::Dash).unwrap();
+        let testnet = IdentityKeyDerivation::from_seed(&SEED, Network::Testnet).unwrap();
+
+        assert_eq!(
+            mainnet.authentication_key_path(2, 3).unwrap().to_string(),
+            "m/9'/5'/5'/0'/0'/2'/3'"
+        );
+        assert_eq!(
+            testnet
+                .registration_funding_key_path(4)
+                .unwrap()
+                .to_string(),
+            "m/9'/1'/5'/1'/4"
+        );
+        assert_eq!(
+            testnet.top_up_funding_key_path(0).unwrap().to_string(),
+            "m/9'/1'/5'/2'/0"
+        );
+    }
+
+    #[test]
+    fn test_derived_keys_are_deterministic() {
+        let derivation = IdentityKeyDerivation::from_seed(&SEED, Network::Testnet).unwrap();
+        let spec = IdentityKeySpec::new(Purpose::AUTHENTICATION, SecurityLevel::MASTER);
+
+        let (key, private_key) = derivation.identity_public_key(0, 0, spec).unwrap();
+        let (same_key, same_private_key) = derivation.identity_public_key(0, 0, spec).unwrap();
+        let (other_key, _) = derivation.identity_public_key(1, 0, spec).unwrap();
+
+        assert_eq!(key, same_key);
+        assert_eq!(private_key, same_private_key);
+        assert_ne!(key.data(), other_key.data());
+        assert_eq!(key.data().len(), 33);
+        assert_eq!(
+            derivation.identity_master_key_hash(0).unwrap(),
+            hash160::Hash::hash(key.data().as_slice()).to_byte_array()
+        );
+    }
+
+    #[test]
+    fn test_signer() {
+        let derivation = IdentityKeyDerivation::from_seed(&SEED, Network::Testnet).unwrap();
+        let spec = IdentityKeySpec::new(Purpose::AUTHENTICATION, SecurityLevel::HIGH);
+        let (key, private_key) = derivation.identity_public_key(0, 1, spec).unwrap();
+        let (unknown_key, _) = derivation.identity_public_key(0, 2, spec).unwrap();
+
+        let mut signer = IdentitySigner::new();
+        signer.add_key(&key, private_key);
+
+        assert!(signer.can_sign_with(&key));
+        assert!(!signer.can_sign_with(&unknown_key));
+        assert!(signer.sign(&key, b"data").is_ok());
+        assert!(signer.sign(&unknown_key, b"data").is_err());
+    }
+}
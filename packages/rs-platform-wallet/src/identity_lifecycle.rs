@@ -0,0 +1,453 @@
+//! Identity lifecycle operations of a platform wallet
+//!
+//! Registration, top ups and key updates produce signed state transitions. Broadcasting them is left
+//! to the caller; once a transition is executed on Platform, the local state is updated with
+//! [IdentityManager::add_derived_identity](crate::IdentityManager::add_derived_identity) or
+//! [PlatformWalletInfo::apply_identity_update].
+
+use crate::identity_keys::{IdentityKeyDerivation, IdentityKeySpec, IdentitySigner};
+use crate::{PlatformWalletError, PlatformWalletInfo};
+use dpp::identity::accessors::{IdentityGettersV0, IdentitySettersV0};
+use dpp::identity::identity_public_key::accessors::v0::{
+    IdentityPublicKeyGettersV0, IdentityPublicKeySettersV0,
+};
+use dpp::identity::{Identity, IdentityPublicKey, KeyID, KeyType, Purpose, SecurityLevel};
+use dpp::native_bls::NativeBlsModule;
+use dpp::prelude::{AssetLockProof, Identifier, IdentityNonce, TimestampMillis};
+use dpp::state_transition::identity_create_transition::methods::IdentityCreateTransitionMethodsV0;
+use dpp::state_transition::identity_create_transition::IdentityCreateTransition;
+use dpp::state_transition::identity_topup_transition::methods::IdentityTopUpTransitionMethodsV0;
+use dpp::state_transition::identity_topup_transition::IdentityTopUpTransition;
+use dpp::state_transition::identity_update_transition::methods::IdentityUpdateTransitionMethodsV0;
+use dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
+use dpp::state_transition::StateTransition;
+use dpp::version::PlatformVersion;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Display;
+use std::future::Future;
+
+/// Default number of consecutive unused identity indices after which discovery stops
+pub const DEFAULT_IDENTITY_DISCOVERY_GAP_LIMIT: u32 = 5;
+
+impl PlatformWalletInfo {
+    /// Create a signed identity create transition for a new identity at `identity_index`.
+    ///
+    /// The identity id is derived from the asset lock proof and its keys from the wallet seed.
+    /// `asset_lock_private_key` is the key of the asset lock output, usually
+    /// [IdentityKeyDerivation::registration_funding_private_key].
+    ///
+    /// Returns the identity to add to the wallet once the transition is executed.
+    pub fn register_identity(
+        &self,
+        keys: &IdentityKeyDerivation,
+        identity_index: u32,
+        key_specs: &[IdentityKeySpec],
+        asset_lock_proof: AssetLockProof,
+        asset_lock_private_key: &[u8; 32],
+        platform_version: &PlatformVersion,
+    ) -> Result<(Identity, StateTransition), PlatformWalletError> {
+        if let Some(existing) = self
+            .identity_manager
+            .identities
+            .values()
+            .find(|managed| managed.identity_index == Some(identity_index))
+        {
+            return Err(PlatformWalletError::IdentityIndexInUse(
+                identity_index,
+                existing.id(),
+            ));
+        }
+
+        let identity_id = asset_lock_proof.create_identifier()?;
+
+        let mut signer = IdentitySigner::new();
+        let mut public_keys = BTreeMap::new();
+        for (key_id, spec) in (0..).zip(key_specs) {
+            let (public_key, private_key) =
+                keys.identity_public_key(identity_index, key_id, *spec)?;
+            signer.add_key(&public_key, private_key);
+            public_keys.insert(key_id, public_key);
+        }
+
+        let identity = Identity::new_with_id_and_keys(identity_id, public_keys, platform_version)?;
+
+        let transition = IdentityCreateTransition::try_from_identity_with_signer(
+            &identity,
+            asset_lock_proof,
+            asset_lock_private_key,
+            &signer,
+            &NativeBlsModule,
+            0,
+            platform_version,
+        )?;
+
+        Ok((identity, transition))
+    }
+
+    /// Create a signed identity top up transition funded by a wallet asset lock.
+    ///
+    /// `asset_lock_private_key` is the key of the asset lock output, usually
+    /// [IdentityKeyDerivation::top_up_funding_private_key].
+    pub fn top_up_identity(
+        &self,
+        identity_id: &Identifier,
+        asset_lock_proof: AssetLockProof,
+        asset_lock_private_key: &[u8; 32],
+        platform_version: &PlatformVersion,
+    ) -> Result<StateTransition, PlatformWalletError> {
+        let identity = self
+            .identity_manager
+            .get_identity(identity_id)
+            .ok_or(PlatformWalletError::IdentityNotFound(*identity_id))?;
+
+        Ok(IdentityTopUpTransition::try_from_identity(
+            identity,
+            asset_lock_proof,
+            asset_lock_private_key,
+            0,
+            platform_version,
+            None,
+        )?)
+    }
+
+    /// Create a signed identity update transition adding keys derived from the wallet seed
+    /// and disabling `disable_key_ids`.
+    ///
+    /// New keys get the next free key ids. The transition is signed with the master key of the
+    /// identity, so the identity must be derived from the wallet seed.
+    ///
+    /// Returns the transition together with the added public keys.
+    pub fn update_identity_keys(
+        &self,
+        keys: &IdentityKeyDerivation,
+        identity_id: &Identifier,
+        add_key_specs: &[IdentityKeySpec],
+        disable_key_ids: &[KeyID],
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+    ) -> Result<(StateTransition, Vec<IdentityPublicKey>), PlatformWalletError> {
+        let managed = self
+            .identity_manager
+            .get_managed_identity(identity_id)
+            .ok_or(PlatformWalletError::IdentityNotFound(*identity_id))?;
+        let identity_index = managed
+            .identity_index
+            .ok_or(PlatformWalletError::IdentityNotDerived(*identity_id))?;
+        let identity = &managed.identity;
+
+        for key_id in disable_key_ids {
+            match identity.get_public_key_by_id(*key_id) {
+                None => {
+                    return Err(PlatformWalletError::IdentityKeyNotFound(
+                        *identity_id,
+                        *key_id,
+                    ))
+                }
+                Some(key) if key.security_level() == SecurityLevel::MASTER => {
+                    return Err(PlatformWalletError::InvalidIdentityData(format!(
+                        "master key {} can't be disabled",
+                        key_id
+                    )))
+                }
+                Some(_) => {}
+            }
+        }
+
+        let master_key = identity
+            .get_first_public_key_matching(
+                Purpose::AUTHENTICATION,
+                HashSet::from([SecurityLevel::MASTER]),
+                HashSet::from([KeyType::ECDSA_SECP256K1]),
+                false,
+            )
+            .ok_or_else(|| {
+                PlatformWalletError::InvalidIdentityData(format!(
+                    "identity {} has no enabled master key",
+                    identity_id
+                ))
+            })?;
+
+        let mut signer = IdentitySigner::new();
+        let (derived_master_key, master_private_key) = keys.identity_public_key(
+            identity_index,
+            master_key.id(),
+            IdentityKeySpec::new(Purpose::AUTHENTICATION, SecurityLevel::MASTER),
+        )?;
+        if derived_master_key.data() != master_key.data() {
+            return Err(PlatformWalletError::IdentityNotDerived(*identity_id));
+        }
+        signer.add_key(master_key, master_private_key);
+
+        let first_key_id = identity
+            .public_keys()
+            .keys()
+            .max()
+            .map_or(0, |key_id| key_id + 1);
+        let mut add_public_keys = Vec::with_capacity(add_key_specs.len());
+        for (key_id, spec) in (first_key_id..).zip(add_key_specs) {
+            let (public_key, private_key) =
+                keys.identity_public_key(identity_index, key_id, *spec)?;
+            signer.add_key(&public_key, private_key);
+            add_public_keys.push(public_key);
+        }
+
+        let transition = IdentityUpdateTransition::try_from_identity_with_signer(
+            identity,
+            &master_key.id(),
+            add_public_keys.clone(),
+            disable_key_ids.to_vec(),
+            nonce,
+            0,
+            &signer,
+            platform_version,
+            None,
+        )?;
+
+        Ok((transition, add_public_keys))
+    }
+
+    /// Create a signed identity update transition replacing key `key_id` with a new key
+    /// of the same purpose and security level.
+    pub fn rotate_identity_key(
+        &self,
+        keys: &IdentityKeyDerivation,
+        identity_id: &Identifier,
+        key_id: KeyID,
+        nonce: IdentityNonce,
+        platform_version: &PlatformVersion,
+    ) -> Result<(StateTransition, IdentityPublicKey), PlatformWalletError> {
+        let key = self
+            .identity_manager
+            .get_identity(identity_id)
+            .ok_or(PlatformWalletError::IdentityNotFound(*identity_id))?
+            .get_public_key_by_id(key_id)
+            .ok_or(PlatformWalletError::IdentityKeyNotFound(
+                *identity_id,
+                key_id,
+            ))?;
+        let spec = IdentityKeySpec::new(key.purpose(), key.security_level());
+
+        let (transition, mut added) = self.update_identity_keys(
+            keys,
+            identity_id,
+            &[spec],
+            &[key_id],
+            nonce,
+            platform_version,
+        )?;
+
+        Ok((transition, added.remove(0)))
+    }
+
+    /// Apply an executed identity update transition to the local identity
+    pub fn apply_identity_update(
+        &mut self,
+        identity_id: &Identifier,
+        added_public_keys: Vec<IdentityPublicKey>,
+        disabled_key_ids: &[KeyID],
+        disabled_at: TimestampMillis,
+    ) -> Result<(), PlatformWalletError> {
+        let identity = self
+            .identity_manager
+            .get_identity_mut(identity_id)
+            .ok_or(PlatformWalletError::IdentityNotFound(*identity_id))?;
+
+        for key_id in disabled_key_ids {
+            identity
+                .get_public_key_by_id_mut(*key_id)
+                .ok_or(PlatformWalletError::IdentityKeyNotFound(
+                    *identity_id,
+                    *key_id,
+                ))?
+                .set_disabled_at(disabled_at);
+        }
+        identity.add_public_keys(added_public_keys);
+        identity.bump_revision();
+
+        Ok(())
+    }
+
+    /// Discover identities registered with keys derived from the wallet seed.
+    ///
+    /// Master key hashes of consecutive identity indices are looked up with `fetch_by_key_hash`
+    /// until `gap_limit` indices in a row have no identity. Found identities are added to the wallet.
+    ///
+    /// Returns ids of the newly added identities.
+    pub async fn discover_identities<F, Fut, E>(
+        &mut self,
+        keys: &IdentityKeyDerivation,
+        gap_limit: u32,
+        mut fetch_by_key_hash: F,
+    ) -> Result<Vec<Identifier>, PlatformWalletError>
+    where
+        F: FnMut([u8; 20]) -> Fut,
+        Fut: Future<Output = Result<Option<Identity>, E>>,
+        E: Display,
+    {
+        let mut discovered = Vec::new();
+        let mut unused = 0;
+        let mut identity_index = 0;
+
+        while unused < gap_limit {
+            let key_hash = keys.identity_master_key_hash(identity_index)?;
+
+            let identity = fetch_by_key_hash(key_hash)
+                .await
+                .map_err(|e| PlatformWalletError::IdentityDiscovery(e.to_string()))?;
+
+            match identity {
+                Some(identity) => {
+                    unused = 0;
+
+                    let identity_id = identity.id();
+                    if !self.identity_manager.identities.contains_key(&identity_id) {
+                        self.identity_manager
+                            .add_derived_identity(identity, identity_index)?;
+                        discovered.push(identity_id);
+                    }
+                }
+                None => unused += 1,
+            }
+
+            identity_index += 1;
+        }
+
+        Ok(discovered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::dashcore::hashes::{hash160, Hash};
+    use dpp::identity::v0::IdentityV0;
+    use key_wallet::Network;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    const SEED: [u8; 64] = [7u8; 64];
+
+    /// Poll a future that never waits
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    fn derived_identity(keys: &IdentityKeyDerivation, identity_index: u32) -> Identity {
+        let public_keys = (0..)
+            .zip(IdentityKeySpec::default_identity_keys())
+            .map(|(key_id, spec)| {
+                let (public_key, _) = keys
+                    .identity_public_key(identity_index, key_id, spec)
+                    .unwrap();
+                (key_id, public_key)
+            })
+            .collect();
+
+        Identity::V0(IdentityV0 {
+            id: Identifier::from([identity_index as u8 + 1; 32]),
+            public_keys,
+            balance: 0,
+            revision: 0,
+        })
+    }
+
+    #[test]
+    fn test_discover_identities() {
+        let keys = IdentityKeyDerivation::from_seed(&SEED, Network::Testnet).unwrap();
+        let registered: Vec<Identity> = [0, 2]
+            .into_iter()
+            .map(|index| derived_identity(&keys, index))
+            .collect();
+        let fetch = |key_hash: [u8; 20]| {
+            let found = registered
+                .iter()
+                .find(|identity| {
+                    let master_key = identity.get_public_key_by_id(0).unwrap();
+                    hash160::Hash::hash(master_key.data().as_slice()).to_byte_array() == key_hash
+                })
+                .cloned();
+            std::future::ready(Ok::<_, String>(found))
+        };
+
+        let mut wallet = PlatformWalletInfo::new([1u8; 32], "test".to_string());
+        let discovered = block_on(wallet.discover_identities(&keys, 2, fetch)).unwrap();
+
+        assert_eq!(discovered, vec![registered[0].id(), registered[1].id()]);
+        let managed = wallet
+            .identity_manager
+            .get_managed_identity(&registered[1].id())
+            .unwrap();
+        assert_eq!(managed.identity_index, Some(2));
+        assert_eq!(wallet.identity_manager.next_identity_index(), 3);
+
+        // Known identities are not reported again
+        let discovered = block_on(wallet.discover_identities(&keys, 2, fetch)).unwrap();
+        assert!(discovered.is_empty());
+    }
+
+    #[test]
+    fn test_update_identity_keys() {
+        let keys = IdentityKeyDerivation::from_seed(&SEED, Network::Testnet).unwrap();
+        let identity = derived_identity(&keys, 0);
+        let identity_id = identity.id();
+        let platform_version = PlatformVersion::latest();
+
+        let mut wallet = PlatformWalletInfo::new([1u8; 32], "test".to_string());
+        wallet
+            .identity_manager
+            .add_derived_identity(identity, 0)
+            .unwrap();
+
+        let (transition, added) = wallet
+            .rotate_identity_key(&keys, &identity_id, 2, 1, platform_version)
+            .unwrap();
+        assert!(matches!(transition, StateTransition::IdentityUpdate(_)));
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].id(), 3);
+        assert_eq!(added[0].security_level(), SecurityLevel::HIGH);
+
+        let result =
+            wallet.update_identity_keys(&keys, &identity_id, &[], &[0], 1, platform_version);
+        assert!(matches!(
+            result,
+            Err(PlatformWalletError::InvalidIdentityData(_))
+        ));
+
+        wallet
+            .apply_identity_update(&identity_id, added, &[2], 1000)
+            .unwrap();
+        let identity = wallet.get_identity(&identity_id).unwrap();
+        assert_eq!(identity.revision(), 1);
+        assert_eq!(identity.public_keys().len(), 4);
+        assert!(identity.get_public_key_by_id(2).unwrap().is_disabled());
+    }
+
+    #[test]
+    fn test_update_keys_of_imported_identity() {
+        let keys = IdentityKeyDerivation::from_seed(&SEED, Network::Testnet).unwrap();
+        let identity = derived_identity(&keys, 0);
+        let identity_id = identity.id();
+
+        let mut wallet = PlatformWalletInfo::new([1u8; 32], "test".to_string());
+        wallet.add_identity(identity).unwrap();
+
+        let result = wallet.update_identity_keys(
+            &keys,
+            &identity_id,
+            &IdentityKeySpec::default_identity_keys(),
+            &[],
+            1,
+            PlatformVersion::latest(),
+        );
+        assert!(matches!(
+            result,
+            Err(PlatformWalletError::IdentityNotDerived(_))
+        ));
+    }
+}
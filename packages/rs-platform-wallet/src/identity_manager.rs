@@ -28,15 +28,39 @@ impl IdentityManager {
 
     /// Add an identity to the manager
     pub fn add_identity(&mut self, identity: Identity) -> Result<(), PlatformWalletError> {
-        let identity_id = identity.id();
+        self.add_managed_identity(ManagedIdentity::new(identity))
+    }
+
+    /// Add an identity whose keys are derived from the wallet seed at `identity_index`
+    pub fn add_derived_identity(
+        &mut self,
+        identity: Identity,
+        identity_index: u32,
+    ) -> Result<(), PlatformWalletError> {
+        if let Some(existing) = self
+            .identities
+            .values()
+            .find(|managed| managed.identity_index == Some(identity_index))
+        {
+            return Err(PlatformWalletError::IdentityIndexInUse(
+                identity_index,
+                existing.id(),
+            ));
+        }
+
+        self.add_managed_identity(ManagedIdentity::new_derived(identity, identity_index))
+    }
+
+    fn add_managed_identity(
+        &mut self,
+        managed_identity: ManagedIdentity,
+    ) -> Result<(), PlatformWalletError> {
+        let identity_id = managed_identity.id();
 
         if self.identities.contains_key(&identity_id) {
             return Err(PlatformWalletError::IdentityAlreadyExists(identity_id));
         }
 
-        // Create managed identity
-        let managed_identity = ManagedIdentity::new(identity);
-
         // Add the managed identity
         self.identities.insert(identity_id, managed_identity);
 
@@ -150,6 +174,15 @@ impl IdentityManager {
             .collect()
     }
 
+    /// Next unused DIP-13 identity index
+    pub fn next_identity_index(&self) -> u32 {
+        self.identities
+            .values()
+            .filter_map(|managed| managed.identity_index)
+            .max()
+            .map_or(0, |index| index + 1)
+    }
+
     /// Get total credit balance across all identities
     pub fn total_credit_balance(&self) -> u64 {
         self.identities
@@ -244,4 +277,30 @@ mod tests {
         assert_eq!(managed.last_sync_height, None);
         assert_eq!(managed.id(), identity_id);
     }
+
+    #[test]
+    fn test_derived_identities() {
+        let mut manager = IdentityManager::new();
+        assert_eq!(manager.next_identity_index(), 0);
+
+        manager
+            .add_identity(create_test_identity(Identifier::from([1u8; 32])))
+            .unwrap();
+        assert_eq!(manager.next_identity_index(), 0);
+
+        manager
+            .add_derived_identity(create_test_identity(Identifier::from([2u8; 32])), 0)
+            .unwrap();
+        manager
+            .add_derived_identity(create_test_identity(Identifier::from([3u8; 32])), 2)
+            .unwrap();
+        assert_eq!(manager.next_identity_index(), 3);
+
+        let result =
+            manager.add_derived_identity(create_test_identity(Identifier::from([4u8; 32])), 2);
+        assert!(matches!(
+            result,
+            Err(PlatformWalletError::IdentityIndexInUse(2, _))
+        ));
+    }
 }
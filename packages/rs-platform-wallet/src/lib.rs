@@ -25,9 +25,12 @@ use key_wallet::wallet::managed_wallet_info::wallet_info_interface::WalletInfoIn
 use key_wallet::wallet::managed_wallet_info::{ManagedWalletInfo, TransactionRecord};
 use key_wallet::{Address, Network, Utxo, Wallet, WalletBalance};
 use std::collections::BTreeSet;
+pub mod identity_keys;
+pub mod identity_lifecycle;
 pub mod identity_manager;
 pub mod managed_identity;
 
+pub use identity_keys::{IdentityKeyDerivation, IdentityKeySpec, IdentitySigner};
 pub use identity_manager::IdentityManager;
 pub use managed_identity::ManagedIdentity;
 
@@ -379,6 +382,24 @@ pub enum PlatformWalletError {
 
     #[error("Invalid identity data: {0}")]
     InvalidIdentityData(String),
+
+    #[error("Identity index {0} is already used by identity {1}")]
+    IdentityIndexInUse(u32, Identifier),
+
+    #[error("Keys of identity {0} are not derived from the wallet seed")]
+    IdentityNotDerived(Identifier),
+
+    #[error("Key {1} not found in identity {0}")]
+    IdentityKeyNotFound(Identifier, dpp::identity::KeyID),
+
+    #[error("Key derivation failed: {0}")]
+    KeyDerivation(String),
+
+    #[error("Identity discovery failed: {0}")]
+    IdentityDiscovery(String),
+
+    #[error("Protocol error: {0}")]
+    Protocol(#[from] dpp::ProtocolError),
 }
 
 #[cfg(test)]
@@ -24,6 +24,9 @@ pub struct ManagedIdentity {
 
     /// Whether this identity is active
     pub is_active: bool,
+
+    /// DIP-13 index of the identity, if its keys are derived from the wallet seed
+    pub identity_index: Option<u32>,
 }
 
 impl ManagedIdentity {
@@ -35,6 +38,15 @@ impl ManagedIdentity {
             last_sync_height: None,
             label: None,
             is_active: true,
+            identity_index: None,
+        }
+    }
+
+    /// Create a new managed identity with keys derived from the wallet seed at `identity_index`
+    pub fn new_derived(identity: Identity, identity_index: u32) -> Self {
+        Self {
+            identity_index: Some(identity_index),
+            ..Self::new(identity)
         }
     }
 
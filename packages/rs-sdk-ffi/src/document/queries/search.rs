@@ -65,8 +65,9 @@ fn parse_where_operator(op: &str) -> Result<WhereOperator, FFIError> {
         "<=" | "lte" => Ok(WhereOperator::LessThanOrEquals),
         "in" => Ok(WhereOperator::In),
         "startsWith" => Ok(WhereOperator::StartsWith),
-        // "contains" and "elementMatch" are not supported in the current version
-        "contains" | "elementMatch" => Err(FFIError::InternalError(format!(
+        "contains" => Ok(WhereOperator::Contains),
+        // "elementMatch" is not supported in the current version
+        "elementMatch" => Err(FFIError::InternalError(format!(
             "Operator '{}' is not supported",
             op
         ))),
@@ -0,0 +1,702 @@
+//! [ContextProvider] that persists data contracts, token configurations and quorum public keys on disk.
+//!
+//! [PersistentContextProvider] wraps another provider, like the trusted HTTP provider or a Core RPC based one,
+//! and only asks it for data that isn't in its store yet. Data contracts and token configurations are fetched
+//! from Platform with proofs once the [Sdk] is set; quorum public keys are taken from the wrapped provider,
+//! checked by a [QuorumVerifier] and appended to a chain of pinned quorum keys. Everything is kept per network,
+//! so the provider is warm right after a restart.
+//!
+//! Data contracts and token configurations can be updated on Platform, so they are fetched again once they are
+//! older than the cache TTL (see [PersistentContextProvider::with_cache_ttl]). Quorum keys never change.
+
+mod quorum_chain;
+mod store;
+
+pub use quorum_chain::QuorumChainEntry;
+
+use crate::platform::Fetch;
+use crate::sync::block_on;
+use crate::{Error, Sdk};
+use arc_swap::ArcSwapAny;
+use dash_context_provider::{ContextProvider, ContextProviderError};
+use dpp::dashcore::Network;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::accessors::v1::DataContractV1Getters;
+use dpp::data_contract::TokenConfiguration;
+use dpp::prelude::{CoreBlockHeight, DataContract, Identifier};
+use dpp::tokens::contract_info::v0::TokenContractInfoV0Accessors;
+use dpp::tokens::contract_info::TokenContractInfo;
+use dpp::version::PlatformVersion;
+use quorum_chain::QuorumChain;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use store::ProviderStore;
+
+/// Default time data contracts and token configurations are used before they are fetched again
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Verifies quorum public keys before they are accepted into the quorum chain.
+///
+/// Implement it to check keys against a source independent of the upstream provider, like the masternode list
+/// of an SPV client. [LowLevelDashCoreClient](crate::core::LowLevelDashCoreClient) verifies keys against
+/// a Dash Core node.
+pub trait QuorumVerifier: Send + Sync {
+    /// Verify that `public_key` is the threshold public key of the quorum,
+    /// valid at `core_chain_locked_height`.
+    fn verify_quorum_public_key(
+        &self,
+        quorum_type: u32,
+        quorum_hash: [u8; 32],
+        public_key: &[u8; 48],
+        core_chain_locked_height: CoreBlockHeight,
+    ) -> Result<(), ContextProviderError>;
+}
+
+/// Verifies quorum public keys against the quorum info of the Dash Core node
+#[cfg(feature = "mocks")]
+impl QuorumVerifier for crate::core::LowLevelDashCoreClient {
+    fn verify_quorum_public_key(
+        &self,
+        quorum_type: u32,
+        quorum_hash: [u8; 32],
+        public_key: &[u8; 48],
+        _core_chain_locked_height: CoreBlockHeight,
+    ) -> Result<(), ContextProviderError> {
+        let core_public_key = self.get_quorum_public_key(quorum_type, quorum_hash)?;
+
+        if &core_public_key != public_key {
+            return Err(ContextProviderError::InvalidQuorum(format!(
+                "public key of quorum type {} hash {} doesn't match the one known to Dash Core",
+                quorum_type,
+                hex::encode(quorum_hash)
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Context provider persisting fetched data on disk.
+///
+/// Lookups go through an in-memory cache, then the on-disk store, and only then to Platform
+/// or the wrapped provider. See [module documentation](self) for details.
+pub struct PersistentContextProvider {
+    network: Network,
+    store: ProviderStore,
+    quorum_chain: QuorumChain,
+    quorum_verifier: Box<dyn QuorumVerifier>,
+    /// Source of quorum public keys, platform activation height, and data not available on Platform
+    upstream: Box<dyn ContextProvider>,
+    /// [Sdk] used to fetch proven data contracts and token configurations.
+    ///
+    /// We use [Arc] as we have circular dependencies between Sdk and ContextProvider.
+    sdk: ArcSwapAny<Arc<Option<Sdk>>>,
+    cache_ttl: Duration,
+    data_contracts: RwLock<HashMap<Identifier, Cached<Arc<DataContract>>>>,
+    token_configurations: RwLock<HashMap<Identifier, Cached<TokenConfiguration>>>,
+    activation_height: RwLock<Option<CoreBlockHeight>>,
+}
+
+/// In-memory cache entry
+struct Cached<T> {
+    value: T,
+    expires_at: Instant,
+}
+
+impl<T: Clone> Cached<T> {
+    /// Value of the entry, unless it expired
+    fn get(&self) -> Option<T> {
+        (Instant::now() < self.expires_at).then(|| self.value.clone())
+    }
+}
+
+impl PersistentContextProvider {
+    /// Open the provider store at `path` for the `network`.
+    ///
+    /// Quorum keys and other data missing in the store are requested from the `upstream` provider.
+    /// Quorum keys are accepted only if the `quorum_verifier` confirms them.
+    pub fn new<P: ContextProvider + 'static, V: QuorumVerifier + 'static>(
+        path: impl AsRef<Path>,
+        network: Network,
+        upstream: P,
+        quorum_verifier: V,
+    ) -> Result<Self, Error> {
+        let path = path.as_ref();
+
+        let store = ProviderStore::open(path, network).map_err(|e| {
+            Error::Config(format!(
+                "cannot open context provider store {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        let quorum_chain = QuorumChain::load(&store.quorum_chain_path())?;
+
+        Ok(Self {
+            network,
+            store,
+            quorum_chain,
+            quorum_verifier: Box::new(quorum_verifier),
+            upstream: Box::new(upstream),
+            sdk: ArcSwapAny::new(Arc::new(None)),
+            cache_ttl: DEFAULT_CACHE_TTL,
+            data_contracts: RwLock::new(HashMap::new()),
+            token_configurations: RwLock::new(HashMap::new()),
+            activation_height: RwLock::new(None),
+        })
+    }
+
+    /// Fetch data contracts and token configurations again once they are older than `ttl`.
+    ///
+    /// Defaults to [DEFAULT_CACHE_TTL].
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Set the Sdk to use when fetching data from Platform.
+    /// This is useful when the Sdk is created after the ContextProvider.
+    ///
+    /// Without the Sdk, data contracts and token configurations missing in the store are requested
+    /// from the upstream provider and are not persisted.
+    pub fn set_sdk(&self, sdk: Option<Sdk>) {
+        self.sdk.store(Arc::new(sdk));
+    }
+
+    /// Network the provider stores data for
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// All quorums pinned in the quorum chain, ordered by core height
+    pub fn quorum_chain(&self) -> Vec<QuorumChainEntry> {
+        self.quorum_chain.entries()
+    }
+
+    /// Remove a data contract from the caches, so it is fetched again on next use.
+    ///
+    /// Use it after the contract was updated on Platform.
+    pub fn invalidate_data_contract(&self, id: &Identifier) -> Result<(), ContextProviderError> {
+        self.data_contracts
+            .write()
+            .expect("data contracts lock poisoned")
+            .remove(id);
+
+        self.store.remove_data_contract(id)
+    }
+
+    /// Remove a token configuration from the caches, so it is fetched again on next use.
+    pub fn invalidate_token_configuration(
+        &self,
+        token_id: &Identifier,
+    ) -> Result<(), ContextProviderError> {
+        self.token_configurations
+            .write()
+            .expect("token configurations lock poisoned")
+            .remove(token_id);
+
+        self.store.remove_token_configuration(token_id)
+    }
+
+    /// Time left until data fetched `age` ago expires
+    fn remaining_ttl(&self, age: Duration) -> Option<Duration> {
+        self.cache_ttl.checked_sub(age).filter(|ttl| !ttl.is_zero())
+    }
+
+    fn cache_data_contract(&self, contract: Arc<DataContract>, ttl: Duration) {
+        self.data_contracts
+            .write()
+            .expect("data contracts lock poisoned")
+            .insert(
+                contract.id(),
+                Cached {
+                    value: contract,
+                    expires_at: Instant::now() + ttl,
+                },
+            );
+    }
+
+    fn cache_token_configuration(
+        &self,
+        token_id: Identifier,
+        config: TokenConfiguration,
+        ttl: Duration,
+    ) {
+        self.token_configurations
+            .write()
+            .expect("token configurations lock poisoned")
+            .insert(
+                token_id,
+                Cached {
+                    value: config,
+                    expires_at: Instant::now() + ttl,
+                },
+            );
+    }
+
+    /// Fetch data contract from Platform with proof and persist it
+    fn fetch_data_contract(
+        &self,
+        sdk: &Sdk,
+        id: Identifier,
+    ) -> Result<Option<Arc<DataContract>>, ContextProviderError> {
+        let sdk_cloned = sdk.clone();
+
+        let (data_contract, metadata) =
+            block_on(
+                async move { DataContract::fetch_with_metadata(&sdk_cloned, id, None).await },
+            )?
+            .map_err(|e| ContextProviderError::DataContractFailure(e.to_string()))?;
+
+        let Some(data_contract) = data_contract else {
+            // The contract might have been stored before it was removed from Platform
+            self.store.remove_data_contract(&id)?;
+
+            return Ok(None);
+        };
+
+        self.store
+            .write_data_contract(&data_contract, metadata.height, sdk.version())?;
+
+        Ok(Some(Arc::new(data_contract)))
+    }
+
+    /// Fetch token configuration from Platform with proof and persist it
+    fn fetch_token_configuration(
+        &self,
+        sdk: &Sdk,
+        token_id: Identifier,
+    ) -> Result<Option<TokenConfiguration>, ContextProviderError> {
+        let sdk_cloned = sdk.clone();
+
+        let contract_info =
+            block_on(async move { TokenContractInfo::fetch(&sdk_cloned, token_id).await })?
+                .map_err(|e| ContextProviderError::TokenConfigurationFailure(e.to_string()))?;
+
+        let Some(contract_info) = contract_info else {
+            self.store.remove_token_configuration(&token_id)?;
+
+            return Ok(None);
+        };
+
+        let Some(data_contract) =
+            self.get_data_contract(&contract_info.contract_id(), sdk.version())?
+        else {
+            return Err(ContextProviderError::TokenConfigurationFailure(format!(
+                "data contract {} of token {} not found",
+                contract_info.contract_id(),
+                token_id
+            )));
+        };
+
+        let config = data_contract
+            .expected_token_configuration(contract_info.token_contract_position())
+            .map_err(|e| ContextProviderError::TokenConfigurationFailure(e.to_string()))?
+            .clone();
+
+        self.store.write_token_configuration(&token_id, &config)?;
+
+        Ok(Some(config))
+    }
+}
+
+impl ContextProvider for PersistentContextProvider {
+    fn get_data_contract(
+        &self,
+        id: &Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Arc<DataContract>>, ContextProviderError> {
+        if let Some(contract) = self
+            .data_contracts
+            .read()
+            .expect("data contracts lock poisoned")
+            .get(id)
+            .and_then(Cached::get)
+        {
+            return Ok(Some(contract));
+        }
+
+        if let Some(stored) = self.store.read_data_contract(id, platform_version)? {
+            match self.remaining_ttl(stored.age) {
+                Some(ttl) => {
+                    tracing::trace!(%id, height = stored.height, "data contract loaded from store");
+
+                    let contract = Arc::new(stored.contract);
+                    self.cache_data_contract(contract.clone(), ttl);
+
+                    return Ok(Some(contract));
+                }
+                None => {
+                    tracing::trace!(%id, height = stored.height, "stored data contract expired")
+                }
+            }
+        }
+
+        let sdk_guard = self.sdk.load();
+
+        let contract = match sdk_guard.as_ref() {
+            Some(sdk) => self.fetch_data_contract(sdk, *id)?,
+            None => self.upstream.get_data_contract(id, platform_version)?,
+        };
+
+        if let Some(ref contract) = contract {
+            self.cache_data_contract(contract.clone(), self.cache_ttl);
+        }
+
+        Ok(contract)
+    }
+
+    fn get_token_configuration(
+        &self,
+        token_id: &Identifier,
+    ) -> Result<Option<TokenConfiguration>, ContextProviderError> {
+        if let Some(config) = self
+            .token_configurations
+            .read()
+            .expect("token configurations lock poisoned")
+            .get(token_id)
+            .and_then(Cached::get)
+        {
+            return Ok(Some(config));
+        }
+
+        if let Some(stored) = self.store.read_token_configuration(token_id)? {
+            if let Some(ttl) = self.remaining_ttl(stored.age) {
+                self.cache_token_configuration(*token_id, stored.config.clone(), ttl);
+
+                return Ok(Some(stored.config));
+            }
+        }
+
+        let sdk_guard = self.sdk.load();
+
+        let config = match sdk_guard.as_ref() {
+            Some(sdk) => self.fetch_token_configuration(sdk, *token_id)?,
+            None => self.upstream.get_token_configuration(token_id)?,
+        };
+
+        if let Some(ref config) = config {
+            self.cache_token_configuration(*token_id, config.clone(), self.cache_ttl);
+        }
+
+        Ok(config)
+    }
+
+    fn get_quorum_public_key(
+        &self,
+        quorum_type: u32,
+        quorum_hash: [u8; 32],
+        core_chain_locked_height: u32,
+    ) -> Result<[u8; 48], ContextProviderError> {
+        if let Some(entry) = self.quorum_chain.get(quorum_type, &quorum_hash) {
+            return Ok(entry.public_key);
+        }
+
+        let public_key = self.upstream.get_quorum_public_key(
+            quorum_type,
+            quorum_hash,
+            core_chain_locked_height,
+        )?;
+
+        self.quorum_verifier.verify_quorum_public_key(
+            quorum_type,
+            quorum_hash,
+            &public_key,
+            core_chain_locked_height,
+        )?;
+
+        self.quorum_chain.append(QuorumChainEntry {
+            quorum_type,
+            quorum_hash,
+            public_key,
+            core_height: core_chain_locked_height,
+        })?;
+
+        Ok(public_key)
+    }
+
+    fn get_platform_activation_height(&self) -> Result<CoreBlockHeight, ContextProviderError> {
+        if let Some(height) = *self
+            .activation_height
+            .read()
+            .expect("activation height lock poisoned")
+        {
+            return Ok(height);
+        }
+
+        let height = match self.store.read_activation_height()? {
+            Some(height) => height,
+            None => {
+                let height = self.upstream.get_platform_activation_height()?;
+                self.store.write_activation_height(height)?;
+                height
+            }
+        };
+
+        *self
+            .activation_height
+            .write()
+            .expect("activation height lock poisoned") = Some(height);
+
+        Ok(height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Compressed generator of the BLS12-381 G1 group, a valid public key
+    const PUBLIC_KEY: [u8; 48] = [
+        0x97, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c, 0x4f, 0xa9, 0xac,
+        0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05, 0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b,
+        0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f, 0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb,
+        0x22, 0xc6, 0xbb,
+    ];
+
+    /// Upstream provider counting requests
+    #[derive(Default)]
+    struct CountingProvider {
+        public_key: [u8; 48],
+        data_contract: Option<Arc<DataContract>>,
+        requests: Arc<AtomicUsize>,
+    }
+
+    impl ContextProvider for CountingProvider {
+        fn get_data_contract(
+            &self,
+            _id: &Identifier,
+            _platform_version: &PlatformVersion,
+        ) -> Result<Option<Arc<DataContract>>, ContextProviderError> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            Ok(self.data_contract.clone())
+        }
+
+        fn get_token_configuration(
+            &self,
+            _token_id: &Identifier,
+        ) -> Result<Option<TokenConfiguration>, ContextProviderError> {
+            Ok(None)
+        }
+
+        fn get_quorum_public_key(
+            &self,
+            _quorum_type: u32,
+            _quorum_hash: [u8; 32],
+            _core_chain_locked_height: u32,
+        ) -> Result<[u8; 48], ContextProviderError> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            Ok(self.public_key)
+        }
+
+        fn get_platform_activation_height(&self) -> Result<CoreBlockHeight, ContextProviderError> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            Ok(1320)
+        }
+    }
+
+    struct AcceptingVerifier;
+
+    impl QuorumVerifier for AcceptingVerifier {
+        fn verify_quorum_public_key(
+            &self,
+            _quorum_type: u32,
+            _quorum_hash: [u8; 32],
+            _public_key: &[u8; 48],
+            _core_chain_locked_height: CoreBlockHeight,
+        ) -> Result<(), ContextProviderError> {
+            Ok(())
+        }
+    }
+
+    struct RejectingVerifier;
+
+    impl QuorumVerifier for RejectingVerifier {
+        fn verify_quorum_public_key(
+            &self,
+            _quorum_type: u32,
+            quorum_hash: [u8; 32],
+            _public_key: &[u8; 48],
+            _core_chain_locked_height: CoreBlockHeight,
+        ) -> Result<(), ContextProviderError> {
+            Err(ContextProviderError::InvalidQuorum(hex::encode(
+                quorum_hash,
+            )))
+        }
+    }
+
+    #[test]
+    fn test_quorum_keys_are_persisted_and_pinned() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let upstream = CountingProvider {
+            public_key: PUBLIC_KEY,
+            requests: requests.clone(),
+        };
+        let provider = PersistentContextProvider::new(
+            dir.path(),
+            Network::Regtest,
+            upstream,
+            AcceptingVerifier,
+        )
+        .expect("open provider");
+
+        for _ in 0..2 {
+            let key = provider
+                .get_quorum_public_key(106, [1; 32], 1000)
+                .expect("get quorum public key");
+            assert_eq!(key, PUBLIC_KEY);
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        drop(provider);
+
+        // Reopened provider is warm and ignores keys reported by upstream for pinned quorums
+        let upstream = CountingProvider {
+            public_key: [0xab; 48],
+            requests: requests.clone(),
+        };
+        let provider = PersistentContextProvider::new(
+            dir.path(),
+            Network::Regtest,
+            upstream,
+            AcceptingVerifier,
+        )
+        .expect("reopen provider");
+
+        let key = provider
+            .get_quorum_public_key(106, [1; 32], 2000)
+            .expect("get quorum public key");
+        assert_eq!(key, PUBLIC_KEY);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        assert_eq!(
+            provider.quorum_chain(),
+            vec![QuorumChainEntry {
+                quorum_type: 106,
+                quorum_hash: [1; 32],
+                public_key: PUBLIC_KEY,
+                core_height: 1000,
+            }]
+        );
+
+        // Store of another network is independent
+        let provider = PersistentContextProvider::new(
+            dir.path(),
+            Network::Testnet,
+            CountingProvider::default(),
+            AcceptingVerifier,
+        )
+        .expect("open provider");
+        assert!(provider.quorum_chain().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_quorum_keys_are_rejected() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+
+        let provider = PersistentContextProvider::new(
+            dir.path(),
+            Network::Regtest,
+            CountingProvider {
+                public_key: [0; 48],
+                ..Default::default()
+            },
+            AcceptingVerifier,
+        )
+        .expect("open provider");
+
+        assert_matches!(
+            provider.get_quorum_public_key(106, [1; 32], 1000),
+            Err(ContextProviderError::InvalidQuorum(_))
+        );
+
+        let provider = PersistentContextProvider::new(
+            dir.path(),
+            Network::Regtest,
+            CountingProvider {
+                public_key: PUBLIC_KEY,
+                ..Default::default()
+            },
+            RejectingVerifier,
+        )
+        .expect("open provider");
+
+        assert_matches!(
+            provider.get_quorum_public_key(106, [1; 32], 1000),
+            Err(ContextProviderError::InvalidQuorum(_))
+        );
+        assert!(provider.quorum_chain().is_empty());
+    }
+
+    #[test]
+    fn test_activation_height_is_persisted() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        for _ in 0..2 {
+            let provider = PersistentContextProvider::new(
+                dir.path(),
+                Network::Regtest,
+                CountingProvider {
+                    requests: requests.clone(),
+                    ..Default::default()
+                },
+                AcceptingVerifier,
+            )
+            .expect("open provider");
+
+            assert_eq!(
+                provider
+                    .get_platform_activation_height()
+                    .expect("get activation height"),
+                1320
+            );
+        }
+
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_expired_data_contracts_are_fetched_again() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let platform_version = PlatformVersion::latest();
+        let contract = Arc::new(
+            load_system_data_contract(SystemDataContract::DPNS, platform_version)
+                .expect("load dpns contract"),
+        );
+
+        for (ttl, expected_requests) in [(DEFAULT_CACHE_TTL, 1), (Duration::ZERO, 3)] {
+            let requests = Arc::new(AtomicUsize::new(0));
+
+            let provider = PersistentContextProvider::new(
+                dir.path(),
+                Network::Regtest,
+                CountingProvider {
+                    data_contract: Some(contract.clone()),
+                    requests: requests.clone(),
+                    ..Default::default()
+                },
+                AcceptingVerifier,
+            )
+            .expect("open provider")
+            .with_cache_ttl(ttl);
+
+            for _ in 0..3 {
+                let fetched = provider
+                    .get_data_contract(&contract.id(), platform_version)
+                    .expect("get data contract")
+                    .expect("data contract exists");
+                assert_eq!(fetched.id(), contract.id());
+            }
+
+            assert_eq!(requests.load(Ordering::SeqCst), expected_requests);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Chain of verified quorum public keys.
+//!
+//! Every quorum key accepted by [PersistentContextProvider](super::PersistentContextProvider) is appended
+//! to the chain file as a fixed size record and pinned: once a quorum hash is known, any other public key
+//! reported for it is rejected. The chain is loaded on start, so known quorums never need to be fetched again.
+
+use super::store::store_error;
+use dash_context_provider::ContextProviderError;
+use dpp::bls_signatures::{Bls12381G2Impl, PublicKey as BlsPublicKey};
+use dpp::prelude::CoreBlockHeight;
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// Size of a single record in the chain file:
+/// quorum type (4), quorum hash (32), public key (48), core height (4)
+const RECORD_SIZE: usize = 4 + 32 + 48 + 4;
+
+/// A quorum public key accepted into the chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuorumChainEntry {
+    /// LLMQ type of the quorum
+    pub quorum_type: u32,
+    /// Hash of the quorum
+    pub quorum_hash: [u8; 32],
+    /// Threshold public key of the quorum
+    pub public_key: [u8; 48],
+    /// Core chain locked height at which the quorum was first required
+    pub core_height: CoreBlockHeight,
+}
+
+impl QuorumChainEntry {
+    fn to_bytes(self) -> [u8; RECORD_SIZE] {
+        let mut bytes = [0u8; RECORD_SIZE];
+        bytes[0..4].copy_from_slice(&self.quorum_type.to_be_bytes());
+        bytes[4..36].copy_from_slice(&self.quorum_hash);
+        bytes[36..84].copy_from_slice(&self.public_key);
+        bytes[84..88].copy_from_slice(&self.core_height.to_be_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; RECORD_SIZE]) -> Self {
+        let mut quorum_hash = [0u8; 32];
+        quorum_hash.copy_from_slice(&bytes[4..36]);
+        let mut public_key = [0u8; 48];
+        public_key.copy_from_slice(&bytes[36..84]);
+
+        Self {
+            quorum_type: u32::from_be_bytes(bytes[0..4].try_into().expect("4 bytes")),
+            quorum_hash,
+            public_key,
+            core_height: u32::from_be_bytes(bytes[84..88].try_into().expect("4 bytes")),
+        }
+    }
+}
+
+/// Persistent chain of quorum public keys
+pub(crate) struct QuorumChain {
+    path: PathBuf,
+    entries: RwLock<BTreeMap<(u32, [u8; 32]), QuorumChainEntry>>,
+}
+
+impl QuorumChain {
+    /// Load the chain from `path`.
+    ///
+    /// A partially written trailing record is dropped. Conflicting records mean the file was tampered with,
+    /// so they are reported as an error.
+    pub(crate) fn load(path: &Path) -> Result<Self, ContextProviderError> {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(store_error(path, e)),
+        };
+
+        let aligned_len = bytes.len() - bytes.len() % RECORD_SIZE;
+        if aligned_len != bytes.len() {
+            tracing::warn!(path = %path.display(), "truncating partially written quorum chain record");
+
+            OpenOptions::new()
+                .write(true)
+                .open(path)
+                .and_then(|file| file.set_len(aligned_len as u64))
+                .map_err(|e| store_error(path, e))?;
+        }
+
+        let mut entries = BTreeMap::new();
+
+        for record in bytes.chunks_exact(RECORD_SIZE) {
+            let entry = QuorumChainEntry::from_bytes(record.try_into().expect("record size"));
+
+            if let Some(existing) = entries.insert((entry.quorum_type, entry.quorum_hash), entry) {
+                if existing.public_key != entry.public_key {
+                    return Err(conflicting_key_error(&entry));
+                }
+            }
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries: RwLock::new(entries),
+        })
+    }
+
+    /// Get a pinned quorum
+    pub(crate) fn get(&self, quorum_type: u32, quorum_hash: &[u8; 32]) -> Option<QuorumChainEntry> {
+        self.entries
+            .read()
+            .expect("quorum chain lock poisoned")
+            .get(&(quorum_type, *quorum_hash))
+            .copied()
+    }
+
+    /// All quorums in the chain, ordered by core height
+    pub(crate) fn entries(&self) -> Vec<QuorumChainEntry> {
+        let mut entries: Vec<_> = self
+            .entries
+            .read()
+            .expect("quorum chain lock poisoned")
+            .values()
+            .copied()
+            .collect();
+        entries.sort_by_key(|entry| entry.core_height);
+
+        entries
+    }
+
+    /// Validate the quorum public key and append it to the chain.
+    ///
+    /// Appending an already pinned quorum with the same key is a no-op.
+    pub(crate) fn append(&self, entry: QuorumChainEntry) -> Result<(), ContextProviderError> {
+        BlsPublicKey::<Bls12381G2Impl>::try_from(entry.public_key.as_slice()).map_err(|e| {
+            ContextProviderError::InvalidQuorum(format!(
+                "invalid public key of quorum {}: {}",
+                hex::encode(entry.quorum_hash),
+                e
+            ))
+        })?;
+
+        let mut entries = self.entries.write().expect("quorum chain lock poisoned");
+
+        if let Some(existing) = entries.get(&(entry.quorum_type, entry.quorum_hash)) {
+            return if existing.public_key == entry.public_key {
+                Ok(())
+            } else {
+                Err(conflicting_key_error(&entry))
+            };
+        }
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(&entry.to_bytes()))
+            .map_err(|e| store_error(&self.path, e))?;
+
+        entries.insert((entry.quorum_type, entry.quorum_hash), entry);
+
+        Ok(())
+    }
+}
+
+fn conflicting_key_error(entry: &QuorumChainEntry) -> ContextProviderError {
+    ContextProviderError::InvalidQuorum(format!(
+        "public key of quorum type {} hash {} conflicts with the pinned one",
+        entry.quorum_type,
+        hex::encode(entry.quorum_hash)
+    ))
+}
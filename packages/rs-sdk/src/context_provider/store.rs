@@ -0,0 +1,258 @@
+//! On-disk store used by [PersistentContextProvider](super::PersistentContextProvider).
+//!
+//! Layout of the store directory:
+//!
+//! ```text
+//! <path>/<network>/
+//!     data_contracts/<hex contract id>.bin
+//!     token_configurations/<hex token id>.bin
+//!     quorum_chain.v0.dat
+//!     activation_height
+//! ```
+//!
+//! Data contract files start with the big endian platform height at which the contract was proven,
+//! followed by the platform serialized contract. Token configurations are bincode encoded.
+//! Modification time of the files is used as the time the data was fetched.
+
+use dash_context_provider::ContextProviderError;
+use dpp::bincode;
+use dpp::dashcore::Network;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::TokenConfiguration;
+use dpp::prelude::{BlockHeight, CoreBlockHeight, DataContract, Identifier};
+use dpp::serialization::{
+    PlatformDeserializableWithPotentialValidationFromVersionedStructure,
+    PlatformSerializableWithPlatformVersion,
+};
+use dpp::version::PlatformVersion;
+use hex::ToHex;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+const DATA_CONTRACTS_DIR: &str = "data_contracts";
+const TOKEN_CONFIGURATIONS_DIR: &str = "token_configurations";
+const QUORUM_CHAIN_FILE: &str = "quorum_chain.v0.dat";
+const ACTIVATION_HEIGHT_FILE: &str = "activation_height";
+
+static BINCODE_CONFIG: bincode::config::Configuration = bincode::config::standard();
+
+/// Data contract read from the store
+pub(crate) struct StoredDataContract {
+    /// The contract
+    pub(crate) contract: DataContract,
+    /// Platform height at which the contract was proven
+    pub(crate) height: BlockHeight,
+    /// Time elapsed since the contract was stored
+    pub(crate) age: Duration,
+}
+
+/// Token configuration read from the store
+pub(crate) struct StoredTokenConfiguration {
+    /// The token configuration
+    pub(crate) config: TokenConfiguration,
+    /// Time elapsed since the token configuration was stored
+    pub(crate) age: Duration,
+}
+
+/// Store of context provider data for a single network
+pub(crate) struct ProviderStore {
+    root: PathBuf,
+}
+
+impl ProviderStore {
+    /// Open the store at `path`, creating directories of the `network` if needed
+    pub(crate) fn open(path: &Path, network: Network) -> io::Result<Self> {
+        let root = path.join(network.to_string());
+
+        fs::create_dir_all(root.join(DATA_CONTRACTS_DIR))?;
+        fs::create_dir_all(root.join(TOKEN_CONFIGURATIONS_DIR))?;
+
+        Ok(Self { root })
+    }
+
+    /// Path of the quorum chain file
+    pub(crate) fn quorum_chain_path(&self) -> PathBuf {
+        self.root.join(QUORUM_CHAIN_FILE)
+    }
+
+    /// Read a data contract, if it was stored.
+    ///
+    /// Files that can't be decoded are removed and reported as missing.
+    pub(crate) fn read_data_contract(
+        &self,
+        id: &Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<StoredDataContract>, ContextProviderError> {
+        let path = self.data_contract_path(id);
+
+        let Some((bytes, age)) = read(&path)? else {
+            return Ok(None);
+        };
+
+        let decoded = bytes
+            .split_first_chunk::<8>()
+            .ok_or_else(|| "truncated file".to_string())
+            .and_then(|(height, contract)| {
+                DataContract::versioned_deserialize(contract, false, platform_version)
+                    .map(|contract| StoredDataContract {
+                        contract,
+                        height: BlockHeight::from_be_bytes(*height),
+                        age,
+                    })
+                    .map_err(|e| e.to_string())
+            });
+
+        match decoded {
+            Ok(stored) => Ok(Some(stored)),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = e, "removing undecodable data contract from store");
+                remove(&path)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Store a data contract proven at platform `height`
+    pub(crate) fn write_data_contract(
+        &self,
+        contract: &DataContract,
+        height: BlockHeight,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), ContextProviderError> {
+        let serialized = contract
+            .serialize_to_bytes_with_platform_version(platform_version)
+            .map_err(|e| ContextProviderError::DataContractFailure(e.to_string()))?;
+
+        let mut bytes = Vec::with_capacity(8 + serialized.len());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes.extend_from_slice(&serialized);
+
+        write(&self.data_contract_path(&contract.id()), &bytes)
+    }
+
+    /// Remove a data contract from the store
+    pub(crate) fn remove_data_contract(&self, id: &Identifier) -> Result<(), ContextProviderError> {
+        remove(&self.data_contract_path(id))
+    }
+
+    /// Read a token configuration, if it was stored
+    pub(crate) fn read_token_configuration(
+        &self,
+        token_id: &Identifier,
+    ) -> Result<Option<StoredTokenConfiguration>, ContextProviderError> {
+        let path = self.token_configuration_path(token_id);
+
+        let Some((bytes, age)) = read(&path)? else {
+            return Ok(None);
+        };
+
+        match bincode::decode_from_slice(&bytes, BINCODE_CONFIG) {
+            Ok((config, _)) => Ok(Some(StoredTokenConfiguration { config, age })),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "removing undecodable token configuration from store");
+                remove(&path)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Remove a token configuration from the store
+    pub(crate) fn remove_token_configuration(
+        &self,
+        token_id: &Identifier,
+    ) -> Result<(), ContextProviderError> {
+        remove(&self.token_configuration_path(token_id))
+    }
+
+    /// Store a token configuration
+    pub(crate) fn write_token_configuration(
+        &self,
+        token_id: &Identifier,
+        config: &TokenConfiguration,
+    ) -> Result<(), ContextProviderError> {
+        let bytes = bincode::encode_to_vec(config, BINCODE_CONFIG)
+            .map_err(|e| ContextProviderError::TokenConfigurationFailure(e.to_string()))?;
+
+        write(&self.token_configuration_path(token_id), &bytes)
+    }
+
+    /// Read platform activation height, if it was stored
+    pub(crate) fn read_activation_height(
+        &self,
+    ) -> Result<Option<CoreBlockHeight>, ContextProviderError> {
+        let bytes = read(&self.root.join(ACTIVATION_HEIGHT_FILE))?;
+
+        Ok(bytes
+            .and_then(|(bytes, _)| bytes.try_into().ok())
+            .map(CoreBlockHeight::from_be_bytes))
+    }
+
+    /// Store platform activation height
+    pub(crate) fn write_activation_height(
+        &self,
+        height: CoreBlockHeight,
+    ) -> Result<(), ContextProviderError> {
+        write(
+            &self.root.join(ACTIVATION_HEIGHT_FILE),
+            &height.to_be_bytes(),
+        )
+    }
+
+    fn data_contract_path(&self, id: &Identifier) -> PathBuf {
+        self.root
+            .join(DATA_CONTRACTS_DIR)
+            .join(format!("{}.bin", id.encode_hex::<String>()))
+    }
+
+    fn token_configuration_path(&self, token_id: &Identifier) -> PathBuf {
+        self.root
+            .join(TOKEN_CONFIGURATIONS_DIR)
+            .join(format!("{}.bin", token_id.encode_hex::<String>()))
+    }
+}
+
+/// Read the file and the time elapsed since it was written
+fn read(path: &Path) -> Result<Option<(Vec<u8>, Duration)>, ContextProviderError> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(store_error(path, e)),
+    };
+
+    let modified = fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|e| store_error(path, e))?;
+
+    // Files modified in the future are treated as just written
+    let age = SystemTime::now()
+        .duration_since(modified)
+        .unwrap_or_default();
+
+    Ok(Some((bytes, age)))
+}
+
+/// Write the file atomically, so a crash never leaves a partially written entry behind
+fn write(path: &Path, bytes: &[u8]) -> Result<(), ContextProviderError> {
+    let tmp = path.with_extension("tmp");
+
+    fs::write(&tmp, bytes)
+        .and_then(|_| fs::rename(&tmp, path))
+        .map_err(|e| store_error(path, e))
+}
+
+fn remove(path: &Path) -> Result<(), ContextProviderError> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != ErrorKind::NotFound => Err(store_error(path, e)),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn store_error(path: &Path, error: io::Error) -> ContextProviderError {
+    ContextProviderError::Generic(format!(
+        "context provider store {}: {}",
+        path.display(),
+        error
+    ))
+}
@@ -8,7 +8,7 @@ use dpp::serialization::PlatformDeserializable;
 use dpp::version::PlatformVersionError;
 use dpp::{dashcore_rpc, ProtocolError};
 use rs_dapi_client::transport::TransportError;
-use rs_dapi_client::{CanRetry, DapiClientError, ExecutionError};
+use rs_dapi_client::{CanRetry, DapiClientError, ErrorClass, ExecutionError};
 use std::fmt::Debug;
 use std::time::Duration;
 
@@ -187,6 +187,14 @@ impl CanRetry for Error {
     fn can_retry(&self) -> bool {
         matches!(self, Error::StaleNode(..) | Error::TimeoutReached(_, _))
     }
+
+    fn error_class(&self) -> ErrorClass {
+        match self {
+            Error::StaleNode(_) => ErrorClass::StaleNode,
+            Error::TimeoutReached(_, _) => ErrorClass::Timeout,
+            _ => ErrorClass::Permanent,
+        }
+    }
 }
 
 /// Server returned stale metadata
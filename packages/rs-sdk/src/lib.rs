@@ -62,6 +62,8 @@
 #![allow(rustdoc::private_intra_doc_links)]
 #![allow(clippy::result_large_err)]
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod context_provider;
 pub mod core;
 pub mod error;
 mod internal_cache;
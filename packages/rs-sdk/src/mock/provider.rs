@@ -101,11 +101,7 @@ impl GrpcContextProvider {
 
     /// Save quorum public key to disk.
     ///
-    /// Files are named: `quorum_pubkey-<int_quorum_type>-<hex_quorum_hash>.json`
-    ///
-    /// Note that this will overwrite files with the same quorum type and quorum hash.
-    ///
-    /// Any errors are logged on `warn` level and ignored.
+    /// See [dump_quorum_public_key()] for details.
     #[cfg(feature = "mocks")]
     fn dump_quorum_public_key(
         &self,
@@ -114,49 +110,18 @@ impl GrpcContextProvider {
         _core_chain_locked_height: u32,
         public_key: &[u8],
     ) {
-        use hex::ToHex;
-
-        let path = match &self.dump_dir {
-            Some(p) => p,
-            None => return,
-        };
-
-        let encoded = hex::encode(public_key);
-
-        let file = path.join(format!(
-            "quorum_pubkey-{}-{}.json",
-            quorum_type,
-            quorum_hash.encode_hex::<String>()
-        ));
-
-        if let Err(e) = std::fs::write(file, encoded) {
-            tracing::warn!("Unable to write dump file {:?}: {}", path, e);
+        if let Some(path) = &self.dump_dir {
+            dump_quorum_public_key(path, quorum_type, quorum_hash, public_key);
         }
     }
 
     /// Save data contract to disk.
     ///
-    /// Files are named: `quorum_pubkey-<int_quorum_type>-<hex_quorum_hash>.json`
-    ///
-    /// Note that this will overwrite files with the same quorum type and quorum hash.
-    ///
-    /// Any errors are logged on `warn` level and ignored.
+    /// See [dump_data_contract()] for details.
     #[cfg(feature = "mocks")]
     fn dump_data_contract(&self, data_contract: &DataContract) {
-        use dpp::data_contract::accessors::v0::DataContractV0Getters;
-        use hex::ToHex;
-
-        let path = match &self.dump_dir {
-            Some(p) => p,
-            None => return,
-        };
-        let id = data_contract.id();
-
-        let file = path.join(format!("data_contract-{}.json", id.encode_hex::<String>()));
-
-        let encoded = serde_json::to_vec(data_contract).expect("serialize data contract");
-        if let Err(e) = std::fs::write(file, encoded) {
-            tracing::warn!("Unable to write dump file {:?}: {}", path, e);
+        if let Some(path) = &self.dump_dir {
+            dump_data_contract(path, data_contract);
         }
     }
 }
@@ -241,6 +206,113 @@ impl ContextProvider for GrpcContextProvider {
     }
 }
 
+/// Context provider that saves quorum public keys and data contracts returned by another provider to disk.
+///
+/// Used by [SdkBuilder::with_session_recording()](crate::SdkBuilder::with_session_recording()), so that
+/// [MockContextProvider](dash_context_provider::MockContextProvider) can serve them when the session is replayed.
+pub struct RecordingContextProvider {
+    inner: Box<dyn ContextProvider>,
+    dump_dir: std::path::PathBuf,
+}
+
+impl RecordingContextProvider {
+    /// Create new context provider that records responses of `inner` in `dump_dir`.
+    pub fn new(inner: Box<dyn ContextProvider>, dump_dir: std::path::PathBuf) -> Self {
+        Self { inner, dump_dir }
+    }
+}
+
+impl ContextProvider for RecordingContextProvider {
+    fn get_data_contract(
+        &self,
+        id: &Identifier,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<Arc<DataContract>>, ContextProviderError> {
+        let data_contract = self.inner.get_data_contract(id, platform_version)?;
+
+        if let Some(ref dc) = data_contract {
+            dump_data_contract(&self.dump_dir, dc);
+        }
+
+        Ok(data_contract)
+    }
+
+    fn get_token_configuration(
+        &self,
+        token_id: &Identifier,
+    ) -> Result<Option<TokenConfiguration>, ContextProviderError> {
+        self.inner.get_token_configuration(token_id)
+    }
+
+    fn get_quorum_public_key(
+        &self,
+        quorum_type: u32,
+        quorum_hash: [u8; 32],
+        core_chain_locked_height: u32,
+    ) -> Result<[u8; 48], ContextProviderError> {
+        let key =
+            self.inner
+                .get_quorum_public_key(quorum_type, quorum_hash, core_chain_locked_height)?;
+
+        dump_quorum_public_key(&self.dump_dir, quorum_type, quorum_hash, &key);
+
+        Ok(key)
+    }
+
+    fn get_platform_activation_height(&self) -> Result<CoreBlockHeight, ContextProviderError> {
+        self.inner.get_platform_activation_height()
+    }
+}
+
+/// Save quorum public key to disk.
+///
+/// Files are named: `quorum_pubkey-<int_quorum_type>-<hex_quorum_hash>.json`
+///
+/// Note that this will overwrite files with the same quorum type and quorum hash.
+///
+/// Any errors are logged on `warn` level and ignored.
+fn dump_quorum_public_key(
+    path: &std::path::Path,
+    quorum_type: u32,
+    quorum_hash: [u8; 32],
+    public_key: &[u8],
+) {
+    use hex::ToHex;
+
+    let encoded = hex::encode(public_key);
+
+    let file = path.join(format!(
+        "quorum_pubkey-{}-{}.json",
+        quorum_type,
+        quorum_hash.encode_hex::<String>()
+    ));
+
+    if let Err(e) = std::fs::write(file, encoded) {
+        tracing::warn!("Unable to write dump file {:?}: {}", path, e);
+    }
+}
+
+/// Save data contract to disk.
+///
+/// Files are named: `data_contract-<hex_data_contract_id>.json`
+///
+/// Note that this will overwrite files with the same data contract id.
+///
+/// Any errors are logged on `warn` level and ignored.
+fn dump_data_contract(path: &std::path::Path, data_contract: &DataContract) {
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use hex::ToHex;
+
+    let id = data_contract.id();
+
+    let file = path.join(format!("data_contract-{}.json", id.encode_hex::<String>()));
+
+    let encoded = serde_json::to_vec(data_contract).expect("serialize data contract");
+    if let Err(e) = std::fs::write(file, encoded) {
+        tracing::warn!("Unable to write dump file {:?}: {}", path, e);
+    }
+}
+
 /// Thread-safe cache of various objects inside the SDK.
 ///
 /// This is used to cache objects that are expensive to fetch from Platform, like data contracts.
@@ -4,10 +4,16 @@ use dpp::bincode::config::standard;
 use dpp::data_contract::associated_token::token_perpetual_distribution::reward_distribution_moment::RewardDistributionMoment;
 use dpp::data_contract::group::Group;
 use dpp::group::group_action::GroupAction;
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::prelude::BlockHeight;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
 use dpp::tokens::contract_info::TokenContractInfo;
 use dpp::tokens::info::IdentityTokenInfo;
+use dpp::tokens::order_book::TokenOrder;
 use dpp::tokens::status::TokenStatus;
 use dpp::tokens::token_pricing_schedule::TokenPricingSchedule;
+use dpp::tokens::vesting::TokenVesting;
 use dpp::{
     bincode,
     block::{extended_epoch_info::ExtendedEpochInfo, finalized_epoch_info::FinalizedEpochInfo},
@@ -24,13 +30,20 @@ use dpp::{
     voting::votes::{resource_vote::ResourceVote, Vote},
 };
 use drive::grovedb::Element;
+use drive_proof_verifier::types::block_state_transitions::BlockStateTransitions;
+use drive_proof_verifier::types::document_aggregate::DocumentsAggregate;
 use drive_proof_verifier::types::evonode_status::EvoNodeStatus;
 use drive_proof_verifier::types::groups::GroupActions;
+use drive_proof_verifier::types::identity_state_transitions::IdentityStateTransitions;
 use drive_proof_verifier::types::identity_token_balance::{
-    IdentitiesTokenBalances, IdentityTokenBalances,
+    IdentitiesTokenBalances, IdentityTokenBalances, TokenHolders,
 };
+use drive_proof_verifier::types::token_allowances::TokenAllowances;
+use drive_proof_verifier::types::token_events::{TokenEventRecord, TokenEvents};
 use drive_proof_verifier::types::token_info::{IdentitiesTokenInfos, IdentityTokenInfos};
+use drive_proof_verifier::types::token_order_book::TokenOrderBookOrders;
 use drive_proof_verifier::types::token_status::TokenStatuses;
+use drive_proof_verifier::types::token_vestings::TokenVestings;
 use drive_proof_verifier::types::{
     Contenders, ContestedResources, CurrentQuorumsInfo, ElementFetchRequestItem,
     IdentityBalanceAndRevision, IndexMap, MasternodeProtocolVote, PrefundedSpecializedBalance,
@@ -334,6 +347,20 @@ impl MockResponse for IdentitiesTokenBalances {
     }
 }
 
+impl MockResponse for TokenHolders {
+    fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        self.0.mock_serialize(sdk)
+    }
+
+    fn mock_deserialize(sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        let map = RetrievedValues::mock_deserialize(sdk, buf);
+        Self(map)
+    }
+}
+
 impl MockResponse for IdentityTokenInfos {
     fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
         // Clone and collect into vector
@@ -406,6 +433,123 @@ impl MockResponse for TokenStatuses {
     }
 }
 
+impl MockResponse for TokenOrderBookOrders {
+    fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        // Clone and collect into vector, keeping the order of the book
+        let vec: Vec<(Identifier, Option<TokenOrder>)> =
+            self.iter().map(|(k, v)| (*k, v.clone())).collect();
+
+        // Serialize vector
+        platform_encode_to_vec(vec, BINCODE_CONFIG, sdk.version())
+            .expect("encode TokenOrderBookOrders")
+    }
+
+    fn mock_deserialize(sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        // deserialize vector
+        let vec: Vec<(Identifier, Option<TokenOrder>)> =
+            platform_versioned_decode_from_slice(buf, BINCODE_CONFIG, sdk.version())
+                .expect("decode TokenOrderBookOrders");
+
+        RetrievedValues::from_iter(vec)
+    }
+}
+
+impl MockResponse for TokenVestings {
+    fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        // Clone and collect into vector, keeping the order of the vestings
+        let vec: Vec<(Identifier, Option<TokenVesting>)> =
+            self.iter().map(|(k, v)| (*k, v.clone())).collect();
+
+        // Serialize vector
+        platform_encode_to_vec(vec, BINCODE_CONFIG, sdk.version()).expect("encode TokenVestings")
+    }
+
+    fn mock_deserialize(sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        // deserialize vector
+        let vec: Vec<(Identifier, Option<TokenVesting>)> =
+            platform_versioned_decode_from_slice(buf, BINCODE_CONFIG, sdk.version())
+                .expect("decode TokenVestings");
+
+        RetrievedValues::from_iter(vec)
+    }
+}
+
+impl MockResponse for TokenAllowances {
+    fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        // Clone and collect into vector, keeping the order of the allowances
+        let vec: Vec<(TokenAllowanceSpender, Option<TokenAllowance>)> =
+            self.iter().map(|(k, v)| (*k, v.clone())).collect();
+
+        // Serialize vector
+        platform_encode_to_vec(vec, BINCODE_CONFIG, sdk.version()).expect("encode TokenAllowances")
+    }
+
+    fn mock_deserialize(sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        // deserialize vector
+        let vec: Vec<(TokenAllowanceSpender, Option<TokenAllowance>)> =
+            platform_versioned_decode_from_slice(buf, BINCODE_CONFIG, sdk.version())
+                .expect("decode TokenAllowances");
+
+        RetrievedValues::from_iter(vec)
+    }
+}
+
+impl MockResponse for IdentityStateTransitions {
+    fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        // Clone and collect into vector, keeping the order of the state transitions
+        let vec: Vec<((BlockHeight, u32), Option<IdentityStateTransitionRecord>)> =
+            self.iter().map(|(k, v)| (*k, v.clone())).collect();
+
+        // Serialize vector
+        platform_encode_to_vec(vec, BINCODE_CONFIG, sdk.version())
+            .expect("encode IdentityStateTransitions")
+    }
+
+    fn mock_deserialize(sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        // deserialize vector
+        let vec: Vec<((BlockHeight, u32), Option<IdentityStateTransitionRecord>)> =
+            platform_versioned_decode_from_slice(buf, BINCODE_CONFIG, sdk.version())
+                .expect("decode IdentityStateTransitions");
+
+        RetrievedValues::from_iter(vec)
+    }
+}
+
+impl MockResponse for TokenEvents {
+    fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
+        // Clone and collect into vector, keeping the order of the events
+        let vec: Vec<(Identifier, Option<TokenEventRecord>)> =
+            self.iter().map(|(k, v)| (*k, v.clone())).collect();
+
+        // Serialize vector
+        platform_encode_to_vec(vec, BINCODE_CONFIG, sdk.version()).expect("encode TokenEvents")
+    }
+
+    fn mock_deserialize(sdk: &MockDashPlatformSdk, buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        // deserialize vector
+        let vec: Vec<(Identifier, Option<TokenEventRecord>)> =
+            platform_versioned_decode_from_slice(buf, BINCODE_CONFIG, sdk.version())
+                .expect("decode TokenEvents");
+
+        RetrievedValues::from_iter(vec)
+    }
+}
+
 impl MockResponse for TokenContractInfo {
     fn mock_serialize(&self, sdk: &MockDashPlatformSdk) -> Vec<u8> {
         platform_encode_to_vec(self, BINCODE_CONFIG, sdk.version())
@@ -479,8 +623,10 @@ impl_mock_response!(Voters);
 impl_mock_response!(VotePollsGroupedByTimestamp);
 impl_mock_response!(PrefundedSpecializedBalance);
 impl_mock_response!(TotalCreditsInPlatform);
+impl_mock_response!(DocumentsAggregate);
 impl_mock_response!(ElementFetchRequestItem);
 impl_mock_response!(EvoNodeStatus);
+impl_mock_response!(BlockStateTransitions);
 impl_mock_response!(CurrentQuorumsInfo);
 impl_mock_response!(Group);
 impl_mock_response!(TokenPricingSchedule);
@@ -5,7 +5,7 @@ use super::MockResponse;
 use crate::{
     platform::{
         types::{evonode::EvoNode, identity::IdentityRequest},
-        DocumentQuery, Fetch, FetchMany, Query,
+        DocumentAggregateQuery, DocumentQuery, Fetch, FetchMany, Query,
     },
     sync::block_on,
     Error, Sdk,
@@ -13,6 +13,7 @@ use crate::{
 use arc_swap::ArcSwapOption;
 use dapi_grpc::platform::v0::{Proof, ResponseMetadata};
 use dapi_grpc::{
+    core::v0 as core_proto,
     mock::Mockable,
     platform::v0::{self as proto},
 };
@@ -101,6 +102,9 @@ impl MockDashPlatformSdk {
     /// This function can be used to load expectations after the Sdk is created, or use alternative location.
     /// Expectation files must be prefixed with [DapiClient::DUMP_FILE_PREFIX] and
     /// have `.json` extension.
+    ///
+    /// Files recorded with [SdkBuilder::with_session_recording()](crate::SdkBuilder::with_session_recording())
+    /// are loaded in the order they were recorded, so repeated requests get their responses in the same order.
     pub fn load_expectations_sync<P: AsRef<std::path::Path>>(
         &mut self,
         dir: P,
@@ -115,7 +119,7 @@ impl MockDashPlatformSdk {
             ))
         })?;
 
-        let files: Vec<PathBuf> = entries
+        let mut files: Vec<PathBuf> = entries
             .into_iter()
             .filter_map(|x| x.ok())
             .filter(|f| {
@@ -126,6 +130,12 @@ impl MockDashPlatformSdk {
             .map(|f| f.path())
             .collect();
 
+        // Responses recorded in a session must be loaded in the order they were received
+        files.sort_by_key(|f| {
+            f.file_name()
+                .and_then(|name| DapiClient::dump_session_sequence(&name.to_string_lossy()))
+        });
+
         let mut dapi = block_on(self.dapi.clone().lock_owned())?;
 
         for filename in &files {
@@ -134,6 +144,9 @@ impl MockDashPlatformSdk {
 
             match request_type {
                 "DocumentQuery" => load_expectation::<DocumentQuery>(&mut dapi, filename)?,
+                "DocumentAggregateQuery" => {
+                    load_expectation::<DocumentAggregateQuery>(&mut dapi, filename)?
+                }
                 "GetEpochsInfoRequest" => {
                     load_expectation::<proto::GetEpochsInfoRequest>(&mut dapi, filename)?
                 }
@@ -157,6 +170,9 @@ impl MockDashPlatformSdk {
                 "GetIdentityContractNonceRequest" => {
                     load_expectation::<proto::GetIdentityContractNonceRequest>(&mut dapi, filename)?
                 }
+                "GetIdentityStateTransitionsRequest" => load_expectation::<
+                    proto::GetIdentityStateTransitionsRequest,
+                >(&mut dapi, filename)?,
                 "GetIdentityBalanceAndRevisionRequest" => load_expectation::<
                     proto::GetIdentityBalanceAndRevisionRequest,
                 >(&mut dapi, filename)?,
@@ -205,6 +221,21 @@ impl MockDashPlatformSdk {
                 "GetIdentitiesTokenBalancesRequest" => load_expectation::<
                     proto::GetIdentitiesTokenBalancesRequest,
                 >(&mut dapi, filename)?,
+                "GetTokenHoldersRequest" => {
+                    load_expectation::<proto::GetTokenHoldersRequest>(&mut dapi, filename)?
+                }
+                "GetTokenOrderBookRequest" => {
+                    load_expectation::<proto::GetTokenOrderBookRequest>(&mut dapi, filename)?
+                }
+                "GetTokenVestingsRequest" => {
+                    load_expectation::<proto::GetTokenVestingsRequest>(&mut dapi, filename)?
+                }
+                "GetTokenAllowancesRequest" => {
+                    load_expectation::<proto::GetTokenAllowancesRequest>(&mut dapi, filename)?
+                }
+                "GetTokenEventsRequest" => {
+                    load_expectation::<proto::GetTokenEventsRequest>(&mut dapi, filename)?
+                }
                 "GetIdentityTokenInfosRequest" => {
                     load_expectation::<proto::GetIdentityTokenInfosRequest>(&mut dapi, filename)?
                 }
@@ -238,6 +269,60 @@ impl MockDashPlatformSdk {
                         &mut dapi, filename,
                     )?
                 }
+                "GetIdentityNonceRequest" => {
+                    load_expectation::<proto::GetIdentityNonceRequest>(&mut dapi, filename)?
+                }
+                "GetIdentityByPublicKeyHashRequest" => load_expectation::<
+                    proto::GetIdentityByPublicKeyHashRequest,
+                >(&mut dapi, filename)?,
+                "GetIdentityByNonUniquePublicKeyHashRequest" => {
+                    load_expectation::<proto::GetIdentityByNonUniquePublicKeyHashRequest>(
+                        &mut dapi, filename,
+                    )?
+                }
+                "GetIdentitiesBalancesRequest" => {
+                    load_expectation::<proto::GetIdentitiesBalancesRequest>(&mut dapi, filename)?
+                }
+                "GetFinalizedEpochInfosRequest" => {
+                    load_expectation::<proto::GetFinalizedEpochInfosRequest>(&mut dapi, filename)?
+                }
+                "GetEvonodesProposedEpochBlocksByIdsRequest" => {
+                    load_expectation::<proto::GetEvonodesProposedEpochBlocksByIdsRequest>(
+                        &mut dapi, filename,
+                    )?
+                }
+                "GetEvonodesProposedEpochBlocksByRangeRequest" => {
+                    load_expectation::<proto::GetEvonodesProposedEpochBlocksByRangeRequest>(
+                        &mut dapi, filename,
+                    )?
+                }
+                "GetCurrentQuorumsInfoRequest" => {
+                    load_expectation::<proto::GetCurrentQuorumsInfoRequest>(&mut dapi, filename)?
+                }
+                "GetTokenContractInfoRequest" => {
+                    load_expectation::<proto::GetTokenContractInfoRequest>(&mut dapi, filename)?
+                }
+                "GetStatusRequest" => {
+                    load_expectation::<proto::GetStatusRequest>(&mut dapi, filename)?
+                }
+                "GetBlockStateTransitionsRequest" => {
+                    load_expectation::<proto::GetBlockStateTransitionsRequest>(&mut dapi, filename)?
+                }
+                "BroadcastStateTransitionRequest" => {
+                    load_expectation::<proto::BroadcastStateTransitionRequest>(&mut dapi, filename)?
+                }
+                "WaitForStateTransitionResultRequest" => load_expectation::<
+                    proto::WaitForStateTransitionResultRequest,
+                >(&mut dapi, filename)?,
+                "GetTransactionRequest" => {
+                    load_expectation::<core_proto::GetTransactionRequest>(&mut dapi, filename)?
+                }
+                "BroadcastTransactionRequest" => load_expectation::<
+                    core_proto::BroadcastTransactionRequest,
+                >(&mut dapi, filename)?,
+                "GetBlockchainStatusRequest" => {
+                    load_expectation::<core_proto::GetBlockchainStatusRequest>(&mut dapi, filename)?
+                }
                 _ => {
                     return Err(Error::Config(format!(
                         "unknown request type {} in {}, missing match arm in load_expectations?",
@@ -478,6 +563,16 @@ fn load_expectation<T: TransportRequest>(
             ))
         })?
         .deserialize();
-    dapi_guard.expect(&data.0, &data.1)?;
+
+    let session_sequence = path
+        .file_name()
+        .and_then(|name| DapiClient::dump_session_sequence(&name.to_string_lossy()));
+
+    if session_sequence.is_some() {
+        dapi_guard.expect_next(&data.0, &data.1);
+    } else {
+        dapi_guard.expect(&data.0, &data.1)?;
+    }
+
     Ok(())
 }
@@ -19,13 +19,16 @@ pub mod types;
 pub mod documents;
 pub mod dpns_usernames;
 pub mod group_actions;
+pub mod identity_state_transitions;
 pub mod tokens;
 
 pub use dapi_grpc::platform::v0 as proto;
 pub use dash_context_provider::ContextProvider;
 #[cfg(feature = "mocks")]
 pub use dash_context_provider::MockContextProvider;
+pub use documents::document_aggregate_query::DocumentAggregateQuery;
 pub use documents::document_query::DocumentQuery;
+pub use documents::document_subscription::DocumentSubscriptionQuery;
 pub use dpp::{
     self as dpp,
     document::Document,
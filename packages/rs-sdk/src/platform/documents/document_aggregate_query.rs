@@ -0,0 +1,225 @@
+//! Count and sum of documents matching a [DocumentQuery].
+
+use crate::platform::documents::document_query::{serialize_vec_to_cbor, DocumentQuery};
+use crate::platform::Fetch;
+use crate::{error::Error, sdk::Sdk};
+use dapi_grpc::platform::v0::get_documents_aggregate_request::get_documents_aggregate_request_v0::AggregateFunction;
+use dapi_grpc::platform::v0::get_documents_aggregate_request::{
+    GetDocumentsAggregateRequestV0, Version,
+};
+use dapi_grpc::platform::v0::get_documents_request::get_documents_request_v0::Start;
+use dapi_grpc::platform::v0::{
+    self as platform_proto, GetDocumentsAggregateRequest, Proof, ResponseMetadata,
+};
+use dash_context_provider::ContextProvider;
+use dpp::dashcore::Network;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::prelude::Identifier;
+use dpp::version::PlatformVersion;
+use drive::config::DEFAULT_QUERY_LIMIT;
+use drive::query::{DocumentAggregateFunction, DriveDocumentAggregateQuery, DriveDocumentQuery};
+use drive_proof_verifier::types::document_aggregate::DocumentsAggregate;
+use drive_proof_verifier::FromProof;
+use rs_dapi_client::transport::{
+    AppliedRequestSettings, BoxFuture, TransportError, TransportRequest,
+};
+
+/// Request that is used to count or sum documents on the Dash Platform.
+///
+/// A single request covers one range of at most `limit` documents of the inner
+/// [DocumentQuery], starting after the document given in [`Start::StartAfter`].
+/// Use [DocumentAggregateQuery::fetch_total] to aggregate over all matching documents.
+#[derive(Debug, Clone, PartialEq, dapi_grpc_macros::Mockable)]
+#[cfg_attr(feature = "mocks", derive(serde::Serialize, serde::Deserialize))]
+pub struct DocumentAggregateQuery {
+    /// Documents to aggregate
+    pub document_query: DocumentQuery,
+    /// Integer property to sum, or `None` to count documents
+    pub sum_property: Option<String>,
+}
+
+impl DocumentAggregateQuery {
+    /// Count documents matching the provided query.
+    pub fn count(document_query: DocumentQuery) -> Self {
+        Self {
+            document_query,
+            sum_property: None,
+        }
+    }
+
+    /// Sum an integer property of documents matching the provided query.
+    pub fn sum(document_query: DocumentQuery, property: &str) -> Self {
+        Self {
+            document_query,
+            sum_property: Some(property.to_string()),
+        }
+    }
+
+    /// Continue aggregation after the provided document.
+    pub fn with_start_after(mut self, document_id: Identifier) -> Self {
+        self.document_query.start = Some(Start::StartAfter(document_id.to_vec()));
+
+        self
+    }
+
+    /// Number of documents covered by a single request.
+    ///
+    /// Always sent to Platform explicitly, so the proved range can be reconstructed
+    /// regardless of node configuration.
+    fn range_limit(&self) -> u16 {
+        match self.document_query.limit {
+            0 => DEFAULT_QUERY_LIMIT,
+            limit => limit.min(u16::MAX as u32) as u16,
+        }
+    }
+
+    fn start_after(&self) -> Result<Vec<u8>, Error> {
+        match &self.document_query.start {
+            None => Ok(vec![]),
+            Some(Start::StartAfter(after)) => Ok(after.clone()),
+            Some(Start::StartAt(_)) => Err(Error::Generic(
+                "aggregate queries only support start after".to_string(),
+            )),
+        }
+    }
+
+    /// Fetch the aggregate over all documents matching the query.
+    ///
+    /// Sends consecutive requests, each verified against its proof, until all matching
+    /// documents are covered.
+    ///
+    /// Every matching document is proved and downloaded, `limit` documents per request,
+    /// so the cost is linear in the number of matching documents. Narrow the query with
+    /// where clauses rather than aggregating very large collections.
+    pub async fn fetch_total(self, sdk: &Sdk) -> Result<DocumentsAggregate, Error> {
+        let mut total = DocumentsAggregate::default();
+        let mut query = self;
+
+        loop {
+            let range = DocumentsAggregate::fetch(sdk, query.clone())
+                .await?
+                .unwrap_or_default();
+
+            total.count = total
+                .count
+                .checked_add(range.count)
+                .ok_or_else(|| Error::Generic("document count overflow".to_string()))?;
+            total.sum = total
+                .sum
+                .checked_add(range.sum)
+                .ok_or_else(|| Error::Generic("document sum overflow".to_string()))?;
+
+            match range.next_start_after {
+                Some(next) => query = query.with_start_after(next),
+                None => return Ok(total),
+            }
+        }
+    }
+}
+
+impl TransportRequest for DocumentAggregateQuery {
+    type Client = <GetDocumentsAggregateRequest as TransportRequest>::Client;
+    type Response = <GetDocumentsAggregateRequest as TransportRequest>::Response;
+    const SETTINGS_OVERRIDES: rs_dapi_client::RequestSettings =
+        <GetDocumentsAggregateRequest as TransportRequest>::SETTINGS_OVERRIDES;
+    const READ_ONLY: bool = <GetDocumentsAggregateRequest as TransportRequest>::READ_ONLY;
+
+    fn request_name(&self) -> &'static str {
+        "GetDocumentsAggregateRequest"
+    }
+
+    fn method_name(&self) -> &'static str {
+        "get_documents_aggregate"
+    }
+
+    fn execute_transport<'c>(
+        self,
+        client: &'c mut Self::Client,
+        settings: &AppliedRequestSettings,
+    ) -> BoxFuture<'c, Result<Self::Response, TransportError>> {
+        let request: GetDocumentsAggregateRequest = self
+            .try_into()
+            .expect("DocumentAggregateQuery should always be valid");
+        request.execute_transport(client, settings)
+    }
+}
+
+impl FromProof<DocumentAggregateQuery> for DocumentsAggregate {
+    type Request = DocumentAggregateQuery;
+    type Response = platform_proto::GetDocumentsAggregateResponse;
+    fn maybe_from_proof_with_metadata<'a, I: Into<Self::Request>, O: Into<Self::Response>>(
+        request: I,
+        response: O,
+        network: Network,
+        platform_version: &PlatformVersion,
+        provider: &'a dyn ContextProvider,
+    ) -> Result<(Option<Self>, ResponseMetadata, Proof), drive_proof_verifier::Error>
+    where
+        Self: Sized + 'a,
+    {
+        let request: Self::Request = request.into();
+        let drive_query: DriveDocumentAggregateQuery = (&request).try_into().map_err(|e| {
+            drive_proof_verifier::Error::RequestError {
+                error: format!(
+                    "Failed to convert DocumentAggregateQuery to DriveDocumentAggregateQuery: {}",
+                    e
+                ),
+            }
+        })?;
+
+        <DocumentsAggregate as FromProof<DriveDocumentAggregateQuery>>::maybe_from_proof_with_metadata(
+            drive_query,
+            response,
+            network,
+            platform_version,
+            provider,
+        )
+    }
+}
+
+impl TryFrom<DocumentAggregateQuery> for GetDocumentsAggregateRequest {
+    type Error = Error;
+    fn try_from(dapi_request: DocumentAggregateQuery) -> Result<Self, Self::Error> {
+        let document_query = &dapi_request.document_query;
+        let where_clauses = serialize_vec_to_cbor(document_query.where_clauses.clone())?;
+        let order_by = serialize_vec_to_cbor(document_query.order_by_clauses.clone())?;
+
+        let function = match dapi_request.sum_property {
+            Some(_) => AggregateFunction::Sum,
+            None => AggregateFunction::Count,
+        };
+
+        Ok(GetDocumentsAggregateRequest {
+            version: Some(Version::V0(GetDocumentsAggregateRequestV0 {
+                data_contract_id: document_query.data_contract.id().to_vec(),
+                document_type: document_query.document_type_name.clone(),
+                r#where: where_clauses,
+                order_by,
+                function: function as i32,
+                sum_property: dapi_request.sum_property.clone().unwrap_or_default(),
+                limit: dapi_request.range_limit() as u32,
+                start_after: dapi_request.start_after()?,
+                prove: true,
+            })),
+        })
+    }
+}
+
+impl<'a> TryFrom<&'a DocumentAggregateQuery> for DriveDocumentAggregateQuery<'a> {
+    type Error = Error;
+
+    fn try_from(request: &'a DocumentAggregateQuery) -> Result<Self, Self::Error> {
+        // validate start before building the query
+        request.start_after()?;
+
+        let mut query: DriveDocumentQuery<'a> = (&request.document_query).try_into()?;
+        query.limit = Some(request.range_limit());
+
+        let function = match &request.sum_property {
+            Some(property) => DocumentAggregateFunction::Sum(property.clone()),
+            None => DocumentAggregateFunction::Count,
+        };
+
+        DriveDocumentAggregateQuery::new(query, function).map_err(Error::Drive)
+    }
+}
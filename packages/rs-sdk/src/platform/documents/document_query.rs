@@ -136,6 +136,7 @@ impl TransportRequest for DocumentQuery {
     type Response = <GetDocumentsRequest as TransportRequest>::Response;
     const SETTINGS_OVERRIDES: rs_dapi_client::RequestSettings =
         <GetDocumentsRequest as TransportRequest>::SETTINGS_OVERRIDES;
+    const READ_ONLY: bool = <GetDocumentsRequest as TransportRequest>::READ_ONLY;
 
     fn request_name(&self) -> &'static str {
         "GetDocumentsRequest"
@@ -368,7 +369,7 @@ impl<'a> TryFrom<&'a DocumentQuery> for DriveDocumentQuery<'a> {
     }
 }
 
-fn serialize_vec_to_cbor<T: Into<Value>>(input: Vec<T>) -> Result<Vec<u8>, Error> {
+pub(crate) fn serialize_vec_to_cbor<T: Into<Value>>(input: Vec<T>) -> Result<Vec<u8>, Error> {
     let values = Value::Array(
         input
             .into_iter()
@@ -376,7 +377,11 @@ fn serialize_vec_to_cbor<T: Into<Value>>(input: Vec<T>) -> Result<Vec<u8>, Error
             .collect::<Vec<Value>>(),
     );
 
-    let cbor_values: CborValue = TryInto::<CborValue>::try_into(values)
+    serialize_value_to_cbor(values)
+}
+
+pub(crate) fn serialize_value_to_cbor(value: Value) -> Result<Vec<u8>, Error> {
+    let cbor_values: CborValue = TryInto::<CborValue>::try_into(value)
         .map_err(|e| Error::Protocol(dpp::ProtocolError::EncodingError(e.to_string())))?;
 
     let mut serialized = Vec::new();
@@ -0,0 +1,254 @@
+//! Live subscription to the document transitions of committed blocks.
+
+use crate::platform::documents::document_query::{serialize_value_to_cbor, serialize_vec_to_cbor};
+use crate::{error::Error, sdk::Sdk};
+use dapi_grpc::platform::v0::subscribe_documents_request::subscribe_documents_request_v0::DocumentAction;
+use dapi_grpc::platform::v0::subscribe_documents_request::{SubscribeDocumentsRequestV0, Version};
+use dapi_grpc::platform::v0::subscribe_documents_response::{
+    self, subscribe_documents_response_v0::MatchedStateTransition,
+};
+use dapi_grpc::platform::v0::{
+    Proof, ResponseMetadata, SubscribeDocumentsRequest, SubscribeDocumentsResponse,
+};
+use dpp::prelude::Identifier;
+use dpp::serialization::PlatformDeserializable;
+use dpp::state_transition::StateTransition;
+use drive::query::filter::DocumentActionMatchClauses;
+use drive::query::{InternalClauses, ValueClause, WhereClause};
+use futures::{Stream, StreamExt};
+use rs_dapi_client::transport::TransportError;
+use rs_dapi_client::{DapiClientError, DapiRequestExecutor, IntoInner, RequestSettings};
+
+/// Subscription to the document transitions of one document type.
+///
+/// Every committed block with at least one executed document transition matching
+/// [DocumentSubscriptionQuery::action_clauses] is sent as a [DocumentSubscriptionEvent].
+#[derive(Debug, Clone)]
+pub struct DocumentSubscriptionQuery {
+    /// Data contract ID
+    pub data_contract_id: Identifier,
+    /// Document type name
+    pub document_type_name: String,
+    /// Action of the watched document transitions and the clauses they must match
+    pub action_clauses: DocumentActionMatchClauses,
+    /// Whether every matched state transition is sent with a proof of its execution
+    pub prove: bool,
+}
+
+impl DocumentSubscriptionQuery {
+    /// Subscribe to the document transitions of a document type matching the action clauses.
+    pub fn new(
+        data_contract_id: Identifier,
+        document_type_name: &str,
+        action_clauses: DocumentActionMatchClauses,
+    ) -> Self {
+        Self {
+            data_contract_id,
+            document_type_name: document_type_name.to_string(),
+            action_clauses,
+            prove: false,
+        }
+    }
+
+    /// Request a proof of execution for every matched state transition.
+    pub fn with_proofs(mut self, prove: bool) -> Self {
+        self.prove = prove;
+        self
+    }
+}
+
+/// Serializes where clauses to CBOR, no clauses are sent as empty bytes
+fn serialize_clauses_to_cbor(clauses: InternalClauses) -> Result<Vec<u8>, Error> {
+    if clauses.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    serialize_vec_to_cbor(Vec::<WhereClause>::from(clauses))
+}
+
+/// Serializes a value clause to CBOR, no clause is sent as empty bytes
+fn serialize_value_clause_to_cbor(value_clause: Option<ValueClause>) -> Result<Vec<u8>, Error> {
+    value_clause
+        .map(|value_clause| serialize_value_to_cbor(value_clause.into()))
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+impl TryFrom<DocumentSubscriptionQuery> for SubscribeDocumentsRequest {
+    type Error = Error;
+
+    fn try_from(query: DocumentSubscriptionQuery) -> Result<Self, Self::Error> {
+        let (action, new_document_clauses, original_document_clauses, value_clause) =
+            match query.action_clauses {
+                DocumentActionMatchClauses::Create {
+                    new_document_clauses,
+                } => (
+                    DocumentAction::Create,
+                    new_document_clauses,
+                    InternalClauses::default(),
+                    None,
+                ),
+                DocumentActionMatchClauses::Replace {
+                    original_document_clauses,
+                    new_document_clauses,
+                } => (
+                    DocumentAction::Replace,
+                    new_document_clauses,
+                    original_document_clauses,
+                    None,
+                ),
+                DocumentActionMatchClauses::Delete {
+                    original_document_clauses,
+                } => (
+                    DocumentAction::Delete,
+                    InternalClauses::default(),
+                    original_document_clauses,
+                    None,
+                ),
+                DocumentActionMatchClauses::Transfer {
+                    original_document_clauses,
+                    owner_clause,
+                } => (
+                    DocumentAction::Transfer,
+                    InternalClauses::default(),
+                    original_document_clauses,
+                    owner_clause,
+                ),
+                DocumentActionMatchClauses::UpdatePrice {
+                    original_document_clauses,
+                    price_clause,
+                } => (
+                    DocumentAction::UpdatePrice,
+                    InternalClauses::default(),
+                    original_document_clauses,
+                    price_clause,
+                ),
+                DocumentActionMatchClauses::Purchase {
+                    original_document_clauses,
+                    owner_clause,
+                } => (
+                    DocumentAction::Purchase,
+                    InternalClauses::default(),
+                    original_document_clauses,
+                    owner_clause,
+                ),
+            };
+
+        Ok(SubscribeDocumentsRequest {
+            version: Some(Version::V0(SubscribeDocumentsRequestV0 {
+                data_contract_id: query.data_contract_id.to_vec(),
+                document_type: query.document_type_name,
+                action: action as i32,
+                new_document_where: serialize_clauses_to_cbor(new_document_clauses)?,
+                original_document_where: serialize_clauses_to_cbor(original_document_clauses)?,
+                value_clause: serialize_value_clause_to_cbor(value_clause)?,
+                prove: query.prove,
+            })),
+        })
+    }
+}
+
+/// A state transition of a committed block with document transitions matching a subscription
+#[derive(Debug, Clone)]
+pub struct MatchedDocumentStateTransition {
+    /// The executed state transition
+    pub state_transition: StateTransition,
+    /// Indices of the matching transitions within the batch
+    pub transition_indices: Vec<u32>,
+    /// Proof of the execution of the state transition, when requested.
+    ///
+    /// The proof is made against the committed state at
+    /// [DocumentSubscriptionEvent::proof_height] and is not verified by the SDK.
+    pub proof: Option<Proof>,
+    /// Why the requested proof could not be made
+    pub proof_error: Option<String>,
+}
+
+/// The state transitions of a committed block matching a subscription
+#[derive(Debug, Clone)]
+pub struct DocumentSubscriptionEvent {
+    /// Height of the committed block
+    pub block_height: u64,
+    /// Matched state transitions, in block order
+    pub state_transitions: Vec<MatchedDocumentStateTransition>,
+    /// Metadata of the response
+    pub metadata: Option<ResponseMetadata>,
+    /// Height of the committed state the proofs were made against.
+    ///
+    /// Proofs are made when Platform sends the block, so this is [Self::block_height] or a later
+    /// height when the subscriber is behind.
+    pub proof_height: u64,
+    /// Why Platform could not match the block against the subscription, no state transitions
+    /// are sent for it
+    pub error: Option<String>,
+}
+
+impl TryFrom<SubscribeDocumentsResponse> for DocumentSubscriptionEvent {
+    type Error = Error;
+
+    fn try_from(response: SubscribeDocumentsResponse) -> Result<Self, Self::Error> {
+        let Some(subscribe_documents_response::Version::V0(response)) = response.version else {
+            return Err(Error::InvalidProvedResponse(
+                "missing version of document subscription response".to_string(),
+            ));
+        };
+
+        let state_transitions = response
+            .state_transitions
+            .into_iter()
+            .map(
+                |MatchedStateTransition {
+                     state_transition,
+                     transition_indices,
+                     proof,
+                     proof_error,
+                 }| {
+                    Ok(MatchedDocumentStateTransition {
+                        state_transition: StateTransition::deserialize_from_bytes(
+                            &state_transition,
+                        )?,
+                        transition_indices,
+                        proof,
+                        proof_error: (!proof_error.is_empty()).then_some(proof_error),
+                    })
+                },
+            )
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(DocumentSubscriptionEvent {
+            block_height: response.block_height,
+            state_transitions,
+            metadata: response.metadata,
+            proof_height: response.proof_height,
+            error: (!response.error.is_empty()).then_some(response.error),
+        })
+    }
+}
+
+impl Sdk {
+    /// Subscribes to the document transitions of committed blocks.
+    ///
+    /// The stream yields a [DocumentSubscriptionEvent] for every committed block with at least one
+    /// executed document transition matching the query. A block Platform could not match is sent
+    /// with [DocumentSubscriptionEvent::error] set. Platform closes the stream after sending an
+    /// error, e.g. when the subscriber is too slow to keep up with new blocks.
+    pub async fn subscribe_documents(
+        &self,
+        query: DocumentSubscriptionQuery,
+    ) -> Result<impl Stream<Item = Result<DocumentSubscriptionEvent, Error>>, Error> {
+        let request = SubscribeDocumentsRequest::try_from(query)?;
+
+        let stream = self
+            .execute(request, RequestSettings::default())
+            .await
+            .into_inner()?;
+
+        Ok(stream.map(|message| {
+            message
+                .map_err(|status| {
+                    Error::from(DapiClientError::Transport(TransportError::Grpc(status)))
+                })
+                .and_then(DocumentSubscriptionEvent::try_from)
+        }))
+    }
+}
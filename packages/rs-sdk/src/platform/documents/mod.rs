@@ -1,2 +1,6 @@
+pub mod document_aggregate_query;
 pub mod document_query;
+pub mod document_subscription;
 pub mod transitions;
+#[cfg(feature = "document-validation")]
+pub mod validation;
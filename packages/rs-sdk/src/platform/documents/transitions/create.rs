@@ -1,3 +1,7 @@
+#[cfg(feature = "document-validation")]
+use crate::platform::documents::validation::{
+    DocumentTransitionValidation, ValidateDocumentTransition,
+};
 use crate::platform::transition::broadcast::BroadcastStateTransition;
 use crate::platform::transition::put_settings::PutSettings;
 use crate::{Error, Sdk};
@@ -8,6 +12,8 @@ use dpp::identity::signer::Signer;
 use dpp::identity::IdentityPublicKey;
 use dpp::prelude::UserFeeIncrease;
 use dpp::serialization::PlatformSerializable;
+#[cfg(feature = "document-validation")]
+use dpp::state_transition::batch_transition::batched_transition::document_transition_action_type::DocumentTransitionActionType;
 use dpp::state_transition::batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
 use dpp::state_transition::batch_transition::methods::StateTransitionCreationOptions;
 use dpp::state_transition::batch_transition::BatchTransition;
@@ -171,6 +177,24 @@ impl DocumentCreateTransitionBuilder {
     }
 }
 
+#[cfg(feature = "document-validation")]
+impl ValidateDocumentTransition for DocumentCreateTransitionBuilder {
+    fn document_transition_validation(
+        &self,
+        check_unique_indexes: bool,
+    ) -> DocumentTransitionValidation<'_> {
+        DocumentTransitionValidation {
+            data_contract: &self.data_contract,
+            document_type_name: &self.document_type_name,
+            action: DocumentTransitionActionType::Create,
+            document: Some(&self.document),
+            identity_id: self.document.owner_id(),
+            token_payment_info: self.token_payment_info.as_ref(),
+            check_unique_indexes,
+        }
+    }
+}
+
 /// Result types returned from document creation operations.
 #[derive(Debug)]
 pub enum DocumentCreateResult {
@@ -1,3 +1,7 @@
+#[cfg(feature = "document-validation")]
+use crate::platform::documents::validation::{
+    DocumentTransitionValidation, ValidateDocumentTransition,
+};
 use crate::platform::transition::broadcast::BroadcastStateTransition;
 use crate::platform::transition::put_settings::PutSettings;
 use crate::platform::Identifier;
@@ -9,6 +13,8 @@ use dpp::fee::Credits;
 use dpp::identity::signer::Signer;
 use dpp::identity::IdentityPublicKey;
 use dpp::prelude::UserFeeIncrease;
+#[cfg(feature = "document-validation")]
+use dpp::state_transition::batch_transition::batched_transition::document_transition_action_type::DocumentTransitionActionType;
 use dpp::state_transition::batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
 use dpp::state_transition::batch_transition::methods::StateTransitionCreationOptions;
 use dpp::state_transition::batch_transition::BatchTransition;
@@ -225,6 +231,24 @@ impl DocumentPurchaseTransitionBuilder {
     }
 }
 
+#[cfg(feature = "document-validation")]
+impl ValidateDocumentTransition for DocumentPurchaseTransitionBuilder {
+    fn document_transition_validation(
+        &self,
+        check_unique_indexes: bool,
+    ) -> DocumentTransitionValidation<'_> {
+        DocumentTransitionValidation {
+            data_contract: &self.data_contract,
+            document_type_name: &self.document_type_name,
+            action: DocumentTransitionActionType::Purchase,
+            document: Some(&self.document),
+            identity_id: self.purchaser_id,
+            token_payment_info: self.token_payment_info.as_ref(),
+            check_unique_indexes,
+        }
+    }
+}
+
 /// Result types returned from document purchase operations.
 #[derive(Debug)]
 pub enum DocumentPurchaseResult {
@@ -0,0 +1,826 @@
+//! Local validation of document transitions before they are signed and broadcast.
+//!
+//! Platform only reports schema violations, unique index conflicts or missing token payments
+//! as consensus errors once the state transition was broadcast, and the identity pays fees for
+//! them. [Sdk::validate_document_transition] runs the same checks against the fetched data
+//! contract and the current state, so predictably invalid transitions are never signed.
+
+use crate::platform::documents::document_query::DocumentQuery;
+use crate::platform::tokens::identity_token_balances::IdentityTokenBalancesQuery;
+use crate::platform::FetchMany;
+use crate::{Error, Sdk};
+use dpp::balances::credits::TokenAmount;
+use dpp::consensus::basic::document::{
+    DocumentCreationNotAllowedError, InvalidDocumentTransitionActionError, InvalidDocumentTypeError,
+};
+use dpp::consensus::state::document::duplicate_unique_index_error::DuplicateUniqueIndexError;
+use dpp::consensus::state::state_error::StateError;
+use dpp::consensus::state::token::{
+    IdentityDoesNotHaveEnoughTokenBalanceError, IdentityHasNotAgreedToPayRequiredTokenAmountError,
+    IdentityTryingToPayWithWrongTokenError, RequiredTokenPaymentInfoNotSetError,
+};
+use dpp::consensus::ConsensusError;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::{DocumentTypeV0Getters, DocumentTypeV1Getters};
+use dpp::data_contract::document_type::restricted_creation::CreationRestrictionMode;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::data_contract::validate_document::DataContractDocumentValidationMethodsV0;
+use dpp::data_contract::DataContract;
+use dpp::document::{property_names, Document, DocumentV0Getters};
+use dpp::nft::TradeMode;
+use dpp::platform_value::Value;
+use dpp::prelude::Identifier;
+use dpp::state_transition::batch_transition::batched_transition::document_transition_action_type::DocumentTransitionActionType;
+use dpp::tokens::calculate_token_id;
+use dpp::tokens::token_amount_on_contract_token::DocumentActionTokenCost;
+use dpp::tokens::token_payment_info::methods::v0::TokenPaymentInfoMethodsV0;
+use dpp::tokens::token_payment_info::v0::v0_accessors::TokenPaymentInfoAccessorsV0;
+use dpp::tokens::token_payment_info::TokenPaymentInfo;
+use dpp::validation::SimpleConsensusValidationResult;
+use dpp::version::PlatformVersion;
+use drive::query::{WhereClause, WhereOperator};
+use std::sync::Arc;
+
+/// A document transition to validate before it is signed
+#[derive(Debug, Clone)]
+pub struct DocumentTransitionValidation<'a> {
+    /// The data contract of the document
+    pub data_contract: &'a Arc<DataContract>,
+    /// The name of the document type
+    pub document_type_name: &'a str,
+    /// The action of the transition
+    pub action: DocumentTransitionActionType,
+    /// The document as it will be stored, `None` for deletions. For purchases, the document
+    /// as currently stored, still owned by the seller
+    pub document: Option<&'a Document>,
+    /// The identity submitting the transition, which also pays the token costs. For purchases,
+    /// the purchaser
+    pub identity_id: Identifier,
+    /// The token payment info of the transition
+    pub token_payment_info: Option<&'a TokenPaymentInfo>,
+    /// Query the unique indexes of the document type, with proofs, to detect conflicts
+    /// with existing documents
+    pub check_unique_indexes: bool,
+}
+
+/// Document transition builders that can be validated locally before they are signed
+#[async_trait::async_trait]
+pub trait ValidateDocumentTransition: Sync {
+    /// The transition of the builder, as it will be signed
+    fn document_transition_validation(
+        &self,
+        check_unique_indexes: bool,
+    ) -> DocumentTransitionValidation<'_>;
+
+    /// Validates the document transition locally, before it is signed
+    ///
+    /// # Arguments
+    ///
+    /// * `sdk` - The SDK instance
+    /// * `check_unique_indexes` - Whether to query the unique indexes for conflicting documents
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SimpleConsensusValidationResult, Error>` - The consensus errors of the transition, if any
+    async fn validate(
+        &self,
+        sdk: &Sdk,
+        check_unique_indexes: bool,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        sdk.validate_document_transition(self.document_transition_validation(check_unique_indexes))
+            .await
+    }
+}
+
+impl Sdk {
+    /// Validates a document transition locally before it is signed and broadcast.
+    ///
+    /// Checks, in the order Platform does:
+    /// - that the document type allows the action: creation restriction mode, mutability,
+    ///   deletion, transferability and trade mode
+    /// - the document against the document type schema, including field sizes
+    /// - the token cost of the action against the token payment info and the token balance
+    ///   of the identity
+    /// - optionally, the unique indexes of the document type for conflicting documents
+    ///
+    /// # Returns
+    ///
+    /// The consensus errors Platform would return for the transition, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is missing for an action other than a deletion or
+    /// if the token balance or conflicting documents can not be fetched.
+    pub async fn validate_document_transition(
+        &self,
+        validation: DocumentTransitionValidation<'_>,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let platform_version = self.version();
+        let data_contract = validation.data_contract;
+
+        let Some(document_type) =
+            data_contract.document_type_optional_for_name(validation.document_type_name)
+        else {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidDocumentTypeError::new(
+                    validation.document_type_name.to_string(),
+                    data_contract.id(),
+                )
+                .into(),
+            ));
+        };
+
+        let result = validate_structure(&validation, document_type, platform_version)?;
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
+        let result = self.validate_token_cost(&validation, document_type).await?;
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
+        if validation.check_unique_indexes
+            && matches!(
+                validation.action,
+                DocumentTransitionActionType::Create | DocumentTransitionActionType::Replace
+            )
+        {
+            return self
+                .validate_unique_indexes(&validation, document_type)
+                .await;
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+
+    async fn validate_token_cost(
+        &self,
+        validation: &DocumentTransitionValidation<'_>,
+        document_type: DocumentTypeRef<'_>,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let token_cost = match validation.action {
+            DocumentTransitionActionType::Create => document_type.document_creation_token_cost(),
+            DocumentTransitionActionType::Replace => {
+                document_type.document_replacement_token_cost()
+            }
+            DocumentTransitionActionType::Delete => document_type.document_deletion_token_cost(),
+            DocumentTransitionActionType::Transfer => document_type.document_transfer_token_cost(),
+            DocumentTransitionActionType::UpdatePrice => {
+                document_type.document_price_update_token_cost()
+            }
+            DocumentTransitionActionType::Purchase => document_type.document_purchase_token_cost(),
+            DocumentTransitionActionType::IgnoreWhileBumpingRevision => None,
+        };
+
+        let Some(DocumentActionTokenCost {
+            contract_id,
+            token_contract_position,
+            token_amount,
+            ..
+        }) = token_cost
+        else {
+            return Ok(SimpleConsensusValidationResult::new());
+        };
+
+        let action = format!("{:?}", validation.action);
+        let token_id: Identifier = calculate_token_id(
+            contract_id
+                .unwrap_or(validation.data_contract.id())
+                .as_bytes(),
+            token_contract_position,
+        )
+        .into();
+
+        let Some(token_payment_info) = validation.token_payment_info else {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::StateError(StateError::RequiredTokenPaymentInfoNotSetError(
+                    RequiredTokenPaymentInfoNotSetError::new(token_id, action),
+                )),
+            ));
+        };
+
+        if !token_payment_info.matches_token_contract(&contract_id, token_contract_position) {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::StateError(StateError::IdentityTryingToPayWithWrongTokenError(
+                    IdentityTryingToPayWithWrongTokenError::new(
+                        contract_id,
+                        token_contract_position,
+                        token_id,
+                        token_payment_info.payment_token_contract_id(),
+                        token_payment_info.token_contract_position(),
+                        token_payment_info.token_id(validation.data_contract.id()),
+                    ),
+                )),
+            ));
+        }
+
+        if !token_payment_info.is_valid_for_required_cost(token_amount) {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::StateError(
+                    StateError::IdentityHasNotAgreedToPayRequiredTokenAmountError(
+                        IdentityHasNotAgreedToPayRequiredTokenAmountError::new(
+                            token_id,
+                            token_amount,
+                            token_payment_info.minimum_token_cost(),
+                            token_payment_info.maximum_token_cost(),
+                            action,
+                        ),
+                    ),
+                ),
+            ));
+        }
+
+        let balances = TokenAmount::fetch_many(
+            self,
+            IdentityTokenBalancesQuery {
+                identity_id: validation.identity_id,
+                token_ids: vec![token_id],
+            },
+        )
+        .await?;
+
+        let balance = balances
+            .get(&token_id)
+            .copied()
+            .flatten()
+            .unwrap_or_default();
+
+        if balance < token_amount {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                ConsensusError::StateError(StateError::IdentityDoesNotHaveEnoughTokenBalanceError(
+                    IdentityDoesNotHaveEnoughTokenBalanceError::new(
+                        token_id,
+                        validation.identity_id,
+                        token_amount,
+                        balance,
+                        format!("Document {} token payment", action),
+                    ),
+                )),
+            ));
+        }
+
+        Ok(SimpleConsensusValidationResult::new())
+    }
+
+    async fn validate_unique_indexes(
+        &self,
+        validation: &DocumentTransitionValidation<'_>,
+        document_type: DocumentTypeRef<'_>,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let document = required_document(validation)?;
+
+        let mut results = vec![];
+
+        for index in document_type
+            .indexes()
+            .values()
+            .filter(|index| index.unique)
+        {
+            let where_clauses = index
+                .properties
+                .iter()
+                .map(|property| {
+                    index_property_value(document, &property.name).map(|value| WhereClause {
+                        field: property.name.clone(),
+                        operator: WhereOperator::Equal,
+                        value,
+                    })
+                })
+                .collect::<Option<Vec<_>>>();
+
+            // Missing values make the index no longer unique
+            let Some(where_clauses) = where_clauses else {
+                continue;
+            };
+
+            let mut query = DocumentQuery::new(
+                Arc::clone(validation.data_contract),
+                validation.document_type_name,
+            )?;
+            query.where_clauses = where_clauses;
+            query.limit = 2;
+
+            let documents = Document::fetch_many(self, query).await?;
+
+            let conflicting = documents
+                .iter()
+                .any(|(id, existing)| existing.is_some() && *id != document.id());
+
+            if conflicting {
+                results.push(SimpleConsensusValidationResult::new_with_error(
+                    ConsensusError::StateError(StateError::DuplicateUniqueIndexError(
+                        DuplicateUniqueIndexError::new(document.id(), index.property_names()),
+                    )),
+                ));
+            }
+        }
+
+        Ok(SimpleConsensusValidationResult::merge_many_errors(results))
+    }
+}
+
+fn required_document<'a>(
+    validation: &DocumentTransitionValidation<'a>,
+) -> Result<&'a Document, Error> {
+    validation.document.ok_or_else(|| {
+        Error::Generic(format!(
+            "a document is required to validate a {:?} transition",
+            validation.action
+        ))
+    })
+}
+
+fn validate_structure(
+    validation: &DocumentTransitionValidation<'_>,
+    document_type: DocumentTypeRef<'_>,
+    platform_version: &PlatformVersion,
+) -> Result<SimpleConsensusValidationResult, Error> {
+    let data_contract = validation.data_contract;
+    let document_type_name = validation.document_type_name;
+
+    let action_error = match validation.action {
+        DocumentTransitionActionType::Create => {
+            let creation_allowed = match document_type.creation_restriction_mode() {
+                CreationRestrictionMode::NoRestrictions => true,
+                CreationRestrictionMode::OwnerOnly => {
+                    validation.identity_id == data_contract.owner_id()
+                }
+                CreationRestrictionMode::NoCreationAllowed => false,
+            };
+
+            if !creation_allowed {
+                return Ok(SimpleConsensusValidationResult::new_with_error(
+                    DocumentCreationNotAllowedError::new(
+                        data_contract.id(),
+                        document_type_name.to_string(),
+                        document_type.creation_restriction_mode(),
+                    )
+                    .into(),
+                ));
+            }
+
+            None
+        }
+        DocumentTransitionActionType::Replace => (!document_type.documents_mutable()).then(|| {
+            format!(
+                "{} is not mutable and can not be replaced",
+                document_type_name
+            )
+        }),
+        DocumentTransitionActionType::Delete => {
+            (!document_type.documents_can_be_deleted()).then(|| {
+                format!(
+                    "documents of type {} can not be deleted",
+                    document_type_name
+                )
+            })
+        }
+        DocumentTransitionActionType::Transfer => {
+            (!document_type.documents_transferable().is_transferable())
+                .then(|| format!("{} is not a transferable document type", document_type_name))
+        }
+        DocumentTransitionActionType::UpdatePrice => {
+            (!document_type.trade_mode().seller_sets_price()).then(|| {
+                format!(
+                    "{} is in trade mode {} that does not support the seller setting the price",
+                    document_type_name,
+                    document_type.trade_mode(),
+                )
+            })
+        }
+        DocumentTransitionActionType::Purchase => {
+            if required_document(validation)?.owner_id() == validation.identity_id {
+                Some(format!(
+                    "on document type: {} identity trying to purchase a document that is already owned by the purchaser",
+                    document_type_name
+                ))
+            } else {
+                (document_type.trade_mode() != TradeMode::DirectPurchase).then(|| {
+                    format!(
+                        "{} trade mode is not direct purchase but we are trying to purchase directly",
+                        document_type_name
+                    )
+                })
+            }
+        }
+        DocumentTransitionActionType::IgnoreWhileBumpingRevision => None,
+    };
+
+    if let Some(message) = action_error {
+        return Ok(SimpleConsensusValidationResult::new_with_error(
+            InvalidDocumentTransitionActionError::new(message).into(),
+        ));
+    }
+
+    if matches!(
+        validation.action,
+        DocumentTransitionActionType::Create | DocumentTransitionActionType::Replace
+    ) {
+        return data_contract
+            .validate_document(
+                document_type_name,
+                required_document(validation)?,
+                platform_version,
+            )
+            .map_err(Error::Protocol);
+    }
+
+    Ok(SimpleConsensusValidationResult::new())
+}
+
+/// The value of an index property of the document, `None` if it is not set
+fn index_property_value(document: &Document, property_name: &str) -> Option<Value> {
+    match property_name {
+        property_names::OWNER_ID => Some(document.owner_id().into()),
+        property_names::CREATED_AT => document.created_at().map(Value::U64),
+        property_names::UPDATED_AT => document.updated_at().map(Value::U64),
+        property_names::TRANSFERRED_AT => document.transferred_at().map(Value::U64),
+        property_names::CREATED_AT_BLOCK_HEIGHT => {
+            document.created_at_block_height().map(Value::U64)
+        }
+        property_names::UPDATED_AT_BLOCK_HEIGHT => {
+            document.updated_at_block_height().map(Value::U64)
+        }
+        property_names::TRANSFERRED_AT_BLOCK_HEIGHT => {
+            document.transferred_at_block_height().map(Value::U64)
+        }
+        property_names::CREATED_AT_CORE_BLOCK_HEIGHT => {
+            document.created_at_core_block_height().map(Value::U32)
+        }
+        property_names::UPDATED_AT_CORE_BLOCK_HEIGHT => {
+            document.updated_at_core_block_height().map(Value::U32)
+        }
+        property_names::TRANSFERRED_AT_CORE_BLOCK_HEIGHT => {
+            document.transferred_at_core_block_height().map(Value::U32)
+        }
+        _ => document
+            .properties()
+            .get(property_name)
+            .filter(|value| !value.is_null())
+            .cloned(),
+    }
+}
+
+#[cfg(all(test, feature = "mocks"))]
+mod tests {
+    use super::*;
+    use dpp::consensus::basic::BasicError;
+    use dpp::data_contract::DataContractFactory;
+    use dpp::document::DocumentV0;
+    use dpp::platform_value::platform_value;
+    use dpp::tokens::gas_fees_paid_by::GasFeesPaidBy;
+    use dpp::tokens::token_payment_info::v0::TokenPaymentInfoV0;
+    use drive_proof_verifier::types::Documents;
+    use std::collections::BTreeMap;
+
+    /// A data contract with a `note` document type, its schema extended with `options`
+    fn note_contract(owner_id: Identifier, options: Value) -> Arc<DataContract> {
+        let mut schema = platform_value!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "maxLength": 63,
+                    "position": 0
+                }
+            },
+            "additionalProperties": false,
+        });
+
+        for (key, value) in options.into_btree_string_map().expect("expected a map") {
+            schema
+                .insert(key, value)
+                .expect("expected to extend the schema");
+        }
+
+        let created = DataContractFactory::new(PlatformVersion::latest().protocol_version)
+            .expect("expected a factory")
+            .create(owner_id, 0, platform_value!({ "note": schema }), None, None)
+            .expect("expected to create a data contract");
+
+        Arc::new(created.data_contract_owned())
+    }
+
+    fn note(owner_id: Identifier, name: &str) -> Document {
+        DocumentV0 {
+            id: Identifier::random(),
+            owner_id,
+            properties: BTreeMap::from([("name".to_string(), Value::Text(name.to_string()))]),
+            revision: Some(1),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    fn validation<'a>(
+        data_contract: &'a Arc<DataContract>,
+        action: DocumentTransitionActionType,
+        document: Option<&'a Document>,
+        identity_id: Identifier,
+    ) -> DocumentTransitionValidation<'a> {
+        DocumentTransitionValidation {
+            data_contract,
+            document_type_name: "note",
+            action,
+            document,
+            identity_id,
+            token_payment_info: None,
+            check_unique_indexes: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_reject_creation_by_other_identities_when_restricted_to_owner() {
+        let sdk = Sdk::new_mock();
+        let owner_id = Identifier::random();
+        let identity_id = Identifier::random();
+        let data_contract =
+            note_contract(owner_id, platform_value!({ "creationRestrictionMode": 1 }));
+
+        let document = note(identity_id, "alice");
+
+        let result = sdk
+            .validate_document_transition(validation(
+                &data_contract,
+                DocumentTransitionActionType::Create,
+                Some(&document),
+                identity_id,
+            ))
+            .await
+            .expect("expected to validate");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(
+                BasicError::DocumentCreationNotAllowedError(_)
+            )]
+        ));
+
+        let document = note(owner_id, "alice");
+
+        let result = sdk
+            .validate_document_transition(validation(
+                &data_contract,
+                DocumentTransitionActionType::Create,
+                Some(&document),
+                owner_id,
+            ))
+            .await
+            .expect("expected to validate");
+
+        assert!(result.is_valid());
+    }
+
+    #[tokio::test]
+    async fn should_reject_replacing_immutable_documents() {
+        let sdk = Sdk::new_mock();
+        let owner_id = Identifier::random();
+        let data_contract = note_contract(owner_id, platform_value!({ "documentsMutable": false }));
+
+        let document = note(owner_id, "alice");
+
+        let result = sdk
+            .validate_document_transition(validation(
+                &data_contract,
+                DocumentTransitionActionType::Replace,
+                Some(&document),
+                owner_id,
+            ))
+            .await
+            .expect("expected to validate");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(
+                BasicError::InvalidDocumentTransitionActionError(_)
+            )]
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_reject_trades_not_supported_by_the_trade_mode() {
+        let sdk = Sdk::new_mock();
+        let owner_id = Identifier::random();
+        let purchaser_id = Identifier::random();
+        let data_contract = note_contract(owner_id, platform_value!({}));
+
+        let document = note(owner_id, "alice");
+
+        for (action, identity_id) in [
+            (DocumentTransitionActionType::UpdatePrice, owner_id),
+            (DocumentTransitionActionType::Purchase, purchaser_id),
+        ] {
+            let result = sdk
+                .validate_document_transition(validation(
+                    &data_contract,
+                    action,
+                    Some(&document),
+                    identity_id,
+                ))
+                .await
+                .expect("expected to validate");
+
+            assert!(matches!(
+                result.errors.as_slice(),
+                [ConsensusError::BasicError(
+                    BasicError::InvalidDocumentTransitionActionError(_)
+                )]
+            ));
+        }
+
+        // Direct purchases are allowed, but not of documents the purchaser already owns
+        let data_contract = note_contract(
+            owner_id,
+            platform_value!({ "tradeMode": 1, "transferable": 1 }),
+        );
+
+        let result = sdk
+            .validate_document_transition(validation(
+                &data_contract,
+                DocumentTransitionActionType::Purchase,
+                Some(&document),
+                owner_id,
+            ))
+            .await
+            .expect("expected to validate");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::BasicError(
+                BasicError::InvalidDocumentTransitionActionError(_)
+            )]
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_reject_token_payments_not_matching_the_token_cost() {
+        let sdk = Sdk::new_mock();
+        let owner_id = Identifier::random();
+        let token_contract_id = Identifier::random();
+        let data_contract = note_contract(
+            owner_id,
+            platform_value!({
+                "tokenCost": {
+                    "create": {
+                        "contractId": token_contract_id,
+                        "tokenPosition": 0,
+                        "amount": 10
+                    }
+                }
+            }),
+        );
+
+        let document = note(owner_id, "alice");
+
+        let result = sdk
+            .validate_document_transition(validation(
+                &data_contract,
+                DocumentTransitionActionType::Create,
+                Some(&document),
+                owner_id,
+            ))
+            .await
+            .expect("expected to validate");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::StateError(
+                StateError::RequiredTokenPaymentInfoNotSetError(_)
+            )]
+        ));
+
+        let wrong_token: TokenPaymentInfo = TokenPaymentInfoV0 {
+            payment_token_contract_id: Some(token_contract_id),
+            token_contract_position: 1,
+            minimum_token_cost: None,
+            maximum_token_cost: None,
+            gas_fees_paid_by: GasFeesPaidBy::DocumentOwner,
+        }
+        .into();
+
+        let result = sdk
+            .validate_document_transition(DocumentTransitionValidation {
+                token_payment_info: Some(&wrong_token),
+                ..validation(
+                    &data_contract,
+                    DocumentTransitionActionType::Create,
+                    Some(&document),
+                    owner_id,
+                )
+            })
+            .await
+            .expect("expected to validate");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::StateError(
+                StateError::IdentityTryingToPayWithWrongTokenError(_)
+            )]
+        ));
+
+        let too_expensive: TokenPaymentInfo = TokenPaymentInfoV0 {
+            payment_token_contract_id: Some(token_contract_id),
+            token_contract_position: 0,
+            minimum_token_cost: None,
+            maximum_token_cost: Some(5),
+            gas_fees_paid_by: GasFeesPaidBy::DocumentOwner,
+        }
+        .into();
+
+        let result = sdk
+            .validate_document_transition(DocumentTransitionValidation {
+                token_payment_info: Some(&too_expensive),
+                ..validation(
+                    &data_contract,
+                    DocumentTransitionActionType::Create,
+                    Some(&document),
+                    owner_id,
+                )
+            })
+            .await
+            .expect("expected to validate");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::StateError(
+                StateError::IdentityHasNotAgreedToPayRequiredTokenAmountError(_)
+            )]
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_detect_unique_index_conflicts() {
+        let mut sdk = Sdk::new_mock();
+        let owner_id = Identifier::random();
+        let data_contract = note_contract(
+            owner_id,
+            platform_value!({
+                "indices": [
+                    {
+                        "name": "name",
+                        "properties": [{ "name": "asc" }],
+                        "unique": true
+                    }
+                ]
+            }),
+        );
+
+        let existing = note(owner_id, "alice");
+
+        let mut query = DocumentQuery::new(Arc::clone(&data_contract), "note")
+            .expect("expected a document query");
+        query.where_clauses = vec![WhereClause {
+            field: "name".to_string(),
+            operator: WhereOperator::Equal,
+            value: Value::Text("alice".to_string()),
+        }];
+        query.limit = 2;
+
+        sdk.mock()
+            .expect_fetch_many(
+                query,
+                Some(Documents::from([(existing.id(), Some(existing.clone()))])),
+            )
+            .await
+            .expect("expected to set the expectation");
+
+        let document = note(owner_id, "alice");
+
+        let result = sdk
+            .validate_document_transition(DocumentTransitionValidation {
+                check_unique_indexes: true,
+                ..validation(
+                    &data_contract,
+                    DocumentTransitionActionType::Create,
+                    Some(&document),
+                    owner_id,
+                )
+            })
+            .await
+            .expect("expected to validate");
+
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ConsensusError::StateError(
+                StateError::DuplicateUniqueIndexError(_)
+            )]
+        ));
+
+        // Replacing the document holding the unique values doesn't conflict with itself
+        let result = sdk
+            .validate_document_transition(DocumentTransitionValidation {
+                check_unique_indexes: true,
+                ..validation(
+                    &data_contract,
+                    DocumentTransitionActionType::Replace,
+                    Some(&existing),
+                    owner_id,
+                )
+            })
+            .await
+            .expect("expected to validate");
+
+        assert!(result.is_valid());
+    }
+}
@@ -24,7 +24,7 @@ use rs_dapi_client::{ExecutionError, ExecutionResponse, InnerInto, IntoInner};
 use std::fmt::Debug;
 
 use super::types::identity::IdentityRequest;
-use super::DocumentQuery;
+use super::{DocumentAggregateQuery, DocumentQuery};
 
 /// Trait implemented by objects that can be fetched from Platform.
 ///
@@ -263,6 +263,10 @@ impl Fetch for Document {
     type Request = DocumentQuery;
 }
 
+impl Fetch for drive_proof_verifier::types::document_aggregate::DocumentsAggregate {
+    type Request = DocumentAggregateQuery;
+}
+
 impl Fetch for drive_proof_verifier::types::IdentityBalance {
     type Request = platform_proto::GetIdentityBalanceRequest;
 }
@@ -6,6 +6,7 @@ use dapi_grpc::platform::v0::{
     self as platform_proto, GetStatusRequest, GetStatusResponse, ResponseMetadata,
 };
 use dpp::{dashcore::Network, version::PlatformVersion};
+use drive_proof_verifier::types::block_state_transitions::BlockStateTransitions;
 use drive_proof_verifier::types::evonode_status::EvoNodeStatus;
 use drive_proof_verifier::unproved::FromUnproved;
 use rs_dapi_client::{transport::TransportRequest, DapiRequest, RequestSettings};
@@ -116,6 +117,10 @@ impl FetchUnproved for drive_proof_verifier::types::CurrentQuorumsInfo {
     type Request = platform_proto::GetCurrentQuorumsInfoRequest;
 }
 
+impl FetchUnproved for BlockStateTransitions {
+    type Request = platform_proto::GetBlockStateTransitionsRequest;
+}
+
 impl FetchUnproved for EvoNodeStatus {
     type Request = EvoNode;
 }
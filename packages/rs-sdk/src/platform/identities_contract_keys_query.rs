@@ -68,6 +68,7 @@ impl TransportRequest for IdentitiesContractKeysQuery {
     type Response = <GetIdentitiesContractKeysRequest as TransportRequest>::Response;
     const SETTINGS_OVERRIDES: rs_dapi_client::RequestSettings =
         <GetIdentitiesContractKeysRequest as TransportRequest>::SETTINGS_OVERRIDES;
+    const READ_ONLY: bool = <GetIdentitiesContractKeysRequest as TransportRequest>::READ_ONLY;
 
     fn request_name(&self) -> &'static str {
         "getIdentitiesContractKeysRequest"
@@ -0,0 +1,66 @@
+//! Fetching of the state transitions recorded in the history of an identity.
+use crate::platform::{FetchMany, Identifier, Query};
+use crate::Error;
+use dapi_grpc::platform::v0::get_identity_state_transitions_request::get_identity_state_transitions_request_v0::StartAfter;
+use dapi_grpc::platform::v0::get_identity_state_transitions_request::GetIdentityStateTransitionsRequestV0;
+use dapi_grpc::platform::v0::{
+    get_identity_state_transitions_request, GetIdentityStateTransitionsRequest,
+};
+use dpp::identity::state_transition_record::IdentityStateTransitionRecord;
+use dpp::prelude::BlockHeight;
+pub use drive_proof_verifier::types::identity_state_transitions::IdentityStateTransitions;
+
+#[derive(Debug, Clone)]
+/// Query to fetch the state transitions recorded in the history of an identity.
+///
+/// State transitions are recorded from protocol version 11.
+pub struct IdentityStateTransitionsQuery {
+    /// Identity ID
+    pub identity_id: Identifier,
+    /// Only fetch state transitions after this block height and index in the block, in the
+    /// requested order
+    pub start_after: Option<(BlockHeight, u32)>,
+    /// An optional limit of state transitions to fetch
+    pub limit: Option<u16>,
+    /// Whether to fetch the oldest state transitions first
+    pub order_ascending: bool,
+}
+
+impl IdentityStateTransitionsQuery {
+    /// Create a query fetching the most recent state transitions of an identity first
+    pub fn new(identity_id: Identifier) -> Self {
+        Self {
+            identity_id,
+            start_after: None,
+            limit: None,
+            order_ascending: false,
+        }
+    }
+}
+
+impl Query<GetIdentityStateTransitionsRequest> for IdentityStateTransitionsQuery {
+    fn query(self, prove: bool) -> Result<GetIdentityStateTransitionsRequest, Error> {
+        let request = GetIdentityStateTransitionsRequest {
+            version: Some(get_identity_state_transitions_request::Version::V0(
+                GetIdentityStateTransitionsRequestV0 {
+                    identity_id: self.identity_id.to_vec(),
+                    start_after: self.start_after.map(|(block_height, index_in_block)| {
+                        StartAfter {
+                            block_height,
+                            index_in_block,
+                        }
+                    }),
+                    count: self.limit.map(|limit| limit as u32),
+                    order_ascending: self.order_ascending,
+                    prove,
+                },
+            )),
+        };
+
+        Ok(request)
+    }
+}
+
+impl FetchMany<(BlockHeight, u32), IdentityStateTransitions> for IdentityStateTransitionRecord {
+    type Request = GetIdentityStateTransitionsRequest;
+}
@@ -6,6 +6,7 @@ use super::types::evonode::EvoNode;
 use crate::error::Error;
 use crate::platform::documents::document_query::DocumentQuery;
 use dapi_grpc::mock::Mockable;
+use dapi_grpc::platform::v0::get_block_state_transitions_request::GetBlockStateTransitionsRequestV0;
 use dapi_grpc::platform::v0::get_contested_resource_identity_votes_request::GetContestedResourceIdentityVotesRequestV0;
 use dapi_grpc::platform::v0::get_contested_resource_voters_for_identity_request::GetContestedResourceVotersForIdentityRequestV0;
 use dapi_grpc::platform::v0::get_contested_resources_request::GetContestedResourcesRequestV0;
@@ -26,14 +27,18 @@ use dapi_grpc::platform::v0::{
     GetTotalCreditsInPlatformRequest, KeyRequestType, SpecificKeys,
 };
 use dapi_grpc::platform::v0::{
-    get_status_request, GetContestedResourceIdentityVotesRequest,
-    GetPrefundedSpecializedBalanceRequest, GetStatusRequest, GetTokenDirectPurchasePricesRequest,
+    get_block_state_transitions_request, get_status_request, GetBlockStateTransitionsRequest,
+    GetContestedResourceIdentityVotesRequest, GetPrefundedSpecializedBalanceRequest,
+    GetStatusRequest, GetTokenDirectPurchasePricesRequest,
     GetTokenPerpetualDistributionLastClaimRequest, GetVotePollsByEndDateRequest,
 };
 use dpp::dashcore_rpc::dashcore::{hashes::Hash, ProTxHash};
 use dpp::identity::KeyID;
 use dpp::version::PlatformVersionError;
-use dpp::{block::epoch::EpochIndex, prelude::Identifier};
+use dpp::{
+    block::epoch::EpochIndex,
+    prelude::{BlockHeight, Identifier},
+};
 use drive::query::contested_resource_votes_given_by_identity_query::ContestedResourceVotesGivenByIdentityQuery;
 use drive::query::vote_poll_contestant_votes_query::ContestedDocumentVotePollVotesDriveQuery;
 use drive::query::vote_poll_vote_state_query::ContestedDocumentVotePollDriveQuery;
@@ -724,6 +729,25 @@ impl Query<GetCurrentQuorumsInfoRequest> for NoParamQuery {
     }
 }
 
+/// Query for the state transitions executed in the block at the given height
+impl Query<GetBlockStateTransitionsRequest> for BlockHeight {
+    fn query(self, prove: bool) -> Result<GetBlockStateTransitionsRequest, Error> {
+        if prove {
+            unimplemented!(
+                "query with proof are not supported for GetBlockStateTransitionsRequest"
+            );
+        }
+
+        let request: GetBlockStateTransitionsRequest = GetBlockStateTransitionsRequest {
+            version: Some(get_block_state_transitions_request::Version::V0(
+                GetBlockStateTransitionsRequestV0 { height: self },
+            )),
+        };
+
+        Ok(request)
+    }
+}
+
 impl Query<GetEvonodesProposedEpochBlocksByRangeRequest> for LimitQuery<Option<EpochIndex>> {
     fn query(self, prove: bool) -> Result<GetEvonodesProposedEpochBlocksByRangeRequest, Error> {
         if !prove {
@@ -0,0 +1,195 @@
+use crate::platform::transition::put_settings::PutSettings;
+use crate::platform::Identifier;
+use crate::{Error, Sdk};
+use dpp::balances::credits::TokenAmount;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::{DataContract, TokenContractPosition};
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::prelude::{TimestampMillis, UserFeeIncrease};
+use dpp::state_transition::batch_transition::methods::v1::DocumentsBatchTransitionMethodsV1;
+use dpp::state_transition::batch_transition::methods::StateTransitionCreationOptions;
+use dpp::state_transition::batch_transition::BatchTransition;
+use dpp::state_transition::StateTransition;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::calculate_token_id;
+use dpp::version::PlatformVersion;
+use std::sync::Arc;
+
+/// A builder to configure and broadcast token approve transitions
+pub struct TokenApproveTransitionBuilder {
+    pub data_contract: Arc<DataContract>,
+    pub token_position: TokenContractPosition,
+    pub owner_id: Identifier,
+    pub spender: TokenAllowanceSpender,
+    pub amount: TokenAmount,
+    pub expires_at: Option<TimestampMillis>,
+    pub public_note: Option<String>,
+    pub settings: Option<PutSettings>,
+    pub user_fee_increase: Option<UserFeeIncrease>,
+    pub state_transition_creation_options: Option<StateTransitionCreationOptions>,
+}
+
+impl TokenApproveTransitionBuilder {
+    /// Start building an approve request for the provided DataContract.
+    ///
+    /// An amount of 0 revokes the allowance previously granted to the spender.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_contract` - An Arc to the data contract
+    /// * `token_position` - The position of the token in the contract
+    /// * `owner_id` - The identifier of the identity granting the allowance
+    /// * `spender` - The identity or group allowed to spend the tokens
+    /// * `amount` - The amount of tokens the spender may transfer
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new builder instance
+    pub fn new(
+        data_contract: Arc<DataContract>,
+        token_position: TokenContractPosition,
+        owner_id: Identifier,
+        spender: TokenAllowanceSpender,
+        amount: TokenAmount,
+    ) -> Self {
+        Self {
+            data_contract,
+            token_position,
+            owner_id,
+            spender,
+            amount,
+            expires_at: None,
+            public_note: None,
+            settings: None,
+            user_fee_increase: None,
+            state_transition_creation_options: None,
+        }
+    }
+
+    /// Sets the time after which the allowance can no longer be used
+    ///
+    /// # Arguments
+    ///
+    /// * `expires_at` - The expiration time in milliseconds
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The updated builder
+    pub fn with_expires_at(mut self, expires_at: TimestampMillis) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Adds a public note to the token approve transition
+    ///
+    /// # Arguments
+    ///
+    /// * `note` - The public note to add
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The updated builder
+    pub fn with_public_note(mut self, note: String) -> Self {
+        self.public_note = Some(note);
+        self
+    }
+
+    /// Adds a user fee increase to the token approve transition
+    ///
+    /// # Arguments
+    ///
+    /// * `user_fee_increase` - The user fee increase to add
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The updated builder
+    pub fn with_user_fee_increase(mut self, user_fee_increase: UserFeeIncrease) -> Self {
+        self.user_fee_increase = Some(user_fee_increase);
+        self
+    }
+
+    /// Adds settings to the token approve transition
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - The settings to add
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The updated builder
+    pub fn with_settings(mut self, settings: PutSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Adds state transition creation options to the token approve transition
+    ///
+    /// # Arguments
+    ///
+    /// * `state_transition_creation_options` - The state transition creation options to add
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The updated builder
+    pub fn with_state_transition_creation_options(
+        mut self,
+        state_transition_creation_options: StateTransitionCreationOptions,
+    ) -> Self {
+        self.state_transition_creation_options = Some(state_transition_creation_options);
+        self
+    }
+
+    /// Signs the token approve transition
+    ///
+    /// # Arguments
+    ///
+    /// * `sdk` - The SDK instance
+    /// * `identity_public_key` - The public key of the identity
+    /// * `signer` - The signer instance
+    /// * `platform_version` - The platform version
+    ///
+    /// # Returns
+    ///
+    /// * `Result<StateTransition, Error>` - The signed state transition or an error
+    pub async fn sign(
+        self,
+        sdk: &Sdk,
+        identity_public_key: &IdentityPublicKey,
+        signer: &impl Signer,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateTransition, Error> {
+        let token_id = Identifier::from(calculate_token_id(
+            self.data_contract.id().as_bytes(),
+            self.token_position,
+        ));
+
+        let identity_contract_nonce = sdk
+            .get_identity_contract_nonce(
+                self.owner_id,
+                self.data_contract.id(),
+                true,
+                self.settings,
+            )
+            .await?;
+
+        let state_transition = BatchTransition::new_token_approve_transition(
+            token_id,
+            self.owner_id,
+            self.data_contract.id(),
+            self.token_position,
+            self.spender,
+            self.amount,
+            self.expires_at,
+            self.public_note.clone(),
+            identity_public_key,
+            identity_contract_nonce,
+            self.user_fee_increase.unwrap_or_default(),
+            signer,
+            platform_version,
+            self.state_transition_creation_options,
+        )?;
+
+        Ok(state_transition)
+    }
+}
@@ -1,13 +1,19 @@
 //! Fungible token state transitions
 
+pub mod approve;
 pub mod burn;
+pub mod cancel_order;
 pub mod claim;
+pub mod claim_vested;
 pub mod config_update;
 pub mod destroy;
 pub mod emergency_action;
 pub mod freeze;
 pub mod mint;
+pub mod place_order;
 pub mod purchase;
 pub mod set_price;
 pub mod transfer;
+pub mod transfer_from;
 pub mod unfreeze;
+pub mod vesting_transfer;
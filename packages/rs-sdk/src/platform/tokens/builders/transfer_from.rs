@@ -0,0 +1,198 @@
+use crate::platform::transition::put_settings::PutSettings;
+use crate::platform::Identifier;
+use crate::{Error, Sdk};
+use dpp::balances::credits::TokenAmount;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::{DataContract, TokenContractPosition};
+use dpp::group::GroupStateTransitionInfoStatus;
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::prelude::UserFeeIncrease;
+use dpp::state_transition::batch_transition::methods::v1::DocumentsBatchTransitionMethodsV1;
+use dpp::state_transition::batch_transition::methods::StateTransitionCreationOptions;
+use dpp::state_transition::batch_transition::BatchTransition;
+use dpp::state_transition::StateTransition;
+use dpp::tokens::calculate_token_id;
+use dpp::version::PlatformVersion;
+use std::sync::Arc;
+
+/// A builder to configure and broadcast token transfer from transitions
+pub struct TokenTransferFromTransitionBuilder {
+    pub data_contract: Arc<DataContract>,
+    pub token_position: TokenContractPosition,
+    pub spender_id: Identifier,
+    pub from_identity_id: Identifier,
+    pub recipient_id: Identifier,
+    pub amount: TokenAmount,
+    pub public_note: Option<String>,
+    pub settings: Option<PutSettings>,
+    pub user_fee_increase: Option<UserFeeIncrease>,
+    pub using_group_info: Option<GroupStateTransitionInfoStatus>,
+    pub state_transition_creation_options: Option<StateTransitionCreationOptions>,
+}
+
+impl TokenTransferFromTransitionBuilder {
+    /// Start building a transfer from request for the provided DataContract.
+    ///
+    /// # Arguments
+    ///
+    /// * `data_contract` - An Arc to the data contract
+    /// * `token_position` - The position of the token in the contract
+    /// * `spender_id` - The identifier of the identity using the allowance
+    /// * `from_identity_id` - The identifier of the identity that granted the allowance
+    /// * `recipient_id` - The identifier of the recipient of the tokens
+    /// * `amount` - The amount of tokens to transfer
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The new builder instance
+    pub fn new(
+        data_contract: Arc<DataContract>,
+        token_position: TokenContractPosition,
+        spender_id: Identifier,
+        from_identity_id: Identifier,
+        recipient_id: Identifier,
+        amount: TokenAmount,
+    ) -> Self {
+        Self {
+            data_contract,
+            token_position,
+            spender_id,
+            from_identity_id,
+            recipient_id,
+            amount,
+            public_note: None,
+            settings: None,
+            user_fee_increase: None,
+            using_group_info: None,
+            state_transition_creation_options: None,
+        }
+    }
+
+    /// Adds a public note to the token transfer from transition
+    ///
+    /// # Arguments
+    ///
+    /// * `note` - The public note to add
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The updated builder
+    pub fn with_public_note(mut self, note: String) -> Self {
+        self.public_note = Some(note);
+        self
+    }
+
+    /// Adds a user fee increase to the token transfer from transition
+    ///
+    /// # Arguments
+    ///
+    /// * `user_fee_increase` - The user fee increase to add
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The updated builder
+    pub fn with_user_fee_increase(mut self, user_fee_increase: UserFeeIncrease) -> Self {
+        self.user_fee_increase = Some(user_fee_increase);
+        self
+    }
+
+    /// Spends the allowance granted to a group instead of the one granted to the spender
+    ///
+    /// # Arguments
+    ///
+    /// * `group_info` - The group information to add
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The updated builder
+    pub fn with_using_group_info(mut self, group_info: GroupStateTransitionInfoStatus) -> Self {
+        self.using_group_info = Some(group_info);
+        self
+    }
+
+    /// Adds settings to the token transfer from transition
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - The settings to add
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The updated builder
+    pub fn with_settings(mut self, settings: PutSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Adds state transition creation options to the token transfer from transition
+    ///
+    /// # Arguments
+    ///
+    /// * `state_transition_creation_options` - The state transition creation options to add
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The updated builder
+    pub fn with_state_transition_creation_options(
+        mut self,
+        state_transition_creation_options: StateTransitionCreationOptions,
+    ) -> Self {
+        self.state_transition_creation_options = Some(state_transition_creation_options);
+        self
+    }
+
+    /// Signs the token transfer from transition
+    ///
+    /// # Arguments
+    ///
+    /// * `sdk` - The SDK instance
+    /// * `identity_public_key` - The public key of the identity
+    /// * `signer` - The signer instance
+    /// * `platform_version` - The platform version
+    ///
+    /// # Returns
+    ///
+    /// * `Result<StateTransition, Error>` - The signed state transition or an error
+    pub async fn sign(
+        self,
+        sdk: &Sdk,
+        identity_public_key: &IdentityPublicKey,
+        signer: &impl Signer,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateTransition, Error> {
+        let token_id = Identifier::from(calculate_token_id(
+            self.data_contract.id().as_bytes(),
+            self.token_position,
+        ));
+
+        let identity_contract_nonce = sdk
+            .get_identity_contract_nonce(
+                self.spender_id,
+                self.data_contract.id(),
+                true,
+                self.settings,
+            )
+            .await?;
+
+        let state_transition = BatchTransition::new_token_transfer_from_transition(
+            token_id,
+            self.spender_id,
+            self.data_contract.id(),
+            self.token_position,
+            self.from_identity_id,
+            self.recipient_id,
+            self.amount,
+            self.public_note.clone(),
+            self.using_group_info,
+            identity_public_key,
+            identity_contract_nonce,
+            self.user_fee_increase.unwrap_or_default(),
+            signer,
+            platform_version,
+            self.state_transition_creation_options,
+        )?;
+
+        Ok(state_transition)
+    }
+}
@@ -1,13 +1,23 @@
 pub mod builders;
 /// Identity token balances queries
 pub mod identity_token_balances;
+/// Token allowances query
+pub mod token_allowances;
 /// Token contract info query
 pub mod token_contract_info;
+/// Token events query
+pub mod token_events;
+/// Token holders query
+pub mod token_holders;
 /// Identity token balances queries
 pub mod token_info;
+/// Token order book query
+pub mod token_order_book;
 /// Token status query
 pub mod token_status;
 /// Token total supply query
 pub mod token_total_supply;
+/// Token vestings query
+pub mod token_vestings;
 /// Token state transitions
 pub mod transitions;
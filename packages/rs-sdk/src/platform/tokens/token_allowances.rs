@@ -0,0 +1,70 @@
+use crate::platform::{FetchMany, Identifier, Query};
+use crate::Error;
+use dapi_grpc::platform::v0::get_token_allowances_request::get_token_allowances_request_v0::{
+    allowance_spender, AllowanceSpender,
+};
+use dapi_grpc::platform::v0::get_token_allowances_request::GetTokenAllowancesRequestV0;
+use dapi_grpc::platform::v0::{get_token_allowances_request, GetTokenAllowancesRequest};
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use dpp::tokens::allowance::TokenAllowance;
+pub use drive_proof_verifier::types::token_allowances::TokenAllowances;
+
+#[derive(Debug, Clone)]
+/// Query to fetch the allowances an owner granted for a token, ordered by spender
+pub struct TokenAllowancesQuery {
+    /// Token ID
+    pub token_id: Identifier,
+    /// Identity ID of the owner that granted the allowances
+    pub owner_id: Identifier,
+    /// Only fetch allowances of spenders after this one, identities come before groups
+    pub start_after: Option<TokenAllowanceSpender>,
+    /// An optional limit of allowances to fetch
+    pub limit: Option<u16>,
+}
+
+impl TokenAllowancesQuery {
+    /// Create a query fetching the allowances an owner granted for a token
+    pub fn new(token_id: Identifier, owner_id: Identifier) -> Self {
+        Self {
+            token_id,
+            owner_id,
+            start_after: None,
+            limit: None,
+        }
+    }
+}
+
+impl Query<GetTokenAllowancesRequest> for TokenAllowancesQuery {
+    fn query(self, prove: bool) -> Result<GetTokenAllowancesRequest, Error> {
+        let start_after = self.start_after.map(|start_after| AllowanceSpender {
+            spender: Some(match start_after {
+                TokenAllowanceSpender::Identity(identity_id) => {
+                    allowance_spender::Spender::IdentityId(identity_id.to_vec())
+                }
+                TokenAllowanceSpender::Group(group_contract_position) => {
+                    allowance_spender::Spender::GroupContractPosition(
+                        group_contract_position as u32,
+                    )
+                }
+            }),
+        });
+
+        let request = GetTokenAllowancesRequest {
+            version: Some(get_token_allowances_request::Version::V0(
+                GetTokenAllowancesRequestV0 {
+                    token_id: self.token_id.to_vec(),
+                    owner_id: self.owner_id.to_vec(),
+                    start_after,
+                    count: self.limit.map(|limit| limit as u32),
+                    prove,
+                },
+            )),
+        };
+
+        Ok(request)
+    }
+}
+
+impl FetchMany<TokenAllowanceSpender, TokenAllowances> for TokenAllowance {
+    type Request = GetTokenAllowancesRequest;
+}
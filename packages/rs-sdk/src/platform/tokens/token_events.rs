@@ -0,0 +1,107 @@
+use crate::platform::{FetchMany, Identifier, Query};
+use crate::Error;
+use dapi_grpc::platform::v0::get_token_events_request::get_token_events_request_v0::{
+    BlockRange, Filter, StartAfter, TokenEventType,
+};
+use dapi_grpc::platform::v0::get_token_events_request::GetTokenEventsRequestV0;
+use dapi_grpc::platform::v0::{get_token_events_request, GetTokenEventsRequest};
+pub use drive::query::token_event_history_query::{
+    TokenEventHistoryFilter, TokenEventHistoryStartAfter, TokenEventHistoryType,
+};
+pub use drive_proof_verifier::types::token_events::{TokenEventRecord, TokenEvents};
+
+#[derive(Debug, Clone)]
+/// Query to fetch historical events of a token, ordered by the height of the block they were
+/// recorded in when filtering by a block height range and by the block time otherwise
+///
+/// Only tokens keeping history record their events in the token history contract.
+pub struct TokenEventsQuery {
+    /// Token ID
+    pub token_id: Identifier,
+    /// The kind of events to fetch, events of every kind are fetched when not set
+    pub event_type: Option<TokenEventHistoryType>,
+    /// An optional restriction of the fetched events
+    pub filter: Option<TokenEventHistoryFilter>,
+    /// Only fetch events ordered after this one, usually the last event of the previous page
+    pub start_after: Option<TokenEventHistoryStartAfter>,
+    /// An optional limit of events to fetch
+    pub limit: Option<u16>,
+    /// Whether the oldest events come first
+    pub order_ascending: bool,
+}
+
+impl TokenEventsQuery {
+    /// Create a query fetching the latest events of every kind for a token
+    pub fn new(token_id: Identifier) -> Self {
+        Self {
+            token_id,
+            event_type: None,
+            filter: None,
+            start_after: None,
+            limit: None,
+            order_ascending: false,
+        }
+    }
+}
+
+fn token_event_type(event_type: TokenEventHistoryType) -> TokenEventType {
+    match event_type {
+        TokenEventHistoryType::Mint => TokenEventType::Mint,
+        TokenEventHistoryType::Burn => TokenEventType::Burn,
+        TokenEventHistoryType::Freeze => TokenEventType::Freeze,
+        TokenEventHistoryType::Unfreeze => TokenEventType::Unfreeze,
+        TokenEventHistoryType::DestroyFrozenFunds => TokenEventType::DestroyFrozenFunds,
+        TokenEventHistoryType::Transfer => TokenEventType::Transfer,
+        TokenEventHistoryType::Claim => TokenEventType::Claim,
+        TokenEventHistoryType::EmergencyAction => TokenEventType::EmergencyAction,
+        TokenEventHistoryType::ConfigUpdate => TokenEventType::ConfigUpdate,
+        TokenEventHistoryType::DirectPricing => TokenEventType::DirectPricing,
+        TokenEventHistoryType::DirectPurchase => TokenEventType::DirectPurchase,
+    }
+}
+
+impl Query<GetTokenEventsRequest> for TokenEventsQuery {
+    fn query(self, prove: bool) -> Result<GetTokenEventsRequest, Error> {
+        let filter = self.filter.map(|filter| match filter {
+            TokenEventHistoryFilter::ByIdentity(identity_id) => {
+                Filter::IdentityId(identity_id.to_vec())
+            }
+            TokenEventHistoryFilter::ByBlockHeightRange {
+                start_height,
+                end_height,
+            } => Filter::BlockRange(BlockRange {
+                start_height,
+                end_height,
+            }),
+        });
+
+        let start_after = self.start_after.map(|start_after| StartAfter {
+            document_id: start_after.document_id.to_vec(),
+            event_type: token_event_type(start_after.event_type) as i32,
+            created_at: start_after.created_at,
+            created_at_block_height: start_after.created_at_block_height,
+        });
+
+        let request = GetTokenEventsRequest {
+            version: Some(get_token_events_request::Version::V0(
+                GetTokenEventsRequestV0 {
+                    token_id: self.token_id.to_vec(),
+                    event_type: self
+                        .event_type
+                        .map(|event_type| token_event_type(event_type) as i32),
+                    filter,
+                    start_after,
+                    count: self.limit.map(|limit| limit as u32),
+                    order_ascending: self.order_ascending,
+                    prove,
+                },
+            )),
+        };
+
+        Ok(request)
+    }
+}
+
+impl FetchMany<Identifier, TokenEvents> for TokenEventRecord {
+    type Request = GetTokenEventsRequest;
+}
@@ -0,0 +1,83 @@
+use crate::platform::{FetchMany, Identifier, Query};
+use crate::Error;
+use dapi_grpc::platform::v0::get_token_holders_request::{
+    GetTokenHoldersRequestV0, StartAtIdentityId,
+};
+use dapi_grpc::platform::v0::{get_token_holders_request, GetTokenHoldersRequest};
+use dpp::balances::credits::TokenAmount;
+use dpp::prelude::StartAtIncluded;
+use drive::config::DEFAULT_QUERY_LIMIT;
+pub use drive_proof_verifier::types::identity_token_balance::TokenHolders;
+
+#[derive(Debug, Clone)]
+/// Query to fetch the holders of a token with their balances, paginated by identity ID
+pub struct TokenHoldersQuery {
+    /// Token ID
+    pub token_id: Identifier,
+    /// Optional identity ID to start from
+    /// If not provided, the holder with the lowest identity ID will be fetched first
+    /// If provided, the holder with this identity ID will be included in the result if `StartAtIncluded` is `true`
+    pub start_at_identity_id: Option<(Identifier, StartAtIncluded)>,
+    /// An optional limit of holders to fetch
+    pub limit: Option<u16>,
+    /// Sort the fetched page by balance, highest first, instead of by identity ID
+    pub order_by_balance: bool,
+}
+
+impl TokenHoldersQuery {
+    /// Create a query fetching the first page of holders of a token
+    pub fn new(token_id: Identifier) -> Self {
+        Self {
+            token_id,
+            start_at_identity_id: None,
+            limit: None,
+            order_by_balance: false,
+        }
+    }
+
+    /// Create a query fetching the page of holders following `holders`,
+    /// or `None` if `holders` is the last page
+    pub fn next_page(&self, holders: &TokenHolders) -> Option<Self> {
+        let limit = self.limit.unwrap_or(DEFAULT_QUERY_LIMIT) as usize;
+        if holders.len() < limit {
+            return None;
+        }
+
+        // Pages are cut by identity ID even when sorted by balance
+        let last_identity_id = holders.keys().max()?;
+
+        Some(Self {
+            token_id: self.token_id,
+            start_at_identity_id: Some((*last_identity_id, false)),
+            limit: self.limit,
+            order_by_balance: self.order_by_balance,
+        })
+    }
+}
+
+impl Query<GetTokenHoldersRequest> for TokenHoldersQuery {
+    fn query(self, prove: bool) -> Result<GetTokenHoldersRequest, Error> {
+        let request = GetTokenHoldersRequest {
+            version: Some(get_token_holders_request::Version::V0(
+                GetTokenHoldersRequestV0 {
+                    token_id: self.token_id.to_vec(),
+                    start_at_identity_id: self.start_at_identity_id.map(
+                        |(identity_id, included)| StartAtIdentityId {
+                            start_identity_id: identity_id.to_vec(),
+                            start_identity_id_included: included,
+                        },
+                    ),
+                    count: self.limit.map(|limit| limit as u32),
+                    prove,
+                    order_by_balance: self.order_by_balance,
+                },
+            )),
+        };
+
+        Ok(request)
+    }
+}
+
+impl FetchMany<Identifier, TokenHolders> for TokenAmount {
+    type Request = GetTokenHoldersRequest;
+}
@@ -0,0 +1,73 @@
+use crate::platform::{FetchMany, Identifier, Query};
+use crate::Error;
+use dapi_grpc::platform::v0::get_token_order_book_request::{
+    GetTokenOrderBookRequestV0, OrderSide, QuoteToken,
+};
+use dapi_grpc::platform::v0::{get_token_order_book_request, GetTokenOrderBookRequest};
+use dpp::tokens::allowed_currency::AllowedCurrency;
+use dpp::tokens::order_book::{TokenOrder, TokenOrderSide};
+pub use drive_proof_verifier::types::token_order_book::TokenOrderBookOrders;
+
+#[derive(Debug, Clone)]
+/// Query to fetch the best resting orders of one side of a token order book, in price-time priority
+pub struct TokenOrderBookQuery {
+    /// Token ID
+    pub token_id: Identifier,
+    /// Whether buy or sell orders are fetched
+    pub side: TokenOrderSide,
+    /// The currency the orders are quoted in
+    pub quote_currency: AllowedCurrency,
+    /// An optional limit of orders to fetch
+    pub limit: Option<u16>,
+}
+
+impl TokenOrderBookQuery {
+    /// Create a query fetching the best orders of one side of a token order book
+    pub fn new(
+        token_id: Identifier,
+        side: TokenOrderSide,
+        quote_currency: AllowedCurrency,
+    ) -> Self {
+        Self {
+            token_id,
+            side,
+            quote_currency,
+            limit: None,
+        }
+    }
+}
+
+impl Query<GetTokenOrderBookRequest> for TokenOrderBookQuery {
+    fn query(self, prove: bool) -> Result<GetTokenOrderBookRequest, Error> {
+        let side = match self.side {
+            TokenOrderSide::Buy => OrderSide::Buy,
+            TokenOrderSide::Sell => OrderSide::Sell,
+        };
+
+        let quote_token = match self.quote_currency {
+            AllowedCurrency::TradingInDash => None,
+            AllowedCurrency::OnContract(contract_id, token_position) => Some(QuoteToken {
+                contract_id: contract_id.to_vec(),
+                token_position: token_position as u32,
+            }),
+        };
+
+        let request = GetTokenOrderBookRequest {
+            version: Some(get_token_order_book_request::Version::V0(
+                GetTokenOrderBookRequestV0 {
+                    token_id: self.token_id.to_vec(),
+                    side: side as i32,
+                    quote_token,
+                    count: self.limit.map(|limit| limit as u32),
+                    prove,
+                },
+            )),
+        };
+
+        Ok(request)
+    }
+}
+
+impl FetchMany<Identifier, TokenOrderBookOrders> for TokenOrder {
+    type Request = GetTokenOrderBookRequest;
+}
@@ -0,0 +1,53 @@
+use crate::platform::{FetchMany, Identifier, Query};
+use crate::Error;
+use dapi_grpc::platform::v0::get_token_vestings_request::GetTokenVestingsRequestV0;
+use dapi_grpc::platform::v0::{get_token_vestings_request, GetTokenVestingsRequest};
+use dpp::tokens::vesting::TokenVesting;
+pub use drive_proof_verifier::types::token_vestings::TokenVestings;
+
+#[derive(Debug, Clone)]
+/// Query to fetch the vestings of a token locked for a recipient, ordered by vesting ID
+pub struct TokenVestingsQuery {
+    /// Token ID
+    pub token_id: Identifier,
+    /// Identity ID of the recipient of the vestings
+    pub recipient_id: Identifier,
+    /// Only fetch vestings with an ID greater than this one
+    pub start_after: Option<Identifier>,
+    /// An optional limit of vestings to fetch
+    pub limit: Option<u16>,
+}
+
+impl TokenVestingsQuery {
+    /// Create a query fetching the vestings of a token locked for a recipient
+    pub fn new(token_id: Identifier, recipient_id: Identifier) -> Self {
+        Self {
+            token_id,
+            recipient_id,
+            start_after: None,
+            limit: None,
+        }
+    }
+}
+
+impl Query<GetTokenVestingsRequest> for TokenVestingsQuery {
+    fn query(self, prove: bool) -> Result<GetTokenVestingsRequest, Error> {
+        let request = GetTokenVestingsRequest {
+            version: Some(get_token_vestings_request::Version::V0(
+                GetTokenVestingsRequestV0 {
+                    token_id: self.token_id.to_vec(),
+                    recipient_id: self.recipient_id.to_vec(),
+                    start_after: self.start_after.map(|start_after| start_after.to_vec()),
+                    count: self.limit.map(|limit| limit as u32),
+                    prove,
+                },
+            )),
+        };
+
+        Ok(request)
+    }
+}
+
+impl FetchMany<Identifier, TokenVestings> for TokenVesting {
+    type Request = GetTokenVestingsRequest;
+}
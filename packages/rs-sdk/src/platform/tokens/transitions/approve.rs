@@ -0,0 +1,84 @@
+//! Token allowance approval for the Dash Platform SDK.
+//!
+//! This module provides functionality to allow another identity or a group
+//! to transfer tokens on behalf of the owner.
+
+use crate::platform::tokens::builders::approve::TokenApproveTransitionBuilder;
+use crate::platform::transition::broadcast::BroadcastStateTransition;
+use crate::{Error, Sdk};
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::platform_value::Identifier;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+use dpp::tokens::allowance::TokenAllowance;
+
+/// Result types returned from approving a token allowance.
+///
+/// Allowance actions never keep history, the proof shows the allowance as it
+/// is stored after the transition was executed.
+pub enum ApproveResult {
+    /// The owner ID and the allowance that is now granted to the spender.
+    Allowance(Identifier, TokenAllowance),
+    /// The owner ID, the allowance was revoked.
+    Revoked(Identifier),
+}
+
+impl Sdk {
+    /// Grants, replaces or revokes the allowance of a spender.
+    ///
+    /// This method broadcasts an approve transition. The spender can afterwards
+    /// transfer up to the approved amount out of the balance of the owner until
+    /// the allowance expires. Approving an amount of 0 revokes the allowance.
+    ///
+    /// # Arguments
+    ///
+    /// * `approve_transition_builder` - Builder containing the spender and the approved amount
+    /// * `signing_key` - The identity public key for signing the transition
+    /// * `signer` - Implementation of the Signer trait for cryptographic signing
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `ApproveResult` on success, or an `Error` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transition signing fails
+    /// - Broadcasting the transition fails
+    /// - The proof verification returns an unexpected result type
+    /// - The spender does not exist
+    pub async fn token_approve<S: Signer>(
+        &self,
+        approve_transition_builder: TokenApproveTransitionBuilder,
+        signing_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<ApproveResult, Error> {
+        let platform_version = self.version();
+
+        let put_settings = approve_transition_builder.settings;
+
+        let state_transition = approve_transition_builder
+            .sign(self, signing_key, signer, platform_version)
+            .await?;
+
+        let proof_result = state_transition
+            .broadcast_and_wait::<StateTransitionProofResult>(self, put_settings)
+            .await?;
+
+        match proof_result {
+            StateTransitionProofResult::VerifiedTokenAllowance(owner_id, Some(allowance)) => {
+                Ok(ApproveResult::Allowance(owner_id, allowance))
+            }
+            StateTransitionProofResult::VerifiedTokenAllowance(owner_id, None) => {
+                Ok(ApproveResult::Revoked(owner_id))
+            }
+            _ => Err(Error::DriveProofError(
+                drive::error::proof::ProofError::UnexpectedResultProof(
+                    "Expected VerifiedTokenAllowance for approve transition".to_string(),
+                ),
+                vec![],
+                Default::default(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Token order book order cancellation for the Dash Platform SDK.
+//!
+//! This module provides functionality to cancel resting limit orders, giving
+//! back what was escrowed for them.
+
+use crate::platform::tokens::builders::cancel_order::TokenCancelOrderTransitionBuilder;
+use crate::platform::transition::broadcast::BroadcastStateTransition;
+use crate::{Error, Sdk};
+use dpp::balances::credits::TokenAmount;
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::platform_value::Identifier;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+
+/// Result types returned from cancelling a token order.
+///
+/// Order book actions never keep history, the proof shows the balance of the
+/// traded token of the identity that cancelled the order.
+pub enum CancelOrderResult {
+    /// The identity ID and its token balance after the order was cancelled.
+    TokenBalance(Identifier, TokenAmount),
+    /// The identity does not hold the token.
+    NoTokenBalance(Identifier),
+}
+
+impl Sdk {
+    /// Cancels a resting limit order on the order book of a token.
+    ///
+    /// This method broadcasts a cancel order transition. The order is taken off
+    /// the book and what is left of its escrow is given back to the identity
+    /// that placed it.
+    ///
+    /// # Arguments
+    ///
+    /// * `cancel_order_transition_builder` - Builder containing the id of the order to cancel
+    /// * `signing_key` - The identity public key for signing the transition
+    /// * `signer` - Implementation of the Signer trait for cryptographic signing
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a `CancelOrderResult` on success, or an `Error` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transition signing fails
+    /// - Broadcasting the transition fails
+    /// - The proof verification returns an unexpected result type
+    /// - The order does not exist or was placed by another identity
+    pub async fn token_cancel_order<S: Signer>(
+        &self,
+        cancel_order_transition_builder: TokenCancelOrderTransitionBuilder,
+        signing_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<CancelOrderResult, Error> {
+        let platform_version = self.version();
+
+        let put_settings = cancel_order_transition_builder.settings;
+
+        let state_transition = cancel_order_transition_builder
+            .sign(self, signing_key, signer, platform_version)
+            .await?;
+
+        let proof_result = state_transition
+            .broadcast_and_wait::<StateTransitionProofResult>(self, put_settings)
+            .await?;
+
+        match proof_result {
+            StateTransitionProofResult::VerifiedTokenBalance(owner_id, balance) => {
+                Ok(CancelOrderResult::TokenBalance(owner_id, balance))
+            }
+            StateTransitionProofResult::VerifiedTokenBalanceAbsence(owner_id) => {
+                Ok(CancelOrderResult::NoTokenBalance(owner_id))
+            }
+            _ => Err(Error::DriveProofError(
+                drive::error::proof::ProofError::UnexpectedResultProof(
+                    "Expected VerifiedTokenBalance or VerifiedTokenBalanceAbsence for cancel order transition".to_string(),
+                ),
+                vec![],
+                Default::default(),
+            )),
+        }
+    }
+}
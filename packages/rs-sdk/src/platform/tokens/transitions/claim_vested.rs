@@ -0,0 +1,84 @@
+//! Vested token claiming for the Dash Platform SDK.
+//!
+//! This module provides functionality to claim tokens locked by a vesting
+//! transfer once they have vested.
+
+use crate::platform::tokens::builders::claim_vested::TokenClaimVestedTransitionBuilder;
+use crate::platform::transition::broadcast::BroadcastStateTransition;
+use crate::{Error, Sdk};
+use dpp::balances::credits::TokenAmount;
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::platform_value::Identifier;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+
+/// Result types returned from claiming vested tokens.
+///
+/// Vesting actions never keep history, the proof shows the token balance of
+/// the recipient of the vesting.
+pub enum ClaimVestedResult {
+    /// The recipient ID and its token balance after the vested tokens were claimed.
+    TokenBalance(Identifier, TokenAmount),
+    /// The recipient does not hold the token.
+    NoTokenBalance(Identifier),
+}
+
+impl Sdk {
+    /// Claims the tokens of a vesting that have vested and were not claimed yet.
+    ///
+    /// This method broadcasts a claim vested transition. Whatever the schedule of the
+    /// vesting has released so far is moved from escrow to the balance of the
+    /// recipient, the vesting is removed once everything has been claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `claim_vested_transition_builder` - Builder containing the id of the vesting to claim from
+    /// * `signing_key` - The identity public key for signing the transition
+    /// * `signer` - Implementation of the Signer trait for cryptographic signing
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a `ClaimVestedResult` on success, or an `Error` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transition signing fails
+    /// - Broadcasting the transition fails
+    /// - The proof verification returns an unexpected result type
+    /// - The vesting does not exist or nothing has vested since the last claim
+    pub async fn token_claim_vested<S: Signer>(
+        &self,
+        claim_vested_transition_builder: TokenClaimVestedTransitionBuilder,
+        signing_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<ClaimVestedResult, Error> {
+        let platform_version = self.version();
+
+        let put_settings = claim_vested_transition_builder.settings;
+
+        let state_transition = claim_vested_transition_builder
+            .sign(self, signing_key, signer, platform_version)
+            .await?;
+
+        let proof_result = state_transition
+            .broadcast_and_wait::<StateTransitionProofResult>(self, put_settings)
+            .await?;
+
+        match proof_result {
+            StateTransitionProofResult::VerifiedTokenBalance(owner_id, balance) => {
+                Ok(ClaimVestedResult::TokenBalance(owner_id, balance))
+            }
+            StateTransitionProofResult::VerifiedTokenBalanceAbsence(owner_id) => {
+                Ok(ClaimVestedResult::NoTokenBalance(owner_id))
+            }
+            _ => Err(Error::DriveProofError(
+                drive::error::proof::ProofError::UnexpectedResultProof(
+                    "Expected VerifiedTokenBalance or VerifiedTokenBalanceAbsence for claim vested transition".to_string(),
+                ),
+                vec![],
+                Default::default(),
+            )),
+        }
+    }
+}
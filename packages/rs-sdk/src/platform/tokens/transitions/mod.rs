@@ -4,12 +4,21 @@
 //! fungible tokens on the Dash Platform. Each submodule implements a specific
 //! type of token operation.
 
+/// Allowance approvals letting another identity or a group spend tokens of the owner.
+pub mod approve;
+
 /// Token burning operations for permanently removing tokens from circulation.
 pub mod burn;
 
+/// Order cancelling operations giving back what was escrowed for a resting order.
+pub mod cancel_order;
+
 /// Token claiming operations for acquiring ownership of unclaimed tokens.
 pub mod claim;
 
+/// Claiming operations for tokens that have vested from a vesting transfer.
+pub mod claim_vested;
+
 /// Token configuration updates for modifying token contract settings.
 pub mod config_update;
 
@@ -28,23 +37,38 @@ pub mod freeze;
 /// Token minting operations for creating new tokens.
 pub mod mint;
 
+/// Limit order placing operations on the order book of a token.
+pub mod place_order;
+
 /// Price setting operations for tokens available for direct purchase.
 pub mod set_price_for_direct_purchase;
 
 /// Token transfer operations between identities.
 pub mod transfer;
 
+/// Token transfers out of the balance of another identity using its allowance.
+pub mod transfer_from;
+
 /// Token unfreezing operations to restore transfer capabilities.
 pub mod unfreeze;
 
+/// Transfer operations locking tokens for a recipient under a vesting schedule.
+pub mod vesting_transfer;
+
+pub use approve::ApproveResult;
 pub use burn::BurnResult;
+pub use cancel_order::CancelOrderResult;
 pub use claim::ClaimResult;
+pub use claim_vested::ClaimVestedResult;
 pub use config_update::ConfigUpdateResult;
 pub use destroy_frozen_funds::DestroyFrozenFundsResult;
 pub use direct_purchase::DirectPurchaseResult;
 pub use emergency_action::EmergencyActionResult;
 pub use freeze::FreezeResult;
 pub use mint::MintResult;
+pub use place_order::PlaceOrderResult;
 pub use set_price_for_direct_purchase::SetPriceResult;
 pub use transfer::TransferResult;
+pub use transfer_from::TransferFromResult;
 pub use unfreeze::UnfreezeResult;
+pub use vesting_transfer::VestingTransferResult;
@@ -0,0 +1,85 @@
+//! Token order book order placement for the Dash Platform SDK.
+//!
+//! This module provides functionality to place limit orders on the order book
+//! of a token, buying or selling it against Dash credits or another token.
+
+use crate::platform::tokens::builders::place_order::TokenPlaceOrderTransitionBuilder;
+use crate::platform::transition::broadcast::BroadcastStateTransition;
+use crate::{Error, Sdk};
+use dpp::balances::credits::TokenAmount;
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::platform_value::Identifier;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+
+/// Result types returned from placing a token order.
+///
+/// Order book actions never keep history, the proof shows the balance of the
+/// traded token of the identity that placed the order.
+pub enum PlaceOrderResult {
+    /// The identity ID and its token balance after the order was placed and matched.
+    TokenBalance(Identifier, TokenAmount),
+    /// The identity does not hold the token, a buy order that was not filled yet.
+    NoTokenBalance(Identifier),
+}
+
+impl Sdk {
+    /// Places a limit order on the order book of a token.
+    ///
+    /// This method broadcasts a place order transition. The order is matched right
+    /// away against the resting orders of the other side of the book, in price-time
+    /// priority and at their prices. What is not filled rests on the book, with the
+    /// tokens or the quote currency it needs escrowed until it is filled or cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `place_order_transition_builder` - Builder containing the side, quote currency, amount and limit price
+    /// * `signing_key` - The identity public key for signing the transition
+    /// * `signer` - Implementation of the Signer trait for cryptographic signing
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a `PlaceOrderResult` on success, or an `Error` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transition signing fails
+    /// - Broadcasting the transition fails
+    /// - The proof verification returns an unexpected result type
+    /// - The identity can not pay for the order
+    pub async fn token_place_order<S: Signer>(
+        &self,
+        place_order_transition_builder: TokenPlaceOrderTransitionBuilder,
+        signing_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<PlaceOrderResult, Error> {
+        let platform_version = self.version();
+
+        let put_settings = place_order_transition_builder.settings;
+
+        let state_transition = place_order_transition_builder
+            .sign(self, signing_key, signer, platform_version)
+            .await?;
+
+        let proof_result = state_transition
+            .broadcast_and_wait::<StateTransitionProofResult>(self, put_settings)
+            .await?;
+
+        match proof_result {
+            StateTransitionProofResult::VerifiedTokenBalance(owner_id, balance) => {
+                Ok(PlaceOrderResult::TokenBalance(owner_id, balance))
+            }
+            StateTransitionProofResult::VerifiedTokenBalanceAbsence(owner_id) => {
+                Ok(PlaceOrderResult::NoTokenBalance(owner_id))
+            }
+            _ => Err(Error::DriveProofError(
+                drive::error::proof::ProofError::UnexpectedResultProof(
+                    "Expected VerifiedTokenBalance or VerifiedTokenBalanceAbsence for place order transition".to_string(),
+                ),
+                vec![],
+                Default::default(),
+            )),
+        }
+    }
+}
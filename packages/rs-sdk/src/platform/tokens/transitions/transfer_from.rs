@@ -0,0 +1,88 @@
+//! Allowance based token transfers for the Dash Platform SDK.
+//!
+//! This module provides functionality to transfer tokens out of the balance
+//! of another identity using an allowance it granted.
+
+use crate::platform::tokens::builders::transfer_from::TokenTransferFromTransitionBuilder;
+use crate::platform::transition::broadcast::BroadcastStateTransition;
+use crate::{Error, Sdk};
+use dpp::balances::credits::TokenAmount;
+use dpp::data_contract::group::GroupSumPower;
+use dpp::group::group_action_status::GroupActionStatus;
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::platform_value::Identifier;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+use std::collections::BTreeMap;
+
+/// Result types returned from transfer from operations.
+///
+/// Allowance actions never keep history, the result depends on whether the
+/// allowance of a group was used.
+pub enum TransferFromResult {
+    /// Token balances of the owner of the allowance and of the recipient.
+    IdentitiesBalances(BTreeMap<Identifier, TokenAmount>),
+    /// Group-based transfer with the balance of the recipient and status information.
+    GroupActionWithBalance(GroupSumPower, GroupActionStatus, Option<TokenAmount>),
+}
+
+impl Sdk {
+    /// Transfers tokens out of the balance of another identity using its allowance.
+    ///
+    /// This method broadcasts a transfer from transition. The amount is deducted
+    /// from both the balance of the owner and the remaining allowance. When the
+    /// allowance was granted to a group, the transfer only happens once enough
+    /// members of the group have signed off on it.
+    ///
+    /// # Arguments
+    ///
+    /// * `transfer_from_transition_builder` - Builder containing the owner, recipient and amount
+    /// * `signing_key` - The identity public key for signing the transition
+    /// * `signer` - Implementation of the Signer trait for cryptographic signing
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a `TransferFromResult` on success, or an `Error` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transition signing fails
+    /// - Broadcasting the transition fails
+    /// - The proof verification returns an unexpected result type
+    /// - The allowance does not exist, has expired or is too low
+    pub async fn token_transfer_from<S: Signer>(
+        &self,
+        transfer_from_transition_builder: TokenTransferFromTransitionBuilder,
+        signing_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<TransferFromResult, Error> {
+        let platform_version = self.version();
+
+        let put_settings = transfer_from_transition_builder.settings;
+
+        let state_transition = transfer_from_transition_builder
+            .sign(self, signing_key, signer, platform_version)
+            .await?;
+
+        let proof_result = state_transition
+            .broadcast_and_wait::<StateTransitionProofResult>(self, put_settings)
+            .await?;
+
+        match proof_result {
+            StateTransitionProofResult::VerifiedTokenIdentitiesBalances(balances) => {
+                Ok(TransferFromResult::IdentitiesBalances(balances))
+            }
+            StateTransitionProofResult::VerifiedTokenGroupActionWithTokenBalance(power, status, balance) => {
+                Ok(TransferFromResult::GroupActionWithBalance(power, status, balance))
+            }
+            _ => Err(Error::DriveProofError(
+                drive::error::proof::ProofError::UnexpectedResultProof(
+                    "Expected VerifiedTokenIdentitiesBalances or VerifiedTokenGroupActionWithTokenBalance for transfer from transition".to_string(),
+                ),
+                vec![],
+                Default::default(),
+            )),
+        }
+    }
+}
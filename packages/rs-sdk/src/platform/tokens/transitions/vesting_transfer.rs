@@ -0,0 +1,84 @@
+//! Token vesting transfers for the Dash Platform SDK.
+//!
+//! This module provides functionality to lock tokens for a recipient under a
+//! vesting schedule, the recipient claims them as they vest.
+
+use crate::platform::tokens::builders::vesting_transfer::TokenVestingTransferTransitionBuilder;
+use crate::platform::transition::broadcast::BroadcastStateTransition;
+use crate::{Error, Sdk};
+use dpp::balances::credits::TokenAmount;
+use dpp::identity::signer::Signer;
+use dpp::identity::IdentityPublicKey;
+use dpp::platform_value::Identifier;
+use dpp::state_transition::proof_result::StateTransitionProofResult;
+
+/// Result types returned from a token vesting transfer.
+///
+/// Vesting actions never keep history, the proof shows the token balance of
+/// the sender.
+pub enum VestingTransferResult {
+    /// The sender ID and its token balance after the tokens were locked.
+    TokenBalance(Identifier, TokenAmount),
+    /// The sender does not hold the token anymore.
+    NoTokenBalance(Identifier),
+}
+
+impl Sdk {
+    /// Locks tokens for a recipient under a vesting schedule.
+    ///
+    /// This method broadcasts a vesting transfer transition. The tokens leave the
+    /// balance of the sender right away and are held in escrow, the recipient
+    /// claims them over time as the schedule releases them.
+    ///
+    /// # Arguments
+    ///
+    /// * `vesting_transfer_transition_builder` - Builder containing the recipient, amount and schedule
+    /// * `signing_key` - The identity public key for signing the transition
+    /// * `signer` - Implementation of the Signer trait for cryptographic signing
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a `VestingTransferResult` on success, or an `Error` on failure.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transition signing fails
+    /// - Broadcasting the transition fails
+    /// - The proof verification returns an unexpected result type
+    /// - The sender does not have enough tokens or the schedule is invalid
+    pub async fn token_vesting_transfer<S: Signer>(
+        &self,
+        vesting_transfer_transition_builder: TokenVestingTransferTransitionBuilder,
+        signing_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<VestingTransferResult, Error> {
+        let platform_version = self.version();
+
+        let put_settings = vesting_transfer_transition_builder.settings;
+
+        let state_transition = vesting_transfer_transition_builder
+            .sign(self, signing_key, signer, platform_version)
+            .await?;
+
+        let proof_result = state_transition
+            .broadcast_and_wait::<StateTransitionProofResult>(self, put_settings)
+            .await?;
+
+        match proof_result {
+            StateTransitionProofResult::VerifiedTokenBalance(owner_id, balance) => {
+                Ok(VestingTransferResult::TokenBalance(owner_id, balance))
+            }
+            StateTransitionProofResult::VerifiedTokenBalanceAbsence(owner_id) => {
+                Ok(VestingTransferResult::NoTokenBalance(owner_id))
+            }
+            _ => Err(Error::DriveProofError(
+                drive::error::proof::ProofError::UnexpectedResultProof(
+                    "Expected VerifiedTokenBalance or VerifiedTokenBalanceAbsence for vesting transfer transition".to_string(),
+                ),
+                vec![],
+                Default::default(),
+            )),
+        }
+    }
+}
@@ -7,6 +7,7 @@ pub mod put_contract;
 pub mod put_document;
 pub mod put_identity;
 pub mod put_settings;
+pub mod simulate;
 pub mod top_up_identity;
 pub mod transfer;
 pub mod transfer_document;
@@ -0,0 +1,136 @@
+//! Dry-run of a state transition against the current Platform state.
+use super::put_settings::PutSettings;
+use crate::error::StateTransitionBroadcastError;
+use crate::{Error, Sdk};
+use dapi_grpc::platform::v0::simulate_state_transition_request::{
+    SimulateStateTransitionRequestV0, Version,
+};
+use dapi_grpc::platform::v0::simulate_state_transition_response::{
+    self, simulate_state_transition_response_v0,
+};
+use dapi_grpc::platform::v0::{
+    ResponseMetadata, SimulateStateTransitionRequest, SimulateStateTransitionResponse,
+};
+use dpp::fee::Credits;
+use dpp::prelude::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::state_transition::StateTransition;
+use rs_dapi_client::{DapiRequestExecutor, IntoInner, RequestSettings};
+use std::collections::BTreeMap;
+
+/// Estimated fees of a simulated state transition
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SimulatedFee {
+    /// Storage fee
+    pub storage_fee: Credits,
+    /// Processing fee
+    pub processing_fee: Credits,
+    /// Credits refunded to identities for removed storage, summed over epochs
+    pub fee_refunds: BTreeMap<Identifier, Credits>,
+    /// Bytes removed from the system
+    pub removed_bytes_from_system: u32,
+}
+
+impl TryFrom<simulate_state_transition_response_v0::FeeResult> for SimulatedFee {
+    type Error = Error;
+
+    fn try_from(
+        fee_result: simulate_state_transition_response_v0::FeeResult,
+    ) -> Result<Self, Self::Error> {
+        let fee_refunds = fee_result
+            .fee_refunds
+            .into_iter()
+            .map(|refund| {
+                let identity_id = Identifier::from_bytes(&refund.identity_id)
+                    .map_err(|e| Error::Protocol(e.into()))?;
+
+                Ok((identity_id, refund.credits))
+            })
+            .collect::<Result<BTreeMap<_, _>, Error>>()?;
+
+        Ok(Self {
+            storage_fee: fee_result.storage_fee,
+            processing_fee: fee_result.processing_fee,
+            fee_refunds,
+            removed_bytes_from_system: fee_result.removed_bytes_from_system,
+        })
+    }
+}
+
+/// Outcome of a simulated state transition
+#[derive(Debug)]
+pub struct StateTransitionSimulation {
+    /// Fees the state transition would be charged, `None` when it would not be paid for
+    pub fee: Option<SimulatedFee>,
+    /// Consensus error the state transition would fail with
+    pub error: Option<StateTransitionBroadcastError>,
+    /// Metadata of the state the state transition was simulated against
+    pub metadata: Option<ResponseMetadata>,
+}
+
+impl StateTransitionSimulation {
+    /// Whether the state transition would be executed successfully
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+impl TryFrom<SimulateStateTransitionResponse> for StateTransitionSimulation {
+    type Error = Error;
+
+    fn try_from(response: SimulateStateTransitionResponse) -> Result<Self, Self::Error> {
+        let Some(simulate_state_transition_response::Version::V0(response)) = response.version
+        else {
+            return Err(Error::Generic(
+                "missing version of state transition simulation response".to_string(),
+            ));
+        };
+
+        Ok(Self {
+            fee: response
+                .fee_result
+                .map(SimulatedFee::try_from)
+                .transpose()?,
+            error: response
+                .error
+                .map(StateTransitionBroadcastError::try_from)
+                .transpose()?,
+            metadata: response.metadata,
+        })
+    }
+}
+
+/// Dry-run of a state transition without broadcasting it
+#[async_trait::async_trait]
+pub trait SimulateStateTransition {
+    /// Validates the state transition against the last committed Platform state and estimates
+    /// its fees. Nothing is broadcast or written.
+    ///
+    /// Only [PutSettings::request_settings] of `settings` is used.
+    async fn simulate(
+        &self,
+        sdk: &Sdk,
+        settings: Option<PutSettings>,
+    ) -> Result<StateTransitionSimulation, Error>;
+}
+
+#[async_trait::async_trait]
+impl SimulateStateTransition for StateTransition {
+    async fn simulate(
+        &self,
+        sdk: &Sdk,
+        settings: Option<PutSettings>,
+    ) -> Result<StateTransitionSimulation, Error> {
+        let request = SimulateStateTransitionRequest {
+            version: Some(Version::V0(SimulateStateTransitionRequestV0 {
+                state_transition: self.serialize_to_bytes()?,
+            })),
+        };
+
+        let request_settings = settings.map(RequestSettings::from).unwrap_or_default();
+
+        let response = sdk.execute(request, request_settings).await.into_inner()?;
+
+        StateTransitionSimulation::try_from(response)
+    }
+}
@@ -4,7 +4,10 @@ use crate::error::{Error, StaleNodeError};
 use crate::internal_cache::InternalSdkCache;
 use crate::mock::MockResponse;
 #[cfg(feature = "mocks")]
-use crate::mock::{provider::GrpcContextProvider, MockDashPlatformSdk};
+use crate::mock::{
+    provider::{GrpcContextProvider, RecordingContextProvider},
+    MockDashPlatformSdk,
+};
 use crate::platform::transition::put_settings::PutSettings;
 use crate::platform::{Fetch, Identifier};
 use arc_swap::ArcSwapOption;
@@ -65,6 +68,8 @@ const DEFAULT_REQUEST_SETTINGS: RequestSettings = RequestSettings {
     timeout: None,
     ban_failed_address: None,
     connect_timeout: None,
+    hedge_delay: None,
+    retry_policy: None,
 };
 
 /// a type to represent staleness in seconds
@@ -775,6 +780,12 @@ pub struct SdkBuilder {
     #[cfg(feature = "mocks")]
     dump_dir: Option<PathBuf>,
 
+    /// Record the whole session in `dump_dir`.
+    ///
+    /// See [SdkBuilder::with_session_recording] for more information.
+    #[cfg(feature = "mocks")]
+    session_recording: bool,
+
     /// Cancellation token; once cancelled, all pending requests should be aborted.
     pub(crate) cancel_token: CancellationToken,
 
@@ -821,6 +832,8 @@ impl Default for SdkBuilder {
 
             #[cfg(feature = "mocks")]
             dump_dir: None,
+            #[cfg(feature = "mocks")]
+            session_recording: false,
         }
     }
 }
@@ -1035,6 +1048,39 @@ impl SdkBuilder {
         self
     }
 
+    /// Record all requests sent to Platform during the session in `dump_dir`, so the session can be
+    /// replayed offline with [SdkBuilder::with_session_replay()].
+    ///
+    /// Unlike [SdkBuilder::with_dump_dir()], every response is saved, even if the same request is sent
+    /// multiple times, and responses are replayed in the order they were received. Quorum public keys and
+    /// data contracts returned by the context provider configured with [SdkBuilder::with_context_provider()]
+    /// are saved alongside. Context provider set later with [Sdk::set_context_provider()] is not recorded.
+    ///
+    /// The directory is created when the Sdk is built.
+    ///
+    /// Available only when `mocks` feature is enabled.
+    #[cfg(feature = "mocks")]
+    pub fn with_session_recording(mut self, dump_dir: &Path) -> Self {
+        self.dump_dir = Some(dump_dir.to_path_buf());
+        self.session_recording = true;
+        self
+    }
+
+    /// Replay session recorded with [SdkBuilder::with_session_recording()] instead of connecting to Platform.
+    ///
+    /// The Sdk is built in mock mode and serves all responses, quorum public keys and data contracts from `dump_dir`.
+    /// As recorded responses are old, time tolerance configured with [SdkBuilder::with_time_tolerance()] is disabled.
+    ///
+    /// Available only when `mocks` feature is enabled.
+    #[cfg(feature = "mocks")]
+    pub fn with_session_replay(mut self, dump_dir: &Path) -> Self {
+        self.addresses = None;
+        self.dump_dir = Some(dump_dir.to_path_buf());
+        self.session_recording = false;
+        self.metadata_time_tolerance_ms = None;
+        self
+    }
+
     /// Build the Sdk instance.
     ///
     /// This method will create the Sdk instance based on the configuration provided to the builder.
@@ -1050,6 +1096,17 @@ impl SdkBuilder {
             None => DEFAULT_REQUEST_SETTINGS,
         };
 
+        #[cfg(feature = "mocks")]
+        if let (Some(dump_dir), true) = (&self.dump_dir, self.session_recording) {
+            std::fs::create_dir_all(dump_dir).map_err(|e| {
+                Error::Config(format!(
+                    "cannot create session recording directory {}: {}",
+                    dump_dir.display(),
+                    e
+                ))
+            })?;
+        }
+
         let sdk= match self.addresses {
             // non-mock mode
             Some(addresses) => {
@@ -1061,7 +1118,20 @@ impl SdkBuilder {
                 }
 
                 #[cfg(feature = "mocks")]
-                let dapi = dapi.dump_dir(self.dump_dir.clone());
+                let dapi = match (&self.dump_dir, self.session_recording) {
+                    (Some(dump_dir), true) => dapi.record_session(dump_dir.clone()),
+                    (dump_dir, _) => dapi.dump_dir(dump_dir.clone()),
+                };
+
+                #[cfg(feature = "mocks")]
+                let context_provider = match (self.context_provider, &self.dump_dir, self.session_recording) {
+                    (Some(context_provider), Some(dump_dir), true) => Some(Box::new(
+                        RecordingContextProvider::new(context_provider, dump_dir.clone()),
+                    ) as Box<dyn ContextProvider>),
+                    (context_provider, _, _) => context_provider,
+                };
+                #[cfg(not(feature = "mocks"))]
+                let context_provider = self.context_provider;
 
                 #[allow(unused_mut)] // needs to be mutable for #[cfg(feature = "mocks")]
                 let mut sdk= Sdk{
@@ -1069,7 +1139,7 @@ impl SdkBuilder {
                     dapi_client_settings,
                     inner:SdkInstance::Dapi { dapi,  version:self.version },
                     proofs:self.proofs,
-                    context_provider: ArcSwapOption::new( self.context_provider.map(Arc::new)),
+                    context_provider: ArcSwapOption::new(context_provider.map(Arc::new)),
                     cancel_token: self.cancel_token,
                     internal_cache: Default::default(),
                     // Note: in the future, we need to securely initialize initial height during Sdk bootstrap or first request.
@@ -8,6 +8,7 @@ use arc_swap::ArcSwap;
 use dash_context_provider::ContextProviderError;
 use rs_dapi_client::{
     update_address_ban_status, AddressList, CanRetry, ExecutionResult, RequestSettings,
+    RetryBackoff,
 };
 use std::fmt::Display;
 use std::{
@@ -183,9 +184,9 @@ where
 {
     let max_retries = settings.retries.unwrap_or_default();
 
-    let backoff_strategy = backon::ConstantBuilder::default()
-        .with_delay(std::time::Duration::from_millis(10)) // we use different server, so no real delay needed, just to avoid spamming
-        .with_max_times(max_retries);
+    let backoff_strategy =
+        RetryBackoff::new(settings.retry_policy.unwrap_or_default(), max_retries);
+    let retry_decision = backoff_strategy.clone();
 
     let mut retries: usize = 0;
 
@@ -217,7 +218,7 @@ where
 
     let result = ::backon::Retryable::retry(closure, backoff_strategy)
         .when(|e| {
-            if e.can_retry() {
+            if retry_decision.should_retry(e) {
                 // requests sent for current execution attempt;
                 let requests_sent = e.retries + 1;
 
@@ -257,7 +258,7 @@ where
 mod test {
     use super::*;
     use derive_more::Display;
-    use rs_dapi_client::ExecutionError;
+    use rs_dapi_client::{ExecutionError, RetryPolicy};
     use std::{
         future::Future,
         sync::atomic::{AtomicUsize, Ordering},
@@ -382,6 +383,7 @@ mod test {
             // we retry 5 times, and expect 5 retries + 1 initial request
             let mut global_settings = RequestSettings::default();
             global_settings.retries = Some(expected_requests - 1);
+            global_settings.retry_policy = Some(RetryPolicy::immediate());
 
             let closure = |s| {
                 let counter = counter.clone();
@@ -0,0 +1,51 @@
+use crate::identifier::IdentifierWrapper;
+use dpp::balances::credits::TokenAmount;
+use dpp::data_contract::GroupContractPosition;
+use dpp::state_transition::batch_transition::token_approve_transition::v0::v0_methods::TokenApproveTransitionV0Methods;
+use dpp::state_transition::batch_transition::TokenApproveTransition;
+use dpp::tokens::allowance::spender::TokenAllowanceSpender;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen(js_name=TokenApproveTransition)]
+#[derive(Debug, Clone)]
+pub struct TokenApproveTransitionWasm(TokenApproveTransition);
+
+impl From<TokenApproveTransition> for TokenApproveTransitionWasm {
+    fn from(value: TokenApproveTransition) -> Self {
+        Self(value)
+    }
+}
+
+#[wasm_bindgen(js_class = TokenApproveTransition)]
+impl TokenApproveTransitionWasm {
+    #[wasm_bindgen(js_name=getSpenderIdentityId)]
+    pub fn spender_identity_id(&self) -> Option<IdentifierWrapper> {
+        match self.0.spender() {
+            TokenAllowanceSpender::Identity(identity_id) => Some(identity_id.into()),
+            TokenAllowanceSpender::Group(_) => None,
+        }
+    }
+
+    #[wasm_bindgen(js_name=getSpenderGroupPosition)]
+    pub fn spender_group_position(&self) -> Option<GroupContractPosition> {
+        match self.0.spender() {
+            TokenAllowanceSpender::Identity(_) => None,
+            TokenAllowanceSpender::Group(position) => Some(position),
+        }
+    }
+
+    #[wasm_bindgen(js_name=getAmount)]
+    pub fn amount(&self) -> TokenAmount {
+        self.0.amount()
+    }
+
+    #[wasm_bindgen(js_name=getExpiresAt)]
+    pub fn expires_at(&self) -> Option<u64> {
+        self.0.expires_at()
+    }
+
+    #[wasm_bindgen(js_name=getPublicNote)]
+    pub fn public_note(&self) -> Option<String> {
+        self.0.public_note().cloned()
+    }
+}
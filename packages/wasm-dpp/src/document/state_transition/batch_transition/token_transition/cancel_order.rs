@@ -0,0 +1,22 @@
+use crate::identifier::IdentifierWrapper;
+use dpp::state_transition::batch_transition::token_cancel_order_transition::v0::v0_methods::TokenCancelOrderTransitionV0Methods;
+use dpp::state_transition::batch_transition::TokenCancelOrderTransition;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen(js_name=TokenCancelOrderTransition)]
+#[derive(Debug, Clone)]
+pub struct TokenCancelOrderTransitionWasm(TokenCancelOrderTransition);
+
+impl From<TokenCancelOrderTransition> for TokenCancelOrderTransitionWasm {
+    fn from(value: TokenCancelOrderTransition) -> Self {
+        Self(value)
+    }
+}
+
+#[wasm_bindgen(js_class = TokenCancelOrderTransition)]
+impl TokenCancelOrderTransitionWasm {
+    #[wasm_bindgen(js_name=getOrderId)]
+    pub fn order_id(&self) -> IdentifierWrapper {
+        self.0.order_id().into()
+    }
+}
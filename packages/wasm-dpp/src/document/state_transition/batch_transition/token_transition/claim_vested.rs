@@ -0,0 +1,22 @@
+use crate::identifier::IdentifierWrapper;
+use dpp::state_transition::batch_transition::token_claim_vested_transition::v0::v0_methods::TokenClaimVestedTransitionV0Methods;
+use dpp::state_transition::batch_transition::TokenClaimVestedTransition;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen(js_name=TokenClaimVestedTransition)]
+#[derive(Debug, Clone)]
+pub struct TokenClaimVestedTransitionWasm(TokenClaimVestedTransition);
+
+impl From<TokenClaimVestedTransition> for TokenClaimVestedTransitionWasm {
+    fn from(value: TokenClaimVestedTransition) -> Self {
+        Self(value)
+    }
+}
+
+#[wasm_bindgen(js_class = TokenClaimVestedTransition)]
+impl TokenClaimVestedTransitionWasm {
+    #[wasm_bindgen(js_name=getVestingId)]
+    pub fn vesting_id(&self) -> IdentifierWrapper {
+        self.0.vesting_id().into()
+    }
+}
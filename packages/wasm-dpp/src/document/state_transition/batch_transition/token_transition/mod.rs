@@ -1,26 +1,38 @@
+pub mod approve;
 pub mod burn;
+pub mod cancel_order;
 mod claim;
+pub mod claim_vested;
 pub mod config;
 pub mod destroy;
 pub mod direct_purchase;
 pub mod emergency_action;
 pub mod freeze;
 pub mod mint;
+pub mod place_order;
 pub mod set_price_for_direct_purchase;
 pub mod transfer;
+pub mod transfer_from;
 pub mod unfreeze;
+pub mod vesting_transfer;
 
+use crate::batch_transition::token_transition::approve::TokenApproveTransitionWasm;
 use crate::batch_transition::token_transition::burn::TokenBurnTransitionWasm;
+use crate::batch_transition::token_transition::cancel_order::TokenCancelOrderTransitionWasm;
 use crate::batch_transition::token_transition::claim::TokenClaimTransitionWasm;
+use crate::batch_transition::token_transition::claim_vested::TokenClaimVestedTransitionWasm;
 use crate::batch_transition::token_transition::config::TokenConfigUpdateTransitionWasm;
 use crate::batch_transition::token_transition::destroy::TokenDestroyFrozenFundsTransitionWasm;
 use crate::batch_transition::token_transition::direct_purchase::TokenDirectPurchaseTransitionWasm;
 use crate::batch_transition::token_transition::emergency_action::TokenEmergencyActionTransitionWasm;
 use crate::batch_transition::token_transition::freeze::TokenFreezeTransitionWasm;
 use crate::batch_transition::token_transition::mint::TokenMintTransitionWasm;
+use crate::batch_transition::token_transition::place_order::TokenPlaceOrderTransitionWasm;
 use crate::batch_transition::token_transition::set_price_for_direct_purchase::TokenSetPriceForDirectPurchaseTransitionWasm;
 use crate::batch_transition::token_transition::transfer::TokenTransferTransitionWasm;
+use crate::batch_transition::token_transition::transfer_from::TokenTransferFromTransitionWasm;
 use crate::batch_transition::token_transition::unfreeze::TokenUnfreezeTransitionWasm;
+use crate::batch_transition::token_transition::vesting_transfer::TokenVestingTransferTransitionWasm;
 use crate::identifier::IdentifierWrapper;
 use dpp::prelude::IdentityNonce;
 use dpp::state_transition::batch_transition::batched_transition::token_transition::{
@@ -44,6 +56,12 @@ pub enum TokenTransitionType {
     ConfigUpdate,
     DirectPurchase,
     SetPriceForDirectPurchase,
+    PlaceOrder,
+    CancelOrder,
+    VestingTransfer,
+    ClaimVested,
+    Approve,
+    TransferFrom,
 }
 
 impl From<&TokenTransition> for TokenTransitionType {
@@ -62,6 +80,12 @@ impl From<&TokenTransition> for TokenTransitionType {
             TokenTransition::SetPriceForDirectPurchase(_) => {
                 TokenTransitionType::SetPriceForDirectPurchase
             }
+            TokenTransition::PlaceOrder(_) => TokenTransitionType::PlaceOrder,
+            TokenTransition::CancelOrder(_) => TokenTransitionType::CancelOrder,
+            TokenTransition::VestingTransfer(_) => TokenTransitionType::VestingTransfer,
+            TokenTransition::ClaimVested(_) => TokenTransitionType::ClaimVested,
+            TokenTransition::Approve(_) => TokenTransitionType::Approve,
+            TokenTransition::TransferFrom(_) => TokenTransitionType::TransferFrom,
         }
     }
 }
@@ -149,6 +173,24 @@ impl TokenTransitionWasm {
             TokenTransition::SetPriceForDirectPurchase(set_price) => {
                 TokenSetPriceForDirectPurchaseTransitionWasm::from(set_price.clone()).into()
             }
+            TokenTransition::PlaceOrder(place_order) => {
+                TokenPlaceOrderTransitionWasm::from(place_order.clone()).into()
+            }
+            TokenTransition::CancelOrder(cancel_order) => {
+                TokenCancelOrderTransitionWasm::from(cancel_order.clone()).into()
+            }
+            TokenTransition::VestingTransfer(vesting_transfer) => {
+                TokenVestingTransferTransitionWasm::from(vesting_transfer.clone()).into()
+            }
+            TokenTransition::ClaimVested(claim_vested) => {
+                TokenClaimVestedTransitionWasm::from(claim_vested.clone()).into()
+            }
+            TokenTransition::Approve(approve) => {
+                TokenApproveTransitionWasm::from(approve.clone()).into()
+            }
+            TokenTransition::TransferFrom(transfer_from) => {
+                TokenTransferFromTransitionWasm::from(transfer_from.clone()).into()
+            }
         }
     }
 }
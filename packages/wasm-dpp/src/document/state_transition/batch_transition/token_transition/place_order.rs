@@ -0,0 +1,38 @@
+use dpp::balances::credits::TokenAmount;
+use dpp::state_transition::batch_transition::token_place_order_transition::v0::v0_methods::TokenPlaceOrderTransitionV0Methods;
+use dpp::state_transition::batch_transition::TokenPlaceOrderTransition;
+use dpp::tokens::order_book::TokenOrderSide;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen(js_name=TokenPlaceOrderTransition)]
+#[derive(Debug, Clone)]
+pub struct TokenPlaceOrderTransitionWasm(TokenPlaceOrderTransition);
+
+impl From<TokenPlaceOrderTransition> for TokenPlaceOrderTransitionWasm {
+    fn from(value: TokenPlaceOrderTransition) -> Self {
+        Self(value)
+    }
+}
+
+#[wasm_bindgen(js_class = TokenPlaceOrderTransition)]
+impl TokenPlaceOrderTransitionWasm {
+    #[wasm_bindgen(js_name=isBuy)]
+    pub fn is_buy(&self) -> bool {
+        self.0.side() == TokenOrderSide::Buy
+    }
+
+    #[wasm_bindgen(js_name=getQuoteCurrency)]
+    pub fn quote_currency(&self) -> String {
+        self.0.quote_currency().to_string()
+    }
+
+    #[wasm_bindgen(js_name=getAmount)]
+    pub fn amount(&self) -> TokenAmount {
+        self.0.amount()
+    }
+
+    #[wasm_bindgen(js_name=getPricePerToken)]
+    pub fn price_per_token(&self) -> u64 {
+        self.0.price_per_token()
+    }
+}
@@ -0,0 +1,38 @@
+use crate::identifier::IdentifierWrapper;
+use dpp::balances::credits::TokenAmount;
+use dpp::state_transition::batch_transition::token_transfer_from_transition::v0::v0_methods::TokenTransferFromTransitionV0Methods;
+use dpp::state_transition::batch_transition::TokenTransferFromTransition;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen(js_name=TokenTransferFromTransition)]
+#[derive(Debug, Clone)]
+pub struct TokenTransferFromTransitionWasm(TokenTransferFromTransition);
+
+impl From<TokenTransferFromTransition> for TokenTransferFromTransitionWasm {
+    fn from(value: TokenTransferFromTransition) -> Self {
+        Self(value)
+    }
+}
+
+#[wasm_bindgen(js_class = TokenTransferFromTransition)]
+impl TokenTransferFromTransitionWasm {
+    #[wasm_bindgen(js_name=getFromIdentityId)]
+    pub fn from_identity_id(&self) -> IdentifierWrapper {
+        self.0.from_identity_id().into()
+    }
+
+    #[wasm_bindgen(js_name=getRecipientId)]
+    pub fn recipient_id(&self) -> IdentifierWrapper {
+        self.0.recipient_id().into()
+    }
+
+    #[wasm_bindgen(js_name=getAmount)]
+    pub fn amount(&self) -> TokenAmount {
+        self.0.amount()
+    }
+
+    #[wasm_bindgen(js_name=getPublicNote)]
+    pub fn public_note(&self) -> Option<String> {
+        self.0.public_note().cloned()
+    }
+}
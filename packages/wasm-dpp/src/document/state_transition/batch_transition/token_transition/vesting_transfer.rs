@@ -0,0 +1,43 @@
+use crate::identifier::IdentifierWrapper;
+use dpp::balances::credits::TokenAmount;
+use dpp::state_transition::batch_transition::token_vesting_transfer_transition::v0::v0_methods::TokenVestingTransferTransitionV0Methods;
+use dpp::state_transition::batch_transition::TokenVestingTransferTransition;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen(js_name=TokenVestingTransferTransition)]
+#[derive(Debug, Clone)]
+pub struct TokenVestingTransferTransitionWasm(TokenVestingTransferTransition);
+
+impl From<TokenVestingTransferTransition> for TokenVestingTransferTransitionWasm {
+    fn from(value: TokenVestingTransferTransition) -> Self {
+        Self(value)
+    }
+}
+
+#[wasm_bindgen(js_class = TokenVestingTransferTransition)]
+impl TokenVestingTransferTransitionWasm {
+    #[wasm_bindgen(js_name=getRecipientId)]
+    pub fn recipient_id(&self) -> IdentifierWrapper {
+        self.0.recipient_id().into()
+    }
+
+    #[wasm_bindgen(js_name=getAmount)]
+    pub fn amount(&self) -> TokenAmount {
+        self.0.amount()
+    }
+
+    #[wasm_bindgen(js_name=getCliffDuration)]
+    pub fn cliff_duration(&self) -> u64 {
+        self.0.schedule().cliff_duration
+    }
+
+    #[wasm_bindgen(js_name=getInterval)]
+    pub fn interval(&self) -> u64 {
+        self.0.schedule().interval
+    }
+
+    #[wasm_bindgen(js_name=getPublicNote)]
+    pub fn public_note(&self) -> Option<String> {
+        self.0.public_note().cloned()
+    }
+}
@@ -61,12 +61,16 @@ use dpp::consensus::state::data_trigger::DataTriggerError::{
   DataTriggerConditionError, DataTriggerExecutionError, DataTriggerInvalidResultError,
 };
 use wasm_bindgen::{JsError, JsValue};
-use dpp::consensus::basic::data_contract::{ContestedUniqueIndexOnMutableDocumentTypeError, ContestedUniqueIndexWithUniqueIndexError, DataContractTokenConfigurationUpdateError, DecimalsOverLimitError, DuplicateKeywordsError, GroupExceedsMaxMembersError, GroupHasTooFewMembersError, GroupMemberHasPowerOfZeroError, GroupMemberHasPowerOverLimitError, GroupNonUnilateralMemberPowerHasLessThanRequiredPowerError, GroupPositionDoesNotExistError, GroupRequiredPowerIsInvalidError, GroupTotalPowerLessThanRequiredError, InvalidDescriptionLengthError, InvalidDocumentTypeRequiredSecurityLevelError, InvalidKeywordCharacterError, InvalidKeywordLengthError, InvalidTokenBaseSupplyError, InvalidTokenDistributionFunctionDivideByZeroError, InvalidTokenDistributionFunctionIncoherenceError, InvalidTokenDistributionFunctionInvalidParameterError, InvalidTokenDistributionFunctionInvalidParameterTupleError, InvalidTokenLanguageCodeError, InvalidTokenNameCharacterError, InvalidTokenNameLengthError, MainGroupIsNotDefinedError, NewTokensDestinationIdentityOptionRequiredError, NonContiguousContractGroupPositionsError, NonContiguousContractTokenPositionsError, RedundantDocumentPaidForByTokenWithContractId, TokenPaymentByBurningOnlyAllowedOnInternalTokenError, TooManyKeywordsError, UnknownDocumentActionTokenEffectError, UnknownDocumentCreationRestrictionModeError, UnknownGasFeesPaidByError, UnknownSecurityLevelError, UnknownStorageKeyRequirementsError, UnknownTradeModeError, UnknownTransferableTypeError};
+use dpp::consensus::basic::data_contract::{ContestedUniqueIndexOnMutableDocumentTypeError, ContestedUniqueIndexWithUniqueIndexError, DataContractTokenConfigurationUpdateError, DecimalsOverLimitError, DuplicateKeywordsError, GroupExceedsMaxMembersError, GroupHasTooFewMembersError, GroupMemberHasPowerOfZeroError, GroupMemberHasPowerOverLimitError, GroupNonUnilateralMemberPowerHasLessThanRequiredPowerError, GroupPositionDoesNotExistError, GroupRequiredPowerIsInvalidError, GroupTotalPowerLessThanRequiredError, InvalidDescriptionLengthError, InvalidDocumentTypeRequiredSecurityLevelError, InvalidDocumentTypeTimeToLiveError, InvalidKeywordCharacterError, InvalidKeywordLengthError, InvalidTokenBaseSupplyError, InvalidTokenDistributionFunctionDivideByZeroError, InvalidTokenDistributionFunctionIncoherenceError, InvalidTokenDistributionFunctionInvalidParameterError, InvalidTokenDistributionFunctionInvalidParameterTupleError, InvalidTokenLanguageCodeError, InvalidTokenNameCharacterError, InvalidTokenNameLengthError, MainGroupIsNotDefinedError, NewTokensDestinationIdentityOptionRequiredError, NonContiguousContractGroupPositionsError, NonContiguousContractTokenPositionsError, RedundantDocumentPaidForByTokenWithContractId, TokenPaymentByBurningOnlyAllowedOnInternalTokenError, TooManyKeywordsError, UnknownDocumentActionTokenEffectError, UnknownDocumentCreationRestrictionModeError, UnknownGasFeesPaidByError, UnknownSecurityLevelError, UnknownStorageKeyRequirementsError, UnknownTradeModeError, UnknownTransferableTypeError};
 use dpp::consensus::basic::document::{ContestedDocumentsTemporarilyNotAllowedError, DocumentCreationNotAllowedError, DocumentFieldMaxSizeExceededError, MaxDocumentsTransitionsExceededError, MissingPositionsInDocumentTypePropertiesError};
 use dpp::consensus::basic::group::GroupActionNotAllowedOnTransitionError;
 use dpp::consensus::basic::identity::{DataContractBoundsNotPresentError, DisablingKeyIdAlsoBeingAddedInSameTransitionError, InvalidIdentityCreditWithdrawalTransitionAmountError, InvalidIdentityUpdateTransitionDisableKeysError, InvalidIdentityUpdateTransitionEmptyError, InvalidKeyPurposeForContractBoundsError, TooManyMasterPublicKeyError, WithdrawalOutputScriptNotAllowedWhenSigningWithOwnerKeyError};
 use dpp::consensus::basic::overflow_error::OverflowError;
-use dpp::consensus::basic::token::{ChoosingTokenMintRecipientNotAllowedError, ContractHasNoTokensError, DestinationIdentityForTokenMintingNotSetError, InvalidActionIdError, InvalidTokenAmountError, InvalidTokenConfigUpdateNoChangeError, InvalidTokenIdError, InvalidTokenNoteTooBigError, InvalidTokenPositionError, MissingDefaultLocalizationError, TokenNoteOnlyAllowedWhenProposerError, TokenTransferToOurselfError, InvalidTokenDistributionTimeIntervalNotMinuteAlignedError, InvalidTokenDistributionTimeIntervalTooShortError, InvalidTokenDistributionBlockIntervalTooShortError};
+use dpp::consensus::basic::token::{ChoosingTokenMintRecipientNotAllowedError, ContractHasNoTokensError, DestinationIdentityForTokenMintingNotSetError, InvalidActionIdError, InvalidTokenAmountError, InvalidTokenConfigUpdateNoChangeError, InvalidTokenIdError, InvalidTokenNoteTooBigError, InvalidTokenPositionError, MissingDefaultLocalizationError, TokenNoteOnlyAllowedWhenProposerError, TokenTransferToOurselfError, InvalidTokenDistributionTimeIntervalNotMinuteAlignedError, InvalidTokenDistributionTimeIntervalTooShortError, InvalidTokenDistributionBlockIntervalTooShortError, InvalidTokenOrderPriceError, TokenOrderQuotedInSameTokenError, InvalidTokenVestingScheduleError, TokenApproveToOurselfError};
+use dpp::consensus::state::data_contract::data_contract_delete_permission_error::DataContractDeletePermissionError;
+use dpp::consensus::state::data_contract::data_contract_has_active_tokens_or_groups_error::DataContractHasActiveTokensOrGroupsError;
+use dpp::consensus::state::data_contract::data_contract_has_documents_error::DataContractHasDocumentsError;
+use dpp::consensus::state::data_contract::data_contract_not_deletable_error::DataContractNotDeletableError;
 use dpp::consensus::state::data_contract::data_contract_not_found_error::DataContractNotFoundError;
 use dpp::consensus::state::data_contract::data_contract_update_action_not_allowed_error::DataContractUpdateActionNotAllowedError;
 use dpp::consensus::state::data_contract::document_type_update_error::DocumentTypeUpdateError;
@@ -87,8 +91,13 @@ use dpp::consensus::state::identity::no_transfer_key_for_core_withdrawal_availab
 use dpp::consensus::state::identity::RecipientIdentityDoesNotExistError;
 use dpp::consensus::state::prefunded_specialized_balances::prefunded_specialized_balance_insufficient_error::PrefundedSpecializedBalanceInsufficientError;
 use dpp::consensus::state::prefunded_specialized_balances::prefunded_specialized_balance_not_found_error::PrefundedSpecializedBalanceNotFoundError;
-use dpp::consensus::state::token::{IdentityDoesNotHaveEnoughTokenBalanceError, IdentityTokenAccountNotFrozenError, IdentityTokenAccountFrozenError, TokenIsPausedError, IdentityTokenAccountAlreadyFrozenError, UnauthorizedTokenActionError, TokenSettingMaxSupplyToLessThanCurrentSupplyError, TokenMintPastMaxSupplyError, NewTokensDestinationIdentityDoesNotExistError, NewAuthorizedActionTakerIdentityDoesNotExistError, NewAuthorizedActionTakerGroupDoesNotExistError, NewAuthorizedActionTakerMainGroupNotSetError, InvalidGroupPositionError, TokenAlreadyPausedError, TokenNotPausedError, InvalidTokenClaimPropertyMismatch, InvalidTokenClaimNoCurrentRewards, InvalidTokenClaimWrongClaimant, TokenTransferRecipientIdentityNotExistError, PreProgrammedDistributionTimestampInPastError, IdentityHasNotAgreedToPayRequiredTokenAmountError, RequiredTokenPaymentInfoNotSetError, IdentityTryingToPayWithWrongTokenError, TokenDirectPurchaseUserPriceTooLow, TokenAmountUnderMinimumSaleAmount, TokenNotForDirectSale, InvalidTokenPositionStateError};
+use dpp::consensus::state::token::{IdentityDoesNotHaveEnoughTokenBalanceError, IdentityTokenAccountNotFrozenError, IdentityTokenAccountFrozenError, TokenIsPausedError, IdentityTokenAccountAlreadyFrozenError, UnauthorizedTokenActionError, TokenSettingMaxSupplyToLessThanCurrentSupplyError, TokenMintPastMaxSupplyError, NewTokensDestinationIdentityDoesNotExistError, NewAuthorizedActionTakerIdentityDoesNotExistError, NewAuthorizedActionTakerGroupDoesNotExistError, NewAuthorizedActionTakerMainGroupNotSetError, InvalidGroupPositionError, TokenAlreadyPausedError, TokenNotPausedError, InvalidTokenClaimPropertyMismatch, InvalidTokenClaimNoCurrentRewards, InvalidTokenClaimWrongClaimant, TokenTransferRecipientIdentityNotExistError, PreProgrammedDistributionTimestampInPastError, IdentityHasNotAgreedToPayRequiredTokenAmountError, RequiredTokenPaymentInfoNotSetError, IdentityTryingToPayWithWrongTokenError, TokenDirectPurchaseUserPriceTooLow, TokenAmountUnderMinimumSaleAmount, TokenNotForDirectSale, InvalidTokenPositionStateError, TokenOrderBookTradingNotAllowedError, TokenOrderNotFoundError, TokenOrderNotOwnedError, TokenVestingNotFoundError, TokenVestingNothingToClaimError, TokenAllowanceNotFoundError, TokenAllowanceExceededError, TokenAllowanceExpiredError, TokenOrderSelfTradeError, TokenOrderFillLimitExceededError};
 use dpp::consensus::state::voting::masternode_incorrect_voter_identity_id_error::MasternodeIncorrectVoterIdentityIdError;
+use dpp::consensus::state::voting::yes_no_abstain_vote_poll_not_open_error::YesNoAbstainVotePollNotOpenError;
+use dpp::consensus::state::voting::masternode_not_eligible_for_vote_poll_error::MasternodeNotEligibleForVotePollError;
+use dpp::consensus::state::voting::unauthorized_vote_poll_creator_error::UnauthorizedVotePollCreatorError;
+use dpp::consensus::state::voting::vote_poll_already_exists_error::VotePollAlreadyExistsError;
+use dpp::consensus::state::voting::invalid_yes_no_abstain_vote_poll_question_error::InvalidYesNoAbstainVotePollQuestionError;
 use dpp::consensus::state::voting::masternode_incorrect_voting_address_error::MasternodeIncorrectVotingAddressError;
 use dpp::consensus::state::voting::masternode_not_found_error::MasternodeNotFoundError;
 use dpp::consensus::state::voting::masternode_vote_already_present_error::MasternodeVoteAlreadyPresentError;
@@ -430,6 +439,63 @@ pub fn from_state_error(state_error: &StateError) -> JsValue {
         StateError::InvalidTokenPositionStateError(e) => {
             generic_consensus_error!(InvalidTokenPositionStateError, e).into()
         }
+        StateError::TokenOrderBookTradingNotAllowedError(e) => {
+            generic_consensus_error!(TokenOrderBookTradingNotAllowedError, e).into()
+        }
+        StateError::TokenOrderNotFoundError(e) => {
+            generic_consensus_error!(TokenOrderNotFoundError, e).into()
+        }
+        StateError::TokenOrderNotOwnedError(e) => {
+            generic_consensus_error!(TokenOrderNotOwnedError, e).into()
+        }
+        StateError::DataContractNotDeletableError(e) => {
+            generic_consensus_error!(DataContractNotDeletableError, e).into()
+        }
+        StateError::DataContractDeletePermissionError(e) => {
+            generic_consensus_error!(DataContractDeletePermissionError, e).into()
+        }
+        StateError::DataContractHasActiveTokensOrGroupsError(e) => {
+            generic_consensus_error!(DataContractHasActiveTokensOrGroupsError, e).into()
+        }
+        StateError::DataContractHasDocumentsError(e) => {
+            generic_consensus_error!(DataContractHasDocumentsError, e).into()
+        }
+        StateError::YesNoAbstainVotePollNotOpenError(e) => {
+            generic_consensus_error!(YesNoAbstainVotePollNotOpenError, e).into()
+        }
+        StateError::InvalidYesNoAbstainVotePollQuestionError(e) => {
+            generic_consensus_error!(InvalidYesNoAbstainVotePollQuestionError, e).into()
+        }
+        StateError::TokenVestingNotFoundError(e) => {
+            generic_consensus_error!(TokenVestingNotFoundError, e).into()
+        }
+        StateError::TokenVestingNothingToClaimError(e) => {
+            generic_consensus_error!(TokenVestingNothingToClaimError, e).into()
+        }
+        StateError::TokenAllowanceNotFoundError(e) => {
+            generic_consensus_error!(TokenAllowanceNotFoundError, e).into()
+        }
+        StateError::TokenAllowanceExceededError(e) => {
+            generic_consensus_error!(TokenAllowanceExceededError, e).into()
+        }
+        StateError::TokenAllowanceExpiredError(e) => {
+            generic_consensus_error!(TokenAllowanceExpiredError, e).into()
+        }
+        StateError::TokenOrderSelfTradeError(e) => {
+            generic_consensus_error!(TokenOrderSelfTradeError, e).into()
+        }
+        StateError::TokenOrderFillLimitExceededError(e) => {
+            generic_consensus_error!(TokenOrderFillLimitExceededError, e).into()
+        }
+        StateError::UnauthorizedVotePollCreatorError(e) => {
+            generic_consensus_error!(UnauthorizedVotePollCreatorError, e).into()
+        }
+        StateError::VotePollAlreadyExistsError(e) => {
+            generic_consensus_error!(VotePollAlreadyExistsError, e).into()
+        }
+        StateError::MasternodeNotEligibleForVotePollError(e) => {
+            generic_consensus_error!(MasternodeNotEligibleForVotePollError, e).into()
+        }
     }
 }
 
@@ -850,6 +916,21 @@ fn from_basic_error(basic_error: &BasicError) -> JsValue {
         BasicError::InvalidKeyPurposeForContractBoundsError(e) => {
             generic_consensus_error!(InvalidKeyPurposeForContractBoundsError, e).into()
         }
+        BasicError::InvalidTokenOrderPriceError(e) => {
+            generic_consensus_error!(InvalidTokenOrderPriceError, e).into()
+        }
+        BasicError::TokenOrderQuotedInSameTokenError(e) => {
+            generic_consensus_error!(TokenOrderQuotedInSameTokenError, e).into()
+        }
+        BasicError::InvalidDocumentTypeTimeToLiveError(e) => {
+            generic_consensus_error!(InvalidDocumentTypeTimeToLiveError, e).into()
+        }
+        BasicError::InvalidTokenVestingScheduleError(e) => {
+            generic_consensus_error!(InvalidTokenVestingScheduleError, e).into()
+        }
+        BasicError::TokenApproveToOurselfError(e) => {
+            generic_consensus_error!(TokenApproveToOurselfError, e).into()
+        }
     }
 }
 
@@ -13,6 +13,8 @@ pub enum StateTransitionTypeWasm {
     IdentityCreditWithdrawal = 6,
     IdentityCreditTransfer = 7,
     MasternodeVote = 8,
+    DataContractDelete = 9,
+    VotePollCreate = 10,
 }
 
 impl From<StateTransitionType> for StateTransitionTypeWasm {
@@ -31,6 +33,8 @@ impl From<StateTransitionType> for StateTransitionTypeWasm {
                 StateTransitionTypeWasm::IdentityCreditTransfer
             }
             StateTransitionType::MasternodeVote => StateTransitionTypeWasm::MasternodeVote,
+            StateTransitionType::DataContractDelete => StateTransitionTypeWasm::DataContractDelete,
+            StateTransitionType::VotePollCreate => StateTransitionTypeWasm::VotePollCreate,
         }
     }
 }
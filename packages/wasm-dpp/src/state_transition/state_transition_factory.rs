@@ -61,6 +61,12 @@ impl StateTransitionFactoryWasm {
                 StateTransition::MasternodeVote(st) => {
                     Ok(MasternodeVoteTransitionWasm::from(st).into())
                 }
+                StateTransition::DataContractDelete(_) => Err(JsValue::from_str(
+                    "data contract delete transition is not supported in wasm-dpp",
+                )),
+                StateTransition::VotePollCreate(_) => Err(JsValue::from_str(
+                    "vote poll create transition is not supported in wasm-dpp",
+                )),
             },
             Err(dpp::ProtocolError::StateTransitionError(e)) => match e {
                 StateTransitionError::InvalidStateTransitionError {
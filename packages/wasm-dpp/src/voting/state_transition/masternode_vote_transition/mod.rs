@@ -297,8 +297,10 @@ impl MasternodeVoteTransitionWasm {
 
                         Some(js_object)
                     }
+                    VotePoll::YesNoAbstainVotePoll(_) => None,
                 }
             }
+            Vote::YesNoAbstainVote(_) => None,
         }
     }
 
@@ -247,6 +247,7 @@ fn parse_where_clause(clause_js: &JsValue) -> Result<WhereClause, JsValue> {
         "BetweenExcludeRight" => WhereOperator::BetweenExcludeRight,
         "In" => WhereOperator::In,
         "StartsWith" => WhereOperator::StartsWith,
+        "Contains" => WhereOperator::Contains,
         _ => {
             return Err(format_error_with_context(
                 ErrorCategory::InvalidInput,
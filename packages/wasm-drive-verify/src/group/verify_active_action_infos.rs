@@ -697,6 +697,153 @@ fn token_event_to_js(event: &TokenEvent) -> Result<JsValue, JsValue> {
             )
             .map_err(|_| JsValue::from_str("Failed to set credits"))?;
         }
+        TokenEvent::PlaceOrder(side, quote_currency, amount, price_per_token) => {
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("type"),
+                &JsValue::from_str("PlaceOrder"),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set type"))?;
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("side"),
+                &JsValue::from_str(&side.to_string()),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set side"))?;
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("quoteCurrency"),
+                &JsValue::from_str(&quote_currency.to_string()),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set quoteCurrency"))?;
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("amount"),
+                &JsValue::from_str(&amount.to_string()),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set amount"))?;
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("pricePerToken"),
+                &JsValue::from_str(&price_per_token.to_string()),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set pricePerToken"))?;
+        }
+        TokenEvent::CancelOrder(order_id) => {
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("type"),
+                &JsValue::from_str("CancelOrder"),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set type"))?;
+            let order_id_array = Uint8Array::from(order_id.as_slice());
+            Reflect::set(&obj, &JsValue::from_str("orderId"), &order_id_array)
+                .map_err(|_| JsValue::from_str("Failed to set orderId"))?;
+        }
+        TokenEvent::VestingTransfer(recipient, amount, public_note) => {
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("type"),
+                &JsValue::from_str("VestingTransfer"),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set type"))?;
+            let recipient_array = Uint8Array::from(recipient.as_slice());
+            Reflect::set(&obj, &JsValue::from_str("recipient"), &recipient_array)
+                .map_err(|_| JsValue::from_str("Failed to set recipient"))?;
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("amount"),
+                &JsValue::from_str(&amount.to_string()),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set amount"))?;
+            match public_note {
+                Some(n) => Reflect::set(
+                    &obj,
+                    &JsValue::from_str("publicNote"),
+                    &JsValue::from_str(n),
+                ),
+                None => Reflect::set(&obj, &JsValue::from_str("publicNote"), &JsValue::NULL),
+            }
+            .map_err(|_| JsValue::from_str("Failed to set publicNote"))?;
+        }
+        TokenEvent::ClaimVested(vesting_id) => {
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("type"),
+                &JsValue::from_str("ClaimVested"),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set type"))?;
+            let vesting_id_array = Uint8Array::from(vesting_id.as_slice());
+            Reflect::set(&obj, &JsValue::from_str("vestingId"), &vesting_id_array)
+                .map_err(|_| JsValue::from_str("Failed to set vestingId"))?;
+        }
+        TokenEvent::Approve(spender, amount, expires_at, public_note) => {
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("type"),
+                &JsValue::from_str("Approve"),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set type"))?;
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("spender"),
+                &JsValue::from_str(&spender.to_string()),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set spender"))?;
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("amount"),
+                &JsValue::from_str(&amount.to_string()),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set amount"))?;
+            match expires_at {
+                Some(expires_at) => Reflect::set(
+                    &obj,
+                    &JsValue::from_str("expiresAt"),
+                    &JsValue::from_f64(*expires_at as f64),
+                ),
+                None => Reflect::set(&obj, &JsValue::from_str("expiresAt"), &JsValue::NULL),
+            }
+            .map_err(|_| JsValue::from_str("Failed to set expiresAt"))?;
+            match public_note {
+                Some(n) => Reflect::set(
+                    &obj,
+                    &JsValue::from_str("publicNote"),
+                    &JsValue::from_str(n),
+                ),
+                None => Reflect::set(&obj, &JsValue::from_str("publicNote"), &JsValue::NULL),
+            }
+            .map_err(|_| JsValue::from_str("Failed to set publicNote"))?;
+        }
+        TokenEvent::TransferFrom(from, recipient, amount, public_note) => {
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("type"),
+                &JsValue::from_str("TransferFrom"),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set type"))?;
+            let from_array = Uint8Array::from(from.as_slice());
+            Reflect::set(&obj, &JsValue::from_str("from"), &from_array)
+                .map_err(|_| JsValue::from_str("Failed to set from"))?;
+            let recipient_array = Uint8Array::from(recipient.as_slice());
+            Reflect::set(&obj, &JsValue::from_str("recipient"), &recipient_array)
+                .map_err(|_| JsValue::from_str("Failed to set recipient"))?;
+            Reflect::set(
+                &obj,
+                &JsValue::from_str("amount"),
+                &JsValue::from_str(&amount.to_string()),
+            )
+            .map_err(|_| JsValue::from_str("Failed to set amount"))?;
+            match public_note {
+                Some(n) => Reflect::set(
+                    &obj,
+                    &JsValue::from_str("publicNote"),
+                    &JsValue::from_str(n),
+                ),
+                None => Reflect::set(&obj, &JsValue::from_str("publicNote"), &JsValue::NULL),
+            }
+            .map_err(|_| JsValue::from_str("Failed to set publicNote"))?;
+        }
     }
 
     Ok(obj.into())
@@ -121,6 +121,7 @@ fn parse_where_clause(json_clause: &JsonValue) -> Result<WhereClause, WasmSdkErr
         "BetweenExcludeRight" => WhereOperator::BetweenExcludeRight,
         "in" | "In" => WhereOperator::In,
         "startsWith" | "StartsWith" => WhereOperator::StartsWith,
+        "contains" | "Contains" => WhereOperator::Contains,
         _ => {
             return Err(WasmSdkError::invalid_argument(format!(
                 "Unknown operator: {}",